@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -40,6 +44,63 @@ pub struct Base64DecodeImageResult {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Base64FileResult {
+    pub success: bool,
+    /// Plain Base64, or a `data:` URI when requested
+    pub output: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size_bytes: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodeFileInfo {
+    pub output_path: String,
+    pub size_bytes: usize,
+    /// Taken from the data URI prefix, or sniffed for images
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TextEncoding {
+    /// UTF-8 text
+    PlainText,
+    Base64,
+    /// RFC 4648 URL-safe alphabet (`-`, `_`)
+    Base64Url,
+    /// RFC 4648 Base32
+    Base32,
+    HexLower,
+    HexUpper,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodingConvertResult {
+    pub success: bool,
+    pub output: String,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Largest file accepted by `encode_file_to_base64` and produced by `decode_base64_to_file`
+pub const MAX_FILE_BYTES: usize = 50 * 1024 * 1024;
+
+fn size_limit_error(size: usize) -> String {
+    format!(
+        "File is too large ({:.1} MB); the limit is {} MB",
+        size as f64 / (1024.0 * 1024.0),
+        MAX_FILE_BYTES / (1024 * 1024)
+    )
+}
+
 /// Encode a string to Base64
 pub fn encode_base64(input: &str, url_safe: bool) -> Base64EncodeResult {
     use base64::{engine::general_purpose, Engine};
@@ -97,11 +158,7 @@ pub fn decode_base64(input: &str, url_safe: bool) -> Base64DecodeResult {
                 String::from_utf8(bytes).unwrap()
             } else {
                 // Convert to hex representation for binary data
-                bytes
-                    .iter()
-                    .map(|b| format!("{:02X}", b))
-                    .collect::<Vec<_>>()
-                    .join(" ")
+                hex_dump(&bytes)
             };
 
             Base64DecodeResult {
@@ -190,23 +247,7 @@ pub fn decode_base64_image(input: &str) -> Base64DecodeImageResult {
         };
     }
 
-    // Handle data URL format
-    let (mime_type, base64_data) = if input.starts_with("data:") {
-        // Parse data URL: data:image/png;base64,xxxxx
-        if let Some(comma_pos) = input.find(',') {
-            let header = &input[5..comma_pos]; // Skip "data:"
-            let mime = if let Some(semi_pos) = header.find(';') {
-                Some(header[..semi_pos].to_string())
-            } else {
-                Some(header.to_string())
-            };
-            (mime, &input[comma_pos + 1..])
-        } else {
-            (None, input)
-        }
-    } else {
-        (None, input)
-    };
+    let (mime_type, base64_data) = split_data_url(input);
 
     // Remove whitespace from base64 data
     let cleaned_input: String = base64_data.chars().filter(|c| !c.is_whitespace()).collect();
@@ -248,6 +289,662 @@ pub fn decode_base64_image(input: &str) -> Base64DecodeImageResult {
     }
 }
 
+/// Convert text between plain text, Base64, Base32 and hex by way of the underlying bytes.
+///
+/// Whitespace in encoded input is ignored and padding is optional. Bytes that are not valid
+/// UTF-8 are returned as a hex dump with a warning when converting to plain text.
+pub fn convert_encoding(
+    input: &str,
+    from: TextEncoding,
+    to: TextEncoding,
+) -> EncodingConvertResult {
+    let bytes = match decode_text(input, from) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return EncodingConvertResult {
+                success: false,
+                output: String::new(),
+                warnings: vec![],
+                error: Some(e),
+            }
+        }
+    };
+
+    let mut warnings = Vec::new();
+    let output = match to {
+        TextEncoding::PlainText => match String::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(e) => {
+                warnings.push(format!(
+                    "The decoded bytes are not valid UTF-8 (at byte {}); showing a hex dump instead",
+                    e.utf8_error().valid_up_to()
+                ));
+                hex_dump(e.as_bytes())
+            }
+        },
+        _ => encode_bytes(&bytes, to),
+    };
+
+    EncodingConvertResult {
+        success: true,
+        output,
+        warnings,
+        error: None,
+    }
+}
+
+fn encode_bytes(bytes: &[u8], encoding: TextEncoding) -> String {
+    use base64::{engine::general_purpose, Engine};
+
+    match encoding {
+        TextEncoding::PlainText => String::from_utf8_lossy(bytes).into_owned(),
+        TextEncoding::Base64 => general_purpose::STANDARD.encode(bytes),
+        TextEncoding::Base64Url => general_purpose::URL_SAFE.encode(bytes),
+        TextEncoding::Base32 => encode_base32(bytes),
+        TextEncoding::HexLower => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+        TextEncoding::HexUpper => bytes.iter().map(|b| format!("{:02X}", b)).collect(),
+    }
+}
+
+fn decode_text(input: &str, encoding: TextEncoding) -> Result<Vec<u8>, String> {
+    if encoding == TextEncoding::PlainText {
+        return Ok(input.as_bytes().to_vec());
+    }
+
+    // Keep each character's 1-based position in the original input for error messages
+    let chars: Vec<(usize, char)> = input
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(i, c)| (i + 1, c))
+        .collect();
+
+    match encoding {
+        TextEncoding::PlainText => unreachable!(),
+        TextEncoding::Base64 | TextEncoding::Base64Url => decode_base64_chars(&chars, encoding),
+        TextEncoding::Base32 => decode_base32_chars(&chars),
+        TextEncoding::HexLower | TextEncoding::HexUpper => decode_hex_chars(&chars),
+    }
+}
+
+/// Split off trailing `=` padding, rejecting `=` anywhere else
+fn strip_padding<'a>(
+    chars: &'a [(usize, char)],
+    name: &str,
+) -> Result<&'a [(usize, char)], String> {
+    let data_len = chars.len() - chars.iter().rev().take_while(|(_, c)| *c == '=').count();
+    match chars[..data_len].iter().find(|(_, c)| *c == '=') {
+        Some((pos, _)) => Err(format!(
+            "Invalid {}: unexpected padding at position {}",
+            name, pos
+        )),
+        None => Ok(&chars[..data_len]),
+    }
+}
+
+fn decode_base64_chars(chars: &[(usize, char)], encoding: TextEncoding) -> Result<Vec<u8>, String> {
+    use base64::alphabet;
+    use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+    use base64::Engine;
+
+    let (name, extra) = if encoding == TextEncoding::Base64Url {
+        ("Base64URL", ['-', '_'])
+    } else {
+        ("Base64", ['+', '/'])
+    };
+    let data = strip_padding(chars, name)?;
+    if let Some((pos, c)) = data
+        .iter()
+        .find(|(_, c)| !c.is_ascii_alphanumeric() && !extra.contains(c))
+    {
+        return Err(format!(
+            "Invalid {} character '{}' at position {}",
+            name, c, pos
+        ));
+    }
+    if data.len() % 4 == 1 {
+        return Err(format!(
+            "Invalid {}: {} characters cannot encode whole bytes",
+            name,
+            data.len()
+        ));
+    }
+
+    let config = GeneralPurposeConfig::new()
+        .with_decode_padding_mode(DecodePaddingMode::RequireNone)
+        .with_decode_allow_trailing_bits(true);
+    let engine = if encoding == TextEncoding::Base64Url {
+        GeneralPurpose::new(&alphabet::URL_SAFE, config)
+    } else {
+        GeneralPurpose::new(&alphabet::STANDARD, config)
+    };
+    let text: String = data.iter().map(|(_, c)| c).collect();
+    engine
+        .decode(text)
+        .map_err(|e| format!("Invalid {}: {}", name, e))
+}
+
+fn encode_base32(bytes: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    for chunk in bytes.chunks(5) {
+        let mut buffer = [0u8; 5];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let bits = buffer.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        let symbols = (chunk.len() * 8).div_ceil(5);
+        for i in 0..8 {
+            if i < symbols {
+                let index = (bits >> (35 - i * 5)) & 0x1F;
+                output.push(BASE32_ALPHABET[index as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+fn decode_base32_chars(chars: &[(usize, char)]) -> Result<Vec<u8>, String> {
+    let data = strip_padding(chars, "Base32")?;
+    let values = data
+        .iter()
+        .map(|(pos, c)| {
+            BASE32_ALPHABET
+                .iter()
+                .position(|a| *a as char == c.to_ascii_uppercase())
+                .ok_or_else(|| format!("Invalid Base32 character '{}' at position {}", c, pos))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if matches!(values.len() % 8, 1 | 3 | 6) {
+        return Err(format!(
+            "Invalid Base32: {} characters cannot encode whole bytes",
+            values.len()
+        ));
+    }
+
+    let mut output = Vec::with_capacity(values.len() * 5 / 8);
+    let mut buffer = 0u64;
+    let mut bits = 0;
+    for value in values {
+        buffer = (buffer << 5) | value as u64;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Ok(output)
+}
+
+fn decode_hex_chars(chars: &[(usize, char)]) -> Result<Vec<u8>, String> {
+    let digits = match chars {
+        [(_, '0'), (_, 'x' | 'X'), rest @ ..] => rest,
+        _ => chars,
+    };
+    if let Some((pos, c)) = digits.iter().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid hex character '{}' at position {}", c, pos));
+    }
+    if digits.len() % 2 == 1 {
+        return Err(format!(
+            "Invalid hex: odd number of digits ({}); the last byte is incomplete",
+            digits.len()
+        ));
+    }
+
+    Ok(digits
+        .chunks(2)
+        .map(|pair| {
+            let high = pair[0].1.to_digit(16).unwrap_or_default();
+            let low = pair[1].1.to_digit(16).unwrap_or_default();
+            (high * 16 + low) as u8
+        })
+        .collect())
+}
+
+fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Encode any file to Base64, guessing its MIME type from the extension
+pub fn encode_file_to_base64(path: &str, as_data_uri: bool) -> Base64FileResult {
+    use base64::{engine::general_purpose, Engine};
+
+    let failure = |error: String| Base64FileResult {
+        success: false,
+        output: String::new(),
+        file_name: String::new(),
+        mime_type: String::new(),
+        size_bytes: 0,
+        error: Some(error),
+    };
+
+    let path = Path::new(path);
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) => return failure(format!("Failed to read file: {}", e)),
+    };
+    if metadata.len() > MAX_FILE_BYTES as u64 {
+        return failure(size_limit_error(metadata.len() as usize));
+    }
+
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => return failure(format!("Failed to read file: {}", e)),
+    };
+
+    let mime_type = mime_guess::from_path(path)
+        .first_raw()
+        .unwrap_or("application/octet-stream")
+        .to_string();
+    let encoded = general_purpose::STANDARD.encode(&bytes);
+    let output = if as_data_uri {
+        format!("data:{};base64,{}", mime_type, encoded)
+    } else {
+        encoded
+    };
+
+    Base64FileResult {
+        success: true,
+        output,
+        file_name: path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        mime_type,
+        size_bytes: bytes.len(),
+        error: None,
+    }
+}
+
+/// Decode Base64 (optionally a data URI) and write the bytes to `output_path`
+pub fn decode_base64_to_file(input: &str, output_path: &str) -> Result<DecodeFileInfo, String> {
+    use base64::{engine::general_purpose, Engine};
+
+    let (mime_type, base64_data) = split_data_url(input.trim());
+    let cleaned_input: String = base64_data.chars().filter(|c| !c.is_whitespace()).collect();
+    if cleaned_input.is_empty() {
+        return Err("Input is empty".to_string());
+    }
+
+    // Reject before decoding so an oversized paste is not held twice in memory
+    let estimated_size = cleaned_input.len() / 4 * 3;
+    if estimated_size > MAX_FILE_BYTES + 2 {
+        return Err(size_limit_error(estimated_size));
+    }
+
+    let bytes = general_purpose::STANDARD
+        .decode(&cleaned_input)
+        .or_else(|_| general_purpose::URL_SAFE.decode(&cleaned_input))
+        .or_else(|_| general_purpose::STANDARD_NO_PAD.decode(&cleaned_input))
+        .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(&cleaned_input))
+        .map_err(|e| format!("Invalid Base64: {}", e))?;
+    if bytes.len() > MAX_FILE_BYTES {
+        return Err(size_limit_error(bytes.len()));
+    }
+
+    fs::write(output_path, &bytes).map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(DecodeFileInfo {
+        output_path: output_path.to_string(),
+        size_bytes: bytes.len(),
+        mime_type: mime_type.or_else(|| detect_image_type(&bytes)),
+    })
+}
+
+/// Bytes read per step of the file-to-file conversions; a multiple of 3 so every encoded
+/// chunk but the last ends without padding
+const STREAM_CHUNK_BYTES: usize = 3 * 256 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Base64StreamProgress {
+    pub processed_bytes: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Base64StreamResult {
+    pub success: bool,
+    pub output_path: String,
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+    pub cancelled: bool,
+    pub error: Option<String>,
+}
+
+/// `Base64StreamProgress` of one file job, tagged with the id `Base64JobStore::start` gave it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Base64JobProgress {
+    pub job_id: u64,
+    #[serde(flatten)]
+    pub progress: Base64StreamProgress,
+}
+
+/// Sent once a file job has stopped, whether it finished, failed or was cancelled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Base64JobFinished {
+    pub job_id: u64,
+    pub result: Base64StreamResult,
+}
+
+/// Cancellation flags of the running file-to-file conversions by job id, managed as Tauri state
+#[derive(Default)]
+pub struct Base64JobStore {
+    next_id: AtomicU64,
+    jobs: Mutex<HashMap<u64, Arc<AtomicBool>>>,
+}
+
+impl Base64JobStore {
+    /// Register a new job and hand out its id and cancellation flag
+    pub fn start(&self) -> (u64, Arc<AtomicBool>) {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+        let cancel = Arc::new(AtomicBool::new(false));
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.insert(id, cancel.clone());
+        }
+        (id, cancel)
+    }
+
+    /// Ask job `id` to stop. Returns false when no such job is running.
+    pub fn cancel(&self, id: u64) -> bool {
+        let cancel = self
+            .jobs
+            .lock()
+            .ok()
+            .and_then(|jobs| jobs.get(&id).cloned());
+        match cancel {
+            Some(cancel) => {
+                cancel.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forget a job once it has stopped
+    pub fn finish(&self, id: u64) {
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.remove(&id);
+        }
+    }
+}
+
+/// Read until `buf` is full or the input ends
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Why a stream stopped early
+#[derive(Debug)]
+enum StreamError {
+    Cancelled,
+    Failed(String),
+}
+
+fn encode_stream<F>(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    chunk_bytes: usize,
+    total_bytes: u64,
+    cancel: &AtomicBool,
+    mut on_progress: F,
+) -> Result<(u64, u64), StreamError>
+where
+    F: FnMut(Base64StreamProgress),
+{
+    use base64::{engine::general_purpose, Engine};
+
+    let mut buf = vec![0u8; (chunk_bytes / 3).max(1) * 3];
+    let mut encoded = String::new();
+    let (mut read, mut written) = (0u64, 0u64);
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            return Err(StreamError::Cancelled);
+        }
+        let n = read_full(reader, &mut buf)
+            .map_err(|e| StreamError::Failed(format!("Failed to read file: {}", e)))?;
+        if n == 0 {
+            break;
+        }
+        encoded.clear();
+        general_purpose::STANDARD.encode_string(&buf[..n], &mut encoded);
+        writer
+            .write_all(encoded.as_bytes())
+            .map_err(|e| StreamError::Failed(format!("Failed to write file: {}", e)))?;
+        read += n as u64;
+        written += encoded.len() as u64;
+        on_progress(Base64StreamProgress {
+            processed_bytes: read,
+            total_bytes,
+        });
+        if n < buf.len() {
+            break;
+        }
+    }
+    Ok((read, written))
+}
+
+fn decode_stream<F>(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    chunk_bytes: usize,
+    total_bytes: u64,
+    cancel: &AtomicBool,
+    mut on_progress: F,
+) -> Result<(u64, u64), StreamError>
+where
+    F: FnMut(Base64StreamProgress),
+{
+    use base64::engine::{general_purpose, DecodePaddingMode, GeneralPurpose};
+    use base64::{alphabet, Engine};
+
+    // Padding is optional, and line breaks are dropped before decoding
+    let engine = GeneralPurpose::new(
+        &alphabet::STANDARD,
+        general_purpose::PAD.with_decode_padding_mode(DecodePaddingMode::Indifferent),
+    );
+    let mut buf = vec![0u8; chunk_bytes.max(4)];
+    // Base64 characters not yet decoded, fewer than 4 between chunks
+    let mut pending: Vec<u8> = Vec::new();
+    let mut decoded = Vec::new();
+    // Characters decoded so far, to locate errors
+    let mut offset = 0usize;
+    let mut first = true;
+    let (mut read, mut written) = (0u64, 0u64);
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            return Err(StreamError::Cancelled);
+        }
+        let n = read_full(reader, &mut buf)
+            .map_err(|e| StreamError::Failed(format!("Failed to read file: {}", e)))?;
+        read += n as u64;
+        let mut chunk = &buf[..n];
+        // A data URI header can only be at the very start
+        if first {
+            first = false;
+            if chunk.starts_with(b"data:") {
+                if let Some(comma) = chunk.iter().take(256).position(|&b| b == b',') {
+                    chunk = &chunk[comma + 1..];
+                }
+            }
+        }
+        pending.extend(chunk.iter().filter(|b| !b.is_ascii_whitespace()).map(|&b| {
+            // Accept the URL-safe alphabet too
+            match b {
+                b'-' => b'+',
+                b'_' => b'/',
+                b => b,
+            }
+        }));
+
+        let done = n < buf.len();
+        let usable = if done {
+            pending.len()
+        } else {
+            pending.len() / 4 * 4
+        };
+        if usable > 0 {
+            decoded.clear();
+            engine
+                .decode_vec(&pending[..usable], &mut decoded)
+                .map_err(|e| {
+                    StreamError::Failed(format!(
+                        "Invalid Base64 after {} characters: {}",
+                        offset, e
+                    ))
+                })?;
+            writer
+                .write_all(&decoded)
+                .map_err(|e| StreamError::Failed(format!("Failed to write file: {}", e)))?;
+            written += decoded.len() as u64;
+            offset += usable;
+            pending.drain(..usable);
+        }
+        on_progress(Base64StreamProgress {
+            processed_bytes: read,
+            total_bytes,
+        });
+        if done {
+            break;
+        }
+    }
+    Ok((read, written))
+}
+
+type Stream<F> = fn(
+    &mut BufReader<fs::File>,
+    &mut BufWriter<fs::File>,
+    usize,
+    u64,
+    &AtomicBool,
+    F,
+) -> Result<(u64, u64), StreamError>;
+
+/// Run `stream` from `input_path` into a `.part` file that replaces `output_path` only
+/// once the whole input has been converted
+fn stream_file_to_file<F>(
+    input_path: &str,
+    output_path: &str,
+    stream: Stream<F>,
+    cancel: &AtomicBool,
+    on_progress: F,
+) -> Base64StreamResult
+where
+    F: FnMut(Base64StreamProgress),
+{
+    let mut result = Base64StreamResult {
+        output_path: output_path.to_string(),
+        ..Default::default()
+    };
+    let input = match fs::File::open(input_path) {
+        Ok(file) => file,
+        Err(e) => {
+            result.error = Some(format!("Failed to read file: {}", e));
+            return result;
+        }
+    };
+    let total_bytes = input.metadata().map(|m| m.len()).unwrap_or(0);
+    let part_path = format!("{}.part", output_path);
+    let output = match fs::File::create(&part_path) {
+        Ok(file) => file,
+        Err(e) => {
+            result.error = Some(format!("Failed to write file: {}", e));
+            return result;
+        }
+    };
+
+    let mut reader = BufReader::new(input);
+    let mut writer = BufWriter::new(output);
+    let streamed = stream(
+        &mut reader,
+        &mut writer,
+        STREAM_CHUNK_BYTES,
+        total_bytes,
+        cancel,
+        on_progress,
+    )
+    .and_then(|counts| {
+        writer
+            .flush()
+            .map_err(|e| StreamError::Failed(format!("Failed to write file: {}", e)))?;
+        drop(writer);
+        fs::rename(&part_path, output_path)
+            .map_err(|e| StreamError::Failed(format!("Failed to write file: {}", e)))?;
+        Ok(counts)
+    });
+
+    match streamed {
+        Ok((read, written)) => {
+            result.success = true;
+            result.bytes_read = read;
+            result.bytes_written = written;
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&part_path);
+            match e {
+                StreamError::Cancelled => result.cancelled = true,
+                StreamError::Failed(message) => result.error = Some(message),
+            }
+        }
+    }
+    result
+}
+
+/// Encode a file of any size to a Base64 text file, a chunk at a time
+pub fn encode_file_to_base64_file<F>(
+    input_path: &str,
+    output_path: &str,
+    cancel: &AtomicBool,
+    on_progress: F,
+) -> Base64StreamResult
+where
+    F: FnMut(Base64StreamProgress),
+{
+    stream_file_to_file(input_path, output_path, encode_stream, cancel, on_progress)
+}
+
+/// Decode a Base64 text file of any size, including line-wrapped (MIME) text, URL-safe
+/// text and data URIs, a chunk at a time
+pub fn decode_base64_file_to_file<F>(
+    input_path: &str,
+    output_path: &str,
+    cancel: &AtomicBool,
+    on_progress: F,
+) -> Base64StreamResult
+where
+    F: FnMut(Base64StreamProgress),
+{
+    stream_file_to_file(input_path, output_path, decode_stream, cancel, on_progress)
+}
+
+/// Split `data:<mime>;base64,<data>` into its MIME type and payload; other input is returned as is
+fn split_data_url(input: &str) -> (Option<String>, &str) {
+    let Some(rest) = input.strip_prefix("data:") else {
+        return (None, input);
+    };
+    let Some((header, data)) = rest.split_once(',') else {
+        return (None, input);
+    };
+    let mime = header.split(';').next().unwrap_or(header);
+    ((!mime.is_empty()).then(|| mime.to_string()), data)
+}
+
 /// Detect image type from magic bytes
 fn detect_image_type(bytes: &[u8]) -> Option<String> {
     if bytes.len() < 4 {
@@ -341,4 +1038,244 @@ mod tests {
         let decode_result = decode_base64("", false);
         assert!(!decode_result.success);
     }
+
+    #[test]
+    fn test_file_round_trip_with_data_uri() {
+        let dir = std::env::temp_dir();
+        let source = dir.join("taurin_base64_file.pdf");
+        let target = dir.join("taurin_base64_file_out.bin");
+        let bytes: Vec<u8> = (0..=255).collect();
+        fs::write(&source, &bytes).unwrap();
+
+        let encoded = encode_file_to_base64(source.to_str().unwrap(), true);
+        assert!(encoded.success);
+        assert_eq!(encoded.mime_type, "application/pdf");
+        assert_eq!(encoded.file_name, "taurin_base64_file.pdf");
+        assert_eq!(encoded.size_bytes, 256);
+        assert!(encoded.output.starts_with("data:application/pdf;base64,"));
+
+        let info = decode_base64_to_file(&encoded.output, target.to_str().unwrap()).unwrap();
+        let written = fs::read(&target).unwrap();
+        let _ = fs::remove_file(&source);
+        let _ = fs::remove_file(&target);
+
+        assert_eq!(info.size_bytes, 256);
+        assert_eq!(info.mime_type.as_deref(), Some("application/pdf"));
+        assert_eq!(written, bytes);
+    }
+
+    #[test]
+    fn test_file_helpers_reject_bad_input() {
+        let missing = encode_file_to_base64("/nonexistent/taurin.bin", false);
+        assert!(!missing.success);
+
+        let target = std::env::temp_dir().join("taurin_base64_invalid.bin");
+        assert!(decode_base64_to_file("!!!", target.to_str().unwrap()).is_err());
+        assert!(
+            decode_base64_to_file("data:text/plain;base64,", target.to_str().unwrap()).is_err()
+        );
+
+        let oversized = "A".repeat((MAX_FILE_BYTES / 3 + 1) * 4);
+        let error = decode_base64_to_file(&oversized, target.to_str().unwrap()).unwrap_err();
+        assert!(error.contains("too large"));
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn test_convert_encoding_round_trips() {
+        use TextEncoding::*;
+
+        let text = "Hello, 世界!?";
+        for encoding in [Base64, Base64Url, Base32, HexLower, HexUpper] {
+            let encoded = convert_encoding(text, PlainText, encoding);
+            assert!(encoded.success, "{:?}", encoding);
+            let decoded = convert_encoding(&encoded.output, encoding, PlainText);
+            assert_eq!(decoded.output, text, "{:?}", encoding);
+            assert!(decoded.warnings.is_empty());
+        }
+
+        // RFC 4648 test vectors
+        assert_eq!(
+            convert_encoding("foobar", PlainText, Base32).output,
+            "MZXW6YTBOI======"
+        );
+        assert_eq!(convert_encoding("fo", PlainText, Base32).output, "MZXQ====");
+        assert_eq!(
+            convert_encoding("mzxw6ytb", Base32, PlainText).output,
+            "fooba"
+        );
+        assert_eq!(
+            convert_encoding("Zm9vYg", Base64, HexUpper).output,
+            "666F6F62"
+        );
+        assert_eq!(convert_encoding("0x66 6f", HexLower, Base64).output, "Zm8=");
+        assert_eq!(convert_encoding("+/8=", Base64, Base64Url).output, "-_8=");
+    }
+
+    #[test]
+    fn test_convert_encoding_reports_positions() {
+        use TextEncoding::*;
+
+        let error = convert_encoding("Zm9v\nY!", Base64, PlainText)
+            .error
+            .unwrap();
+        assert!(error.contains("'!' at position 7"), "{}", error);
+
+        let error = convert_encoding("-_8=", Base64, PlainText).error.unwrap();
+        assert!(error.contains("'-' at position 1"), "{}", error);
+
+        let error = convert_encoding("MZ=XW", Base32, PlainText).error.unwrap();
+        assert!(error.contains("position 3"), "{}", error);
+
+        let error = convert_encoding("MZ1", Base32, PlainText).error.unwrap();
+        assert!(error.contains("'1' at position 3"), "{}", error);
+
+        let error = convert_encoding("abc", HexLower, PlainText).error.unwrap();
+        assert!(error.contains("odd number"), "{}", error);
+
+        let error = convert_encoding("ab zz", HexLower, PlainText)
+            .error
+            .unwrap();
+        assert!(error.contains("'z' at position 4"), "{}", error);
+    }
+
+    #[test]
+    fn test_convert_encoding_non_utf8_warns() {
+        let result = convert_encoding("ff00c3", TextEncoding::HexLower, TextEncoding::PlainText);
+        assert!(result.success);
+        assert_eq!(result.output, "FF 00 C3");
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_split_data_url() {
+        assert_eq!(
+            split_data_url("data:image/png;base64,AAAA"),
+            (Some("image/png".to_string()), "AAAA")
+        );
+        assert_eq!(split_data_url("data:,AAAA"), (None, "AAAA"));
+        assert_eq!(split_data_url("AAAA"), (None, "AAAA"));
+    }
+
+    #[test]
+    fn test_streams_handle_chunk_boundaries() {
+        use base64::{engine::general_purpose, Engine};
+
+        let bytes: Vec<u8> = (0..1000u32).map(|i| (i * 7 % 256) as u8).collect();
+        let expected = general_purpose::STANDARD.encode(&bytes);
+        let cancel = AtomicBool::new(false);
+        // Chunk sizes that do and do not line up with 3-byte groups and 4-character quads
+        for chunk in [1, 3, 7, 64, 4096] {
+            let mut encoded = Vec::new();
+            let mut updates = 0;
+            let (read, written) = encode_stream(
+                &mut bytes.as_slice(),
+                &mut encoded,
+                chunk,
+                bytes.len() as u64,
+                &cancel,
+                |_| updates += 1,
+            )
+            .unwrap();
+            assert_eq!(String::from_utf8(encoded.clone()).unwrap(), expected);
+            assert_eq!((read, written), (1000, expected.len() as u64));
+            assert!(updates > 0);
+
+            let mut decoded = Vec::new();
+            decode_stream(
+                &mut encoded.as_slice(),
+                &mut decoded,
+                chunk,
+                0,
+                &cancel,
+                |_| {},
+            )
+            .unwrap();
+            assert_eq!(decoded, bytes, "chunk size {}", chunk);
+        }
+
+        // MIME wraps lines at 76 characters with CRLF
+        let wrapped = expected
+            .as_bytes()
+            .chunks(76)
+            .map(|line| std::str::from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("\r\n");
+        for chunk in [5, 77, 78, 1000] {
+            let mut decoded = Vec::new();
+            decode_stream(
+                &mut wrapped.as_bytes(),
+                &mut decoded,
+                chunk,
+                0,
+                &cancel,
+                |_| {},
+            )
+            .unwrap();
+            assert_eq!(decoded, bytes, "chunk size {}", chunk);
+        }
+
+        let mut sink = Vec::new();
+        let bad = decode_stream(&mut "QUJD!!!!".as_bytes(), &mut sink, 4, 0, &cancel, |_| {});
+        assert!(matches!(bad, Err(StreamError::Failed(e)) if e.contains("after 4 characters")));
+    }
+
+    #[test]
+    fn test_file_to_file_round_trip_and_cancel() {
+        let dir = std::env::temp_dir();
+        let source = dir.join("taurin_base64_stream.bin");
+        let encoded_path = dir.join("taurin_base64_stream.b64");
+        let decoded_path = dir.join("taurin_base64_stream_out.bin");
+        let bytes: Vec<u8> = (0..STREAM_CHUNK_BYTES + 10)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        fs::write(&source, &bytes).unwrap();
+
+        let store = Base64JobStore::default();
+        let (job_id, cancel) = store.start();
+        let mut last = None;
+        let encoded = encode_file_to_base64_file(
+            source.to_str().unwrap(),
+            encoded_path.to_str().unwrap(),
+            &cancel,
+            |p| last = Some(p),
+        );
+        store.finish(job_id);
+        assert!(encoded.success);
+        let last = last.unwrap();
+        assert_eq!(last.processed_bytes, last.total_bytes);
+        assert!(!store.cancel(job_id));
+
+        let (job_id, cancel) = store.start();
+        let decoded = decode_base64_file_to_file(
+            encoded_path.to_str().unwrap(),
+            decoded_path.to_str().unwrap(),
+            &cancel,
+            |_| {},
+        );
+        store.finish(job_id);
+        assert!(decoded.success);
+        assert_eq!(decoded.bytes_written, bytes.len() as u64);
+        assert_eq!(fs::read(&decoded_path).unwrap(), bytes);
+        let _ = fs::remove_file(&decoded_path);
+
+        let (cancelled_id, cancel) = store.start();
+        let (other_id, other) = store.start();
+        assert_ne!(cancelled_id, other_id);
+        assert!(store.cancel(cancelled_id));
+        assert!(!other.load(Ordering::SeqCst));
+        store.finish(other_id);
+        let cancelled = decode_base64_file_to_file(
+            encoded_path.to_str().unwrap(),
+            decoded_path.to_str().unwrap(),
+            &cancel,
+            |_| {},
+        );
+        assert!(cancelled.cancelled && !cancelled.success);
+        assert!(!decoded_path.exists());
+        assert!(!Path::new(&format!("{}.part", decoded_path.display())).exists());
+
+        let _ = fs::remove_file(&source);
+        let _ = fs::remove_file(&encoded_path);
+    }
 }
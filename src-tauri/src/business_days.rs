@@ -0,0 +1,536 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Years the Japanese holiday rules (Happy Monday, equinox formula, era changes) are
+/// implemented for
+const JAPAN_YEAR_RANGE: std::ops::RangeInclusive<i32> = 2000..=2099;
+/// Largest number of business days to add, and largest span to count, in one call
+const MAX_SPAN_DAYS: i64 = 36_600;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum HolidayCountry {
+    Japan,
+    /// Weekends and custom closure days only
+    None,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum HolidayKind {
+    /// 国民の祝日
+    National,
+    /// 振替休日: a national holiday fell on Sunday
+    Substitute,
+    /// 国民の休日: a day sandwiched between two national holidays
+    Citizens,
+    /// A closure day supplied by the user
+    Custom,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Holiday {
+    /// `YYYY-MM-DD`
+    pub date: String,
+    pub name: String,
+    pub kind: HolidayKind,
+    /// 0 = Sunday … 6 = Saturday
+    pub weekday: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BusinessDayResult {
+    pub success: bool,
+    pub start_date: String,
+    /// The computed date for `add_business_days`, the given end for `count_business_days`
+    pub end_date: String,
+    pub end_weekday: u32,
+    pub business_days: i64,
+    /// Days passed, not counting the start date when adding
+    pub calendar_days: i64,
+    pub weekend_days: i64,
+    /// Holidays and closure days that fell on a weekday and were skipped
+    pub holidays: Vec<Holiday>,
+    pub error: Option<String>,
+}
+
+impl BusinessDayResult {
+    fn failure(error: String) -> Self {
+        Self {
+            success: false,
+            start_date: String::new(),
+            end_date: String::new(),
+            end_weekday: 0,
+            business_days: 0,
+            calendar_days: 0,
+            weekend_days: 0,
+            holidays: Vec::new(),
+            error: Some(error),
+        }
+    }
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid holiday date")
+}
+
+/// The `n`-th Monday of the month, used by the Happy Monday holidays
+fn nth_monday(year: i32, month: u32, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Mon, n).expect("valid weekday")
+}
+
+/// 春分日 by the usual approximation, accurate for 1980–2099
+fn vernal_equinox_day(year: i32) -> u32 {
+    let y = f64::from(year - 1980);
+    (20.8431 + 0.242194 * y - (y / 4.0).floor()).floor() as u32
+}
+
+/// 秋分日 by the usual approximation, accurate for 1980–2099
+fn autumnal_equinox_day(year: i32) -> u32 {
+    let y = f64::from(year - 1980);
+    (23.2488 + 0.242194 * y - (y / 4.0).floor()).floor() as u32
+}
+
+/// 国民の祝日 defined directly by the law for `year`, including the one-off days of 2019
+/// and the Olympic moves of 2020 and 2021
+fn national_holidays(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let mut days = vec![
+        (ymd(year, 1, 1), "元日"),
+        (nth_monday(year, 1, 2), "成人の日"),
+        (ymd(year, 2, 11), "建国記念の日"),
+        (ymd(year, 3, vernal_equinox_day(year)), "春分の日"),
+        (ymd(year, 5, 3), "憲法記念日"),
+        (ymd(year, 5, 5), "こどもの日"),
+        (ymd(year, 9, autumnal_equinox_day(year)), "秋分の日"),
+        (ymd(year, 11, 3), "文化の日"),
+        (ymd(year, 11, 23), "勤労感謝の日"),
+    ];
+
+    match year {
+        ..=2018 => days.push((ymd(year, 12, 23), "天皇誕生日")),
+        2019 => {}
+        _ => days.push((ymd(year, 2, 23), "天皇誕生日")),
+    }
+
+    if year < 2007 {
+        days.push((ymd(year, 4, 29), "みどりの日"));
+    } else {
+        days.push((ymd(year, 4, 29), "昭和の日"));
+        days.push((ymd(year, 5, 4), "みどりの日"));
+    }
+
+    let marine_day = match year {
+        ..=2002 => ymd(year, 7, 20),
+        2020 => ymd(2020, 7, 23),
+        2021 => ymd(2021, 7, 22),
+        _ => nth_monday(year, 7, 3),
+    };
+    days.push((marine_day, "海の日"));
+
+    let mountain_day = match year {
+        ..=2015 => None,
+        2020 => Some(ymd(2020, 8, 10)),
+        2021 => Some(ymd(2021, 8, 8)),
+        _ => Some(ymd(year, 8, 11)),
+    };
+    days.extend(mountain_day.map(|date| (date, "山の日")));
+
+    let respect_for_aged_day = if year <= 2002 {
+        ymd(year, 9, 15)
+    } else {
+        nth_monday(year, 9, 3)
+    };
+    days.push((respect_for_aged_day, "敬老の日"));
+
+    match year {
+        ..=2019 => days.push((nth_monday(year, 10, 2), "体育の日")),
+        2020 => days.push((ymd(2020, 7, 24), "スポーツの日")),
+        2021 => days.push((ymd(2021, 7, 23), "スポーツの日")),
+        _ => days.push((nth_monday(year, 10, 2), "スポーツの日")),
+    }
+
+    if year == 2019 {
+        days.push((ymd(2019, 5, 1), "天皇の即位の日"));
+        days.push((ymd(2019, 10, 22), "即位礼正殿の儀の行われる日"));
+    }
+
+    days
+}
+
+/// All Japanese public holidays in `year`, keyed by date
+fn japanese_holidays(year: i32) -> BTreeMap<NaiveDate, (&'static str, HolidayKind)> {
+    let mut holidays: BTreeMap<NaiveDate, (&'static str, HolidayKind)> = national_holidays(year)
+        .into_iter()
+        .map(|(date, name)| (date, (name, HolidayKind::National)))
+        .collect();
+    let national: Vec<NaiveDate> = holidays.keys().copied().collect();
+    let is_national = |date: NaiveDate| national.binary_search(&date).is_ok();
+
+    // 国民の休日: a non-holiday between two national holidays. Until 2006 a Sunday
+    // in that position stayed an ordinary Sunday.
+    for window in national.windows(2) {
+        let between = window[0].succ_opt().expect("date in range");
+        let sandwiched = window[1].signed_duration_since(window[0]).num_days() == 2;
+        if sandwiched && (year >= 2007 || between.weekday() != Weekday::Sun) {
+            holidays.insert(between, ("国民の休日", HolidayKind::Citizens));
+        }
+    }
+
+    // 振替休日: from 2007 the first following day that is not a national holiday,
+    // before that only the Monday and only when it is not a holiday itself
+    for &date in national.iter().filter(|d| d.weekday() == Weekday::Sun) {
+        let mut next = date.succ_opt().expect("date in range");
+        if year >= 2007 {
+            while is_national(next) {
+                next = next.succ_opt().expect("date in range");
+            }
+        } else if is_national(next) {
+            continue;
+        }
+        holidays
+            .entry(next)
+            .or_insert(("振替休日", HolidayKind::Substitute));
+    }
+
+    holidays
+}
+
+fn to_holiday(date: NaiveDate, name: &str, kind: HolidayKind) -> Holiday {
+    Holiday {
+        date: date.format("%Y-%m-%d").to_string(),
+        name: name.to_string(),
+        kind,
+        weekday: date.weekday().num_days_from_sunday(),
+    }
+}
+
+/// Public holidays of `country` in `year`; empty outside the supported range
+pub fn list_holidays(year: u16, country: HolidayCountry) -> Vec<Holiday> {
+    let year = i32::from(year);
+    match country {
+        HolidayCountry::Japan if JAPAN_YEAR_RANGE.contains(&year) => japanese_holidays(year)
+            .into_iter()
+            .map(|(date, (name, kind))| to_holiday(date, name, kind))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: {} (expected YYYY-MM-DD)", value.trim()))
+}
+
+/// Holiday lookups for one calculation; national holidays are computed per year on demand
+struct HolidayCalendar {
+    country: HolidayCountry,
+    custom: HashMap<NaiveDate, String>,
+    years: HashMap<i32, BTreeMap<NaiveDate, (&'static str, HolidayKind)>>,
+}
+
+impl HolidayCalendar {
+    /// Custom entries are `YYYY-MM-DD`, optionally followed by a label such as
+    /// `2025-12-29 年末休業`; blank entries are ignored
+    fn new(country: HolidayCountry, custom_holidays: &[String]) -> Result<Self, String> {
+        let mut custom = HashMap::new();
+        for entry in custom_holidays {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (date, label) = entry.split_once(char::is_whitespace).unwrap_or((entry, ""));
+            custom.insert(parse_date(date)?, label.trim().to_string());
+        }
+        Ok(Self {
+            country,
+            custom,
+            years: HashMap::new(),
+        })
+    }
+
+    /// The holiday on `date`, if any. National holidays take precedence over custom ones.
+    fn holiday(&mut self, date: NaiveDate) -> Result<Option<Holiday>, String> {
+        if self.country == HolidayCountry::Japan {
+            let year = date.year();
+            if !JAPAN_YEAR_RANGE.contains(&year) {
+                return Err(format!(
+                    "Japanese holidays are only available for {}–{}",
+                    JAPAN_YEAR_RANGE.start(),
+                    JAPAN_YEAR_RANGE.end()
+                ));
+            }
+            let holidays = self
+                .years
+                .entry(year)
+                .or_insert_with(|| japanese_holidays(year));
+            if let Some((name, kind)) = holidays.get(&date) {
+                return Ok(Some(to_holiday(date, name, *kind)));
+            }
+        }
+        Ok(self
+            .custom
+            .get(&date)
+            .map(|label| to_holiday(date, label, HolidayKind::Custom)))
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Running totals while walking the calendar one day at a time
+#[derive(Default)]
+struct Tally {
+    business_days: i64,
+    calendar_days: i64,
+    weekend_days: i64,
+    holidays: Vec<Holiday>,
+}
+
+impl Tally {
+    /// Record `date` and return whether it was a business day
+    fn visit(&mut self, calendar: &mut HolidayCalendar, date: NaiveDate) -> Result<bool, String> {
+        self.calendar_days += 1;
+        if is_weekend(date) {
+            // Still validates the year range for dates that are only weekends
+            calendar.holiday(date)?;
+            self.weekend_days += 1;
+            return Ok(false);
+        }
+        match calendar.holiday(date)? {
+            Some(holiday) => {
+                self.holidays.push(holiday);
+                Ok(false)
+            }
+            None => {
+                self.business_days += 1;
+                Ok(true)
+            }
+        }
+    }
+
+    fn into_result(self, start: NaiveDate, end: NaiveDate) -> BusinessDayResult {
+        BusinessDayResult {
+            success: true,
+            start_date: start.format("%Y-%m-%d").to_string(),
+            end_date: end.format("%Y-%m-%d").to_string(),
+            end_weekday: end.weekday().num_days_from_sunday(),
+            business_days: self.business_days,
+            calendar_days: self.calendar_days,
+            weekend_days: self.weekend_days,
+            holidays: self.holidays,
+            error: None,
+        }
+    }
+}
+
+/// The date `days` business days after `start_date` (before it when negative).
+///
+/// The start date itself is not counted, so adding 1 on a Friday gives the next Monday.
+pub fn add_business_days(
+    start_date: String,
+    days: i32,
+    country: HolidayCountry,
+    custom_holidays: Vec<String>,
+) -> BusinessDayResult {
+    let start = match parse_date(&start_date) {
+        Ok(date) => date,
+        Err(e) => return BusinessDayResult::failure(e),
+    };
+    if i64::from(days).abs() > MAX_SPAN_DAYS {
+        return BusinessDayResult::failure(format!(
+            "Days must be between -{} and {}",
+            MAX_SPAN_DAYS, MAX_SPAN_DAYS
+        ));
+    }
+    let mut calendar = match HolidayCalendar::new(country, &custom_holidays) {
+        Ok(calendar) => calendar,
+        Err(e) => return BusinessDayResult::failure(e),
+    };
+    if let Err(e) = calendar.holiday(start) {
+        return BusinessDayResult::failure(e);
+    }
+
+    let target = i64::from(days).abs();
+    let mut tally = Tally::default();
+    let mut current = start;
+    while tally.business_days < target {
+        let next = if days > 0 {
+            current.succ_opt()
+        } else {
+            current.pred_opt()
+        };
+        current = match next {
+            Some(date) => date,
+            None => return BusinessDayResult::failure("Date out of range".to_string()),
+        };
+        if let Err(e) = tally.visit(&mut calendar, current) {
+            return BusinessDayResult::failure(e);
+        }
+    }
+    let mut result = tally.into_result(start, current);
+    result.business_days = i64::from(days);
+    result
+}
+
+/// Business days from `start` to `end`, both ends included
+pub fn count_business_days(
+    start: String,
+    end: String,
+    country: HolidayCountry,
+    custom_holidays: Vec<String>,
+) -> BusinessDayResult {
+    let (start, end) = match (parse_date(&start), parse_date(&end)) {
+        (Ok(start), Ok(end)) => (start, end),
+        (Err(e), _) | (_, Err(e)) => return BusinessDayResult::failure(e),
+    };
+    if end < start {
+        return BusinessDayResult::failure("End date is before the start date".to_string());
+    }
+    if end.signed_duration_since(start).num_days() > MAX_SPAN_DAYS {
+        return BusinessDayResult::failure(format!(
+            "The range must be at most {} days",
+            MAX_SPAN_DAYS
+        ));
+    }
+    let mut calendar = match HolidayCalendar::new(country, &custom_holidays) {
+        Ok(calendar) => calendar,
+        Err(e) => return BusinessDayResult::failure(e),
+    };
+
+    let mut tally = Tally::default();
+    for date in start.iter_days().take_while(|date| *date <= end) {
+        if let Err(e) = tally.visit(&mut calendar, date) {
+            return BusinessDayResult::failure(e);
+        }
+    }
+    tally.into_result(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holiday_dates(year: u16) -> Vec<String> {
+        list_holidays(year, HolidayCountry::Japan)
+            .into_iter()
+            .map(|h| h.date[5..].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_japanese_holidays_2020_to_2030() {
+        let expected: [(u16, &str); 11] = [
+            (2020, "01-01 01-13 02-11 02-23 02-24 03-20 04-29 05-03 05-04 05-05 05-06 07-23 07-24 08-10 09-21 09-22 11-03 11-23"),
+            (2021, "01-01 01-11 02-11 02-23 03-20 04-29 05-03 05-04 05-05 07-22 07-23 08-08 08-09 09-20 09-23 11-03 11-23"),
+            (2022, "01-01 01-10 02-11 02-23 03-21 04-29 05-03 05-04 05-05 07-18 08-11 09-19 09-23 10-10 11-03 11-23"),
+            (2023, "01-01 01-02 01-09 02-11 02-23 03-21 04-29 05-03 05-04 05-05 07-17 08-11 09-18 09-23 10-09 11-03 11-23"),
+            (2024, "01-01 01-08 02-11 02-12 02-23 03-20 04-29 05-03 05-04 05-05 05-06 07-15 08-11 08-12 09-16 09-22 09-23 10-14 11-03 11-04 11-23"),
+            (2025, "01-01 01-13 02-11 02-23 02-24 03-20 04-29 05-03 05-04 05-05 05-06 07-21 08-11 09-15 09-23 10-13 11-03 11-23 11-24"),
+            (2026, "01-01 01-12 02-11 02-23 03-20 04-29 05-03 05-04 05-05 05-06 07-20 08-11 09-21 09-22 09-23 10-12 11-03 11-23"),
+            (2027, "01-01 01-11 02-11 02-23 03-21 03-22 04-29 05-03 05-04 05-05 07-19 08-11 09-20 09-23 10-11 11-03 11-23"),
+            (2028, "01-01 01-10 02-11 02-23 03-20 04-29 05-03 05-04 05-05 07-17 08-11 09-18 09-22 10-09 11-03 11-23"),
+            (2029, "01-01 01-08 02-11 02-12 02-23 03-20 04-29 04-30 05-03 05-04 05-05 07-16 08-11 09-17 09-23 09-24 10-08 11-03 11-23"),
+            (2030, "01-01 01-14 02-11 02-23 03-20 04-29 05-03 05-04 05-05 05-06 07-15 08-11 08-12 09-16 09-23 10-14 11-03 11-04 11-23"),
+        ];
+        for (year, dates) in expected {
+            let expected: Vec<&str> = dates.split(' ').collect();
+            assert_eq!(holiday_dates(year), expected, "holidays in {}", year);
+        }
+
+        let holidays = list_holidays(2026, HolidayCountry::Japan);
+        let sept22 = holidays.iter().find(|h| h.date == "2026-09-22").unwrap();
+        assert_eq!(sept22.kind, HolidayKind::Citizens);
+        let may6 = holidays.iter().find(|h| h.date == "2026-05-06").unwrap();
+        assert_eq!(may6.kind, HolidayKind::Substitute);
+    }
+
+    #[test]
+    fn test_japanese_holidays_before_2020() {
+        let holidays = list_holidays(2019, HolidayCountry::Japan);
+        let names: HashMap<&str, &str> = holidays
+            .iter()
+            .map(|h| (&h.date[5..], h.name.as_str()))
+            .collect();
+        assert_eq!(names["04-30"], "国民の休日");
+        assert_eq!(names["05-01"], "天皇の即位の日");
+        assert_eq!(names["05-02"], "国民の休日");
+        assert_eq!(names["05-06"], "振替休日");
+        assert_eq!(names["10-22"], "即位礼正殿の儀の行われる日");
+        assert!(!names.contains_key("12-23"));
+
+        // Pre-2007 rules: 4/29 was みどりの日 and 5/4 a 国民の休日
+        assert!(holiday_dates(2005).contains(&"05-04".to_string()));
+        let holidays_2005 = list_holidays(2005, HolidayCountry::Japan);
+        let april29 = holidays_2005
+            .iter()
+            .find(|h| h.date == "2005-04-29")
+            .unwrap();
+        assert_eq!(april29.name, "みどりの日");
+        assert!(list_holidays(1999, HolidayCountry::Japan).is_empty());
+        assert!(list_holidays(2025, HolidayCountry::None).is_empty());
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        // Golden week 2025: 5/3–5/6 are holidays (5/6 is a 振替休日)
+        let result = add_business_days("2025-05-02".into(), 1, HolidayCountry::Japan, vec![]);
+        assert!(result.success);
+        assert_eq!(result.end_date, "2025-05-07");
+        assert_eq!(result.weekend_days, 2);
+        assert_eq!(result.holidays.len(), 2);
+        assert_eq!(result.holidays[1].kind, HolidayKind::Substitute);
+
+        let result = add_business_days("2025-05-07".into(), -1, HolidayCountry::Japan, vec![]);
+        assert_eq!(result.end_date, "2025-05-02");
+        assert_eq!(result.business_days, -1);
+
+        let custom = vec!["2025-05-07 創立記念日".to_string(), String::new()];
+        let result = add_business_days("2025-05-02".into(), 1, HolidayCountry::Japan, custom);
+        assert_eq!(result.end_date, "2025-05-08");
+        assert_eq!(result.holidays[2].kind, HolidayKind::Custom);
+        assert_eq!(result.holidays[2].name, "創立記念日");
+
+        let result = add_business_days("2025-05-02".into(), 1, HolidayCountry::None, vec![]);
+        assert_eq!(result.end_date, "2025-05-05");
+        assert_eq!(result.calendar_days, 3);
+
+        let result = add_business_days("2025-05-02".into(), 0, HolidayCountry::Japan, vec![]);
+        assert_eq!(result.end_date, "2025-05-02");
+
+        let invalid = vec!["2025-13-01".to_string()];
+        assert!(!add_business_days("2025-05-02".into(), 1, HolidayCountry::Japan, invalid).success);
+        assert!(!add_business_days("2099-12-31".into(), 1, HolidayCountry::Japan, vec![]).success);
+    }
+
+    #[test]
+    fn test_count_business_days() {
+        // May 2025: 5/3 and 5/4 fall on the weekend, leaving 5/5 and 5/6 as weekday holidays
+        let result = count_business_days(
+            "2025-05-01".into(),
+            "2025-05-31".into(),
+            HolidayCountry::Japan,
+            vec![],
+        );
+        assert!(result.success);
+        assert_eq!(result.calendar_days, 31);
+        assert_eq!(result.weekend_days, 9);
+        assert_eq!(result.holidays.len(), 2);
+        assert_eq!(result.business_days, 20);
+
+        let single = count_business_days(
+            "2025-05-07".into(),
+            "2025-05-07".into(),
+            HolidayCountry::Japan,
+            vec![],
+        );
+        assert_eq!(single.business_days, 1);
+
+        let reversed = count_business_days(
+            "2025-05-31".into(),
+            "2025-05-01".into(),
+            HolidayCountry::Japan,
+            vec![],
+        );
+        assert!(!reversed.success);
+    }
+}
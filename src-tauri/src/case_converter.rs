@@ -0,0 +1,297 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum CaseStyle {
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+    Constant,
+    Title,
+}
+
+impl CaseStyle {
+    pub const ALL: [CaseStyle; 6] = [
+        CaseStyle::Camel,
+        CaseStyle::Pascal,
+        CaseStyle::Snake,
+        CaseStyle::Kebab,
+        CaseStyle::Constant,
+        CaseStyle::Title,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            CaseStyle::Camel => "camelCase",
+            CaseStyle::Pascal => "PascalCase",
+            CaseStyle::Snake => "snake_case",
+            CaseStyle::Kebab => "kebab-case",
+            CaseStyle::Constant => "CONSTANT_CASE",
+            CaseStyle::Title => "Title Case",
+        }
+    }
+
+    fn join(self, words: &[String]) -> String {
+        match self {
+            CaseStyle::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            CaseStyle::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            CaseStyle::Snake => lower_words(words).join("_"),
+            CaseStyle::Kebab => lower_words(words).join("-"),
+            CaseStyle::Constant => words
+                .iter()
+                .map(|w| w.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            CaseStyle::Title => words
+                .iter()
+                .map(|w| capitalize(w))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaseConvertResult {
+    pub output: String,
+    /// Words found in the (first non-empty line of the) input
+    pub words: Vec<String>,
+    /// Style of the input, when exactly one style reproduces it
+    pub detected_style: Option<CaseStyle>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaseConversion {
+    pub style: CaseStyle,
+    pub label: String,
+    pub output: String,
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn lower_words(words: &[String]) -> Vec<String> {
+    words.iter().map(|w| w.to_lowercase()).collect()
+}
+
+/// Split an identifier or phrase into words.
+///
+/// Spaces, underscores, hyphens and other punctuation separate words, and so do case changes:
+/// `fooBar` → foo/Bar, `HTTPServer` → HTTP/Server. Digits stay with the word before them
+/// (`base64Encode` → base64/Encode) and an uppercase letter after digits starts a new word.
+pub fn split_words(input: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for token in input
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|t| t.replace('\'', ""))
+        .filter(|t| !t.is_empty())
+    {
+        let chars: Vec<char> = token.chars().collect();
+        let mut current = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            if i > 0 && c.is_uppercase() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                let boundary = prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next_is_lower);
+                if boundary && !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            current.push(c);
+        }
+        if !current.is_empty() {
+            words.push(current);
+        }
+    }
+    words
+}
+
+fn detect_style(input: &str, words: &[String]) -> Option<CaseStyle> {
+    let mut matches = CaseStyle::ALL
+        .into_iter()
+        .filter(|style| style.join(words) == input);
+    match (matches.next(), matches.next()) {
+        (Some(style), None) => Some(style),
+        _ => None,
+    }
+}
+
+fn convert_text(input: &str, target: CaseStyle, multiline: bool) -> String {
+    if multiline {
+        input
+            .lines()
+            .map(|line| target.join(&split_words(line)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        target.join(&split_words(input))
+    }
+}
+
+/// Convert `input` to `target`. With `multiline`, each line is converted on its own and empty
+/// lines are kept; otherwise the whole input becomes a single identifier.
+pub fn convert_case(input: &str, target: CaseStyle, multiline: bool) -> CaseConvertResult {
+    let sample = if multiline {
+        input
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or("")
+    } else {
+        input.trim()
+    };
+    let words = split_words(sample);
+
+    CaseConvertResult {
+        output: convert_text(input, target, multiline),
+        detected_style: detect_style(sample, &words),
+        words,
+    }
+}
+
+/// Convert `input` to every supported style at once.
+pub fn convert_all_cases(input: &str, multiline: bool) -> Vec<CaseConversion> {
+    CaseStyle::ALL
+        .into_iter()
+        .map(|style| CaseConversion {
+            style,
+            label: style.label().to_string(),
+            output: convert_text(input, style, multiline),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(input: &str) -> Vec<String> {
+        split_words(input)
+    }
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(words("fooBarBaz"), ["foo", "Bar", "Baz"]);
+        assert_eq!(words("foo_bar-baz qux"), ["foo", "bar", "baz", "qux"]);
+        assert_eq!(words("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(
+            words("getHTTPResponseCode"),
+            ["get", "HTTP", "Response", "Code"]
+        );
+        assert_eq!(words("XMLHttpRequest"), ["XML", "Http", "Request"]);
+        assert_eq!(words("MAX_RETRY_COUNT"), ["MAX", "RETRY", "COUNT"]);
+        assert_eq!(
+            words("  --leading__and trailing--  "),
+            ["leading", "and", "trailing"]
+        );
+        assert_eq!(words("don't stop"), ["dont", "stop"]);
+        assert!(words("").is_empty());
+    }
+
+    #[test]
+    fn test_split_words_with_digits() {
+        assert_eq!(words("base64Encode"), ["base64", "Encode"]);
+        assert_eq!(words("HTTP2Server"), ["HTTP2", "Server"]);
+        assert_eq!(words("v2Api"), ["v2", "Api"]);
+        assert_eq!(words("user_id2"), ["user", "id2"]);
+        assert_eq!(words("sha256sum"), ["sha256sum"]);
+    }
+
+    #[test]
+    fn test_convert_case() {
+        let cases = [
+            (CaseStyle::Camel, "httpServerConfig"),
+            (CaseStyle::Pascal, "HttpServerConfig"),
+            (CaseStyle::Snake, "http_server_config"),
+            (CaseStyle::Kebab, "http-server-config"),
+            (CaseStyle::Constant, "HTTP_SERVER_CONFIG"),
+            (CaseStyle::Title, "Http Server Config"),
+        ];
+        for (style, expected) in cases {
+            assert_eq!(
+                convert_case("HTTPServerConfig", style, false).output,
+                expected
+            );
+            // Every style converts back to the same words
+            assert_eq!(
+                convert_case(expected, CaseStyle::Snake, false).output,
+                "http_server_config"
+            );
+        }
+        assert_eq!(
+            convert_case("HTTPServer", CaseStyle::Snake, false).output,
+            "http_server"
+        );
+    }
+
+    #[test]
+    fn test_detect_style() {
+        assert_eq!(
+            convert_case("userName", CaseStyle::Snake, false).detected_style,
+            Some(CaseStyle::Camel)
+        );
+        assert_eq!(
+            convert_case("user-name", CaseStyle::Snake, false).detected_style,
+            Some(CaseStyle::Kebab)
+        );
+        assert_eq!(
+            convert_case("USER_NAME", CaseStyle::Snake, false).detected_style,
+            Some(CaseStyle::Constant)
+        );
+        assert_eq!(
+            convert_case("User Name", CaseStyle::Snake, false).detected_style,
+            Some(CaseStyle::Title)
+        );
+        // A single lowercase word is valid camel, snake and kebab case alike
+        assert_eq!(
+            convert_case("user", CaseStyle::Snake, false).detected_style,
+            None
+        );
+        assert_eq!(
+            convert_case("HTTPServer", CaseStyle::Snake, false).detected_style,
+            None
+        );
+    }
+
+    #[test]
+    fn test_multiline() {
+        let input = "firstName\nlast_name\n\nEMAIL-ADDRESS";
+        let result = convert_case(input, CaseStyle::Camel, true);
+        assert_eq!(result.output, "firstName\nlastName\n\nemailAddress");
+        assert_eq!(result.words, ["first", "Name"]);
+
+        let single = convert_case(input, CaseStyle::Snake, false);
+        assert_eq!(single.output, "first_name_last_name_email_address");
+    }
+
+    #[test]
+    fn test_convert_all_cases() {
+        let results = convert_all_cases("user id", false);
+        let outputs: Vec<&str> = results.iter().map(|r| r.output.as_str()).collect();
+        assert_eq!(
+            outputs,
+            ["userId", "UserId", "user_id", "user-id", "USER_ID", "User Id"]
+        );
+        assert_eq!(results[4].label, "CONSTANT_CASE");
+    }
+}
@@ -1,13 +1,26 @@
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// 400字詰め原稿用紙: 20 columns by 20 rows
+const MANUSCRIPT_COLUMNS: usize = 20;
+const MANUSCRIPT_ROWS: usize = 20;
+/// Every URL counts as this many characters in a tweet, whatever its length
+const TWEET_URL_LENGTH: usize = 23;
+const TWEET_MAX_LENGTH: usize = 280;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharCountResult {
     pub char_count: usize,
     pub char_count_no_spaces: usize,
+    pub char_count_no_newlines: usize,
+    pub grapheme_count: usize,
     pub word_count: usize,
     pub line_count: usize,
     pub paragraph_count: usize,
     pub byte_count_utf8: usize,
+    pub byte_count_utf16: usize,
     pub byte_count_sjis: usize,
     pub alphanumeric_count: usize,
     pub hiragana_count: usize,
@@ -15,13 +28,19 @@ pub struct CharCountResult {
     pub kanji_count: usize,
     pub fullwidth_count: usize,
     pub halfwidth_count: usize,
+    pub manuscript_pages: usize,
+    pub tweet_weighted_length: usize,
+    pub tweet_remaining: i64,
 }
 
 pub fn count_chars(text: &str) -> CharCountResult {
     let char_count = text.chars().count();
     let char_count_no_spaces = text.chars().filter(|c| !c.is_whitespace()).count();
+    let char_count_no_newlines = text.chars().filter(|c| !matches!(c, '\n' | '\r')).count();
+    let grapheme_count = text.graphemes(true).count();
 
-    let word_count = text.split_whitespace().count();
+    // UAX #29 word boundaries, so text without spaces between words is still split
+    let word_count = text.unicode_words().count();
 
     let line_count = if text.is_empty() {
         0
@@ -36,6 +55,7 @@ pub fn count_chars(text: &str) -> CharCountResult {
     };
 
     let byte_count_utf8 = text.len();
+    let byte_count_utf16 = text.encode_utf16().count() * 2;
 
     let byte_count_sjis = estimate_sjis_bytes(text);
 
@@ -70,13 +90,18 @@ pub fn count_chars(text: &str) -> CharCountResult {
         }
     }
 
+    let tweet_weighted_length = tweet_weighted_length(text);
+
     CharCountResult {
         char_count,
         char_count_no_spaces,
+        char_count_no_newlines,
+        grapheme_count,
         word_count,
         line_count,
         paragraph_count,
         byte_count_utf8,
+        byte_count_utf16,
         byte_count_sjis,
         alphanumeric_count,
         hiragana_count,
@@ -84,7 +109,71 @@ pub fn count_chars(text: &str) -> CharCountResult {
         kanji_count,
         fullwidth_count,
         halfwidth_count,
+        manuscript_pages: manuscript_pages(text),
+        tweet_weighted_length,
+        tweet_remaining: TWEET_MAX_LENGTH as i64 - tweet_weighted_length as i64,
+    }
+}
+
+/// Sheets of 400字詰め原稿用紙 the text fills. Every line starts on a new row, and an empty
+/// line still takes up a row.
+fn manuscript_pages(text: &str) -> usize {
+    if text.is_empty() {
+        return 0;
+    }
+    let rows: usize = text
+        .lines()
+        .map(|line| {
+            line.graphemes(true)
+                .count()
+                .div_ceil(MANUSCRIPT_COLUMNS)
+                .max(1)
+        })
+        .sum();
+    rows.div_ceil(MANUSCRIPT_ROWS)
+}
+
+/// Length as counted by X (Twitter): Latin and general punctuation count once, everything
+/// else including CJK counts twice, an emoji sequence counts twice as a whole and every URL
+/// counts as 23.
+fn tweet_weighted_length(text: &str) -> usize {
+    static URL_RE: OnceLock<Regex> = OnceLock::new();
+    let url_re = URL_RE.get_or_init(|| Regex::new(r"https?://[^\s]+").unwrap());
+
+    let mut length = 0;
+    let mut last = 0;
+    for m in url_re.find_iter(text) {
+        length += weighted_length(&text[last..m.start()]) + TWEET_URL_LENGTH;
+        last = m.end();
     }
+    length + weighted_length(&text[last..])
+}
+
+fn weighted_length(text: &str) -> usize {
+    text.graphemes(true)
+        .map(|grapheme| {
+            if is_emoji_grapheme(grapheme) {
+                2
+            } else {
+                grapheme.chars().map(char_weight).sum()
+            }
+        })
+        .sum()
+}
+
+fn char_weight(c: char) -> usize {
+    match c as u32 {
+        // Variation selectors only pick the presentation of the preceding character
+        0xFE00..=0xFE0F => 0,
+        0x0000..=0x10FF | 0x2000..=0x200D | 0x2010..=0x201F | 0x2032..=0x2037 => 1,
+        _ => 2,
+    }
+}
+
+fn is_emoji_grapheme(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .any(|c| matches!(c as u32, 0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x200D | 0xFE0F))
 }
 
 fn is_hiragana(c: char) -> bool {
@@ -174,4 +263,56 @@ mod tests {
         assert_eq!(result.katakana_count, 4);
         assert_eq!(result.fullwidth_count, 4);
     }
+
+    #[test]
+    fn test_graphemes_and_encodings() {
+        let family = "👨\u{200D}👩\u{200D}👧\u{200D}👦";
+        let result = count_chars(family);
+        assert_eq!(result.grapheme_count, 1);
+        assert_eq!(result.char_count, 7);
+        assert_eq!(result.byte_count_utf8, 25);
+        assert_eq!(result.byte_count_utf16, 22);
+
+        let combining = count_chars("e\u{0301}が");
+        assert_eq!(combining.grapheme_count, 2);
+        assert_eq!(combining.char_count, 3);
+    }
+
+    #[test]
+    fn test_words_without_spaces() {
+        let result = count_chars("Hello, world! It's 2024.");
+        assert_eq!(result.word_count, 4);
+        assert!(count_chars("東京都に住む").word_count > 1);
+    }
+
+    #[test]
+    fn test_manuscript_pages() {
+        assert_eq!(count_chars("").manuscript_pages, 0);
+        assert_eq!(count_chars("あ").manuscript_pages, 1);
+        assert_eq!(count_chars(&"あ".repeat(400)).manuscript_pages, 1);
+        assert_eq!(count_chars(&"あ".repeat(401)).manuscript_pages, 2);
+        // 21 short lines need 21 rows
+        assert_eq!(count_chars(&"あ\n".repeat(21)).manuscript_pages, 2);
+
+        let result = count_chars("一行目\r\n二行目");
+        assert_eq!(result.char_count_no_newlines, 6);
+    }
+
+    #[test]
+    fn test_tweet_weighted_length() {
+        let result = count_chars("Hello");
+        assert_eq!(result.tweet_weighted_length, 5);
+        assert_eq!(result.tweet_remaining, 275);
+
+        assert_eq!(count_chars("こんにちは").tweet_weighted_length, 10);
+        assert_eq!(
+            count_chars("👨\u{200D}👩\u{200D}👧\u{200D}👦!").tweet_weighted_length,
+            3
+        );
+        assert_eq!(
+            count_chars("see https://example.com/a/very/long/path?q=1").tweet_weighted_length,
+            4 + 23
+        );
+        assert_eq!(count_chars(&"あ".repeat(141)).tweet_remaining, -2);
+    }
 }
@@ -0,0 +1,595 @@
+use serde::{Deserialize, Serialize};
+
+const SETUID: u16 = 0o4000;
+const SETGID: u16 = 0o2000;
+const STICKY: u16 = 0o1000;
+
+/// Read/write/execute masks for each class, in `u`, `g`, `o` order
+const CLASSES: [(char, u16, u16, u16); 3] = [
+    ('u', 0o400, 0o200, 0o100),
+    ('g', 0o040, 0o020, 0o010),
+    ('o', 0o004, 0o002, 0o001),
+];
+
+/// File type letters that may lead an `ls -l` style string
+const FILE_TYPES: &str = "-dlcbps";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PermissionClass {
+    /// `owner`, `group` or `other`
+    pub class: String,
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+    pub description_en: String,
+    pub description_ja: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpecialBit {
+    /// `setuid`, `setgid` or `sticky`
+    pub name: String,
+    pub description_en: String,
+    pub description_ja: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PermissionResult {
+    pub success: bool,
+    /// Three digits, or four when a special bit is set
+    pub octal: String,
+    /// `rwxr-xr-x` style, with `s`/`S`/`t`/`T` for the special bits
+    pub symbolic: String,
+    pub classes: Vec<PermissionClass>,
+    /// Special bits that are set
+    pub special_bits: Vec<SpecialBit>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+    /// 0-based character index of the invalid character
+    pub error_position: Option<usize>,
+}
+
+impl PermissionResult {
+    fn failure(error: ParseError) -> Self {
+        Self {
+            success: false,
+            octal: String::new(),
+            symbolic: String::new(),
+            classes: Vec::new(),
+            special_bits: Vec::new(),
+            warnings: Vec::new(),
+            error: Some(error.message),
+            error_position: error.position,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PermissionPreset {
+    pub octal: String,
+    pub symbolic: String,
+    pub description_en: String,
+    pub description_ja: String,
+}
+
+#[derive(Debug)]
+struct ParseError {
+    message: String,
+    position: Option<usize>,
+}
+
+impl ParseError {
+    fn at(position: usize, message: String) -> Self {
+        Self {
+            message: format!("{} at position {}", message, position + 1),
+            position: Some(position),
+        }
+    }
+}
+
+/// How the input was written, which decides how it is parsed
+enum Notation {
+    Octal,
+    /// `rwxr-xr-x`, optionally with an `ls -l` file type letter in front
+    Listing,
+    /// `u+x,g-w` style clauses applied to a base mode
+    Relative,
+}
+
+fn detect_notation(input: &str) -> Notation {
+    let chars: Vec<char> = input.chars().collect();
+    if chars.first().is_some_and(char::is_ascii_digit) {
+        return Notation::Octal;
+    }
+    if chars.iter().any(|c| "+=,".contains(*c)) {
+        return Notation::Relative;
+    }
+    match chars.len() {
+        9 if "r-".contains(chars[0]) => Notation::Listing,
+        10 if FILE_TYPES.contains(chars[0]) => Notation::Listing,
+        _ => Notation::Relative,
+    }
+}
+
+fn parse_octal(input: &str) -> Result<u16, ParseError> {
+    if let Some((position, c)) = input.char_indices().find(|(_, c)| !('0'..='7').contains(c)) {
+        return Err(ParseError::at(
+            position,
+            format!("Invalid octal digit '{}'", c),
+        ));
+    }
+    // A leading zero is allowed on top of the four significant digits (e.g. `00755`)
+    let significant = input.trim_start_matches('0');
+    if input.len() < 3 || significant.len() > 4 {
+        return Err(ParseError {
+            message: format!("Octal mode must have 3 or 4 digits, got {}", input.len()),
+            position: None,
+        });
+    }
+    Ok(u16::from_str_radix(input, 8).unwrap_or(0))
+}
+
+fn parse_listing(input: &str) -> Result<u16, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let offset = chars.len() - 9;
+    let mut mode = 0;
+    for (index, (who, r, w, x)) in CLASSES.iter().enumerate() {
+        let base = offset + index * 3;
+        let expect = |position: usize, allowed: &str| -> Result<char, ParseError> {
+            let c = chars[position];
+            if allowed.contains(c) {
+                Ok(c)
+            } else {
+                let options: Vec<String> = allowed.chars().map(|a| format!("'{}'", a)).collect();
+                Err(ParseError::at(
+                    position,
+                    format!("Expected {} but found '{}'", options.join(" or "), c),
+                ))
+            }
+        };
+        if expect(base, "r-")? == 'r' {
+            mode |= r;
+        }
+        if expect(base + 1, "w-")? == 'w' {
+            mode |= w;
+        }
+        let (special, set, unset) = match who {
+            'u' => (SETUID, 's', 'S'),
+            'g' => (SETGID, 's', 'S'),
+            _ => (STICKY, 't', 'T'),
+        };
+        let allowed = format!("x{}{}-", set, unset);
+        match expect(base + 2, &allowed)? {
+            'x' => mode |= x,
+            c if c == set => mode |= x | special,
+            c if c == unset => mode |= special,
+            _ => {}
+        }
+    }
+    Ok(mode)
+}
+
+/// Apply `chmod` style clauses such as `u+x,g-w`, `a=r` or `g=u` to `base`.
+///
+/// Like `chmod`, a clause without `u`/`g`/`o`/`a` applies to everyone (the umask is
+/// ignored), and `X` adds execute only when some execute bit is already set.
+fn apply_clauses(base: u16, change: &str) -> Result<u16, ParseError> {
+    let chars: Vec<char> = change.chars().collect();
+    if chars.is_empty() {
+        return Err(ParseError {
+            message: "Permission is empty".to_string(),
+            position: None,
+        });
+    }
+    let mut mode = base;
+    let mut pos = 0;
+    loop {
+        let mut who_mask = 0u16;
+        while pos < chars.len() && "ugoa".contains(chars[pos]) {
+            who_mask |= match chars[pos] {
+                'u' => 0o4700,
+                'g' => 0o2070,
+                'o' => 0o1007,
+                _ => 0o7777,
+            };
+            pos += 1;
+        }
+        let explicit_who = who_mask != 0;
+        if !explicit_who {
+            who_mask = 0o7777;
+        }
+
+        let mut has_op = false;
+        while pos < chars.len() && "+-=".contains(chars[pos]) {
+            has_op = true;
+            let op = chars[pos];
+            pos += 1;
+
+            let mut perm = 0u16;
+            if pos < chars.len() && "ugo".contains(chars[pos]) {
+                // Copy another class's bits, e.g. `g=u`
+                let source = match chars[pos] {
+                    'u' => (mode >> 6) & 0o7,
+                    'g' => (mode >> 3) & 0o7,
+                    _ => mode & 0o7,
+                };
+                perm = source * 0o111;
+                pos += 1;
+            } else {
+                while pos < chars.len() && "rwxXst".contains(chars[pos]) {
+                    perm |= match chars[pos] {
+                        'r' => 0o444,
+                        'w' => 0o222,
+                        'x' => 0o111,
+                        'X' if mode & 0o111 != 0 => 0o111,
+                        'X' => 0,
+                        's' => SETUID | SETGID,
+                        _ => STICKY,
+                    };
+                    pos += 1;
+                }
+            }
+
+            let perm = perm & who_mask;
+            match op {
+                '+' => mode |= perm,
+                '-' => mode &= !perm,
+                // `=` resets every bit of the named classes, special bits included
+                _ => mode = (mode & !who_mask) | perm,
+            }
+        }
+
+        if !has_op {
+            return Err(match chars.get(pos) {
+                Some(c) => {
+                    ParseError::at(pos, format!("Expected '+', '-' or '=' but found '{}'", c))
+                }
+                None if explicit_who => {
+                    ParseError::at(pos, "Expected '+', '-' or '=' after the class".to_string())
+                }
+                None => ParseError::at(pos, "Expected a permission clause".to_string()),
+            });
+        }
+
+        match chars.get(pos) {
+            None => return Ok(mode),
+            Some(',') if pos + 1 < chars.len() => pos += 1,
+            Some(',') => {
+                return Err(ParseError::at(
+                    pos,
+                    "Trailing ',' without a clause".to_string(),
+                ))
+            }
+            Some(c) => return Err(ParseError::at(pos, format!("Unexpected character '{}'", c))),
+        }
+    }
+}
+
+fn to_symbolic(mode: u16) -> String {
+    let mut out = String::with_capacity(9);
+    for (who, r, w, x) in CLASSES {
+        out.push(if mode & r != 0 { 'r' } else { '-' });
+        out.push(if mode & w != 0 { 'w' } else { '-' });
+        let (special, set, unset) = match who {
+            'u' => (SETUID, 's', 'S'),
+            'g' => (SETGID, 's', 'S'),
+            _ => (STICKY, 't', 'T'),
+        };
+        out.push(match (mode & x != 0, mode & special != 0) {
+            (true, true) => set,
+            (false, true) => unset,
+            (true, false) => 'x',
+            (false, false) => '-',
+        });
+    }
+    out
+}
+
+fn to_octal(mode: u16) -> String {
+    if mode & 0o7000 != 0 {
+        format!("{:04o}", mode)
+    } else {
+        format!("{:03o}", mode)
+    }
+}
+
+fn describe_class(class: &str, read: bool, write: bool, execute: bool) -> (String, String) {
+    let (subject_en, subject_ja) = match class {
+        "owner" => ("Owner", "所有者"),
+        "group" => ("Group", "グループ"),
+        _ => ("Others", "その他"),
+    };
+    let abilities: Vec<(&str, &str)> = [
+        (read, ("read", "読み取り")),
+        (write, ("write", "書き込み")),
+        (execute, ("execute", "実行")),
+    ]
+    .into_iter()
+    .filter_map(|(set, names)| set.then_some(names))
+    .collect();
+    if abilities.is_empty() {
+        return (
+            format!("{} has no access", subject_en),
+            format!("{}はアクセスできません", subject_ja),
+        );
+    }
+    let en: Vec<&str> = abilities.iter().map(|(en, _)| *en).collect();
+    let en = match en.as_slice() {
+        [only] => only.to_string(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+        [] => unreachable!(),
+    };
+    let ja: Vec<&str> = abilities.iter().map(|(_, ja)| *ja).collect();
+    (
+        format!("{} can {}", subject_en, en),
+        format!("{}は{}が可能", subject_ja, ja.join("・")),
+    )
+}
+
+fn describe_mode(mode: u16) -> PermissionResult {
+    let classes = ["owner", "group", "other"]
+        .iter()
+        .zip(CLASSES)
+        .map(|(class, (_, r, w, x))| {
+            let (read, write, execute) = (mode & r != 0, mode & w != 0, mode & x != 0);
+            let (description_en, description_ja) = describe_class(class, read, write, execute);
+            PermissionClass {
+                class: class.to_string(),
+                read,
+                write,
+                execute,
+                description_en,
+                description_ja,
+            }
+        })
+        .collect();
+
+    let specials = [
+        (
+            SETUID,
+            0o100,
+            "setuid",
+            "Runs with the privileges of the file's owner",
+            "実行時にファイル所有者の権限で動作します",
+        ),
+        (
+            SETGID,
+            0o010,
+            "setgid",
+            "Runs with the file's group; new files in a directory inherit its group",
+            "実行時にファイルのグループ権限で動作し、ディレクトリでは新規ファイルがグループを継承します",
+        ),
+        (
+            STICKY,
+            0o001,
+            "sticky",
+            "In a directory, only a file's owner can delete or rename it",
+            "ディレクトリ内のファイルを削除・改名できるのは所有者のみになります",
+        ),
+    ];
+    let mut special_bits = Vec::new();
+    let mut warnings = Vec::new();
+    for (bit, execute, name, en, ja) in specials {
+        if mode & bit == 0 {
+            continue;
+        }
+        special_bits.push(SpecialBit {
+            name: name.to_string(),
+            description_en: en.to_string(),
+            description_ja: ja.to_string(),
+        });
+        if mode & execute == 0 && bit != STICKY {
+            warnings.push(format!("{} is set without execute permission", name));
+        }
+    }
+    if mode & 0o002 != 0 && mode & STICKY == 0 {
+        warnings.push("Anyone can modify this file (world-writable)".to_string());
+    }
+
+    PermissionResult {
+        success: true,
+        octal: to_octal(mode),
+        symbolic: to_symbolic(mode),
+        classes,
+        special_bits,
+        warnings,
+        error: None,
+        error_position: None,
+    }
+}
+
+fn parse_mode(input: &str) -> Result<u16, ParseError> {
+    match detect_notation(input) {
+        Notation::Octal => parse_octal(input),
+        Notation::Listing => parse_listing(input),
+        Notation::Relative => apply_clauses(0, input),
+    }
+}
+
+/// Convert between octal (`755`, `0644`, `4755`) and symbolic (`rwxr-xr-x`) notation.
+///
+/// Relative clauses such as `u+x,g-w` are applied to `000`.
+pub fn parse_permission(input: &str) -> PermissionResult {
+    let input = input.trim();
+    match parse_mode(input) {
+        Ok(mode) => {
+            let mut result = describe_mode(mode);
+            if matches!(detect_notation(input), Notation::Relative) {
+                result
+                    .warnings
+                    .insert(0, "Relative change applied to 000".to_string());
+            }
+            result
+        }
+        Err(e) => PermissionResult::failure(e),
+    }
+}
+
+/// Apply `change` (relative clauses or an absolute mode) to `base`, e.g. `u+x` on `644`
+/// gives `744`
+pub fn apply_symbolic_change(base: &str, change: &str) -> PermissionResult {
+    let base_mode = match detect_notation(base.trim()) {
+        Notation::Relative => Err(ParseError {
+            message: "Base must be an octal or rwx permission".to_string(),
+            position: None,
+        }),
+        _ => parse_mode(base.trim()),
+    };
+    let base_mode = match base_mode {
+        Ok(mode) => mode,
+        Err(e) => {
+            let mut result = PermissionResult::failure(e);
+            result.error = result.error.map(|e| format!("Base: {}", e));
+            // Positions refer to the change input in the UI
+            result.error_position = None;
+            return result;
+        }
+    };
+    let change = change.trim();
+    let mode = match detect_notation(change) {
+        Notation::Relative => apply_clauses(base_mode, change),
+        _ => parse_mode(change),
+    };
+    match mode {
+        Ok(mode) => describe_mode(mode),
+        Err(e) => PermissionResult::failure(e),
+    }
+}
+
+/// Common modes and what they are used for
+pub fn list_permission_presets() -> Vec<PermissionPreset> {
+    const PRESETS: [(u16, &str, &str); 10] = [
+        (
+            0o644,
+            "Regular file: owner edits, everyone reads",
+            "通常のファイル（所有者のみ編集、全員が読み取り可）",
+        ),
+        (
+            0o755,
+            "Directory or executable script",
+            "ディレクトリ・実行可能なスクリプト",
+        ),
+        (
+            0o600,
+            "Private file such as an SSH key or .env",
+            "SSH 秘密鍵や .env などの非公開ファイル",
+        ),
+        (
+            0o700,
+            "Private directory such as ~/.ssh",
+            "~/.ssh などの非公開ディレクトリ",
+        ),
+        (
+            0o400,
+            "Read-only secret, e.g. a cloud key pair",
+            "読み取り専用の秘密ファイル（クラウドの鍵ペアなど）",
+        ),
+        (
+            0o664,
+            "File shared with the group",
+            "グループで共同編集するファイル",
+        ),
+        (
+            0o775,
+            "Directory shared with the group",
+            "グループで共同利用するディレクトリ",
+        ),
+        (
+            0o2775,
+            "Shared project directory; new files keep the group",
+            "新規ファイルがグループを継承する共有ディレクトリ",
+        ),
+        (
+            0o1777,
+            "Shared temporary directory like /tmp",
+            "/tmp のような共有一時ディレクトリ",
+        ),
+        (
+            0o4755,
+            "Program that runs as its owner, like passwd",
+            "所有者権限で動作するプログラム（passwd など）",
+        ),
+    ];
+    PRESETS
+        .iter()
+        .map(|(mode, en, ja)| PermissionPreset {
+            octal: to_octal(*mode),
+            symbolic: to_symbolic(*mode),
+            description_en: en.to_string(),
+            description_ja: ja.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_octal_and_symbolic_round_trip() {
+        let result = parse_permission("755");
+        assert!(result.success);
+        assert_eq!(result.symbolic, "rwxr-xr-x");
+        assert_eq!(
+            result.classes[1].description_en,
+            "Group can read and execute"
+        );
+        assert_eq!(
+            result.classes[2].description_ja,
+            "その他は読み取り・実行が可能"
+        );
+
+        assert_eq!(parse_permission("0644").symbolic, "rw-r--r--");
+        assert_eq!(parse_permission("rw-r--r--").octal, "644");
+        assert_eq!(parse_permission("-rwxr-xr-x").octal, "755");
+        assert_eq!(parse_permission("drwxrwxrwt").octal, "1777");
+
+        let setuid = parse_permission("4755");
+        assert_eq!(setuid.symbolic, "rwsr-xr-x");
+        assert_eq!(setuid.special_bits[0].name, "setuid");
+        assert_eq!(parse_permission("rwSr--r--").octal, "4644");
+        assert_eq!(parse_permission("rwSr--r--").warnings.len(), 1);
+        assert_eq!(parse_permission("2770").symbolic, "rwxrws---");
+    }
+
+    #[test]
+    fn test_relative_changes() {
+        assert_eq!(apply_symbolic_change("644", "u+x").octal, "744");
+        assert_eq!(apply_symbolic_change("775", "g-w,o-rx").octal, "750");
+        assert_eq!(apply_symbolic_change("rw-r--r--", "a+x").octal, "755");
+        assert_eq!(apply_symbolic_change("777", "go=r").octal, "744");
+        assert_eq!(apply_symbolic_change("640", "o=g").octal, "644");
+        assert_eq!(apply_symbolic_change("644", "+X").octal, "644");
+        assert_eq!(apply_symbolic_change("744", "+X").octal, "755");
+        assert_eq!(apply_symbolic_change("755", "u+s").octal, "4755");
+        assert_eq!(apply_symbolic_change("777", "+t").octal, "1777");
+        assert_eq!(apply_symbolic_change("644", "600").octal, "600");
+
+        let relative = parse_permission("u+rwx,g+rx");
+        assert_eq!(relative.octal, "750");
+        assert!(!relative.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_errors_point_at_the_position() {
+        let result = parse_permission("758");
+        assert!(!result.success);
+        assert_eq!(result.error_position, Some(2));
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Invalid octal digit '8' at position 3")
+        );
+
+        assert!(!parse_permission("75").success);
+        assert!(!parse_permission("17777").success);
+        assert_eq!(parse_permission("rwxr-xr-q").error_position, Some(8));
+        assert_eq!(parse_permission("rwxrwxwrx").error_position, Some(6));
+        assert_eq!(apply_symbolic_change("644", "u+x,").error_position, Some(3));
+        assert_eq!(apply_symbolic_change("644", "ux").error_position, Some(1));
+        assert_eq!(apply_symbolic_change("644", "u+z").error_position, Some(2));
+        assert!(apply_symbolic_change("u+x", "g+x")
+            .error
+            .unwrap()
+            .starts_with("Base:"));
+    }
+}
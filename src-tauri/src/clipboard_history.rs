@@ -0,0 +1,434 @@
+use crate::json_store::load_json_store_for_update;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Entries kept in the history; pinned entries are never dropped to make room
+pub const MAX_ENTRIES: usize = 200;
+/// Larger copies are not recorded
+pub const MAX_ENTRY_BYTES: usize = 100 * 1024;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(800);
+/// Error used by the monitor for text that `add_entry` skips
+const NOT_RECORDED: &str = "Not recorded";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipEntry {
+    pub id: String,
+    pub content: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ClipboardSettings {
+    /// Skip short single words that look like passwords or tokens
+    pub exclude_secrets: bool,
+}
+
+impl Default for ClipboardSettings {
+    fn default() -> Self {
+        Self {
+            exclude_secrets: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ClipboardHistory {
+    pub settings: ClipboardSettings,
+    /// Newest first
+    pub entries: Vec<ClipEntry>,
+}
+
+/// True for text that is probably a password: one short word mixing at least three
+/// of lowercase, uppercase, digits and symbols
+pub fn looks_like_secret(text: &str) -> bool {
+    let len = text.chars().count();
+    if !(8..=32).contains(&len) || text.chars().any(char::is_whitespace) || text.contains("://") {
+        return false;
+    }
+    let classes = [
+        text.chars().any(|c| c.is_lowercase()),
+        text.chars().any(|c| c.is_uppercase()),
+        text.chars().any(|c| c.is_ascii_digit()),
+        text.chars().any(|c| c.is_ascii_punctuation()),
+    ];
+    classes.iter().filter(|present| **present).count() >= 3
+}
+
+/// Record `content` as the newest entry, returning it if it was added.
+///
+/// Blank text, text over `MAX_ENTRY_BYTES`, a repeat of the newest entry and, when enabled,
+/// text that looks like a secret are ignored.
+pub fn add_entry(history: &mut ClipboardHistory, content: String) -> Option<ClipEntry> {
+    if content.trim().is_empty() || content.len() > MAX_ENTRY_BYTES {
+        return None;
+    }
+    if history.settings.exclude_secrets && looks_like_secret(content.trim()) {
+        return None;
+    }
+    if history
+        .entries
+        .first()
+        .is_some_and(|newest| newest.content == content)
+    {
+        return None;
+    }
+
+    let entry = ClipEntry {
+        id: uuid::Uuid::new_v4().to_string(),
+        content,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        pinned: false,
+    };
+    history.entries.insert(0, entry.clone());
+    while history.entries.len() > MAX_ENTRIES {
+        match history.entries.iter().rposition(|e| !e.pinned) {
+            Some(oldest) => {
+                history.entries.remove(oldest);
+            }
+            None => break,
+        }
+    }
+    Some(entry)
+}
+
+pub fn toggle_pin(history: &mut ClipboardHistory, id: &str) -> Result<ClipEntry, String> {
+    let entry = history
+        .entries
+        .iter_mut()
+        .find(|e| e.id == id)
+        .ok_or_else(|| format!("Clipboard entry not found: {}", id))?;
+    entry.pinned = !entry.pinned;
+    Ok(entry.clone())
+}
+
+pub fn remove_entry(history: &mut ClipboardHistory, id: &str) -> Result<(), String> {
+    let before = history.entries.len();
+    history.entries.retain(|e| e.id != id);
+    if history.entries.len() == before {
+        return Err(format!("Clipboard entry not found: {}", id));
+    }
+    Ok(())
+}
+
+/// Remove every entry except the pinned ones
+pub fn clear_unpinned(history: &mut ClipboardHistory) {
+    history.entries.retain(|e| e.pinned);
+}
+
+fn get_history_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("clipboard_history.json"))
+}
+
+/// A corrupt history file is moved aside and recording starts over, rather than every
+/// later update failing and the monitor silently recording nothing
+fn load_history(app: &AppHandle) -> Result<ClipboardHistory, String> {
+    load_json_store_for_update(&get_history_path(app)?, "clipboard history")
+}
+
+fn save_history(app: &AppHandle, history: &ClipboardHistory) -> Result<(), String> {
+    let path = get_history_path(app)?;
+    let content =
+        serde_json::to_string(history).map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write clipboard history: {}", e))
+}
+
+/// Clipboard history and monitor state, managed as Tauri state
+#[derive(Default)]
+pub struct ClipboardStore {
+    running: AtomicBool,
+    /// Bumped on every start so a stopped monitor thread cannot outlive a restart
+    generation: AtomicU64,
+    /// Loaded from disk on first use
+    history: Mutex<Option<ClipboardHistory>>,
+    /// Text copied by `copy_sensitive_text` that is waiting to be cleared, with the number
+    /// of that copy so an earlier timer cannot clear a later copy of the same text
+    sensitive: Mutex<Option<(u64, String)>>,
+    sensitive_copies: AtomicU64,
+}
+
+impl ClipboardStore {
+    fn with_history<T>(
+        &self,
+        app: &AppHandle,
+        f: impl FnOnce(&mut ClipboardHistory) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let mut guard = self
+            .history
+            .lock()
+            .map_err(|_| "Clipboard history is unavailable".to_string())?;
+        if guard.is_none() {
+            *guard = Some(load_history(app)?);
+        }
+        f(guard.as_mut().expect("history was just loaded"))
+    }
+
+    /// Run `f` on the history and save it when `f` succeeds
+    fn update<T>(
+        &self,
+        app: &AppHandle,
+        f: impl FnOnce(&mut ClipboardHistory) -> Result<T, String>,
+    ) -> Result<T, String> {
+        self.with_history(app, |history| {
+            let value = f(history)?;
+            save_history(app, history)?;
+            Ok(value)
+        })
+    }
+
+    fn read<T>(
+        &self,
+        app: &AppHandle,
+        f: impl FnOnce(&ClipboardHistory) -> T,
+    ) -> Result<T, String> {
+        self.with_history(app, |history| Ok(f(history)))
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Remember `text` as the latest sensitive copy and return its number
+    fn begin_sensitive(&self, text: &str) -> u64 {
+        let copy = self.sensitive_copies.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Ok(mut guard) = self.sensitive.lock() {
+            *guard = Some((copy, text.to_string()));
+        }
+        copy
+    }
+
+    /// Forget sensitive copy `copy`, returning its text if no later sensitive copy replaced it
+    fn finish_sensitive(&self, copy: u64) -> Option<String> {
+        let mut guard = self.sensitive.lock().ok()?;
+        match guard.take() {
+            Some((latest, text)) if latest == copy => Some(text),
+            other => {
+                *guard = other;
+                None
+            }
+        }
+    }
+
+    fn is_sensitive(&self, text: &str) -> bool {
+        self.sensitive
+            .lock()
+            .map(|guard| guard.as_ref().is_some_and(|(_, t)| t == text))
+            .unwrap_or(false)
+    }
+}
+
+/// Start polling the clipboard; does nothing if the monitor is already running.
+/// Each new entry is emitted as `clipboard-history-updated`.
+pub fn start_clipboard_monitor(app: &AppHandle) {
+    let store = app.state::<ClipboardStore>();
+    if store.running.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let generation = store.generation.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    thread::spawn(move || {
+        let store = app.state::<ClipboardStore>();
+        let mut last_seen: Option<String> = None;
+        while store.is_running() && store.generation.load(Ordering::SeqCst) == generation {
+            if let Ok(text) = app.clipboard().read_text() {
+                if last_seen.as_ref() != Some(&text) {
+                    last_seen = Some(text.clone());
+                    if store.is_sensitive(&text) {
+                        thread::sleep(POLL_INTERVAL);
+                        continue;
+                    }
+                    // Skipped text is returned as an error so the unchanged history is not rewritten
+                    let added = store.update(&app, |h| {
+                        add_entry(h, text).ok_or_else(|| NOT_RECORDED.to_string())
+                    });
+                    match added {
+                        Ok(entry) => {
+                            let _ = app.emit("clipboard-history-updated", entry);
+                        }
+                        Err(e) if e != NOT_RECORDED => {
+                            eprintln!("Failed to record clipboard entry: {}", e)
+                        }
+                        Err(_) => {}
+                    }
+                }
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
+
+/// Copy `text` and clear the clipboard after `clear_after_secs`, unless something else has
+/// been copied by then. The sensitive text is never recorded in the history, and
+/// `clipboard-cleared` is emitted once the clipboard has been cleared.
+pub fn copy_sensitive_text(
+    app: &AppHandle,
+    text: String,
+    clear_after_secs: u64,
+) -> Result<(), String> {
+    let store = app.state::<ClipboardStore>();
+    let copy = store.begin_sensitive(&text);
+    app.clipboard()
+        .write_text(text)
+        .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
+    let app = app.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(clear_after_secs));
+        let store = app.state::<ClipboardStore>();
+        let Some(text) = store.finish_sensitive(copy) else {
+            return;
+        };
+        if app.clipboard().read_text().ok().as_ref() == Some(&text)
+            && app.clipboard().clear().is_ok()
+        {
+            let _ = app.emit("clipboard-cleared", ());
+        }
+    });
+    Ok(())
+}
+
+pub fn stop_clipboard_monitor(store: &ClipboardStore) {
+    store.running.store(false, Ordering::SeqCst);
+}
+
+pub fn get_clipboard_history(
+    app: &AppHandle,
+    store: &ClipboardStore,
+) -> Result<Vec<ClipEntry>, String> {
+    store.read(app, |h| h.entries.clone())
+}
+
+/// Clear the history, keeping pinned entries; returns what is left
+pub fn clear_clipboard_history(
+    app: &AppHandle,
+    store: &ClipboardStore,
+) -> Result<Vec<ClipEntry>, String> {
+    store.update(app, |h| {
+        clear_unpinned(h);
+        Ok(h.entries.clone())
+    })
+}
+
+pub fn pin_clip_entry(
+    app: &AppHandle,
+    store: &ClipboardStore,
+    id: &str,
+) -> Result<ClipEntry, String> {
+    store.update(app, |h| toggle_pin(h, id))
+}
+
+pub fn delete_clip_entry(app: &AppHandle, store: &ClipboardStore, id: &str) -> Result<(), String> {
+    store.update(app, |h| remove_entry(h, id))
+}
+
+pub fn get_clipboard_settings(
+    app: &AppHandle,
+    store: &ClipboardStore,
+) -> Result<ClipboardSettings, String> {
+    store.read(app, |h| h.settings.clone())
+}
+
+pub fn update_clipboard_settings(
+    app: &AppHandle,
+    store: &ClipboardStore,
+    settings: ClipboardSettings,
+) -> Result<ClipboardSettings, String> {
+    store.update(app, |h| {
+        h.settings = settings;
+        Ok(h.settings.clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_entry_skips_repeats_and_oversized_text() {
+        let mut history = ClipboardHistory::default();
+        assert!(add_entry(&mut history, "hello".to_string()).is_some());
+        assert!(add_entry(&mut history, "hello".to_string()).is_none());
+        assert!(add_entry(&mut history, "   \n".to_string()).is_none());
+        assert!(add_entry(&mut history, "x".repeat(MAX_ENTRY_BYTES + 1)).is_none());
+        assert!(add_entry(&mut history, "world".to_string()).is_some());
+        // Only consecutive copies are collapsed
+        assert!(add_entry(&mut history, "hello".to_string()).is_some());
+
+        let contents: Vec<&str> = history.entries.iter().map(|e| e.content.as_str()).collect();
+        assert_eq!(contents, vec!["hello", "world", "hello"]);
+    }
+
+    #[test]
+    fn test_add_entry_keeps_pinned_entries_when_full() {
+        let mut history = ClipboardHistory::default();
+        let first = add_entry(&mut history, "keep me".to_string()).unwrap();
+        toggle_pin(&mut history, &first.id).unwrap();
+        for i in 0..MAX_ENTRIES + 10 {
+            add_entry(&mut history, format!("entry {}", i));
+        }
+        assert_eq!(history.entries.len(), MAX_ENTRIES);
+        assert_eq!(history.entries.last().unwrap().id, first.id);
+        assert_eq!(
+            history.entries[0].content,
+            format!("entry {}", MAX_ENTRIES + 9)
+        );
+
+        clear_unpinned(&mut history);
+        assert_eq!(history.entries.len(), 1);
+        assert!(history.entries[0].pinned);
+        assert!(toggle_pin(&mut history, "missing").is_err());
+        assert!(remove_entry(&mut history, &first.id).is_ok());
+        assert!(history.entries.is_empty());
+    }
+
+    #[test]
+    fn test_sensitive_copy_only_cleared_by_latest_timer() {
+        let store = ClipboardStore::default();
+        let first = store.begin_sensitive("s3cret!Pass");
+        let second = store.begin_sensitive("s3cret!Pass");
+        assert!(store.is_sensitive("s3cret!Pass"));
+        assert!(!store.is_sensitive("something else"));
+
+        // The first timer must not clear a copy made after it started
+        assert_eq!(store.finish_sensitive(first), None);
+        assert!(store.is_sensitive("s3cret!Pass"));
+        assert_eq!(
+            store.finish_sensitive(second).as_deref(),
+            Some("s3cret!Pass")
+        );
+        assert!(!store.is_sensitive("s3cret!Pass"));
+        assert_eq!(store.finish_sensitive(second), None);
+    }
+
+    #[test]
+    fn test_secret_detection() {
+        assert!(looks_like_secret("Tr0ub4dor&3"));
+        assert!(looks_like_secret("hunter2!xyz"));
+        assert!(!looks_like_secret("password"));
+        assert!(!looks_like_secret("Hello, World 2024!"));
+        assert!(!looks_like_secret("https://Example.com/a1"));
+        assert!(!looks_like_secret("Ab1!"));
+
+        let mut history = ClipboardHistory::default();
+        assert!(add_entry(&mut history, "Tr0ub4dor&3".to_string()).is_none());
+        history.settings.exclude_secrets = false;
+        assert!(add_entry(&mut history, "Tr0ub4dor&3".to_string()).is_some());
+    }
+}
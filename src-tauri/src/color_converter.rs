@@ -0,0 +1,770 @@
+use serde::{Deserialize, Serialize};
+
+/// CSS named colors (CSS Color Module Level 4)
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum PaletteKind {
+    Complementary,
+    Analogous,
+    Triadic,
+    /// Ten lightness steps of the base hue, from lightest to darkest
+    Shades,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorValues {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: f64,
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+    pub hsv_saturation: f64,
+    pub hsv_value: f64,
+    pub cyan: f64,
+    pub magenta: f64,
+    pub yellow: f64,
+    pub key: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorConvertResult {
+    pub success: bool,
+    pub hex: String,
+    pub rgb: String,
+    pub hsl: String,
+    pub hsv: String,
+    pub cmyk: String,
+    /// CSS color name with exactly this color, if any
+    pub name: Option<String>,
+    pub values: Option<ColorValues>,
+    pub error: Option<String>,
+    /// The part of the input that could not be interpreted
+    pub invalid_part: Option<String>,
+}
+
+impl ColorConvertResult {
+    fn failure(error: ColorParseError) -> Self {
+        Self {
+            success: false,
+            hex: String::new(),
+            rgb: String::new(),
+            hsl: String::new(),
+            hsv: String::new(),
+            cmyk: String::new(),
+            name: None,
+            values: None,
+            error: Some(error.message),
+            invalid_part: Some(error.fragment),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaletteColor {
+    pub hex: String,
+    pub rgb: String,
+    pub hsl: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaletteResult {
+    pub success: bool,
+    pub colors: Vec<PaletteColor>,
+    pub error: Option<String>,
+    pub invalid_part: Option<String>,
+}
+
+#[derive(Debug)]
+struct ColorParseError {
+    message: String,
+    fragment: String,
+}
+
+impl ColorParseError {
+    fn new(message: impl Into<String>, fragment: &str) -> Self {
+        Self {
+            message: message.into(),
+            fragment: fragment.to_string(),
+        }
+    }
+}
+
+/// sRGB color with channels in 0..=255 and alpha in 0..=1
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rgba {
+    r: f64,
+    g: f64,
+    b: f64,
+    a: f64,
+}
+
+impl Rgba {
+    fn from_u32(value: u32) -> Self {
+        Self {
+            r: ((value >> 16) & 0xff) as f64,
+            g: ((value >> 8) & 0xff) as f64,
+            b: (value & 0xff) as f64,
+            a: 1.0,
+        }
+    }
+
+    fn channels(&self) -> (u8, u8, u8) {
+        let channel = |v: f64| v.round().clamp(0.0, 255.0) as u8;
+        (channel(self.r), channel(self.g), channel(self.b))
+    }
+
+    /// (hue 0..360, saturation 0..100, lightness 0..100)
+    fn to_hsl(self) -> (f64, f64, f64) {
+        let (r, g, b) = (self.r / 255.0, self.g / 255.0, self.b / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l * 100.0);
+        }
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        (hue(r, g, b, max, delta), s * 100.0, l * 100.0)
+    }
+
+    /// (hue 0..360, saturation 0..100, value 0..100)
+    fn to_hsv(self) -> (f64, f64, f64) {
+        let (r, g, b) = (self.r / 255.0, self.g / 255.0, self.b / 255.0);
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        if delta == 0.0 {
+            return (0.0, 0.0, max * 100.0);
+        }
+        (hue(r, g, b, max, delta), delta / max * 100.0, max * 100.0)
+    }
+
+    /// (cyan, magenta, yellow, key) in 0..100
+    fn to_cmyk(self) -> (f64, f64, f64, f64) {
+        let (r, g, b) = (self.r / 255.0, self.g / 255.0, self.b / 255.0);
+        let k = 1.0 - r.max(g).max(b);
+        if k >= 1.0 {
+            return (0.0, 0.0, 0.0, 100.0);
+        }
+        let component = |v: f64| (1.0 - v - k) / (1.0 - k) * 100.0;
+        (component(r), component(g), component(b), k * 100.0)
+    }
+
+    fn from_hsl(h: f64, s: f64, l: f64, a: f64) -> Self {
+        let (s, l) = (s / 100.0, l / 100.0);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let h = h.rem_euclid(360.0) / 60.0;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        Self {
+            r: (r + m) * 255.0,
+            g: (g + m) * 255.0,
+            b: (b + m) * 255.0,
+            a,
+        }
+    }
+
+    fn hex(&self) -> String {
+        let (r, g, b) = self.channels();
+        if self.a < 1.0 {
+            let a = (self.a * 255.0).round() as u8;
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        } else {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        }
+    }
+
+    fn rgb(&self) -> String {
+        let (r, g, b) = self.channels();
+        if self.a < 1.0 {
+            format!("rgba({}, {}, {}, {})", r, g, b, format_alpha(self.a))
+        } else {
+            format!("rgb({}, {}, {})", r, g, b)
+        }
+    }
+
+    fn hsl(&self) -> String {
+        let (h, s, l) = self.to_hsl();
+        let (h, s, l) = (h.round() % 360.0, s.round(), l.round());
+        if self.a < 1.0 {
+            format!("hsla({}, {}%, {}%, {})", h, s, l, format_alpha(self.a))
+        } else {
+            format!("hsl({}, {}%, {}%)", h, s, l)
+        }
+    }
+}
+
+fn hue(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    let h = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    h * 60.0
+}
+
+fn format_alpha(alpha: f64) -> String {
+    let formatted = format!("{:.3}", alpha);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn parse_color(input: &str) -> Result<Rgba, ColorParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ColorParseError::new("Input is empty", ""));
+    }
+    let lower = trimmed.to_ascii_lowercase();
+
+    if let Some(hex) = lower.strip_prefix('#') {
+        return parse_hex(hex, trimmed);
+    }
+    if let Some(open) = lower.find('(') {
+        let function = lower[..open].trim();
+        let Some(args) = lower[open + 1..].strip_suffix(')') else {
+            return Err(ColorParseError::new(
+                "Missing closing parenthesis",
+                &trimmed[open..],
+            ));
+        };
+        return match function {
+            "rgb" | "rgba" => parse_rgb_args(args),
+            "hsl" | "hsla" => parse_hsl_args(args),
+            _ => Err(ColorParseError::new(
+                format!("Unknown color function '{}'", function),
+                &trimmed[..open],
+            )),
+        };
+    }
+    if lower == "transparent" {
+        return Ok(Rgba {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.0,
+        });
+    }
+    if let Some((_, value)) = NAMED_COLORS.iter().find(|(name, _)| *name == lower) {
+        return Ok(Rgba::from_u32(*value));
+    }
+    // Hex without the leading '#'
+    if matches!(lower.len(), 3 | 4 | 6 | 8) && lower.chars().all(|c| c.is_ascii_hexdigit()) {
+        return parse_hex(&lower, trimmed);
+    }
+
+    Err(ColorParseError::new(
+        "Not a hex code, rgb()/hsl() value or CSS color name",
+        trimmed,
+    ))
+}
+
+fn parse_hex(hex: &str, original: &str) -> Result<Rgba, ColorParseError> {
+    if let Some(bad) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(ColorParseError::new(
+            format!("'{}' is not a hexadecimal digit", bad),
+            &bad.to_string(),
+        ));
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).unwrap_or(0) as f64;
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0) as f64;
+    match hex.len() {
+        3 | 4 => Ok(Rgba {
+            r: digit(0) * 17.0,
+            g: digit(1) * 17.0,
+            b: digit(2) * 17.0,
+            a: if hex.len() == 4 {
+                digit(3) * 17.0 / 255.0
+            } else {
+                1.0
+            },
+        }),
+        6 | 8 => Ok(Rgba {
+            r: pair(0),
+            g: pair(2),
+            b: pair(4),
+            a: if hex.len() == 8 { pair(6) / 255.0 } else { 1.0 },
+        }),
+        n => Err(ColorParseError::new(
+            format!("Hex colors have 3, 4, 6 or 8 digits, but found {}", n),
+            original,
+        )),
+    }
+}
+
+/// Split function arguments written either as `a, b, c, d` or `a b c / d`
+fn split_args(args: &str) -> Vec<&str> {
+    if args.contains(',') {
+        args.split(',').map(str::trim).collect()
+    } else {
+        args.split(|c: char| c.is_whitespace() || c == '/')
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+}
+
+fn parse_number(arg: &str, what: &str) -> Result<f64, ColorParseError> {
+    arg.parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| ColorParseError::new(format!("Expected a number for {}", what), arg))
+}
+
+/// A number, or a percentage of `full`
+fn parse_scaled(arg: &str, full: f64, what: &str) -> Result<f64, ColorParseError> {
+    match arg.strip_suffix('%') {
+        Some(percent) => Ok(parse_number(percent, what)? / 100.0 * full),
+        None => parse_number(arg, what),
+    }
+}
+
+fn parse_percent(arg: &str, what: &str) -> Result<f64, ColorParseError> {
+    let Some(percent) = arg.strip_suffix('%') else {
+        return Err(ColorParseError::new(
+            format!("Expected a percentage for {}", what),
+            arg,
+        ));
+    };
+    Ok(parse_number(percent, what)?.clamp(0.0, 100.0))
+}
+
+fn parse_alpha(args: &[&str], index: usize) -> Result<f64, ColorParseError> {
+    match args.get(index) {
+        Some(arg) => Ok(parse_scaled(arg, 1.0, "alpha")?.clamp(0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
+
+fn check_arg_count(args: &[&str], function: &str) -> Result<(), ColorParseError> {
+    if args.len() < 3 || args.len() > 4 {
+        return Err(ColorParseError::new(
+            format!(
+                "{}() takes 3 or 4 values, but found {}",
+                function,
+                args.len()
+            ),
+            &args.join(", "),
+        ));
+    }
+    Ok(())
+}
+
+fn parse_rgb_args(args: &str) -> Result<Rgba, ColorParseError> {
+    let args = split_args(args);
+    check_arg_count(&args, "rgb")?;
+    Ok(Rgba {
+        r: parse_scaled(args[0], 255.0, "red")?.clamp(0.0, 255.0),
+        g: parse_scaled(args[1], 255.0, "green")?.clamp(0.0, 255.0),
+        b: parse_scaled(args[2], 255.0, "blue")?.clamp(0.0, 255.0),
+        a: parse_alpha(&args, 3)?,
+    })
+}
+
+fn parse_hsl_args(args: &str) -> Result<Rgba, ColorParseError> {
+    let args = split_args(args);
+    check_arg_count(&args, "hsl")?;
+    let hue = parse_number(args[0].trim_end_matches("deg"), "hue")?;
+    let saturation = parse_percent(args[1], "saturation")?;
+    let lightness = parse_percent(args[2], "lightness")?;
+    Ok(Rgba::from_hsl(
+        hue,
+        saturation,
+        lightness,
+        parse_alpha(&args, 3)?,
+    ))
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+/// Parse a hex code, `rgb()`/`rgba()`, `hsl()`/`hsla()` or CSS color name and return it in
+/// every supported notation.
+pub fn convert_color(input: &str) -> ColorConvertResult {
+    let color = match parse_color(input) {
+        Ok(color) => color,
+        Err(e) => return ColorConvertResult::failure(e),
+    };
+
+    let (red, green, blue) = color.channels();
+    let (hue, saturation, lightness) = color.to_hsl();
+    let (_, hsv_saturation, hsv_value) = color.to_hsv();
+    let (cyan, magenta, yellow, key) = color.to_cmyk();
+    let packed = ((red as u32) << 16) | ((green as u32) << 8) | blue as u32;
+    let name = NAMED_COLORS
+        .iter()
+        .find(|(_, value)| *value == packed && color.a >= 1.0)
+        .map(|(name, _)| name.to_string());
+
+    ColorConvertResult {
+        success: true,
+        hex: color.hex(),
+        rgb: color.rgb(),
+        hsl: color.hsl(),
+        hsv: format!(
+            "hsv({}, {}%, {}%)",
+            hue.round() % 360.0,
+            hsv_saturation.round(),
+            hsv_value.round()
+        ),
+        cmyk: format!(
+            "cmyk({}%, {}%, {}%, {}%)",
+            cyan.round(),
+            magenta.round(),
+            yellow.round(),
+            key.round()
+        ),
+        name,
+        values: Some(ColorValues {
+            red,
+            green,
+            blue,
+            alpha: color.a,
+            hue: round1(hue),
+            saturation: round1(saturation),
+            lightness: round1(lightness),
+            hsv_saturation: round1(hsv_saturation),
+            hsv_value: round1(hsv_value),
+            cyan: round1(cyan),
+            magenta: round1(magenta),
+            yellow: round1(yellow),
+            key: round1(key),
+        }),
+        error: None,
+        invalid_part: None,
+    }
+}
+
+/// Generate a palette around `base`. Hue rotations keep saturation, lightness and alpha.
+pub fn generate_palette(base: &str, kind: PaletteKind) -> PaletteResult {
+    let color = match parse_color(base) {
+        Ok(color) => color,
+        Err(e) => {
+            return PaletteResult {
+                success: false,
+                colors: Vec::new(),
+                error: Some(e.message),
+                invalid_part: Some(e.fragment),
+            }
+        }
+    };
+
+    let (h, s, l) = color.to_hsl();
+    let rotate = |offsets: &[f64]| -> Vec<Rgba> {
+        offsets
+            .iter()
+            .map(|offset| Rgba::from_hsl(h + offset, s, l, color.a))
+            .collect()
+    };
+    let colors = match kind {
+        PaletteKind::Complementary => rotate(&[0.0, 180.0]),
+        PaletteKind::Analogous => rotate(&[-30.0, 0.0, 30.0]),
+        PaletteKind::Triadic => rotate(&[0.0, 120.0, 240.0]),
+        PaletteKind::Shades => (0..10)
+            .map(|i| Rgba::from_hsl(h, s, 95.0 - i as f64 * 10.0, color.a))
+            .collect(),
+    };
+
+    PaletteResult {
+        success: true,
+        colors: colors
+            .iter()
+            .map(|c| PaletteColor {
+                hex: c.hex(),
+                rgb: c.rgb(),
+                hsl: c.hsl(),
+            })
+            .collect(),
+        error: None,
+        invalid_part: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_hex() {
+        let result = convert_color("#ff8800");
+        assert!(result.success);
+        assert_eq!(result.hex, "#ff8800");
+        assert_eq!(result.rgb, "rgb(255, 136, 0)");
+        assert_eq!(result.hsl, "hsl(32, 100%, 50%)");
+        assert_eq!(result.hsv, "hsv(32, 100%, 100%)");
+        assert_eq!(result.cmyk, "cmyk(0%, 47%, 100%, 0%)");
+
+        assert_eq!(convert_color("#F80").hex, "#ff8800");
+        assert_eq!(convert_color("ff8800").hex, "#ff8800");
+    }
+
+    #[test]
+    fn test_all_input_formats_agree() {
+        for input in [
+            "#ff6347",
+            "rgb(255, 99, 71)",
+            "rgb(255 99 71)",
+            "RGB(100%, 38.8%, 27.84%)",
+            "hsl(9, 100%, 64%)",
+            "tomato",
+            "Tomato",
+        ] {
+            let result = convert_color(input);
+            assert!(result.success, "{}: {:?}", input, result.error);
+            assert_eq!(result.hex, "#ff6347", "{}", input);
+            assert_eq!(result.name.as_deref(), Some("tomato"), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_alpha() {
+        let result = convert_color("#ff880080");
+        assert_eq!(result.hex, "#ff880080");
+        assert_eq!(result.rgb, "rgba(255, 136, 0, 0.502)");
+        assert!(result.hsl.starts_with("hsla(32, 100%, 50%"));
+        assert!(result.name.is_none());
+
+        let result = convert_color("rgba(0, 0, 255, 0.5)");
+        assert_eq!(result.hex, "#0000ff80");
+        assert_eq!(result.values.unwrap().alpha, 0.5);
+
+        assert_eq!(
+            convert_color("rgb(0 0 255 / 25%)").rgb,
+            "rgba(0, 0, 255, 0.25)"
+        );
+        assert_eq!(convert_color("hsla(240, 100%, 50%, 0.5)").hex, "#0000ff80");
+        assert_eq!(convert_color("transparent").hex, "#00000000");
+    }
+
+    #[test]
+    fn test_grays_and_black() {
+        let black = convert_color("black");
+        assert_eq!(black.hsl, "hsl(0, 0%, 0%)");
+        assert_eq!(black.cmyk, "cmyk(0%, 0%, 0%, 100%)");
+
+        let gray = convert_color("#808080");
+        assert_eq!(gray.name.as_deref(), Some("gray"));
+        assert_eq!(gray.hsv, "hsv(0, 0%, 50%)");
+    }
+
+    #[test]
+    fn test_invalid_input_reports_the_part() {
+        let result = convert_color("#ff88zz");
+        assert!(!result.success);
+        assert_eq!(result.invalid_part.as_deref(), Some("z"));
+
+        let result = convert_color("rgb(255, abc, 0)");
+        assert!(result.error.unwrap().contains("green"));
+        assert_eq!(result.invalid_part.as_deref(), Some("abc"));
+
+        let result = convert_color("hsl(32, 100, 50%)");
+        assert!(result.error.unwrap().contains("saturation"));
+        assert_eq!(result.invalid_part.as_deref(), Some("100"));
+
+        let result = convert_color("rgb(1, 2)");
+        assert!(result.error.unwrap().contains("3 or 4"));
+
+        let result = convert_color("lab(50 20 30)");
+        assert_eq!(result.invalid_part.as_deref(), Some("lab"));
+
+        let result = convert_color("notacolor");
+        assert_eq!(result.invalid_part.as_deref(), Some("notacolor"));
+
+        assert!(!convert_color("rgb(1, 2, 3").success);
+        assert!(!convert_color("#12345").success);
+    }
+
+    #[test]
+    fn test_generate_palette() {
+        let complementary = generate_palette("#ff0000", PaletteKind::Complementary);
+        let hexes: Vec<&str> = complementary
+            .colors
+            .iter()
+            .map(|c| c.hex.as_str())
+            .collect();
+        assert_eq!(hexes, vec!["#ff0000", "#00ffff"]);
+
+        let triadic = generate_palette("red", PaletteKind::Triadic);
+        let hexes: Vec<&str> = triadic.colors.iter().map(|c| c.hex.as_str()).collect();
+        assert_eq!(hexes, vec!["#ff0000", "#00ff00", "#0000ff"]);
+
+        let analogous = generate_palette("hsl(120, 100%, 50%)", PaletteKind::Analogous);
+        assert_eq!(analogous.colors[0].hsl, "hsl(90, 100%, 50%)");
+        assert_eq!(analogous.colors[2].hsl, "hsl(150, 100%, 50%)");
+
+        let shades = generate_palette("#3366cc", PaletteKind::Shades);
+        assert_eq!(shades.colors.len(), 10);
+        assert!(shades.colors[0].hsl.ends_with("95%)"));
+        assert!(shades.colors[9].hsl.ends_with("5%)"));
+
+        let invalid = generate_palette("rgb(x, 0, 0)", PaletteKind::Shades);
+        assert!(!invalid.success);
+        assert_eq!(invalid.invalid_part.as_deref(), Some("x"));
+    }
+}
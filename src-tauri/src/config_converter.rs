@@ -0,0 +1,319 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigConvertResult {
+    pub success: bool,
+    pub output: String,
+    pub error: Option<String>,
+}
+
+/// Convert a configuration document between JSON, YAML and TOML.
+///
+/// Everything goes through a `serde_json::Value`, so key order is kept, YAML anchors, aliases
+/// and merge keys are expanded, and TOML datetimes become strings.
+pub fn convert_config(input: &str, from: ConfigFormat, to: ConfigFormat) -> ConfigConvertResult {
+    match parse_config(input, from).and_then(|value| write_config(&value, to)) {
+        Ok(output) => ConfigConvertResult {
+            success: true,
+            output,
+            error: None,
+        },
+        Err(e) => ConfigConvertResult {
+            success: false,
+            output: String::new(),
+            error: Some(e),
+        },
+    }
+}
+
+pub(crate) fn parse_config(input: &str, format: ConfigFormat) -> Result<Value, String> {
+    match format {
+        ConfigFormat::Json => {
+            serde_json::from_str(input).map_err(|e| format!("Failed to parse JSON: {}", e))
+        }
+        ConfigFormat::Yaml => {
+            let mut value: serde_yaml::Value =
+                serde_yaml::from_str(input).map_err(|e| format!("Failed to parse YAML: {}", e))?;
+            value
+                .apply_merge()
+                .map_err(|e| format!("Failed to expand YAML merge keys: {}", e))?;
+            yaml_to_json(value, "")
+        }
+        ConfigFormat::Toml => {
+            let table: toml::Table =
+                toml::from_str(input).map_err(|e| format!("Failed to parse TOML: {}", e))?;
+            toml_to_json(toml::Value::Table(table), "")
+        }
+    }
+}
+
+fn write_config(value: &Value, format: ConfigFormat) -> Result<String, String> {
+    match format {
+        ConfigFormat::Json => serde_json::to_string_pretty(value)
+            .map_err(|e| format!("Failed to serialize JSON: {}", e)),
+        ConfigFormat::Yaml => {
+            serde_yaml::to_string(value).map_err(|e| format!("Failed to serialize YAML: {}", e))
+        }
+        ConfigFormat::Toml => {
+            let toml::Value::Table(table) = json_to_toml(value, "")? else {
+                return Err(format!(
+                    "TOML documents must be a table at the top level, but the input is {}",
+                    describe(value)
+                ));
+            };
+            toml::to_string(&table).map_err(|e| format!("Failed to serialize TOML: {}", e))
+        }
+    }
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "(root)"
+    } else {
+        path
+    }
+}
+
+fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn float_to_json(f: f64, path: &str) -> Result<Value, String> {
+    Number::from_f64(f).map(Value::Number).ok_or_else(|| {
+        format!(
+            "JSON cannot represent the non-finite number {} at {}",
+            f,
+            display_path(path)
+        )
+    })
+}
+
+fn yaml_to_json(value: serde_yaml::Value, path: &str) -> Result<Value, String> {
+    use serde_yaml::Value as Yaml;
+
+    Ok(match value {
+        Yaml::Null => Value::Null,
+        Yaml::Bool(b) => Value::Bool(b),
+        Yaml::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::from(i)
+            } else if let Some(u) = n.as_u64() {
+                Value::from(u)
+            } else {
+                float_to_json(n.as_f64().unwrap_or(f64::NAN), path)?
+            }
+        }
+        Yaml::String(s) => Value::String(s),
+        Yaml::Sequence(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| yaml_to_json(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<_, _>>()?,
+        ),
+        Yaml::Mapping(mapping) => {
+            let mut object = Map::new();
+            for (key, item) in mapping {
+                let key = match key {
+                    Yaml::String(s) => s,
+                    Yaml::Number(n) => n.to_string(),
+                    Yaml::Bool(b) => b.to_string(),
+                    Yaml::Null => "null".to_string(),
+                    _ => {
+                        return Err(format!(
+                            "Only scalar mapping keys can be converted, found a complex key at {}",
+                            display_path(path)
+                        ))
+                    }
+                };
+                let item = yaml_to_json(item, &child_path(path, &key))?;
+                object.insert(key, item);
+            }
+            Value::Object(object)
+        }
+        // Custom tags such as `!Ref` have no equivalent elsewhere; keep the tagged value
+        Yaml::Tagged(tagged) => yaml_to_json(tagged.value, path)?,
+    })
+}
+
+fn toml_to_json(value: toml::Value, path: &str) -> Result<Value, String> {
+    Ok(match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => float_to_json(f, path)?,
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(dt) => Value::String(dt.to_string()),
+        toml::Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| toml_to_json(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<_, _>>()?,
+        ),
+        toml::Value::Table(table) => {
+            let mut object = Map::new();
+            for (key, item) in table {
+                let item = toml_to_json(item, &child_path(path, &key))?;
+                object.insert(key, item);
+            }
+            Value::Object(object)
+        }
+    })
+}
+
+fn json_to_toml(value: &Value, path: &str) -> Result<toml::Value, String> {
+    Ok(match value {
+        Value::Null => {
+            return Err(format!(
+                "TOML has no null value; remove or replace the null at {}",
+                display_path(path)
+            ))
+        }
+        Value::Bool(b) => toml::Value::Boolean(*b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                toml::Value::Integer(i)
+            } else if n.is_u64() {
+                return Err(format!(
+                    "TOML integers are 64-bit signed; {} at {} is too large",
+                    n,
+                    display_path(path)
+                ));
+            } else {
+                toml::Value::Float(n.as_f64().unwrap_or_default())
+            }
+        }
+        Value::String(s) => toml::Value::String(s.clone()),
+        Value::Array(items) => toml::Value::Array(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| json_to_toml(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(object) => {
+            let mut table = toml::Table::new();
+            for (key, item) in object {
+                table.insert(key.clone(), json_to_toml(item, &child_path(path, key))?);
+            }
+            toml::Value::Table(table)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON_INPUT: &str = r#"{"name": "app", "version": 2, "enabled": true, "ports": [80, 443], "db": {"host": "localhost", "ratio": 0.5}}"#;
+
+    fn convert(input: &str, from: ConfigFormat, to: ConfigFormat) -> String {
+        let result = convert_config(input, from, to);
+        assert!(result.success, "{:?}", result.error);
+        result.output
+    }
+
+    #[test]
+    fn test_round_trip_between_all_formats() {
+        let expected: Value = serde_json::from_str(JSON_INPUT).unwrap();
+        let formats = [ConfigFormat::Json, ConfigFormat::Yaml, ConfigFormat::Toml];
+
+        for from in formats {
+            let source = convert(JSON_INPUT, ConfigFormat::Json, from);
+            for to in formats {
+                let converted = convert(&source, from, to);
+                let back = convert(&converted, to, ConfigFormat::Json);
+                let value: Value = serde_json::from_str(&back).unwrap();
+                assert_eq!(value, expected, "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_order_is_preserved() {
+        let input = "zeta = 1\nalpha = 2\n\n[middle]\ny = 1\nb = 2\n";
+        let yaml = convert(input, ConfigFormat::Toml, ConfigFormat::Yaml);
+        assert_eq!(yaml, "zeta: 1\nalpha: 2\nmiddle:\n  y: 1\n  b: 2\n");
+
+        let toml = convert(&yaml, ConfigFormat::Yaml, ConfigFormat::Toml);
+        assert_eq!(toml, input);
+    }
+
+    #[test]
+    fn test_yaml_anchors_and_merge_keys_are_expanded() {
+        let input = "base: &base\n  retries: 3\n  timeout: 10\nservice:\n  <<: *base\n  timeout: 30\ncopy: *base\n";
+        let json = convert(input, ConfigFormat::Yaml, ConfigFormat::Json);
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["service"]["retries"], 3);
+        assert_eq!(value["service"]["timeout"], 30);
+        assert_eq!(value["copy"], value["base"]);
+    }
+
+    #[test]
+    fn test_toml_datetimes_become_strings() {
+        let json = convert(
+            "released = 2024-01-02T03:04:05Z\n",
+            ConfigFormat::Toml,
+            ConfigFormat::Json,
+        );
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["released"], "2024-01-02T03:04:05Z");
+    }
+
+    #[test]
+    fn test_unconvertible_toml_reports_reason() {
+        let top_level_array = convert_config("[1, 2]", ConfigFormat::Json, ConfigFormat::Toml);
+        assert!(!top_level_array.success);
+        assert!(top_level_array.error.unwrap().contains("an array"));
+
+        let null = convert_config(
+            r#"{"db": {"password": null}}"#,
+            ConfigFormat::Json,
+            ConfigFormat::Toml,
+        );
+        assert!(null.error.unwrap().contains("db.password"));
+
+        let huge = convert_config(
+            r#"{"ids": [18446744073709551615]}"#,
+            ConfigFormat::Json,
+            ConfigFormat::Toml,
+        );
+        assert!(huge.error.unwrap().contains("ids[0]"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = convert_config("a: [1", ConfigFormat::Yaml, ConfigFormat::Json);
+        assert!(!result.success);
+        assert!(result.error.unwrap().starts_with("Failed to parse YAML"));
+
+        let result = convert_config("a = ", ConfigFormat::Toml, ConfigFormat::Json);
+        assert!(result.error.unwrap().starts_with("Failed to parse TOML"));
+    }
+}
@@ -0,0 +1,1034 @@
+use chrono::{
+    DateTime, Datelike, Local, LocalResult, NaiveDate, NaiveDateTime, TimeDelta, TimeZone,
+    Timelike, Utc,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use crate::unix_time_converter::{parse_timezone, TimezoneOption};
+
+const NEXT_RUN_COUNT: usize = 10;
+/// Give up looking for run times this far ahead (e.g. `0 0 30 2 *` never runs)
+const MAX_SEARCH_YEARS: i32 = 30;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const MONTHS_EN: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const DAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+const DAYS_EN: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const DAYS_JA: [&str; 7] = [
+    "日曜日",
+    "月曜日",
+    "火曜日",
+    "水曜日",
+    "木曜日",
+    "金曜日",
+    "土曜日",
+];
+
+const ALIASES: [(&str, &str); 7] = [
+    ("@yearly", "0 0 1 1 *"),
+    ("@annually", "0 0 1 1 *"),
+    ("@monthly", "0 0 1 * *"),
+    ("@weekly", "0 0 * * 0"),
+    ("@daily", "0 0 * * *"),
+    ("@midnight", "0 0 * * *"),
+    ("@hourly", "0 * * * *"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FieldKind {
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+
+impl FieldKind {
+    fn key(self) -> &'static str {
+        match self {
+            FieldKind::Second => "second",
+            FieldKind::Minute => "minute",
+            FieldKind::Hour => "hour",
+            FieldKind::DayOfMonth => "day_of_month",
+            FieldKind::Month => "month",
+            FieldKind::DayOfWeek => "day_of_week",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            FieldKind::Second => "Second",
+            FieldKind::Minute => "Minute",
+            FieldKind::Hour => "Hour",
+            FieldKind::DayOfMonth => "Day of month",
+            FieldKind::Month => "Month",
+            FieldKind::DayOfWeek => "Day of week",
+        }
+    }
+
+    /// Allowed range; day of week accepts 7 as an alias for Sunday
+    fn range(self) -> (u32, u32) {
+        match self {
+            FieldKind::Second | FieldKind::Minute => (0, 59),
+            FieldKind::Hour => (0, 23),
+            FieldKind::DayOfMonth => (1, 31),
+            FieldKind::Month => (1, 12),
+            FieldKind::DayOfWeek => (0, 7),
+        }
+    }
+
+    fn names(self) -> &'static [&'static str] {
+        match self {
+            FieldKind::Month => &MONTH_NAMES,
+            FieldKind::DayOfWeek => &DAY_NAMES,
+            _ => &[],
+        }
+    }
+
+    /// (singular, plural) unit names used in English descriptions
+    fn unit_en(self) -> (&'static str, &'static str) {
+        match self {
+            FieldKind::Second => ("second", "seconds"),
+            FieldKind::Minute => ("minute", "minutes"),
+            FieldKind::Hour => ("hour", "hours"),
+            FieldKind::DayOfMonth => ("day", "days"),
+            FieldKind::Month => ("month", "months"),
+            FieldKind::DayOfWeek => ("day of the week", "days of the week"),
+        }
+    }
+
+    fn every(self) -> (&'static str, &'static str) {
+        match self {
+            FieldKind::Second => ("every second", "毎秒"),
+            FieldKind::Minute => ("every minute", "毎分"),
+            FieldKind::Hour => ("every hour", "毎時"),
+            FieldKind::DayOfMonth => ("every day", "毎日"),
+            FieldKind::Month => ("every month", "毎月"),
+            FieldKind::DayOfWeek => ("every day of the week", "全曜日"),
+        }
+    }
+
+    /// Preposition in front of single values and ranges in English
+    fn preposition(self) -> &'static str {
+        match self {
+            FieldKind::Month => "in",
+            FieldKind::DayOfMonth | FieldKind::DayOfWeek => "on",
+            _ => "at",
+        }
+    }
+
+    /// Japanese (value suffix, interval unit)
+    fn unit_ja(self) -> (&'static str, &'static str) {
+        match self {
+            FieldKind::Second => ("秒", "秒"),
+            FieldKind::Minute => ("分", "分"),
+            FieldKind::Hour => ("時", "時間"),
+            FieldKind::DayOfMonth => ("日", "日"),
+            FieldKind::Month => ("月", "ヶ月"),
+            FieldKind::DayOfWeek => ("", "日"),
+        }
+    }
+
+    fn is_named(self) -> bool {
+        matches!(self, FieldKind::Month | FieldKind::DayOfWeek)
+    }
+
+    fn value_en(self, value: u32) -> String {
+        match self {
+            FieldKind::Month => MONTHS_EN[(value - 1) as usize].to_string(),
+            FieldKind::DayOfWeek => DAYS_EN[(value % 7) as usize].to_string(),
+            _ => value.to_string(),
+        }
+    }
+
+    fn value_ja(self, value: u32) -> String {
+        match self {
+            FieldKind::DayOfWeek => DAYS_JA[(value % 7) as usize].to_string(),
+            _ => format!("{}{}", value, self.unit_ja().0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PartRange {
+    All,
+    Single(u32),
+    Between(u32, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Part {
+    range: PartRange,
+    step: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    kind: FieldKind,
+    text: String,
+    parts: Vec<Part>,
+    /// Bit n is set when value n matches
+    mask: u64,
+}
+
+impl Field {
+    fn contains(&self, value: u32) -> bool {
+        self.mask & (1 << value) != 0
+    }
+
+    /// Whether the field narrows the schedule; cron treats `*`, `*/n` and `?` as unrestricted
+    /// when combining day of month with day of week
+    fn is_restricted(&self) -> bool {
+        !self.text.starts_with('*') && self.text != "?"
+    }
+
+    fn single_value(&self) -> Option<u32> {
+        match self.parts.as_slice() {
+            [Part {
+                range: PartRange::Single(v),
+                step: None,
+            }] => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn describe_en(&self) -> String {
+        let kind = self.kind;
+        let (unit, plural) = kind.unit_en();
+        let singles: Option<Vec<u32>> = self
+            .parts
+            .iter()
+            .map(|p| match (p.range, p.step) {
+                (PartRange::Single(v), None) => Some(v),
+                _ => None,
+            })
+            .collect();
+        if let Some(values) = singles {
+            let list = values
+                .iter()
+                .map(|v| kind.value_en(*v))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return match (kind.is_named(), values.len()) {
+                (true, _) => format!("{} {}", kind.preposition(), list),
+                (false, 1) => format!("{} {} {}", kind.preposition(), unit, list),
+                (false, _) => format!("{} {} {}", kind.preposition(), plural, list),
+            };
+        }
+
+        let range_en = |a: u32, b: u32| {
+            let prefix = match kind.preposition() {
+                "at" => String::new(),
+                p => format!("{} ", p),
+            };
+            if kind.is_named() {
+                format!(
+                    "{}{} through {}",
+                    prefix,
+                    kind.value_en(a),
+                    kind.value_en(b)
+                )
+            } else {
+                format!("{}{} {} through {}", prefix, plural, a, b)
+            }
+        };
+        self.parts
+            .iter()
+            .map(|part| match (part.range, part.step) {
+                (PartRange::All, None) => kind.every().0.to_string(),
+                (PartRange::All, Some(n)) => format!("every {} {}", n, plural),
+                (PartRange::Single(v), None) if kind.is_named() => kind.value_en(v),
+                (PartRange::Single(v), None) => format!("{} {}", unit, v),
+                (PartRange::Single(v), Some(n)) => format!(
+                    "every {} {} starting at {}",
+                    n,
+                    plural,
+                    if kind.is_named() {
+                        kind.value_en(v)
+                    } else {
+                        format!("{} {}", unit, v)
+                    }
+                ),
+                (PartRange::Between(a, b), None) => range_en(a, b),
+                (PartRange::Between(a, b), Some(n)) => {
+                    format!("every {} {}, {}", n, plural, range_en(a, b))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn describe_ja(&self) -> String {
+        let kind = self.kind;
+        let (suffix, interval) = kind.unit_ja();
+        let singles: Option<Vec<u32>> = self
+            .parts
+            .iter()
+            .map(|p| match (p.range, p.step) {
+                (PartRange::Single(v), None) => Some(v),
+                _ => None,
+            })
+            .collect();
+        if let Some(values) = singles {
+            if kind.is_named() {
+                return values
+                    .iter()
+                    .map(|v| kind.value_ja(*v))
+                    .collect::<Vec<_>>()
+                    .join("、");
+            }
+            let list = values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join("、");
+            return format!("{}{}", list, suffix);
+        }
+
+        let range_ja = |a: u32, b: u32| {
+            if kind.is_named() {
+                format!("{}〜{}", kind.value_ja(a), kind.value_ja(b))
+            } else {
+                format!("{}〜{}{}", a, b, suffix)
+            }
+        };
+        self.parts
+            .iter()
+            .map(|part| match (part.range, part.step) {
+                (PartRange::All, None) => kind.every().1.to_string(),
+                (PartRange::All, Some(n)) => format!("{}{}ごと", n, interval),
+                (PartRange::Single(v), None) => kind.value_ja(v),
+                (PartRange::Single(v), Some(n)) => {
+                    format!("{}から{}{}ごと", kind.value_ja(v), n, interval)
+                }
+                (PartRange::Between(a, b), None) => range_ja(a, b),
+                (PartRange::Between(a, b), Some(n)) => {
+                    format!("{}の間で{}{}ごと", range_ja(a, b), n, interval)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("、")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Schedule {
+    second: Option<Field>,
+    minute: Field,
+    hour: Field,
+    day_of_month: Field,
+    month: Field,
+    day_of_week: Field,
+}
+
+impl Schedule {
+    fn fields(&self) -> Vec<&Field> {
+        let mut fields: Vec<&Field> = self.second.iter().collect();
+        fields.extend([
+            &self.minute,
+            &self.hour,
+            &self.day_of_month,
+            &self.month,
+            &self.day_of_week,
+        ]);
+        fields
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let dom = self.day_of_month.contains(date.day());
+        let dow = self
+            .day_of_week
+            .contains(date.weekday().num_days_from_sunday());
+        // Vixie cron: when both day fields are restricted, either one may match
+        if self.day_of_month.is_restricted() && self.day_of_week.is_restricted() {
+            dom || dow
+        } else {
+            dom && dow
+        }
+    }
+
+    fn matches_second(&self, second: u32) -> bool {
+        match &self.second {
+            Some(field) => field.contains(second),
+            None => second == 0,
+        }
+    }
+
+    /// Wall-clock times after `start` (exclusive) that match, in ascending order
+    fn next_local_times(&self, start: NaiveDateTime, count: usize) -> Vec<NaiveDateTime> {
+        let mut results = Vec::with_capacity(count);
+        let Some(mut t) = start.with_nanosecond(0).map(|t| t + TimeDelta::seconds(1)) else {
+            return results;
+        };
+        let horizon = start.year() + MAX_SEARCH_YEARS;
+
+        while results.len() < count && t.year() <= horizon {
+            let date = t.date();
+            if !self.month.contains(date.month()) {
+                let (year, month) = if date.month() == 12 {
+                    (date.year() + 1, 1)
+                } else {
+                    (date.year(), date.month() + 1)
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+                    .unwrap_or(t + TimeDelta::days(1));
+            } else if !self.matches_day(date) {
+                t = (date + TimeDelta::days(1))
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap_or(t + TimeDelta::days(1));
+            } else if !self.hour.contains(t.hour()) {
+                t = date.and_hms_opt(t.hour(), 0, 0).unwrap_or(t) + TimeDelta::hours(1);
+            } else if !self.minute.contains(t.minute()) {
+                t = date.and_hms_opt(t.hour(), t.minute(), 0).unwrap_or(t) + TimeDelta::minutes(1);
+            } else if !self.matches_second(t.second()) {
+                t += TimeDelta::seconds(1);
+            } else {
+                results.push(t);
+                t += TimeDelta::seconds(1);
+            }
+        }
+        results
+    }
+}
+
+#[derive(Debug)]
+struct FieldError {
+    kind: FieldKind,
+    message: String,
+}
+
+fn parse_value(kind: FieldKind, text: &str) -> Result<u32, FieldError> {
+    let (min, max) = kind.range();
+    let error = |message: String| FieldError { kind, message };
+    let upper = text.to_ascii_uppercase();
+    if let Some(index) = kind.names().iter().position(|name| *name == upper) {
+        // Month names start at 1, day names at 0 (Sunday)
+        return Ok(index as u32 + if kind == FieldKind::Month { 1 } else { 0 });
+    }
+    let value: u32 = text.parse().map_err(|_| {
+        error(if kind.names().is_empty() {
+            format!("'{}' is not a number ({}-{})", text, min, max)
+        } else {
+            format!(
+                "'{}' is not a number ({}-{}) or name ({})",
+                text,
+                min,
+                max,
+                kind.names().join(", ")
+            )
+        })
+    })?;
+    if value < min || value > max {
+        return Err(error(format!(
+            "{} is out of range ({}-{})",
+            value, min, max
+        )));
+    }
+    Ok(value)
+}
+
+fn parse_part(kind: FieldKind, text: &str) -> Result<Part, FieldError> {
+    let error = |message: String| FieldError { kind, message };
+    if text.is_empty() {
+        return Err(error("Empty list item".to_string()));
+    }
+    if text.contains('#') || (!kind.is_named() && text.contains(['L', 'W'])) {
+        return Err(error(format!(
+            "'{}' uses L, W or #, which are not supported",
+            text
+        )));
+    }
+
+    let (range_text, step) = match text.split_once('/') {
+        Some((range, step)) => {
+            let step: u32 = step
+                .parse()
+                .map_err(|_| error(format!("Invalid step '{}'", step)))?;
+            if step == 0 {
+                return Err(error("Step must be greater than 0".to_string()));
+            }
+            (range, (step > 1).then_some(step))
+        }
+        None => (text, None),
+    };
+
+    let range = match range_text {
+        "*" | "?" => PartRange::All,
+        _ => match range_text.split_once('-') {
+            Some((a, b)) => {
+                let (a, b) = (parse_value(kind, a)?, parse_value(kind, b)?);
+                if a > b {
+                    return Err(error(format!(
+                        "Range start {} is greater than end {}",
+                        a, b
+                    )));
+                }
+                PartRange::Between(a, b)
+            }
+            None => PartRange::Single(parse_value(kind, range_text)?),
+        },
+    };
+    Ok(Part { range, step })
+}
+
+fn parse_field(kind: FieldKind, text: &str) -> Result<Field, FieldError> {
+    let (min, max) = kind.range();
+    let parts = text
+        .split(',')
+        .map(|part| parse_part(kind, part.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut mask = 0u64;
+    for part in &parts {
+        let (start, end) = match part.range {
+            PartRange::All => (min, max),
+            PartRange::Single(v) if part.step.is_some() => (v, max),
+            PartRange::Single(v) => (v, v),
+            PartRange::Between(a, b) => (a, b),
+        };
+        let step = part.step.unwrap_or(1) as usize;
+        for value in (start..=end).step_by(step) {
+            mask |= 1 << value;
+        }
+    }
+    if kind == FieldKind::DayOfWeek && mask & (1 << 7) != 0 {
+        mask = (mask & !(1 << 7)) | 1;
+    }
+
+    Ok(Field {
+        kind,
+        text: text.to_string(),
+        parts,
+        mask,
+    })
+}
+
+fn parse_schedule(expression: &str) -> Result<Schedule, FieldError> {
+    let tokens: Vec<&str> = expression.split_whitespace().collect();
+    let (second, rest) = match tokens.len() {
+        5 => (None, &tokens[..]),
+        6 => (
+            Some(parse_field(FieldKind::Second, tokens[0])?),
+            &tokens[1..],
+        ),
+        n => {
+            return Err(FieldError {
+                kind: FieldKind::Minute,
+                message: format!("Expected 5 or 6 fields, but found {}", n),
+            })
+        }
+    };
+    Ok(Schedule {
+        second,
+        minute: parse_field(FieldKind::Minute, rest[0])?,
+        hour: parse_field(FieldKind::Hour, rest[1])?,
+        day_of_month: parse_field(FieldKind::DayOfMonth, rest[2])?,
+        month: parse_field(FieldKind::Month, rest[3])?,
+        day_of_week: parse_field(FieldKind::DayOfWeek, rest[4])?,
+    })
+}
+
+fn describe_schedule(schedule: &Schedule) -> (String, String) {
+    let has_seconds = schedule.second.is_some();
+    let second = schedule.second.as_ref();
+    let fixed_time = (
+        second.map_or(Some(0), Field::single_value),
+        schedule.minute.single_value(),
+        schedule.hour.single_value(),
+    );
+
+    let (time_en, time_ja) = match fixed_time {
+        (Some(s), Some(m), Some(h)) => {
+            if has_seconds {
+                (
+                    format!("At {:02}:{:02}:{:02}", h, m, s),
+                    format!("{}時{}分{}秒", h, m, s),
+                )
+            } else {
+                (format!("At {:02}:{:02}", h, m), format!("{}時{}分", h, m))
+            }
+        }
+        _ => {
+            let mut fields: Vec<&Field> = second.into_iter().collect();
+            fields.push(&schedule.minute);
+            if schedule.hour.is_restricted() {
+                fields.push(&schedule.hour);
+            }
+            let en = fields
+                .iter()
+                .map(|f| f.describe_en())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let mut ja = fields
+                .iter()
+                .rev()
+                .map(|f| f.describe_ja())
+                .collect::<Vec<_>>()
+                .join("、");
+            if !schedule.hour.is_restricted() && schedule.minute.single_value().is_some() {
+                ja = format!("毎時{}", ja);
+            }
+            let mut chars = en.chars();
+            let en = match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => en,
+            };
+            (en, ja)
+        }
+    };
+
+    let dom = schedule.day_of_month.is_restricted();
+    let dow = schedule.day_of_week.is_restricted();
+    let (day_en, day_ja) = match (dom, dow) {
+        (true, true) => (
+            Some(format!(
+                "{} or {}",
+                schedule.day_of_month.describe_en(),
+                schedule.day_of_week.describe_en()
+            )),
+            Some(format!(
+                "{}または{}",
+                schedule.day_of_month.describe_ja(),
+                schedule.day_of_week.describe_ja()
+            )),
+        ),
+        (true, false) => (
+            Some(schedule.day_of_month.describe_en()),
+            Some(schedule.day_of_month.describe_ja()),
+        ),
+        (false, true) => (
+            Some(schedule.day_of_week.describe_en()),
+            Some(schedule.day_of_week.describe_ja()),
+        ),
+        (false, false) => (None, None),
+    };
+    let (month_en, month_ja) = if schedule.month.is_restricted() {
+        (
+            Some(schedule.month.describe_en()),
+            Some(schedule.month.describe_ja()),
+        )
+    } else {
+        (None, None)
+    };
+
+    let en = std::iter::once(time_en)
+        .chain(day_en)
+        .chain(month_en)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let prefix_ja: Vec<String> = month_ja.into_iter().chain(day_ja).collect();
+    let ja = if prefix_ja.is_empty() {
+        let every_day = if fixed_time.1.is_some() && fixed_time.2.is_some() {
+            "毎日"
+        } else {
+            ""
+        };
+        format!("{}{}に実行", every_day, time_ja)
+    } else {
+        format!("{}の{}に実行", prefix_ja.join("、"), time_ja)
+    };
+    (en, ja)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CronFieldDescription {
+    /// `second`, `minute`, `hour`, `day_of_month`, `month` or `day_of_week`
+    pub field: String,
+    pub value: String,
+    pub description_en: String,
+    pub description_ja: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CronNextRun {
+    pub datetime: String,
+    pub iso8601: String,
+    pub day_of_week: String,
+    pub unix_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CronParseResult {
+    pub success: bool,
+    /// The expression that was evaluated, with aliases such as `@daily` expanded
+    pub normalized: String,
+    pub has_seconds: bool,
+    pub fields: Vec<CronFieldDescription>,
+    pub description_en: String,
+    pub description_ja: String,
+    pub next_runs: Vec<CronNextRun>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+    /// Key of the field that failed to parse
+    pub invalid_field: Option<String>,
+}
+
+impl CronParseResult {
+    fn failure(error: String, invalid_field: Option<&str>) -> Self {
+        Self {
+            success: false,
+            normalized: String::new(),
+            has_seconds: false,
+            fields: Vec::new(),
+            description_en: String::new(),
+            description_ja: String::new(),
+            next_runs: Vec::new(),
+            warnings: Vec::new(),
+            error: Some(error),
+            invalid_field: invalid_field.map(str::to_string),
+        }
+    }
+}
+
+fn next_runs_in<Z: TimeZone>(schedule: &Schedule, tz: &Z, now: DateTime<Utc>) -> Vec<CronNextRun>
+where
+    Z::Offset: Display,
+{
+    let start = now.with_timezone(tz).naive_local();
+    let mut runs = Vec::with_capacity(NEXT_RUN_COUNT);
+    let mut from = start;
+    // Times skipped by a DST gap have no instant, so search again until enough are found
+    while runs.len() < NEXT_RUN_COUNT {
+        let candidates = schedule.next_local_times(from, NEXT_RUN_COUNT - runs.len());
+        let Some(last) = candidates.last().copied() else {
+            break;
+        };
+        for naive in candidates {
+            let dt = match tz.from_local_datetime(&naive) {
+                LocalResult::Single(dt) => dt,
+                LocalResult::Ambiguous(earliest, _) => earliest,
+                LocalResult::None => continue,
+            };
+            runs.push(CronNextRun {
+                datetime: dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+                iso8601: dt.to_rfc3339(),
+                day_of_week: dt.format("%a").to_string(),
+                unix_seconds: dt.timestamp(),
+            });
+        }
+        from = last;
+    }
+    runs
+}
+
+/// Parse a 5-field (or 6-field, with seconds) cron expression, describe it and list the next
+/// run times in `timezone`.
+pub fn parse_cron(expression: &str, timezone: TimezoneOption) -> CronParseResult {
+    parse_cron_at(expression, timezone, Utc::now())
+}
+
+fn parse_cron_at(
+    expression: &str,
+    timezone: TimezoneOption,
+    now: DateTime<Utc>,
+) -> CronParseResult {
+    let trimmed = expression.trim();
+    if trimmed.is_empty() {
+        return CronParseResult::failure("Expression is empty".to_string(), None);
+    }
+
+    let normalized = if trimmed.starts_with('@') {
+        let alias = trimmed.to_ascii_lowercase();
+        if alias == "@reboot" {
+            return CronParseResult::failure(
+                "@reboot runs at startup and has no schedule".to_string(),
+                None,
+            );
+        }
+        match ALIASES.iter().find(|(name, _)| *name == alias) {
+            Some((_, expanded)) => expanded.to_string(),
+            None => return CronParseResult::failure(format!("Unknown alias '{}'", trimmed), None),
+        }
+    } else {
+        trimmed.split_whitespace().collect::<Vec<_>>().join(" ")
+    };
+
+    let schedule = match parse_schedule(&normalized) {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            return CronParseResult::failure(
+                format!("{}: {}", e.kind.label(), e.message),
+                Some(e.kind.key()),
+            )
+        }
+    };
+
+    let next_runs = match &timezone {
+        TimezoneOption::Local => next_runs_in(&schedule, &Local, now),
+        TimezoneOption::Utc => next_runs_in(&schedule, &Utc, now),
+        TimezoneOption::Named(name) => match parse_timezone(name) {
+            Ok(tz) => next_runs_in(&schedule, &tz, now),
+            Err(e) => return CronParseResult::failure(e, None),
+        },
+    };
+
+    let mut warnings = Vec::new();
+    if next_runs.is_empty() {
+        warnings.push(format!(
+            "No run time found in the next {} years",
+            MAX_SEARCH_YEARS
+        ));
+    }
+
+    let (description_en, description_ja) = describe_schedule(&schedule);
+    CronParseResult {
+        success: true,
+        has_seconds: schedule.second.is_some(),
+        fields: schedule
+            .fields()
+            .into_iter()
+            .map(|f| CronFieldDescription {
+                field: f.kind.key().to_string(),
+                value: f.text.clone(),
+                description_en: f.describe_en(),
+                description_ja: f.describe_ja(),
+            })
+            .collect(),
+        normalized,
+        description_en,
+        description_ja,
+        next_runs,
+        warnings,
+        error: None,
+        invalid_field: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn run_times(result: &CronParseResult) -> Vec<&str> {
+        result
+            .next_runs
+            .iter()
+            .map(|r| r.datetime.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_every_fifteen_minutes() {
+        let result = parse_cron_at(
+            "*/15 * * * *",
+            TimezoneOption::Utc,
+            at("2025-06-02T10:07:30Z"),
+        );
+        assert!(result.success);
+        assert_eq!(result.next_runs.len(), 10);
+        assert_eq!(
+            run_times(&result)[..3],
+            [
+                "2025-06-02 10:15:00",
+                "2025-06-02 10:30:00",
+                "2025-06-02 10:45:00"
+            ]
+        );
+        assert_eq!(result.description_en, "Every 15 minutes");
+        assert_eq!(result.description_ja, "15分ごとに実行");
+    }
+
+    #[test]
+    fn test_weekdays_in_named_timezone() {
+        // 2025-06-06 is a Friday
+        let result = parse_cron_at(
+            "30 9 * * MON-FRI",
+            TimezoneOption::Named("Asia/Tokyo".to_string()),
+            at("2025-06-06T01:00:00Z"),
+        );
+        assert_eq!(
+            run_times(&result)[..2],
+            ["2025-06-09 09:30:00", "2025-06-10 09:30:00"]
+        );
+        assert!(result.next_runs[0].iso8601.ends_with("+09:00"));
+        assert_eq!(result.next_runs[0].day_of_week, "Mon");
+        assert_eq!(result.description_en, "At 09:30, on Monday through Friday");
+        assert_eq!(result.description_ja, "月曜日〜金曜日の9時30分に実行");
+    }
+
+    #[test]
+    fn test_aliases() {
+        let result = parse_cron_at("@daily", TimezoneOption::Utc, at("2025-06-02T10:00:00Z"));
+        assert_eq!(result.normalized, "0 0 * * *");
+        assert_eq!(run_times(&result)[0], "2025-06-03 00:00:00");
+        assert_eq!(result.description_ja, "毎日0時0分に実行");
+
+        let yearly = parse_cron_at("@YEARLY", TimezoneOption::Utc, at("2025-06-02T10:00:00Z"));
+        assert_eq!(run_times(&yearly)[0], "2026-01-01 00:00:00");
+        assert_eq!(yearly.description_en, "At 00:00, on day 1, in January");
+
+        assert!(!parse_cron("@reboot", TimezoneOption::Utc).success);
+        assert!(!parse_cron("@often", TimezoneOption::Utc).success);
+    }
+
+    #[test]
+    fn test_six_fields_with_seconds() {
+        let result = parse_cron_at(
+            "*/20 * * * * *",
+            TimezoneOption::Utc,
+            at("2025-06-02T10:00:05Z"),
+        );
+        assert!(result.has_seconds);
+        assert_eq!(result.fields[0].field, "second");
+        assert_eq!(
+            run_times(&result)[..3],
+            [
+                "2025-06-02 10:00:20",
+                "2025-06-02 10:00:40",
+                "2025-06-02 10:01:00"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_day_of_month_or_day_of_week() {
+        // Runs on the 1st and on every Monday; 2025-06-01 is a Sunday
+        let result = parse_cron_at("0 0 1 * 1", TimezoneOption::Utc, at("2025-05-31T12:00:00Z"));
+        assert_eq!(
+            run_times(&result)[..3],
+            [
+                "2025-06-01 00:00:00",
+                "2025-06-02 00:00:00",
+                "2025-06-09 00:00:00"
+            ]
+        );
+        assert!(result.description_en.contains("on day 1 or on Monday"));
+
+        // Sunday as 7 and ranges ending at 7
+        let sunday = parse_cron_at(
+            "0 12 * * 5-7",
+            TimezoneOption::Utc,
+            at("2025-06-02T00:00:00Z"),
+        );
+        let days: Vec<&str> = sunday.next_runs[..3]
+            .iter()
+            .map(|r| r.day_of_week.as_str())
+            .collect();
+        assert_eq!(days, ["Fri", "Sat", "Sun"]);
+    }
+
+    #[test]
+    fn test_field_descriptions() {
+        let result = parse_cron_at(
+            "0,30 9-17/2 1-15 JAN,JUL *",
+            TimezoneOption::Utc,
+            at("2025-06-02T00:00:00Z"),
+        );
+        let en: Vec<&str> = result
+            .fields
+            .iter()
+            .map(|f| f.description_en.as_str())
+            .collect();
+        assert_eq!(
+            en,
+            [
+                "at minutes 0, 30",
+                "every 2 hours, hours 9 through 17",
+                "on days 1 through 15",
+                "in January, July",
+                "every day of the week"
+            ]
+        );
+        let ja: Vec<&str> = result
+            .fields
+            .iter()
+            .map(|f| f.description_ja.as_str())
+            .collect();
+        assert_eq!(
+            ja,
+            [
+                "0、30分",
+                "9〜17時の間で2時間ごと",
+                "1〜15日",
+                "1月、7月",
+                "全曜日"
+            ]
+        );
+        assert_eq!(run_times(&result)[0], "2025-07-01 09:00:00");
+    }
+
+    #[test]
+    fn test_invalid_field_is_reported() {
+        let result = parse_cron("0 25 * * *", TimezoneOption::Utc);
+        assert!(!result.success);
+        assert_eq!(result.invalid_field.as_deref(), Some("hour"));
+        assert!(result.error.unwrap().contains("25 is out of range (0-23)"));
+
+        let result = parse_cron("* * * FOO *", TimezoneOption::Utc);
+        assert_eq!(result.invalid_field.as_deref(), Some("month"));
+
+        let result = parse_cron("*/0 * * * *", TimezoneOption::Utc);
+        assert_eq!(result.invalid_field.as_deref(), Some("minute"));
+
+        let result = parse_cron("* * 20-10 * *", TimezoneOption::Utc);
+        assert_eq!(result.invalid_field.as_deref(), Some("day_of_month"));
+
+        let result = parse_cron("* * L * *", TimezoneOption::Utc);
+        assert!(result.error.unwrap().contains("not supported"));
+
+        let result = parse_cron("* * * *", TimezoneOption::Utc);
+        assert!(result.error.unwrap().contains("5 or 6 fields"));
+
+        let result = parse_cron(
+            "* * * * *",
+            TimezoneOption::Named("Mars/Olympus".to_string()),
+        );
+        assert!(!result.success);
+    }
+
+    #[test]
+    fn test_never_runs() {
+        let result = parse_cron_at(
+            "0 0 30 2 *",
+            TimezoneOption::Utc,
+            at("2025-06-02T00:00:00Z"),
+        );
+        assert!(result.success);
+        assert!(result.next_runs.is_empty());
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_dst_gap_is_skipped() {
+        // 2025-03-09 02:30 does not exist in New York
+        let result = parse_cron_at(
+            "30 2 * * *",
+            TimezoneOption::Named("America/New_York".to_string()),
+            at("2025-03-08T12:00:00Z"),
+        );
+        assert_eq!(
+            run_times(&result)[..2],
+            ["2025-03-10 02:30:00", "2025-03-11 02:30:00"]
+        );
+        assert_eq!(result.next_runs.len(), 10);
+    }
+}
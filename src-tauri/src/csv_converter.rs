@@ -0,0 +1,427 @@
+use crate::csv_viewer::read_csv;
+use csv::WriterBuilder;
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvToJsonOptions {
+    /// Convert numeric and boolean cells to JSON numbers/booleans instead of strings
+    pub infer_types: bool,
+    pub pretty: bool,
+}
+
+impl Default for CsvToJsonOptions {
+    fn default() -> Self {
+        Self {
+            infer_types: true,
+            pretty: true,
+        }
+    }
+}
+
+/// A JSON value whose objects keep their key order. `serde_json::Map` sorts keys, and
+/// here the order is the column order, in both directions.
+#[derive(Debug, Clone, PartialEq)]
+enum OrderedValue {
+    /// Anything but an array or object
+    Scalar(Value),
+    Array(Vec<OrderedValue>),
+    Object(Vec<(String, OrderedValue)>),
+}
+
+/// Insert `key`, replacing an earlier value in place like a JSON parser does for
+/// duplicate keys
+fn insert_ordered(entries: &mut Vec<(String, OrderedValue)>, key: String, value: OrderedValue) {
+    match entries.iter_mut().find(|(existing, _)| *existing == key) {
+        Some((_, slot)) => *slot = value,
+        None => entries.push((key, value)),
+    }
+}
+
+impl Serialize for OrderedValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            OrderedValue::Scalar(value) => value.serialize(serializer),
+            OrderedValue::Array(items) => serializer.collect_seq(items),
+            OrderedValue::Object(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderedValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedVisitor;
+
+        impl<'de> Visitor<'de> for OrderedVisitor {
+            type Value = OrderedValue;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("any JSON value")
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<OrderedValue, E> {
+                Ok(OrderedValue::Scalar(Value::Bool(v)))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<OrderedValue, E> {
+                Ok(OrderedValue::Scalar(Value::from(v)))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<OrderedValue, E> {
+                Ok(OrderedValue::Scalar(Value::from(v)))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<OrderedValue, E> {
+                Ok(OrderedValue::Scalar(Value::from(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<OrderedValue, E> {
+                Ok(OrderedValue::Scalar(Value::String(v.to_string())))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<OrderedValue, E> {
+                Ok(OrderedValue::Scalar(Value::String(v)))
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<OrderedValue, E> {
+                Ok(OrderedValue::Scalar(Value::Null))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<OrderedValue, A::Error> {
+                let mut items = Vec::new();
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                Ok(OrderedValue::Array(items))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<OrderedValue, A::Error> {
+                let mut entries = Vec::new();
+                while let Some((key, value)) = map.next_entry()? {
+                    insert_ordered(&mut entries, key, value);
+                }
+                Ok(OrderedValue::Object(entries))
+            }
+        }
+
+        deserializer.deserialize_any(OrderedVisitor)
+    }
+}
+
+/// Convert a CSV file into a JSON array of objects keyed by the header row
+pub fn csv_to_json(path: &str, options: CsvToJsonOptions) -> Result<String, String> {
+    let data = read_csv(path)?;
+
+    let records: Vec<OrderedValue> = data
+        .rows
+        .iter()
+        .map(|row| {
+            let mut entries = Vec::with_capacity(data.headers.len());
+            for (i, header) in data.headers.iter().enumerate() {
+                let cell = row.get(i).map(String::as_str).unwrap_or("");
+                let value = if options.infer_types {
+                    infer_value(cell)
+                } else {
+                    Value::String(cell.to_string())
+                };
+                insert_ordered(&mut entries, header.clone(), OrderedValue::Scalar(value));
+            }
+            OrderedValue::Object(entries)
+        })
+        .collect();
+
+    let result = if options.pretty {
+        serde_json::to_string_pretty(&records)
+    } else {
+        serde_json::to_string(&records)
+    };
+    result.map_err(|e| format!("Failed to serialize JSON: {}", e))
+}
+
+/// Convert a CSV file to JSON and write it to `output_path`
+pub fn export_csv_to_json(
+    path: &str,
+    output_path: &str,
+    options: CsvToJsonOptions,
+) -> Result<(), String> {
+    let json = csv_to_json(path, options)?;
+    std::fs::write(output_path, json).map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Convert a JSON array of objects into a CSV file.
+///
+/// Nested objects are flattened into dot-notation columns (`a.b`), arrays are kept as JSON
+/// text, and keys missing from a record are written as empty cells.
+pub fn json_to_csv(input: &str, output_path: &str) -> Result<(), String> {
+    let (headers, rows) = json_to_table(input)?;
+
+    let mut writer = WriterBuilder::new()
+        .has_headers(true)
+        .from_path(output_path)
+        .map_err(|e| format!("Failed to create file: {}", e))?;
+
+    writer
+        .write_record(&headers)
+        .map_err(|e| format!("Failed to write headers: {}", e))?;
+
+    for row in rows {
+        writer
+            .write_record(&row)
+            .map_err(|e| format!("Failed to write row: {}", e))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| format!("Failed to flush: {}", e))?;
+
+    Ok(())
+}
+
+fn json_to_table(input: &str) -> Result<(Vec<String>, Vec<Vec<String>>), String> {
+    let value: OrderedValue =
+        serde_json::from_str(input).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let items = match value {
+        OrderedValue::Array(items) => items,
+        OrderedValue::Object(_) => vec![value],
+        OrderedValue::Scalar(_) => return Err("JSON must be an array of objects".to_string()),
+    };
+
+    let mut headers: Vec<String> = Vec::new();
+    let mut records: Vec<HashMap<String, OrderedValue>> = Vec::with_capacity(items.len());
+
+    for (index, item) in items.into_iter().enumerate() {
+        let OrderedValue::Object(object) = item else {
+            return Err(format!("Item at index {} is not an object", index));
+        };
+        let mut flat = Vec::new();
+        flatten_object("", &object, &mut flat);
+        for (key, _) in &flat {
+            if !headers.contains(key) {
+                headers.push(key.clone());
+            }
+        }
+        records.push(flat.into_iter().collect());
+    }
+
+    let rows = records
+        .iter()
+        .map(|record| {
+            headers
+                .iter()
+                .map(|header| record.get(header).map(cell_text).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+
+    Ok((headers, rows))
+}
+
+fn flatten_object(
+    prefix: &str,
+    object: &[(String, OrderedValue)],
+    out: &mut Vec<(String, OrderedValue)>,
+) {
+    for (key, value) in object {
+        let column = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            OrderedValue::Object(nested) if !nested.is_empty() => {
+                flatten_object(&column, nested, out)
+            }
+            _ => insert_ordered(out, column, value.clone()),
+        }
+    }
+}
+
+fn cell_text(value: &OrderedValue) -> String {
+    match value {
+        OrderedValue::Scalar(Value::Null) => String::new(),
+        OrderedValue::Scalar(Value::String(s)) => s.clone(),
+        OrderedValue::Scalar(value) => value.to_string(),
+        OrderedValue::Array(_) | OrderedValue::Object(_) => {
+            serde_json::to_string(value).unwrap_or_default()
+        }
+    }
+}
+
+/// Infer a JSON type for a cell. Only values that read back exactly as written become
+/// numbers, so identifiers like "007", "1e5", "0x1" or "12345678901234567890" stay strings.
+fn infer_value(cell: &str) -> Value {
+    let trimmed = cell.trim();
+    match trimmed.to_lowercase().as_str() {
+        "true" => return Value::Bool(true),
+        "false" => return Value::Bool(false),
+        _ => {}
+    }
+
+    // Keep values like "007" or "+81" as strings so identifiers are not mangled
+    let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
+    let has_leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    if trimmed.is_empty() || trimmed.starts_with('+') || has_leading_zero {
+        return Value::String(cell.to_string());
+    }
+
+    if let Ok(i) = trimmed.parse::<i64>() {
+        return Value::from(i);
+    }
+    // A plain decimal: digits, one point, digits, nothing else
+    let is_plain_decimal = digits.split_once('.').is_some_and(|(whole, fraction)| {
+        !whole.is_empty()
+            && !fraction.is_empty()
+            && whole.bytes().all(|b| b.is_ascii_digit())
+            && fraction.bytes().all(|b| b.is_ascii_digit())
+    });
+    if is_plain_decimal {
+        if let Some(number) = trimmed.parse::<f64>().ok().and_then(Number::from_f64) {
+            if number.to_string() == trimmed {
+                return Value::Number(number);
+            }
+        }
+    }
+    Value::String(cell.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_csv_to_json_with_type_inference() {
+        let path = std::env::temp_dir().join("taurin_csv_converter_infer.csv");
+        fs::write(
+            &path,
+            "name,age,active,zip\nalice,30,true,0123\nbob,4.5,FALSE,\n",
+        )
+        .unwrap();
+
+        let json = csv_to_json(path.to_str().unwrap(), CsvToJsonOptions::default()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["name"], "alice");
+        assert_eq!(value[0]["age"], 30);
+        assert_eq!(value[0]["active"], true);
+        assert_eq!(value[0]["zip"], "0123");
+        assert_eq!(value[1]["age"], 4.5);
+        assert_eq!(value[1]["active"], false);
+        assert_eq!(value[1]["zip"], "");
+    }
+
+    #[test]
+    fn test_infer_value_keeps_lossy_numbers_as_text() {
+        assert_eq!(infer_value("42"), Value::from(42));
+        assert_eq!(infer_value("-3"), Value::from(-3));
+        assert_eq!(infer_value(" 4.5 "), Value::from(4.5));
+        assert_eq!(infer_value("-0.25"), Value::from(-0.25));
+        for cell in [
+            "12345678901234567890",
+            "1e5",
+            "0x1",
+            "NaN",
+            "inf",
+            "1.50",
+            ".5",
+            "5.",
+            "+81",
+            "007",
+            "0.1000000000000000055511151231257827",
+        ] {
+            assert_eq!(
+                infer_value(cell),
+                Value::String(cell.to_string()),
+                "{}",
+                cell
+            );
+        }
+    }
+
+    #[test]
+    fn test_csv_to_json_keeps_header_order() {
+        let path = std::env::temp_dir().join("taurin_csv_converter_order.csv");
+        fs::write(
+            &path,
+            "zeta,alpha,mid
+1,2,3
+",
+        )
+        .unwrap();
+
+        let options = CsvToJsonOptions {
+            infer_types: true,
+            pretty: true,
+        };
+        let json = csv_to_json(path.to_str().unwrap(), options).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            json,
+            "[\n  {\n    \"zeta\": 1,\n    \"alpha\": 2,\n    \"mid\": 3\n  }\n]"
+        );
+    }
+
+    #[test]
+    fn test_csv_to_json_without_type_inference() {
+        let path = std::env::temp_dir().join("taurin_csv_converter_plain.csv");
+        fs::write(&path, "b,a\n1,true\n").unwrap();
+
+        let options = CsvToJsonOptions {
+            infer_types: false,
+            pretty: false,
+        };
+        let json = csv_to_json(path.to_str().unwrap(), options).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(json, r#"[{"b":"1","a":"true"}]"#);
+    }
+
+    #[test]
+    fn test_json_to_table_flattens_and_fills_missing() {
+        let input = r#"[
+            {"id": 1, "user": {"name": "alice", "address": {"city": "Tokyo"}}, "tags": ["a", "b"]},
+            {"id": 2, "extra": null}
+        ]"#;
+        let (headers, rows) = json_to_table(input).unwrap();
+
+        assert_eq!(
+            headers,
+            vec!["id", "user.name", "user.address.city", "tags", "extra"]
+        );
+        assert_eq!(rows[0], vec!["1", "alice", "Tokyo", r#"["a","b"]"#, ""]);
+        assert_eq!(rows[1], vec!["2", "", "", "", ""]);
+    }
+
+    #[test]
+    fn test_json_to_csv_rejects_non_objects() {
+        assert!(json_to_table("[1, 2]").is_err());
+        assert!(json_to_table("\"text\"").is_err());
+        assert!(json_to_table("not json").is_err());
+    }
+
+    #[test]
+    fn test_json_to_csv_roundtrip() {
+        let path = std::env::temp_dir().join("taurin_csv_converter_roundtrip.csv");
+        json_to_csv(r#"[{"a": "x,y", "b": 2}]"#, path.to_str().unwrap()).unwrap();
+
+        let data = read_csv(path.to_str().unwrap()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(data.headers, vec!["a", "b"]);
+        assert_eq!(data.rows[0], vec!["x,y", "2"]);
+    }
+}
@@ -1,7 +1,27 @@
-use csv::{ReaderBuilder, WriterBuilder};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use csv::{ByteRecord, Position, Reader, ReaderBuilder, Terminator, WriterBuilder};
+use encoding_rs::SHIFT_JIS;
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::path::Path;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+/// Rows between the seek checkpoints kept for chunked reads
+const CHECKPOINT_INTERVAL: usize = 1000;
+/// Bytes inspected when detecting the encoding and delimiter
+const SNIFF_BYTES: u64 = 64 * 1024;
+/// Delimiters recognised by detection, in order of preference on a tie
+const DELIMITERS: [u8; 3] = [b',', b'\t', b';'];
+/// Indexed files kept in memory before the cache is reset
+const MAX_CACHED_INDEXES: usize = 16;
+/// Distinct values counted per column; beyond this `unique_count` is a lower bound
+const MAX_TRACKED_VALUES: usize = 100_000;
+/// Most frequent values reported for text columns
+const TOP_VALUE_COUNT: usize = 5;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CsvData {
@@ -9,6 +29,26 @@ pub struct CsvData {
     pub rows: Vec<Vec<String>>,
     pub total_rows: usize,
     pub total_columns: usize,
+    /// Number of rows matching the filter (equals `total_rows` when unfiltered)
+    pub filtered_rows: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsvQueryOptions {
+    pub sort_column: Option<usize>,
+    pub sort_desc: bool,
+    pub filter_column: Option<usize>,
+    pub filter_text: Option<String>,
+    pub offset: usize,
+    /// Maximum number of rows to return (0 = all remaining rows)
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CsvEncoding {
+    Utf8,
+    ShiftJis,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,49 +58,378 @@ pub struct CsvInfo {
     pub row_count: usize,
     pub column_count: usize,
     pub headers: Vec<String>,
+    pub delimiter: char,
+    pub encoding: CsvEncoding,
 }
 
-pub fn read_csv(path: &str) -> Result<CsvData, String> {
-    let file_path = Path::new(path);
+/// A window of rows returned by [`read_csv_chunk`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvChunk {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// Index of the first returned row
+    pub offset: usize,
+    pub total_rows: usize,
+    pub total_columns: usize,
+}
 
-    if !file_path.exists() {
-        return Err("File not found".to_string());
+/// A single cell change for [`update_csv_cells`]; `row` counts data rows, not the header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellEdit {
+    pub row: usize,
+    pub col: usize,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvEditResult {
+    pub edited_rows: usize,
+    /// Distinct cells changed; a later edit of the same cell replaces an earlier one
+    pub edited_cells: usize,
+    pub total_rows: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsvStatsOptions {
+    /// Row count above which only a sample is analyzed (0 = always analyze every row)
+    pub sample_threshold: usize,
+    /// Approximate number of rows analyzed when sampling, spread evenly over the file
+    pub sample_size: usize,
+}
+
+impl Default for CsvStatsOptions {
+    fn default() -> Self {
+        Self {
+            sample_threshold: 1_000_000,
+            sample_size: 200_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColumnType {
+    Number,
+    Date,
+    Boolean,
+    String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumericStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    /// Sample standard deviation (0 for a single value)
+    pub std_dev: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueCount {
+    pub value: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnSummary {
+    pub name: String,
+    pub inferred_type: ColumnType,
+    /// Empty cells and literal `NULL`s
+    pub null_count: usize,
+    pub unique_count: usize,
+    /// True when there were too many distinct values to count them all
+    pub unique_count_capped: bool,
+    /// Set for number columns
+    pub numeric: Option<NumericStats>,
+    /// Set for text columns
+    pub top_values: Vec<ValueCount>,
+    /// Longest value in characters, for text columns
+    pub max_length: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvColumnStats {
+    pub total_rows: usize,
+    pub analyzed_rows: usize,
+    pub sampled: bool,
+    pub columns: Vec<ColumnSummary>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CsvFormat {
+    delimiter: u8,
+    encoding: CsvEncoding,
+}
+
+/// Everything needed to jump into a file without re-reading it from the start
+struct CsvIndex {
+    modified: Option<SystemTime>,
+    file_size: u64,
+    format: CsvFormat,
+    headers: Vec<String>,
+    row_count: usize,
+    /// Position of every `CHECKPOINT_INTERVAL`-th row
+    checkpoints: Vec<Position>,
+}
+
+fn detect_encoding(sample: &[u8]) -> CsvEncoding {
+    match std::str::from_utf8(sample) {
+        Ok(_) => CsvEncoding::Utf8,
+        // A character cut off by the end of the sample is still valid UTF-8
+        Err(e) if e.error_len().is_none() => CsvEncoding::Utf8,
+        Err(_) => CsvEncoding::ShiftJis,
     }
+}
 
-    let content =
-        fs::read_to_string(file_path).map_err(|e| format!("Failed to read file: {}", e))?;
+/// Pick the candidate delimiter that occurs most often in the first line.
+///
+/// Counting raw bytes is safe for Shift_JIS too, since none of the candidates
+/// can appear as the second byte of a double-byte character.
+pub(crate) fn detect_delimiter(sample: &[u8]) -> u8 {
+    let mut counts = [0usize; DELIMITERS.len()];
+    let mut in_quotes = false;
+    for &byte in sample {
+        match byte {
+            b'"' => in_quotes = !in_quotes,
+            b'\n' | b'\r' if !in_quotes => break,
+            _ if !in_quotes => {
+                if let Some(i) = DELIMITERS.iter().position(|&d| d == byte) {
+                    counts[i] += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    let (best, count) =
+        counts.iter().enumerate().fold(
+            (0, 0),
+            |best, (i, &c)| if c > best.1 { (i, c) } else { best },
+        );
+    if count == 0 {
+        b','
+    } else {
+        DELIMITERS[best]
+    }
+}
+
+fn sniff_format(path: &Path) -> Result<CsvFormat, String> {
+    let mut sample = Vec::new();
+    open_file(path)?
+        .take(SNIFF_BYTES)
+        .read_to_end(&mut sample)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    Ok(CsvFormat {
+        delimiter: detect_delimiter(&sample),
+        encoding: detect_encoding(&sample),
+    })
+}
 
-    let mut reader = ReaderBuilder::new()
+fn open_file(path: &Path) -> Result<File, String> {
+    if !path.exists() {
+        return Err("File not found".to_string());
+    }
+    File::open(path).map_err(|e| format!("Failed to read file: {}", e))
+}
+
+fn open_reader(path: &Path, format: CsvFormat) -> Result<Reader<BufReader<File>>, String> {
+    let file = open_file(path)?;
+    Ok(ReaderBuilder::new()
         .has_headers(true)
         .flexible(true)
-        .from_reader(content.as_bytes());
+        .delimiter(format.delimiter)
+        .from_reader(BufReader::new(file)))
+}
 
-    let headers: Vec<String> = reader
-        .headers()
-        .map_err(|e| format!("Failed to read headers: {}", e))?
+fn decode_record(record: &ByteRecord, encoding: CsvEncoding) -> Vec<String> {
+    record
         .iter()
-        .map(|s| s.to_string())
-        .collect();
+        .map(|field| match encoding {
+            CsvEncoding::Utf8 => String::from_utf8_lossy(field).into_owned(),
+            CsvEncoding::ShiftJis => SHIFT_JIS.decode_without_bom_handling(field).0.into_owned(),
+        })
+        .collect()
+}
+
+fn read_headers<R: Read>(
+    reader: &mut Reader<R>,
+    encoding: CsvEncoding,
+) -> Result<Vec<String>, String> {
+    let headers = reader
+        .byte_headers()
+        .map_err(|e| format!("Failed to read headers: {}", e))?;
+    Ok(decode_record(headers, encoding))
+}
+
+fn load_csv(path: &str) -> Result<(Vec<String>, Vec<Vec<String>>), String> {
+    let file_path = Path::new(path);
+    let format = sniff_format(file_path)?;
+    let mut reader = open_reader(file_path, format)?;
+    let headers = read_headers(&mut reader, format.encoding)?;
 
     let mut rows: Vec<Vec<String>> = Vec::new();
 
-    for result in reader.records() {
+    for result in reader.byte_records() {
         let record = result.map_err(|e| format!("Failed to read row: {}", e))?;
-        let row: Vec<String> = record.iter().map(|s| s.to_string()).collect();
-        rows.push(row);
+        rows.push(decode_record(&record, format.encoding));
+    }
+
+    Ok((headers, rows))
+}
+
+fn build_index(
+    path: &Path,
+    modified: Option<SystemTime>,
+    file_size: u64,
+) -> Result<CsvIndex, String> {
+    let format = sniff_format(path)?;
+    let mut reader = open_reader(path, format)?;
+    let headers = read_headers(&mut reader, format.encoding)?;
+
+    let mut checkpoints = Vec::new();
+    let mut row_count = 0;
+    let mut record = ByteRecord::new();
+    loop {
+        let position = reader.position().clone();
+        let has_row = reader
+            .read_byte_record(&mut record)
+            .map_err(|e| format!("Failed to read row: {}", e))?;
+        if !has_row {
+            break;
+        }
+        if row_count % CHECKPOINT_INTERVAL == 0 {
+            checkpoints.push(position);
+        }
+        row_count += 1;
     }
 
+    Ok(CsvIndex {
+        modified,
+        file_size,
+        format,
+        headers,
+        row_count,
+        checkpoints,
+    })
+}
+
+fn index_cache() -> &'static Mutex<HashMap<PathBuf, Arc<CsvIndex>>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, Arc<CsvIndex>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Index `path` once and reuse it until the file changes on disk.
+fn csv_index(path: &Path) -> Result<Arc<CsvIndex>, String> {
+    let cache = index_cache();
+
+    let metadata = fs::metadata(path).map_err(|_| "File not found".to_string())?;
+    let (modified, file_size) = (metadata.modified().ok(), metadata.len());
+    if let Some(index) = cache.lock().unwrap().get(path) {
+        if index.modified == modified && index.file_size == file_size {
+            return Ok(Arc::clone(index));
+        }
+    }
+
+    let index = Arc::new(build_index(path, modified, file_size)?);
+    let mut cache = cache.lock().unwrap();
+    if cache.len() >= MAX_CACHED_INDEXES {
+        cache.clear();
+    }
+    cache.insert(path.to_path_buf(), Arc::clone(&index));
+    Ok(index)
+}
+
+pub fn read_csv(path: &str) -> Result<CsvData, String> {
+    let (headers, rows) = load_csv(path)?;
+
+    let total_rows = rows.len();
+    let total_columns = headers.len();
+
+    Ok(CsvData {
+        headers,
+        rows,
+        total_rows,
+        total_columns,
+        filtered_rows: total_rows,
+    })
+}
+
+/// Read a CSV file and apply filtering, sorting and paging on the backend
+pub fn query_csv(path: &str, options: CsvQueryOptions) -> Result<CsvData, String> {
+    let (headers, mut rows) = load_csv(path)?;
+
     let total_rows = rows.len();
     let total_columns = headers.len();
 
+    for column in [options.sort_column, options.filter_column]
+        .into_iter()
+        .flatten()
+    {
+        if column >= total_columns {
+            return Err(format!("Column index {} is out of range", column));
+        }
+    }
+
+    if let Some(filter_text) = options.filter_text.as_deref().filter(|t| !t.is_empty()) {
+        let needle = filter_text.to_lowercase();
+        rows.retain(|row| match options.filter_column {
+            Some(column) => row
+                .get(column)
+                .is_some_and(|cell| cell.to_lowercase().contains(&needle)),
+            None => row.iter().any(|cell| cell.to_lowercase().contains(&needle)),
+        });
+    }
+
+    let filtered_rows = rows.len();
+
+    if let Some(column) = options.sort_column {
+        rows.sort_by(|a, b| {
+            let a_cell = a.get(column).map(String::as_str).unwrap_or("");
+            let b_cell = b.get(column).map(String::as_str).unwrap_or("");
+            let ordering = compare_cells(a_cell, b_cell);
+            if options.sort_desc {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    let limit = if options.limit == 0 {
+        usize::MAX
+    } else {
+        options.limit
+    };
+    let rows: Vec<Vec<String>> = rows.into_iter().skip(options.offset).take(limit).collect();
+
     Ok(CsvData {
         headers,
         rows,
         total_rows,
         total_columns,
+        filtered_rows,
     })
 }
 
+/// A cell counts as a number only when it parses to a finite value, so "NaN" and
+/// "inf" sort as text and the ordering stays total
+fn cell_number(cell: &str) -> Option<f64> {
+    cell.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+/// Compare numerically when both cells are numbers, otherwise case-insensitively.
+/// Numbers sort before text so mixed columns stay grouped.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (cell_number(a), cell_number(b)) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// Describe a CSV file. The first call scans the whole file once and caches an
+/// index, which later [`read_csv_chunk`] calls reuse.
 pub fn get_csv_info(path: &str) -> Result<CsvInfo, String> {
     let file_path = Path::new(path);
 
@@ -68,9 +437,7 @@ pub fn get_csv_info(path: &str) -> Result<CsvInfo, String> {
         return Err("File not found".to_string());
     }
 
-    let metadata =
-        fs::metadata(file_path).map_err(|e| format!("Failed to get file metadata: {}", e))?;
-    let file_size = metadata.len();
+    let index = csv_index(file_path)?;
 
     let file_name = file_path
         .file_name()
@@ -78,30 +445,275 @@ pub fn get_csv_info(path: &str) -> Result<CsvInfo, String> {
         .unwrap_or("unknown")
         .to_string();
 
-    let content =
-        fs::read_to_string(file_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    Ok(CsvInfo {
+        file_name,
+        file_size: index.file_size,
+        row_count: index.row_count,
+        column_count: index.headers.len(),
+        headers: index.headers.clone(),
+        delimiter: char::from(index.format.delimiter),
+        encoding: index.format.encoding,
+    })
+}
 
-    let mut reader = ReaderBuilder::new()
-        .has_headers(true)
-        .flexible(true)
-        .from_reader(content.as_bytes());
+/// Read the header and at most `limit` rows starting at row `offset`, without
+/// loading the rest of the file.
+pub fn read_csv_chunk(path: &str, offset: usize, limit: usize) -> Result<CsvChunk, String> {
+    let file_path = Path::new(path);
+    let index = csv_index(file_path)?;
+
+    let mut rows = Vec::new();
+    if let Some(checkpoint) = index.checkpoints.get(offset / CHECKPOINT_INTERVAL) {
+        let mut reader = open_reader(file_path, index.format)?;
+        reader
+            .seek(checkpoint.clone())
+            .map_err(|e| format!("Failed to seek: {}", e))?;
+        for result in reader
+            .byte_records()
+            .skip(offset % CHECKPOINT_INTERVAL)
+            .take(limit)
+        {
+            let record = result.map_err(|e| format!("Failed to read row: {}", e))?;
+            rows.push(decode_record(&record, index.format.encoding));
+        }
+    }
+
+    Ok(CsvChunk {
+        headers: index.headers.clone(),
+        rows,
+        offset,
+        total_rows: index.row_count,
+        total_columns: index.headers.len(),
+    })
+}
+
+fn is_null(value: &str) -> bool {
+    value.is_empty() || value.eq_ignore_ascii_case("null")
+}
 
-    let headers: Vec<String> = reader
-        .headers()
-        .map_err(|e| format!("Failed to read headers: {}", e))?
+fn parse_number(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+fn is_boolean(value: &str) -> bool {
+    ["true", "false", "yes", "no"]
+        .iter()
+        .any(|b| value.eq_ignore_ascii_case(b))
+}
+
+/// ISO 8601 dates and date-times, plus `yyyy/mm/dd` with an optional time.
+fn is_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    // Cheap rejection before trying the formats
+    if bytes.len() < 8
+        || !bytes[..4].iter().all(u8::is_ascii_digit)
+        || !matches!(bytes[4], b'-' | b'/')
+    {
+        return false;
+    }
+    const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%Y/%m/%d"];
+    const DATE_TIME_FORMATS: [&str; 6] = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+        "%Y/%m/%d %H:%M:%S",
+        "%Y/%m/%d %H:%M",
+    ];
+    DATE_FORMATS
+        .iter()
+        .any(|f| NaiveDate::parse_from_str(value, f).is_ok())
+        || DATE_TIME_FORMATS
+            .iter()
+            .any(|f| NaiveDateTime::parse_from_str(value, f).is_ok())
+        || DateTime::parse_from_rfc3339(value).is_ok()
+}
+
+/// Running statistics for one column, updated a cell at a time
+#[derive(Default)]
+struct ColumnAccumulator {
+    null_count: usize,
+    non_null_count: usize,
+    number_count: usize,
+    date_count: usize,
+    boolean_count: usize,
+    frequencies: HashMap<String, usize>,
+    unique_capped: bool,
+    /// Kept only while every value so far has been numeric
+    numbers: Vec<f64>,
+    max_length: usize,
+}
+
+impl ColumnAccumulator {
+    fn add(&mut self, raw: &str) {
+        let value = raw.trim();
+        if is_null(value) {
+            self.null_count += 1;
+            return;
+        }
+        self.non_null_count += 1;
+        self.max_length = self.max_length.max(value.chars().count());
+
+        match parse_number(value) {
+            Some(number) if self.number_count + 1 == self.non_null_count => {
+                self.number_count += 1;
+                self.numbers.push(number);
+            }
+            Some(_) => self.number_count += 1,
+            None => self.numbers = Vec::new(),
+        }
+        if is_boolean(value) {
+            self.boolean_count += 1;
+        }
+        if is_date(value) {
+            self.date_count += 1;
+        }
+
+        if let Some(count) = self.frequencies.get_mut(value) {
+            *count += 1;
+        } else if self.frequencies.len() < MAX_TRACKED_VALUES {
+            self.frequencies.insert(value.to_string(), 1);
+        } else {
+            self.unique_capped = true;
+        }
+    }
+
+    fn inferred_type(&self) -> ColumnType {
+        let all = |count: usize| self.non_null_count > 0 && count == self.non_null_count;
+        if all(self.boolean_count) {
+            ColumnType::Boolean
+        } else if all(self.number_count) {
+            ColumnType::Number
+        } else if all(self.date_count) {
+            ColumnType::Date
+        } else {
+            ColumnType::String
+        }
+    }
+
+    fn finish(mut self, name: String) -> ColumnSummary {
+        let inferred_type = self.inferred_type();
+        let numeric = (inferred_type == ColumnType::Number)
+            .then(|| numeric_stats(&mut self.numbers))
+            .flatten();
+
+        let (top_values, max_length) = if inferred_type == ColumnType::String {
+            let mut values: Vec<(&String, &usize)> = self.frequencies.iter().collect();
+            // Most frequent first, ties in value order so results are stable
+            values.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+            let top = values
+                .into_iter()
+                .take(TOP_VALUE_COUNT)
+                .map(|(value, &count)| ValueCount {
+                    value: value.clone(),
+                    count,
+                })
+                .collect();
+            (top, Some(self.max_length))
+        } else {
+            (Vec::new(), None)
+        };
+
+        ColumnSummary {
+            name,
+            inferred_type,
+            null_count: self.null_count,
+            unique_count: self.frequencies.len(),
+            unique_count_capped: self.unique_capped,
+            numeric,
+            top_values,
+            max_length,
+        }
+    }
+}
+
+fn numeric_stats(numbers: &mut [f64]) -> Option<NumericStats> {
+    if numbers.is_empty() {
+        return None;
+    }
+    numbers.sort_by(|a, b| a.total_cmp(b));
+    let n = numbers.len();
+    let mean = numbers.iter().sum::<f64>() / n as f64;
+    let median = if n.is_multiple_of(2) {
+        (numbers[n / 2 - 1] + numbers[n / 2]) / 2.0
+    } else {
+        numbers[n / 2]
+    };
+    let std_dev = if n > 1 {
+        let variance = numbers.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        variance.sqrt()
+    } else {
+        0.0
+    };
+    Some(NumericStats {
+        min: numbers[0],
+        max: numbers[n - 1],
+        mean,
+        median,
+        std_dev,
+    })
+}
+
+/// Summarize every column of a CSV file with the default sampling options.
+pub fn analyze_csv_columns(path: &str) -> Result<CsvColumnStats, String> {
+    analyze_csv_columns_with(path, &CsvStatsOptions::default())
+}
+
+/// Summarize every column in a single pass, looking at an evenly spread sample of
+/// rows when the file is larger than `options.sample_threshold`.
+pub fn analyze_csv_columns_with(
+    path: &str,
+    options: &CsvStatsOptions,
+) -> Result<CsvColumnStats, String> {
+    let file_path = Path::new(path);
+    let index = csv_index(file_path)?;
+    let total_rows = index.row_count;
+
+    let sampled = options.sample_threshold > 0
+        && total_rows > options.sample_threshold
+        && options.sample_size > 0
+        && options.sample_size < total_rows;
+    let stride = if sampled {
+        total_rows.div_ceil(options.sample_size)
+    } else {
+        1
+    };
+
+    let mut columns: Vec<ColumnAccumulator> = index
+        .headers
         .iter()
-        .map(|s| s.to_string())
+        .map(|_| ColumnAccumulator::default())
         .collect();
+    let mut reader = open_reader(file_path, index.format)?;
+    let mut record = ByteRecord::new();
+    let mut row: usize = 0;
+    let mut analyzed_rows = 0;
+    while reader
+        .read_byte_record(&mut record)
+        .map_err(|e| format!("Failed to read row: {}", e))?
+    {
+        if row.is_multiple_of(stride) {
+            analyzed_rows += 1;
+            let cells = decode_record(&record, index.format.encoding);
+            // Short rows count as nulls for their missing cells
+            for (i, column) in columns.iter_mut().enumerate() {
+                column.add(cells.get(i).map(String::as_str).unwrap_or(""));
+            }
+        }
+        row += 1;
+    }
 
-    let row_count = reader.records().count();
-    let column_count = headers.len();
+    let columns = columns
+        .into_iter()
+        .zip(index.headers.iter())
+        .map(|(column, name)| column.finish(name.clone()))
+        .collect();
 
-    Ok(CsvInfo {
-        file_name,
-        file_size,
-        row_count,
-        column_count,
-        headers,
+    Ok(CsvColumnStats {
+        total_rows,
+        analyzed_rows,
+        sampled,
+        columns,
     })
 }
 
@@ -127,3 +739,492 @@ pub fn save_csv(path: &str, headers: &[String], rows: &[Vec<String>]) -> Result<
 
     Ok(())
 }
+
+fn encode_value(value: &str, encoding: CsvEncoding) -> Result<Vec<u8>, String> {
+    match encoding {
+        CsvEncoding::Utf8 => Ok(value.as_bytes().to_vec()),
+        CsvEncoding::ShiftJis => {
+            let (bytes, _, had_errors) = SHIFT_JIS.encode(value);
+            if had_errors {
+                return Err(format!("\"{}\" cannot be written as Shift_JIS", value));
+            }
+            Ok(bytes.into_owned())
+        }
+    }
+}
+
+/// The line ending a raw row was written with; empty for a last row without one
+fn line_terminator(raw: &[u8]) -> &'static [u8] {
+    if raw.ends_with(b"\r\n") {
+        b"\r\n"
+    } else if raw.ends_with(b"\n") {
+        b"\n"
+    } else if raw.ends_with(b"\r") {
+        b"\r"
+    } else {
+        b""
+    }
+}
+
+/// Serialize `record` with `cells` replaced, quoting only the fields that need it
+fn edited_row(
+    record: &ByteRecord,
+    cells: &BTreeMap<usize, Vec<u8>>,
+    delimiter: u8,
+) -> Result<Vec<u8>, String> {
+    let mut fields: Vec<&[u8]> = record.iter().collect();
+    if let Some((&last, _)) = cells.last_key_value() {
+        if fields.len() <= last {
+            fields.resize(last + 1, b"");
+        }
+    }
+    for (&col, value) in cells {
+        fields[col] = value;
+    }
+
+    let mut writer = WriterBuilder::new()
+        .delimiter(delimiter)
+        .terminator(Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    writer
+        .write_record(&fields)
+        .map_err(|e| format!("Failed to write row: {}", e))?;
+    let mut row = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to write row: {}", e))?;
+    row.pop();
+    Ok(row)
+}
+
+/// Apply cell edits by streaming the file into a sibling `.part` file and renaming
+/// it over the original. Rows without edits are copied byte for byte, so their
+/// quoting and line endings stay exactly as they were; edited rows keep their
+/// line ending and encoding.
+pub fn update_csv_cells(path: &str, edits: Vec<CellEdit>) -> Result<CsvEditResult, String> {
+    let file_path = Path::new(path);
+    let index = csv_index(file_path)?;
+
+    let mut rows: BTreeMap<usize, BTreeMap<usize, Vec<u8>>> = BTreeMap::new();
+    for edit in &edits {
+        if edit.row >= index.row_count {
+            return Err(format!(
+                "Row {} is out of range ({} rows)",
+                edit.row, index.row_count
+            ));
+        }
+        if edit.col >= index.headers.len() {
+            return Err(format!(
+                "Column {} is out of range ({} columns)",
+                edit.col,
+                index.headers.len()
+            ));
+        }
+        let value = encode_value(&edit.value, index.format.encoding)?;
+        rows.entry(edit.row).or_default().insert(edit.col, value);
+    }
+    let result = CsvEditResult {
+        edited_rows: rows.len(),
+        edited_cells: rows.values().map(BTreeMap::len).sum(),
+        total_rows: index.row_count,
+    };
+    if rows.is_empty() {
+        return Ok(result);
+    }
+
+    let part_path = format!("{}.part", path);
+    let written =
+        write_edited_copy(file_path, &index, &rows, Path::new(&part_path)).and_then(|_| {
+            if let Ok(metadata) = fs::metadata(file_path) {
+                let _ = fs::set_permissions(&part_path, metadata.permissions());
+            }
+            fs::rename(&part_path, file_path).map_err(|e| format!("Failed to replace file: {}", e))
+        });
+    if let Err(e) = written {
+        let _ = fs::remove_file(&part_path);
+        return Err(e);
+    }
+
+    index_cache().lock().unwrap().remove(file_path);
+    Ok(result)
+}
+
+fn write_edited_copy(
+    path: &Path,
+    index: &CsvIndex,
+    rows: &BTreeMap<usize, BTreeMap<usize, Vec<u8>>>,
+    output_path: &Path,
+) -> Result<(), String> {
+    let write_error = |e: io::Error| format!("Failed to write file: {}", e);
+    let read_error = |e: io::Error| format!("Failed to read file: {}", e);
+
+    let mut reader = open_reader(path, index.format)?;
+    read_headers(&mut reader, index.format.encoding)?;
+    let mut source = BufReader::new(open_file(path)?);
+    let mut output = BufWriter::new(File::create(output_path).map_err(write_error)?);
+
+    let mut record = ByteRecord::new();
+    let mut row = 0;
+    // Bytes of `source` already written to `output`
+    let mut copied = 0;
+    for (&target, cells) in rows {
+        while row < target {
+            reader
+                .read_byte_record(&mut record)
+                .map_err(|e| format!("Failed to read row: {}", e))?;
+            row += 1;
+        }
+        let start = reader.position().byte();
+        reader
+            .read_byte_record(&mut record)
+            .map_err(|e| format!("Failed to read row: {}", e))?;
+        row += 1;
+        let end = reader.position().byte();
+
+        io::copy(&mut (&mut source).take(start - copied), &mut output).map_err(write_error)?;
+        let mut raw = Vec::new();
+        (&mut source)
+            .take(end - start)
+            .read_to_end(&mut raw)
+            .map_err(read_error)?;
+        copied = end;
+
+        // Blank lines skipped by the reader belong to the start of the next row
+        let body = raw
+            .iter()
+            .position(|&b| b != b'\r' && b != b'\n')
+            .unwrap_or(raw.len());
+        output.write_all(&raw[..body]).map_err(write_error)?;
+        output
+            .write_all(&edited_row(&record, cells, index.format.delimiter)?)
+            .map_err(write_error)?;
+        output
+            .write_all(line_terminator(&raw[body..]))
+            .map_err(write_error)?;
+    }
+    io::copy(&mut source, &mut output).map_err(write_error)?;
+    output.flush().map_err(write_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_sample(name: &str) -> String {
+        let path = std::env::temp_dir().join(name);
+        fs::write(
+            &path,
+            "name,score,city\nalice,9,Tokyo\nBob,10,osaka\ncarol,100,tokyo\ndave,2,Nagoya\n",
+        )
+        .unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_query_numeric_sort() {
+        let path = write_sample("taurin_csv_query_sort.csv");
+        let options = CsvQueryOptions {
+            sort_column: Some(1),
+            ..Default::default()
+        };
+        let data = query_csv(&path, options).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let scores: Vec<&str> = data.rows.iter().map(|r| r[1].as_str()).collect();
+        assert_eq!(scores, vec!["2", "9", "10", "100"]);
+    }
+
+    #[test]
+    fn test_compare_cells_non_finite_sort_as_text() {
+        let mut cells = vec!["NaN", "3", "inf", "-1.5", "abc", "nan", "-infinity", "10"];
+        cells.sort_by(|a, b| compare_cells(a, b));
+        assert_eq!(
+            cells,
+            vec!["-1.5", "3", "10", "-infinity", "abc", "inf", "NaN", "nan"]
+        );
+        assert_eq!(compare_cells("NaN", "NaN"), Ordering::Equal);
+        assert_eq!(compare_cells("1", "NaN"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_query_case_insensitive_sort_desc() {
+        let path = write_sample("taurin_csv_query_sort_desc.csv");
+        let options = CsvQueryOptions {
+            sort_column: Some(0),
+            sort_desc: true,
+            ..Default::default()
+        };
+        let data = query_csv(&path, options).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let names: Vec<&str> = data.rows.iter().map(|r| r[0].as_str()).collect();
+        assert_eq!(names, vec!["dave", "carol", "Bob", "alice"]);
+    }
+
+    #[test]
+    fn test_query_filter_and_paging() {
+        let path = write_sample("taurin_csv_query_filter.csv");
+        let options = CsvQueryOptions {
+            sort_column: Some(1),
+            filter_column: Some(2),
+            filter_text: Some("TOKYO".to_string()),
+            offset: 1,
+            limit: 1,
+            ..Default::default()
+        };
+        let data = query_csv(&path, options).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(data.total_rows, 4);
+        assert_eq!(data.filtered_rows, 2);
+        assert_eq!(data.rows.len(), 1);
+        assert_eq!(data.rows[0][0], "carol");
+    }
+
+    #[test]
+    fn test_query_invalid_column() {
+        let path = write_sample("taurin_csv_query_invalid.csv");
+        let options = CsvQueryOptions {
+            sort_column: Some(5),
+            ..Default::default()
+        };
+        let result = query_csv(&path, options);
+        let _ = fs::remove_file(&path);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_detect_delimiter() {
+        assert_eq!(detect_delimiter(b"a,b,c\n1;2,3"), b',');
+        assert_eq!(detect_delimiter(b"a\tb\tc\n"), b'\t');
+        assert_eq!(detect_delimiter(b"\"x,y\";b;c\n"), b';');
+        assert_eq!(detect_delimiter(b"single\n"), b',');
+    }
+
+    #[test]
+    fn test_detect_encoding() {
+        assert_eq!(detect_encoding("名前,年齢".as_bytes()), CsvEncoding::Utf8);
+        // "名" is three bytes in UTF-8; a sample ending mid-character is still UTF-8
+        assert_eq!(detect_encoding(&"a,名".as_bytes()[..3]), CsvEncoding::Utf8);
+        let (sjis, _, _) = SHIFT_JIS.encode("名前,年齢");
+        assert_eq!(detect_encoding(&sjis), CsvEncoding::ShiftJis);
+    }
+
+    #[test]
+    fn test_shift_jis_semicolon_file() {
+        let path = std::env::temp_dir().join("taurin_csv_sjis.csv");
+        let (bytes, _, _) = SHIFT_JIS.encode("名前;都市\n太郎;東京\n花子;大阪\n");
+        fs::write(&path, &bytes).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let info = get_csv_info(&path).unwrap();
+        let data = read_csv(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(info.encoding, CsvEncoding::ShiftJis);
+        assert_eq!(info.delimiter, ';');
+        assert_eq!(info.headers, vec!["名前", "都市"]);
+        assert_eq!(data.rows[1], vec!["花子", "大阪"]);
+    }
+
+    #[test]
+    fn test_read_csv_chunk_across_checkpoints() {
+        let path = std::env::temp_dir().join("taurin_csv_chunk.csv");
+        let mut content = "\u{feff}id\tnote\n".to_string();
+        for i in 0..2500 {
+            content.push_str(&format!("{}\t\"line\nbreak {}\"\n", i, i));
+        }
+        fs::write(&path, content).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let info = get_csv_info(&path).unwrap();
+        assert_eq!(info.row_count, 2500);
+        assert_eq!(info.headers, vec!["id", "note"]);
+
+        let chunk = read_csv_chunk(&path, 995, 10).unwrap();
+        assert_eq!(chunk.offset, 995);
+        assert_eq!(chunk.total_rows, 2500);
+        let ids: Vec<&str> = chunk.rows.iter().map(|r| r[0].as_str()).collect();
+        assert_eq!(ids, (995..1005).map(|i| i.to_string()).collect::<Vec<_>>());
+        assert_eq!(chunk.rows[0][1], "line\nbreak 995");
+
+        let tail = read_csv_chunk(&path, 2498, 100).unwrap();
+        assert_eq!(tail.rows.len(), 2);
+        assert!(read_csv_chunk(&path, 5000, 10).unwrap().rows.is_empty());
+
+        // The cached index is rebuilt once the file changes
+        fs::write(&path, "id\tnote\n1\tonly\n").unwrap();
+        let changed = read_csv_chunk(&path, 0, 10).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(changed.total_rows, 1);
+        assert_eq!(changed.rows[0][1], "only");
+    }
+
+    #[test]
+    fn test_analyze_csv_columns() {
+        let path = std::env::temp_dir().join("taurin_csv_stats.csv");
+        fs::write(
+            &path,
+            "id,price,joined,active,city\n\
+             1,10,2024-01-05,true,Tokyo\n\
+             2,,2024/02/10,false,osaka\n\
+             3,20,2024-03-01T09:30:00Z,yes,Tokyo\n\
+             4,NULL,2024-04-01 12:00,no,\n\
+             5,30,2024-05-20,TRUE,Tokyo\n",
+        )
+        .unwrap();
+        let stats = analyze_csv_columns(&path.to_string_lossy()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!((stats.total_rows, stats.analyzed_rows), (5, 5));
+        assert!(!stats.sampled);
+        let types: Vec<ColumnType> = stats.columns.iter().map(|c| c.inferred_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                ColumnType::Number,
+                ColumnType::Number,
+                ColumnType::Date,
+                ColumnType::Boolean,
+                ColumnType::String
+            ]
+        );
+
+        let price = &stats.columns[1];
+        assert_eq!(price.null_count, 2);
+        assert_eq!(price.unique_count, 3);
+        let numeric = price.numeric.as_ref().unwrap();
+        assert_eq!(
+            (numeric.min, numeric.max, numeric.median),
+            (10.0, 30.0, 20.0)
+        );
+        assert!((numeric.mean - 20.0).abs() < 1e-9);
+        assert!((numeric.std_dev - 10.0).abs() < 1e-9);
+
+        let city = &stats.columns[4];
+        assert_eq!(city.null_count, 1);
+        assert_eq!(city.max_length, Some(5));
+        assert_eq!(
+            city.top_values,
+            vec![
+                ValueCount {
+                    value: "Tokyo".to_string(),
+                    count: 3
+                },
+                ValueCount {
+                    value: "osaka".to_string(),
+                    count: 1
+                }
+            ]
+        );
+        assert!(stats.columns[0].top_values.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_csv_columns_sampling() {
+        let path = std::env::temp_dir().join("taurin_csv_stats_sample.csv");
+        let mut content = "n,label\n".to_string();
+        for i in 0..1000 {
+            content.push_str(&format!("{},{}\n", i, if i == 999 { "x" } else { "7" }));
+        }
+        fs::write(&path, content).unwrap();
+        let options = CsvStatsOptions {
+            sample_threshold: 500,
+            sample_size: 100,
+        };
+        let stats = analyze_csv_columns_with(&path.to_string_lossy(), &options).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert!(stats.sampled);
+        assert_eq!(stats.total_rows, 1000);
+        assert_eq!(stats.analyzed_rows, 100);
+        let n = stats.columns[0].numeric.as_ref().unwrap();
+        assert_eq!((n.min, n.max), (0.0, 990.0));
+        // Row 999 was skipped, so the label column still looks numeric
+        assert_eq!(stats.columns[1].inferred_type, ColumnType::Number);
+    }
+
+    #[test]
+    fn test_is_date() {
+        assert!(is_date("2024-02-29"));
+        assert!(is_date("2024/2/3"));
+        assert!(is_date("2024-01-05T10:20:30.5"));
+        assert!(is_date("2024-01-05T10:20:30+09:00"));
+        assert!(!is_date("2023-02-29"));
+        assert!(!is_date("20240101"));
+        assert!(!is_date("Tokyo"));
+    }
+
+    fn cell(row: usize, col: usize, value: &str) -> CellEdit {
+        CellEdit {
+            row,
+            col,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_update_csv_cells_keeps_untouched_rows() {
+        let path = std::env::temp_dir().join("taurin_csv_update_crlf.csv");
+        fs::write(
+            &path,
+            "\u{feff}id,note\r\n1,\"plain\"\r\n2,keep\r\n\r\n3,\"a,b\"",
+        )
+        .unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let result = update_csv_cells(
+            &path,
+            vec![
+                cell(1, 1, "first"),
+                cell(1, 1, "comma, \"quoted\"\nnewline"),
+                cell(2, 0, "three"),
+            ],
+        )
+        .unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        let data = read_csv(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(result.edited_rows, 2);
+        assert_eq!(result.edited_cells, 2);
+        assert_eq!(result.total_rows, 3);
+        assert_eq!(
+            content,
+            "\u{feff}id,note\r\n1,\"plain\"\r\n2,\"comma, \"\"quoted\"\"\nnewline\"\r\n\r\nthree,\"a,b\""
+        );
+        assert_eq!(data.rows[1][1], "comma, \"quoted\"\nnewline");
+        assert_eq!(data.rows[2], vec!["three", "a,b"]);
+    }
+
+    #[test]
+    fn test_update_csv_cells_lf_and_shift_jis() {
+        let path = std::env::temp_dir().join("taurin_csv_update_sjis.csv");
+        let (bytes, _, _) = SHIFT_JIS.encode("名前;都市\n太郎;東京\n花子;大阪\n");
+        fs::write(&path, &bytes).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        update_csv_cells(&path, vec![cell(0, 1, "名古屋")]).unwrap();
+        let written = fs::read(&path).unwrap();
+        let data = read_csv(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let (expected, _, _) = SHIFT_JIS.encode("名前;都市\n太郎;名古屋\n花子;大阪\n");
+        assert_eq!(written, expected.into_owned());
+        assert_eq!(data.rows[0], vec!["太郎", "名古屋"]);
+    }
+
+    #[test]
+    fn test_update_csv_cells_out_of_range() {
+        let path = write_sample("taurin_csv_update_range.csv");
+        let row_error = update_csv_cells(&path, vec![cell(4, 0, "x")]).unwrap_err();
+        let col_error = update_csv_cells(&path, vec![cell(0, 3, "x")]).unwrap_err();
+        let unchanged = read_csv(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert!(row_error.contains("Row 4"));
+        assert!(col_error.contains("Column 3"));
+        assert_eq!(unchanged.rows[0], vec!["alice", "9", "Tokyo"]);
+        assert!(!Path::new(&format!("{}.part", path)).exists());
+    }
+}
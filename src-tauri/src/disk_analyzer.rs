@@ -0,0 +1,298 @@
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use walkdir::WalkDir;
+
+/// Emit a progress event every this many files
+const PROGRESS_INTERVAL: u64 = 1000;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirNode {
+    pub name: String,
+    pub path: String,
+    /// Total size of every file below this directory
+    pub size: u64,
+    pub file_count: u64,
+    /// Number of directories below this one, at any depth
+    pub dir_count: u64,
+    /// Subdirectories up to the requested depth, largest first
+    pub children: Vec<DirNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LargeFile {
+    pub path: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirSizeResult {
+    pub success: bool,
+    pub root: Option<DirNode>,
+    pub largest_files: Vec<LargeFile>,
+    /// Entries skipped because they could not be read, usually for lack of permission
+    pub error_count: usize,
+    pub error: Option<String>,
+}
+
+/// Payload of the `disk-scan-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskScanProgress {
+    pub scanned_files: u64,
+    pub scanned_bytes: u64,
+    pub current_path: String,
+}
+
+struct Scanner<'a, F> {
+    max_depth: u32,
+    top_files: usize,
+    files: AtomicU64,
+    bytes: AtomicU64,
+    errors: AtomicUsize,
+    on_progress: &'a F,
+}
+
+/// Keep the `limit` largest files, trimming only once the list has doubled
+fn keep_largest(files: &mut Vec<LargeFile>, limit: usize, force: bool) {
+    if force || files.len() > limit * 2 {
+        files.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+        files.truncate(limit);
+    }
+}
+
+impl<F> Scanner<'_, F>
+where
+    F: Fn(DiskScanProgress) + Sync,
+{
+    fn error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_file(
+        &self,
+        path: &Path,
+        size: u64,
+        node: &mut DirNode,
+        largest: &mut Vec<LargeFile>,
+    ) {
+        node.size += size;
+        node.file_count += 1;
+        let bytes = self.bytes.fetch_add(size, Ordering::Relaxed) + size;
+        let files = self.files.fetch_add(1, Ordering::Relaxed) + 1;
+        if files.is_multiple_of(PROGRESS_INTERVAL) {
+            (self.on_progress)(DiskScanProgress {
+                scanned_files: files,
+                scanned_bytes: bytes,
+                current_path: path.to_string_lossy().to_string(),
+            });
+        }
+        if self.top_files > 0 {
+            largest.push(LargeFile {
+                path: path.to_string_lossy().to_string(),
+                size,
+            });
+            keep_largest(largest, self.top_files, false);
+        }
+    }
+
+    /// Total everything below `path` without building child nodes
+    fn total_subtree(&self, path: &Path, node: &mut DirNode, largest: &mut Vec<LargeFile>) {
+        for entry in WalkDir::new(path).min_depth(1).follow_links(false) {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) => {
+                    self.error();
+                    continue;
+                }
+            };
+            let file_type = entry.file_type();
+            if file_type.is_dir() {
+                node.dir_count += 1;
+            } else if file_type.is_file() {
+                match entry.metadata() {
+                    Ok(metadata) => self.record_file(entry.path(), metadata.len(), node, largest),
+                    Err(_) => self.error(),
+                }
+            }
+        }
+    }
+
+    fn scan_dir(&self, path: &Path, depth: u32) -> (DirNode, Vec<LargeFile>) {
+        let mut node = DirNode {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string_lossy().to_string()),
+            path: path.to_string_lossy().to_string(),
+            ..DirNode::default()
+        };
+        let mut largest = Vec::new();
+
+        if depth >= self.max_depth {
+            self.total_subtree(path, &mut node, &mut largest);
+            return (node, largest);
+        }
+
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(_) => {
+                self.error();
+                return (node, largest);
+            }
+        };
+        let mut subdirs: Vec<PathBuf> = Vec::new();
+        for entry in entries {
+            let Ok(entry) = entry else {
+                self.error();
+                continue;
+            };
+            // DirEntry::file_type does not follow symlinks, so links are neither files nor dirs here
+            match entry.file_type() {
+                Ok(file_type) if file_type.is_dir() => subdirs.push(entry.path()),
+                Ok(file_type) if file_type.is_file() => match entry.metadata() {
+                    Ok(metadata) => {
+                        self.record_file(&entry.path(), metadata.len(), &mut node, &mut largest)
+                    }
+                    Err(_) => self.error(),
+                },
+                Ok(_) => {}
+                Err(_) => self.error(),
+            }
+        }
+
+        let children: Vec<(DirNode, Vec<LargeFile>)> = subdirs
+            .par_iter()
+            .map(|dir| self.scan_dir(dir, depth + 1))
+            .collect();
+        for (child, child_largest) in children {
+            node.size += child.size;
+            node.file_count += child.file_count;
+            node.dir_count += child.dir_count + 1;
+            node.children.push(child);
+            largest.extend(child_largest);
+            keep_largest(&mut largest, self.top_files, false);
+        }
+        node.children
+            .sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+        (node, largest)
+    }
+}
+
+/// Total the size of `path` and return it as a tree of directories `max_depth` levels deep.
+///
+/// Subdirectories are scanned in parallel and anything deeper than `max_depth` is folded
+/// into its ancestor. Symbolic links are not followed, and unreadable entries are counted
+/// in `error_count` instead of stopping the scan.
+pub fn analyze_directory<F>(
+    path: &str,
+    max_depth: u32,
+    top_files: usize,
+    on_progress: F,
+) -> DirSizeResult
+where
+    F: Fn(DiskScanProgress) + Sync,
+{
+    let root = Path::new(path);
+    if !root.is_dir() {
+        return DirSizeResult {
+            error: Some(format!("Folder not found: {}", path)),
+            ..DirSizeResult::default()
+        };
+    }
+
+    let scanner = Scanner {
+        max_depth,
+        top_files,
+        files: AtomicU64::new(0),
+        bytes: AtomicU64::new(0),
+        errors: AtomicUsize::new(0),
+        on_progress: &on_progress,
+    };
+    let (node, mut largest_files) = scanner.scan_dir(root, 0);
+    keep_largest(&mut largest_files, top_files, true);
+
+    DirSizeResult {
+        success: true,
+        root: Some(node),
+        largest_files,
+        error_count: scanner.errors.load(Ordering::Relaxed),
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn test_dir(name: &str) -> TempDir {
+        let dir = TempDir::new(&format!("disk_analyzer_{}", name));
+        fs::create_dir_all(dir.join("photos/2024/summer")).unwrap();
+        fs::create_dir_all(dir.join("docs")).unwrap();
+        fs::write(dir.join("root.txt"), vec![0u8; 10]).unwrap();
+        fs::write(dir.join("docs/a.md"), vec![0u8; 200]).unwrap();
+        fs::write(dir.join("photos/cover.jpg"), vec![0u8; 300]).unwrap();
+        fs::write(dir.join("photos/2024/b.jpg"), vec![0u8; 400]).unwrap();
+        fs::write(dir.join("photos/2024/summer/c.jpg"), vec![0u8; 500]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_analyze_directory_builds_tree() {
+        let dir = test_dir("tree");
+        let result = analyze_directory(&dir.to_string_lossy(), 1, 2, |_| {});
+        assert!(result.success);
+        assert_eq!(result.error_count, 0);
+
+        let root = result.root.unwrap();
+        assert_eq!(root.size, 1410);
+        assert_eq!(root.file_count, 5);
+        assert_eq!(root.dir_count, 4);
+        // Depth 1 lists the top-level folders, largest first, without their subfolders
+        let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["photos", "docs"]);
+        assert_eq!(root.children[0].size, 1200);
+        assert_eq!(root.children[0].dir_count, 2);
+        assert!(root.children[0].children.is_empty());
+
+        let largest: Vec<u64> = result.largest_files.iter().map(|f| f.size).collect();
+        assert_eq!(largest, vec![500, 400]);
+        assert!(result.largest_files[0].path.ends_with("c.jpg"));
+
+        let deep = analyze_directory(&dir.to_string_lossy(), 3, 0, |_| {});
+        let photos = &deep.root.unwrap().children[0];
+        assert_eq!(photos.children[0].name, "2024");
+        assert_eq!(photos.children[0].children[0].size, 500);
+        assert!(deep.largest_files.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_analyze_directory_skips_symlinks() {
+        let dir = test_dir("symlinks");
+        std::os::unix::fs::symlink(dir.join("photos"), dir.join("docs/photos_link")).unwrap();
+        std::os::unix::fs::symlink(dir.join("root.txt"), dir.join("link.txt")).unwrap();
+
+        for depth in [0, 2] {
+            let root = analyze_directory(&dir.to_string_lossy(), depth, 5, |_| {})
+                .root
+                .unwrap();
+            assert_eq!(root.size, 1410);
+            assert_eq!(root.file_count, 5);
+        }
+    }
+
+    #[test]
+    fn test_analyze_directory_missing_folder() {
+        let result = analyze_directory("/nonexistent/taurin_disk_analyzer", 2, 10, |_| {});
+        assert!(!result.success);
+        assert!(result.root.is_none());
+        assert!(result.error.is_some());
+    }
+}
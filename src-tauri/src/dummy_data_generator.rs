@@ -0,0 +1,963 @@
+use chrono::{Duration, NaiveDate};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const MAX_RECORDS: u32 = 100_000;
+const MAX_PARAGRAPHS: u32 = 100;
+const MAX_WORDS_PER_PARAGRAPH: u32 = 1000;
+const DEFAULT_INTEGER_RANGE: (i64, i64) = (0, 100);
+const DEFAULT_DATE_FROM: &str = "2000-01-01";
+const DEFAULT_DATE_TO: &str = "2025-12-31";
+const DEFAULT_TABLE_NAME: &str = "dummy_data";
+/// Random seeds stay within JavaScript's safe integer range so the UI can show and replay them
+pub(crate) const MAX_RANDOM_SEED: u64 = (1 << 53) - 1;
+
+const LOREM_OPENING: [&str; 8] = [
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+];
+
+const LOREM_WORDS: [&str; 64] = [
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "in",
+    "reprehenderit",
+    "voluptate",
+    "velit",
+    "esse",
+    "cillum",
+    "fugiat",
+    "nulla",
+    "pariatur",
+    "excepteur",
+    "sint",
+    "occaecat",
+    "cupidatat",
+    "non",
+    "proident",
+    "sunt",
+    "culpa",
+    "qui",
+    "officia",
+    "deserunt",
+    "mollit",
+    "anim",
+    "id",
+    "est",
+    "laborum",
+    "integer",
+    "vitae",
+];
+
+/// Opening of 宮沢賢治「ポラーノの広場」, split into phrases
+const JAPANESE_OPENING: [&str; 12] = [
+    "あのイーハトーヴォの",
+    "すきとおった風、",
+    "夏でも",
+    "底に",
+    "冷たさをもつ",
+    "青いそら、",
+    "うつくしい",
+    "森で",
+    "飾られた",
+    "モリーオ市、",
+    "郊外の",
+    "ぎらぎらひかる草の波。",
+];
+
+const JAPANESE_PHRASES: [&str; 40] = [
+    "あのイーハトーヴォの",
+    "すきとおった風",
+    "夏でも",
+    "底に",
+    "冷たさをもつ",
+    "青いそら",
+    "うつくしい森で",
+    "飾られた",
+    "モリーオ市",
+    "郊外の",
+    "ぎらぎらひかる",
+    "草の波",
+    "またそのなかで",
+    "いっしょになった",
+    "たくさんのひとたち",
+    "羊飼のミーロや",
+    "顔の赤いこどもたち",
+    "つめくさの花の",
+    "あかりをたよりに",
+    "ひとりで",
+    "野原を",
+    "あるいて行くと",
+    "遠くで",
+    "ぼんやりと",
+    "かすかな楽隊の音が",
+    "きこえてきます",
+    "いまこの暗い",
+    "巨きな石の建物のなかで",
+    "考えていると",
+    "みんなむかし風の",
+    "なつかしい",
+    "青い幻燈のように",
+    "思われます",
+    "銀河の",
+    "岸に沿って",
+    "汽車は",
+    "しずかに",
+    "走りつづけ",
+    "りんどうの花が",
+    "咲いていました",
+];
+
+const JA_FAMILY_NAMES: [&str; 30] = [
+    "佐藤",
+    "鈴木",
+    "高橋",
+    "田中",
+    "伊藤",
+    "渡辺",
+    "山本",
+    "中村",
+    "小林",
+    "加藤",
+    "吉田",
+    "山田",
+    "佐々木",
+    "山口",
+    "松本",
+    "井上",
+    "木村",
+    "林",
+    "斎藤",
+    "清水",
+    "山崎",
+    "森",
+    "池田",
+    "橋本",
+    "阿部",
+    "石川",
+    "山下",
+    "中島",
+    "石井",
+    "小川",
+];
+
+const JA_GIVEN_NAMES: [&str; 30] = [
+    "翔太",
+    "大輔",
+    "拓也",
+    "健太",
+    "直樹",
+    "蓮",
+    "悠真",
+    "湊",
+    "陽翔",
+    "樹",
+    "隆",
+    "誠",
+    "浩",
+    "修",
+    "亮",
+    "陽菜",
+    "結衣",
+    "美咲",
+    "さくら",
+    "葵",
+    "凛",
+    "芽依",
+    "花子",
+    "愛",
+    "恵",
+    "由美",
+    "真由美",
+    "彩",
+    "優子",
+    "千尋",
+];
+
+const EN_FIRST_NAMES: [&str; 30] = [
+    "James",
+    "Mary",
+    "John",
+    "Patricia",
+    "Robert",
+    "Jennifer",
+    "Michael",
+    "Linda",
+    "William",
+    "Elizabeth",
+    "David",
+    "Barbara",
+    "Richard",
+    "Susan",
+    "Joseph",
+    "Jessica",
+    "Thomas",
+    "Sarah",
+    "Charles",
+    "Karen",
+    "Daniel",
+    "Nancy",
+    "Matthew",
+    "Lisa",
+    "Anthony",
+    "Emily",
+    "Mark",
+    "Olivia",
+    "Steven",
+    "Emma",
+];
+
+const EN_LAST_NAMES: [&str; 30] = [
+    "Smith",
+    "Johnson",
+    "Williams",
+    "Brown",
+    "Jones",
+    "Garcia",
+    "Miller",
+    "Davis",
+    "Rodriguez",
+    "Martinez",
+    "Wilson",
+    "Anderson",
+    "Taylor",
+    "Thomas",
+    "Moore",
+    "Jackson",
+    "Martin",
+    "Lee",
+    "Thompson",
+    "White",
+    "Harris",
+    "Clark",
+    "Lewis",
+    "Walker",
+    "Hall",
+    "Allen",
+    "Young",
+    "King",
+    "Wright",
+    "Scott",
+];
+
+/// Reserved for documentation by RFC 2606, so generated addresses never reach a real inbox
+const EMAIL_DOMAINS: [&str; 3] = ["example.com", "example.net", "example.org"];
+
+const JA_CITIES: [(&str, &str); 15] = [
+    ("東京都", "千代田区"),
+    ("東京都", "新宿区"),
+    ("東京都", "世田谷区"),
+    ("神奈川県", "横浜市中区"),
+    ("大阪府", "大阪市北区"),
+    ("愛知県", "名古屋市中村区"),
+    ("北海道", "札幌市中央区"),
+    ("福岡県", "福岡市博多区"),
+    ("京都府", "京都市左京区"),
+    ("兵庫県", "神戸市中央区"),
+    ("宮城県", "仙台市青葉区"),
+    ("広島県", "広島市中区"),
+    ("岩手県", "花巻市"),
+    ("静岡県", "静岡市葵区"),
+    ("沖縄県", "那覇市"),
+];
+
+const JA_TOWNS: [&str; 12] = [
+    "本町",
+    "中央",
+    "緑町",
+    "桜台",
+    "旭町",
+    "栄町",
+    "港町",
+    "若葉",
+    "青葉台",
+    "東町",
+    "西町",
+    "南町",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum DummyFieldKind {
+    NameJa,
+    NameEn,
+    Email,
+    Phone,
+    Address,
+    /// A date between `dateFrom` and `dateTo`
+    Date,
+    /// An integer between `min` and `max`
+    Integer,
+    Uuid,
+    Boolean,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DummyFieldSpec {
+    pub name: String,
+    pub kind: DummyFieldKind,
+    #[serde(default)]
+    pub min: Option<i64>,
+    #[serde(default)]
+    pub max: Option<i64>,
+    #[serde(default)]
+    pub date_from: Option<String>,
+    #[serde(default)]
+    pub date_to: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum DummyOutputFormat {
+    Json,
+    Csv,
+    Sql,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoremResult {
+    pub success: bool,
+    pub text: String,
+    pub paragraphs: Vec<String>,
+    pub word_count: usize,
+    pub char_count: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DummyDataResult {
+    pub success: bool,
+    pub output: String,
+    pub record_count: usize,
+    /// Seed actually used; passing it back reproduces the same output
+    pub seed: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl DummyDataResult {
+    fn failure(error: String) -> Self {
+        Self {
+            success: false,
+            output: String::new(),
+            record_count: 0,
+            seed: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum DummyValue {
+    Text(String),
+    Integer(i64),
+    Boolean(bool),
+}
+
+impl DummyValue {
+    fn to_json(&self) -> Value {
+        match self {
+            DummyValue::Text(s) => Value::String(s.clone()),
+            DummyValue::Integer(n) => Value::from(*n),
+            DummyValue::Boolean(b) => Value::Bool(*b),
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        match self {
+            DummyValue::Text(s) => s.clone(),
+            DummyValue::Integer(n) => n.to_string(),
+            DummyValue::Boolean(b) => b.to_string(),
+        }
+    }
+
+    fn to_sql(&self) -> String {
+        match self {
+            DummyValue::Text(s) => format!("'{}'", s.replace('\'', "''")),
+            DummyValue::Integer(n) => n.to_string(),
+            DummyValue::Boolean(b) => if *b { "TRUE" } else { "FALSE" }.to_string(),
+        }
+    }
+}
+
+/// A field spec with its ranges resolved and validated
+enum FieldGenerator {
+    Simple(DummyFieldKind),
+    Integer(i64, i64),
+    Date(NaiveDate, i64),
+}
+
+impl FieldGenerator {
+    fn from_spec(spec: &DummyFieldSpec) -> Result<Self, String> {
+        match spec.kind {
+            DummyFieldKind::Integer => {
+                let min = spec.min.unwrap_or(DEFAULT_INTEGER_RANGE.0);
+                let max = spec.max.unwrap_or(DEFAULT_INTEGER_RANGE.1);
+                if min > max {
+                    return Err(format!(
+                        "フィールド「{}」の最小値({})が最大値({})より大きいです",
+                        spec.name, min, max
+                    ));
+                }
+                Ok(FieldGenerator::Integer(min, max))
+            }
+            DummyFieldKind::Date => {
+                let parse = |value: Option<&str>, default: &str| {
+                    let value = value.filter(|v| !v.trim().is_empty()).unwrap_or(default);
+                    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
+                        format!(
+                            "フィールド「{}」の日付「{}」を解釈できません (YYYY-MM-DD形式で入力してください)",
+                            spec.name, value
+                        )
+                    })
+                };
+                let from = parse(spec.date_from.as_deref(), DEFAULT_DATE_FROM)?;
+                let to = parse(spec.date_to.as_deref(), DEFAULT_DATE_TO)?;
+                if from > to {
+                    return Err(format!(
+                        "フィールド「{}」の開始日が終了日より後になっています",
+                        spec.name
+                    ));
+                }
+                Ok(FieldGenerator::Date(from, (to - from).num_days()))
+            }
+            kind => Ok(FieldGenerator::Simple(kind)),
+        }
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> DummyValue {
+        match self {
+            FieldGenerator::Integer(min, max) => DummyValue::Integer(rng.gen_range(*min..=*max)),
+            FieldGenerator::Date(from, days) => {
+                let date = *from + Duration::days(rng.gen_range(0..=*days));
+                DummyValue::Text(date.format("%Y-%m-%d").to_string())
+            }
+            FieldGenerator::Simple(kind) => generate_simple(*kind, rng),
+        }
+    }
+}
+
+fn pick<'a>(rng: &mut StdRng, items: &[&'a str]) -> &'a str {
+    items.choose(rng).copied().unwrap_or_default()
+}
+
+fn generate_simple(kind: DummyFieldKind, rng: &mut StdRng) -> DummyValue {
+    let text = match kind {
+        DummyFieldKind::NameJa => format!(
+            "{} {}",
+            pick(rng, &JA_FAMILY_NAMES),
+            pick(rng, &JA_GIVEN_NAMES)
+        ),
+        DummyFieldKind::NameEn => format!(
+            "{} {}",
+            pick(rng, &EN_FIRST_NAMES),
+            pick(rng, &EN_LAST_NAMES)
+        ),
+        DummyFieldKind::Email => format!(
+            "{}.{}{}@{}",
+            pick(rng, &EN_FIRST_NAMES).to_lowercase(),
+            pick(rng, &EN_LAST_NAMES).to_lowercase(),
+            rng.gen_range(1..1000),
+            pick(rng, &EMAIL_DOMAINS)
+        ),
+        DummyFieldKind::Phone => format!(
+            "0{}0-{:04}-{:04}",
+            rng.gen_range(7..=9),
+            rng.gen_range(0..10000),
+            rng.gen_range(0..10000)
+        ),
+        DummyFieldKind::Address => {
+            let (prefecture, city) = *JA_CITIES.choose(rng).unwrap_or(&JA_CITIES[0]);
+            format!(
+                "〒{:03}-{:04} {}{}{}{}-{}-{}",
+                rng.gen_range(0..1000),
+                rng.gen_range(0..10000),
+                prefecture,
+                city,
+                pick(rng, &JA_TOWNS),
+                rng.gen_range(1..=9),
+                rng.gen_range(1..=30),
+                rng.gen_range(1..=20)
+            )
+        }
+        DummyFieldKind::Uuid => uuid::Builder::from_random_bytes(rng.gen())
+            .into_uuid()
+            .to_string(),
+        DummyFieldKind::Boolean => return DummyValue::Boolean(rng.gen()),
+        DummyFieldKind::Integer | DummyFieldKind::Date => unreachable!("handled by FieldGenerator"),
+    };
+    DummyValue::Text(text)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn english_paragraph(rng: &mut StdRng, words: usize, first: bool) -> String {
+    let mut tokens: Vec<&str> = Vec::with_capacity(words);
+    if first {
+        tokens.extend(LOREM_OPENING.iter().take(words));
+    }
+    while tokens.len() < words {
+        tokens.push(pick(rng, &LOREM_WORDS));
+    }
+
+    let mut paragraph = String::new();
+    let mut sentence_left = 0;
+    for (i, word) in tokens.iter().enumerate() {
+        if sentence_left == 0 {
+            if i > 0 {
+                paragraph.push_str(". ");
+            }
+            paragraph.push_str(&capitalize(word));
+            let min = if first && i == 0 {
+                LOREM_OPENING.len()
+            } else {
+                6
+            };
+            sentence_left = rng.gen_range(min..=14);
+        } else {
+            // Keep the classic opening intact: "Lorem ipsum dolor sit amet, consectetur ..."
+            let comma = if first && i < LOREM_OPENING.len() {
+                i == 5
+            } else {
+                sentence_left > 2 && rng.gen_ratio(1, 8)
+            };
+            paragraph.push_str(if comma { ", " } else { " " });
+            paragraph.push_str(word);
+        }
+        sentence_left -= 1;
+    }
+    paragraph.push('.');
+    paragraph
+}
+
+fn japanese_paragraph(rng: &mut StdRng, phrases: usize, first: bool) -> String {
+    let mut paragraph = String::new();
+    let mut count = 0;
+    if first {
+        for phrase in JAPANESE_OPENING.iter().take(phrases) {
+            paragraph.push_str(phrase);
+            count += 1;
+        }
+    }
+
+    let mut sentence_left = rng.gen_range(4..=9);
+    while count < phrases {
+        paragraph.push_str(pick(rng, &JAPANESE_PHRASES));
+        count += 1;
+        sentence_left -= 1;
+        if sentence_left == 0 || count == phrases {
+            paragraph.push('。');
+            sentence_left = rng.gen_range(4..=9);
+        } else if rng.gen_ratio(1, 5) {
+            paragraph.push('、');
+        }
+    }
+    if !paragraph.ends_with('。') {
+        paragraph = paragraph.trim_end_matches('、').to_string();
+        paragraph.push('。');
+    }
+    paragraph
+}
+
+/// Generate placeholder paragraphs. For Japanese, `words_per_paragraph` counts phrases (文節).
+pub fn generate_lorem(paragraphs: u32, words_per_paragraph: u32, japanese: bool) -> LoremResult {
+    let paragraph_count = paragraphs.clamp(1, MAX_PARAGRAPHS) as usize;
+    let words = words_per_paragraph.clamp(1, MAX_WORDS_PER_PARAGRAPH) as usize;
+    let mut rng = StdRng::from_entropy();
+
+    let paragraphs: Vec<String> = (0..paragraph_count)
+        .map(|i| {
+            if japanese {
+                japanese_paragraph(&mut rng, words, i == 0)
+            } else {
+                english_paragraph(&mut rng, words, i == 0)
+            }
+        })
+        .collect();
+
+    let separator = if japanese { "\n" } else { "\n\n" };
+    let text = paragraphs.join(separator);
+    LoremResult {
+        success: true,
+        word_count: words * paragraph_count,
+        char_count: text.chars().count(),
+        text,
+        paragraphs,
+        error: None,
+    }
+}
+
+fn sql_identifier(name: &str) -> String {
+    let plain = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name.chars().next().is_some_and(|c| !c.is_ascii_digit());
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+fn render_json(fields: &[DummyFieldSpec], rows: &[Vec<DummyValue>]) -> Result<String, String> {
+    let records: Vec<Value> = rows
+        .iter()
+        .map(|row| {
+            let object: Map<String, Value> = fields
+                .iter()
+                .zip(row)
+                .map(|(field, value)| (field.name.clone(), value.to_json()))
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+    serde_json::to_string_pretty(&records).map_err(|e| format!("JSONの生成に失敗しました: {}", e))
+}
+
+fn render_csv(fields: &[DummyFieldSpec], rows: &[Vec<DummyValue>]) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(fields.iter().map(|f| f.name.as_str()))
+        .map_err(|e| format!("CSVヘッダーの書き込みに失敗しました: {}", e))?;
+    for row in rows {
+        writer
+            .write_record(row.iter().map(DummyValue::to_csv))
+            .map_err(|e| format!("CSV行の書き込みに失敗しました: {}", e))?;
+    }
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("CSVの書き込みに失敗しました: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("CSVの書き込みに失敗しました: {}", e))
+}
+
+fn render_sql(table_name: &str, fields: &[DummyFieldSpec], rows: &[Vec<DummyValue>]) -> String {
+    let table = sql_identifier(table_name);
+    let columns = fields
+        .iter()
+        .map(|f| sql_identifier(&f.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    rows.iter()
+        .map(|row| {
+            let values = row
+                .iter()
+                .map(DummyValue::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("INSERT INTO {} ({}) VALUES ({});", table, columns, values)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Generate `count` records. The same `seed` and field specs always produce the same output.
+pub fn generate_dummy_records(
+    count: u32,
+    fields: Vec<DummyFieldSpec>,
+    format: DummyOutputFormat,
+    seed: Option<u64>,
+    table_name: Option<&str>,
+) -> DummyDataResult {
+    if count == 0 || count > MAX_RECORDS {
+        return DummyDataResult::failure(format!("件数は1〜{}件で指定してください", MAX_RECORDS));
+    }
+    if fields.is_empty() {
+        return DummyDataResult::failure("フィールドを1つ以上追加してください".to_string());
+    }
+    for (i, field) in fields.iter().enumerate() {
+        if field.name.trim().is_empty() {
+            return DummyDataResult::failure(format!("{}番目のフィールド名が空です", i + 1));
+        }
+        if fields[..i].iter().any(|f| f.name == field.name) {
+            return DummyDataResult::failure(format!(
+                "フィールド名「{}」が重複しています",
+                field.name
+            ));
+        }
+    }
+
+    let generators = match fields
+        .iter()
+        .map(FieldGenerator::from_spec)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(generators) => generators,
+        Err(e) => return DummyDataResult::failure(e),
+    };
+
+    let seed = seed.unwrap_or_else(|| rand::thread_rng().gen_range(0..=MAX_RANDOM_SEED));
+    let mut rng = StdRng::seed_from_u64(seed);
+    let rows: Vec<Vec<DummyValue>> = (0..count)
+        .map(|_| generators.iter().map(|g| g.generate(&mut rng)).collect())
+        .collect();
+
+    let output = match format {
+        DummyOutputFormat::Json => render_json(&fields, &rows),
+        DummyOutputFormat::Csv => render_csv(&fields, &rows),
+        DummyOutputFormat::Sql => Ok(render_sql(
+            table_name
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .unwrap_or(DEFAULT_TABLE_NAME),
+            &fields,
+            &rows,
+        )),
+    };
+
+    match output {
+        Ok(output) => DummyDataResult {
+            success: true,
+            output,
+            record_count: rows.len(),
+            seed: Some(seed),
+            error: None,
+        },
+        Err(e) => DummyDataResult::failure(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, kind: DummyFieldKind) -> DummyFieldSpec {
+        DummyFieldSpec {
+            name: name.to_string(),
+            kind,
+            min: None,
+            max: None,
+            date_from: None,
+            date_to: None,
+        }
+    }
+
+    fn all_fields() -> Vec<DummyFieldSpec> {
+        vec![
+            field("id", DummyFieldKind::Uuid),
+            field("name", DummyFieldKind::NameJa),
+            field("name_en", DummyFieldKind::NameEn),
+            field("email", DummyFieldKind::Email),
+            field("phone", DummyFieldKind::Phone),
+            field("address", DummyFieldKind::Address),
+            field("birthday", DummyFieldKind::Date),
+            field("age", DummyFieldKind::Integer),
+            field("active", DummyFieldKind::Boolean),
+        ]
+    }
+
+    #[test]
+    fn test_generate_lorem_english() {
+        let result = generate_lorem(3, 20, false);
+        assert!(result.success);
+        assert_eq!(result.paragraphs.len(), 3);
+        assert!(result.paragraphs[0].starts_with("Lorem ipsum dolor sit amet, consectetur"));
+        assert!(result
+            .paragraphs
+            .iter()
+            .all(|p| p.split_whitespace().count() == 20 && p.ends_with('.')));
+        assert_eq!(result.word_count, 60);
+    }
+
+    #[test]
+    fn test_generate_lorem_japanese() {
+        let result = generate_lorem(2, 30, true);
+        assert!(result.paragraphs[0].starts_with("あのイーハトーヴォのすきとおった風、"));
+        assert!(result.paragraphs.iter().all(|p| p.ends_with('。')));
+        assert_eq!(result.text.lines().count(), 2);
+
+        let short = generate_lorem(1, 2, true);
+        assert_eq!(short.text, "あのイーハトーヴォのすきとおった風。");
+    }
+
+    #[test]
+    fn test_generate_lorem_clamps() {
+        let result = generate_lorem(0, 0, false);
+        assert_eq!(result.paragraphs, vec!["Lorem.".to_string()]);
+    }
+
+    #[test]
+    fn test_seed_is_reproducible() {
+        let a = generate_dummy_records(50, all_fields(), DummyOutputFormat::Json, Some(42), None);
+        let b = generate_dummy_records(50, all_fields(), DummyOutputFormat::Json, Some(42), None);
+        let c = generate_dummy_records(50, all_fields(), DummyOutputFormat::Json, Some(43), None);
+        assert!(a.success);
+        assert_eq!(a.output, b.output);
+        assert_ne!(a.output, c.output);
+        assert_eq!(a.seed, Some(42));
+
+        let random = generate_dummy_records(5, all_fields(), DummyOutputFormat::Csv, None, None);
+        let replay =
+            generate_dummy_records(5, all_fields(), DummyOutputFormat::Csv, random.seed, None);
+        assert_eq!(random.output, replay.output);
+    }
+
+    #[test]
+    fn test_json_output_types_and_ranges() {
+        let mut fields = all_fields();
+        fields[6].date_from = Some("2024-02-28".to_string());
+        fields[6].date_to = Some("2024-03-01".to_string());
+        fields[7].min = Some(-5);
+        fields[7].max = Some(5);
+
+        let result = generate_dummy_records(200, fields, DummyOutputFormat::Json, Some(1), None);
+        let records: Vec<Value> = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(records.len(), 200);
+        assert_eq!(result.record_count, 200);
+        for record in &records {
+            let keys: Vec<&String> = record.as_object().unwrap().keys().collect();
+            assert_eq!(keys[0], "id");
+            assert!(uuid::Uuid::parse_str(record["id"].as_str().unwrap()).is_ok());
+            assert!(record["email"].as_str().unwrap().contains("@example."));
+            assert!(["2024-02-28", "2024-02-29", "2024-03-01"]
+                .contains(&record["birthday"].as_str().unwrap()));
+            let age = record["age"].as_i64().unwrap();
+            assert!((-5..=5).contains(&age));
+            assert!(record["active"].is_boolean());
+            assert!(record["address"].as_str().unwrap().starts_with('〒'));
+        }
+    }
+
+    #[test]
+    fn test_csv_output() {
+        let result = generate_dummy_records(
+            3,
+            vec![
+                field("name", DummyFieldKind::NameEn),
+                field("ok", DummyFieldKind::Boolean),
+            ],
+            DummyOutputFormat::Csv,
+            Some(7),
+            None,
+        );
+        let lines: Vec<&str> = result.output.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "name,ok");
+        assert!(lines[1].ends_with(",true") || lines[1].ends_with(",false"));
+    }
+
+    #[test]
+    fn test_sql_output() {
+        let result = generate_dummy_records(
+            2,
+            vec![
+                field("user name", DummyFieldKind::NameJa),
+                field("age", DummyFieldKind::Integer),
+            ],
+            DummyOutputFormat::Sql,
+            Some(7),
+            Some("users"),
+        );
+        let lines: Vec<&str> = result.output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("INSERT INTO users (\"user name\", age) VALUES ('"));
+        assert!(lines[0].ends_with(");"));
+
+        assert_eq!(
+            DummyValue::Text("O'Brien".to_string()).to_sql(),
+            "'O''Brien'"
+        );
+
+        let default_table = generate_dummy_records(
+            1,
+            vec![field("id", DummyFieldKind::Uuid)],
+            DummyOutputFormat::Sql,
+            None,
+            Some("  "),
+        );
+        assert!(default_table
+            .output
+            .starts_with("INSERT INTO dummy_data (id)"));
+    }
+
+    #[test]
+    fn test_invalid_requests() {
+        let fields = vec![field("id", DummyFieldKind::Uuid)];
+        assert!(
+            !generate_dummy_records(0, fields.clone(), DummyOutputFormat::Json, None, None).success
+        );
+        assert!(
+            !generate_dummy_records(MAX_RECORDS + 1, fields, DummyOutputFormat::Json, None, None)
+                .success
+        );
+        assert!(!generate_dummy_records(1, vec![], DummyOutputFormat::Json, None, None).success);
+
+        let duplicate = vec![
+            field("a", DummyFieldKind::Uuid),
+            field("a", DummyFieldKind::Email),
+        ];
+        let result = generate_dummy_records(1, duplicate, DummyOutputFormat::Json, None, None);
+        assert!(result.error.unwrap().contains("重複"));
+
+        let mut range = field("n", DummyFieldKind::Integer);
+        range.min = Some(10);
+        range.max = Some(1);
+        assert!(
+            !generate_dummy_records(1, vec![range], DummyOutputFormat::Json, None, None).success
+        );
+
+        let mut date = field("d", DummyFieldKind::Date);
+        date.date_from = Some("2024/01/01".to_string());
+        let result = generate_dummy_records(1, vec![date], DummyOutputFormat::Json, None, None);
+        assert!(result.error.unwrap().contains("2024/01/01"));
+    }
+
+    #[test]
+    fn test_max_records() {
+        let result = generate_dummy_records(
+            MAX_RECORDS,
+            vec![field("n", DummyFieldKind::Integer)],
+            DummyOutputFormat::Csv,
+            Some(0),
+            None,
+        );
+        assert_eq!(result.record_count, MAX_RECORDS as usize);
+    }
+}
@@ -0,0 +1,471 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
+
+/// Bytes hashed in the quick pass that weeds out most same-size files
+const PARTIAL_HASH_BYTES: u64 = 8 * 1024;
+
+/// Emit a progress event every this many files within a stage
+const PROGRESS_INTERVAL: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SymlinkMode {
+    /// Ignore symbolic links entirely
+    #[default]
+    Skip,
+    /// Treat links as the files and directories they point to
+    Follow,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DuplicateFindOptions {
+    /// Files smaller than this many bytes are ignored
+    pub min_size: u64,
+    /// Extensions to include, without the dot; empty means all files
+    pub extensions: Vec<String>,
+    pub include_hidden: bool,
+    pub symlinks: SymlinkMode,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateFile {
+    pub path: String,
+    pub size: u64,
+    pub modified: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateGroup {
+    /// SHA-256 of the file contents, shared by every file in the group
+    pub hash: String,
+    pub size: u64,
+    pub files: Vec<DuplicateFile>,
+    /// Space freed by keeping only one copy
+    pub reclaimable_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateFindResult {
+    pub success: bool,
+    pub groups: Vec<DuplicateGroup>,
+    pub scanned_files: usize,
+    pub duplicate_files: usize,
+    pub reclaimable_bytes: u64,
+    /// Files and directories that could not be read
+    pub skipped: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScanStage {
+    Scanning,
+    PartialHash,
+    FullHash,
+}
+
+/// Payload of the `duplicate-scan-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateScanProgress {
+    pub stage: ScanStage,
+    pub processed: usize,
+    /// Number of files in the stage; unknown (0) while scanning
+    pub total: usize,
+    pub current_file: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteFailure {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDuplicatesResult {
+    pub success: bool,
+    pub deleted: Vec<String>,
+    pub failed: Vec<DeleteFailure>,
+}
+
+struct Candidate {
+    path: PathBuf,
+    size: u64,
+    modified: Option<String>,
+}
+
+fn is_hidden(entry: &DirEntry) -> bool {
+    entry.file_name().to_string_lossy().starts_with('.')
+}
+
+fn matches_extension(path: &Path, extensions: &[String]) -> bool {
+    if extensions.is_empty() {
+        return true;
+    }
+    path.extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .is_some_and(|ext| {
+            extensions
+                .iter()
+                .any(|wanted| wanted.trim_start_matches('.').to_lowercase() == ext)
+        })
+}
+
+/// Hash the first `limit` bytes of the file, or all of it when `limit` is `None`
+fn hash_file(path: &Path, limit: Option<u64>) -> io::Result<String> {
+    let file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    match limit {
+        Some(limit) => io::copy(&mut file.take(limit), &mut hasher)?,
+        None => io::copy(&mut io::BufReader::new(file), &mut hasher)?,
+    };
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Walk `dirs` and collect the files that pass the filters in `options`
+fn collect_candidates<F>(
+    dirs: &[String],
+    options: &DuplicateFindOptions,
+    skipped: &mut usize,
+    on_progress: &mut F,
+) -> Vec<Candidate>
+where
+    F: FnMut(DuplicateScanProgress),
+{
+    let follow = options.symlinks == SymlinkMode::Follow;
+    // Overlapping folders and followed links can reach the same file twice
+    let mut seen = HashSet::new();
+    let mut candidates = Vec::new();
+
+    for dir in dirs {
+        let walker = WalkDir::new(dir)
+            .follow_links(follow)
+            .into_iter()
+            .filter_entry(|e| options.include_hidden || e.depth() == 0 || !is_hidden(e));
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) => {
+                    *skipped += 1;
+                    continue;
+                }
+            };
+            if !entry.file_type().is_file()
+                || (!follow && entry.path_is_symlink())
+                || !matches_extension(entry.path(), &options.extensions)
+            {
+                continue;
+            }
+            let metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                Err(_) => {
+                    *skipped += 1;
+                    continue;
+                }
+            };
+            if metadata.len() < options.min_size.max(1) {
+                continue;
+            }
+            let key = fs::canonicalize(entry.path()).unwrap_or_else(|_| entry.path().into());
+            if !seen.insert(key) {
+                continue;
+            }
+
+            candidates.push(Candidate {
+                path: entry.path().to_path_buf(),
+                size: metadata.len(),
+                modified: metadata
+                    .modified()
+                    .ok()
+                    .map(|time| DateTime::<Utc>::from(time).to_rfc3339()),
+            });
+            if candidates.len().is_multiple_of(PROGRESS_INTERVAL) {
+                on_progress(DuplicateScanProgress {
+                    stage: ScanStage::Scanning,
+                    processed: candidates.len(),
+                    total: 0,
+                    current_file: entry.path().to_string_lossy().to_string(),
+                });
+            }
+        }
+    }
+    candidates
+}
+
+/// Split every group by the hash of its files, dropping files that end up alone
+fn regroup<F>(
+    groups: Vec<Vec<Candidate>>,
+    stage: ScanStage,
+    limit: Option<u64>,
+    skipped: &mut usize,
+    on_progress: &mut F,
+) -> Vec<(String, Vec<Candidate>)>
+where
+    F: FnMut(DuplicateScanProgress),
+{
+    let total: usize = groups.iter().map(Vec::len).sum();
+    let mut processed: usize = 0;
+    let mut result = Vec::new();
+
+    for group in groups {
+        let mut by_hash: HashMap<String, Vec<Candidate>> = HashMap::new();
+        for candidate in group {
+            processed += 1;
+            if processed.is_multiple_of(PROGRESS_INTERVAL) || processed == total {
+                on_progress(DuplicateScanProgress {
+                    stage,
+                    processed,
+                    total,
+                    current_file: candidate.path.to_string_lossy().to_string(),
+                });
+            }
+            match hash_file(&candidate.path, limit) {
+                Ok(hash) => by_hash.entry(hash).or_default().push(candidate),
+                Err(_) => *skipped += 1,
+            }
+        }
+        result.extend(by_hash.into_iter().filter(|(_, files)| files.len() > 1));
+    }
+    result
+}
+
+/// Find files with identical contents under `dirs`.
+///
+/// Files are grouped by size first, then by a hash of their first 8 KB, and only
+/// files that still match are hashed in full, so most files are never read completely.
+pub fn find_duplicates<F>(
+    dirs: &[String],
+    options: &DuplicateFindOptions,
+    mut on_progress: F,
+) -> DuplicateFindResult
+where
+    F: FnMut(DuplicateScanProgress),
+{
+    if dirs.is_empty() {
+        return DuplicateFindResult {
+            error: Some("No folders selected".to_string()),
+            ..DuplicateFindResult::default()
+        };
+    }
+    if let Some(dir) = dirs.iter().find(|dir| !Path::new(dir).is_dir()) {
+        return DuplicateFindResult {
+            error: Some(format!("Folder not found: {}", dir)),
+            ..DuplicateFindResult::default()
+        };
+    }
+
+    let mut skipped = 0;
+    let candidates = collect_candidates(dirs, options, &mut skipped, &mut on_progress);
+    let scanned_files = candidates.len();
+
+    let mut by_size: HashMap<u64, Vec<Candidate>> = HashMap::new();
+    for candidate in candidates {
+        by_size.entry(candidate.size).or_default().push(candidate);
+    }
+    let same_size: Vec<Vec<Candidate>> = by_size
+        .into_values()
+        .filter(|files| files.len() > 1)
+        .collect();
+
+    let partial = regroup(
+        same_size,
+        ScanStage::PartialHash,
+        Some(PARTIAL_HASH_BYTES),
+        &mut skipped,
+        &mut on_progress,
+    );
+    // Small files were read completely by the partial pass, so its hash is already final
+    let (complete, pending): (Vec<_>, Vec<_>) = partial
+        .into_iter()
+        .partition(|(_, files)| files[0].size <= PARTIAL_HASH_BYTES);
+    let mut matched = complete;
+    matched.extend(regroup(
+        pending.into_iter().map(|(_, files)| files).collect(),
+        ScanStage::FullHash,
+        None,
+        &mut skipped,
+        &mut on_progress,
+    ));
+
+    let mut groups: Vec<DuplicateGroup> = matched
+        .into_iter()
+        .map(|(hash, files)| {
+            let size = files[0].size;
+            let mut files: Vec<DuplicateFile> = files
+                .into_iter()
+                .map(|c| DuplicateFile {
+                    path: c.path.to_string_lossy().to_string(),
+                    size: c.size,
+                    modified: c.modified,
+                })
+                .collect();
+            files.sort_by(|a, b| a.path.cmp(&b.path));
+            DuplicateGroup {
+                hash,
+                size,
+                reclaimable_bytes: size * (files.len() as u64 - 1),
+                files,
+            }
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.reclaimable_bytes
+            .cmp(&a.reclaimable_bytes)
+            .then_with(|| a.files[0].path.cmp(&b.files[0].path))
+    });
+
+    DuplicateFindResult {
+        success: true,
+        scanned_files,
+        duplicate_files: groups.iter().map(|g| g.files.len()).sum(),
+        reclaimable_bytes: groups.iter().map(|g| g.reclaimable_bytes).sum(),
+        groups,
+        skipped,
+        error: None,
+    }
+}
+
+/// Move `paths` to the system trash, one at a time so a failure does not stop the rest
+pub fn delete_duplicate_files(paths: &[String]) -> DeleteDuplicatesResult {
+    let mut result = DeleteDuplicatesResult::default();
+    for path in paths {
+        if !Path::new(path).exists() {
+            result.failed.push(DeleteFailure {
+                path: path.clone(),
+                error: "File not found".to_string(),
+            });
+            continue;
+        }
+        match trash::delete(path) {
+            Ok(()) => result.deleted.push(path.clone()),
+            Err(e) => result.failed.push(DeleteFailure {
+                path: path.clone(),
+                error: e.to_string(),
+            }),
+        }
+    }
+    result.success = result.failed.is_empty();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn scan(dir: &Path, options: &DuplicateFindOptions) -> DuplicateFindResult {
+        find_duplicates(&[dir.to_string_lossy().to_string()], options, |_| {})
+    }
+
+    #[test]
+    fn test_find_duplicates_groups_identical_files() {
+        let dir = TempDir::new("duplicate_finder_groups");
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("a.txt"), "hello").unwrap();
+        fs::write(dir.join("sub/b.txt"), "hello").unwrap();
+        fs::write(dir.join("c.txt"), "world").unwrap();
+        // Same size and same first 8 KB, different tail
+        let mut big = vec![7u8; 20_000];
+        fs::write(dir.join("big1.bin"), &big).unwrap();
+        fs::write(dir.join("big2.bin"), &big).unwrap();
+        big[19_999] = 8;
+        fs::write(dir.join("big3.bin"), &big).unwrap();
+
+        let mut stages = Vec::new();
+        let result = find_duplicates(
+            &[dir.to_string_lossy().to_string()],
+            &DuplicateFindOptions::default(),
+            |p| stages.push(p.stage),
+        );
+        assert!(result.success);
+        assert_eq!(result.scanned_files, 6);
+        assert_eq!(result.groups.len(), 2);
+        assert_eq!(result.groups[0].size, 20_000);
+        assert!(result.groups[0].files[0].path.ends_with("big1.bin"));
+        assert!(result.groups[0].files[1].path.ends_with("big2.bin"));
+        assert_eq!(result.groups[1].files.len(), 2);
+        assert_eq!(result.duplicate_files, 4);
+        assert_eq!(result.reclaimable_bytes, 20_005);
+        assert!(stages.contains(&ScanStage::FullHash));
+    }
+
+    #[test]
+    fn test_find_duplicates_filters() {
+        let dir = TempDir::new("duplicate_finder_filters");
+        fs::create_dir_all(dir.join(".cache")).unwrap();
+        for name in ["a.JPG", "b.jpg", "c.png", "d.png", ".e.jpg", ".cache/f.jpg"] {
+            fs::write(dir.join(name), "same").unwrap();
+        }
+
+        let options = DuplicateFindOptions {
+            extensions: vec![".jpg".to_string()],
+            ..DuplicateFindOptions::default()
+        };
+        let result = scan(&dir, &options);
+        assert_eq!(result.groups.len(), 1);
+        assert_eq!(result.groups[0].files.len(), 2);
+
+        let options = DuplicateFindOptions {
+            include_hidden: true,
+            ..options
+        };
+        assert_eq!(scan(&dir, &options).groups[0].files.len(), 4);
+
+        let options = DuplicateFindOptions {
+            min_size: 5,
+            ..options
+        };
+        assert!(scan(&dir, &options).groups.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_duplicates_symlinks() {
+        let dir = TempDir::new("duplicate_finder_symlinks");
+        let other = TempDir::new("duplicate_finder_symlinks_target");
+        fs::write(dir.join("a.txt"), "data").unwrap();
+        fs::write(other.join("b.txt"), "data").unwrap();
+        std::os::unix::fs::symlink(dir.join("a.txt"), dir.join("link.txt")).unwrap();
+        std::os::unix::fs::symlink(&other, dir.join("linked_dir")).unwrap();
+
+        assert!(scan(&dir, &DuplicateFindOptions::default())
+            .groups
+            .is_empty());
+
+        // The link to a.txt is the same file, so only the linked folder adds a duplicate
+        let options = DuplicateFindOptions {
+            symlinks: SymlinkMode::Follow,
+            ..DuplicateFindOptions::default()
+        };
+        let result = scan(&dir, &options);
+        assert_eq!(result.groups.len(), 1);
+        assert_eq!(result.groups[0].files.len(), 2);
+    }
+
+    #[test]
+    fn test_find_duplicates_missing_folder() {
+        let result = find_duplicates(
+            &["/nonexistent/taurin_duplicate_finder".to_string()],
+            &DuplicateFindOptions::default(),
+            |_| {},
+        );
+        assert!(!result.success);
+        assert!(result.error.is_some());
+
+        let deleted = delete_duplicate_files(&["/nonexistent/taurin_file".to_string()]);
+        assert!(!deleted.success);
+        assert_eq!(deleted.failed.len(), 1);
+    }
+}
@@ -0,0 +1,485 @@
+use chardetng::EncodingDetector;
+use encoding_rs::{EncoderResult, Encoding, EUC_JP, SHIFT_JIS, UTF_16BE, UTF_16LE, UTF_8};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::Read;
+
+/// Bytes read from the start of a file when only detecting its encoding
+const DETECT_SAMPLE_SIZE: u64 = 1024 * 1024;
+/// Characters of decoded text returned with a detection result
+const PREVIEW_CHARS: usize = 500;
+/// Unmappable characters reported individually; the rest are only counted
+const MAX_REPORTED_UNMAPPABLE: usize = 100;
+
+/// Encodings accepted as an explicit source or as a target
+const SUPPORTED_ENCODINGS: [&Encoding; 5] = [UTF_8, SHIFT_JIS, EUC_JP, UTF_16LE, UTF_16BE];
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    #[default]
+    Keep,
+    Lf,
+    Crlf,
+    Cr,
+}
+
+/// What to do with characters the target encoding cannot represent
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnmappableAction {
+    /// Write `?` in their place
+    #[default]
+    Replace,
+    /// Leave the output file unwritten
+    Abort,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodingConvertOptions {
+    pub line_ending: LineEnding,
+    pub on_unmappable: UnmappableAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodingDetectResult {
+    pub encoding: String,
+    /// 0.0 to 1.0; 1.0 when a BOM or valid UTF-8 settles it
+    pub confidence: f32,
+    pub has_bom: bool,
+    /// "LF", "CRLF", "CR", "Mixed" or "None"
+    pub line_ending: String,
+    pub preview: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmappableChar {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    pub character: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodingConvertFileResult {
+    pub success: bool,
+    pub source_encoding: String,
+    pub target_encoding: String,
+    pub bytes_written: usize,
+    pub unmappable_count: usize,
+    /// The first unmappable characters, up to a fixed limit
+    pub unmappable: Vec<UnmappableChar>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+fn resolve_encoding(label: &str) -> Result<&'static Encoding, String> {
+    Encoding::for_label(label.trim().as_bytes())
+        .filter(|encoding| SUPPORTED_ENCODINGS.contains(encoding))
+        .ok_or_else(|| format!("Unsupported encoding: {}", label))
+}
+
+/// Guess UTF-16 without a BOM from where the zero bytes of ASCII characters fall
+fn detect_utf16(bytes: &[u8]) -> Option<&'static Encoding> {
+    let pairs = bytes.len() / 2;
+    if pairs < 2 {
+        return None;
+    }
+    let even_zeros = bytes.iter().step_by(2).filter(|b| **b == 0).count();
+    let odd_zeros = bytes.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+    if odd_zeros * 10 >= pairs * 3 && even_zeros * 20 < pairs {
+        Some(UTF_16LE)
+    } else if even_zeros * 10 >= pairs * 3 && odd_zeros * 20 < pairs {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+/// Whether `bytes` is UTF-8, allowing a character cut off at the end of a sample
+fn is_utf8(bytes: &[u8], truncated: bool) -> bool {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => true,
+        Err(e) => truncated && e.error_len().is_none(),
+    }
+}
+
+/// Encoding of `bytes`, how sure the guess is and whether it starts with a BOM
+pub fn detect_bytes(bytes: &[u8], truncated: bool) -> (&'static Encoding, f32, bool) {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return (encoding, 1.0, true);
+    }
+    if let Some(encoding) = detect_utf16(bytes) {
+        return (encoding, 0.8, false);
+    }
+    if is_utf8(bytes, truncated) {
+        return (UTF_8, 1.0, false);
+    }
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, !truncated);
+    let (encoding, confident) = detector.guess_assess(None, false);
+    (encoding, if confident { 0.9 } else { 0.5 }, false)
+}
+
+fn describe_line_ending(text: &str) -> String {
+    let crlf = text.matches("\r\n").count();
+    let cr = text.matches('\r').count() - crlf;
+    let lf = text.matches('\n').count() - crlf;
+    let kinds = [(crlf, "CRLF"), (lf, "LF"), (cr, "CR")];
+    let mut present = kinds.iter().filter(|(count, _)| *count > 0);
+    match (present.next(), present.next()) {
+        (None, _) => "None",
+        (Some((_, name)), None) => name,
+        _ => "Mixed",
+    }
+    .to_string()
+}
+
+fn convert_line_endings(text: &str, line_ending: LineEnding) -> String {
+    let separator = match line_ending {
+        LineEnding::Keep => return text.to_string(),
+        LineEnding::Lf => "\n",
+        LineEnding::Crlf => "\r\n",
+        LineEnding::Cr => "\r",
+    };
+    text.replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .replace('\n', separator)
+}
+
+/// Tracks line and column while walking forward through `text`
+struct Position<'a> {
+    text: &'a str,
+    scanned: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> Position<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            scanned: 0,
+            line: 1,
+            line_start: 0,
+        }
+    }
+
+    fn at(&mut self, offset: usize) -> (usize, usize) {
+        let bytes = self.text.as_bytes();
+        for i in self.scanned..offset {
+            let is_break =
+                bytes[i] == b'\n' || (bytes[i] == b'\r' && bytes.get(i + 1) != Some(&b'\n'));
+            if is_break {
+                self.line += 1;
+                self.line_start = i + 1;
+            }
+        }
+        self.scanned = offset;
+        (
+            self.line,
+            self.text[self.line_start..offset].chars().count() + 1,
+        )
+    }
+}
+
+/// Encode `text` with a legacy encoding, writing `?` for characters it cannot represent
+fn encode_legacy(
+    text: &str,
+    encoding: &'static Encoding,
+    result: &mut EncodingConvertFileResult,
+) -> Vec<u8> {
+    let mut encoder = encoding.new_encoder();
+    let mut output = Vec::with_capacity(text.len());
+    let mut buffer = [0u8; 8192];
+    let mut offset = 0;
+    let mut position = Position::new(text);
+    loop {
+        let (status, read, written) =
+            encoder.encode_from_utf8_without_replacement(&text[offset..], &mut buffer, true);
+        output.extend_from_slice(&buffer[..written]);
+        offset += read;
+        match status {
+            EncoderResult::InputEmpty => break,
+            EncoderResult::OutputFull => {}
+            EncoderResult::Unmappable(c) => {
+                output.push(b'?');
+                result.unmappable_count += 1;
+                if result.unmappable.len() < MAX_REPORTED_UNMAPPABLE {
+                    let (line, column) = position.at(offset - c.len_utf8());
+                    result.unmappable.push(UnmappableChar {
+                        line,
+                        column,
+                        character: c.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    output
+}
+
+fn encode_text(
+    text: &str,
+    encoding: &'static Encoding,
+    bom: bool,
+    result: &mut EncodingConvertFileResult,
+) -> Vec<u8> {
+    if encoding == UTF_8 {
+        let mut output = if bom {
+            vec![0xEF, 0xBB, 0xBF]
+        } else {
+            Vec::new()
+        };
+        output.extend_from_slice(text.as_bytes());
+        return output;
+    }
+    if encoding == UTF_16LE || encoding == UTF_16BE {
+        let little_endian = encoding == UTF_16LE;
+        let to_bytes = |unit: u16| {
+            if little_endian {
+                unit.to_le_bytes()
+            } else {
+                unit.to_be_bytes()
+            }
+        };
+        let mut output = Vec::with_capacity(text.len() * 2 + 2);
+        if bom {
+            output.extend_from_slice(&to_bytes(0xFEFF));
+        }
+        for unit in text.encode_utf16() {
+            output.extend_from_slice(&to_bytes(unit));
+        }
+        return output;
+    }
+    if bom {
+        result.warnings.push(format!(
+            "{} has no BOM; the file was written without one",
+            encoding.name()
+        ));
+    }
+    encode_legacy(text, encoding, result)
+}
+
+/// Re-encode `bytes` from `from` (detected when `None`) to `to`.
+///
+/// The returned bytes are only meant to be written when the result is successful.
+pub fn convert_bytes(
+    bytes: &[u8],
+    from: Option<&str>,
+    to: &str,
+    bom: bool,
+    options: &EncodingConvertOptions,
+) -> (EncodingConvertFileResult, Vec<u8>) {
+    let mut result = EncodingConvertFileResult::default();
+    let source = match from {
+        Some(label) => resolve_encoding(label),
+        None => Ok(detect_bytes(bytes, false).0),
+    };
+    let (source, target) = match (source, resolve_encoding(to)) {
+        (Ok(source), Ok(target)) => (source, target),
+        (Err(e), _) | (_, Err(e)) => {
+            result.error = Some(e);
+            return (result, Vec::new());
+        }
+    };
+    result.source_encoding = source.name().to_string();
+    result.target_encoding = target.name().to_string();
+
+    let (text, had_errors) = source.decode_with_bom_removal(bytes);
+    if had_errors {
+        result.warnings.push(format!(
+            "The input contains bytes that are not valid {}; they were replaced with U+FFFD",
+            source.name()
+        ));
+    }
+    let text = convert_line_endings(&text, options.line_ending);
+    let output = encode_text(&text, target, bom, &mut result);
+
+    if result.unmappable_count > 0 {
+        let message = format!(
+            "{} character(s) cannot be represented in {}",
+            result.unmappable_count,
+            target.name()
+        );
+        if options.on_unmappable == UnmappableAction::Abort {
+            result.error = Some(message);
+            return (result, Vec::new());
+        }
+        result
+            .warnings
+            .push(format!("{}; they were replaced with '?'", message));
+    }
+    result.success = true;
+    (result, output)
+}
+
+/// Detect the encoding of the file at `path` from its first megabyte
+pub fn detect_encoding(path: &str) -> Result<EncodingDetectResult, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let size = file
+        .metadata()
+        .map_err(|e| format!("Failed to read file: {}", e))?
+        .len();
+    let mut bytes = Vec::new();
+    file.take(DETECT_SAMPLE_SIZE)
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let (encoding, confidence, has_bom) = detect_bytes(&bytes, size > DETECT_SAMPLE_SIZE);
+    let (text, _) = encoding.decode_with_bom_removal(&bytes);
+    Ok(EncodingDetectResult {
+        encoding: encoding.name().to_string(),
+        confidence,
+        has_bom,
+        line_ending: describe_line_ending(&text),
+        preview: text.chars().take(PREVIEW_CHARS).collect(),
+    })
+}
+
+/// Convert the file at `input_path` to `to` and write it to `output_path`.
+///
+/// `from` is detected when `None`. `bom` adds a byte order mark for UTF-8 and UTF-16.
+pub fn convert_file_encoding(
+    input_path: &str,
+    output_path: &str,
+    from: Option<String>,
+    to: String,
+    bom: bool,
+    options: EncodingConvertOptions,
+) -> EncodingConvertFileResult {
+    let bytes = match fs::read(input_path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return EncodingConvertFileResult {
+                error: Some(format!("Failed to read file: {}", e)),
+                ..Default::default()
+            }
+        }
+    };
+    let (mut result, output) = convert_bytes(&bytes, from.as_deref(), &to, bom, &options);
+    if !result.success {
+        return result;
+    }
+    if let Err(e) = fs::write(output_path, &output) {
+        result.success = false;
+        result.error = Some(format!("Failed to write file: {}", e));
+        return result;
+    }
+    result.bytes_written = output.len();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "名前,年齢,都市\r\n山田太郎,30,東京\r\n佐藤花子,25,大阪\r\n";
+
+    #[test]
+    fn test_detect_bytes() {
+        let (sjis, _, _) = SHIFT_JIS.encode(SAMPLE);
+        assert_eq!(detect_bytes(&sjis, false).0, SHIFT_JIS);
+        let (euc, _, _) = EUC_JP.encode(SAMPLE);
+        assert_eq!(detect_bytes(&euc, false).0, EUC_JP);
+        assert_eq!(detect_bytes(SAMPLE.as_bytes(), false), (UTF_8, 1.0, false));
+
+        let mut utf8_bom = vec![0xEF, 0xBB, 0xBF];
+        utf8_bom.extend_from_slice(SAMPLE.as_bytes());
+        assert_eq!(detect_bytes(&utf8_bom, false), (UTF_8, 1.0, true));
+
+        let utf16le: Vec<u8> = "id,name\n1,abc\n"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        assert_eq!(detect_bytes(&utf16le, false).0, UTF_16LE);
+        let utf16be: Vec<u8> = "id,name\n1,abc\n"
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        assert_eq!(detect_bytes(&utf16be, false).0, UTF_16BE);
+
+        // A sample cut in the middle of a character is still UTF-8
+        assert_eq!(detect_bytes(&"a名".as_bytes()[..2], true).0, UTF_8);
+        assert_eq!(describe_line_ending(SAMPLE), "CRLF");
+        assert_eq!(describe_line_ending("a\nb\r\n"), "Mixed");
+    }
+
+    #[test]
+    fn test_convert_bytes_round_trip() {
+        let (sjis, _, _) = SHIFT_JIS.encode(SAMPLE);
+        let options = EncodingConvertOptions {
+            line_ending: LineEnding::Lf,
+            ..Default::default()
+        };
+        let (result, output) = convert_bytes(&sjis, None, "utf-8", true, &options);
+        assert!(result.success);
+        assert_eq!(result.source_encoding, "Shift_JIS");
+        assert_eq!(&output[..3], &[0xEF, 0xBB, 0xBF]);
+        assert_eq!(
+            std::str::from_utf8(&output[3..]).unwrap(),
+            SAMPLE.replace("\r\n", "\n")
+        );
+
+        let (result, utf16) = convert_bytes(
+            SAMPLE.as_bytes(),
+            Some("UTF-8"),
+            "UTF-16BE",
+            true,
+            &EncodingConvertOptions::default(),
+        );
+        assert!(result.success);
+        assert_eq!(&utf16[..2], &[0xFE, 0xFF]);
+        let (result, back) = convert_bytes(
+            &utf16,
+            None,
+            "euc-jp",
+            false,
+            &EncodingConvertOptions::default(),
+        );
+        assert_eq!(result.source_encoding, "UTF-16BE");
+        assert_eq!(back, EUC_JP.encode(SAMPLE).0.into_owned());
+
+        let (result, _) = convert_bytes(b"abc", Some("latin1"), "utf-8", false, &options);
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unsupported"));
+    }
+
+    #[test]
+    fn test_convert_bytes_unmappable() {
+        let text = "abc\n絵文字😀と①\r丸";
+        let (result, output) = convert_bytes(
+            text.as_bytes(),
+            None,
+            "Shift_JIS",
+            false,
+            &EncodingConvertOptions::default(),
+        );
+        assert!(result.success);
+        assert_eq!(result.unmappable_count, 1);
+        assert_eq!(
+            result.unmappable,
+            vec![UnmappableChar {
+                line: 2,
+                column: 4,
+                character: "😀".to_string(),
+            }]
+        );
+        assert_eq!(SHIFT_JIS.decode(&output).0, "abc\n絵文字?と①\r丸");
+
+        let options = EncodingConvertOptions {
+            on_unmappable: UnmappableAction::Abort,
+            ..Default::default()
+        };
+        let (result, output) = convert_bytes(text.as_bytes(), None, "EUC-JP", false, &options);
+        assert!(!result.success);
+        assert!(output.is_empty());
+        assert_eq!(result.unmappable_count, 1);
+        assert!(result.error.unwrap().contains("EUC-JP"));
+    }
+}
@@ -0,0 +1,772 @@
+use crate::config_converter::{parse_config, ConfigFormat};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EnvQuote {
+    #[default]
+    None,
+    Single,
+    Double,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EnvTargetFormat {
+    Json,
+    Yaml,
+    /// The `environment:` mapping of a docker-compose service
+    DockerComposeEnv,
+    /// `export KEY=value` lines for a POSIX shell
+    ShellExport,
+}
+
+/// Part of a value that may contain variable references
+#[derive(Debug, Clone, PartialEq)]
+enum ValuePart {
+    Literal(String),
+    /// `raw` is the reference as written, e.g. `${HOST:-localhost}`
+    Reference {
+        raw: String,
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvEntry {
+    pub key: String,
+    /// The value with escapes resolved and references kept as written
+    pub value: String,
+    /// 1-based line of the key; multi-line values continue below it
+    pub line: usize,
+    pub quote: EnvQuote,
+    pub exported: bool,
+    /// Text after ` #` on the same line
+    pub comment: Option<String>,
+    /// Names used as `$NAME` or `${NAME}`; single-quoted values never expand
+    pub references: Vec<String>,
+    #[serde(skip)]
+    parts: Vec<ValuePart>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvComment {
+    pub line: usize,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvIssue {
+    pub line: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateKey {
+    pub key: String,
+    /// Every line defining the key; the last one wins
+    pub lines: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvParseResult {
+    pub success: bool,
+    pub entries: Vec<EnvEntry>,
+    pub comments: Vec<EnvComment>,
+    pub duplicates: Vec<DuplicateKey>,
+    /// Referenced names not defined in the file, expected from the environment
+    pub external_references: Vec<String>,
+    pub errors: Vec<EnvIssue>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvConvertResult {
+    pub success: bool,
+    pub output: String,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl EnvConvertResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvDiffResult {
+    pub success: bool,
+    pub only_in_left: Vec<String>,
+    pub only_in_right: Vec<String>,
+    /// Keys on both sides whose values differ
+    pub changed: Vec<String>,
+    pub common: usize,
+    pub error: Option<String>,
+}
+
+impl EnvDiffResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Split an expandable value into literal text and `$NAME` / `${NAME...}` references.
+/// `escaped_dollars` holds char offsets of `$` that were written as `\$`.
+fn split_references(value: &str, escaped_dollars: &HashSet<usize>) -> Vec<ValuePart> {
+    let chars: Vec<char> = value.chars().collect();
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '$' && !escaped_dollars.contains(&i) {
+            let reference = if chars.get(i + 1) == Some(&'{') {
+                chars[i + 2..].iter().position(|c| *c == '}').map(|end| {
+                    let inner: String = chars[i + 2..i + 2 + end].iter().collect();
+                    let name: String = inner.chars().take_while(|c| is_name_char(*c)).collect();
+                    (end + 3, name)
+                })
+            } else {
+                let name: String = chars[i + 1..]
+                    .iter()
+                    .take_while(|c| is_name_char(**c))
+                    .collect();
+                Some((name.len() + 1, name))
+            };
+            if let Some((len, name)) = reference.filter(|(_, name)| is_valid_key(name)) {
+                if !literal.is_empty() {
+                    parts.push(ValuePart::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(ValuePart::Reference {
+                    raw: chars[i..i + len].iter().collect(),
+                    name,
+                });
+                i += len;
+                continue;
+            }
+        }
+        literal.push(chars[i]);
+        i += 1;
+    }
+    if !literal.is_empty() {
+        parts.push(ValuePart::Literal(literal));
+    }
+    parts
+}
+
+/// Read a quoted value starting after the opening quote, continuing onto following lines
+/// until the closing quote. Returns the value, the escaped `$` offsets, the index of the
+/// closing line and the text after the closing quote.
+fn read_quoted<'a>(
+    lines: &[&'a str],
+    start: usize,
+    first: &'a str,
+    quote: char,
+) -> Option<(String, HashSet<usize>, usize, &'a str)> {
+    let mut value = String::new();
+    let mut escaped_dollars = HashSet::new();
+    let mut index = start;
+    let mut text = first;
+    loop {
+        let mut chars = text.char_indices();
+        while let Some((pos, c)) = chars.next() {
+            if c == quote {
+                return Some((value, escaped_dollars, index, &text[pos + 1..]));
+            }
+            if c == '\\' && quote == '"' {
+                match chars.next().map(|(_, next)| next) {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some('$') => {
+                        escaped_dollars.insert(value.chars().count());
+                        value.push('$');
+                    }
+                    Some(next @ ('"' | '\\')) => value.push(next),
+                    Some(next) => {
+                        value.push('\\');
+                        value.push(next);
+                    }
+                    None => value.push('\\'),
+                }
+                continue;
+            }
+            value.push(c);
+        }
+        index += 1;
+        text = *lines.get(index)?;
+        value.push('\n');
+    }
+}
+
+/// Parse a `.env` file. Lines may start with `export`; values may be unquoted, single-quoted
+/// (literal) or double-quoted (escapes, may span lines). Problems are collected per line
+/// rather than stopping the parse.
+pub fn parse_env_file(content: &str) -> EnvParseResult {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut result = EnvParseResult::default();
+    let mut index = 0;
+    while index < lines.len() {
+        let line_no = index + 1;
+        let line = lines[index].trim();
+        index += 1;
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(text) = line.strip_prefix('#') {
+            result.comments.push(EnvComment {
+                line: line_no,
+                text: text.trim().to_string(),
+            });
+            continue;
+        }
+
+        let (exported, assignment) = match line.strip_prefix("export") {
+            Some(rest) if rest.starts_with(char::is_whitespace) => (true, rest.trim_start()),
+            _ => (false, line),
+        };
+        let Some((key, raw_value)) = assignment.split_once('=') else {
+            result.errors.push(EnvIssue {
+                line: line_no,
+                message: "Expected KEY=VALUE".to_string(),
+            });
+            continue;
+        };
+        let key = key.trim();
+        if !is_valid_key(key) {
+            result.errors.push(EnvIssue {
+                line: line_no,
+                message: format!("Invalid key \"{}\"", key),
+            });
+            continue;
+        }
+
+        let raw_value = raw_value.trim_start();
+        let (value, quote, escaped_dollars, rest) = match raw_value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                match read_quoted(&lines, line_no - 1, &raw_value[1..], quote) {
+                    Some((value, escaped, end, rest)) => {
+                        index = end + 1;
+                        let kind = if quote == '"' {
+                            EnvQuote::Double
+                        } else {
+                            EnvQuote::Single
+                        };
+                        (value, kind, escaped, rest.trim())
+                    }
+                    None => {
+                        result.errors.push(EnvIssue {
+                            line: line_no,
+                            message: format!("Unterminated {} quote for {}", quote, key),
+                        });
+                        continue;
+                    }
+                }
+            }
+            _ => {
+                // An inline comment needs whitespace before `#`, so `a#b` stays one value
+                let end = raw_value
+                    .char_indices()
+                    .find(|(pos, c)| *c == '#' && raw_value[..*pos].ends_with(char::is_whitespace))
+                    .map_or(raw_value.len(), |(pos, _)| pos);
+                (
+                    raw_value[..end].trim_end().to_string(),
+                    EnvQuote::None,
+                    HashSet::new(),
+                    raw_value[end..].trim(),
+                )
+            }
+        };
+
+        let comment = match rest.strip_prefix('#') {
+            Some(comment) => Some(comment.trim().to_string()),
+            None if rest.is_empty() => None,
+            None => {
+                result.errors.push(EnvIssue {
+                    line: line_no,
+                    message: format!("Unexpected text after the closing quote of {}", key),
+                });
+                None
+            }
+        };
+        let parts = if quote == EnvQuote::Single {
+            vec![ValuePart::Literal(value.clone())]
+        } else {
+            split_references(&value, &escaped_dollars)
+        };
+        let mut references: Vec<String> = Vec::new();
+        for part in &parts {
+            if let ValuePart::Reference { name, .. } = part {
+                if !references.contains(name) {
+                    references.push(name.clone());
+                }
+            }
+        }
+        result.entries.push(EnvEntry {
+            key: key.to_string(),
+            value,
+            line: line_no,
+            quote,
+            exported,
+            comment,
+            references,
+            parts,
+        });
+    }
+
+    let mut lines_by_key: Vec<(&str, Vec<usize>)> = Vec::new();
+    for entry in &result.entries {
+        match lines_by_key.iter_mut().find(|(key, _)| *key == entry.key) {
+            Some((_, lines)) => lines.push(entry.line),
+            None => lines_by_key.push((entry.key.as_str(), vec![entry.line])),
+        }
+    }
+    result.duplicates = lines_by_key
+        .into_iter()
+        .filter(|(_, lines)| lines.len() > 1)
+        .map(|(key, lines)| DuplicateKey {
+            key: key.to_string(),
+            lines,
+        })
+        .collect();
+
+    let defined: HashSet<&str> = result.entries.iter().map(|e| e.key.as_str()).collect();
+    let mut external = Vec::new();
+    for name in result.entries.iter().flat_map(|e| &e.references) {
+        if !defined.contains(name.as_str()) && !external.contains(name) {
+            external.push(name.clone());
+        }
+    }
+    result.external_references = external;
+    result.success = result.errors.is_empty();
+    result
+}
+
+/// Entries in first-appearance order, each holding the value of its last definition
+fn effective_entries(entries: &[EnvEntry]) -> Vec<&EnvEntry> {
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    let mut effective: Vec<&EnvEntry> = Vec::new();
+    for entry in entries {
+        match positions.get(entry.key.as_str()) {
+            Some(&pos) => effective[pos] = entry,
+            None => {
+                positions.insert(&entry.key, effective.len());
+                effective.push(entry);
+            }
+        }
+    }
+    effective
+}
+
+fn parse_errors_message(errors: &[EnvIssue]) -> String {
+    errors
+        .iter()
+        .map(|issue| format!("Line {}: {}", issue.line, issue.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quote for a POSIX shell. Values with references use double quotes so they still expand;
+/// everything else is single-quoted.
+fn shell_quote(entry: &EnvEntry) -> String {
+    let is_plain = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_-./:@%+,=".contains(c))
+    };
+    if entry.references.is_empty() {
+        if is_plain(&entry.value) {
+            return entry.value.clone();
+        }
+        return format!("'{}'", entry.value.replace('\'', r"'\''"));
+    }
+    let mut quoted = String::from("\"");
+    for part in &entry.parts {
+        match part {
+            ValuePart::Literal(text) => {
+                for c in text.chars() {
+                    if matches!(c, '"' | '\\' | '$' | '`') {
+                        quoted.push('\\');
+                    }
+                    quoted.push(c);
+                }
+            }
+            ValuePart::Reference { raw, .. } => quoted.push_str(raw),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// docker-compose interpolates `$`, so literal dollars are doubled while references stay
+fn compose_value(entry: &EnvEntry) -> String {
+    entry
+        .parts
+        .iter()
+        .map(|part| match part {
+            ValuePart::Literal(text) => text.replace('$', "$$"),
+            ValuePart::Reference { raw, .. } => raw.clone(),
+        })
+        .collect()
+}
+
+/// Convert a `.env` file, keeping key order. Duplicate keys keep their first position and
+/// take the last value, matching how dotenv loaders and docker-compose resolve them.
+pub fn convert_env(content: &str, to: EnvTargetFormat) -> EnvConvertResult {
+    let parsed = parse_env_file(content);
+    if !parsed.success {
+        return EnvConvertResult::failure(parse_errors_message(&parsed.errors));
+    }
+    let entries = effective_entries(&parsed.entries);
+    let mut warnings: Vec<String> = parsed
+        .duplicates
+        .iter()
+        .map(|dup| {
+            format!(
+                "{} is defined {} times; the value from line {} is used",
+                dup.key,
+                dup.lines.len(),
+                dup.lines.last().copied().unwrap_or_default()
+            )
+        })
+        .collect();
+
+    let output = match to {
+        EnvTargetFormat::Json | EnvTargetFormat::Yaml => {
+            if entries.iter().any(|e| !e.references.is_empty()) {
+                warnings.push("Variable references are kept as written, not expanded".to_string());
+            }
+            let object: Map<String, Value> = entries
+                .iter()
+                .map(|e| (e.key.clone(), Value::String(e.value.clone())))
+                .collect();
+            let value = Value::Object(object);
+            if to == EnvTargetFormat::Json {
+                serde_json::to_string_pretty(&value)
+                    .map_err(|e| format!("Failed to serialize JSON: {}", e))
+            } else {
+                serde_yaml::to_string(&value)
+                    .map_err(|e| format!("Failed to serialize YAML: {}", e))
+            }
+        }
+        EnvTargetFormat::DockerComposeEnv => {
+            let environment: Map<String, Value> = entries
+                .iter()
+                .map(|e| (e.key.clone(), Value::String(compose_value(e))))
+                .collect();
+            let mut root = Map::new();
+            root.insert("environment".to_string(), Value::Object(environment));
+            serde_yaml::to_string(&Value::Object(root))
+                .map_err(|e| format!("Failed to serialize YAML: {}", e))
+        }
+        EnvTargetFormat::ShellExport => Ok(entries
+            .iter()
+            .map(|e| format!("export {}={}\n", e.key, shell_quote(e)))
+            .collect()),
+    };
+
+    match output {
+        Ok(output) => EnvConvertResult {
+            success: true,
+            output,
+            warnings,
+            error: None,
+        },
+        Err(e) => EnvConvertResult::failure(e),
+    }
+}
+
+/// Quote a value for a `.env` file, leaving `$` alone so references still expand
+fn env_quote(value: &str) -> String {
+    let is_plain = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "_-./:@%+,=${}".contains(c));
+    if is_plain {
+        return value.to_string();
+    }
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Build a `.env` file from a flat JSON, YAML or TOML object. A lone top-level
+/// `environment` key and the docker-compose list form (`- KEY=value`) are accepted too.
+pub fn config_to_env(input: &str, from: ConfigFormat) -> EnvConvertResult {
+    let value = match parse_config(input, from) {
+        Ok(value) => value,
+        Err(e) => return EnvConvertResult::failure(e),
+    };
+    let value = match value {
+        Value::Object(mut object) if object.len() == 1 && object.contains_key("environment") => {
+            object.remove("environment").unwrap_or_default()
+        }
+        other => other,
+    };
+
+    let pairs: Vec<(String, Value)> = match value {
+        Value::Object(object) => object.into_iter().collect(),
+        Value::Array(items) => {
+            let mut pairs = Vec::new();
+            for item in items {
+                let Some(item) = item.as_str() else {
+                    return EnvConvertResult::failure(
+                        "List entries must be strings of the form KEY=value".to_string(),
+                    );
+                };
+                // A bare `KEY` in compose passes the variable through from the host
+                let (key, value) = item.split_once('=').unwrap_or((item, ""));
+                pairs.push((key.to_string(), Value::String(value.to_string())));
+            }
+            pairs
+        }
+        _ => {
+            return EnvConvertResult::failure(
+                "Expected an object of variables or a list of KEY=value strings".to_string(),
+            )
+        }
+    };
+
+    let mut output = String::new();
+    let mut seen = HashSet::new();
+    let mut warnings = Vec::new();
+    for (key, value) in pairs {
+        if !is_valid_key(&key) {
+            return EnvConvertResult::failure(format!("Invalid key \"{}\"", key));
+        }
+        let value = match value {
+            Value::String(s) => s,
+            Value::Null => String::new(),
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Array(_) | Value::Object(_) => {
+                return EnvConvertResult::failure(format!(
+                    "Value of {} must be a string, number or boolean",
+                    key
+                ))
+            }
+        };
+        if !seen.insert(key.clone()) {
+            warnings.push(format!("{} is defined more than once", key));
+        }
+        output.push_str(&format!("{}={}\n", key, env_quote(&value)));
+    }
+    EnvConvertResult {
+        success: true,
+        output,
+        warnings,
+        error: None,
+    }
+}
+
+/// Compare the keys of two `.env` files, e.g. to find settings missing in one environment
+pub fn diff_env(left: &str, right: &str) -> EnvDiffResult {
+    let left = parse_env_file(left);
+    let right = parse_env_file(right);
+    for (side, parsed) in [("Left", &left), ("Right", &right)] {
+        if !parsed.success {
+            return EnvDiffResult::failure(format!(
+                "{}: {}",
+                side,
+                parse_errors_message(&parsed.errors)
+            ));
+        }
+    }
+    let left = effective_entries(&left.entries);
+    let right = effective_entries(&right.entries);
+    let right_values: HashMap<&str, &str> = right
+        .iter()
+        .map(|e| (e.key.as_str(), e.value.as_str()))
+        .collect();
+    let left_keys: HashSet<&str> = left.iter().map(|e| e.key.as_str()).collect();
+
+    let mut result = EnvDiffResult {
+        success: true,
+        ..EnvDiffResult::default()
+    };
+    for entry in &left {
+        match right_values.get(entry.key.as_str()) {
+            Some(value) => {
+                result.common += 1;
+                if *value != entry.value {
+                    result.changed.push(entry.key.clone());
+                }
+            }
+            None => result.only_in_left.push(entry.key.clone()),
+        }
+    }
+    result.only_in_right = right
+        .iter()
+        .filter(|e| !left_keys.contains(e.key.as_str()))
+        .map(|e| e.key.clone())
+        .collect();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"# Database
+export DB_HOST=localhost
+DB_PORT=5432 # default port
+DB_URL="postgres://${DB_USER}@$DB_HOST:${DB_PORT}/app"
+PASSWORD='p@ss$word # not a comment'
+GREETING="line1\nline2 \"quoted\" \$HOME"
+CERT="-----BEGIN-----
+abc
+-----END-----"
+TAG=v1#beta
+DB_PORT=6543
+"#;
+
+    #[test]
+    fn test_parse_env_file() {
+        let result = parse_env_file(SAMPLE);
+        assert!(result.success, "{:?}", result.errors);
+        assert_eq!(result.comments.len(), 1);
+        let get = |key: &str| result.entries.iter().find(|e| e.key == key).unwrap();
+
+        assert!(get("DB_HOST").exported);
+        assert_eq!(get("DB_PORT").value, "5432");
+        assert_eq!(get("DB_PORT").comment.as_deref(), Some("default port"));
+        assert_eq!(
+            get("DB_URL").references,
+            vec!["DB_USER", "DB_HOST", "DB_PORT"]
+        );
+        assert_eq!(get("PASSWORD").value, "p@ss$word # not a comment");
+        assert_eq!(get("PASSWORD").quote, EnvQuote::Single);
+        assert!(get("PASSWORD").references.is_empty());
+        assert_eq!(get("GREETING").value, "line1\nline2 \"quoted\" $HOME");
+        assert!(get("GREETING").references.is_empty());
+        assert_eq!(get("CERT").value, "-----BEGIN-----\nabc\n-----END-----");
+        assert_eq!(get("CERT").line, 7);
+        assert_eq!(get("TAG").value, "v1#beta");
+        assert_eq!(get("TAG").line, 10);
+
+        assert_eq!(result.duplicates.len(), 1);
+        assert_eq!(result.duplicates[0].key, "DB_PORT");
+        assert_eq!(result.duplicates[0].lines, vec![3, 11]);
+        assert_eq!(result.external_references, vec!["DB_USER"]);
+    }
+
+    #[test]
+    fn test_parse_env_file_errors() {
+        let result = parse_env_file("OK=1\nNO_EQUALS\n1BAD=x\nOPEN=\"never closed\nNEXT=2\n");
+        assert!(!result.success);
+        let lines: Vec<usize> = result.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2, 3, 4]);
+        // The unterminated quote does not swallow the following lines
+        assert!(result.entries.iter().any(|e| e.key == "NEXT"));
+
+        let result = parse_env_file("A=\"x\" trailing\n");
+        assert!(!result.success);
+    }
+
+    #[test]
+    fn test_convert_env_json_and_yaml() {
+        let result = convert_env(SAMPLE, EnvTargetFormat::Json);
+        assert!(result.success);
+        let value: Value = serde_json::from_str(&result.output).unwrap();
+        let keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+        assert_eq!(
+            keys,
+            vec!["DB_HOST", "DB_PORT", "DB_URL", "PASSWORD", "GREETING", "CERT", "TAG"]
+        );
+        assert_eq!(value["DB_PORT"], "6543");
+        assert_eq!(value["CERT"], "-----BEGIN-----\nabc\n-----END-----");
+        assert!(result.warnings.iter().any(|w| w.contains("DB_PORT")));
+
+        let result = convert_env("ENABLED=true\nCOUNT=010\n", EnvTargetFormat::Yaml);
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&result.output).unwrap();
+        // Values stay strings rather than turning into YAML booleans or numbers
+        assert_eq!(yaml["ENABLED"].as_str(), Some("true"));
+        assert_eq!(yaml["COUNT"].as_str(), Some("010"));
+    }
+
+    #[test]
+    fn test_convert_env_compose_and_shell() {
+        let input = "URL=http://${HOST}/x\nPRICE='$5'\nMSG=it's fine\nPLAIN=abc\n";
+        let result = convert_env(input, EnvTargetFormat::DockerComposeEnv);
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&result.output).unwrap();
+        assert_eq!(
+            yaml["environment"]["URL"].as_str(),
+            Some("http://${HOST}/x")
+        );
+        assert_eq!(yaml["environment"]["PRICE"].as_str(), Some("$$5"));
+
+        let result = convert_env(input, EnvTargetFormat::ShellExport);
+        assert_eq!(
+            result.output,
+            "export URL=\"http://${HOST}/x\"\nexport PRICE='$5'\nexport MSG='it'\\''s fine'\nexport PLAIN=abc\n"
+        );
+    }
+
+    #[test]
+    fn test_config_to_env_round_trip() {
+        let json =
+            r#"{"NAME": "app", "DEBUG": true, "PORT": 8080, "NOTE": "a \"b\"\nc", "EMPTY": null}"#;
+        let result = config_to_env(json, ConfigFormat::Json);
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(
+            result.output,
+            "NAME=app\nDEBUG=true\nPORT=8080\nNOTE=\"a \\\"b\\\"\\nc\"\nEMPTY=\n"
+        );
+        let back = parse_env_file(&result.output);
+        assert_eq!(back.entries[3].value, "a \"b\"\nc");
+
+        let compose = "environment:\n  - HOST=db\n  - TOKEN\n";
+        let result = config_to_env(compose, ConfigFormat::Yaml);
+        assert_eq!(result.output, "HOST=db\nTOKEN=\n");
+
+        assert!(!config_to_env(r#"{"A": {"B": 1}}"#, ConfigFormat::Json).success);
+        assert!(!config_to_env("[1, 2]", ConfigFormat::Json).success);
+    }
+
+    #[test]
+    fn test_diff_env() {
+        let result = diff_env("A=1\nB=2\nC=3\n", "B=2\nC=30\nD=4\n");
+        assert!(result.success);
+        assert_eq!(result.only_in_left, vec!["A"]);
+        assert_eq!(result.only_in_right, vec!["D"]);
+        assert_eq!(result.changed, vec!["C"]);
+        assert_eq!(result.common, 2);
+
+        assert!(!diff_env("A=1", "BROKEN").success);
+    }
+}
@@ -0,0 +1,873 @@
+use serde::{Deserialize, Serialize};
+
+/// How HTML escaping writes each character reference
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum EntityStyle {
+    /// `&amp;`, `&copy;`; characters without a name fall back to hex references
+    Named,
+    /// `&#38;`
+    Decimal,
+    /// `&#x26;`
+    Hex,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum EscapeMode {
+    HtmlEntities {
+        style: EntityStyle,
+        /// Also escape every non-ASCII character, not just `& < > " '`
+        encode_non_ascii: bool,
+    },
+    /// `\uXXXX`, with surrogate pairs for characters outside the BMP
+    UnicodeEscape,
+    JsonStringEscape,
+    XmlEscape,
+    /// Unescape only: decode HTML entities and backslash escapes mixed in one text
+    Auto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EscapeWarning {
+    /// Character offset (0-based) of the sequence in the input
+    pub position: usize,
+    pub sequence: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EscapeResult {
+    pub success: bool,
+    pub output: String,
+    /// Number of characters escaped, or sequences decoded
+    pub replaced_count: usize,
+    pub warnings: Vec<EscapeWarning>,
+    pub error: Option<String>,
+}
+
+impl EscapeResult {
+    fn failure(message: &str) -> Self {
+        Self {
+            success: false,
+            output: String::new(),
+            replaced_count: 0,
+            warnings: Vec::new(),
+            error: Some(message.to_string()),
+        }
+    }
+}
+
+/// HTML 4 named character references, plus &apos; from XML and HTML5
+const NAMED_ENTITIES: [(&str, char); 253] = [
+    ("quot", '\u{22}'),
+    ("amp", '\u{26}'),
+    ("apos", '\u{27}'),
+    ("lt", '\u{3c}'),
+    ("gt", '\u{3e}'),
+    ("nbsp", '\u{a0}'),
+    ("iexcl", '\u{a1}'),
+    ("cent", '\u{a2}'),
+    ("pound", '\u{a3}'),
+    ("curren", '\u{a4}'),
+    ("yen", '\u{a5}'),
+    ("brvbar", '\u{a6}'),
+    ("sect", '\u{a7}'),
+    ("uml", '\u{a8}'),
+    ("copy", '\u{a9}'),
+    ("ordf", '\u{aa}'),
+    ("laquo", '\u{ab}'),
+    ("not", '\u{ac}'),
+    ("shy", '\u{ad}'),
+    ("reg", '\u{ae}'),
+    ("macr", '\u{af}'),
+    ("deg", '\u{b0}'),
+    ("plusmn", '\u{b1}'),
+    ("sup2", '\u{b2}'),
+    ("sup3", '\u{b3}'),
+    ("acute", '\u{b4}'),
+    ("micro", '\u{b5}'),
+    ("para", '\u{b6}'),
+    ("middot", '\u{b7}'),
+    ("cedil", '\u{b8}'),
+    ("sup1", '\u{b9}'),
+    ("ordm", '\u{ba}'),
+    ("raquo", '\u{bb}'),
+    ("frac14", '\u{bc}'),
+    ("frac12", '\u{bd}'),
+    ("frac34", '\u{be}'),
+    ("iquest", '\u{bf}'),
+    ("Agrave", '\u{c0}'),
+    ("Aacute", '\u{c1}'),
+    ("Acirc", '\u{c2}'),
+    ("Atilde", '\u{c3}'),
+    ("Auml", '\u{c4}'),
+    ("Aring", '\u{c5}'),
+    ("AElig", '\u{c6}'),
+    ("Ccedil", '\u{c7}'),
+    ("Egrave", '\u{c8}'),
+    ("Eacute", '\u{c9}'),
+    ("Ecirc", '\u{ca}'),
+    ("Euml", '\u{cb}'),
+    ("Igrave", '\u{cc}'),
+    ("Iacute", '\u{cd}'),
+    ("Icirc", '\u{ce}'),
+    ("Iuml", '\u{cf}'),
+    ("ETH", '\u{d0}'),
+    ("Ntilde", '\u{d1}'),
+    ("Ograve", '\u{d2}'),
+    ("Oacute", '\u{d3}'),
+    ("Ocirc", '\u{d4}'),
+    ("Otilde", '\u{d5}'),
+    ("Ouml", '\u{d6}'),
+    ("times", '\u{d7}'),
+    ("Oslash", '\u{d8}'),
+    ("Ugrave", '\u{d9}'),
+    ("Uacute", '\u{da}'),
+    ("Ucirc", '\u{db}'),
+    ("Uuml", '\u{dc}'),
+    ("Yacute", '\u{dd}'),
+    ("THORN", '\u{de}'),
+    ("szlig", '\u{df}'),
+    ("agrave", '\u{e0}'),
+    ("aacute", '\u{e1}'),
+    ("acirc", '\u{e2}'),
+    ("atilde", '\u{e3}'),
+    ("auml", '\u{e4}'),
+    ("aring", '\u{e5}'),
+    ("aelig", '\u{e6}'),
+    ("ccedil", '\u{e7}'),
+    ("egrave", '\u{e8}'),
+    ("eacute", '\u{e9}'),
+    ("ecirc", '\u{ea}'),
+    ("euml", '\u{eb}'),
+    ("igrave", '\u{ec}'),
+    ("iacute", '\u{ed}'),
+    ("icirc", '\u{ee}'),
+    ("iuml", '\u{ef}'),
+    ("eth", '\u{f0}'),
+    ("ntilde", '\u{f1}'),
+    ("ograve", '\u{f2}'),
+    ("oacute", '\u{f3}'),
+    ("ocirc", '\u{f4}'),
+    ("otilde", '\u{f5}'),
+    ("ouml", '\u{f6}'),
+    ("divide", '\u{f7}'),
+    ("oslash", '\u{f8}'),
+    ("ugrave", '\u{f9}'),
+    ("uacute", '\u{fa}'),
+    ("ucirc", '\u{fb}'),
+    ("uuml", '\u{fc}'),
+    ("yacute", '\u{fd}'),
+    ("thorn", '\u{fe}'),
+    ("yuml", '\u{ff}'),
+    ("OElig", '\u{152}'),
+    ("oelig", '\u{153}'),
+    ("Scaron", '\u{160}'),
+    ("scaron", '\u{161}'),
+    ("Yuml", '\u{178}'),
+    ("fnof", '\u{192}'),
+    ("circ", '\u{2c6}'),
+    ("tilde", '\u{2dc}'),
+    ("Alpha", '\u{391}'),
+    ("Beta", '\u{392}'),
+    ("Gamma", '\u{393}'),
+    ("Delta", '\u{394}'),
+    ("Epsilon", '\u{395}'),
+    ("Zeta", '\u{396}'),
+    ("Eta", '\u{397}'),
+    ("Theta", '\u{398}'),
+    ("Iota", '\u{399}'),
+    ("Kappa", '\u{39a}'),
+    ("Lambda", '\u{39b}'),
+    ("Mu", '\u{39c}'),
+    ("Nu", '\u{39d}'),
+    ("Xi", '\u{39e}'),
+    ("Omicron", '\u{39f}'),
+    ("Pi", '\u{3a0}'),
+    ("Rho", '\u{3a1}'),
+    ("Sigma", '\u{3a3}'),
+    ("Tau", '\u{3a4}'),
+    ("Upsilon", '\u{3a5}'),
+    ("Phi", '\u{3a6}'),
+    ("Chi", '\u{3a7}'),
+    ("Psi", '\u{3a8}'),
+    ("Omega", '\u{3a9}'),
+    ("alpha", '\u{3b1}'),
+    ("beta", '\u{3b2}'),
+    ("gamma", '\u{3b3}'),
+    ("delta", '\u{3b4}'),
+    ("epsilon", '\u{3b5}'),
+    ("zeta", '\u{3b6}'),
+    ("eta", '\u{3b7}'),
+    ("theta", '\u{3b8}'),
+    ("iota", '\u{3b9}'),
+    ("kappa", '\u{3ba}'),
+    ("lambda", '\u{3bb}'),
+    ("mu", '\u{3bc}'),
+    ("nu", '\u{3bd}'),
+    ("xi", '\u{3be}'),
+    ("omicron", '\u{3bf}'),
+    ("pi", '\u{3c0}'),
+    ("rho", '\u{3c1}'),
+    ("sigmaf", '\u{3c2}'),
+    ("sigma", '\u{3c3}'),
+    ("tau", '\u{3c4}'),
+    ("upsilon", '\u{3c5}'),
+    ("phi", '\u{3c6}'),
+    ("chi", '\u{3c7}'),
+    ("psi", '\u{3c8}'),
+    ("omega", '\u{3c9}'),
+    ("thetasym", '\u{3d1}'),
+    ("upsih", '\u{3d2}'),
+    ("piv", '\u{3d6}'),
+    ("ensp", '\u{2002}'),
+    ("emsp", '\u{2003}'),
+    ("thinsp", '\u{2009}'),
+    ("zwnj", '\u{200c}'),
+    ("zwj", '\u{200d}'),
+    ("lrm", '\u{200e}'),
+    ("rlm", '\u{200f}'),
+    ("ndash", '\u{2013}'),
+    ("mdash", '\u{2014}'),
+    ("lsquo", '\u{2018}'),
+    ("rsquo", '\u{2019}'),
+    ("sbquo", '\u{201a}'),
+    ("ldquo", '\u{201c}'),
+    ("rdquo", '\u{201d}'),
+    ("bdquo", '\u{201e}'),
+    ("dagger", '\u{2020}'),
+    ("Dagger", '\u{2021}'),
+    ("bull", '\u{2022}'),
+    ("hellip", '\u{2026}'),
+    ("permil", '\u{2030}'),
+    ("prime", '\u{2032}'),
+    ("Prime", '\u{2033}'),
+    ("lsaquo", '\u{2039}'),
+    ("rsaquo", '\u{203a}'),
+    ("oline", '\u{203e}'),
+    ("frasl", '\u{2044}'),
+    ("euro", '\u{20ac}'),
+    ("image", '\u{2111}'),
+    ("weierp", '\u{2118}'),
+    ("real", '\u{211c}'),
+    ("trade", '\u{2122}'),
+    ("alefsym", '\u{2135}'),
+    ("larr", '\u{2190}'),
+    ("uarr", '\u{2191}'),
+    ("rarr", '\u{2192}'),
+    ("darr", '\u{2193}'),
+    ("harr", '\u{2194}'),
+    ("crarr", '\u{21b5}'),
+    ("lArr", '\u{21d0}'),
+    ("uArr", '\u{21d1}'),
+    ("rArr", '\u{21d2}'),
+    ("dArr", '\u{21d3}'),
+    ("hArr", '\u{21d4}'),
+    ("forall", '\u{2200}'),
+    ("part", '\u{2202}'),
+    ("exist", '\u{2203}'),
+    ("empty", '\u{2205}'),
+    ("nabla", '\u{2207}'),
+    ("isin", '\u{2208}'),
+    ("notin", '\u{2209}'),
+    ("ni", '\u{220b}'),
+    ("prod", '\u{220f}'),
+    ("sum", '\u{2211}'),
+    ("minus", '\u{2212}'),
+    ("lowast", '\u{2217}'),
+    ("radic", '\u{221a}'),
+    ("prop", '\u{221d}'),
+    ("infin", '\u{221e}'),
+    ("ang", '\u{2220}'),
+    ("and", '\u{2227}'),
+    ("or", '\u{2228}'),
+    ("cap", '\u{2229}'),
+    ("cup", '\u{222a}'),
+    ("int", '\u{222b}'),
+    ("there4", '\u{2234}'),
+    ("sim", '\u{223c}'),
+    ("cong", '\u{2245}'),
+    ("asymp", '\u{2248}'),
+    ("ne", '\u{2260}'),
+    ("equiv", '\u{2261}'),
+    ("le", '\u{2264}'),
+    ("ge", '\u{2265}'),
+    ("sub", '\u{2282}'),
+    ("sup", '\u{2283}'),
+    ("nsub", '\u{2284}'),
+    ("sube", '\u{2286}'),
+    ("supe", '\u{2287}'),
+    ("oplus", '\u{2295}'),
+    ("otimes", '\u{2297}'),
+    ("perp", '\u{22a5}'),
+    ("sdot", '\u{22c5}'),
+    ("lceil", '\u{2308}'),
+    ("rceil", '\u{2309}'),
+    ("lfloor", '\u{230a}'),
+    ("rfloor", '\u{230b}'),
+    ("lang", '\u{2329}'),
+    ("rang", '\u{232a}'),
+    ("loz", '\u{25ca}'),
+    ("spades", '\u{2660}'),
+    ("clubs", '\u{2663}'),
+    ("hearts", '\u{2665}'),
+    ("diams", '\u{2666}'),
+];
+
+const XML_ENTITIES: [&str; 5] = ["amp", "lt", "gt", "quot", "apos"];
+
+fn entity_name(c: char) -> Option<&'static str> {
+    NAMED_ENTITIES
+        .iter()
+        .find(|(_, ch)| *ch == c)
+        .map(|(name, _)| *name)
+}
+
+fn entity_char(name: &str) -> Option<char> {
+    NAMED_ENTITIES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, ch)| *ch)
+}
+
+fn push_utf16_escape(out: &mut String, c: char) {
+    let mut units = [0u16; 2];
+    for unit in c.encode_utf16(&mut units) {
+        out.push_str(&format!("\\u{:04x}", unit));
+    }
+}
+
+/// Escape `input` for the target format.
+pub fn escape_text(input: &str, mode: EscapeMode) -> EscapeResult {
+    let mut output = String::with_capacity(input.len());
+    let mut replaced_count = 0;
+
+    for c in input.chars() {
+        let before = output.len();
+        match mode {
+            EscapeMode::HtmlEntities {
+                style,
+                encode_non_ascii,
+            } => {
+                let special = matches!(c, '&' | '<' | '>' | '"' | '\'');
+                if special || (encode_non_ascii && !c.is_ascii()) {
+                    match style {
+                        EntityStyle::Named => match entity_name(c) {
+                            // &apos; is not defined in HTML 4
+                            Some(name) if c != '\'' => output.push_str(&format!("&{};", name)),
+                            _ => output.push_str(&format!("&#x{:x};", c as u32)),
+                        },
+                        EntityStyle::Decimal => output.push_str(&format!("&#{};", c as u32)),
+                        EntityStyle::Hex => output.push_str(&format!("&#x{:x};", c as u32)),
+                    }
+                } else {
+                    output.push(c);
+                }
+            }
+            EscapeMode::UnicodeEscape => {
+                if c.is_ascii() {
+                    output.push(c);
+                } else {
+                    push_utf16_escape(&mut output, c);
+                }
+            }
+            EscapeMode::JsonStringEscape => match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '\n' => output.push_str("\\n"),
+                '\r' => output.push_str("\\r"),
+                '\t' => output.push_str("\\t"),
+                '\u{8}' => output.push_str("\\b"),
+                '\u{c}' => output.push_str("\\f"),
+                c if c.is_control() && (c as u32) < 0x80 => push_utf16_escape(&mut output, c),
+                c => output.push(c),
+            },
+            EscapeMode::XmlEscape => match c {
+                '&' => output.push_str("&amp;"),
+                '<' => output.push_str("&lt;"),
+                '>' => output.push_str("&gt;"),
+                '"' => output.push_str("&quot;"),
+                '\'' => output.push_str("&apos;"),
+                c => output.push(c),
+            },
+            EscapeMode::Auto => return EscapeResult::failure(
+                "Auto detection is only available when unescaping; choose a format to escape to",
+            ),
+        }
+        if output.len() != before + c.len_utf8() {
+            replaced_count += 1;
+        }
+    }
+
+    EscapeResult {
+        success: true,
+        output,
+        replaced_count,
+        warnings: Vec::new(),
+        error: None,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum EntitySet {
+    Html,
+    Xml,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum BackslashSet {
+    /// `\uXXXX`, `\u{...}`, `\UXXXXXXXX` and `\xHH`; other backslashes are plain text
+    Unicode,
+    /// Exactly the escapes JSON allows; anything else is invalid
+    Json,
+    /// Union of both, leaving unknown escapes untouched
+    All,
+}
+
+enum Sequence {
+    /// Not an escape at all; the introducing character is plain text
+    Text,
+    Decoded {
+        value: String,
+        consumed: usize,
+    },
+    Invalid {
+        consumed: usize,
+        sequence: String,
+        message: String,
+    },
+}
+
+struct Unescaper {
+    chars: Vec<char>,
+    entities: Option<EntitySet>,
+    backslashes: Option<BackslashSet>,
+}
+
+impl Unescaper {
+    fn text(&self, start: usize, end: usize) -> String {
+        self.chars[start..end.min(self.chars.len())]
+            .iter()
+            .collect()
+    }
+
+    fn hex_digits(&self, start: usize, max: usize) -> usize {
+        self.chars[start.min(self.chars.len())..]
+            .iter()
+            .take(max)
+            .take_while(|c| c.is_ascii_hexdigit())
+            .count()
+    }
+
+    fn invalid(&self, start: usize, consumed: usize, shown: usize, message: &str) -> Sequence {
+        Sequence::Invalid {
+            consumed,
+            sequence: self.text(start, start + shown.max(consumed)),
+            message: message.to_string(),
+        }
+    }
+
+    fn entity(&self, start: usize, set: EntitySet) -> Sequence {
+        // Only `&...;` with a plausible body counts; a lone `&` is ordinary text
+        let Some(end) = self.chars[start + 1..]
+            .iter()
+            .take(32)
+            .position(|c| !(c.is_ascii_alphanumeric() || *c == '#'))
+            .map(|offset| start + 1 + offset)
+            .filter(|&end| end > start + 1 && self.chars[end] == ';')
+        else {
+            return Sequence::Text;
+        };
+        let body = self.text(start + 1, end);
+        let consumed = end - start + 1;
+
+        if let Some(number) = body.strip_prefix('#') {
+            let parsed = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16),
+                None => number.parse::<u32>(),
+            };
+            return match parsed {
+                Ok(code) => match char::from_u32(code).filter(|c| *c != '\0') {
+                    Some(c) => Sequence::Decoded {
+                        value: c.to_string(),
+                        consumed,
+                    },
+                    None => self.invalid(start, consumed, 0, "Not a valid Unicode code point"),
+                },
+                Err(_) => self.invalid(start, consumed, 0, "Invalid numeric character reference"),
+            };
+        }
+
+        match entity_char(&body) {
+            Some(_) if set == EntitySet::Xml && !XML_ENTITIES.contains(&body.as_str()) => self
+                .invalid(
+                    start,
+                    consumed,
+                    0,
+                    "Not one of the five predefined XML entities",
+                ),
+            Some(c) => Sequence::Decoded {
+                value: c.to_string(),
+                consumed,
+            },
+            None => self.invalid(start, consumed, 0, "Unknown named entity"),
+        }
+    }
+
+    /// Read `\uXXXX` at `start`, pairing a high surrogate with the low surrogate after it.
+    fn utf16_escape(&self, start: usize) -> Sequence {
+        let digits = self.hex_digits(start + 2, 4);
+        if digits < 4 {
+            return self.invalid(start, 2 + digits, 6, "\\u must be followed by 4 hex digits");
+        }
+        let unit = u32::from_str_radix(&self.text(start + 2, start + 6), 16).unwrap();
+        match unit {
+            0xD800..=0xDBFF => {
+                let low_start = start + 6;
+                let low = (self.text(low_start, low_start + 2) == "\\u"
+                    && self.hex_digits(low_start + 2, 4) == 4)
+                    .then(|| u32::from_str_radix(&self.text(low_start + 2, low_start + 6), 16))
+                    .and_then(Result::ok)
+                    .filter(|low| (0xDC00..=0xDFFF).contains(low));
+                match low {
+                    Some(low) => {
+                        let code = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+                        Sequence::Decoded {
+                            value: char::from_u32(code).unwrap().to_string(),
+                            consumed: 12,
+                        }
+                    }
+                    None => self.invalid(start, 6, 0, "High surrogate without a low surrogate"),
+                }
+            }
+            0xDC00..=0xDFFF => self.invalid(start, 6, 0, "Low surrogate without a high surrogate"),
+            _ => Sequence::Decoded {
+                value: char::from_u32(unit).unwrap().to_string(),
+                consumed: 6,
+            },
+        }
+    }
+
+    fn code_point_escape(
+        &self,
+        start: usize,
+        prefix: usize,
+        digits: usize,
+        close: bool,
+    ) -> Sequence {
+        let consumed = prefix + digits + usize::from(close);
+        let hex = self.text(start + prefix, start + prefix + digits);
+        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+            Some(c) => Sequence::Decoded {
+                value: c.to_string(),
+                consumed,
+            },
+            None => self.invalid(start, consumed, 0, "Not a valid Unicode code point"),
+        }
+    }
+
+    fn backslash(&self, start: usize, set: BackslashSet) -> Sequence {
+        let unicode = set != BackslashSet::Json;
+        let json = set != BackslashSet::Unicode;
+        let Some(&next) = self.chars.get(start + 1) else {
+            return if set == BackslashSet::Json {
+                self.invalid(start, 1, 0, "Trailing backslash")
+            } else {
+                Sequence::Text
+            };
+        };
+
+        let simple = match next {
+            '"' => Some('"'),
+            '\\' => Some('\\'),
+            '/' => Some('/'),
+            'b' => Some('\u{8}'),
+            'f' => Some('\u{c}'),
+            'n' => Some('\n'),
+            'r' => Some('\r'),
+            't' => Some('\t'),
+            '\'' if set == BackslashSet::All => Some('\''),
+            _ => None,
+        };
+        if let (true, Some(c)) = (json, simple) {
+            return Sequence::Decoded {
+                value: c.to_string(),
+                consumed: 2,
+            };
+        }
+
+        match next {
+            'u' if unicode && self.chars.get(start + 2) == Some(&'{') => {
+                let digits = self.hex_digits(start + 3, 6);
+                if digits == 0 || self.chars.get(start + 3 + digits) != Some(&'}') {
+                    return self.invalid(
+                        start,
+                        3 + digits,
+                        10,
+                        "\\u{...} must contain 1 to 6 hex digits",
+                    );
+                }
+                self.code_point_escape(start, 3, digits, true)
+            }
+            'u' => self.utf16_escape(start),
+            'U' if unicode => {
+                let digits = self.hex_digits(start + 2, 8);
+                if digits < 8 {
+                    return self.invalid(
+                        start,
+                        2 + digits,
+                        10,
+                        "\\U must be followed by 8 hex digits",
+                    );
+                }
+                self.code_point_escape(start, 2, 8, false)
+            }
+            'x' if unicode => {
+                let digits = self.hex_digits(start + 2, 2);
+                if digits < 2 {
+                    return self.invalid(
+                        start,
+                        2 + digits,
+                        4,
+                        "\\x must be followed by 2 hex digits",
+                    );
+                }
+                self.code_point_escape(start, 2, 2, false)
+            }
+            _ if set == BackslashSet::Json => self.invalid(start, 2, 0, "Unknown escape sequence"),
+            _ => Sequence::Text,
+        }
+    }
+
+    fn run(&self) -> EscapeResult {
+        let mut output = String::with_capacity(self.chars.len());
+        let mut replaced_count = 0;
+        let mut warnings = Vec::new();
+        let mut i = 0;
+
+        while i < self.chars.len() {
+            let c = self.chars[i];
+            let sequence = match (c, self.entities, self.backslashes) {
+                ('&', Some(set), _) => self.entity(i, set),
+                ('\\', _, Some(set)) => self.backslash(i, set),
+                _ => Sequence::Text,
+            };
+            match sequence {
+                Sequence::Text => {
+                    output.push(c);
+                    i += 1;
+                }
+                Sequence::Decoded { value, consumed } => {
+                    output.push_str(&value);
+                    replaced_count += 1;
+                    i += consumed;
+                }
+                Sequence::Invalid {
+                    consumed,
+                    sequence,
+                    message,
+                } => {
+                    // Keep the broken sequence as-is so nothing is lost
+                    output.push_str(&self.text(i, i + consumed));
+                    warnings.push(EscapeWarning {
+                        position: i,
+                        sequence,
+                        message,
+                    });
+                    i += consumed;
+                }
+            }
+        }
+
+        EscapeResult {
+            success: true,
+            output,
+            replaced_count,
+            warnings,
+            error: None,
+        }
+    }
+}
+
+/// Decode escape sequences in `input`. Invalid sequences are left in place and reported as
+/// warnings instead of failing the whole conversion.
+pub fn unescape_text(input: &str, mode: EscapeMode) -> EscapeResult {
+    let (entities, backslashes) = match mode {
+        EscapeMode::HtmlEntities { .. } => (Some(EntitySet::Html), None),
+        EscapeMode::XmlEscape => (Some(EntitySet::Xml), None),
+        EscapeMode::UnicodeEscape => (None, Some(BackslashSet::Unicode)),
+        EscapeMode::JsonStringEscape => (None, Some(BackslashSet::Json)),
+        EscapeMode::Auto => (Some(EntitySet::Html), Some(BackslashSet::All)),
+    };
+
+    Unescaper {
+        chars: input.chars().collect(),
+        entities,
+        backslashes,
+    }
+    .run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML_NAMED: EscapeMode = EscapeMode::HtmlEntities {
+        style: EntityStyle::Named,
+        encode_non_ascii: false,
+    };
+
+    fn escape(input: &str, mode: EscapeMode) -> String {
+        escape_text(input, mode).output
+    }
+
+    fn unescape(input: &str, mode: EscapeMode) -> String {
+        unescape_text(input, mode).output
+    }
+
+    #[test]
+    fn test_escape_html() {
+        let input = r#"<a href="x">Tom & Jerry's</a> ©あ"#;
+        assert_eq!(
+            escape(input, HTML_NAMED),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#x27;s&lt;/a&gt; ©あ"
+        );
+        let all = EscapeMode::HtmlEntities {
+            style: EntityStyle::Named,
+            encode_non_ascii: true,
+        };
+        assert_eq!(escape("© あ", all), "&copy; &#x3042;");
+        let decimal = EscapeMode::HtmlEntities {
+            style: EntityStyle::Decimal,
+            encode_non_ascii: true,
+        };
+        assert_eq!(escape("<あ😀", decimal), "&#60;&#12354;&#128512;");
+        let result = escape_text("a<b>c", HTML_NAMED);
+        assert_eq!(result.replaced_count, 2);
+    }
+
+    #[test]
+    fn test_escape_unicode_json_xml() {
+        assert_eq!(
+            escape("aあ😀", EscapeMode::UnicodeEscape),
+            "a\\u3042\\ud83d\\ude00"
+        );
+        assert_eq!(
+            escape("say \"hi\"\n\tC:\\ \u{1}", EscapeMode::JsonStringEscape),
+            "say \\\"hi\\\"\\n\\tC:\\\\ \\u0001"
+        );
+        assert_eq!(
+            escape("<x a='1'>&</x>", EscapeMode::XmlEscape),
+            "&lt;x a=&apos;1&apos;&gt;&amp;&lt;/x&gt;"
+        );
+        assert!(!escape_text("x", EscapeMode::Auto).success);
+    }
+
+    #[test]
+    fn test_unescape_html() {
+        assert_eq!(
+            unescape(
+                "&lt;p&gt;Tom &amp; Jerry &copy; &#x3042;&#12354;&#X1F600;",
+                HTML_NAMED
+            ),
+            "<p>Tom & Jerry © ああ😀"
+        );
+        // Bare ampersands and unterminated references are ordinary text
+        let result = unescape_text("AT&T & R&D &amp", HTML_NAMED);
+        assert_eq!(result.output, "AT&T & R&D &amp");
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_unescape_unicode() {
+        assert_eq!(
+            unescape(
+                "\\u3042\\ud83d\\ude00 \\u{1F600} \\x41 \\U0001F600",
+                EscapeMode::UnicodeEscape
+            ),
+            "あ😀 😀 A 😀"
+        );
+        // Other backslashes are untouched in this mode
+        assert_eq!(
+            unescape("C:\\temp\\n", EscapeMode::UnicodeEscape),
+            "C:\\temp\\n"
+        );
+    }
+
+    #[test]
+    fn test_unescape_json() {
+        assert_eq!(
+            unescape(
+                "line1\\nline2\\t\\\"q\\\" \\\\ \\/ \\u00e9",
+                EscapeMode::JsonStringEscape
+            ),
+            "line1\nline2\t\"q\" \\ / é"
+        );
+        let result = unescape_text("a\\qb\\", EscapeMode::JsonStringEscape);
+        assert_eq!(result.output, "a\\qb\\");
+        assert_eq!(result.warnings.len(), 2);
+        assert_eq!(result.warnings[0].position, 1);
+        assert_eq!(result.warnings[1].message, "Trailing backslash");
+    }
+
+    #[test]
+    fn test_unescape_xml() {
+        let result = unescape_text("&lt;&apos;&copy;&#169;", EscapeMode::XmlEscape);
+        assert_eq!(result.output, "<'&copy;©");
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].sequence, "&copy;");
+    }
+
+    #[test]
+    fn test_invalid_sequences_are_warnings() {
+        let result = unescape_text(
+            "ok \\uZZZZ \\u3042 &#xZZ; &bogus; \\ud83d!",
+            EscapeMode::Auto,
+        );
+        assert!(result.success);
+        assert_eq!(result.output, "ok \\uZZZZ あ &#xZZ; &bogus; \\ud83d!");
+        assert_eq!(result.replaced_count, 1);
+        let positions: Vec<usize> = result.warnings.iter().map(|w| w.position).collect();
+        assert_eq!(positions, [3, 17, 24, 32]);
+        assert_eq!(result.warnings[0].sequence, "\\uZZZZ");
+        assert_eq!(
+            result.warnings[3].message,
+            "High surrogate without a low surrogate"
+        );
+    }
+
+    #[test]
+    fn test_auto_handles_mixed_formats() {
+        assert_eq!(
+            unescape(
+                "&lt;b&gt;\\u3053\\u3093 &#x306b;\\n&quot;ok&quot;",
+                EscapeMode::Auto
+            ),
+            "<b>こん に\n\"ok\""
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = "<tag attr=\"v\">'&'</tag>\n\tあ😀©";
+        let modes = [
+            EscapeMode::HtmlEntities {
+                style: EntityStyle::Named,
+                encode_non_ascii: true,
+            },
+            EscapeMode::HtmlEntities {
+                style: EntityStyle::Hex,
+                encode_non_ascii: true,
+            },
+            EscapeMode::UnicodeEscape,
+            EscapeMode::JsonStringEscape,
+            EscapeMode::XmlEscape,
+        ];
+        for mode in modes {
+            let escaped = escape(text, mode);
+            assert_eq!(unescape(&escaped, mode), text, "{:?}", mode);
+            assert_eq!(unescape(&escaped, EscapeMode::Auto), text, "{:?}", mode);
+        }
+    }
+}
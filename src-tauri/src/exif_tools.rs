@@ -0,0 +1,727 @@
+use crate::image_editor::EditResult;
+use exif::{DateTime, Exif, In, Reader, Tag, Value};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufReader, Cursor};
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+const EXIF_PREFIX: &[u8] = b"Exif\0\0";
+/// Longest value shown in the raw field list before it is cut short
+const MAX_FIELD_VALUE_LEN: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GpsInfo {
+    /// Signed decimal degrees, ready for a map link
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Degrees, minutes and seconds such as `35°39'31.20"N`
+    pub latitude_dms: String,
+    pub longitude_dms: String,
+    /// Meters above sea level (negative below)
+    pub altitude: Option<f64>,
+    pub map_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExifField {
+    /// `primary` or `thumbnail`
+    pub ifd: String,
+    pub tag: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExifResult {
+    pub success: bool,
+    pub has_exif: bool,
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    pub lens_model: Option<String>,
+    pub software: Option<String>,
+    /// `YYYY-MM-DD HH:MM:SS` in the camera's local time
+    pub date_time_original: Option<String>,
+    pub iso: Option<u32>,
+    /// Shutter speed such as `1/250` or `2` (seconds)
+    pub exposure_time: Option<String>,
+    pub f_number: Option<f64>,
+    /// Millimeters
+    pub focal_length: Option<f64>,
+    /// EXIF orientation, 1 (upright) to 8
+    pub orientation: Option<u16>,
+    pub gps: Option<GpsInfo>,
+    pub fields: Vec<ExifField>,
+    pub error: Option<String>,
+}
+
+impl ExifResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+fn ascii_field(exif: &Exif, tag: Tag) -> Option<String> {
+    match &exif.get_field(tag, In::PRIMARY)?.value {
+        Value::Ascii(parts) => {
+            let text = String::from_utf8_lossy(parts.first()?);
+            let text = text.trim_matches(|c: char| c == '\0' || c.is_whitespace());
+            (!text.is_empty()).then(|| text.to_string())
+        }
+        _ => None,
+    }
+}
+
+fn rational_field(exif: &Exif, tag: Tag) -> Option<f64> {
+    match &exif.get_field(tag, In::PRIMARY)?.value {
+        Value::Rational(v) if !v.is_empty() && v[0].denom != 0 => Some(v[0].to_f64()),
+        _ => None,
+    }
+}
+
+fn uint_field(exif: &Exif, tag: Tag) -> Option<u32> {
+    exif.get_field(tag, In::PRIMARY)?.value.get_uint(0)
+}
+
+fn date_time_original(exif: &Exif) -> Option<String> {
+    let field = exif.get_field(Tag::DateTimeOriginal, In::PRIMARY)?;
+    match &field.value {
+        Value::Ascii(parts) => Some(DateTime::from_ascii(parts.first()?).ok()?.to_string()),
+        _ => None,
+    }
+}
+
+/// Shutter speed as photographers write it: `1/250` below a second, `2.5` above.
+fn format_exposure(seconds: f64) -> String {
+    if seconds > 0.0 && seconds < 1.0 {
+        format!("1/{}", (1.0 / seconds).round())
+    } else {
+        let text = format!("{:.1}", seconds);
+        text.trim_end_matches(".0").to_string()
+    }
+}
+
+/// Format absolute decimal degrees as `D°M'S.SS"` followed by the hemisphere.
+fn format_dms(degrees: f64, hemisphere: char) -> String {
+    let hundredths = (degrees.abs() * 360_000.0).round() as u64;
+    let d = hundredths / 360_000;
+    let m = hundredths % 360_000 / 6_000;
+    let s = (hundredths % 6_000) as f64 / 100.0;
+    format!("{}°{}'{:.2}\"{}", d, m, s, hemisphere)
+}
+
+/// One GPS axis as signed decimal degrees, negative for the S and W references.
+fn gps_coordinate(exif: &Exif, value_tag: Tag, ref_tag: Tag) -> Option<f64> {
+    let Value::Rational(parts) = &exif.get_field(value_tag, In::PRIMARY)?.value else {
+        return None;
+    };
+    if parts.len() < 3 || parts.iter().any(|r| r.denom == 0) {
+        return None;
+    }
+    let degrees = parts[0].to_f64() + parts[1].to_f64() / 60.0 + parts[2].to_f64() / 3600.0;
+    let negative = ascii_field(exif, ref_tag).is_some_and(|r| r == "S" || r == "W");
+    Some(if negative { -degrees } else { degrees })
+}
+
+fn gps_info(exif: &Exif) -> Option<GpsInfo> {
+    let latitude = gps_coordinate(exif, Tag::GPSLatitude, Tag::GPSLatitudeRef)?;
+    let longitude = gps_coordinate(exif, Tag::GPSLongitude, Tag::GPSLongitudeRef)?;
+    let altitude = rational_field(exif, Tag::GPSAltitude).map(|meters| {
+        if uint_field(exif, Tag::GPSAltitudeRef) == Some(1) {
+            -meters
+        } else {
+            meters
+        }
+    });
+    Some(GpsInfo {
+        latitude,
+        longitude,
+        latitude_dms: format_dms(latitude, if latitude < 0.0 { 'S' } else { 'N' }),
+        longitude_dms: format_dms(longitude, if longitude < 0.0 { 'W' } else { 'E' }),
+        altitude,
+        map_url: format!(
+            "https://www.google.com/maps?q={:.6},{:.6}",
+            latitude, longitude
+        ),
+    })
+}
+
+fn exif_result(exif: &Exif) -> ExifResult {
+    let fields = exif
+        .fields()
+        .filter(|field| field.tag != Tag::MakerNote)
+        .map(|field| {
+            let mut value = field.display_value().with_unit(exif).to_string();
+            if value.chars().count() > MAX_FIELD_VALUE_LEN {
+                value = value.chars().take(MAX_FIELD_VALUE_LEN).collect::<String>() + "…";
+            }
+            ExifField {
+                ifd: field.ifd_num.to_string(),
+                tag: field.tag.to_string(),
+                value,
+            }
+        })
+        .collect();
+
+    ExifResult {
+        success: true,
+        has_exif: true,
+        camera_make: ascii_field(exif, Tag::Make),
+        camera_model: ascii_field(exif, Tag::Model),
+        lens_model: ascii_field(exif, Tag::LensModel),
+        software: ascii_field(exif, Tag::Software),
+        date_time_original: date_time_original(exif),
+        iso: uint_field(exif, Tag::PhotographicSensitivity),
+        exposure_time: rational_field(exif, Tag::ExposureTime).map(format_exposure),
+        f_number: rational_field(exif, Tag::FNumber),
+        focal_length: rational_field(exif, Tag::FocalLength),
+        orientation: uint_field(exif, Tag::Orientation).map(|o| o as u16),
+        gps: gps_info(exif),
+        fields,
+        error: None,
+    }
+}
+
+/// Read the camera, exposure and GPS metadata embedded in an image.
+///
+/// An image without EXIF is not an error: the result succeeds with `has_exif` unset.
+pub fn read_exif(path: &str) -> ExifResult {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) => return ExifResult::failure(format!("Failed to open file: {}", e)),
+    };
+    match Reader::new().read_from_container(&mut BufReader::new(file)) {
+        Ok(exif) => exif_result(&exif),
+        Err(exif::Error::NotFound(_)) => ExifResult {
+            success: true,
+            ..ExifResult::default()
+        },
+        Err(e) => ExifResult::failure(format!("Failed to read EXIF: {}", e)),
+    }
+}
+
+/// A big-endian TIFF block holding nothing but the Orientation tag.
+fn orientation_exif(orientation: u16) -> Vec<u8> {
+    let mut tiff = b"MM\0\x2a\0\0\0\x08".to_vec();
+    tiff.extend_from_slice(&1u16.to_be_bytes());
+    tiff.extend_from_slice(&0x0112u16.to_be_bytes());
+    tiff.extend_from_slice(&3u16.to_be_bytes()); // SHORT
+    tiff.extend_from_slice(&1u32.to_be_bytes());
+    tiff.extend_from_slice(&orientation.to_be_bytes());
+    tiff.extend_from_slice(&[0, 0]);
+    tiff.extend_from_slice(&0u32.to_be_bytes()); // no next IFD
+    tiff
+}
+
+fn truncated() -> String {
+    "Image file is truncated or corrupt".to_string()
+}
+
+/// Drop the EXIF, XMP, IPTC and comment segments from a JPEG, leaving the scan data untouched.
+fn strip_jpeg(data: &[u8], exif: Option<&[u8]>) -> Result<Vec<u8>, String> {
+    let mut output = data[..2].to_vec();
+    let mut pos = 2;
+    let mut inserted = exif.is_none();
+
+    while pos < data.len() {
+        if data[pos] != 0xFF {
+            return Err(truncated());
+        }
+        let marker = *data.get(pos + 1).ok_or_else(truncated)?;
+        if marker == 0xFF {
+            // Fill byte before the actual marker
+            pos += 1;
+            continue;
+        }
+        // Keep EXIF after the JFIF header, which must stay first
+        if !inserted && marker != 0xE0 {
+            let exif = exif.unwrap_or_default();
+            let length = (EXIF_PREFIX.len() + exif.len() + 2) as u16;
+            output.extend_from_slice(&[0xFF, 0xE1]);
+            output.extend_from_slice(&length.to_be_bytes());
+            output.extend_from_slice(EXIF_PREFIX);
+            output.extend_from_slice(exif);
+            inserted = true;
+        }
+        // Start of scan or end of image: the rest is compressed data
+        if marker == 0xDA || marker == 0xD9 {
+            output.extend_from_slice(&data[pos..]);
+            return Ok(output);
+        }
+        if marker == 0x01 || (0xD0..=0xD7).contains(&marker) {
+            output.extend_from_slice(&data[pos..pos + 2]);
+            pos += 2;
+            continue;
+        }
+
+        let length = data
+            .get(pos + 2..pos + 4)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+            .ok_or_else(truncated)?;
+        let end = pos + 2 + length;
+        if length < 2 || end > data.len() {
+            return Err(truncated());
+        }
+        // APP1 (EXIF/XMP), APP13 (IPTC) and comments
+        if !matches!(marker, 0xE1 | 0xED | 0xFE) {
+            output.extend_from_slice(&data[pos..end]);
+        }
+        pos = end;
+    }
+    Err(truncated())
+}
+
+fn png_chunk(kind: &[u8; 4], contents: &[u8]) -> Vec<u8> {
+    let mut chunk = (contents.len() as u32).to_be_bytes().to_vec();
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(contents);
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(kind);
+    hasher.update(contents);
+    chunk.extend_from_slice(&hasher.finalize().to_be_bytes());
+    chunk
+}
+
+/// Drop the eXIf, text and timestamp chunks from a PNG; color and pixel chunks are kept.
+fn strip_png(data: &[u8], exif: Option<&[u8]>) -> Result<Vec<u8>, String> {
+    let mut output = PNG_SIGNATURE.to_vec();
+    let mut pos = PNG_SIGNATURE.len();
+
+    while pos < data.len() {
+        let header = data.get(pos..pos + 8).ok_or_else(truncated)?;
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let kind = &header[4..8];
+        let end = pos + 12 + length;
+        if end > data.len() {
+            return Err(truncated());
+        }
+        if !matches!(kind, b"eXIf" | b"tEXt" | b"zTXt" | b"iTXt" | b"tIME") {
+            output.extend_from_slice(&data[pos..end]);
+        }
+        // eXIf has to come before the image data
+        if kind == b"IHDR" {
+            if let Some(exif) = exif {
+                output.extend_from_slice(&png_chunk(b"eXIf", exif));
+            }
+        }
+        pos = end;
+    }
+    Ok(output)
+}
+
+const VP8X_FLAG_EXIF: u8 = 0x08;
+const VP8X_FLAG_XMP: u8 = 0x04;
+const VP8X_FLAG_ALPHA: u8 = 0x10;
+
+/// A `VP8X` chunk for a still image that has none yet, needed before EXIF can be attached.
+fn vp8x_chunk(data: &[u8], bitstream: &[u8], lossless: bool) -> Result<Vec<u8>, String> {
+    let (width, height) = image::ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| e.to_string())?
+        .into_dimensions()
+        .map_err(|e| format!("Failed to read image size: {}", e))?;
+    // VP8L stores an alpha hint in bit 28 of its header
+    let alpha = lossless
+        && bitstream.len() >= 5
+        && u32::from_le_bytes([bitstream[1], bitstream[2], bitstream[3], bitstream[4]]) & (1 << 28)
+            != 0;
+
+    let mut chunk = b"VP8X".to_vec();
+    chunk.extend_from_slice(&10u32.to_le_bytes());
+    chunk.push(if alpha { VP8X_FLAG_ALPHA } else { 0 });
+    chunk.extend_from_slice(&[0, 0, 0]);
+    chunk.extend_from_slice(&(width - 1).to_le_bytes()[..3]);
+    chunk.extend_from_slice(&(height - 1).to_le_bytes()[..3]);
+    Ok(chunk)
+}
+
+/// Drop the EXIF and XMP chunks from a WebP and clear their flags in `VP8X`.
+fn strip_webp(data: &[u8], exif: Option<&[u8]>) -> Result<Vec<u8>, String> {
+    let mut chunks: Vec<Vec<u8>> = Vec::new();
+    let mut pos = 12;
+
+    while pos < data.len() {
+        let header = data.get(pos..pos + 8).ok_or_else(truncated)?;
+        let length = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        // Chunks are padded to an even length
+        let end = (pos + 8 + length + length % 2).min(data.len());
+        if pos + 8 + length > data.len() {
+            return Err(truncated());
+        }
+        match &header[..4] {
+            b"EXIF" | b"XMP " => {}
+            _ => chunks.push(data[pos..end].to_vec()),
+        }
+        pos = end;
+    }
+
+    if let Some(exif) = exif {
+        if !chunks.iter().any(|chunk| chunk.starts_with(b"VP8X")) {
+            let bitstream = chunks
+                .iter()
+                .find(|chunk| chunk.starts_with(b"VP8"))
+                .ok_or_else(truncated)?;
+            let vp8x = vp8x_chunk(data, &bitstream[8..], bitstream.starts_with(b"VP8L"))?;
+            chunks.insert(0, vp8x);
+        }
+        let mut chunk = b"EXIF".to_vec();
+        chunk.extend_from_slice(&(exif.len() as u32).to_le_bytes());
+        chunk.extend_from_slice(exif);
+        if exif.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunks.push(chunk);
+    }
+    for chunk in &mut chunks {
+        if chunk.starts_with(b"VP8X") && chunk.len() > 8 {
+            chunk[8] &= !(VP8X_FLAG_EXIF | VP8X_FLAG_XMP);
+            if exif.is_some() {
+                chunk[8] |= VP8X_FLAG_EXIF;
+            }
+        }
+    }
+
+    let body: Vec<u8> = chunks.concat();
+    let mut output = b"RIFF".to_vec();
+    output.extend_from_slice(&((body.len() + 4) as u32).to_le_bytes());
+    output.extend_from_slice(b"WEBP");
+    output.extend_from_slice(&body);
+    Ok(output)
+}
+
+/// Remove metadata from `data` without re-encoding, optionally keeping the orientation.
+fn strip_metadata(data: &[u8], keep_orientation: bool) -> Result<Vec<u8>, String> {
+    let orientation = if keep_orientation {
+        Reader::new()
+            .read_from_container(&mut Cursor::new(data))
+            .ok()
+            .and_then(|exif| uint_field(&exif, Tag::Orientation))
+            .filter(|&o| o != 1)
+            .map(|o| orientation_exif(o as u16))
+    } else {
+        None
+    };
+    let exif = orientation.as_deref();
+
+    if data.starts_with(&[0xFF, 0xD8]) {
+        strip_jpeg(data, exif)
+    } else if data.starts_with(PNG_SIGNATURE) {
+        strip_png(data, exif)
+    } else if data.len() > 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        strip_webp(data, exif)
+    } else {
+        Err("Unsupported format: only JPEG, PNG and WebP are supported".to_string())
+    }
+}
+
+/// Remove EXIF and other metadata from an image without touching its pixels.
+///
+/// With `keep_orientation`, a minimal EXIF block holding only the Orientation tag is written
+/// back so rotated photos still display upright.
+pub fn strip_exif(input_path: &str, output_path: &str, keep_orientation: bool) -> EditResult {
+    let mut result = EditResult {
+        success: false,
+        output_path: output_path.to_string(),
+        original_size: 0,
+        new_size: 0,
+        new_width: 0,
+        new_height: 0,
+        error: None,
+    };
+
+    let data = match fs::read(input_path) {
+        Ok(data) => data,
+        Err(e) => {
+            result.error = Some(format!("Failed to read file: {}", e));
+            return result;
+        }
+    };
+    result.original_size = data.len() as u64;
+
+    let stripped = match strip_metadata(&data, keep_orientation) {
+        Ok(stripped) => stripped,
+        Err(e) => {
+            result.error = Some(e);
+            return result;
+        }
+    };
+    if let Err(e) = fs::write(output_path, &stripped) {
+        result.error = Some(format!("Failed to write file: {}", e));
+        return result;
+    }
+
+    result.success = true;
+    result.new_size = stripped.len() as u64;
+    if let Ok((width, height)) = image::image_dimensions(output_path) {
+        result.new_width = width;
+        result.new_height = height;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+    use exif::experimental::Writer;
+    use exif::{Field, Rational};
+    use image::{ImageFormat, Rgb, RgbImage};
+
+    fn ascii(tag: Tag, text: &str) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Ascii(vec![text.as_bytes().to_vec()]),
+        }
+    }
+
+    fn rationals(tag: Tag, values: &[(u32, u32)]) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Rational(
+                values
+                    .iter()
+                    .map(|&(num, denom)| Rational { num, denom })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn short(tag: Tag, value: u16) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Short(vec![value]),
+        }
+    }
+
+    /// A camera-style EXIF block with a position in Tokyo
+    fn sample_exif() -> Vec<u8> {
+        let fields = [
+            ascii(Tag::Make, "Canon"),
+            ascii(Tag::Model, "EOS R6"),
+            short(Tag::Orientation, 6),
+            ascii(Tag::DateTimeOriginal, "2024:05:01 12:34:56"),
+            short(Tag::PhotographicSensitivity, 400),
+            rationals(Tag::ExposureTime, &[(1, 250)]),
+            rationals(Tag::FNumber, &[(28, 10)]),
+            rationals(Tag::FocalLength, &[(50, 1)]),
+            ascii(Tag::GPSLatitudeRef, "N"),
+            rationals(Tag::GPSLatitude, &[(35, 1), (39, 1), (3120, 100)]),
+            ascii(Tag::GPSLongitudeRef, "E"),
+            rationals(Tag::GPSLongitude, &[(139, 1), (44, 1), (5436, 100)]),
+            rationals(Tag::GPSAltitude, &[(40, 1)]),
+        ];
+        let mut writer = Writer::new();
+        for field in &fields {
+            writer.push_field(field);
+        }
+        let mut buffer = Cursor::new(Vec::new());
+        writer.write(&mut buffer, false).unwrap();
+        buffer.into_inner()
+    }
+
+    fn encode(format: ImageFormat) -> (Vec<u8>, RgbImage) {
+        let image = RgbImage::from_fn(16, 8, |x, y| Rgb([x as u8 * 16, y as u8 * 32, 128]));
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), format)
+            .unwrap();
+        (bytes, image)
+    }
+
+    /// A JPEG carrying `exif`, an XMP packet and a comment
+    fn sample_jpeg(exif: &[u8]) -> Vec<u8> {
+        let (bytes, _) = encode(ImageFormat::Jpeg);
+        let mut segments = Vec::new();
+        let mut app1 = EXIF_PREFIX.to_vec();
+        app1.extend_from_slice(exif);
+        let xmp = b"http://ns.adobe.com/xap/1.0/\0<x:xmpmeta/>".to_vec();
+        for (marker, contents) in [(0xE1, app1), (0xE1, xmp), (0xFE, b"secret".to_vec())] {
+            segments.extend_from_slice(&[0xFF, marker]);
+            segments.extend_from_slice(&((contents.len() + 2) as u16).to_be_bytes());
+            segments.extend_from_slice(&contents);
+        }
+        [&bytes[..2], &segments, &bytes[2..]].concat()
+    }
+
+    fn read_bytes(data: &[u8], name: &str) -> ExifResult {
+        let dir = TempDir::new("exif_read");
+        let path = dir.join(name);
+        fs::write(&path, data).unwrap();
+        read_exif(path.to_str().unwrap())
+    }
+
+    #[test]
+    fn test_read_exif() {
+        let result = read_bytes(&sample_jpeg(&sample_exif()), "read.jpg");
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.has_exif);
+        assert_eq!(result.camera_make.as_deref(), Some("Canon"));
+        assert_eq!(result.camera_model.as_deref(), Some("EOS R6"));
+        assert_eq!(
+            result.date_time_original.as_deref(),
+            Some("2024-05-01 12:34:56")
+        );
+        assert_eq!(result.iso, Some(400));
+        assert_eq!(result.exposure_time.as_deref(), Some("1/250"));
+        assert_eq!(result.f_number, Some(2.8));
+        assert_eq!(result.focal_length, Some(50.0));
+        assert_eq!(result.orientation, Some(6));
+        assert!(result.fields.iter().any(|f| f.tag == "Model"));
+
+        let gps = result.gps.unwrap();
+        assert!((gps.latitude - 35.658667).abs() < 1e-6);
+        assert!((gps.longitude - 139.7484333).abs() < 1e-6);
+        assert_eq!(gps.latitude_dms, "35°39'31.20\"N");
+        assert_eq!(gps.longitude_dms, "139°44'54.36\"E");
+        assert_eq!(gps.altitude, Some(40.0));
+        assert_eq!(
+            gps.map_url,
+            "https://www.google.com/maps?q=35.658667,139.748433"
+        );
+    }
+
+    #[test]
+    fn test_read_without_exif() {
+        let (png, _) = encode(ImageFormat::Png);
+        let result = read_bytes(&png, "none.png");
+        assert!(result.success);
+        assert!(!result.has_exif);
+        assert!(result.gps.is_none());
+
+        let missing = read_exif("/nonexistent/taurin.jpg");
+        assert!(!missing.success);
+        assert!(missing.error.unwrap().contains("Failed to open file"));
+    }
+
+    #[test]
+    fn test_format_helpers() {
+        assert_eq!(format_exposure(1.0 / 250.0), "1/250");
+        assert_eq!(format_exposure(2.0), "2");
+        assert_eq!(format_exposure(2.5), "2.5");
+        assert_eq!(format_dms(-33.8688, 'S'), "33°52'7.68\"S");
+        // Seconds that round up carry into the minutes
+        assert_eq!(format_dms(10.999_999_9, 'N'), "11°0'0.00\"N");
+    }
+
+    #[test]
+    fn test_strip_jpeg() {
+        let original = sample_jpeg(&sample_exif());
+        let stripped = strip_metadata(&original, false).unwrap();
+        assert!(stripped.len() < original.len());
+        assert!(!stripped.windows(4).any(|w| w == b"Exif"));
+        assert!(!stripped.windows(6).any(|w| w == b"secret"));
+        assert!(!stripped.windows(7).any(|w| w == b"xmpmeta"));
+        // The compressed image data is carried over byte for byte
+        let (plain, _) = encode(ImageFormat::Jpeg);
+        assert_eq!(stripped, plain);
+        assert!(!read_bytes(&stripped, "stripped.jpg").has_exif);
+    }
+
+    #[test]
+    fn test_strip_keeps_orientation() {
+        let stripped = strip_metadata(&sample_jpeg(&sample_exif()), true).unwrap();
+        let result = read_bytes(&stripped, "oriented.jpg");
+        assert!(result.has_exif);
+        assert_eq!(result.orientation, Some(6));
+        assert!(result.camera_model.is_none());
+        assert!(result.gps.is_none());
+        assert_eq!(result.fields.len(), 1);
+        image::load_from_memory(&stripped).unwrap();
+
+        // Upright images need no EXIF at all
+        let upright = strip_metadata(&sample_jpeg(&orientation_exif(1)), true).unwrap();
+        assert!(!read_bytes(&upright, "upright.jpg").has_exif);
+    }
+
+    #[test]
+    fn test_strip_png() {
+        let (png, image) = encode(ImageFormat::Png);
+        let iend = png.len() - 12;
+        let tagged = [
+            &png[..iend],
+            &png_chunk(b"tEXt", b"Comment\0secret"),
+            &png_chunk(b"eXIf", &sample_exif()),
+            &png[iend..],
+        ]
+        .concat();
+        assert!(read_bytes(&tagged, "tagged.png").has_exif);
+
+        let stripped = strip_metadata(&tagged, false).unwrap();
+        assert_eq!(stripped, png);
+
+        let oriented = strip_metadata(&tagged, true).unwrap();
+        assert_eq!(read_bytes(&oriented, "oriented.png").orientation, Some(6));
+        let decoded = image::load_from_memory(&oriented).unwrap().to_rgb8();
+        assert_eq!(decoded, image);
+    }
+
+    #[test]
+    fn test_strip_webp() {
+        let (webp, image) = encode(ImageFormat::WebP);
+        let mut exif_chunk = b"EXIF".to_vec();
+        let exif = sample_exif();
+        exif_chunk.extend_from_slice(&(exif.len() as u32).to_le_bytes());
+        exif_chunk.extend_from_slice(&exif);
+        if exif.len() % 2 == 1 {
+            exif_chunk.push(0);
+        }
+        let mut vp8x = b"VP8X".to_vec();
+        vp8x.extend_from_slice(&10u32.to_le_bytes());
+        vp8x.extend_from_slice(&[VP8X_FLAG_EXIF, 0, 0, 0, 15, 0, 0, 7, 0, 0]);
+        let body = [&vp8x, &webp[12..], &exif_chunk].concat();
+        let tagged = [
+            b"RIFF".as_slice(),
+            &((body.len() + 4) as u32).to_le_bytes(),
+            b"WEBP",
+            &body,
+        ]
+        .concat();
+        assert!(read_bytes(&tagged, "tagged.webp").has_exif);
+
+        let stripped = strip_metadata(&tagged, false).unwrap();
+        assert!(!read_bytes(&stripped, "stripped.webp").has_exif);
+        assert_eq!(stripped[12 + 8] & VP8X_FLAG_EXIF, 0);
+        assert_eq!(image::load_from_memory(&stripped).unwrap().to_rgb8(), image);
+
+        // A plain lossless WebP gets a VP8X header so the orientation can be attached
+        let oriented = strip_metadata(&tagged, true).unwrap();
+        assert_eq!(read_bytes(&oriented, "oriented.webp").orientation, Some(6));
+        let with_orientation = strip_webp(&webp, Some(&orientation_exif(3))).unwrap();
+        assert_eq!(&with_orientation[12..16], b"VP8X");
+        assert_eq!(
+            read_bytes(&with_orientation, "upgraded.webp").orientation,
+            Some(3)
+        );
+        assert_eq!(
+            image::load_from_memory(&with_orientation)
+                .unwrap()
+                .to_rgb8(),
+            image
+        );
+    }
+
+    #[test]
+    fn test_strip_exif_file() {
+        let dir = TempDir::new("exif_strip");
+        let input = dir.join("input.jpg");
+        let output = dir.join("output.jpg");
+        fs::write(&input, sample_jpeg(&sample_exif())).unwrap();
+        let result = strip_exif(input.to_str().unwrap(), output.to_str().unwrap(), false);
+        let stripped = read_exif(output.to_str().unwrap());
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.new_size < result.original_size);
+        assert_eq!((result.new_width, result.new_height), (16, 8));
+        assert!(!stripped.has_exif);
+
+        let bmp = dir.join("input.bmp");
+        fs::write(&bmp, encode(ImageFormat::Bmp).0).unwrap();
+        let result = strip_exif(bmp.to_str().unwrap(), output.to_str().unwrap(), false);
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unsupported format"));
+    }
+}
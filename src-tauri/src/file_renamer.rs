@@ -0,0 +1,551 @@
+use crate::regex_tester::{replace_regex, RegexFlags};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AffixPosition {
+    #[default]
+    Prefix,
+    Suffix,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NumberingRule {
+    pub start: u64,
+    pub step: u64,
+    /// Minimum width of the number
+    pub digits: usize,
+    /// Pad to `digits` with zeros rather than leaving the number as is
+    pub zero_pad: bool,
+    pub position: AffixPosition,
+    pub separator: String,
+}
+
+impl Default for NumberingRule {
+    fn default() -> Self {
+        Self {
+            start: 1,
+            step: 1,
+            digits: 3,
+            zero_pad: true,
+            position: AffixPosition::Prefix,
+            separator: "_".to_string(),
+        }
+    }
+}
+
+/// Inserts the file's modification time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DateInsertRule {
+    /// A chrono strftime format such as `%Y%m%d`
+    pub format: String,
+    pub position: AffixPosition,
+    pub separator: String,
+}
+
+impl Default for DateInsertRule {
+    fn default() -> Self {
+        Self {
+            format: "%Y%m%d".to_string(),
+            position: AffixPosition::Prefix,
+            separator: "_".to_string(),
+        }
+    }
+}
+
+/// Steps applied to each file name, in field order; all of them are optional
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RenameRule {
+    /// Text to find in the name without its extension; empty skips the replacement
+    pub find: String,
+    pub replace: String,
+    /// Treat `find` as a regular expression; `replace` may then use `$1` and `${name}`
+    pub use_regex: bool,
+    pub case_insensitive: bool,
+    pub date: Option<DateInsertRule>,
+    pub numbering: Option<NumberingRule>,
+    pub prefix: String,
+    pub suffix: String,
+    pub lowercase_extension: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamePair {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamePreviewItem {
+    pub from: String,
+    pub to: String,
+    pub old_name: String,
+    pub new_name: String,
+    pub changed: bool,
+    /// Another file in the batch, or one already on disk, ends up with the same name
+    pub conflict: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamePreviewResult {
+    pub success: bool,
+    pub items: Vec<RenamePreviewItem>,
+    /// The renames to pass to `apply_rename`; unchanged files are left out
+    pub plan: Vec<RenamePair>,
+    pub conflicts: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameApplyResult {
+    pub success: bool,
+    pub renamed: usize,
+    /// Feeding this back into `apply_rename` restores the original names
+    pub undo: Vec<RenamePair>,
+    pub error: Option<String>,
+}
+
+impl RenameApplyResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+/// Split `name` into stem and extension (without the dot); dotfiles have no extension
+fn split_name(name: &str) -> (&str, Option<&str>) {
+    match name.rfind('.') {
+        Some(i) if i > 0 => (&name[..i], Some(&name[i + 1..])),
+        _ => (name, None),
+    }
+}
+
+fn add_affix(stem: String, text: &str, separator: &str, position: AffixPosition) -> String {
+    match position {
+        AffixPosition::Prefix => format!("{}{}{}", text, separator, stem),
+        AffixPosition::Suffix => format!("{}{}{}", stem, separator, text),
+    }
+}
+
+fn format_number(value: u64, rule: &NumberingRule) -> String {
+    if rule.zero_pad {
+        format!("{:0width$}", value, width = rule.digits)
+    } else {
+        value.to_string()
+    }
+}
+
+fn modified_date(path: &Path, format: &str) -> Result<String, String> {
+    let modified = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map_err(|e| format!("Failed to read modification time: {}", e))?;
+    let mut text = String::new();
+    write!(text, "{}", DateTime::<Local>::from(modified).format(format))
+        .map_err(|_| format!("Invalid date format: {}", format))?;
+    Ok(text)
+}
+
+/// Build the new file name for the `index`-th file of the batch
+fn rename_one(path: &Path, index: usize, rule: &RenameRule) -> Result<String, String> {
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| "Path has no file name".to_string())?;
+    let (stem, extension) = split_name(name);
+
+    let mut stem = if rule.find.is_empty() {
+        stem.to_string()
+    } else if rule.use_regex {
+        let flags = RegexFlags {
+            global: true,
+            case_insensitive: rule.case_insensitive,
+            multiline: false,
+            dot_all: false,
+        };
+        let result = replace_regex(&rule.find, stem, &rule.replace, flags);
+        if !result.success {
+            return Err(result
+                .error
+                .unwrap_or_else(|| "Invalid pattern".to_string()));
+        }
+        result.result
+    } else if rule.case_insensitive {
+        let pattern = format!("(?i){}", regex::escape(&rule.find));
+        regex::Regex::new(&pattern)
+            .map_err(|e| e.to_string())?
+            .replace_all(stem, regex::NoExpand(&rule.replace))
+            .into_owned()
+    } else {
+        stem.replace(&rule.find, &rule.replace)
+    };
+
+    if let Some(date) = &rule.date {
+        let text = modified_date(path, &date.format)?;
+        stem = add_affix(stem, &text, &date.separator, date.position);
+    }
+    if let Some(numbering) = &rule.numbering {
+        let value = numbering
+            .start
+            .saturating_add(numbering.step.saturating_mul(index as u64));
+        let text = format_number(value, numbering);
+        stem = add_affix(stem, &text, &numbering.separator, numbering.position);
+    }
+    stem = format!("{}{}{}", rule.prefix, stem, rule.suffix);
+
+    let new_name = match extension {
+        Some(ext) if rule.lowercase_extension => format!("{}.{}", stem, ext.to_lowercase()),
+        Some(ext) => format!("{}.{}", stem, ext),
+        None => stem,
+    };
+    if new_name.trim().is_empty() {
+        return Err("New name is empty".to_string());
+    }
+    if new_name.contains(['/', '\\']) || new_name == "." || new_name == ".." {
+        return Err(format!("\"{}\" is not a valid file name", new_name));
+    }
+    Ok(new_name)
+}
+
+/// Names are compared without case so that plans are safe on case-insensitive file systems
+fn conflict_key(path: &Path) -> String {
+    path.to_string_lossy().to_lowercase()
+}
+
+/// Preview the new names for `paths` under `rule` and flag any collisions
+pub fn preview_rename(paths: Vec<String>, rule: &RenameRule) -> RenamePreviewResult {
+    if paths.is_empty() {
+        return RenamePreviewResult {
+            error: Some("No files selected".to_string()),
+            ..RenamePreviewResult::default()
+        };
+    }
+
+    let mut items: Vec<RenamePreviewItem> = paths
+        .iter()
+        .enumerate()
+        .map(|(index, from)| {
+            let path = Path::new(from);
+            let old_name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            match rename_one(path, index, rule) {
+                Ok(new_name) => {
+                    let to = path.with_file_name(&new_name).to_string_lossy().to_string();
+                    RenamePreviewItem {
+                        changed: to != *from,
+                        from: from.clone(),
+                        to,
+                        old_name,
+                        new_name,
+                        conflict: false,
+                        error: None,
+                    }
+                }
+                Err(e) => RenamePreviewItem {
+                    from: from.clone(),
+                    to: from.clone(),
+                    new_name: old_name.clone(),
+                    old_name,
+                    changed: false,
+                    conflict: false,
+                    error: Some(e),
+                },
+            }
+        })
+        .collect();
+
+    // Every file ends up at `to`, renamed or not, so any shared target is a collision
+    let mut targets: HashMap<String, usize> = HashMap::new();
+    for item in &items {
+        *targets
+            .entry(conflict_key(Path::new(&item.to)))
+            .or_default() += 1;
+    }
+    let sources: HashSet<String> = items
+        .iter()
+        .map(|item| conflict_key(Path::new(&item.from)))
+        .collect();
+    for item in &mut items {
+        let key = conflict_key(Path::new(&item.to));
+        let duplicate = targets.get(&key).copied().unwrap_or(0) > 1;
+        // Renaming onto a file outside the batch would overwrite it
+        let occupied = item.changed
+            && !sources.contains(&key)
+            && Path::new(&item.to).exists()
+            && !is_same_file(&item.from, &item.to);
+        item.conflict = duplicate || occupied;
+    }
+
+    let conflicts = items.iter().filter(|item| item.conflict).count();
+    let plan = items
+        .iter()
+        .filter(|item| item.changed && item.error.is_none())
+        .map(|item| RenamePair {
+            from: item.from.clone(),
+            to: item.to.clone(),
+        })
+        .collect();
+    RenamePreviewResult {
+        success: true,
+        items,
+        plan,
+        conflicts,
+        error: None,
+    }
+}
+
+/// True when `to` only differs from `from` in case and names the same file
+fn is_same_file(from: &str, to: &str) -> bool {
+    match (fs::canonicalize(from), fs::canonicalize(to)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn validate_plan(plan: &[RenamePair]) -> Result<(), String> {
+    let mut targets = HashSet::new();
+    let sources: HashSet<String> = plan
+        .iter()
+        .map(|pair| conflict_key(Path::new(&pair.from)))
+        .collect();
+    for pair in plan {
+        if !Path::new(&pair.from).exists() {
+            return Err(format!("File not found: {}", pair.from));
+        }
+        let key = conflict_key(Path::new(&pair.to));
+        if !targets.insert(key.clone()) {
+            return Err(format!(
+                "More than one file would be renamed to {}",
+                pair.to
+            ));
+        }
+        if !sources.contains(&key)
+            && Path::new(&pair.to).exists()
+            && !is_same_file(&pair.from, &pair.to)
+        {
+            return Err(format!("{} already exists", pair.to));
+        }
+    }
+    Ok(())
+}
+
+/// A name next to `path` that does not exist yet, used as a stepping stone
+fn temporary_path(path: &Path, index: usize) -> PathBuf {
+    let mut n = 0;
+    loop {
+        let candidate = path.with_file_name(format!(".taurin-rename-{}-{}", index, n));
+        if !candidate.exists() {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+/// Move renamed files back, newest first; failures are ignored since this is already the error path
+fn roll_back(done: &[(PathBuf, PathBuf)]) {
+    for (from, to) in done.iter().rev() {
+        let _ = fs::rename(to, from);
+    }
+}
+
+/// Carry out a plan from `preview_rename`. Nothing is renamed if the plan has any
+/// collision, and a failure part way through puts back what was already moved
+pub fn apply_rename(plan: Vec<RenamePair>) -> RenameApplyResult {
+    if plan.is_empty() {
+        return RenameApplyResult::failure("Nothing to rename".to_string());
+    }
+    if let Err(e) = validate_plan(&plan) {
+        return RenameApplyResult::failure(e);
+    }
+
+    // Going through temporary names lets swaps and chains (a -> b, b -> a) work
+    let mut done: Vec<(PathBuf, PathBuf)> = Vec::with_capacity(plan.len() * 2);
+    let mut staged = Vec::with_capacity(plan.len());
+    for (index, pair) in plan.iter().enumerate() {
+        let from = PathBuf::from(&pair.from);
+        let temp = temporary_path(&from, index);
+        if let Err(e) = fs::rename(&from, &temp) {
+            roll_back(&done);
+            return RenameApplyResult::failure(format!("Failed to rename {}: {}", pair.from, e));
+        }
+        done.push((from, temp.clone()));
+        staged.push((temp, PathBuf::from(&pair.to)));
+    }
+    for (temp, to) in staged {
+        if let Err(e) = fs::rename(&temp, &to) {
+            roll_back(&done);
+            return RenameApplyResult::failure(format!(
+                "Failed to rename to {}: {}",
+                to.display(),
+                e
+            ));
+        }
+        done.push((temp, to));
+    }
+
+    RenameApplyResult {
+        success: true,
+        renamed: plan.len(),
+        undo: plan
+            .iter()
+            .map(|pair| RenamePair {
+                from: pair.to.clone(),
+                to: pair.from.clone(),
+            })
+            .collect(),
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn test_dir(name: &str, files: &[&str]) -> TempDir {
+        let dir = TempDir::new(&format!("file_renamer_{}", name));
+        for file in files {
+            fs::write(dir.join(file), file).unwrap();
+        }
+        dir
+    }
+
+    fn paths(dir: &Path, files: &[&str]) -> Vec<String> {
+        files
+            .iter()
+            .map(|f| dir.join(f).to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_preview_combines_rules() {
+        let files = ["IMG_0001.JPG", "IMG_0002.JPG", ".env"];
+        let dir = test_dir("combine", &files);
+        let rule = RenameRule {
+            find: r"IMG_(\d+)".to_string(),
+            replace: "photo-$1".to_string(),
+            use_regex: true,
+            numbering: Some(NumberingRule {
+                start: 9,
+                ..NumberingRule::default()
+            }),
+            suffix: "_v".to_string(),
+            lowercase_extension: true,
+            ..RenameRule::default()
+        };
+        let result = preview_rename(paths(&dir, &files), &rule);
+        assert!(result.success);
+        let names: Vec<&str> = result.items.iter().map(|i| i.new_name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["009_photo-0001_v.jpg", "010_photo-0002_v.jpg", "011_.env_v"]
+        );
+        assert_eq!(result.conflicts, 0);
+        assert_eq!(result.plan.len(), 3);
+    }
+
+    #[test]
+    fn test_preview_inserts_modified_date() {
+        let files = ["report.txt"];
+        let dir = test_dir("date", &files);
+        let expected = modified_date(&dir.join("report.txt"), "%Y-%m-%d").unwrap();
+        let rule = RenameRule {
+            date: Some(DateInsertRule {
+                format: "%Y-%m-%d".to_string(),
+                position: AffixPosition::Suffix,
+                separator: " ".to_string(),
+            }),
+            ..RenameRule::default()
+        };
+        let result = preview_rename(paths(&dir, &files), &rule);
+        assert_eq!(result.items[0].new_name, format!("report {}.txt", expected));
+    }
+
+    #[test]
+    fn test_conflicts_block_apply() {
+        let files = ["a-1.txt", "a_1.txt", "b.txt", "c.txt"];
+        let dir = test_dir("conflict", &files);
+        let rule = RenameRule {
+            find: "-".to_string(),
+            replace: "_".to_string(),
+            ..RenameRule::default()
+        };
+        let result = preview_rename(paths(&dir, &files[..2]), &rule);
+        assert_eq!(result.conflicts, 2);
+
+        // b.txt -> c.txt would overwrite a file outside the batch
+        let rule = RenameRule {
+            find: "b".to_string(),
+            replace: "c".to_string(),
+            ..RenameRule::default()
+        };
+        let result = preview_rename(paths(&dir, &files[2..3]), &rule);
+        assert_eq!(result.conflicts, 1);
+        let applied = apply_rename(result.plan);
+        assert!(!applied.success);
+        assert_eq!(fs::read_to_string(dir.join("c.txt")).unwrap(), "c.txt");
+    }
+
+    #[test]
+    fn test_apply_swap_and_undo() {
+        let files = ["one.txt", "two.txt"];
+        let dir = test_dir("swap", &files);
+        let plan = vec![
+            RenamePair {
+                from: dir.join("one.txt").to_string_lossy().to_string(),
+                to: dir.join("two.txt").to_string_lossy().to_string(),
+            },
+            RenamePair {
+                from: dir.join("two.txt").to_string_lossy().to_string(),
+                to: dir.join("one.txt").to_string_lossy().to_string(),
+            },
+        ];
+        let result = apply_rename(plan);
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.renamed, 2);
+        assert_eq!(fs::read_to_string(dir.join("two.txt")).unwrap(), "one.txt");
+
+        let undone = apply_rename(result.undo);
+        assert!(undone.success);
+        assert_eq!(fs::read_to_string(dir.join("one.txt")).unwrap(), "one.txt");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_reported_per_file() {
+        let files = ["x.txt"];
+        let dir = test_dir("invalid", &files);
+        let rule = RenameRule {
+            find: "(".to_string(),
+            use_regex: true,
+            ..RenameRule::default()
+        };
+        let result = preview_rename(paths(&dir, &files), &rule);
+        assert!(result.items[0].error.is_some());
+        assert!(result.plan.is_empty());
+
+        let rule = RenameRule {
+            find: "x".to_string(),
+            replace: "a/b".to_string(),
+            ..RenameRule::default()
+        };
+        let result = preview_rename(paths(&dir, &files), &rule);
+        assert!(result.items[0].error.is_some());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::settings::load_settings;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// Oldest entries are dropped once a tool's file holds more than this
+const MAX_ENTRIES_PER_TOOL: usize = 1000;
+/// Tools whose output is a secret; they are only saved when the user opts in
+const SENSITIVE_TOOLS: [&str; 1] = ["password_generator"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerationEntry {
+    pub value: String,
+    pub created_at: String,
+}
+
+fn get_history_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let dir = app_data_dir.join("generation_history");
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create history dir: {}", e))?;
+    Ok(dir)
+}
+
+/// `<dir>/<tool>.jsonl`; the tool name becomes a file name, so only `[A-Za-z0-9_-]` is allowed
+fn tool_history_path(dir: &Path, tool: &str) -> Result<PathBuf, String> {
+    let valid = !tool.is_empty()
+        && tool
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !valid {
+        return Err(format!("Invalid tool name: {}", tool));
+    }
+    Ok(dir.join(format!("{}.jsonl", tool)))
+}
+
+/// Lines that fail to parse are skipped, so one bad write doesn't lose the whole history
+fn read_entries(path: &Path) -> Vec<GenerationEntry> {
+    fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Append `values` to the history file, oldest first, and rotate out entries beyond
+/// `MAX_ENTRIES_PER_TOOL`
+fn append_entries(path: &Path, values: Vec<String>, created_at: &str) -> Result<usize, String> {
+    let new_entries: Vec<GenerationEntry> = values
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .map(|value| GenerationEntry {
+            value,
+            created_at: created_at.to_string(),
+        })
+        .collect();
+    if new_entries.is_empty() {
+        return Ok(0);
+    }
+    let added = new_entries.len();
+
+    let existing = read_entries(path);
+    let to_line = |entry: &GenerationEntry| {
+        serde_json::to_string(entry).map_err(|e| format!("Failed to serialize: {}", e))
+    };
+
+    if existing.len() + added > MAX_ENTRIES_PER_TOOL {
+        let skip = existing.len() + added - MAX_ENTRIES_PER_TOOL;
+        let mut content = String::new();
+        for entry in existing.iter().chain(&new_entries).skip(skip) {
+            content.push_str(&to_line(entry)?);
+            content.push('\n');
+        }
+        fs::write(path, content).map_err(|e| format!("Failed to write history file: {}", e))?;
+    } else {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("Failed to open history file: {}", e))?;
+        for entry in &new_entries {
+            writeln!(file, "{}", to_line(entry)?)
+                .map_err(|e| format!("Failed to write history file: {}", e))?;
+        }
+    }
+    Ok(added)
+}
+
+/// Save generated values for `tool`, returning how many were saved.
+///
+/// Output of sensitive tools is silently dropped unless password history is enabled in
+/// the settings.
+pub fn append_history(
+    app: &AppHandle,
+    tool: String,
+    entries: Vec<String>,
+) -> Result<usize, String> {
+    if SENSITIVE_TOOLS.contains(&tool.as_str())
+        && !load_settings(app).generation_history.save_passwords
+    {
+        return Ok(0);
+    }
+    let path = tool_history_path(&get_history_dir(app)?, &tool)?;
+    append_entries(&path, entries, &chrono::Utc::now().to_rfc3339())
+}
+
+/// Up to `limit` entries for `tool`, newest first
+pub fn get_history(
+    app: &AppHandle,
+    tool: String,
+    limit: u32,
+) -> Result<Vec<GenerationEntry>, String> {
+    let path = tool_history_path(&get_history_dir(app)?, &tool)?;
+    let mut entries = read_entries(&path);
+    entries.reverse();
+    entries.truncate(limit as usize);
+    Ok(entries)
+}
+
+pub fn clear_history(app: &AppHandle, tool: String) -> Result<(), String> {
+    let path = tool_history_path(&get_history_dir(app)?, &tool)?;
+    match fs::remove_file(&path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("Failed to delete history file: {}", e))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_tool_names_are_restricted() {
+        let dir = Path::new("/tmp");
+        assert!(tool_history_path(dir, "uuid_generator").is_ok());
+        assert!(tool_history_path(dir, "../settings").is_err());
+        assert!(tool_history_path(dir, "").is_err());
+    }
+
+    #[test]
+    fn test_append_rotates_and_skips_bad_lines() {
+        let dir = TempDir::new("generation_history_rotate");
+        let path = tool_history_path(&dir, "uuid_generator").unwrap();
+
+        let values = |range: std::ops::Range<usize>| range.map(|i| i.to_string()).collect();
+        assert_eq!(append_entries(&path, values(0..600), "t1").unwrap(), 600);
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+        assert_eq!(append_entries(&path, values(600..1100), "t2").unwrap(), 500);
+        assert_eq!(append_entries(&path, vec![String::new()], "t3").unwrap(), 0);
+
+        let entries = read_entries(&path);
+        assert_eq!(entries.len(), MAX_ENTRIES_PER_TOOL);
+        assert_eq!(entries[0].value, "100");
+        assert_eq!(entries.last().unwrap().value, "1099");
+        assert_eq!(entries.last().unwrap().created_at, "t2");
+    }
+}
@@ -0,0 +1,845 @@
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+use xxhash_rust::xxh64::Xxh64;
+
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashResult {
+    pub md5: String,
+    pub sha1: String,
+    pub sha256: String,
+    pub sha512: String,
+    pub crc32: String,
+    pub xxh64: String,
+    pub blake3: String,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashCompareResult {
+    pub matches: bool,
+    pub normalized_a: String,
+    pub normalized_b: String,
+}
+
+/// Computes every supported digest in a single pass over the input
+struct MultiHasher {
+    md5: Md5,
+    sha1: Sha1,
+    sha256: Sha256,
+    sha512: Sha512,
+    crc32: crc32fast::Hasher,
+    xxh64: Xxh64,
+    blake3: blake3::Hasher,
+    size_bytes: u64,
+}
+
+impl MultiHasher {
+    fn new() -> Self {
+        Self {
+            md5: Md5::new(),
+            sha1: Sha1::new(),
+            sha256: Sha256::new(),
+            sha512: Sha512::new(),
+            crc32: crc32fast::Hasher::new(),
+            xxh64: Xxh64::new(0),
+            blake3: blake3::Hasher::new(),
+            size_bytes: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.md5.update(data);
+        self.sha1.update(data);
+        self.sha256.update(data);
+        self.sha512.update(data);
+        self.crc32.update(data);
+        self.xxh64.update(data);
+        self.blake3.update(data);
+        self.size_bytes += data.len() as u64;
+    }
+
+    fn finalize(self, uppercase: bool) -> HashResult {
+        HashResult {
+            md5: to_hex(&self.md5.finalize(), uppercase),
+            sha1: to_hex(&self.sha1.finalize(), uppercase),
+            sha256: to_hex(&self.sha256.finalize(), uppercase),
+            sha512: to_hex(&self.sha512.finalize(), uppercase),
+            crc32: to_hex(&self.crc32.finalize().to_be_bytes(), uppercase),
+            xxh64: to_hex(&self.xxh64.digest().to_be_bytes(), uppercase),
+            blake3: to_hex(self.blake3.finalize().as_bytes(), uppercase),
+            size_bytes: self.size_bytes,
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8], uppercase: bool) -> String {
+    bytes
+        .iter()
+        .map(|b| {
+            if uppercase {
+                format!("{:02X}", b)
+            } else {
+                format!("{:02x}", b)
+            }
+        })
+        .collect()
+}
+
+/// Hash a UTF-8 string with all supported algorithms
+pub fn compute_text_hashes(input: &str, uppercase: bool) -> HashResult {
+    let mut hasher = MultiHasher::new();
+    hasher.update(input.as_bytes());
+    hasher.finalize(uppercase)
+}
+
+/// Hash a file with all supported algorithms, streaming it in fixed-size chunks
+pub fn compute_file_hashes(path: &str) -> Result<HashResult, String> {
+    let file_path = Path::new(path);
+
+    if !file_path.exists() {
+        return Err("File not found".to_string());
+    }
+    if !file_path.is_file() {
+        return Err("Path is not a file".to_string());
+    }
+
+    let mut hasher = MultiHasher::new();
+    read_chunks(file_path, |chunk| hasher.update(chunk))?;
+
+    Ok(hasher.finalize(false))
+}
+
+/// Stream a file through `on_chunk` in `READ_BUFFER_SIZE` pieces
+fn read_chunks<F>(path: &Path, mut on_chunk: F) -> Result<(), String>
+where
+    F: FnMut(&[u8]),
+{
+    let file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut reader = BufReader::with_capacity(READ_BUFFER_SIZE, file);
+    let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+
+    loop {
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read file: {}", e))?;
+        if read == 0 {
+            return Ok(());
+        }
+        on_chunk(&buffer[..read]);
+    }
+}
+
+/// Compare two hash strings ignoring case and whitespace
+pub fn compare_hashes(a: &str, b: &str) -> HashCompareResult {
+    let normalized_a = normalize_hash(a);
+    let normalized_b = normalize_hash(b);
+    let matches = !normalized_a.is_empty() && normalized_a == normalized_b;
+
+    HashCompareResult {
+        matches,
+        normalized_a,
+        normalized_b,
+    }
+}
+
+fn normalize_hash(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Bytes hashed between two progress events while verifying or generating
+const PROGRESS_STEP_BYTES: u64 = 4 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChecksumAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+    Crc32,
+    Xxh64,
+    Blake3,
+}
+
+impl ChecksumAlgorithm {
+    /// Tag written in front of BSD-style lines, as `shasum --tag` does
+    fn bsd_tag(self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5",
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+            Self::Sha512 => "SHA512",
+            Self::Crc32 => "CRC32",
+            Self::Xxh64 => "XXH64",
+            Self::Blake3 => "BLAKE3",
+        }
+    }
+
+    fn from_bsd_tag(tag: &str) -> Option<Self> {
+        match tag.to_ascii_uppercase().replace('-', "").as_str() {
+            "MD5" => Some(Self::Md5),
+            "SHA1" => Some(Self::Sha1),
+            "SHA256" => Some(Self::Sha256),
+            "SHA512" => Some(Self::Sha512),
+            "CRC32" => Some(Self::Crc32),
+            "XXH64" | "XXHASH64" => Some(Self::Xxh64),
+            "BLAKE3" => Some(Self::Blake3),
+            _ => None,
+        }
+    }
+
+    /// GNU-style lines carry no algorithm name, so it is inferred from the
+    /// digest length. SHA-256 and BLAKE3 share a length; `prefer_blake3` picks
+    /// BLAKE3 for `.b3` / `b3sum` style checksum files.
+    fn from_hex_len(len: usize, prefer_blake3: bool) -> Option<Self> {
+        match len {
+            8 => Some(Self::Crc32),
+            16 => Some(Self::Xxh64),
+            32 => Some(Self::Md5),
+            40 => Some(Self::Sha1),
+            64 if prefer_blake3 => Some(Self::Blake3),
+            64 => Some(Self::Sha256),
+            128 => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+
+    fn hex_len(self) -> usize {
+        match self {
+            Self::Crc32 => 8,
+            Self::Xxh64 => 16,
+            Self::Md5 => 32,
+            Self::Sha1 => 40,
+            Self::Sha256 | Self::Blake3 => 64,
+            Self::Sha512 => 128,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChecksumFormat {
+    /// `<hash>  <file>`, as written by `sha256sum`
+    #[default]
+    Gnu,
+    /// `SHA256 (<file>) = <hash>`, as written by `shasum --tag`
+    Bsd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChecksumStatus {
+    Match,
+    Mismatch,
+    Missing,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksumEntry {
+    pub file_name: String,
+    pub path: String,
+    pub algorithm: ChecksumAlgorithm,
+    pub expected: String,
+    pub actual: Option<String>,
+    pub status: ChecksumStatus,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksumVerifyResult {
+    pub success: bool,
+    pub entries: Vec<ChecksumEntry>,
+    pub matched: usize,
+    pub mismatched: usize,
+    pub missing: usize,
+    pub failed: usize,
+    pub invalid_lines: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksumGenerateResult {
+    pub success: bool,
+    pub output_path: String,
+    pub content: String,
+    pub entry_count: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksumProgress {
+    pub current_file: String,
+    pub processed_files: usize,
+    pub total_files: usize,
+    pub processed_bytes: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ChecksumLine {
+    algorithm: ChecksumAlgorithm,
+    expected: String,
+    file_name: String,
+}
+
+/// Hashes with a single algorithm, for checksum files where computing every
+/// digest of a large download would be wasted work
+enum SingleHasher {
+    Md5(Md5),
+    Sha1(Sha1),
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Crc32(crc32fast::Hasher),
+    Xxh64(Xxh64),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl SingleHasher {
+    fn new(algorithm: ChecksumAlgorithm) -> Self {
+        match algorithm {
+            ChecksumAlgorithm::Md5 => Self::Md5(Md5::new()),
+            ChecksumAlgorithm::Sha1 => Self::Sha1(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+            ChecksumAlgorithm::Sha512 => Self::Sha512(Sha512::new()),
+            ChecksumAlgorithm::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Xxh64 => Self::Xxh64(Xxh64::new(0)),
+            ChecksumAlgorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Md5(h) => h.update(data),
+            Self::Sha1(h) => h.update(data),
+            Self::Sha256(h) => h.update(data),
+            Self::Sha512(h) => h.update(data),
+            Self::Crc32(h) => h.update(data),
+            Self::Xxh64(h) => h.update(data),
+            Self::Blake3(h) => {
+                h.update(data);
+            }
+        }
+    }
+
+    fn finalize(self) -> String {
+        match self {
+            Self::Md5(h) => to_hex(&h.finalize(), false),
+            Self::Sha1(h) => to_hex(&h.finalize(), false),
+            Self::Sha256(h) => to_hex(&h.finalize(), false),
+            Self::Sha512(h) => to_hex(&h.finalize(), false),
+            Self::Crc32(h) => to_hex(&h.finalize().to_be_bytes(), false),
+            Self::Xxh64(h) => to_hex(&h.digest().to_be_bytes(), false),
+            Self::Blake3(h) => to_hex(h.finalize().as_bytes(), false),
+        }
+    }
+}
+
+/// Tracks bytes across all files and throttles progress events
+struct ProgressTracker<F: FnMut(ChecksumProgress)> {
+    on_progress: F,
+    total_files: usize,
+    total_bytes: u64,
+    processed_bytes: u64,
+    last_reported: u64,
+}
+
+impl<F: FnMut(ChecksumProgress)> ProgressTracker<F> {
+    fn report(&mut self, current_file: &str, processed_files: usize) {
+        self.last_reported = self.processed_bytes;
+        (self.on_progress)(ChecksumProgress {
+            current_file: current_file.to_string(),
+            processed_files,
+            total_files: self.total_files,
+            processed_bytes: self.processed_bytes,
+            total_bytes: self.total_bytes,
+        });
+    }
+
+    fn hash_file(
+        &mut self,
+        path: &Path,
+        algorithm: ChecksumAlgorithm,
+        display_name: &str,
+        processed_files: usize,
+    ) -> Result<String, String> {
+        self.report(display_name, processed_files);
+        let mut hasher = SingleHasher::new(algorithm);
+        read_chunks(path, |chunk| {
+            hasher.update(chunk);
+            self.processed_bytes += chunk.len() as u64;
+            if self.processed_bytes - self.last_reported >= PROGRESS_STEP_BYTES {
+                self.report(display_name, processed_files);
+            }
+        })?;
+        Ok(hasher.finalize())
+    }
+}
+
+fn is_blake3_checksum_name(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    name.contains("blake3") || name.contains("b3sum") || name.ends_with(".b3")
+}
+
+/// Parse one line in either GNU (`<hash>  <file>`, `<hash> *<file>`) or BSD
+/// (`SHA256 (<file>) = <hash>`) form. A leading backslash marks a file name
+/// with `\\` / `\n` escapes, as coreutils writes them.
+fn parse_checksum_line(line: &str, prefer_blake3: bool) -> Option<ChecksumLine> {
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let unescape = |name: &str| {
+        if escaped {
+            unescape_file_name(name)
+        } else {
+            name.to_string()
+        }
+    };
+    let is_hex = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit());
+
+    if let Some((tag, rest)) = line.split_once(" (") {
+        if let (Some(algorithm), Some(split)) = (
+            ChecksumAlgorithm::from_bsd_tag(tag.trim()),
+            rest.rfind(") = "),
+        ) {
+            let expected = rest[split + 4..].trim();
+            if is_hex(expected) && expected.len() == algorithm.hex_len() {
+                return Some(ChecksumLine {
+                    algorithm,
+                    expected: expected.to_lowercase(),
+                    file_name: unescape(&rest[..split]),
+                });
+            }
+            return None;
+        }
+    }
+
+    let (expected, rest) = line.split_once(' ')?;
+    if !is_hex(expected) {
+        return None;
+    }
+    let algorithm = ChecksumAlgorithm::from_hex_len(expected.len(), prefer_blake3)?;
+    let name = rest
+        .strip_prefix(' ')
+        .or_else(|| rest.strip_prefix('*'))
+        .unwrap_or(rest);
+    if name.is_empty() {
+        return None;
+    }
+    Some(ChecksumLine {
+        algorithm,
+        expected: expected.to_lowercase(),
+        file_name: unescape(name),
+    })
+}
+
+fn unescape_file_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn escape_file_name(name: &str) -> (bool, String) {
+    if !name.contains(['\\', '\n', '\r']) {
+        return (false, name.to_string());
+    }
+    let escaped = name
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+    (true, escaped)
+}
+
+fn format_checksum_line(
+    hash: &str,
+    file_name: &str,
+    algorithm: ChecksumAlgorithm,
+    format: ChecksumFormat,
+) -> String {
+    let (escaped, name) = escape_file_name(file_name);
+    let prefix = if escaped { "\\" } else { "" };
+    match format {
+        ChecksumFormat::Gnu => format!("{}{}  {}", prefix, hash, name),
+        ChecksumFormat::Bsd => format!("{}{} ({}) = {}", prefix, algorithm.bsd_tag(), name, hash),
+    }
+}
+
+/// Verify every entry of a `sha256sum`-style or BSD-style checksum file.
+/// Relative names resolve against `base_dir`, or the checksum file's own
+/// directory when `base_dir` is empty.
+pub fn verify_checksum_file<F>(
+    checksum_file_path: &str,
+    base_dir: &str,
+    on_progress: F,
+) -> ChecksumVerifyResult
+where
+    F: FnMut(ChecksumProgress),
+{
+    let failure = |error: String| ChecksumVerifyResult {
+        error: Some(error),
+        ..Default::default()
+    };
+
+    let checksum_path = Path::new(checksum_file_path);
+    let content = match fs::read(checksum_path) {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        Err(e) => return failure(format!("Failed to read checksum file: {}", e)),
+    };
+    let base = if base_dir.trim().is_empty() {
+        checksum_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    } else {
+        PathBuf::from(base_dir)
+    };
+
+    let prefer_blake3 = is_blake3_checksum_name(checksum_path);
+    let mut lines = Vec::new();
+    let mut invalid_lines = 0;
+    for raw in content.lines() {
+        let line = raw.trim_end_matches('\r');
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        match parse_checksum_line(line, prefer_blake3) {
+            Some(parsed) => lines.push(parsed),
+            None => invalid_lines += 1,
+        }
+    }
+    if lines.is_empty() {
+        return failure("No checksum entries found".to_string());
+    }
+
+    let targets: Vec<PathBuf> = lines.iter().map(|l| base.join(&l.file_name)).collect();
+    let mut tracker = ProgressTracker {
+        on_progress,
+        total_files: lines.len(),
+        total_bytes: targets
+            .iter()
+            .filter_map(|p| fs::metadata(p).ok())
+            .filter(|m| m.is_file())
+            .map(|m| m.len())
+            .sum(),
+        processed_bytes: 0,
+        last_reported: 0,
+    };
+
+    let mut result = ChecksumVerifyResult {
+        success: true,
+        invalid_lines,
+        ..Default::default()
+    };
+    for (index, (line, path)) in lines.into_iter().zip(targets).enumerate() {
+        let (status, actual, error) = if !path.is_file() {
+            (ChecksumStatus::Missing, None, None)
+        } else {
+            match tracker.hash_file(&path, line.algorithm, &line.file_name, index) {
+                Ok(actual) if actual == line.expected => {
+                    (ChecksumStatus::Match, Some(actual), None)
+                }
+                Ok(actual) => (ChecksumStatus::Mismatch, Some(actual), None),
+                Err(e) => (ChecksumStatus::Error, None, Some(e)),
+            }
+        };
+        match status {
+            ChecksumStatus::Match => result.matched += 1,
+            ChecksumStatus::Mismatch => result.mismatched += 1,
+            ChecksumStatus::Missing => result.missing += 1,
+            ChecksumStatus::Error => result.failed += 1,
+        }
+        result.entries.push(ChecksumEntry {
+            file_name: line.file_name,
+            path: path.to_string_lossy().into_owned(),
+            algorithm: line.algorithm,
+            expected: line.expected,
+            actual,
+            status,
+            error,
+        });
+    }
+    tracker.report("", result.entries.len());
+
+    result
+}
+
+/// Hash `paths` and write them as a checksum file to `output_path`. Files under
+/// the output directory are listed relative to it so the result verifies in
+/// place; an empty `output_path` only returns the content.
+pub fn generate_checksum_file<F>(
+    paths: &[String],
+    algorithm: ChecksumAlgorithm,
+    format: ChecksumFormat,
+    output_path: &str,
+    on_progress: F,
+) -> ChecksumGenerateResult
+where
+    F: FnMut(ChecksumProgress),
+{
+    let failure = |error: String| ChecksumGenerateResult {
+        error: Some(error),
+        ..Default::default()
+    };
+
+    if paths.is_empty() {
+        return failure("No files selected".to_string());
+    }
+    if let Some(missing) = paths.iter().find(|p| !Path::new(p).is_file()) {
+        return failure(format!("File not found: {}", missing));
+    }
+
+    let output_dir = Path::new(output_path).parent().map(Path::to_path_buf);
+    let mut tracker = ProgressTracker {
+        on_progress,
+        total_files: paths.len(),
+        total_bytes: paths
+            .iter()
+            .filter_map(|p| fs::metadata(p).ok())
+            .map(|m| m.len())
+            .sum(),
+        processed_bytes: 0,
+        last_reported: 0,
+    };
+
+    let mut content = String::new();
+    for (index, path) in paths.iter().enumerate() {
+        let path = Path::new(path);
+        let display_name = output_dir
+            .as_deref()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .and_then(|dir| path.strip_prefix(dir).ok())
+            .unwrap_or(path)
+            .to_string_lossy()
+            .into_owned();
+        let hash = match tracker.hash_file(path, algorithm, &display_name, index) {
+            Ok(hash) => hash,
+            Err(e) => return failure(e),
+        };
+        content.push_str(&format_checksum_line(
+            &hash,
+            &display_name,
+            algorithm,
+            format,
+        ));
+        content.push('\n');
+    }
+    tracker.report("", paths.len());
+
+    if !output_path.trim().is_empty() {
+        if let Err(e) = fs::write(output_path, &content) {
+            return failure(format!("Failed to write checksum file: {}", e));
+        }
+    }
+
+    ChecksumGenerateResult {
+        success: true,
+        output_path: output_path.to_string(),
+        content,
+        entry_count: paths.len(),
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_empty_string_vectors() {
+        let result = compute_text_hashes("", false);
+        assert_eq!(result.md5, "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(result.sha1, "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(
+            result.sha256,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            result.sha512,
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+             47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        );
+        assert_eq!(result.crc32, "00000000");
+        assert_eq!(result.xxh64, "ef46db3751d8e999");
+        assert_eq!(
+            result.blake3,
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        assert_eq!(result.size_bytes, 0);
+    }
+
+    #[test]
+    fn test_abc_vectors() {
+        let result = compute_text_hashes("abc", false);
+        assert_eq!(result.md5, "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(result.sha1, "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            result.sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            result.sha512,
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(result.crc32, "352441c2");
+        assert_eq!(result.xxh64, "44bc2cf5ad770999");
+        assert_eq!(
+            result.blake3,
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[test]
+    fn test_uppercase_output() {
+        let result = compute_text_hashes("abc", true);
+        assert_eq!(result.md5, "900150983CD24FB0D6963F7D28E17F72");
+    }
+
+    #[test]
+    fn test_file_hashes_match_text_hashes() {
+        let path = std::env::temp_dir().join("taurin_hash_generator_test.txt");
+        let mut file = File::create(&path).unwrap();
+        let content = "a".repeat(READ_BUFFER_SIZE * 2 + 17);
+        file.write_all(content.as_bytes()).unwrap();
+        drop(file);
+
+        let from_file = compute_file_hashes(path.to_str().unwrap()).unwrap();
+        let from_text = compute_text_hashes(&content, false);
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(from_file.sha256, from_text.sha256);
+        assert_eq!(from_file.md5, from_text.md5);
+        assert_eq!(from_file.size_bytes, content.len() as u64);
+    }
+
+    #[test]
+    fn test_file_not_found() {
+        assert!(compute_file_hashes("/nonexistent/taurin/file.bin").is_err());
+    }
+
+    #[test]
+    fn test_compare_hashes() {
+        let result = compare_hashes(
+            "900150983CD24FB0 D6963F7D28E17F72",
+            " 900150983cd24fb0d6963f7d28e17f72\n",
+        );
+        assert!(result.matches);
+        assert!(!compare_hashes("abc", "abd").matches);
+        assert!(!compare_hashes("", " ").matches);
+    }
+
+    #[test]
+    fn test_parse_checksum_lines() {
+        let sha = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let gnu = parse_checksum_line(&format!("{}  dir/a file.txt", sha), false).unwrap();
+        assert_eq!(gnu.algorithm, ChecksumAlgorithm::Sha256);
+        assert_eq!(gnu.file_name, "dir/a file.txt");
+
+        let binary = parse_checksum_line(&format!("{} *a.iso", sha), true).unwrap();
+        assert_eq!(binary.algorithm, ChecksumAlgorithm::Blake3);
+        assert_eq!(binary.file_name, "a.iso");
+
+        let bsd = parse_checksum_line(
+            &format!("SHA256 (x (1).txt) = {}", sha.to_uppercase()),
+            false,
+        )
+        .unwrap();
+        assert_eq!(bsd.algorithm, ChecksumAlgorithm::Sha256);
+        assert_eq!(bsd.file_name, "x (1).txt");
+        assert_eq!(bsd.expected, sha);
+
+        let escaped = parse_checksum_line("\\352441c2  a\\nb", false).unwrap();
+        assert_eq!(escaped.algorithm, ChecksumAlgorithm::Crc32);
+        assert_eq!(escaped.file_name, "a\nb");
+
+        assert!(parse_checksum_line("MD5 (a) = 1234", false).is_none());
+        assert!(parse_checksum_line("not a checksum line", false).is_none());
+    }
+
+    #[test]
+    fn test_generate_and_verify_checksum_file() {
+        let dir = std::env::temp_dir().join("taurin_checksum_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let a = dir.join("a.txt");
+        let b = dir.join("b.txt");
+        fs::write(&a, "abc").unwrap();
+        fs::write(&b, "hello").unwrap();
+        let paths = vec![
+            a.to_string_lossy().into_owned(),
+            b.to_string_lossy().into_owned(),
+        ];
+
+        for format in [ChecksumFormat::Gnu, ChecksumFormat::Bsd] {
+            let output = dir.join("SHA256SUMS");
+            let generated = generate_checksum_file(
+                &paths,
+                ChecksumAlgorithm::Sha256,
+                format,
+                output.to_str().unwrap(),
+                |_| {},
+            );
+            assert!(generated.success, "{:?}", generated.error);
+            assert_eq!(generated.entry_count, 2);
+
+            let mut events = 0;
+            let verified = verify_checksum_file(output.to_str().unwrap(), "", |_| events += 1);
+            assert!(verified.success);
+            assert_eq!(verified.matched, 2);
+            assert!(events > 0);
+        }
+
+        let crc = generate_checksum_file(
+            &paths,
+            ChecksumAlgorithm::Crc32,
+            ChecksumFormat::Gnu,
+            "",
+            |_| {},
+        );
+        assert!(crc.content.starts_with("352441c2  "));
+
+        fs::write(&b, "changed").unwrap();
+        fs::remove_file(&a).unwrap();
+        let verified = verify_checksum_file(dir.join("SHA256SUMS").to_str().unwrap(), "", |_| {});
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(verified.missing, 1);
+        assert_eq!(verified.mismatched, 1);
+        assert_eq!(verified.entries[0].status, ChecksumStatus::Missing);
+        assert_eq!(verified.entries[1].status, ChecksumStatus::Mismatch);
+    }
+}
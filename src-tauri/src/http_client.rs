@@ -0,0 +1,372 @@
+use crate::json_formatter::format_json;
+use base64::{engine::general_purpose, Engine};
+use reqwest::redirect::Policy;
+use reqwest::{Client, Method, Response};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// Used when the request does not set a timeout
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+/// Redirects followed when `follow_redirects` is on
+const MAX_REDIRECTS: usize = 10;
+/// Responses are cut off past this size so a huge download cannot exhaust memory
+const MAX_BODY_BYTES: u64 = 20 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRequestSpec {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    pub timeout_secs: Option<u64>,
+    #[serde(default = "default_true")]
+    pub follow_redirects: bool,
+    /// Skip certificate verification, for servers with self-signed certificates
+    #[serde(default)]
+    pub allow_invalid_certs: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpResponseResult {
+    pub success: bool,
+    pub status: u16,
+    pub status_text: String,
+    pub headers: Vec<(String, String)>,
+    /// The body as text, or Base64 when it is not valid UTF-8
+    pub body: String,
+    pub body_is_base64: bool,
+    /// Pretty-printed body when it parses as JSON
+    pub formatted_json: Option<String>,
+    /// URL of the final response, after any redirects
+    pub final_url: String,
+    pub duration_ms: u64,
+    pub size_bytes: u64,
+    /// True when the body was cut off at the size limit
+    pub truncated: bool,
+    pub error: Option<String>,
+}
+
+impl HttpResponseResult {
+    fn failure(message: String, duration_ms: u64) -> Self {
+        Self {
+            duration_ms,
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+/// Check the URL is absolute http(s) with a host, and normalize the method name
+fn validate_request(spec: &HttpRequestSpec) -> Result<String, String> {
+    let url = spec.url.trim();
+    let (scheme, rest) = url
+        .split_once("://")
+        .ok_or_else(|| "URL must start with http:// or https://".to_string())?;
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return Err(format!(
+            "Unsupported URL scheme: {} (only http and https are allowed)",
+            scheme
+        ));
+    }
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    if host.is_empty() || host.starts_with(':') {
+        return Err("URL has no host".to_string());
+    }
+
+    let method = spec.method.trim().to_ascii_uppercase();
+    if method.is_empty() || !method.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("Invalid HTTP method: {}", spec.method));
+    }
+    for (name, _) in &spec.headers {
+        let name = name.trim();
+        if name.is_empty() || name.chars().any(|c| c.is_ascii_whitespace() || c == ':') {
+            return Err(format!("Invalid header name: {:?}", name));
+        }
+    }
+    Ok(method)
+}
+
+/// Text bodies are returned as-is; anything else is Base64 encoded
+fn encode_body(bytes: Vec<u8>) -> (String, bool) {
+    match String::from_utf8(bytes) {
+        Ok(text) => (text, false),
+        Err(e) => (general_purpose::STANDARD.encode(e.into_bytes()), true),
+    }
+}
+
+/// Client for the app's own requests (exchange rates, link checks), so they share
+/// connections. Callers set a timeout on each request.
+pub(crate) fn shared_client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(Client::new)
+}
+
+/// reqwest's `Display` leaves out the underlying cause (timeout, TLS, DNS, ...)
+pub(crate) fn describe_error(e: &reqwest::Error) -> String {
+    let mut message = e.to_string();
+    let mut source = e.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+fn build_client(spec: &HttpRequestSpec) -> Result<Client, String> {
+    Client::builder()
+        .timeout(Duration::from_secs(
+            spec.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS).max(1),
+        ))
+        .redirect(if spec.follow_redirects {
+            Policy::limited(MAX_REDIRECTS)
+        } else {
+            Policy::none()
+        })
+        .tls_danger_accept_invalid_certs(spec.allow_invalid_certs)
+        .build()
+        .map_err(|e| format!("Failed to configure HTTP client: {}", describe_error(&e)))
+}
+
+async fn response_result(mut response: Response, started: Instant) -> HttpResponseResult {
+    let status = response.status();
+    let final_url = response.url().to_string();
+    let headers: Vec<(String, String)> = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            )
+        })
+        .collect();
+
+    let mut bytes = Vec::new();
+    let mut truncated = false;
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                let room = MAX_BODY_BYTES as usize - bytes.len();
+                if chunk.len() > room {
+                    bytes.extend_from_slice(&chunk[..room]);
+                    truncated = true;
+                    break;
+                }
+                bytes.extend_from_slice(&chunk);
+            }
+            Ok(None) => break,
+            Err(e) => {
+                return HttpResponseResult::failure(
+                    format!("Failed to read response body: {}", describe_error(&e)),
+                    started.elapsed().as_millis() as u64,
+                )
+            }
+        }
+    }
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let size_bytes = bytes.len() as u64;
+    let (body, body_is_base64) = encode_body(bytes);
+    let formatted_json = (!body_is_base64 && !truncated && !body.trim().is_empty())
+        .then(|| format_json(&body, 2))
+        .filter(|result| result.success)
+        .map(|result| result.formatted);
+
+    HttpResponseResult {
+        success: true,
+        status: status.as_u16(),
+        status_text: status.canonical_reason().unwrap_or_default().to_string(),
+        headers,
+        body,
+        body_is_base64,
+        formatted_json,
+        final_url,
+        duration_ms,
+        size_bytes,
+        truncated,
+        error: None,
+    }
+}
+
+/// Send an HTTP request and collect the response. 4xx/5xx responses are returned as
+/// successful results, since they are still responses worth showing.
+pub async fn send_http_request(spec: HttpRequestSpec) -> HttpResponseResult {
+    let method = match validate_request(&spec).and_then(|method| {
+        Method::from_bytes(method.as_bytes())
+            .map_err(|_| format!("Invalid HTTP method: {}", method))
+    }) {
+        Ok(method) => method,
+        Err(e) => return HttpResponseResult::failure(e, 0),
+    };
+    let client = match build_client(&spec) {
+        Ok(client) => client,
+        Err(e) => return HttpResponseResult::failure(e, 0),
+    };
+
+    let mut request = client.request(method, spec.url.trim());
+    for (name, value) in &spec.headers {
+        request = request.header(name.trim(), value);
+    }
+    if let Some(body) = spec.body.filter(|body| !body.is_empty()) {
+        request = request.body(body);
+    }
+
+    let started = Instant::now();
+    match request.send().await {
+        Ok(response) => response_result(response, started).await,
+        Err(e) => HttpResponseResult::failure(
+            format!("Request failed: {}", describe_error(&e)),
+            started.elapsed().as_millis() as u64,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn spec(method: &str, url: &str) -> HttpRequestSpec {
+        HttpRequestSpec {
+            method: method.to_string(),
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+            timeout_secs: Some(5),
+            follow_redirects: true,
+            allow_invalid_certs: false,
+        }
+    }
+
+    /// Serve one canned response and return the raw request that was received
+    fn serve_once(response: &'static [u8]) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            let mut buf = [0u8; 4096];
+            // Read the head, then as much body as Content-Length announces
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                received.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&received).to_string();
+                let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                    continue;
+                };
+                let length = head
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().ok())?
+                    })
+                    .unwrap_or(0);
+                if n == 0 || body.len() >= length {
+                    break;
+                }
+            }
+            stream.write_all(response).unwrap();
+            String::from_utf8_lossy(&received).to_string()
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_validate_request() {
+        assert_eq!(
+            validate_request(&spec(" post ", "https://example.com/a")),
+            Ok("POST".to_string())
+        );
+        assert!(validate_request(&spec("GET", "HTTP://localhost:8080")).is_ok());
+        for url in [
+            "ftp://example.com",
+            "file:///etc/passwd",
+            "example.com",
+            "http://",
+        ] {
+            assert!(validate_request(&spec("GET", url)).is_err(), "{}", url);
+        }
+        assert!(validate_request(&spec("GE T", "https://example.com")).is_err());
+
+        let mut bad_header = spec("GET", "https://example.com");
+        bad_header
+            .headers
+            .push(("X Bad".to_string(), "1".to_string()));
+        assert!(validate_request(&bad_header).is_err());
+
+        let mut self_signed = spec("GET", "https://localhost");
+        self_signed.allow_invalid_certs = true;
+        assert!(build_client(&self_signed).is_ok());
+    }
+
+    #[test]
+    fn test_encode_body() {
+        assert_eq!(encode_body(b"hello".to_vec()), ("hello".to_string(), false));
+        assert_eq!(
+            encode_body(vec![0xff, 0x00, 0x10]),
+            ("/wAQ".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn test_send_http_request_round_trip() {
+        let (url, server) = serve_once(
+            b"HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nX-Test: a\r\nContent-Length: 13\r\nConnection: close\r\n\r\n{\"error\":\"x\"}",
+        );
+        let mut request = spec("post", &format!("{}/items?q=1", url));
+        request
+            .headers
+            .push(("X-Token".to_string(), "secret".to_string()));
+        request.body = Some("payload".to_string());
+
+        let result = tauri::async_runtime::block_on(send_http_request(request));
+        let received = server.join().unwrap();
+
+        assert!(received.starts_with("POST /items?q=1 HTTP/1.1"));
+        assert!(received.to_ascii_lowercase().contains("x-token: secret"));
+        assert!(received.ends_with("payload"));
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(
+            (result.status, result.status_text.as_str()),
+            (404, "Not Found")
+        );
+        assert!(result
+            .headers
+            .iter()
+            .any(|(name, value)| name.eq_ignore_ascii_case("x-test") && value == "a"));
+        assert_eq!(result.body, "{\"error\":\"x\"}");
+        assert_eq!(result.size_bytes, 13);
+        assert_eq!(
+            result.formatted_json.as_deref(),
+            Some("{\n  \"error\": \"x\"\n}")
+        );
+    }
+
+    #[test]
+    fn test_redirect_not_followed() {
+        let (url, server) = serve_once(
+            b"HTTP/1.1 302 Found\r\nLocation: /elsewhere\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        );
+        let mut request = spec("GET", &url);
+        request.follow_redirects = false;
+        let result = tauri::async_runtime::block_on(send_http_request(request));
+        server.join().unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.status, 302);
+        assert!(result.formatted_json.is_none());
+    }
+}
@@ -1,10 +1,18 @@
-use image::{DynamicImage, ImageFormat, ImageReader};
+use image::codecs::gif::GifDecoder;
+use image::codecs::ico::{IcoEncoder, IcoFrame};
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::{
+    imageops, AnimationDecoder, DynamicImage, ExtendedColorType, ImageFormat, ImageReader, Rgba,
+    RgbaImage,
+};
 use ravif::{Encoder, Img};
 use rgb::RGBA8;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
-use std::io::Cursor;
-use std::path::Path;
+use std::io::{BufReader, BufWriter, Cursor};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompressionOptions {
@@ -22,6 +30,62 @@ pub struct CompressionResult {
     pub compressed_size: u64,
     pub compression_ratio: f64,
     pub error: Option<String>,
+    /// Non-fatal notes such as dropped animation frames or transparency
+    pub warnings: Vec<String>,
+    /// Set by `compress_to_target_size`
+    #[serde(default)]
+    pub target: Option<TargetSizeInfo>,
+}
+
+/// What `compress_to_target_size` settled on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetSizeInfo {
+    pub target_bytes: u64,
+    /// Final quality for JPEG, WebP and AVIF; `None` for PNG
+    pub quality: Option<u8>,
+    /// Bits kept per color channel for PNG (8 means no color reduction); `None` otherwise
+    pub color_bits: Option<u8>,
+    /// Factor applied to width and height, 1.0 when the image was not resized
+    pub scale: f64,
+    pub attempts: u32,
+    /// False when even the smallest attempt was larger than the target.
+    /// That attempt is written anyway.
+    pub reached: bool,
+}
+
+impl CompressionResult {
+    fn failure(output_path: &str, original_size: u64, message: String) -> Self {
+        Self {
+            success: false,
+            output_path: output_path.to_string(),
+            original_size,
+            compressed_size: 0,
+            compression_ratio: 0.0,
+            error: Some(message),
+            warnings: Vec::new(),
+            target: None,
+        }
+    }
+
+    fn written(output: &Path, original_size: u64, warnings: Vec<String>) -> Self {
+        let compressed_size = fs::metadata(output).map(|m| m.len()).unwrap_or(0);
+        let compression_ratio = if original_size > 0 {
+            (1.0 - (compressed_size as f64 / original_size as f64)) * 100.0
+        } else {
+            0.0
+        };
+
+        Self {
+            success: true,
+            output_path: output.to_string_lossy().to_string(),
+            original_size,
+            compressed_size,
+            compression_ratio,
+            error: None,
+            warnings,
+            target: None,
+        }
+    }
 }
 
 pub fn compress_image(
@@ -29,47 +93,10 @@ pub fn compress_image(
     output_path: &str,
     options: CompressionOptions,
 ) -> CompressionResult {
-    let input = Path::new(input_path);
     let output = Path::new(output_path);
-
-    let original_size = match fs::metadata(input) {
-        Ok(meta) => meta.len(),
-        Err(e) => {
-            return CompressionResult {
-                success: false,
-                output_path: output_path.to_string(),
-                original_size: 0,
-                compressed_size: 0,
-                compression_ratio: 0.0,
-                error: Some(format!("Failed to read input file: {}", e)),
-            };
-        }
-    };
-
-    let img = match ImageReader::open(input) {
-        Ok(reader) => match reader.decode() {
-            Ok(img) => img,
-            Err(e) => {
-                return CompressionResult {
-                    success: false,
-                    output_path: output_path.to_string(),
-                    original_size,
-                    compressed_size: 0,
-                    compression_ratio: 0.0,
-                    error: Some(format!("Failed to decode image: {}", e)),
-                };
-            }
-        },
-        Err(e) => {
-            return CompressionResult {
-                success: false,
-                output_path: output_path.to_string(),
-                original_size,
-                compressed_size: 0,
-                compression_ratio: 0.0,
-                error: Some(format!("Failed to open image: {}", e)),
-            };
-        }
+    let (img, original_size) = match open_input(input_path, output_path) {
+        Ok(opened) => opened,
+        Err(failure) => return *failure,
     };
 
     let img = resize_if_needed(img, options.width, options.height);
@@ -83,34 +110,617 @@ pub fn compress_image(
     };
 
     match result {
-        Ok(_) => {
-            let compressed_size = fs::metadata(output).map(|m| m.len()).unwrap_or(0);
-            let compression_ratio = if original_size > 0 {
-                (1.0 - (compressed_size as f64 / original_size as f64)) * 100.0
+        Ok(_) => CompressionResult::written(output, original_size, Vec::new()),
+        Err(e) => CompressionResult::failure(output_path, original_size, e),
+    }
+}
+
+/// Decode the input image, returning it with the input file size
+fn open_input(
+    input_path: &str,
+    output_path: &str,
+) -> Result<(DynamicImage, u64), Box<CompressionResult>> {
+    let input = Path::new(input_path);
+    let original_size = fs::metadata(input).map(|m| m.len()).map_err(|e| {
+        CompressionResult::failure(output_path, 0, format!("Failed to read input file: {}", e))
+    })?;
+
+    let reader = ImageReader::open(input).map_err(|e| {
+        CompressionResult::failure(
+            output_path,
+            original_size,
+            format!("Failed to open image: {}", e),
+        )
+    })?;
+    let img = reader.decode().map_err(|e| {
+        CompressionResult::failure(
+            output_path,
+            original_size,
+            format!("Failed to decode image: {}", e),
+        )
+    })?;
+    Ok((img, original_size))
+}
+
+/// Most encodes `compress_to_target_size` runs before settling
+const MAX_TARGET_ATTEMPTS: u32 = 10;
+/// Bounds on how much each resize step shrinks the previous one
+const MIN_RESIZE_STEP: f64 = 0.5;
+const MAX_RESIZE_STEP: f64 = 0.9;
+
+/// One encode tried by `search_target_size`
+struct TargetAttempt {
+    level: u8,
+    scale: f64,
+    data: Vec<u8>,
+}
+
+/// Find the highest level in `min_level..=max_level` whose encoding fits in `target_bytes`.
+///
+/// The level is binary searched starting from `min_level`. If even that is too large and
+/// `allow_resize` is set, the image is shrunk in steps (still at `min_level`) until it
+/// fits. Returns the best attempt, whether it fits, and the number of encodes.
+fn search_target_size<E>(
+    img: &DynamicImage,
+    target_bytes: u64,
+    min_level: u8,
+    max_level: u8,
+    allow_resize: bool,
+    encode: E,
+) -> Result<(TargetAttempt, bool, u32), String>
+where
+    E: Fn(&DynamicImage, u8) -> Result<Vec<u8>, String>,
+{
+    let fits = |data: &[u8]| data.len() as u64 <= target_bytes;
+    let mut attempts = 1;
+    let mut best = TargetAttempt {
+        level: min_level,
+        scale: 1.0,
+        data: encode(img, min_level)?,
+    };
+
+    if fits(&best.data) {
+        let (mut low, mut high) = (min_level + 1, max_level);
+        while low <= high && attempts < MAX_TARGET_ATTEMPTS {
+            let mid = low + (high - low) / 2;
+            let data = encode(img, mid)?;
+            attempts += 1;
+            if fits(&data) {
+                best = TargetAttempt {
+                    level: mid,
+                    scale: 1.0,
+                    data,
+                };
+                low = mid + 1;
             } else {
-                0.0
+                high = mid - 1;
+            }
+        }
+        return Ok((best, true, attempts));
+    }
+    if !allow_resize {
+        return Ok((best, false, attempts));
+    }
+
+    let mut scale = 1.0;
+    let mut last_size = best.data.len();
+    let mut dimensions = (img.width(), img.height());
+    while attempts < MAX_TARGET_ATTEMPTS && dimensions != (1, 1) {
+        // File size grows roughly with the pixel count, so aim for the square root of the
+        // remaining ratio with a little headroom
+        let step = ((target_bytes as f64 / last_size as f64).sqrt() * 0.95)
+            .clamp(MIN_RESIZE_STEP, MAX_RESIZE_STEP);
+        scale *= step;
+        dimensions = (
+            ((img.width() as f64 * scale).round() as u32).max(1),
+            ((img.height() as f64 * scale).round() as u32).max(1),
+        );
+        let resized = img.resize_exact(
+            dimensions.0,
+            dimensions.1,
+            image::imageops::FilterType::Lanczos3,
+        );
+        let data = encode(&resized, min_level)?;
+        attempts += 1;
+        last_size = data.len();
+        if data.len() < best.data.len() {
+            best = TargetAttempt {
+                level: min_level,
+                scale,
+                data,
             };
+        }
+        if fits(&best.data) {
+            return Ok((best, true, attempts));
+        }
+    }
+    Ok((best, false, attempts))
+}
 
-            CompressionResult {
-                success: true,
-                output_path: output_path.to_string(),
-                original_size,
-                compressed_size,
-                compression_ratio,
-                error: None,
-            }
+/// Map each color channel down to `bits` bits (spread back over 0-255) so PNG's
+/// compression has fewer distinct values to deal with. Alpha is left alone.
+fn reduce_colors(img: &DynamicImage, bits: u8) -> DynamicImage {
+    if bits >= 8 {
+        return img.clone();
+    }
+    let levels = (1u16 << bits) - 1;
+    let mut rgba = img.to_rgba8();
+    for pixel in rgba.pixels_mut() {
+        for channel in &mut pixel.0[..3] {
+            *channel = ((*channel >> (8 - bits)) as u16 * 255 / levels) as u8;
         }
-        Err(e) => CompressionResult {
-            success: false,
-            output_path: output_path.to_string(),
+    }
+    let reduced = DynamicImage::ImageRgba8(rgba);
+    if img.color().has_alpha() {
+        reduced
+    } else {
+        DynamicImage::ImageRgb8(reduced.to_rgb8())
+    }
+}
+
+/// Compress to at most `target_bytes`, using the highest quality that fits.
+///
+/// JPEG, WebP and AVIF search the quality between `min_quality` and 100. PNG has no
+/// quality setting, so it reduces colors instead: `min_quality` sets the fewest bits per
+/// channel it may go down to (100 keeps all 8, 50 allows 4). When the minimum is still
+/// too large, `allow_resize` shrinks the image step by step; otherwise the smallest
+/// attempt is written with a warning. At most `MAX_TARGET_ATTEMPTS` encodes are run.
+pub fn compress_to_target_size(
+    input_path: &str,
+    output_path: &str,
+    target_bytes: u64,
+    format: &str,
+    min_quality: u8,
+    allow_resize: bool,
+) -> CompressionResult {
+    let output = Path::new(output_path);
+    if target_bytes == 0 {
+        return CompressionResult::failure(
+            output_path,
+            0,
+            "Target size must be greater than zero".to_string(),
+        );
+    }
+    let (img, original_size) = match open_input(input_path, output_path) {
+        Ok(opened) => opened,
+        Err(failure) => return *failure,
+    };
+    let min_quality = min_quality.clamp(1, 100);
+
+    let format = format.to_lowercase();
+    let searched = match format.as_str() {
+        "avif" => search_target_size(
+            &img,
+            target_bytes,
+            min_quality,
+            100,
+            allow_resize,
+            encode_avif,
+        ),
+        "webp" => search_target_size(
+            &img,
+            target_bytes,
+            min_quality,
+            100,
+            allow_resize,
+            |i, q| Ok(encode_webp(i, q)),
+        ),
+        "jpeg" | "jpg" => search_target_size(
+            &img,
+            target_bytes,
+            min_quality,
+            100,
+            allow_resize,
+            encode_jpeg,
+        ),
+        "png" => {
+            let min_bits = ((min_quality as u32 * 8).div_ceil(100) as u8).clamp(1, 8);
+            search_target_size(&img, target_bytes, min_bits, 8, allow_resize, |i, bits| {
+                encode_png(&reduce_colors(i, bits))
+            })
+        }
+        _ => Err(format!("Unsupported format: {}", format)),
+    };
+    let (attempt, reached, attempts) = match searched {
+        Ok(searched) => searched,
+        Err(e) => return CompressionResult::failure(output_path, original_size, e),
+    };
+
+    if let Err(e) = fs::write(output, &attempt.data) {
+        return CompressionResult::failure(
+            output_path,
             original_size,
-            compressed_size: 0,
-            compression_ratio: 0.0,
-            error: Some(e),
-        },
+            format!("Failed to write output file: {}", e),
+        );
+    }
+    let mut warnings = Vec::new();
+    if !reached {
+        warnings.push(format!(
+            "Could not get below {} bytes; the smallest result was {} bytes",
+            target_bytes,
+            attempt.data.len()
+        ));
+    }
+    let is_png = format == "png";
+    let mut result = CompressionResult::written(output, original_size, warnings);
+    result.target = Some(TargetSizeInfo {
+        target_bytes,
+        quality: (!is_png).then_some(attempt.level),
+        color_bits: is_png.then_some(attempt.level),
+        scale: attempt.scale,
+        attempts,
+        reached,
+    });
+    result
+}
+
+/// Sizes embedded in ICO output, capped at the source size
+const ICO_SIZES: [u32; 6] = [16, 32, 48, 64, 128, 256];
+const DEFAULT_CONVERT_QUALITY: u8 = 90;
+/// Major brands of HEIF files written by phones and cameras
+const HEIF_BRANDS: [&[u8; 4]; 6] = [b"heic", b"heix", b"heim", b"heis", b"hevc", b"hevx"];
+
+fn is_heif(path: &Path) -> bool {
+    let by_extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| matches!(e.to_lowercase().as_str(), "heic" | "heif" | "hif"));
+    by_extension
+        || fs::read(path).is_ok_and(|data| {
+            data.len() >= 12
+                && &data[4..8] == b"ftyp"
+                && HEIF_BRANDS.iter().any(|brand| &data[8..12] == *brand)
+        })
+}
+
+#[cfg(feature = "heic")]
+fn decode_heif(path: &Path) -> Result<DynamicImage, String> {
+    use libheif_rs::{ColorSpace, HeifContext, LibHeif, RgbChroma};
+
+    let to_error = |e: libheif_rs::HeifError| format!("Failed to decode HEIC: {}", e);
+    let lib = LibHeif::new();
+    let context = HeifContext::read_from_file(&path.to_string_lossy()).map_err(to_error)?;
+    let handle = context.primary_image_handle().map_err(to_error)?;
+    let image = lib
+        .decode(&handle, ColorSpace::Rgb(RgbChroma::Rgba), None)
+        .map_err(to_error)?;
+    let plane = image
+        .planes()
+        .interleaved
+        .ok_or_else(|| "Failed to decode HEIC: no RGBA plane".to_string())?;
+
+    let row_len = plane.width as usize * 4;
+    let mut pixels = Vec::with_capacity(row_len * plane.height as usize);
+    for row in plane.data.chunks(plane.stride).take(plane.height as usize) {
+        pixels.extend_from_slice(&row[..row_len]);
+    }
+    RgbaImage::from_raw(plane.width, plane.height, pixels)
+        .map(DynamicImage::ImageRgba8)
+        .ok_or_else(|| "Failed to decode HEIC: unexpected plane size".to_string())
+}
+
+/// Without libheif, let the system `sips` tool do the decoding on macOS.
+#[cfg(all(not(feature = "heic"), target_os = "macos"))]
+fn decode_heif(path: &Path) -> Result<DynamicImage, String> {
+    let temp = std::env::temp_dir().join(format!("taurin_heic_{}.png", std::process::id()));
+    let status = std::process::Command::new("sips")
+        .args(["-s", "format", "png"])
+        .arg(path)
+        .arg("--out")
+        .arg(&temp)
+        .output()
+        .map_err(|e| format!("Failed to run sips: {}", e))?;
+    if !status.status.success() {
+        return Err(format!(
+            "Failed to decode HEIC: {}",
+            String::from_utf8_lossy(&status.stderr).trim()
+        ));
+    }
+    let image = image::open(&temp).map_err(|e| format!("Failed to decode HEIC: {}", e));
+    let _ = fs::remove_file(&temp);
+    image
+}
+
+#[cfg(all(not(feature = "heic"), not(target_os = "macos")))]
+fn decode_heif(_path: &Path) -> Result<DynamicImage, String> {
+    Err("HEIC/HEIF input requires a build with the `heic` feature (libheif)".to_string())
+}
+
+/// Whether the file holds more than one frame; only the first one is converted.
+fn is_animated(path: &Path, format: ImageFormat) -> bool {
+    let Ok(file) = fs::File::open(path) else {
+        return false;
+    };
+    let reader = BufReader::new(file);
+    match format {
+        ImageFormat::Gif => GifDecoder::new(reader)
+            .map(|decoder| decoder.into_frames().take(2).count() > 1)
+            .unwrap_or(false),
+        ImageFormat::Png => PngDecoder::new(reader)
+            .and_then(|decoder| decoder.is_apng())
+            .unwrap_or(false),
+        ImageFormat::WebP => WebPDecoder::new(reader)
+            .map(|decoder| decoder.has_animation())
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Decode any supported input, including HEIC, noting anything that will be lost.
+fn decode_for_conversion(path: &Path) -> Result<(DynamicImage, Vec<String>), String> {
+    if is_heif(path) {
+        return decode_heif(path).map(|img| (img, Vec::new()));
+    }
+
+    let reader = ImageReader::open(path)
+        .map_err(|e| format!("Failed to open image: {}", e))?
+        .with_guessed_format()
+        .map_err(|e| format!("Failed to open image: {}", e))?;
+    let mut warnings = Vec::new();
+    if reader
+        .format()
+        .is_some_and(|format| is_animated(path, format))
+    {
+        warnings.push("Animated image: only the first frame was converted".to_string());
+    }
+    let img = reader
+        .decode()
+        .map_err(|e| format!("Failed to decode image: {}", e))?;
+    Ok((img, warnings))
+}
+
+fn has_transparency(img: &DynamicImage) -> bool {
+    img.color().has_alpha() && img.to_rgba8().pixels().any(|p| p[3] < 255)
+}
+
+/// Composite onto white so transparent areas do not turn black in formats without alpha.
+fn flatten_on_white(img: &DynamicImage) -> DynamicImage {
+    let mut background = RgbaImage::from_pixel(img.width(), img.height(), Rgba([255; 4]));
+    imageops::overlay(&mut background, &img.to_rgba8(), 0, 0);
+    DynamicImage::ImageRgba8(background)
+}
+
+/// Convert an image to another format without resizing.
+///
+/// Besides the compressor's formats this reads HEIC/HEIF and writes GIF, BMP, TIFF and
+/// multi-size ICO. `quality` applies to JPEG, WebP and AVIF.
+pub fn convert_image_format(
+    input_path: &str,
+    output_path: &str,
+    format: &str,
+    quality: Option<u8>,
+) -> CompressionResult {
+    let input = Path::new(input_path);
+    let output = Path::new(output_path);
+    let quality = quality.unwrap_or(DEFAULT_CONVERT_QUALITY).clamp(1, 100);
+
+    let original_size = match fs::metadata(input) {
+        Ok(meta) => meta.len(),
+        Err(e) => {
+            return CompressionResult::failure(
+                output_path,
+                0,
+                format!("Failed to read input file: {}", e),
+            );
+        }
+    };
+    let (img, mut warnings) = match decode_for_conversion(input) {
+        Ok(decoded) => decoded,
+        Err(e) => return CompressionResult::failure(output_path, original_size, e),
+    };
+
+    let format = format.to_lowercase();
+    if format == "jpeg" || format == "jpg" {
+        if has_transparency(&img) {
+            warnings.push(
+                "JPEG does not support transparency; transparent areas were filled with white"
+                    .to_string(),
+            );
+        }
+    } else if format == "ico" && img.width() != img.height() {
+        let side = img.width().max(img.height());
+        warnings.push(format!(
+            "Icons are square; the image was centered on a {}x{} transparent canvas",
+            side, side
+        ));
+    }
+
+    let result = match format.as_str() {
+        "avif" => save_as_avif(&img, output, quality),
+        "webp" => save_as_webp(&img, output, quality),
+        "jpeg" | "jpg" => save_as_jpeg(&flatten_on_white(&img), output, quality),
+        "png" => save_as_png(&img, output),
+        "ico" => save_as_ico(&img, output),
+        "gif" | "bmp" | "tiff" | "tif" => {
+            let image_format = ImageFormat::from_extension(&format).unwrap_or(ImageFormat::Png);
+            img.save_with_format(output, image_format)
+                .map_err(|e| format!("{} encoding failed: {}", format.to_uppercase(), e))
+        }
+        _ => Err(format!("Unsupported format: {}", format)),
+    };
+
+    match result {
+        Ok(_) => CompressionResult::written(output, original_size, warnings),
+        Err(e) => CompressionResult::failure(output_path, original_size, e),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCompressionItem {
+    pub input_path: String,
+    pub result: CompressionResult,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCompressionResult {
+    pub items: Vec<BatchCompressionItem>,
+    pub total_files: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub total_original_size: u64,
+    pub total_compressed_size: u64,
+    /// Bytes saved across successful files (negative if outputs grew)
+    pub total_saved_bytes: i64,
+    pub total_compression_ratio: f64,
+}
+
+/// Payload of the `compression-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressionProgress {
+    pub completed: usize,
+    pub total: usize,
+    pub current_file: String,
+    pub success: bool,
+}
+
+/// Compress several images into `output_dir`, reporting progress after each file.
+///
+/// A failing file is recorded and the remaining files are still processed.
+pub fn compress_images_batch<F>(
+    inputs: &[String],
+    output_dir: &str,
+    options: &CompressionOptions,
+    on_progress: F,
+) -> BatchCompressionResult
+where
+    F: FnMut(CompressionProgress),
+{
+    run_batch(
+        inputs,
+        output_dir,
+        &options.output_format,
+        on_progress,
+        |input, output| compress_image(input, output, options.clone()),
+    )
+}
+
+/// Convert several images into `output_dir`; the batch counterpart of
+/// [`convert_image_format`].
+pub fn convert_images_batch<F>(
+    inputs: &[String],
+    output_dir: &str,
+    format: &str,
+    quality: Option<u8>,
+    on_progress: F,
+) -> BatchCompressionResult
+where
+    F: FnMut(CompressionProgress),
+{
+    run_batch(inputs, output_dir, format, on_progress, |input, output| {
+        convert_image_format(input, output, format, quality)
+    })
+}
+
+fn run_batch<F, P>(
+    inputs: &[String],
+    output_dir: &str,
+    output_format: &str,
+    mut on_progress: F,
+    mut process: P,
+) -> BatchCompressionResult
+where
+    F: FnMut(CompressionProgress),
+    P: FnMut(&str, &str) -> CompressionResult,
+{
+    let total = inputs.len();
+    let mut items = Vec::with_capacity(total);
+    let mut reserved: HashSet<PathBuf> = HashSet::new();
+
+    if let Err(e) = fs::create_dir_all(output_dir) {
+        for input_path in inputs {
+            items.push(BatchCompressionItem {
+                input_path: input_path.clone(),
+                result: CompressionResult::failure(
+                    "",
+                    0,
+                    format!("Failed to create output directory: {}", e),
+                ),
+            });
+        }
+        return summarize_batch(items);
+    }
+
+    for (index, input_path) in inputs.iter().enumerate() {
+        let output_path = unique_output_path(
+            Path::new(output_dir),
+            input_path,
+            output_format,
+            &mut reserved,
+        );
+        let result = process(input_path, &output_path.to_string_lossy());
+
+        on_progress(CompressionProgress {
+            completed: index + 1,
+            total,
+            current_file: input_path.clone(),
+            success: result.success,
+        });
+
+        items.push(BatchCompressionItem {
+            input_path: input_path.clone(),
+            result,
+        });
+    }
+
+    summarize_batch(items)
+}
+
+fn summarize_batch(items: Vec<BatchCompressionItem>) -> BatchCompressionResult {
+    let succeeded_items: Vec<&CompressionResult> = items
+        .iter()
+        .map(|item| &item.result)
+        .filter(|result| result.success)
+        .collect();
+    let succeeded = succeeded_items.len();
+    let total_original_size: u64 = succeeded_items.iter().map(|r| r.original_size).sum();
+    let total_compressed_size: u64 = succeeded_items.iter().map(|r| r.compressed_size).sum();
+    let total_compression_ratio = if total_original_size > 0 {
+        (1.0 - (total_compressed_size as f64 / total_original_size as f64)) * 100.0
+    } else {
+        0.0
+    };
+
+    BatchCompressionResult {
+        total_files: items.len(),
+        succeeded,
+        failed: items.len() - succeeded,
+        total_original_size,
+        total_compressed_size,
+        total_saved_bytes: total_original_size as i64 - total_compressed_size as i64,
+        total_compression_ratio,
+        items,
     }
 }
 
+/// Build `<output_dir>/<stem>.<ext>`, appending `_1`, `_2`, ... when the name is taken
+/// on disk or by an earlier file in the same batch.
+fn unique_output_path(
+    output_dir: &Path,
+    input_path: &str,
+    output_format: &str,
+    reserved: &mut HashSet<PathBuf>,
+) -> PathBuf {
+    let stem = Path::new(input_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("image");
+    let extension = match output_format.to_lowercase().as_str() {
+        "jpeg" | "jpg" => "jpg".to_string(),
+        "tif" => "tiff".to_string(),
+        other => other.to_string(),
+    };
+
+    let mut candidate = output_dir.join(format!("{}.{}", stem, extension));
+    let mut counter = 1;
+    while candidate.exists() || reserved.contains(&candidate) {
+        candidate = output_dir.join(format!("{}_{}.{}", stem, counter, extension));
+        counter += 1;
+    }
+
+    reserved.insert(candidate.clone());
+    candidate
+}
+
 fn resize_if_needed(img: DynamicImage, width: Option<u32>, height: Option<u32>) -> DynamicImage {
     match (width, height) {
         (Some(w), Some(h)) => img.resize_exact(w, h, image::imageops::FilterType::Lanczos3),
@@ -129,6 +739,11 @@ fn resize_if_needed(img: DynamicImage, width: Option<u32>, height: Option<u32>)
 }
 
 fn save_as_avif(img: &DynamicImage, output: &Path, quality: u8) -> Result<(), String> {
+    let data = encode_avif(img, quality)?;
+    fs::write(output, data).map_err(|e| format!("Failed to write AVIF file: {}", e))
+}
+
+fn encode_avif(img: &DynamicImage, quality: u8) -> Result<Vec<u8>, String> {
     let rgba = img.to_rgba8();
     let width = rgba.width() as usize;
     let height = rgba.height() as usize;
@@ -145,30 +760,36 @@ fn save_as_avif(img: &DynamicImage, output: &Path, quality: u8) -> Result<(), St
     let encoded = encoder
         .encode_rgba(img_ref)
         .map_err(|e| format!("AVIF encoding failed: {}", e))?;
-
-    fs::write(output, encoded.avif_file).map_err(|e| format!("Failed to write AVIF file: {}", e))
+    Ok(encoded.avif_file)
 }
 
 fn save_as_webp(img: &DynamicImage, output: &Path, quality: u8) -> Result<(), String> {
+    fs::write(output, encode_webp(img, quality))
+        .map_err(|e| format!("Failed to write WebP file: {}", e))
+}
+
+fn encode_webp(img: &DynamicImage, quality: u8) -> Vec<u8> {
     let rgba = img.to_rgba8();
     let width = rgba.width();
     let height = rgba.height();
 
     let encoder = webp::Encoder::from_rgba(&rgba, width, height);
-    let webp_data = encoder.encode(quality as f32);
-
-    fs::write(output, &*webp_data).map_err(|e| format!("Failed to write WebP file: {}", e))
+    encoder.encode(quality as f32).to_vec()
 }
 
 fn save_as_jpeg(img: &DynamicImage, output: &Path, quality: u8) -> Result<(), String> {
+    let data = encode_jpeg(img, quality)?;
+    fs::write(output, data).map_err(|e| format!("Failed to write JPEG file: {}", e))
+}
+
+fn encode_jpeg(img: &DynamicImage, quality: u8) -> Result<Vec<u8>, String> {
     let rgb = img.to_rgb8();
     let mut buffer = Cursor::new(Vec::new());
 
     let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut buffer, quality);
     rgb.write_with_encoder(encoder)
         .map_err(|e| format!("JPEG encoding failed: {}", e))?;
-
-    fs::write(output, buffer.into_inner()).map_err(|e| format!("Failed to write JPEG file: {}", e))
+    Ok(buffer.into_inner())
 }
 
 fn save_as_png(img: &DynamicImage, output: &Path) -> Result<(), String> {
@@ -176,6 +797,40 @@ fn save_as_png(img: &DynamicImage, output: &Path) -> Result<(), String> {
         .map_err(|e| format!("PNG encoding failed: {}", e))
 }
 
+fn encode_png(img: &DynamicImage) -> Result<Vec<u8>, String> {
+    let mut buffer = Cursor::new(Vec::new());
+    img.write_to(&mut buffer, ImageFormat::Png)
+        .map_err(|e| format!("PNG encoding failed: {}", e))?;
+    Ok(buffer.into_inner())
+}
+
+/// Write a square, padded copy of `img` at every standard icon size up to its own size.
+fn save_as_ico(img: &DynamicImage, output: &Path) -> Result<(), String> {
+    let side = img.width().max(img.height());
+    let mut square = RgbaImage::new(side, side);
+    imageops::overlay(
+        &mut square,
+        &img.to_rgba8(),
+        ((side - img.width()) / 2) as i64,
+        ((side - img.height()) / 2) as i64,
+    );
+
+    let frames = ICO_SIZES
+        .iter()
+        .filter(|&&size| size <= side.max(ICO_SIZES[0]))
+        .map(|&size| {
+            let resized = imageops::resize(&square, size, size, imageops::FilterType::Lanczos3);
+            IcoFrame::as_png(resized.as_raw(), size, size, ExtendedColorType::Rgba8)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("ICO encoding failed: {}", e))?;
+
+    let file = fs::File::create(output).map_err(|e| format!("Failed to write ICO file: {}", e))?;
+    IcoEncoder::new(BufWriter::new(file))
+        .encode_images(&frames)
+        .map_err(|e| format!("ICO encoding failed: {}", e))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageInfo {
     pub width: u32,
@@ -209,3 +864,756 @@ pub fn get_image_info(path: &str) -> Result<ImageInfo, String> {
         file_size,
     })
 }
+
+/// Largest edge `export_multiple_sizes` will produce
+const MAX_EXPORT_SIZE: u32 = 8192;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SizeFit {
+    /// Crop the largest centered square, then scale it to `size`x`size`
+    #[default]
+    SquareCrop,
+    /// Keep the aspect ratio and scale the longer edge to `size`
+    LongEdge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UpscaleMode {
+    /// Enlarge the source, with a warning that the result may be blurry
+    #[default]
+    Upscale,
+    /// Leave out sizes larger than the source
+    Skip,
+}
+
+/// How each exported file is named
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum NamingPattern {
+    /// One name for every size, with `{size}`, `{width}`, `{height}`, `{name}` (the input
+    /// file stem) and `{ext}` placeholders; `.{ext}` is appended when there is no extension
+    Template { template: String },
+    /// One file name per entry of `sizes`, in the same order
+    FileNames { names: Vec<String> },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MultiExportOptions {
+    pub fit: SizeFit,
+    pub upscale: UpscaleMode,
+    /// For JPEG, WebP and AVIF output
+    pub quality: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiExportItem {
+    pub size: u32,
+    pub width: u32,
+    pub height: u32,
+    pub output_path: String,
+    pub file_size: u64,
+    pub success: bool,
+    /// Left out because it is larger than the source
+    pub skipped: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiExportResult {
+    pub success: bool,
+    pub items: Vec<MultiExportItem>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizePreset {
+    pub id: String,
+    pub name: String,
+    pub sizes: Vec<u32>,
+    pub format: String,
+    pub fit: SizeFit,
+    pub naming: NamingPattern,
+}
+
+fn size_preset(id: &str, name: &str, files: &[(u32, &str)]) -> SizePreset {
+    SizePreset {
+        id: id.to_string(),
+        name: name.to_string(),
+        sizes: files.iter().map(|(size, _)| *size).collect(),
+        format: "png".to_string(),
+        fit: SizeFit::SquareCrop,
+        naming: NamingPattern::FileNames {
+            names: files.iter().map(|(_, name)| name.to_string()).collect(),
+        },
+    }
+}
+
+/// Common icon sets. The Tauri set has everything `bundle.icon` and the Windows Store
+/// need except `icon.icns`, which `tauri icon` can build from `icon.png`.
+pub fn list_size_presets() -> Vec<SizePreset> {
+    vec![
+        size_preset(
+            "tauri",
+            "Tauri icon set",
+            &[
+                (32, "32x32.png"),
+                (128, "128x128.png"),
+                (256, "128x128@2x.png"),
+                (512, "icon.png"),
+                (256, "icon.ico"),
+                (30, "Square30x30Logo.png"),
+                (44, "Square44x44Logo.png"),
+                (71, "Square71x71Logo.png"),
+                (89, "Square89x89Logo.png"),
+                (107, "Square107x107Logo.png"),
+                (142, "Square142x142Logo.png"),
+                (150, "Square150x150Logo.png"),
+                (284, "Square284x284Logo.png"),
+                (310, "Square310x310Logo.png"),
+                (50, "StoreLogo.png"),
+            ],
+        ),
+        size_preset(
+            "favicon",
+            "Favicon set",
+            &[
+                (16, "favicon-16x16.png"),
+                (32, "favicon-32x32.png"),
+                (48, "favicon.ico"),
+                (180, "apple-touch-icon.png"),
+                (192, "android-chrome-192x192.png"),
+                (512, "android-chrome-512x512.png"),
+            ],
+        ),
+        size_preset(
+            "apple_touch_icon",
+            "Apple touch icon",
+            &[
+                (180, "apple-touch-icon.png"),
+                (167, "apple-touch-icon-167x167.png"),
+                (152, "apple-touch-icon-152x152.png"),
+                (120, "apple-touch-icon-120x120.png"),
+            ],
+        ),
+    ]
+}
+
+fn format_extension(format: &str) -> &str {
+    match format {
+        "jpeg" => "jpg",
+        "tif" => "tiff",
+        other => other,
+    }
+}
+
+/// File name for one size, or an error when it would leave `output_dir`
+fn export_file_name(
+    naming: &NamingPattern,
+    index: usize,
+    stem: &str,
+    size: u32,
+    (width, height): (u32, u32),
+    format: &str,
+) -> Result<String, String> {
+    let ext = format_extension(format);
+    let name = match naming {
+        NamingPattern::Template { template } => {
+            let name = template
+                .replace("{size}", &size.to_string())
+                .replace("{width}", &width.to_string())
+                .replace("{height}", &height.to_string())
+                .replace("{name}", stem)
+                .replace("{ext}", ext);
+            if Path::new(&name).extension().is_none() {
+                format!("{}.{}", name, ext)
+            } else {
+                name
+            }
+        }
+        NamingPattern::FileNames { names } => names[index].clone(),
+    };
+    let name = name.trim().to_string();
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(format!("Invalid file name: '{}'", name));
+    }
+    Ok(name)
+}
+
+/// Size the export of `size` will have, without resizing anything
+fn fitted_dimensions(width: u32, height: u32, size: u32, fit: SizeFit) -> (u32, u32) {
+    match fit {
+        SizeFit::SquareCrop => (size, size),
+        SizeFit::LongEdge => {
+            let long = width.max(height) as f64;
+            let scale = |edge: u32| ((edge as f64 * size as f64 / long).round() as u32).max(1);
+            (scale(width), scale(height))
+        }
+    }
+}
+
+/// Scale (and crop) `img` for one export size
+fn fit_to_size(img: &DynamicImage, size: u32, fit: SizeFit) -> DynamicImage {
+    let (width, height) = fitted_dimensions(img.width(), img.height(), size, fit);
+    let filter = imageops::FilterType::Lanczos3;
+    match fit {
+        SizeFit::SquareCrop => {
+            let side = img.width().min(img.height());
+            img.crop_imm(
+                (img.width() - side) / 2,
+                (img.height() - side) / 2,
+                side,
+                side,
+            )
+            .resize_exact(width, height, filter)
+        }
+        SizeFit::LongEdge => img.resize_exact(width, height, filter),
+    }
+}
+
+fn save_export(img: &DynamicImage, output: &Path, format: &str, quality: u8) -> Result<(), String> {
+    match format {
+        "png" => save_as_png(img, output),
+        "jpeg" | "jpg" => save_as_jpeg(&flatten_on_white(img), output, quality),
+        "webp" => save_as_webp(img, output, quality),
+        "avif" => save_as_avif(img, output, quality),
+        "ico" => save_as_ico(img, output),
+        _ => Err(format!("Unsupported format: {}", format)),
+    }
+}
+
+/// Write `input_path` to `output_dir` once per entry of `sizes`.
+///
+/// Each file is written in the format its name's extension implies, falling back to
+/// `format`, so a set can mix PNGs with an ICO. A size that fails does not stop the rest.
+pub fn export_multiple_sizes(
+    input_path: &str,
+    output_dir: &str,
+    sizes: &[u32],
+    format: &str,
+    naming: &NamingPattern,
+    options: &MultiExportOptions,
+) -> MultiExportResult {
+    let fail = |error: String| MultiExportResult {
+        error: Some(error),
+        ..Default::default()
+    };
+    if sizes.is_empty() {
+        return fail("No sizes were given".to_string());
+    }
+    if let Some(size) = sizes
+        .iter()
+        .find(|size| !(1..=MAX_EXPORT_SIZE).contains(*size))
+    {
+        return fail(format!(
+            "Size {} is out of range (1-{})",
+            size, MAX_EXPORT_SIZE
+        ));
+    }
+    if let NamingPattern::FileNames { names } = naming {
+        if names.len() != sizes.len() {
+            return fail(format!(
+                "Expected {} file names, got {}",
+                sizes.len(),
+                names.len()
+            ));
+        }
+    }
+    let format = format.to_lowercase();
+    let output_dir = Path::new(output_dir);
+    if !output_dir.is_dir() {
+        return fail(format!("Not a directory: {}", output_dir.display()));
+    }
+    let (img, mut warnings) = match decode_for_conversion(Path::new(input_path)) {
+        Ok(decoded) => decoded,
+        Err(e) => return fail(e),
+    };
+    let stem = Path::new(input_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("image");
+    // The edge that gets scaled to `size`
+    let source_size = match options.fit {
+        SizeFit::SquareCrop => img.width().min(img.height()),
+        SizeFit::LongEdge => img.width().max(img.height()),
+    };
+
+    let mut names = HashSet::new();
+    let mut planned = Vec::new();
+    for (index, &size) in sizes.iter().enumerate() {
+        let dimensions = fitted_dimensions(img.width(), img.height(), size, options.fit);
+        let name = match export_file_name(naming, index, stem, size, dimensions, &format) {
+            Ok(name) => name,
+            Err(e) => return fail(e),
+        };
+        if !names.insert(name.clone()) {
+            return fail(format!("More than one size would be written to {}", name));
+        }
+        planned.push((size, dimensions, name));
+    }
+
+    let quality = options
+        .quality
+        .unwrap_or(DEFAULT_CONVERT_QUALITY)
+        .clamp(1, 100);
+    let mut upscaled = Vec::new();
+    let mut items = Vec::new();
+    for (size, (width, height), name) in planned {
+        let output = output_dir.join(&name);
+        let mut item = MultiExportItem {
+            size,
+            width,
+            height,
+            output_path: output.to_string_lossy().to_string(),
+            file_size: 0,
+            success: false,
+            skipped: false,
+            error: None,
+        };
+        if size > source_size {
+            if options.upscale == UpscaleMode::Skip {
+                item.skipped = true;
+                items.push(item);
+                continue;
+            }
+            upscaled.push(size.to_string());
+        }
+        let item_format = Path::new(&name)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase)
+            .filter(|ext| {
+                matches!(
+                    ext.as_str(),
+                    "png" | "jpg" | "jpeg" | "webp" | "avif" | "ico"
+                )
+            })
+            .unwrap_or_else(|| format.clone());
+        match save_export(
+            &fit_to_size(&img, size, options.fit),
+            &output,
+            &item_format,
+            quality,
+        ) {
+            Ok(()) => {
+                item.success = true;
+                item.file_size = fs::metadata(&output).map(|m| m.len()).unwrap_or(0);
+            }
+            Err(e) => item.error = Some(e),
+        }
+        items.push(item);
+    }
+
+    if !upscaled.is_empty() {
+        upscaled.dedup();
+        warnings.push(format!(
+            "The source is only {}px; these sizes were upscaled and may look blurry: {}",
+            source_size,
+            upscaled.join(", ")
+        ));
+    }
+    let skipped = items.iter().filter(|item| item.skipped).count();
+    if skipped > 0 {
+        warnings.push(format!(
+            "{} size(s) larger than the {}px source were skipped",
+            skipped, source_size
+        ));
+    }
+    MultiExportResult {
+        success: items.iter().any(|item| item.success),
+        items,
+        warnings,
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn png_options() -> CompressionOptions {
+        CompressionOptions {
+            quality: 80,
+            width: None,
+            height: None,
+            output_format: "png".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_batch_continues_after_failure() {
+        let dir = TempDir::new("batch_compress");
+        let input_dir = dir.join("in");
+        fs::create_dir_all(&input_dir).unwrap();
+        let good = input_dir.join("photo.png");
+        image::RgbaImage::from_pixel(8, 8, image::Rgba([200, 10, 10, 255]))
+            .save(&good)
+            .unwrap();
+
+        let inputs = vec![
+            good.to_string_lossy().to_string(),
+            input_dir.join("missing.png").to_string_lossy().to_string(),
+            good.to_string_lossy().to_string(),
+        ];
+        let output_dir = dir.join("out");
+        let mut progress = Vec::new();
+        let result = compress_images_batch(
+            &inputs,
+            &output_dir.to_string_lossy(),
+            &png_options(),
+            |p| progress.push((p.completed, p.total, p.success)),
+        );
+
+        assert_eq!(result.total_files, 3);
+        assert_eq!(result.succeeded, 2);
+        assert_eq!(result.failed, 1);
+        assert_eq!(progress, vec![(1, 3, true), (2, 3, false), (3, 3, true)]);
+        assert!(result.items[0].result.output_path.ends_with("photo.png"));
+        assert!(result.items[2].result.output_path.ends_with("photo_1.png"));
+        assert!(result.total_original_size > 0);
+    }
+
+    /// A gradient with pseudo-random noise, so quality and colors affect the file size
+    fn noisy_image(path: &Path, size: u32) {
+        let mut seed = 0x2545_f491u32;
+        let img = image::RgbImage::from_fn(size, size, |x, y| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let noise = (seed >> 24) as u8 / 4;
+            image::Rgb([
+                (x * 255 / size) as u8 / 2 + noise,
+                (y * 255 / size) as u8 / 2 + noise,
+                noise * 2,
+            ])
+        });
+        img.save(path).unwrap();
+    }
+
+    #[test]
+    fn test_compress_to_target_size_searches_quality() {
+        let dir = TempDir::new("target_size_quality");
+        let input = dir.join("photo.png");
+        noisy_image(&input, 128);
+        let input = input.to_str().unwrap();
+        let full = dir.join("full.jpg");
+        let full = full.to_str().unwrap();
+        let target = dir.join("target.jpg");
+        let target = target.to_str().unwrap();
+
+        let max = compress_to_target_size(input, full, u64::MAX, "jpeg", 1, false);
+        assert_eq!(max.target.unwrap().quality, Some(100));
+
+        let budget = max.compressed_size / 2;
+        let result = compress_to_target_size(input, target, budget, "jpg", 10, false);
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.compressed_size <= budget);
+        let info = result.target.unwrap();
+        assert!(info.reached);
+        assert!(info.attempts <= MAX_TARGET_ATTEMPTS);
+        assert_eq!(info.scale, 1.0);
+        let quality = info.quality.unwrap();
+        assert!((10..100).contains(&quality));
+        // One step up no longer fits
+        let above = encode_jpeg(&image::open(input).unwrap(), quality + 1).unwrap();
+        assert!(above.len() as u64 > budget);
+    }
+
+    #[test]
+    fn test_compress_to_target_size_resizes_when_allowed() {
+        let dir = TempDir::new("target_size_resize");
+        let input = dir.join("photo.png");
+        noisy_image(&input, 256);
+        let input = input.to_str().unwrap();
+        let output = dir.join("small.webp");
+        let output = output.to_str().unwrap();
+
+        let result = compress_to_target_size(input, output, 3_000, "webp", 80, false);
+        assert!(result.success);
+        assert!(result.compressed_size > 3_000);
+        let info = result.target.unwrap();
+        assert!(!info.reached);
+        assert_eq!((info.quality, info.attempts), (Some(80), 1));
+        assert!(result.warnings[0].contains("3000"));
+
+        let result = compress_to_target_size(input, output, 3_000, "webp", 80, true);
+        assert!(result.compressed_size <= 3_000);
+        let info = result.target.unwrap();
+        assert!(info.reached);
+        assert!(info.scale < 1.0);
+        assert!(info.attempts <= MAX_TARGET_ATTEMPTS);
+        let img = image::open(output).unwrap();
+        assert_eq!(img.width(), (256.0 * info.scale).round() as u32);
+    }
+
+    #[test]
+    fn test_compress_to_target_size_reduces_png_colors() {
+        let dir = TempDir::new("target_size_png");
+        let input = dir.join("photo.png");
+        noisy_image(&input, 128);
+        let input = input.to_str().unwrap();
+        let output = dir.join("out.png");
+        let output = output.to_str().unwrap();
+        let lossless = encode_png(&image::open(input).unwrap()).unwrap().len() as u64;
+
+        // PNG has no quality setting, so colors are reduced instead
+        let result = compress_to_target_size(input, output, lossless * 2 / 3, "png", 1, false);
+        assert!(result.compressed_size <= lossless * 2 / 3);
+        let info = result.target.unwrap();
+        assert!(info.reached);
+        assert_eq!(info.quality, None);
+        assert!(info.color_bits.unwrap() < 8);
+
+        // With min_quality 100 only lossless output is allowed
+        let result = compress_to_target_size(input, output, lossless / 2, "png", 100, false);
+        let info = result.target.unwrap();
+        assert!(!info.reached);
+        assert_eq!(info.color_bits, Some(8));
+        assert_eq!(result.compressed_size, lossless);
+
+        assert!(compress_to_target_size(input, output, 0, "png", 50, true)
+            .error
+            .is_some());
+        assert!(
+            compress_to_target_size(input, output, 1_000, "bmp", 50, true)
+                .error
+                .unwrap()
+                .contains("Unsupported")
+        );
+    }
+
+    #[test]
+    fn test_unique_output_path_avoids_existing_files() {
+        let dir = TempDir::new("batch_unique");
+        fs::write(dir.join("a.jpg"), b"x").unwrap();
+
+        let mut reserved = HashSet::new();
+        let first = unique_output_path(&dir, "/src/a.png", "jpeg", &mut reserved);
+        let second = unique_output_path(&dir, "/other/a.webp", "jpeg", &mut reserved);
+
+        assert_eq!(first, dir.join("a_1.jpg"));
+        assert_eq!(second, dir.join("a_2.jpg"));
+    }
+
+    #[test]
+    fn test_convert_to_ico_embeds_multiple_sizes() {
+        let dir = TempDir::new("convert_ico");
+        let input = dir.join("logo.png");
+        image::RgbaImage::from_pixel(64, 40, image::Rgba([0, 120, 255, 255]))
+            .save(&input)
+            .unwrap();
+        let output = dir.join("logo.ico");
+
+        let result = convert_image_format(
+            &input.to_string_lossy(),
+            &output.to_string_lossy(),
+            "ico",
+            None,
+        );
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.warnings.len(), 1);
+        let data = fs::read(&output).unwrap();
+        // ICONDIR: reserved, type 1 (icon), image count
+        assert_eq!(&data[..4], &[0, 0, 1, 0]);
+        assert_eq!(u16::from_le_bytes([data[4], data[5]]), 4);
+        let decoded = image::open(&output).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (64, 64));
+    }
+
+    #[test]
+    fn test_convert_animated_gif_warns_about_frames() {
+        use image::codecs::gif::GifEncoder;
+        use image::Frame;
+
+        let dir = TempDir::new("convert_gif");
+        let input = dir.join("anim.gif");
+        let frames = [[255, 0, 0, 255], [0, 255, 0, 255]]
+            .map(|color| Frame::new(image::RgbaImage::from_pixel(4, 4, image::Rgba(color))));
+        GifEncoder::new(fs::File::create(&input).unwrap())
+            .encode_frames(frames)
+            .unwrap();
+        let output = dir.join("anim.png");
+
+        let result = convert_image_format(
+            &input.to_string_lossy(),
+            &output.to_string_lossy(),
+            "png",
+            None,
+        );
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.warnings[0].contains("first frame"));
+        let first = image::open(&output).unwrap().to_rgba8();
+        assert!(first.get_pixel(0, 0)[0] > 200);
+    }
+
+    #[test]
+    fn test_convert_to_jpeg_flattens_transparency() {
+        let dir = TempDir::new("convert_jpeg");
+        let input = dir.join("clear.png");
+        image::RgbaImage::from_pixel(8, 8, image::Rgba([0, 0, 0, 0]))
+            .save(&input)
+            .unwrap();
+        let output = dir.join("clear.jpg");
+
+        let result = convert_image_format(
+            &input.to_string_lossy(),
+            &output.to_string_lossy(),
+            "jpg",
+            Some(95),
+        );
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.warnings[0].contains("transparency"));
+        let pixel = *image::open(&output).unwrap().to_rgb8().get_pixel(4, 4);
+        assert!(pixel.0.iter().all(|&c| c > 240));
+    }
+
+    #[test]
+    fn test_convert_rejects_unsupported_format() {
+        let dir = TempDir::new("convert_unsupported");
+        let input = dir.join("photo.png");
+        image::RgbaImage::from_pixel(4, 4, image::Rgba([1, 2, 3, 255]))
+            .save(&input)
+            .unwrap();
+
+        let result = convert_image_format(
+            &input.to_string_lossy(),
+            &dir.join("photo.xyz").to_string_lossy(),
+            "xyz",
+            None,
+        );
+
+        assert!(!result.success);
+        assert_eq!(result.error.as_deref(), Some("Unsupported format: xyz"));
+    }
+
+    #[test]
+    fn test_convert_batch_uses_target_extension() {
+        let dir = TempDir::new("convert_batch");
+        let input = dir.join("scan.png");
+        image::RgbaImage::from_pixel(4, 4, image::Rgba([9, 9, 9, 255]))
+            .save(&input)
+            .unwrap();
+        let output_dir = dir.join("out");
+
+        let result = convert_images_batch(
+            &[input.to_string_lossy().to_string()],
+            &output_dir.to_string_lossy(),
+            "tif",
+            None,
+            |_| {},
+        );
+
+        assert_eq!(result.succeeded, 1);
+        assert!(result.items[0].result.output_path.ends_with("scan.tiff"));
+        assert!(image::open(output_dir.join("scan.tiff")).is_ok());
+    }
+
+    #[test]
+    fn test_export_multiple_sizes_with_template() {
+        let dir = TempDir::new("multi_export");
+        let input = dir.join("logo.png");
+        image::RgbaImage::from_pixel(200, 100, image::Rgba([10, 120, 200, 255]))
+            .save(&input)
+            .unwrap();
+        let out = dir.join("out");
+        fs::create_dir_all(&out).unwrap();
+
+        let naming = NamingPattern::Template {
+            template: "{name}_{size}".to_string(),
+        };
+        let result = export_multiple_sizes(
+            input.to_str().unwrap(),
+            out.to_str().unwrap(),
+            &[16, 64, 256],
+            "png",
+            &naming,
+            &MultiExportOptions::default(),
+        );
+        assert!(result.success);
+        assert_eq!(result.items.len(), 3);
+        let img = image::open(out.join("logo_64.png")).unwrap();
+        assert_eq!((img.width(), img.height()), (64, 64));
+        // 256 is larger than the 100px square that can be cropped from the source
+        assert!(result.warnings[0].contains("256"));
+
+        let options = MultiExportOptions {
+            fit: SizeFit::LongEdge,
+            upscale: UpscaleMode::Skip,
+            quality: None,
+        };
+        let naming = NamingPattern::Template {
+            template: "wide_{width}x{height}.jpg".to_string(),
+        };
+        let result = export_multiple_sizes(
+            input.to_str().unwrap(),
+            out.to_str().unwrap(),
+            &[100, 400],
+            "png",
+            &naming,
+            &options,
+        );
+        assert!(result.items[0].success);
+        assert!(result.items[1].skipped);
+        let img = image::open(out.join("wide_100x50.jpg")).unwrap();
+        assert_eq!((img.width(), img.height()), (100, 50));
+    }
+
+    #[test]
+    fn test_export_multiple_sizes_with_preset() {
+        let dir = TempDir::new("multi_export_preset");
+        let input = dir.join("icon.png");
+        image::RgbaImage::from_pixel(512, 512, image::Rgba([0, 0, 0, 255]))
+            .save(&input)
+            .unwrap();
+
+        let presets = list_size_presets();
+        let favicon = presets.iter().find(|p| p.id == "favicon").unwrap();
+        let result = export_multiple_sizes(
+            input.to_str().unwrap(),
+            dir.to_str().unwrap(),
+            &favicon.sizes,
+            &favicon.format,
+            &favicon.naming,
+            &MultiExportOptions::default(),
+        );
+        assert!(result.items.iter().all(|item| item.success));
+        assert!(result.warnings.is_empty());
+        let ico = fs::read(dir.join("favicon.ico")).unwrap();
+        // ICO header: reserved 0, type 1, then the image count (16, 32 and 48)
+        assert_eq!(&ico[..6], &[0, 0, 1, 0, 3, 0]);
+
+        let clash = NamingPattern::Template {
+            template: "icon.png".to_string(),
+        };
+        let result = export_multiple_sizes(
+            input.to_str().unwrap(),
+            dir.to_str().unwrap(),
+            &[16, 32],
+            "png",
+            &clash,
+            &MultiExportOptions::default(),
+        );
+        assert!(result.error.unwrap().contains("icon.png"));
+
+        let escape = NamingPattern::Template {
+            template: "../{size}.png".to_string(),
+        };
+        let result = export_multiple_sizes(
+            input.to_str().unwrap(),
+            dir.to_str().unwrap(),
+            &[16],
+            "png",
+            &escape,
+            &MultiExportOptions::default(),
+        );
+        assert!(result.error.unwrap().contains("Invalid file name"));
+    }
+}
@@ -1,8 +1,31 @@
-use image::{DynamicImage, ImageBuffer, ImageFormat, ImageReader, Rgba};
+use ab_glyph::{point, Font, FontArc, FontVec, PxScale, ScaleFont};
+use image::{DynamicImage, ImageBuffer, ImageFormat, ImageReader, Rgba, RgbaImage};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::io::Cursor;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Bundled font used for watermark text
+const WATERMARK_FONT: &[u8] = include_bytes!("../fonts/DejaVuSans.ttf");
+
+/// Bundled font for Japanese glyphs missing from `WATERMARK_FONT`
+const WATERMARK_CJK_FONT: &[u8] = include_bytes!("../fonts/NotoSansJP-Regular.otf");
+
+/// System fonts tried for glyphs neither bundled font covers
+const SYSTEM_FALLBACK_FONTS: &[&str] = &[
+    "/System/Library/Fonts/ヒラギノ角ゴシック W3.ttc",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    "/Library/Fonts/Arial Unicode.ttf",
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+    "C:\\Windows\\Fonts\\meiryo.ttc",
+    "C:\\Windows\\Fonts\\msgothic.ttc",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/fonts-japanese-gothic.ttf",
+];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageEditorInfo {
@@ -39,6 +62,132 @@ pub enum ImageFilter {
     Sharpen,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WatermarkPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Center,
+}
+
+/// What `crop_image` does with a region that extends past the image
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum CropBounds {
+    /// Reject the crop with an error
+    #[default]
+    Error,
+    /// Shrink the region to the part that overlaps the image
+    Clamp,
+}
+
+/// Width:height ratio for `crop_image_with_aspect`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AspectRatio {
+    Square,
+    Ratio4x3,
+    Ratio16x9,
+    Ratio3x2,
+    Custom { width: u32, height: u32 },
+}
+
+impl AspectRatio {
+    fn dimensions(self) -> (u32, u32) {
+        match self {
+            AspectRatio::Square => (1, 1),
+            AspectRatio::Ratio4x3 => (4, 3),
+            AspectRatio::Ratio16x9 => (16, 9),
+            AspectRatio::Ratio3x2 => (3, 2),
+            AspectRatio::Custom { width, height } => (width, height),
+        }
+    }
+}
+
+/// Where an aspect crop sits within the image
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum CropAnchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl CropAnchor {
+    /// Horizontal and vertical position as fractions of the free space (0, 1/2 or 1)
+    fn offsets(self) -> (u32, u32) {
+        match self {
+            CropAnchor::TopLeft => (0, 0),
+            CropAnchor::Top => (1, 0),
+            CropAnchor::TopRight => (2, 0),
+            CropAnchor::Left => (0, 1),
+            CropAnchor::Center => (1, 1),
+            CropAnchor::Right => (2, 1),
+            CropAnchor::BottomLeft => (0, 2),
+            CropAnchor::Bottom => (1, 2),
+            CropAnchor::BottomRight => (2, 2),
+        }
+    }
+}
+
+/// A crop rectangle inside an image
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CropRegion {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatermarkOptions {
+    pub text: String,
+    pub font_size: f32,
+    pub position: WatermarkPosition,
+    /// 0.0 (transparent) to 1.0 (opaque)
+    pub opacity: f32,
+    /// Hex color such as `#ffffff` or `fff`
+    pub color: String,
+}
+
+/// How `combine_images` arranges its inputs
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CombineLayout {
+    Horizontal,
+    Vertical,
+    /// Left to right, top to bottom; every cell is as large as the largest image
+    Grid {
+        columns: u32,
+    },
+}
+
+/// How each input is scaled before `combine_images` places it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CombineResize {
+    /// Shrink to the smallest height, or the smallest width in a vertical layout
+    MatchSize,
+    Original,
+    /// Scale to fit inside the box, keeping the aspect ratio
+    Fit {
+        width: u32,
+        height: u32,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombineOptions {
+    /// Gap between neighbouring images in pixels
+    pub padding: u32,
+    /// Hex color such as `#ffffff`; empty or `transparent` for no background
+    pub background: String,
+    pub resize: CombineResize,
+}
+
 pub fn get_editor_image_info(path: &str) -> Result<ImageEditorInfo, String> {
     let input = Path::new(path);
 
@@ -88,15 +237,7 @@ fn save_image(img: &DynamicImage, output_path: &str) -> Result<(), String> {
         .unwrap_or_else(|| "png".to_string());
 
     match format.as_str() {
-        "jpg" | "jpeg" => {
-            let rgb = img.to_rgb8();
-            let mut buffer = Cursor::new(Vec::new());
-            let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut buffer, 90);
-            rgb.write_with_encoder(encoder)
-                .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
-            fs::write(output, buffer.into_inner())
-                .map_err(|e| format!("Failed to write file: {}", e))?;
-        }
+        "jpg" | "jpeg" => save_jpeg(img, output)?,
         _ => {
             img.save_with_format(output, ImageFormat::Png)
                 .map_err(|e| format!("Failed to save image: {}", e))?;
@@ -106,6 +247,15 @@ fn save_image(img: &DynamicImage, output_path: &str) -> Result<(), String> {
     Ok(())
 }
 
+fn save_jpeg(img: &DynamicImage, output: &Path) -> Result<(), String> {
+    let rgb = img.to_rgb8();
+    let mut buffer = Cursor::new(Vec::new());
+    let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut buffer, 90);
+    rgb.write_with_encoder(encoder)
+        .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
+    fs::write(output, buffer.into_inner()).map_err(|e| format!("Failed to write file: {}", e))
+}
+
 fn create_result(
     success: bool,
     output_path: &str,
@@ -176,6 +326,74 @@ pub fn rotate_image(input_path: &str, output_path: &str, angle: RotationAngle) -
     create_result(true, output_path, original_size, Some(&rotated), None)
 }
 
+/// Validate a requested crop against an image of `image_width` x `image_height`.
+fn resolve_crop_region(
+    image_width: u32,
+    image_height: u32,
+    region: CropRegion,
+    bounds: CropBounds,
+) -> Result<CropRegion, String> {
+    if region.width == 0 || region.height == 0 {
+        return Err("Crop area is empty".to_string());
+    }
+    // Widen before adding so huge values cannot overflow
+    let fits = u64::from(region.x) + u64::from(region.width) <= u64::from(image_width)
+        && u64::from(region.y) + u64::from(region.height) <= u64::from(image_height);
+    if fits {
+        return Ok(region);
+    }
+
+    match bounds {
+        CropBounds::Error => Err("Crop area exceeds image bounds".to_string()),
+        CropBounds::Clamp => {
+            if region.x >= image_width || region.y >= image_height {
+                return Err("Crop area is outside the image".to_string());
+            }
+            Ok(CropRegion {
+                width: region.width.min(image_width - region.x),
+                height: region.height.min(image_height - region.y),
+                ..region
+            })
+        }
+    }
+}
+
+/// The largest region of the given aspect ratio that fits the image, placed at `anchor`.
+fn aspect_crop_region(
+    image_width: u32,
+    image_height: u32,
+    aspect: AspectRatio,
+    anchor: CropAnchor,
+) -> Result<CropRegion, String> {
+    let (ratio_w, ratio_h) = aspect.dimensions();
+    if ratio_w == 0 || ratio_h == 0 {
+        return Err("Aspect ratio must be positive".to_string());
+    }
+    let (image_w, image_h) = (u64::from(image_width), u64::from(image_height));
+    let (ratio_w, ratio_h) = (u64::from(ratio_w), u64::from(ratio_h));
+
+    // Keep the full height when the image is wider than the ratio, otherwise the full width
+    let (width, height) = if image_w * ratio_h >= image_h * ratio_w {
+        ((image_h * ratio_w + ratio_h / 2) / ratio_h, image_h)
+    } else {
+        (image_w, (image_w * ratio_h + ratio_w / 2) / ratio_w)
+    };
+    let width = width.clamp(1, image_w) as u32;
+    let height = height.clamp(1, image_h) as u32;
+
+    let (anchor_x, anchor_y) = anchor.offsets();
+    Ok(CropRegion {
+        x: (image_width - width) * anchor_x / 2,
+        y: (image_height - height) * anchor_y / 2,
+        width,
+        height,
+    })
+}
+
+fn crop_to_region(img: &DynamicImage, region: CropRegion) -> DynamicImage {
+    img.crop_imm(region.x, region.y, region.width, region.height)
+}
+
 pub fn crop_image(
     input_path: &str,
     output_path: &str,
@@ -183,23 +401,51 @@ pub fn crop_image(
     y: u32,
     width: u32,
     height: u32,
+    bounds: CropBounds,
 ) -> EditResult {
     let (img, original_size) = match load_image(input_path) {
         Ok(result) => result,
         Err(e) => return create_result(false, output_path, 0, None, Some(e)),
     };
 
-    if x + width > img.width() || y + height > img.height() {
-        return create_result(
-            false,
-            output_path,
-            original_size,
-            None,
-            Some("Crop area exceeds image bounds".to_string()),
-        );
+    let requested = CropRegion {
+        x,
+        y,
+        width,
+        height,
+    };
+    let region = match resolve_crop_region(img.width(), img.height(), requested, bounds) {
+        Ok(region) => region,
+        Err(e) => return create_result(false, output_path, original_size, None, Some(e)),
+    };
+
+    let cropped = crop_to_region(&img, region);
+
+    if let Err(e) = save_image(&cropped, output_path) {
+        return create_result(false, output_path, original_size, None, Some(e));
     }
 
-    let cropped = img.crop_imm(x, y, width, height);
+    create_result(true, output_path, original_size, Some(&cropped), None)
+}
+
+/// Crop the largest `aspect` region that fits the image, positioned at `anchor`.
+pub fn crop_image_with_aspect(
+    input_path: &str,
+    output_path: &str,
+    aspect: AspectRatio,
+    anchor: CropAnchor,
+) -> EditResult {
+    let (img, original_size) = match load_image(input_path) {
+        Ok(result) => result,
+        Err(e) => return create_result(false, output_path, 0, None, Some(e)),
+    };
+
+    let region = match aspect_crop_region(img.width(), img.height(), aspect, anchor) {
+        Ok(region) => region,
+        Err(e) => return create_result(false, output_path, original_size, None, Some(e)),
+    };
+
+    let cropped = crop_to_region(&img, region);
 
     if let Err(e) = save_image(&cropped, output_path) {
         return create_result(false, output_path, original_size, None, Some(e));
@@ -315,3 +561,1232 @@ pub fn flip_vertical(input_path: &str, output_path: &str) -> EditResult {
 
     create_result(true, output_path, original_size, Some(&flipped), None)
 }
+
+/// Check the text and font size, and return the parsed color
+fn validate_watermark_options(options: &WatermarkOptions) -> Result<[u8; 3], String> {
+    if options.text.trim().is_empty() {
+        return Err("Watermark text is empty".to_string());
+    }
+    if options.font_size <= 0.0 {
+        return Err("Font size must be greater than 0".to_string());
+    }
+    parse_hex_color(&options.color)
+}
+
+pub fn add_text_watermark(
+    input_path: &str,
+    output_path: &str,
+    options: WatermarkOptions,
+) -> EditResult {
+    let (img, original_size) = match load_image(input_path) {
+        Ok(result) => result,
+        Err(e) => return create_result(false, output_path, 0, None, Some(e)),
+    };
+
+    let color = match validate_watermark_options(&options) {
+        Ok(color) => color,
+        Err(e) => return create_result(false, output_path, original_size, None, Some(e)),
+    };
+
+    let mut canvas = img.to_rgba8();
+    draw_watermark(&mut canvas, &options, color);
+    let watermarked = DynamicImage::ImageRgba8(canvas);
+
+    // Keep the input's format regardless of the output extension
+    let format = ImageFormat::from_path(input_path).unwrap_or(ImageFormat::Png);
+    if let Err(e) = save_image_as(&watermarked, output_path, format) {
+        return create_result(false, output_path, original_size, None, Some(e));
+    }
+
+    create_result(true, output_path, original_size, Some(&watermarked), None)
+}
+
+fn save_image_as(img: &DynamicImage, output_path: &str, format: ImageFormat) -> Result<(), String> {
+    match format {
+        ImageFormat::Jpeg => save_jpeg(img, Path::new(output_path)),
+        other => img
+            .save_with_format(output_path, other)
+            .map_err(|e| format!("Failed to save image: {}", e)),
+    }
+}
+
+fn parse_hex_color(color: &str) -> Result<[u8; 3], String> {
+    let hex = color.trim().trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid color: {}", color));
+    }
+    let expanded: String = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 => hex.to_string(),
+        _ => return Err(format!("Invalid color: {}", color)),
+    };
+
+    let channel = |i: usize| {
+        u8::from_str_radix(&expanded[i..i + 2], 16).map_err(|_| format!("Invalid color: {}", color))
+    };
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// `combine_images` refuses to create an image wider or taller than this
+const MAX_COMBINED_DIMENSION: u64 = 20_000;
+
+/// Size of each input after `resize` is applied
+fn combined_image_sizes(
+    sizes: &[(u32, u32)],
+    layout: CombineLayout,
+    resize: CombineResize,
+) -> Vec<(u32, u32)> {
+    let scale = |(w, h): (u32, u32), factor: f64| {
+        (
+            ((f64::from(w) * factor).round() as u32).max(1),
+            ((f64::from(h) * factor).round() as u32).max(1),
+        )
+    };
+    match resize {
+        CombineResize::Original => sizes.to_vec(),
+        CombineResize::MatchSize if layout == CombineLayout::Vertical => {
+            let width = sizes.iter().map(|(w, _)| *w).min().unwrap_or(1);
+            sizes
+                .iter()
+                .map(|&size| scale(size, f64::from(width) / f64::from(size.0)))
+                .collect()
+        }
+        CombineResize::MatchSize => {
+            let height = sizes.iter().map(|(_, h)| *h).min().unwrap_or(1);
+            sizes
+                .iter()
+                .map(|&size| scale(size, f64::from(height) / f64::from(size.1)))
+                .collect()
+        }
+        CombineResize::Fit { width, height } => sizes
+            .iter()
+            .map(|&size| {
+                let factor = (f64::from(width) / f64::from(size.0))
+                    .min(f64::from(height) / f64::from(size.1));
+                scale(size, factor)
+            })
+            .collect(),
+    }
+}
+
+/// Canvas size and the top-left corner of each image; images smaller than their row,
+/// column or cell are centered in it
+fn combined_layout(
+    sizes: &[(u32, u32)],
+    layout: CombineLayout,
+    padding: u32,
+) -> ((u64, u64), Vec<(u64, u64)>) {
+    let padding = u64::from(padding);
+    let gaps = |count: u64| padding * count.saturating_sub(1);
+    let count = sizes.len() as u64;
+    let widths = sizes.iter().map(|(w, _)| u64::from(*w));
+    let heights = sizes.iter().map(|(_, h)| u64::from(*h));
+
+    match layout {
+        CombineLayout::Horizontal => {
+            let height = heights.max().unwrap_or(0);
+            let mut x = 0;
+            let positions = sizes
+                .iter()
+                .map(|&(w, h)| {
+                    let position = (x, (height - u64::from(h)) / 2);
+                    x += u64::from(w) + padding;
+                    position
+                })
+                .collect();
+            ((widths.sum::<u64>() + gaps(count), height), positions)
+        }
+        CombineLayout::Vertical => {
+            let width = widths.max().unwrap_or(0);
+            let mut y = 0;
+            let positions = sizes
+                .iter()
+                .map(|&(w, h)| {
+                    let position = ((width - u64::from(w)) / 2, y);
+                    y += u64::from(h) + padding;
+                    position
+                })
+                .collect();
+            ((width, heights.sum::<u64>() + gaps(count)), positions)
+        }
+        CombineLayout::Grid { columns } => {
+            let columns = u64::from(columns).clamp(1, count.max(1));
+            let rows = count.div_ceil(columns);
+            let (cell_width, cell_height) = (widths.max().unwrap_or(0), heights.max().unwrap_or(0));
+            let positions = sizes
+                .iter()
+                .enumerate()
+                .map(|(index, &(w, h))| {
+                    let (column, row) = (index as u64 % columns, index as u64 / columns);
+                    (
+                        column * (cell_width + padding) + (cell_width - u64::from(w)) / 2,
+                        row * (cell_height + padding) + (cell_height - u64::from(h)) / 2,
+                    )
+                })
+                .collect();
+            (
+                (
+                    cell_width * columns + gaps(columns),
+                    cell_height * rows + gaps(rows),
+                ),
+                positions,
+            )
+        }
+    }
+}
+
+/// Place several images side by side, stacked, or in a grid and save them as one.
+///
+/// Inputs may mix formats; the output format follows the output extension.
+pub fn combine_images(
+    input_paths: Vec<String>,
+    output_path: String,
+    layout: CombineLayout,
+    options: CombineOptions,
+) -> EditResult {
+    let fail = |error: String| create_result(false, &output_path, 0, None, Some(error));
+
+    if input_paths.is_empty() {
+        return fail("No images to combine".to_string());
+    }
+    if layout == (CombineLayout::Grid { columns: 0 }) {
+        return fail("Grid must have at least one column".to_string());
+    }
+    if let CombineResize::Fit { width, height } = options.resize {
+        if width == 0 || height == 0 {
+            return fail("Fit size must be greater than 0".to_string());
+        }
+    }
+    let background = match options.background.trim() {
+        "" | "transparent" => Rgba([0, 0, 0, 0]),
+        color => match parse_hex_color(color) {
+            Ok([r, g, b]) => Rgba([r, g, b, 255]),
+            Err(e) => return fail(e),
+        },
+    };
+
+    let mut images = Vec::with_capacity(input_paths.len());
+    let mut original_size = 0;
+    for path in &input_paths {
+        match load_image(path) {
+            Ok((img, size)) => {
+                original_size += size;
+                images.push(img);
+            }
+            Err(e) => return fail(format!("{}: {}", path, e)),
+        }
+    }
+
+    let sizes: Vec<(u32, u32)> = images.iter().map(|i| (i.width(), i.height())).collect();
+    let sizes = combined_image_sizes(&sizes, layout, options.resize);
+    let ((width, height), positions) = combined_layout(&sizes, layout, options.padding);
+    if width > MAX_COMBINED_DIMENSION || height > MAX_COMBINED_DIMENSION {
+        return fail(format!(
+            "Combined image would be {}×{} px, larger than the {} px limit. Use a smaller size or fewer images.",
+            width, height, MAX_COMBINED_DIMENSION
+        ));
+    }
+
+    let mut canvas = RgbaImage::from_pixel(width as u32, height as u32, background);
+    for ((img, &(w, h)), &(x, y)) in images.iter().zip(&sizes).zip(&positions) {
+        let layer = if (w, h) == (img.width(), img.height()) {
+            img.to_rgba8()
+        } else {
+            img.resize_exact(w, h, image::imageops::FilterType::Lanczos3)
+                .to_rgba8()
+        };
+        image::imageops::overlay(&mut canvas, &layer, x as i64, y as i64);
+    }
+    let combined = DynamicImage::ImageRgba8(canvas);
+
+    if let Err(e) = save_image(&combined, &output_path) {
+        return create_result(false, &output_path, original_size, None, Some(e));
+    }
+    create_result(true, &output_path, original_size, Some(&combined), None)
+}
+
+fn fallback_font() -> Option<&'static FontArc> {
+    static FALLBACK: OnceLock<Option<FontArc>> = OnceLock::new();
+    FALLBACK
+        .get_or_init(|| {
+            SYSTEM_FALLBACK_FONTS.iter().find_map(|path| {
+                let data = fs::read(path).ok()?;
+                FontVec::try_from_vec_and_index(data, 0)
+                    .ok()
+                    .map(FontArc::new)
+            })
+        })
+        .as_ref()
+}
+
+/// Bundled Latin and Japanese fonts with a system fallback for glyphs neither covers
+struct WatermarkFonts {
+    primary: FontArc,
+    cjk: FontArc,
+    fallback: Option<&'static FontArc>,
+}
+
+impl WatermarkFonts {
+    fn load() -> Self {
+        Self {
+            primary: FontArc::try_from_slice(WATERMARK_FONT).expect("bundled font is valid"),
+            cjk: FontArc::try_from_slice(WATERMARK_CJK_FONT).expect("bundled font is valid"),
+            fallback: fallback_font(),
+        }
+    }
+
+    fn font_for(&self, c: char) -> &FontArc {
+        if self.primary.glyph_id(c).0 != 0 {
+            return &self.primary;
+        }
+        if self.cjk.glyph_id(c).0 != 0 {
+            return &self.cjk;
+        }
+        match self.fallback {
+            Some(fallback) if fallback.glyph_id(c).0 != 0 => fallback,
+            _ => &self.primary,
+        }
+    }
+
+    fn advance(&self, c: char, scale: PxScale) -> f32 {
+        let font = self.font_for(c);
+        font.as_scaled(scale).h_advance(font.glyph_id(c))
+    }
+
+    fn measure(&self, text: &str, scale: PxScale) -> f32 {
+        text.chars().map(|c| self.advance(c, scale)).sum()
+    }
+
+    fn line_height(&self, scale: PxScale) -> f32 {
+        self.primary.as_scaled(scale).height() + self.primary.as_scaled(scale).line_gap()
+    }
+}
+
+/// Greedy line wrapping that prefers breaking at spaces and falls back to per-character
+/// breaks so CJK text without spaces still fits.
+fn wrap_text(fonts: &WatermarkFonts, text: &str, scale: PxScale, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut current = String::new();
+        let mut width = 0.0;
+        let mut last_space: Option<usize> = None;
+
+        for c in paragraph.chars() {
+            let advance = fonts.advance(c, scale);
+            if width + advance > max_width && !current.is_empty() {
+                match last_space {
+                    Some(index) => {
+                        let rest = current[index + 1..].to_string();
+                        current.truncate(index);
+                        lines.push(std::mem::take(&mut current));
+                        width = fonts.measure(&rest, scale);
+                        current = rest;
+                    }
+                    None => {
+                        lines.push(std::mem::take(&mut current));
+                        width = 0.0;
+                    }
+                }
+                last_space = None;
+                if c == ' ' {
+                    continue;
+                }
+            }
+            if c == ' ' {
+                last_space = Some(current.len());
+            }
+            current.push(c);
+            width += advance;
+        }
+        lines.push(current);
+    }
+
+    lines
+}
+
+fn draw_watermark(canvas: &mut RgbaImage, options: &WatermarkOptions, color: [u8; 3]) {
+    let fonts = WatermarkFonts::load();
+    let (img_width, img_height) = (canvas.width() as f32, canvas.height() as f32);
+    let opacity = options.opacity.clamp(0.0, 1.0);
+
+    // Wrap to the image width, then shrink until the block also fits vertically
+    let mut font_size = options.font_size;
+    let (scale, margin, lines, line_height) = loop {
+        let scale = PxScale::from(font_size);
+        let margin = (font_size * 0.5).max(4.0);
+        let max_width = (img_width - margin * 2.0).max(1.0);
+        let lines = wrap_text(&fonts, &options.text, scale, max_width);
+        let line_height = fonts.line_height(scale);
+        let block_height = line_height * lines.len() as f32;
+        if block_height <= img_height - margin * 2.0 || font_size <= 8.0 {
+            break (scale, margin, lines, line_height);
+        }
+        font_size = (font_size * 0.9).max(8.0);
+    };
+
+    let block_height = line_height * lines.len() as f32;
+    let top = match options.position {
+        WatermarkPosition::TopLeft | WatermarkPosition::TopRight => margin,
+        WatermarkPosition::BottomLeft | WatermarkPosition::BottomRight => {
+            img_height - margin - block_height
+        }
+        WatermarkPosition::Center => (img_height - block_height) / 2.0,
+    };
+    let ascent = fonts.primary.as_scaled(scale).ascent();
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_width = fonts.measure(line, scale);
+        let mut x = match options.position {
+            WatermarkPosition::TopLeft | WatermarkPosition::BottomLeft => margin,
+            WatermarkPosition::TopRight | WatermarkPosition::BottomRight => {
+                img_width - margin - line_width
+            }
+            WatermarkPosition::Center => (img_width - line_width) / 2.0,
+        };
+        let baseline = top + line_height * index as f32 + ascent;
+
+        for c in line.chars() {
+            let font = fonts.font_for(c);
+            let glyph = font
+                .glyph_id(c)
+                .with_scale_and_position(scale, point(x, baseline));
+            x += font.as_scaled(scale).h_advance(glyph.id);
+
+            let Some(outlined) = font.outline_glyph(glyph) else {
+                continue;
+            };
+            let bounds = outlined.px_bounds();
+            outlined.draw(|gx, gy, coverage| {
+                let px = bounds.min.x as i32 + gx as i32;
+                let py = bounds.min.y as i32 + gy as i32;
+                if px < 0 || py < 0 || px >= canvas.width() as i32 || py >= canvas.height() as i32 {
+                    return;
+                }
+                let alpha = coverage.clamp(0.0, 1.0) * opacity;
+                let pixel = canvas.get_pixel_mut(px as u32, py as u32);
+                for channel in 0..3 {
+                    let blended =
+                        pixel[channel] as f32 * (1.0 - alpha) + color[channel] as f32 * alpha;
+                    pixel[channel] = blended.round() as u8;
+                }
+                let dst_alpha = pixel[3] as f32 / 255.0;
+                pixel[3] = ((alpha + dst_alpha * (1.0 - alpha)) * 255.0).round() as u8;
+            });
+        }
+    }
+}
+
+pub type SessionId = String;
+
+/// A single edit applied within an edit session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EditOperation {
+    Resize {
+        width: u32,
+        height: u32,
+        maintain_aspect: bool,
+    },
+    Rotate {
+        angle: RotationAngle,
+    },
+    Crop {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    CropAspect {
+        aspect: AspectRatio,
+        anchor: CropAnchor,
+    },
+    Brightness {
+        value: i32,
+    },
+    Contrast {
+        value: f32,
+    },
+    Filter {
+        filter: ImageFilter,
+    },
+    FlipHorizontal,
+    FlipVertical,
+    Watermark {
+        options: WatermarkOptions,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditSessionState {
+    pub session_id: SessionId,
+    pub current_path: String,
+    pub width: u32,
+    pub height: u32,
+    pub position: usize,
+    pub history_len: usize,
+    pub can_undo: bool,
+    pub can_redo: bool,
+}
+
+struct EditSession {
+    dir: PathBuf,
+    source_size: u64,
+    /// Snapshot files; `history[0]` is the original image
+    history: Vec<PathBuf>,
+    position: usize,
+    next_snapshot: usize,
+}
+
+impl EditSession {
+    fn current(&self) -> &Path {
+        &self.history[self.position]
+    }
+
+    fn snapshot_path(&mut self) -> PathBuf {
+        let path = self.dir.join(format!("step_{:04}.png", self.next_snapshot));
+        self.next_snapshot += 1;
+        path
+    }
+
+    fn state(&self, session_id: &str) -> Result<EditSessionState, String> {
+        let (width, height) = image::image_dimensions(self.current())
+            .map_err(|e| format!("Failed to read image dimensions: {}", e))?;
+        Ok(EditSessionState {
+            session_id: session_id.to_string(),
+            current_path: self.current().to_string_lossy().to_string(),
+            width,
+            height,
+            position: self.position,
+            history_len: self.history.len(),
+            can_undo: self.position > 0,
+            can_redo: self.position + 1 < self.history.len(),
+        })
+    }
+}
+
+/// In-memory edit sessions, managed as Tauri state
+pub struct EditSessionStore {
+    base_dir: PathBuf,
+    sessions: Mutex<HashMap<SessionId, EditSession>>,
+}
+
+impl Default for EditSessionStore {
+    fn default() -> Self {
+        Self::new(std::env::temp_dir().join("taurin-edit-sessions"))
+    }
+}
+
+impl EditSessionStore {
+    pub fn new(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Remove every session and its intermediate files
+    pub fn cleanup_all(&self) {
+        if let Ok(mut sessions) = self.sessions.lock() {
+            for (_, session) in sessions.drain() {
+                let _ = fs::remove_dir_all(&session.dir);
+            }
+        }
+    }
+
+    fn with_session<T>(
+        &self,
+        session_id: &str,
+        f: impl FnOnce(&mut EditSession) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|e| format!("Failed to lock edit sessions: {}", e))?;
+        let session = sessions
+            .get_mut(session_id)
+            .ok_or_else(|| format!("Edit session not found: {}", session_id))?;
+        f(session)
+    }
+}
+
+pub fn start_edit_session(store: &EditSessionStore, path: &str) -> Result<SessionId, String> {
+    let (img, source_size) = load_image(path)?;
+
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let dir = store.base_dir.join(&session_id);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create session dir: {}", e))?;
+
+    let mut session = EditSession {
+        dir,
+        source_size,
+        history: Vec::new(),
+        position: 0,
+        next_snapshot: 0,
+    };
+    let original = session.snapshot_path();
+    if let Err(e) = save_image_as(&img, &original.to_string_lossy(), ImageFormat::Png) {
+        let _ = fs::remove_dir_all(&session.dir);
+        return Err(e);
+    }
+    session.history.push(original);
+
+    store
+        .sessions
+        .lock()
+        .map_err(|e| format!("Failed to lock edit sessions: {}", e))?
+        .insert(session_id.clone(), session);
+
+    Ok(session_id)
+}
+
+pub fn apply_operation(
+    store: &EditSessionStore,
+    session_id: &str,
+    operation: EditOperation,
+) -> EditResult {
+    match apply_operation_in_session(store, session_id, &operation) {
+        Ok((path, source_size, edited)) => {
+            create_result(true, &path, source_size, Some(&edited), None)
+        }
+        Err(e) => create_result(false, "", 0, None, Some(e)),
+    }
+}
+
+/// Decode, edit and encode without holding the session lock, so other sessions are not
+/// blocked, then commit the new snapshot if the session has not moved on in the meantime
+fn apply_operation_in_session(
+    store: &EditSessionStore,
+    session_id: &str,
+    operation: &EditOperation,
+) -> Result<(String, u64, DynamicImage), String> {
+    let (base, snapshot, source_size) = store.with_session(session_id, |session| {
+        Ok((
+            session.current().to_path_buf(),
+            session.snapshot_path(),
+            session.source_size,
+        ))
+    })?;
+
+    let (img, _) = load_image(&base.to_string_lossy())?;
+    let edited = apply_operation_to_image(img, operation)?;
+    let snapshot_str = snapshot.to_string_lossy().to_string();
+    save_image_as(&edited, &snapshot_str, ImageFormat::Png)?;
+
+    let committed = store.with_session(session_id, |session| {
+        if session.current() != base {
+            return Err("The image changed while the edit was running. Try again".to_string());
+        }
+        // A new edit discards anything that could have been redone
+        for path in session.history.drain(session.position + 1..) {
+            let _ = fs::remove_file(path);
+        }
+        session.history.push(snapshot.clone());
+        session.position = session.history.len() - 1;
+        Ok(())
+    });
+    if let Err(e) = committed {
+        let _ = fs::remove_file(&snapshot);
+        return Err(e);
+    }
+
+    Ok((snapshot_str, source_size, edited))
+}
+
+pub fn undo_operation(
+    store: &EditSessionStore,
+    session_id: &str,
+) -> Result<EditSessionState, String> {
+    store.with_session(session_id, |session| {
+        if session.position == 0 {
+            return Err("Nothing to undo".to_string());
+        }
+        session.position -= 1;
+        session.state(session_id)
+    })
+}
+
+pub fn redo_operation(
+    store: &EditSessionStore,
+    session_id: &str,
+) -> Result<EditSessionState, String> {
+    store.with_session(session_id, |session| {
+        if session.position + 1 >= session.history.len() {
+            return Err("Nothing to redo".to_string());
+        }
+        session.position += 1;
+        session.state(session_id)
+    })
+}
+
+pub fn get_session_state(
+    store: &EditSessionStore,
+    session_id: &str,
+) -> Result<EditSessionState, String> {
+    store.with_session(session_id, |session| session.state(session_id))
+}
+
+/// Save the session's current image to `output_path`, using the format implied by its extension
+pub fn export_session(store: &EditSessionStore, session_id: &str, output_path: &str) -> EditResult {
+    let result = store.with_session(session_id, |session| {
+        let (img, _) = load_image(&session.current().to_string_lossy())?;
+        let format = ImageFormat::from_path(output_path).unwrap_or(ImageFormat::Png);
+        save_image_as(&img, output_path, format)?;
+        Ok((session.source_size, img))
+    });
+
+    match result {
+        Ok((source_size, img)) => create_result(true, output_path, source_size, Some(&img), None),
+        Err(e) => create_result(false, output_path, 0, None, Some(e)),
+    }
+}
+
+/// Discard a session and delete its intermediate files
+pub fn close_edit_session(store: &EditSessionStore, session_id: &str) -> Result<(), String> {
+    let session = store
+        .sessions
+        .lock()
+        .map_err(|e| format!("Failed to lock edit sessions: {}", e))?
+        .remove(session_id)
+        .ok_or_else(|| format!("Edit session not found: {}", session_id))?;
+    fs::remove_dir_all(&session.dir).map_err(|e| format!("Failed to remove session files: {}", e))
+}
+
+fn apply_operation_to_image(
+    img: DynamicImage,
+    operation: &EditOperation,
+) -> Result<DynamicImage, String> {
+    let edited = match operation {
+        EditOperation::Resize {
+            width,
+            height,
+            maintain_aspect,
+        } => {
+            if *maintain_aspect {
+                img.resize(*width, *height, image::imageops::FilterType::Lanczos3)
+            } else {
+                img.resize_exact(*width, *height, image::imageops::FilterType::Lanczos3)
+            }
+        }
+        EditOperation::Rotate { angle } => match angle {
+            RotationAngle::Rotate90 => img.rotate90(),
+            RotationAngle::Rotate180 => img.rotate180(),
+            RotationAngle::Rotate270 => img.rotate270(),
+        },
+        EditOperation::Crop {
+            x,
+            y,
+            width,
+            height,
+        } => {
+            let requested = CropRegion {
+                x: *x,
+                y: *y,
+                width: *width,
+                height: *height,
+            };
+            let region =
+                resolve_crop_region(img.width(), img.height(), requested, CropBounds::Error)?;
+            crop_to_region(&img, region)
+        }
+        EditOperation::CropAspect { aspect, anchor } => {
+            let region = aspect_crop_region(img.width(), img.height(), *aspect, *anchor)?;
+            crop_to_region(&img, region)
+        }
+        EditOperation::Brightness { value } => {
+            DynamicImage::ImageRgba8(image::imageops::brighten(&img, *value))
+        }
+        EditOperation::Contrast { value } => {
+            DynamicImage::ImageRgba8(image::imageops::contrast(&img, *value))
+        }
+        EditOperation::Filter { filter } => match filter {
+            ImageFilter::Grayscale => DynamicImage::ImageLuma8(img.to_luma8()),
+            ImageFilter::Sepia => apply_sepia(&img),
+            ImageFilter::Invert => {
+                let mut inverted = img;
+                inverted.invert();
+                inverted
+            }
+            ImageFilter::Blur => img.blur(3.0),
+            ImageFilter::Sharpen => img.unsharpen(1.0, 5),
+        },
+        EditOperation::FlipHorizontal => img.fliph(),
+        EditOperation::FlipVertical => img.flipv(),
+        EditOperation::Watermark { options } => {
+            let color = validate_watermark_options(options)?;
+            let mut canvas = img.to_rgba8();
+            draw_watermark(&mut canvas, options, color);
+            DynamicImage::ImageRgba8(canvas)
+        }
+    };
+    Ok(edited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watermark_options(text: &str, position: WatermarkPosition) -> WatermarkOptions {
+        WatermarkOptions {
+            text: text.to_string(),
+            font_size: 24.0,
+            position,
+            opacity: 1.0,
+            color: "#ff0000".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#ff8000").unwrap(), [255, 128, 0]);
+        assert_eq!(parse_hex_color("fff").unwrap(), [255, 255, 255]);
+        assert!(parse_hex_color("#12345").is_err());
+        assert!(parse_hex_color("zzzzzz").is_err());
+        assert!(parse_hex_color("#あ").is_err());
+        assert!(parse_hex_color("#ffあ").is_err());
+    }
+
+    #[test]
+    fn test_wrap_text_breaks_long_lines() {
+        let fonts = WatermarkFonts::load();
+        let scale = PxScale::from(20.0);
+        let lines = wrap_text(&fonts, "hello wonderful world", scale, 150.0);
+
+        assert!(lines.len() > 1);
+        for line in &lines {
+            assert!(fonts.measure(line, scale) <= 150.0);
+        }
+        assert_eq!(lines.join(" "), "hello wonderful world");
+    }
+
+    #[test]
+    fn test_japanese_glyphs_come_from_bundled_font() {
+        let fonts = WatermarkFonts::load();
+        for c in ['あ', 'ア', '漢'] {
+            assert_ne!(fonts.cjk.glyph_id(c).0, 0);
+            assert_ne!(fonts.font_for(c).glyph_id(c).0, 0);
+        }
+    }
+
+    #[test]
+    fn test_watermark_draws_in_requested_corner() {
+        let mut canvas = RgbaImage::from_pixel(200, 100, Rgba([0, 0, 0, 255]));
+        draw_watermark(
+            &mut canvas,
+            &watermark_options("Sample", WatermarkPosition::BottomRight),
+            [255, 0, 0],
+        );
+
+        let red_pixels: Vec<(u32, u32)> = canvas
+            .enumerate_pixels()
+            .filter(|(_, _, p)| p[0] > 128)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert!(!red_pixels.is_empty());
+        assert!(red_pixels.iter().all(|&(x, y)| x >= 100 && y >= 50));
+    }
+
+    #[test]
+    fn test_add_text_watermark_keeps_input_format() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_watermark_input.jpg");
+        let output = dir.join("taurin_watermark_output.png");
+        DynamicImage::ImageRgb8(image::RgbImage::from_pixel(
+            120,
+            60,
+            image::Rgb([20, 20, 20]),
+        ))
+        .save(&input)
+        .unwrap();
+
+        let result = add_text_watermark(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            watermark_options("Taurin", WatermarkPosition::Center),
+        );
+        let format = image::ImageReader::open(&output)
+            .unwrap()
+            .with_guessed_format()
+            .unwrap()
+            .format();
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(format, Some(ImageFormat::Jpeg));
+    }
+
+    #[test]
+    fn test_add_text_watermark_rejects_invalid_options() {
+        let input = std::env::temp_dir().join("taurin_watermark_invalid.png");
+        let output = std::env::temp_dir().join("taurin_watermark_invalid_out.png");
+        RgbaImage::from_pixel(10, 10, Rgba([0, 0, 0, 255]))
+            .save(&input)
+            .unwrap();
+        let (input_str, output_str) = (input.to_str().unwrap(), output.to_str().unwrap());
+
+        let empty = add_text_watermark(
+            input_str,
+            output_str,
+            watermark_options("  ", WatermarkPosition::Center),
+        );
+        let mut bad_color = watermark_options("x", WatermarkPosition::Center);
+        bad_color.color = "red".to_string();
+        let bad_color = add_text_watermark(input_str, output_str, bad_color);
+        let _ = fs::remove_file(&input);
+
+        assert!(!empty.success);
+        assert!(!bad_color.success);
+        assert!(!output.exists());
+    }
+
+    fn session_store(name: &str) -> (EditSessionStore, PathBuf) {
+        let base = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&base);
+        let input = std::env::temp_dir().join(format!("{}.png", name));
+        RgbaImage::from_pixel(40, 20, Rgba([10, 20, 30, 255]))
+            .save(&input)
+            .unwrap();
+        (EditSessionStore::new(base), input)
+    }
+
+    #[test]
+    fn test_edit_session_undo_redo() {
+        let (store, input) = session_store("taurin_edit_session_undo");
+        let id = start_edit_session(&store, input.to_str().unwrap()).unwrap();
+
+        let rotated = apply_operation(
+            &store,
+            &id,
+            EditOperation::Rotate {
+                angle: RotationAngle::Rotate90,
+            },
+        );
+        assert!(rotated.success);
+        assert_eq!((rotated.new_width, rotated.new_height), (20, 40));
+
+        let cropped = apply_operation(
+            &store,
+            &id,
+            EditOperation::Crop {
+                x: 0,
+                y: 0,
+                width: 10,
+                height: 10,
+            },
+        );
+        assert_eq!((cropped.new_width, cropped.new_height), (10, 10));
+
+        let state = undo_operation(&store, &id).unwrap();
+        assert_eq!((state.width, state.height), (20, 40));
+        assert!(state.can_undo && state.can_redo);
+
+        let state = undo_operation(&store, &id).unwrap();
+        assert_eq!((state.width, state.height), (40, 20));
+        assert!(!state.can_undo);
+        assert!(undo_operation(&store, &id).is_err());
+
+        let state = redo_operation(&store, &id).unwrap();
+        assert_eq!((state.width, state.height), (20, 40));
+
+        // Applying after undo drops the redo branch
+        apply_operation(&store, &id, EditOperation::FlipHorizontal);
+        let state = get_session_state(&store, &id).unwrap();
+        assert_eq!(state.history_len, 3);
+        assert!(!state.can_redo);
+
+        store.cleanup_all();
+        let _ = fs::remove_file(&input);
+    }
+
+    #[test]
+    fn test_edit_session_export_and_close() {
+        let (store, input) = session_store("taurin_edit_session_export");
+        let id = start_edit_session(&store, input.to_str().unwrap()).unwrap();
+        apply_operation(&store, &id, EditOperation::FlipVertical);
+
+        let output = std::env::temp_dir().join("taurin_edit_session_export_out.jpg");
+        let result = export_session(&store, &id, output.to_str().unwrap());
+        assert!(result.success, "{:?}", result.error);
+        let format = image::ImageReader::open(&output)
+            .unwrap()
+            .with_guessed_format()
+            .unwrap()
+            .format();
+        assert_eq!(format, Some(ImageFormat::Jpeg));
+
+        let session_dir = store.base_dir.join(&id);
+        assert!(session_dir.exists());
+        close_edit_session(&store, &id).unwrap();
+        assert!(!session_dir.exists());
+        assert!(apply_operation(&store, &id, EditOperation::FlipVertical)
+            .error
+            .is_some());
+
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+    }
+
+    #[test]
+    fn test_edit_session_failed_operation_keeps_history() {
+        let (store, input) = session_store("taurin_edit_session_failure");
+        let id = start_edit_session(&store, input.to_str().unwrap()).unwrap();
+
+        let result = apply_operation(
+            &store,
+            &id,
+            EditOperation::Crop {
+                x: 30,
+                y: 0,
+                width: 20,
+                height: 10,
+            },
+        );
+        assert!(!result.success);
+        assert_eq!(get_session_state(&store, &id).unwrap().history_len, 1);
+
+        let mut tiny_text = watermark_options("x", WatermarkPosition::Center);
+        tiny_text.font_size = 0.0;
+        let result = apply_operation(&store, &id, EditOperation::Watermark { options: tiny_text });
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Font size must be greater than 0")
+        );
+
+        store.cleanup_all();
+        let _ = fs::remove_file(&input);
+    }
+
+    #[test]
+    fn test_edit_session_failed_save_keeps_redo() {
+        let (store, input) = session_store("taurin_edit_session_failed_save");
+        let id = start_edit_session(&store, input.to_str().unwrap()).unwrap();
+        apply_operation(&store, &id, EditOperation::FlipHorizontal);
+        undo_operation(&store, &id).unwrap();
+
+        // A directory where the next snapshot would go makes saving it fail
+        fs::create_dir_all(store.base_dir.join(&id).join("step_0002.png")).unwrap();
+        let result = apply_operation(&store, &id, EditOperation::FlipVertical);
+        assert!(!result.success);
+
+        let state = get_session_state(&store, &id).unwrap();
+        assert_eq!((state.position, state.history_len), (0, 2));
+        assert!(state.can_redo);
+        assert!(redo_operation(&store, &id).is_ok());
+
+        store.cleanup_all();
+        let _ = fs::remove_file(&input);
+    }
+
+    fn region(x: u32, y: u32, width: u32, height: u32) -> CropRegion {
+        CropRegion {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_crop_image_bounds_modes() {
+        let input = std::env::temp_dir().join("taurin_crop_bounds.png");
+        let output = std::env::temp_dir().join("taurin_crop_bounds_out.png");
+        RgbaImage::from_pixel(40, 30, Rgba([0, 0, 255, 255]))
+            .save(&input)
+            .unwrap();
+        let (input_str, output_str) = (input.to_str().unwrap(), output.to_str().unwrap());
+
+        let inside = crop_image(input_str, output_str, 10, 5, 30, 25, CropBounds::Error);
+        assert!(inside.success, "{:?}", inside.error);
+        assert_eq!((inside.new_width, inside.new_height), (30, 25));
+
+        let rejected = crop_image(input_str, output_str, 10, 5, 31, 25, CropBounds::Error);
+        assert!(!rejected.success);
+        assert_eq!(
+            rejected.error.as_deref(),
+            Some("Crop area exceeds image bounds")
+        );
+
+        let clamped = crop_image(input_str, output_str, 10, 5, 100, 100, CropBounds::Clamp);
+        assert!(clamped.success, "{:?}", clamped.error);
+        assert_eq!((clamped.new_width, clamped.new_height), (30, 25));
+
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+    }
+
+    #[test]
+    fn test_resolve_crop_region_edge_cases() {
+        // Would overflow u32 if added directly
+        let huge = region(u32::MAX, 0, 10, 10);
+        assert!(resolve_crop_region(40, 30, huge, CropBounds::Error).is_err());
+        assert_eq!(
+            resolve_crop_region(40, 30, region(5, 0, u32::MAX, 10), CropBounds::Clamp),
+            Ok(region(5, 0, 35, 10))
+        );
+        assert_eq!(
+            resolve_crop_region(40, 30, region(40, 0, 10, 10), CropBounds::Clamp),
+            Err("Crop area is outside the image".to_string())
+        );
+        assert_eq!(
+            resolve_crop_region(40, 30, region(0, 0, 0, 10), CropBounds::Clamp),
+            Err("Crop area is empty".to_string())
+        );
+    }
+
+    #[test]
+    fn test_aspect_crop_region() {
+        // Landscape source: full height is kept
+        assert_eq!(
+            aspect_crop_region(400, 300, AspectRatio::Square, CropAnchor::Center),
+            Ok(region(50, 0, 300, 300))
+        );
+        assert_eq!(
+            aspect_crop_region(400, 300, AspectRatio::Square, CropAnchor::Right),
+            Ok(region(100, 0, 300, 300))
+        );
+        // Portrait source: full width is kept
+        assert_eq!(
+            aspect_crop_region(300, 600, AspectRatio::Ratio16x9, CropAnchor::Bottom),
+            Ok(region(0, 431, 300, 169))
+        );
+        assert_eq!(
+            aspect_crop_region(300, 600, AspectRatio::Ratio4x3, CropAnchor::TopLeft),
+            Ok(region(0, 0, 300, 225))
+        );
+        // Already the right shape
+        assert_eq!(
+            aspect_crop_region(300, 200, AspectRatio::Ratio3x2, CropAnchor::BottomRight),
+            Ok(region(0, 0, 300, 200))
+        );
+        let portrait = AspectRatio::Custom {
+            width: 9,
+            height: 16,
+        };
+        assert_eq!(
+            aspect_crop_region(1920, 1080, portrait, CropAnchor::Left),
+            Ok(region(0, 0, 608, 1080))
+        );
+        let invalid = AspectRatio::Custom {
+            width: 0,
+            height: 1,
+        };
+        assert!(aspect_crop_region(100, 100, invalid, CropAnchor::Center).is_err());
+    }
+
+    #[test]
+    fn test_crop_image_with_aspect() {
+        let input = std::env::temp_dir().join("taurin_crop_aspect.png");
+        let output = std::env::temp_dir().join("taurin_crop_aspect_out.png");
+        let mut img = RgbaImage::from_pixel(60, 40, Rgba([0, 0, 0, 255]));
+        img.put_pixel(59, 20, Rgba([255, 0, 0, 255]));
+        img.save(&input).unwrap();
+
+        let result = crop_image_with_aspect(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            AspectRatio::Square,
+            CropAnchor::Right,
+        );
+        let cropped = image::open(&output).unwrap().to_rgba8();
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!((result.new_width, result.new_height), (40, 40));
+        assert_eq!(cropped.get_pixel(39, 20)[0], 255);
+    }
+
+    #[test]
+    fn test_combined_layout() {
+        let sizes = [(100, 50), (40, 80)];
+        let (canvas, positions) = combined_layout(&sizes, CombineLayout::Horizontal, 10);
+        assert_eq!(canvas, (150, 80));
+        assert_eq!(positions, vec![(0, 15), (110, 0)]);
+
+        let (canvas, positions) = combined_layout(&sizes, CombineLayout::Vertical, 0);
+        assert_eq!(canvas, (100, 130));
+        assert_eq!(positions, vec![(0, 0), (30, 50)]);
+
+        let grid = [(10, 10), (10, 10), (10, 10)];
+        let (canvas, positions) = combined_layout(&grid, CombineLayout::Grid { columns: 2 }, 2);
+        assert_eq!(canvas, (22, 22));
+        assert_eq!(positions[2], (0, 12));
+
+        let matched =
+            combined_image_sizes(&sizes, CombineLayout::Horizontal, CombineResize::MatchSize);
+        assert_eq!(matched, vec![(100, 50), (25, 50)]);
+        let fitted = combined_image_sizes(
+            &sizes,
+            CombineLayout::Horizontal,
+            CombineResize::Fit {
+                width: 20,
+                height: 20,
+            },
+        );
+        assert_eq!(fitted, vec![(20, 10), (10, 20)]);
+    }
+
+    #[test]
+    fn test_combine_images() {
+        let dir = std::env::temp_dir();
+        let first = dir.join("taurin_combine_a.png");
+        let second = dir.join("taurin_combine_b.jpg");
+        let output = dir.join("taurin_combine_out.png");
+        RgbaImage::from_pixel(30, 20, Rgba([255, 0, 0, 255]))
+            .save(&first)
+            .unwrap();
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(10, 40, Rgba([0, 0, 255, 255])))
+            .to_rgb8()
+            .save(&second)
+            .unwrap();
+        let inputs = vec![
+            first.to_string_lossy().to_string(),
+            second.to_string_lossy().to_string(),
+        ];
+        let options = |resize| CombineOptions {
+            padding: 5,
+            background: "#ffffff".to_string(),
+            resize,
+        };
+
+        let result = combine_images(
+            inputs.clone(),
+            output.to_string_lossy().to_string(),
+            CombineLayout::Horizontal,
+            options(CombineResize::Original),
+        );
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!((result.new_width, result.new_height), (45, 40));
+        let combined = image::open(&output).unwrap().to_rgba8();
+        assert_eq!(combined.get_pixel(0, 0), &Rgba([255, 255, 255, 255]));
+        assert_eq!(combined.get_pixel(0, 15), &Rgba([255, 0, 0, 255]));
+
+        let missing = dir.join("taurin_combine_missing.png");
+        let mut with_missing = inputs.clone();
+        with_missing.push(missing.to_string_lossy().to_string());
+        let result = combine_images(
+            with_missing,
+            output.to_string_lossy().to_string(),
+            CombineLayout::Vertical,
+            options(CombineResize::MatchSize),
+        );
+        assert!(result.error.unwrap().contains("taurin_combine_missing.png"));
+
+        // Fit makes the inputs 15000 and 5000 px wide, so the row exceeds the limit
+        let result = combine_images(
+            inputs,
+            output.to_string_lossy().to_string(),
+            CombineLayout::Horizontal,
+            options(CombineResize::Fit {
+                width: 15_000,
+                height: 20_000,
+            }),
+        );
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("20000 px limit"));
+
+        for path in [first, second, output] {
+            fs::remove_file(path).ok();
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use serde::{Deserialize, Serialize};
+
+/// Full-width equivalents of U+FF61..=U+FF9F, in code point order
+const HALF_KANA_TABLE: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+const HALF_KANA_START: u32 = 0xFF61;
+/// Half-width punctuation at the start of the block; converted to full width but not back,
+/// since 。「」、・ are shared with hiragana text
+const HALF_KANA_PUNCTUATION: usize = 5;
+const HALF_DAKUTEN: char = '\u{FF9E}';
+const HALF_HANDAKUTEN: char = '\u{FF9F}';
+/// Offset between ASCII `!`..=`~` and the full-width forms U+FF01..=U+FF5E
+const FULL_WIDTH_OFFSET: u32 = 0xFEE0;
+/// Offset between hiragana and the matching katakana
+const KANA_OFFSET: u32 = 0x60;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum JpConversion {
+    ToHalfWidthAlnum,
+    ToFullWidthAlnum,
+    /// ASCII punctuation such as `！` `（` `＠`
+    ToHalfWidthSymbols,
+    ToFullWidthSymbols,
+    HalfKanaToFullKana,
+    FullKanaToHalfKana,
+    HiraganaToKatakana,
+    KatakanaToHiragana,
+    /// Ideographic (全角) and no-break spaces to ASCII spaces
+    NormalizeSpaces,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JpConversionStat {
+    pub conversion: JpConversion,
+    /// Replacements made; a half-width kana with its ﾞ or ﾟ counts once
+    pub converted_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JpConvertResult {
+    pub output: String,
+    pub input_length: usize,
+    pub output_length: usize,
+    /// Sum over all steps, so a character changed twice counts twice
+    pub converted_count: usize,
+    pub steps: Vec<JpConversionStat>,
+}
+
+fn half_kana_to_full(c: char) -> Option<char> {
+    (c as u32)
+        .checked_sub(HALF_KANA_START)
+        .and_then(|i| HALF_KANA_TABLE.chars().nth(i as usize))
+}
+
+fn full_kana_to_half(c: char) -> Option<char> {
+    HALF_KANA_TABLE
+        .chars()
+        .skip(HALF_KANA_PUNCTUATION)
+        .position(|full| full == c)
+        .and_then(|i| char::from_u32(HALF_KANA_START + (HALF_KANA_PUNCTUATION + i) as u32))
+}
+
+/// Katakana with a dakuten (゛) added, e.g. カ → ガ
+fn voiced(c: char) -> Option<char> {
+    match c {
+        'ウ' => Some('ヴ'),
+        'ワ' => Some('ヷ'),
+        'ヲ' => Some('ヺ'),
+        'カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ' | 'タ' | 'チ'
+        | 'ツ' | 'テ' | 'ト' | 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => {
+            char::from_u32(c as u32 + 1)
+        }
+        _ => None,
+    }
+}
+
+/// Katakana with a handakuten (゜) added, e.g. ハ → パ
+fn semi_voiced(c: char) -> Option<char> {
+    match c {
+        'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => char::from_u32(c as u32 + 2),
+        _ => None,
+    }
+}
+
+/// Base katakana and half-width mark of a voiced or semi-voiced katakana, e.g. ガ → (カ, ﾞ)
+fn decompose_voiced(c: char) -> Option<(char, char)> {
+    HALF_KANA_TABLE.chars().find_map(|base| {
+        if voiced(base) == Some(c) {
+            Some((base, HALF_DAKUTEN))
+        } else if semi_voiced(base) == Some(c) {
+            Some((base, HALF_HANDAKUTEN))
+        } else {
+            None
+        }
+    })
+}
+
+fn shift(c: char, offset: u32, up: bool) -> char {
+    let code = if up {
+        c as u32 + offset
+    } else {
+        c as u32 - offset
+    };
+    char::from_u32(code).unwrap_or(c)
+}
+
+fn convert_half_kana(input: &str) -> (String, usize) {
+    let mut output = String::with_capacity(input.len());
+    let mut count = 0;
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        let Some(full) = half_kana_to_full(c) else {
+            output.push(c);
+            continue;
+        };
+        // A following ﾞ or ﾟ belongs to this kana when the pair has a single full-width form
+        let combined = match chars.peek() {
+            Some(&HALF_DAKUTEN) => voiced(full),
+            Some(&HALF_HANDAKUTEN) => semi_voiced(full),
+            _ => None,
+        };
+        if let Some(combined) = combined {
+            chars.next();
+            output.push(combined);
+        } else {
+            output.push(full);
+        }
+        count += 1;
+    }
+    (output, count)
+}
+
+fn convert_full_kana(input: &str) -> (String, usize) {
+    let mut output = String::with_capacity(input.len());
+    let mut count = 0;
+    for c in input.chars() {
+        if let Some(half) = full_kana_to_half(c) {
+            output.push(half);
+        } else if let Some((base, mark)) = decompose_voiced(c) {
+            output.extend(full_kana_to_half(base));
+            output.push(mark);
+        } else {
+            output.push(c);
+            continue;
+        }
+        count += 1;
+    }
+    (output, count)
+}
+
+/// Replace each character for which `map` returns a different one
+fn map_chars(input: &str, map: impl Fn(char) -> Option<char>) -> (String, usize) {
+    let mut count = 0;
+    let output = input
+        .chars()
+        .map(|c| match map(c) {
+            Some(mapped) if mapped != c => {
+                count += 1;
+                mapped
+            }
+            _ => c,
+        })
+        .collect();
+    (output, count)
+}
+
+impl JpConversion {
+    fn apply(self, input: &str) -> (String, usize) {
+        match self {
+            JpConversion::ToHalfWidthAlnum => map_chars(input, |c| {
+                matches!(c, '０'..='９' | 'Ａ'..='Ｚ' | 'ａ'..='ｚ')
+                    .then(|| shift(c, FULL_WIDTH_OFFSET, false))
+            }),
+            JpConversion::ToFullWidthAlnum => map_chars(input, |c| {
+                c.is_ascii_alphanumeric()
+                    .then(|| shift(c, FULL_WIDTH_OFFSET, true))
+            }),
+            JpConversion::ToHalfWidthSymbols => map_chars(input, |c| {
+                ('！'..='～')
+                    .contains(&c)
+                    .then(|| shift(c, FULL_WIDTH_OFFSET, false))
+                    .filter(char::is_ascii_punctuation)
+            }),
+            JpConversion::ToFullWidthSymbols => map_chars(input, |c| {
+                c.is_ascii_punctuation()
+                    .then(|| shift(c, FULL_WIDTH_OFFSET, true))
+            }),
+            JpConversion::HalfKanaToFullKana => convert_half_kana(input),
+            JpConversion::FullKanaToHalfKana => convert_full_kana(input),
+            JpConversion::HiraganaToKatakana => map_chars(input, |c| match c {
+                'ぁ'..='ゖ' | 'ゝ' | 'ゞ' => Some(shift(c, KANA_OFFSET, true)),
+                _ => None,
+            }),
+            JpConversion::KatakanaToHiragana => map_chars(input, |c| match c {
+                'ァ'..='ヶ' | 'ヽ' | 'ヾ' => Some(shift(c, KANA_OFFSET, false)),
+                _ => None,
+            }),
+            JpConversion::NormalizeSpaces => map_chars(input, |c| {
+                matches!(c, '\u{3000}' | '\u{00A0}').then_some(' ')
+            }),
+        }
+    }
+}
+
+/// Apply `conversions` to `input` one after another, in the given order
+pub fn convert_japanese_text(input: &str, conversions: Vec<JpConversion>) -> JpConvertResult {
+    let mut output = input.to_string();
+    let mut steps = Vec::with_capacity(conversions.len());
+    for conversion in conversions {
+        let (converted, converted_count) = conversion.apply(&output);
+        output = converted;
+        steps.push(JpConversionStat {
+            conversion,
+            converted_count,
+        });
+    }
+
+    JpConvertResult {
+        input_length: input.chars().count(),
+        output_length: output.chars().count(),
+        converted_count: steps.iter().map(|step| step.converted_count).sum(),
+        steps,
+        output,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, conversions: &[JpConversion]) -> String {
+        convert_japanese_text(input, conversions.to_vec()).output
+    }
+
+    #[test]
+    fn test_half_kana_table() {
+        assert_eq!(HALF_KANA_TABLE.chars().count(), 63);
+        assert_eq!(half_kana_to_full('ｱ'), Some('ア'));
+        assert_eq!(half_kana_to_full('ﾝ'), Some('ン'));
+        assert_eq!(half_kana_to_full('ﾟ'), Some('゜'));
+        assert_eq!(full_kana_to_half('ヲ'), Some('ｦ'));
+        assert_eq!(full_kana_to_half('。'), None);
+    }
+
+    #[test]
+    fn test_half_kana_with_marks() {
+        let result = convert_japanese_text(
+            "ｶﾞｷﾞｸﾞ ﾊﾟﾋﾟﾌﾟ ｳﾞｧｲｵﾘﾝ ｱﾞ ﾟ｢ﾃｽﾄ｣｡",
+            vec![JpConversion::HalfKanaToFullKana],
+        );
+        assert_eq!(
+            result.output,
+            "ガギグ パピプ ヴァイオリン ア゛ ゜「テスト」。"
+        );
+        assert_eq!(result.input_length, 32);
+        assert_eq!(result.output_length, 25);
+        // ｶﾞ is one replacement; ｱﾞ has no combined form, so it is two
+        assert_eq!(result.steps[0].converted_count, 21);
+        assert_eq!(result.converted_count, 21);
+    }
+
+    #[test]
+    fn test_full_kana_to_half() {
+        assert_eq!(
+            convert(
+                "ガイドブック・パン、ヴ ヵ",
+                &[JpConversion::FullKanaToHalfKana]
+            ),
+            "ｶﾞｲﾄﾞﾌﾞｯｸ・ﾊﾟﾝ、ｳﾞ ヵ"
+        );
+        let round_trip = convert(
+            "デジタル・プロダクト",
+            &[
+                JpConversion::FullKanaToHalfKana,
+                JpConversion::HalfKanaToFullKana,
+            ],
+        );
+        assert_eq!(round_trip, "デジタル・プロダクト");
+    }
+
+    #[test]
+    fn test_alnum_and_symbols() {
+        let input = "ＡＢＣ１２３ｘｙｚ！（＠）";
+        assert_eq!(
+            convert(input, &[JpConversion::ToHalfWidthAlnum]),
+            "ABC123xyz！（＠）"
+        );
+        assert_eq!(
+            convert(
+                input,
+                &[
+                    JpConversion::ToHalfWidthAlnum,
+                    JpConversion::ToHalfWidthSymbols
+                ]
+            ),
+            "ABC123xyz!(@)"
+        );
+        assert_eq!(
+            convert("Rust 2024!", &[JpConversion::ToFullWidthAlnum]),
+            "Ｒｕｓｔ ２０２４!"
+        );
+        assert_eq!(convert("a-b", &[JpConversion::ToFullWidthSymbols]), "a－b");
+    }
+
+    #[test]
+    fn test_hiragana_katakana() {
+        assert_eq!(
+            convert("ひらがな・ゔぁ ゝ ー", &[JpConversion::HiraganaToKatakana]),
+            "ヒラガナ・ヴァ ヽ ー"
+        );
+        assert_eq!(
+            convert("カタカナ ヴ ヷ ヾ", &[JpConversion::KatakanaToHiragana]),
+            "かたかな ゔ ヷ ゞ"
+        );
+    }
+
+    #[test]
+    fn test_conversions_apply_in_order() {
+        let input = "ﾃｽﾄ　ＯＫ";
+        let to_hiragana_first = convert_japanese_text(
+            input,
+            vec![
+                JpConversion::KatakanaToHiragana,
+                JpConversion::HalfKanaToFullKana,
+                JpConversion::NormalizeSpaces,
+            ],
+        );
+        assert_eq!(to_hiragana_first.output, "テスト ＯＫ");
+        assert_eq!(to_hiragana_first.steps[0].converted_count, 0);
+
+        let full_kana_first = convert_japanese_text(
+            input,
+            vec![
+                JpConversion::HalfKanaToFullKana,
+                JpConversion::KatakanaToHiragana,
+                JpConversion::NormalizeSpaces,
+                JpConversion::ToHalfWidthAlnum,
+            ],
+        );
+        assert_eq!(full_kana_first.output, "てすと OK");
+        assert_eq!(full_kana_first.converted_count, 3 + 3 + 1 + 2);
+        assert_eq!(full_kana_first.input_length, full_kana_first.output_length);
+    }
+
+    #[test]
+    fn test_no_conversions() {
+        let result = convert_japanese_text("そのまま", Vec::new());
+        assert_eq!(result.output, "そのまま");
+        assert_eq!(result.converted_count, 0);
+        assert!(result.steps.is_empty());
+    }
+}
@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Most children returned by one `get_json_tree_children` call; larger containers are paged
+pub const MAX_TREE_PAGE: usize = 10_000;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,15 +29,17 @@ pub struct ErrorPosition {
     pub column: usize,
 }
 
+/// One tree node without its children, which are fetched on expand
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct JsonTreeNode {
+pub struct JsonTreeNodeSummary {
     pub key: String,
     pub value_type: JsonValueType,
     pub value: Option<String>,
+    /// JSONPath of the node, e.g. `$.items[0]['a b']`
     pub path: String,
-    pub children: Vec<JsonTreeNode>,
-    pub expanded: bool,
+    /// Members of an object or items of an array; 0 for scalars
+    pub child_count: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -48,10 +55,18 @@ pub enum JsonValueType {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct JsonParseResult {
-    pub success: bool,
-    pub tree: Option<JsonTreeNode>,
-    pub error: Option<String>,
+pub struct JsonTreeRoot {
+    /// Handle for `get_json_tree_children`, valid until `release_json_tree`
+    pub id: String,
+    pub root: JsonTreeNodeSummary,
+    /// First page of the root's children
+    pub children: Vec<JsonTreeNodeSummary>,
+}
+
+/// Parsed documents kept between tree calls so large inputs are parsed only once
+#[derive(Default)]
+pub struct JsonTreeStore {
+    trees: Mutex<HashMap<String, Value>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +98,137 @@ pub struct JsonSearchMatch {
     pub value_type: JsonValueType,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaValidateResult {
+    pub valid: bool,
+    pub violations: Vec<SchemaViolation>,
+    /// The input document could not be parsed
+    pub error: Option<String>,
+    /// The schema is not valid JSON or not a valid JSON Schema
+    pub schema_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaViolation {
+    /// Instance location in the same format as `JsonTreeNodeSummary::path`
+    pub path: String,
+    pub keyword: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonQueryType {
+    /// RFC 6901 JSON Pointer, e.g. `/items/0/name`
+    Pointer,
+    /// JSONPath subset: `.name`, `['name']`, `[0]`, `[-1]`, `*` and `..`
+    JsonPath,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonQueryResult {
+    pub success: bool,
+    pub matches: Vec<JsonQueryMatch>,
+    pub total_count: usize,
+    /// Pretty-printed extraction: the value itself for a pointer, an array of values for JSONPath
+    pub formatted: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonQueryMatch {
+    /// Location in the same notation as the query
+    pub path: String,
+    /// Compact JSON text of the matched value
+    pub value: String,
+    pub value_type: JsonValueType,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonArrayDiffMode {
+    /// Compare elements index by index
+    #[default]
+    Ordered,
+    /// Pair elements regardless of position, by `array_id_key` or by equal content
+    Unordered,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsonDiffOptions {
+    pub array_mode: JsonArrayDiffMode,
+    /// In unordered mode, object elements with the same value under this key are compared
+    /// with each other, so an edited element shows as changed instead of removed and added
+    pub array_id_key: Option<String>,
+    /// Treat numbers such as `1` and `1.0` as equal
+    pub ignore_number_format: bool,
+    /// When false, an object whose keys were reordered is reported as changed
+    pub ignore_key_order: bool,
+}
+
+impl Default for JsonDiffOptions {
+    fn default() -> Self {
+        Self {
+            array_mode: JsonArrayDiffMode::Ordered,
+            array_id_key: None,
+            ignore_number_format: true,
+            ignore_key_order: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonDiffKind {
+    Added,
+    Removed,
+    Changed,
+    TypeChanged,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonDiffEntry {
+    /// Location in the same format as `JsonTreeNodeSummary::path`; in the new document, except
+    /// for removed values, which are located in the old one
+    pub path: String,
+    pub kind: JsonDiffKind,
+    /// Compact JSON text of the old value, absent for added values
+    pub old_value: Option<String>,
+    /// Compact JSON text of the new value, absent for removed values
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonDiffResult {
+    pub success: bool,
+    pub entries: Vec<JsonDiffEntry>,
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub type_changed: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathSelector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Child(PathSelector),
+    Descendant(PathSelector),
+}
+
 pub fn format_json(input: &str, indent_size: usize) -> JsonFormatResult {
     match serde_json::from_str::<Value>(input) {
         Ok(value) => {
@@ -177,98 +323,104 @@ pub fn minify_json(input: &str) -> JsonMinifyResult {
     }
 }
 
-pub fn parse_to_tree(input: &str) -> JsonParseResult {
-    match serde_json::from_str::<Value>(input) {
-        Ok(value) => {
-            let tree = value_to_tree(&value, "root".to_string(), "$".to_string());
-            JsonParseResult {
-                success: true,
-                tree: Some(tree),
-                error: None,
-            }
-        }
-        Err(e) => JsonParseResult {
-            success: false,
-            tree: None,
-            error: Some(format!("Parse error: {}", e)),
+/// Parse `input` and keep it in `store`, returning the root and the first page of its children
+pub fn parse_json_tree_root(store: &JsonTreeStore, input: &str) -> Result<JsonTreeRoot, String> {
+    let value = serde_json::from_str::<Value>(input).map_err(|e| format!("Parse error: {}", e))?;
+    let root = node_summary("root".to_string(), "$".to_string(), &value);
+    let children = child_summaries(&value, "$", 0, MAX_TREE_PAGE);
+
+    let id = uuid::Uuid::new_v4().to_string();
+    store
+        .trees
+        .lock()
+        .map_err(|e| format!("Failed to lock JSON trees: {}", e))?
+        .insert(id.clone(), value);
+    Ok(JsonTreeRoot { id, root, children })
+}
+
+/// Up to `limit` children of the node at `path`, starting at `offset`
+pub fn get_json_tree_children(
+    store: &JsonTreeStore,
+    input_id: &str,
+    path: &str,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<JsonTreeNodeSummary>, String> {
+    let trees = store
+        .trees
+        .lock()
+        .map_err(|e| format!("Failed to lock JSON trees: {}", e))?;
+    let root = trees
+        .get(input_id)
+        .ok_or_else(|| format!("JSON tree not found: {}", input_id))?;
+    let node = resolve_tree_path(root, path)?;
+    Ok(child_summaries(
+        node,
+        path,
+        offset,
+        limit.clamp(1, MAX_TREE_PAGE),
+    ))
+}
+
+/// Drop a parsed document; unknown ids are ignored
+pub fn release_json_tree(store: &JsonTreeStore, input_id: &str) {
+    if let Ok(mut trees) = store.trees.lock() {
+        trees.remove(input_id);
+    }
+}
+
+fn node_summary(key: String, path: String, value: &Value) -> JsonTreeNodeSummary {
+    JsonTreeNodeSummary {
+        key,
+        value_type: get_value_type(value),
+        value: Some(value_to_string(value)),
+        path,
+        child_count: match value {
+            Value::Object(map) => map.len(),
+            Value::Array(arr) => arr.len(),
+            _ => 0,
         },
     }
 }
 
-fn value_to_tree(value: &Value, key: String, path: String) -> JsonTreeNode {
+fn child_summaries(
+    value: &Value,
+    path: &str,
+    offset: usize,
+    limit: usize,
+) -> Vec<JsonTreeNodeSummary> {
     match value {
-        Value::Object(map) => {
-            let children: Vec<JsonTreeNode> = map
-                .iter()
-                .map(|(k, v)| {
-                    let child_path = format!("{}.{}", path, k);
-                    value_to_tree(v, k.clone(), child_path)
-                })
-                .collect();
-
-            JsonTreeNode {
-                key,
-                value_type: JsonValueType::Object,
-                value: Some(format!("{{{} keys}}", map.len())),
-                path,
-                children,
-                expanded: true,
-            }
-        }
-        Value::Array(arr) => {
-            let children: Vec<JsonTreeNode> = arr
-                .iter()
-                .enumerate()
-                .map(|(i, v)| {
-                    let child_path = format!("{}[{}]", path, i);
-                    value_to_tree(v, format!("[{}]", i), child_path)
-                })
-                .collect();
-
-            JsonTreeNode {
-                key,
-                value_type: JsonValueType::Array,
-                value: Some(format!("[{} items]", arr.len())),
-                path,
-                children,
-                expanded: true,
-            }
-        }
-        Value::String(s) => JsonTreeNode {
-            key,
-            value_type: JsonValueType::String,
-            value: Some(format!("\"{}\"", s)),
-            path,
-            children: vec![],
-            expanded: false,
-        },
-        Value::Number(n) => JsonTreeNode {
-            key,
-            value_type: JsonValueType::Number,
-            value: Some(n.to_string()),
-            path,
-            children: vec![],
-            expanded: false,
-        },
-        Value::Bool(b) => JsonTreeNode {
-            key,
-            value_type: JsonValueType::Boolean,
-            value: Some(b.to_string()),
-            path,
-            children: vec![],
-            expanded: false,
-        },
-        Value::Null => JsonTreeNode {
-            key,
-            value_type: JsonValueType::Null,
-            value: Some("null".to_string()),
-            path,
-            children: vec![],
-            expanded: false,
-        },
+        Value::Object(map) => map
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(k, v)| node_summary(k.clone(), member_path(path, k), v))
+            .collect(),
+        Value::Array(arr) => arr
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit)
+            .map(|(i, v)| node_summary(format!("[{}]", i), format!("{}[{}]", path, i), v))
+            .collect(),
+        _ => Vec::new(),
     }
 }
 
+/// Find the node at a path produced by `node_summary`, i.e. names and indices only
+fn resolve_tree_path<'a>(root: &'a Value, path: &str) -> Result<&'a Value, String> {
+    parse_json_path(path)?
+        .iter()
+        .try_fold(root, |value, segment| match (segment, value) {
+            (PathSegment::Child(PathSelector::Name(name)), Value::Object(map)) => map.get(name),
+            (PathSegment::Child(PathSelector::Index(index)), Value::Array(arr)) => {
+                usize::try_from(*index).ok().and_then(|i| arr.get(i))
+            }
+            _ => None,
+        })
+        .ok_or_else(|| format!("No node at {}", path))
+}
+
 pub fn search_json(
     input: &str,
     query: &str,
@@ -392,6 +544,647 @@ fn search_value(
     }
 }
 
+/// Validate `input` against a JSON Schema.
+///
+/// The draft is taken from the schema's `$schema` (draft-07 and 2020-12 among others) and
+/// defaults to 2020-12. `format` is always asserted.
+pub fn validate_json_with_schema(input: &str, schema: &str) -> JsonSchemaValidateResult {
+    let failure = |error: Option<String>, schema_error: Option<String>| JsonSchemaValidateResult {
+        valid: false,
+        violations: vec![],
+        error,
+        schema_error,
+    };
+
+    let schema = match serde_json::from_str::<Value>(schema) {
+        Ok(schema) => schema,
+        Err(e) => return failure(None, Some(format!("Schema parse error: {}", e))),
+    };
+    let validator = match jsonschema::options()
+        .should_validate_formats(true)
+        .build(&schema)
+    {
+        Ok(validator) => validator,
+        Err(e) => {
+            return failure(
+                None,
+                Some(format!(
+                    "Invalid schema at {}: {}",
+                    pointer_to_tree_path(e.schema_path.as_str()),
+                    e
+                )),
+            )
+        }
+    };
+    let instance = match serde_json::from_str::<Value>(input) {
+        Ok(instance) => instance,
+        Err(e) => return failure(Some(format!("Parse error: {}", e)), None),
+    };
+
+    let violations: Vec<SchemaViolation> = validator
+        .iter_errors(&instance)
+        .map(|e| SchemaViolation {
+            path: pointer_to_tree_path(e.instance_path.as_str()),
+            keyword: schema_keyword(e.schema_path.as_str()),
+            message: e.to_string(),
+        })
+        .collect();
+
+    JsonSchemaValidateResult {
+        valid: violations.is_empty(),
+        violations,
+        error: None,
+        schema_error: None,
+    }
+}
+
+/// Convert a JSON Pointer into the `$.key[0]` form used by the tree view
+fn pointer_to_tree_path(pointer: &str) -> String {
+    let Ok(tokens) = parse_pointer(pointer) else {
+        return pointer.to_string();
+    };
+    tokens.iter().fold("$".to_string(), |path, token| {
+        if !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit()) {
+            format!("{}[{}]", path, token)
+        } else {
+            member_path(&path, token)
+        }
+    })
+}
+
+/// The schema keyword that failed: the last non-index segment of the schema path
+fn schema_keyword(schema_path: &str) -> String {
+    schema_path
+        .rsplit('/')
+        .find(|segment| !segment.is_empty() && !segment.bytes().all(|b| b.is_ascii_digit()))
+        .unwrap_or_default()
+        .replace("~1", "/")
+        .replace("~0", "~")
+}
+
+pub fn query_json(input: &str, query: &str, query_type: JsonQueryType) -> JsonQueryResult {
+    let failure = |error: String| JsonQueryResult {
+        success: false,
+        matches: vec![],
+        total_count: 0,
+        formatted: String::new(),
+        error: Some(error),
+    };
+
+    let root = match serde_json::from_str::<Value>(input) {
+        Ok(value) => value,
+        Err(e) => return failure(format!("Parse error: {}", e)),
+    };
+
+    let found = match query_type {
+        JsonQueryType::Pointer => parse_pointer(query).map(|tokens| {
+            resolve_pointer(&root, &tokens)
+                .map(|value| (query.to_string(), value))
+                .into_iter()
+                .collect::<Vec<_>>()
+        }),
+        JsonQueryType::JsonPath => {
+            parse_json_path(query).map(|segments| evaluate_json_path(&root, &segments))
+        }
+    };
+    let found = match found {
+        Ok(found) => found,
+        Err(e) => return failure(e),
+    };
+
+    let extracted = match (query_type, found.first()) {
+        (JsonQueryType::Pointer, Some((_, value))) => (*value).clone(),
+        (JsonQueryType::Pointer, None) => Value::Null,
+        (JsonQueryType::JsonPath, _) => {
+            Value::Array(found.iter().map(|(_, v)| (*v).clone()).collect())
+        }
+    };
+    let formatted = if found.is_empty() && query_type == JsonQueryType::Pointer {
+        String::new()
+    } else {
+        serde_json::to_string_pretty(&extracted).unwrap_or_default()
+    };
+
+    let matches: Vec<JsonQueryMatch> = found
+        .into_iter()
+        .map(|(path, value)| JsonQueryMatch {
+            path,
+            value: value.to_string(),
+            value_type: get_value_type(value),
+        })
+        .collect();
+
+    JsonQueryResult {
+        success: true,
+        total_count: matches.len(),
+        matches,
+        formatted,
+        error: None,
+    }
+}
+
+/// Split a JSON Pointer into unescaped reference tokens
+fn parse_pointer(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(vec![]);
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err("Invalid JSON Pointer: must be empty or start with '/'".to_string());
+    };
+
+    let mut tokens = Vec::new();
+    let mut offset = 1;
+    for raw in rest.split('/') {
+        let mut token = String::new();
+        let mut chars = raw.char_indices();
+        while let Some((i, c)) = chars.next() {
+            if c != '~' {
+                token.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some((_, '0')) => token.push('~'),
+                Some((_, '1')) => token.push('/'),
+                _ => {
+                    return Err(format!(
+                        "Invalid JSON Pointer at position {}: '~' must be followed by '0' or '1'",
+                        pointer[..offset + i].chars().count() + 1
+                    ))
+                }
+            }
+        }
+        offset += raw.len() + 1;
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn resolve_pointer<'a>(root: &'a Value, tokens: &[String]) -> Option<&'a Value> {
+    tokens.iter().try_fold(root, |value, token| match value {
+        Value::Object(map) => map.get(token),
+        Value::Array(arr) => {
+            // RFC 6901 forbids leading zeros and signs in array indices
+            let canonical = token == "0" || (!token.starts_with('0') && !token.is_empty());
+            if canonical && token.bytes().all(|b| b.is_ascii_digit()) {
+                token.parse::<usize>().ok().and_then(|i| arr.get(i))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    })
+}
+
+fn path_error(position: usize, message: &str) -> String {
+    format!("Invalid JSONPath at position {}: {}", position + 1, message)
+}
+
+fn parse_json_path(query: &str) -> Result<Vec<PathSegment>, String> {
+    let chars: Vec<char> = query.trim().chars().collect();
+    if chars.first() != Some(&'$') {
+        return Err(path_error(0, "query must start with '$'"));
+    }
+
+    let mut segments = Vec::new();
+    let mut pos = 1;
+    while pos < chars.len() {
+        match chars[pos] {
+            '.' if chars.get(pos + 1) == Some(&'.') => {
+                pos += 2;
+                let selector = if chars.get(pos) == Some(&'[') {
+                    parse_bracket_selector(&chars, &mut pos)?
+                } else {
+                    parse_dot_selector(&chars, &mut pos)?
+                };
+                segments.push(PathSegment::Descendant(selector));
+            }
+            '.' => {
+                pos += 1;
+                segments.push(PathSegment::Child(parse_dot_selector(&chars, &mut pos)?));
+            }
+            '[' => segments.push(PathSegment::Child(parse_bracket_selector(
+                &chars, &mut pos,
+            )?)),
+            c => return Err(path_error(pos, &format!("unexpected '{}'", c))),
+        }
+    }
+    Ok(segments)
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '$'
+}
+
+fn parse_dot_selector(chars: &[char], pos: &mut usize) -> Result<PathSelector, String> {
+    if chars.get(*pos) == Some(&'*') {
+        *pos += 1;
+        return Ok(PathSelector::Wildcard);
+    }
+    let start = *pos;
+    while *pos < chars.len() && is_name_char(chars[*pos]) {
+        *pos += 1;
+    }
+    if start == *pos {
+        return Err(path_error(start, "expected a member name or '*'"));
+    }
+    Ok(PathSelector::Name(chars[start..*pos].iter().collect()))
+}
+
+fn parse_bracket_selector(chars: &[char], pos: &mut usize) -> Result<PathSelector, String> {
+    let open = *pos;
+    *pos += 1;
+    let skip_spaces = |pos: &mut usize| {
+        while chars.get(*pos).is_some_and(|c| c.is_whitespace()) {
+            *pos += 1;
+        }
+    };
+    skip_spaces(pos);
+
+    let selector = match chars.get(*pos) {
+        Some('*') => {
+            *pos += 1;
+            PathSelector::Wildcard
+        }
+        Some(&quote @ ('\'' | '"')) => {
+            *pos += 1;
+            let mut name = String::new();
+            loop {
+                match chars.get(*pos) {
+                    None => return Err(path_error(open, "unterminated quoted name")),
+                    Some('\\') => {
+                        match chars.get(*pos + 1) {
+                            Some(&c) => name.push(c),
+                            None => return Err(path_error(open, "unterminated quoted name")),
+                        }
+                        *pos += 2;
+                    }
+                    Some(&c) if c == quote => {
+                        *pos += 1;
+                        break;
+                    }
+                    Some(&c) => {
+                        name.push(c);
+                        *pos += 1;
+                    }
+                }
+            }
+            PathSelector::Name(name)
+        }
+        Some(c) if c.is_ascii_digit() || *c == '-' => {
+            let start = *pos;
+            *pos += 1;
+            while chars.get(*pos).is_some_and(|c| c.is_ascii_digit()) {
+                *pos += 1;
+            }
+            let text: String = chars[start..*pos].iter().collect();
+            let index = text
+                .parse::<i64>()
+                .map_err(|_| path_error(start, &format!("invalid array index '{}'", text)))?;
+            PathSelector::Index(index)
+        }
+        _ => {
+            return Err(path_error(
+                *pos,
+                "expected an array index, '*' or a quoted name",
+            ))
+        }
+    };
+
+    skip_spaces(pos);
+    if chars.get(*pos) != Some(&']') {
+        return Err(path_error(*pos, "expected ']'"));
+    }
+    *pos += 1;
+    Ok(selector)
+}
+
+fn evaluate_json_path<'a>(root: &'a Value, segments: &[PathSegment]) -> Vec<(String, &'a Value)> {
+    let mut nodes = vec![("$".to_string(), root)];
+    for segment in segments {
+        let mut next = Vec::new();
+        match segment {
+            PathSegment::Child(selector) => {
+                for (path, value) in &nodes {
+                    select_children(path, value, selector, &mut next);
+                }
+            }
+            PathSegment::Descendant(selector) => {
+                for (path, value) in &nodes {
+                    let mut subtree = Vec::new();
+                    collect_descendants(path.clone(), value, &mut subtree);
+                    for (path, value) in &subtree {
+                        select_children(path, value, selector, &mut next);
+                    }
+                }
+            }
+        }
+        nodes = next;
+    }
+    nodes
+}
+
+/// The node itself followed by every nested value, in document order
+fn collect_descendants<'a>(path: String, value: &'a Value, out: &mut Vec<(String, &'a Value)>) {
+    out.push((path.clone(), value));
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                collect_descendants(member_path(&path, k), v, out);
+            }
+        }
+        Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                collect_descendants(format!("{}[{}]", path, i), v, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn select_children<'a>(
+    path: &str,
+    value: &'a Value,
+    selector: &PathSelector,
+    out: &mut Vec<(String, &'a Value)>,
+) {
+    match (selector, value) {
+        (PathSelector::Name(name), Value::Object(map)) => {
+            if let Some(v) = map.get(name) {
+                out.push((member_path(path, name), v));
+            }
+        }
+        (PathSelector::Index(index), Value::Array(arr)) => {
+            let resolved = if *index < 0 {
+                arr.len() as i64 + index
+            } else {
+                *index
+            };
+            if let Some(v) = usize::try_from(resolved).ok().and_then(|i| arr.get(i)) {
+                out.push((format!("{}[{}]", path, resolved), v));
+            }
+        }
+        (PathSelector::Wildcard, Value::Object(map)) => {
+            for (k, v) in map {
+                out.push((member_path(path, k), v));
+            }
+        }
+        (PathSelector::Wildcard, Value::Array(arr)) => {
+            for (i, v) in arr.iter().enumerate() {
+                out.push((format!("{}[{}]", path, i), v));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Structural difference between two JSON documents
+pub fn diff_json(old_input: &str, new_input: &str, options: &JsonDiffOptions) -> JsonDiffResult {
+    let failure = |error: String| JsonDiffResult {
+        success: false,
+        entries: vec![],
+        added: 0,
+        removed: 0,
+        changed: 0,
+        type_changed: 0,
+        error: Some(error),
+    };
+    let old = match serde_json::from_str::<Value>(old_input) {
+        Ok(value) => value,
+        Err(e) => return failure(format!("Parse error in old JSON: {}", e)),
+    };
+    let new = match serde_json::from_str::<Value>(new_input) {
+        Ok(value) => value,
+        Err(e) => return failure(format!("Parse error in new JSON: {}", e)),
+    };
+
+    let mut entries = Vec::new();
+    diff_values(&old, &new, "$", options, &mut entries);
+    let count = |kind: JsonDiffKind| entries.iter().filter(|e| e.kind == kind).count();
+    JsonDiffResult {
+        success: true,
+        added: count(JsonDiffKind::Added),
+        removed: count(JsonDiffKind::Removed),
+        changed: count(JsonDiffKind::Changed),
+        type_changed: count(JsonDiffKind::TypeChanged),
+        entries,
+        error: None,
+    }
+}
+
+fn diff_entry(
+    path: &str,
+    kind: JsonDiffKind,
+    old: Option<&Value>,
+    new: Option<&Value>,
+) -> JsonDiffEntry {
+    JsonDiffEntry {
+        path: path.to_string(),
+        kind,
+        old_value: old.map(Value::to_string),
+        new_value: new.map(Value::to_string),
+    }
+}
+
+fn diff_values(
+    old: &Value,
+    new: &Value,
+    path: &str,
+    options: &JsonDiffOptions,
+    out: &mut Vec<JsonDiffEntry>,
+) {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            if !options.ignore_key_order {
+                let old_keys: Vec<&String> = old_map
+                    .keys()
+                    .filter(|k| new_map.contains_key(*k))
+                    .collect();
+                let new_keys: Vec<&String> = new_map
+                    .keys()
+                    .filter(|k| old_map.contains_key(*k))
+                    .collect();
+                if old_keys != new_keys {
+                    out.push(JsonDiffEntry {
+                        path: path.to_string(),
+                        kind: JsonDiffKind::Changed,
+                        old_value: Some(serde_json::to_string(&old_keys).unwrap_or_default()),
+                        new_value: Some(serde_json::to_string(&new_keys).unwrap_or_default()),
+                    });
+                }
+            }
+            for (key, old_value) in old_map {
+                let child_path = format!("{}.{}", path, key);
+                match new_map.get(key) {
+                    Some(new_value) => diff_values(old_value, new_value, &child_path, options, out),
+                    None => out.push(diff_entry(
+                        &child_path,
+                        JsonDiffKind::Removed,
+                        Some(old_value),
+                        None,
+                    )),
+                }
+            }
+            for (key, new_value) in new_map {
+                if !old_map.contains_key(key) {
+                    out.push(diff_entry(
+                        &format!("{}.{}", path, key),
+                        JsonDiffKind::Added,
+                        None,
+                        Some(new_value),
+                    ));
+                }
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items)) => match options.array_mode {
+            JsonArrayDiffMode::Ordered => {
+                for (i, old_item) in old_items.iter().enumerate() {
+                    let child_path = format!("{}[{}]", path, i);
+                    match new_items.get(i) {
+                        Some(new_item) => {
+                            diff_values(old_item, new_item, &child_path, options, out)
+                        }
+                        None => out.push(diff_entry(
+                            &child_path,
+                            JsonDiffKind::Removed,
+                            Some(old_item),
+                            None,
+                        )),
+                    }
+                }
+                for (i, new_item) in new_items.iter().enumerate().skip(old_items.len()) {
+                    out.push(diff_entry(
+                        &format!("{}[{}]", path, i),
+                        JsonDiffKind::Added,
+                        None,
+                        Some(new_item),
+                    ));
+                }
+            }
+            JsonArrayDiffMode::Unordered => {
+                diff_unordered_arrays(old_items, new_items, path, options, out)
+            }
+        },
+        (Value::Number(a), Value::Number(b)) => {
+            let equal = a == b
+                || (options.ignore_number_format
+                    && a.as_f64().is_some_and(|a| b.as_f64() == Some(a)));
+            if !equal {
+                out.push(diff_entry(
+                    path,
+                    JsonDiffKind::Changed,
+                    Some(old),
+                    Some(new),
+                ));
+            }
+        }
+        _ if get_value_type(old) != get_value_type(new) => {
+            out.push(diff_entry(
+                path,
+                JsonDiffKind::TypeChanged,
+                Some(old),
+                Some(new),
+            ));
+        }
+        _ => {
+            if old != new {
+                out.push(diff_entry(
+                    path,
+                    JsonDiffKind::Changed,
+                    Some(old),
+                    Some(new),
+                ));
+            }
+        }
+    }
+}
+
+/// Pair up elements by id first, then by equal content; the rest were removed or added
+fn diff_unordered_arrays(
+    old_items: &[Value],
+    new_items: &[Value],
+    path: &str,
+    options: &JsonDiffOptions,
+    out: &mut Vec<JsonDiffEntry>,
+) {
+    let mut new_used = vec![false; new_items.len()];
+    let mut old_match: Vec<Option<usize>> = vec![None; old_items.len()];
+
+    if let Some(id_key) = options.array_id_key.as_deref() {
+        for (i, old_item) in old_items.iter().enumerate() {
+            let Some(id) = old_item.get(id_key) else {
+                continue;
+            };
+            let found = new_items
+                .iter()
+                .enumerate()
+                .position(|(j, new_item)| !new_used[j] && new_item.get(id_key) == Some(id));
+            if let Some(j) = found {
+                new_used[j] = true;
+                old_match[i] = Some(j);
+            }
+        }
+    }
+    for (i, old_item) in old_items.iter().enumerate() {
+        if old_match[i].is_some() {
+            continue;
+        }
+        let found = new_items.iter().enumerate().position(|(j, new_item)| {
+            if new_used[j] {
+                return false;
+            }
+            let mut scratch = Vec::new();
+            diff_values(old_item, new_item, path, options, &mut scratch);
+            scratch.is_empty()
+        });
+        if let Some(j) = found {
+            new_used[j] = true;
+            old_match[i] = Some(j);
+        }
+    }
+
+    for (i, old_item) in old_items.iter().enumerate() {
+        match old_match[i] {
+            Some(j) => diff_values(
+                old_item,
+                &new_items[j],
+                &format!("{}[{}]", path, j),
+                options,
+                out,
+            ),
+            None => out.push(diff_entry(
+                &format!("{}[{}]", path, i),
+                JsonDiffKind::Removed,
+                Some(old_item),
+                None,
+            )),
+        }
+    }
+    for (j, new_item) in new_items.iter().enumerate() {
+        if !new_used[j] {
+            out.push(diff_entry(
+                &format!("{}[{}]", path, j),
+                JsonDiffKind::Added,
+                None,
+                Some(new_item),
+            ));
+        }
+    }
+}
+
+fn member_path(path: &str, key: &str) -> String {
+    let plain = key.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if plain {
+        format!("{}.{}", path, key)
+    } else {
+        format!(
+            "{}['{}']",
+            path,
+            key.replace('\\', "\\\\").replace('\'', "\\'")
+        )
+    }
+}
+
 fn value_to_string(value: &Value) -> String {
     match value {
         Value::String(s) => format!("\"{}\"", s),
@@ -426,6 +1219,22 @@ mod tests {
         assert!(result.formatted.contains("\"name\": \"test\""));
     }
 
+    #[test]
+    fn test_object_keys_keep_input_order() {
+        let input = r#"{"zeta":1,"alpha":{"y":2,"b":3},"mid":[]}"#;
+        assert_eq!(minify_json(input).minified, input);
+
+        assert_eq!(
+            format_json(input, 2).formatted,
+            "{\n  \"zeta\": 1,\n  \"alpha\": {\n    \"y\": 2,\n    \"b\": 3\n  },\n  \"mid\": []\n}"
+        );
+
+        let store = JsonTreeStore::default();
+        let tree = parse_json_tree_root(&store, input).unwrap();
+        let paths: Vec<_> = tree.children.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, ["$.zeta", "$.alpha", "$.mid"]);
+    }
+
     #[test]
     fn test_validate_json_valid() {
         let input = r#"{"name": "test"}"#;
@@ -455,13 +1264,42 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_to_tree() {
-        let input = r#"{"name": "test", "nested": {"value": 123}}"#;
-        let result = parse_to_tree(input);
-        assert!(result.success);
-        assert!(result.tree.is_some());
-        let tree = result.tree.unwrap();
-        assert_eq!(tree.value_type, JsonValueType::Object);
+    fn test_parse_json_tree_root() {
+        let store = JsonTreeStore::default();
+        let input = r#"{"name": "test", "nested": {"value": 123}, "a b": [1, 2]}"#;
+        let tree = parse_json_tree_root(&store, input).unwrap();
+        assert_eq!(tree.root.value_type, JsonValueType::Object);
+        assert_eq!(tree.root.child_count, 3);
+
+        let paths: Vec<_> = tree.children.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, ["$.name", "$.nested", "$['a b']"]);
+        assert_eq!(tree.children[1].child_count, 1);
+        assert_eq!(tree.children[2].value.as_deref(), Some("[2 items]"));
+
+        let nested = get_json_tree_children(&store, &tree.id, "$.nested", 0, 100).unwrap();
+        assert_eq!(nested[0].path, "$.nested.value");
+        assert_eq!(nested[0].value.as_deref(), Some("123"));
+        let items = get_json_tree_children(&store, &tree.id, "$['a b']", 1, 100).unwrap();
+        assert_eq!(items[0].key, "[1]");
+
+        assert!(get_json_tree_children(&store, &tree.id, "$.missing", 0, 100).is_err());
+        release_json_tree(&store, &tree.id);
+        assert!(get_json_tree_children(&store, &tree.id, "$", 0, 100).is_err());
+        assert!(parse_json_tree_root(&store, "{").is_err());
+    }
+
+    #[test]
+    fn test_json_tree_paging() {
+        let store = JsonTreeStore::default();
+        let input = serde_json::to_string(&(0..25_000).collect::<Vec<u32>>()).unwrap();
+        let tree = parse_json_tree_root(&store, &input).unwrap();
+        assert_eq!(tree.root.child_count, 25_000);
+        assert_eq!(tree.children.len(), MAX_TREE_PAGE);
+
+        let page = get_json_tree_children(&store, &tree.id, "$", 20_000, 50_000).unwrap();
+        assert_eq!(page.len(), 5_000);
+        assert_eq!(page[0].path, "$[20000]");
+        assert_eq!(page[4_999].value.as_deref(), Some("24999"));
     }
 
     #[test]
@@ -471,4 +1309,260 @@ mod tests {
         assert!(result.success);
         assert!(result.total_count > 0);
     }
+
+    const QUERY_INPUT: &str = r#"{
+        "items": [
+            {"name": "apple", "tags": {"name": "fruit"}},
+            {"name": "carrot"}
+        ],
+        "a/b": {"m~n": 1},
+        "odd key": true
+    }"#;
+
+    #[test]
+    fn test_query_json_pointer() {
+        let result = query_json(QUERY_INPUT, "/items/1/name", JsonQueryType::Pointer);
+        assert!(result.success);
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.matches[0].path, "/items/1/name");
+        assert_eq!(result.matches[0].value, "\"carrot\"");
+        assert_eq!(result.formatted, "\"carrot\"");
+
+        let escaped = query_json(QUERY_INPUT, "/a~1b/m~0n", JsonQueryType::Pointer);
+        assert_eq!(escaped.matches[0].value, "1");
+
+        let whole = query_json(QUERY_INPUT, "", JsonQueryType::Pointer);
+        assert_eq!(whole.matches[0].value_type, JsonValueType::Object);
+
+        let missing = query_json(QUERY_INPUT, "/items/01", JsonQueryType::Pointer);
+        assert!(missing.success);
+        assert_eq!(missing.total_count, 0);
+        assert!(missing.formatted.is_empty());
+
+        let invalid = query_json(QUERY_INPUT, "/items/~2", JsonQueryType::Pointer);
+        assert!(!invalid.success);
+        assert!(invalid.error.unwrap().contains("position 8"));
+        assert!(query_json(QUERY_INPUT, "items", JsonQueryType::Pointer)
+            .error
+            .is_some());
+    }
+
+    #[test]
+    fn test_query_json_path() {
+        let result = query_json(QUERY_INPUT, "$.items[*].name", JsonQueryType::JsonPath);
+        assert!(result.success);
+        let paths: Vec<&str> = result.matches.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(paths, vec!["$.items[0].name", "$.items[1].name"]);
+        let formatted: Value = serde_json::from_str(&result.formatted).unwrap();
+        assert_eq!(formatted, serde_json::json!(["apple", "carrot"]));
+
+        let recursive = query_json(QUERY_INPUT, "$..name", JsonQueryType::JsonPath);
+        let paths: Vec<&str> = recursive.matches.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["$.items[0].name", "$.items[0].tags.name", "$.items[1].name"]
+        );
+
+        let last = query_json(QUERY_INPUT, "$.items[-1]['name']", JsonQueryType::JsonPath);
+        assert_eq!(last.matches[0].path, "$.items[1].name");
+
+        let quoted = query_json(QUERY_INPUT, "$['odd key']", JsonQueryType::JsonPath);
+        assert_eq!(quoted.matches[0].path, "$['odd key']");
+        assert_eq!(quoted.matches[0].value, "true");
+    }
+
+    const SCHEMA: &str = r#"{
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "required": ["name", "items"],
+        "properties": {
+            "name": {"type": "string", "minLength": 3},
+            "items": {"type": "array", "items": {"type": "integer", "maximum": 10}},
+            "email": {"type": "string", "format": "email"}
+        }
+    }"#;
+
+    #[test]
+    fn test_validate_json_with_schema() {
+        let valid = validate_json_with_schema(r#"{"name": "abc", "items": [1, 2]}"#, SCHEMA);
+        assert!(valid.valid);
+        assert!(valid.violations.is_empty());
+
+        let result = validate_json_with_schema(
+            r#"{"name": "ab", "items": [1, 20], "email": "nope"}"#,
+            SCHEMA,
+        );
+        assert!(!result.valid);
+        let found: Vec<(&str, &str)> = result
+            .violations
+            .iter()
+            .map(|v| (v.path.as_str(), v.keyword.as_str()))
+            .collect();
+        assert!(found.contains(&("$.name", "minLength")));
+        assert!(found.contains(&("$.items[1]", "maximum")));
+        assert!(found.contains(&("$.email", "format")));
+
+        let missing = validate_json_with_schema(r#"{"name": "abc"}"#, SCHEMA);
+        assert_eq!(missing.violations[0].path, "$");
+        assert_eq!(missing.violations[0].keyword, "required");
+    }
+
+    #[test]
+    fn test_validate_json_with_schema_2020_12() {
+        let schema = r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "array",
+            "prefixItems": [{"type": "string"}],
+            "items": false
+        }"#;
+        assert!(validate_json_with_schema(r#"["a"]"#, schema).valid);
+        let result = validate_json_with_schema(r#"[1, 2]"#, schema);
+        let keywords: Vec<&str> = result
+            .violations
+            .iter()
+            .map(|v| v.keyword.as_str())
+            .collect();
+        assert!(keywords.contains(&"type"));
+        assert!(keywords.contains(&"items"));
+    }
+
+    #[test]
+    fn test_validate_json_with_invalid_schema() {
+        let result = validate_json_with_schema("{}", r#"{"type": 5}"#);
+        assert!(!result.valid);
+        assert!(result.schema_error.is_some());
+        assert!(result.error.is_none());
+
+        let result = validate_json_with_schema("{}", "not json");
+        assert!(result
+            .schema_error
+            .unwrap()
+            .starts_with("Schema parse error"));
+
+        let result = validate_json_with_schema("{", "{}");
+        assert!(result.schema_error.is_none());
+        assert!(result.error.is_some());
+    }
+
+    #[test]
+    fn test_pointer_to_tree_path() {
+        assert_eq!(pointer_to_tree_path(""), "$");
+        assert_eq!(pointer_to_tree_path("/items/0/a~1b"), "$.items[0]['a/b']");
+    }
+
+    #[test]
+    fn test_query_json_path_syntax_errors() {
+        let cases = [
+            ("items", "position 1"),
+            ("$.items[0", "position 10"),
+            ("$.items[x]", "position 9"),
+            ("$.", "position 3"),
+            ("$['name]", "position 2"),
+        ];
+        for (query, expected) in cases {
+            let result = query_json(QUERY_INPUT, query, JsonQueryType::JsonPath);
+            assert!(!result.success, "{}", query);
+            let error = result.error.unwrap();
+            assert!(error.contains(expected), "{}: {}", query, error);
+        }
+    }
+
+    #[test]
+    fn test_diff_json_objects_and_numbers() {
+        let old = r#"{"name":"a","count":1,"tags":["x","y"],"meta":{"v":1},"gone":true}"#;
+        let new = r#"{"meta":"none","tags":["x","z","w"],"count":1.0,"name":"b","extra":null}"#;
+        let result = diff_json(old, new, &JsonDiffOptions::default());
+        assert!(result.success);
+        let find = |path: &str| {
+            result
+                .entries
+                .iter()
+                .find(|e| e.path == path)
+                .map(|e| e.kind)
+        };
+        assert_eq!(find("$.name"), Some(JsonDiffKind::Changed));
+        assert_eq!(find("$.count"), None);
+        assert_eq!(find("$.tags[1]"), Some(JsonDiffKind::Changed));
+        assert_eq!(find("$.tags[2]"), Some(JsonDiffKind::Added));
+        assert_eq!(find("$.meta"), Some(JsonDiffKind::TypeChanged));
+        assert_eq!(find("$.gone"), Some(JsonDiffKind::Removed));
+        assert_eq!(find("$.extra"), Some(JsonDiffKind::Added));
+        assert_eq!(find("$"), None);
+        assert_eq!(
+            (
+                result.added,
+                result.removed,
+                result.changed,
+                result.type_changed
+            ),
+            (2, 1, 2, 1)
+        );
+
+        let strict = JsonDiffOptions {
+            ignore_number_format: false,
+            ignore_key_order: false,
+            ..Default::default()
+        };
+        let result = diff_json(old, new, &strict);
+        let count = result.entries.iter().find(|e| e.path == "$.count").unwrap();
+        assert_eq!(count.old_value.as_deref(), Some("1"));
+        assert_eq!(count.new_value.as_deref(), Some("1.0"));
+        let root = result.entries.iter().find(|e| e.path == "$").unwrap();
+        assert_eq!(
+            root.old_value.as_deref(),
+            Some(r#"["name","count","tags","meta"]"#)
+        );
+
+        let result = diff_json("{", "{}", &JsonDiffOptions::default());
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("old JSON"));
+    }
+
+    #[test]
+    fn test_diff_json_unordered_arrays() {
+        let old = r#"[{"id":1,"v":"a"},{"id":2,"v":"b"},{"id":3,"v":"c"},5]"#;
+        let new = r#"[5,{"id":3,"v":"c"},{"id":1,"v":"A"},{"id":4,"v":"d"}]"#;
+
+        let ordered = diff_json(old, new, &JsonDiffOptions::default());
+        assert!(ordered.entries.len() > 3);
+
+        let by_content = JsonDiffOptions {
+            array_mode: JsonArrayDiffMode::Unordered,
+            ..Default::default()
+        };
+        let result = diff_json(old, new, &by_content);
+        let kinds: Vec<(&str, JsonDiffKind)> = result
+            .entries
+            .iter()
+            .map(|e| (e.path.as_str(), e.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("$[0]", JsonDiffKind::Removed),
+                ("$[1]", JsonDiffKind::Removed),
+                ("$[2]", JsonDiffKind::Added),
+                ("$[3]", JsonDiffKind::Added),
+            ]
+        );
+
+        let by_id = JsonDiffOptions {
+            array_id_key: Some("id".to_string()),
+            ..by_content
+        };
+        let result = diff_json(old, new, &by_id);
+        let kinds: Vec<(&str, JsonDiffKind)> = result
+            .entries
+            .iter()
+            .map(|e| (e.path.as_str(), e.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("$[2].v", JsonDiffKind::Changed),
+                ("$[1]", JsonDiffKind::Removed),
+                ("$[3]", JsonDiffKind::Added),
+            ]
+        );
+    }
 }
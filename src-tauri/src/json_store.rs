@@ -0,0 +1,64 @@
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::Path;
+
+/// Read a JSON file kept in the app data dir, treating a missing file as empty.
+/// `what` names the file in error messages.
+pub fn read_json_store<T: DeserializeOwned + Default>(
+    path: &Path,
+    what: &str,
+) -> Result<T, String> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", what, e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", what, e))
+}
+
+/// Read a JSON store before changing it. A file that cannot be parsed is renamed to
+/// `<name>.json.corrupt` instead of being overwritten, so it can still be recovered by hand.
+pub fn load_json_store_for_update<T: DeserializeOwned + Default>(
+    path: &Path,
+    what: &str,
+) -> Result<T, String> {
+    match read_json_store(path, what) {
+        Ok(value) => Ok(value),
+        Err(e) if path.exists() => {
+            eprintln!("{}; moving it aside", e);
+            fs::rename(path, path.with_extension("json.corrupt"))
+                .map_err(|e| format!("Failed to back up corrupt {}: {}", what, e))?;
+            Ok(T::default())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_corrupt_store_is_moved_aside() {
+        let dir = TempDir::new("json_store");
+        let path = dir.join("store.json");
+
+        let missing: Vec<String> = read_json_store(&path, "store").unwrap();
+        assert!(missing.is_empty());
+
+        fs::write(&path, "[\"a\",").unwrap();
+        assert!(read_json_store::<Vec<String>>(&path, "store").is_err());
+        let recovered: Vec<String> = load_json_store_for_update(&path, "store").unwrap();
+        assert!(recovered.is_empty());
+        assert!(!path.exists());
+        assert_eq!(
+            fs::read_to_string(path.with_extension("json.corrupt")).unwrap(),
+            "[\"a\","
+        );
+
+        fs::write(&path, "[\"b\"]").unwrap();
+        let saved: Vec<String> = load_json_store_for_update(&path, "store").unwrap();
+        assert_eq!(saved, vec!["b".to_string()]);
+    }
+}
@@ -0,0 +1,381 @@
+use crate::unix_time_converter::{unix_to_datetime, TimestampUnit, TimezoneOption};
+use base64::{engine::general_purpose, Engine};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Sha384, Sha512};
+
+const TIME_CLAIMS: [&str; 3] = ["exp", "iat", "nbf"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum JwtPart {
+    Header,
+    Payload,
+    Signature,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum JwtErrorKind {
+    EmptyToken,
+    InvalidStructure,
+    InvalidBase64,
+    InvalidJson,
+    UnsupportedAlgorithm,
+    InvalidSignature,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtError {
+    pub kind: JwtErrorKind,
+    pub part: Option<JwtPart>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtTimeClaim {
+    pub claim: String,
+    pub timestamp: i64,
+    pub datetime_utc: String,
+    pub datetime_local: String,
+    pub relative_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtDecodeResult {
+    pub success: bool,
+    pub header: String,
+    pub payload: String,
+    pub signature: String,
+    pub algorithm: Option<String>,
+    pub time_claims: Vec<JwtTimeClaim>,
+    pub is_expired: bool,
+    pub is_not_yet_valid: bool,
+    pub error: Option<JwtError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtVerifyResult {
+    pub success: bool,
+    pub valid: bool,
+    pub algorithm: Option<String>,
+    pub error: Option<JwtError>,
+}
+
+struct JwtParts<'a> {
+    header_b64: &'a str,
+    payload_b64: &'a str,
+    signature_b64: &'a str,
+    header: serde_json::Value,
+    payload: serde_json::Value,
+}
+
+impl JwtDecodeResult {
+    fn failure(error: JwtError) -> Self {
+        Self {
+            success: false,
+            header: String::new(),
+            payload: String::new(),
+            signature: String::new(),
+            algorithm: None,
+            time_claims: Vec::new(),
+            is_expired: false,
+            is_not_yet_valid: false,
+            error: Some(error),
+        }
+    }
+}
+
+impl JwtVerifyResult {
+    fn failure(algorithm: Option<String>, error: JwtError) -> Self {
+        Self {
+            success: false,
+            valid: false,
+            algorithm,
+            error: Some(error),
+        }
+    }
+}
+
+fn jwt_error(kind: JwtErrorKind, part: Option<JwtPart>, message: impl Into<String>) -> JwtError {
+    JwtError {
+        kind,
+        part,
+        message: message.into(),
+    }
+}
+
+fn part_name(part: &JwtPart) -> &'static str {
+    match part {
+        JwtPart::Header => "header",
+        JwtPart::Payload => "payload",
+        JwtPart::Signature => "signature",
+    }
+}
+
+/// Decode a Base64URL segment, tolerating optional `=` padding
+fn decode_segment(segment: &str, part: JwtPart) -> Result<Vec<u8>, JwtError> {
+    general_purpose::URL_SAFE_NO_PAD
+        .decode(segment.trim_end_matches('='))
+        .map_err(|e| {
+            let message = format!("Invalid Base64URL in {}: {}", part_name(&part), e);
+            jwt_error(JwtErrorKind::InvalidBase64, Some(part), message)
+        })
+}
+
+fn decode_json_segment(segment: &str, part: JwtPart) -> Result<serde_json::Value, JwtError> {
+    let bytes = decode_segment(segment, part.clone())?;
+    serde_json::from_slice(&bytes).map_err(|e| {
+        let message = format!("Invalid JSON in {}: {}", part_name(&part), e);
+        jwt_error(JwtErrorKind::InvalidJson, Some(part), message)
+    })
+}
+
+fn split_token(token: &str) -> Result<JwtParts<'_>, JwtError> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(jwt_error(JwtErrorKind::EmptyToken, None, "Token is empty"));
+    }
+
+    let segments: Vec<&str> = token.split('.').collect();
+    if segments.len() != 3 {
+        return Err(jwt_error(
+            JwtErrorKind::InvalidStructure,
+            None,
+            format!(
+                "JWT must have 3 parts separated by '.', found {}",
+                segments.len()
+            ),
+        ));
+    }
+
+    let header = decode_json_segment(segments[0], JwtPart::Header)?;
+    let payload = decode_json_segment(segments[1], JwtPart::Payload)?;
+
+    Ok(JwtParts {
+        header_b64: segments[0],
+        payload_b64: segments[1],
+        signature_b64: segments[2],
+        header,
+        payload,
+    })
+}
+
+fn time_claim(claim: &str, timestamp: i64) -> JwtTimeClaim {
+    let utc = unix_to_datetime(timestamp, TimestampUnit::Seconds, TimezoneOption::Utc);
+    let local = unix_to_datetime(timestamp, TimestampUnit::Seconds, TimezoneOption::Local);
+
+    JwtTimeClaim {
+        claim: claim.to_string(),
+        timestamp,
+        datetime_utc: utc.datetime,
+        datetime_local: local.datetime,
+        relative_time: utc.relative_time,
+    }
+}
+
+/// Decode a JWT without verifying its signature
+pub fn decode_jwt(token: &str) -> JwtDecodeResult {
+    let parts = match split_token(token) {
+        Ok(parts) => parts,
+        Err(e) => return JwtDecodeResult::failure(e),
+    };
+
+    if !parts.signature_b64.is_empty() {
+        if let Err(e) = decode_segment(parts.signature_b64, JwtPart::Signature) {
+            return JwtDecodeResult::failure(e);
+        }
+    }
+
+    let time_claims: Vec<JwtTimeClaim> = TIME_CLAIMS
+        .iter()
+        .filter_map(|claim| {
+            parts
+                .payload
+                .get(*claim)
+                .and_then(|v| v.as_i64().or_else(|| v.as_f64().map(|f| f as i64)))
+                .map(|ts| time_claim(claim, ts))
+        })
+        .collect();
+
+    let now = chrono::Utc::now().timestamp();
+    let claim_value = |name: &str| {
+        time_claims
+            .iter()
+            .find(|c| c.claim == name)
+            .map(|c| c.timestamp)
+    };
+    let is_expired = claim_value("exp").map(|exp| exp <= now).unwrap_or(false);
+    let is_not_yet_valid = claim_value("nbf").map(|nbf| nbf > now).unwrap_or(false);
+
+    let algorithm = parts
+        .header
+        .get("alg")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    JwtDecodeResult {
+        success: true,
+        header: serde_json::to_string_pretty(&parts.header).unwrap_or_default(),
+        payload: serde_json::to_string_pretty(&parts.payload).unwrap_or_default(),
+        signature: parts.signature_b64.to_string(),
+        algorithm,
+        time_claims,
+        is_expired,
+        is_not_yet_valid,
+        error: None,
+    }
+}
+
+fn hmac_sign<M: Mac + hmac::digest::KeyInit>(secret: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = <M as Mac>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Verify an HS256/HS384/HS512 signed JWT with a shared secret
+pub fn verify_jwt(token: &str, secret: &str) -> JwtVerifyResult {
+    let parts = match split_token(token) {
+        Ok(parts) => parts,
+        Err(e) => return JwtVerifyResult::failure(None, e),
+    };
+
+    let algorithm = parts
+        .header
+        .get("alg")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    let signature = match decode_segment(parts.signature_b64, JwtPart::Signature) {
+        Ok(sig) => sig,
+        Err(e) => return JwtVerifyResult::failure(algorithm, e),
+    };
+
+    let signing_input = format!("{}.{}", parts.header_b64, parts.payload_b64);
+    let key = secret.as_bytes();
+    let expected = match algorithm.as_deref() {
+        Some("HS256") => hmac_sign::<Hmac<Sha256>>(key, signing_input.as_bytes()),
+        Some("HS384") => hmac_sign::<Hmac<Sha384>>(key, signing_input.as_bytes()),
+        Some("HS512") => hmac_sign::<Hmac<Sha512>>(key, signing_input.as_bytes()),
+        other => {
+            let message = format!(
+                "Unsupported algorithm: {} (only HS256/HS384/HS512 are supported)",
+                other.unwrap_or("none")
+            );
+            return JwtVerifyResult::failure(
+                algorithm.clone(),
+                jwt_error(
+                    JwtErrorKind::UnsupportedAlgorithm,
+                    Some(JwtPart::Header),
+                    message,
+                ),
+            );
+        }
+    };
+
+    if constant_time_eq(&expected, &signature) {
+        JwtVerifyResult {
+            success: true,
+            valid: true,
+            algorithm,
+            error: None,
+        }
+    } else {
+        JwtVerifyResult {
+            success: true,
+            valid: false,
+            algorithm,
+            error: Some(jwt_error(
+                JwtErrorKind::InvalidSignature,
+                Some(JwtPart::Signature),
+                "Signature does not match",
+            )),
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // https://jwt.io default example, signed with "your-256-bit-secret"
+    const HS256_TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+        eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+        SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+
+    fn make_token(header: &str, payload: &str, secret: &str) -> String {
+        let h = general_purpose::URL_SAFE_NO_PAD.encode(header);
+        let p = general_purpose::URL_SAFE_NO_PAD.encode(payload);
+        let input = format!("{}.{}", h, p);
+        let sig = hmac_sign::<Hmac<Sha256>>(secret.as_bytes(), input.as_bytes());
+        format!("{}.{}", input, general_purpose::URL_SAFE_NO_PAD.encode(sig))
+    }
+
+    #[test]
+    fn test_decode_jwt() {
+        let result = decode_jwt(HS256_TOKEN);
+        assert!(result.success);
+        assert_eq!(result.algorithm.as_deref(), Some("HS256"));
+        assert!(result.payload.contains("John Doe"));
+        assert_eq!(result.time_claims.len(), 1);
+        assert_eq!(result.time_claims[0].claim, "iat");
+        assert_eq!(
+            result.time_claims[0].datetime_utc,
+            "2018-01-18 01:30:22 UTC"
+        );
+        assert!(!result.is_expired);
+    }
+
+    #[test]
+    fn test_verify_jwt() {
+        assert!(verify_jwt(HS256_TOKEN, "your-256-bit-secret").valid);
+
+        let wrong = verify_jwt(HS256_TOKEN, "wrong-secret");
+        assert!(wrong.success);
+        assert!(!wrong.valid);
+        assert_eq!(wrong.error.unwrap().kind, JwtErrorKind::InvalidSignature);
+    }
+
+    #[test]
+    fn test_expired_token() {
+        let token = make_token(r#"{"alg":"HS256"}"#, r#"{"exp":1000}"#, "secret");
+        let result = decode_jwt(&token);
+        assert!(result.success);
+        assert!(result.is_expired);
+    }
+
+    #[test]
+    fn test_invalid_structure() {
+        let result = decode_jwt("abc.def");
+        assert!(!result.success);
+        assert_eq!(result.error.unwrap().kind, JwtErrorKind::InvalidStructure);
+    }
+
+    #[test]
+    fn test_invalid_base64() {
+        let result = decode_jwt("!!!.eyJhIjoxfQ.sig");
+        let error = result.error.unwrap();
+        assert_eq!(error.kind, JwtErrorKind::InvalidBase64);
+        assert_eq!(error.part, Some(JwtPart::Header));
+    }
+
+    #[test]
+    fn test_unsupported_algorithm() {
+        let token = make_token(r#"{"alg":"RS256"}"#, r#"{}"#, "secret");
+        let result = verify_jwt(&token, "secret");
+        assert!(!result.success);
+        assert_eq!(
+            result.error.unwrap().kind,
+            JwtErrorKind::UnsupportedAlgorithm
+        );
+    }
+}
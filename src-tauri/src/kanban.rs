@@ -1,7 +1,12 @@
+use crate::settings::load_settings;
+use chrono::{Duration as ChronoDuration, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TaskPriority {
@@ -18,6 +23,13 @@ pub enum TaskColumn {
     Done,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Subtask {
+    pub id: String,
+    pub title: String,
+    pub done: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
@@ -29,14 +41,219 @@ pub struct Task {
     pub due_date: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// Order within the column. Boards saved before this field existed load as 0 and are
+    /// renumbered by creation time.
+    #[serde(default)]
+    pub position: u32,
+    #[serde(default)]
+    pub subtasks: Vec<Subtask>,
+    /// Completed subtasks / total subtasks (0.0 - 1.0), or None when there are none.
+    /// Computed on load; never read back from disk.
+    #[serde(default, skip_deserializing)]
+    pub subtask_progress: Option<f64>,
+}
+
+impl Task {
+    fn refresh_progress(&mut self) {
+        self.subtask_progress = if self.subtasks.is_empty() {
+            None
+        } else {
+            let done = self.subtasks.iter().filter(|s| s.done).count();
+            Some(done as f64 / self.subtasks.len() as f64)
+        };
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KanbanBoard {
+    /// Empty for boards saved before multiple boards existed, until they are migrated
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
     pub tasks: Vec<Task>,
     pub columns: Vec<ColumnConfig>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BoardSummary {
+    pub id: String,
+    pub name: String,
+    pub task_count: usize,
+    /// Whether this is the board commands fall back to when no board ID is given
+    pub last_opened: bool,
+}
+
+/// Open tasks (not in Done) with a due date, across every board
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DueSummary {
+    pub overdue: Vec<Task>,
+    pub due_today: Vec<Task>,
+    /// Due within the next six days, after today
+    pub due_this_week: Vec<Task>,
+}
+
+/// Payload of the `kanban-due-counts` event, used for the sidebar badge
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DueCounts {
+    pub overdue: usize,
+    pub due_today: usize,
+    pub due_this_week: usize,
+}
+
+impl DueSummary {
+    fn counts(&self) -> DueCounts {
+        DueCounts {
+            overdue: self.overdue.len(),
+            due_today: self.due_today.len(),
+            due_this_week: self.due_this_week.len(),
+        }
+    }
+}
+
+/// Everything stored in `kanban.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct KanbanData {
+    boards: Vec<KanbanBoard>,
+    #[serde(default)]
+    last_board_id: Option<String>,
+}
+
+impl KanbanData {
+    /// Index of `board_id`, or of the last opened board when it is None
+    fn board_index(&self, board_id: Option<&str>) -> Result<usize, String> {
+        let id = match board_id {
+            Some(id) => id,
+            None => match self.last_board_id.as_deref() {
+                Some(id) if self.boards.iter().any(|b| b.id == id) => id,
+                _ => return Ok(0),
+            },
+        };
+        self.boards
+            .iter()
+            .position(|b| b.id == id)
+            .ok_or_else(|| format!("Board not found: {}", id))
+    }
+
+    fn summaries(&self) -> Vec<BoardSummary> {
+        let last_index = self.board_index(None).unwrap_or(0);
+        self.boards
+            .iter()
+            .enumerate()
+            .map(|(index, board)| BoardSummary {
+                id: board.id.clone(),
+                name: board.name.clone(),
+                task_count: board.tasks.len(),
+                last_opened: index == last_index,
+            })
+            .collect()
+    }
+
+    /// Remove a board. One that still has tasks is only removed with `confirmed`.
+    fn remove_board(&mut self, board_id: &str, confirmed: bool) -> Result<(), String> {
+        let index = self.board_index(Some(board_id))?;
+        if self.boards.len() == 1 {
+            return Err("The last board cannot be deleted".to_string());
+        }
+        let task_count = self.boards[index].tasks.len();
+        if task_count > 0 && !confirmed {
+            return Err(format!(
+                "Board \"{}\" still has {} task(s); confirm to delete it",
+                self.boards[index].name, task_count
+            ));
+        }
+        self.boards.remove(index);
+        if self.last_board_id.as_deref() == Some(board_id) {
+            self.last_board_id = self.boards.first().map(|b| b.id.clone());
+        }
+        Ok(())
+    }
+}
+
+const DEFAULT_BOARD_NAME: &str = "Default";
+const MAX_BOARD_NAME_CHARS: usize = 100;
+
+fn new_board(name: &str) -> KanbanBoard {
+    KanbanBoard {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+fn validate_board_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Board name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_BOARD_NAME_CHARS {
+        return Err(format!(
+            "Board name is limited to {} characters",
+            MAX_BOARD_NAME_CHARS
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Parse `kanban.json`, moving a single board saved by older versions into a board
+/// named "Default". Returns whether anything had to be migrated.
+fn parse_data(content: &str) -> Result<(KanbanData, bool), String> {
+    let value: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| format!("Failed to parse kanban data: {}", e))?;
+    let (mut data, mut migrated) = if value.get("boards").is_some() {
+        let data: KanbanData = serde_json::from_value(value)
+            .map_err(|e| format!("Failed to parse kanban data: {}", e))?;
+        (data, false)
+    } else {
+        let board: KanbanBoard = serde_json::from_value(value)
+            .map_err(|e| format!("Failed to parse kanban data: {}", e))?;
+        (
+            KanbanData {
+                boards: vec![board],
+                last_board_id: None,
+            },
+            true,
+        )
+    };
+
+    for board in &mut data.boards {
+        if board.id.is_empty() {
+            board.id = uuid::Uuid::new_v4().to_string();
+            migrated = true;
+        }
+        if board.name.trim().is_empty() {
+            board.name = DEFAULT_BOARD_NAME.to_string();
+            migrated = true;
+        }
+        normalize_positions(board);
+        board.tasks.iter_mut().for_each(Task::refresh_progress);
+    }
+    if data.boards.is_empty() {
+        data.boards.push(new_board(DEFAULT_BOARD_NAME));
+        migrated = true;
+    }
+    if data.last_board_id.is_none() {
+        data.last_board_id = Some(data.boards[0].id.clone());
+    }
+    Ok((data, migrated))
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardExportFormat {
+    Json,
+    Csv,
+}
+
+const CSV_HEADERS: [&str; 6] = [
+    "title",
+    "description",
+    "column",
+    "priority",
+    "assignee",
+    "due_date",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColumnConfig {
     pub id: TaskColumn,
@@ -47,6 +264,8 @@ pub struct ColumnConfig {
 impl Default for KanbanBoard {
     fn default() -> Self {
         Self {
+            id: String::new(),
+            name: String::new(),
             tasks: Vec::new(),
             columns: vec![
                 ColumnConfig {
@@ -79,56 +298,290 @@ fn get_data_path(app: &AppHandle) -> Result<PathBuf, String> {
     Ok(app_data_dir.join("kanban.json"))
 }
 
-pub fn load_board(app: &AppHandle) -> Result<KanbanBoard, String> {
+fn load_data(app: &AppHandle) -> Result<KanbanData, String> {
     let path = get_data_path(app)?;
-    if path.exists() {
-        let content =
-            fs::read_to_string(&path).map_err(|e| format!("Failed to read kanban file: {}", e))?;
-        serde_json::from_str(&content).map_err(|e| format!("Failed to parse kanban data: {}", e))
+    let content = if path.exists() {
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read kanban file: {}", e))?
     } else {
-        Ok(KanbanBoard::default())
+        "{\"boards\": []}".to_string()
+    };
+    let (data, migrated) = parse_data(&content)?;
+    if migrated {
+        save_data(app, &data)?;
     }
+    Ok(data)
 }
 
-pub fn save_board(app: &AppHandle, board: &KanbanBoard) -> Result<(), String> {
+fn save_data(app: &AppHandle, data: &KanbanData) -> Result<(), String> {
     let path = get_data_path(app)?;
     let content =
-        serde_json::to_string_pretty(board).map_err(|e| format!("Failed to serialize: {}", e))?;
-    fs::write(&path, content).map_err(|e| format!("Failed to write kanban file: {}", e))
+        serde_json::to_string_pretty(data).map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write kanban file: {}", e))?;
+    // Any task change can move the sidebar badge
+    let _ = app.emit("kanban-due-counts", summarize_due(data, today()).counts());
+    Ok(())
+}
+
+fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// Read the date part of `due_date`; values are stored as `YYYY-MM-DD` local dates
+fn parse_due_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    NaiveDate::parse_from_str(value.get(..10).unwrap_or(value), "%Y-%m-%d").ok()
+}
+
+/// Sort open tasks on every board into overdue, due today and due in the next six days
+fn summarize_due(data: &KanbanData, today: NaiveDate) -> DueSummary {
+    let week_end = today + ChronoDuration::days(6);
+    let mut dated: Vec<(NaiveDate, &Task)> = data
+        .boards
+        .iter()
+        .flat_map(|b| &b.tasks)
+        .filter(|t| t.column != TaskColumn::Done)
+        .filter_map(|t| Some((parse_due_date(t.due_date.as_deref()?)?, t)))
+        .collect();
+    dated.sort_by_key(|(due, _)| *due);
+
+    let mut summary = DueSummary::default();
+    for (due, task) in dated {
+        let bucket = if due < today {
+            &mut summary.overdue
+        } else if due == today {
+            &mut summary.due_today
+        } else if due <= week_end {
+            &mut summary.due_this_week
+        } else {
+            continue;
+        };
+        bucket.push(task.clone());
+    }
+    summary
+}
+
+pub fn get_due_summary(app: &AppHandle) -> Result<DueSummary, String> {
+    Ok(summarize_due(&load_data(app)?, today()))
+}
+
+fn notify_due(app: &AppHandle, counts: DueCounts) {
+    let mut parts = Vec::new();
+    if counts.overdue > 0 {
+        parts.push(format!("{} overdue", counts.overdue));
+    }
+    if counts.due_today > 0 {
+        parts.push(format!("{} due today", counts.due_today));
+    }
+    if parts.is_empty() {
+        return;
+    }
+    let _ = app
+        .notification()
+        .builder()
+        .title("Kanban tasks need attention")
+        .body(parts.join(", "))
+        .show();
+}
+
+/// Check due dates on startup and then every `check_interval_minutes` from the settings,
+/// emitting the badge counts and notifying about overdue tasks and tasks due today.
+/// A changed interval takes effect after the next check.
+pub fn start_due_checker(app: &AppHandle) {
+    let app = app.clone();
+    thread::spawn(move || loop {
+        let settings = load_settings(&app).kanban_due;
+        if let Ok(summary) = get_due_summary(&app) {
+            let counts = summary.counts();
+            let _ = app.emit("kanban-due-counts", counts);
+            if settings.notify {
+                notify_due(&app, counts);
+            }
+        }
+        thread::sleep(Duration::from_secs(
+            u64::from(settings.check_interval_minutes) * 60,
+        ));
+    });
+}
+
+/// Load the data, apply `f` to one board (the last opened one when `board_id` is None),
+/// and save it again
+fn with_board<T, F>(app: &AppHandle, board_id: Option<&str>, f: F) -> Result<T, String>
+where
+    F: FnOnce(&mut KanbanBoard) -> Result<T, String>,
+{
+    let mut data = load_data(app)?;
+    let index = data.board_index(board_id)?;
+    let result = f(&mut data.boards[index])?;
+    save_data(app, &data)?;
+    Ok(result)
+}
+
+/// Load a board and remember it as the last opened one. Without `board_id` this is the
+/// board that was open last time.
+pub fn load_board(app: &AppHandle, board_id: Option<String>) -> Result<KanbanBoard, String> {
+    let mut data = load_data(app)?;
+    let index = data.board_index(board_id.as_deref())?;
+    let board = data.boards[index].clone();
+    if data.last_board_id.as_deref() != Some(board.id.as_str()) {
+        data.last_board_id = Some(board.id.clone());
+        save_data(app, &data)?;
+    }
+    Ok(board)
+}
+
+pub fn list_boards(app: &AppHandle) -> Result<Vec<BoardSummary>, String> {
+    Ok(load_data(app)?.summaries())
+}
+
+pub fn create_board(app: &AppHandle, name: String) -> Result<BoardSummary, String> {
+    let name = validate_board_name(&name)?;
+    let mut data = load_data(app)?;
+    let board = new_board(&name);
+    data.last_board_id = Some(board.id.clone());
+    data.boards.push(board);
+    save_data(app, &data)?;
+    data.summaries()
+        .pop()
+        .ok_or_else(|| "Failed to create board".to_string())
+}
+
+pub fn rename_board(
+    app: &AppHandle,
+    board_id: String,
+    name: String,
+) -> Result<BoardSummary, String> {
+    let name = validate_board_name(&name)?;
+    let mut data = load_data(app)?;
+    let index = data.board_index(Some(&board_id))?;
+    data.boards[index].name = name;
+    save_data(app, &data)?;
+    Ok(data.summaries().swap_remove(index))
+}
+
+/// Delete a board. Boards that still have tasks need `confirmed` set.
+pub fn delete_board(
+    app: &AppHandle,
+    board_id: String,
+    confirmed: bool,
+) -> Result<Vec<BoardSummary>, String> {
+    let mut data = load_data(app)?;
+    data.remove_board(&board_id, confirmed)?;
+    save_data(app, &data)?;
+    Ok(data.summaries())
+}
+
+fn column_rank(column: &TaskColumn) -> u8 {
+    match column {
+        TaskColumn::Todo => 0,
+        TaskColumn::InProgress => 1,
+        TaskColumn::Done => 2,
+    }
+}
+
+/// Sort tasks by column and position, then renumber each column from 0.
+///
+/// Ties (e.g. boards saved without positions, where every task is 0) fall back to creation
+/// time, so this also migrates old data.
+fn normalize_positions(board: &mut KanbanBoard) {
+    board.tasks.sort_by(|a, b| {
+        column_rank(&a.column)
+            .cmp(&column_rank(&b.column))
+            .then(a.position.cmp(&b.position))
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+
+    let mut next_position = [0u32; 3];
+    for task in &mut board.tasks {
+        let slot = &mut next_position[column_rank(&task.column) as usize];
+        task.position = *slot;
+        *slot += 1;
+    }
+}
+
+fn next_position(board: &KanbanBoard, column: &TaskColumn) -> u32 {
+    board
+        .tasks
+        .iter()
+        .filter(|t| t.column == *column)
+        .map(|t| t.position + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Move a task to `new_index` within `column` (which may differ from its current column)
+fn reorder_in_board(
+    board: &mut KanbanBoard,
+    task_id: &str,
+    column: TaskColumn,
+    new_index: usize,
+) -> Result<(), String> {
+    let index = board
+        .tasks
+        .iter()
+        .position(|t| t.id == task_id)
+        .ok_or_else(|| format!("Task not found: {}", task_id))?;
+    let mut task = board.tasks.remove(index);
+
+    let mut column_tasks: Vec<Task> = Vec::new();
+    let mut other_tasks: Vec<Task> = Vec::new();
+    for t in board.tasks.drain(..) {
+        if t.column == column {
+            column_tasks.push(t);
+        } else {
+            other_tasks.push(t);
+        }
+    }
+
+    if task.column != column {
+        task.column = column;
+    }
+    task.updated_at = chrono::Utc::now().to_rfc3339();
+    column_tasks.insert(new_index.min(column_tasks.len()), task);
+    for (position, t) in column_tasks.iter_mut().enumerate() {
+        t.position = position as u32;
+    }
+
+    board.tasks = other_tasks;
+    board.tasks.extend(column_tasks);
+    normalize_positions(board);
+    Ok(())
 }
 
 pub fn create_task(
     app: &AppHandle,
+    board_id: Option<String>,
     title: String,
     description: Option<String>,
     priority: TaskPriority,
     assignee: Option<String>,
     due_date: Option<String>,
 ) -> Result<Task, String> {
-    let mut board = load_board(app)?;
+    with_board(app, board_id.as_deref(), |board| {
+        let now = chrono::Utc::now().to_rfc3339();
+        let task = Task {
+            id: uuid::Uuid::new_v4().to_string(),
+            title,
+            description,
+            column: TaskColumn::Todo,
+            priority,
+            assignee,
+            due_date,
+            created_at: now.clone(),
+            updated_at: now,
+            position: next_position(board, &TaskColumn::Todo),
+            subtasks: Vec::new(),
+            subtask_progress: None,
+        };
 
-    let now = chrono::Utc::now().to_rfc3339();
-    let task = Task {
-        id: uuid::Uuid::new_v4().to_string(),
-        title,
-        description,
-        column: TaskColumn::Todo,
-        priority,
-        assignee,
-        due_date,
-        created_at: now.clone(),
-        updated_at: now,
-    };
-
-    board.tasks.push(task.clone());
-    save_board(app, &board)?;
-
-    Ok(task)
+        board.tasks.push(task.clone());
+        Ok(task)
+    })
 }
 
 #[allow(clippy::too_many_arguments)]
 pub fn update_task(
     app: &AppHandle,
+    board_id: Option<String>,
     task_id: String,
     title: Option<String>,
     description: Option<String>,
@@ -137,53 +590,676 @@ pub fn update_task(
     assignee: Option<String>,
     due_date: Option<String>,
 ) -> Result<Task, String> {
-    let mut board = load_board(app)?;
+    with_board(app, board_id.as_deref(), |board| {
+        let task = board
+            .tasks
+            .iter_mut()
+            .find(|t| t.id == task_id)
+            .ok_or_else(|| format!("Task not found: {}", task_id))?;
 
-    let task = board
-        .tasks
-        .iter_mut()
-        .find(|t| t.id == task_id)
-        .ok_or_else(|| format!("Task not found: {}", task_id))?;
+        if let Some(t) = title {
+            task.title = t;
+        }
+        if let Some(d) = description {
+            task.description = Some(d);
+        }
+        if let Some(c) = column {
+            if task.column != c {
+                task.column = c;
+                // Moving to another column appends to the end of it
+                task.position = u32::MAX;
+            }
+        }
+        if let Some(p) = priority {
+            task.priority = p;
+        }
+        if assignee.is_some() {
+            task.assignee = assignee;
+        }
+        if due_date.is_some() {
+            task.due_date = due_date;
+        }
+        task.updated_at = chrono::Utc::now().to_rfc3339();
+
+        normalize_positions(board);
+        let updated_task = board
+            .tasks
+            .iter()
+            .find(|t| t.id == task_id)
+            .cloned()
+            .ok_or_else(|| format!("Task not found: {}", task_id))?;
+        Ok(updated_task)
+    })
+}
 
-    if let Some(t) = title {
-        task.title = t;
+pub fn delete_task(
+    app: &AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+) -> Result<(), String> {
+    with_board(app, board_id.as_deref(), |board| {
+        let initial_len = board.tasks.len();
+        board.tasks.retain(|t| t.id != task_id);
+
+        if board.tasks.len() == initial_len {
+            return Err(format!("Task not found: {}", task_id));
+        }
+
+        normalize_positions(board);
+        Ok(())
+    })
+}
+
+pub fn move_task(
+    app: &AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    column: TaskColumn,
+) -> Result<Task, String> {
+    update_task(
+        app,
+        board_id,
+        task_id,
+        None,
+        None,
+        Some(column),
+        None,
+        None,
+        None,
+    )
+}
+
+/// Reorder a task within a column or move it to a position in another column
+pub fn reorder_task(
+    app: &AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    column: TaskColumn,
+    new_index: usize,
+) -> Result<KanbanBoard, String> {
+    with_board(app, board_id.as_deref(), |board| {
+        reorder_in_board(board, &task_id, column, new_index)?;
+        Ok(board.clone())
+    })
+}
+
+/// Load the board, apply `f` to one task, and save it again
+fn modify_task<F>(
+    app: &AppHandle,
+    board_id: Option<&str>,
+    task_id: &str,
+    f: F,
+) -> Result<Task, String>
+where
+    F: FnOnce(&mut Task) -> Result<(), String>,
+{
+    with_board(app, board_id, |board| {
+        let task = board
+            .tasks
+            .iter_mut()
+            .find(|t| t.id == task_id)
+            .ok_or_else(|| format!("Task not found: {}", task_id))?;
+
+        f(task)?;
+        task.updated_at = chrono::Utc::now().to_rfc3339();
+        task.refresh_progress();
+        Ok(task.clone())
+    })
+}
+
+fn push_subtask(task: &mut Task, title: &str) -> Result<(), String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("Subtask title cannot be empty".to_string());
     }
-    if let Some(d) = description {
-        task.description = Some(d);
+    task.subtasks.push(Subtask {
+        id: uuid::Uuid::new_v4().to_string(),
+        title: title.to_string(),
+        done: false,
+    });
+    Ok(())
+}
+
+fn find_subtask<'a>(task: &'a mut Task, subtask_id: &str) -> Result<&'a mut Subtask, String> {
+    task.subtasks
+        .iter_mut()
+        .find(|s| s.id == subtask_id)
+        .ok_or_else(|| format!("Subtask not found: {}", subtask_id))
+}
+
+pub fn add_subtask(
+    app: &AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    title: String,
+) -> Result<Task, String> {
+    modify_task(app, board_id.as_deref(), &task_id, |task| {
+        push_subtask(task, &title)
+    })
+}
+
+pub fn toggle_subtask(
+    app: &AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    subtask_id: String,
+) -> Result<Task, String> {
+    modify_task(app, board_id.as_deref(), &task_id, |task| {
+        let subtask = find_subtask(task, &subtask_id)?;
+        subtask.done = !subtask.done;
+        Ok(())
+    })
+}
+
+pub fn delete_subtask(
+    app: &AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    subtask_id: String,
+) -> Result<Task, String> {
+    modify_task(app, board_id.as_deref(), &task_id, |task| {
+        find_subtask(task, &subtask_id)?;
+        task.subtasks.retain(|s| s.id != subtask_id);
+        Ok(())
+    })
+}
+
+/// Write the board to `path` as JSON (the full board) or CSV (one task per row)
+pub fn export_board(
+    app: &AppHandle,
+    board_id: Option<String>,
+    path: String,
+    format: BoardExportFormat,
+) -> Result<(), String> {
+    let data = load_data(app)?;
+    let board = &data.boards[data.board_index(board_id.as_deref())?];
+    let content = match format {
+        BoardExportFormat::Json => serde_json::to_string_pretty(board)
+            .map_err(|e| format!("Failed to serialize kanban data: {}", e))?,
+        BoardExportFormat::Csv => board_to_csv(board)?,
+    };
+    fs::write(&path, content).map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Import tasks from a JSON or CSV export (chosen by file extension).
+///
+/// With `merge` the tasks are appended to the board, and tasks whose ID already exists get
+/// a new one. Otherwise the board's tasks and columns are replaced, keeping its ID and name.
+pub fn import_board(
+    app: &AppHandle,
+    board_id: Option<String>,
+    path: String,
+    merge: bool,
+) -> Result<KanbanBoard, String> {
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let is_csv = std::path::Path::new(&path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    let imported = if is_csv {
+        KanbanBoard {
+            tasks: tasks_from_csv(&content)?,
+            ..Default::default()
+        }
+    } else {
+        board_from_json(&content)?
+    };
+
+    with_board(app, board_id.as_deref(), |board| {
+        if merge {
+            merge_tasks(board, imported.tasks);
+        } else {
+            board.tasks = imported.tasks;
+            board.columns = imported.columns;
+            normalize_positions(board);
+        }
+        Ok(board.clone())
+    })
+}
+
+fn board_to_csv(board: &KanbanBoard) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(CSV_HEADERS)
+        .map_err(|e| format!("Failed to write headers: {}", e))?;
+
+    for task in &board.tasks {
+        writer
+            .write_record([
+                task.title.as_str(),
+                task.description.as_deref().unwrap_or(""),
+                column_name(&task.column),
+                priority_name(&task.priority),
+                task.assignee.as_deref().unwrap_or(""),
+                task.due_date.as_deref().unwrap_or(""),
+            ])
+            .map_err(|e| format!("Failed to write row: {}", e))?;
     }
-    if let Some(c) = column {
-        task.column = c;
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to flush: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("Failed to encode CSV: {}", e))
+}
+
+fn board_from_json(content: &str) -> Result<KanbanBoard, String> {
+    serde_json::from_str(content).map_err(|e| {
+        format!(
+            "Invalid JSON at line {}, column {}: {}",
+            e.line(),
+            e.column(),
+            e
+        )
+    })
+}
+
+fn tasks_from_csv(content: &str) -> Result<Vec<Task>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("Failed to read CSV header at line 1: {}", e))?
+        .clone();
+    let index_of = |name: &str| headers.iter().position(|h| h.trim() == name);
+    let title_index =
+        index_of("title").ok_or("CSV header at line 1 must contain a \"title\" column")?;
+    let indices: Vec<Option<usize>> = CSV_HEADERS.iter().map(|h| index_of(h)).collect();
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let mut tasks = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| {
+            let line = e.position().map(|p| p.line()).unwrap_or_default();
+            format!("Invalid CSV at line {}: {}", line, e)
+        })?;
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+        let field = |i: usize| {
+            indices[i]
+                .and_then(|index| record.get(index))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        let title = record.get(title_index).unwrap_or("").trim();
+        if title.is_empty() {
+            return Err(format!("Missing title at line {}", line));
+        }
+        let column = match field(2) {
+            Some(value) => parse_column(&value)
+                .ok_or_else(|| format!("Unknown column \"{}\" at line {}", value, line))?,
+            None => TaskColumn::Todo,
+        };
+        let priority = match field(3) {
+            Some(value) => parse_priority(&value)
+                .ok_or_else(|| format!("Unknown priority \"{}\" at line {}", value, line))?,
+            None => TaskPriority::Medium,
+        };
+
+        tasks.push(Task {
+            id: uuid::Uuid::new_v4().to_string(),
+            title: title.to_string(),
+            description: field(1),
+            column,
+            priority,
+            assignee: field(4),
+            due_date: field(5),
+            created_at: now.clone(),
+            updated_at: now.clone(),
+            position: tasks.len() as u32,
+            subtasks: Vec::new(),
+            subtask_progress: None,
+        });
     }
-    if let Some(p) = priority {
-        task.priority = p;
+
+    Ok(tasks)
+}
+
+/// Append `tasks` to the end of their columns, re-issuing IDs that are already taken
+fn merge_tasks(board: &mut KanbanBoard, tasks: Vec<Task>) {
+    let mut tasks = tasks;
+    tasks.sort_by_key(|t| t.position);
+    for mut task in tasks {
+        if board.tasks.iter().any(|t| t.id == task.id) {
+            task.id = uuid::Uuid::new_v4().to_string();
+        }
+        task.position = next_position(board, &task.column);
+        board.tasks.push(task);
     }
-    if assignee.is_some() {
-        task.assignee = assignee;
+    normalize_positions(board);
+}
+
+fn column_name(column: &TaskColumn) -> &'static str {
+    match column {
+        TaskColumn::Todo => "Todo",
+        TaskColumn::InProgress => "InProgress",
+        TaskColumn::Done => "Done",
     }
-    if due_date.is_some() {
-        task.due_date = due_date;
+}
+
+fn parse_column(value: &str) -> Option<TaskColumn> {
+    match value.to_lowercase().replace([' ', '_', '-'], "").as_str() {
+        "todo" => Some(TaskColumn::Todo),
+        "inprogress" => Some(TaskColumn::InProgress),
+        "done" => Some(TaskColumn::Done),
+        _ => None,
     }
-    task.updated_at = chrono::Utc::now().to_rfc3339();
+}
 
-    let updated_task = task.clone();
-    save_board(app, &board)?;
+fn priority_name(priority: &TaskPriority) -> &'static str {
+    match priority {
+        TaskPriority::Low => "Low",
+        TaskPriority::Medium => "Medium",
+        TaskPriority::High => "High",
+        TaskPriority::Urgent => "Urgent",
+    }
+}
 
-    Ok(updated_task)
+fn parse_priority(value: &str) -> Option<TaskPriority> {
+    match value.to_lowercase().as_str() {
+        "low" => Some(TaskPriority::Low),
+        "medium" => Some(TaskPriority::Medium),
+        "high" => Some(TaskPriority::High),
+        "urgent" => Some(TaskPriority::Urgent),
+        _ => None,
+    }
 }
 
-pub fn delete_task(app: &AppHandle, task_id: String) -> Result<(), String> {
-    let mut board = load_board(app)?;
-    let initial_len = board.tasks.len();
-    board.tasks.retain(|t| t.id != task_id);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    if board.tasks.len() == initial_len {
-        return Err(format!("Task not found: {}", task_id));
+    fn task(id: &str, column: TaskColumn, position: u32, created_at: &str) -> Task {
+        Task {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: None,
+            column,
+            priority: TaskPriority::Medium,
+            assignee: None,
+            due_date: None,
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+            position,
+            subtasks: Vec::new(),
+            subtask_progress: None,
+        }
     }
 
-    save_board(app, &board)?;
-    Ok(())
-}
+    fn ids(board: &KanbanBoard, column: TaskColumn) -> Vec<(String, u32)> {
+        board
+            .tasks
+            .iter()
+            .filter(|t| t.column == column)
+            .map(|t| (t.id.clone(), t.position))
+            .collect()
+    }
+
+    #[test]
+    fn test_legacy_board_without_positions_is_migrated() {
+        let json = r#"{
+            "tasks": [
+                {"id": "b", "title": "b", "description": null, "column": "Todo", "priority": "Low",
+                 "assignee": null, "due_date": null,
+                 "created_at": "2024-01-02T00:00:00Z", "updated_at": "2024-01-02T00:00:00Z"},
+                {"id": "done", "title": "d", "description": null, "column": "Done", "priority": "Low",
+                 "assignee": null, "due_date": null,
+                 "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z"},
+                {"id": "a", "title": "a", "description": null, "column": "Todo", "priority": "Low",
+                 "assignee": null, "due_date": null,
+                 "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z"}
+            ],
+            "columns": []
+        }"#;
+        let mut board: KanbanBoard = serde_json::from_str(json).unwrap();
+        normalize_positions(&mut board);
+
+        assert_eq!(
+            ids(&board, TaskColumn::Todo),
+            vec![("a".to_string(), 0), ("b".to_string(), 1)]
+        );
+        assert_eq!(board.tasks.last().unwrap().id, "done");
+    }
+
+    #[test]
+    fn test_reorder_within_column() {
+        let mut board = KanbanBoard {
+            tasks: vec![
+                task("a", TaskColumn::Todo, 0, "1"),
+                task("b", TaskColumn::Todo, 1, "2"),
+                task("c", TaskColumn::Todo, 2, "3"),
+            ],
+            ..Default::default()
+        };
+
+        reorder_in_board(&mut board, "c", TaskColumn::Todo, 0).unwrap();
+        assert_eq!(
+            ids(&board, TaskColumn::Todo),
+            vec![
+                ("c".to_string(), 0),
+                ("a".to_string(), 1),
+                ("b".to_string(), 2)
+            ]
+        );
+
+        reorder_in_board(&mut board, "c", TaskColumn::Todo, 99).unwrap();
+        assert_eq!(board.tasks.last().unwrap().id, "c");
+        assert_eq!(board.tasks.last().unwrap().position, 2);
+    }
+
+    #[test]
+    fn test_reorder_across_columns_renumbers_both() {
+        let mut board = KanbanBoard {
+            tasks: vec![
+                task("a", TaskColumn::Todo, 0, "1"),
+                task("b", TaskColumn::Todo, 1, "2"),
+                task("x", TaskColumn::Done, 0, "3"),
+            ],
+            ..Default::default()
+        };
+
+        reorder_in_board(&mut board, "a", TaskColumn::Done, 0).unwrap();
+
+        assert_eq!(ids(&board, TaskColumn::Todo), vec![("b".to_string(), 0)]);
+        assert_eq!(
+            ids(&board, TaskColumn::Done),
+            vec![("a".to_string(), 0), ("x".to_string(), 1)]
+        );
+        assert!(reorder_in_board(&mut board, "missing", TaskColumn::Todo, 0).is_err());
+    }
+
+    #[test]
+    fn test_subtasks_and_progress() {
+        let mut t = task("a", TaskColumn::Todo, 0, "1");
+        t.refresh_progress();
+        assert_eq!(t.subtask_progress, None);
 
-pub fn move_task(app: &AppHandle, task_id: String, column: TaskColumn) -> Result<Task, String> {
-    update_task(app, task_id, None, None, Some(column), None, None, None)
+        push_subtask(&mut t, "  first ").unwrap();
+        push_subtask(&mut t, "second").unwrap();
+        assert!(push_subtask(&mut t, "   ").is_err());
+        assert_eq!(t.subtasks[0].title, "first");
+
+        let id = t.subtasks[0].id.clone();
+        find_subtask(&mut t, &id).unwrap().done = true;
+        t.refresh_progress();
+        assert_eq!(t.subtask_progress, Some(0.5));
+        assert!(find_subtask(&mut t, "missing").is_err());
+    }
+
+    #[test]
+    fn test_task_without_subtasks_field_deserializes() {
+        let json = r#"{"id": "a", "title": "a", "description": null, "column": "Todo",
+            "priority": "Low", "assignee": null, "due_date": null,
+            "created_at": "1", "updated_at": "1", "subtask_progress": 1.0}"#;
+        let t: Task = serde_json::from_str(json).unwrap();
+        assert!(t.subtasks.is_empty());
+        assert_eq!(t.subtask_progress, None);
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let mut source = task("a", TaskColumn::InProgress, 0, "1");
+        source.title = "Write, \"quoted\" docs".to_string();
+        source.description = Some("line 1\nline 2".to_string());
+        source.priority = TaskPriority::Urgent;
+        source.due_date = Some("2024-05-01".to_string());
+        let board = KanbanBoard {
+            tasks: vec![source],
+            ..Default::default()
+        };
+
+        let csv = board_to_csv(&board).unwrap();
+        assert!(csv.starts_with("title,description,column,priority,assignee,due_date\n"));
+
+        let tasks = tasks_from_csv(&csv).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Write, \"quoted\" docs");
+        assert_eq!(tasks[0].description.as_deref(), Some("line 1\nline 2"));
+        assert_eq!(tasks[0].column, TaskColumn::InProgress);
+        assert_eq!(tasks[0].priority, TaskPriority::Urgent);
+        assert_eq!(tasks[0].assignee, None);
+        assert_eq!(tasks[0].due_date.as_deref(), Some("2024-05-01"));
+        assert_ne!(tasks[0].id, "a");
+    }
+
+    #[test]
+    fn test_invalid_imports_report_line() {
+        let err = tasks_from_csv("title,column\nok,Todo\nbad,Someday\n").unwrap_err();
+        assert!(err.contains("line 3"), "{}", err);
+
+        let err = tasks_from_csv("title,priority\n,Low\n").unwrap_err();
+        assert!(err.contains("line 2"), "{}", err);
+
+        assert!(tasks_from_csv("name\nfoo\n").is_err());
+
+        let err = board_from_json("{\n  \"tasks\": [\n    {\"id\": 1}\n  ]\n}").unwrap_err();
+        assert!(err.contains("line 3"), "{}", err);
+    }
+
+    #[test]
+    fn test_merge_reissues_conflicting_ids() {
+        let mut board = KanbanBoard {
+            tasks: vec![
+                task("a", TaskColumn::Todo, 0, "1"),
+                task("b", TaskColumn::Todo, 1, "2"),
+            ],
+            ..Default::default()
+        };
+
+        merge_tasks(
+            &mut board,
+            vec![
+                task("a", TaskColumn::Todo, 0, "3"),
+                task("c", TaskColumn::Done, 0, "4"),
+            ],
+        );
+
+        assert_eq!(board.tasks.len(), 4);
+        let todo = ids(&board, TaskColumn::Todo);
+        assert_eq!(todo[0], ("a".to_string(), 0));
+        assert_eq!(todo[1], ("b".to_string(), 1));
+        assert_ne!(todo[2].0, "a");
+        assert_eq!(todo[2].1, 2);
+        assert_eq!(ids(&board, TaskColumn::Done), vec![("c".to_string(), 0)]);
+    }
+
+    #[test]
+    fn test_single_board_file_is_migrated_to_default_board() {
+        let json = r#"{
+            "tasks": [
+                {"id": "a", "title": "a", "description": null, "column": "Todo", "priority": "Low",
+                 "assignee": null, "due_date": null,
+                 "created_at": "1", "updated_at": "1"}
+            ],
+            "columns": []
+        }"#;
+        let (data, migrated) = parse_data(json).unwrap();
+        assert!(migrated);
+        assert_eq!(data.boards.len(), 1);
+        assert_eq!(data.boards[0].name, "Default");
+        assert_eq!(data.boards[0].tasks[0].id, "a");
+        assert!(!data.boards[0].id.is_empty());
+        assert_eq!(
+            data.last_board_id.as_deref(),
+            Some(data.boards[0].id.as_str())
+        );
+
+        // Saving and parsing again does not migrate a second time
+        let saved = serde_json::to_string(&data).unwrap();
+        let (again, migrated) = parse_data(&saved).unwrap();
+        assert!(!migrated);
+        assert_eq!(again.boards[0].id, data.boards[0].id);
+
+        let (empty, migrated) = parse_data(r#"{"boards": []}"#).unwrap();
+        assert!(migrated);
+        assert_eq!(empty.boards.len(), 1);
+    }
+
+    #[test]
+    fn test_board_index_and_delete_confirmation() {
+        let mut work = new_board("Work");
+        work.tasks.push(task("a", TaskColumn::Todo, 0, "1"));
+        let personal = new_board("Personal");
+        let mut data = KanbanData {
+            last_board_id: Some(personal.id.clone()),
+            boards: vec![work.clone(), personal.clone()],
+        };
+
+        assert_eq!(data.board_index(None).unwrap(), 1);
+        assert_eq!(data.board_index(Some(&work.id)).unwrap(), 0);
+        assert!(data.board_index(Some("missing")).is_err());
+        assert!(data.summaries()[1].last_opened);
+
+        let err = data.remove_board(&work.id, false).unwrap_err();
+        assert!(err.contains("1 task"), "{}", err);
+        data.remove_board(&personal.id, false).unwrap();
+        assert_eq!(data.last_board_id.as_deref(), Some(work.id.as_str()));
+        assert!(data.remove_board(&work.id, true).is_err());
+        assert_eq!(data.boards.len(), 1);
+
+        assert!(validate_board_name("  ").is_err());
+        assert_eq!(validate_board_name(" Side ").unwrap(), "Side");
+    }
+
+    #[test]
+    fn test_summarize_due_uses_local_dates_and_skips_done() {
+        let dated = |id: &str, column: TaskColumn, due: &str| {
+            let mut t = task(id, column, 0, "1");
+            t.due_date = Some(due.to_string());
+            t
+        };
+        let mut work = new_board("Work");
+        work.tasks = vec![
+            dated("late", TaskColumn::InProgress, "2024-04-30"),
+            dated("today", TaskColumn::Todo, "2024-05-01"),
+            dated("done", TaskColumn::Done, "2024-04-01"),
+            dated("bad", TaskColumn::Todo, "someday"),
+        ];
+        let mut personal = new_board("Personal");
+        personal.tasks = vec![
+            dated("sunday", TaskColumn::Todo, "2024-05-07"),
+            dated("later", TaskColumn::Todo, "2024-05-08"),
+            dated("older", TaskColumn::Todo, "2024-04-01"),
+            task("undated", TaskColumn::Todo, 1, "1"),
+        ];
+        let data = KanbanData {
+            boards: vec![work, personal],
+            last_board_id: None,
+        };
+
+        let today = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let summary = summarize_due(&data, today);
+        let ids = |tasks: &[Task]| tasks.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&summary.overdue), vec!["older", "late"]);
+        assert_eq!(ids(&summary.due_today), vec!["today"]);
+        assert_eq!(ids(&summary.due_this_week), vec!["sunday"]);
+        assert_eq!(
+            summary.counts(),
+            DueCounts {
+                overdue: 2,
+                due_today: 1,
+                due_this_week: 1
+            }
+        );
+    }
 }
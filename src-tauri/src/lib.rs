@@ -1,80 +1,253 @@
 mod base64_encoder;
+mod business_days;
+mod case_converter;
 mod char_counter;
+mod chmod_calculator;
+mod clipboard_history;
+mod color_converter;
+mod config_converter;
+mod cron_parser;
+mod csv_converter;
 mod csv_viewer;
+mod disk_analyzer;
+mod dummy_data_generator;
+mod duplicate_finder;
+mod encoding_converter;
+mod env_converter;
+mod escape_converter;
+mod exif_tools;
+mod file_renamer;
+mod generation_history;
+mod hash_generator;
+mod http_client;
 mod image_compressor;
 mod image_editor;
 mod input_history;
+mod japanese_text_converter;
 mod json_formatter;
+mod json_store;
+mod jwt_decoder;
 mod kanban;
+mod markdown_table;
 mod markdown_to_pdf;
+mod network_tools;
+mod ocr;
 mod password_generator;
 mod pdf_tools;
+mod pomodoro;
+mod process_tools;
+mod qr_generator;
+mod qr_reader;
+mod radix_converter;
 mod regex_tester;
+mod reminders;
 mod scratch_pad;
+mod settings;
+mod sql_formatter;
+mod system_info;
+#[cfg(test)]
+mod test_support;
 mod text_diff;
+mod text_tools;
+mod type_generator;
 mod unit_converter;
 mod unix_time_converter;
+mod url_encoder;
+mod usage_tracker;
 mod uuid_generator;
+mod window_state;
+mod workflows;
 
 use base64_encoder::{
-    decode_base64, decode_base64_image, encode_base64, encode_image_to_base64,
-    Base64DecodeImageResult, Base64DecodeResult, Base64EncodeResult, Base64ImageResult,
+    convert_encoding, decode_base64, decode_base64_file_to_file, decode_base64_image,
+    decode_base64_to_file, encode_base64, encode_file_to_base64, encode_file_to_base64_file,
+    encode_image_to_base64, Base64DecodeImageResult, Base64DecodeResult, Base64EncodeResult,
+    Base64FileResult, Base64ImageResult, Base64JobFinished, Base64JobProgress, Base64JobStore,
+    Base64StreamProgress, Base64StreamResult, DecodeFileInfo, EncodingConvertResult, TextEncoding,
+};
+use business_days::{
+    add_business_days, count_business_days, list_holidays, BusinessDayResult, Holiday,
+    HolidayCountry,
+};
+use case_converter::{
+    convert_all_cases, convert_case, CaseConversion, CaseConvertResult, CaseStyle,
 };
 use char_counter::{count_chars, CharCountResult};
-use csv_viewer::{get_csv_info, read_csv, save_csv, CsvData, CsvInfo};
+use chmod_calculator::{
+    apply_symbolic_change, list_permission_presets, parse_permission, PermissionPreset,
+    PermissionResult,
+};
+use clipboard_history::{
+    clear_clipboard_history, copy_sensitive_text, delete_clip_entry, get_clipboard_history,
+    get_clipboard_settings, pin_clip_entry, start_clipboard_monitor, stop_clipboard_monitor,
+    update_clipboard_settings, ClipEntry, ClipboardSettings, ClipboardStore,
+};
+use color_converter::{
+    convert_color, generate_palette, ColorConvertResult, PaletteKind, PaletteResult,
+};
+use config_converter::{convert_config, ConfigConvertResult, ConfigFormat};
+use cron_parser::{parse_cron, CronParseResult};
+use csv_converter::{csv_to_json, export_csv_to_json, json_to_csv, CsvToJsonOptions};
+use csv_viewer::{
+    analyze_csv_columns, analyze_csv_columns_with, get_csv_info, query_csv, read_csv,
+    read_csv_chunk, save_csv, update_csv_cells, CellEdit, CsvChunk, CsvColumnStats, CsvData,
+    CsvEditResult, CsvInfo, CsvQueryOptions, CsvStatsOptions,
+};
+use disk_analyzer::{analyze_directory, DirSizeResult};
+use dummy_data_generator::{
+    generate_dummy_records, generate_lorem, DummyDataResult, DummyFieldSpec, DummyOutputFormat,
+    LoremResult,
+};
+use duplicate_finder::{
+    delete_duplicate_files, find_duplicates, DeleteDuplicatesResult, DuplicateFindOptions,
+    DuplicateFindResult,
+};
+use encoding_converter::{
+    convert_file_encoding, detect_encoding, EncodingConvertFileResult, EncodingConvertOptions,
+    EncodingDetectResult,
+};
+use env_converter::{
+    config_to_env, convert_env, diff_env, parse_env_file, EnvConvertResult, EnvDiffResult,
+    EnvParseResult, EnvTargetFormat,
+};
+use escape_converter::{escape_text, unescape_text, EscapeMode, EscapeResult};
+use exif_tools::{read_exif, strip_exif, ExifResult};
+use file_renamer::{
+    apply_rename, preview_rename, RenameApplyResult, RenamePair, RenamePreviewResult, RenameRule,
+};
+use generation_history::{append_history, clear_history, get_history, GenerationEntry};
+use hash_generator::{
+    compare_hashes, compute_file_hashes, compute_text_hashes, generate_checksum_file,
+    verify_checksum_file, ChecksumAlgorithm, ChecksumFormat, ChecksumGenerateResult,
+    ChecksumVerifyResult, HashCompareResult, HashResult,
+};
+use http_client::{send_http_request, HttpRequestSpec, HttpResponseResult};
 use image_compressor::{
-    compress_image, get_image_info, CompressionOptions, CompressionResult, ImageInfo,
+    compress_image, compress_images_batch, compress_to_target_size, convert_image_format,
+    convert_images_batch, export_multiple_sizes, get_image_info, list_size_presets,
+    BatchCompressionResult, CompressionOptions, CompressionResult, ImageInfo, MultiExportOptions,
+    MultiExportResult, NamingPattern, SizePreset,
 };
 use image_editor::{
-    adjust_brightness, adjust_contrast, apply_filter, crop_image, flip_horizontal, flip_vertical,
-    get_editor_image_info, resize_image, rotate_image, EditResult, ImageEditorInfo, ImageFilter,
-    RotationAngle,
+    add_text_watermark, adjust_brightness, adjust_contrast, apply_filter, apply_operation,
+    close_edit_session, combine_images, crop_image, crop_image_with_aspect, export_session,
+    flip_horizontal, flip_vertical, get_editor_image_info, get_session_state, redo_operation,
+    resize_image, rotate_image, start_edit_session, undo_operation, AspectRatio, CombineLayout,
+    CombineOptions, CropAnchor, CropBounds, EditOperation, EditResult, EditSessionState,
+    EditSessionStore, ImageEditorInfo, ImageFilter, RotationAngle, SessionId, WatermarkOptions,
 };
 use input_history::{
     add_history_entry, clear_tool_history, delete_history_entry, get_tool_history,
     get_tool_history_settings, search_tool_history, update_tool_history_settings, HistoryEntry,
     ToolHistorySettings,
 };
+use japanese_text_converter::{convert_japanese_text, JpConversion, JpConvertResult};
 use json_formatter::{
-    format_json, minify_json, parse_to_tree, search_json, validate_json, JsonFormatResult,
-    JsonMinifyResult, JsonParseResult, JsonSearchResult, JsonValidateResult,
+    diff_json, format_json, get_json_tree_children, minify_json, parse_json_tree_root, query_json,
+    release_json_tree, search_json, validate_json, validate_json_with_schema, JsonDiffOptions,
+    JsonDiffResult, JsonFormatResult, JsonMinifyResult, JsonQueryResult, JsonQueryType,
+    JsonSchemaValidateResult, JsonSearchResult, JsonTreeNodeSummary, JsonTreeRoot, JsonTreeStore,
+    JsonValidateResult,
 };
+use jwt_decoder::{decode_jwt, verify_jwt, JwtDecodeResult, JwtVerifyResult};
 use kanban::{
-    create_task, delete_task, load_board, move_task, update_task, KanbanBoard, Task, TaskColumn,
-    TaskPriority,
+    add_subtask, create_board, create_task, delete_board, delete_subtask, delete_task,
+    export_board, get_due_summary, import_board, list_boards, load_board, move_task, rename_board,
+    reorder_task, start_due_checker, toggle_subtask, update_task, BoardExportFormat, BoardSummary,
+    DueSummary, KanbanBoard, Task, TaskColumn, TaskPriority,
+};
+use markdown_table::{
+    csv_to_markdown_table, edit_markdown_table, format_markdown_table, markdown_table_to_csv,
+    ColumnAlign, TableFormatResult, TableOperation,
 };
 use markdown_to_pdf::{
-    convert_markdown_to_pdf, markdown_to_html, read_markdown, MarkdownInfo, MarkdownToHtmlResult,
-    MarkdownToPdfResult,
+    check_markdown_links, convert_markdown_to_pdf, markdown_to_html, read_markdown, CodeTheme,
+    LinkCheckResult, MarkdownInfo, MarkdownRenderOptions, MarkdownToHtmlResult,
+    MarkdownToPdfResult, PdfPageOptions,
+};
+use network_tools::{
+    analyze_cidr, check_port_async, parse_ip, CidrResult, IpInfo, PortCheckResult,
 };
+use ocr::{extract_text_from_image, OcrLanguage, OcrResult};
 use password_generator::{
-    generate_passphrases, generate_passwords, PassphraseOptions, PasswordGenerateResult,
-    PasswordOptions,
+    evaluate_password_strength, generate_passphrases, generate_passwords, PassphraseOptions,
+    PasswordGenerateResult, PasswordOptions, PasswordStrengthResult,
 };
 use pdf_tools::{
-    get_pdf_info, merge_pdfs, split_pdf_by_pages, split_pdf_by_range, PdfInfo, PdfMergeResult,
-    PdfSplitResult,
+    compress_pdf, decrypt_pdf, encrypt_pdf, export_pdf_text, extract_pdf_text, get_pdf_info,
+    merge_pdfs, render_pdf_page_thumbnail, render_pdf_thumbnails, rotate_pdf_pages,
+    split_pdf_by_pages, split_pdf_by_range, PdfCompressResult, PdfCompressionLevel,
+    PdfDecryptResult, PdfEncryptResult, PdfInfo, PdfMergeResult, PdfRotateResult, PdfSplitResult,
+    PdfTextResult, PdfThumbnailResult, PdfThumbnailsResult,
+};
+use pomodoro::{
+    get_pomodoro_state, get_pomodoro_stats, pause_pomodoro, start_pomodoro, stop_pomodoro,
+    DailyPomodoroStats, DateRange, PomodoroState, PomodoroStore,
+};
+use process_tools::{find_process_by_port, kill_process, list_processes, ProcessInfo, ProcessSort};
+use qr_generator::{
+    build_vcard_content, build_wifi_content, generate_qr, save_qr, QrGenerateResult, QrOptions,
+    VCardInfo, WifiConfig,
+};
+use qr_reader::{decode_qr_from_image, QrDecodeResult};
+use radix_converter::{
+    bits_to_float, convert_radix, convert_radix_with_precision, float_to_bits, FloatBitsResult,
+    FloatPrecision, RadixConvertResult,
+};
+use regex_tester::{
+    delete_regex_preset, generate_regex_samples, list_regex_presets, replace_regex_with_timeout,
+    save_regex_preset, test_regex_with_timeout, RegexFlags, RegexPreset, RegexResult,
+    RegexSampleResult, ReplaceResult,
+};
+use reminders::{
+    cancel_reminder, create_reminder, init_reminders, list_reminders, Reminder, ReminderStore,
 };
-use regex_tester::{replace_regex, test_regex, RegexFlags, RegexResult, ReplaceResult};
 use scratch_pad::{
-    create_note, delete_note, export_to_file, load_scratch_pad, set_active_note, update_note, Note,
-    ScratchPadData,
+    create_note, delete_note, export_all_notes, export_note_as, export_to_file, list_note_history,
+    load_scratch_pad, restore_note_version, search_notes, set_active_note, update_note, Note,
+    NoteExportFormat, NoteHistoryEntry, NoteSearchResult, ScratchPadData,
+};
+use settings::{load_settings, reset_settings, save_settings, theme_name, AppSettings};
+use sql_formatter::{format_sql, SqlFormatOptions, SqlFormatResult};
+use system_info::{get_system_info, SystemInfoResult, SystemMonitorStore};
+use text_diff::{
+    compare_directories, compute_diff_with, compute_file_diff, compute_three_way_diff, export_diff,
+    get_file_info, DiffExportFormat, DiffExportResult, DiffMode, DiffOptions, DiffResult,
+    DirCompareOptions, DirCompareResult, FileInfo, ThreeWayDiffResult,
 };
-use text_diff::{compute_diff, get_file_info, DiffMode, DiffResult, FileInfo};
+use text_tools::{process_lines, LineOperation, LineProcessResult};
+use type_generator::{generate_types, TypeGenOptions, TypeGenResult, TypeTarget};
 use unit_converter::{
-    convert_area, convert_data_size, convert_length, convert_temperature, convert_time,
-    convert_volume, convert_weight, AreaUnit, ConversionResult, DataSizeUnit, LengthUnit,
-    TemperatureUnit, TimeUnit, VolumeUnit, WeightUnit,
+    convert_area, convert_currency, convert_data_size, convert_energy, convert_fuel_economy,
+    convert_length, convert_pressure, convert_speed, convert_temperature, convert_time,
+    convert_to_all, convert_volume, convert_weight, load_currency_rates, update_currency_rates,
+    AreaUnit, ConversionResult, CurrencyRatesResult, CurrencyUnit, DataSizeUnit, EnergyUnit,
+    FuelEconomyUnit, LengthUnit, MultiConversionResult, PressureUnit, SpeedUnit, TemperatureUnit,
+    TimeUnit, UnitCategory, VolumeUnit, WeightUnit,
 };
 use unix_time_converter::{
-    datetime_to_unix, get_current_unix_time, unix_to_datetime, CurrentUnixTimeResult,
-    DateTimeToUnixResult, TimestampUnit, TimezoneOption, UnixToDateTimeResult,
+    add_duration, batch_convert_timestamps, datetime_to_unix, diff_datetimes,
+    get_current_unix_time, list_timezones, unix_to_datetime, BatchConvertResult,
+    CurrentUnixTimeResult, DateTimeToUnixResult, DurationResult, TimestampUnit, TimezoneInfo,
+    TimezoneOption, UnixToDateTimeResult,
 };
+use url_encoder::{
+    decode_url, encode_url, parse_query_string, QueryParseResult, UrlDecodeResult, UrlEncodeResult,
+};
+use usage_tracker::{get_tool_usage, record_tool_usage, ToolUsage, UsageStore};
 use uuid_generator::{
-    generate_uuids, validate_uuid, UuidFormat, UuidGenerateOptions, UuidGenerateResult,
-    UuidValidateResult, UuidVersion,
+    decode_ulid, generate_ids, generate_uuids, validate_uuid, validate_uuids_bulk,
+    IdGenerateOptions, IdGenerateResult, IdKind, UlidDecodeResult, UuidBulkValidateResult,
+    UuidFormat, UuidGenerateOptions, UuidGenerateResult, UuidValidateResult, UuidVersion,
+};
+use window_state::{get_last_tab, restore_window_state, save_last_tab, save_window_state};
+use workflows::{
+    delete_workflow, list_workflows, run_workflow, save_workflow, Workflow, WorkflowRunResult,
+    WorkflowStep,
 };
 
+use std::sync::atomic::AtomicBool;
+
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
@@ -99,442 +272,1882 @@ fn compress_image_cmd(
 }
 
 #[tauri::command]
-fn get_image_info_cmd(path: String) -> Result<ImageInfo, String> {
-    get_image_info(&path)
+async fn compress_to_target_size_cmd(
+    input_path: String,
+    output_path: String,
+    target_bytes: u64,
+    format: String,
+    min_quality: u8,
+    allow_resize: bool,
+) -> CompressionResult {
+    compress_to_target_size(
+        &input_path,
+        &output_path,
+        target_bytes,
+        &format,
+        min_quality,
+        allow_resize,
+    )
+}
+
+#[tauri::command]
+async fn compress_images_batch_cmd(
+    app: tauri::AppHandle,
+    inputs: Vec<String>,
+    output_dir: String,
+    options: CompressionOptions,
+) -> BatchCompressionResult {
+    compress_images_batch(&inputs, &output_dir, &options, |progress| {
+        let _ = app.emit("compression-progress", progress);
+    })
+}
+
+#[tauri::command]
+async fn convert_image_format_cmd(
+    input_path: String,
+    output_path: String,
+    format: String,
+    quality: Option<u8>,
+) -> CompressionResult {
+    convert_image_format(&input_path, &output_path, &format, quality)
+}
+
+#[tauri::command]
+async fn convert_images_batch_cmd(
+    app: tauri::AppHandle,
+    inputs: Vec<String>,
+    output_dir: String,
+    format: String,
+    quality: Option<u8>,
+) -> BatchCompressionResult {
+    convert_images_batch(&inputs, &output_dir, &format, quality, |progress| {
+        let _ = app.emit("compression-progress", progress);
+    })
+}
+
+#[tauri::command]
+async fn export_multiple_sizes_cmd(
+    input_path: String,
+    output_dir: String,
+    sizes: Vec<u32>,
+    format: String,
+    naming: NamingPattern,
+    options: Option<MultiExportOptions>,
+) -> MultiExportResult {
+    export_multiple_sizes(
+        &input_path,
+        &output_dir,
+        &sizes,
+        &format,
+        &naming,
+        &options.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+fn list_size_presets_cmd() -> Vec<SizePreset> {
+    list_size_presets()
+}
+
+#[tauri::command]
+fn get_image_info_cmd(path: String) -> Result<ImageInfo, String> {
+    get_image_info(&path)
+}
+
+#[tauri::command]
+fn read_csv_cmd(path: String) -> Result<CsvData, String> {
+    read_csv(&path)
+}
+
+#[tauri::command]
+async fn read_csv_chunk_cmd(path: String, offset: usize, limit: usize) -> Result<CsvChunk, String> {
+    read_csv_chunk(&path, offset, limit)
+}
+
+#[tauri::command]
+fn query_csv_cmd(path: String, options: CsvQueryOptions) -> Result<CsvData, String> {
+    query_csv(&path, options)
+}
+
+#[tauri::command]
+async fn get_csv_info_cmd(path: String) -> Result<CsvInfo, String> {
+    get_csv_info(&path)
+}
+
+#[tauri::command]
+async fn analyze_csv_columns_cmd(
+    path: String,
+    options: Option<CsvStatsOptions>,
+) -> Result<CsvColumnStats, String> {
+    match options {
+        Some(options) => analyze_csv_columns_with(&path, &options),
+        None => analyze_csv_columns(&path),
+    }
+}
+
+#[tauri::command]
+fn save_csv_cmd(path: String, headers: Vec<String>, rows: Vec<Vec<String>>) -> Result<(), String> {
+    save_csv(&path, &headers, &rows)
+}
+
+#[tauri::command]
+async fn update_csv_cells_cmd(path: String, edits: Vec<CellEdit>) -> Result<CsvEditResult, String> {
+    update_csv_cells(&path, edits)
+}
+
+#[tauri::command]
+fn get_pdf_info_cmd(path: String) -> Result<PdfInfo, String> {
+    get_pdf_info(&path)
+}
+
+#[tauri::command]
+fn split_pdf_by_pages_cmd(input_path: String, output_dir: String) -> PdfSplitResult {
+    split_pdf_by_pages(&input_path, &output_dir)
+}
+
+#[tauri::command]
+fn split_pdf_by_range_cmd(
+    input_path: String,
+    output_path: String,
+    start_page: u32,
+    end_page: u32,
+) -> PdfSplitResult {
+    split_pdf_by_range(&input_path, &output_path, start_page, end_page)
+}
+
+#[tauri::command]
+fn merge_pdfs_cmd(input_paths: Vec<String>, output_path: String) -> PdfMergeResult {
+    merge_pdfs(&input_paths, &output_path)
+}
+
+#[tauri::command]
+fn rotate_pdf_pages_cmd(
+    input_path: String,
+    output_path: String,
+    pages: Vec<u32>,
+    angle: u16,
+) -> PdfRotateResult {
+    rotate_pdf_pages(&input_path, &output_path, pages, angle)
+}
+
+#[tauri::command]
+fn extract_pdf_text_cmd(
+    input_path: String,
+    start_page: Option<u32>,
+    end_page: Option<u32>,
+) -> PdfTextResult {
+    extract_pdf_text(&input_path, start_page, end_page)
+}
+
+#[tauri::command]
+fn export_pdf_text_cmd(path: String, output_path: String) -> Result<(), String> {
+    export_pdf_text(&path, &output_path)
+}
+
+#[tauri::command]
+fn encrypt_pdf_cmd(
+    input_path: String,
+    output_path: String,
+    user_password: String,
+    owner_password: Option<String>,
+) -> PdfEncryptResult {
+    encrypt_pdf(&input_path, &output_path, user_password, owner_password)
+}
+
+#[tauri::command]
+fn decrypt_pdf_cmd(input_path: String, output_path: String, password: String) -> PdfDecryptResult {
+    decrypt_pdf(&input_path, &output_path, password)
+}
+
+#[tauri::command]
+async fn render_pdf_page_thumbnail_cmd(
+    input_path: String,
+    page: u32,
+    max_width: u32,
+) -> PdfThumbnailResult {
+    render_pdf_page_thumbnail(&input_path, page, max_width)
+}
+
+#[tauri::command]
+async fn render_pdf_thumbnails_cmd(
+    app: tauri::AppHandle,
+    input_path: String,
+    max_width: u32,
+) -> PdfThumbnailsResult {
+    render_pdf_thumbnails(&input_path, max_width, |progress| {
+        let _ = app.emit("pdf-thumbnail-progress", progress);
+    })
+}
+
+#[tauri::command]
+async fn compress_pdf_cmd(
+    input_path: String,
+    output_path: String,
+    level: PdfCompressionLevel,
+) -> PdfCompressResult {
+    compress_pdf(&input_path, &output_path, level)
+}
+
+#[tauri::command]
+fn read_markdown_cmd(path: String) -> Result<MarkdownInfo, String> {
+    read_markdown(&path)
+}
+
+#[tauri::command]
+async fn check_markdown_links_cmd(path: String, check_external: bool) -> LinkCheckResult {
+    check_markdown_links(&path, check_external).await
+}
+
+#[tauri::command]
+fn markdown_to_html_cmd(
+    markdown: String,
+    theme: Option<CodeTheme>,
+    options: Option<MarkdownRenderOptions>,
+) -> MarkdownToHtmlResult {
+    markdown_to_html(
+        &markdown,
+        theme.unwrap_or_default(),
+        options.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+fn format_markdown_table_cmd(input: String, align: Option<Vec<ColumnAlign>>) -> TableFormatResult {
+    format_markdown_table(&input, align)
+}
+
+#[tauri::command]
+fn edit_markdown_table_cmd(input: String, operation: TableOperation) -> TableFormatResult {
+    edit_markdown_table(&input, operation)
+}
+
+#[tauri::command]
+fn csv_to_markdown_table_cmd(csv_text: String) -> TableFormatResult {
+    csv_to_markdown_table(&csv_text)
+}
+
+#[tauri::command]
+fn markdown_table_to_csv_cmd(input: String) -> TableFormatResult {
+    markdown_table_to_csv(&input)
+}
+
+#[tauri::command]
+fn convert_markdown_to_pdf_cmd(
+    markdown: String,
+    output_path: String,
+    source_path: Option<String>,
+    theme: Option<CodeTheme>,
+    include_toc: Option<bool>,
+    options: Option<MarkdownRenderOptions>,
+    page_options: Option<PdfPageOptions>,
+) -> MarkdownToPdfResult {
+    convert_markdown_to_pdf(
+        &markdown,
+        &output_path,
+        source_path.as_deref(),
+        theme.unwrap_or_default(),
+        include_toc.unwrap_or(false),
+        options.unwrap_or_default(),
+        &page_options.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+fn load_kanban_board_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+) -> Result<KanbanBoard, String> {
+    load_board(&app, board_id)
+}
+
+#[tauri::command]
+fn list_boards_cmd(app: tauri::AppHandle) -> Result<Vec<BoardSummary>, String> {
+    list_boards(&app)
+}
+
+#[tauri::command]
+fn create_board_cmd(app: tauri::AppHandle, name: String) -> Result<BoardSummary, String> {
+    create_board(&app, name)
+}
+
+#[tauri::command]
+fn rename_board_cmd(
+    app: tauri::AppHandle,
+    board_id: String,
+    name: String,
+) -> Result<BoardSummary, String> {
+    rename_board(&app, board_id, name)
+}
+
+#[tauri::command]
+fn delete_board_cmd(
+    app: tauri::AppHandle,
+    board_id: String,
+    confirmed: Option<bool>,
+) -> Result<Vec<BoardSummary>, String> {
+    delete_board(&app, board_id, confirmed.unwrap_or(false))
+}
+
+#[tauri::command]
+fn create_task_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    title: String,
+    description: Option<String>,
+    priority: TaskPriority,
+    assignee: Option<String>,
+    due_date: Option<String>,
+) -> Result<Task, String> {
+    create_task(
+        &app,
+        board_id,
+        title,
+        description,
+        priority,
+        assignee,
+        due_date,
+    )
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+fn update_task_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    title: Option<String>,
+    description: Option<String>,
+    column: Option<TaskColumn>,
+    priority: Option<TaskPriority>,
+    assignee: Option<String>,
+    due_date: Option<String>,
+) -> Result<Task, String> {
+    update_task(
+        &app,
+        board_id,
+        task_id,
+        title,
+        description,
+        column,
+        priority,
+        assignee,
+        due_date,
+    )
+}
+
+#[tauri::command]
+fn delete_task_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+) -> Result<(), String> {
+    delete_task(&app, board_id, task_id)
+}
+
+#[tauri::command]
+fn move_task_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    column: TaskColumn,
+) -> Result<Task, String> {
+    move_task(&app, board_id, task_id, column)
+}
+
+#[tauri::command]
+fn reorder_task_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    column: TaskColumn,
+    new_index: usize,
+) -> Result<KanbanBoard, String> {
+    reorder_task(&app, board_id, task_id, column, new_index)
+}
+
+#[tauri::command]
+fn export_kanban_board_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    path: String,
+    format: BoardExportFormat,
+) -> Result<(), String> {
+    export_board(&app, board_id, path, format)
+}
+
+#[tauri::command]
+fn import_kanban_board_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    path: String,
+    merge: bool,
+) -> Result<KanbanBoard, String> {
+    import_board(&app, board_id, path, merge)
+}
+
+#[tauri::command]
+fn add_subtask_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    title: String,
+) -> Result<Task, String> {
+    add_subtask(&app, board_id, task_id, title)
+}
+
+#[tauri::command]
+fn toggle_subtask_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    subtask_id: String,
+) -> Result<Task, String> {
+    toggle_subtask(&app, board_id, task_id, subtask_id)
+}
+
+#[tauri::command]
+fn delete_subtask_cmd(
+    app: tauri::AppHandle,
+    board_id: Option<String>,
+    task_id: String,
+    subtask_id: String,
+) -> Result<Task, String> {
+    delete_subtask(&app, board_id, task_id, subtask_id)
+}
+
+#[tauri::command]
+fn get_due_summary_cmd(app: tauri::AppHandle) -> Result<DueSummary, String> {
+    get_due_summary(&app)
+}
+
+#[tauri::command]
+fn get_editor_image_info_cmd(path: String) -> Result<ImageEditorInfo, String> {
+    get_editor_image_info(&path)
+}
+
+#[tauri::command]
+fn resize_image_cmd(
+    input_path: String,
+    output_path: String,
+    width: u32,
+    height: u32,
+    maintain_aspect: bool,
+) -> EditResult {
+    resize_image(&input_path, &output_path, width, height, maintain_aspect)
+}
+
+#[tauri::command]
+fn rotate_image_cmd(input_path: String, output_path: String, angle: RotationAngle) -> EditResult {
+    rotate_image(&input_path, &output_path, angle)
+}
+
+#[tauri::command]
+fn crop_image_cmd(
+    input_path: String,
+    output_path: String,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    bounds: Option<CropBounds>,
+) -> EditResult {
+    crop_image(
+        &input_path,
+        &output_path,
+        x,
+        y,
+        width,
+        height,
+        bounds.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+fn crop_image_with_aspect_cmd(
+    input_path: String,
+    output_path: String,
+    aspect: AspectRatio,
+    anchor: CropAnchor,
+) -> EditResult {
+    crop_image_with_aspect(&input_path, &output_path, aspect, anchor)
+}
+
+#[tauri::command]
+fn adjust_brightness_cmd(input_path: String, output_path: String, value: i32) -> EditResult {
+    adjust_brightness(&input_path, &output_path, value)
+}
+
+#[tauri::command]
+fn adjust_contrast_cmd(input_path: String, output_path: String, value: f32) -> EditResult {
+    adjust_contrast(&input_path, &output_path, value)
+}
+
+#[tauri::command]
+fn apply_filter_cmd(input_path: String, output_path: String, filter: ImageFilter) -> EditResult {
+    apply_filter(&input_path, &output_path, filter)
+}
+
+#[tauri::command]
+fn flip_horizontal_cmd(input_path: String, output_path: String) -> EditResult {
+    flip_horizontal(&input_path, &output_path)
+}
+
+#[tauri::command]
+fn flip_vertical_cmd(input_path: String, output_path: String) -> EditResult {
+    flip_vertical(&input_path, &output_path)
+}
+
+#[tauri::command]
+fn add_text_watermark_cmd(
+    input_path: String,
+    output_path: String,
+    options: WatermarkOptions,
+) -> EditResult {
+    add_text_watermark(&input_path, &output_path, options)
+}
+
+#[tauri::command]
+async fn combine_images_cmd(
+    input_paths: Vec<String>,
+    output_path: String,
+    layout: CombineLayout,
+    options: CombineOptions,
+) -> EditResult {
+    combine_images(input_paths, output_path, layout, options)
+}
+
+#[tauri::command]
+fn start_edit_session_cmd(
+    store: tauri::State<'_, EditSessionStore>,
+    path: String,
+) -> Result<SessionId, String> {
+    start_edit_session(&store, &path)
+}
+
+#[tauri::command]
+fn apply_operation_cmd(
+    store: tauri::State<'_, EditSessionStore>,
+    session_id: String,
+    operation: EditOperation,
+) -> EditResult {
+    apply_operation(&store, &session_id, operation)
+}
+
+#[tauri::command]
+fn undo_operation_cmd(
+    store: tauri::State<'_, EditSessionStore>,
+    session_id: String,
+) -> Result<EditSessionState, String> {
+    undo_operation(&store, &session_id)
+}
+
+#[tauri::command]
+fn redo_operation_cmd(
+    store: tauri::State<'_, EditSessionStore>,
+    session_id: String,
+) -> Result<EditSessionState, String> {
+    redo_operation(&store, &session_id)
+}
+
+#[tauri::command]
+fn get_session_state_cmd(
+    store: tauri::State<'_, EditSessionStore>,
+    session_id: String,
+) -> Result<EditSessionState, String> {
+    get_session_state(&store, &session_id)
+}
+
+#[tauri::command]
+fn export_session_cmd(
+    store: tauri::State<'_, EditSessionStore>,
+    session_id: String,
+    output_path: String,
+) -> EditResult {
+    export_session(&store, &session_id, &output_path)
+}
+
+#[tauri::command]
+fn close_edit_session_cmd(
+    store: tauri::State<'_, EditSessionStore>,
+    session_id: String,
+) -> Result<(), String> {
+    close_edit_session(&store, &session_id)
+}
+
+#[tauri::command]
+fn save_workflow_cmd(
+    app: tauri::AppHandle,
+    name: String,
+    steps: Vec<WorkflowStep>,
+) -> Result<Workflow, String> {
+    save_workflow(&app, name, steps)
+}
+
+#[tauri::command]
+fn list_workflows_cmd(app: tauri::AppHandle) -> Vec<Workflow> {
+    list_workflows(&app)
+}
+
+#[tauri::command]
+fn delete_workflow_cmd(app: tauri::AppHandle, id: String) -> Result<(), String> {
+    delete_workflow(&app, id)
+}
+
+#[tauri::command]
+async fn run_workflow_cmd(
+    app: tauri::AppHandle,
+    workflow_id: String,
+    input_path: String,
+) -> WorkflowRunResult {
+    run_workflow(&app, &workflow_id, &input_path)
+}
+
+#[tauri::command]
+async fn read_exif_cmd(path: String) -> ExifResult {
+    read_exif(&path)
+}
+
+#[tauri::command]
+async fn strip_exif_cmd(
+    input_path: String,
+    output_path: String,
+    keep_orientation: bool,
+) -> EditResult {
+    strip_exif(&input_path, &output_path, keep_orientation)
+}
+
+#[tauri::command]
+fn generate_uuids_cmd(
+    version: UuidVersion,
+    format: UuidFormat,
+    count: u32,
+    namespace: Option<String>,
+    name: Option<String>,
+) -> UuidGenerateResult {
+    let options = UuidGenerateOptions {
+        version,
+        format,
+        count,
+        namespace,
+        name,
+    };
+    generate_uuids(options)
+}
+
+#[tauri::command]
+fn generate_ids_cmd(kind: IdKind, options: IdGenerateOptions) -> IdGenerateResult {
+    generate_ids(kind, options)
+}
+
+#[tauri::command]
+fn decode_ulid_cmd(input: String) -> UlidDecodeResult {
+    decode_ulid(&input)
+}
+
+#[tauri::command]
+fn validate_uuid_cmd(input: String) -> UuidValidateResult {
+    validate_uuid(&input)
+}
+
+#[tauri::command]
+fn validate_uuids_bulk_cmd(input: String) -> UuidBulkValidateResult {
+    validate_uuids_bulk(&input)
+}
+
+#[tauri::command]
+fn generate_lorem_cmd(paragraphs: u32, words_per_paragraph: u32, japanese: bool) -> LoremResult {
+    generate_lorem(paragraphs, words_per_paragraph, japanese)
+}
+
+#[tauri::command]
+async fn generate_dummy_records_cmd(
+    count: u32,
+    fields: Vec<DummyFieldSpec>,
+    format: DummyOutputFormat,
+    seed: Option<u64>,
+    table_name: Option<String>,
+) -> DummyDataResult {
+    generate_dummy_records(count, fields, format, seed, table_name.as_deref())
+}
+
+#[tauri::command]
+fn generate_passwords_cmd(options: PasswordOptions) -> PasswordGenerateResult {
+    generate_passwords(options)
+}
+
+#[tauri::command]
+fn evaluate_password_strength_cmd(password: String) -> PasswordStrengthResult {
+    evaluate_password_strength(&password)
+}
+
+#[tauri::command]
+fn generate_passphrases_cmd(options: PassphraseOptions) -> PasswordGenerateResult {
+    generate_passphrases(options)
+}
+
+#[tauri::command]
+fn generate_qr_cmd(content: String, options: QrOptions) -> QrGenerateResult {
+    generate_qr(&content, options)
+}
+
+#[tauri::command]
+async fn save_qr_cmd(
+    content: String,
+    options: QrOptions,
+    output_path: String,
+) -> Result<(), String> {
+    save_qr(&content, options, &output_path)
+}
+
+#[tauri::command]
+fn build_wifi_qr_content_cmd(config: WifiConfig) -> String {
+    build_wifi_content(&config)
+}
+
+#[tauri::command]
+fn build_vcard_qr_content_cmd(card: VCardInfo) -> String {
+    build_vcard_content(&card)
+}
+
+#[tauri::command]
+async fn decode_qr_from_image_cmd(path: String) -> QrDecodeResult {
+    decode_qr_from_image(&path)
+}
+
+#[tauri::command]
+async fn extract_text_from_image_cmd(path: String, language: OcrLanguage) -> OcrResult {
+    extract_text_from_image(&path, language)
+}
+
+#[tauri::command]
+fn convert_length_cmd(value: f64, from: LengthUnit, to: LengthUnit) -> ConversionResult {
+    convert_length(value, from, to)
+}
+
+#[tauri::command]
+fn convert_weight_cmd(value: f64, from: WeightUnit, to: WeightUnit) -> ConversionResult {
+    convert_weight(value, from, to)
+}
+
+#[tauri::command]
+fn convert_data_size_cmd(value: f64, from: DataSizeUnit, to: DataSizeUnit) -> ConversionResult {
+    convert_data_size(value, from, to)
+}
+
+#[tauri::command]
+fn convert_temperature_cmd(
+    value: f64,
+    from: TemperatureUnit,
+    to: TemperatureUnit,
+) -> ConversionResult {
+    convert_temperature(value, from, to)
+}
+
+#[tauri::command]
+fn convert_time_cmd(value: f64, from: TimeUnit, to: TimeUnit) -> ConversionResult {
+    convert_time(value, from, to)
+}
+
+#[tauri::command]
+fn convert_area_cmd(value: f64, from: AreaUnit, to: AreaUnit) -> ConversionResult {
+    convert_area(value, from, to)
+}
+
+#[tauri::command]
+fn convert_volume_cmd(value: f64, from: VolumeUnit, to: VolumeUnit) -> ConversionResult {
+    convert_volume(value, from, to)
+}
+
+#[tauri::command]
+fn convert_speed_cmd(value: f64, from: SpeedUnit, to: SpeedUnit) -> ConversionResult {
+    convert_speed(value, from, to)
+}
+
+#[tauri::command]
+fn convert_pressure_cmd(value: f64, from: PressureUnit, to: PressureUnit) -> ConversionResult {
+    convert_pressure(value, from, to)
+}
+
+#[tauri::command]
+fn convert_energy_cmd(value: f64, from: EnergyUnit, to: EnergyUnit) -> ConversionResult {
+    convert_energy(value, from, to)
+}
+
+#[tauri::command]
+fn convert_fuel_economy_cmd(
+    value: f64,
+    from: FuelEconomyUnit,
+    to: FuelEconomyUnit,
+) -> ConversionResult {
+    convert_fuel_economy(value, from, to)
+}
+
+#[tauri::command]
+fn convert_currency_cmd(
+    app: tauri::AppHandle,
+    value: f64,
+    from: CurrencyUnit,
+    to: CurrencyUnit,
+) -> ConversionResult {
+    convert_currency(value, from, to, &load_currency_rates(&app))
+}
+
+#[tauri::command]
+fn convert_to_all_cmd(
+    app: tauri::AppHandle,
+    value: f64,
+    category: UnitCategory,
+    from_unit: String,
+    significant_digits: Option<u32>,
+) -> MultiConversionResult {
+    let rates = if category == UnitCategory::Currency {
+        load_currency_rates(&app)
+    } else {
+        Default::default()
+    };
+    convert_to_all(value, category, &from_unit, significant_digits, &rates)
+}
+
+#[tauri::command]
+async fn update_currency_rates_cmd(app: tauri::AppHandle) -> CurrencyRatesResult {
+    update_currency_rates(&app).await
+}
+
+#[tauri::command]
+fn convert_radix_cmd(
+    input: String,
+    from_base: u32,
+    to_base: u32,
+    fraction_digits: Option<usize>,
+) -> RadixConvertResult {
+    match fraction_digits {
+        Some(digits) => convert_radix_with_precision(&input, from_base, to_base, digits),
+        None => convert_radix(&input, from_base, to_base),
+    }
+}
+
+#[tauri::command]
+fn float_to_bits_cmd(input: String, precision: FloatPrecision) -> FloatBitsResult {
+    float_to_bits(&input, precision)
+}
+
+#[tauri::command]
+fn bits_to_float_cmd(input: String, precision: FloatPrecision) -> FloatBitsResult {
+    bits_to_float(&input, precision)
+}
+
+#[tauri::command]
+fn convert_color_cmd(input: String) -> ColorConvertResult {
+    convert_color(&input)
+}
+
+#[tauri::command]
+fn generate_palette_cmd(base: String, kind: PaletteKind) -> PaletteResult {
+    generate_palette(&base, kind)
+}
+
+#[tauri::command]
+fn convert_case_cmd(input: String, target: CaseStyle, multiline: bool) -> CaseConvertResult {
+    convert_case(&input, target, multiline)
+}
+
+#[tauri::command]
+fn convert_all_cases_cmd(input: String, multiline: bool) -> Vec<CaseConversion> {
+    convert_all_cases(&input, multiline)
+}
+
+#[tauri::command]
+fn compute_diff_cmd(
+    old_text: String,
+    new_text: String,
+    mode: DiffMode,
+    options: Option<DiffOptions>,
+) -> DiffResult {
+    compute_diff_with(&old_text, &new_text, mode, &options.unwrap_or_default())
+}
+
+#[tauri::command]
+fn compute_file_diff_cmd(
+    old_path: String,
+    new_path: String,
+    mode: DiffMode,
+    options: Option<DiffOptions>,
+) -> Result<DiffResult, String> {
+    compute_file_diff(&old_path, &new_path, mode, &options.unwrap_or_default())
+}
+
+#[tauri::command]
+async fn compare_directories_cmd(
+    app: tauri::AppHandle,
+    dir_a: String,
+    dir_b: String,
+    options: DirCompareOptions,
+) -> DirCompareResult {
+    compare_directories(&dir_a, &dir_b, &options, |progress| {
+        let _ = app.emit("dir-compare-progress", progress);
+    })
+}
+
+#[tauri::command]
+fn compute_three_way_diff_cmd(
+    base: String,
+    ours: String,
+    theirs: String,
+    mode: DiffMode,
+) -> ThreeWayDiffResult {
+    compute_three_way_diff(&base, &ours, &theirs, mode)
+}
+
+#[tauri::command]
+fn export_diff_cmd(
+    old_text: String,
+    new_text: String,
+    mode: DiffMode,
+    format: DiffExportFormat,
+    output_path: Option<String>,
+    context_lines: Option<usize>,
+) -> DiffExportResult {
+    export_diff(
+        &old_text,
+        &new_text,
+        mode,
+        format,
+        output_path,
+        context_lines,
+    )
+}
+
+#[tauri::command]
+fn test_regex_cmd(
+    pattern: String,
+    test_text: String,
+    flags: RegexFlags,
+    timeout_ms: Option<u64>,
+) -> RegexResult {
+    test_regex_with_timeout(pattern, test_text, flags, timeout_ms)
+}
+
+#[tauri::command]
+fn replace_regex_cmd(
+    pattern: String,
+    test_text: String,
+    replacement: String,
+    flags: RegexFlags,
+    timeout_ms: Option<u64>,
+) -> ReplaceResult {
+    replace_regex_with_timeout(pattern, test_text, replacement, flags, timeout_ms)
+}
+
+#[tauri::command]
+async fn generate_regex_samples_cmd(
+    pattern: String,
+    count: u32,
+    max_repeat: u32,
+) -> RegexSampleResult {
+    generate_regex_samples(&pattern, count, max_repeat)
+}
+
+#[tauri::command]
+fn list_regex_presets_cmd(app: tauri::AppHandle) -> Vec<RegexPreset> {
+    list_regex_presets(&app)
+}
+
+#[tauri::command]
+fn save_regex_preset_cmd(
+    app: tauri::AppHandle,
+    name: String,
+    pattern: String,
+    flags: RegexFlags,
+    description: Option<String>,
+) -> Result<RegexPreset, String> {
+    save_regex_preset(&app, name, pattern, flags, description)
+}
+
+#[tauri::command]
+fn delete_regex_preset_cmd(app: tauri::AppHandle, id: String) -> Result<(), String> {
+    delete_regex_preset(&app, id)
+}
+
+#[tauri::command]
+fn get_text_file_info_cmd(path: String) -> Result<FileInfo, String> {
+    get_file_info(&path)
+}
+
+#[tauri::command]
+fn load_scratch_pad_cmd(app: tauri::AppHandle) -> Result<ScratchPadData, String> {
+    load_scratch_pad(&app)
+}
+
+#[tauri::command]
+fn create_note_cmd(app: tauri::AppHandle) -> Result<Note, String> {
+    create_note(&app)
+}
+
+#[tauri::command]
+fn update_note_cmd(
+    app: tauri::AppHandle,
+    note_id: String,
+    content: String,
+    title: Option<String>,
+) -> Result<Note, String> {
+    update_note(&app, note_id, content, title)
+}
+
+#[tauri::command]
+fn search_notes_cmd(app: tauri::AppHandle, query: String) -> Result<Vec<NoteSearchResult>, String> {
+    search_notes(&app, query)
+}
+
+#[tauri::command]
+fn list_note_history_cmd(
+    app: tauri::AppHandle,
+    note_id: String,
+) -> Result<Vec<NoteHistoryEntry>, String> {
+    list_note_history(&app, note_id)
+}
+
+#[tauri::command]
+fn restore_note_version_cmd(
+    app: tauri::AppHandle,
+    note_id: String,
+    version_id: String,
+) -> Result<Note, String> {
+    restore_note_version(&app, note_id, version_id)
+}
+
+#[tauri::command]
+fn delete_note_cmd(app: tauri::AppHandle, note_id: String) -> Result<ScratchPadData, String> {
+    delete_note(&app, note_id)
+}
+
+#[tauri::command]
+fn set_active_note_cmd(app: tauri::AppHandle, note_id: String) -> Result<ScratchPadData, String> {
+    set_active_note(&app, note_id)
+}
+
+#[tauri::command]
+fn export_to_file_cmd(content: String, path: String) -> Result<(), String> {
+    export_to_file(content, path)
+}
+
+#[tauri::command]
+async fn export_note_as_cmd(
+    app: tauri::AppHandle,
+    note_id: String,
+    format: NoteExportFormat,
+    path: String,
+) -> Result<(), String> {
+    export_note_as(&app, note_id, format, path)
+}
+
+#[tauri::command]
+async fn export_all_notes_cmd(
+    app: tauri::AppHandle,
+    dir: String,
+    format: NoteExportFormat,
+) -> Result<Vec<String>, String> {
+    export_all_notes(&app, dir, format)
+}
+
+#[tauri::command]
+fn format_json_cmd(input: String, indent_size: usize) -> JsonFormatResult {
+    format_json(&input, indent_size)
+}
+
+#[tauri::command]
+fn validate_json_cmd(input: String) -> JsonValidateResult {
+    validate_json(&input)
+}
+
+#[tauri::command]
+fn minify_json_cmd(input: String) -> JsonMinifyResult {
+    minify_json(&input)
+}
+
+#[tauri::command]
+async fn parse_json_tree_root_cmd(
+    store: tauri::State<'_, JsonTreeStore>,
+    input: String,
+) -> Result<JsonTreeRoot, String> {
+    parse_json_tree_root(&store, &input)
+}
+
+#[tauri::command]
+fn get_json_tree_children_cmd(
+    store: tauri::State<'_, JsonTreeStore>,
+    input_id: String,
+    path: String,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<JsonTreeNodeSummary>, String> {
+    get_json_tree_children(&store, &input_id, &path, offset, limit)
+}
+
+#[tauri::command]
+fn release_json_tree_cmd(store: tauri::State<'_, JsonTreeStore>, id: String) {
+    release_json_tree(&store, &id)
+}
+
+#[tauri::command]
+fn search_json_cmd(
+    input: String,
+    query: String,
+    search_keys: bool,
+    search_values: bool,
+) -> JsonSearchResult {
+    search_json(&input, &query, search_keys, search_values)
+}
+
+#[tauri::command]
+fn validate_json_with_schema_cmd(input: String, schema: String) -> JsonSchemaValidateResult {
+    validate_json_with_schema(&input, &schema)
+}
+
+#[tauri::command]
+fn query_json_cmd(input: String, query: String, query_type: JsonQueryType) -> JsonQueryResult {
+    query_json(&input, &query, query_type)
+}
+
+#[tauri::command]
+fn diff_json_cmd(old_input: String, new_input: String, options: JsonDiffOptions) -> JsonDiffResult {
+    diff_json(&old_input, &new_input, &options)
+}
+
+#[tauri::command]
+fn generate_types_cmd(input: String, target: TypeTarget, options: TypeGenOptions) -> TypeGenResult {
+    generate_types(&input, target, &options)
+}
+
+#[tauri::command]
+fn encode_base64_cmd(input: String, url_safe: bool) -> Base64EncodeResult {
+    encode_base64(&input, url_safe)
+}
+
+#[tauri::command]
+fn decode_base64_cmd(input: String, url_safe: bool) -> Base64DecodeResult {
+    decode_base64(&input, url_safe)
+}
+
+#[tauri::command]
+fn encode_image_to_base64_cmd(path: String) -> Base64ImageResult {
+    encode_image_to_base64(&path)
+}
+
+#[tauri::command]
+fn decode_base64_image_cmd(input: String) -> Base64DecodeImageResult {
+    decode_base64_image(&input)
+}
+
+#[tauri::command]
+fn convert_encoding_cmd(
+    input: String,
+    from: TextEncoding,
+    to: TextEncoding,
+) -> EncodingConvertResult {
+    convert_encoding(&input, from, to)
+}
+
+#[tauri::command]
+fn detect_encoding_cmd(path: String) -> Result<EncodingDetectResult, String> {
+    detect_encoding(&path)
+}
+
+#[tauri::command]
+fn convert_file_encoding_cmd(
+    input_path: String,
+    output_path: String,
+    from: Option<String>,
+    to: String,
+    bom: bool,
+    options: EncodingConvertOptions,
+) -> EncodingConvertFileResult {
+    convert_file_encoding(&input_path, &output_path, from, to, bom, options)
+}
+
+#[tauri::command]
+fn encode_file_to_base64_cmd(path: String, as_data_uri: bool) -> Base64FileResult {
+    encode_file_to_base64(&path, as_data_uri)
+}
+
+#[tauri::command]
+fn decode_base64_to_file_cmd(input: String, output_path: String) -> Result<DecodeFileInfo, String> {
+    decode_base64_to_file(&input, &output_path)
+}
+
+/// Run a file-to-file conversion in the background and return its job id right away.
+/// Progress and the final result arrive as events tagged with that id.
+fn spawn_base64_file_job<F>(app: tauri::AppHandle, convert: F) -> u64
+where
+    F: FnOnce(&AtomicBool, &mut dyn FnMut(Base64StreamProgress)) -> Base64StreamResult
+        + Send
+        + 'static,
+{
+    let (job_id, cancel) = app.state::<Base64JobStore>().start();
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = convert(&cancel, &mut |progress| {
+            let _ = app.emit(
+                "base64-file-progress",
+                Base64JobProgress { job_id, progress },
+            );
+        });
+        app.state::<Base64JobStore>().finish(job_id);
+        let _ = app.emit("base64-file-finished", Base64JobFinished { job_id, result });
+    });
+    job_id
+}
+
+#[tauri::command]
+fn encode_file_to_base64_file_cmd(
+    app: tauri::AppHandle,
+    input_path: String,
+    output_path: String,
+) -> u64 {
+    spawn_base64_file_job(app, move |cancel, on_progress| {
+        encode_file_to_base64_file(&input_path, &output_path, cancel, on_progress)
+    })
+}
+
+#[tauri::command]
+fn decode_base64_file_to_file_cmd(
+    app: tauri::AppHandle,
+    input_path: String,
+    output_path: String,
+) -> u64 {
+    spawn_base64_file_job(app, move |cancel, on_progress| {
+        decode_base64_file_to_file(&input_path, &output_path, cancel, on_progress)
+    })
+}
+
+#[tauri::command]
+fn cancel_base64_file_job_cmd(store: tauri::State<'_, Base64JobStore>, job_id: u64) -> bool {
+    store.cancel(job_id)
+}
+
+#[tauri::command]
+fn unix_to_datetime_cmd(
+    timestamp: i64,
+    unit: TimestampUnit,
+    timezone: TimezoneOption,
+) -> UnixToDateTimeResult {
+    unix_to_datetime(timestamp, unit, timezone)
+}
+
+#[tauri::command]
+fn datetime_to_unix_cmd(datetime_str: String, timezone: TimezoneOption) -> DateTimeToUnixResult {
+    datetime_to_unix(&datetime_str, timezone)
+}
+
+#[tauri::command]
+fn get_current_unix_time_cmd() -> CurrentUnixTimeResult {
+    get_current_unix_time()
+}
+
+#[tauri::command]
+fn list_timezones_cmd() -> Vec<TimezoneInfo> {
+    list_timezones()
+}
+
+#[tauri::command]
+fn parse_cron_cmd(expression: String, timezone: TimezoneOption) -> CronParseResult {
+    parse_cron(&expression, timezone)
+}
+
+#[tauri::command]
+fn parse_permission_cmd(input: String) -> PermissionResult {
+    parse_permission(&input)
+}
+
+#[tauri::command]
+fn apply_symbolic_change_cmd(base: String, change: String) -> PermissionResult {
+    apply_symbolic_change(&base, &change)
+}
+
+#[tauri::command]
+fn list_permission_presets_cmd() -> Vec<PermissionPreset> {
+    list_permission_presets()
+}
+
+#[tauri::command]
+fn add_business_days_cmd(
+    start_date: String,
+    days: i32,
+    country: HolidayCountry,
+    custom_holidays: Vec<String>,
+) -> BusinessDayResult {
+    add_business_days(start_date, days, country, custom_holidays)
+}
+
+#[tauri::command]
+fn count_business_days_cmd(
+    start: String,
+    end: String,
+    country: HolidayCountry,
+    custom_holidays: Vec<String>,
+) -> BusinessDayResult {
+    count_business_days(start, end, country, custom_holidays)
+}
+
+#[tauri::command]
+fn list_holidays_cmd(year: u16, country: HolidayCountry) -> Vec<Holiday> {
+    list_holidays(year, country)
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+fn add_duration_cmd(
+    timestamp: i64,
+    unit: TimestampUnit,
+    years: i32,
+    months: i32,
+    days: i32,
+    hours: i32,
+    minutes: i32,
+    seconds: i32,
+    timezone: TimezoneOption,
+) -> DateTimeToUnixResult {
+    add_duration(
+        timestamp, unit, years, months, days, hours, minutes, seconds, timezone,
+    )
+}
+
+#[tauri::command]
+fn batch_convert_timestamps_cmd(
+    input: String,
+    unit: TimestampUnit,
+    timezone: TimezoneOption,
+) -> BatchConvertResult {
+    batch_convert_timestamps(input, unit, timezone)
+}
+
+#[tauri::command]
+fn diff_datetimes_cmd(a: i64, b: i64, unit: TimestampUnit) -> DurationResult {
+    diff_datetimes(a, b, unit)
+}
+
+#[tauri::command]
+fn count_chars_cmd(text: String) -> CharCountResult {
+    count_chars(&text)
+}
+
+#[tauri::command]
+fn convert_japanese_text_cmd(input: String, conversions: Vec<JpConversion>) -> JpConvertResult {
+    convert_japanese_text(&input, conversions)
+}
+
+#[tauri::command]
+fn process_lines_cmd(input: String, operations: Vec<LineOperation>) -> LineProcessResult {
+    process_lines(&input, operations)
+}
+
+#[tauri::command]
+fn add_history_entry_cmd(
+    app: tauri::AppHandle,
+    tool_id: String,
+    inputs: serde_json::Value,
+    label: Option<String>,
+) -> Result<HistoryEntry, String> {
+    add_history_entry(&app, tool_id, inputs, label)
+}
+
+#[tauri::command]
+fn get_tool_history_cmd(
+    app: tauri::AppHandle,
+    tool_id: String,
+) -> Result<Vec<HistoryEntry>, String> {
+    get_tool_history(&app, tool_id)
+}
+
+#[tauri::command]
+fn search_tool_history_cmd(
+    app: tauri::AppHandle,
+    tool_id: String,
+    query: String,
+) -> Result<Vec<HistoryEntry>, String> {
+    search_tool_history(&app, tool_id, query)
+}
+
+#[tauri::command]
+fn delete_history_entry_cmd(app: tauri::AppHandle, entry_id: String) -> Result<(), String> {
+    delete_history_entry(&app, entry_id)
+}
+
+#[tauri::command]
+fn clear_tool_history_cmd(app: tauri::AppHandle, tool_id: String) -> Result<(), String> {
+    clear_tool_history(&app, tool_id)
 }
 
 #[tauri::command]
-fn read_csv_cmd(path: String) -> Result<CsvData, String> {
-    read_csv(&path)
+fn update_tool_history_settings_cmd(
+    app: tauri::AppHandle,
+    tool_id: String,
+    enabled: bool,
+    max_entries: usize,
+) -> Result<ToolHistorySettings, String> {
+    update_tool_history_settings(&app, tool_id, enabled, max_entries)
 }
 
 #[tauri::command]
-fn get_csv_info_cmd(path: String) -> Result<CsvInfo, String> {
-    get_csv_info(&path)
+fn get_tool_history_settings_cmd(
+    app: tauri::AppHandle,
+    tool_id: String,
+) -> Result<ToolHistorySettings, String> {
+    get_tool_history_settings(&app, tool_id)
 }
 
 #[tauri::command]
-fn save_csv_cmd(path: String, headers: Vec<String>, rows: Vec<Vec<String>>) -> Result<(), String> {
-    save_csv(&path, &headers, &rows)
+fn append_history_cmd(
+    app: tauri::AppHandle,
+    tool: String,
+    entries: Vec<String>,
+) -> Result<usize, String> {
+    append_history(&app, tool, entries)
 }
 
 #[tauri::command]
-fn get_pdf_info_cmd(path: String) -> Result<PdfInfo, String> {
-    get_pdf_info(&path)
+fn get_history_cmd(
+    app: tauri::AppHandle,
+    tool: String,
+    limit: u32,
+) -> Result<Vec<GenerationEntry>, String> {
+    get_history(&app, tool, limit)
 }
 
 #[tauri::command]
-fn split_pdf_by_pages_cmd(input_path: String, output_dir: String) -> PdfSplitResult {
-    split_pdf_by_pages(&input_path, &output_dir)
+fn clear_history_cmd(app: tauri::AppHandle, tool: String) -> Result<(), String> {
+    clear_history(&app, tool)
 }
 
 #[tauri::command]
-fn split_pdf_by_range_cmd(
-    input_path: String,
-    output_path: String,
-    start_page: u32,
-    end_page: u32,
-) -> PdfSplitResult {
-    split_pdf_by_range(&input_path, &output_path, start_page, end_page)
+fn compute_text_hashes_cmd(input: String, uppercase: bool) -> HashResult {
+    compute_text_hashes(&input, uppercase)
 }
 
 #[tauri::command]
-fn merge_pdfs_cmd(input_paths: Vec<String>, output_path: String) -> PdfMergeResult {
-    merge_pdfs(&input_paths, &output_path)
+fn compute_file_hashes_cmd(path: String) -> Result<HashResult, String> {
+    compute_file_hashes(&path)
 }
 
 #[tauri::command]
-fn read_markdown_cmd(path: String) -> Result<MarkdownInfo, String> {
-    read_markdown(&path)
+fn compare_hashes_cmd(a: String, b: String) -> HashCompareResult {
+    compare_hashes(&a, &b)
 }
 
 #[tauri::command]
-fn markdown_to_html_cmd(markdown: String) -> MarkdownToHtmlResult {
-    markdown_to_html(&markdown)
+async fn verify_checksum_file_cmd(
+    app: tauri::AppHandle,
+    checksum_file_path: String,
+    base_dir: String,
+) -> ChecksumVerifyResult {
+    verify_checksum_file(&checksum_file_path, &base_dir, |progress| {
+        let _ = app.emit("checksum-progress", progress);
+    })
 }
 
 #[tauri::command]
-fn convert_markdown_to_pdf_cmd(
-    markdown: String,
+async fn generate_checksum_file_cmd(
+    app: tauri::AppHandle,
+    paths: Vec<String>,
+    algorithm: ChecksumAlgorithm,
+    format: ChecksumFormat,
     output_path: String,
-    source_path: Option<String>,
-) -> MarkdownToPdfResult {
-    convert_markdown_to_pdf(&markdown, &output_path, source_path.as_deref())
+) -> ChecksumGenerateResult {
+    generate_checksum_file(&paths, algorithm, format, &output_path, |progress| {
+        let _ = app.emit("checksum-progress", progress);
+    })
 }
 
 #[tauri::command]
-fn load_kanban_board_cmd(app: tauri::AppHandle) -> Result<KanbanBoard, String> {
-    load_board(&app)
+fn decode_jwt_cmd(token: String) -> JwtDecodeResult {
+    decode_jwt(&token)
 }
 
 #[tauri::command]
-fn create_task_cmd(
-    app: tauri::AppHandle,
-    title: String,
-    description: Option<String>,
-    priority: TaskPriority,
-    assignee: Option<String>,
-    due_date: Option<String>,
-) -> Result<Task, String> {
-    create_task(&app, title, description, priority, assignee, due_date)
+fn verify_jwt_cmd(token: String, secret: String) -> JwtVerifyResult {
+    verify_jwt(&token, &secret)
 }
 
 #[tauri::command]
-#[allow(clippy::too_many_arguments)]
-fn update_task_cmd(
-    app: tauri::AppHandle,
-    task_id: String,
-    title: Option<String>,
-    description: Option<String>,
-    column: Option<TaskColumn>,
-    priority: Option<TaskPriority>,
-    assignee: Option<String>,
-    due_date: Option<String>,
-) -> Result<Task, String> {
-    update_task(
-        &app,
-        task_id,
-        title,
-        description,
-        column,
-        priority,
-        assignee,
-        due_date,
-    )
+fn encode_url_cmd(input: String, encode_all: bool) -> UrlEncodeResult {
+    encode_url(&input, encode_all)
 }
 
 #[tauri::command]
-fn delete_task_cmd(app: tauri::AppHandle, task_id: String) -> Result<(), String> {
-    delete_task(&app, task_id)
+fn decode_url_cmd(input: String) -> UrlDecodeResult {
+    decode_url(&input)
 }
 
 #[tauri::command]
-fn move_task_cmd(
-    app: tauri::AppHandle,
-    task_id: String,
-    column: TaskColumn,
-) -> Result<Task, String> {
-    move_task(&app, task_id, column)
+fn parse_query_string_cmd(input: String) -> QueryParseResult {
+    parse_query_string(&input)
 }
 
 #[tauri::command]
-fn get_editor_image_info_cmd(path: String) -> Result<ImageEditorInfo, String> {
-    get_editor_image_info(&path)
+fn escape_text_cmd(input: String, mode: EscapeMode) -> EscapeResult {
+    escape_text(&input, mode)
 }
 
 #[tauri::command]
-fn resize_image_cmd(
-    input_path: String,
-    output_path: String,
-    width: u32,
-    height: u32,
-    maintain_aspect: bool,
-) -> EditResult {
-    resize_image(&input_path, &output_path, width, height, maintain_aspect)
+fn unescape_text_cmd(input: String, mode: EscapeMode) -> EscapeResult {
+    unescape_text(&input, mode)
 }
 
 #[tauri::command]
-fn rotate_image_cmd(input_path: String, output_path: String, angle: RotationAngle) -> EditResult {
-    rotate_image(&input_path, &output_path, angle)
+fn csv_to_json_cmd(path: String, options: CsvToJsonOptions) -> Result<String, String> {
+    csv_to_json(&path, options)
 }
 
 #[tauri::command]
-fn crop_image_cmd(
-    input_path: String,
+fn export_csv_to_json_cmd(
+    path: String,
     output_path: String,
-    x: u32,
-    y: u32,
-    width: u32,
-    height: u32,
-) -> EditResult {
-    crop_image(&input_path, &output_path, x, y, width, height)
+    options: CsvToJsonOptions,
+) -> Result<(), String> {
+    export_csv_to_json(&path, &output_path, options)
 }
 
 #[tauri::command]
-fn adjust_brightness_cmd(input_path: String, output_path: String, value: i32) -> EditResult {
-    adjust_brightness(&input_path, &output_path, value)
+fn json_to_csv_cmd(input: String, output_path: String) -> Result<(), String> {
+    json_to_csv(&input, &output_path)
 }
 
 #[tauri::command]
-fn adjust_contrast_cmd(input_path: String, output_path: String, value: f32) -> EditResult {
-    adjust_contrast(&input_path, &output_path, value)
+fn convert_config_cmd(input: String, from: ConfigFormat, to: ConfigFormat) -> ConfigConvertResult {
+    convert_config(&input, from, to)
 }
 
 #[tauri::command]
-fn apply_filter_cmd(input_path: String, output_path: String, filter: ImageFilter) -> EditResult {
-    apply_filter(&input_path, &output_path, filter)
+fn parse_env_file_cmd(content: String) -> EnvParseResult {
+    parse_env_file(&content)
 }
 
 #[tauri::command]
-fn flip_horizontal_cmd(input_path: String, output_path: String) -> EditResult {
-    flip_horizontal(&input_path, &output_path)
+fn convert_env_cmd(content: String, to: EnvTargetFormat) -> EnvConvertResult {
+    convert_env(&content, to)
 }
 
 #[tauri::command]
-fn flip_vertical_cmd(input_path: String, output_path: String) -> EditResult {
-    flip_vertical(&input_path, &output_path)
+fn config_to_env_cmd(input: String, from: ConfigFormat) -> EnvConvertResult {
+    config_to_env(&input, from)
 }
 
 #[tauri::command]
-fn generate_uuids_cmd(version: UuidVersion, format: UuidFormat, count: u32) -> UuidGenerateResult {
-    let options = UuidGenerateOptions {
-        version,
-        format,
-        count,
-    };
-    generate_uuids(options)
+fn diff_env_cmd(left: String, right: String) -> EnvDiffResult {
+    diff_env(&left, &right)
 }
 
 #[tauri::command]
-fn validate_uuid_cmd(input: String) -> UuidValidateResult {
-    validate_uuid(&input)
+async fn send_http_request_cmd(request: HttpRequestSpec) -> HttpResponseResult {
+    send_http_request(request).await
 }
 
 #[tauri::command]
-fn generate_passwords_cmd(options: PasswordOptions) -> PasswordGenerateResult {
-    generate_passwords(options)
+fn analyze_cidr_cmd(input: String) -> CidrResult {
+    analyze_cidr(&input)
 }
 
 #[tauri::command]
-fn generate_passphrases_cmd(options: PassphraseOptions) -> PasswordGenerateResult {
-    generate_passphrases(options)
+fn parse_ip_cmd(input: String) -> IpInfo {
+    parse_ip(&input)
 }
 
 #[tauri::command]
-fn convert_length_cmd(value: f64, from: LengthUnit, to: LengthUnit) -> ConversionResult {
-    convert_length(value, from, to)
+async fn check_port_cmd(host: String, port: u16, timeout_ms: u64) -> PortCheckResult {
+    check_port_async(host, port, timeout_ms).await
 }
 
 #[tauri::command]
-fn convert_weight_cmd(value: f64, from: WeightUnit, to: WeightUnit) -> ConversionResult {
-    convert_weight(value, from, to)
+fn format_sql_cmd(input: String, options: SqlFormatOptions) -> SqlFormatResult {
+    format_sql(&input, &options)
 }
 
 #[tauri::command]
-fn convert_data_size_cmd(value: f64, from: DataSizeUnit, to: DataSizeUnit) -> ConversionResult {
-    convert_data_size(value, from, to)
+fn preview_rename_cmd(paths: Vec<String>, rule: RenameRule) -> RenamePreviewResult {
+    preview_rename(paths, &rule)
 }
 
 #[tauri::command]
-fn convert_temperature_cmd(
-    value: f64,
-    from: TemperatureUnit,
-    to: TemperatureUnit,
-) -> ConversionResult {
-    convert_temperature(value, from, to)
+fn apply_rename_cmd(plan: Vec<RenamePair>) -> RenameApplyResult {
+    apply_rename(plan)
 }
 
 #[tauri::command]
-fn convert_time_cmd(value: f64, from: TimeUnit, to: TimeUnit) -> ConversionResult {
-    convert_time(value, from, to)
+async fn find_duplicates_cmd(
+    app: tauri::AppHandle,
+    dirs: Vec<String>,
+    options: DuplicateFindOptions,
+) -> DuplicateFindResult {
+    find_duplicates(&dirs, &options, |progress| {
+        let _ = app.emit("duplicate-scan-progress", progress);
+    })
 }
 
 #[tauri::command]
-fn convert_area_cmd(value: f64, from: AreaUnit, to: AreaUnit) -> ConversionResult {
-    convert_area(value, from, to)
+async fn delete_duplicate_files_cmd(paths: Vec<String>) -> DeleteDuplicatesResult {
+    delete_duplicate_files(&paths)
 }
 
 #[tauri::command]
-fn convert_volume_cmd(value: f64, from: VolumeUnit, to: VolumeUnit) -> ConversionResult {
-    convert_volume(value, from, to)
+async fn analyze_directory_cmd(
+    app: tauri::AppHandle,
+    path: String,
+    max_depth: u32,
+    top_files: usize,
+) -> DirSizeResult {
+    analyze_directory(&path, max_depth, top_files, |progress| {
+        let _ = app.emit("disk-scan-progress", progress);
+    })
 }
 
 #[tauri::command]
-fn compute_diff_cmd(old_text: String, new_text: String, mode: DiffMode) -> DiffResult {
-    compute_diff(&old_text, &new_text, mode)
+async fn get_system_info_cmd() -> SystemInfoResult {
+    get_system_info()
 }
 
 #[tauri::command]
-fn test_regex_cmd(pattern: String, test_text: String, flags: RegexFlags) -> RegexResult {
-    test_regex(&pattern, &test_text, flags)
+fn start_system_monitor_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, SystemMonitorStore>,
+    interval_ms: u64,
+) -> Result<(), String> {
+    let emitter = app.clone();
+    store.start(interval_ms, move |stats| {
+        let _ = emitter.emit("system-stats", stats);
+    })
 }
 
 #[tauri::command]
-fn replace_regex_cmd(
-    pattern: String,
-    test_text: String,
-    replacement: String,
-    flags: RegexFlags,
-) -> ReplaceResult {
-    replace_regex(&pattern, &test_text, &replacement, flags)
+fn stop_system_monitor_cmd(store: tauri::State<'_, SystemMonitorStore>) -> bool {
+    store.stop()
 }
 
 #[tauri::command]
-fn get_text_file_info_cmd(path: String) -> Result<FileInfo, String> {
-    get_file_info(&path)
+fn is_system_monitor_running_cmd(store: tauri::State<'_, SystemMonitorStore>) -> bool {
+    store.is_running()
 }
 
 #[tauri::command]
-fn load_scratch_pad_cmd(app: tauri::AppHandle) -> Result<ScratchPadData, String> {
-    load_scratch_pad(&app)
+async fn list_processes_cmd(sort: ProcessSort, filter: Option<String>) -> Vec<ProcessInfo> {
+    list_processes(sort, filter.as_deref())
 }
 
 #[tauri::command]
-fn create_note_cmd(app: tauri::AppHandle) -> Result<Note, String> {
-    create_note(&app)
+async fn find_process_by_port_cmd(port: u16) -> Result<Vec<ProcessInfo>, String> {
+    find_process_by_port(port)
 }
 
 #[tauri::command]
-fn update_note_cmd(
-    app: tauri::AppHandle,
-    note_id: String,
-    content: String,
-) -> Result<Note, String> {
-    update_note(&app, note_id, content)
+fn kill_process_cmd(pid: u32, force: bool) -> Result<(), String> {
+    kill_process(pid, force)
 }
 
 #[tauri::command]
-fn delete_note_cmd(app: tauri::AppHandle, note_id: String) -> Result<ScratchPadData, String> {
-    delete_note(&app, note_id)
+fn start_clipboard_monitor_cmd(app: tauri::AppHandle) {
+    start_clipboard_monitor(&app)
 }
 
 #[tauri::command]
-fn set_active_note_cmd(app: tauri::AppHandle, note_id: String) -> Result<ScratchPadData, String> {
-    set_active_note(&app, note_id)
+fn stop_clipboard_monitor_cmd(store: tauri::State<'_, ClipboardStore>) {
+    stop_clipboard_monitor(&store)
 }
 
 #[tauri::command]
-fn export_to_file_cmd(content: String, path: String) -> Result<(), String> {
-    export_to_file(content, path)
+fn is_clipboard_monitor_running_cmd(store: tauri::State<'_, ClipboardStore>) -> bool {
+    store.is_running()
 }
 
 #[tauri::command]
-fn format_json_cmd(input: String, indent_size: usize) -> JsonFormatResult {
-    format_json(&input, indent_size)
+fn get_clipboard_history_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, ClipboardStore>,
+) -> Result<Vec<ClipEntry>, String> {
+    get_clipboard_history(&app, &store)
 }
 
 #[tauri::command]
-fn validate_json_cmd(input: String) -> JsonValidateResult {
-    validate_json(&input)
+fn clear_clipboard_history_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, ClipboardStore>,
+) -> Result<Vec<ClipEntry>, String> {
+    clear_clipboard_history(&app, &store)
 }
 
 #[tauri::command]
-fn minify_json_cmd(input: String) -> JsonMinifyResult {
-    minify_json(&input)
+fn pin_clip_entry_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, ClipboardStore>,
+    id: String,
+) -> Result<ClipEntry, String> {
+    pin_clip_entry(&app, &store, &id)
 }
 
 #[tauri::command]
-fn parse_json_to_tree_cmd(input: String) -> JsonParseResult {
-    parse_to_tree(&input)
+fn delete_clip_entry_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, ClipboardStore>,
+    id: String,
+) -> Result<(), String> {
+    delete_clip_entry(&app, &store, &id)
 }
 
 #[tauri::command]
-fn search_json_cmd(
-    input: String,
-    query: String,
-    search_keys: bool,
-    search_values: bool,
-) -> JsonSearchResult {
-    search_json(&input, &query, search_keys, search_values)
+fn copy_sensitive_text_cmd(
+    app: tauri::AppHandle,
+    text: String,
+    clear_after_secs: u64,
+) -> Result<(), String> {
+    copy_sensitive_text(&app, text, clear_after_secs)
 }
 
 #[tauri::command]
-fn encode_base64_cmd(input: String, url_safe: bool) -> Base64EncodeResult {
-    encode_base64(&input, url_safe)
+fn get_clipboard_settings_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, ClipboardStore>,
+) -> Result<ClipboardSettings, String> {
+    get_clipboard_settings(&app, &store)
 }
 
 #[tauri::command]
-fn decode_base64_cmd(input: String, url_safe: bool) -> Base64DecodeResult {
-    decode_base64(&input, url_safe)
+fn update_clipboard_settings_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, ClipboardStore>,
+    settings: ClipboardSettings,
+) -> Result<ClipboardSettings, String> {
+    update_clipboard_settings(&app, &store, settings)
 }
 
 #[tauri::command]
-fn encode_image_to_base64_cmd(path: String) -> Base64ImageResult {
-    encode_image_to_base64(&path)
+fn start_pomodoro_cmd(
+    app: tauri::AppHandle,
+    task_id: Option<String>,
+    work_minutes: u32,
+    break_minutes: u32,
+) -> Result<PomodoroState, String> {
+    start_pomodoro(&app, task_id, work_minutes, break_minutes)
 }
 
 #[tauri::command]
-fn decode_base64_image_cmd(input: String) -> Base64DecodeImageResult {
-    decode_base64_image(&input)
+fn pause_pomodoro_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PomodoroStore>,
+) -> Result<PomodoroState, String> {
+    pause_pomodoro(&app, &store)
 }
 
 #[tauri::command]
-fn unix_to_datetime_cmd(
-    timestamp: i64,
-    unit: TimestampUnit,
-    timezone: TimezoneOption,
-) -> UnixToDateTimeResult {
-    unix_to_datetime(timestamp, unit, timezone)
+fn stop_pomodoro_cmd(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PomodoroStore>,
+) -> PomodoroState {
+    stop_pomodoro(&app, &store)
 }
 
 #[tauri::command]
-fn datetime_to_unix_cmd(datetime_str: String, timezone: TimezoneOption) -> DateTimeToUnixResult {
-    datetime_to_unix(&datetime_str, timezone)
+fn get_pomodoro_state_cmd(store: tauri::State<'_, PomodoroStore>) -> PomodoroState {
+    get_pomodoro_state(&store)
 }
 
 #[tauri::command]
-fn get_current_unix_time_cmd() -> CurrentUnixTimeResult {
-    get_current_unix_time()
+fn get_pomodoro_stats_cmd(
+    app: tauri::AppHandle,
+    date_range: DateRange,
+) -> Result<Vec<DailyPomodoroStats>, String> {
+    get_pomodoro_stats(&app, &date_range)
 }
 
 #[tauri::command]
-fn count_chars_cmd(text: String) -> CharCountResult {
-    count_chars(&text)
+fn create_reminder_cmd(
+    app: tauri::AppHandle,
+    title: String,
+    body: String,
+    fire_at: i64,
+) -> Result<Reminder, String> {
+    create_reminder(&app, &title, &body, fire_at)
 }
 
 #[tauri::command]
-fn add_history_entry_cmd(
-    app: tauri::AppHandle,
-    tool_id: String,
-    inputs: serde_json::Value,
-    label: Option<String>,
-) -> Result<HistoryEntry, String> {
-    add_history_entry(&app, tool_id, inputs, label)
+fn list_reminders_cmd(app: tauri::AppHandle) -> Result<Vec<Reminder>, String> {
+    list_reminders(&app)
 }
 
 #[tauri::command]
-fn get_tool_history_cmd(
+fn cancel_reminder_cmd(app: tauri::AppHandle, id: String) -> Result<Reminder, String> {
+    cancel_reminder(&app, &id)
+}
+
+#[tauri::command]
+fn record_tool_usage_cmd(
     app: tauri::AppHandle,
+    store: tauri::State<'_, UsageStore>,
     tool_id: String,
-) -> Result<Vec<HistoryEntry>, String> {
-    get_tool_history(&app, tool_id)
+) -> Result<(), String> {
+    record_tool_usage(&app, &store, &tool_id)
 }
 
 #[tauri::command]
-fn search_tool_history_cmd(
+fn get_tool_usage_cmd(
     app: tauri::AppHandle,
-    tool_id: String,
-    query: String,
-) -> Result<Vec<HistoryEntry>, String> {
-    search_tool_history(&app, tool_id, query)
+    store: tauri::State<'_, UsageStore>,
+) -> Result<Vec<ToolUsage>, String> {
+    get_tool_usage(&app, &store)
 }
 
 #[tauri::command]
-fn delete_history_entry_cmd(app: tauri::AppHandle, entry_id: String) -> Result<(), String> {
-    delete_history_entry(&app, entry_id)
+fn load_settings_cmd(app: tauri::AppHandle) -> AppSettings {
+    load_settings(&app)
 }
 
 #[tauri::command]
-fn clear_tool_history_cmd(app: tauri::AppHandle, tool_id: String) -> Result<(), String> {
-    clear_tool_history(&app, tool_id)
+fn save_settings_cmd(app: tauri::AppHandle, settings: AppSettings) -> Result<AppSettings, String> {
+    save_settings(&app, settings)
 }
 
 #[tauri::command]
-fn update_tool_history_settings_cmd(
-    app: tauri::AppHandle,
-    tool_id: String,
-    enabled: bool,
-    max_entries: usize,
-) -> Result<ToolHistorySettings, String> {
-    update_tool_history_settings(&app, tool_id, enabled, max_entries)
+fn reset_settings_cmd(app: tauri::AppHandle) -> Result<AppSettings, String> {
+    reset_settings(&app)
 }
 
 #[tauri::command]
-fn get_tool_history_settings_cmd(
-    app: tauri::AppHandle,
-    tool_id: String,
-) -> Result<ToolHistorySettings, String> {
-    get_tool_history_settings(&app, tool_id)
+fn get_system_theme_cmd(window: tauri::Window) -> String {
+    theme_name(window.theme().unwrap_or(tauri::Theme::Light))
+}
+
+#[tauri::command]
+fn save_last_tab_cmd(app: tauri::AppHandle, tab_id: String) -> Result<(), String> {
+    save_last_tab(&app, tab_id)
 }
 
-use tauri::{Emitter, WindowEvent};
+#[tauri::command]
+fn get_last_tab_cmd(app: tauri::AppHandle) -> Option<String> {
+    get_last_tab(&app)
+}
+
+use tauri::{Emitter, Manager, RunEvent, WindowEvent};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
-        .setup(|_app| Ok(()))
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_notification::init())
+        .manage(EditSessionStore::default())
+        .manage(ClipboardStore::default())
+        .manage(PomodoroStore::default())
+        .manage(UsageStore::default())
+        .manage(Base64JobStore::default())
+        .manage(ReminderStore::default())
+        .manage(JsonTreeStore::default())
+        .manage(SystemMonitorStore::default())
+        .setup(|app| {
+            restore_window_state(app.handle());
+            init_reminders(app.handle());
+            start_due_checker(app.handle());
+            // The window starts hidden so it does not visibly jump to the restored bounds
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+            }
+            Ok(())
+        })
         .on_window_event(|window, event| {
             if let WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, .. }) = event {
                 let paths_str: Vec<String> = paths
@@ -543,39 +2156,107 @@ pub fn run() {
                     .collect();
                 let _ = window.emit("file-drop", paths_str);
             }
+            if let WindowEvent::ThemeChanged(theme) = event {
+                let _ = window.emit("system-theme-changed", theme_name(*theme));
+            }
+            if let WindowEvent::CloseRequested { .. } = event {
+                let _ = save_window_state(window);
+            }
         })
         .invoke_handler(tauri::generate_handler![
             greet,
             compress_image_cmd,
+            compress_images_batch_cmd,
+            compress_to_target_size_cmd,
+            convert_image_format_cmd,
+            convert_images_batch_cmd,
+            export_multiple_sizes_cmd,
+            list_size_presets_cmd,
             get_image_info_cmd,
             read_csv_cmd,
+            read_csv_chunk_cmd,
+            query_csv_cmd,
             get_csv_info_cmd,
+            analyze_csv_columns_cmd,
             save_csv_cmd,
+            update_csv_cells_cmd,
             get_pdf_info_cmd,
             split_pdf_by_pages_cmd,
             split_pdf_by_range_cmd,
             merge_pdfs_cmd,
+            rotate_pdf_pages_cmd,
+            extract_pdf_text_cmd,
+            export_pdf_text_cmd,
+            encrypt_pdf_cmd,
+            decrypt_pdf_cmd,
+            compress_pdf_cmd,
+            render_pdf_page_thumbnail_cmd,
+            render_pdf_thumbnails_cmd,
             load_kanban_board_cmd,
+            list_boards_cmd,
+            create_board_cmd,
+            rename_board_cmd,
+            delete_board_cmd,
+            get_due_summary_cmd,
             create_task_cmd,
             update_task_cmd,
             delete_task_cmd,
             move_task_cmd,
+            reorder_task_cmd,
+            export_kanban_board_cmd,
+            import_kanban_board_cmd,
+            add_subtask_cmd,
+            toggle_subtask_cmd,
+            delete_subtask_cmd,
             get_editor_image_info_cmd,
             resize_image_cmd,
             rotate_image_cmd,
             crop_image_cmd,
+            crop_image_with_aspect_cmd,
             adjust_brightness_cmd,
             adjust_contrast_cmd,
             apply_filter_cmd,
             flip_horizontal_cmd,
             flip_vertical_cmd,
+            add_text_watermark_cmd,
+            combine_images_cmd,
+            start_edit_session_cmd,
+            apply_operation_cmd,
+            undo_operation_cmd,
+            redo_operation_cmd,
+            get_session_state_cmd,
+            export_session_cmd,
+            close_edit_session_cmd,
+            save_workflow_cmd,
+            list_workflows_cmd,
+            delete_workflow_cmd,
+            run_workflow_cmd,
+            read_exif_cmd,
+            strip_exif_cmd,
             read_markdown_cmd,
+            check_markdown_links_cmd,
             markdown_to_html_cmd,
+            format_markdown_table_cmd,
+            edit_markdown_table_cmd,
+            csv_to_markdown_table_cmd,
+            markdown_table_to_csv_cmd,
             convert_markdown_to_pdf_cmd,
             generate_uuids_cmd,
+            generate_ids_cmd,
+            decode_ulid_cmd,
             validate_uuid_cmd,
+            validate_uuids_bulk_cmd,
+            generate_lorem_cmd,
+            generate_dummy_records_cmd,
             generate_passwords_cmd,
+            evaluate_password_strength_cmd,
             generate_passphrases_cmd,
+            generate_qr_cmd,
+            save_qr_cmd,
+            build_wifi_qr_content_cmd,
+            build_vcard_qr_content_cmd,
+            decode_qr_from_image_cmd,
+            extract_text_from_image_cmd,
             convert_length_cmd,
             convert_weight_cmd,
             convert_data_size_cmd,
@@ -583,37 +2264,164 @@ pub fn run() {
             convert_time_cmd,
             convert_area_cmd,
             convert_volume_cmd,
+            convert_speed_cmd,
+            convert_pressure_cmd,
+            convert_energy_cmd,
+            convert_fuel_economy_cmd,
+            convert_currency_cmd,
+            convert_to_all_cmd,
+            update_currency_rates_cmd,
+            convert_radix_cmd,
+            float_to_bits_cmd,
+            bits_to_float_cmd,
+            convert_color_cmd,
+            generate_palette_cmd,
+            convert_case_cmd,
+            convert_all_cases_cmd,
             compute_diff_cmd,
+            compute_file_diff_cmd,
+            compare_directories_cmd,
+            compute_three_way_diff_cmd,
+            export_diff_cmd,
             get_text_file_info_cmd,
             test_regex_cmd,
             replace_regex_cmd,
+            generate_regex_samples_cmd,
+            list_regex_presets_cmd,
+            save_regex_preset_cmd,
+            delete_regex_preset_cmd,
             load_scratch_pad_cmd,
             create_note_cmd,
             update_note_cmd,
             delete_note_cmd,
             set_active_note_cmd,
+            search_notes_cmd,
+            list_note_history_cmd,
+            restore_note_version_cmd,
             export_to_file_cmd,
+            export_note_as_cmd,
+            export_all_notes_cmd,
             format_json_cmd,
             validate_json_cmd,
             minify_json_cmd,
-            parse_json_to_tree_cmd,
+            parse_json_tree_root_cmd,
+            get_json_tree_children_cmd,
+            release_json_tree_cmd,
             search_json_cmd,
+            query_json_cmd,
+            diff_json_cmd,
+            generate_types_cmd,
+            validate_json_with_schema_cmd,
             encode_base64_cmd,
             decode_base64_cmd,
             encode_image_to_base64_cmd,
             decode_base64_image_cmd,
+            encode_file_to_base64_cmd,
+            decode_base64_to_file_cmd,
+            encode_file_to_base64_file_cmd,
+            decode_base64_file_to_file_cmd,
+            cancel_base64_file_job_cmd,
+            convert_encoding_cmd,
+            detect_encoding_cmd,
+            convert_file_encoding_cmd,
             unix_to_datetime_cmd,
             datetime_to_unix_cmd,
             get_current_unix_time_cmd,
+            list_timezones_cmd,
+            parse_cron_cmd,
+            add_business_days_cmd,
+            count_business_days_cmd,
+            list_holidays_cmd,
+            parse_permission_cmd,
+            apply_symbolic_change_cmd,
+            list_permission_presets_cmd,
+            add_duration_cmd,
+            diff_datetimes_cmd,
+            batch_convert_timestamps_cmd,
             count_chars_cmd,
+            convert_japanese_text_cmd,
+            process_lines_cmd,
             add_history_entry_cmd,
             get_tool_history_cmd,
             search_tool_history_cmd,
             delete_history_entry_cmd,
             clear_tool_history_cmd,
             update_tool_history_settings_cmd,
-            get_tool_history_settings_cmd
+            get_tool_history_settings_cmd,
+            append_history_cmd,
+            get_history_cmd,
+            clear_history_cmd,
+            compute_text_hashes_cmd,
+            compute_file_hashes_cmd,
+            compare_hashes_cmd,
+            verify_checksum_file_cmd,
+            generate_checksum_file_cmd,
+            decode_jwt_cmd,
+            verify_jwt_cmd,
+            encode_url_cmd,
+            decode_url_cmd,
+            parse_query_string_cmd,
+            escape_text_cmd,
+            unescape_text_cmd,
+            csv_to_json_cmd,
+            export_csv_to_json_cmd,
+            json_to_csv_cmd,
+            convert_config_cmd,
+            parse_env_file_cmd,
+            convert_env_cmd,
+            config_to_env_cmd,
+            diff_env_cmd,
+            send_http_request_cmd,
+            analyze_cidr_cmd,
+            parse_ip_cmd,
+            check_port_cmd,
+            format_sql_cmd,
+            preview_rename_cmd,
+            apply_rename_cmd,
+            find_duplicates_cmd,
+            delete_duplicate_files_cmd,
+            analyze_directory_cmd,
+            get_system_info_cmd,
+            start_system_monitor_cmd,
+            stop_system_monitor_cmd,
+            is_system_monitor_running_cmd,
+            list_processes_cmd,
+            find_process_by_port_cmd,
+            kill_process_cmd,
+            start_clipboard_monitor_cmd,
+            stop_clipboard_monitor_cmd,
+            is_clipboard_monitor_running_cmd,
+            get_clipboard_history_cmd,
+            clear_clipboard_history_cmd,
+            pin_clip_entry_cmd,
+            delete_clip_entry_cmd,
+            get_clipboard_settings_cmd,
+            update_clipboard_settings_cmd,
+            copy_sensitive_text_cmd,
+            start_pomodoro_cmd,
+            pause_pomodoro_cmd,
+            stop_pomodoro_cmd,
+            get_pomodoro_state_cmd,
+            get_pomodoro_stats_cmd,
+            create_reminder_cmd,
+            list_reminders_cmd,
+            cancel_reminder_cmd,
+            record_tool_usage_cmd,
+            get_tool_usage_cmd,
+            load_settings_cmd,
+            save_settings_cmd,
+            reset_settings_cmd,
+            get_system_theme_cmd,
+            save_last_tab_cmd,
+            get_last_tab_cmd
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let RunEvent::Exit = event {
+                app.state::<EditSessionStore>().cleanup_all();
+                app.state::<SystemMonitorStore>().stop();
+                let _ = app.state::<UsageStore>().flush(app);
+            }
+        });
 }
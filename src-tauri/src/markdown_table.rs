@@ -0,0 +1,452 @@
+use crate::csv_viewer::detect_delimiter;
+use csv::{ReaderBuilder, WriterBuilder};
+use serde::{Deserialize, Serialize};
+use unicode_width::UnicodeWidthStr;
+
+/// Narrowest delimiter cell, so `---` and `:-:` still parse as delimiters
+const MIN_COLUMN_WIDTH: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ColumnAlign {
+    /// `---`
+    #[default]
+    None,
+    /// `:---`
+    Left,
+    /// `:---:`
+    Center,
+    /// `---:`
+    Right,
+}
+
+/// Simple edits to the rows and columns of a table. Row indices count body rows only,
+/// and an index equal to the row or column count appends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TableOperation {
+    InsertRow { index: usize },
+    DeleteRow { index: usize },
+    InsertColumn { index: usize },
+    DeleteColumn { index: usize },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableFormatResult {
+    pub success: bool,
+    /// The formatted Markdown table, or CSV for `markdown_table_to_csv`
+    pub output: String,
+    /// Body rows, not counting the header
+    pub rows: usize,
+    pub columns: usize,
+    pub aligns: Vec<ColumnAlign>,
+    pub error: Option<String>,
+}
+
+impl TableFormatResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+/// Cells keep their Markdown form, so an escaped pipe stays `\|`
+#[derive(Debug, Clone, PartialEq)]
+struct Table {
+    header: Vec<String>,
+    aligns: Vec<ColumnAlign>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn columns(&self) -> usize {
+        self.header.len()
+    }
+
+    /// Pad every row to the widest one so no cell is dropped
+    fn normalize(&mut self) {
+        let columns = self
+            .rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.header.len(), self.aligns.len(), 1])
+            .max()
+            .unwrap_or(1);
+        self.header.resize(columns, String::new());
+        self.aligns.resize(columns, ColumnAlign::None);
+        for row in &mut self.rows {
+            row.resize(columns, String::new());
+        }
+    }
+
+    fn apply(&mut self, operation: TableOperation) -> Result<(), String> {
+        let columns = self.columns();
+        match operation {
+            TableOperation::InsertRow { index } => {
+                if index > self.rows.len() {
+                    return Err(format!("Row {} is out of range", index));
+                }
+                self.rows.insert(index, vec![String::new(); columns]);
+            }
+            TableOperation::DeleteRow { index } => {
+                if index >= self.rows.len() {
+                    return Err(format!("Row {} is out of range", index));
+                }
+                self.rows.remove(index);
+            }
+            TableOperation::InsertColumn { index } => {
+                if index > columns {
+                    return Err(format!("Column {} is out of range", index));
+                }
+                self.header.insert(index, String::new());
+                self.aligns.insert(index, ColumnAlign::None);
+                for row in &mut self.rows {
+                    row.insert(index, String::new());
+                }
+            }
+            TableOperation::DeleteColumn { index } => {
+                if index >= columns {
+                    return Err(format!("Column {} is out of range", index));
+                }
+                if columns == 1 {
+                    return Err("A table needs at least one column".to_string());
+                }
+                self.header.remove(index);
+                self.aligns.remove(index);
+                for row in &mut self.rows {
+                    row.remove(index);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn render(&self) -> String {
+        let widths: Vec<usize> = (0..self.columns())
+            .map(|col| {
+                self.rows
+                    .iter()
+                    .map(|row| row[col].width())
+                    .chain([self.header[col].width(), MIN_COLUMN_WIDTH])
+                    .max()
+                    .unwrap_or(MIN_COLUMN_WIDTH)
+            })
+            .collect();
+
+        let render_row = |cells: &[String]| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .zip(&self.aligns)
+                .map(|((cell, &width), &align)| pad_cell(cell, width, align))
+                .collect();
+            format!("| {} |", padded.join(" | "))
+        };
+        let delimiter: Vec<String> = widths
+            .iter()
+            .zip(&self.aligns)
+            .map(|(&width, &align)| delimiter_cell(width, align))
+            .collect();
+
+        let mut lines = vec![
+            render_row(&self.header),
+            format!("| {} |", delimiter.join(" | ")),
+        ];
+        lines.extend(self.rows.iter().map(|row| render_row(row)));
+        lines.join("\n")
+    }
+
+    fn into_result(self, output: String) -> TableFormatResult {
+        TableFormatResult {
+            success: true,
+            output,
+            rows: self.rows.len(),
+            columns: self.columns(),
+            aligns: self.aligns,
+            error: None,
+        }
+    }
+}
+
+fn pad_cell(cell: &str, width: usize, align: ColumnAlign) -> String {
+    let space = width.saturating_sub(cell.width());
+    let (left, right) = match align {
+        ColumnAlign::Right => (space, 0),
+        ColumnAlign::Center => (space / 2, space - space / 2),
+        ColumnAlign::None | ColumnAlign::Left => (0, space),
+    };
+    format!("{}{}{}", " ".repeat(left), cell, " ".repeat(right))
+}
+
+fn delimiter_cell(width: usize, align: ColumnAlign) -> String {
+    match align {
+        ColumnAlign::None => "-".repeat(width),
+        ColumnAlign::Left => format!(":{}", "-".repeat(width - 1)),
+        ColumnAlign::Center => format!(":{}:", "-".repeat(width - 2)),
+        ColumnAlign::Right => format!("{}:", "-".repeat(width - 1)),
+    }
+}
+
+/// Split a table row on pipes that are not escaped as `\|`. The outer pipes are optional.
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+
+    let mut cells = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current.push(c);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            '|' => cells.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    // A closing pipe leaves nothing after it
+    if !current.trim().is_empty() || cells.is_empty() {
+        cells.push(current);
+    }
+    cells
+        .into_iter()
+        .map(|cell| cell.trim().to_string())
+        .collect()
+}
+
+fn parse_align(cell: &str) -> Option<ColumnAlign> {
+    let left = cell.starts_with(':');
+    let right = cell.len() > 1 && cell.ends_with(':');
+    let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+    if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+        return None;
+    }
+    Some(match (left, right) {
+        (true, true) => ColumnAlign::Center,
+        (true, false) => ColumnAlign::Left,
+        (false, true) => ColumnAlign::Right,
+        (false, false) => ColumnAlign::None,
+    })
+}
+
+fn parse_table(input: &str) -> Result<Table, String> {
+    let lines: Vec<&str> = input.lines().filter(|l| !l.trim().is_empty()).collect();
+    let Some(header_line) = lines.first() else {
+        return Err("Input is empty".to_string());
+    };
+    let aligns = lines
+        .get(1)
+        .map(|line| split_row(line))
+        .and_then(|cells| {
+            cells
+                .iter()
+                .map(|c| parse_align(c))
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| {
+            "The second line must be a delimiter row such as | --- | :---: |".to_string()
+        })?;
+
+    let mut table = Table {
+        header: split_row(header_line),
+        aligns,
+        rows: lines[2..].iter().map(|line| split_row(line)).collect(),
+    };
+    table.normalize();
+    Ok(table)
+}
+
+/// Escape pipes for a Markdown cell; line breaks become `<br>` so the row stays on one line
+fn escape_cell(value: &str) -> String {
+    value
+        .trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\r', '\n'], "<br>")
+}
+
+fn unescape_cell(cell: &str) -> String {
+    cell.replace("\\|", "|")
+}
+
+/// Re-align a Markdown table so every column's pipes line up, counting full-width
+/// characters as two columns. `align` replaces the alignment of the columns it covers.
+pub fn format_markdown_table(input: &str, align: Option<Vec<ColumnAlign>>) -> TableFormatResult {
+    let mut table = match parse_table(input) {
+        Ok(table) => table,
+        Err(e) => return TableFormatResult::failure(e),
+    };
+    if let Some(align) = align {
+        for (current, new) in table.aligns.iter_mut().zip(align) {
+            *current = new;
+        }
+    }
+    let output = table.render();
+    table.into_result(output)
+}
+
+/// Apply a row or column edit and return the re-formatted table
+pub fn edit_markdown_table(input: &str, operation: TableOperation) -> TableFormatResult {
+    let mut table = match parse_table(input) {
+        Ok(table) => table,
+        Err(e) => return TableFormatResult::failure(e),
+    };
+    if let Err(e) = table.apply(operation) {
+        return TableFormatResult::failure(e);
+    }
+    let output = table.render();
+    table.into_result(output)
+}
+
+/// Build a Markdown table from CSV text, using the first record as the header.
+/// Comma, tab and semicolon delimiters are detected.
+pub fn csv_to_markdown_table(csv_text: &str) -> TableFormatResult {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(detect_delimiter(csv_text.as_bytes()))
+        .from_reader(csv_text.as_bytes());
+    let mut records = Vec::new();
+    for record in reader.records() {
+        match record {
+            Ok(record) => records.push(record.iter().map(escape_cell).collect::<Vec<_>>()),
+            Err(e) => return TableFormatResult::failure(format!("Invalid CSV: {}", e)),
+        }
+    }
+    if records.is_empty() {
+        return TableFormatResult::failure("Input is empty".to_string());
+    }
+
+    let header = records.remove(0);
+    let mut table = Table {
+        aligns: vec![ColumnAlign::None; header.len()],
+        header,
+        rows: records,
+    };
+    table.normalize();
+    let output = table.render();
+    table.into_result(output)
+}
+
+/// Convert a Markdown table to CSV, with escaped pipes turned back into plain ones
+pub fn markdown_table_to_csv(input: &str) -> TableFormatResult {
+    let table = match parse_table(input) {
+        Ok(table) => table,
+        Err(e) => return TableFormatResult::failure(e),
+    };
+    let mut writer = WriterBuilder::new().from_writer(Vec::new());
+    for row in std::iter::once(&table.header).chain(&table.rows) {
+        if let Err(e) = writer.write_record(row.iter().map(|cell| unescape_cell(cell))) {
+            return TableFormatResult::failure(format!("Failed to write CSV: {}", e));
+        }
+    }
+    let output = match writer.into_inner() {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        Err(e) => return TableFormatResult::failure(format!("Failed to write CSV: {}", e)),
+    };
+    table.into_result(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_aligns_columns() {
+        let input = "|Name|Qty|Note|\n|:-|--:|:-:|\n|りんご|3|赤い|\n| banana | 12 |\n";
+        let result = format_markdown_table(input, None);
+        assert!(result.success);
+        assert_eq!(
+            result.output,
+            "| Name   | Qty | Note |\n\
+             | :----- | --: | :--: |\n\
+             | りんご |   3 | 赤い |\n\
+             | banana |  12 |      |"
+        );
+        assert_eq!((result.rows, result.columns), (2, 3));
+        assert_eq!(
+            result.aligns,
+            vec![ColumnAlign::Left, ColumnAlign::Right, ColumnAlign::Center]
+        );
+    }
+
+    #[test]
+    fn test_format_changes_alignment() {
+        let result = format_markdown_table(
+            "a | b\n--- | ---\nx | y",
+            Some(vec![ColumnAlign::Center, ColumnAlign::Right]),
+        );
+        assert_eq!(result.output, "|  a  |   b |\n| :-: | --: |\n|  x  |   y |");
+        assert!(!format_markdown_table("| a |\n| b |", None).success);
+        assert!(!format_markdown_table("  \n", None).success);
+    }
+
+    #[test]
+    fn test_escaped_pipes_stay_in_their_cell() {
+        let input = "| cmd | desc |\n|---|---|\n| `a \\| b` | or \\| pipe |";
+        let result = format_markdown_table(input, None);
+        assert_eq!(result.columns, 2);
+        assert!(result.output.ends_with("| `a \\| b` | or \\| pipe |"));
+
+        let csv = markdown_table_to_csv(input);
+        assert_eq!(csv.output, "cmd,desc\n`a | b`,or | pipe\n");
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let csv = "id,name,memo\n1,\"Smith, J\",a|b\n2,全角,\"two\nlines\"\n";
+        let table = csv_to_markdown_table(csv);
+        assert!(table.success);
+        assert_eq!(
+            table.output,
+            "| id  | name     | memo         |\n\
+             | --- | -------- | ------------ |\n\
+             | 1   | Smith, J | a\\|b         |\n\
+             | 2   | 全角     | two<br>lines |"
+        );
+        let back = markdown_table_to_csv(&table.output);
+        assert_eq!(
+            back.output,
+            "id,name,memo\n1,\"Smith, J\",a|b\n2,全角,two<br>lines\n"
+        );
+
+        let tsv = csv_to_markdown_table("a\tb\n1\t2");
+        assert_eq!(tsv.columns, 2);
+        assert!(!csv_to_markdown_table("").success);
+    }
+
+    #[test]
+    fn test_edit_rows_and_columns() {
+        let input = "| a | b |\n|:--|--:|\n| 1 | 2 |";
+
+        let result = edit_markdown_table(input, TableOperation::InsertRow { index: 1 });
+        assert_eq!(result.rows, 2);
+        assert!(result.output.ends_with("| 1   |   2 |\n|     |     |"));
+
+        let result = edit_markdown_table(input, TableOperation::InsertColumn { index: 1 });
+        assert_eq!(
+            result.aligns,
+            vec![ColumnAlign::Left, ColumnAlign::None, ColumnAlign::Right]
+        );
+        assert_eq!(
+            result.output,
+            "| a   |     |   b |\n| :-- | --- | --: |\n| 1   |     |   2 |"
+        );
+
+        let result = edit_markdown_table(input, TableOperation::DeleteColumn { index: 0 });
+        assert_eq!(result.output, "|   b |\n| --: |\n|   2 |");
+        let result = edit_markdown_table(input, TableOperation::DeleteRow { index: 0 });
+        assert_eq!(result.rows, 0);
+
+        assert!(!edit_markdown_table(input, TableOperation::DeleteRow { index: 1 }).success);
+        assert!(!edit_markdown_table(input, TableOperation::InsertColumn { index: 3 }).success);
+        let single = "| a |\n|---|";
+        assert!(!edit_markdown_table(single, TableOperation::DeleteColumn { index: 0 }).success);
+    }
+}
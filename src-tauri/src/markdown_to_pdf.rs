@@ -1,14 +1,85 @@
-use pulldown_cmark::{Options, Parser};
+use crate::char_counter::count_chars;
+use crate::http_client::{describe_error, shared_client};
+use crate::pdf_tools::extract_pdf_text;
+use lopdf::{Bookmark, Document, Object};
+use percent_encoding::percent_decode_str;
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::process::Command;
+use std::sync::OnceLock;
+use std::time::Duration;
+use syntect::highlighting::ThemeSet;
+use syntect::html::highlighted_html_for_string;
+use syntect::parsing::{SyntaxReference, SyntaxSet};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarkdownInfo {
     pub file_name: String,
     pub file_size: u64,
     pub content: String,
+    #[serde(default)]
+    pub stats: MarkdownStats,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarkdownStats {
+    pub outline: Vec<OutlineEntry>,
+    /// Counted over the rendered text, so markup and code blocks are left out
+    pub word_count: usize,
+    pub char_count: usize,
+    pub char_count_no_spaces: usize,
+    pub code_block_count: usize,
+    /// Most used first; indented and unlabelled blocks are counted under an empty language
+    pub code_languages: Vec<CodeLanguageCount>,
+    pub images: Vec<MarkdownReference>,
+    pub links: Vec<MarkdownReference>,
+}
+
+/// A heading with the headings of lower levels that follow it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutlineEntry {
+    pub level: u8,
+    pub text: String,
+    pub line: usize,
+    pub children: Vec<OutlineEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CodeLanguageCount {
+    pub language: String,
+    pub count: usize,
+}
+
+/// A link or image target with its text (alt text for images) and 1-based line number
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarkdownReference {
+    pub url: String,
+    pub text: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokenLink {
+    pub url: String,
+    pub line: usize,
+    pub is_image: bool,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkCheckResult {
+    pub success: bool,
+    pub checked_local: usize,
+    /// Distinct http(s) URLs requested
+    pub checked_external: usize,
+    /// http(s) links left unchecked because external checks were turned off
+    pub skipped_external: usize,
+    /// In document order
+    pub broken: Vec<BrokenLink>,
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,9 +95,364 @@ pub struct MarkdownToPdfResult {
 pub struct MarkdownToHtmlResult {
     pub success: bool,
     pub html: String,
+    /// Name of the syntect theme used for code blocks
+    pub theme: String,
+    /// Linked list of the H1-H3 headings; empty when the document has none
+    pub toc_html: String,
+    /// Options the HTML was rendered with, so the preview knows whether to run mermaid.js/KaTeX
+    pub options: MarkdownRenderOptions,
     pub error: Option<String>,
 }
 
+/// Markdown extensions applied on top of CommonMark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarkdownRenderOptions {
+    /// GitHub Flavored Markdown: tables, task lists, strikethrough and footnotes
+    pub gfm: bool,
+    /// Emit ```mermaid blocks as `<div class="mermaid">` for mermaid.js instead of code
+    pub mermaid: bool,
+    /// Keep `$...$` and `$$...$$` as `math-inline`/`math-display` spans for KaTeX
+    pub math: bool,
+}
+
+impl Default for MarkdownRenderOptions {
+    fn default() -> Self {
+        Self {
+            gfm: true,
+            mermaid: true,
+            math: true,
+        }
+    }
+}
+
+impl MarkdownRenderOptions {
+    fn parser_options(self) -> Options {
+        let mut options = Options::all();
+        if !self.gfm {
+            options.remove(
+                Options::ENABLE_GFM
+                    | Options::ENABLE_TABLES
+                    | Options::ENABLE_TASKLISTS
+                    | Options::ENABLE_STRIKETHROUGH
+                    | Options::ENABLE_OLD_FOOTNOTES,
+            );
+        }
+        if !self.math {
+            options.remove(Options::ENABLE_MATH);
+        }
+        options
+    }
+}
+
+/// A heading that appears in the table of contents
+#[derive(Debug, Clone, PartialEq)]
+struct Heading {
+    level: u8,
+    title: String,
+    anchor: String,
+}
+
+/// Color theme for syntax highlighted code blocks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CodeTheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl CodeTheme {
+    fn syntect_name(self) -> &'static str {
+        match self {
+            CodeTheme::Light => "InspiredGitHub",
+            CodeTheme::Dark => "base16-ocean.dark",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum PaperSize {
+    #[default]
+    A4,
+    A3,
+    Letter,
+    Legal,
+    /// Any size in millimeters, given in portrait orientation
+    Custom {
+        width_mm: f64,
+        height_mm: f64,
+    },
+}
+
+impl PaperSize {
+    /// Portrait width and height in millimeters
+    fn dimensions_mm(self) -> (f64, f64) {
+        match self {
+            PaperSize::A4 => (210.0, 297.0),
+            PaperSize::A3 => (297.0, 420.0),
+            PaperSize::Letter => (215.9, 279.4),
+            PaperSize::Legal => (215.9, 355.6),
+            PaperSize::Custom {
+                width_mm,
+                height_mm,
+            } => (width_mm, height_mm),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Orientation {
+    #[default]
+    Portrait,
+    Landscape,
+}
+
+/// Page margins in millimeters
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Margins {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl Default for Margins {
+    fn default() -> Self {
+        Self {
+            top: 15.0,
+            right: 15.0,
+            bottom: 15.0,
+            left: 15.0,
+        }
+    }
+}
+
+/// Page layout for PDF output.
+///
+/// `header_text` and `footer_text` may contain `{page}`, `{total}`, `{date}` and `{title}`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PdfPageOptions {
+    pub paper_size: PaperSize,
+    pub orientation: Orientation,
+    pub margin_mm: Margins,
+    pub header_text: Option<String>,
+    pub footer_text: Option<String>,
+    /// Show "page / total" in the bottom right corner
+    pub page_numbers: bool,
+}
+
+/// Piece of a header or footer template
+#[derive(Debug, Clone, PartialEq)]
+enum HeaderPart {
+    Text(String),
+    Page,
+    Total,
+}
+
+/// Page settings checked and resolved once, then shared by every converter pass
+#[derive(Debug, Clone, PartialEq)]
+struct PageSetup {
+    width_mm: f64,
+    height_mm: f64,
+    margins: Margins,
+    header: Option<Vec<HeaderPart>>,
+    footer: Option<Vec<HeaderPart>>,
+    page_numbers: bool,
+}
+
+impl PdfPageOptions {
+    fn resolve(&self, title: &str, date: &str) -> Result<PageSetup, String> {
+        let (width, height) = self.paper_size.dimensions_mm();
+        if !(width.is_finite() && height.is_finite() && width > 0.0 && height > 0.0) {
+            return Err(format!("Invalid paper size: {}mm x {}mm", width, height));
+        }
+        let (width_mm, height_mm) = match self.orientation {
+            Orientation::Portrait => (width, height),
+            Orientation::Landscape => (height, width),
+        };
+
+        let margins = self.margin_mm;
+        if [margins.top, margins.right, margins.bottom, margins.left]
+            .iter()
+            .any(|m| !m.is_finite() || *m < 0.0)
+        {
+            return Err("Margins must be zero or positive".to_string());
+        }
+        let content_width = width_mm - margins.left - margins.right;
+        let content_height = height_mm - margins.top - margins.bottom;
+        if content_width <= 0.0 || content_height <= 0.0 {
+            return Err(format!(
+                "Margins leave no room for content: {:.1}mm x {:.1}mm on a {:.1}mm x {:.1}mm page",
+                content_width, content_height, width_mm, height_mm
+            ));
+        }
+
+        let template = |text: &Option<String>| {
+            text.as_deref()
+                .filter(|t| !t.trim().is_empty())
+                .map(|t| parse_header_template(t, title, date))
+        };
+        Ok(PageSetup {
+            width_mm,
+            height_mm,
+            margins,
+            header: template(&self.header_text),
+            footer: template(&self.footer_text),
+            page_numbers: self.page_numbers,
+        })
+    }
+}
+
+/// Split a header/footer template into text and page counters, filling in `{date}` and `{title}`
+fn parse_header_template(template: &str, title: &str, date: &str) -> Vec<HeaderPart> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let placeholder = ["{page}", "{total}", "{date}", "{title}"]
+            .into_iter()
+            .find(|p| rest.starts_with(p));
+        match placeholder {
+            Some("{date}") => text.push_str(date),
+            Some("{title}") => text.push_str(title),
+            Some(counter) => {
+                if !text.is_empty() {
+                    parts.push(HeaderPart::Text(std::mem::take(&mut text)));
+                }
+                parts.push(if counter == "{page}" {
+                    HeaderPart::Page
+                } else {
+                    HeaderPart::Total
+                });
+            }
+            None => {
+                text.push('{');
+                rest = &rest[1..];
+                continue;
+            }
+        }
+        rest = &rest[placeholder.map_or(1, str::len)..];
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        parts.push(HeaderPart::Text(text));
+    }
+    parts
+}
+
+/// wkhtmltopdf header text, where `[page]` and `[topage]` are substituted per page
+fn wkhtmltopdf_header(parts: &[HeaderPart]) -> String {
+    parts
+        .iter()
+        .map(|part| match part {
+            HeaderPart::Text(text) => text.clone(),
+            HeaderPart::Page => "[page]".to_string(),
+            HeaderPart::Total => "[topage]".to_string(),
+        })
+        .collect()
+}
+
+/// CSS `content` value for an `@page` margin box
+fn css_header_content(parts: &[HeaderPart]) -> String {
+    parts
+        .iter()
+        .map(|part| match part {
+            HeaderPart::Text(text) => format!(
+                "\"{}\"",
+                text.replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', " ")
+                    // Keep user text from closing the surrounding <style> element
+                    .replace('<', "\\3C ")
+            ),
+            HeaderPart::Page => "counter(page)".to_string(),
+            HeaderPart::Total => "counter(pages)".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl PageSetup {
+    fn page_number_parts() -> Vec<HeaderPart> {
+        vec![
+            HeaderPart::Page,
+            HeaderPart::Text(" / ".to_string()),
+            HeaderPart::Total,
+        ]
+    }
+
+    fn wkhtmltopdf_args(&self) -> Vec<String> {
+        let mm = |v: f64| format!("{}mm", v);
+        let mut args = vec![
+            "--page-width".to_string(),
+            mm(self.width_mm),
+            "--page-height".to_string(),
+            mm(self.height_mm),
+            "--margin-top".to_string(),
+            mm(self.margins.top),
+            "--margin-bottom".to_string(),
+            mm(self.margins.bottom),
+            "--margin-left".to_string(),
+            mm(self.margins.left),
+            "--margin-right".to_string(),
+            mm(self.margins.right),
+        ];
+        let mut push = |flag: &str, parts: &[HeaderPart]| {
+            args.push(flag.to_string());
+            args.push(wkhtmltopdf_header(parts));
+        };
+        if let Some(header) = &self.header {
+            push("--header-center", header);
+        }
+        if let Some(footer) = &self.footer {
+            push("--footer-center", footer);
+        }
+        if self.page_numbers {
+            push("--footer-right", &Self::page_number_parts());
+        }
+        if self.header.is_some() || self.footer.is_some() || self.page_numbers {
+            args.extend(["--header-font-size", "9", "--footer-font-size", "9"].map(String::from));
+        }
+        args
+    }
+
+    /// `@page` rule for Chrome, which takes the page size, margins and margin boxes from CSS
+    fn page_css(&self) -> String {
+        let mut boxes = String::new();
+        let mut push = |position: &str, parts: &[HeaderPart]| {
+            boxes.push_str(&format!(
+                " @{} {{ content: {}; font-size: 9px; color: #666; }}",
+                position,
+                css_header_content(parts)
+            ));
+        };
+        if let Some(header) = &self.header {
+            push("top-center", header);
+        }
+        if let Some(footer) = &self.footer {
+            push("bottom-center", footer);
+        }
+        if self.page_numbers {
+            push("bottom-right", &Self::page_number_parts());
+        }
+        format!(
+            "@page {{ size: {}mm {}mm; margin: {}mm {}mm {}mm {}mm;{} }}\n",
+            self.width_mm,
+            self.height_mm,
+            self.margins.top,
+            self.margins.right,
+            self.margins.bottom,
+            self.margins.left,
+            boxes
+        )
+    }
+}
+
 pub fn read_markdown(path: &str) -> Result<MarkdownInfo, String> {
     let metadata =
         fs::metadata(path).map_err(|e| format!("Failed to read file metadata: {}", e))?;
@@ -40,38 +466,545 @@ pub fn read_markdown(path: &str) -> Result<MarkdownInfo, String> {
 
     let content = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
 
+    let stats = markdown_stats(&content);
+
     Ok(MarkdownInfo {
         file_name,
         file_size,
         content,
+        stats,
     })
 }
 
-pub fn markdown_to_html(markdown: &str) -> MarkdownToHtmlResult {
-    let options = Options::all();
-    let parser = Parser::new_ext(markdown, options);
-    let mut html_output = String::new();
+pub fn markdown_stats(markdown: &str) -> MarkdownStats {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(markdown.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset);
+
+    let mut stats = MarkdownStats::default();
+    let mut text = String::new();
+    let mut heading: Option<OutlineEntry> = None;
+    // Open links and images as (is_image, reference); an image can sit inside a link
+    let mut references: Vec<(bool, MarkdownReference)> = Vec::new();
+    let mut in_code_block = false;
+    let mut languages: HashMap<String, usize> = HashMap::new();
+
+    let parser = Parser::new_ext(markdown, MarkdownRenderOptions::default().parser_options());
+    for (event, range) in parser.into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                heading = Some(OutlineEntry {
+                    level: level as u8,
+                    text: String::new(),
+                    line: line_of(range.start),
+                    children: Vec::new(),
+                });
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(mut entry) = heading.take() {
+                    entry.text = entry.text.trim().to_string();
+                    insert_outline_entry(&mut stats.outline, entry);
+                }
+                text.push('\n');
+            }
+            Event::Start(Tag::CodeBlock(kind)) => {
+                in_code_block = true;
+                stats.code_block_count += 1;
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                *languages.entry(language).or_default() += 1;
+            }
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Start(Tag::Link { ref dest_url, .. })
+            | Event::Start(Tag::Image { ref dest_url, .. }) => {
+                let is_image = matches!(event, Event::Start(Tag::Image { .. }));
+                references.push((
+                    is_image,
+                    MarkdownReference {
+                        url: dest_url.to_string(),
+                        text: String::new(),
+                        line: line_of(range.start),
+                    },
+                ));
+            }
+            Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
+                if let Some((is_image, reference)) = references.pop() {
+                    if let Some((_, outer)) = references.last_mut() {
+                        outer.text.push_str(&reference.text);
+                    }
+                    if is_image {
+                        stats.images.push(reference);
+                    } else {
+                        stats.links.push(reference);
+                    }
+                }
+            }
+            Event::Text(t) | Event::Code(t) if !in_code_block => {
+                text.push_str(&t);
+                if let Some(entry) = heading.as_mut() {
+                    entry.text.push_str(&t);
+                }
+                if let Some((_, reference)) = references.last_mut() {
+                    reference.text.push_str(&t);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak | Event::End(TagEnd::Paragraph) => text.push('\n'),
+            _ => {}
+        }
+    }
+
+    let counts = count_chars(&text);
+    stats.word_count = counts.word_count;
+    stats.char_count = counts.char_count_no_newlines;
+    stats.char_count_no_spaces = counts.char_count_no_spaces;
+
+    stats.code_languages = languages
+        .into_iter()
+        .map(|(language, count)| CodeLanguageCount { language, count })
+        .collect();
+    stats.code_languages.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.language.cmp(&b.language))
+    });
+    stats
+}
+
+fn insert_outline_entry(entries: &mut Vec<OutlineEntry>, entry: OutlineEntry) {
+    match entries.last_mut() {
+        Some(last) if last.level < entry.level => insert_outline_entry(&mut last.children, entry),
+        _ => entries.push(entry),
+    }
+}
+
+/// Timeout for each http(s) link
+const LINK_CHECK_TIMEOUT_SECS: u64 = 5;
+
+/// Checks that relative links and images point to existing files and, when
+/// `check_external` is set, that http(s) links answer a HEAD request
+pub async fn check_markdown_links(path: &str, check_external: bool) -> LinkCheckResult {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            return LinkCheckResult {
+                success: false,
+                checked_local: 0,
+                checked_external: 0,
+                skipped_external: 0,
+                broken: Vec::new(),
+                error: Some(format!("Failed to read file: {}", e)),
+            }
+        }
+    };
+    let base_dir = Path::new(path).parent().unwrap_or(Path::new(""));
+    let stats = markdown_stats(&content);
+    let references: Vec<(bool, MarkdownReference)> = stats
+        .links
+        .into_iter()
+        .map(|r| (false, r))
+        .chain(stats.images.into_iter().map(|r| (true, r)))
+        .collect();
+
+    let mut result = LinkCheckResult {
+        success: true,
+        checked_local: 0,
+        checked_external: 0,
+        skipped_external: 0,
+        broken: Vec::new(),
+        error: None,
+    };
+    let mut external: Vec<(bool, MarkdownReference)> = Vec::new();
+    for (is_image, reference) in references {
+        let url = reference.url.trim();
+        match url_scheme(url) {
+            Some(scheme) if scheme == "http" || scheme == "https" => {
+                external.push((is_image, reference));
+            }
+            // mailto:, data: and the like have nothing to check
+            Some(_) => {}
+            None => {
+                let file = url.split(['#', '?']).next().unwrap_or("");
+                // Links to an anchor in this document
+                if file.is_empty() {
+                    continue;
+                }
+                result.checked_local += 1;
+                let file = percent_decode_str(file).decode_utf8_lossy();
+                if !base_dir.join(file.as_ref()).exists() {
+                    result.broken.push(BrokenLink {
+                        url: reference.url,
+                        line: reference.line,
+                        is_image,
+                        reason: "File not found".to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    if check_external {
+        let mut urls: Vec<&str> = external.iter().map(|(_, r)| r.url.trim()).collect();
+        urls.sort_unstable();
+        urls.dedup();
+        result.checked_external = urls.len();
+
+        let checks: Vec<_> = urls
+            .iter()
+            .map(|url| {
+                let url = url.to_string();
+                tauri::async_runtime::spawn(async move { check_url(&url).await })
+            })
+            .collect();
+        let mut failures: HashMap<&str, String> = HashMap::new();
+        for (url, check) in urls.iter().zip(checks) {
+            let result = check
+                .await
+                .unwrap_or_else(|e| Err(format!("Link check failed: {}", e)));
+            if let Err(reason) = result {
+                failures.insert(url, reason);
+            }
+        }
+        for (is_image, reference) in &external {
+            if let Some(reason) = failures.get(reference.url.trim()) {
+                result.broken.push(BrokenLink {
+                    url: reference.url.clone(),
+                    line: reference.line,
+                    is_image: *is_image,
+                    reason: reason.clone(),
+                });
+            }
+        }
+    } else {
+        result.skipped_external = external.len();
+    }
+
+    result.broken.sort_by_key(|b| b.line);
+    result
+}
+
+/// The URL scheme in lowercase; single letters are taken as Windows drive letters
+fn url_scheme(url: &str) -> Option<String> {
+    let (scheme, _) = url.split_once(':')?;
+    let valid = scheme.len() > 1
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then(|| scheme.to_ascii_lowercase())
+}
 
-    pulldown_cmark::html::push_html(&mut html_output, parser);
+async fn check_url(url: &str) -> Result<(), String> {
+    let client = shared_client();
+    let timeout = Duration::from_secs(LINK_CHECK_TIMEOUT_SECS);
+    let mut response = client.head(url).timeout(timeout).send().await;
+    // Some servers don't implement HEAD
+    if let Ok(head) = &response {
+        if matches!(head.status().as_u16(), 405 | 501) {
+            response = client.get(url).timeout(timeout).send().await;
+        }
+    }
+    match response {
+        Ok(response)
+            if response.status().is_client_error() || response.status().is_server_error() =>
+        {
+            let status = response.status();
+            Err(format!(
+                "HTTP {} {}",
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default()
+            ))
+        }
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("Request failed: {}", describe_error(&e))),
+    }
+}
+
+pub fn markdown_to_html(
+    markdown: &str,
+    theme: CodeTheme,
+    options: MarkdownRenderOptions,
+) -> MarkdownToHtmlResult {
+    let (html, headings) = render_html(markdown, theme, options);
 
     MarkdownToHtmlResult {
         success: true,
-        html: html_output,
+        html,
+        theme: theme.syntect_name().to_string(),
+        toc_html: toc_list_html(&headings, None),
+        options,
         error: None,
     }
 }
 
-fn generate_full_html(markdown: &str, base_path: Option<&str>) -> String {
-    let options = Options::all();
-    let parser = Parser::new_ext(markdown, options);
-    let mut html_body = String::new();
-    pulldown_cmark::html::push_html(&mut html_body, parser);
+/// Render Markdown to HTML, replacing fenced code blocks with syntax highlighted markup.
+///
+/// Headings get anchor ids so the table of contents can link to them; the H1-H3 headings
+/// are returned alongside the HTML.
+fn render_html(
+    markdown: &str,
+    theme: CodeTheme,
+    options: MarkdownRenderOptions,
+) -> (String, Vec<Heading>) {
+    let parser = Parser::new_ext(markdown, options.parser_options());
+    let mut events = Vec::new();
+    let mut code_block: Option<(String, String)> = None;
+    // Indices of the open lists and items, so task list markers can tag them
+    let mut open_lists: Vec<usize> = Vec::new();
+    let mut open_items: Vec<usize> = Vec::new();
+
+    for event in parser {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) => {
+                code_block = Some((lang.to_string(), String::new()));
+            }
+            Event::Text(text) if code_block.is_some() => {
+                if let Some((_, code)) = code_block.as_mut() {
+                    code.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) if code_block.is_some() => {
+                if let Some((lang, code)) = code_block.take() {
+                    let html = if options.mermaid && is_mermaid(&lang) {
+                        format!("<div class=\"mermaid\">{}</div>\n", escape_html(&code))
+                    } else {
+                        highlight_code(&code, &lang, theme)
+                    };
+                    events.push(Event::Html(CowStr::from(html)));
+                }
+            }
+            Event::Start(Tag::List(_)) => {
+                open_lists.push(events.len());
+                events.push(event);
+            }
+            Event::End(TagEnd::List(_)) => {
+                open_lists.pop();
+                events.push(event);
+            }
+            Event::Start(Tag::Item) => {
+                open_items.push(events.len());
+                events.push(event);
+            }
+            Event::End(TagEnd::Item) => {
+                open_items.pop();
+                events.push(event);
+            }
+            Event::TaskListMarker(checked) => {
+                mark_task_item(&mut events, &open_lists, &open_items);
+                events.push(Event::Html(CowStr::from(task_checkbox_html(checked))));
+            }
+            other => events.push(other),
+        }
+    }
+
+    let headings = assign_heading_anchors(&mut events);
+
+    let mut html_output = String::new();
+    pulldown_cmark::html::push_html(&mut html_output, events.into_iter());
+    (html_output, headings)
+}
+
+fn is_mermaid(lang: &str) -> bool {
+    lang.split_whitespace()
+        .next()
+        .is_some_and(|token| token.eq_ignore_ascii_case("mermaid"))
+}
+
+/// GitHub's markup for task lists: classes on the item and its list so they can drop the bullet
+fn mark_task_item(events: &mut [Event], open_lists: &[usize], open_items: &[usize]) {
+    if let Some(&item) = open_items.last() {
+        events[item] = Event::Html(CowStr::from("<li class=\"task-list-item\">"));
+    }
+    let Some(&list) = open_lists.last() else {
+        return;
+    };
+    // Only bullet lists are retagged; ordered ones keep their start number
+    if matches!(events[list], Event::Start(Tag::List(None))) {
+        events[list] = Event::Html(CowStr::from("<ul class=\"contains-task-list\">\n"));
+    }
+}
+
+fn task_checkbox_html(checked: bool) -> String {
+    format!(
+        "<input type=\"checkbox\" class=\"task-list-item-checkbox\" disabled=\"\"{} /> ",
+        if checked { " checked=\"\"" } else { "" }
+    )
+}
+
+fn assign_heading_anchors(events: &mut [Event]) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut used_anchors: HashMap<String, usize> = HashMap::new();
+
+    for i in 0..events.len() {
+        let Event::Start(Tag::Heading { level, id, .. }) = &events[i] else {
+            continue;
+        };
+        let level = *level as u8;
+        let existing_id = id.as_ref().map(|id| id.to_string());
+
+        let title: String = events[i + 1..]
+            .iter()
+            .take_while(|e| !matches!(e, Event::End(TagEnd::Heading(_))))
+            .filter_map(|e| match e {
+                Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                _ => None,
+            })
+            .collect();
+
+        let anchor = existing_id.unwrap_or_else(|| unique_anchor(&title, &mut used_anchors));
+        if let Event::Start(Tag::Heading { id, .. }) = &mut events[i] {
+            *id = Some(CowStr::from(anchor.clone()));
+        }
+
+        if level <= 3 {
+            headings.push(Heading {
+                level,
+                title: title.trim().to_string(),
+                anchor,
+            });
+        }
+    }
+
+    headings
+}
+
+/// GitHub-style slug (lowercase, spaces to hyphens) with a numeric suffix for duplicates
+fn unique_anchor(title: &str, used: &mut HashMap<String, usize>) -> String {
+    let slug: String = title
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() => Some(c),
+            ' ' | '-' | '_' => Some('-'),
+            _ => None,
+        })
+        .collect();
+    let slug = if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug
+    };
+
+    let count = used.entry(slug.clone()).or_insert(0);
+    let anchor = if *count == 0 {
+        slug
+    } else {
+        format!("{}-{}", slug, count)
+    };
+    *count += 1;
+    anchor
+}
+
+/// Render the headings as a linked list. Page numbers are shown when provided.
+fn toc_list_html(headings: &[Heading], page_numbers: Option<&[Option<u32>]>) -> String {
+    if headings.is_empty() {
+        return String::new();
+    }
+
+    let mut html = String::from("<nav class=\"toc\">\n<ul>\n");
+    for (i, heading) in headings.iter().enumerate() {
+        let page = match page_numbers {
+            Some(pages) => format!(
+                "<span class=\"toc-page\">{}</span>",
+                pages
+                    .get(i)
+                    .copied()
+                    .flatten()
+                    .map(|p| p.to_string())
+                    .unwrap_or_default()
+            ),
+            None => String::new(),
+        };
+        html.push_str(&format!(
+            "<li class=\"toc-level-{}\"><a href=\"#{}\">{}</a>{}</li>\n",
+            heading.level,
+            escape_html(&heading.anchor),
+            escape_html(&heading.title),
+            page
+        ));
+    }
+    html.push_str("</ul>\n</nav>\n");
+    html
+}
+
+/// The table of contents page inserted before the document body
+fn toc_page_html(headings: &[Heading], page_numbers: &[Option<u32>]) -> String {
+    format!(
+        "<section class=\"toc-page-section\">\n<h1 class=\"toc-title\">目次</h1>\n{}</section>\n",
+        toc_list_html(headings, Some(page_numbers))
+    )
+}
+
+fn highlighter() -> &'static (SyntaxSet, ThemeSet) {
+    static HIGHLIGHTER: OnceLock<(SyntaxSet, ThemeSet)> = OnceLock::new();
+    HIGHLIGHTER.get_or_init(|| {
+        (
+            SyntaxSet::load_defaults_newlines(),
+            ThemeSet::load_defaults(),
+        )
+    })
+}
+
+/// Look up a syntax by the fence info string, mapping common aliases that are missing from the
+/// bundled syntax set onto the closest available grammar
+fn find_syntax<'a>(syntax_set: &'a SyntaxSet, lang: &str) -> Option<&'a SyntaxReference> {
+    let token = lang
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+        .next()?
+        .to_lowercase();
+    let token = match token.as_str() {
+        "" => return None,
+        // TypeScript has no bundled grammar; JavaScript highlighting is close enough
+        "ts" | "typescript" | "tsx" | "jsx" | "javascript" => "js",
+        "shell" | "sh" | "zsh" | "console" => "bash",
+        "yml" => "yaml",
+        "golang" => "go",
+        "c++" => "cpp",
+        "c#" | "csharp" => "cs",
+        other => other,
+    };
+    syntax_set.find_syntax_by_token(token)
+}
+
+fn highlight_code(code: &str, lang: &str, theme: CodeTheme) -> String {
+    let (syntax_set, theme_set) = highlighter();
+    let highlighted = find_syntax(syntax_set, lang).and_then(|syntax| {
+        let theme = theme_set.themes.get(theme.syntect_name())?;
+        highlighted_html_for_string(code, syntax_set, syntax, theme).ok()
+    });
 
+    highlighted.unwrap_or_else(|| {
+        let lang = lang.split_whitespace().next().unwrap_or("");
+        if lang.is_empty() {
+            format!("<pre><code>{}</code></pre>\n", escape_html(code))
+        } else {
+            format!(
+                "<pre><code class=\"language-{}\">{}</code></pre>\n",
+                escape_html(lang),
+                escape_html(code)
+            )
+        }
+    })
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub(crate) fn generate_full_html(html_body: &str, base_path: Option<&str>) -> String {
     // base_pathがあれば画像の相対パスを絶対パスに変換
     let html_body = if let Some(base) = base_path {
-        convert_relative_paths(&html_body, base)
+        convert_relative_paths(html_body, base)
     } else {
-        html_body
+        html_body.to_string()
     };
 
     format!(
@@ -203,6 +1136,61 @@ fn generate_full_html(markdown: &str, base_path: Option<&str>) -> String {
         em {{
             font-style: italic;
         }}
+        del {{
+            color: #888;
+        }}
+        .contains-task-list {{
+            list-style: none;
+            padding-left: 0.5em;
+        }}
+        .task-list-item-checkbox {{
+            margin: 0 0.5em 0 0;
+            vertical-align: middle;
+        }}
+        .footnote-definition {{
+            font-size: 0.9em;
+            color: #555;
+        }}
+        .footnote-definition p {{
+            display: inline;
+        }}
+        .mermaid, .math-display {{
+            display: block;
+            font-family: 'SF Mono', 'Monaco', 'Menlo', 'Consolas', monospace;
+            white-space: pre-wrap;
+            margin: 0 0 1em 0;
+        }}
+        .toc-page-section {{
+            page-break-after: always;
+        }}
+        .toc-title {{
+            border-bottom: none;
+        }}
+        .toc ul {{
+            list-style: none;
+            padding-left: 0;
+        }}
+        .toc li {{
+            display: flex;
+            align-items: baseline;
+            margin-bottom: 0.4em;
+        }}
+        .toc li a {{
+            flex: 1;
+            color: #333;
+        }}
+        .toc-level-2 {{
+            padding-left: 1.5em;
+        }}
+        .toc-level-3 {{
+            padding-left: 3em;
+            font-size: 0.95em;
+        }}
+        .toc-page {{
+            min-width: 3em;
+            text-align: right;
+            color: #666;
+        }}
         @media print {{
             body {{
                 padding: 0;
@@ -277,15 +1265,38 @@ pub fn convert_markdown_to_pdf(
     markdown: &str,
     output_path: &str,
     source_path: Option<&str>,
+    theme: CodeTheme,
+    include_toc: bool,
+    options: MarkdownRenderOptions,
+    page_options: &PdfPageOptions,
 ) -> MarkdownToPdfResult {
-    let converter = find_pdf_converter();
+    let (html_body, headings) = render_html(markdown, theme, options);
 
-    match converter {
-        Some(tool) if tool == "wkhtmltopdf" => {
-            convert_with_wkhtmltopdf(markdown, output_path, source_path)
+    let title = headings
+        .first()
+        .map(|h| h.title.clone())
+        .or_else(|| {
+            source_path
+                .and_then(|p| Path::new(p).file_stem())
+                .map(|stem| stem.to_string_lossy().to_string())
+        })
+        .unwrap_or_default();
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let page = match page_options.resolve(&title, &date) {
+        Ok(page) => page,
+        Err(e) => {
+            return MarkdownToPdfResult {
+                success: false,
+                output_path: String::new(),
+                page_count: 0,
+                file_size: 0,
+                error: Some(e),
+            }
         }
-        Some(tool) => convert_with_chrome(&tool, markdown, output_path, source_path),
-        None => MarkdownToPdfResult {
+    };
+
+    let Some(converter) = find_pdf_converter() else {
+        return MarkdownToPdfResult {
             success: false,
             output_path: String::new(),
             page_count: 0,
@@ -293,22 +1304,183 @@ pub fn convert_markdown_to_pdf(
             error: Some(
                 "PDF converter not found. Please install wkhtmltopdf or Google Chrome.".to_string(),
             ),
-        },
+        };
+    };
+
+    // 見出しがなければ目次ページは出力しない
+    if !include_toc || headings.is_empty() {
+        let html = generate_full_html(&html_body, source_path);
+        return run_converter(&converter, &html, output_path, &page);
     }
+
+    convert_with_toc(
+        &converter,
+        &html_body,
+        &headings,
+        output_path,
+        source_path,
+        &page,
+    )
 }
 
-fn convert_with_wkhtmltopdf(
-    markdown: &str,
+fn run_converter(
+    converter: &str,
+    html: &str,
+    output_path: &str,
+    page: &PageSetup,
+) -> MarkdownToPdfResult {
+    if converter == "wkhtmltopdf" {
+        convert_with_wkhtmltopdf(html, output_path, page)
+    } else {
+        // wkhtmltopdf takes the layout as flags; Chrome reads it from an @page rule
+        let html = html.replacen("</style>", &format!("{}</style>", page.page_css()), 1);
+        convert_with_chrome(converter, &html, output_path)
+    }
+}
+
+/// Convert with a table of contents page.
+///
+/// The HTML converters cannot report where headings land, so the body and the TOC are first
+/// rendered separately: the body to find each heading's page, the TOC to learn how many pages
+/// it takes. The final render then has correct page numbers, and bookmarks are added with lopdf.
+fn convert_with_toc(
+    converter: &str,
+    html_body: &str,
+    headings: &[Heading],
     output_path: &str,
     source_path: Option<&str>,
+    page: &PageSetup,
 ) -> MarkdownToPdfResult {
-    let html = generate_full_html(markdown, source_path);
+    let temp_pdf = std::env::temp_dir().join(format!("md_to_pdf_{}_toc.pdf", std::process::id()));
+    let temp_pdf = temp_pdf.to_string_lossy().to_string();
+
+    let body_pass = run_converter(
+        converter,
+        &generate_full_html(html_body, source_path),
+        &temp_pdf,
+        page,
+    );
+    if !body_pass.success {
+        return body_pass;
+    }
+    let body_pages = locate_headings(&temp_pdf, headings);
+
+    let blank_numbers = vec![None; headings.len()];
+    let toc_pass = run_converter(
+        converter,
+        &generate_full_html(&toc_page_html(headings, &blank_numbers), None),
+        &temp_pdf,
+        page,
+    );
+    let toc_page_count = pdf_page_count(&temp_pdf).max(1);
+    let _ = fs::remove_file(&temp_pdf);
+    if !toc_pass.success {
+        return toc_pass;
+    }
+
+    let page_numbers: Vec<Option<u32>> = body_pages
+        .iter()
+        .map(|page| page.map(|p| p + toc_page_count))
+        .collect();
+    let html = generate_full_html(
+        &format!("{}{}", toc_page_html(headings, &page_numbers), html_body),
+        source_path,
+    );
+
+    let result = run_converter(converter, &html, output_path, page);
+    if result.success {
+        // Bookmarks are a convenience; the PDF itself is already complete
+        let _ = add_outline(output_path, headings, &page_numbers);
+    }
+    result
+}
+
+fn pdf_page_count(path: &str) -> u32 {
+    Document::load(path)
+        .map(|doc| doc.get_pages().len() as u32)
+        .unwrap_or(0)
+}
+
+/// Find the 1-based page each heading appears on by searching the extracted text in order
+fn locate_headings(pdf_path: &str, headings: &[Heading]) -> Vec<Option<u32>> {
+    let normalize =
+        |text: &str| -> String { text.chars().filter(|c| !c.is_whitespace()).collect() };
 
+    let result = extract_pdf_text(pdf_path, None, None);
+    let page_texts: Vec<String> = result.pages.iter().map(|p| normalize(&p.text)).collect();
+
+    let mut cursor = 0;
+    headings
+        .iter()
+        .map(|heading| {
+            let needle = normalize(&heading.title);
+            if needle.is_empty() {
+                return None;
+            }
+            let index = (cursor..page_texts.len()).find(|&i| page_texts[i].contains(&needle))?;
+            cursor = index;
+            Some(result.pages[index].page)
+        })
+        .collect()
+}
+
+/// Add a bookmark outline mirroring the heading hierarchy, unless the converter already did
+fn add_outline(
+    pdf_path: &str,
+    headings: &[Heading],
+    page_numbers: &[Option<u32>],
+) -> Result<(), String> {
+    let mut doc = Document::load(pdf_path).map_err(|e| format!("Failed to load PDF: {}", e))?;
+    let has_outline = doc
+        .catalog()
+        .map(|catalog| catalog.has(b"Outlines"))
+        .unwrap_or(false);
+    if has_outline {
+        return Ok(());
+    }
+
+    let pages = doc.get_pages();
+    let mut parents: Vec<(u8, u32)> = Vec::new();
+    for (heading, page) in headings.iter().zip(page_numbers) {
+        let Some(&page_id) = page.and_then(|p| pages.get(&p)) else {
+            continue;
+        };
+        while parents
+            .last()
+            .is_some_and(|(level, _)| *level >= heading.level)
+        {
+            parents.pop();
+        }
+        let parent = parents.last().map(|(_, id)| *id);
+        let bookmark = Bookmark::new(heading.title.clone(), [0.0, 0.0, 0.0], 0, page_id);
+        let id = doc.add_bookmark(bookmark, parent);
+        parents.push((heading.level, id));
+    }
+
+    let Some(outline_id) = doc.build_outline() else {
+        return Ok(());
+    };
+    let catalog = doc
+        .catalog_mut()
+        .map_err(|e| format!("Failed to get catalog: {}", e))?;
+    catalog.set("Outlines", Object::Reference(outline_id));
+    catalog.set("PageMode", "UseOutlines");
+
+    doc.save(pdf_path)
+        .map_err(|e| format!("Failed to save PDF: {}", e))?;
+    Ok(())
+}
+
+fn convert_with_wkhtmltopdf(
+    html: &str,
+    output_path: &str,
+    page: &PageSetup,
+) -> MarkdownToPdfResult {
     // 一時HTMLファイルを作成
     let temp_dir = std::env::temp_dir();
     let temp_html = temp_dir.join(format!("md_to_pdf_{}.html", std::process::id()));
 
-    if let Err(e) = fs::write(&temp_html, &html) {
+    if let Err(e) = fs::write(&temp_html, html) {
         return MarkdownToPdfResult {
             success: false,
             output_path: String::new(),
@@ -319,23 +1491,9 @@ fn convert_with_wkhtmltopdf(
     }
 
     let result = Command::new("wkhtmltopdf")
-        .args([
-            "--enable-local-file-access",
-            "--encoding",
-            "UTF-8",
-            "--page-size",
-            "A4",
-            "--margin-top",
-            "15mm",
-            "--margin-bottom",
-            "15mm",
-            "--margin-left",
-            "15mm",
-            "--margin-right",
-            "15mm",
-            temp_html.to_str().unwrap(),
-            output_path,
-        ])
+        .args(["--enable-local-file-access", "--encoding", "UTF-8"])
+        .args(page.wkhtmltopdf_args())
+        .args([temp_html.to_str().unwrap(), output_path])
         .output();
 
     // 一時ファイルを削除
@@ -372,19 +1530,12 @@ fn convert_with_wkhtmltopdf(
     }
 }
 
-fn convert_with_chrome(
-    chrome_path: &str,
-    markdown: &str,
-    output_path: &str,
-    source_path: Option<&str>,
-) -> MarkdownToPdfResult {
-    let html = generate_full_html(markdown, source_path);
-
+fn convert_with_chrome(chrome_path: &str, html: &str, output_path: &str) -> MarkdownToPdfResult {
     // 一時HTMLファイルを作成
     let temp_dir = std::env::temp_dir();
     let temp_html = temp_dir.join(format!("md_to_pdf_{}.html", std::process::id()));
 
-    if let Err(e) = fs::write(&temp_html, &html) {
+    if let Err(e) = fs::write(&temp_html, html) {
         return MarkdownToPdfResult {
             success: false,
             output_path: String::new(),
@@ -438,3 +1589,388 @@ fn convert_with_chrome(
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_html_highlights_known_languages() {
+        let result = markdown_to_html(
+            "```rust\nfn main() {}\n```\n",
+            CodeTheme::Light,
+            MarkdownRenderOptions::default(),
+        );
+
+        assert!(result.success);
+        assert_eq!(result.theme, "InspiredGitHub");
+        assert!(result.html.contains("<span style=\""));
+        assert!(result.html.contains("main"));
+    }
+
+    #[test]
+    fn test_markdown_to_html_maps_language_aliases() {
+        let (syntax_set, _) = highlighter();
+        for lang in [
+            "rust", "js", "ts", "python", "go", "java", "sh", "yml", "json", "sql",
+        ] {
+            assert!(find_syntax(syntax_set, lang).is_some(), "{}", lang);
+        }
+        assert!(find_syntax(syntax_set, "not-a-language").is_none());
+    }
+
+    #[test]
+    fn test_markdown_to_html_falls_back_to_plain_code() {
+        let result = markdown_to_html(
+            "```unknownlang\n<b> & x\n```\n",
+            CodeTheme::Dark,
+            MarkdownRenderOptions::default(),
+        );
+
+        assert_eq!(result.theme, "base16-ocean.dark");
+        assert!(result
+            .html
+            .contains(r#"<pre><code class="language-unknownlang">&lt;b&gt; &amp; x"#));
+        assert!(!result.html.contains("<span style="));
+    }
+
+    #[test]
+    fn test_theme_changes_code_block_colors() {
+        let markdown = "```python\nprint('hi')\n```\n";
+        let light = markdown_to_html(markdown, CodeTheme::Light, MarkdownRenderOptions::default());
+        let dark = markdown_to_html(markdown, CodeTheme::Dark, MarkdownRenderOptions::default());
+
+        assert_ne!(light.html, dark.html);
+        assert!(markdown_to_html(
+            "plain *text*",
+            CodeTheme::Light,
+            MarkdownRenderOptions::default()
+        )
+        .html
+        .contains("<em>text</em>"));
+    }
+
+    fn heading(level: u8, title: &str, anchor: &str) -> Heading {
+        Heading {
+            level,
+            title: title.to_string(),
+            anchor: anchor.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_html_assigns_unique_heading_anchors() {
+        let markdown = "# Intro\n## Setup `cargo`\n## Setup cargo\n#### Deep\n# 日本語 見出し\n";
+        let (html, headings) =
+            render_html(markdown, CodeTheme::Light, MarkdownRenderOptions::default());
+
+        assert_eq!(
+            headings,
+            vec![
+                heading(1, "Intro", "intro"),
+                heading(2, "Setup cargo", "setup-cargo"),
+                heading(2, "Setup cargo", "setup-cargo-1"),
+                heading(1, "日本語 見出し", "日本語-見出し"),
+            ]
+        );
+        assert!(html.contains(r#"<h2 id="setup-cargo-1">"#));
+        assert!(html.contains(r#"<h4 id="deep">"#));
+    }
+
+    #[test]
+    fn test_markdown_to_html_toc() {
+        let result = markdown_to_html(
+            "# Title\n\n## A & B\n",
+            CodeTheme::Light,
+            MarkdownRenderOptions::default(),
+        );
+        assert!(result
+            .toc_html
+            .contains(r##"<li class="toc-level-2"><a href="#a--b">A &amp; B</a></li>"##));
+
+        let empty = markdown_to_html(
+            "just text",
+            CodeTheme::Light,
+            MarkdownRenderOptions::default(),
+        );
+        assert!(empty.toc_html.is_empty());
+    }
+
+    #[test]
+    fn test_toc_page_includes_page_numbers() {
+        let headings = vec![heading(1, "One", "one"), heading(2, "Two", "two")];
+        let html = toc_page_html(&headings, &[Some(2), None]);
+
+        assert!(html.contains(r##"<a href="#one">One</a><span class="toc-page">2</span>"##));
+        assert!(html.contains(r##"<a href="#two">Two</a><span class="toc-page"></span>"##));
+    }
+
+    fn create_pdf(path: &str, page_texts: &[&str]) {
+        use lopdf::{dictionary, Stream};
+
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let kids: Vec<Object> = page_texts
+            .iter()
+            .map(|text| {
+                let content = format!("BT /F1 12 Tf 72 720 Td ({}) Tj ET", text).into_bytes();
+                let content_id = doc.add_object(Stream::new(dictionary! {}, content));
+                Object::Reference(doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+                    "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+                    "Contents" => content_id,
+                }))
+            })
+            .collect();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => page_texts.len() as i64,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    #[test]
+    fn test_locate_headings_and_add_outline() {
+        let path = std::env::temp_dir().join("taurin_md_toc_outline.pdf");
+        let path = path.to_str().unwrap();
+        create_pdf(path, &["Intro text", "Usage more", "Usage again"]);
+
+        let headings = vec![
+            heading(1, "Intro", "intro"),
+            heading(2, "Usage", "usage"),
+            heading(2, "Missing", "missing"),
+            heading(1, "Usage", "usage-1"),
+        ];
+        let pages = locate_headings(path, &headings);
+        assert_eq!(pages, vec![Some(1), Some(2), None, Some(2)]);
+
+        add_outline(path, &headings, &pages).unwrap();
+        let doc = Document::load(path).unwrap();
+        let _ = fs::remove_file(path);
+
+        let catalog = doc.catalog().unwrap();
+        let outline_id = catalog.get(b"Outlines").unwrap().as_reference().unwrap();
+        let outline = doc.get_dictionary(outline_id).unwrap();
+        // Two top-level entries; the first has "Usage" nested beneath it
+        let first = doc
+            .get_dictionary(outline.get(b"First").unwrap().as_reference().unwrap())
+            .unwrap();
+        assert!(first.has(b"First"));
+        assert!(first.has(b"Next"));
+    }
+
+    #[test]
+    fn test_gfm_extensions() {
+        let markdown = "- [x] done\n- [ ] todo\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n~~old~~ note[^1]\n\n[^1]: source\n";
+        let html =
+            markdown_to_html(markdown, CodeTheme::Light, MarkdownRenderOptions::default()).html;
+
+        assert!(html.contains(r#"<ul class="contains-task-list">"#));
+        assert!(html.contains(
+            r#"<li class="task-list-item"><input type="checkbox" class="task-list-item-checkbox" disabled="" checked="" /> done</li>"#
+        ));
+        assert!(html.contains(r#"disabled="" /> todo"#));
+        assert!(html.contains("<table>") && html.contains("<td>2</td>"));
+        assert!(html.contains("<del>old</del>"));
+        assert!(html.contains(r#"class="footnote-reference""#));
+
+        let plain = MarkdownRenderOptions {
+            gfm: false,
+            ..Default::default()
+        };
+        let html = markdown_to_html(markdown, CodeTheme::Light, plain).html;
+        assert!(!html.contains("<table>") && !html.contains("checkbox"));
+        assert!(html.contains("~~old~~"));
+    }
+
+    #[test]
+    fn test_mermaid_and_math_options() {
+        let markdown = "```mermaid\ngraph TD; A-->B\n```\n\nInline $a<b$ and\n\n$$x^2$$\n";
+        let html =
+            markdown_to_html(markdown, CodeTheme::Light, MarkdownRenderOptions::default()).html;
+        assert!(html.contains("<div class=\"mermaid\">graph TD; A--&gt;B\n</div>"));
+        assert!(html.contains(r#"<span class="math math-inline">a&lt;b</span>"#));
+        assert!(html.contains(r#"<span class="math math-display">x^2</span>"#));
+
+        let off = MarkdownRenderOptions {
+            gfm: true,
+            mermaid: false,
+            math: false,
+        };
+        let result = markdown_to_html(markdown, CodeTheme::Light, off);
+        assert_eq!(result.options, off);
+        assert!(!result.html.contains("class=\"mermaid\""));
+        assert!(!result.html.contains("math-inline"));
+        assert!(result.html.contains("$x^2$"));
+    }
+
+    #[test]
+    fn test_header_template_placeholders() {
+        let parts =
+            parse_header_template("{title} - {page}/{total} ({date}) {x}", "Doc", "2024-05-01");
+        assert_eq!(
+            parts,
+            vec![
+                HeaderPart::Text("Doc - ".to_string()),
+                HeaderPart::Page,
+                HeaderPart::Text("/".to_string()),
+                HeaderPart::Total,
+                HeaderPart::Text(" (2024-05-01) {x}".to_string()),
+            ]
+        );
+        assert_eq!(
+            wkhtmltopdf_header(&parts),
+            "Doc - [page]/[topage] (2024-05-01) {x}"
+        );
+        assert_eq!(
+            css_header_content(&parts),
+            r#""Doc - " counter(page) "/" counter(pages) " (2024-05-01) {x}""#
+        );
+        assert_eq!(
+            css_header_content(&parse_header_template(r#"say "hi"\"#, "", "")),
+            r#""say \"hi\"\\""#
+        );
+    }
+
+    #[test]
+    fn test_page_options_resolve() {
+        let options = PdfPageOptions {
+            paper_size: PaperSize::Letter,
+            orientation: Orientation::Landscape,
+            footer_text: Some("{page}".to_string()),
+            header_text: Some("  ".to_string()),
+            ..Default::default()
+        };
+        let page = options.resolve("T", "D").unwrap();
+        assert_eq!((page.width_mm, page.height_mm), (279.4, 215.9));
+        assert_eq!(page.header, None);
+        assert_eq!(page.footer, Some(vec![HeaderPart::Page]));
+        let args = page.wkhtmltopdf_args();
+        assert!(args.windows(2).any(|w| w == ["--page-width", "279.4mm"]));
+        assert!(args.windows(2).any(|w| w == ["--footer-center", "[page]"]));
+        assert!(page.page_css().contains("size: 279.4mm 215.9mm"));
+
+        let zero = PdfPageOptions {
+            margin_mm: Margins {
+                top: 0.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 0.0,
+            },
+            ..Default::default()
+        };
+        assert!(zero.resolve("", "").is_ok());
+
+        let tiny = PdfPageOptions {
+            paper_size: PaperSize::Custom {
+                width_mm: 20.0,
+                height_mm: 20.0,
+            },
+            ..Default::default()
+        };
+        assert!(tiny.resolve("", "").unwrap_err().contains("no room"));
+
+        let invalid = PdfPageOptions {
+            paper_size: PaperSize::Custom {
+                width_mm: f64::NAN,
+                height_mm: 100.0,
+            },
+            ..Default::default()
+        };
+        assert!(invalid.resolve("", "").is_err());
+    }
+
+    #[test]
+    fn test_convert_rejects_impossible_layout() {
+        let options = PdfPageOptions {
+            margin_mm: Margins {
+                left: 150.0,
+                right: 150.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = convert_markdown_to_pdf(
+            "# Title",
+            "/tmp/never_written.pdf",
+            None,
+            CodeTheme::Light,
+            false,
+            MarkdownRenderOptions::default(),
+            &options,
+        );
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("no room"));
+    }
+
+    #[test]
+    fn test_markdown_stats() {
+        let markdown = "# Title\n\nSome **bold** words and [a link](other.md).\n\n## Part\n\n### Detail\n\n![logo](img/logo.png)\n\n## Second\n\n```rust\nfn main() {}\n```\n\n```rust\n```\n\n    indented\n";
+        let stats = markdown_stats(markdown);
+
+        assert_eq!(stats.outline.len(), 1);
+        let title = &stats.outline[0];
+        assert_eq!((title.text.as_str(), title.line), ("Title", 1));
+        let children: Vec<&str> = title.children.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(children, ["Part", "Second"]);
+        assert_eq!(title.children[0].children[0].text, "Detail");
+
+        assert_eq!(stats.code_block_count, 3);
+        assert_eq!(
+            stats.code_languages[0],
+            CodeLanguageCount {
+                language: "rust".to_string(),
+                count: 2
+            }
+        );
+        assert_eq!(stats.links[0].url, "other.md");
+        assert_eq!(stats.links[0].text, "a link");
+        assert_eq!(stats.links[0].line, 3);
+        assert_eq!(stats.images[0].url, "img/logo.png");
+        assert_eq!(stats.images[0].line, 9);
+        // Headings, prose and alt text, but not code
+        assert_eq!(stats.word_count, 11);
+    }
+
+    #[test]
+    fn test_check_markdown_links_local() {
+        let dir = std::env::temp_dir().join(format!("md_links_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("exists.md"), "").unwrap();
+        let path = dir.join("doc.md");
+        fs::write(
+            &path,
+            "[ok](exists.md#intro)\n[anchor](#top)\n[mail](mailto:a@example.com)\n\n![gone](missing%20image.png)\n\n[site](https://example.com)\n",
+        )
+        .unwrap();
+
+        let result =
+            tauri::async_runtime::block_on(check_markdown_links(path.to_str().unwrap(), false));
+        fs::remove_dir_all(&dir).ok();
+
+        assert!(result.success);
+        assert_eq!(result.checked_local, 2);
+        assert_eq!(result.skipped_external, 1);
+        assert_eq!(result.broken.len(), 1);
+        assert_eq!(result.broken[0].url, "missing%20image.png");
+        assert_eq!(result.broken[0].line, 5);
+        assert!(result.broken[0].is_image);
+    }
+}
@@ -0,0 +1,521 @@
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+/// Used when a port check does not set a timeout
+const DEFAULT_TIMEOUT_MS: u64 = 3000;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CidrResult {
+    pub success: bool,
+    pub version: u8,
+    pub prefix_length: u8,
+    /// The block in canonical form, e.g. `192.168.1.0/24`
+    pub cidr: String,
+    pub network_address: String,
+    /// Not set for IPv6 or for IPv4 /31 and /32 blocks
+    pub broadcast_address: Option<String>,
+    pub first_host: String,
+    pub last_host: String,
+    /// Kept as strings because IPv6 counts overflow JSON numbers
+    pub total_addresses: String,
+    pub host_count: String,
+    pub subnet_mask: String,
+    pub wildcard_mask: String,
+    /// True when the input address had host bits set
+    pub host_bits_set: bool,
+    pub error: Option<String>,
+}
+
+impl CidrResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IpCategory {
+    Unspecified,
+    Loopback,
+    Private,
+    SharedAddress,
+    LinkLocal,
+    UniqueLocal,
+    Multicast,
+    Broadcast,
+    Documentation,
+    Reserved,
+    Global,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpInfo {
+    pub success: bool,
+    pub address: String,
+    pub version: u8,
+    pub category: Option<IpCategory>,
+    /// The fully expanded form; for IPv4 this equals `address`
+    pub expanded: String,
+    pub binary: String,
+    /// The address as an unsigned integer, in decimal
+    pub integer: String,
+    /// The in-addr.arpa / ip6.arpa name used for reverse DNS lookups
+    pub reverse_pointer: String,
+    /// The embedded IPv4 address of an IPv4-mapped IPv6 address
+    pub mapped_ipv4: Option<String>,
+    pub error: Option<String>,
+}
+
+impl IpInfo {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortCheckResult {
+    /// False when the check could not run, e.g. the host did not resolve
+    pub success: bool,
+    pub host: String,
+    pub port: u16,
+    pub open: bool,
+    pub resolved_address: Option<String>,
+    pub response_time_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Parse an address, explaining which part of an IPv4 dotted quad is wrong
+fn parse_address(text: &str) -> Result<IpAddr, String> {
+    if let Ok(addr) = text.parse::<IpAddr>() {
+        return Ok(addr);
+    }
+    if text.is_empty() {
+        return Err("IP address is missing".to_string());
+    }
+    if text.contains(':') {
+        return Err(format!("'{}' is not a valid IPv6 address", text));
+    }
+    let octets: Vec<&str> = text.split('.').collect();
+    if octets.len() != 4 {
+        return Err(format!(
+            "'{}' has {} octets; an IPv4 address needs 4",
+            text,
+            octets.len()
+        ));
+    }
+    for (i, octet) in octets.iter().enumerate() {
+        if octet.is_empty() {
+            return Err(format!("Octet {} of '{}' is empty", i + 1, text));
+        }
+        if !octet.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!(
+                "Octet {} of '{}' is not a number: '{}'",
+                i + 1,
+                text,
+                octet
+            ));
+        }
+        if octet.len() > 1 && octet.starts_with('0') {
+            return Err(format!(
+                "Octet {} of '{}' has a leading zero: '{}'",
+                i + 1,
+                text,
+                octet
+            ));
+        }
+        if octet.parse::<u16>().map_or(true, |v| v > 255) {
+            return Err(format!(
+                "Octet {} of '{}' is out of range (0-255): {}",
+                i + 1,
+                text,
+                octet
+            ));
+        }
+    }
+    Err(format!("'{}' is not a valid IPv4 address", text))
+}
+
+/// Mask with the top `prefix` bits of a `bits`-wide address set
+fn prefix_mask(prefix: u8, bits: u32) -> u128 {
+    let all = if bits == 128 {
+        u128::MAX
+    } else {
+        (1u128 << bits) - 1
+    };
+    if prefix == 0 {
+        0
+    } else {
+        (all << (bits - prefix as u32)) & all
+    }
+}
+
+fn to_ip(value: u128, v4: bool) -> IpAddr {
+    if v4 {
+        IpAddr::V4(Ipv4Addr::from(value as u32))
+    } else {
+        IpAddr::V6(Ipv6Addr::from(value))
+    }
+}
+
+/// Calculate the network, host range and masks of a CIDR block
+pub fn analyze_cidr(input: &str) -> CidrResult {
+    let input = input.trim();
+    if input.is_empty() {
+        return CidrResult::failure("Input is empty".to_string());
+    }
+    let Some((addr_text, prefix_text)) = input.split_once('/') else {
+        return CidrResult::failure(format!(
+            "Missing prefix length: expected '{}/<prefix>'",
+            input
+        ));
+    };
+    let addr = match parse_address(addr_text.trim()) {
+        Ok(addr) => addr,
+        Err(e) => return CidrResult::failure(e),
+    };
+    let (v4, bits, value) = match addr {
+        IpAddr::V4(a) => (true, 32u32, u32::from(a) as u128),
+        IpAddr::V6(a) => (false, 128u32, u128::from(a)),
+    };
+    let prefix_text = prefix_text.trim();
+    if prefix_text.is_empty() || !prefix_text.chars().all(|c| c.is_ascii_digit()) {
+        return CidrResult::failure(format!("Prefix length '{}' is not a number", prefix_text));
+    }
+    let prefix = match prefix_text.parse::<u32>() {
+        Ok(p) if p <= bits => p as u8,
+        _ => {
+            return CidrResult::failure(format!(
+                "Prefix length {} is out of range (0-{})",
+                prefix_text, bits
+            ))
+        }
+    };
+
+    let mask = prefix_mask(prefix, bits);
+    let wildcard = !mask & prefix_mask(bits as u8, bits);
+    let network = value & mask;
+    let last = network | wildcard;
+    let host_bits = bits - prefix as u32;
+    let total = if host_bits == 128 {
+        // 2^128 does not fit in u128
+        "340282366920938463463374607431768211456".to_string()
+    } else {
+        (1u128 << host_bits).to_string()
+    };
+
+    // IPv4 reserves the network and broadcast addresses, except for
+    // point-to-point /31 links (RFC 3021) and single-host /32s
+    let (first_host, last_host, host_count, broadcast) = if v4 && host_bits >= 2 {
+        (
+            network + 1,
+            last - 1,
+            ((1u128 << host_bits) - 2).to_string(),
+            Some(to_ip(last, v4).to_string()),
+        )
+    } else {
+        (network, last, total.clone(), None)
+    };
+
+    CidrResult {
+        success: true,
+        version: if v4 { 4 } else { 6 },
+        prefix_length: prefix,
+        cidr: format!("{}/{}", to_ip(network, v4), prefix),
+        network_address: to_ip(network, v4).to_string(),
+        broadcast_address: broadcast,
+        first_host: to_ip(first_host, v4).to_string(),
+        last_host: to_ip(last_host, v4).to_string(),
+        total_addresses: total,
+        host_count,
+        subnet_mask: to_ip(mask, v4).to_string(),
+        wildcard_mask: to_ip(wildcard, v4).to_string(),
+        host_bits_set: value != network,
+        error: None,
+    }
+}
+
+fn classify_v4(addr: Ipv4Addr) -> IpCategory {
+    let [a, b, c, _] = addr.octets();
+    if addr.is_unspecified() {
+        IpCategory::Unspecified
+    } else if addr.is_loopback() {
+        IpCategory::Loopback
+    } else if addr.is_broadcast() {
+        IpCategory::Broadcast
+    } else if addr.is_private() {
+        IpCategory::Private
+    } else if a == 100 && (64..128).contains(&b) {
+        IpCategory::SharedAddress
+    } else if addr.is_link_local() {
+        IpCategory::LinkLocal
+    } else if addr.is_multicast() {
+        IpCategory::Multicast
+    } else if addr.is_documentation() {
+        IpCategory::Documentation
+    } else if a == 0 || a >= 240 || (a == 192 && b == 0 && c == 0) || (a == 198 && b & 0xfe == 18) {
+        IpCategory::Reserved
+    } else {
+        IpCategory::Global
+    }
+}
+
+fn classify_v6(addr: Ipv6Addr) -> IpCategory {
+    let first = addr.segments()[0];
+    if addr.is_unspecified() {
+        IpCategory::Unspecified
+    } else if addr.is_loopback() {
+        IpCategory::Loopback
+    } else if let Some(v4) = addr.to_ipv4_mapped() {
+        classify_v4(v4)
+    } else if addr.is_multicast() {
+        IpCategory::Multicast
+    } else if first & 0xffc0 == 0xfe80 {
+        IpCategory::LinkLocal
+    } else if first & 0xfe00 == 0xfc00 {
+        IpCategory::UniqueLocal
+    } else if first == 0x2001 && addr.segments()[1] == 0x0db8 {
+        IpCategory::Documentation
+    } else if first & 0xe000 == 0x2000 {
+        IpCategory::Global
+    } else {
+        IpCategory::Reserved
+    }
+}
+
+/// Classify an address and show its alternative representations
+pub fn parse_ip(input: &str) -> IpInfo {
+    let addr = match parse_address(input.trim()) {
+        Ok(addr) => addr,
+        Err(e) => return IpInfo::failure(e),
+    };
+    match addr {
+        IpAddr::V4(a) => {
+            let [o1, o2, o3, o4] = a.octets();
+            IpInfo {
+                success: true,
+                address: a.to_string(),
+                version: 4,
+                category: Some(classify_v4(a)),
+                expanded: a.to_string(),
+                binary: a
+                    .octets()
+                    .iter()
+                    .map(|o| format!("{:08b}", o))
+                    .collect::<Vec<_>>()
+                    .join("."),
+                integer: u32::from(a).to_string(),
+                reverse_pointer: format!("{}.{}.{}.{}.in-addr.arpa", o4, o3, o2, o1),
+                mapped_ipv4: None,
+                error: None,
+            }
+        }
+        IpAddr::V6(a) => {
+            let nibbles: Vec<String> = a
+                .octets()
+                .iter()
+                .rev()
+                .flat_map(|o| [format!("{:x}", o & 0x0f), format!("{:x}", o >> 4)])
+                .collect();
+            IpInfo {
+                success: true,
+                address: a.to_string(),
+                version: 6,
+                category: Some(classify_v6(a)),
+                expanded: a
+                    .segments()
+                    .iter()
+                    .map(|s| format!("{:04x}", s))
+                    .collect::<Vec<_>>()
+                    .join(":"),
+                binary: a
+                    .segments()
+                    .iter()
+                    .map(|s| format!("{:016b}", s))
+                    .collect::<Vec<_>>()
+                    .join(":"),
+                integer: u128::from(a).to_string(),
+                reverse_pointer: format!("{}.ip6.arpa", nibbles.join(".")),
+                mapped_ipv4: a.to_ipv4_mapped().map(|v4| v4.to_string()),
+                error: None,
+            }
+        }
+    }
+}
+
+/// Try a TCP connection to each resolved address until one succeeds
+pub fn check_port(host: &str, port: u16, timeout_ms: u64) -> PortCheckResult {
+    let host = host.trim();
+    let mut result = PortCheckResult {
+        host: host.to_string(),
+        port,
+        ..PortCheckResult::default()
+    };
+    if host.is_empty() {
+        result.error = Some("Host is empty".to_string());
+        return result;
+    }
+    if port == 0 {
+        result.error = Some("Port must be between 1 and 65535".to_string());
+        return result;
+    }
+    // Bracketed IPv6 literals are accepted as typed in a URL
+    let bare = host.trim_start_matches('[').trim_end_matches(']');
+    let addrs: Vec<SocketAddr> = match (bare, port).to_socket_addrs() {
+        Ok(addrs) => addrs.collect(),
+        Err(e) => {
+            result.error = Some(format!("Failed to resolve host: {}", e));
+            return result;
+        }
+    };
+    let Some(first) = addrs.first() else {
+        result.error = Some("Host resolved to no addresses".to_string());
+        return result;
+    };
+    result.success = true;
+    result.resolved_address = Some(first.ip().to_string());
+
+    let timeout = Duration::from_millis(if timeout_ms == 0 {
+        DEFAULT_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+    let mut last_error = None;
+    for addr in &addrs {
+        let started = Instant::now();
+        match TcpStream::connect_timeout(addr, timeout) {
+            Ok(_) => {
+                result.open = true;
+                result.resolved_address = Some(addr.ip().to_string());
+                result.response_time_ms = Some(started.elapsed().as_millis() as u64);
+                return result;
+            }
+            Err(e) => last_error = Some(e.to_string()),
+        }
+    }
+    result.error = last_error;
+    result
+}
+
+/// Run `check_port` on a blocking worker so a slow connect does not stall the runtime
+pub async fn check_port_async(host: String, port: u16, timeout_ms: u64) -> PortCheckResult {
+    let fallback_host = host.clone();
+    tauri::async_runtime::spawn_blocking(move || check_port(&host, port, timeout_ms))
+        .await
+        .unwrap_or_else(|e| PortCheckResult {
+            host: fallback_host,
+            port,
+            error: Some(format!("Port check task failed: {}", e)),
+            ..PortCheckResult::default()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_analyze_cidr_ipv4() {
+        let result = analyze_cidr("192.168.1.77/24");
+        assert!(result.success);
+        assert_eq!(result.cidr, "192.168.1.0/24");
+        assert_eq!(result.network_address, "192.168.1.0");
+        assert_eq!(result.broadcast_address.as_deref(), Some("192.168.1.255"));
+        assert_eq!(result.first_host, "192.168.1.1");
+        assert_eq!(result.last_host, "192.168.1.254");
+        assert_eq!(result.host_count, "254");
+        assert_eq!(result.subnet_mask, "255.255.255.0");
+        assert_eq!(result.wildcard_mask, "0.0.0.255");
+        assert!(result.host_bits_set);
+
+        let p2p = analyze_cidr("10.0.0.0/31");
+        assert_eq!(p2p.host_count, "2");
+        assert_eq!(p2p.broadcast_address, None);
+        assert_eq!(analyze_cidr("0.0.0.0/0").total_addresses, "4294967296");
+    }
+
+    #[test]
+    fn test_analyze_cidr_ipv6() {
+        let result = analyze_cidr("2001:db8::1/64");
+        assert!(result.success);
+        assert_eq!(result.version, 6);
+        assert_eq!(result.network_address, "2001:db8::");
+        assert_eq!(result.last_host, "2001:db8::ffff:ffff:ffff:ffff");
+        assert_eq!(result.host_count, "18446744073709551616");
+        assert_eq!(result.subnet_mask, "ffff:ffff:ffff:ffff::");
+        assert_eq!(
+            analyze_cidr("::/0").total_addresses,
+            "340282366920938463463374607431768211456"
+        );
+    }
+
+    #[test]
+    fn test_analyze_cidr_errors() {
+        let cases = [
+            ("192.168.1.0", "Missing prefix"),
+            ("192.168.1.0/33", "out of range (0-32)"),
+            ("192.168.1.0/abc", "not a number"),
+            ("192.168.300.0/24", "Octet 3"),
+            ("192.168.1/24", "has 3 octets"),
+            ("2001:db8::/129", "out of range (0-128)"),
+        ];
+        for (input, expected) in cases {
+            let result = analyze_cidr(input);
+            assert!(!result.success, "{}", input);
+            let error = result.error.unwrap();
+            assert!(error.contains(expected), "{}: {}", input, error);
+        }
+    }
+
+    #[test]
+    fn test_parse_ip_categories() {
+        let cases = [
+            ("127.0.0.1", IpCategory::Loopback),
+            ("10.1.2.3", IpCategory::Private),
+            ("100.64.0.1", IpCategory::SharedAddress),
+            ("169.254.1.1", IpCategory::LinkLocal),
+            ("8.8.8.8", IpCategory::Global),
+            ("fd00::1", IpCategory::UniqueLocal),
+            ("fe80::1", IpCategory::LinkLocal),
+            ("::ffff:192.168.0.1", IpCategory::Private),
+            ("2606:4700::1111", IpCategory::Global),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_ip(input).category, Some(expected), "{}", input);
+        }
+        let info = parse_ip("192.0.2.1");
+        assert_eq!(info.reverse_pointer, "1.2.0.192.in-addr.arpa");
+        assert_eq!(info.integer, "3221225985");
+        assert_eq!(
+            parse_ip("::1").expanded,
+            "0000:0000:0000:0000:0000:0000:0000:0001"
+        );
+    }
+
+    #[test]
+    fn test_check_port() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let open = check_port("127.0.0.1", port, 1000);
+        assert!(open.success);
+        assert!(open.open);
+        assert!(open.response_time_ms.is_some());
+
+        drop(listener);
+        let closed = check_port("127.0.0.1", port, 1000);
+        assert!(closed.success);
+        assert!(!closed.open);
+
+        assert!(!check_port("", 80, 1000).success);
+    }
+}
@@ -0,0 +1,332 @@
+use crate::qr_reader::BoundingBox;
+use serde::{Deserialize, Serialize};
+
+/// Shown when neither the `ocr` feature nor the `tesseract` command is available
+const ENGINE_UNAVAILABLE: &str = "OCRエンジンが利用できません";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum OcrLanguage {
+    English,
+    Japanese,
+    /// Japanese text mixed with Latin words, as in most Japanese UIs
+    EnglishJapanese,
+}
+
+impl OcrLanguage {
+    /// Tesseract traineddata name(s)
+    fn code(self) -> &'static str {
+        match self {
+            OcrLanguage::English => "eng",
+            OcrLanguage::Japanese => "jpn",
+            OcrLanguage::EnglishJapanese => "jpn+eng",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum OcrErrorKind {
+    /// The file is missing or not an image we can decode
+    ImageUnreadable,
+    /// No OCR engine is built in or installed
+    EngineUnavailable,
+    /// The engine ran but failed, e.g. because language data is missing
+    RecognitionFailed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrLine {
+    pub text: String,
+    pub bounds: BoundingBox,
+    /// Mean word confidence, 0-100
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrResult {
+    pub success: bool,
+    /// Recognized lines joined with newlines, with a blank line between paragraphs
+    pub text: String,
+    pub lines: Vec<OcrLine>,
+    /// Source dimensions, for drawing `bounds` over a scaled preview
+    pub image_width: u32,
+    pub image_height: u32,
+    pub error: Option<String>,
+    pub error_kind: Option<OcrErrorKind>,
+}
+
+impl OcrResult {
+    fn failure(kind: OcrErrorKind, message: String) -> Self {
+        Self {
+            success: false,
+            text: String::new(),
+            lines: Vec::new(),
+            image_width: 0,
+            image_height: 0,
+            error: Some(message),
+            error_kind: Some(kind),
+        }
+    }
+}
+
+/// Page, block, paragraph and line number of a word in tesseract's TSV output
+type LineKey = (u32, u32, u32, u32);
+
+struct TsvWord<'a> {
+    line_key: LineKey,
+    bounds: BoundingBox,
+    confidence: f32,
+    text: &'a str,
+}
+
+fn parse_tsv_word(row: &str) -> Option<TsvWord<'_>> {
+    let fields: Vec<&str> = row.splitn(12, '\t').collect();
+    // Level 5 is a word; the other levels are page, block, paragraph and line summaries
+    if fields.len() < 12 || fields[0] != "5" {
+        return None;
+    }
+    let number = |index: usize| fields[index].parse::<u32>().ok();
+    let text = fields[11].trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some(TsvWord {
+        line_key: (number(1)?, number(2)?, number(3)?, number(4)?),
+        bounds: BoundingBox {
+            x: number(6)?,
+            y: number(7)?,
+            width: number(8)?,
+            height: number(9)?,
+        },
+        confidence: fields[10].parse::<f32>().ok()?.max(0.0),
+        text,
+    })
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3000}'..='\u{30FF}' // CJK punctuation, hiragana, katakana
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{FF00}'..='\u{FFEF}') // full-width forms
+}
+
+/// Join words with spaces, except next to Japanese characters where tesseract splits without them
+fn join_words(words: &[&str]) -> String {
+    let mut text = String::new();
+    for word in words {
+        let glued =
+            text.chars().last().is_some_and(is_cjk) || word.chars().next().is_some_and(is_cjk);
+        if !text.is_empty() && !glued {
+            text.push(' ');
+        }
+        text.push_str(word);
+    }
+    text
+}
+
+fn union(a: BoundingBox, b: BoundingBox) -> BoundingBox {
+    let x = a.x.min(b.x);
+    let y = a.y.min(b.y);
+    BoundingBox {
+        x,
+        y,
+        width: (a.x + a.width).max(b.x + b.width) - x,
+        height: (a.y + a.height).max(b.y + b.height) - y,
+    }
+}
+
+/// Group tesseract's TSV word rows into lines, returning the full text and the lines
+fn lines_from_tsv(tsv: &str) -> (String, Vec<OcrLine>) {
+    let mut grouped: Vec<(LineKey, Vec<TsvWord>)> = Vec::new();
+    for word in tsv.lines().filter_map(parse_tsv_word) {
+        match grouped.last_mut() {
+            Some((key, words)) if *key == word.line_key => words.push(word),
+            _ => grouped.push((word.line_key, vec![word])),
+        }
+    }
+
+    let mut text = String::new();
+    let mut lines = Vec::new();
+    let mut previous_paragraph = None;
+    for (key, words) in grouped {
+        let paragraph = (key.0, key.1, key.2);
+        if let Some(previous) = previous_paragraph {
+            text.push_str(if previous == paragraph { "\n" } else { "\n\n" });
+        }
+        previous_paragraph = Some(paragraph);
+
+        let line_text = join_words(&words.iter().map(|w| w.text).collect::<Vec<_>>());
+        text.push_str(&line_text);
+        lines.push(OcrLine {
+            text: line_text,
+            bounds: words
+                .iter()
+                .skip(1)
+                .fold(words[0].bounds, |acc, w| union(acc, w.bounds)),
+            confidence: words.iter().map(|w| w.confidence).sum::<f32>() / words.len() as f32,
+        });
+    }
+    (text, lines)
+}
+
+/// Error from running an engine, before it is turned into an `OcrResult`
+enum EngineError {
+    #[cfg_attr(feature = "ocr", allow(dead_code))]
+    Unavailable,
+    Failed(String),
+}
+
+#[cfg(feature = "ocr")]
+fn recognize_tsv(path: &str, language: OcrLanguage) -> Result<String, EngineError> {
+    use leptess::LepTess;
+
+    let mut engine = LepTess::new(None, language.code())
+        .map_err(|e| EngineError::Failed(format!("Failed to initialize tesseract: {}", e)))?;
+    engine
+        .set_image(path)
+        .map_err(|e| EngineError::Failed(format!("Failed to load image: {}", e)))?;
+    engine
+        .get_tsv_text(0)
+        .map_err(|e| EngineError::Failed(format!("Failed to read OCR output: {}", e)))
+}
+
+#[cfg(not(feature = "ocr"))]
+fn recognize_tsv(path: &str, language: OcrLanguage) -> Result<String, EngineError> {
+    let output = std::process::Command::new("tesseract")
+        .arg(path)
+        .args(["stdout", "-l", language.code(), "tsv"])
+        .output()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => EngineError::Unavailable,
+            _ => EngineError::Failed(format!("Failed to run tesseract: {}", e)),
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(EngineError::Failed(format!(
+            "tesseract failed: {}",
+            stderr.trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Recognize the text in the image at `path`. An image without text is a success with no lines.
+pub fn extract_text_from_image(path: &str, language: OcrLanguage) -> OcrResult {
+    let (image_width, image_height) = match image::image_dimensions(path) {
+        Ok(dimensions) => dimensions,
+        Err(e) => {
+            return OcrResult::failure(
+                OcrErrorKind::ImageUnreadable,
+                format!("Failed to open image: {}", e),
+            )
+        }
+    };
+
+    match recognize_tsv(path, language) {
+        Ok(tsv) => {
+            let (text, lines) = lines_from_tsv(&tsv);
+            OcrResult {
+                success: true,
+                text,
+                lines,
+                image_width,
+                image_height,
+                error: None,
+                error_kind: None,
+            }
+        }
+        Err(EngineError::Unavailable) => OcrResult::failure(
+            OcrErrorKind::EngineUnavailable,
+            ENGINE_UNAVAILABLE.to_string(),
+        ),
+        Err(EngineError::Failed(message)) => {
+            OcrResult::failure(OcrErrorKind::RecognitionFailed, message)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    const HEADER: &str =
+        "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext";
+
+    #[test]
+    fn test_language_codes() {
+        assert_eq!(OcrLanguage::English.code(), "eng");
+        assert_eq!(OcrLanguage::Japanese.code(), "jpn");
+        assert_eq!(OcrLanguage::EnglishJapanese.code(), "jpn+eng");
+    }
+
+    #[test]
+    fn test_lines_from_tsv() {
+        let tsv = [
+            HEADER,
+            "1\t1\t0\t0\t0\t0\t0\t0\t400\t200\t-1\t",
+            "4\t1\t1\t1\t1\t0\t10\t20\t120\t16\t-1\t",
+            "5\t1\t1\t1\t1\t1\t10\t20\t50\t16\t96.5\tHello,",
+            "5\t1\t1\t1\t1\t2\t70\t22\t60\t14\t91.5\tworld",
+            "5\t1\t1\t1\t2\t1\t10\t40\t30\t16\t88\t設定",
+            "5\t1\t1\t1\t2\t2\t42\t40\t30\t16\t80\tを開く",
+            "5\t1\t1\t1\t2\t3\t80\t40\t20\t16\t-1\t ",
+            "5\t1\t2\t1\t1\t1\t10\t100\t40\t16\t70\tRust",
+            "5\t1\t2\t1\t1\t2\t52\t100\t40\t16\t90\t言語",
+        ]
+        .join("\n");
+        let (text, lines) = lines_from_tsv(&tsv);
+
+        assert_eq!(text, "Hello, world\n設定を開く\n\nRust言語");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0].bounds,
+            BoundingBox {
+                x: 10,
+                y: 20,
+                width: 120,
+                height: 16
+            }
+        );
+        assert_eq!(lines[0].confidence, 94.0);
+        assert_eq!(lines[1].text, "設定を開く");
+        assert_eq!(lines[1].confidence, 84.0);
+    }
+
+    #[test]
+    fn test_empty_tsv() {
+        let (text, lines) = lines_from_tsv(HEADER);
+        assert!(text.is_empty());
+        assert!(lines.is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_image() {
+        let result = extract_text_from_image("/nonexistent/screenshot.png", OcrLanguage::English);
+        assert!(!result.success);
+        assert_eq!(result.error_kind, Some(OcrErrorKind::ImageUnreadable));
+    }
+
+    #[test]
+    fn test_blank_image() {
+        let path =
+            std::env::temp_dir().join(format!("taurin_ocr_blank_{}.png", std::process::id()));
+        RgbImage::from_pixel(64, 32, Rgb([255, 255, 255]))
+            .save(&path)
+            .unwrap();
+        let result = extract_text_from_image(path.to_str().unwrap(), OcrLanguage::English);
+        std::fs::remove_file(&path).ok();
+
+        // Either no engine here, or no text found, which is not an error
+        if result.success {
+            assert!(result.lines.is_empty());
+            assert_eq!((result.image_width, result.image_height), (64, 32));
+        } else if result.error_kind == Some(OcrErrorKind::EngineUnavailable) {
+            assert_eq!(result.error.as_deref(), Some(ENGINE_UNAVAILABLE));
+        } else {
+            panic!("unexpected OCR failure: {:?}", result.error);
+        }
+    }
+}
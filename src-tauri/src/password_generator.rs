@@ -1,6 +1,8 @@
 use rand::seq::SliceRandom;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
 
 const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
 const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
@@ -8,6 +10,27 @@ const DIGITS: &str = "0123456789";
 const SYMBOLS: &str = "!@#$%^&*()_+-=[]{}|;:,.<>?";
 const AMBIGUOUS_CHARS: &str = "0O1lI";
 
+/// Regeneration attempts per password when `min_strength_score` is set.
+const MAX_STRENGTH_ATTEMPTS: usize = 200;
+
+/// Guesses per second assumed for crack time (offline attack on a fast hash).
+const GUESSES_PER_SECOND: f64 = 1e10;
+
+const KEYBOARD_ROWS: &[&str] = &["qwertyuiop", "asdfghjkl", "zxcvbnm", "1234567890"];
+
+const COMMON_PASSWORDS: &[&str] = &[
+    "password", "passwd", "123456", "qwerty", "letmein", "welcome", "admin", "login", "iloveyou",
+    "monkey", "dragon", "master", "sunshine", "princess", "football", "baseball", "shadow",
+    "superman", "trustno1", "abc123", "starwars", "whatever", "freedom", "secret", "hello",
+    "charlie", "donald", "michael", "jordan", "hunter", "killer", "pokemon", "access",
+];
+
+/// 2048 words from the BIP-39 Japanese wordlist, transliterated to Hepburn romaji.
+const JAPANESE_ROMAJI_WORDS: &str = include_str!("../wordlists/japanese_romaji.txt");
+
+/// Custom wordlists smaller than this are flagged as too weak.
+const MIN_RECOMMENDED_WORDS: usize = 256;
+
 const WORD_LIST: &[&str] = &[
     "apple", "banana", "cherry", "dragon", "eagle", "falcon", "guitar", "hammer", "island",
     "jungle", "kingdom", "lemon", "marble", "nectar", "orange", "puzzle", "quartz", "river",
@@ -32,6 +55,8 @@ pub struct PasswordOptions {
     pub exclude_ambiguous: bool,
     pub custom_exclude: String,
     pub count: u32,
+    /// Regenerate until every password reaches this score (0-4)
+    pub min_strength_score: Option<u8>,
 }
 
 impl Default for PasswordOptions {
@@ -45,10 +70,30 @@ impl Default for PasswordOptions {
             exclude_ambiguous: false,
             custom_exclude: String::new(),
             count: 1,
+            min_strength_score: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BuiltinWordlist {
+    English,
+    JapaneseRomaji,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum WordlistSource {
+    Builtin(BuiltinWordlist),
+    /// A text file with one word per line
+    CustomFile(String),
+}
+
+impl Default for WordlistSource {
+    fn default() -> Self {
+        WordlistSource::Builtin(BuiltinWordlist::English)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PassphraseOptions {
@@ -57,6 +102,8 @@ pub struct PassphraseOptions {
     pub capitalize: bool,
     pub include_number: bool,
     pub count: u32,
+    #[serde(default)]
+    pub wordlist: WordlistSource,
 }
 
 impl Default for PassphraseOptions {
@@ -67,6 +114,7 @@ impl Default for PassphraseOptions {
             capitalize: true,
             include_number: true,
             count: 1,
+            wordlist: WordlistSource::default(),
         }
     }
 }
@@ -75,6 +123,7 @@ impl Default for PassphraseOptions {
 pub struct GeneratedPassword {
     pub value: String,
     pub strength: PasswordStrength,
+    pub evaluation: PasswordStrengthResult,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,13 +133,41 @@ pub struct PasswordStrength {
     pub entropy: f64,
 }
 
+/// Pattern-aware strength estimate of an arbitrary password.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordStrengthResult {
+    pub score: u8,
+    pub label: String,
+    pub entropy: f64,
+    pub crack_time_seconds: f64,
+    pub crack_time_display: String,
+    pub suggestions: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PasswordGenerateResult {
     pub success: bool,
     pub passwords: Vec<GeneratedPassword>,
+    /// Wordlist used for passphrases
+    pub wordlist_name: Option<String>,
+    pub entropy_per_word: Option<f64>,
+    pub warnings: Vec<String>,
     pub error: Option<String>,
 }
 
+impl PasswordGenerateResult {
+    fn failure(error: String) -> Self {
+        Self {
+            success: false,
+            passwords: vec![],
+            wordlist_name: None,
+            entropy_per_word: None,
+            warnings: vec![],
+            error: Some(error),
+        }
+    }
+}
+
 fn build_charset(options: &PasswordOptions) -> String {
     let mut charset = String::new();
 
@@ -147,15 +224,220 @@ fn calculate_strength(password: &str, charset_size: usize) -> PasswordStrength {
     }
 }
 
+/// Estimate how hard `password` is to guess.
+///
+/// Starts from the character pool size and discounts repeated characters, sequences such as
+/// `abc` or `321`, keyboard runs such as `qwer`, and common passwords (including simple leet
+/// substitutions).
+pub fn evaluate_password_strength(password: &str) -> PasswordStrengthResult {
+    let chars: Vec<char> = password.chars().collect();
+    let has_lower = chars.iter().any(|c| c.is_ascii_lowercase());
+    let has_upper = chars.iter().any(|c| c.is_ascii_uppercase());
+    let has_digit = chars.iter().any(|c| c.is_ascii_digit());
+    let has_symbol = chars.iter().any(|c| c.is_ascii_punctuation() || *c == ' ');
+    let has_other = chars.iter().any(|c| !c.is_ascii());
+
+    let pool = [
+        (has_lower, 26),
+        (has_upper, 26),
+        (has_digit, 10),
+        (has_symbol, 33),
+        (has_other, 100),
+    ]
+    .iter()
+    .filter(|(present, _)| *present)
+    .map(|(_, size)| size)
+    .sum::<usize>()
+    .max(1);
+    let bits_per_char = (pool as f64).log2();
+
+    // Bits contributed by each character; predictable characters are discounted
+    let mut costs = vec![bits_per_char; chars.len()];
+    let repeats = mark_repeats(&chars, &mut costs);
+    let sequences = mark_sequences(&chars, &mut costs);
+    let keyboard = mark_keyboard_runs(&chars, &mut costs);
+    let normalized: Vec<char> = chars.iter().map(|c| unleet(*c)).collect();
+    let common =
+        mark_common_passwords(&chars, &mut costs) | mark_common_passwords(&normalized, &mut costs);
+
+    let mut entropy: f64 = costs.iter().sum();
+    if common {
+        entropy += (COMMON_PASSWORDS.len() as f64).log2();
+    }
+
+    let guesses = 2f64.powf(entropy);
+    let score = if guesses < 1e3 {
+        0
+    } else if guesses < 1e6 {
+        1
+    } else if guesses < 1e8 {
+        2
+    } else if guesses < 1e10 {
+        3
+    } else {
+        4
+    };
+    let label = ["非常に弱い", "弱い", "普通", "強い", "非常に強い"][score as usize];
+    let crack_time_seconds = guesses / 2.0 / GUESSES_PER_SECOND;
+
+    let mut suggestions = Vec::new();
+    if chars.len() < 12 {
+        suggestions.push("12文字以上にしてください".to_string());
+    }
+    if !has_upper {
+        suggestions.push("大文字を追加してください".to_string());
+    }
+    if !has_lower {
+        suggestions.push("小文字を追加してください".to_string());
+    }
+    if !has_digit {
+        suggestions.push("数字を追加してください".to_string());
+    }
+    if !has_symbol {
+        suggestions.push("記号を追加してください".to_string());
+    }
+    if repeats {
+        suggestions.push("同じ文字の繰り返しを避けてください".to_string());
+    }
+    if sequences {
+        suggestions.push("連続した文字（abc、123など）を避けてください".to_string());
+    }
+    if keyboard {
+        suggestions.push("キーボードの並び（qwertyなど）を避けてください".to_string());
+    }
+    if common {
+        suggestions.push("よく使われるパスワードや単語を避けてください".to_string());
+    }
+
+    PasswordStrengthResult {
+        score,
+        label: label.to_string(),
+        entropy: (entropy * 100.0).round() / 100.0,
+        crack_time_seconds,
+        crack_time_display: format_crack_time(crack_time_seconds),
+        suggestions,
+    }
+}
+
+fn unleet(c: char) -> char {
+    match c.to_ascii_lowercase() {
+        '@' | '4' => 'a',
+        '3' => 'e',
+        '1' | '!' => 'i',
+        '0' => 'o',
+        '$' | '5' => 's',
+        '7' => 't',
+        other => other,
+    }
+}
+
+/// Characters that repeat the previous one (`aaa`) cost one bit.
+fn mark_repeats(chars: &[char], costs: &mut [f64]) -> bool {
+    let mut found = false;
+    for i in 1..chars.len() {
+        if chars[i] == chars[i - 1] {
+            costs[i] = costs[i].min(1.0);
+            found = true;
+        }
+    }
+    found
+}
+
+/// Characters continuing an ascending or descending run (`abc`, `987`) cost one bit.
+fn mark_sequences(chars: &[char], costs: &mut [f64]) -> bool {
+    let mut found = false;
+    for i in 2..chars.len() {
+        let step = chars[i] as i64 - chars[i - 1] as i64;
+        let previous = chars[i - 1] as i64 - chars[i - 2] as i64;
+        if step.abs() == 1 && step == previous && chars[i].is_ascii_alphanumeric() {
+            costs[i] = costs[i].min(1.0);
+            found = true;
+        }
+    }
+    found
+}
+
+/// Runs of four or more adjacent keys (`qwer`, `lkjh`) cost one bit after the first key.
+fn mark_keyboard_runs(chars: &[char], costs: &mut [f64]) -> bool {
+    let lower: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    let mut found = false;
+
+    for row in KEYBOARD_ROWS {
+        let forward: Vec<char> = row.chars().collect();
+        let backward: Vec<char> = forward.iter().rev().copied().collect();
+        for keys in [&forward, &backward] {
+            for start in 0..lower.len() {
+                let Some(offset) = keys.iter().position(|k| *k == lower[start]) else {
+                    continue;
+                };
+                let run = lower[start..]
+                    .iter()
+                    .zip(&keys[offset..])
+                    .take_while(|(c, k)| c == k)
+                    .count();
+                if run >= 4 {
+                    for cost in &mut costs[start + 1..start + run] {
+                        *cost = cost.min(1.0);
+                    }
+                    found = true;
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Common passwords cost nothing beyond picking them from the list.
+fn mark_common_passwords(chars: &[char], costs: &mut [f64]) -> bool {
+    let lower: String = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    let mut found = false;
+
+    for word in COMMON_PASSWORDS {
+        for (byte_start, _) in lower.match_indices(word) {
+            let start = lower[..byte_start].chars().count();
+            for cost in &mut costs[start..start + word.chars().count()] {
+                *cost = 0.0;
+            }
+            found = true;
+        }
+    }
+    found
+}
+
+fn format_crack_time(seconds: f64) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = 60.0 * MINUTE;
+    const DAY: f64 = 24.0 * HOUR;
+    const YEAR: f64 = 365.0 * DAY;
+
+    if seconds < 1.0 {
+        "一瞬".to_string()
+    } else if seconds < MINUTE {
+        format!("{}秒", seconds.round())
+    } else if seconds < HOUR {
+        format!("{}分", (seconds / MINUTE).round())
+    } else if seconds < DAY {
+        format!("{}時間", (seconds / HOUR).round())
+    } else if seconds < YEAR {
+        format!("{}日", (seconds / DAY).round())
+    } else if seconds < 100.0 * YEAR {
+        format!("{}年", (seconds / YEAR).round())
+    } else {
+        "100年以上".to_string()
+    }
+}
+
 pub fn generate_passwords(options: PasswordOptions) -> PasswordGenerateResult {
     let charset = build_charset(&options);
 
     if charset.is_empty() {
-        return PasswordGenerateResult {
-            success: false,
-            passwords: vec![],
-            error: Some("文字種を1つ以上選択してください".to_string()),
-        };
+        return PasswordGenerateResult::failure("文字種を1つ以上選択してください".to_string());
+    }
+
+    if options.min_strength_score.is_some_and(|score| score > 4) {
+        return PasswordGenerateResult::failure(
+            "最低強度スコアは0〜4で指定してください".to_string(),
+        );
     }
 
     let charset_chars: Vec<char> = charset.chars().collect();
@@ -163,9 +445,12 @@ pub fn generate_passwords(options: PasswordOptions) -> PasswordGenerateResult {
     let length = options.length.clamp(4, 128) as usize;
     let count = options.count.clamp(1, 100);
 
+    let min_score = options.min_strength_score.unwrap_or(0);
+
     let mut rng = rand::thread_rng();
-    let passwords: Vec<GeneratedPassword> = (0..count)
-        .map(|_| {
+    let mut passwords = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let accepted = (0..MAX_STRENGTH_ATTEMPTS).find_map(|_| {
             let password: String = (0..length)
                 .map(|_| {
                     let idx = rng.gen_range(0..charset_size);
@@ -173,29 +458,100 @@ pub fn generate_passwords(options: PasswordOptions) -> PasswordGenerateResult {
                 })
                 .collect();
 
-            let strength = calculate_strength(&password, charset_size);
-            GeneratedPassword {
-                value: password,
-                strength,
-            }
-        })
-        .collect();
+            let evaluation = evaluate_password_strength(&password);
+            (evaluation.score >= min_score).then_some((password, evaluation))
+        });
+
+        let Some((password, evaluation)) = accepted else {
+            return PasswordGenerateResult::failure(format!(
+                "{}回試行しても強度スコア{}以上のパスワードを生成できませんでした。長さや文字種を増やしてください",
+                MAX_STRENGTH_ATTEMPTS, min_score
+            ));
+        };
+
+        let strength = calculate_strength(&password, charset_size);
+        passwords.push(GeneratedPassword {
+            value: password,
+            strength,
+            evaluation,
+        });
+    }
 
     PasswordGenerateResult {
         success: true,
         passwords,
+        wordlist_name: None,
+        entropy_per_word: None,
+        warnings: vec![],
         error: None,
     }
 }
 
+/// Load the words for `source`, dropping blank lines and duplicates. Returns the display name
+/// of the wordlist along with its words.
+fn load_wordlist(source: &WordlistSource) -> Result<(String, Vec<String>), String> {
+    let (name, text) = match source {
+        WordlistSource::Builtin(BuiltinWordlist::English) => {
+            return Ok((
+                "English".to_string(),
+                WORD_LIST.iter().map(|w| w.to_string()).collect(),
+            ))
+        }
+        WordlistSource::Builtin(BuiltinWordlist::JapaneseRomaji) => (
+            "Japanese (Romaji)".to_string(),
+            JAPANESE_ROMAJI_WORDS.to_string(),
+        ),
+        WordlistSource::CustomFile(path) => {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| format!("辞書ファイルの読み込みに失敗しました: {}", e))?;
+            let name = Path::new(path)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.clone());
+            (name, text)
+        }
+    };
+
+    let mut seen = HashSet::new();
+    let words: Vec<String> = text
+        .lines()
+        .map(str::trim)
+        .filter(|w| !w.is_empty() && seen.insert(*w))
+        .map(str::to_string)
+        .collect();
+    Ok((name, words))
+}
+
 pub fn generate_passphrases(options: PassphraseOptions) -> PasswordGenerateResult {
     let word_count = options.word_count.clamp(2, 10) as usize;
     let count = options.count.clamp(1, 100);
 
+    let (wordlist_name, word_list) = match load_wordlist(&options.wordlist) {
+        Ok(loaded) => loaded,
+        Err(e) => return PasswordGenerateResult::failure(e),
+    };
+    if word_list.len() < word_count {
+        return PasswordGenerateResult::failure(format!(
+            "辞書の単語数（{}語）が単語数の指定（{}語）より少ないです",
+            word_list.len(),
+            word_count
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    if word_list.len() < MIN_RECOMMENDED_WORDS {
+        warnings.push(format!(
+            "辞書の単語数が{}語しかないため、エントロピーが不足します（{}語以上を推奨）",
+            word_list.len(),
+            MIN_RECOMMENDED_WORDS
+        ));
+    }
+    let entropy_per_word = (word_list.len() as f64).log2();
+
     let mut rng = rand::thread_rng();
     let passwords: Vec<GeneratedPassword> = (0..count)
         .map(|_| {
-            let mut words: Vec<String> = WORD_LIST
+            let mut words: Vec<String> = word_list
                 .choose_multiple(&mut rng, word_count)
                 .map(|w| {
                     if options.capitalize {
@@ -217,8 +573,7 @@ pub fn generate_passphrases(options: PassphraseOptions) -> PasswordGenerateResul
 
             let passphrase = words.join(&options.separator);
 
-            let pool_size = WORD_LIST.len();
-            let entropy = (word_count as f64) * (pool_size as f64).log2()
+            let entropy = (word_count as f64) * entropy_per_word
                 + if options.include_number {
                     100_f64.log2()
                 } else {
@@ -241,9 +596,11 @@ pub fn generate_passphrases(options: PassphraseOptions) -> PasswordGenerateResul
                 entropy: (entropy * 100.0).round() / 100.0,
             };
 
+            let evaluation = evaluate_password_strength(&passphrase);
             GeneratedPassword {
                 value: passphrase,
                 strength,
+                evaluation,
             }
         })
         .collect();
@@ -251,6 +608,9 @@ pub fn generate_passphrases(options: PassphraseOptions) -> PasswordGenerateResul
     PasswordGenerateResult {
         success: true,
         passwords,
+        wordlist_name: Some(wordlist_name),
+        entropy_per_word: Some((entropy_per_word * 100.0).round() / 100.0),
+        warnings,
         error: None,
     }
 }
@@ -270,6 +630,7 @@ mod tests {
             exclude_ambiguous: false,
             custom_exclude: String::new(),
             count: 1,
+            min_strength_score: None,
         };
 
         let result = generate_passwords(options);
@@ -289,6 +650,7 @@ mod tests {
             exclude_ambiguous: false,
             custom_exclude: String::new(),
             count: 5,
+            min_strength_score: None,
         };
 
         let result = generate_passwords(options);
@@ -307,6 +669,7 @@ mod tests {
             exclude_ambiguous: true,
             custom_exclude: String::new(),
             count: 1,
+            min_strength_score: None,
         };
 
         let result = generate_passwords(options);
@@ -327,6 +690,7 @@ mod tests {
             capitalize: true,
             include_number: true,
             count: 1,
+            wordlist: WordlistSource::default(),
         };
 
         let result = generate_passphrases(options);
@@ -335,6 +699,61 @@ mod tests {
         assert!(result.passwords[0].value.contains('-'));
     }
 
+    #[test]
+    fn test_japanese_romaji_passphrase() {
+        let options = PassphraseOptions {
+            capitalize: false,
+            include_number: false,
+            wordlist: WordlistSource::Builtin(BuiltinWordlist::JapaneseRomaji),
+            ..PassphraseOptions::default()
+        };
+
+        let result = generate_passphrases(options);
+        assert!(result.success);
+        assert_eq!(result.wordlist_name.as_deref(), Some("Japanese (Romaji)"));
+        assert_eq!(result.entropy_per_word, Some(11.0));
+        assert!(result.warnings.is_empty());
+        let passphrase = &result.passwords[0].value;
+        assert_eq!(passphrase.split('-').count(), 4);
+        assert!(passphrase
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c == '-'));
+    }
+
+    #[test]
+    fn test_custom_wordlist() {
+        let path = std::env::temp_dir().join("taurin_passphrase_wordlist.txt");
+        std::fs::write(&path, "alpha\n\nbravo\nalpha\n  charlie  \ndelta\n").unwrap();
+
+        let options = PassphraseOptions {
+            word_count: 4,
+            include_number: false,
+            capitalize: false,
+            wordlist: WordlistSource::CustomFile(path.to_string_lossy().into_owned()),
+            ..PassphraseOptions::default()
+        };
+        let result = generate_passphrases(options.clone());
+        assert!(result.success);
+        assert_eq!(result.entropy_per_word, Some(2.0));
+        assert_eq!(result.warnings.len(), 1);
+        let mut words: Vec<&str> = result.passwords[0].value.split('-').collect();
+        words.sort();
+        assert_eq!(words, vec!["alpha", "bravo", "charlie", "delta"]);
+
+        let too_many = generate_passphrases(PassphraseOptions {
+            word_count: 5,
+            ..options
+        });
+        assert!(!too_many.success);
+
+        std::fs::remove_file(&path).unwrap();
+        let missing = generate_passphrases(PassphraseOptions {
+            wordlist: WordlistSource::CustomFile(path.to_string_lossy().into_owned()),
+            ..PassphraseOptions::default()
+        });
+        assert!(!missing.success);
+    }
+
     #[test]
     fn test_empty_charset() {
         let options = PasswordOptions {
@@ -346,6 +765,7 @@ mod tests {
             exclude_ambiguous: false,
             custom_exclude: String::new(),
             count: 1,
+            min_strength_score: None,
         };
 
         let result = generate_passwords(options);
@@ -364,10 +784,70 @@ mod tests {
             exclude_ambiguous: false,
             custom_exclude: String::new(),
             count: 1,
+            min_strength_score: None,
         };
 
         let result = generate_passwords(options);
         assert!(result.success);
         assert!(result.passwords[0].strength.score >= 4);
     }
+
+    #[test]
+    fn test_evaluate_weak_passwords() {
+        let common = evaluate_password_strength("P@ssw0rd");
+        assert_eq!(common.score, 0);
+        assert!(common
+            .suggestions
+            .iter()
+            .any(|s| s.contains("よく使われる")));
+
+        let repeated = evaluate_password_strength("aaaaaaaaaaaa");
+        assert!(repeated.score <= 1);
+        assert!(repeated.suggestions.iter().any(|s| s.contains("繰り返し")));
+
+        let keyboard = evaluate_password_strength("qwertyuiop12");
+        assert!(keyboard.score <= 2);
+        assert!(keyboard
+            .suggestions
+            .iter()
+            .any(|s| s.contains("キーボード")));
+
+        let sequence = evaluate_password_strength("abcdefgh1234");
+        assert!(sequence.suggestions.iter().any(|s| s.contains("連続")));
+        assert!(sequence.suggestions.iter().any(|s| s.contains("大文字")));
+    }
+
+    #[test]
+    fn test_evaluate_strong_password() {
+        let result = evaluate_password_strength("x7#Kp9!mQ2@vL4$z");
+        assert_eq!(result.score, 4);
+        assert!(result.entropy > 90.0);
+        assert!(result.suggestions.is_empty());
+        assert_eq!(result.crack_time_display, "100年以上");
+    }
+
+    #[test]
+    fn test_min_strength_score() {
+        let options = PasswordOptions {
+            length: 12,
+            count: 10,
+            min_strength_score: Some(4),
+            ..PasswordOptions::default()
+        };
+        let result = generate_passwords(options);
+        assert!(result.success);
+        assert!(result.passwords.iter().all(|p| p.evaluation.score >= 4));
+
+        let impossible = PasswordOptions {
+            length: 4,
+            include_lowercase: false,
+            include_uppercase: false,
+            include_symbols: false,
+            min_strength_score: Some(4),
+            ..PasswordOptions::default()
+        };
+        let result = generate_passwords(impossible);
+        assert!(!result.success);
+        assert!(result.error.is_some());
+    }
 }
@@ -1,14 +1,30 @@
-use lopdf::{dictionary, Document, Object, ObjectId};
+use hayro::hayro_interpret::InterpreterSettings;
+use hayro::hayro_syntax::{DecryptionError, LoadPdfError, Pdf};
+use hayro::vello_cpu::color::palette::css::WHITE;
+use hayro::{render, PixmapSettings, RenderCache, RenderSettings};
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, GrayImage, RgbImage};
+use lopdf::encryption::crypt_filters::{Aes128CryptFilter, CryptFilter};
+use lopdf::{
+    dictionary, Dictionary, Document, EncryptionState, EncryptionVersion, Object, ObjectId,
+    Permissions, Stream, StringFormat,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::SystemTime;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PdfInfo {
     pub page_count: u32,
     pub file_size: u64,
     pub file_name: String,
+    pub is_encrypted: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +43,115 @@ pub struct PdfMergeResult {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfRotateResult {
+    pub success: bool,
+    pub output_path: String,
+    pub rotated_pages: Vec<u32>,
+    pub errors: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageText {
+    pub page: u32,
+    pub text: String,
+    /// Set when the page has no extractable text layer (e.g. scanned images)
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfTextResult {
+    pub success: bool,
+    pub pages: Vec<PageText>,
+    pub full_text: String,
+    pub page_count: u32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfEncryptResult {
+    pub success: bool,
+    pub output_path: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfDecryptResult {
+    pub success: bool,
+    pub output_path: String,
+    /// True when the failure was caused by a wrong password
+    pub invalid_password: bool,
+    pub error: Option<String>,
+}
+
+/// How hard `compress_pdf` recompresses embedded images
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PdfCompressionLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl PdfCompressionLevel {
+    /// JPEG quality and the resolution images are downsampled to
+    fn settings(self) -> (u8, f64) {
+        match self {
+            Self::Low => (85, 200.0),
+            Self::Medium => (70, 150.0),
+            Self::High => (50, 96.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfCompressResult {
+    pub success: bool,
+    pub output_path: String,
+    pub original_size: u64,
+    pub compressed_size: u64,
+    /// Percentage of the original size saved
+    pub reduction_ratio: f64,
+    pub images_found: usize,
+    /// Images that were re-encoded and came out smaller
+    pub images_compressed: usize,
+    /// Set when the PDF has no images or recompressing them saved nothing.
+    /// No file is written in that case.
+    pub nothing_to_reduce: bool,
+    pub error: Option<String>,
+}
+
+/// One rendered page. Pages that fail to render carry the error instead of an image.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfThumbnailResult {
+    pub success: bool,
+    /// 1-based page number
+    pub page: u32,
+    /// PNG image encoded as Base64
+    pub image_base64: String,
+    pub width: u32,
+    pub height: u32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfThumbnailsResult {
+    pub success: bool,
+    pub page_count: u32,
+    pub thumbnails: Vec<PdfThumbnailResult>,
+    /// Set when the document itself could not be opened
+    pub error: Option<String>,
+}
+
+/// Sent after each page of `render_pdf_thumbnails`, so a grid can fill in as pages arrive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfThumbnailProgress {
+    pub input_path: String,
+    pub total: u32,
+    pub thumbnail: PdfThumbnailResult,
+}
+
 pub fn get_pdf_info(path: &str) -> Result<PdfInfo, String> {
     let metadata =
         fs::metadata(path).map_err(|e| format!("Failed to read file metadata: {}", e))?;
@@ -45,17 +170,18 @@ pub fn get_pdf_info(path: &str) -> Result<PdfInfo, String> {
         page_count,
         file_size,
         file_name,
+        is_encrypted: is_encrypted(&doc),
     })
 }
 
 pub fn split_pdf_by_pages(input_path: &str, output_dir: &str) -> PdfSplitResult {
-    let doc = match Document::load(input_path) {
+    let doc = match load_unencrypted(input_path) {
         Ok(d) => d,
         Err(e) => {
             return PdfSplitResult {
                 success: false,
                 output_paths: vec![],
-                error: Some(format!("Failed to load PDF: {}", e)),
+                error: Some(e),
             }
         }
     };
@@ -97,13 +223,13 @@ pub fn split_pdf_by_range(
     start_page: u32,
     end_page: u32,
 ) -> PdfSplitResult {
-    let doc = match Document::load(input_path) {
+    let doc = match load_unencrypted(input_path) {
         Ok(d) => d,
         Err(e) => {
             return PdfSplitResult {
                 success: false,
                 output_paths: vec![],
-                error: Some(format!("Failed to load PDF: {}", e)),
+                error: Some(e),
             }
         }
     };
@@ -152,7 +278,7 @@ pub fn merge_pdfs(input_paths: &[String], output_path: &str) -> PdfMergeResult {
     }
 
     // Load the first document as the base
-    let mut merged_doc = match Document::load(&input_paths[0]) {
+    let mut merged_doc = match load_unencrypted(&input_paths[0]) {
         Ok(d) => d,
         Err(e) => {
             return PdfMergeResult {
@@ -160,14 +286,14 @@ pub fn merge_pdfs(input_paths: &[String], output_path: &str) -> PdfMergeResult {
                 output_path: String::new(),
                 page_count: 0,
                 file_size: 0,
-                error: Some(format!("Failed to load {}: {}", input_paths[0], e)),
+                error: Some(format!("{}: {}", input_paths[0], e)),
             }
         }
     };
 
     // For each additional document, merge its pages
     for path in input_paths.iter().skip(1) {
-        let doc = match Document::load(path) {
+        let doc = match load_unencrypted(path) {
             Ok(d) => d,
             Err(e) => {
                 return PdfMergeResult {
@@ -175,7 +301,7 @@ pub fn merge_pdfs(input_paths: &[String], output_path: &str) -> PdfMergeResult {
                     output_path: String::new(),
                     page_count: 0,
                     file_size: 0,
-                    error: Some(format!("Failed to load {}: {}", path, e)),
+                    error: Some(format!("{}: {}", path, e)),
                 }
             }
         };
@@ -213,6 +339,885 @@ pub fn merge_pdfs(input_paths: &[String], output_path: &str) -> PdfMergeResult {
     }
 }
 
+/// Rotate the given 1-based pages (all pages when `pages` is empty) by 90, 180 or 270 degrees.
+///
+/// Page numbers that do not exist are reported in `errors` while the remaining pages are
+/// still rotated.
+pub fn rotate_pdf_pages(
+    input_path: &str,
+    output_path: &str,
+    pages: Vec<u32>,
+    angle: u16,
+) -> PdfRotateResult {
+    let fail = |error: String| PdfRotateResult {
+        success: false,
+        output_path: String::new(),
+        rotated_pages: vec![],
+        errors: vec![],
+        error: Some(error),
+    };
+
+    if !matches!(angle, 90 | 180 | 270) {
+        return fail(format!(
+            "Invalid rotation angle: {} (must be 90, 180 or 270)",
+            angle
+        ));
+    }
+
+    let mut doc = match load_unencrypted(input_path) {
+        Ok(d) => d,
+        Err(e) => return fail(e),
+    };
+
+    let page_map = doc.get_pages();
+    let targets: Vec<u32> = if pages.is_empty() {
+        page_map.keys().copied().collect()
+    } else {
+        let mut unique = pages;
+        unique.sort_unstable();
+        unique.dedup();
+        unique
+    };
+
+    let mut rotated_pages = Vec::new();
+    let mut errors = Vec::new();
+
+    for page in targets {
+        let Some(&page_id) = page_map.get(&page) else {
+            errors.push(format!(
+                "Page {} does not exist (document has {} pages)",
+                page,
+                page_map.len()
+            ));
+            continue;
+        };
+
+        let current = current_rotation(&doc, page_id);
+        let rotation = (current + angle as i64).rem_euclid(360);
+        match doc.get_dictionary_mut(page_id) {
+            Ok(page_dict) => {
+                page_dict.set("Rotate", Object::Integer(rotation));
+                rotated_pages.push(page);
+            }
+            Err(e) => errors.push(format!("Failed to rotate page {}: {}", page, e)),
+        }
+    }
+
+    if rotated_pages.is_empty() {
+        return PdfRotateResult {
+            errors,
+            ..fail("No pages were rotated".to_string())
+        };
+    }
+
+    if let Err(e) = doc.save(output_path) {
+        return PdfRotateResult {
+            errors,
+            ..fail(format!("Failed to save rotated PDF: {}", e))
+        };
+    }
+
+    PdfRotateResult {
+        success: true,
+        output_path: output_path.to_string(),
+        rotated_pages,
+        errors,
+        error: None,
+    }
+}
+
+/// Extract the text layer of each page in the (inclusive, 1-based) range.
+///
+/// Missing bounds default to the first and last page. Text is decoded through the fonts'
+/// encodings and ToUnicode maps, so CJK text works when the PDF embeds that mapping.
+pub fn extract_pdf_text(
+    input_path: &str,
+    start_page: Option<u32>,
+    end_page: Option<u32>,
+) -> PdfTextResult {
+    let fail = |error: String| PdfTextResult {
+        success: false,
+        pages: vec![],
+        full_text: String::new(),
+        page_count: 0,
+        error: Some(error),
+    };
+
+    let doc = match load_unencrypted(input_path) {
+        Ok(d) => d,
+        Err(e) => return fail(e),
+    };
+
+    let page_count = doc.get_pages().len() as u32;
+    let start = start_page.unwrap_or(1);
+    let end = end_page.unwrap_or(page_count);
+
+    if start < 1 || end > page_count || start > end {
+        return fail(format!(
+            "Invalid page range: {}-{} (document has {} pages)",
+            start, end, page_count
+        ));
+    }
+
+    let pages: Vec<PageText> = (start..=end)
+        .map(|page| {
+            let text: String = doc
+                .extract_text_chunks(&[page])
+                .into_iter()
+                .filter_map(Result::ok)
+                .collect();
+            let text = text.trim_end().to_string();
+            let note = if text.trim().is_empty() {
+                Some("No text found on this page (it may be a scanned image)".to_string())
+            } else {
+                None
+            };
+            PageText { page, text, note }
+        })
+        .collect();
+
+    let full_text = pages
+        .iter()
+        .filter(|p| p.note.is_none())
+        .map(|p| p.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    PdfTextResult {
+        success: true,
+        pages,
+        full_text,
+        page_count,
+        error: None,
+    }
+}
+
+/// Extract the text of every page and write it to a `.txt` file
+pub fn export_pdf_text(input_path: &str, output_path: &str) -> Result<(), String> {
+    let result = extract_pdf_text(input_path, None, None);
+    if let Some(error) = result.error {
+        return Err(error);
+    }
+    fs::write(output_path, result.full_text).map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Protect a PDF with AES-128 encryption.
+///
+/// The owner password defaults to the user password when not given.
+pub fn encrypt_pdf(
+    input_path: &str,
+    output_path: &str,
+    user_password: String,
+    owner_password: Option<String>,
+) -> PdfEncryptResult {
+    let fail = |error: String| PdfEncryptResult {
+        success: false,
+        output_path: String::new(),
+        error: Some(error),
+    };
+
+    if user_password.is_empty() {
+        return fail("Password must not be empty".to_string());
+    }
+
+    let mut doc = match load_unencrypted(input_path) {
+        Ok(d) => d,
+        Err(e) => return fail(e),
+    };
+
+    // The file identifier is part of the encryption key derivation
+    if doc.trailer.get(b"ID").is_err() {
+        let id = Object::String(
+            uuid::Uuid::new_v4().as_bytes().to_vec(),
+            StringFormat::Hexadecimal,
+        );
+        doc.trailer.set("ID", vec![id.clone(), id]);
+    }
+
+    let owner_password = owner_password
+        .filter(|p| !p.is_empty())
+        .unwrap_or_else(|| user_password.clone());
+    let crypt_filter: Arc<dyn CryptFilter> = Arc::new(Aes128CryptFilter);
+    let version = EncryptionVersion::V4 {
+        document: &doc,
+        encrypt_metadata: true,
+        crypt_filters: BTreeMap::from([(b"StdCF".to_vec(), crypt_filter)]),
+        stream_filter: b"StdCF".to_vec(),
+        string_filter: b"StdCF".to_vec(),
+        owner_password: &owner_password,
+        user_password: &user_password,
+        permissions: Permissions::default(),
+    };
+
+    let state = match EncryptionState::try_from(version) {
+        Ok(state) => state,
+        Err(e) => return fail(format!("Failed to prepare encryption: {}", e)),
+    };
+    if let Err(e) = doc.encrypt(&state) {
+        return fail(format!("Failed to encrypt PDF: {}", e));
+    }
+    if let Err(e) = doc.save(output_path) {
+        return fail(format!("Failed to save encrypted PDF: {}", e));
+    }
+
+    PdfEncryptResult {
+        success: true,
+        output_path: output_path.to_string(),
+        error: None,
+    }
+}
+
+/// Remove password protection from a PDF.
+///
+/// AES-256 documents accept either password. For older security handlers (revision 4 and
+/// below) lopdf can only derive the decryption key from the user (open) password.
+pub fn decrypt_pdf(input_path: &str, output_path: &str, password: String) -> PdfDecryptResult {
+    let fail = |error: String, invalid_password: bool| PdfDecryptResult {
+        success: false,
+        output_path: String::new(),
+        invalid_password,
+        error: Some(error),
+    };
+
+    let mut doc = match Document::load(input_path) {
+        Ok(d) => d,
+        Err(e) => return fail(format!("Failed to load PDF: {}", e), false),
+    };
+
+    if !is_encrypted(&doc) {
+        return fail("PDF is not password protected".to_string(), false);
+    }
+
+    // Documents with an empty user password are already decrypted on load
+    if doc.is_encrypted() {
+        if doc.authenticate_user_password(&password).is_err() {
+            if doc.authenticate_owner_password(&password).is_err() {
+                return fail("Incorrect password".to_string(), true);
+            }
+            if encryption_revision(&doc) <= 4 {
+                return fail(
+                    "This PDF can only be unlocked with its open (user) password".to_string(),
+                    true,
+                );
+            }
+        }
+
+        doc = match Document::load_with_password(input_path, &password) {
+            Ok(d) if !d.is_encrypted() => d,
+            Ok(_) | Err(lopdf::Error::InvalidPassword) => {
+                return fail("Incorrect password".to_string(), true)
+            }
+            Err(e) => return fail(format!("Failed to decrypt PDF: {}", e), false),
+        };
+    }
+
+    if let Err(e) = doc.save(output_path) {
+        return fail(format!("Failed to save decrypted PDF: {}", e), false);
+    }
+
+    PdfDecryptResult {
+        success: true,
+        output_path: output_path.to_string(),
+        invalid_password: false,
+        error: None,
+    }
+}
+
+/// Shrink a PDF by re-encoding its embedded images as JPEG and downsampling those shown
+/// at more than the level's resolution.
+///
+/// Only 8-bit RGB and grayscale images stored with `DCTDecode` or `FlateDecode` are
+/// touched; text, vector content and other image types are written back unchanged. An
+/// image is only replaced when the new encoding is smaller. The displayed size comes from
+/// the page content streams, so images only drawn inside form XObjects are recompressed
+/// without downsampling.
+pub fn compress_pdf(
+    input_path: &str,
+    output_path: &str,
+    level: PdfCompressionLevel,
+) -> PdfCompressResult {
+    let original_size = fs::metadata(input_path).map(|m| m.len()).unwrap_or(0);
+    let fail = |error: String| PdfCompressResult {
+        success: false,
+        output_path: String::new(),
+        original_size,
+        compressed_size: 0,
+        reduction_ratio: 0.0,
+        images_found: 0,
+        images_compressed: 0,
+        nothing_to_reduce: false,
+        error: Some(error),
+    };
+
+    let mut doc = match load_unencrypted(input_path) {
+        Ok(d) => d,
+        Err(e) => return fail(e),
+    };
+
+    // Soft masks and stencil masks are images too, but hold transparency rather than pictures
+    let masks: HashSet<ObjectId> = doc
+        .objects
+        .values()
+        .filter_map(|object| object.as_stream().ok())
+        .flat_map(|stream| {
+            [b"SMask".as_slice(), b"Mask"]
+                .into_iter()
+                .filter_map(|key| stream.dict.get(key).and_then(Object::as_reference).ok())
+        })
+        .collect();
+    let image_ids: Vec<ObjectId> = doc
+        .objects
+        .iter()
+        .filter(|(id, object)| {
+            !masks.contains(id)
+                && object.as_stream().is_ok_and(|stream| {
+                    stream
+                        .dict
+                        .get(b"Subtype")
+                        .and_then(Object::as_name)
+                        .is_ok_and(|name| name == b"Image")
+                })
+        })
+        .map(|(&id, _)| id)
+        .collect();
+
+    let nothing_to_reduce = |images_found: usize, message: &str| PdfCompressResult {
+        images_found,
+        nothing_to_reduce: true,
+        ..fail(message.to_string())
+    };
+    if image_ids.is_empty() {
+        return nothing_to_reduce(0, "The PDF has no images, so there is nothing to reduce");
+    }
+
+    let (quality, max_dpi) = level.settings();
+    let display_sizes = image_display_sizes(&doc);
+    let mut images_compressed = 0;
+    for id in &image_ids {
+        let replacement = match doc.get_object(*id).and_then(Object::as_stream) {
+            Ok(stream) => recompress_image(
+                &doc,
+                stream,
+                display_sizes.get(id).copied(),
+                quality,
+                max_dpi,
+            ),
+            Err(_) => None,
+        };
+        if let (Some(stream), Some(object)) = (replacement, doc.objects.get_mut(id)) {
+            *object = Object::Stream(stream);
+            images_compressed += 1;
+        }
+    }
+
+    let mut buffer = Vec::new();
+    if let Err(e) = doc.save_to(&mut buffer) {
+        return fail(format!("Failed to build compressed PDF: {}", e));
+    }
+    let compressed_size = buffer.len() as u64;
+    if images_compressed == 0 || compressed_size >= original_size {
+        return nothing_to_reduce(
+            image_ids.len(),
+            "Recompressing the images would not make the PDF smaller",
+        );
+    }
+    if let Err(e) = fs::write(output_path, &buffer) {
+        return fail(format!("Failed to save compressed PDF: {}", e));
+    }
+
+    PdfCompressResult {
+        success: true,
+        output_path: output_path.to_string(),
+        original_size,
+        compressed_size,
+        reduction_ratio: (1.0 - compressed_size as f64 / original_size as f64) * 100.0,
+        images_found: image_ids.len(),
+        images_compressed,
+        nothing_to_reduce: false,
+        error: None,
+    }
+}
+
+/// Largest size, in points, each image XObject is drawn at directly from a page
+fn image_display_sizes(doc: &Document) -> HashMap<ObjectId, (f64, f64)> {
+    let mut sizes: HashMap<ObjectId, (f64, f64)> = HashMap::new();
+    for page_id in doc.get_pages().into_values() {
+        let xobjects = page_xobjects(doc, page_id);
+        let Ok(content) = doc.get_and_decode_page_content(page_id) else {
+            continue;
+        };
+
+        // Only the scale part of the transformation matters for the drawn size
+        let mut ctm = [1.0, 0.0, 0.0, 1.0];
+        let mut saved = Vec::new();
+        for op in &content.operations {
+            match op.operator.as_str() {
+                "q" => saved.push(ctm),
+                "Q" => ctm = saved.pop().unwrap_or(ctm),
+                "cm" => {
+                    let m: Vec<f64> = op
+                        .operands
+                        .iter()
+                        .filter_map(|o| o.as_float().ok().map(f64::from))
+                        .collect();
+                    if m.len() == 6 {
+                        ctm = [
+                            m[0] * ctm[0] + m[1] * ctm[2],
+                            m[0] * ctm[1] + m[1] * ctm[3],
+                            m[2] * ctm[0] + m[3] * ctm[2],
+                            m[2] * ctm[1] + m[3] * ctm[3],
+                        ];
+                    }
+                }
+                "Do" => {
+                    let Some(id) = op
+                        .operands
+                        .first()
+                        .and_then(|o| o.as_name().ok())
+                        .and_then(|name| xobjects.get(name))
+                    else {
+                        continue;
+                    };
+                    let width = ctm[0].hypot(ctm[1]);
+                    let height = ctm[2].hypot(ctm[3]);
+                    let size = sizes.entry(*id).or_insert((0.0, 0.0));
+                    *size = (size.0.max(width), size.1.max(height));
+                }
+                _ => {}
+            }
+        }
+    }
+    sizes
+}
+
+/// XObject names available to a page, including ones inherited from the page tree
+fn page_xobjects(doc: &Document, page_id: ObjectId) -> HashMap<Vec<u8>, ObjectId> {
+    let mut xobjects = HashMap::new();
+    let Ok((inline, resource_ids)) = doc.get_page_resources(page_id) else {
+        return xobjects;
+    };
+    let resources = inline.into_iter().chain(
+        resource_ids
+            .iter()
+            .filter_map(|id| doc.get_dictionary(*id).ok()),
+    );
+    for resources in resources {
+        let Some(dict) = resources
+            .get(b"XObject")
+            .ok()
+            .and_then(|o| doc.dereference(o).ok())
+            .and_then(|(_, o)| o.as_dict().ok())
+        else {
+            continue;
+        };
+        for (name, value) in dict.iter() {
+            if let Ok(id) = value.as_reference() {
+                xobjects.entry(name.clone()).or_insert(id);
+            }
+        }
+    }
+    xobjects
+}
+
+/// Color components of an image we know how to re-encode: 1 for gray, 3 for RGB
+fn image_components(doc: &Document, dict: &Dictionary) -> Option<u8> {
+    let (_, color_space) = doc.dereference(dict.get(b"ColorSpace").ok()?).ok()?;
+    match color_space {
+        Object::Name(name) if name == b"DeviceGray" => Some(1),
+        Object::Name(name) if name == b"DeviceRGB" => Some(3),
+        Object::Array(items) if items.first()?.as_name().ok()? == b"ICCBased" => {
+            let (_, profile) = doc.dereference(items.get(1)?).ok()?;
+            match profile
+                .as_stream()
+                .ok()?
+                .dict
+                .get(b"N")
+                .ok()?
+                .as_i64()
+                .ok()?
+            {
+                1 => Some(1),
+                3 => Some(3),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Decode, downsample and re-encode one image as JPEG, returning `None` when the image is
+/// not supported or the result would not be smaller
+fn recompress_image(
+    doc: &Document,
+    stream: &Stream,
+    display_size: Option<(f64, f64)>,
+    quality: u8,
+    max_dpi: f64,
+) -> Option<Stream> {
+    let dict = &stream.dict;
+    let is_mask = dict
+        .get(b"ImageMask")
+        .and_then(Object::as_bool)
+        .unwrap_or(false);
+    // A color key mask matches exact colors, which JPEG would not preserve
+    let has_color_key = dict.get(b"Mask").is_ok_and(|m| m.as_array().is_ok());
+    if is_mask || has_color_key || dict.get(b"BitsPerComponent").ok()?.as_i64().ok()? != 8 {
+        return None;
+    }
+    let components = image_components(doc, dict)?;
+    let width = u32::try_from(dict.get(b"Width").ok()?.as_i64().ok()?).ok()?;
+    let height = u32::try_from(dict.get(b"Height").ok()?.as_i64().ok()?).ok()?;
+
+    let img = match stream.filters().ok()?.as_slice() {
+        [b"DCTDecode"] => {
+            let img =
+                image::load_from_memory_with_format(&stream.content, image::ImageFormat::Jpeg)
+                    .ok()?;
+            if components == 1 {
+                DynamicImage::ImageLuma8(img.to_luma8())
+            } else {
+                DynamicImage::ImageRgb8(img.to_rgb8())
+            }
+        }
+        [b"FlateDecode"] => {
+            let raw = stream.decompressed_content().ok()?;
+            if components == 1 {
+                DynamicImage::ImageLuma8(GrayImage::from_raw(width, height, raw)?)
+            } else {
+                DynamicImage::ImageRgb8(RgbImage::from_raw(width, height, raw)?)
+            }
+        }
+        _ => return None,
+    };
+
+    // Downsample when the image is drawn at well over the target resolution
+    let img = match display_size {
+        Some((shown_width, shown_height)) if shown_width > 0.0 && shown_height > 0.0 => {
+            let dpi = (img.width() as f64 * 72.0 / shown_width)
+                .min(img.height() as f64 * 72.0 / shown_height);
+            if dpi > max_dpi * 1.1 {
+                let scale = max_dpi / dpi;
+                img.resize_exact(
+                    ((img.width() as f64 * scale).round() as u32).max(1),
+                    ((img.height() as f64 * scale).round() as u32).max(1),
+                    image::imageops::FilterType::Lanczos3,
+                )
+            } else {
+                img
+            }
+        }
+        _ => img,
+    };
+
+    let mut encoded = Vec::new();
+    JpegEncoder::new_with_quality(&mut encoded, quality)
+        .encode_image(&img)
+        .ok()?;
+    if encoded.len() >= stream.content.len() {
+        return None;
+    }
+
+    let mut dict = dict.clone();
+    dict.set("Width", img.width() as i64);
+    dict.set("Height", img.height() as i64);
+    dict.set("Filter", "DCTDecode");
+    dict.remove(b"DecodeParms");
+    let mut recompressed = Stream::new(dict, encoded);
+    recompressed.allows_compression = false;
+    Some(recompressed)
+}
+
+/// Render one page (1-based) as a PNG no wider than `max_width` pixels
+pub fn render_pdf_page_thumbnail(
+    input_path: &str,
+    page: u32,
+    max_width: u32,
+) -> PdfThumbnailResult {
+    let (reply, response) = mpsc::channel();
+    let job = RenderJob::Page {
+        path: input_path.to_string(),
+        page,
+        max_width,
+        reply,
+    };
+    if renderer().send(job).is_err() {
+        return thumbnail_failure(page, "PDF renderer has stopped".to_string());
+    }
+    response
+        .recv()
+        .unwrap_or_else(|_| thumbnail_failure(page, "PDF renderer has stopped".to_string()))
+}
+
+/// Render every page, calling `on_progress` as each one finishes
+pub fn render_pdf_thumbnails<F>(
+    input_path: &str,
+    max_width: u32,
+    mut on_progress: F,
+) -> PdfThumbnailsResult
+where
+    F: FnMut(PdfThumbnailProgress),
+{
+    let (reply, response) = mpsc::channel();
+    let job = RenderJob::PageCount {
+        path: input_path.to_string(),
+        reply,
+    };
+    let page_count = match renderer()
+        .send(job)
+        .map_err(|_| ())
+        .and_then(|_| response.recv().map_err(|_| ()))
+    {
+        Ok(Ok(count)) => count,
+        Ok(Err(e)) => return thumbnails_failure(e),
+        Err(_) => return thumbnails_failure("PDF renderer has stopped".to_string()),
+    };
+
+    let thumbnails = (1..=page_count)
+        .map(|page| {
+            let thumbnail = render_pdf_page_thumbnail(input_path, page, max_width);
+            on_progress(PdfThumbnailProgress {
+                input_path: input_path.to_string(),
+                total: page_count,
+                thumbnail: thumbnail.clone(),
+            });
+            thumbnail
+        })
+        .collect();
+
+    PdfThumbnailsResult {
+        success: true,
+        page_count,
+        thumbnails,
+        error: None,
+    }
+}
+
+fn thumbnail_failure(page: u32, error: String) -> PdfThumbnailResult {
+    PdfThumbnailResult {
+        success: false,
+        page,
+        image_base64: String::new(),
+        width: 0,
+        height: 0,
+        error: Some(error),
+    }
+}
+
+fn thumbnails_failure(error: String) -> PdfThumbnailsResult {
+    PdfThumbnailsResult {
+        success: false,
+        page_count: 0,
+        thumbnails: vec![],
+        error: Some(error),
+    }
+}
+
+/// Documents kept open by the renderer. A thumbnail grid asks for every page right after
+/// opening a file, and reopening it for each page is the slow part.
+const MAX_OPEN_DOCUMENTS: usize = 4;
+/// Upper bound on `max_width`, so a typo cannot allocate a huge bitmap
+const MAX_THUMBNAIL_WIDTH: u32 = 2000;
+/// Height limit for the same reason, for very tall, narrow pages
+const MAX_THUMBNAIL_HEIGHT: u32 = 4000;
+
+enum RenderJob {
+    PageCount {
+        path: String,
+        reply: Sender<Result<u32, String>>,
+    },
+    Page {
+        path: String,
+        page: u32,
+        max_width: u32,
+        reply: Sender<PdfThumbnailResult>,
+    },
+}
+
+/// One thread owns the parsed documents and renders pages in order, so the public
+/// functions send it jobs. Rendering is pure Rust (hayro), with no native library to ship.
+fn renderer() -> &'static Sender<RenderJob> {
+    static RENDERER: OnceLock<Sender<RenderJob>> = OnceLock::new();
+    RENDERER.get_or_init(|| {
+        let (sender, jobs) = mpsc::channel();
+        thread::spawn(move || run_renderer(jobs));
+        sender
+    })
+}
+
+struct OpenDocument {
+    path: PathBuf,
+    /// Reopen when the file changes on disk
+    modified: Option<SystemTime>,
+    document: Pdf,
+}
+
+fn run_renderer(jobs: mpsc::Receiver<RenderJob>) {
+    let mut open: Vec<OpenDocument> = Vec::new();
+
+    for job in jobs {
+        match job {
+            RenderJob::PageCount { path, reply } => {
+                let count = catch_render_panic(&mut open, |open| {
+                    open_document(open, &path).map(|document| document.pages().len() as u32)
+                });
+                let _ = reply.send(count);
+            }
+            RenderJob::Page {
+                path,
+                page,
+                max_width,
+                reply,
+            } => {
+                let result = catch_render_panic(&mut open, |open| {
+                    open_document(open, &path)
+                        .and_then(|document| render_page(document, page, max_width))
+                })
+                .unwrap_or_else(|e| thumbnail_failure(page, e));
+                let _ = reply.send(result);
+            }
+        }
+    }
+}
+
+/// Run one job, turning a panic in the PDF parser or renderer into an error so the
+/// renderer thread keeps serving later jobs. The open documents are dropped after a panic,
+/// since the one that caused it may be left half-parsed.
+fn catch_render_panic<T>(
+    open: &mut Vec<OpenDocument>,
+    job: impl FnOnce(&mut Vec<OpenDocument>) -> Result<T, String>,
+) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| job(open))).unwrap_or_else(|_| {
+        open.clear();
+        Err("Failed to render PDF: the renderer crashed on this document".to_string())
+    })
+}
+
+/// Return the open document for `path`, loading it if needed. The most recently used
+/// document is kept first and the least recently used one is closed past the limit.
+fn open_document<'a>(open: &'a mut Vec<OpenDocument>, path: &str) -> Result<&'a Pdf, String> {
+    let path = PathBuf::from(path);
+    let modified = fs::metadata(&path)
+        .map_err(|e| format!("Failed to read file: {}", e))?
+        .modified()
+        .ok();
+    match open.iter().position(|d| d.path == path) {
+        Some(index) if open[index].modified == modified => {
+            let document = open.remove(index);
+            open.insert(0, document);
+        }
+        cached => {
+            if let Some(index) = cached {
+                open.remove(index);
+            }
+            let data = fs::read(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+            let document = Pdf::new(data).map_err(|e| match e {
+                LoadPdfError::Decryption(DecryptionError::PasswordProtected) => {
+                    "PDF is password protected. Remove the password before previewing it"
+                        .to_string()
+                }
+                LoadPdfError::Decryption(e) => format!("Failed to decrypt PDF: {:?}", e),
+                LoadPdfError::Invalid => {
+                    "Failed to open PDF: the file is not a valid PDF".to_string()
+                }
+            })?;
+            open.insert(
+                0,
+                OpenDocument {
+                    path,
+                    modified,
+                    document,
+                },
+            );
+            open.truncate(MAX_OPEN_DOCUMENTS);
+        }
+    }
+    Ok(&open[0].document)
+}
+
+fn render_page(document: &Pdf, page: u32, max_width: u32) -> Result<PdfThumbnailResult, String> {
+    let pages = document.pages();
+    let page_count = pages.len() as u32;
+    let pdf_page = page
+        .checked_sub(1)
+        .and_then(|index| pages.get(index as usize))
+        .ok_or_else(|| {
+            format!(
+                "Page {} does not exist (document has {} pages)",
+                page, page_count
+            )
+        })?;
+
+    let (page_width, page_height) = pdf_page.render_dimensions();
+    if page_width <= 0.0 || page_height <= 0.0 {
+        return Err(format!("Page {} has no size", page));
+    }
+    let scale = (max_width.clamp(1, MAX_THUMBNAIL_WIDTH) as f32 / page_width)
+        .min(MAX_THUMBNAIL_HEIGHT as f32 / page_height);
+    let pixmap = render(
+        pdf_page,
+        &RenderCache::new(),
+        &InterpreterSettings::default(),
+        &RenderSettings::default(),
+        &PixmapSettings {
+            x_scale: scale,
+            y_scale: scale,
+            bg_color: WHITE,
+        },
+    );
+    let (width, height) = (pixmap.width() as u32, pixmap.height() as u32);
+    let png = pixmap
+        .into_png()
+        .map_err(|e| format!("Failed to encode page {}: {}", page, e))?;
+
+    use base64::{engine::general_purpose, Engine};
+    Ok(PdfThumbnailResult {
+        success: true,
+        page,
+        image_base64: general_purpose::STANDARD.encode(png),
+        width,
+        height,
+        error: None,
+    })
+}
+
+fn encryption_revision(doc: &Document) -> i64 {
+    doc.get_encrypted()
+        .and_then(|dict| dict.get(b"R"))
+        .and_then(Object::as_i64)
+        .unwrap_or(0)
+}
+
+/// Encrypted documents are decrypted on load when they have an empty user password, so check
+/// both the remaining `/Encrypt` entry and whether the document was encrypted originally
+fn is_encrypted(doc: &Document) -> bool {
+    doc.is_encrypted() || doc.was_encrypted()
+}
+
+fn load_unencrypted(path: &str) -> Result<Document, String> {
+    let doc = Document::load(path).map_err(|e| format!("Failed to load PDF: {}", e))?;
+    if is_encrypted(&doc) {
+        return Err("PDF is password protected. Remove the password before editing it".to_string());
+    }
+    Ok(doc)
+}
+
+/// `/Rotate` is inheritable, so fall back to the parent page tree nodes when the page has none
+fn current_rotation(doc: &Document, page_id: ObjectId) -> i64 {
+    let mut node_id = Some(page_id);
+    // Guard against malformed documents with cyclic Parent references
+    for _ in 0..32 {
+        let Some(id) = node_id else { break };
+        let Ok(dict) = doc.get_dictionary(id) else {
+            break;
+        };
+        if let Ok(rotate) = dict.get(b"Rotate").and_then(Object::as_i64) {
+            return rotate;
+        }
+        node_id = dict.get(b"Parent").and_then(Object::as_reference).ok();
+    }
+    0
+}
+
 fn extract_pages(
     src_doc: &Document,
     page_ids: &[ObjectId],
@@ -374,3 +1379,565 @@ fn clone_object_value(
         _ => Ok(obj.clone()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_pdf(path: &str, page_count: usize) {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let kids: Vec<Object> = (0..page_count)
+            .map(|_| {
+                let content_id = doc.add_object(Stream::new(dictionary! {}, Vec::new()));
+                Object::Reference(doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+                    "Contents" => content_id,
+                }))
+            })
+            .collect();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => page_count as i64,
+                "Rotate" => 90,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    fn create_text_pdf(path: &str, page_texts: &[&str]) {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+        let kids: Vec<Object> = page_texts
+            .iter()
+            .map(|text| {
+                let content = if text.is_empty() {
+                    Vec::new()
+                } else {
+                    format!("BT /F1 12 Tf 72 720 Td ({}) Tj ET", text).into_bytes()
+                };
+                let content_id = doc.add_object(Stream::new(dictionary! {}, content));
+                Object::Reference(doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+                    "Resources" => resources_id,
+                    "Contents" => content_id,
+                }))
+            })
+            .collect();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => page_texts.len() as i64,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    /// One page with a line of text and an RGB image drawn `shown` points wide and tall
+    fn create_image_pdf(path: &str, image: Stream, shown: u32) {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let image_id = doc.add_object(image);
+        let content = format!(
+            "q {} 0 0 {} 72 300 cm /Im0 Do Q BT /F1 12 Tf 72 720 Td (Keep) Tj ET",
+            shown, shown
+        );
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.into_bytes()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            "Resources" => dictionary! {
+                "Font" => dictionary! { "F1" => font_id },
+                "XObject" => dictionary! { "Im0" => image_id },
+            },
+            "Contents" => content_id,
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    /// A gradient with a little noise, stored the way scanners usually write it
+    fn test_image(size: u32) -> RgbImage {
+        let mut seed = 0x1234_5678u32;
+        RgbImage::from_fn(size, size, |x, y| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let noise = (seed >> 28) as u8;
+            image::Rgb([(x % 200) as u8 + noise, (y % 200) as u8 + noise, 128])
+        })
+    }
+
+    fn image_stream(img: &RgbImage, filter: &str) -> Stream {
+        let dict = dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Image",
+            "Width" => img.width() as i64,
+            "Height" => img.height() as i64,
+            "ColorSpace" => "DeviceRGB",
+            "BitsPerComponent" => 8,
+        };
+        if filter == "DCTDecode" {
+            let mut jpeg = Vec::new();
+            JpegEncoder::new_with_quality(&mut jpeg, 100)
+                .encode_image(img)
+                .unwrap();
+            let mut dict = dict;
+            dict.set("Filter", "DCTDecode");
+            Stream::new(dict, jpeg)
+        } else {
+            let mut stream = Stream::new(dict, img.as_raw().clone());
+            stream.compress().unwrap();
+            assert!(stream.filters().unwrap() == [b"FlateDecode"]);
+            stream
+        }
+    }
+
+    fn first_image(path: &str) -> Dictionary {
+        let doc = Document::load(path).unwrap();
+        doc.objects
+            .values()
+            .filter_map(|o| o.as_stream().ok())
+            .find(|s| s.dict.get(b"Subtype").and_then(Object::as_name).ok() == Some(b"Image"))
+            .unwrap()
+            .dict
+            .clone()
+    }
+
+    fn page_rotations(path: &str) -> Vec<i64> {
+        let doc = Document::load(path).unwrap();
+        doc.get_pages()
+            .values()
+            .map(|&id| current_rotation(&doc, id))
+            .collect()
+    }
+
+    #[test]
+    fn test_rotate_pdf_pages_adds_to_inherited_rotation() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_rotate_input.pdf");
+        let output = dir.join("taurin_pdf_rotate_output.pdf");
+        create_test_pdf(input.to_str().unwrap(), 3);
+
+        let result = rotate_pdf_pages(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            vec![1, 3, 5],
+            270,
+        );
+        let rotations = page_rotations(output.to_str().unwrap());
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+
+        assert!(result.success);
+        assert_eq!(result.rotated_pages, vec![1, 3]);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].contains("Page 5"));
+        assert_eq!(rotations, vec![0, 90, 0]);
+    }
+
+    #[test]
+    fn test_rotate_pdf_pages_all_pages_when_empty() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_rotate_all_input.pdf");
+        let output = dir.join("taurin_pdf_rotate_all_output.pdf");
+        create_test_pdf(input.to_str().unwrap(), 2);
+
+        let result = rotate_pdf_pages(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            vec![],
+            180,
+        );
+        let rotations = page_rotations(output.to_str().unwrap());
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+
+        assert!(result.success);
+        assert_eq!(result.rotated_pages, vec![1, 2]);
+        assert_eq!(rotations, vec![270, 270]);
+    }
+
+    #[test]
+    fn test_rotate_pdf_pages_rejects_invalid_input() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_rotate_invalid.pdf");
+        let output = dir.join("taurin_pdf_rotate_invalid_out.pdf");
+        create_test_pdf(input.to_str().unwrap(), 1);
+
+        let bad_angle = rotate_pdf_pages(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            vec![],
+            45,
+        );
+        let bad_pages = rotate_pdf_pages(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            vec![2],
+            90,
+        );
+        let _ = fs::remove_file(&input);
+
+        assert!(!bad_angle.success);
+        assert!(!bad_pages.success);
+        assert_eq!(bad_pages.errors.len(), 1);
+        assert!(!output.exists());
+    }
+
+    #[test]
+    fn test_extract_pdf_text_per_page() {
+        let path = std::env::temp_dir().join("taurin_pdf_text.pdf");
+        create_text_pdf(path.to_str().unwrap(), &["Hello PDF", "", "Last page"]);
+
+        let all = extract_pdf_text(path.to_str().unwrap(), None, None);
+        let range = extract_pdf_text(path.to_str().unwrap(), Some(2), Some(3));
+        let invalid = extract_pdf_text(path.to_str().unwrap(), Some(3), Some(4));
+        let _ = fs::remove_file(&path);
+
+        assert!(all.success);
+        assert_eq!(all.page_count, 3);
+        assert_eq!(all.pages.len(), 3);
+        assert_eq!(all.pages[0].text, "Hello PDF");
+        assert!(all.pages[0].note.is_none());
+        assert!(all.pages[1].note.is_some());
+        assert_eq!(all.full_text, "Hello PDF\n\nLast page");
+
+        assert_eq!(range.pages.len(), 2);
+        assert_eq!(range.pages[0].page, 2);
+        assert!(!invalid.success);
+    }
+
+    #[test]
+    fn test_export_pdf_text_writes_file() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_text_export.pdf");
+        let output = dir.join("taurin_pdf_text_export.txt");
+        create_text_pdf(input.to_str().unwrap(), &["Exported"]);
+
+        export_pdf_text(input.to_str().unwrap(), output.to_str().unwrap()).unwrap();
+        let text = fs::read_to_string(&output).unwrap();
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+
+        assert_eq!(text, "Exported");
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_pdf() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_encrypt_input.pdf");
+        let encrypted = dir.join("taurin_pdf_encrypt_locked.pdf");
+        let decrypted = dir.join("taurin_pdf_encrypt_unlocked.pdf");
+        create_text_pdf(input.to_str().unwrap(), &["Secret", "Page two"]);
+
+        let encrypt = encrypt_pdf(
+            input.to_str().unwrap(),
+            encrypted.to_str().unwrap(),
+            "user".to_string(),
+            Some("owner".to_string()),
+        );
+        let info = get_pdf_info(encrypted.to_str().unwrap());
+        let split = split_pdf_by_range(
+            encrypted.to_str().unwrap(),
+            decrypted.to_str().unwrap(),
+            1,
+            1,
+        );
+        let merge = merge_pdfs(
+            &[
+                input.to_str().unwrap().to_string(),
+                encrypted.to_str().unwrap().to_string(),
+            ],
+            decrypted.to_str().unwrap(),
+        );
+        let wrong = decrypt_pdf(
+            encrypted.to_str().unwrap(),
+            decrypted.to_str().unwrap(),
+            "wrong".to_string(),
+        );
+        let wrong_created = decrypted.exists();
+        let owner = decrypt_pdf(
+            encrypted.to_str().unwrap(),
+            decrypted.to_str().unwrap(),
+            "owner".to_string(),
+        );
+        let decrypt = decrypt_pdf(
+            encrypted.to_str().unwrap(),
+            decrypted.to_str().unwrap(),
+            "user".to_string(),
+        );
+        let text = extract_pdf_text(decrypted.to_str().unwrap(), None, None);
+        let not_encrypted = decrypt_pdf(
+            input.to_str().unwrap(),
+            decrypted.to_str().unwrap(),
+            "user".to_string(),
+        );
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&encrypted);
+        let _ = fs::remove_file(&decrypted);
+
+        assert!(encrypt.success, "{:?}", encrypt.error);
+        assert!(info.map(|i| i.is_encrypted).unwrap_or(true));
+        assert!(!split.success);
+        assert!(split.error.unwrap().contains("password protected"));
+        assert!(!merge.success);
+
+        assert!(!wrong.success);
+        assert!(wrong.invalid_password);
+        assert!(!wrong_created);
+        assert!(!owner.success);
+        assert_ne!(owner.error, wrong.error);
+
+        assert!(decrypt.success, "{:?}", decrypt.error);
+        assert!(text.success);
+        assert_eq!(text.pages[0].text, "Secret");
+
+        assert!(!not_encrypted.success);
+        assert!(!not_encrypted.invalid_password);
+    }
+
+    #[test]
+    fn test_compress_pdf_downsamples_images() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_compress_input.pdf");
+        let output = dir.join("taurin_pdf_compress_output.pdf");
+        // 600px drawn over two inches is 300dpi
+        create_image_pdf(
+            input.to_str().unwrap(),
+            image_stream(&test_image(600), "FlateDecode"),
+            144,
+        );
+
+        let result = compress_pdf(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            PdfCompressionLevel::Medium,
+        );
+        let image = first_image(output.to_str().unwrap());
+        let text = extract_pdf_text(output.to_str().unwrap(), None, None);
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!((result.images_found, result.images_compressed), (1, 1));
+        assert!(result.compressed_size < result.original_size);
+        assert!(result.reduction_ratio > 0.0);
+        assert_eq!(image.get(b"Width").unwrap().as_i64().unwrap(), 300);
+        assert_eq!(
+            image.get(b"Filter").unwrap().as_name().unwrap(),
+            b"DCTDecode"
+        );
+        assert_eq!(text.pages[0].text, "Keep");
+    }
+
+    #[test]
+    fn test_compress_pdf_keeps_resolution_of_small_images() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_compress_jpeg_input.pdf");
+        let output = dir.join("taurin_pdf_compress_jpeg_output.pdf");
+        // Drawn at 72dpi, which is already below every level's target
+        create_image_pdf(
+            input.to_str().unwrap(),
+            image_stream(&test_image(200), "DCTDecode"),
+            200,
+        );
+
+        let result = compress_pdf(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            PdfCompressionLevel::High,
+        );
+        let image = first_image(output.to_str().unwrap());
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.images_compressed, 1);
+        assert_eq!(image.get(b"Width").unwrap().as_i64().unwrap(), 200);
+    }
+
+    #[test]
+    fn test_compress_pdf_without_images_or_encrypted() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_compress_text.pdf");
+        let encrypted = dir.join("taurin_pdf_compress_locked.pdf");
+        let output = dir.join("taurin_pdf_compress_text_out.pdf");
+        create_text_pdf(input.to_str().unwrap(), &["Only text"]);
+        encrypt_pdf(
+            input.to_str().unwrap(),
+            encrypted.to_str().unwrap(),
+            "user".to_string(),
+            None,
+        );
+
+        let text_only = compress_pdf(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            PdfCompressionLevel::High,
+        );
+        let written = output.exists();
+        let locked = compress_pdf(
+            encrypted.to_str().unwrap(),
+            output.to_str().unwrap(),
+            PdfCompressionLevel::High,
+        );
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&encrypted);
+
+        assert!(!text_only.success);
+        assert!(text_only.nothing_to_reduce);
+        assert_eq!(text_only.images_found, 0);
+        assert!(!written);
+        assert!(!locked.success);
+        assert!(!locked.nothing_to_reduce);
+        assert!(locked.error.unwrap().contains("password protected"));
+    }
+
+    #[test]
+    fn test_render_thumbnails_reports_errors_instead_of_placeholders() {
+        let missing = std::env::temp_dir().join("taurin_pdf_thumbnail_missing.pdf");
+        let missing = missing.to_str().unwrap();
+
+        let page = render_pdf_page_thumbnail(missing, 2, 200);
+        assert!(!page.success);
+        assert_eq!(page.page, 2);
+        assert!(page.image_base64.is_empty());
+        assert!(page.error.is_some());
+
+        // The renderer thread keeps serving requests after a failure
+        let mut progress = 0;
+        let all = render_pdf_thumbnails(missing, 200, |_| progress += 1);
+        assert!(!all.success);
+        assert!(all.error.is_some());
+        assert_eq!(progress, 0);
+    }
+
+    #[test]
+    fn test_render_thumbnails() {
+        let path = std::env::temp_dir().join("taurin_pdf_thumbnail_pages.pdf");
+        create_text_pdf(path.to_str().unwrap(), &["First", "Second"]);
+
+        let mut progress = 0;
+        let all = render_pdf_thumbnails(path.to_str().unwrap(), 120, |_| progress += 1);
+        let missing = render_pdf_page_thumbnail(path.to_str().unwrap(), 3, 120);
+        let _ = fs::remove_file(&path);
+
+        assert!(all.success, "{:?}", all.error);
+        assert_eq!((all.page_count, progress), (2, 2));
+        for (thumbnail, page) in all.thumbnails.iter().zip(1..) {
+            assert!(thumbnail.success, "{:?}", thumbnail.error);
+            assert_eq!(thumbnail.page, page);
+            // A4 portrait scaled to the requested width
+            assert_eq!((thumbnail.width, thumbnail.height), (120, 169));
+            use base64::{engine::general_purpose, Engine};
+            let png = general_purpose::STANDARD
+                .decode(&thumbnail.image_base64)
+                .unwrap();
+            let image = image::load_from_memory(&png).unwrap();
+            assert_eq!(image.width(), 120);
+        }
+        assert!(!missing.success);
+        assert!(missing.error.unwrap().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_render_tall_page_caps_height() {
+        let path = std::env::temp_dir().join("taurin_pdf_thumbnail_tall.pdf");
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let content_id = doc.add_object(Stream::new(dictionary! {}, Vec::new()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 20.into(), 14400.into()],
+            "Contents" => content_id,
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![Object::Reference(page_id)],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(&path).unwrap();
+
+        let thumbnail = render_pdf_page_thumbnail(path.to_str().unwrap(), 1, 2000);
+        let _ = fs::remove_file(&path);
+
+        assert!(thumbnail.success, "{:?}", thumbnail.error);
+        assert!(thumbnail.height <= MAX_THUMBNAIL_HEIGHT);
+        assert!(thumbnail.width < 2000);
+    }
+
+    #[test]
+    fn test_encrypt_pdf_requires_password() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("taurin_pdf_encrypt_empty.pdf");
+        let output = dir.join("taurin_pdf_encrypt_empty_out.pdf");
+        create_text_pdf(input.to_str().unwrap(), &["Text"]);
+
+        let result = encrypt_pdf(
+            input.to_str().unwrap(),
+            output.to_str().unwrap(),
+            String::new(),
+            None,
+        );
+        let info = get_pdf_info(input.to_str().unwrap()).unwrap();
+        let _ = fs::remove_file(&input);
+
+        assert!(!result.success);
+        assert!(!info.is_encrypted);
+    }
+}
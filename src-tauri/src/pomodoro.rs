@@ -0,0 +1,446 @@
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// Longest work or break period accepted, in minutes
+const MAX_MINUTES: u32 = 240;
+/// Longest span `get_pomodoro_stats` reports on, in days
+const MAX_STATS_DAYS: i64 = 366;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PomodoroPhase {
+    #[default]
+    Idle,
+    Work,
+    Break,
+}
+
+/// Snapshot of the timer, also the payload of the `pomodoro-tick` event
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PomodoroState {
+    pub phase: PomodoroPhase,
+    pub task_id: Option<String>,
+    pub remaining_seconds: u32,
+    pub work_minutes: u32,
+    pub break_minutes: u32,
+    pub paused: bool,
+    /// Work periods finished since the timer was started
+    pub completed: u32,
+}
+
+/// What happened when the remaining time ran out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhaseChange {
+    WorkFinished,
+    BreakFinished,
+}
+
+impl PomodoroState {
+    pub fn new(task_id: Option<String>, work_minutes: u32, break_minutes: u32) -> Self {
+        Self {
+            phase: PomodoroPhase::Work,
+            task_id,
+            remaining_seconds: work_minutes * 60,
+            work_minutes,
+            break_minutes,
+            paused: false,
+            completed: 0,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.phase != PomodoroPhase::Idle && !self.paused
+    }
+
+    /// Advance the timer by one second, switching between work and break when time runs out
+    pub fn tick(&mut self) -> Option<PhaseChange> {
+        if !self.is_running() {
+            return None;
+        }
+        self.remaining_seconds = self.remaining_seconds.saturating_sub(1);
+        if self.remaining_seconds > 0 {
+            return None;
+        }
+        match self.phase {
+            PomodoroPhase::Work => {
+                self.completed += 1;
+                self.phase = PomodoroPhase::Break;
+                self.remaining_seconds = self.break_minutes * 60;
+                Some(PhaseChange::WorkFinished)
+            }
+            PomodoroPhase::Break => {
+                self.phase = PomodoroPhase::Work;
+                self.remaining_seconds = self.work_minutes * 60;
+                Some(PhaseChange::BreakFinished)
+            }
+            PomodoroPhase::Idle => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PomodoroRecord {
+    pub task_id: Option<String>,
+    /// Local time the work period ended, RFC 3339
+    pub completed_at: String,
+    pub minutes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateRange {
+    /// First day, `YYYY-MM-DD`
+    pub start: String,
+    /// Last day (inclusive), `YYYY-MM-DD`
+    pub end: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskPomodoroCount {
+    pub task_id: Option<String>,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyPomodoroStats {
+    pub date: String,
+    pub count: u32,
+    pub minutes: u32,
+    pub tasks: Vec<TaskPomodoroCount>,
+}
+
+/// Completed count, minutes and count per task for one day
+type DayTotals = (u32, u32, BTreeMap<Option<String>, u32>);
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: {} (expected YYYY-MM-DD)", value))
+}
+
+/// Count `records` per local day from `range.start` to `range.end`, including days without any
+pub fn aggregate_stats(
+    records: &[PomodoroRecord],
+    range: &DateRange,
+) -> Result<Vec<DailyPomodoroStats>, String> {
+    let start = parse_date(&range.start)?;
+    let end = parse_date(&range.end)?;
+    if end < start {
+        return Err("The end date is before the start date".to_string());
+    }
+    if (end - start).num_days() >= MAX_STATS_DAYS {
+        return Err(format!("Date range is limited to {} days", MAX_STATS_DAYS));
+    }
+
+    let mut days: BTreeMap<NaiveDate, DayTotals> = BTreeMap::new();
+    let mut day = start;
+    while day <= end {
+        days.insert(day, Default::default());
+        day += ChronoDuration::days(1);
+    }
+    for record in records {
+        let Ok(completed_at) = DateTime::parse_from_rfc3339(&record.completed_at) else {
+            continue;
+        };
+        let date = completed_at.with_timezone(&Local).date_naive();
+        if let Some((count, minutes, tasks)) = days.get_mut(&date) {
+            *count += 1;
+            *minutes += record.minutes;
+            *tasks.entry(record.task_id.clone()).or_default() += 1;
+        }
+    }
+
+    Ok(days
+        .into_iter()
+        .map(|(date, (count, minutes, tasks))| {
+            let mut tasks: Vec<TaskPomodoroCount> = tasks
+                .into_iter()
+                .map(|(task_id, count)| TaskPomodoroCount { task_id, count })
+                .collect();
+            tasks.sort_by_key(|t| std::cmp::Reverse(t.count));
+            DailyPomodoroStats {
+                date: date.format("%Y-%m-%d").to_string(),
+                count,
+                minutes,
+                tasks,
+            }
+        })
+        .collect())
+}
+
+fn get_stats_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("pomodoro_stats.json"))
+}
+
+fn load_records(app: &AppHandle) -> Result<Vec<PomodoroRecord>, String> {
+    let path = get_stats_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read pomodoro stats: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse pomodoro stats: {}", e))
+}
+
+fn append_record(app: &AppHandle, record: PomodoroRecord) -> Result<(), String> {
+    let mut records = load_records(app)?;
+    records.push(record);
+    let path = get_stats_path(app)?;
+    let content = serde_json::to_string_pretty(&records)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write pomodoro stats: {}", e))
+}
+
+/// The running timer, managed as Tauri state. It lives only in memory, so a restart
+/// always comes back idle.
+#[derive(Default)]
+pub struct PomodoroStore {
+    state: Mutex<PomodoroState>,
+    /// Bumped on every start and stop so only the newest ticker thread keeps running
+    generation: AtomicU64,
+}
+
+impl PomodoroStore {
+    fn snapshot(&self) -> PomodoroState {
+        self.state.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+}
+
+fn notify_phase_change(app: &AppHandle, change: PhaseChange, state: &PomodoroState) {
+    let (title, body) = match change {
+        PhaseChange::WorkFinished => (
+            "Pomodoro complete".to_string(),
+            format!("Take a {} minute break.", state.break_minutes),
+        ),
+        PhaseChange::BreakFinished => (
+            "Break is over".to_string(),
+            format!("Time for {} minutes of focus.", state.work_minutes),
+        ),
+    };
+    let _ = app.notification().builder().title(title).body(body).show();
+}
+
+fn run_ticker(app: AppHandle, generation: u64) {
+    thread::spawn(move || {
+        let store = app.state::<PomodoroStore>();
+        // Sleeping until fixed deadlines keeps the countdown from drifting
+        let mut next = Instant::now();
+        loop {
+            next += Duration::from_secs(1);
+            thread::sleep(next.saturating_duration_since(Instant::now()));
+            let Ok(mut state) = store.state.lock() else {
+                break;
+            };
+            // Checked under the state lock, which is also held when the generation is
+            // bumped, so a replaced ticker can never tick the new timer
+            if store.generation.load(Ordering::SeqCst) != generation {
+                break;
+            }
+            if !state.is_running() {
+                continue;
+            }
+            let change = state.tick();
+            let snapshot = state.clone();
+            drop(state);
+
+            let _ = app.emit("pomodoro-tick", &snapshot);
+            if let Some(change) = change {
+                if change == PhaseChange::WorkFinished {
+                    let _ = append_record(
+                        &app,
+                        PomodoroRecord {
+                            task_id: snapshot.task_id.clone(),
+                            completed_at: Local::now().to_rfc3339(),
+                            minutes: snapshot.work_minutes,
+                        },
+                    );
+                }
+                notify_phase_change(&app, change, &snapshot);
+            }
+        }
+    });
+}
+
+/// Start a new work period, replacing any timer that is already running
+pub fn start_pomodoro(
+    app: &AppHandle,
+    task_id: Option<String>,
+    work_minutes: u32,
+    break_minutes: u32,
+) -> Result<PomodoroState, String> {
+    if !(1..=MAX_MINUTES).contains(&work_minutes) || !(1..=MAX_MINUTES).contains(&break_minutes) {
+        return Err(format!(
+            "Work and break lengths must be between 1 and {} minutes",
+            MAX_MINUTES
+        ));
+    }
+    let store = app.state::<PomodoroStore>();
+    let state = PomodoroState::new(
+        task_id.filter(|id| !id.trim().is_empty()),
+        work_minutes,
+        break_minutes,
+    );
+    let generation = {
+        let mut current = store
+            .state
+            .lock()
+            .map_err(|_| "Timer is unavailable".to_string())?;
+        *current = state.clone();
+        store.generation.fetch_add(1, Ordering::SeqCst) + 1
+    };
+    run_ticker(app.clone(), generation);
+    let _ = app.emit("pomodoro-tick", &state);
+    Ok(state)
+}
+
+/// Pause a running timer, or resume a paused one
+pub fn pause_pomodoro(app: &AppHandle, store: &PomodoroStore) -> Result<PomodoroState, String> {
+    let snapshot = {
+        let mut state = store
+            .state
+            .lock()
+            .map_err(|_| "Timer is unavailable".to_string())?;
+        if state.phase == PomodoroPhase::Idle {
+            return Err("No timer is running".to_string());
+        }
+        state.paused = !state.paused;
+        state.clone()
+    };
+    let _ = app.emit("pomodoro-tick", &snapshot);
+    Ok(snapshot)
+}
+
+pub fn stop_pomodoro(app: &AppHandle, store: &PomodoroStore) -> PomodoroState {
+    if let Ok(mut state) = store.state.lock() {
+        *state = PomodoroState::default();
+        store.generation.fetch_add(1, Ordering::SeqCst);
+    }
+    let snapshot = store.snapshot();
+    let _ = app.emit("pomodoro-tick", &snapshot);
+    snapshot
+}
+
+pub fn get_pomodoro_state(store: &PomodoroStore) -> PomodoroState {
+    store.snapshot()
+}
+
+pub fn get_pomodoro_stats(
+    app: &AppHandle,
+    range: &DateRange,
+) -> Result<Vec<DailyPomodoroStats>, String> {
+    aggregate_stats(&load_records(app)?, range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_switches_between_work_and_break() {
+        let mut state = PomodoroState::new(Some("task-1".to_string()), 1, 1);
+        for _ in 0..59 {
+            assert_eq!(state.tick(), None);
+        }
+        assert_eq!(state.remaining_seconds, 1);
+        assert_eq!(state.tick(), Some(PhaseChange::WorkFinished));
+        assert_eq!(state.phase, PomodoroPhase::Break);
+        assert_eq!(state.remaining_seconds, 60);
+        assert_eq!(state.completed, 1);
+
+        state.paused = true;
+        assert_eq!(state.tick(), None);
+        assert_eq!(state.remaining_seconds, 60);
+        state.paused = false;
+        for _ in 0..59 {
+            state.tick();
+        }
+        assert_eq!(state.tick(), Some(PhaseChange::BreakFinished));
+        assert_eq!(state.phase, PomodoroPhase::Work);
+        assert_eq!(state.completed, 1);
+    }
+
+    #[test]
+    fn test_new_store_is_idle() {
+        // Nothing is persisted for the timer itself, so a restarted app starts idle
+        let store = PomodoroStore::default();
+        let state = get_pomodoro_state(&store);
+        assert_eq!(state.phase, PomodoroPhase::Idle);
+        assert!(!state.is_running());
+        let mut idle = state.clone();
+        assert_eq!(idle.tick(), None);
+        assert_eq!(idle, state);
+    }
+
+    #[test]
+    fn test_aggregate_stats_fills_every_day() {
+        let at = |date: &str, hour: u32| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+                .and_local_timezone(Local)
+                .unwrap()
+                .to_rfc3339()
+        };
+        let record = |task: Option<&str>, completed_at: String| PomodoroRecord {
+            task_id: task.map(str::to_string),
+            completed_at,
+            minutes: 25,
+        };
+        let records = vec![
+            record(Some("a"), at("2024-05-01", 9)),
+            record(Some("a"), at("2024-05-01", 10)),
+            record(None, at("2024-05-01", 11)),
+            record(Some("b"), at("2024-05-03", 9)),
+            record(Some("b"), at("2024-06-01", 9)),
+            record(Some("b"), "not a date".to_string()),
+        ];
+        let range = DateRange {
+            start: "2024-05-01".to_string(),
+            end: "2024-05-03".to_string(),
+        };
+        let stats = aggregate_stats(&records, &range).unwrap();
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[0].date, "2024-05-01");
+        assert_eq!(stats[0].count, 3);
+        assert_eq!(stats[0].minutes, 75);
+        assert_eq!(
+            stats[0].tasks[0],
+            TaskPomodoroCount {
+                task_id: Some("a".to_string()),
+                count: 2
+            }
+        );
+        assert_eq!(stats[1].count, 0);
+        assert_eq!(stats[2].count, 1);
+
+        let reversed = DateRange {
+            start: "2024-05-03".to_string(),
+            end: "2024-05-01".to_string(),
+        };
+        assert!(aggregate_stats(&records, &reversed).is_err());
+        let invalid = DateRange {
+            start: "05/01/2024".to_string(),
+            end: "2024-05-01".to_string(),
+        };
+        assert!(aggregate_stats(&records, &invalid).is_err());
+    }
+}
@@ -0,0 +1,426 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::thread;
+use sysinfo::{
+    Pid, Process, ProcessRefreshKind, ProcessesToUpdate, Signal, System, UpdateKind,
+    MINIMUM_CPU_UPDATE_INTERVAL,
+};
+
+/// Process IDs that belong to the OS itself: the idle/swapper task, init/launchd and, on
+/// Windows, the System process
+#[cfg(windows)]
+const SYSTEM_PIDS: [u32; 2] = [0, 4];
+#[cfg(not(windows))]
+const SYSTEM_PIDS: [u32; 2] = [0, 1];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProcessSort {
+    /// Highest CPU usage first
+    #[default]
+    Cpu,
+    /// Largest memory first
+    Memory,
+    Pid,
+    Name,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    /// Percent of one core, so busy multi-threaded processes can exceed 100
+    pub cpu_usage: f32,
+    pub memory_bytes: u64,
+    /// The full command line, or the executable path when it cannot be read
+    pub command: String,
+    /// This app, an OS process or one owned by another user; `kill_process` refuses these
+    pub protected: bool,
+}
+
+/// Load every process with CPU usage, which needs two refreshes a moment apart
+fn process_snapshot() -> System {
+    let refresh = ProcessRefreshKind::nothing()
+        .with_cpu()
+        .with_memory()
+        .with_cmd(UpdateKind::OnlyIfNotSet)
+        .with_exe(UpdateKind::OnlyIfNotSet)
+        .with_user(UpdateKind::OnlyIfNotSet)
+        .without_tasks();
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+    thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+    system
+}
+
+fn is_protected(system: &System, process: &Process) -> bool {
+    let pid = process.pid().as_u32();
+    if pid == std::process::id() || SYSTEM_PIDS.contains(&pid) {
+        return true;
+    }
+    let own_user = system
+        .process(Pid::from_u32(std::process::id()))
+        .and_then(|own| own.user_id());
+    match (own_user, process.user_id()) {
+        (Some(own), Some(user)) => own != user,
+        // Without an owner to compare, only the fixed IDs above are refused
+        _ => false,
+    }
+}
+
+fn process_info(system: &System, process: &Process) -> ProcessInfo {
+    let name = process.name().to_string_lossy().into_owned();
+    let command = process
+        .cmd()
+        .iter()
+        .map(|arg| arg.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let command = if command.is_empty() {
+        process
+            .exe()
+            .map(|exe| exe.to_string_lossy().into_owned())
+            .unwrap_or_else(|| name.clone())
+    } else {
+        command
+    };
+    ProcessInfo {
+        pid: process.pid().as_u32(),
+        parent_pid: process.parent().map(|pid| pid.as_u32()),
+        name,
+        cpu_usage: process.cpu_usage(),
+        memory_bytes: process.memory(),
+        command,
+        protected: is_protected(system, process),
+    }
+}
+
+fn sort_processes(processes: &mut [ProcessInfo], sort: ProcessSort) {
+    match sort {
+        ProcessSort::Cpu => processes.sort_by(|a, b| {
+            b.cpu_usage
+                .partial_cmp(&a.cpu_usage)
+                .unwrap_or(Ordering::Equal)
+                .then(a.pid.cmp(&b.pid))
+        }),
+        ProcessSort::Memory => {
+            processes.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes).then(a.pid.cmp(&b.pid)))
+        }
+        ProcessSort::Pid => processes.sort_by_key(|p| p.pid),
+        ProcessSort::Name => processes.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then(a.pid.cmp(&b.pid))
+        }),
+    }
+}
+
+/// List running processes. `filter` matches the PID exactly or the name or command line
+/// case-insensitively.
+pub fn list_processes(sort: ProcessSort, filter: Option<&str>) -> Vec<ProcessInfo> {
+    let system = process_snapshot();
+    let filter = filter
+        .map(|f| f.trim().to_lowercase())
+        .filter(|f| !f.is_empty());
+    let mut processes: Vec<ProcessInfo> = system
+        .processes()
+        .values()
+        .map(|process| process_info(&system, process))
+        .filter(|info| match &filter {
+            Some(filter) => {
+                info.pid.to_string() == *filter
+                    || info.name.to_lowercase().contains(filter.as_str())
+                    || info.command.to_lowercase().contains(filter.as_str())
+            }
+            None => true,
+        })
+        .collect();
+    sort_processes(&mut processes, sort);
+    processes
+}
+
+/// Processes with a TCP or UDP socket bound to local `port`
+pub fn find_process_by_port(port: u16) -> Result<Vec<ProcessInfo>, String> {
+    let mut pids = pids_using_port(port)?;
+    pids.sort_unstable();
+    pids.dedup();
+    if pids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let system = process_snapshot();
+    let mut processes: Vec<ProcessInfo> = pids
+        .iter()
+        .filter_map(|pid| system.process(Pid::from_u32(*pid)))
+        .map(|process| process_info(&system, process))
+        .collect();
+    sort_processes(&mut processes, ProcessSort::Pid);
+    Ok(processes)
+}
+
+/// Ask a process to exit, or kill it outright with `force`. This app, OS processes and
+/// processes of other users are refused.
+pub fn kill_process(pid: u32, force: bool) -> Result<(), String> {
+    if pid == std::process::id() {
+        return Err("Refusing to kill this app".to_string());
+    }
+    let mut system = System::new();
+    let target = Pid::from_u32(pid);
+    let own = Pid::from_u32(std::process::id());
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[target, own]),
+        false,
+        ProcessRefreshKind::nothing().with_user(UpdateKind::Always),
+    );
+    let process = system
+        .process(target)
+        .ok_or_else(|| format!("Process {} not found", pid))?;
+    if is_protected(&system, process) {
+        return Err(format!(
+            "Refusing to kill process {}: it belongs to the system or another user",
+            pid
+        ));
+    }
+
+    let signal = if force { Signal::Kill } else { Signal::Term };
+    // Windows has no polite termination signal, so it always kills
+    let sent = process.kill_with(signal).unwrap_or_else(|| process.kill());
+    if sent {
+        Ok(())
+    } else {
+        Err(format!("Failed to kill process {}", pid))
+    }
+}
+
+/// Socket inodes bound to local `port` in a `/proc/net/{tcp,udp}[6]` table
+#[cfg(any(target_os = "linux", test))]
+fn proc_net_inodes(table: &str, port: u16) -> Vec<u64> {
+    table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let local_port = fields.get(1)?.rsplit(':').next()?;
+            if u16::from_str_radix(local_port, 16).ok()? != port {
+                return None;
+            }
+            fields
+                .get(9)?
+                .parse::<u64>()
+                .ok()
+                .filter(|inode| *inode != 0)
+        })
+        .collect()
+}
+
+/// PIDs from `lsof -t` output, one per line
+#[cfg(any(all(unix, not(target_os = "linux")), test))]
+fn parse_lsof_pids(output: &str) -> Vec<u32> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().parse().ok())
+        .collect()
+}
+
+/// PIDs from `netstat -ano` rows whose local address uses `port`
+#[cfg(any(windows, test))]
+fn parse_netstat_pids(output: &str, port: u16) -> Vec<u32> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let protocol = fields.first()?;
+            if !protocol.eq_ignore_ascii_case("tcp") && !protocol.eq_ignore_ascii_case("udp") {
+                return None;
+            }
+            let local_port = fields.get(1)?.rsplit(':').next()?;
+            if local_port.parse::<u16>().ok()? != port {
+                return None;
+            }
+            fields.last()?.parse().ok()
+        })
+        .collect()
+}
+
+/// Matches socket inodes against every readable `/proc/<pid>/fd`; sockets of other users'
+/// processes cannot be seen without privileges
+#[cfg(target_os = "linux")]
+fn pids_using_port(port: u16) -> Result<Vec<u32>, String> {
+    use std::collections::HashSet;
+    use std::fs;
+
+    let mut inodes = HashSet::new();
+    for table in ["tcp", "tcp6", "udp", "udp6"] {
+        if let Ok(content) = fs::read_to_string(format!("/proc/net/{}", table)) {
+            inodes.extend(proc_net_inodes(&content, port));
+        }
+    }
+    if inodes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let entries =
+        fs::read_dir("/proc").map_err(|e| format!("Failed to read process list: {}", e))?;
+    let mut pids = Vec::new();
+    for entry in entries.flatten() {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        let uses_port = fds.flatten().any(|fd| {
+            fs::read_link(fd.path()).is_ok_and(|link| {
+                link.to_str()
+                    .and_then(|s| s.strip_prefix("socket:["))
+                    .and_then(|s| s.strip_suffix(']'))
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .is_some_and(|inode| inodes.contains(&inode))
+            })
+        });
+        if uses_port {
+            pids.push(pid);
+        }
+    }
+    Ok(pids)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn pids_using_port(port: u16) -> Result<Vec<u32>, String> {
+    let output = std::process::Command::new("lsof")
+        .args(["-nP", "-t", "-i", &format!(":{}", port)])
+        .output()
+        .map_err(|e| format!("Failed to run lsof: {}", e))?;
+    // lsof exits with 1 both on errors and when nothing uses the port
+    if !output.status.success() && !output.stderr.is_empty() {
+        return Err(format!(
+            "lsof failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(parse_lsof_pids(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(windows)]
+fn pids_using_port(port: u16) -> Result<Vec<u32>, String> {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let output = std::process::Command::new("netstat")
+        .arg("-ano")
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|e| format!("Failed to run netstat: {}", e))?;
+    if !output.status.success() {
+        return Err("netstat failed".to_string());
+    }
+    Ok(parse_netstat_pids(
+        &String::from_utf8_lossy(&output.stdout),
+        port,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(pid: u32, name: &str, cpu_usage: f32, memory_bytes: u64) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            parent_pid: None,
+            name: name.to_string(),
+            cpu_usage,
+            memory_bytes,
+            command: name.to_string(),
+            protected: false,
+        }
+    }
+
+    #[test]
+    fn test_sort_processes() {
+        let mut processes = vec![
+            info(30, "node", 12.5, 300),
+            info(10, "Bash", 0.0, 900),
+            info(20, "code", 55.0, 100),
+            info(40, "cargo", 12.5, 100),
+        ];
+        let pids = |p: &[ProcessInfo]| p.iter().map(|p| p.pid).collect::<Vec<_>>();
+
+        sort_processes(&mut processes, ProcessSort::Cpu);
+        assert_eq!(pids(&processes), vec![20, 30, 40, 10]);
+        sort_processes(&mut processes, ProcessSort::Memory);
+        assert_eq!(pids(&processes), vec![10, 30, 20, 40]);
+        sort_processes(&mut processes, ProcessSort::Name);
+        assert_eq!(pids(&processes), vec![10, 40, 20, 30]);
+        sort_processes(&mut processes, ProcessSort::Pid);
+        assert_eq!(pids(&processes), vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn test_list_processes_includes_self() {
+        let own = std::process::id();
+        let processes = list_processes(ProcessSort::Pid, Some(&own.to_string()));
+        let me = processes.iter().find(|p| p.pid == own).unwrap();
+        assert!(me.protected);
+        assert!(!me.command.is_empty());
+        assert!(list_processes(ProcessSort::Pid, None).len() >= processes.len());
+    }
+
+    #[test]
+    fn test_proc_net_inodes() {
+        let table = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n\
+   0: 0100007F:0BB8 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 52341 1 0000000000000000 100 0 0 10 0\n\
+   1: 00000000:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 52342 1 0000000000000000 100 0 0 10 0\n\
+   2: 0100007F:C350 0100007F:0BB8 01 00000000:00000000 00:00000000 00000000  1000        0 0 1 0000000000000000 20 4 30 10 -1\n";
+        assert_eq!(proc_net_inodes(table, 3000), vec![52341]);
+        assert_eq!(proc_net_inodes(table, 8080), vec![52342]);
+        assert!(proc_net_inodes(table, 50000).is_empty());
+    }
+
+    #[test]
+    fn test_parse_lsof_and_netstat() {
+        assert_eq!(parse_lsof_pids("4242\n515\n\n"), vec![4242, 515]);
+
+        let netstat = "\r\nActive Connections\r\n\r\n  Proto  Local Address          Foreign Address        State           PID\r\n\
+  TCP    0.0.0.0:3000           0.0.0.0:0              LISTENING       4242\r\n\
+  TCP    127.0.0.1:52000        127.0.0.1:3000         ESTABLISHED     777\r\n\
+  TCP    [::]:3000              [::]:0                 LISTENING       4242\r\n\
+  UDP    0.0.0.0:3000           *:*                                    515\r\n";
+        assert_eq!(parse_netstat_pids(netstat, 3000), vec![4242, 4242, 515]);
+        assert!(parse_netstat_pids(netstat, 8080).is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_find_process_by_port() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let found = find_process_by_port(port).unwrap();
+        assert!(found.iter().any(|p| p.pid == std::process::id()));
+    }
+
+    #[test]
+    fn test_kill_process_refuses_protected() {
+        assert!(kill_process(std::process::id(), true).is_err());
+        assert!(kill_process(SYSTEM_PIDS[1], false).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_process_terminates_child() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        kill_process(child.id(), false).unwrap();
+        let status = child.wait().unwrap();
+        assert!(!status.success());
+    }
+}
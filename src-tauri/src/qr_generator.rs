@@ -0,0 +1,647 @@
+use crate::color_converter::convert_color;
+use base64::{engine::general_purpose, Engine};
+use image::{ImageFormat, Rgba, RgbaImage};
+use qrcode::{Color, EcLevel, QrCode, Version};
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use std::path::Path;
+
+const MIN_SIZE: u32 = 21;
+const MAX_SIZE: u32 = 4096;
+const MAX_MARGIN: u32 = 20;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ErrorCorrection {
+    L,
+    M,
+    Q,
+    H,
+}
+
+impl ErrorCorrection {
+    fn level(self) -> EcLevel {
+        match self {
+            ErrorCorrection::L => EcLevel::L,
+            ErrorCorrection::M => EcLevel::M,
+            ErrorCorrection::Q => EcLevel::Q,
+            ErrorCorrection::H => EcLevel::H,
+        }
+    }
+
+    /// Bytes that fit in a version 40 code at this level
+    fn byte_capacity(self) -> usize {
+        match self {
+            ErrorCorrection::L => 2953,
+            ErrorCorrection::M => 2331,
+            ErrorCorrection::Q => 1663,
+            ErrorCorrection::H => 1273,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrOptions {
+    /// Width and height of the image in pixels
+    pub size: u32,
+    pub error_correction: ErrorCorrection,
+    /// Any color accepted by the color converter (hex, rgb(), CSS names)
+    pub foreground: String,
+    pub background: String,
+    /// Quiet zone around the code, in modules
+    pub margin: u32,
+}
+
+impl Default for QrOptions {
+    fn default() -> Self {
+        Self {
+            size: 256,
+            error_correction: ErrorCorrection::M,
+            foreground: "#000000".to_string(),
+            background: "#ffffff".to_string(),
+            margin: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrGenerateResult {
+    pub success: bool,
+    /// PNG as a `data:image/png;base64,...` URI
+    pub data_uri: String,
+    pub version: u32,
+    /// Modules per side, excluding the margin
+    pub modules: u32,
+    pub byte_length: usize,
+    pub error: Option<String>,
+}
+
+impl QrGenerateResult {
+    fn failure(message: String) -> Self {
+        Self {
+            success: false,
+            data_uri: String::new(),
+            version: 0,
+            modules: 0,
+            byte_length: 0,
+            error: Some(message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum WifiSecurity {
+    Wpa,
+    Wep,
+    None,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WifiConfig {
+    pub ssid: String,
+    pub password: String,
+    pub security: WifiSecurity,
+    pub hidden: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VCardInfo {
+    pub first_name: String,
+    pub last_name: String,
+    pub organization: String,
+    pub title: String,
+    pub phone: String,
+    pub email: String,
+    pub url: String,
+    pub address: String,
+    pub note: String,
+}
+
+/// A QR code laid out for rendering
+struct QrLayout {
+    code: QrCode,
+    foreground: [u8; 4],
+    background: [u8; 4],
+    margin: u32,
+    size: u32,
+}
+
+impl QrLayout {
+    fn modules(&self) -> u32 {
+        self.code.width() as u32
+    }
+
+    fn version(&self) -> u32 {
+        match self.code.version() {
+            Version::Normal(v) | Version::Micro(v) => v as u32,
+        }
+    }
+
+    /// Whole pixels per module, with any remainder spread evenly around the code
+    fn module_pixels(&self) -> (u32, u32) {
+        let total = self.modules() + self.margin * 2;
+        let scale = self.size / total;
+        (scale, (self.size - scale * total) / 2)
+    }
+
+    fn is_dark(&self, x: u32, y: u32) -> bool {
+        self.code[(x as usize, y as usize)] == Color::Dark
+    }
+
+    fn to_png(&self) -> Result<Vec<u8>, String> {
+        let (scale, offset) = self.module_pixels();
+        let origin = offset + self.margin * scale;
+        let modules = self.modules();
+        let image = RgbaImage::from_fn(self.size, self.size, |px, py| {
+            let inside = px >= origin && py >= origin;
+            let (mx, my) = (
+                px.wrapping_sub(origin) / scale,
+                py.wrapping_sub(origin) / scale,
+            );
+            if inside && mx < modules && my < modules && self.is_dark(mx, my) {
+                Rgba(self.foreground)
+            } else {
+                Rgba(self.background)
+            }
+        });
+
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+        Ok(bytes)
+    }
+
+    fn to_svg(&self) -> String {
+        let modules = self.modules();
+        let total = modules + self.margin * 2;
+        let mut path = String::new();
+        for y in 0..modules {
+            let mut x = 0;
+            while x < modules {
+                if !self.is_dark(x, y) {
+                    x += 1;
+                    continue;
+                }
+                // Merge horizontal runs to keep the path short
+                let start = x;
+                while x < modules && self.is_dark(x, y) {
+                    x += 1;
+                }
+                path.push_str(&format!(
+                    "M{},{}h{}v1h-{}z",
+                    start + self.margin,
+                    y + self.margin,
+                    x - start,
+                    x - start
+                ));
+            }
+        }
+
+        format!(
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size}\" height=\"{size}\" ",
+                "viewBox=\"0 0 {total} {total}\" shape-rendering=\"crispEdges\">\n",
+                "<rect width=\"{total}\" height=\"{total}\" {background}/>\n",
+                "<path d=\"{path}\" {foreground}/>\n",
+                "</svg>\n"
+            ),
+            size = self.size,
+            total = total,
+            background = svg_fill(self.background),
+            path = path,
+            foreground = svg_fill(self.foreground),
+        )
+    }
+}
+
+fn svg_fill([r, g, b, a]: [u8; 4]) -> String {
+    let fill = format!("fill=\"#{:02x}{:02x}{:02x}\"", r, g, b);
+    if a == 255 {
+        fill
+    } else {
+        format!("{} fill-opacity=\"{:.3}\"", fill, a as f64 / 255.0)
+    }
+}
+
+fn parse_color(input: &str, what: &str) -> Result<[u8; 4], String> {
+    let result = convert_color(input);
+    match result.values {
+        Some(v) if result.success => Ok([v.red, v.green, v.blue, (v.alpha * 255.0).round() as u8]),
+        _ => Err(format!(
+            "Invalid {} color: {}",
+            what,
+            result.error.unwrap_or_default()
+        )),
+    }
+}
+
+fn layout(content: &str, options: &QrOptions) -> Result<QrLayout, String> {
+    if content.is_empty() {
+        return Err("Content is empty".to_string());
+    }
+    if options.margin > MAX_MARGIN {
+        return Err(format!("Margin must be at most {} modules", MAX_MARGIN));
+    }
+    if !(MIN_SIZE..=MAX_SIZE).contains(&options.size) {
+        return Err(format!(
+            "Size must be between {} and {} px",
+            MIN_SIZE, MAX_SIZE
+        ));
+    }
+    let foreground = parse_color(&options.foreground, "foreground")?;
+    let background = parse_color(&options.background, "background")?;
+
+    let level = options.error_correction;
+    let code = QrCode::with_error_correction_level(content.as_bytes(), level.level()).map_err(
+        |e| match e {
+            qrcode::types::QrError::DataTooLong => format!(
+                "Content is too long for a QR code: {} bytes, but at most {} bytes fit at error correction level {:?}",
+                content.len(),
+                level.byte_capacity(),
+                level
+            ),
+            other => format!("Failed to encode QR code: {}", other),
+        },
+    )?;
+
+    let layout = QrLayout {
+        code,
+        foreground,
+        background,
+        margin: options.margin,
+        size: options.size,
+    };
+    let total = layout.modules() + options.margin * 2;
+    if options.size < total {
+        return Err(format!(
+            "Size {} px is too small for {} modules (including margin); use at least {} px",
+            options.size, total, total
+        ));
+    }
+    Ok(layout)
+}
+
+/// Render `content` as a PNG QR code for preview.
+pub fn generate_qr(content: &str, options: QrOptions) -> QrGenerateResult {
+    let layout = match layout(content, &options) {
+        Ok(layout) => layout,
+        Err(e) => return QrGenerateResult::failure(e),
+    };
+    match layout.to_png() {
+        Ok(png) => QrGenerateResult {
+            success: true,
+            data_uri: format!(
+                "data:image/png;base64,{}",
+                general_purpose::STANDARD.encode(png)
+            ),
+            version: layout.version(),
+            modules: layout.modules(),
+            byte_length: content.len(),
+            error: None,
+        },
+        Err(e) => QrGenerateResult::failure(e),
+    }
+}
+
+/// Save the QR code as PNG or SVG, chosen by the extension of `output_path`.
+pub fn save_qr(content: &str, options: QrOptions, output_path: &str) -> Result<(), String> {
+    let extension = Path::new(output_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    let layout = layout(content, &options)?;
+    let bytes = match extension.as_deref() {
+        Some("png") => layout.to_png()?,
+        Some("svg") => layout.to_svg().into_bytes(),
+        _ => return Err("Output file must end in .png or .svg".to_string()),
+    };
+    std::fs::write(output_path, bytes).map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Backslash-escape the characters with special meaning in `WIFI:` strings.
+fn escape_wifi(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | ';' | ',' | ':' | '"') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Build the `WIFI:T:WPA;S:...;P:...;;` string that phone cameras recognize.
+pub fn build_wifi_content(config: &WifiConfig) -> String {
+    let mut content = String::from("WIFI:");
+    match config.security {
+        WifiSecurity::Wpa => content.push_str("T:WPA;"),
+        WifiSecurity::Wep => content.push_str("T:WEP;"),
+        WifiSecurity::None => content.push_str("T:nopass;"),
+    }
+    content.push_str(&format!("S:{};", escape_wifi(&config.ssid)));
+    if config.security != WifiSecurity::None {
+        content.push_str(&format!("P:{};", escape_wifi(&config.password)));
+    }
+    if config.hidden {
+        content.push_str("H:true;");
+    }
+    content.push(';');
+    content
+}
+
+/// Parse a `WIFI:` string back into its fields; the inverse of [`build_wifi_content`].
+///
+/// Returns `None` when `content` is not a Wi-Fi payload or has no SSID.
+pub fn parse_wifi_content(content: &str) -> Option<WifiConfig> {
+    let body = content.strip_prefix("WIFI:")?;
+    let mut config = WifiConfig {
+        ssid: String::new(),
+        password: String::new(),
+        security: WifiSecurity::None,
+        hidden: false,
+    };
+
+    // Split on unescaped `;`, dropping the backslashes as we go
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => field.extend(chars.next()),
+            ';' => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    for field in fields {
+        let Some((key, value)) = field.split_once(':') else {
+            continue;
+        };
+        match key {
+            "S" => config.ssid = value.to_string(),
+            "P" => config.password = value.to_string(),
+            "T" => {
+                config.security = match value.to_ascii_uppercase().as_str() {
+                    "WEP" => WifiSecurity::Wep,
+                    "" | "NOPASS" => WifiSecurity::None,
+                    _ => WifiSecurity::Wpa,
+                }
+            }
+            "H" => config.hidden = value.eq_ignore_ascii_case("true"),
+            _ => {}
+        }
+    }
+
+    (!config.ssid.is_empty()).then_some(config)
+}
+
+fn escape_vcard(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Build a vCard 3.0 contact; empty fields are left out.
+pub fn build_vcard_content(card: &VCardInfo) -> String {
+    let full_name = [card.first_name.trim(), card.last_name.trim()]
+        .iter()
+        .filter(|s| !s.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:3.0".to_string(),
+        format!(
+            "N:{};{};;;",
+            escape_vcard(card.last_name.trim()),
+            escape_vcard(card.first_name.trim())
+        ),
+        format!("FN:{}", escape_vcard(&full_name)),
+    ];
+    let optional = [
+        ("ORG", &card.organization),
+        ("TITLE", &card.title),
+        ("TEL", &card.phone),
+        ("EMAIL", &card.email),
+        ("URL", &card.url),
+        ("ADR", &card.address),
+        ("NOTE", &card.note),
+    ];
+    for (key, value) in optional {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        // ADR has seven components; put the free-form address in the street field
+        if key == "ADR" {
+            lines.push(format!("ADR:;;{};;;;", escape_vcard(value)));
+        } else {
+            lines.push(format!("{}:{}", key, escape_vcard(value)));
+        }
+    }
+    lines.push("END:VCARD".to_string());
+    lines.join("\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_png(data_uri: &str) -> RgbaImage {
+        let encoded = data_uri.strip_prefix("data:image/png;base64,").unwrap();
+        let bytes = general_purpose::STANDARD.decode(encoded).unwrap();
+        image::load_from_memory(&bytes).unwrap().to_rgba8()
+    }
+
+    #[test]
+    fn test_generate_qr() {
+        let result = generate_qr("https://example.com", QrOptions::default());
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.version, 2);
+        assert_eq!(result.modules, 25);
+        assert_eq!(result.byte_length, 19);
+
+        let image = decode_png(&result.data_uri);
+        assert_eq!(image.dimensions(), (256, 256));
+        // 256 / (25 + 8) = 7 px per module; the 25 px left over are split around the code
+        assert_eq!(image.get_pixel(0, 0), &Rgba([255, 255, 255, 255]));
+        let finder = 12 + 4 * 7;
+        assert_eq!(image.get_pixel(finder, finder), &Rgba([0, 0, 0, 255]));
+        assert_eq!(
+            image.get_pixel(finder - 1, finder),
+            &Rgba([255, 255, 255, 255])
+        );
+    }
+
+    #[test]
+    fn test_colors_and_margin() {
+        let options = QrOptions {
+            size: 100,
+            error_correction: ErrorCorrection::H,
+            foreground: "navy".to_string(),
+            background: "transparent".to_string(),
+            margin: 0,
+        };
+        let result = generate_qr("hello", options);
+        assert!(result.success);
+        let image = decode_png(&result.data_uri);
+        // 21 modules at 4 px with 8 px left over
+        assert_eq!(image.get_pixel(8, 8), &Rgba([0, 0, 128, 255]));
+        assert_eq!(image.get_pixel(0, 0), &Rgba([0, 0, 0, 0]));
+
+        let bad = QrOptions {
+            foreground: "notacolor".to_string(),
+            ..QrOptions::default()
+        };
+        assert!(generate_qr("hello", bad)
+            .error
+            .unwrap()
+            .starts_with("Invalid foreground color"));
+    }
+
+    #[test]
+    fn test_content_too_long() {
+        let options = QrOptions {
+            error_correction: ErrorCorrection::H,
+            ..QrOptions::default()
+        };
+        let result = generate_qr(&"x".repeat(1300), options);
+        assert!(!result.success);
+        let error = result.error.unwrap();
+        assert!(error.contains("1300 bytes"), "{}", error);
+        assert!(error.contains("1273"), "{}", error);
+
+        let fits = QrOptions {
+            size: 1024,
+            error_correction: ErrorCorrection::L,
+            ..QrOptions::default()
+        };
+        assert!(generate_qr(&"x".repeat(1300), fits).success);
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert!(!generate_qr("", QrOptions::default()).success);
+        let small = QrOptions {
+            size: 28,
+            ..QrOptions::default()
+        };
+        let error = generate_qr("hello", small).error.unwrap();
+        assert!(error.contains("at least 29 px"), "{}", error);
+    }
+
+    #[test]
+    fn test_save_qr() {
+        let dir = std::env::temp_dir();
+        let png = dir.join("taurin_qr_test.png");
+        let svg = dir.join("taurin_qr_test.svg");
+
+        save_qr("hello", QrOptions::default(), png.to_str().unwrap()).unwrap();
+        let image = image::open(&png).unwrap();
+        assert_eq!(image.width(), 256);
+
+        save_qr("hello", QrOptions::default(), svg.to_str().unwrap()).unwrap();
+        let content = std::fs::read_to_string(&svg).unwrap();
+        assert!(content.contains("viewBox=\"0 0 29 29\""));
+        assert!(content.contains("fill=\"#000000\""));
+        assert!(content.contains("M4,4h7v1h-7z"));
+
+        assert!(save_qr("hello", QrOptions::default(), "out.gif").is_err());
+        let _ = std::fs::remove_file(png);
+        let _ = std::fs::remove_file(svg);
+    }
+
+    #[test]
+    fn test_build_wifi_content() {
+        let config = WifiConfig {
+            ssid: "Cafe;Guest".to_string(),
+            password: "p@ss:word\\1".to_string(),
+            security: WifiSecurity::Wpa,
+            hidden: false,
+        };
+        assert_eq!(
+            build_wifi_content(&config),
+            "WIFI:T:WPA;S:Cafe\\;Guest;P:p@ss\\:word\\\\1;;"
+        );
+
+        let open = WifiConfig {
+            ssid: "Free".to_string(),
+            password: "ignored".to_string(),
+            security: WifiSecurity::None,
+            hidden: true,
+        };
+        assert_eq!(build_wifi_content(&open), "WIFI:T:nopass;S:Free;H:true;;");
+    }
+
+    #[test]
+    fn test_parse_wifi_content() {
+        let config = WifiConfig {
+            ssid: "Cafe;Guest".to_string(),
+            password: "p@ss:word\\1".to_string(),
+            security: WifiSecurity::Wpa,
+            hidden: true,
+        };
+        assert_eq!(
+            parse_wifi_content(&build_wifi_content(&config)),
+            Some(config)
+        );
+
+        // Field order is free and WPA2/WPA3 variants count as WPA
+        let parsed = parse_wifi_content("WIFI:S:Home;T:WPA2;P:secret;;").unwrap();
+        assert_eq!(parsed.ssid, "Home");
+        assert_eq!(parsed.password, "secret");
+        assert_eq!(parsed.security, WifiSecurity::Wpa);
+        assert_eq!(
+            parse_wifi_content("WIFI:T:WEP;S:Old;P:abc;;")
+                .unwrap()
+                .security,
+            WifiSecurity::Wep
+        );
+        assert_eq!(
+            parse_wifi_content("WIFI:S:Open;;").unwrap().security,
+            WifiSecurity::None
+        );
+
+        assert_eq!(parse_wifi_content("WIFI:T:WPA;P:secret;;"), None);
+        assert_eq!(parse_wifi_content("https://example.com"), None);
+    }
+
+    #[test]
+    fn test_build_vcard_content() {
+        let card = VCardInfo {
+            first_name: "Taro".to_string(),
+            last_name: "Yamada".to_string(),
+            organization: "Example, Inc.".to_string(),
+            phone: "+81-3-1234-5678".to_string(),
+            email: "taro@example.com".to_string(),
+            address: "1-2-3 Chiyoda\nTokyo".to_string(),
+            ..VCardInfo::default()
+        };
+        assert_eq!(
+            build_vcard_content(&card),
+            [
+                "BEGIN:VCARD",
+                "VERSION:3.0",
+                "N:Yamada;Taro;;;",
+                "FN:Taro Yamada",
+                "ORG:Example\\, Inc.",
+                "TEL:+81-3-1234-5678",
+                "EMAIL:taro@example.com",
+                "ADR:;;1-2-3 Chiyoda\\nTokyo;;;;",
+                "END:VCARD",
+            ]
+            .join("\r\n")
+        );
+    }
+}
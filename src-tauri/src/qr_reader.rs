@@ -0,0 +1,288 @@
+use crate::qr_generator::{parse_wifi_content, WifiConfig};
+use image::GrayImage;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum QrDecodeErrorKind {
+    /// The file is missing or not an image we can decode
+    ImageUnreadable,
+    /// The image loaded but contains nothing that looks like a QR code
+    NotFound,
+    /// QR codes were located but none of them could be decoded
+    DecodeFailed,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QrPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Axis-aligned box around a code, clamped to the image
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundingBox {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedQr {
+    pub content: String,
+    pub bounds: BoundingBox,
+    /// The four corners as detected, which differ from `bounds` when the code is rotated
+    pub corners: Vec<QrPoint>,
+    pub version: usize,
+    pub is_url: bool,
+    /// Parsed network details when the content is a `WIFI:` payload
+    pub wifi: Option<WifiConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QrDecodeResult {
+    pub success: bool,
+    pub codes: Vec<DecodedQr>,
+    /// Source dimensions, for drawing `bounds` over a scaled preview
+    pub image_width: u32,
+    pub image_height: u32,
+    pub error: Option<String>,
+    pub error_kind: Option<QrDecodeErrorKind>,
+}
+
+impl QrDecodeResult {
+    fn failure(kind: QrDecodeErrorKind, message: String) -> Self {
+        Self {
+            success: false,
+            codes: Vec::new(),
+            image_width: 0,
+            image_height: 0,
+            error: Some(message),
+            error_kind: Some(kind),
+        }
+    }
+}
+
+fn is_url(content: &str) -> bool {
+    let lower = content.to_ascii_lowercase();
+    ["http://", "https://"].iter().any(|scheme| {
+        lower.len() > scheme.len()
+            && lower.starts_with(scheme)
+            && !content.chars().any(char::is_whitespace)
+    })
+}
+
+fn bounding_box(corners: &[QrPoint], width: u32, height: u32) -> BoundingBox {
+    let clamp_x = |v: i32| v.clamp(0, width as i32) as u32;
+    let clamp_y = |v: i32| v.clamp(0, height as i32) as u32;
+    let min_x = clamp_x(corners.iter().map(|p| p.x).min().unwrap_or(0));
+    let max_x = clamp_x(corners.iter().map(|p| p.x).max().unwrap_or(0));
+    let min_y = clamp_y(corners.iter().map(|p| p.y).min().unwrap_or(0));
+    let max_y = clamp_y(corners.iter().map(|p| p.y).max().unwrap_or(0));
+    BoundingBox {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x,
+        height: max_y - min_y,
+    }
+}
+
+fn decoded_qr(
+    content: String,
+    corners: Vec<QrPoint>,
+    version: usize,
+    width: u32,
+    height: u32,
+) -> DecodedQr {
+    DecodedQr {
+        bounds: bounding_box(&corners, width, height),
+        corners,
+        version,
+        is_url: is_url(&content),
+        wifi: parse_wifi_content(&content),
+        content,
+    }
+}
+
+fn decode_luma(image: &GrayImage) -> QrDecodeResult {
+    let (width, height) = image.dimensions();
+    let mut prepared =
+        rqrr::PreparedImage::prepare_from_greyscale(width as usize, height as usize, |x, y| {
+            image.get_pixel(x as u32, y as u32).0[0]
+        });
+    let grids = prepared.detect_grids();
+    let failure = |kind, message| QrDecodeResult {
+        image_width: width,
+        image_height: height,
+        ..QrDecodeResult::failure(kind, message)
+    };
+    if grids.is_empty() {
+        return failure(
+            QrDecodeErrorKind::NotFound,
+            "No QR code found in the image".to_string(),
+        );
+    }
+
+    let mut codes = Vec::new();
+    let mut last_error = None;
+    for grid in &grids {
+        match grid.decode() {
+            Ok((meta, content)) => {
+                let corners = grid
+                    .bounds
+                    .iter()
+                    .map(|p| QrPoint { x: p.x, y: p.y })
+                    .collect();
+                codes.push(decoded_qr(content, corners, meta.version.0, width, height));
+            }
+            Err(e) => last_error = Some(e.to_string()),
+        }
+    }
+
+    if codes.is_empty() {
+        return failure(
+            QrDecodeErrorKind::DecodeFailed,
+            format!(
+                "Found {} QR code(s) but could not decode them: {}",
+                grids.len(),
+                last_error.unwrap_or_default()
+            ),
+        );
+    }
+    // Reading order: top to bottom, then left to right
+    codes.sort_by_key(|code| (code.bounds.y, code.bounds.x));
+    QrDecodeResult {
+        success: true,
+        codes,
+        image_width: width,
+        image_height: height,
+        error: None,
+        error_kind: None,
+    }
+}
+
+/// Find and decode every QR code in the image at `path`.
+pub fn decode_qr_from_image(path: &str) -> QrDecodeResult {
+    match image::open(path) {
+        Ok(image) => decode_luma(&image.to_luma8()),
+        Err(e) => QrDecodeResult::failure(
+            QrDecodeErrorKind::ImageUnreadable,
+            format!("Failed to open image: {}", e),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qr_generator::{build_wifi_content, generate_qr, QrOptions, WifiSecurity};
+    use base64::{engine::general_purpose, Engine};
+    use image::{imageops, Luma};
+
+    fn qr_image(content: &str) -> GrayImage {
+        let result = generate_qr(content, QrOptions::default());
+        let encoded = result
+            .data_uri
+            .strip_prefix("data:image/png;base64,")
+            .unwrap();
+        let bytes = general_purpose::STANDARD.decode(encoded).unwrap();
+        image::load_from_memory(&bytes).unwrap().to_luma8()
+    }
+
+    #[test]
+    fn test_is_url() {
+        assert!(is_url("https://example.com/path?q=1"));
+        assert!(is_url("HTTP://EXAMPLE.COM"));
+        assert!(!is_url("https://"));
+        assert!(!is_url("see https://example.com"));
+        assert!(!is_url("mailto:someone@example.com"));
+    }
+
+    #[test]
+    fn test_decoded_qr_metadata() {
+        // A slightly rotated code whose corner pokes outside the image
+        let corners = vec![
+            QrPoint { x: 12, y: -3 },
+            QrPoint { x: 90, y: 8 },
+            QrPoint { x: 80, y: 85 },
+            QrPoint { x: 2, y: 75 },
+        ];
+        let content = "WIFI:T:WPA;S:Office;P:hunter2;;".to_string();
+        let code = decoded_qr(content, corners.clone(), 2, 88, 100);
+        assert_eq!(
+            code.bounds,
+            BoundingBox {
+                x: 2,
+                y: 0,
+                width: 86,
+                height: 85
+            }
+        );
+        assert_eq!(code.corners, corners);
+        assert!(!code.is_url);
+        let wifi = code.wifi.unwrap();
+        assert_eq!(wifi.ssid, "Office");
+        assert_eq!(wifi.password, "hunter2");
+        assert_eq!(wifi.security, WifiSecurity::Wpa);
+
+        let code = decoded_qr("https://example.com".to_string(), corners, 2, 88, 100);
+        assert!(code.is_url);
+        assert!(code.wifi.is_none());
+    }
+
+    #[test]
+    fn test_unreadable_image() {
+        let missing = decode_qr_from_image("/nonexistent/taurin_qr.png");
+        assert!(!missing.success);
+        assert_eq!(missing.error_kind, Some(QrDecodeErrorKind::ImageUnreadable));
+
+        let path = std::env::temp_dir().join("taurin_qr_reader_garbage.png");
+        std::fs::write(&path, b"not an image").unwrap();
+        let garbage = decode_qr_from_image(path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+        assert_eq!(garbage.error_kind, Some(QrDecodeErrorKind::ImageUnreadable));
+    }
+
+    #[test]
+    fn test_no_qr_found() {
+        let blank = GrayImage::from_pixel(200, 200, Luma([255]));
+        let result = decode_luma(&blank);
+        assert!(!result.success);
+        assert_eq!(result.error_kind, Some(QrDecodeErrorKind::NotFound));
+        assert!(result.codes.is_empty());
+        assert_eq!((result.image_width, result.image_height), (200, 200));
+    }
+
+    #[test]
+    fn test_decode_generated_qr() {
+        let wifi = WifiConfig {
+            ssid: "Cafe;Guest".to_string(),
+            password: "latte".to_string(),
+            security: WifiSecurity::Wpa,
+            hidden: false,
+        };
+        let mut canvas = GrayImage::from_pixel(600, 300, Luma([255]));
+        imageops::overlay(&mut canvas, &qr_image("https://example.com"), 0, 0);
+        imageops::overlay(&mut canvas, &qr_image(&build_wifi_content(&wifi)), 320, 20);
+
+        let path = std::env::temp_dir().join("taurin_qr_reader_roundtrip.png");
+        canvas.save(&path).unwrap();
+        let result = decode_qr_from_image(path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.codes.len(), 2);
+        assert_eq!(result.image_width, 600);
+        let url = &result.codes[0];
+        assert_eq!(url.content, "https://example.com");
+        assert!(url.is_url);
+        assert!(url.bounds.x < 60 && url.bounds.width > 150);
+        let network = &result.codes[1];
+        assert!(network.bounds.x >= 320);
+        assert_eq!(network.wifi.as_ref(), Some(&wifi));
+    }
+}
@@ -0,0 +1,464 @@
+use serde::{Deserialize, Serialize};
+
+/// Fraction digits produced when no precision is given
+const DEFAULT_FRACTION_DIGITS: usize = 16;
+const MAX_FRACTION_DIGITS: usize = 64;
+const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RadixConvertResult {
+    pub success: bool,
+    /// Base the input was read in, after `0x` / `0b` / `0o` detection
+    pub detected_base: u32,
+    /// The value in the requested target base
+    pub output: String,
+    pub binary: String,
+    pub octal: String,
+    pub decimal: String,
+    pub hexadecimal: String,
+    /// Integer part in the smallest of 8/16/32/64/128 bits that holds it, two's complement
+    /// for negative values, in groups of 4
+    pub bit_pattern: String,
+    pub bit_width: u32,
+    pub byte_count: u32,
+    /// The fraction did not terminate within the requested number of digits
+    pub fraction_truncated: bool,
+    pub error: Option<String>,
+}
+
+impl RadixConvertResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum FloatPrecision {
+    F32,
+    F64,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum FloatClass {
+    #[default]
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    NaN,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FloatBitsResult {
+    pub success: bool,
+    /// Shortest decimal text that reads back as the same value
+    pub value: String,
+    pub hex: String,
+    pub sign: String,
+    pub exponent: String,
+    pub mantissa: String,
+    /// Exponent with the bias removed; not meaningful for zero, infinity and NaN
+    pub exponent_value: i32,
+    pub class: FloatClass,
+    pub error: Option<String>,
+}
+
+impl FloatBitsResult {
+    fn failure(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Default::default()
+        }
+    }
+}
+
+/// A number split into its parts, with separators and any prefix removed
+struct ParsedNumber {
+    negative: bool,
+    base: u32,
+    integer: u128,
+    /// Fraction digit values in `base`
+    fraction: Vec<u32>,
+}
+
+fn parse_number(input: &str, from_base: u32) -> Result<ParsedNumber, String> {
+    let cleaned: String = input
+        .trim()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .collect();
+    let (negative, unsigned) = match cleaned.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, cleaned.strip_prefix('+').unwrap_or(&cleaned)),
+    };
+
+    // A prefix is only honoured in auto mode or when it names the chosen base, so hex
+    // digits such as `0b1` are not mistaken for a binary prefix
+    let lower = unsigned.to_ascii_lowercase();
+    let prefixed = [("0x", 16), ("0b", 2), ("0o", 8)]
+        .into_iter()
+        .find(|(prefix, base)| lower.starts_with(prefix) && (from_base == 0 || from_base == *base));
+    let (base, digits) = match prefixed {
+        Some((prefix, base)) => (base, &unsigned[prefix.len()..]),
+        None => (if from_base == 0 { 10 } else { from_base }, unsigned),
+    };
+    if !(2..=36).contains(&base) {
+        return Err(format!("Base must be between 2 and 36, got {}", base));
+    }
+
+    let (integer_digits, fraction_digits) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (digits, ""),
+    };
+    if integer_digits.is_empty() && fraction_digits.is_empty() {
+        return Err("Enter a number".to_string());
+    }
+    let digit_value = |c: char| {
+        c.to_digit(base)
+            .ok_or_else(|| format!("Invalid digit '{}' for base {}", c, base))
+    };
+
+    let mut integer: u128 = 0;
+    for c in integer_digits.chars() {
+        let digit = digit_value(c)?;
+        integer = integer
+            .checked_mul(base as u128)
+            .and_then(|v| v.checked_add(digit as u128))
+            .ok_or_else(|| "Value exceeds 128 bits".to_string())?;
+    }
+    if negative && integer > i128::MIN.unsigned_abs() {
+        return Err("Negative value exceeds the 128-bit signed range".to_string());
+    }
+    let fraction = fraction_digits
+        .chars()
+        .map(digit_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ParsedNumber {
+        negative,
+        base,
+        integer,
+        fraction,
+    })
+}
+
+fn integer_to_base(mut value: u128, base: u32) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::new();
+    while value > 0 {
+        digits.push(DIGITS[(value % base as u128) as usize]);
+        value /= base as u128;
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("digits are ASCII")
+}
+
+/// Convert fraction digits from `from` to `to` by repeated multiplication, which stays
+/// exact however many digits the input has. Returns the digits and whether they were cut
+/// off before the fraction terminated.
+fn fraction_to_base(fraction: &[u32], from: u32, to: u32, max_digits: usize) -> (String, bool) {
+    let mut remaining = fraction.to_vec();
+    let mut digits = String::new();
+    while remaining.iter().any(|d| *d != 0) {
+        if digits.len() == max_digits {
+            return (digits, true);
+        }
+        let mut carry = 0;
+        for digit in remaining.iter_mut().rev() {
+            let product = *digit * to + carry;
+            *digit = product % from;
+            carry = product / from;
+        }
+        digits.push(DIGITS[carry as usize] as char);
+    }
+    (digits, false)
+}
+
+fn format_in_base(number: &ParsedNumber, base: u32, max_fraction: usize) -> (String, bool) {
+    let (fraction, truncated) = fraction_to_base(&number.fraction, number.base, base, max_fraction);
+    let mut text = integer_to_base(number.integer, base);
+    if !fraction.is_empty() {
+        text.push('.');
+        text.push_str(&fraction);
+    }
+    if number.negative && (number.integer != 0 || !fraction.is_empty()) {
+        text.insert(0, '-');
+    }
+    (text, truncated)
+}
+
+/// Smallest of 8/16/32/64/128 bits holding the integer part, and its bit pattern
+fn bit_pattern(negative: bool, magnitude: u128) -> (u32, String) {
+    let width = [8u32, 16, 32, 64, 128]
+        .into_iter()
+        .find(|&width| {
+            if negative {
+                magnitude <= 1u128 << (width - 1)
+            } else {
+                width == 128 || magnitude < 1u128 << width
+            }
+        })
+        .unwrap_or(128);
+    let bits = if negative {
+        magnitude.wrapping_neg()
+    } else {
+        magnitude
+    };
+    let text = format!("{:0128b}", bits);
+    let text = &text[(128 - width) as usize..];
+    let grouped = text
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| std::str::from_utf8(chunk).expect("bits are ASCII"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (width, grouped)
+}
+
+/// Convert `input` from `from_base` to `to_base` (2-36). A `from_base` of 0 detects the base
+/// from a `0x` / `0b` / `0o` prefix and otherwise reads decimal.
+pub fn convert_radix(input: &str, from_base: u32, to_base: u32) -> RadixConvertResult {
+    convert_radix_with_precision(input, from_base, to_base, DEFAULT_FRACTION_DIGITS)
+}
+
+/// [`convert_radix`] with the number of fraction digits to produce for non-terminating
+/// fractions
+pub fn convert_radix_with_precision(
+    input: &str,
+    from_base: u32,
+    to_base: u32,
+    fraction_digits: usize,
+) -> RadixConvertResult {
+    if !(2..=36).contains(&to_base) {
+        return RadixConvertResult::failure(format!(
+            "Base must be between 2 and 36, got {}",
+            to_base
+        ));
+    }
+    let number = match parse_number(input, from_base) {
+        Ok(number) => number,
+        Err(e) => return RadixConvertResult::failure(e),
+    };
+    let max_fraction = fraction_digits.min(MAX_FRACTION_DIGITS);
+
+    let (output, truncated) = format_in_base(&number, to_base, max_fraction);
+    let (binary, binary_truncated) = format_in_base(&number, 2, max_fraction);
+    let (octal, octal_truncated) = format_in_base(&number, 8, max_fraction);
+    let (decimal, decimal_truncated) = format_in_base(&number, 10, max_fraction);
+    let (hexadecimal, hex_truncated) = format_in_base(&number, 16, max_fraction);
+    let (bit_width, bit_pattern) = bit_pattern(number.negative, number.integer);
+
+    RadixConvertResult {
+        success: true,
+        detected_base: number.base,
+        output,
+        binary,
+        octal,
+        decimal,
+        hexadecimal,
+        bit_pattern,
+        bit_width,
+        byte_count: bit_width / 8,
+        fraction_truncated: truncated
+            || binary_truncated
+            || octal_truncated
+            || decimal_truncated
+            || hex_truncated,
+        error: None,
+    }
+}
+
+fn float_parts(bits: u64, precision: FloatPrecision) -> FloatBitsResult {
+    let (width, exponent_bits, value) = match precision {
+        FloatPrecision::F32 => (32, 8, f32::from_bits(bits as u32).to_string()),
+        FloatPrecision::F64 => (64, 11, f64::from_bits(bits).to_string()),
+    };
+    let mantissa_bits = width - 1 - exponent_bits;
+    let exponent_mask = (1u64 << exponent_bits) - 1;
+    let exponent = (bits >> mantissa_bits) & exponent_mask;
+    let mantissa = bits & ((1u64 << mantissa_bits) - 1);
+    let bias = (exponent_mask >> 1) as i32;
+
+    let class = match (exponent, mantissa) {
+        (0, 0) => FloatClass::Zero,
+        (0, _) => FloatClass::Subnormal,
+        (e, 0) if e == exponent_mask => FloatClass::Infinite,
+        (e, _) if e == exponent_mask => FloatClass::NaN,
+        _ => FloatClass::Normal,
+    };
+    let exponent_value = match class {
+        FloatClass::Subnormal => 1 - bias,
+        _ => exponent as i32 - bias,
+    };
+
+    FloatBitsResult {
+        success: true,
+        value,
+        hex: format!("{:0width$X}", bits, width = (width / 4) as usize),
+        sign: (bits >> (width - 1)).to_string(),
+        exponent: format!("{:0width$b}", exponent, width = exponent_bits as usize),
+        mantissa: format!("{:0width$b}", mantissa, width = mantissa_bits as usize),
+        exponent_value,
+        class,
+        error: None,
+    }
+}
+
+/// IEEE 754 bit layout of a decimal number (`inf` and `NaN` are accepted too)
+pub fn float_to_bits(input: &str, precision: FloatPrecision) -> FloatBitsResult {
+    let input = input.trim();
+    let bits = match precision {
+        FloatPrecision::F32 => input.parse::<f32>().map(|v| v.to_bits() as u64),
+        FloatPrecision::F64 => input.parse::<f64>().map(f64::to_bits),
+    };
+    match bits {
+        Ok(bits) => float_parts(bits, precision),
+        Err(_) => FloatBitsResult::failure(format!("Invalid number: {}", input)),
+    }
+}
+
+/// The number stored in an IEEE 754 bit pattern, given as hex (`0x` optional) or binary
+/// (`0b` prefix, or exactly 32/64 zeros and ones)
+pub fn bits_to_float(input: &str, precision: FloatPrecision) -> FloatBitsResult {
+    let width = match precision {
+        FloatPrecision::F32 => 32,
+        FloatPrecision::F64 => 64,
+    };
+    let cleaned: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .collect();
+    let lower = cleaned.to_ascii_lowercase();
+    let (digits, radix) = if let Some(rest) = lower.strip_prefix("0b") {
+        (rest, 2)
+    } else if let Some(rest) = lower.strip_prefix("0x") {
+        (rest, 16)
+    } else if lower.len() == width && lower.chars().all(|c| c == '0' || c == '1') {
+        (lower.as_str(), 2)
+    } else {
+        (lower.as_str(), 16)
+    };
+    let max_digits = if radix == 2 { width } else { width / 4 };
+    if digits.is_empty() || digits.len() > max_digits {
+        return FloatBitsResult::failure(format!(
+            "Expected up to {} {} digits",
+            max_digits,
+            if radix == 2 { "binary" } else { "hex" }
+        ));
+    }
+    match u64::from_str_radix(digits, radix) {
+        Ok(bits) => float_parts(bits, precision),
+        Err(_) => FloatBitsResult::failure(format!("Invalid bit pattern: {}", input.trim())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_radix_integers() {
+        let result = convert_radix("0xFF", 0, 10);
+        assert!(result.success);
+        assert_eq!(result.detected_base, 16);
+        assert_eq!(result.output, "255");
+        assert_eq!(result.binary, "11111111");
+        assert_eq!(result.octal, "377");
+        assert_eq!(result.bit_pattern, "1111 1111");
+        assert_eq!((result.bit_width, result.byte_count), (8, 1));
+
+        assert_eq!(convert_radix("0b1111_1111", 0, 16).output, "FF");
+        assert_eq!(convert_radix("0o777", 0, 10).output, "511");
+        assert_eq!(convert_radix("zz", 36, 10).output, "1295");
+        // In base 16 `0b1` is a number, not a binary prefix
+        assert_eq!(convert_radix("0b1", 16, 10).output, "177");
+
+        let negative = convert_radix("-1", 10, 16);
+        assert_eq!(negative.output, "-1");
+        assert_eq!(negative.bit_pattern, "1111 1111");
+        let negative = convert_radix("-129", 10, 2);
+        assert_eq!(negative.bit_width, 16);
+        assert_eq!(negative.bit_pattern, "1111 1111 0111 1111");
+
+        let max = convert_radix(&u128::MAX.to_string(), 10, 16);
+        assert_eq!(max.hexadecimal, "F".repeat(32));
+        assert_eq!(max.byte_count, 16);
+        let min = convert_radix(&i128::MIN.to_string(), 10, 16);
+        assert!(min.success);
+        assert_eq!(min.bit_width, 128);
+
+        assert!(
+            convert_radix("340282366920938463463374607431768211456", 10, 16)
+                .error
+                .unwrap()
+                .contains("128 bits")
+        );
+        assert!(convert_radix("12", 2, 10).error.unwrap().contains("'2'"));
+        assert!(convert_radix("1", 10, 37).error.is_some());
+        assert!(convert_radix("  ", 10, 2).error.is_some());
+    }
+
+    #[test]
+    fn test_convert_radix_fractions() {
+        let result = convert_radix("0.5", 10, 2);
+        assert_eq!(result.output, "0.1");
+        assert!(!result.fraction_truncated);
+
+        assert_eq!(convert_radix("A.8", 16, 10).output, "10.5");
+        assert_eq!(convert_radix("-0.011", 2, 10).output, "-0.375");
+
+        let result = convert_radix_with_precision("0.1", 10, 2, 8);
+        assert_eq!(result.output, "0.00011001");
+        assert!(result.fraction_truncated);
+    }
+
+    #[test]
+    fn test_float_bits() {
+        let one = float_to_bits("1", FloatPrecision::F32);
+        assert_eq!(one.hex, "3F800000");
+        assert_eq!(one.sign, "0");
+        assert_eq!(one.exponent, "01111111");
+        assert_eq!(one.exponent_value, 0);
+        assert_eq!(one.class, FloatClass::Normal);
+
+        let value = float_to_bits("-0.1", FloatPrecision::F64);
+        assert_eq!(value.hex, "BFB999999999999A");
+        assert_eq!(value.mantissa.len(), 52);
+
+        assert_eq!(
+            bits_to_float("0x40490FDB", FloatPrecision::F32).value,
+            "3.1415927"
+        );
+        assert_eq!(
+            bits_to_float(
+                "0100 0000 0100 1001 0000 1111 1101 1011",
+                FloatPrecision::F32
+            )
+            .value,
+            "3.1415927"
+        );
+        assert_eq!(
+            bits_to_float("7FF0000000000000", FloatPrecision::F64).class,
+            FloatClass::Infinite
+        );
+        assert_eq!(
+            bits_to_float("1", FloatPrecision::F32).class,
+            FloatClass::Subnormal
+        );
+        assert_eq!(
+            float_to_bits("NaN", FloatPrecision::F32).class,
+            FloatClass::NaN
+        );
+        assert!(float_to_bits("abc", FloatPrecision::F32).error.is_some());
+        assert!(bits_to_float("123456789", FloatPrecision::F32)
+            .error
+            .is_some());
+    }
+}
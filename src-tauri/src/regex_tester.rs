@@ -1,5 +1,13 @@
-use regex::{Regex, RegexBuilder};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use regex::{Captures, Regex, RegexBuilder};
+use regex_syntax::hir::{Class, Hir, HirKind};
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,8 +22,15 @@ pub struct RegexFlags {
 #[serde(rename_all = "camelCase")]
 pub struct MatchInfo {
     pub full_match: String,
+    /// Byte offsets into the text
     pub start: usize,
     pub end: usize,
+    /// Offsets in Unicode code points
+    pub char_start: usize,
+    pub char_end: usize,
+    /// Offsets in UTF-16 code units, which is what JavaScript string indices use
+    pub utf16_start: usize,
+    pub utf16_end: usize,
     pub groups: Vec<GroupInfo>,
 }
 
@@ -27,6 +42,10 @@ pub struct GroupInfo {
     pub value: String,
     pub start: usize,
     pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub utf16_start: usize,
+    pub utf16_end: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,7 +54,50 @@ pub struct RegexResult {
     pub success: bool,
     pub matches: Vec<MatchInfo>,
     pub match_count: usize,
+    /// Capture group names by group number (index 0 is the whole match)
+    pub group_names: Vec<Option<String>>,
+    /// Set when matching stopped at `MAX_MATCHES`
+    pub truncated: bool,
+    pub timed_out: bool,
     pub error: Option<String>,
+    pub error_kind: Option<RegexErrorKind>,
+}
+
+impl RegexResult {
+    fn failure(error: RegexError) -> Self {
+        Self {
+            success: false,
+            matches: vec![],
+            match_count: 0,
+            group_names: vec![],
+            truncated: false,
+            timed_out: error.kind == RegexErrorKind::Timeout,
+            error: Some(error.message),
+            error_kind: Some(error.kind),
+        }
+    }
+}
+
+/// How a single match was rewritten by `replace_regex`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplacementPair {
+    /// Byte range of the match in the input text
+    pub start: usize,
+    pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub utf16_start: usize,
+    pub utf16_end: usize,
+    pub original: String,
+    pub replaced: String,
+    /// Where the replacement ended up in the output text, in the same three units
+    pub result_start: usize,
+    pub result_end: usize,
+    pub result_char_start: usize,
+    pub result_char_end: usize,
+    pub result_utf16_start: usize,
+    pub result_utf16_end: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,85 +106,208 @@ pub struct ReplaceResult {
     pub success: bool,
     pub result: String,
     pub replacements: usize,
+    pub pairs: Vec<ReplacementPair>,
+    /// Problems with `$` references in the replacement (unknown groups, ambiguous names)
+    pub warnings: Vec<String>,
+    /// Set when replacing stopped at `MAX_MATCHES`; the rest of the text is left as is
+    pub truncated: bool,
+    pub timed_out: bool,
     pub error: Option<String>,
+    pub error_kind: Option<RegexErrorKind>,
+}
+
+impl ReplaceResult {
+    fn failure(error: RegexError) -> Self {
+        Self {
+            success: false,
+            result: String::new(),
+            replacements: 0,
+            pairs: vec![],
+            warnings: vec![],
+            truncated: false,
+            timed_out: error.kind == RegexErrorKind::Timeout,
+            error: Some(error.message),
+            error_kind: Some(error.kind),
+        }
+    }
+}
+
+/// Why a regex run failed, so the frontend can react without parsing messages
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RegexErrorKind {
+    Syntax,
+    /// The compiled program exceeded `REGEX_SIZE_LIMIT`
+    TooComplex,
+    PatternTooLong,
+    InputTooLarge,
+    Timeout,
 }
 
-fn build_regex(pattern: &str, flags: RegexFlags) -> Result<Regex, String> {
+#[derive(Debug, Clone)]
+struct RegexError {
+    kind: RegexErrorKind,
+    message: String,
+}
+
+pub const MAX_PATTERN_LEN: usize = 10_000;
+pub const MAX_TEXT_BYTES: usize = 10 * 1024 * 1024;
+const REGEX_SIZE_LIMIT: usize = 10 * 1024 * 1024;
+const DFA_SIZE_LIMIT: usize = 10 * 1024 * 1024;
+pub const DEFAULT_TIMEOUT_MS: u64 = 2_000;
+
+/// Upper bound on matches collected or replaced, so huge inputs cannot freeze the UI
+pub const MAX_MATCHES: usize = 10_000;
+
+/// Length of `s` in code points and in UTF-16 code units
+fn text_len(s: &str) -> (usize, usize) {
+    s.chars().fold((0, 0), |(chars, utf16), c| {
+        (chars + 1, utf16 + c.len_utf16())
+    })
+}
+
+/// Converts byte offsets into code point and UTF-16 offsets.
+///
+/// Lookups are expected in increasing order (matches come out of the regex that way), so
+/// each one only scans the text between the previous offset and the new one.
+struct OffsetMapper<'a> {
+    text: &'a str,
+    byte: usize,
+    chars: usize,
+    utf16: usize,
+}
+
+impl<'a> OffsetMapper<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            byte: 0,
+            chars: 0,
+            utf16: 0,
+        }
+    }
+
+    fn map(&mut self, byte: usize) -> (usize, usize) {
+        if byte < self.byte {
+            *self = Self::new(self.text);
+        }
+        let (chars, utf16) = text_len(&self.text[self.byte..byte]);
+        self.byte = byte;
+        self.chars += chars;
+        self.utf16 += utf16;
+        (self.chars, self.utf16)
+    }
+}
+
+fn build_regex(pattern: &str, text: &str, flags: RegexFlags) -> Result<Regex, RegexError> {
+    if pattern.chars().count() > MAX_PATTERN_LEN {
+        return Err(RegexError {
+            kind: RegexErrorKind::PatternTooLong,
+            message: format!("Pattern is longer than {} characters", MAX_PATTERN_LEN),
+        });
+    }
+    if text.len() > MAX_TEXT_BYTES {
+        return Err(RegexError {
+            kind: RegexErrorKind::InputTooLarge,
+            message: format!("Text is larger than {} MB", MAX_TEXT_BYTES / (1024 * 1024)),
+        });
+    }
+
     RegexBuilder::new(pattern)
         .case_insensitive(flags.case_insensitive)
         .multi_line(flags.multiline)
         .dot_matches_new_line(flags.dot_all)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .dfa_size_limit(DFA_SIZE_LIMIT)
         .build()
-        .map_err(|e| e.to_string())
+        .map_err(|e| match e {
+            regex::Error::CompiledTooBig(limit) => RegexError {
+                kind: RegexErrorKind::TooComplex,
+                message: format!(
+                    "Pattern is too complex (compiled size exceeds {} bytes)",
+                    limit
+                ),
+            },
+            e => RegexError {
+                kind: RegexErrorKind::Syntax,
+                message: e.to_string(),
+            },
+        })
+}
+
+/// Run `f` on a worker thread, giving up after `timeout`.
+///
+/// The regex engine cannot be interrupted, so a timed-out worker finishes in the background
+/// and its result is dropped.
+fn run_with_timeout<T, F>(f: F, timeout: Duration) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(f());
+    });
+    receiver.recv_timeout(timeout).ok()
+}
+
+fn timeout_error(timeout: Duration) -> RegexError {
+    RegexError {
+        kind: RegexErrorKind::Timeout,
+        message: format!(
+            "Matching took longer than {:.1} seconds and was stopped",
+            timeout.as_secs_f64()
+        ),
+    }
+}
+
+/// `test_regex` on a worker thread, failing with `timed_out` after `timeout_ms`
+pub fn test_regex_with_timeout(
+    pattern: String,
+    test_text: String,
+    flags: RegexFlags,
+    timeout_ms: Option<u64>,
+) -> RegexResult {
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+    run_with_timeout(move || test_regex(&pattern, &test_text, flags), timeout)
+        .unwrap_or_else(|| RegexResult::failure(timeout_error(timeout)))
+}
+
+/// `replace_regex` on a worker thread, failing with `timed_out` after `timeout_ms`
+pub fn replace_regex_with_timeout(
+    pattern: String,
+    test_text: String,
+    replacement: String,
+    flags: RegexFlags,
+    timeout_ms: Option<u64>,
+) -> ReplaceResult {
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+    run_with_timeout(
+        move || replace_regex(&pattern, &test_text, &replacement, flags),
+        timeout,
+    )
+    .unwrap_or_else(|| ReplaceResult::failure(timeout_error(timeout)))
 }
 
 pub fn test_regex(pattern: &str, test_text: &str, flags: RegexFlags) -> RegexResult {
-    let re = match build_regex(pattern, flags) {
+    let re = match build_regex(pattern, test_text, flags) {
         Ok(r) => r,
-        Err(e) => {
-            return RegexResult {
-                success: false,
-                matches: vec![],
-                match_count: 0,
-                error: Some(e),
-            };
-        }
+        Err(e) => return RegexResult::failure(e),
     };
 
-    let mut matches = Vec::new();
     let group_names: Vec<Option<&str>> = re.capture_names().collect();
+    // Non-global: find first match only
+    let limit = if flags.global { MAX_MATCHES } else { 1 };
 
-    if flags.global {
-        // Global flag: find all matches
-        for caps in re.captures_iter(test_text) {
-            let m = caps.get(0).unwrap();
-            let mut groups = Vec::new();
-
-            // Iterate through capture groups (skip index 0 which is the full match)
-            for (i, name) in group_names.iter().enumerate().skip(1) {
-                if let Some(group_match) = caps.get(i) {
-                    groups.push(GroupInfo {
-                        index: i,
-                        name: name.map(|n| n.to_string()),
-                        value: group_match.as_str().to_string(),
-                        start: group_match.start(),
-                        end: group_match.end(),
-                    });
-                }
-            }
-
-            matches.push(MatchInfo {
-                full_match: m.as_str().to_string(),
-                start: m.start(),
-                end: m.end(),
-                groups,
-            });
-        }
-    } else {
-        // Non-global: find first match only
-        if let Some(caps) = re.captures(test_text) {
-            let m = caps.get(0).unwrap();
-            let mut groups = Vec::new();
-
-            for (i, name) in group_names.iter().enumerate().skip(1) {
-                if let Some(group_match) = caps.get(i) {
-                    groups.push(GroupInfo {
-                        index: i,
-                        name: name.map(|n| n.to_string()),
-                        value: group_match.as_str().to_string(),
-                        start: group_match.start(),
-                        end: group_match.end(),
-                    });
-                }
-            }
-
-            matches.push(MatchInfo {
-                full_match: m.as_str().to_string(),
-                start: m.start(),
-                end: m.end(),
-                groups,
-            });
+    let mut matches = Vec::new();
+    let mut truncated = false;
+    let mut offsets = OffsetMapper::new(test_text);
+    for caps in re.captures_iter(test_text) {
+        if matches.len() == limit {
+            truncated = flags.global;
+            break;
         }
+        matches.push(match_info(test_text, &caps, &group_names, &mut offsets));
     }
 
     let match_count = matches.len();
@@ -131,47 +316,794 @@ pub fn test_regex(pattern: &str, test_text: &str, flags: RegexFlags) -> RegexRes
         success: true,
         matches,
         match_count,
+        group_names: group_names
+            .iter()
+            .map(|name| name.map(str::to_string))
+            .collect(),
+        truncated,
+        timed_out: false,
         error: None,
+        error_kind: None,
+    }
+}
+
+fn match_info(
+    text: &str,
+    caps: &Captures,
+    group_names: &[Option<&str>],
+    offsets: &mut OffsetMapper,
+) -> MatchInfo {
+    let m = caps.get(0).unwrap();
+    let (char_start, utf16_start) = offsets.map(m.start());
+    let (char_end, utf16_end) = offsets.map(m.end());
+    // Groups can overlap and nest, so they are measured from the start of the match
+    let group_offsets = |byte: usize| {
+        let (chars, utf16) = text_len(&text[m.start()..byte]);
+        (char_start + chars, utf16_start + utf16)
+    };
+
+    // Iterate through capture groups (skip index 0 which is the full match)
+    let groups = group_names
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter_map(|(i, name)| {
+            caps.get(i).map(|group_match| {
+                let (char_start, utf16_start) = group_offsets(group_match.start());
+                let (char_end, utf16_end) = group_offsets(group_match.end());
+                GroupInfo {
+                    index: i,
+                    name: name.map(|n| n.to_string()),
+                    value: group_match.as_str().to_string(),
+                    start: group_match.start(),
+                    end: group_match.end(),
+                    char_start,
+                    char_end,
+                    utf16_start,
+                    utf16_end,
+                }
+            })
+        })
+        .collect();
+
+    MatchInfo {
+        full_match: m.as_str().to_string(),
+        start: m.start(),
+        end: m.end(),
+        char_start,
+        char_end,
+        utf16_start,
+        utf16_end,
+        groups,
+    }
+}
+
+/// Check `$1` / `${1}` / `$name` / `${name}` references (`$$` is a literal `$`)
+fn check_references(re: &Regex, replacement: &str) -> Vec<String> {
+    let names: Vec<&str> = re.capture_names().flatten().collect();
+    let mut warnings = Vec::new();
+    let mut rest = replacement;
+
+    while let Some(pos) = rest.find('$') {
+        rest = &rest[pos + 1..];
+        if let Some(after) = rest.strip_prefix('$') {
+            rest = after;
+            continue;
+        }
+        let (reference, braced) = match rest.strip_prefix('{') {
+            Some(inner) => match inner.find('}') {
+                Some(close) => {
+                    rest = &inner[close + 1..];
+                    (&inner[..close], true)
+                }
+                None => continue,
+            },
+            None => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                let reference = &rest[..len];
+                rest = &rest[len..];
+                (reference, false)
+            }
+        };
+        if reference.is_empty() {
+            continue;
+        }
+
+        if let Ok(index) = reference.parse::<usize>() {
+            if index >= re.captures_len() {
+                warnings.push(format!(
+                    "${} refers to a group that does not exist (the pattern has {} group(s))",
+                    reference,
+                    re.captures_len() - 1
+                ));
+            }
+        } else if !names.contains(&reference) {
+            let digits = reference.len()
+                - reference
+                    .trim_start_matches(|c: char| c.is_ascii_digit())
+                    .len();
+            if !braced && digits > 0 {
+                warnings.push(format!(
+                    "${} is read as a group named \"{}\"; write ${{{}}}{} to use group {}",
+                    reference,
+                    reference,
+                    &reference[..digits],
+                    &reference[digits..],
+                    &reference[..digits]
+                ));
+            } else {
+                warnings.push(format!("No capture group named \"{}\"", reference));
+            }
+        }
     }
+
+    warnings
 }
 
+/// Replace matches, expanding `$1`, `${1}`, `$name` and `${name}` in `replacement`.
+///
+/// Unknown references expand to an empty string, as in the `regex` crate, and are
+/// reported in `warnings`.
 pub fn replace_regex(
     pattern: &str,
     test_text: &str,
     replacement: &str,
     flags: RegexFlags,
 ) -> ReplaceResult {
-    let re = match build_regex(pattern, flags) {
+    let re = match build_regex(pattern, test_text, flags) {
         Ok(r) => r,
-        Err(e) => {
-            return ReplaceResult {
-                success: false,
-                result: String::new(),
-                replacements: 0,
-                error: Some(e),
-            };
-        }
+        Err(e) => return ReplaceResult::failure(e),
     };
 
-    let mut replacement_count = 0;
+    let limit = if flags.global { MAX_MATCHES } else { 1 };
+    let mut result = String::with_capacity(test_text.len());
+    let mut pairs = Vec::new();
+    let mut truncated = false;
+    let mut last_end = 0;
+    let mut offsets = OffsetMapper::new(test_text);
+    // Output length in code points and UTF-16 units, kept alongside `result.len()`
+    let (mut result_chars, mut result_utf16) = (0, 0);
 
-    let result = if flags.global {
-        // Count replacements
-        replacement_count = re.find_iter(test_text).count();
-        re.replace_all(test_text, replacement).to_string()
-    } else {
-        if re.is_match(test_text) {
-            replacement_count = 1;
+    for caps in re.captures_iter(test_text) {
+        if pairs.len() == limit {
+            truncated = flags.global;
+            break;
         }
-        re.replace(test_text, replacement).to_string()
-    };
+        let m = caps.get(0).unwrap();
+        let mut replaced = String::new();
+        caps.expand(replacement, &mut replaced);
+
+        let (prev_chars, prev_utf16) = offsets.map(last_end);
+        let (char_start, utf16_start) = offsets.map(m.start());
+        let (char_end, utf16_end) = offsets.map(m.end());
+        result.push_str(&test_text[last_end..m.start()]);
+        result_chars += char_start - prev_chars;
+        result_utf16 += utf16_start - prev_utf16;
+
+        let result_start = result.len();
+        let (result_char_start, result_utf16_start) = (result_chars, result_utf16);
+        let (replaced_chars, replaced_utf16) = text_len(&replaced);
+        result.push_str(&replaced);
+        result_chars += replaced_chars;
+        result_utf16 += replaced_utf16;
+        last_end = m.end();
+        pairs.push(ReplacementPair {
+            start: m.start(),
+            end: m.end(),
+            char_start,
+            char_end,
+            utf16_start,
+            utf16_end,
+            original: m.as_str().to_string(),
+            replaced,
+            result_start,
+            result_end: result.len(),
+            result_char_start,
+            result_char_end: result_chars,
+            result_utf16_start,
+            result_utf16_end: result_utf16,
+        });
+    }
+    result.push_str(&test_text[last_end..]);
 
     ReplaceResult {
         success: true,
         result,
-        replacements: replacement_count,
+        replacements: pairs.len(),
+        pairs,
+        warnings: check_references(&re, replacement),
+        truncated,
+        timed_out: false,
         error: None,
+        error_kind: None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegexSampleResult {
+    pub success: bool,
+    /// Distinct strings that each match the whole pattern
+    pub samples: Vec<String>,
+    /// Syntax left out of generation, and why fewer samples than asked for came back
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl RegexSampleResult {
+    fn failure(error: String, warnings: Vec<String>) -> Self {
+        Self {
+            success: false,
+            samples: Vec::new(),
+            warnings,
+            error: Some(error),
+        }
+    }
+}
+
+const MAX_SAMPLES: u32 = 100;
+const MAX_SAMPLE_REPEAT: u32 = 100;
+/// Generation gives up on a sample once it grows past this, e.g. for nested repetitions
+const MAX_SAMPLE_CHARS: usize = 10_000;
+/// Tries per requested sample before settling for fewer
+const SAMPLE_ATTEMPTS_PER_SAMPLE: u32 = 20;
+/// Share of characters picked from printable ASCII when a class allows it, so `.` and
+/// `\w` give readable samples instead of mostly rare scripts
+const ASCII_PREFERENCE: f64 = 0.9;
+
+/// Generate up to `count` distinct strings matching `pattern`.
+///
+/// `*`, `+` and open-ended `{n,}` repeat at most `max_repeat` times beyond their minimum.
+/// Backreferences and lookaround cannot be generated; they are dropped with a warning and
+/// the samples match the rest of the pattern. Every sample is checked with `test_regex`
+/// against the pattern that was generated from before it is returned.
+pub fn generate_regex_samples(pattern: &str, count: u32, max_repeat: u32) -> RegexSampleResult {
+    let count = count.clamp(1, MAX_SAMPLES);
+    let max_repeat = max_repeat.min(MAX_SAMPLE_REPEAT);
+    let (reduced, warnings) = strip_ungeneratable(pattern);
+
+    let hir = match regex_syntax::ParserBuilder::new().build().parse(&reduced) {
+        Ok(hir) => hir,
+        Err(e) => return RegexSampleResult::failure(format!("Invalid pattern: {}", e), warnings),
+    };
+    let anchored = format!(r"\A(?:{})\z", reduced);
+    let flags = RegexFlags {
+        global: false,
+        case_insensitive: false,
+        multiline: false,
+        dot_all: false,
+    };
+
+    let mut rng = rand::thread_rng();
+    let mut samples: Vec<String> = Vec::new();
+    let mut rejected = 0;
+    for _ in 0..count * SAMPLE_ATTEMPTS_PER_SAMPLE {
+        if samples.len() == count as usize {
+            break;
+        }
+        let mut sample = String::new();
+        if !generate_sample(&hir, max_repeat, &mut rng, &mut sample) || samples.contains(&sample) {
+            continue;
+        }
+        let check = test_regex(&anchored, &sample, flags);
+        if let Some(error) = check.error {
+            return RegexSampleResult::failure(error, warnings);
+        }
+        if check.match_count == 1 {
+            samples.push(sample);
+        } else {
+            rejected += 1;
+        }
+    }
+
+    let mut warnings = warnings;
+    if samples.is_empty() && rejected > 0 {
+        warnings.push(
+            "No generated string matched; anchors or word boundaries in the middle of the pattern may be impossible to satisfy"
+                .to_string(),
+        );
+    } else if samples.len() < count as usize {
+        warnings.push(format!(
+            "Only {} distinct samples could be generated",
+            samples.len()
+        ));
+    }
+
+    RegexSampleResult {
+        success: true,
+        samples,
+        warnings,
+        error: None,
+    }
+}
+
+/// Remove backreferences (`\1`, `\k<name>`) and lookaround groups, which the `regex` crate
+/// does not support, returning the remaining pattern and a warning for each kind removed
+fn strip_ungeneratable(pattern: &str) -> (String, Vec<String>) {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut out = String::with_capacity(pattern.len());
+    let (mut backreference, mut lookaround) = (false, false);
+    let mut in_class = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' {
+            let next = chars.get(i + 1).copied();
+            if !in_class && matches!(next, Some('1'..='9')) {
+                backreference = true;
+                i += 2;
+                while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+                    i += 1;
+                }
+                continue;
+            }
+            if !in_class && next == Some('k') && chars.get(i + 2) == Some(&'<') {
+                backreference = true;
+                i = chars[i..]
+                    .iter()
+                    .position(|&c| c == '>')
+                    .map_or(chars.len(), |end| i + end + 1);
+                continue;
+            }
+            out.push(c);
+            if let Some(next) = next {
+                out.push(next);
+            }
+            i += 2;
+            continue;
+        }
+        match c {
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '(' if !in_class => {
+                let rest: String = chars[i + 1..chars.len().min(i + 4)].iter().collect();
+                if ["?=", "?!", "?<=", "?<!"]
+                    .iter()
+                    .any(|p| rest.starts_with(p))
+                {
+                    lookaround = true;
+                    i = group_end(&chars, i) + 1;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        out.push(c);
+        i += 1;
+    }
+
+    let mut warnings = Vec::new();
+    if backreference {
+        warnings.push("Backreferences are not supported and were left out".to_string());
+    }
+    if lookaround {
+        warnings.push("Lookahead and lookbehind are not supported and were left out".to_string());
+    }
+    (out, warnings)
+}
+
+/// Index of the `)` closing the group opened at `start`, or the last index when unclosed
+fn group_end(chars: &[char], start: usize) -> usize {
+    let mut depth = 0;
+    let mut in_class = false;
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '(' if !in_class => depth += 1,
+            ')' if !in_class => {
+                depth -= 1;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    chars.len().saturating_sub(1)
+}
+
+/// Append a random string matching `hir` to `out`; false when that is impossible (an
+/// empty class) or the sample grew past `MAX_SAMPLE_CHARS`
+fn generate_sample(hir: &Hir, max_repeat: u32, rng: &mut impl Rng, out: &mut String) -> bool {
+    if out.len() > MAX_SAMPLE_CHARS {
+        return false;
+    }
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => true,
+        HirKind::Literal(literal) => {
+            out.push_str(&String::from_utf8_lossy(&literal.0));
+            true
+        }
+        HirKind::Class(Class::Unicode(class)) => {
+            let ranges: Vec<(u32, u32)> = class
+                .ranges()
+                .iter()
+                .map(|r| (r.start() as u32, r.end() as u32))
+                .collect();
+            push_random_char(&ranges, rng, out)
+        }
+        HirKind::Class(Class::Bytes(class)) => {
+            // Bytes above 0x7F are not valid UTF-8 on their own
+            let ranges: Vec<(u32, u32)> = class
+                .ranges()
+                .iter()
+                .filter(|r| r.start() < 0x80)
+                .map(|r| (r.start() as u32, r.end().min(0x7F) as u32))
+                .collect();
+            push_random_char(&ranges, rng, out)
+        }
+        HirKind::Repetition(repetition) => {
+            let max = repetition
+                .max
+                .unwrap_or(repetition.min.saturating_add(max_repeat))
+                .max(repetition.min);
+            let times = rng.gen_range(repetition.min..=max);
+            (0..times).all(|_| generate_sample(&repetition.sub, max_repeat, rng, out))
+        }
+        HirKind::Capture(capture) => generate_sample(&capture.sub, max_repeat, rng, out),
+        HirKind::Concat(parts) => parts
+            .iter()
+            .all(|part| generate_sample(part, max_repeat, rng, out)),
+        HirKind::Alternation(branches) => match branches.choose(rng) {
+            Some(branch) => generate_sample(branch, max_repeat, rng, out),
+            None => true,
+        },
+    }
+}
+
+/// Push a character from the inclusive code point `ranges`, preferring printable ASCII
+fn push_random_char(ranges: &[(u32, u32)], rng: &mut impl Rng, out: &mut String) -> bool {
+    let ascii: Vec<(u32, u32)> = ranges
+        .iter()
+        .map(|&(start, end)| (start.max(0x20), end.min(0x7E)))
+        .filter(|(start, end)| start <= end)
+        .collect();
+    let pool = if !ascii.is_empty() && rng.gen_bool(ASCII_PREFERENCE) {
+        &ascii
+    } else {
+        ranges
+    };
+
+    let total: u64 = pool
+        .iter()
+        .map(|(start, end)| (end - start + 1) as u64)
+        .sum();
+    if total == 0 {
+        return false;
+    }
+    let mut n = rng.gen_range(0..total);
+    for &(start, end) in pool {
+        let size = (end - start + 1) as u64;
+        if n < size {
+            return match char::from_u32(start + n as u32) {
+                Some(c) => {
+                    out.push(c);
+                    true
+                }
+                None => false,
+            };
+        }
+        n -= size;
     }
+    false
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RegexPresetCategory {
+    Common,
+    Validation,
+    Web,
+    DateTime,
+    Japanese,
+    /// Presets saved by the user
+    Custom,
+}
+
+/// A named pattern offered in the preset picker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegexPreset {
+    pub id: String,
+    pub name: String,
+    pub pattern: String,
+    pub flags: RegexFlags,
+    pub description: Option<String>,
+    pub category: RegexPresetCategory,
+    /// Built-in presets ship with the app and cannot be deleted
+    #[serde(default)]
+    pub builtin: bool,
+    pub created_at: Option<String>,
+}
+
+const BUILTIN_ID_PREFIX: &str = "builtin:";
+
+/// (id, name, pattern, description, category)
+const BUILTIN_PRESETS: [(&str, &str, &str, &str, RegexPresetCategory); 19] = [
+    (
+        "email",
+        "Email",
+        r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}",
+        "Match email addresses",
+        RegexPresetCategory::Validation,
+    ),
+    (
+        "jp_phone",
+        "Phone (JP)",
+        r"\b0\d{1,4}-\d{1,4}-\d{4}\b",
+        "Match Japanese landline numbers such as 03-1234-5678",
+        RegexPresetCategory::Validation,
+    ),
+    (
+        "jp_mobile",
+        "Mobile (JP)",
+        r"\b0[789]0-?\d{4}-?\d{4}\b",
+        "Match 070, 080 and 090 numbers, with or without hyphens",
+        RegexPresetCategory::Validation,
+    ),
+    (
+        "jp_postal_code",
+        "Postal Code (JP)",
+        r"\b\d{3}-\d{4}\b",
+        "Match Japanese postal codes such as 100-0001",
+        RegexPresetCategory::Validation,
+    ),
+    (
+        "credit_card",
+        "Credit Card",
+        r"\b(?:\d{4}[- ]?){3}\d{4}\b",
+        "Match 16-digit card numbers",
+        RegexPresetCategory::Validation,
+    ),
+    (
+        "url",
+        "URL",
+        r"https?://[\w\-._~:/?#\[\]@!$&'()*+,;=%]+",
+        "Match HTTP/HTTPS URLs",
+        RegexPresetCategory::Web,
+    ),
+    (
+        "ipv4",
+        "IPv4",
+        r"\b(?:(?:25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)\.){3}(?:25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)\b",
+        "Match IPv4 addresses with each octet in 0-255",
+        RegexPresetCategory::Web,
+    ),
+    (
+        "ipv6",
+        "IPv6 (full form)",
+        r"\b(?:[0-9A-Fa-f]{1,4}:){7}[0-9A-Fa-f]{1,4}\b",
+        "Match eight colon-separated groups, without :: shortening",
+        RegexPresetCategory::Web,
+    ),
+    (
+        "html_tag",
+        "HTML Tag",
+        r"</?[a-zA-Z][a-zA-Z0-9]*\b[^>]*>",
+        "Match opening, closing and self-closing HTML tags",
+        RegexPresetCategory::Web,
+    ),
+    (
+        "iso_date",
+        "Date (YYYY-MM-DD)",
+        r"\b\d{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])\b",
+        "Match ISO date format",
+        RegexPresetCategory::DateTime,
+    ),
+    (
+        "time_24h",
+        "Time (HH:MM:SS)",
+        r"\b(?:[01]\d|2[0-3]):[0-5]\d(?::[0-5]\d)?\b",
+        "Match 24-hour time format",
+        RegexPresetCategory::DateTime,
+    ),
+    (
+        "hex_color",
+        "Hex Color",
+        r"#(?:[0-9A-Fa-f]{6}|[0-9A-Fa-f]{3})\b",
+        "Match hex color codes",
+        RegexPresetCategory::Common,
+    ),
+    (
+        "uuid",
+        "UUID",
+        r"\b[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}\b",
+        "Match UUIDs in the 8-4-4-4-12 form",
+        RegexPresetCategory::Common,
+    ),
+    (
+        "whitespace",
+        "Whitespace",
+        r"\s+",
+        "Match whitespace characters",
+        RegexPresetCategory::Common,
+    ),
+    (
+        "numbers",
+        "Numbers",
+        r"-?\d+(?:\.\d+)?",
+        "Match integers and decimals",
+        RegexPresetCategory::Common,
+    ),
+    (
+        "word",
+        "Word Boundary",
+        r"\b\w+\b",
+        "Match whole words",
+        RegexPresetCategory::Common,
+    ),
+    (
+        "hiragana",
+        "Hiragana",
+        r"[\p{Hiragana}ー]+",
+        "Match runs of hiragana",
+        RegexPresetCategory::Japanese,
+    ),
+    (
+        "katakana",
+        "Katakana",
+        r"[\p{Katakana}ー]+",
+        "Match runs of full-width katakana",
+        RegexPresetCategory::Japanese,
+    ),
+    (
+        "kanji",
+        "Kanji",
+        r"\p{Han}+",
+        "Match runs of kanji",
+        RegexPresetCategory::Japanese,
+    ),
+];
+
+pub fn builtin_presets() -> Vec<RegexPreset> {
+    BUILTIN_PRESETS
+        .iter()
+        .map(|(id, name, pattern, description, category)| RegexPreset {
+            id: format!("{}{}", BUILTIN_ID_PREFIX, id),
+            name: name.to_string(),
+            pattern: pattern.to_string(),
+            flags: RegexFlags {
+                global: true,
+                case_insensitive: false,
+                multiline: false,
+                dot_all: false,
+            },
+            description: Some(description.to_string()),
+            category: *category,
+            builtin: true,
+            created_at: None,
+        })
+        .collect()
+}
+
+fn get_presets_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("regex_presets.json"))
+}
+
+fn read_user_presets(path: &Path) -> Result<Vec<RegexPreset>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read presets file: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse presets file: {}", e))
+}
+
+/// Load saved presets for writing. A corrupt file is moved aside rather than
+/// overwritten, so its contents can still be recovered by hand
+fn load_user_presets_for_update(path: &Path) -> Result<Vec<RegexPreset>, String> {
+    match read_user_presets(path) {
+        Ok(presets) => Ok(presets),
+        Err(_) if path.exists() => {
+            fs::rename(path, path.with_extension("json.corrupt"))
+                .map_err(|e| format!("Failed to back up corrupt presets file: {}", e))?;
+            Ok(Vec::new())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn write_user_presets(path: &Path, presets: &[RegexPreset]) -> Result<(), String> {
+    let content =
+        serde_json::to_string_pretty(presets).map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("Failed to write presets file: {}", e))
+}
+
+/// Built-in presets followed by saved ones; an unreadable file leaves only the built-ins
+fn list_presets_at(path: &Path) -> Vec<RegexPreset> {
+    let mut presets = builtin_presets();
+    presets.extend(read_user_presets(path).unwrap_or_default());
+    presets
+}
+
+fn save_preset_at(
+    path: &Path,
+    name: &str,
+    pattern: &str,
+    flags: RegexFlags,
+    description: Option<String>,
+) -> Result<RegexPreset, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Preset name is required".to_string());
+    }
+    if pattern.is_empty() {
+        return Err("Pattern is required".to_string());
+    }
+    build_regex(pattern, "", flags).map_err(|e| e.message)?;
+
+    let mut presets = load_user_presets_for_update(path)?;
+    let taken = builtin_presets()
+        .iter()
+        .chain(presets.iter())
+        .any(|preset| preset.name.trim().eq_ignore_ascii_case(name));
+    if taken {
+        return Err(format!("A preset named \"{}\" already exists", name));
+    }
+
+    let preset = RegexPreset {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        pattern: pattern.to_string(),
+        flags,
+        description: description
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty()),
+        category: RegexPresetCategory::Custom,
+        builtin: false,
+        created_at: Some(chrono::Utc::now().to_rfc3339()),
+    };
+    presets.push(preset.clone());
+    write_user_presets(path, &presets)?;
+    Ok(preset)
+}
+
+fn delete_preset_at(path: &Path, id: &str) -> Result<(), String> {
+    if id.starts_with(BUILTIN_ID_PREFIX) {
+        return Err("Built-in presets cannot be deleted".to_string());
+    }
+    let mut presets = read_user_presets(path)?;
+    let before = presets.len();
+    presets.retain(|preset| preset.id != id);
+    if presets.len() == before {
+        return Err("Preset not found".to_string());
+    }
+    write_user_presets(path, &presets)
+}
+
+pub fn list_regex_presets(app: &AppHandle) -> Vec<RegexPreset> {
+    match get_presets_path(app) {
+        Ok(path) => list_presets_at(&path),
+        Err(_) => builtin_presets(),
+    }
+}
+
+pub fn save_regex_preset(
+    app: &AppHandle,
+    name: String,
+    pattern: String,
+    flags: RegexFlags,
+    description: Option<String>,
+) -> Result<RegexPreset, String> {
+    let path = get_presets_path(app)?;
+    save_preset_at(&path, &name, &pattern, flags, description)
+}
+
+pub fn delete_regex_preset(app: &AppHandle, id: String) -> Result<(), String> {
+    let path = get_presets_path(app)?;
+    delete_preset_at(&path, &id)
 }
 
 #[cfg(test)]
@@ -256,6 +1188,114 @@ mod tests {
         assert_eq!(result.result, "example@test");
     }
 
+    fn global_flags() -> RegexFlags {
+        RegexFlags {
+            global: true,
+            case_insensitive: false,
+            multiline: false,
+            dot_all: false,
+        }
+    }
+
+    #[test]
+    fn test_group_names_and_positions() {
+        let result = test_regex(r"(?P<key>\w+)=(\d+)?", "a=1 b=", global_flags());
+        assert_eq!(
+            result.group_names,
+            vec![None, Some("key".to_string()), None]
+        );
+        let second = &result.matches[1];
+        assert_eq!(second.groups.len(), 1);
+        assert_eq!(second.groups[0].value, "b");
+        assert_eq!((second.groups[0].start, second.groups[0].end), (4, 5));
+    }
+
+    #[test]
+    fn test_replace_pairs_and_named_references() {
+        let result = replace_regex(
+            r"(?P<first>\w+) (?P<last>\w+)",
+            "John Smith, Jane Doe",
+            "${last}_$first",
+            global_flags(),
+        );
+        assert_eq!(result.result, "Smith_John, Doe_Jane");
+        assert_eq!(result.pairs.len(), 2);
+        assert_eq!(result.pairs[1].original, "Jane Doe");
+        assert_eq!(result.pairs[1].replaced, "Doe_Jane");
+        assert_eq!((result.pairs[1].start, result.pairs[1].end), (12, 20));
+        assert!(result.warnings.is_empty());
+
+        let non_global = RegexFlags {
+            global: false,
+            ..global_flags()
+        };
+        let first_only = replace_regex(r"\d", "1 2 3", "#", non_global);
+        assert_eq!(first_only.result, "# 2 3");
+        assert_eq!(first_only.replacements, 1);
+    }
+
+    #[test]
+    fn test_multibyte_offsets() {
+        // The emoji is a surrogate pair in UTF-16 and the accent is a combining character
+        let text = "a\u{1F600} cafe\u{301} \u{1F600}b";
+        let result = test_regex("caf(e\u{301})|\u{1F600}(\\w)", text, global_flags());
+        assert_eq!(result.match_count, 2);
+
+        let cafe = &result.matches[0];
+        assert_eq!((cafe.start, cafe.end), (6, 12));
+        assert_eq!((cafe.char_start, cafe.char_end), (3, 8));
+        assert_eq!((cafe.utf16_start, cafe.utf16_end), (4, 9));
+        let accent = &cafe.groups[0];
+        assert_eq!((accent.start, accent.end), (9, 12));
+        assert_eq!((accent.char_start, accent.char_end), (6, 8));
+        assert_eq!((accent.utf16_start, accent.utf16_end), (7, 9));
+
+        let emoji = &result.matches[1];
+        assert_eq!((emoji.start, emoji.end), (13, 18));
+        assert_eq!((emoji.char_start, emoji.char_end), (9, 11));
+        assert_eq!((emoji.utf16_start, emoji.utf16_end), (10, 13));
+        assert_eq!(emoji.groups[0].index, 2);
+        assert_eq!(
+            (emoji.groups[0].utf16_start, emoji.groups[0].utf16_end),
+            (12, 13)
+        );
+
+        let replaced = replace_regex("\u{1F600}", text, "\u{1F389}!", global_flags());
+        assert_eq!(replaced.result, "a\u{1F389}! cafe\u{301} \u{1F389}!b");
+        let second = &replaced.pairs[1];
+        assert_eq!((second.char_start, second.char_end), (9, 10));
+        assert_eq!((second.utf16_start, second.utf16_end), (10, 12));
+        assert_eq!((second.result_start, second.result_end), (14, 19));
+        assert_eq!((second.result_char_start, second.result_char_end), (10, 12));
+        assert_eq!(
+            (second.result_utf16_start, second.result_utf16_end),
+            (11, 14)
+        );
+    }
+
+    #[test]
+    fn test_replace_reference_warnings() {
+        let result = replace_regex(r"(\w)(\w)", "ab", "$1x $3 ${nope} $$1 ${2}", global_flags());
+        assert_eq!(result.result, "   $1 b");
+        assert_eq!(result.warnings.len(), 3, "{:?}", result.warnings);
+        assert!(result.warnings[0].contains("${1}x"));
+        assert!(result.warnings[1].contains("$3"));
+        assert!(result.warnings[2].contains("nope"));
+    }
+
+    #[test]
+    fn test_match_limit() {
+        let text = "a".repeat(MAX_MATCHES + 5);
+        let result = test_regex("a", &text, global_flags());
+        assert_eq!(result.match_count, MAX_MATCHES);
+        assert!(result.truncated);
+
+        let replaced = replace_regex("a", &text, "b", global_flags());
+        assert_eq!(replaced.replacements, MAX_MATCHES);
+        assert!(replaced.truncated);
+        assert!(replaced.result.ends_with("baaaaa"));
+    }
+
     #[test]
     fn test_invalid_pattern() {
         let flags = RegexFlags {
@@ -268,5 +1308,150 @@ mod tests {
         let result = test_regex(r"[", "test", flags);
         assert!(!result.success);
         assert!(result.error.is_some());
+        assert_eq!(result.error_kind, Some(RegexErrorKind::Syntax));
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let long_pattern = "a".repeat(MAX_PATTERN_LEN + 1);
+        let result = test_regex(&long_pattern, "a", global_flags());
+        assert_eq!(result.error_kind, Some(RegexErrorKind::PatternTooLong));
+
+        let big_text = "a".repeat(MAX_TEXT_BYTES + 1);
+        let result = replace_regex("a", &big_text, "b", global_flags());
+        assert_eq!(result.error_kind, Some(RegexErrorKind::InputTooLarge));
+
+        // Nested counted repetition compiles to far more than the size limit
+        let result = test_regex(r"(\w{100}){100}", "a", global_flags());
+        assert!(!result.success);
+        assert_eq!(result.error_kind, Some(RegexErrorKind::TooComplex));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let result = run_with_timeout(
+            || std::thread::sleep(Duration::from_millis(500)),
+            Duration::from_millis(10),
+        );
+        assert!(result.is_none());
+
+        let result = test_regex_with_timeout("a".into(), "aa".into(), global_flags(), None);
+        assert!(!result.timed_out);
+        assert_eq!(result.match_count, 2);
+
+        let failure = RegexResult::failure(timeout_error(Duration::from_secs(2)));
+        assert!(failure.timed_out);
+        assert_eq!(failure.error_kind, Some(RegexErrorKind::Timeout));
+    }
+
+    fn preset_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("taurin_regex_presets_{}.json", name));
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(path.with_extension("json.corrupt"));
+        path
+    }
+
+    #[test]
+    fn test_builtin_presets_compile_and_match() {
+        let samples = [
+            ("email", "mail user.name+tag@example.co.jp now"),
+            ("url", "see https://example.com/a?b=c"),
+            ("ipv4", "host 192.168.0.1 up"),
+            ("jp_postal_code", "〒100-0001 東京都"),
+            ("jp_phone", "TEL 03-1234-5678"),
+            ("jp_mobile", "090-1234-5678"),
+            ("iso_date", "on 2024-02-29"),
+            ("hex_color", "color: #1e90ff;"),
+            ("katakana", "テスト"),
+        ];
+        let presets = builtin_presets();
+        for preset in &presets {
+            assert!(
+                build_regex(&preset.pattern, "", preset.flags).is_ok(),
+                "{}",
+                preset.id
+            );
+        }
+        for (id, text) in samples {
+            let preset = presets
+                .iter()
+                .find(|p| p.id == format!("{}{}", BUILTIN_ID_PREFIX, id))
+                .unwrap();
+            let result = test_regex(&preset.pattern, text, preset.flags);
+            assert_eq!(result.match_count, 1, "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_save_and_delete_preset() {
+        let path = preset_path("save");
+        let flags = builtin_presets()[0].flags;
+        let saved = save_preset_at(&path, " Order ID ", r"ORD-\d+", flags, None).unwrap();
+        assert_eq!(saved.name, "Order ID");
+        assert!(!saved.builtin);
+
+        let listed = list_presets_at(&path);
+        assert_eq!(listed.len(), BUILTIN_PRESETS.len() + 1);
+
+        let duplicate = save_preset_at(&path, "order id", r"\d+", flags, None);
+        assert!(duplicate.unwrap_err().contains("already exists"));
+        assert!(save_preset_at(&path, "email", r"\w+", flags, None).is_err());
+        assert!(save_preset_at(&path, "Broken", r"(", flags, None).is_err());
+
+        assert!(delete_preset_at(&path, "builtin:email").is_err());
+        delete_preset_at(&path, &saved.id).unwrap();
+        assert_eq!(list_presets_at(&path).len(), BUILTIN_PRESETS.len());
+        assert!(delete_preset_at(&path, &saved.id).is_err());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_corrupt_presets_file_falls_back_to_builtins() {
+        let path = preset_path("corrupt");
+        fs::write(&path, "{ not json").unwrap();
+        assert_eq!(list_presets_at(&path).len(), BUILTIN_PRESETS.len());
+
+        let flags = builtin_presets()[0].flags;
+        save_preset_at(&path, "Fresh", r"\w+", flags, None).unwrap();
+        assert_eq!(list_presets_at(&path).len(), BUILTIN_PRESETS.len() + 1);
+        let backup = path.with_extension("json.corrupt");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "{ not json");
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(&backup);
+    }
+
+    #[test]
+    fn test_generate_regex_samples() {
+        let result = generate_regex_samples(r"[a-c]{2}-\d+", 5, 3);
+        assert!(result.success);
+        assert_eq!(result.samples.len(), 5);
+        let re = Regex::new(r"^[a-c]{2}-\d{1,4}$").unwrap();
+        assert!(
+            result.samples.iter().all(|s| re.is_match(s)),
+            "{:?}",
+            result
+        );
+
+        // Only one string matches a literal
+        let result = generate_regex_samples("abc", 3, 3);
+        assert_eq!(result.samples, ["abc"]);
+        assert_eq!(result.warnings.len(), 1);
+
+        let result = generate_regex_samples("(", 3, 3);
+        assert!(!result.success);
+    }
+
+    #[test]
+    fn test_generate_regex_samples_skips_unsupported_syntax() {
+        let result = generate_regex_samples(r"(\w)\1(?=[)(])x[(?=]", 3, 3);
+        assert!(result.success);
+        assert_eq!(result.warnings.len(), 2, "{:?}", result.warnings);
+        let re = Regex::new(r"^\wx[(?=]$").unwrap();
+        assert!(!result.samples.is_empty());
+        assert!(
+            result.samples.iter().all(|s| re.is_match(s)),
+            "{:?}",
+            result
+        );
     }
 }
@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// How long fired and cancelled reminders stay in the list, in seconds
+const HISTORY_RETENTION_SECS: i64 = 7 * 24 * 60 * 60;
+const MAX_TITLE_CHARS: usize = 200;
+/// Longer bodies are cut off rather than rejected, since they usually come from a note
+const MAX_BODY_CHARS: usize = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReminderStatus {
+    Pending,
+    Fired,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminder {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    /// Unix time in seconds
+    pub fire_at: i64,
+    pub created_at: i64,
+    pub status: ReminderStatus,
+    /// When the reminder fired or was cancelled, Unix seconds
+    #[serde(default)]
+    pub finished_at: Option<i64>,
+}
+
+/// Reminders are kept in memory and mirrored to `reminders.json`, which is read back
+/// on startup so pending ones survive a restart
+#[derive(Default)]
+pub struct ReminderStore {
+    reminders: Mutex<Vec<Reminder>>,
+}
+
+fn now_secs() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+fn get_data_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("reminders.json"))
+}
+
+fn read_reminders(app: &AppHandle) -> Result<Vec<Reminder>, String> {
+    let path = get_data_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read reminders: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse reminders: {}", e))
+}
+
+fn write_reminders(app: &AppHandle, reminders: &[Reminder]) -> Result<(), String> {
+    let path = get_data_path(app)?;
+    let content = serde_json::to_string_pretty(reminders)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write reminders: {}", e))
+}
+
+/// Check a new reminder and build it, trimming the title and body
+fn new_reminder(title: &str, body: &str, fire_at: i64, now: i64) -> Result<Reminder, String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("Reminder title is required".to_string());
+    }
+    if title.chars().count() > MAX_TITLE_CHARS {
+        return Err(format!(
+            "Reminder title is limited to {} characters",
+            MAX_TITLE_CHARS
+        ));
+    }
+    if fire_at <= now {
+        return Err("Reminder time must be in the future".to_string());
+    }
+    Ok(Reminder {
+        id: uuid::Uuid::new_v4().to_string(),
+        title: title.to_string(),
+        body: body.trim().chars().take(MAX_BODY_CHARS).collect(),
+        fire_at,
+        created_at: now,
+        status: ReminderStatus::Pending,
+        finished_at: None,
+    })
+}
+
+/// Drop fired and cancelled reminders older than the retention window.
+/// Returns whether anything was removed.
+fn prune_history(reminders: &mut Vec<Reminder>, now: i64) -> bool {
+    let before = reminders.len();
+    reminders.retain(|r| {
+        r.status == ReminderStatus::Pending
+            || r.finished_at.unwrap_or(r.fire_at) > now - HISTORY_RETENTION_SECS
+    });
+    reminders.len() != before
+}
+
+/// Mark every pending reminder that is due as fired and return copies of them.
+/// Reminders that came due while the app was closed fire on the first pass.
+fn take_due(reminders: &mut [Reminder], now: i64) -> Vec<Reminder> {
+    reminders
+        .iter_mut()
+        .filter(|r| r.status == ReminderStatus::Pending && r.fire_at <= now)
+        .map(|r| {
+            r.status = ReminderStatus::Fired;
+            r.finished_at = Some(now);
+            r.clone()
+        })
+        .collect()
+}
+
+/// Pending reminders soonest first, followed by history newest first
+fn sorted(mut reminders: Vec<Reminder>) -> Vec<Reminder> {
+    reminders.sort_by(|a, b| {
+        let a_pending = a.status == ReminderStatus::Pending;
+        let b_pending = b.status == ReminderStatus::Pending;
+        b_pending.cmp(&a_pending).then_with(|| {
+            if a_pending {
+                a.fire_at.cmp(&b.fire_at)
+            } else {
+                b.finished_at.cmp(&a.finished_at)
+            }
+        })
+    });
+    reminders
+}
+
+fn run_scheduler(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(1));
+        let store = app.state::<ReminderStore>();
+        let due = {
+            let Ok(mut reminders) = store.reminders.lock() else {
+                break;
+            };
+            let due = take_due(&mut reminders, now_secs());
+            if !due.is_empty() {
+                let _ = write_reminders(&app, &reminders);
+            }
+            due
+        };
+        for reminder in due {
+            let _ = app
+                .notification()
+                .builder()
+                .title(&reminder.title)
+                .body(&reminder.body)
+                .show();
+            let _ = app.emit("reminder-fired", &reminder);
+        }
+    });
+}
+
+/// Load saved reminders and start the scheduler. Called once from `setup`.
+pub fn init_reminders(app: &AppHandle) {
+    let mut reminders = read_reminders(app).unwrap_or_default();
+    if prune_history(&mut reminders, now_secs()) {
+        let _ = write_reminders(app, &reminders);
+    }
+    let store = app.state::<ReminderStore>();
+    if let Ok(mut current) = store.reminders.lock() {
+        *current = reminders;
+    }
+    run_scheduler(app.clone());
+}
+
+/// Schedule a notification at `fire_at` (Unix seconds), which must be in the future
+pub fn create_reminder(
+    app: &AppHandle,
+    title: &str,
+    body: &str,
+    fire_at: i64,
+) -> Result<Reminder, String> {
+    let reminder = new_reminder(title, body, fire_at, now_secs())?;
+    let store = app.state::<ReminderStore>();
+    let mut reminders = store
+        .reminders
+        .lock()
+        .map_err(|_| "Reminders are unavailable".to_string())?;
+    reminders.push(reminder.clone());
+    write_reminders(app, &reminders)?;
+    Ok(reminder)
+}
+
+pub fn list_reminders(app: &AppHandle) -> Result<Vec<Reminder>, String> {
+    let store = app.state::<ReminderStore>();
+    let mut reminders = store
+        .reminders
+        .lock()
+        .map_err(|_| "Reminders are unavailable".to_string())?;
+    if prune_history(&mut reminders, now_secs()) {
+        write_reminders(app, &reminders)?;
+    }
+    Ok(sorted(reminders.clone()))
+}
+
+pub fn cancel_reminder(app: &AppHandle, id: &str) -> Result<Reminder, String> {
+    let store = app.state::<ReminderStore>();
+    let mut reminders = store
+        .reminders
+        .lock()
+        .map_err(|_| "Reminders are unavailable".to_string())?;
+    let reminder = reminders
+        .iter_mut()
+        .find(|r| r.id == id)
+        .ok_or_else(|| "Reminder not found".to_string())?;
+    if reminder.status != ReminderStatus::Pending {
+        return Err("Reminder has already fired or been cancelled".to_string());
+    }
+    reminder.status = ReminderStatus::Cancelled;
+    reminder.finished_at = Some(now_secs());
+    let cancelled = reminder.clone();
+    write_reminders(app, &reminders)?;
+    Ok(cancelled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_new_reminder_validation() {
+        let reminder = new_reminder("  Stand-up  ", " notes ", NOW + 60, NOW).unwrap();
+        assert_eq!(reminder.title, "Stand-up");
+        assert_eq!(reminder.body, "notes");
+        assert_eq!(reminder.status, ReminderStatus::Pending);
+
+        assert!(new_reminder("Past", "", NOW - 1, NOW).is_err());
+        assert!(new_reminder("Now", "", NOW, NOW).is_err());
+        assert!(new_reminder("   ", "", NOW + 60, NOW).is_err());
+        assert!(new_reminder(&"x".repeat(MAX_TITLE_CHARS + 1), "", NOW + 60, NOW).is_err());
+    }
+
+    #[test]
+    fn test_take_due_and_prune_history() {
+        let later = new_reminder("Later", "", NOW + 600, NOW).unwrap();
+        let soon = new_reminder("Soon", "", NOW + 60, NOW).unwrap();
+        let mut old = new_reminder("Old", "", NOW - 100, NOW - 200).unwrap();
+        old.status = ReminderStatus::Cancelled;
+        old.finished_at = Some(NOW - HISTORY_RETENTION_SECS - 1);
+        let mut reminders = vec![later, soon, old];
+
+        assert!(take_due(&mut reminders, NOW).is_empty());
+        let fired = take_due(&mut reminders, NOW + 60);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].title, "Soon");
+        assert_eq!(reminders[1].status, ReminderStatus::Fired);
+        // Already fired reminders are not returned again
+        assert!(take_due(&mut reminders, NOW + 120).is_empty());
+
+        assert!(prune_history(&mut reminders, NOW + 60));
+        assert_eq!(reminders.len(), 2);
+        assert!(!prune_history(&mut reminders, NOW + 60));
+
+        let listed = sorted(reminders);
+        assert_eq!(listed[0].title, "Later");
+        assert_eq!(listed[1].title, "Soon");
+    }
+}
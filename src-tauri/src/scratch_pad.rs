@@ -1,16 +1,79 @@
+use crate::markdown_to_pdf::{
+    convert_markdown_to_pdf, generate_full_html, markdown_to_html, CodeTheme,
+    MarkdownRenderOptions, PdfPageOptions,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Note {
     pub id: String,
+    /// Defaults to the first line of `content` until the user sets one
+    #[serde(default)]
+    pub title: String,
     pub content: String,
     pub created_at: String,
     pub updated_at: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteSearchResult {
+    pub note_id: String,
+    pub title: String,
+    pub title_matched: bool,
+    /// Text around the first match in the content (or the start of the content when only
+    /// the title matched), with newlines flattened to spaces
+    pub snippet: String,
+    /// Character range of the match within `snippet`
+    pub snippet_match_start: usize,
+    pub snippet_match_end: usize,
+    /// Character offset of the first match in the content
+    pub match_position: Option<usize>,
+    pub match_count: usize,
+}
+
+/// A saved earlier version of a note, as listed in the history panel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteHistoryEntry {
+    pub version_id: String,
+    pub created_at: String,
+    pub title: String,
+    pub preview: String,
+    pub char_count: usize,
+}
+
+/// On-disk format of a history snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NoteSnapshot {
+    note_id: String,
+    title: String,
+    content: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NoteExportFormat {
+    /// The note content as is
+    Markdown,
+    /// Rendered Markdown wrapped in a standalone page
+    Html,
+    Pdf,
+}
+
+impl NoteExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            NoteExportFormat::Markdown => "md",
+            NoteExportFormat::Html => "html",
+            NoteExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScratchPadData {
     pub notes: Vec<Note>,
@@ -22,6 +85,7 @@ impl Default for ScratchPadData {
         let now = chrono::Utc::now().to_rfc3339();
         let default_note = Note {
             id: uuid::Uuid::new_v4().to_string(),
+            title: String::new(),
             content: String::new(),
             created_at: now.clone(),
             updated_at: now,
@@ -48,8 +112,14 @@ pub fn load_scratch_pad(app: &AppHandle) -> Result<ScratchPadData, String> {
     if path.exists() {
         let file_content = fs::read_to_string(&path)
             .map_err(|e| format!("Failed to read scratch pad file: {}", e))?;
-        serde_json::from_str(&file_content)
-            .map_err(|e| format!("Failed to parse scratch pad data: {}", e))
+        let mut data: ScratchPadData = serde_json::from_str(&file_content)
+            .map_err(|e| format!("Failed to parse scratch pad data: {}", e))?;
+        for note in &mut data.notes {
+            if note.title.is_empty() {
+                note.title = title_from_content(&note.content);
+            }
+        }
+        Ok(data)
     } else {
         Ok(ScratchPadData::default())
     }
@@ -67,6 +137,7 @@ pub fn create_note(app: &AppHandle) -> Result<Note, String> {
     let now = chrono::Utc::now().to_rfc3339();
     let note = Note {
         id: uuid::Uuid::new_v4().to_string(),
+        title: String::new(),
         content: String::new(),
         created_at: now.clone(),
         updated_at: now,
@@ -77,7 +148,42 @@ pub fn create_note(app: &AppHandle) -> Result<Note, String> {
     Ok(note)
 }
 
-pub fn update_note(app: &AppHandle, note_id: String, content: String) -> Result<Note, String> {
+fn get_history_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    Ok(app_data_dir.join("scratch_pad_history"))
+}
+
+const TITLE_MAX_CHARS: usize = 30;
+const SNIPPET_CONTEXT_CHARS: usize = 30;
+
+/// Build a title from the first non-empty line, without Markdown heading markers
+fn title_from_content(content: &str) -> String {
+    let line = content
+        .lines()
+        .map(|l| l.trim_start_matches('#').trim())
+        .find(|l| !l.is_empty())
+        .unwrap_or("");
+    if line.chars().count() > TITLE_MAX_CHARS {
+        let truncated: String = line.chars().take(TITLE_MAX_CHARS - 3).collect();
+        format!("{}...", truncated)
+    } else {
+        line.to_string()
+    }
+}
+
+/// Update a note's content and, optionally, its title.
+///
+/// An empty `title` resets the note to an automatic title. Automatic titles follow the
+/// content; a title the user set is kept as is.
+pub fn update_note(
+    app: &AppHandle,
+    note_id: String,
+    content: String,
+    title: Option<String>,
+) -> Result<Note, String> {
     let mut data = load_scratch_pad(app)?;
     let note = data
         .notes
@@ -85,6 +191,17 @@ pub fn update_note(app: &AppHandle, note_id: String, content: String) -> Result<
         .find(|n| n.id == note_id)
         .ok_or_else(|| format!("Note not found: {}", note_id))?;
 
+    let has_auto_title = note.title == title_from_content(&note.content);
+    note.title = match title.map(|t| t.trim().to_string()) {
+        Some(t) if !t.is_empty() => t,
+        Some(_) => title_from_content(&content),
+        None if has_auto_title => title_from_content(&content),
+        None => std::mem::take(&mut note.title),
+    };
+    if note.content != content {
+        // A failed snapshot must not block saving the note itself
+        let _ = get_history_root(app).and_then(|root| maybe_snapshot(&root, note, now_millis()));
+    }
     note.content = content;
     note.updated_at = chrono::Utc::now().to_rfc3339();
     let updated_note = note.clone();
@@ -106,6 +223,7 @@ pub fn delete_note(app: &AppHandle, note_id: String) -> Result<ScratchPadData, S
         let now = chrono::Utc::now().to_rfc3339();
         let default_note = Note {
             id: uuid::Uuid::new_v4().to_string(),
+            title: String::new(),
             content: String::new(),
             created_at: now.clone(),
             updated_at: now,
@@ -131,3 +249,527 @@ pub fn set_active_note(app: &AppHandle, note_id: String) -> Result<ScratchPadDat
 pub fn export_to_file(content: String, path: String) -> Result<(), String> {
     fs::write(&path, content).map_err(|e| format!("Failed to write file: {}", e))
 }
+
+pub fn export_note_as(
+    app: &AppHandle,
+    note_id: String,
+    format: NoteExportFormat,
+    path: String,
+) -> Result<(), String> {
+    let data = load_scratch_pad(app)?;
+    let note = data
+        .notes
+        .iter()
+        .find(|n| n.id == note_id)
+        .ok_or_else(|| format!("Note not found: {}", note_id))?;
+    write_note(note, format, Path::new(&path))
+}
+
+/// Export every note into `dir`, one file per note named after its title.
+///
+/// Returns the paths written, in note order.
+pub fn export_all_notes(
+    app: &AppHandle,
+    dir: String,
+    format: NoteExportFormat,
+) -> Result<Vec<String>, String> {
+    let data = load_scratch_pad(app)?;
+    let dir = Path::new(&dir);
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    let mut reserved = HashSet::new();
+    let mut written = Vec::new();
+    for note in &data.notes {
+        let path = unique_note_path(dir, &note_file_stem(note), format, &mut reserved);
+        write_note(note, format, &path).map_err(|e| format!("{}: {}", note_file_stem(note), e))?;
+        written.push(path.to_string_lossy().to_string());
+    }
+    Ok(written)
+}
+
+fn write_note(note: &Note, format: NoteExportFormat, path: &Path) -> Result<(), String> {
+    match format {
+        NoteExportFormat::Markdown => {
+            fs::write(path, &note.content).map_err(|e| format!("Failed to write file: {}", e))
+        }
+        NoteExportFormat::Html => {
+            let result = markdown_to_html(
+                &note.content,
+                CodeTheme::Light,
+                MarkdownRenderOptions::default(),
+            );
+            fs::write(path, generate_full_html(&result.html, None))
+                .map_err(|e| format!("Failed to write file: {}", e))
+        }
+        NoteExportFormat::Pdf => {
+            let result = convert_markdown_to_pdf(
+                &note.content,
+                &path.to_string_lossy(),
+                None,
+                CodeTheme::Light,
+                false,
+                MarkdownRenderOptions::default(),
+                &PdfPageOptions::default(),
+            );
+            match result.error {
+                Some(e) if !result.success => Err(e),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+const FILE_STEM_MAX_CHARS: usize = 100;
+/// Device names Windows refuses as file names, whatever the extension
+const WINDOWS_RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// File name (without extension) for a note: its title with characters that are not
+/// allowed in file names on Windows, macOS or Linux replaced by `_`
+fn note_file_stem(note: &Note) -> String {
+    let title = if note.title.trim().is_empty() {
+        title_from_content(&note.content)
+    } else {
+        note.title.clone()
+    };
+    let sanitized: String = title
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .take(FILE_STEM_MAX_CHARS)
+        .collect();
+    // Windows drops trailing dots and spaces, and a leading dot hides the file elsewhere
+    let stem = sanitized.trim().trim_matches('.').trim();
+
+    if stem.is_empty() {
+        "Untitled".to_string()
+    } else if WINDOWS_RESERVED_NAMES
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(stem))
+    {
+        format!("{}_", stem)
+    } else {
+        stem.to_string()
+    }
+}
+
+/// Build `<dir>/<stem>.<ext>`, appending `_1`, `_2`, ... when the name is taken on disk
+/// or by an earlier note in the same export. Names are compared case-insensitively, since
+/// the default file systems on Windows and macOS are.
+fn unique_note_path(
+    dir: &Path,
+    stem: &str,
+    format: NoteExportFormat,
+    reserved: &mut HashSet<String>,
+) -> PathBuf {
+    let extension = format.extension();
+    let mut name = format!("{}.{}", stem, extension);
+    let mut counter = 1;
+    while dir.join(&name).exists() || reserved.contains(&name.to_lowercase()) {
+        name = format!("{}_{}.{}", stem, counter, extension);
+        counter += 1;
+    }
+    reserved.insert(name.to_lowercase());
+    dir.join(name)
+}
+
+const MAX_HISTORY_ENTRIES: usize = 50;
+/// Minimum time between automatic snapshots while a note is being edited
+const SNAPSHOT_INTERVAL_MS: i64 = 5 * 60 * 1000;
+/// Snapshot earlier than the interval when the content changed by at least this many chars
+const SNAPSHOT_MIN_CHAR_DIFF: usize = 200;
+
+fn now_millis() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+fn note_history_dir(root: &Path, note_id: &str) -> Result<PathBuf, String> {
+    if note_id.is_empty() || note_id.contains(['/', '\\', '.']) {
+        return Err(format!("Invalid note id: {}", note_id));
+    }
+    Ok(root.join(note_id))
+}
+
+/// Version ids are the snapshot time in epoch milliseconds, which also sort by age
+fn version_ids(dir: &Path) -> Vec<i64> {
+    let mut ids: Vec<i64> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension()? != "json" {
+                        return None;
+                    }
+                    path.file_stem()?.to_str()?.parse().ok()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    ids.sort_unstable();
+    ids
+}
+
+fn read_snapshot(dir: &Path, version_id: i64) -> Result<NoteSnapshot, String> {
+    let content = fs::read_to_string(dir.join(format!("{}.json", version_id)))
+        .map_err(|e| format!("Failed to read history entry: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse history entry: {}", e))
+}
+
+/// Save the note's current content as a snapshot and drop the oldest beyond the limit
+fn write_snapshot(root: &Path, note: &Note, now: i64) -> Result<(), String> {
+    let dir = note_history_dir(root, &note.id)?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create history dir: {}", e))?;
+
+    let created_at = chrono::DateTime::from_timestamp_millis(now)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default();
+    let snapshot = NoteSnapshot {
+        note_id: note.id.clone(),
+        title: note.title.clone(),
+        content: note.content.clone(),
+        created_at,
+    };
+    let json = serde_json::to_string_pretty(&snapshot)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(dir.join(format!("{}.json", now)), json)
+        .map_err(|e| format!("Failed to write history entry: {}", e))?;
+
+    let ids = version_ids(&dir);
+    for id in ids
+        .iter()
+        .take(ids.len().saturating_sub(MAX_HISTORY_ENTRIES))
+    {
+        let _ = fs::remove_file(dir.join(format!("{}.json", id)));
+    }
+    Ok(())
+}
+
+/// Snapshot the note before an edit unless a recent snapshot is close enough to it
+fn maybe_snapshot(root: &Path, note: &Note, now: i64) -> Result<(), String> {
+    if note.content.is_empty() {
+        return Ok(());
+    }
+    let dir = note_history_dir(root, &note.id)?;
+    if let Some(&latest) = version_ids(&dir).last() {
+        let recent = now - latest < SNAPSHOT_INTERVAL_MS;
+        let similar = read_snapshot(&dir, latest)
+            .map(|s| {
+                s.content
+                    .chars()
+                    .count()
+                    .abs_diff(note.content.chars().count())
+            })
+            .is_ok_and(|diff| diff < SNAPSHOT_MIN_CHAR_DIFF);
+        if recent && similar {
+            return Ok(());
+        }
+    }
+    write_snapshot(root, note, now)
+}
+
+fn list_history(root: &Path, note_id: &str) -> Result<Vec<NoteHistoryEntry>, String> {
+    let dir = note_history_dir(root, note_id)?;
+    Ok(version_ids(&dir)
+        .into_iter()
+        .rev()
+        // Corrupted or unreadable snapshots are left out of the list
+        .filter_map(|id| {
+            let snapshot = read_snapshot(&dir, id).ok()?;
+            let preview = snapshot
+                .content
+                .chars()
+                .take(80)
+                .map(|c| if c == '\n' { ' ' } else { c })
+                .collect();
+            Some(NoteHistoryEntry {
+                version_id: id.to_string(),
+                created_at: snapshot.created_at,
+                title: snapshot.title,
+                preview,
+                char_count: snapshot.content.chars().count(),
+            })
+        })
+        .collect())
+}
+
+/// List saved versions of a note, newest first
+pub fn list_note_history(
+    app: &AppHandle,
+    note_id: String,
+) -> Result<Vec<NoteHistoryEntry>, String> {
+    list_history(&get_history_root(app)?, &note_id)
+}
+
+/// Replace a note's content with a saved version.
+///
+/// The current content is snapshotted first, so a restore can itself be undone.
+pub fn restore_note_version(
+    app: &AppHandle,
+    note_id: String,
+    version_id: String,
+) -> Result<Note, String> {
+    let root = get_history_root(app)?;
+    let version: i64 = version_id
+        .parse()
+        .map_err(|_| format!("Invalid version id: {}", version_id))?;
+    let snapshot = read_snapshot(&note_history_dir(&root, &note_id)?, version)?;
+
+    let mut data = load_scratch_pad(app)?;
+    let note = data
+        .notes
+        .iter_mut()
+        .find(|n| n.id == note_id)
+        .ok_or_else(|| format!("Note not found: {}", note_id))?;
+
+    if note.content != snapshot.content {
+        write_snapshot(&root, note, now_millis())?;
+    }
+    note.title = if snapshot.title.is_empty() {
+        title_from_content(&snapshot.content)
+    } else {
+        snapshot.title
+    };
+    note.content = snapshot.content;
+    note.updated_at = chrono::Utc::now().to_rfc3339();
+    let restored = note.clone();
+    save_data(app, &data)?;
+    Ok(restored)
+}
+
+/// Search note titles and contents (case-insensitive), title matches first
+pub fn search_notes(app: &AppHandle, query: String) -> Result<Vec<NoteSearchResult>, String> {
+    let data = load_scratch_pad(app)?;
+    Ok(search_in_notes(&data.notes, &query))
+}
+
+fn search_in_notes(notes: &[Note], query: &str) -> Vec<NoteSearchResult> {
+    let query = fold_chars(query.trim());
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut results: Vec<NoteSearchResult> = notes
+        .iter()
+        .filter_map(|note| {
+            let title_matched = !find_matches(&fold_chars(&note.title), &query).is_empty();
+            let content: Vec<char> = note.content.chars().collect();
+            let matches = find_matches(&fold_chars(&note.content), &query);
+            if !title_matched && matches.is_empty() {
+                return None;
+            }
+
+            let (snippet, snippet_match_start, snippet_match_end) = match matches.first() {
+                Some(&position) => snippet_around(&content, position, query.len()),
+                None => snippet_around(&content, 0, 0),
+            };
+            Some(NoteSearchResult {
+                note_id: note.id.clone(),
+                title: note.title.clone(),
+                title_matched,
+                snippet,
+                snippet_match_start,
+                snippet_match_end,
+                match_position: matches.first().copied(),
+                match_count: matches.len(),
+            })
+        })
+        .collect();
+
+    results.sort_by_key(|r| !r.title_matched);
+    results
+}
+
+/// Lowercase char by char, keeping one char per input char so indices stay aligned
+fn fold_chars(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+/// Char offsets of non-overlapping occurrences of `needle` in `haystack`
+fn find_matches(haystack: &[char], needle: &[char]) -> Vec<usize> {
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while needle.len() <= haystack.len() && i <= haystack.len() - needle.len() {
+        if haystack[i..i + needle.len()] == *needle {
+            matches.push(i);
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+fn snippet_around(content: &[char], position: usize, length: usize) -> (String, usize, usize) {
+    let start = position.saturating_sub(SNIPPET_CONTEXT_CHARS);
+    let end = (position + length + SNIPPET_CONTEXT_CHARS).min(content.len());
+
+    let mut snippet = String::new();
+    let mut offset = 0;
+    if start > 0 {
+        snippet.push('…');
+        offset = 1;
+    }
+    snippet.extend(content[start..end].iter().map(|&c| match c {
+        '\n' | '\r' | '\t' => ' ',
+        c => c,
+    }));
+    if end < content.len() {
+        snippet.push('…');
+    }
+
+    let match_start = offset + position - start;
+    (snippet, match_start, match_start + length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn note(id: &str, title: &str, content: &str) -> Note {
+        Note {
+            id: id.to_string(),
+            title: title.to_string(),
+            content: content.to_string(),
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_snapshots_are_throttled_and_pruned() {
+        let root = TempDir::new("scratch_history_prune");
+        let mut n = note("n1", "t", "first");
+
+        maybe_snapshot(&root, &n, 1_000).unwrap();
+        n.content = "first edit".to_string();
+        maybe_snapshot(&root, &n, 2_000).unwrap();
+        assert_eq!(list_history(&root, "n1").unwrap().len(), 1);
+
+        n.content = "x".repeat(500);
+        maybe_snapshot(&root, &n, 3_000).unwrap();
+        maybe_snapshot(&root, &n, 3_000 + SNAPSHOT_INTERVAL_MS).unwrap();
+        let entries = list_history(&root, "n1").unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].preview, "first");
+
+        for i in 0..60 {
+            write_snapshot(&root, &n, 10_000_000 + i).unwrap();
+        }
+        let entries = list_history(&root, "n1").unwrap();
+        assert_eq!(entries.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(entries[0].version_id, "10000059");
+    }
+
+    #[test]
+    fn test_corrupted_history_entries_are_skipped() {
+        let root = TempDir::new("scratch_history_corrupt");
+        write_snapshot(&root, &note("n1", "t", "good"), 1).unwrap();
+        fs::write(root.join("n1").join("2.json"), "{ not json").unwrap();
+
+        let entries = list_history(&root, "n1").unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].version_id, "1");
+        assert!(read_snapshot(&root.join("n1"), 2).is_err());
+        assert!(list_history(&root, "../etc").is_err());
+        assert!(list_history(&root, "missing").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_note_file_names_are_sanitized_and_unique() {
+        assert_eq!(
+            note_file_stem(&note("1", "a/b: c*?", "")),
+            "a_b_ c__".to_string()
+        );
+        assert_eq!(note_file_stem(&note("2", "", "# Heading\nbody")), "Heading");
+        assert_eq!(note_file_stem(&note("3", " .. ", "")), "Untitled");
+        assert_eq!(note_file_stem(&note("4", "con", "")), "con_");
+
+        let dir = TempDir::new("scratch_history_export_names");
+        fs::write(dir.join("Taken.md"), "").unwrap();
+        let mut reserved = HashSet::new();
+        let names: Vec<String> = ["Memo", "memo", "Taken"]
+            .iter()
+            .map(|stem| {
+                let path = unique_note_path(&dir, stem, NoteExportFormat::Markdown, &mut reserved);
+                path.file_name().unwrap().to_string_lossy().to_string()
+            })
+            .collect();
+        assert_eq!(names, ["Memo.md", "memo_1.md", "Taken_1.md"]);
+    }
+
+    #[test]
+    fn test_write_note_as_html() {
+        let dir = TempDir::new("scratch_history_export_html");
+        let path = dir.join("note.html");
+        write_note(
+            &note("1", "Note", "# Hello\n\n**bold**"),
+            NoteExportFormat::Html,
+            &path,
+        )
+        .unwrap();
+        let html = fs::read_to_string(&path).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<strong>bold</strong>"));
+    }
+
+    #[test]
+    fn test_title_from_content() {
+        assert_eq!(
+            title_from_content("\n# Meeting notes\nbody"),
+            "Meeting notes"
+        );
+        assert_eq!(title_from_content(""), "");
+        let long = "あ".repeat(40);
+        assert_eq!(title_from_content(&long), format!("{}...", "あ".repeat(27)));
+    }
+
+    #[test]
+    fn test_note_without_title_deserializes() {
+        let json = r#"{"id": "a", "content": "x", "created_at": "", "updated_at": ""}"#;
+        let n: Note = serde_json::from_str(json).unwrap();
+        assert_eq!(n.title, "");
+    }
+
+    #[test]
+    fn test_search_is_case_insensitive_and_char_safe() {
+        let body = format!(
+            "{}Rustの勉強会メモ。rust は楽しい{}",
+            "前".repeat(40),
+            "後".repeat(40)
+        );
+        let notes = vec![
+            note("1", "買い物", &body),
+            note("2", "RUST tips", "no match in body"),
+            note("3", "other", "nothing"),
+        ];
+
+        let results = search_in_notes(&notes, "rust");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].note_id, "2");
+        assert!(results[0].title_matched);
+
+        let hit = &results[1];
+        assert_eq!(hit.match_position, Some(40));
+        assert_eq!(hit.match_count, 2);
+        assert!(hit.snippet.starts_with('…') && hit.snippet.ends_with('…'));
+        let matched: String = hit
+            .snippet
+            .chars()
+            .skip(hit.snippet_match_start)
+            .take(hit.snippet_match_end - hit.snippet_match_start)
+            .collect();
+        assert_eq!(matched, "Rust");
+
+        assert!(search_in_notes(&notes, "  ").is_empty());
+        assert!(search_in_notes(&notes, "存在しない").is_empty());
+    }
+}
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// Bumped whenever a change to `AppSettings` needs more than `#[serde(default)]` to migrate
+pub const SETTINGS_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    /// Follow the OS appearance
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+/// `"dark"` or `"light"`, the value the frontend puts in `data-theme`
+pub fn theme_name(theme: tauri::Theme) -> String {
+    match theme {
+        tauri::Theme::Dark => "dark",
+        _ => "light",
+    }
+    .to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ImageCompressorDefaults {
+    pub quality: u8,
+    pub output_format: String,
+}
+
+impl Default for ImageCompressorDefaults {
+    fn default() -> Self {
+        Self {
+            quality: 80,
+            output_format: "avif".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsonFormatterDefaults {
+    pub indent_size: usize,
+}
+
+impl Default for JsonFormatterDefaults {
+    fn default() -> Self {
+        Self { indent_size: 2 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct KanbanDueSettings {
+    /// Show an OS notification when tasks are overdue or due today
+    pub notify: bool,
+    pub check_interval_minutes: u32,
+}
+
+impl Default for KanbanDueSettings {
+    fn default() -> Self {
+        Self {
+            notify: true,
+            check_interval_minutes: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GenerationHistorySettings {
+    /// Keep generated passwords in the history like other generated values
+    pub save_passwords: bool,
+}
+
+/// Every field has a default so files written by older versions still load
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppSettings {
+    pub version: u32,
+    pub language: String,
+    pub theme: ThemeMode,
+    /// Suggested folder for save dialogs
+    pub default_output_dir: Option<String>,
+    pub image_compressor: ImageCompressorDefaults,
+    pub json_formatter: JsonFormatterDefaults,
+    pub kanban_due: KanbanDueSettings,
+    pub generation_history: GenerationHistorySettings,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            language: "ja".to_string(),
+            theme: ThemeMode::default(),
+            default_output_dir: None,
+            image_compressor: ImageCompressorDefaults::default(),
+            json_formatter: JsonFormatterDefaults::default(),
+            kanban_due: KanbanDueSettings::default(),
+            generation_history: GenerationHistorySettings::default(),
+        }
+    }
+}
+
+const IMAGE_FORMATS: [&str; 4] = ["avif", "webp", "jpeg", "png"];
+/// Allowed range for the due-date check interval, in minutes
+const DUE_CHECK_INTERVAL_RANGE: (u32, u32) = (5, 24 * 60);
+
+/// Replace out-of-range values with their defaults and stamp the current version
+pub fn normalize_settings(mut settings: AppSettings) -> AppSettings {
+    let defaults = AppSettings::default();
+    settings.version = SETTINGS_VERSION;
+    if !["ja", "en"].contains(&settings.language.as_str()) {
+        settings.language = defaults.language;
+    }
+    settings.default_output_dir = settings
+        .default_output_dir
+        .map(|dir| dir.trim().to_string())
+        .filter(|dir| !dir.is_empty());
+    settings.image_compressor.quality = settings.image_compressor.quality.clamp(1, 100);
+    if !IMAGE_FORMATS.contains(&settings.image_compressor.output_format.as_str()) {
+        settings.image_compressor.output_format = defaults.image_compressor.output_format;
+    }
+    if !(1..=8).contains(&settings.json_formatter.indent_size) {
+        settings.json_formatter.indent_size = defaults.json_formatter.indent_size;
+    }
+    let (min_interval, max_interval) = DUE_CHECK_INTERVAL_RANGE;
+    settings.kanban_due.check_interval_minutes = settings
+        .kanban_due
+        .check_interval_minutes
+        .clamp(min_interval, max_interval);
+    settings
+}
+
+/// Read settings from `path`, falling back to the defaults when the file is missing.
+///
+/// A file that cannot be parsed is renamed to `<name>.bak` so the app still starts and
+/// the broken file is kept for inspection.
+pub fn load_settings_from(path: &Path) -> AppSettings {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(_) => return AppSettings::default(),
+    };
+    match serde_json::from_str::<AppSettings>(&content) {
+        Ok(settings) => normalize_settings(settings),
+        Err(_) => {
+            let _ = fs::rename(path, path.with_extension("json.bak"));
+            AppSettings::default()
+        }
+    }
+}
+
+pub fn save_settings_to(path: &Path, settings: AppSettings) -> Result<AppSettings, String> {
+    let settings = normalize_settings(settings);
+    let content = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("Failed to write settings: {}", e))?;
+    Ok(settings)
+}
+
+fn get_settings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("settings.json"))
+}
+
+pub fn load_settings(app: &AppHandle) -> AppSettings {
+    match get_settings_path(app) {
+        Ok(path) => load_settings_from(&path),
+        Err(_) => AppSettings::default(),
+    }
+}
+
+pub fn save_settings(app: &AppHandle, settings: AppSettings) -> Result<AppSettings, String> {
+    save_settings_to(&get_settings_path(app)?, settings)
+}
+
+pub fn reset_settings(app: &AppHandle) -> Result<AppSettings, String> {
+    save_settings(app, AppSettings::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_settings_round_trip_and_normalize() {
+        let dir = TempDir::new("settings_round_trip");
+        let path = dir.join("settings.json");
+        assert_eq!(load_settings_from(&path), AppSettings::default());
+
+        let settings = AppSettings {
+            theme: ThemeMode::Light,
+            language: "en".to_string(),
+            default_output_dir: Some("  /tmp/out  ".to_string()),
+            image_compressor: ImageCompressorDefaults {
+                quality: 0,
+                ..Default::default()
+            },
+            json_formatter: JsonFormatterDefaults { indent_size: 20 },
+            kanban_due: KanbanDueSettings {
+                notify: false,
+                check_interval_minutes: 1,
+            },
+            ..Default::default()
+        };
+        let saved = save_settings_to(&path, settings).unwrap();
+        assert_eq!(saved.default_output_dir.as_deref(), Some("/tmp/out"));
+        assert_eq!(saved.image_compressor.quality, 1);
+        assert_eq!(saved.json_formatter.indent_size, 2);
+        assert!(!saved.kanban_due.notify);
+        assert_eq!(saved.kanban_due.check_interval_minutes, 5);
+        assert_eq!(load_settings_from(&path), saved);
+    }
+
+    #[test]
+    fn test_load_settings_fills_missing_fields() {
+        let dir = TempDir::new("settings_missing_fields");
+        let path = dir.join("settings.json");
+        fs::write(
+            &path,
+            r#"{"version":0,"theme":"light","imageCompressor":{"quality":60}}"#,
+        )
+        .unwrap();
+        let settings = load_settings_from(&path);
+        assert_eq!(settings.version, SETTINGS_VERSION);
+        assert_eq!(settings.theme, ThemeMode::Light);
+        assert_eq!(settings.image_compressor.quality, 60);
+        assert_eq!(settings.image_compressor.output_format, "avif");
+        assert_eq!(settings.json_formatter.indent_size, 2);
+        assert_eq!(settings.language, "ja");
+    }
+
+    #[test]
+    fn test_theme_mode() {
+        assert_eq!(AppSettings::default().theme, ThemeMode::System);
+        let settings: AppSettings = serde_json::from_str(r#"{"theme":"dark"}"#).unwrap();
+        assert_eq!(settings.theme, ThemeMode::Dark);
+        assert_eq!(theme_name(tauri::Theme::Dark), "dark");
+        assert_eq!(theme_name(tauri::Theme::Light), "light");
+    }
+
+    #[test]
+    fn test_load_settings_backs_up_corrupt_file() {
+        let dir = TempDir::new("settings_corrupt");
+        let path = dir.join("settings.json");
+        fs::write(&path, "{ not json").unwrap();
+        assert_eq!(load_settings_from(&path), AppSettings::default());
+        assert!(!path.exists());
+        let backup = path.with_extension("json.bak");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "{ not json");
+    }
+}
@@ -0,0 +1,530 @@
+use serde::{Deserialize, Serialize};
+use sqlformat::{FormatOptions, Indent, QueryParams};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+    Preserve,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SqlDialect {
+    #[default]
+    Generic,
+    MySql,
+    PostgreSql,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SqlFormatOptions {
+    pub indent_width: u8,
+    pub keyword_case: KeywordCase,
+    /// Columns placed on one line in a SELECT list; 1 puts each on its own line
+    pub columns_per_line: usize,
+    pub dialect: SqlDialect,
+    /// Collapse the query onto a single line instead of formatting it
+    pub minify: bool,
+}
+
+impl Default for SqlFormatOptions {
+    fn default() -> Self {
+        Self {
+            indent_width: 2,
+            keyword_case: KeywordCase::Upper,
+            columns_per_line: 1,
+            dialect: SqlDialect::Generic,
+            minify: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlFormatResult {
+    pub success: bool,
+    pub formatted: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Whitespace,
+    LineComment,
+    BlockComment,
+    /// String literals and quoted identifiers, which are never rewritten
+    Quoted,
+    Word,
+    Symbol,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+}
+
+fn line_of(input: &str, offset: usize) -> usize {
+    input[..offset].matches('\n').count() + 1
+}
+
+/// Byte offset just past the closing `quote`, honouring doubled quotes and,
+/// where the dialect allows it, backslash escapes
+fn quoted_end(input: &str, start: usize, quote: char, backslash: bool) -> Option<usize> {
+    let mut chars = input[start + 1..].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if backslash && c == '\\' {
+            chars.next();
+        } else if c == quote {
+            if chars.peek().map(|(_, next)| *next) == Some(quote) {
+                chars.next();
+            } else {
+                return Some(start + 1 + i + 1);
+            }
+        }
+    }
+    None
+}
+
+/// Byte offset just past a PostgreSQL `$tag$ ... $tag$` string, if one starts here
+fn dollar_quoted_end(input: &str, start: usize) -> Option<Result<usize, ()>> {
+    let rest = &input[start + 1..];
+    let tag_len = rest.find('$')?;
+    let tag = &rest[..tag_len];
+    let valid_tag = tag.is_empty()
+        || (tag.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && tag.chars().all(|c| c.is_alphanumeric() || c == '_'));
+    if !valid_tag {
+        return None;
+    }
+    let delimiter = &input[start..start + tag_len + 2];
+    let body_start = start + delimiter.len();
+    Some(
+        input[body_start..]
+            .find(delimiter)
+            .map(|i| body_start + i + delimiter.len())
+            .ok_or(()),
+    )
+}
+
+fn tokenize(input: &str, dialect: SqlDialect) -> Result<Vec<Token<'_>>, String> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while let Some(c) = input[pos..].chars().next() {
+        let rest = &input[pos..];
+        let (kind, end) = if c.is_whitespace() {
+            let len = rest
+                .find(|ch: char| !ch.is_whitespace())
+                .unwrap_or(rest.len());
+            (TokenKind::Whitespace, pos + len)
+        } else if rest.starts_with("--") || (c == '#' && dialect == SqlDialect::MySql) {
+            (
+                TokenKind::LineComment,
+                pos + rest.find('\n').unwrap_or(rest.len()),
+            )
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            let Some(close) = body.find("*/") else {
+                return Err(format!(
+                    "Unterminated block comment starting on line {}",
+                    line_of(input, pos)
+                ));
+            };
+            (TokenKind::BlockComment, pos + 2 + close + 2)
+        } else if c == '\'' || c == '"' || (c == '`' && dialect == SqlDialect::MySql) {
+            let backslash = dialect == SqlDialect::MySql && c != '`';
+            let Some(end) = quoted_end(input, pos, c, backslash) else {
+                return Err(format!(
+                    "Unterminated {} starting on line {}",
+                    if c == '\'' {
+                        "string literal"
+                    } else {
+                        "quoted identifier"
+                    },
+                    line_of(input, pos)
+                ));
+            };
+            (TokenKind::Quoted, end)
+        } else if c == '$' && dialect == SqlDialect::PostgreSql {
+            match dollar_quoted_end(input, pos) {
+                Some(Ok(end)) => (TokenKind::Quoted, end),
+                Some(Err(())) => {
+                    return Err(format!(
+                        "Unterminated dollar-quoted string starting on line {}",
+                        line_of(input, pos)
+                    ))
+                }
+                // `$1` placeholders read as words
+                None => {
+                    let len = rest[1..]
+                        .find(|ch: char| !ch.is_alphanumeric() && ch != '_')
+                        .map_or(rest.len(), |i| i + 1);
+                    (TokenKind::Word, pos + len)
+                }
+            }
+        } else if c.is_alphanumeric() || c == '_' {
+            let len = rest
+                .find(|ch: char| !ch.is_alphanumeric() && ch != '_')
+                .unwrap_or(rest.len());
+            (TokenKind::Word, pos + len)
+        } else {
+            (TokenKind::Symbol, pos + c.len_utf8())
+        };
+        tokens.push(Token {
+            kind,
+            text: &input[pos..end],
+        });
+        pos = end;
+    }
+    Ok(tokens)
+}
+
+fn sqlformat_options(indent_width: u8, uppercase: bool) -> FormatOptions {
+    FormatOptions {
+        indent: Indent::Spaces(indent_width),
+        uppercase,
+        lines_between_queries: 1,
+    }
+}
+
+/// Ask sqlformat which words it treats as keywords: with every word lowercased
+/// beforehand, the ones it uppercases are exactly its keywords
+fn keyword_set(tokens: &[Token], dialect: SqlDialect) -> HashSet<String> {
+    let lowered: String = tokens
+        .iter()
+        .map(|t| match t.kind {
+            TokenKind::Word => t.text.to_lowercase(),
+            _ => t.text.to_string(),
+        })
+        .collect();
+    let formatted = sqlformat::format(&lowered, &QueryParams::None, sqlformat_options(2, true));
+    tokenize(&formatted, dialect)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|t| t.kind == TokenKind::Word && t.text.chars().any(char::is_uppercase))
+        .map(|t| t.text.to_lowercase())
+        .collect()
+}
+
+fn apply_keyword_case(tokens: &[Token], keywords: &HashSet<String>, case: KeywordCase) -> String {
+    tokens
+        .iter()
+        .map(|t| {
+            if t.kind != TokenKind::Word || !keywords.contains(&t.text.to_lowercase()) {
+                return t.text.to_string();
+            }
+            match case {
+                KeywordCase::Upper => t.text.to_uppercase(),
+                KeywordCase::Lower => t.text.to_lowercase(),
+                KeywordCase::Preserve => t.text.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// sqlformat spaces out the `::` cast operator; put it back together
+fn join_casts(tokens: Vec<Token<'_>>) -> Vec<Token<'_>> {
+    let is_colon =
+        |t: Option<&Token>| t.is_some_and(|t| t.kind == TokenKind::Symbol && t.text == ":");
+    let mut out: Vec<Token> = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.iter().enumerate() {
+        let before_cast = is_colon(tokens.get(i + 1)) && is_colon(tokens.get(i + 2));
+        let after_cast = i >= 2 && is_colon(tokens.get(i - 1)) && is_colon(tokens.get(i - 2));
+        if token.kind == TokenKind::Whitespace
+            && !token.text.contains('\n')
+            && (before_cast || after_cast)
+        {
+            continue;
+        }
+        out.push(*token);
+    }
+    out
+}
+
+/// Punctuation that never needs a space next to it once minified
+fn is_tight_symbol(token: &Token) -> bool {
+    token.kind == TokenKind::Symbol && matches!(token.text, "(" | ")" | "," | ";" | ".")
+}
+
+fn minify(tokens: &[Token]) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut last: Option<Token> = None;
+    let mut pending_space = false;
+    for token in tokens {
+        if token.kind == TokenKind::Whitespace {
+            pending_space = true;
+            continue;
+        }
+        if pending_space
+            && last.is_some_and(|prev| !is_tight_symbol(&prev) && !is_tight_symbol(token))
+        {
+            parts.push(" ".to_string());
+        }
+        pending_space = false;
+        let text = match token.kind {
+            // A line comment would swallow the rest of the line, so keep it as a block comment
+            TokenKind::LineComment => {
+                let body = token.text.trim_start_matches("--").trim_start_matches('#');
+                format!("/* {} */", body.trim().replace("*/", "* /"))
+            }
+            _ => token.text.to_string(),
+        };
+        parts.push(text);
+        // Anything after a comment needs separating from it
+        if token.kind == TokenKind::LineComment {
+            pending_space = true;
+        }
+        last = Some(*token);
+    }
+    parts
+}
+
+fn has_line_comment(line: &str, dialect: SqlDialect) -> bool {
+    tokenize(line, dialect).map_or(true, |tokens| {
+        tokens.iter().any(|t| t.kind == TokenKind::LineComment)
+    })
+}
+
+/// Lines that close an item begun on an earlier line, like `END AS x` or `) AS y`
+fn is_item_continuation(rest: &str) -> bool {
+    rest.starts_with(char::is_whitespace)
+        || rest.starts_with(')')
+        || rest
+            .split(|c: char| !c.is_alphanumeric())
+            .next()
+            .is_some_and(|word| word.eq_ignore_ascii_case("end"))
+}
+
+/// Put up to `per_line` single-line SELECT columns on each line
+fn wrap_select_columns(
+    formatted: &str,
+    indent: &str,
+    per_line: usize,
+    dialect: SqlDialect,
+) -> String {
+    let lines: Vec<&str> = formatted.lines().collect();
+    let mut out: Vec<String> = Vec::with_capacity(lines.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        out.push(line.to_string());
+        i += 1;
+        let trimmed = line.trim_start();
+        if !trimmed.eq_ignore_ascii_case("select")
+            && !trimmed.eq_ignore_ascii_case("select distinct")
+        {
+            continue;
+        }
+        let item_indent = format!("{}{}", &line[..line.len() - trimmed.len()], indent);
+
+        let mut items: Vec<Vec<&str>> = Vec::new();
+        while i < lines.len() && lines[i].starts_with(&item_indent) {
+            let rest = &lines[i][item_indent.len()..];
+            match items.last_mut() {
+                Some(item) if is_item_continuation(rest) => item.push(lines[i]),
+                _ => items.push(vec![lines[i]]),
+            }
+            i += 1;
+        }
+
+        let mut row: Vec<&str> = Vec::new();
+        let flush = |row: &mut Vec<&str>, out: &mut Vec<String>| {
+            if !row.is_empty() {
+                out.push(format!("{}{}", item_indent, row.join(" ")));
+                row.clear();
+            }
+        };
+        for item in items {
+            if item.len() == 1 && !has_line_comment(item[0], dialect) {
+                row.push(item[0].trim_start());
+                if row.len() >= per_line {
+                    flush(&mut row, &mut out);
+                }
+            } else {
+                flush(&mut row, &mut out);
+                out.extend(item.iter().map(|l| l.to_string()));
+            }
+        }
+        flush(&mut row, &mut out);
+    }
+    out.join("\n")
+}
+
+pub fn format_sql(input: &str, options: &SqlFormatOptions) -> SqlFormatResult {
+    if input.trim().is_empty() {
+        return SqlFormatResult {
+            success: false,
+            formatted: String::new(),
+            error: Some("Input is empty".to_string()),
+        };
+    }
+    let tokens = match tokenize(input, options.dialect) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            return SqlFormatResult {
+                success: false,
+                formatted: String::new(),
+                error: Some(e),
+            }
+        }
+    };
+    let keywords = keyword_set(&tokens, options.dialect);
+
+    let output = if options.minify {
+        minify(&tokens).concat()
+    } else {
+        let indent_width = options.indent_width.clamp(1, 8);
+        let formatted = sqlformat::format(
+            input,
+            &QueryParams::None,
+            sqlformat_options(indent_width, false),
+        );
+        if options.columns_per_line > 1 {
+            wrap_select_columns(
+                &formatted,
+                &" ".repeat(indent_width as usize),
+                options.columns_per_line,
+                options.dialect,
+            )
+        } else {
+            formatted
+        }
+    };
+
+    // The output only contains tokens the input already had, so it re-tokenizes cleanly
+    let output_tokens = tokenize(&output, options.dialect)
+        .map(join_casts)
+        .unwrap_or_default();
+    SqlFormatResult {
+        success: true,
+        formatted: apply_keyword_case(&output_tokens, &keywords, options.keyword_case),
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(input: &str, options: SqlFormatOptions) -> String {
+        let result = format_sql(input, &options);
+        assert!(result.success, "{:?}", result.error);
+        result.formatted
+    }
+
+    #[test]
+    fn test_format_joins_and_case() {
+        let output = format(
+            "select a.id, case when a.x > 1 then 'big' else 'small' end as size from a left join b on a.id = b.aid where a.id = 1",
+            SqlFormatOptions::default(),
+        );
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "SELECT");
+        assert!(lines.contains(&"  LEFT JOIN b ON a.id = b.aid"));
+        assert!(lines.iter().any(|l| l.trim() == "WHEN a.x > 1 THEN 'big'"));
+        assert!(lines.iter().any(|l| l.trim() == "END AS size"));
+    }
+
+    #[test]
+    fn test_comments_are_kept() {
+        let input = "select a -- first column\n, b /* second */ from t";
+        let formatted = format(input, SqlFormatOptions::default());
+        assert!(formatted.contains("-- first column"));
+        assert!(formatted.contains("/* second */"));
+
+        let minified = format(
+            input,
+            SqlFormatOptions {
+                minify: true,
+                ..SqlFormatOptions::default()
+            },
+        );
+        assert_eq!(
+            minified,
+            "SELECT a /* first column */,b /* second */ FROM t"
+        );
+    }
+
+    #[test]
+    fn test_keywords_in_literals_are_untouched() {
+        let input = "SELECT 'select * from t' AS \"From\", UserId FROM t WHERE note = 'Where'";
+        let lower = format(
+            input,
+            SqlFormatOptions {
+                keyword_case: KeywordCase::Lower,
+                minify: true,
+                ..SqlFormatOptions::default()
+            },
+        );
+        assert_eq!(
+            lower,
+            "select 'select * from t' as \"From\",UserId from t where note = 'Where'"
+        );
+
+        let upper = format(&lower, SqlFormatOptions::default());
+        assert!(upper.contains("'select * from t' AS \"From\""));
+        assert!(upper.contains("note = 'Where'"));
+    }
+
+    #[test]
+    fn test_columns_per_line() {
+        let output = format(
+            "select a, b, c, d, e from t",
+            SqlFormatOptions {
+                columns_per_line: 2,
+                ..SqlFormatOptions::default()
+            },
+        );
+        assert_eq!(output, "SELECT\n  a, b,\n  c, d,\n  e\nFROM\n  t");
+    }
+
+    #[test]
+    fn test_dialects() {
+        let pg = format(
+            "select x::int, $1 from f($$ select 'a' $$)",
+            SqlFormatOptions {
+                dialect: SqlDialect::PostgreSql,
+                minify: true,
+                ..SqlFormatOptions::default()
+            },
+        );
+        assert_eq!(pg, "SELECT x::int,$1 FROM f($$ select 'a' $$)");
+
+        let formatted_pg = format(
+            "select x::int from t",
+            SqlFormatOptions {
+                dialect: SqlDialect::PostgreSql,
+                ..SqlFormatOptions::default()
+            },
+        );
+        assert!(formatted_pg.contains("x::int"));
+
+        let mysql = format(
+            "select `select` from t # note",
+            SqlFormatOptions {
+                dialect: SqlDialect::MySql,
+                minify: true,
+                ..SqlFormatOptions::default()
+            },
+        );
+        assert_eq!(mysql, "SELECT `select` FROM t /* note */");
+    }
+
+    #[test]
+    fn test_unterminated_input() {
+        let result = format_sql("select 'abc from t", &SqlFormatOptions::default());
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("Unterminated string literal"));
+
+        let result = format_sql("select a\n/* open", &SqlFormatOptions::default());
+        assert!(result.error.unwrap().contains("line 2"));
+        assert!(!format_sql("  ", &SqlFormatOptions::default()).success);
+    }
+}
@@ -0,0 +1,280 @@
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sysinfo::{CpuRefreshKind, Disks, MemoryRefreshKind, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+/// Accepted range for the monitor's sampling interval
+pub const MIN_MONITOR_INTERVAL_MS: u64 = 250;
+pub const MAX_MONITOR_INTERVAL_MS: u64 = 60_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskInfo {
+    pub name: String,
+    pub mount_point: String,
+    pub file_system: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+    pub used_bytes: u64,
+    pub is_removable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemInfoResult {
+    pub os_name: String,
+    pub os_version: String,
+    pub kernel_version: String,
+    pub host_name: String,
+    pub cpu_arch: String,
+    pub cpu_model: String,
+    /// Not reported on every platform
+    pub physical_cores: Option<usize>,
+    pub logical_cores: usize,
+    pub uptime_secs: u64,
+    pub stats: SystemStats,
+    pub disks: Vec<DiskInfo>,
+}
+
+/// The frequently changing values, also emitted as `system-stats` by the monitor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemStats {
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// Percent over all cores
+    pub cpu_usage: f32,
+    /// Percent for each logical core
+    pub core_usages: Vec<f32>,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    pub swap_used: u64,
+    pub swap_total: u64,
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// CPU usage is measured between two refreshes, so `system` must have been refreshed before
+fn sample_stats(system: &mut System) -> SystemStats {
+    system.refresh_cpu_specifics(CpuRefreshKind::nothing().with_cpu_usage());
+    system.refresh_memory_specifics(MemoryRefreshKind::everything());
+    SystemStats {
+        timestamp: now_millis(),
+        cpu_usage: system.global_cpu_usage(),
+        core_usages: system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+        memory_used: system.used_memory(),
+        memory_total: system.total_memory(),
+        swap_used: system.used_swap(),
+        swap_total: system.total_swap(),
+    }
+}
+
+fn list_disks() -> Vec<DiskInfo> {
+    Disks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|disk| {
+            let total = disk.total_space();
+            let available = disk.available_space();
+            DiskInfo {
+                name: disk.name().to_string_lossy().into_owned(),
+                mount_point: disk.mount_point().to_string_lossy().into_owned(),
+                file_system: disk.file_system().to_string_lossy().into_owned(),
+                total_bytes: total,
+                available_bytes: available,
+                used_bytes: total.saturating_sub(available),
+                is_removable: disk.is_removable(),
+            }
+        })
+        .collect()
+}
+
+/// Take a full snapshot. Blocks for a short moment because CPU usage needs two samples.
+pub fn get_system_info() -> SystemInfoResult {
+    let mut system = System::new();
+    system.refresh_cpu_all();
+    thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+    let stats = sample_stats(&mut system);
+
+    let cpu_model = system
+        .cpus()
+        .first()
+        .map(|cpu| cpu.brand().trim().to_string())
+        .unwrap_or_default();
+
+    SystemInfoResult {
+        os_name: System::long_os_version()
+            .or_else(System::name)
+            .unwrap_or_else(|| std::env::consts::OS.to_string()),
+        os_version: System::os_version().unwrap_or_default(),
+        kernel_version: System::kernel_version().unwrap_or_default(),
+        host_name: System::host_name().unwrap_or_default(),
+        cpu_arch: System::cpu_arch(),
+        cpu_model,
+        physical_cores: System::physical_core_count(),
+        logical_cores: system.cpus().len(),
+        uptime_secs: System::uptime(),
+        stats,
+        disks: list_disks(),
+    }
+}
+
+struct MonitorThread {
+    /// Dropping the sender wakes the thread and makes it exit
+    stop: Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl MonitorThread {
+    fn stop(self) {
+        drop(self.stop);
+        let _ = self.handle.join();
+    }
+}
+
+/// The running usage monitor, managed as Tauri state
+#[derive(Default)]
+pub struct SystemMonitorStore {
+    monitor: Mutex<Option<MonitorThread>>,
+}
+
+impl SystemMonitorStore {
+    /// Sample usage every `interval_ms` and pass it to `emit`. A monitor that is already
+    /// running is stopped first, so at most one sampling thread exists at a time.
+    pub fn start(
+        &self,
+        interval_ms: u64,
+        emit: impl Fn(SystemStats) + Send + 'static,
+    ) -> Result<(), String> {
+        if !(MIN_MONITOR_INTERVAL_MS..=MAX_MONITOR_INTERVAL_MS).contains(&interval_ms) {
+            return Err(format!(
+                "Interval must be between {} and {} ms",
+                MIN_MONITOR_INTERVAL_MS, MAX_MONITOR_INTERVAL_MS
+            ));
+        }
+        let mut guard = self
+            .monitor
+            .lock()
+            .map_err(|_| "System monitor is unavailable".to_string())?;
+        if let Some(previous) = guard.take() {
+            previous.stop();
+        }
+
+        let interval = Duration::from_millis(interval_ms);
+        let (stop, stopped) = mpsc::channel::<()>();
+        let handle = thread::Builder::new()
+            .name("system-monitor".to_string())
+            .spawn(move || {
+                let mut system = System::new();
+                system.refresh_cpu_all();
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                    emit(sample_stats(&mut system));
+                }
+            })
+            .map_err(|e| format!("Failed to start system monitor: {}", e))?;
+        *guard = Some(MonitorThread { stop, handle });
+        Ok(())
+    }
+
+    /// Stop the monitor and wait for its thread to exit; returns whether one was running
+    pub fn stop(&self) -> bool {
+        let monitor = self.monitor.lock().ok().and_then(|mut guard| guard.take());
+        match monitor {
+            Some(monitor) => {
+                monitor.stop();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.monitor
+            .lock()
+            .map(|guard| guard.is_some())
+            .unwrap_or(false)
+    }
+}
+
+impl Drop for SystemMonitorStore {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    #[test]
+    fn test_get_system_info() {
+        let info = get_system_info();
+        assert!(info.logical_cores >= 1);
+        assert_eq!(info.stats.core_usages.len(), info.logical_cores);
+        assert!((0.0..=100.0).contains(&info.stats.cpu_usage));
+        assert!(info.stats.memory_total > 0);
+        assert!(info.stats.memory_used <= info.stats.memory_total);
+        for disk in &info.disks {
+            assert_eq!(disk.used_bytes + disk.available_bytes, disk.total_bytes);
+        }
+    }
+
+    #[test]
+    fn test_monitor_restart_keeps_a_single_thread() {
+        let store = SystemMonitorStore::default();
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let second = Arc::new(Mutex::new(Vec::new()));
+
+        let samples = first.clone();
+        store
+            .start(MIN_MONITOR_INTERVAL_MS, move |stats| {
+                samples.lock().unwrap().push(stats)
+            })
+            .unwrap();
+        thread::sleep(Duration::from_millis(MIN_MONITOR_INTERVAL_MS * 3));
+
+        let samples = second.clone();
+        store
+            .start(MIN_MONITOR_INTERVAL_MS, move |stats| {
+                samples.lock().unwrap().push(stats)
+            })
+            .unwrap();
+        // The first thread has been joined, so it cannot emit any more
+        let first_count = first.lock().unwrap().len();
+        assert!(first_count >= 1);
+        thread::sleep(Duration::from_millis(MIN_MONITOR_INTERVAL_MS * 3));
+        assert_eq!(first.lock().unwrap().len(), first_count);
+        assert!(!second.lock().unwrap().is_empty());
+        assert!(store.is_running());
+
+        assert!(store.stop());
+        assert!(!store.is_running());
+        assert!(!store.stop());
+    }
+
+    #[test]
+    fn test_monitor_stops_without_waiting_for_the_interval() {
+        let store = SystemMonitorStore::default();
+        store.start(MAX_MONITOR_INTERVAL_MS, |_| {}).unwrap();
+        let started = Instant::now();
+        assert!(store.stop());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_monitor_rejects_bad_interval() {
+        let store = SystemMonitorStore::default();
+        assert!(store.start(10, |_| {}).is_err());
+        assert!(store.start(MAX_MONITOR_INTERVAL_MS + 1, |_| {}).is_err());
+        assert!(!store.is_running());
+    }
+}
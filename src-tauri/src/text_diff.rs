@@ -1,5 +1,15 @@
+use crate::markdown_to_pdf::escape_html;
+use chrono::{DateTime, Utc};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
-use similar::{ChangeTag, TextDiff};
+use sha2::{Digest, Sha256};
+use similar::{capture_diff_slices, Algorithm, ChangeTag, DiffOp, TextDiff};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use walkdir::WalkDir;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -27,6 +37,52 @@ pub struct LineDiff {
     pub tag: String,
     pub content: String,
     pub inline_changes: Vec<InlineChange>,
+    /// Changed parts of a modified line, in UTF-16 code units of `content`.
+    /// Filled in Word and Character mode for delete/insert lines paired with each other.
+    #[serde(default)]
+    pub inline_spans: Vec<InlineSpan>,
+    /// The line only differs in ways [`DiffOptions`] ignores: an equal line whose text
+    /// changed, or a blank line present on one side only (tagged delete/insert)
+    #[serde(default)]
+    pub ignored: bool,
+    /// Text of the new side of an ignored equal line; `content` is the old side
+    #[serde(default)]
+    pub new_content: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WhitespaceMode {
+    #[default]
+    None,
+    Trailing,
+    Leading,
+    All,
+}
+
+/// What to disregard when deciding whether lines differ. Lines are still returned as
+/// written; only the comparison uses the normalized text.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DiffOptions {
+    pub ignore_whitespace: WhitespaceMode,
+    pub ignore_case: bool,
+    pub ignore_blank_lines: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InlineSpanKind {
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlineSpan {
+    pub start: usize,
+    pub end: usize,
+    pub kind: InlineSpanKind,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +99,9 @@ pub struct DiffResult {
     pub lines: Vec<LineDiff>,
     pub stats: DiffStats,
     pub unified_diff: String,
+    /// Set when either input is not text; `lines` is empty and only identity is compared
+    #[serde(default)]
+    pub is_binary: bool,
     pub error: Option<String>,
 }
 
@@ -55,59 +114,215 @@ pub struct DiffStats {
     pub unchanged: usize,
     pub total_lines_old: usize,
     pub total_lines_new: usize,
+    /// Lines whose only changes were ignored (a changed pair counts once)
+    #[serde(default)]
+    pub ignored_changes: usize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffExportFormat {
+    /// `---`/`+++`/`@@` patch that `git apply` / `patch` understand
+    UnifiedPatch,
+    /// Standalone HTML page with a colored side-by-side table
+    Html,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffExportResult {
+    pub success: bool,
+    /// The exported text, when no output path was given
+    pub content: Option<String>,
+    pub output_path: Option<String>,
+    pub error: Option<String>,
 }
 
+const DEFAULT_CONTEXT_LINES: usize = 3;
+/// Per line pair; a slow inline diff falls back to a coarser result rather than stalling
+const INLINE_DIFF_TIMEOUT: Duration = Duration::from_millis(50);
+
 pub fn compute_diff(old_text: &str, new_text: &str, mode: DiffMode) -> DiffResult {
-    let diff = TextDiff::from_lines(old_text, new_text);
+    compute_diff_with(old_text, new_text, mode, &DiffOptions::default())
+}
 
-    let mut lines: Vec<LineDiff> = Vec::new();
-    let mut old_line_num = 1usize;
-    let mut new_line_num = 1usize;
-    let mut additions = 0usize;
-    let mut deletions = 0usize;
-    let mut unchanged = 0usize;
+/// Lines with their endings, split at `\n`, `\r\n` and a lone `\r` like `TextDiff::from_lines`
+fn split_lines(text: &str) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        if byte == b'\n' || (byte == b'\r' && bytes.get(i + 1) != Some(&b'\n')) {
+            lines.push(&text[start..=i]);
+            start = i + 1;
+        }
+    }
+    if start < text.len() {
+        lines.push(&text[start..]);
+    }
+    lines
+}
 
-    for change in diff.iter_all_changes() {
-        let (tag_str, line_old, line_new) = match change.tag() {
-            ChangeTag::Delete => {
-                deletions += 1;
-                let ln = old_line_num;
-                old_line_num += 1;
-                ("delete", Some(ln), None)
-            }
-            ChangeTag::Insert => {
-                additions += 1;
-                let ln = new_line_num;
-                new_line_num += 1;
-                ("insert", None, Some(ln))
-            }
-            ChangeTag::Equal => {
-                unchanged += 1;
-                let lo = old_line_num;
-                let ln = new_line_num;
-                old_line_num += 1;
-                new_line_num += 1;
-                ("equal", Some(lo), Some(ln))
+/// The text a line is compared by under `options`
+fn comparison_key(line: &str, options: &DiffOptions) -> String {
+    let line = match options.ignore_whitespace {
+        WhitespaceMode::None => line.to_string(),
+        WhitespaceMode::Trailing => line.trim_end().to_string(),
+        WhitespaceMode::Leading => line.trim_start().to_string(),
+        WhitespaceMode::All => line.chars().filter(|c| !c.is_whitespace()).collect(),
+    };
+    if options.ignore_case {
+        line.to_lowercase()
+    } else {
+        line
+    }
+}
+
+fn line_diff(
+    tag: &str,
+    line_number_old: Option<usize>,
+    line_number_new: Option<usize>,
+    content: &str,
+) -> LineDiff {
+    LineDiff {
+        line_number_old,
+        line_number_new,
+        tag: tag.to_string(),
+        content: content.to_string(),
+        inline_changes: Vec::new(),
+        inline_spans: Vec::new(),
+        ignored: false,
+        new_content: None,
+    }
+}
+
+/// Builds the line list, emitting skipped blank lines in place as it goes
+struct LineCollector<'a> {
+    old_lines: &'a [&'a str],
+    new_lines: &'a [&'a str],
+    /// Next line of each side not yet emitted
+    old_pos: usize,
+    new_pos: usize,
+    lines: Vec<LineDiff>,
+}
+
+impl LineCollector<'_> {
+    fn equal(&mut self, old: usize, new: usize) {
+        let (old_line, new_line) = (self.old_lines[old], self.new_lines[new]);
+        let mut line = line_diff("equal", Some(old + 1), Some(new + 1), old_line);
+        if old_line != new_line {
+            line.ignored = true;
+            line.new_content = Some(new_line.to_string());
+        }
+        self.lines.push(line);
+        (self.old_pos, self.new_pos) = (old + 1, new + 1);
+    }
+
+    fn delete(&mut self, old: usize, ignored: bool) {
+        let mut line = line_diff("delete", Some(old + 1), None, self.old_lines[old]);
+        line.ignored = ignored;
+        self.lines.push(line);
+        self.old_pos = old + 1;
+    }
+
+    fn insert(&mut self, new: usize, ignored: bool) {
+        let mut line = line_diff("insert", None, Some(new + 1), self.new_lines[new]);
+        line.ignored = ignored;
+        self.lines.push(line);
+        self.new_pos = new + 1;
+    }
+
+    /// Emit the blank lines before `old_end` and `new_end`, pairing them up where both
+    /// sides have one
+    fn blanks_until(&mut self, old_end: usize, new_end: usize) {
+        while self.old_pos < old_end && self.new_pos < new_end {
+            self.equal(self.old_pos, self.new_pos);
+        }
+        while self.old_pos < old_end {
+            self.delete(self.old_pos, true);
+        }
+        while self.new_pos < new_end {
+            self.insert(self.new_pos, true);
+        }
+    }
+}
+
+/// Like [`compute_diff`], but with whitespace, case or blank line changes ignored as
+/// set in `options`. The unified diff always describes the texts as written, so it
+/// stays applicable as a patch.
+pub fn compute_diff_with(
+    old_text: &str,
+    new_text: &str,
+    mode: DiffMode,
+    options: &DiffOptions,
+) -> DiffResult {
+    let (old_lines, new_lines) = (split_lines(old_text), split_lines(new_text));
+    // Indices of the lines taking part in the comparison
+    let compared = |lines: &[&str]| -> Vec<usize> {
+        (0..lines.len())
+            .filter(|&i| !options.ignore_blank_lines || !lines[i].trim().is_empty())
+            .collect()
+    };
+    let (old_compared, new_compared) = (compared(&old_lines), compared(&new_lines));
+    let keys = |lines: &[&str], indices: &[usize]| -> Vec<String> {
+        indices
+            .iter()
+            .map(|&i| comparison_key(lines[i], options))
+            .collect()
+    };
+    let old_keys = keys(&old_lines, &old_compared);
+    let new_keys = keys(&new_lines, &new_compared);
+    let old_at = |i: usize| old_compared.get(i).copied().unwrap_or(old_lines.len());
+    let new_at = |i: usize| new_compared.get(i).copied().unwrap_or(new_lines.len());
+
+    let mut collector = LineCollector {
+        old_lines: &old_lines,
+        new_lines: &new_lines,
+        old_pos: 0,
+        new_pos: 0,
+        lines: Vec::new(),
+    };
+    for op in capture_diff_slices(Algorithm::Myers, &old_keys, &new_keys) {
+        let (old_range, new_range) = (op.old_range(), op.new_range());
+        collector.blanks_until(old_at(old_range.start), new_at(new_range.start));
+        if let DiffOp::Equal { .. } = op {
+            for (old, new) in old_range.zip(new_range) {
+                collector.blanks_until(old_at(old), new_at(new));
+                collector.equal(old_at(old), new_at(new));
             }
-        };
+            continue;
+        }
+        for old in old_range {
+            collector.blanks_until(old_at(old), collector.new_pos);
+            collector.delete(old_at(old), false);
+        }
+        for new in new_range {
+            collector.blanks_until(collector.old_pos, new_at(new));
+            collector.insert(new_at(new), false);
+        }
+    }
+    collector.blanks_until(old_lines.len(), new_lines.len());
+    let mut lines = collector.lines;
 
-        let content = change.value().to_string();
-        let _ = &mode; // Suppress unused warning
-        let inline_changes = Vec::new();
+    let counted = |tag: &str| lines.iter().filter(|l| l.tag == tag && !l.ignored).count();
+    let (additions, deletions) = (counted("insert"), counted("delete"));
+    let unchanged = lines.iter().filter(|l| l.tag == "equal").count();
+    let ignored_changes = lines.iter().filter(|l| l.ignored).count();
 
-        lines.push(LineDiff {
-            line_number_old: line_old,
-            line_number_new: line_new,
-            tag: tag_str.to_string(),
-            content,
-            inline_changes,
-        });
+    let modified_pairs = modified_line_pairs(&lines);
+    if mode != DiffMode::Line {
+        for &(old, new) in &modified_pairs {
+            let (old_spans, new_spans) =
+                compute_inline_spans(&lines[old].content, &lines[new].content, &mode);
+            lines[old].inline_spans = old_spans;
+            lines[new].inline_spans = new_spans;
+        }
     }
 
     let total_lines_old = old_text.lines().count().max(1);
     let total_lines_new = new_text.lines().count().max(1);
 
-    let unified_diff = generate_unified_diff(old_text, new_text);
+    let unified_diff = generate_unified_diff(old_text, new_text, DEFAULT_CONTEXT_LINES);
 
     DiffResult {
         success: true,
@@ -115,16 +330,308 @@ pub fn compute_diff(old_text: &str, new_text: &str, mode: DiffMode) -> DiffResul
         stats: DiffStats {
             additions,
             deletions,
-            modifications: 0,
+            modifications: modified_pairs.len(),
             unchanged,
             total_lines_old,
             total_lines_new,
+            ignored_changes,
         },
         unified_diff,
+        is_binary: false,
         error: None,
     }
 }
 
+/// Bytes sniffed for NUL characters when deciding whether a file is binary
+const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Decode file bytes as text, or None for binary content
+fn decode_text(bytes: Vec<u8>) -> Option<String> {
+    if bytes.iter().take(BINARY_SNIFF_LEN).any(|&b| b == 0) {
+        return None;
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Diff two files directly, without passing their contents through the frontend
+pub fn compute_file_diff(
+    old_path: &str,
+    new_path: &str,
+    mode: DiffMode,
+    options: &DiffOptions,
+) -> Result<DiffResult, String> {
+    let old_bytes = std::fs::read(old_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let new_bytes = std::fs::read(new_path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let identical = old_bytes == new_bytes;
+    match (decode_text(old_bytes), decode_text(new_bytes)) {
+        (Some(old_text), Some(new_text)) => {
+            Ok(compute_diff_with(&old_text, &new_text, mode, options))
+        }
+        _ => Ok(DiffResult {
+            success: true,
+            lines: Vec::new(),
+            stats: DiffStats {
+                additions: 0,
+                deletions: 0,
+                modifications: 0,
+                unchanged: 0,
+                total_lines_old: 0,
+                total_lines_new: 0,
+                ignored_changes: 0,
+            },
+            unified_diff: if identical {
+                String::new()
+            } else {
+                format!("Binary files {} and {} differ\n", old_path, new_path)
+            },
+            is_binary: true,
+            error: None,
+        }),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeWayHunk {
+    /// "equal", "ours", "theirs", "both" (same change on both sides) or "conflict"
+    pub kind: String,
+    pub conflict: bool,
+    /// 1-based first line of the hunk in each input
+    pub base_start: usize,
+    pub ours_start: usize,
+    pub theirs_start: usize,
+    pub base_lines: Vec<String>,
+    pub ours_lines: Vec<String>,
+    pub theirs_lines: Vec<String>,
+    /// Ours vs. theirs at the requested granularity, for conflict hunks only
+    pub inline_changes: Vec<InlineChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeWayDiffResult {
+    pub success: bool,
+    pub hunks: Vec<ThreeWayHunk>,
+    pub conflict_count: usize,
+    /// Merge result, with git-style conflict markers around conflicting hunks
+    pub merged: String,
+    pub error: Option<String>,
+}
+
+/// For each base line, the matching line index in `other` (if unchanged there)
+fn match_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+    for op in capture_diff_slices(Algorithm::Myers, base, other) {
+        if let DiffOp::Equal {
+            old_index,
+            new_index,
+            len,
+        } = op
+        {
+            for i in 0..len {
+                matches[old_index + i] = Some(new_index + i);
+            }
+        }
+    }
+    matches
+}
+
+/// Compare two edits of a common base (diff3) and mark where they conflict
+pub fn compute_three_way_diff(
+    base: &str,
+    ours: &str,
+    theirs: &str,
+    mode: DiffMode,
+) -> ThreeWayDiffResult {
+    let base_lines: Vec<&str> = base.split_inclusive('\n').collect();
+    let ours_lines: Vec<&str> = ours.split_inclusive('\n').collect();
+    let theirs_lines: Vec<&str> = theirs.split_inclusive('\n').collect();
+    let ours_map = match_lines(&base_lines, &ours_lines);
+    let theirs_map = match_lines(&base_lines, &theirs_lines);
+
+    let mut hunks: Vec<ThreeWayHunk> = Vec::new();
+    let (mut i, mut j, mut k) = (0usize, 0usize, 0usize);
+    while i < base_lines.len() || j < ours_lines.len() || k < theirs_lines.len() {
+        // Stable line: unchanged on both sides
+        if i < base_lines.len() && ours_map[i] == Some(j) && theirs_map[i] == Some(k) {
+            match hunks.last_mut() {
+                Some(h) if h.kind == "equal" => {
+                    h.base_lines.push(base_lines[i].to_string());
+                    h.ours_lines.push(ours_lines[j].to_string());
+                    h.theirs_lines.push(theirs_lines[k].to_string());
+                }
+                _ => hunks.push(ThreeWayHunk {
+                    kind: "equal".to_string(),
+                    conflict: false,
+                    base_start: i + 1,
+                    ours_start: j + 1,
+                    theirs_start: k + 1,
+                    base_lines: vec![base_lines[i].to_string()],
+                    ours_lines: vec![ours_lines[j].to_string()],
+                    theirs_lines: vec![theirs_lines[k].to_string()],
+                    inline_changes: Vec::new(),
+                }),
+            }
+            i += 1;
+            j += 1;
+            k += 1;
+            continue;
+        }
+
+        // Unstable chunk: runs until the next line unchanged on both sides
+        let (next_i, next_j, next_k) = (i..base_lines.len())
+            .find_map(|n| Some((n, ours_map[n]?, theirs_map[n]?)))
+            .unwrap_or((base_lines.len(), ours_lines.len(), theirs_lines.len()));
+        let base_chunk = &base_lines[i..next_i];
+        let ours_chunk = &ours_lines[j..next_j];
+        let theirs_chunk = &theirs_lines[k..next_k];
+
+        let kind = if ours_chunk == base_chunk {
+            "theirs"
+        } else if theirs_chunk == base_chunk {
+            "ours"
+        } else if ours_chunk == theirs_chunk {
+            "both"
+        } else {
+            "conflict"
+        };
+        let inline_changes = if kind == "conflict" {
+            compute_inline_diff(&ours_chunk.concat(), &theirs_chunk.concat(), mode.clone())
+        } else {
+            Vec::new()
+        };
+        let to_strings = |lines: &[&str]| lines.iter().map(|l| l.to_string()).collect();
+        hunks.push(ThreeWayHunk {
+            kind: kind.to_string(),
+            conflict: kind == "conflict",
+            base_start: i + 1,
+            ours_start: j + 1,
+            theirs_start: k + 1,
+            base_lines: to_strings(base_chunk),
+            ours_lines: to_strings(ours_chunk),
+            theirs_lines: to_strings(theirs_chunk),
+            inline_changes,
+        });
+        (i, j, k) = (next_i, next_j, next_k);
+    }
+
+    let merged = hunks.iter().map(merged_text).collect();
+    ThreeWayDiffResult {
+        success: true,
+        conflict_count: hunks.iter().filter(|h| h.conflict).count(),
+        hunks,
+        merged,
+        error: None,
+    }
+}
+
+fn merged_text(hunk: &ThreeWayHunk) -> String {
+    let with_newline = |lines: &[String]| {
+        let mut text = lines.concat();
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text
+    };
+    match hunk.kind.as_str() {
+        "theirs" => hunk.theirs_lines.concat(),
+        "conflict" => format!(
+            "<<<<<<< ours\n{}=======\n{}>>>>>>> theirs\n",
+            with_newline(&hunk.ours_lines),
+            with_newline(&hunk.theirs_lines)
+        ),
+        _ => hunk.ours_lines.concat(),
+    }
+}
+
+/// Indices of (deleted, inserted) lines treated as one modified line: within each block of
+/// changes the n-th deleted line is paired with the n-th inserted line
+fn modified_line_pairs(lines: &[LineDiff]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let deletes = lines[i..]
+            .iter()
+            .take_while(|l| l.tag == "delete" && !l.ignored)
+            .count();
+        let inserts = lines[i + deletes..]
+            .iter()
+            .take_while(|l| l.tag == "insert" && !l.ignored)
+            .count();
+        pairs.extend((0..deletes.min(inserts)).map(|n| (i + n, i + deletes + n)));
+        i += (deletes + inserts).max(1);
+    }
+    pairs
+}
+
+/// Scripts written without spaces between words, where a word diff would treat a whole
+/// sentence as one token
+fn has_unspaced_script(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(c,
+            '\u{3040}'..='\u{30FF}' // Hiragana, Katakana
+            | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+            | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+            | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+            | '\u{FF66}'..='\u{FF9F}' // Halfwidth Katakana
+            | '\u{0E00}'..='\u{0E7F}' // Thai
+        )
+    })
+}
+
+/// Changed ranges of a modified line pair, as UTF-16 offsets into each line.
+///
+/// Word mode diffs whole words, but falls back to characters when either line contains
+/// Japanese, Chinese or Thai text. Line endings are left out of the comparison.
+pub fn compute_inline_spans(
+    old_line: &str,
+    new_line: &str,
+    mode: &DiffMode,
+) -> (Vec<InlineSpan>, Vec<InlineSpan>) {
+    let old_line = old_line.trim_end_matches(['\n', '\r']);
+    let new_line = new_line.trim_end_matches(['\n', '\r']);
+    let by_word = match mode {
+        DiffMode::Line => return (Vec::new(), Vec::new()),
+        DiffMode::Word => !has_unspaced_script(old_line) && !has_unspaced_script(new_line),
+        DiffMode::Character => false,
+    };
+    let mut config = TextDiff::configure();
+    config.timeout(INLINE_DIFF_TIMEOUT);
+    let diff = if by_word {
+        config.diff_words(old_line, new_line)
+    } else {
+        config.diff_chars(old_line, new_line)
+    };
+
+    let mut old_spans: Vec<InlineSpan> = Vec::new();
+    let mut new_spans: Vec<InlineSpan> = Vec::new();
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for change in diff.iter_all_changes() {
+        let len = change.value().encode_utf16().count();
+        let (spans, pos, kind) = match change.tag() {
+            ChangeTag::Equal => {
+                old_pos += len;
+                new_pos += len;
+                continue;
+            }
+            ChangeTag::Delete => (&mut old_spans, &mut old_pos, InlineSpanKind::Delete),
+            ChangeTag::Insert => (&mut new_spans, &mut new_pos, InlineSpanKind::Insert),
+        };
+        match spans.last_mut() {
+            // Neighbouring changed tokens read better as one highlight
+            Some(last) if last.end == *pos => last.end += len,
+            _ => spans.push(InlineSpan {
+                start: *pos,
+                end: *pos + len,
+                kind,
+            }),
+        }
+        *pos += len;
+    }
+    (old_spans, new_spans)
+}
+
 #[allow(dead_code)]
 pub fn compute_inline_diff(old_line: &str, new_line: &str, mode: DiffMode) -> Vec<InlineChange> {
     let changes: Vec<InlineChange> = match mode {
@@ -165,20 +672,182 @@ pub fn compute_inline_diff(old_line: &str, new_line: &str, mode: DiffMode) -> Ve
     changes
 }
 
-fn generate_unified_diff(old_text: &str, new_text: &str) -> String {
+fn generate_unified_diff(old_text: &str, new_text: &str, context_lines: usize) -> String {
     let diff = TextDiff::from_lines(old_text, new_text);
     let mut output = String::new();
 
     output.push_str("--- a/original\n");
     output.push_str("+++ b/modified\n");
 
-    for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+    for hunk in diff
+        .unified_diff()
+        .context_radius(context_lines)
+        .iter_hunks()
+    {
         output.push_str(&format!("{}", hunk));
     }
 
     output
 }
 
+/// Export a diff as a unified patch or an HTML page.
+///
+/// Without `output_path` the result is returned in `content` (e.g. for the clipboard).
+pub fn export_diff(
+    old_text: &str,
+    new_text: &str,
+    mode: DiffMode,
+    format: DiffExportFormat,
+    output_path: Option<String>,
+    context_lines: Option<usize>,
+) -> DiffExportResult {
+    let content = match format {
+        DiffExportFormat::UnifiedPatch => generate_unified_diff(
+            old_text,
+            new_text,
+            context_lines.unwrap_or(DEFAULT_CONTEXT_LINES),
+        ),
+        DiffExportFormat::Html => generate_html_diff(old_text, new_text, mode),
+    };
+
+    match output_path {
+        Some(path) => match std::fs::write(&path, content) {
+            Ok(()) => DiffExportResult {
+                success: true,
+                content: None,
+                output_path: Some(path),
+                error: None,
+            },
+            Err(e) => DiffExportResult {
+                success: false,
+                content: None,
+                output_path: None,
+                error: Some(format!("Failed to write file: {}", e)),
+            },
+        },
+        None => DiffExportResult {
+            success: true,
+            content: Some(content),
+            output_path: None,
+            error: None,
+        },
+    }
+}
+
+/// Pair deleted and inserted lines of each change block into side-by-side rows
+fn side_by_side_rows(lines: &[LineDiff]) -> Vec<(Option<&LineDiff>, Option<&LineDiff>)> {
+    let mut rows = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].tag == "equal" {
+            rows.push((Some(&lines[i]), Some(&lines[i])));
+            i += 1;
+            continue;
+        }
+        let deletes: Vec<&LineDiff> = lines[i..]
+            .iter()
+            .take_while(|l| l.tag == "delete")
+            .collect();
+        i += deletes.len();
+        let inserts: Vec<&LineDiff> = lines[i..]
+            .iter()
+            .take_while(|l| l.tag == "insert")
+            .collect();
+        i += inserts.len();
+        for n in 0..deletes.len().max(inserts.len()) {
+            rows.push((deletes.get(n).copied(), inserts.get(n).copied()));
+        }
+    }
+    rows
+}
+
+fn html_cell(line: Option<&LineDiff>, inline: &[InlineChange], side: &str) -> String {
+    let Some(line) = line else {
+        return "<td class=\"num\"></td><td class=\"empty\"></td>".to_string();
+    };
+    let number = if side == "old" {
+        line.line_number_old
+    } else {
+        line.line_number_new
+    };
+    // Inline changes cover both sides; keep the ones that belong on this side
+    let hidden_tag = if side == "old" { "insert" } else { "delete" };
+    let body = if inline.is_empty() {
+        escape_html(line.content.trim_end_matches(['\n', '\r']))
+    } else {
+        inline
+            .iter()
+            .filter(|c| c.tag != hidden_tag)
+            .map(|c| {
+                let text = escape_html(c.value.trim_end_matches(['\n', '\r']));
+                if c.tag == "equal" {
+                    text
+                } else {
+                    format!("<span class=\"hl\">{}</span>", text)
+                }
+            })
+            .collect()
+    };
+    format!(
+        "<td class=\"num\">{}</td><td class=\"{}\">{}</td>",
+        number.map(|n| n.to_string()).unwrap_or_default(),
+        line.tag,
+        body
+    )
+}
+
+fn generate_html_diff(old_text: &str, new_text: &str, mode: DiffMode) -> String {
+    let result = compute_diff(old_text, new_text, mode.clone());
+    let mut rows = String::new();
+    for (old, new) in side_by_side_rows(&result.lines) {
+        let inline = match (old, new) {
+            (Some(o), Some(n)) if o.tag == "delete" && n.tag == "insert" => {
+                compute_inline_diff(&o.content, &n.content, mode.clone())
+            }
+            _ => Vec::new(),
+        };
+        rows.push_str(&format!(
+            "<tr>{}{}</tr>\n",
+            html_cell(old, &inline, "old"),
+            html_cell(new, &inline, "new")
+        ));
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="UTF-8">
+<title>Diff</title>
+<style>
+body {{ font-family: -apple-system, BlinkMacSystemFont, sans-serif; margin: 16px; }}
+.summary {{ margin-bottom: 8px; font-size: 13px; }}
+.summary .add {{ color: #1a7f37; }}
+.summary .del {{ color: #cf222e; }}
+table {{ width: 100%; border-collapse: collapse; table-layout: fixed; font-family: SFMono-Regular, Menlo, Consolas, monospace; font-size: 12px; }}
+th {{ text-align: left; padding: 4px 8px; background: #f6f8fa; border: 1px solid #d0d7de; }}
+td {{ padding: 0 8px; white-space: pre-wrap; word-break: break-all; vertical-align: top; }}
+td.num {{ width: 48px; color: #6e7781; text-align: right; user-select: none; }}
+td.delete {{ background: #ffebe9; }}
+td.insert {{ background: #e6ffec; }}
+td.empty {{ background: #f6f8fa; }}
+td.delete .hl {{ background: #ff818266; }}
+td.insert .hl {{ background: #abf2bc; }}
+</style>
+</head>
+<body>
+<div class="summary"><span class="add">+{}</span> <span class="del">-{}</span></div>
+<table>
+<colgroup><col style="width: 48px"><col><col style="width: 48px"><col></colgroup>
+<tr><th colspan="2">Original</th><th colspan="2">Modified</th></tr>
+{}</table>
+</body>
+</html>
+"#,
+        result.stats.additions, result.stats.deletions, rows
+    )
+}
+
 pub fn read_text_file(path: &str) -> Result<String, String> {
     std::fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))
 }
@@ -209,6 +878,311 @@ pub fn get_file_info(path: &str) -> Result<FileInfo, String> {
     })
 }
 
+/// Emit a directory comparison progress event every this many files
+const DIR_COMPARE_PROGRESS_INTERVAL: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DirCompareMethod {
+    /// Same size and modification time (to the second) counts as identical
+    #[default]
+    SizeAndTime,
+    /// Files of the same size are compared by SHA-256 of their contents
+    Hash,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DirCompareOptions {
+    /// Globs matched against each file or directory name and its path relative to the root;
+    /// a matching directory is skipped with everything in it
+    pub ignore_patterns: Vec<String>,
+    pub method: DirCompareMethod,
+}
+
+impl Default for DirCompareOptions {
+    fn default() -> Self {
+        Self {
+            ignore_patterns: vec![".git".to_string(), "node_modules".to_string()],
+            method: DirCompareMethod::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DirEntryStatus {
+    OnlyInA,
+    OnlyInB,
+    Modified,
+    Identical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirCompareEntry {
+    /// Path relative to both roots, with `/` separators; join it to either root to get
+    /// the paths for `compute_file_diff`
+    pub relative_path: String,
+    pub status: DirEntryStatus,
+    pub size_a: Option<u64>,
+    pub size_b: Option<u64>,
+    pub modified_a: Option<String>,
+    pub modified_b: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirCompareResult {
+    pub success: bool,
+    /// Sorted by relative path
+    pub entries: Vec<DirCompareEntry>,
+    pub only_in_a: usize,
+    pub only_in_b: usize,
+    pub modified: usize,
+    pub identical: usize,
+    /// Files and directories that could not be read
+    pub skipped: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DirCompareStage {
+    ScanningA,
+    ScanningB,
+    Comparing,
+}
+
+/// Payload of the `dir-compare-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirCompareProgress {
+    pub stage: DirCompareStage,
+    pub processed: usize,
+    /// Number of files to compare; unknown (0) while scanning
+    pub total: usize,
+    pub current_file: String,
+}
+
+struct ScannedFile {
+    path: PathBuf,
+    size: u64,
+    modified: Option<SystemTime>,
+}
+
+fn build_ignore_set(patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let glob = Glob::new(pattern.trim_end_matches('/'))
+            .map_err(|e| format!("Invalid ignore pattern '{}': {}", pattern, e))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Invalid ignore patterns: {}", e))
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Collect the files under `root` by relative path, skipping anything `ignore` matches
+fn scan_directory<F>(
+    root: &Path,
+    ignore: &GlobSet,
+    stage: DirCompareStage,
+    skipped: &mut usize,
+    on_progress: &mut F,
+) -> BTreeMap<String, ScannedFile>
+where
+    F: FnMut(DirCompareProgress),
+{
+    let mut files = BTreeMap::new();
+    let walker = WalkDir::new(root).into_iter().filter_entry(|entry| {
+        entry.depth() == 0
+            || !(ignore.is_match(entry.file_name())
+                || ignore.is_match(relative_path(root, entry.path())))
+    });
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => {
+                *skipped += 1;
+                continue;
+            }
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                *skipped += 1;
+                continue;
+            }
+        };
+        files.insert(
+            relative_path(root, entry.path()),
+            ScannedFile {
+                path: entry.path().to_path_buf(),
+                size: metadata.len(),
+                modified: metadata.modified().ok(),
+            },
+        );
+        if files.len().is_multiple_of(DIR_COMPARE_PROGRESS_INTERVAL) {
+            on_progress(DirCompareProgress {
+                stage,
+                processed: files.len(),
+                total: 0,
+                current_file: entry.path().to_string_lossy().to_string(),
+            });
+        }
+    }
+    files
+}
+
+fn hash_file(path: &Path) -> io::Result<Vec<u8>> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut BufReader::new(File::open(path)?), &mut hasher)?;
+    Ok(hasher.finalize().to_vec())
+}
+
+fn unix_seconds(time: Option<SystemTime>) -> Option<u64> {
+    time.and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+}
+
+fn same_contents(a: &ScannedFile, b: &ScannedFile, method: DirCompareMethod) -> io::Result<bool> {
+    if a.size != b.size {
+        return Ok(false);
+    }
+    match method {
+        DirCompareMethod::SizeAndTime => Ok(unix_seconds(a.modified) == unix_seconds(b.modified)),
+        DirCompareMethod::Hash => Ok(hash_file(&a.path)? == hash_file(&b.path)?),
+    }
+}
+
+fn format_time(time: Option<SystemTime>) -> Option<String> {
+    time.map(|t| DateTime::<Utc>::from(t).to_rfc3339())
+}
+
+/// Compare the files under `dir_a` and `dir_b` by relative path.
+///
+/// `on_progress` is called periodically while each directory is scanned and while files
+/// present in both are compared.
+pub fn compare_directories<F>(
+    dir_a: &str,
+    dir_b: &str,
+    options: &DirCompareOptions,
+    mut on_progress: F,
+) -> DirCompareResult
+where
+    F: FnMut(DirCompareProgress),
+{
+    let fail = |error: String| DirCompareResult {
+        error: Some(error),
+        ..Default::default()
+    };
+    let (root_a, root_b) = (Path::new(dir_a), Path::new(dir_b));
+    for root in [root_a, root_b] {
+        if !root.is_dir() {
+            return fail(format!("Not a directory: {}", root.display()));
+        }
+    }
+    let ignore = match build_ignore_set(&options.ignore_patterns) {
+        Ok(ignore) => ignore,
+        Err(e) => return fail(e),
+    };
+
+    let mut result = DirCompareResult::default();
+    let mut files_a = scan_directory(
+        root_a,
+        &ignore,
+        DirCompareStage::ScanningA,
+        &mut result.skipped,
+        &mut on_progress,
+    );
+    let files_b = scan_directory(
+        root_b,
+        &ignore,
+        DirCompareStage::ScanningB,
+        &mut result.skipped,
+        &mut on_progress,
+    );
+
+    let total = files_a.keys().filter(|k| files_b.contains_key(*k)).count();
+    let mut processed = 0;
+    for (relative_path, b) in &files_b {
+        let Some(a) = files_a.remove(relative_path) else {
+            result.entries.push(DirCompareEntry {
+                relative_path: relative_path.clone(),
+                status: DirEntryStatus::OnlyInB,
+                size_a: None,
+                size_b: Some(b.size),
+                modified_a: None,
+                modified_b: format_time(b.modified),
+            });
+            continue;
+        };
+        let status = match same_contents(&a, b, options.method) {
+            Ok(true) => DirEntryStatus::Identical,
+            Ok(false) => DirEntryStatus::Modified,
+            Err(_) => {
+                result.skipped += 1;
+                DirEntryStatus::Modified
+            }
+        };
+        processed += 1;
+        if processed % DIR_COMPARE_PROGRESS_INTERVAL == 0 || processed == total {
+            on_progress(DirCompareProgress {
+                stage: DirCompareStage::Comparing,
+                processed,
+                total,
+                current_file: relative_path.clone(),
+            });
+        }
+        result.entries.push(DirCompareEntry {
+            relative_path: relative_path.clone(),
+            status,
+            size_a: Some(a.size),
+            size_b: Some(b.size),
+            modified_a: format_time(a.modified),
+            modified_b: format_time(b.modified),
+        });
+    }
+    // Whatever is left was never matched by a file in B
+    for (relative_path, a) in files_a {
+        result.entries.push(DirCompareEntry {
+            relative_path,
+            status: DirEntryStatus::OnlyInA,
+            size_a: Some(a.size),
+            size_b: None,
+            modified_a: format_time(a.modified),
+            modified_b: None,
+        });
+    }
+    result
+        .entries
+        .sort_by(|x, y| x.relative_path.cmp(&y.relative_path));
+
+    for entry in &result.entries {
+        match entry.status {
+            DirEntryStatus::OnlyInA => result.only_in_a += 1,
+            DirEntryStatus::OnlyInB => result.only_in_b += 1,
+            DirEntryStatus::Modified => result.modified += 1,
+            DirEntryStatus::Identical => result.identical += 1,
+        }
+    }
+    result.success = true;
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +1231,52 @@ mod tests {
         assert_eq!(result.stats.additions, 1);
         assert_eq!(result.stats.deletions, 1);
         assert_eq!(result.stats.unchanged, 2);
+        assert_eq!(result.stats.modifications, 1);
+        assert!(result.lines.iter().all(|l| l.inline_spans.is_empty()));
+    }
+
+    #[test]
+    fn test_inline_spans_word_and_character() {
+        let result = compute_diff(
+            "the quick fox\nsame\n",
+            "the slow fox\nsame\n",
+            DiffMode::Word,
+        );
+        let spans = |tag: &str| {
+            result
+                .lines
+                .iter()
+                .find(|l| l.tag == tag)
+                .unwrap()
+                .inline_spans
+                .clone()
+        };
+        assert_eq!(
+            spans("delete"),
+            vec![InlineSpan {
+                start: 4,
+                end: 9,
+                kind: InlineSpanKind::Delete
+            }]
+        );
+        assert_eq!(
+            spans("insert"),
+            vec![InlineSpan {
+                start: 4,
+                end: 8,
+                kind: InlineSpanKind::Insert
+            }]
+        );
+
+        // Japanese has no spaces, so word mode highlights single characters
+        let (old, new) = compute_inline_spans("今日は晴れ", "今日は雨", &DiffMode::Word);
+        assert_eq!((old[0].start, old[0].end), (3, 5));
+        assert_eq!((new[0].start, new[0].end), (3, 4));
+
+        // Offsets count UTF-16 units, so the emoji before the change takes two
+        let (old, new) = compute_inline_spans("😀 a\n", "😀 b\n", &DiffMode::Character);
+        assert_eq!((old[0].start, old[0].end), (3, 4));
+        assert_eq!((new[0].start, new[0].end), (3, 4));
     }
 
     #[test]
@@ -271,6 +1291,273 @@ mod tests {
         assert!(result.unified_diff.contains("+line3"));
     }
 
+    #[test]
+    fn test_diff_options_whitespace() {
+        let old = "fn main() {\n  call();\n}\n";
+        let new = "fn main() {\n    call();  \n}\n";
+
+        let plain = compute_diff(old, new, DiffMode::Line);
+        assert_eq!((plain.stats.additions, plain.stats.deletions), (1, 1));
+        assert_eq!(plain.stats.ignored_changes, 0);
+
+        let trailing = DiffOptions {
+            ignore_whitespace: WhitespaceMode::Trailing,
+            ..Default::default()
+        };
+        let result = compute_diff_with(old, new, DiffMode::Line, &trailing);
+        assert_eq!(result.stats.additions, 1);
+
+        let all = DiffOptions {
+            ignore_whitespace: WhitespaceMode::All,
+            ..Default::default()
+        };
+        let result = compute_diff_with(old, new, DiffMode::Line, &all);
+        assert_eq!((result.stats.additions, result.stats.deletions), (0, 0));
+        assert_eq!(result.stats.unchanged, 3);
+        assert_eq!(result.stats.ignored_changes, 1);
+        // Both sides keep their original text
+        let line = &result.lines[1];
+        assert_eq!(line.tag, "equal");
+        assert!(line.ignored);
+        assert_eq!(line.content, "  call();\n");
+        assert_eq!(line.new_content.as_deref(), Some("    call();  \n"));
+        assert_eq!(
+            (line.line_number_old, line.line_number_new),
+            (Some(2), Some(2))
+        );
+        assert!(!result.lines[0].ignored && result.lines[0].new_content.is_none());
+        // The patch still describes the real change
+        assert!(result.unified_diff.contains("+    call();  "));
+    }
+
+    #[test]
+    fn test_diff_options_case_and_leading() {
+        let options = DiffOptions {
+            ignore_whitespace: WhitespaceMode::Leading,
+            ignore_case: true,
+            ..Default::default()
+        };
+        let result = compute_diff_with(
+            "SELECT 1\n\tFROM t\nWHERE a\n",
+            "select 1\nfrom t  \nwhere b\n",
+            DiffMode::Word,
+            &options,
+        );
+        let tags: Vec<(&str, bool)> = result
+            .lines
+            .iter()
+            .map(|l| (l.tag.as_str(), l.ignored))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                ("equal", true),
+                ("delete", false),
+                ("delete", false),
+                ("insert", false),
+                ("insert", false),
+            ]
+        );
+        assert_eq!(result.stats.ignored_changes, 1);
+        // Real modifications still get inline highlights from the original text
+        assert_eq!(result.stats.modifications, 2);
+        assert!(!result.lines[2].inline_spans.is_empty());
+    }
+
+    #[test]
+    fn test_diff_options_blank_lines() {
+        let options = DiffOptions {
+            ignore_blank_lines: true,
+            ..Default::default()
+        };
+        let old = "a\n\nb\nc\n";
+        let new = "a\nb\n\n\nc\nd\n";
+        let result = compute_diff_with(old, new, DiffMode::Line, &options);
+        let summary: Vec<(&str, &str, bool)> = result
+            .lines
+            .iter()
+            .map(|l| (l.tag.as_str(), l.content.as_str(), l.ignored))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("equal", "a\n", false),
+                ("delete", "\n", true),
+                ("equal", "b\n", false),
+                ("insert", "\n", true),
+                ("insert", "\n", true),
+                ("equal", "c\n", false),
+                ("insert", "d\n", false),
+            ]
+        );
+        assert_eq!(result.stats.additions, 1);
+        assert_eq!(result.stats.deletions, 0);
+        assert_eq!(result.stats.ignored_changes, 3);
+
+        // Blank lines on both sides pair up as equal lines
+        let result = compute_diff_with("x\n\ny\n", "x\n  \nz\n", DiffMode::Line, &options);
+        assert_eq!(result.lines[1].tag, "equal");
+        assert_eq!(result.lines[1].new_content.as_deref(), Some("  \n"));
+        assert_eq!((result.stats.additions, result.stats.deletions), (1, 1));
+    }
+
+    #[test]
+    fn test_default_options_match_line_diff() {
+        let old = "one\r\ntwo\rthree\nfour";
+        let new = "one\r\n2\rthree\nfour\n";
+        let result = compute_diff(old, new, DiffMode::Line);
+        let expected: Vec<(String, String)> = TextDiff::from_lines(old, new)
+            .iter_all_changes()
+            .map(|c| {
+                (
+                    format!("{:?}", c.tag()).to_lowercase(),
+                    c.value().to_string(),
+                )
+            })
+            .collect();
+        let actual: Vec<(String, String)> = result
+            .lines
+            .iter()
+            .map(|l| (l.tag.clone(), l.content.clone()))
+            .collect();
+        assert_eq!(actual, expected);
+        assert!(result.lines.iter().all(|l| !l.ignored));
+    }
+
+    #[test]
+    fn test_compute_file_diff_text_and_binary() {
+        let dir = std::env::temp_dir();
+        let old = dir.join("taurin_file_diff_old.txt");
+        let new = dir.join("taurin_file_diff_new.txt");
+        let bin = dir.join("taurin_file_diff.bin");
+        std::fs::write(&old, "a\nb\n").unwrap();
+        std::fs::write(&new, "a\nc\n").unwrap();
+        std::fs::write(&bin, [0x89, b'P', b'N', b'G', 0, 1, 2]).unwrap();
+
+        let options = DiffOptions::default();
+        let text = compute_file_diff(
+            old.to_str().unwrap(),
+            new.to_str().unwrap(),
+            DiffMode::Line,
+            &options,
+        )
+        .unwrap();
+        let binary = compute_file_diff(
+            old.to_str().unwrap(),
+            bin.to_str().unwrap(),
+            DiffMode::Line,
+            &options,
+        )
+        .unwrap();
+        let missing = compute_file_diff(
+            "/nonexistent/a",
+            old.to_str().unwrap(),
+            DiffMode::Line,
+            &options,
+        );
+        for path in [&old, &new, &bin] {
+            let _ = std::fs::remove_file(path);
+        }
+
+        assert!(!text.is_binary);
+        assert_eq!(text.stats.additions, 1);
+        assert!(binary.is_binary);
+        assert!(binary.lines.is_empty());
+        assert!(binary.unified_diff.contains("differ"));
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_three_way_diff_merges_non_conflicting_changes() {
+        let base = "a\nb\nc\nd\n";
+        let ours = "a\nB\nc\nd\n";
+        let theirs = "a\nb\nc\nD\n";
+        let result = compute_three_way_diff(base, ours, theirs, DiffMode::Line);
+
+        assert_eq!(result.conflict_count, 0);
+        let kinds: Vec<&str> = result.hunks.iter().map(|h| h.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["equal", "ours", "equal", "theirs"]);
+        assert_eq!(result.merged, "a\nB\nc\nD\n");
+    }
+
+    #[test]
+    fn test_three_way_diff_reports_conflicts() {
+        let base = "a\nb\nc\n";
+        let ours = "a\nours\nc\n";
+        let theirs = "a\ntheirs\nc\n";
+        let result = compute_three_way_diff(base, ours, theirs, DiffMode::Word);
+
+        assert_eq!(result.conflict_count, 1);
+        let conflict = result.hunks.iter().find(|h| h.conflict).unwrap();
+        assert_eq!(conflict.kind, "conflict");
+        assert_eq!(conflict.base_start, 2);
+        assert_eq!(conflict.ours_lines, vec!["ours\n"]);
+        assert_eq!(conflict.theirs_lines, vec!["theirs\n"]);
+        assert!(!conflict.inline_changes.is_empty());
+        assert_eq!(
+            result.merged,
+            "a\n<<<<<<< ours\nours\n=======\ntheirs\n>>>>>>> theirs\nc\n"
+        );
+
+        let same = compute_three_way_diff(base, ours, ours, DiffMode::Line);
+        assert_eq!(same.conflict_count, 0);
+        assert!(same.hunks.iter().any(|h| h.kind == "both"));
+    }
+
+    #[test]
+    fn test_export_unified_patch_hunk_headers() {
+        let old: String = (1..=20).map(|i| format!("line{}\n", i)).collect();
+        let new = old.replace("line5\n", "changed5\n").replace("line15\n", "");
+
+        let result = export_diff(
+            &old,
+            &new,
+            DiffMode::Line,
+            DiffExportFormat::UnifiedPatch,
+            None,
+            None,
+        );
+        let patch = result.content.unwrap();
+        assert!(patch.starts_with("--- a/original\n+++ b/modified\n"));
+        assert!(patch.contains("@@ -2,7 +2,7 @@\n"), "{}", patch);
+        assert!(patch.contains("@@ -12,7 +12,6 @@\n"), "{}", patch);
+
+        let narrow = export_diff(
+            &old,
+            &new,
+            DiffMode::Line,
+            DiffExportFormat::UnifiedPatch,
+            None,
+            Some(1),
+        )
+        .content
+        .unwrap();
+        assert!(narrow.contains("@@ -4,3 +4,3 @@\n"), "{}", narrow);
+        assert!(narrow.contains("@@ -14,3 +14,2 @@\n"), "{}", narrow);
+        assert!(narrow.contains(" line4\n-line5\n+changed5\n line6\n"));
+    }
+
+    #[test]
+    fn test_export_html_escapes_and_writes_file() {
+        let path = std::env::temp_dir().join("taurin_diff_export.html");
+        let result = export_diff(
+            "a\n<b>\n",
+            "a\n<c>\n",
+            DiffMode::Character,
+            DiffExportFormat::Html,
+            Some(path.to_string_lossy().to_string()),
+            None,
+        );
+        let html = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(result.success);
+        assert!(result.content.is_none());
+        assert!(html.contains("&lt;<span class=\"hl\">b</span>&gt;"));
+        assert!(html.contains("&lt;<span class=\"hl\">c</span>&gt;"));
+        assert!(!html.contains("<b>"));
+    }
+
     #[test]
     fn test_empty_texts() {
         let result = compute_diff("", "", DiffMode::Line);
@@ -280,4 +1567,133 @@ mod tests {
         assert_eq!(result.stats.deletions, 0);
         assert_eq!(result.stats.unchanged, 0);
     }
+
+    fn dir_compare_fixture(name: &str) -> (PathBuf, PathBuf) {
+        let root = std::env::temp_dir().join(format!("taurin_dir_compare_{}", name));
+        let _ = std::fs::remove_dir_all(&root);
+        let (a, b) = (root.join("a"), root.join("b"));
+        for (dir, files) in [
+            (
+                &a,
+                vec![
+                    ("same.txt", "same"),
+                    ("sub/changed.txt", "old"),
+                    ("sub/resized.txt", "short"),
+                    ("only_a.txt", "a"),
+                    (".git/HEAD", "ref: a"),
+                    ("node_modules/pkg/index.js", "a"),
+                    ("debug.log", "a"),
+                ],
+            ),
+            (
+                &b,
+                vec![
+                    ("same.txt", "same"),
+                    ("sub/changed.txt", "new"),
+                    ("sub/resized.txt", "much longer"),
+                    ("sub/deep/only_b.txt", "b"),
+                    (".git/HEAD", "ref: b"),
+                    ("debug.log", "b"),
+                ],
+            ),
+        ] {
+            for (path, content) in files {
+                let path = dir.join(path);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(path, content).unwrap();
+            }
+        }
+        (a, b)
+    }
+
+    #[test]
+    fn test_compare_directories_by_hash() {
+        let (a, b) = dir_compare_fixture("hash");
+        let options = DirCompareOptions {
+            ignore_patterns: vec![
+                ".git".to_string(),
+                "node_modules".to_string(),
+                "*.log".to_string(),
+            ],
+            method: DirCompareMethod::Hash,
+        };
+        let mut events = Vec::new();
+        let result = compare_directories(
+            a.to_str().unwrap(),
+            b.to_str().unwrap(),
+            &options,
+            |progress| events.push(progress),
+        );
+        assert!(result.success);
+        let statuses: Vec<(&str, DirEntryStatus)> = result
+            .entries
+            .iter()
+            .map(|e| (e.relative_path.as_str(), e.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("only_a.txt", DirEntryStatus::OnlyInA),
+                ("same.txt", DirEntryStatus::Identical),
+                ("sub/changed.txt", DirEntryStatus::Modified),
+                ("sub/deep/only_b.txt", DirEntryStatus::OnlyInB),
+                ("sub/resized.txt", DirEntryStatus::Modified),
+            ]
+        );
+        assert_eq!(
+            (
+                result.only_in_a,
+                result.only_in_b,
+                result.modified,
+                result.identical
+            ),
+            (1, 1, 2, 1)
+        );
+        // The last comparison always reports, so the UI can reach 100%
+        let last = events.last().unwrap();
+        assert_eq!(last.stage, DirCompareStage::Comparing);
+        assert_eq!((last.processed, last.total), (3, 3));
+        let _ = std::fs::remove_dir_all(a.parent().unwrap());
+    }
+
+    #[test]
+    fn test_compare_directories_options() {
+        let (a, b) = dir_compare_fixture("options");
+        let result = compare_directories(
+            a.to_str().unwrap(),
+            b.to_str().unwrap(),
+            &DirCompareOptions::default(),
+            |_| {},
+        );
+        // Without the *.log pattern the log files are compared too
+        let log = result
+            .entries
+            .iter()
+            .find(|e| e.relative_path == "debug.log")
+            .unwrap();
+        assert_eq!(log.size_a, Some(1));
+        assert!(!result
+            .entries
+            .iter()
+            .any(|e| e.relative_path.starts_with(".git")));
+
+        let invalid = DirCompareOptions {
+            ignore_patterns: vec!["[".to_string()],
+            ..Default::default()
+        };
+        let result =
+            compare_directories(a.to_str().unwrap(), b.to_str().unwrap(), &invalid, |_| {});
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Invalid ignore pattern"));
+
+        let missing = a.join("missing");
+        let result = compare_directories(
+            missing.to_str().unwrap(),
+            b.to_str().unwrap(),
+            &DirCompareOptions::default(),
+            |_| {},
+        );
+        assert!(result.error.unwrap().contains("Not a directory"));
+        let _ = std::fs::remove_dir_all(a.parent().unwrap());
+    }
 }
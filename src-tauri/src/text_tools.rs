@@ -0,0 +1,316 @@
+use crate::dummy_data_generator::MAX_RANDOM_SEED;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SortMode {
+    /// Plain string order
+    Lexical,
+    /// Digit runs compared as numbers: file2 < file10
+    Natural,
+    /// By the number at the start of each line; lines without one go last
+    Numeric,
+    /// By character count
+    Length,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum LineOperation {
+    Sort { mode: SortMode, descending: bool },
+    Dedup { ignore_case: bool },
+    Reverse,
+    Shuffle { seed: Option<u64> },
+    Trim,
+    RemoveEmpty,
+    AddLineNumbers { start: i64, separator: String },
+    AddPrefix(String),
+    AddSuffix(String),
+    RemovePrefix(String),
+    RemoveSuffix(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineProcessResult {
+    pub output: String,
+    pub input_line_count: usize,
+    pub output_line_count: usize,
+    /// Lines dropped by Dedup, in the order they were removed
+    pub removed_duplicates: Vec<String>,
+    /// Seed used by the last Shuffle, so a random shuffle can be replayed
+    pub shuffle_seed: Option<u64>,
+}
+
+/// Compare two strings treating runs of ASCII digits as numbers.
+///
+/// Text runs are compared case-insensitively first so `File2` and `file10` still sort
+/// naturally; the plain string order breaks remaining ties.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut left = a.chars().peekable();
+    let mut right = b.chars().peekable();
+
+    loop {
+        match (left.peek().copied(), right.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let l_num = take_digits(&mut left);
+                let r_num = take_digits(&mut right);
+                let l_trimmed = l_num.trim_start_matches('0');
+                let r_trimmed = r_num.trim_start_matches('0');
+                let ordering = l_trimmed
+                    .len()
+                    .cmp(&r_trimmed.len())
+                    .then_with(|| l_trimmed.cmp(r_trimmed));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(l), Some(r)) => {
+                let ordering = l.to_lowercase().cmp(r.to_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                left.next();
+                right.next();
+            }
+        }
+    }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+    digits
+}
+
+/// The number a line starts with, ignoring leading whitespace and thousands separators.
+fn leading_number(line: &str) -> Option<f64> {
+    let trimmed = line.trim_start();
+    let end = trimmed
+        .char_indices()
+        .find(|&(i, c)| {
+            !(c.is_ascii_digit() || c == '.' || c == ',' || (i == 0 && (c == '-' || c == '+')))
+        })
+        .map_or(trimmed.len(), |(i, _)| i);
+    trimmed[..end].replace(',', "").parse().ok()
+}
+
+fn sort_lines(lines: &mut [String], mode: SortMode, descending: bool) {
+    let compare = |a: &String, b: &String| match mode {
+        SortMode::Lexical => a.cmp(b),
+        SortMode::Natural => natural_cmp(a, b),
+        SortMode::Numeric => match (leading_number(a), leading_number(b)) {
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+        SortMode::Length => a.chars().count().cmp(&b.chars().count()),
+    };
+    if descending {
+        lines.sort_by(|a, b| compare(b, a));
+    } else {
+        lines.sort_by(compare);
+    }
+}
+
+/// Apply `operations` to the lines of `input` one after another.
+pub fn process_lines(input: &str, operations: Vec<LineOperation>) -> LineProcessResult {
+    let mut lines: Vec<String> = input.lines().map(str::to_string).collect();
+    let input_line_count = lines.len();
+    let mut removed_duplicates = Vec::new();
+    let mut shuffle_seed = None;
+
+    for operation in operations {
+        match operation {
+            LineOperation::Sort { mode, descending } => sort_lines(&mut lines, mode, descending),
+            LineOperation::Dedup { ignore_case } => {
+                let mut seen = HashSet::new();
+                lines.retain(|line| {
+                    let key = if ignore_case {
+                        line.to_lowercase()
+                    } else {
+                        line.clone()
+                    };
+                    if seen.insert(key) {
+                        true
+                    } else {
+                        removed_duplicates.push(line.clone());
+                        false
+                    }
+                });
+            }
+            LineOperation::Reverse => lines.reverse(),
+            LineOperation::Shuffle { seed } => {
+                let seed =
+                    seed.unwrap_or_else(|| rand::thread_rng().gen_range(0..=MAX_RANDOM_SEED));
+                lines.shuffle(&mut StdRng::seed_from_u64(seed));
+                shuffle_seed = Some(seed);
+            }
+            LineOperation::Trim => {
+                for line in &mut lines {
+                    *line = line.trim().to_string();
+                }
+            }
+            LineOperation::RemoveEmpty => lines.retain(|line| !line.trim().is_empty()),
+            LineOperation::AddLineNumbers { start, separator } => {
+                let last = start + lines.len().saturating_sub(1) as i64;
+                let width = start.to_string().len().max(last.to_string().len());
+                for (i, line) in lines.iter_mut().enumerate() {
+                    let number = start + i as i64;
+                    *line = format!("{:>width$}{}{}", number, separator, line);
+                }
+            }
+            LineOperation::AddPrefix(prefix) => {
+                for line in &mut lines {
+                    line.insert_str(0, &prefix);
+                }
+            }
+            LineOperation::AddSuffix(suffix) => {
+                for line in &mut lines {
+                    line.push_str(&suffix);
+                }
+            }
+            LineOperation::RemovePrefix(prefix) => {
+                for line in &mut lines {
+                    if let Some(rest) = line.strip_prefix(prefix.as_str()) {
+                        *line = rest.to_string();
+                    }
+                }
+            }
+            LineOperation::RemoveSuffix(suffix) => {
+                for line in &mut lines {
+                    if let Some(rest) = line.strip_suffix(suffix.as_str()) {
+                        *line = rest.to_string();
+                    }
+                }
+            }
+        }
+    }
+
+    LineProcessResult {
+        output: lines.join("\n"),
+        input_line_count,
+        output_line_count: lines.len(),
+        removed_duplicates,
+        shuffle_seed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(input: &str, mode: SortMode, descending: bool) -> String {
+        process_lines(input, vec![LineOperation::Sort { mode, descending }]).output
+    }
+
+    #[test]
+    fn test_natural_sort() {
+        let input = "file10.txt\nfile2.txt\nfile1.txt\nFile3.txt\nfile02.txt";
+        assert_eq!(
+            sorted(input, SortMode::Natural, false),
+            "file1.txt\nfile02.txt\nfile2.txt\nFile3.txt\nfile10.txt"
+        );
+        assert_eq!(
+            sorted(input, SortMode::Lexical, false),
+            "File3.txt\nfile02.txt\nfile1.txt\nfile10.txt\nfile2.txt"
+        );
+        assert_eq!(natural_cmp("v1.10", "v1.9"), Ordering::Greater);
+        assert_eq!(natural_cmp("a", "a1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_numeric_and_length_sort() {
+        let input = "10 apples\n-3 debt\nnone\n2.5 kg\n1,000 yen";
+        assert_eq!(
+            sorted(input, SortMode::Numeric, false),
+            "-3 debt\n2.5 kg\n10 apples\n1,000 yen\nnone"
+        );
+        assert_eq!(sorted("ccc\na\nbb", SortMode::Length, true), "ccc\nbb\na");
+        // Equal keys keep their original order
+        assert_eq!(sorted("bb\naa\nc", SortMode::Length, false), "c\nbb\naa");
+    }
+
+    #[test]
+    fn test_dedup() {
+        let input = "Apple\nbanana\napple\nbanana\nAPPLE";
+        let result = process_lines(input, vec![LineOperation::Dedup { ignore_case: false }]);
+        assert_eq!(result.output, "Apple\nbanana\napple\nAPPLE");
+        assert_eq!(result.removed_duplicates, ["banana"]);
+
+        let result = process_lines(input, vec![LineOperation::Dedup { ignore_case: true }]);
+        assert_eq!(result.output, "Apple\nbanana");
+        assert_eq!(result.removed_duplicates, ["apple", "banana", "APPLE"]);
+        assert_eq!(result.input_line_count, 5);
+        assert_eq!(result.output_line_count, 2);
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let input = "  - b  \n\n  - a\n - b\n";
+        let result = process_lines(
+            input,
+            vec![
+                LineOperation::Trim,
+                LineOperation::RemoveEmpty,
+                LineOperation::RemovePrefix("- ".to_string()),
+                LineOperation::Dedup { ignore_case: false },
+                LineOperation::Sort {
+                    mode: SortMode::Lexical,
+                    descending: false,
+                },
+                LineOperation::AddSuffix(";".to_string()),
+                LineOperation::AddLineNumbers {
+                    start: 9,
+                    separator: ". ".to_string(),
+                },
+            ],
+        );
+        assert_eq!(result.output, " 9. a;\n10. b;");
+        assert_eq!(result.input_line_count, 4);
+        assert_eq!(result.output_line_count, 2);
+    }
+
+    #[test]
+    fn test_shuffle_is_reproducible() {
+        let input = (1..=20)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let shuffle = |seed| process_lines(&input, vec![LineOperation::Shuffle { seed }]);
+
+        let a = shuffle(Some(7));
+        assert_eq!(a.output, shuffle(Some(7)).output);
+        assert_eq!(a.shuffle_seed, Some(7));
+        assert_ne!(a.output, input);
+
+        let random = shuffle(None);
+        assert!(random.shuffle_seed.unwrap() <= MAX_RANDOM_SEED);
+        assert_eq!(random.output, shuffle(random.shuffle_seed).output);
+    }
+
+    #[test]
+    fn test_reverse_and_affixes() {
+        let result = process_lines(
+            "a.rs\nb.rs\nc.txt",
+            vec![
+                LineOperation::Reverse,
+                LineOperation::RemoveSuffix(".rs".to_string()),
+                LineOperation::AddPrefix("src/".to_string()),
+            ],
+        );
+        assert_eq!(result.output, "src/c.txt\nsrc/b\nsrc/a");
+        assert_eq!(
+            process_lines("", vec![LineOperation::Reverse]).input_line_count,
+            0
+        );
+    }
+}
@@ -0,0 +1,688 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeTarget {
+    /// `export interface` declarations
+    TypeScript,
+    /// Structs deriving serde's `Serialize` and `Deserialize`
+    Rust,
+    /// Structs with `json` tags
+    Go,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum OptionalRule {
+    /// A field is optional when some sampled objects do not have it
+    #[default]
+    Missing,
+    /// A field is optional when it is missing or null in some sample
+    MissingOrNull,
+    /// Every field is optional
+    Always,
+    /// No field is optional; a missing field is typed as nullable instead
+    Never,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TypeGenOptions {
+    /// Name of the type generated for the whole document
+    pub root_name: String,
+    /// Name TypeScript properties in camelCase instead of the JSON keys as written.
+    /// Rust and Go fields always follow their own conventions and keep the JSON key in
+    /// a serde attribute or `json` tag.
+    pub camel_case: bool,
+    pub optional_rule: OptionalRule,
+}
+
+impl Default for TypeGenOptions {
+    fn default() -> Self {
+        Self {
+            root_name: "Root".to_string(),
+            camel_case: false,
+            optional_rule: OptionalRule::Missing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeGenResult {
+    pub success: bool,
+    /// All generated declarations, ready to paste
+    pub code: String,
+    pub type_count: usize,
+    pub error: Option<String>,
+}
+
+/// Type of a value, merged over every sample seen at one position
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    /// Only nulls (or nothing, for elements of empty arrays) were seen
+    Unknown,
+    Bool,
+    Int,
+    Float,
+    String,
+    Array(Box<Shape>),
+    Object(Vec<Field>),
+    /// Samples of incompatible types
+    Any,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Shape {
+    kind: Kind,
+    nullable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Field {
+    key: String,
+    shape: Shape,
+    /// Not present in every sampled object
+    missing: bool,
+}
+
+fn infer(value: &Value) -> Shape {
+    let kind = match value {
+        Value::Null => Kind::Unknown,
+        Value::Bool(_) => Kind::Bool,
+        Value::Number(n) if n.is_f64() => Kind::Float,
+        Value::Number(_) => Kind::Int,
+        Value::String(_) => Kind::String,
+        Value::Array(items) => Kind::Array(Box::new(
+            items.iter().map(infer).reduce(merge).unwrap_or(Shape {
+                kind: Kind::Unknown,
+                nullable: false,
+            }),
+        )),
+        Value::Object(map) => Kind::Object(
+            map.iter()
+                .map(|(key, value)| Field {
+                    key: key.clone(),
+                    shape: infer(value),
+                    missing: false,
+                })
+                .collect(),
+        ),
+    };
+    Shape {
+        kind,
+        nullable: value.is_null(),
+    }
+}
+
+fn merge(a: Shape, b: Shape) -> Shape {
+    let nullable = a.nullable || b.nullable;
+    let kind = match (a.kind, b.kind) {
+        (Kind::Unknown, kind) | (kind, Kind::Unknown) => kind,
+        (Kind::Int, Kind::Float) | (Kind::Float, Kind::Int) => Kind::Float,
+        (Kind::Array(a), Kind::Array(b)) => Kind::Array(Box::new(merge(*a, *b))),
+        (Kind::Object(a), Kind::Object(b)) => Kind::Object(merge_fields(a, b)),
+        (a, b) if a == b => a,
+        _ => Kind::Any,
+    };
+    Shape { kind, nullable }
+}
+
+fn merge_fields(a: Vec<Field>, b: Vec<Field>) -> Vec<Field> {
+    let mut b: Vec<Option<Field>> = b.into_iter().map(Some).collect();
+    let mut merged: Vec<Field> = a
+        .into_iter()
+        .map(|field| {
+            let other = b
+                .iter_mut()
+                .find(|other| other.as_ref().is_some_and(|o| o.key == field.key))
+                .and_then(Option::take);
+            match other {
+                Some(other) => Field {
+                    key: field.key,
+                    shape: merge(field.shape, other.shape),
+                    missing: field.missing || other.missing,
+                },
+                None => Field {
+                    missing: true,
+                    ..field
+                },
+            }
+        })
+        .collect();
+    merged.extend(b.into_iter().flatten().map(|field| Field {
+        missing: true,
+        ..field
+    }));
+    merged
+}
+
+/// Words of a key, split at `_`, `-`, spaces and lower-to-upper case changes
+fn split_words(key: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+    for c in key.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let lower = word.to_lowercase();
+    let mut chars = lower.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn to_pascal_case(key: &str) -> String {
+    split_words(key).iter().map(|w| capitalize(w)).collect()
+}
+
+fn to_camel_case(key: &str) -> String {
+    let pascal = to_pascal_case(key);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn to_snake_case(key: &str) -> String {
+    split_words(key)
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Go names spell these initialisms in capitals (`UserID`, `APIURL`)
+const GO_INITIALISMS: [&str; 12] = [
+    "api", "css", "html", "http", "https", "id", "ip", "json", "sql", "uri", "url", "uuid",
+];
+
+fn to_go_name(key: &str) -> String {
+    split_words(key)
+        .iter()
+        .map(|word| {
+            let lower = word.to_lowercase();
+            if GO_INITIALISMS.contains(&lower.as_str()) {
+                lower.to_uppercase()
+            } else {
+                capitalize(word)
+            }
+        })
+        .collect()
+}
+
+/// Prefix names that would start with a digit or be empty
+fn identifier(name: String, prefix: &str) -> String {
+    if name.chars().next().is_none_or(|c| c.is_ascii_digit()) {
+        format!("{}{}", prefix, name)
+    } else {
+        name
+    }
+}
+
+/// Written as raw identifiers (`r#type`)
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use",
+    "where", "while",
+];
+/// Keywords that cannot be raw identifiers, so they get a trailing underscore
+const RUST_RESERVED: &[&str] = &["crate", "self", "super"];
+
+fn rust_field_name(key: &str) -> String {
+    let name = identifier(to_snake_case(key), "field_");
+    if RUST_RESERVED.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+fn is_ts_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Crude singular form used to name array element types (`orders` -> `order`)
+fn singular(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies").filter(|s| !s.is_empty()) {
+        format!("{}y", stem)
+    } else if name.len() > 3 && name.ends_with('s') && !name.ends_with("ss") {
+        name[..name.len() - 1].to_string()
+    } else {
+        format!("{}_item", name)
+    }
+}
+
+/// One struct or interface to write out, with the type expression of each field
+struct Declaration {
+    name: String,
+    fields: Vec<(Field, String)>,
+}
+
+/// Collects the object types of a shape under unique names
+struct Generator<'a> {
+    target: TypeTarget,
+    options: &'a TypeGenOptions,
+    declarations: Vec<Declaration>,
+    names: HashSet<String>,
+}
+
+impl Generator<'_> {
+    fn unique_name(&mut self, hint: &str) -> String {
+        // Names already written in PascalCase, like most root names, are kept as they are
+        let base = if hint.starts_with(|c: char| c.is_ascii_uppercase())
+            && hint.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            hint.to_string()
+        } else {
+            identifier(to_pascal_case(hint), "Type")
+        };
+        let mut name = base.clone();
+        let mut n = 2;
+        while !self.names.insert(name.clone()) {
+            name = format!("{}{}", base, n);
+            n += 1;
+        }
+        name
+    }
+
+    /// Type expression for `shape`, declaring the object types in it; an object is
+    /// declared before the types of its fields
+    fn type_expr(&mut self, shape: &Shape, name_hint: &str) -> String {
+        let inner = match &shape.kind {
+            Kind::Object(fields) => {
+                let name = self.unique_name(name_hint);
+                let index = self.declarations.len();
+                self.declarations.push(Declaration {
+                    name: name.clone(),
+                    fields: vec![],
+                });
+                let fields = fields
+                    .iter()
+                    .map(|field| (field.clone(), self.field_type(field)))
+                    .collect();
+                self.declarations[index].fields = fields;
+                name
+            }
+            Kind::Array(element) => {
+                let element_type = self.type_expr(element, &singular(name_hint));
+                self.array_of(element_type)
+            }
+            kind => self.scalar(kind).to_string(),
+        };
+        if shape.nullable {
+            self.nullable(inner, &shape.kind)
+        } else {
+            inner
+        }
+    }
+
+    fn array_of(&self, element_type: String) -> String {
+        match self.target {
+            TypeTarget::TypeScript if element_type.contains(' ') => {
+                format!("({})[]", element_type)
+            }
+            TypeTarget::TypeScript => format!("{}[]", element_type),
+            TypeTarget::Rust => format!("Vec<{}>", element_type),
+            TypeTarget::Go => format!("[]{}", element_type),
+        }
+    }
+
+    fn scalar(&self, kind: &Kind) -> &'static str {
+        match (self.target, kind) {
+            (TypeTarget::TypeScript, Kind::Bool) => "boolean",
+            (TypeTarget::TypeScript, Kind::Int | Kind::Float) => "number",
+            (TypeTarget::TypeScript, Kind::String) => "string",
+            (TypeTarget::TypeScript, _) => "unknown",
+            (TypeTarget::Rust, Kind::Bool) => "bool",
+            (TypeTarget::Rust, Kind::Int) => "i64",
+            (TypeTarget::Rust, Kind::Float) => "f64",
+            (TypeTarget::Rust, Kind::String) => "String",
+            (TypeTarget::Rust, _) => "serde_json::Value",
+            (TypeTarget::Go, Kind::Bool) => "bool",
+            (TypeTarget::Go, Kind::Int) => "int64",
+            (TypeTarget::Go, Kind::Float) => "float64",
+            (TypeTarget::Go, Kind::String) => "string",
+            (TypeTarget::Go, _) => "any",
+        }
+    }
+
+    fn nullable(&self, inner: String, kind: &Kind) -> String {
+        match self.target {
+            TypeTarget::TypeScript if *kind == Kind::Unknown => "null".to_string(),
+            TypeTarget::TypeScript if *kind == Kind::Any => inner,
+            TypeTarget::TypeScript => format!("{} | null", inner),
+            TypeTarget::Rust => format!("Option<{}>", inner),
+            // Slices and `any` can already be nil
+            TypeTarget::Go if matches!(kind, Kind::Array(_) | Kind::Any | Kind::Unknown) => inner,
+            TypeTarget::Go => format!("*{}", inner),
+        }
+    }
+
+    fn is_optional(&self, field: &Field) -> bool {
+        match self.options.optional_rule {
+            OptionalRule::Missing => field.missing,
+            OptionalRule::MissingOrNull => field.missing || field.shape.nullable,
+            OptionalRule::Always => true,
+            OptionalRule::Never => false,
+        }
+    }
+
+    fn field_type(&mut self, field: &Field) -> String {
+        let mut shape = field.shape.clone();
+        // Without optional fields, a field some objects lack can only be typed as nullable
+        if field.missing && self.options.optional_rule == OptionalRule::Never {
+            shape.nullable = true;
+        }
+        let expr = self.type_expr(&shape, &field.key);
+        // TypeScript marks optional fields on the name; Rust and Go need a type that can
+        // be left empty
+        if self.is_optional(field) && !shape.nullable && self.target != TypeTarget::TypeScript {
+            self.nullable(expr, &shape.kind)
+        } else {
+            expr
+        }
+    }
+
+    fn render(&self) -> String {
+        let blocks: Vec<String> = self
+            .declarations
+            .iter()
+            .map(|declaration| match self.target {
+                TypeTarget::TypeScript => self.render_typescript(declaration),
+                TypeTarget::Rust => self.render_rust(declaration),
+                TypeTarget::Go => self.render_go(declaration),
+            })
+            .collect();
+        blocks.join("\n")
+    }
+
+    fn render_typescript(&self, declaration: &Declaration) -> String {
+        let mut out = format!("export interface {} {{\n", declaration.name);
+        for (field, ty) in &declaration.fields {
+            let name = if self.options.camel_case {
+                to_camel_case(&field.key)
+            } else {
+                field.key.clone()
+            };
+            let name = if is_ts_identifier(&name) {
+                name
+            } else {
+                serde_json::to_string(&name).unwrap_or_default()
+            };
+            let optional = if self.is_optional(field) { "?" } else { "" };
+            out.push_str(&format!("  {}{}: {};\n", name, optional, ty));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn render_rust(&self, declaration: &Declaration) -> String {
+        let names: Vec<String> = declaration
+            .fields
+            .iter()
+            .map(|(field, _)| rust_field_name(&field.key))
+            .collect();
+        // One `rename_all` covers the struct when every key is its field name in camelCase
+        let keys = || {
+            declaration
+                .fields
+                .iter()
+                .map(|(field, _)| &field.key)
+                .zip(&names)
+        };
+        let rename_all = keys().any(|(key, name)| key != name)
+            && keys().all(|(key, name)| to_camel_case(name) == *key);
+
+        let mut out = "#[derive(Debug, Clone, Serialize, Deserialize)]\n".to_string();
+        if rename_all {
+            out.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        }
+        out.push_str(&format!("pub struct {} {{\n", declaration.name));
+        for ((field, ty), name) in declaration.fields.iter().zip(&names) {
+            let mut attrs = Vec::new();
+            if !rename_all && field.key != *name {
+                attrs.push(format!(
+                    "rename = {}",
+                    serde_json::to_string(&field.key).unwrap_or_default()
+                ));
+            }
+            if self.is_optional(field) {
+                attrs.push("default, skip_serializing_if = \"Option::is_none\"".to_string());
+            }
+            if !attrs.is_empty() {
+                out.push_str(&format!("    #[serde({})]\n", attrs.join(", ")));
+            }
+            let name = if RUST_KEYWORDS.contains(&name.as_str()) {
+                format!("r#{}", name)
+            } else {
+                name.clone()
+            };
+            out.push_str(&format!("    pub {}: {},\n", name, ty));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn render_go(&self, declaration: &Declaration) -> String {
+        let names: Vec<String> = declaration
+            .fields
+            .iter()
+            .map(|(field, _)| identifier(to_go_name(&field.key), "Field"))
+            .collect();
+        // Align names and types in columns, as gofmt does
+        let name_width = names.iter().map(|n| n.chars().count()).max().unwrap_or(0);
+        let type_width = declaration
+            .fields
+            .iter()
+            .map(|(_, ty)| ty.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut out = format!("type {} struct {{\n", declaration.name);
+        for ((field, ty), name) in declaration.fields.iter().zip(&names) {
+            let omitempty = if self.is_optional(field) {
+                ",omitempty"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "\t{:name_width$} {:type_width$} `json:\"{}{}\"`\n",
+                name,
+                ty,
+                field.key.replace('"', "\\\""),
+                omitempty,
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Generate type declarations describing `input`.
+///
+/// Nested objects get their own named types. Array elements are merged across every
+/// sample, so integers mixed with floats become floats, nulls make the type nullable,
+/// and keys absent from some elements are treated per `options.optional_rule`.
+pub fn generate_types(input: &str, target: TypeTarget, options: &TypeGenOptions) -> TypeGenResult {
+    let value = match serde_json::from_str::<Value>(input) {
+        Ok(value) => value,
+        Err(e) => {
+            return TypeGenResult {
+                success: false,
+                code: String::new(),
+                type_count: 0,
+                error: Some(format!("Parse error: {}", e)),
+            }
+        }
+    };
+    let root_name = match options.root_name.trim() {
+        "" => "Root",
+        name => name,
+    };
+
+    let mut generator = Generator {
+        target,
+        options,
+        declarations: vec![],
+        names: HashSet::new(),
+    };
+    let shape = infer(&value);
+    let root_type = match &shape.kind {
+        // The elements of a root array are what the root name describes
+        Kind::Array(element) => {
+            let element_type = generator.type_expr(element, root_name);
+            generator.array_of(element_type)
+        }
+        _ => generator.type_expr(&shape, root_name),
+    };
+
+    let mut blocks = Vec::new();
+    if target == TypeTarget::Rust {
+        blocks.push("use serde::{Deserialize, Serialize};\n".to_string());
+    }
+    // A root that is not an object gets an alias, e.g. `RootList` for an array of `Root`
+    let alias = (!matches!(shape.kind, Kind::Object(_))).then(|| {
+        let alias = if generator.names.contains(root_name) {
+            generator.unique_name(&format!("{}List", root_name))
+        } else {
+            generator.unique_name(root_name)
+        };
+        match target {
+            TypeTarget::TypeScript => format!("export type {} = {};\n", alias, root_type),
+            TypeTarget::Rust => format!("pub type {} = {};\n", alias, root_type),
+            TypeTarget::Go => format!("type {} {}\n", alias, root_type),
+        }
+    });
+    let type_count = generator.declarations.len() + usize::from(alias.is_some());
+    blocks.extend(alias);
+    if !generator.declarations.is_empty() {
+        blocks.push(generator.render());
+    }
+
+    TypeGenResult {
+        success: true,
+        code: blocks.join("\n"),
+        type_count,
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"{
+        "id": 1,
+        "user_name": "alice",
+        "type": "admin",
+        "profile": {"avatarUrl": "a.png", "age": null},
+        "orders": [
+            {"orderId": 10, "total": 5, "note": "x"},
+            {"orderId": 11, "total": 7.5, "note": null, "coupon": "SAVE"}
+        ],
+        "scores": [1, null, 2.5],
+        "empty": []
+    }"#;
+
+    #[test]
+    fn test_generate_typescript() {
+        let result = generate_types(SAMPLE, TypeTarget::TypeScript, &TypeGenOptions::default());
+        assert!(result.success);
+        assert_eq!(
+            result.code,
+            "export interface Root {
+  id: number;
+  user_name: string;
+  type: string;
+  profile: Profile;
+  orders: Order[];
+  scores: (number | null)[];
+  empty: unknown[];
+}
+
+export interface Profile {
+  avatarUrl: string;
+  age: null;
+}
+
+export interface Order {
+  orderId: number;
+  total: number;
+  note: string | null;
+  coupon?: string;
+}
+"
+        );
+        assert_eq!(result.type_count, 3);
+
+        let options = TypeGenOptions {
+            root_name: "User".to_string(),
+            camel_case: true,
+            optional_rule: OptionalRule::MissingOrNull,
+        };
+        let result = generate_types(SAMPLE, TypeTarget::TypeScript, &options);
+        assert!(result.code.starts_with("export interface User {"));
+        assert!(result.code.contains("  userName: string;\n"));
+        assert!(result.code.contains("  note?: string | null;\n"));
+
+        let result = generate_types(r#"[{"a": 1}]"#, TypeTarget::TypeScript, &options);
+        assert!(result
+            .code
+            .starts_with("export type UserList = User[];\n\nexport interface User {"));
+        assert_eq!(result.type_count, 2);
+    }
+
+    #[test]
+    fn test_generate_rust_and_go() {
+        let result = generate_types(SAMPLE, TypeTarget::Rust, &TypeGenOptions::default());
+        let code = result.code;
+        assert!(code.starts_with("use serde::{Deserialize, Serialize};\n\n#[derive("));
+        assert!(code.contains("    pub r#type: String,\n"));
+        assert!(code.contains("    pub scores: Vec<Option<f64>>,\n"));
+        assert!(code.contains("    pub empty: Vec<serde_json::Value>,\n"));
+        assert!(code.contains(
+            "#[serde(rename_all = \"camelCase\")]\npub struct Profile {\n    pub avatar_url: String,\n    pub age: Option<serde_json::Value>,\n}"
+        ));
+        assert!(code.contains(
+            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub coupon: Option<String>,\n"
+        ));
+
+        let result = generate_types(SAMPLE, TypeTarget::Go, &TypeGenOptions::default());
+        assert!(result.code.contains(
+            "type Order struct {\n\tOrderID int64   `json:\"orderId\"`\n\tTotal   float64 `json:\"total\"`\n\tNote    *string `json:\"note\"`\n\tCoupon  *string `json:\"coupon,omitempty\"`\n}"
+        ));
+        assert!(result
+            .code
+            .contains("\tScores   []*float64 `json:\"scores\"`\n"));
+
+        assert!(!generate_types("{", TypeTarget::Go, &TypeGenOptions::default()).success);
+    }
+}
@@ -1,6 +1,34 @@
+use crate::http_client::{describe_error, shared_client};
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+const CURRENCY_RATES_URL: &str = "https://open.er-api.com/v6/latest/USD";
+const CURRENCY_RATES_STALE_DAYS: i64 = 7;
+/// Rates bundled with the app, per 1 USD, used until rates are fetched for the first time
+const DEFAULT_RATES_UPDATED_AT: &str = "2025-06-02T00:00:00Z";
+const DEFAULT_RATES: [(CurrencyUnit, f64); 15] = [
+    (CurrencyUnit::Usd, 1.0),
+    (CurrencyUnit::Eur, 0.879),
+    (CurrencyUnit::Jpy, 143.9),
+    (CurrencyUnit::Gbp, 0.741),
+    (CurrencyUnit::Cny, 7.19),
+    (CurrencyUnit::Krw, 1373.0),
+    (CurrencyUnit::Aud, 1.553),
+    (CurrencyUnit::Cad, 1.373),
+    (CurrencyUnit::Chf, 0.822),
+    (CurrencyUnit::Hkd, 7.845),
+    (CurrencyUnit::Sgd, 1.289),
+    (CurrencyUnit::Inr, 85.6),
+    (CurrencyUnit::Twd, 29.95),
+    (CurrencyUnit::Thb, 32.7),
+    (CurrencyUnit::Brl, 5.72),
+];
 
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum UnitCategory {
     Length,
@@ -10,6 +38,11 @@ pub enum UnitCategory {
     Time,
     Area,
     Volume,
+    Currency,
+    Speed,
+    Pressure,
+    Energy,
+    FuelEconomy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -90,11 +123,473 @@ pub enum VolumeUnit {
     Cup,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum SpeedUnit {
+    KilometerPerHour,
+    MeterPerSecond,
+    MilePerHour,
+    Knot,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum PressureUnit {
+    Pascal,
+    Kilopascal,
+    Bar,
+    Atmosphere,
+    Psi,
+    MillimeterOfMercury,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum EnergyUnit {
+    Joule,
+    Kilojoule,
+    Calorie,
+    Kilocalorie,
+    KilowattHour,
+    Btu,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum FuelEconomyUnit {
+    KilometerPerLiter,
+    LiterPer100Km,
+    MpgUs,
+    MpgUk,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CurrencyUnit {
+    Usd,
+    Eur,
+    Jpy,
+    Gbp,
+    Cny,
+    Krw,
+    Aud,
+    Cad,
+    Chf,
+    Hkd,
+    Sgd,
+    Inr,
+    Twd,
+    Thb,
+    Brl,
+}
+
+impl CurrencyUnit {
+    fn code(&self) -> &'static str {
+        match self {
+            CurrencyUnit::Usd => "USD",
+            CurrencyUnit::Eur => "EUR",
+            CurrencyUnit::Jpy => "JPY",
+            CurrencyUnit::Gbp => "GBP",
+            CurrencyUnit::Cny => "CNY",
+            CurrencyUnit::Krw => "KRW",
+            CurrencyUnit::Aud => "AUD",
+            CurrencyUnit::Cad => "CAD",
+            CurrencyUnit::Chf => "CHF",
+            CurrencyUnit::Hkd => "HKD",
+            CurrencyUnit::Sgd => "SGD",
+            CurrencyUnit::Inr => "INR",
+            CurrencyUnit::Twd => "TWD",
+            CurrencyUnit::Thb => "THB",
+            CurrencyUnit::Brl => "BRL",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RateSource {
+    Bundled,
+    Api,
+}
+
+/// Exchange rates per 1 USD
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyRates {
+    pub rates: BTreeMap<String, f64>,
+    /// RFC 3339 time the rates were published
+    pub updated_at: String,
+    pub source: RateSource,
+}
+
+impl Default for CurrencyRates {
+    fn default() -> Self {
+        Self {
+            rates: DEFAULT_RATES
+                .iter()
+                .map(|(unit, rate)| (unit.code().to_string(), *rate))
+                .collect(),
+            updated_at: DEFAULT_RATES_UPDATED_AT.to_string(),
+            source: RateSource::Bundled,
+        }
+    }
+}
+
+impl CurrencyRates {
+    fn rate(&self, unit: CurrencyUnit) -> Option<f64> {
+        self.rates
+            .get(unit.code())
+            .copied()
+            .filter(|rate| rate.is_finite() && *rate > 0.0)
+    }
+
+    pub fn is_stale_at(&self, now: DateTime<Utc>) -> bool {
+        DateTime::parse_from_rfc3339(&self.updated_at)
+            .map(|updated| {
+                now - updated.with_timezone(&Utc) >= Duration::days(CURRENCY_RATES_STALE_DAYS)
+            })
+            .unwrap_or(true)
+    }
+}
+
+/// Units that can be listed together for [`convert_to_all`]
+trait Unit: Sized + Serialize + DeserializeOwned + 'static {
+    const ALL: &'static [Self];
+
+    fn label(&self) -> &'static str;
+}
+
+impl Unit for LengthUnit {
+    const ALL: &'static [Self] = &[
+        LengthUnit::Meter,
+        LengthUnit::Centimeter,
+        LengthUnit::Millimeter,
+        LengthUnit::Kilometer,
+        LengthUnit::Inch,
+        LengthUnit::Feet,
+        LengthUnit::Yard,
+        LengthUnit::Mile,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            LengthUnit::Meter => "m",
+            LengthUnit::Centimeter => "cm",
+            LengthUnit::Millimeter => "mm",
+            LengthUnit::Kilometer => "km",
+            LengthUnit::Inch => "in",
+            LengthUnit::Feet => "ft",
+            LengthUnit::Yard => "yd",
+            LengthUnit::Mile => "mi",
+        }
+    }
+}
+
+impl Unit for WeightUnit {
+    const ALL: &'static [Self] = &[
+        WeightUnit::Kilogram,
+        WeightUnit::Gram,
+        WeightUnit::Milligram,
+        WeightUnit::Pound,
+        WeightUnit::Ounce,
+        WeightUnit::Ton,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            WeightUnit::Kilogram => "kg",
+            WeightUnit::Gram => "g",
+            WeightUnit::Milligram => "mg",
+            WeightUnit::Pound => "lb",
+            WeightUnit::Ounce => "oz",
+            WeightUnit::Ton => "t",
+        }
+    }
+}
+
+impl Unit for DataSizeUnit {
+    const ALL: &'static [Self] = &[
+        DataSizeUnit::Byte,
+        DataSizeUnit::Kilobyte,
+        DataSizeUnit::Megabyte,
+        DataSizeUnit::Gigabyte,
+        DataSizeUnit::Terabyte,
+        DataSizeUnit::Petabyte,
+        DataSizeUnit::Kibibyte,
+        DataSizeUnit::Mebibyte,
+        DataSizeUnit::Gibibyte,
+        DataSizeUnit::Tebibyte,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            DataSizeUnit::Byte => "B",
+            DataSizeUnit::Kilobyte => "KB",
+            DataSizeUnit::Megabyte => "MB",
+            DataSizeUnit::Gigabyte => "GB",
+            DataSizeUnit::Terabyte => "TB",
+            DataSizeUnit::Petabyte => "PB",
+            DataSizeUnit::Kibibyte => "KiB",
+            DataSizeUnit::Mebibyte => "MiB",
+            DataSizeUnit::Gibibyte => "GiB",
+            DataSizeUnit::Tebibyte => "TiB",
+        }
+    }
+}
+
+impl Unit for TemperatureUnit {
+    const ALL: &'static [Self] = &[
+        TemperatureUnit::Celsius,
+        TemperatureUnit::Fahrenheit,
+        TemperatureUnit::Kelvin,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+}
+
+impl Unit for TimeUnit {
+    const ALL: &'static [Self] = &[
+        TimeUnit::Second,
+        TimeUnit::Minute,
+        TimeUnit::Hour,
+        TimeUnit::Day,
+        TimeUnit::Week,
+        TimeUnit::Month,
+        TimeUnit::Year,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            TimeUnit::Second => "s",
+            TimeUnit::Minute => "min",
+            TimeUnit::Hour => "h",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Month => "month",
+            TimeUnit::Year => "year",
+        }
+    }
+}
+
+impl Unit for AreaUnit {
+    const ALL: &'static [Self] = &[
+        AreaUnit::SquareMeter,
+        AreaUnit::SquareKilometer,
+        AreaUnit::SquareCentimeter,
+        AreaUnit::SquareFeet,
+        AreaUnit::SquareInch,
+        AreaUnit::Hectare,
+        AreaUnit::Acre,
+        AreaUnit::Tsubo,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            AreaUnit::SquareMeter => "m²",
+            AreaUnit::SquareKilometer => "km²",
+            AreaUnit::SquareCentimeter => "cm²",
+            AreaUnit::SquareFeet => "ft²",
+            AreaUnit::SquareInch => "in²",
+            AreaUnit::Hectare => "ha",
+            AreaUnit::Acre => "ac",
+            AreaUnit::Tsubo => "tsubo",
+        }
+    }
+}
+
+impl Unit for VolumeUnit {
+    const ALL: &'static [Self] = &[
+        VolumeUnit::Liter,
+        VolumeUnit::Milliliter,
+        VolumeUnit::CubicMeter,
+        VolumeUnit::CubicCentimeter,
+        VolumeUnit::Gallon,
+        VolumeUnit::Quart,
+        VolumeUnit::Pint,
+        VolumeUnit::Cup,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            VolumeUnit::Liter => "L",
+            VolumeUnit::Milliliter => "mL",
+            VolumeUnit::CubicMeter => "m³",
+            VolumeUnit::CubicCentimeter => "cm³",
+            VolumeUnit::Gallon => "gal",
+            VolumeUnit::Quart => "qt",
+            VolumeUnit::Pint => "pt",
+            VolumeUnit::Cup => "cup",
+        }
+    }
+}
+
+impl Unit for SpeedUnit {
+    const ALL: &'static [Self] = &[
+        SpeedUnit::KilometerPerHour,
+        SpeedUnit::MeterPerSecond,
+        SpeedUnit::MilePerHour,
+        SpeedUnit::Knot,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SpeedUnit::KilometerPerHour => "km/h",
+            SpeedUnit::MeterPerSecond => "m/s",
+            SpeedUnit::MilePerHour => "mph",
+            SpeedUnit::Knot => "kn",
+        }
+    }
+}
+
+impl Unit for PressureUnit {
+    const ALL: &'static [Self] = &[
+        PressureUnit::Pascal,
+        PressureUnit::Kilopascal,
+        PressureUnit::Bar,
+        PressureUnit::Atmosphere,
+        PressureUnit::Psi,
+        PressureUnit::MillimeterOfMercury,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            PressureUnit::Pascal => "Pa",
+            PressureUnit::Kilopascal => "kPa",
+            PressureUnit::Bar => "bar",
+            PressureUnit::Atmosphere => "atm",
+            PressureUnit::Psi => "psi",
+            PressureUnit::MillimeterOfMercury => "mmHg",
+        }
+    }
+}
+
+impl Unit for EnergyUnit {
+    const ALL: &'static [Self] = &[
+        EnergyUnit::Joule,
+        EnergyUnit::Kilojoule,
+        EnergyUnit::Calorie,
+        EnergyUnit::Kilocalorie,
+        EnergyUnit::KilowattHour,
+        EnergyUnit::Btu,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            EnergyUnit::Joule => "J",
+            EnergyUnit::Kilojoule => "kJ",
+            EnergyUnit::Calorie => "cal",
+            EnergyUnit::Kilocalorie => "kcal",
+            EnergyUnit::KilowattHour => "kWh",
+            EnergyUnit::Btu => "BTU",
+        }
+    }
+}
+
+impl Unit for FuelEconomyUnit {
+    const ALL: &'static [Self] = &[
+        FuelEconomyUnit::KilometerPerLiter,
+        FuelEconomyUnit::LiterPer100Km,
+        FuelEconomyUnit::MpgUs,
+        FuelEconomyUnit::MpgUk,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            FuelEconomyUnit::KilometerPerLiter => "km/L",
+            FuelEconomyUnit::LiterPer100Km => "L/100km",
+            FuelEconomyUnit::MpgUs => "mpg (US)",
+            FuelEconomyUnit::MpgUk => "mpg (UK)",
+        }
+    }
+}
+
+impl Unit for CurrencyUnit {
+    const ALL: &'static [Self] = &[
+        CurrencyUnit::Usd,
+        CurrencyUnit::Eur,
+        CurrencyUnit::Jpy,
+        CurrencyUnit::Gbp,
+        CurrencyUnit::Cny,
+        CurrencyUnit::Krw,
+        CurrencyUnit::Aud,
+        CurrencyUnit::Cad,
+        CurrencyUnit::Chf,
+        CurrencyUnit::Hkd,
+        CurrencyUnit::Sgd,
+        CurrencyUnit::Inr,
+        CurrencyUnit::Twd,
+        CurrencyUnit::Thb,
+        CurrencyUnit::Brl,
+    ];
+
+    fn label(&self) -> &'static str {
+        self.code()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyRateInfo {
+    /// Units of `to` per unit of `from`
+    pub rate: f64,
+    pub updated_at: String,
+    pub source: RateSource,
+    pub is_stale: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversionResult {
     pub success: bool,
     pub result: f64,
     pub formatted: String,
+    /// Only set for currency conversions
+    pub rate_info: Option<CurrencyRateInfo>,
+    pub error: Option<String>,
+}
+
+impl ConversionResult {
+    fn ok(result: f64) -> Self {
+        Self {
+            success: true,
+            result,
+            formatted: format_number(result),
+            rate_info: None,
+            error: None,
+        }
+    }
+
+    fn err(message: String) -> Self {
+        Self {
+            success: false,
+            result: 0.0,
+            formatted: String::new(),
+            rate_info: None,
+            error: Some(message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitConversionEntry {
+    /// Unit name as used by the single-unit commands, e.g. `Kilometer`
+    pub unit: String,
+    pub label: String,
+    pub value: f64,
+    pub formatted: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiConversionResult {
+    pub success: bool,
+    pub results: Vec<UnitConversionEntry>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyRatesResult {
+    pub success: bool,
+    /// The fetched rates, or the cached/bundled ones still in use when fetching failed
+    pub rates: CurrencyRates,
+    pub is_stale: bool,
     pub error: Option<String>,
 }
 
@@ -128,12 +623,7 @@ fn meter_to_length(value: f64, unit: &LengthUnit) -> f64 {
 pub fn convert_length(value: f64, from: LengthUnit, to: LengthUnit) -> ConversionResult {
     let meters = length_to_meter(value, &from);
     let result = meter_to_length(meters, &to);
-    ConversionResult {
-        success: true,
-        result,
-        formatted: format_number(result),
-        error: None,
-    }
+    ConversionResult::ok(result)
 }
 
 // Weight conversion (base unit: kilogram)
@@ -162,12 +652,7 @@ fn kg_to_weight(value: f64, unit: &WeightUnit) -> f64 {
 pub fn convert_weight(value: f64, from: WeightUnit, to: WeightUnit) -> ConversionResult {
     let kg = weight_to_kg(value, &from);
     let result = kg_to_weight(kg, &to);
-    ConversionResult {
-        success: true,
-        result,
-        formatted: format_number(result),
-        error: None,
-    }
+    ConversionResult::ok(result)
 }
 
 // Data size conversion (base unit: byte)
@@ -204,12 +689,7 @@ fn bytes_to_data(value: f64, unit: &DataSizeUnit) -> f64 {
 pub fn convert_data_size(value: f64, from: DataSizeUnit, to: DataSizeUnit) -> ConversionResult {
     let bytes = data_to_bytes(value, &from);
     let result = bytes_to_data(bytes, &to);
-    ConversionResult {
-        success: true,
-        result,
-        formatted: format_number(result),
-        error: None,
-    }
+    ConversionResult::ok(result)
 }
 
 // Temperature conversion
@@ -218,23 +698,20 @@ pub fn convert_temperature(
     from: TemperatureUnit,
     to: TemperatureUnit,
 ) -> ConversionResult {
+    ConversionResult::ok(temperature_value(value, &from, &to))
+}
+
+fn temperature_value(value: f64, from: &TemperatureUnit, to: &TemperatureUnit) -> f64 {
     let celsius = match from {
         TemperatureUnit::Celsius => value,
         TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
         TemperatureUnit::Kelvin => value - 273.15,
     };
 
-    let result = match to {
+    match to {
         TemperatureUnit::Celsius => celsius,
         TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
         TemperatureUnit::Kelvin => celsius + 273.15,
-    };
-
-    ConversionResult {
-        success: true,
-        result,
-        formatted: format_number(result),
-        error: None,
     }
 }
 
@@ -266,12 +743,7 @@ fn seconds_to_time(value: f64, unit: &TimeUnit) -> f64 {
 pub fn convert_time(value: f64, from: TimeUnit, to: TimeUnit) -> ConversionResult {
     let seconds = time_to_seconds(value, &from);
     let result = seconds_to_time(seconds, &to);
-    ConversionResult {
-        success: true,
-        result,
-        formatted: format_number(result),
-        error: None,
-    }
+    ConversionResult::ok(result)
 }
 
 // Area conversion (base unit: square meter)
@@ -304,12 +776,7 @@ fn sqm_to_area(value: f64, unit: &AreaUnit) -> f64 {
 pub fn convert_area(value: f64, from: AreaUnit, to: AreaUnit) -> ConversionResult {
     let sqm = area_to_sqm(value, &from);
     let result = sqm_to_area(sqm, &to);
-    ConversionResult {
-        success: true,
-        result,
-        formatted: format_number(result),
-        error: None,
-    }
+    ConversionResult::ok(result)
 }
 
 // Volume conversion (base unit: liter)
@@ -342,14 +809,337 @@ fn liter_to_volume(value: f64, unit: &VolumeUnit) -> f64 {
 pub fn convert_volume(value: f64, from: VolumeUnit, to: VolumeUnit) -> ConversionResult {
     let liters = volume_to_liter(value, &from);
     let result = liter_to_volume(liters, &to);
+    ConversionResult::ok(result)
+}
+
+// Speed conversion (base unit: meter per second)
+fn speed_to_mps(value: f64, unit: &SpeedUnit) -> f64 {
+    match unit {
+        SpeedUnit::KilometerPerHour => value / 3.6,
+        SpeedUnit::MeterPerSecond => value,
+        SpeedUnit::MilePerHour => value * 0.44704,
+        SpeedUnit::Knot => value * 1852.0 / 3600.0,
+    }
+}
+
+fn mps_to_speed(value: f64, unit: &SpeedUnit) -> f64 {
+    match unit {
+        SpeedUnit::KilometerPerHour => value * 3.6,
+        SpeedUnit::MeterPerSecond => value,
+        SpeedUnit::MilePerHour => value / 0.44704,
+        SpeedUnit::Knot => value * 3600.0 / 1852.0,
+    }
+}
+
+pub fn convert_speed(value: f64, from: SpeedUnit, to: SpeedUnit) -> ConversionResult {
+    let mps = speed_to_mps(value, &from);
+    let result = mps_to_speed(mps, &to);
+    ConversionResult::ok(result)
+}
+
+// Pressure conversion (base unit: pascal)
+fn pressure_to_pa(value: f64, unit: &PressureUnit) -> f64 {
+    match unit {
+        PressureUnit::Pascal => value,
+        PressureUnit::Kilopascal => value * 1000.0,
+        PressureUnit::Bar => value * 100_000.0,
+        PressureUnit::Atmosphere => value * 101_325.0,
+        PressureUnit::Psi => value * 6894.757293168,
+        PressureUnit::MillimeterOfMercury => value * 133.322387415,
+    }
+}
+
+fn pa_to_pressure(value: f64, unit: &PressureUnit) -> f64 {
+    match unit {
+        PressureUnit::Pascal => value,
+        PressureUnit::Kilopascal => value / 1000.0,
+        PressureUnit::Bar => value / 100_000.0,
+        PressureUnit::Atmosphere => value / 101_325.0,
+        PressureUnit::Psi => value / 6894.757293168,
+        PressureUnit::MillimeterOfMercury => value / 133.322387415,
+    }
+}
+
+pub fn convert_pressure(value: f64, from: PressureUnit, to: PressureUnit) -> ConversionResult {
+    let pa = pressure_to_pa(value, &from);
+    let result = pa_to_pressure(pa, &to);
+    ConversionResult::ok(result)
+}
+
+// Energy conversion (base unit: joule; thermochemical calorie, IT BTU)
+fn energy_to_joule(value: f64, unit: &EnergyUnit) -> f64 {
+    match unit {
+        EnergyUnit::Joule => value,
+        EnergyUnit::Kilojoule => value * 1000.0,
+        EnergyUnit::Calorie => value * 4.184,
+        EnergyUnit::Kilocalorie => value * 4184.0,
+        EnergyUnit::KilowattHour => value * 3_600_000.0,
+        EnergyUnit::Btu => value * 1055.05585262,
+    }
+}
+
+fn joule_to_energy(value: f64, unit: &EnergyUnit) -> f64 {
+    match unit {
+        EnergyUnit::Joule => value,
+        EnergyUnit::Kilojoule => value / 1000.0,
+        EnergyUnit::Calorie => value / 4.184,
+        EnergyUnit::Kilocalorie => value / 4184.0,
+        EnergyUnit::KilowattHour => value / 3_600_000.0,
+        EnergyUnit::Btu => value / 1055.05585262,
+    }
+}
+
+pub fn convert_energy(value: f64, from: EnergyUnit, to: EnergyUnit) -> ConversionResult {
+    let joules = energy_to_joule(value, &from);
+    let result = joule_to_energy(joules, &to);
+    ConversionResult::ok(result)
+}
+
+// Fuel economy conversion (base unit: km/L). L/100km is the reciprocal of the others, so
+// a zero on either side of it has no finite result.
+const KM_PER_MILE: f64 = 1.609344;
+const LITERS_PER_US_GALLON: f64 = 3.785411784;
+const LITERS_PER_UK_GALLON: f64 = 4.54609;
+
+fn fuel_to_kmpl(value: f64, unit: &FuelEconomyUnit) -> Option<f64> {
+    match unit {
+        FuelEconomyUnit::KilometerPerLiter => Some(value),
+        FuelEconomyUnit::LiterPer100Km => (value != 0.0).then(|| 100.0 / value),
+        FuelEconomyUnit::MpgUs => Some(value * KM_PER_MILE / LITERS_PER_US_GALLON),
+        FuelEconomyUnit::MpgUk => Some(value * KM_PER_MILE / LITERS_PER_UK_GALLON),
+    }
+}
+
+fn kmpl_to_fuel(value: f64, unit: &FuelEconomyUnit) -> Option<f64> {
+    match unit {
+        FuelEconomyUnit::KilometerPerLiter => Some(value),
+        FuelEconomyUnit::LiterPer100Km => (value != 0.0).then(|| 100.0 / value),
+        FuelEconomyUnit::MpgUs => Some(value * LITERS_PER_US_GALLON / KM_PER_MILE),
+        FuelEconomyUnit::MpgUk => Some(value * LITERS_PER_UK_GALLON / KM_PER_MILE),
+    }
+}
+
+pub fn convert_fuel_economy(
+    value: f64,
+    from: FuelEconomyUnit,
+    to: FuelEconomyUnit,
+) -> ConversionResult {
+    match fuel_to_kmpl(value, &from).and_then(|kmpl| kmpl_to_fuel(kmpl, &to)) {
+        Some(result) => ConversionResult::ok(result),
+        None => ConversionResult::err(format!(
+            "Cannot convert 0 {} to {}: the result would be infinite",
+            from.label(),
+            to.label()
+        )),
+    }
+}
+
+// Currency conversion (base unit: USD)
+pub fn convert_currency(
+    value: f64,
+    from: CurrencyUnit,
+    to: CurrencyUnit,
+    rates: &CurrencyRates,
+) -> ConversionResult {
+    let (Some(from_rate), Some(to_rate)) = (rates.rate(from), rates.rate(to)) else {
+        let missing = if rates.rate(from).is_none() { from } else { to };
+        return ConversionResult::err(format!("No exchange rate available for {}", missing.code()));
+    };
+
+    let rate = to_rate / from_rate;
     ConversionResult {
+        rate_info: Some(CurrencyRateInfo {
+            rate,
+            updated_at: rates.updated_at.clone(),
+            source: rates.source.clone(),
+            is_stale: rates.is_stale_at(Utc::now()),
+        }),
+        ..ConversionResult::ok(value * rate)
+    }
+}
+
+fn convert_all_units<U: Unit>(
+    value: f64,
+    from_unit: &str,
+    significant_digits: Option<u32>,
+    convert: impl Fn(f64, &U, &U) -> Option<f64>,
+) -> MultiConversionResult {
+    let Ok(from) = serde_json::from_value::<U>(serde_json::Value::String(from_unit.to_string()))
+    else {
+        return MultiConversionResult {
+            success: false,
+            results: Vec::new(),
+            error: Some(format!("Unknown unit: {}", from_unit)),
+        };
+    };
+
+    let results = U::ALL
+        .iter()
+        .filter_map(|to| {
+            let result = convert(value, &from, to)?;
+            Some(UnitConversionEntry {
+                unit: serde_json::to_value(to)
+                    .ok()
+                    .and_then(|v| v.as_str().map(str::to_string))
+                    .unwrap_or_default(),
+                label: to.label().to_string(),
+                value: result,
+                formatted: match significant_digits {
+                    Some(digits) => format_significant(result, digits),
+                    None => format_number(result),
+                },
+            })
+        })
+        .collect();
+
+    MultiConversionResult {
         success: true,
-        result,
-        formatted: format_number(result),
+        results,
         error: None,
     }
 }
 
+/// Convert `value` from `from_unit` (e.g. `Kilometer`) to every unit of `category`. Currency
+/// rows without an exchange rate and fuel economy rows that would be infinite are left out.
+pub fn convert_to_all(
+    value: f64,
+    category: UnitCategory,
+    from_unit: &str,
+    significant_digits: Option<u32>,
+    rates: &CurrencyRates,
+) -> MultiConversionResult {
+    let digits = significant_digits;
+    match category {
+        UnitCategory::Length => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(meter_to_length(length_to_meter(v, from), to))
+        }),
+        UnitCategory::Weight => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(kg_to_weight(weight_to_kg(v, from), to))
+        }),
+        UnitCategory::DataSize => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(bytes_to_data(data_to_bytes(v, from), to))
+        }),
+        UnitCategory::Temperature => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(temperature_value(v, from, to))
+        }),
+        UnitCategory::Time => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(seconds_to_time(time_to_seconds(v, from), to))
+        }),
+        UnitCategory::Area => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(sqm_to_area(area_to_sqm(v, from), to))
+        }),
+        UnitCategory::Volume => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(liter_to_volume(volume_to_liter(v, from), to))
+        }),
+        UnitCategory::Currency => {
+            convert_all_units(value, from_unit, digits, |v, from: &CurrencyUnit, to| {
+                Some(v * rates.rate(*to)? / rates.rate(*from)?)
+            })
+        }
+        UnitCategory::Speed => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(mps_to_speed(speed_to_mps(v, from), to))
+        }),
+        UnitCategory::Pressure => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(pa_to_pressure(pressure_to_pa(v, from), to))
+        }),
+        UnitCategory::Energy => convert_all_units(value, from_unit, digits, |v, from, to| {
+            Some(joule_to_energy(energy_to_joule(v, from), to))
+        }),
+        UnitCategory::FuelEconomy => convert_all_units(value, from_unit, digits, |v, from, to| {
+            kmpl_to_fuel(fuel_to_kmpl(v, from)?, to)
+        }),
+    }
+}
+
+fn get_rates_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("currency_rates.json"))
+}
+
+/// Cached rates from the last successful update, or the bundled defaults. Never fails so
+/// conversions keep working offline.
+pub fn load_currency_rates(app: &AppHandle) -> CurrencyRates {
+    get_rates_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+#[derive(Deserialize)]
+struct RatesApiResponse {
+    result: String,
+    time_last_update_unix: i64,
+    rates: BTreeMap<String, f64>,
+}
+
+fn parse_rates_response(response: RatesApiResponse) -> Result<CurrencyRates, String> {
+    if response.result != "success" {
+        return Err(format!("Exchange rate API returned '{}'", response.result));
+    }
+    let updated_at = DateTime::from_timestamp(response.time_last_update_unix, 0)
+        .ok_or_else(|| "Exchange rate API returned an invalid timestamp".to_string())?;
+
+    let rates = DEFAULT_RATES
+        .iter()
+        .map(|(unit, _)| {
+            response
+                .rates
+                .get(unit.code())
+                .map(|rate| (unit.code().to_string(), *rate))
+                .ok_or_else(|| format!("Exchange rate API has no rate for {}", unit.code()))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(CurrencyRates {
+        rates,
+        updated_at: updated_at.to_rfc3339(),
+        source: RateSource::Api,
+    })
+}
+
+async fn fetch_currency_rates() -> Result<CurrencyRates, String> {
+    let response: RatesApiResponse = shared_client()
+        .get(CURRENCY_RATES_URL)
+        .timeout(std::time::Duration::from_secs(10))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Failed to fetch exchange rates: {}", describe_error(&e)))?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse exchange rates: {}", e))?;
+    parse_rates_response(response)
+}
+
+/// Fetch the latest rates and cache them in the app data directory. When fetching fails
+/// the rates already in use are returned along with the error.
+pub async fn update_currency_rates(app: &AppHandle) -> CurrencyRatesResult {
+    let fetched = fetch_currency_rates().await.and_then(|rates| {
+        let json = serde_json::to_string_pretty(&rates)
+            .map_err(|e| format!("Failed to serialize exchange rates: {}", e))?;
+        fs::write(get_rates_path(app)?, json)
+            .map_err(|e| format!("Failed to write exchange rate cache: {}", e))?;
+        Ok(rates)
+    });
+
+    let (rates, error) = match fetched {
+        Ok(rates) => (rates, None),
+        Err(e) => (load_currency_rates(app), Some(e)),
+    };
+    CurrencyRatesResult {
+        success: error.is_none(),
+        is_stale: rates.is_stale_at(Utc::now()),
+        rates,
+        error,
+    }
+}
+
 fn format_number(value: f64) -> String {
     if value.abs() < 0.000001 || value.abs() >= 1_000_000_000.0 {
         format!("{:.6e}", value)
@@ -364,6 +1154,41 @@ fn format_number(value: f64) -> String {
     }
 }
 
+/// Format with `digits` significant digits, switching to exponent notation at the same
+/// magnitudes as `format_number`.
+fn format_significant(value: f64, digits: u32) -> String {
+    let digits = digits.clamp(1, 17) as i32;
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}", value);
+    }
+
+    let exponent = value.abs().log10().floor() as i32;
+    if !(-6..9).contains(&exponent) {
+        let formatted = format!("{:.*e}", (digits - 1) as usize, value);
+        let (mantissa, exp) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+        let mantissa = if mantissa.contains('.') {
+            mantissa.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            mantissa
+        };
+        format!("{}e{}", mantissa, exp)
+    } else if exponent >= digits {
+        let factor = 10f64.powi(exponent - digits + 1);
+        format!("{:.0}", (value / factor).round() * factor)
+    } else {
+        let decimals = (digits - 1 - exponent) as usize;
+        let formatted = format!("{:.*}", decimals, value);
+        if formatted.contains('.') {
+            formatted
+                .trim_end_matches('0')
+                .trim_end_matches('.')
+                .to_string()
+        } else {
+            formatted
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -425,4 +1250,227 @@ mod tests {
         let result = convert_volume(1.0, VolumeUnit::Liter, VolumeUnit::Milliliter);
         assert!((result.result - 1000.0).abs() < 0.0001);
     }
+
+    #[test]
+    fn test_speed_conversion() {
+        let result = convert_speed(1.0, SpeedUnit::Knot, SpeedUnit::KilometerPerHour);
+        assert!((result.result - 1.852).abs() < 1e-9);
+
+        let result = convert_speed(
+            100.0,
+            SpeedUnit::KilometerPerHour,
+            SpeedUnit::MeterPerSecond,
+        );
+        assert!((result.result - 27.777778).abs() < 1e-6);
+
+        let result = convert_speed(60.0, SpeedUnit::MilePerHour, SpeedUnit::KilometerPerHour);
+        assert!((result.result - 96.56064).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pressure_conversion() {
+        let result = convert_pressure(1.0, PressureUnit::Atmosphere, PressureUnit::Pascal);
+        assert!((result.result - 101_325.0).abs() < 1e-9);
+
+        let result = convert_pressure(1.0, PressureUnit::Bar, PressureUnit::Kilopascal);
+        assert!((result.result - 100.0).abs() < 1e-9);
+
+        let result = convert_pressure(1.0, PressureUnit::Atmosphere, PressureUnit::Psi);
+        assert!((result.result - 14.695949).abs() < 1e-6);
+
+        let result = convert_pressure(
+            760.0,
+            PressureUnit::MillimeterOfMercury,
+            PressureUnit::Atmosphere,
+        );
+        assert!((result.result - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_energy_conversion() {
+        let result = convert_energy(1.0, EnergyUnit::Kilocalorie, EnergyUnit::Joule);
+        assert!((result.result - 4184.0).abs() < 1e-9);
+
+        let result = convert_energy(1.0, EnergyUnit::KilowattHour, EnergyUnit::Kilojoule);
+        assert!((result.result - 3600.0).abs() < 1e-9);
+
+        let result = convert_energy(1.0, EnergyUnit::Btu, EnergyUnit::Joule);
+        assert!((result.result - 1055.056).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_fuel_economy_conversion() {
+        let result = convert_fuel_economy(
+            20.0,
+            FuelEconomyUnit::KilometerPerLiter,
+            FuelEconomyUnit::LiterPer100Km,
+        );
+        assert!((result.result - 5.0).abs() < 1e-9);
+
+        let result = convert_fuel_economy(
+            1.0,
+            FuelEconomyUnit::MpgUs,
+            FuelEconomyUnit::KilometerPerLiter,
+        );
+        assert!((result.result - 0.425144).abs() < 1e-6);
+
+        let result =
+            convert_fuel_economy(30.0, FuelEconomyUnit::MpgUs, FuelEconomyUnit::LiterPer100Km);
+        assert!((result.result - 7.840486).abs() < 1e-6);
+
+        let result = convert_fuel_economy(1.0, FuelEconomyUnit::MpgUk, FuelEconomyUnit::MpgUs);
+        assert!((result.result - 0.832674).abs() < 1e-6);
+
+        // Zero on either side of the reciprocal has no finite result
+        let result = convert_fuel_economy(
+            0.0,
+            FuelEconomyUnit::LiterPer100Km,
+            FuelEconomyUnit::KilometerPerLiter,
+        );
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("L/100km"));
+        let result =
+            convert_fuel_economy(0.0, FuelEconomyUnit::MpgUk, FuelEconomyUnit::LiterPer100Km);
+        assert!(!result.success);
+
+        let all = convert_to_all(
+            0.0,
+            UnitCategory::FuelEconomy,
+            "KilometerPerLiter",
+            None,
+            &CurrencyRates::default(),
+        );
+        assert!(all.success);
+        assert!(all.results.iter().all(|r| r.unit != "LiterPer100Km"));
+        assert_eq!(all.results.len(), FuelEconomyUnit::ALL.len() - 1);
+    }
+
+    #[test]
+    fn test_currency_conversion() {
+        let rates = CurrencyRates::default();
+        let result = convert_currency(100.0, CurrencyUnit::Usd, CurrencyUnit::Jpy, &rates);
+        assert!(result.success);
+        assert!((result.result - 14390.0).abs() < 0.0001);
+
+        let info = result.rate_info.unwrap();
+        assert_eq!(info.source, RateSource::Bundled);
+        assert_eq!(info.updated_at, DEFAULT_RATES_UPDATED_AT);
+
+        // Cross rates go through USD
+        let result = convert_currency(1.0, CurrencyUnit::Eur, CurrencyUnit::Gbp, &rates);
+        assert!((result.result - 0.741 / 0.879).abs() < 1e-9);
+
+        let result = convert_currency(5.0, CurrencyUnit::Krw, CurrencyUnit::Krw, &rates);
+        assert!((result.result - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_currency_missing_rate() {
+        let mut rates = CurrencyRates::default();
+        rates.rates.remove("BRL");
+        let result = convert_currency(1.0, CurrencyUnit::Usd, CurrencyUnit::Brl, &rates);
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("BRL"));
+    }
+
+    #[test]
+    fn test_currency_rates_staleness() {
+        let rates = CurrencyRates {
+            updated_at: "2024-01-01T00:00:00+00:00".to_string(),
+            ..CurrencyRates::default()
+        };
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        assert!(!rates.is_stale_at(at("2024-01-07T23:59:59Z")));
+        assert!(rates.is_stale_at(at("2024-01-08T00:00:00Z")));
+
+        let broken = CurrencyRates {
+            updated_at: "yesterday".to_string(),
+            ..CurrencyRates::default()
+        };
+        assert!(broken.is_stale_at(at("2024-01-01T00:00:00Z")));
+    }
+
+    #[test]
+    fn test_parse_rates_response() {
+        let json = r#"{"result":"success","time_last_update_unix":1718409601,"rates":{"USD":1,"EUR":0.93,"JPY":157.2,"GBP":0.79,"CNY":7.25,"KRW":1380,"AUD":1.51,"CAD":1.37,"CHF":0.89,"HKD":7.81,"SGD":1.35,"INR":83.5,"TWD":32.3,"THB":36.7,"BRL":5.38,"MXN":18.4}}"#;
+        let rates = parse_rates_response(serde_json::from_str(json).unwrap()).unwrap();
+        assert_eq!(rates.source, RateSource::Api);
+        assert_eq!(rates.updated_at, "2024-06-15T00:00:01+00:00");
+        assert_eq!(rates.rates.len(), 15);
+        assert_eq!(rates.rates["JPY"], 157.2);
+
+        let missing =
+            r#"{"result":"success","time_last_update_unix":1718409601,"rates":{"USD":1}}"#;
+        let error = parse_rates_response(serde_json::from_str(missing).unwrap()).unwrap_err();
+        assert!(error.contains("EUR"));
+
+        let failed = r#"{"result":"error","time_last_update_unix":0,"rates":{}}"#;
+        assert!(parse_rates_response(serde_json::from_str(failed).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_convert_to_all() {
+        let result = convert_to_all(
+            1.0,
+            UnitCategory::Length,
+            "Kilometer",
+            None,
+            &CurrencyRates::default(),
+        );
+        assert!(result.success);
+        assert_eq!(result.results.len(), LengthUnit::ALL.len());
+
+        let meter = &result.results[0];
+        assert_eq!(meter.unit, "Meter");
+        assert_eq!(meter.label, "m");
+        assert_eq!(meter.formatted, "1000");
+        let mile = result.results.iter().find(|r| r.unit == "Mile").unwrap();
+        assert!((mile.value - 0.621371).abs() < 1e-6);
+
+        let result = convert_to_all(
+            100.0,
+            UnitCategory::Temperature,
+            "Celsius",
+            Some(4),
+            &CurrencyRates::default(),
+        );
+        let values: Vec<&str> = result
+            .results
+            .iter()
+            .map(|r| r.formatted.as_str())
+            .collect();
+        assert_eq!(values, vec!["100", "212", "373.1"]);
+
+        let unknown = convert_to_all(
+            1.0,
+            UnitCategory::Length,
+            "Parsec",
+            None,
+            &CurrencyRates::default(),
+        );
+        assert!(!unknown.success);
+        assert!(unknown.error.unwrap().contains("Parsec"));
+    }
+
+    #[test]
+    fn test_convert_to_all_currency() {
+        let mut rates = CurrencyRates::default();
+        rates.rates.remove("BRL");
+        let result = convert_to_all(1.0, UnitCategory::Currency, "USD", None, &rates);
+        assert!(result.success);
+        assert_eq!(result.results.len(), CurrencyUnit::ALL.len() - 1);
+        let jpy = result.results.iter().find(|r| r.unit == "JPY").unwrap();
+        assert_eq!(jpy.formatted, "143.9");
+    }
+
+    #[test]
+    fn test_format_significant() {
+        assert_eq!(format_significant(1234.5678, 3), "1230");
+        assert_eq!(format_significant(1234.5678, 6), "1234.57");
+        assert_eq!(format_significant(0.000123456, 3), "0.000123");
+        assert_eq!(format_significant(1.23456e-8, 3), "1.23e-8");
+        assert_eq!(format_significant(1.5e12, 4), "1.5e12");
+        assert_eq!(format_significant(-2.0, 5), "-2");
+        assert_eq!(format_significant(0.0, 5), "0");
+    }
 }
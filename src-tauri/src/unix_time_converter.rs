@@ -1,16 +1,45 @@
-use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{
+    DateTime, Days, Local, LocalResult, Months, NaiveDateTime, Offset, TimeDelta, TimeZone, Utc,
+};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TimestampUnit {
     Seconds,
     Milliseconds,
+    /// Milliseconds when the value has 13 or more digits, seconds otherwise
+    Auto,
 }
 
+/// Batch results list at most this many invalid values individually.
+const MAX_BATCH_WARNINGS: usize = 100;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TimezoneOption {
     Local,
     Utc,
+    /// An IANA timezone name such as `America/New_York`
+    Named(String),
+}
+
+/// Why a local datetime did not map to exactly one instant.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum DstTransition {
+    /// The clock skipped over this time (spring forward)
+    Nonexistent,
+    /// The clock passed this time twice (fall back)
+    Ambiguous,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimezoneInfo {
+    pub name: String,
+    pub abbreviation: String,
+    pub utc_offset: String,
+    pub offset_seconds: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +59,10 @@ pub struct DateTimeToUnixResult {
     pub success: bool,
     pub unix_seconds: i64,
     pub unix_milliseconds: i64,
+    pub dst_transition: Option<DstTransition>,
+    /// For ambiguous times, the instant using the later of the two offsets
+    pub alternative_unix_seconds: Option<i64>,
+    pub warning: Option<String>,
     pub error: Option<String>,
 }
 
@@ -41,58 +74,142 @@ pub struct CurrentUnixTimeResult {
     pub iso8601: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurationResult {
+    pub success: bool,
+    pub total_seconds: i64,
+    pub total_milliseconds: i64,
+    pub is_negative: bool,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i64,
+    pub human_readable: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchConvertEntry {
+    pub line: usize,
+    pub original: String,
+    pub unix_milliseconds: i64,
+    pub datetime: String,
+    pub iso8601: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchConvertResult {
+    pub success: bool,
+    pub entries: Vec<BatchConvertEntry>,
+    pub converted_count: usize,
+    pub skipped_count: usize,
+    pub warnings: Vec<String>,
+    /// Tab-separated `original<TAB>datetime` lines for pasting into a spreadsheet
+    pub formatted_output: String,
+    pub error: Option<String>,
+}
+
+impl UnixToDateTimeResult {
+    fn failure(error: String) -> Self {
+        Self {
+            success: false,
+            datetime: String::new(),
+            iso8601: String::new(),
+            date: String::new(),
+            time: String::new(),
+            day_of_week: String::new(),
+            relative_time: String::new(),
+            error: Some(error),
+        }
+    }
+}
+
+impl DateTimeToUnixResult {
+    fn failure(error: String) -> Self {
+        Self {
+            success: false,
+            unix_seconds: 0,
+            unix_milliseconds: 0,
+            dst_transition: None,
+            alternative_unix_seconds: None,
+            warning: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// A local datetime resolved to a single instant.
+struct ResolvedTime {
+    unix_seconds: i64,
+    dst_transition: Option<DstTransition>,
+    alternative_unix_seconds: Option<i64>,
+    warning: Option<String>,
+}
+
+fn resolve_unit(timestamp: i64, unit: &TimestampUnit) -> TimestampUnit {
+    match unit {
+        TimestampUnit::Auto if timestamp.unsigned_abs() >= 1_000_000_000_000 => {
+            TimestampUnit::Milliseconds
+        }
+        TimestampUnit::Auto => TimestampUnit::Seconds,
+        unit => unit.clone(),
+    }
+}
+
+fn timestamp_to_utc(timestamp: i64, unit: &TimestampUnit) -> Option<DateTime<Utc>> {
+    match resolve_unit(timestamp, unit) {
+        TimestampUnit::Milliseconds => DateTime::<Utc>::from_timestamp_millis(timestamp),
+        _ => DateTime::<Utc>::from_timestamp(timestamp, 0),
+    }
+}
+
+pub(crate) fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.trim()
+        .parse::<Tz>()
+        .map_err(|_| format!("Unknown timezone: {}", name))
+}
+
+/// List every IANA timezone with its UTC offset at the current moment.
+pub fn list_timezones() -> Vec<TimezoneInfo> {
+    let now = Utc::now().naive_utc();
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|tz| {
+            let offset = tz.offset_from_utc_datetime(&now);
+            let fixed = offset.fix();
+            TimezoneInfo {
+                name: tz.name().to_string(),
+                abbreviation: offset.to_string(),
+                utc_offset: fixed.to_string(),
+                offset_seconds: fixed.local_minus_utc(),
+            }
+        })
+        .collect()
+}
+
 pub fn unix_to_datetime(
     timestamp: i64,
     unit: TimestampUnit,
     timezone: TimezoneOption,
 ) -> UnixToDateTimeResult {
-    let timestamp_secs = match unit {
-        TimestampUnit::Seconds => timestamp,
-        TimestampUnit::Milliseconds => timestamp / 1000,
-    };
-
-    let timestamp_nanos = match unit {
-        TimestampUnit::Seconds => 0,
-        TimestampUnit::Milliseconds => ((timestamp % 1000) * 1_000_000) as u32,
-    };
-
-    let utc_dt = match DateTime::<Utc>::from_timestamp(timestamp_secs, timestamp_nanos) {
+    let utc_dt = match timestamp_to_utc(timestamp, &unit) {
         Some(dt) => dt,
-        None => {
-            return UnixToDateTimeResult {
-                success: false,
-                datetime: String::new(),
-                iso8601: String::new(),
-                date: String::new(),
-                time: String::new(),
-                day_of_week: String::new(),
-                relative_time: String::new(),
-                error: Some("Invalid timestamp".to_string()),
-            }
-        }
+        None => return UnixToDateTimeResult::failure("Invalid timestamp".to_string()),
     };
 
     let (datetime_str, iso8601, date_str, time_str, day_of_week) = match timezone {
         TimezoneOption::Local => {
-            let local_dt: DateTime<Local> = utc_dt.with_timezone(&Local);
-            (
-                local_dt.format("%Y-%m-%d %H:%M:%S").to_string(),
-                local_dt.to_rfc3339(),
-                local_dt.format("%Y-%m-%d").to_string(),
-                local_dt.format("%H:%M:%S").to_string(),
-                local_dt.format("%A").to_string(),
-            )
+            format_datetime(&utc_dt.with_timezone(&Local), "%Y-%m-%d %H:%M:%S")
         }
-        TimezoneOption::Utc => (
-            utc_dt.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-            utc_dt.to_rfc3339(),
-            utc_dt.format("%Y-%m-%d").to_string(),
-            utc_dt.format("%H:%M:%S").to_string(),
-            utc_dt.format("%A").to_string(),
-        ),
+        TimezoneOption::Utc => format_datetime(&utc_dt, "%Y-%m-%d %H:%M:%S UTC"),
+        TimezoneOption::Named(name) => match parse_timezone(&name) {
+            Ok(tz) => format_datetime(&utc_dt.with_timezone(&tz), "%Y-%m-%d %H:%M:%S %Z"),
+            Err(e) => return UnixToDateTimeResult::failure(e),
+        },
     };
 
-    let relative_time = calculate_relative_time(timestamp_secs);
+    let relative_time = calculate_relative_time(utc_dt.timestamp());
 
     UnixToDateTimeResult {
         success: true,
@@ -106,7 +223,41 @@ pub fn unix_to_datetime(
     }
 }
 
+fn format_datetime<Z: TimeZone>(
+    dt: &DateTime<Z>,
+    datetime_format: &str,
+) -> (String, String, String, String, String)
+where
+    Z::Offset: Display,
+{
+    (
+        dt.format(datetime_format).to_string(),
+        dt.to_rfc3339(),
+        dt.format("%Y-%m-%d").to_string(),
+        dt.format("%H:%M:%S").to_string(),
+        dt.format("%A").to_string(),
+    )
+}
+
 pub fn datetime_to_unix(datetime_str: &str, timezone: TimezoneOption) -> DateTimeToUnixResult {
+    let named_timezone = match &timezone {
+        TimezoneOption::Named(name) => match parse_timezone(name) {
+            Ok(tz) => Some(tz),
+            Err(e) => return DateTimeToUnixResult::failure(e),
+        },
+        _ => None,
+    };
+    let resolve = |naive: &NaiveDateTime| match (&timezone, named_timezone) {
+        (TimezoneOption::Utc, _) => ResolvedTime {
+            unix_seconds: naive.and_utc().timestamp(),
+            dst_transition: None,
+            alternative_unix_seconds: None,
+            warning: None,
+        },
+        (_, Some(tz)) => resolve_local_datetime(&tz, naive, tz.name()),
+        _ => resolve_local_datetime(&Local, naive, "local time"),
+    };
+
     // Try multiple formats
     let formats = [
         "%Y-%m-%d %H:%M:%S",
@@ -127,55 +278,387 @@ pub fn datetime_to_unix(datetime_str: &str, timezone: TimezoneOption) -> DateTim
             success: true,
             unix_seconds: unix_secs,
             unix_milliseconds: unix_ms,
+            dst_transition: None,
+            alternative_unix_seconds: None,
+            warning: None,
             error: None,
         };
     }
 
     for format in formats {
-        if let Ok(naive) = NaiveDateTime::parse_from_str(datetime_str, format) {
-            let unix_secs = match timezone {
-                TimezoneOption::Local => Local
-                    .from_local_datetime(&naive)
-                    .single()
-                    .map(|dt| dt.timestamp())
-                    .unwrap_or_else(|| naive.and_utc().timestamp()),
-                TimezoneOption::Utc => naive.and_utc().timestamp(),
-            };
-            let unix_ms = unix_secs * 1000;
+        let naive = NaiveDateTime::parse_from_str(datetime_str, format)
+            .ok()
+            // Try date-only formats
+            .or_else(|| {
+                chrono::NaiveDate::parse_from_str(datetime_str, format)
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+            });
+
+        if let Some(naive) = naive {
+            let resolved = resolve(&naive);
             return DateTimeToUnixResult {
                 success: true,
-                unix_seconds: unix_secs,
-                unix_milliseconds: unix_ms,
+                unix_seconds: resolved.unix_seconds,
+                unix_milliseconds: resolved.unix_seconds * 1000,
+                dst_transition: resolved.dst_transition,
+                alternative_unix_seconds: resolved.alternative_unix_seconds,
+                warning: resolved.warning,
                 error: None,
             };
         }
+    }
 
-        // Try date-only formats
-        if let Ok(naive_date) = chrono::NaiveDate::parse_from_str(datetime_str, format) {
-            let naive = naive_date.and_hms_opt(0, 0, 0).unwrap();
-            let unix_secs = match timezone {
-                TimezoneOption::Local => Local
-                    .from_local_datetime(&naive)
-                    .single()
-                    .map(|dt| dt.timestamp())
-                    .unwrap_or_else(|| naive.and_utc().timestamp()),
-                TimezoneOption::Utc => naive.and_utc().timestamp(),
-            };
-            let unix_ms = unix_secs * 1000;
-            return DateTimeToUnixResult {
-                success: true,
-                unix_seconds: unix_secs,
-                unix_milliseconds: unix_ms,
-                error: None,
-            };
+    DateTimeToUnixResult::failure("Invalid datetime format".to_string())
+}
+
+/// Map a wall-clock time in `tz` to an instant, reporting DST gaps and overlaps.
+///
+/// Times inside a gap are shifted forward using the offset in effect before the transition, and
+/// times inside an overlap use the earlier of the two instants.
+fn resolve_local_datetime<Z: TimeZone>(
+    tz: &Z,
+    naive: &NaiveDateTime,
+    zone_name: &str,
+) -> ResolvedTime
+where
+    Z::Offset: Display,
+{
+    match tz.from_local_datetime(naive) {
+        LocalResult::Single(dt) => ResolvedTime {
+            unix_seconds: dt.timestamp(),
+            dst_transition: None,
+            alternative_unix_seconds: None,
+            warning: None,
+        },
+        LocalResult::Ambiguous(earliest, latest) => ResolvedTime {
+            unix_seconds: earliest.timestamp(),
+            dst_transition: Some(DstTransition::Ambiguous),
+            alternative_unix_seconds: Some(latest.timestamp()),
+            warning: Some(format!(
+                "{} occurs twice in {} because of a DST transition; using {} ({}) rather than {} ({})",
+                naive,
+                zone_name,
+                earliest.offset().fix(),
+                earliest.timestamp(),
+                latest.offset().fix(),
+                latest.timestamp()
+            )),
+        },
+        LocalResult::None => {
+            let offset_before = tz
+                .offset_from_utc_datetime(&(*naive - TimeDelta::days(1)))
+                .fix();
+            let unix_seconds = (*naive - offset_before).and_utc().timestamp();
+            let shifted = tz.timestamp_opt(unix_seconds, 0).earliest();
+            ResolvedTime {
+                unix_seconds,
+                dst_transition: Some(DstTransition::Nonexistent),
+                alternative_unix_seconds: None,
+                warning: Some(match shifted {
+                    Some(dt) => format!(
+                        "{} does not exist in {} because of a DST transition; interpreted as {}",
+                        naive,
+                        zone_name,
+                        dt.format("%Y-%m-%d %H:%M:%S %:z")
+                    ),
+                    None => format!(
+                        "{} does not exist in {} because of a DST transition",
+                        naive, zone_name
+                    ),
+                }),
+            }
+        }
+    }
+}
+
+/// Shift a timestamp by a calendar period plus a clock duration.
+///
+/// Years, months and days follow the wall clock in `timezone`, with the day clamped to the end
+/// of shorter months (Jan 31 + 1 month = Feb 28/29). Hours, minutes and seconds are then added
+/// as elapsed time, so a day is not always 24 hours across a DST transition.
+#[allow(clippy::too_many_arguments)]
+pub fn add_duration(
+    timestamp: i64,
+    unit: TimestampUnit,
+    years: i32,
+    months: i32,
+    days: i32,
+    hours: i32,
+    minutes: i32,
+    seconds: i32,
+    timezone: TimezoneOption,
+) -> DateTimeToUnixResult {
+    let Some(start) = timestamp_to_utc(timestamp, &unit) else {
+        return DateTimeToUnixResult::failure("Invalid timestamp".to_string());
+    };
+
+    let total_months = i64::from(years) * 12 + i64::from(months);
+    let resolved = if total_months == 0 && days == 0 {
+        Ok(ResolvedTime {
+            unix_seconds: start.timestamp(),
+            dst_transition: None,
+            alternative_unix_seconds: None,
+            warning: None,
+        })
+    } else {
+        match &timezone {
+            TimezoneOption::Local => {
+                shift_calendar(&Local, &start, total_months, days, "local time")
+            }
+            TimezoneOption::Utc => shift_calendar(&Utc, &start, total_months, days, "UTC"),
+            TimezoneOption::Named(name) => match parse_timezone(name) {
+                Ok(tz) => shift_calendar(&tz, &start, total_months, days, tz.name()),
+                Err(e) => return DateTimeToUnixResult::failure(e),
+            },
         }
+    };
+    let resolved = match resolved {
+        Ok(resolved) => resolved,
+        Err(e) => return DateTimeToUnixResult::failure(e),
+    };
+
+    let clock_seconds = i64::from(hours) * 3600 + i64::from(minutes) * 60 + i64::from(seconds);
+    let unix_seconds = resolved.unix_seconds + clock_seconds;
+    if DateTime::<Utc>::from_timestamp(unix_seconds, 0).is_none() {
+        return DateTimeToUnixResult::failure("Resulting date is out of range".to_string());
     }
 
     DateTimeToUnixResult {
-        success: false,
-        unix_seconds: 0,
-        unix_milliseconds: 0,
-        error: Some("Invalid datetime format".to_string()),
+        success: true,
+        unix_seconds,
+        unix_milliseconds: unix_seconds * 1000 + i64::from(start.timestamp_subsec_millis()),
+        dst_transition: resolved.dst_transition,
+        alternative_unix_seconds: resolved.alternative_unix_seconds.map(|t| t + clock_seconds),
+        warning: resolved.warning,
+        error: None,
+    }
+}
+
+fn shift_calendar<Z: TimeZone>(
+    tz: &Z,
+    start: &DateTime<Utc>,
+    months: i64,
+    days: i32,
+    zone_name: &str,
+) -> Result<ResolvedTime, String>
+where
+    Z::Offset: Display,
+{
+    let local = start.with_timezone(tz).naive_local();
+    let month_delta = Months::new(months.unsigned_abs().try_into().unwrap_or(u32::MAX));
+    let day_delta = Days::new(u64::from(days.unsigned_abs()));
+
+    let shifted = if months >= 0 {
+        local.checked_add_months(month_delta)
+    } else {
+        local.checked_sub_months(month_delta)
+    }
+    .and_then(|dt| {
+        if days >= 0 {
+            dt.checked_add_days(day_delta)
+        } else {
+            dt.checked_sub_days(day_delta)
+        }
+    })
+    .ok_or_else(|| "Resulting date is out of range".to_string())?;
+
+    Ok(resolve_local_datetime(tz, &shifted, zone_name))
+}
+
+/// Compute `b - a`, negative when `b` is earlier than `a`.
+pub fn diff_datetimes(a: i64, b: i64, unit: TimestampUnit) -> DurationResult {
+    let to_millis = |t: i64| match resolve_unit(t, &unit) {
+        TimestampUnit::Milliseconds => Some(t),
+        _ => t.checked_mul(1000),
+    };
+    let diff = to_millis(a)
+        .zip(to_millis(b))
+        .and_then(|(a, b)| b.checked_sub(a));
+    let Some(total_milliseconds) = diff else {
+        return DurationResult {
+            success: false,
+            total_seconds: 0,
+            total_milliseconds: 0,
+            is_negative: false,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            milliseconds: 0,
+            human_readable: String::new(),
+            error: Some("Duration is out of range".to_string()),
+        };
+    };
+
+    let abs_ms = total_milliseconds.unsigned_abs();
+    let days = (abs_ms / 86_400_000) as i64;
+    let hours = (abs_ms / 3_600_000 % 24) as i64;
+    let minutes = (abs_ms / 60_000 % 60) as i64;
+    let seconds = (abs_ms / 1000 % 60) as i64;
+    let milliseconds = (abs_ms % 1000) as i64;
+
+    let parts: Vec<String> = [
+        (days, "day", "days"),
+        (hours, "hour", "hours"),
+        (minutes, "minute", "minutes"),
+        (seconds, "second", "seconds"),
+        (milliseconds, "millisecond", "milliseconds"),
+    ]
+    .into_iter()
+    .filter(|(value, _, _)| *value != 0)
+    .map(|(value, singular, plural)| {
+        format!("{} {}", value, if value == 1 { singular } else { plural })
+    })
+    .collect();
+
+    let human_readable = if parts.is_empty() {
+        "0 seconds".to_string()
+    } else if total_milliseconds < 0 {
+        format!("-{}", parts.join(", "))
+    } else {
+        parts.join(", ")
+    };
+
+    DurationResult {
+        success: true,
+        total_seconds: total_milliseconds / 1000,
+        total_milliseconds,
+        is_negative: total_milliseconds < 0,
+        days,
+        hours,
+        minutes,
+        seconds,
+        milliseconds,
+        human_readable,
+        error: None,
+    }
+}
+
+/// Timezone parsed once up front so batch conversion does not re-resolve it per value.
+enum BatchZone {
+    Local,
+    Utc,
+    Named(Tz),
+}
+
+impl BatchZone {
+    fn format(&self, utc: &DateTime<Utc>, items: &[Item<'_>]) -> (String, String) {
+        match self {
+            BatchZone::Local => {
+                let dt = utc.with_timezone(&Local);
+                (
+                    dt.format_with_items(items.iter()).to_string(),
+                    dt.to_rfc3339(),
+                )
+            }
+            BatchZone::Utc => (
+                utc.format_with_items(items.iter()).to_string(),
+                utc.to_rfc3339(),
+            ),
+            BatchZone::Named(tz) => {
+                let dt = utc.with_timezone(tz);
+                (
+                    dt.format_with_items(items.iter()).to_string(),
+                    dt.to_rfc3339(),
+                )
+            }
+        }
+    }
+}
+
+/// Convert every timestamp in `input` to a datetime.
+///
+/// Values may be separated by newlines, commas or whitespace in any mix. Values that are not
+/// valid timestamps are skipped and reported with their line number.
+pub fn batch_convert_timestamps(
+    input: String,
+    unit: TimestampUnit,
+    timezone: TimezoneOption,
+) -> BatchConvertResult {
+    let (zone, datetime_format) = match timezone {
+        TimezoneOption::Local => (BatchZone::Local, "%Y-%m-%d %H:%M:%S"),
+        TimezoneOption::Utc => (BatchZone::Utc, "%Y-%m-%d %H:%M:%S UTC"),
+        TimezoneOption::Named(name) => match parse_timezone(&name) {
+            Ok(tz) => (BatchZone::Named(tz), "%Y-%m-%d %H:%M:%S %Z"),
+            Err(e) => {
+                return BatchConvertResult {
+                    success: false,
+                    entries: Vec::new(),
+                    converted_count: 0,
+                    skipped_count: 0,
+                    warnings: Vec::new(),
+                    formatted_output: String::new(),
+                    error: Some(e),
+                }
+            }
+        },
+    };
+    let items: Vec<Item<'_>> = StrftimeItems::new(datetime_format).collect();
+
+    let mut entries = Vec::new();
+    let mut warnings = Vec::new();
+    let mut skipped_count = 0;
+    let mut formatted_output = String::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let tokens = line
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| !token.is_empty());
+
+        for token in tokens {
+            let converted = token
+                .parse::<i64>()
+                .ok()
+                .and_then(|timestamp| timestamp_to_utc(timestamp, &unit));
+
+            let Some(utc) = converted else {
+                skipped_count += 1;
+                if warnings.len() < MAX_BATCH_WARNINGS {
+                    warnings.push(format!(
+                        "Line {}: '{}' is not a valid timestamp",
+                        index + 1,
+                        token
+                    ));
+                }
+                continue;
+            };
+
+            let (datetime, iso8601) = zone.format(&utc, &items);
+            formatted_output.push_str(token);
+            formatted_output.push('\t');
+            formatted_output.push_str(&datetime);
+            formatted_output.push('\n');
+
+            entries.push(BatchConvertEntry {
+                line: index + 1,
+                original: token.to_string(),
+                unix_milliseconds: utc.timestamp_millis(),
+                datetime,
+                iso8601,
+            });
+        }
+    }
+
+    if skipped_count > MAX_BATCH_WARNINGS {
+        warnings.push(format!(
+            "...and {} more invalid values",
+            skipped_count - MAX_BATCH_WARNINGS
+        ));
+    }
+
+    let error = entries
+        .is_empty()
+        .then(|| "No valid timestamps found".to_string());
+
+    BatchConvertResult {
+        success: error.is_none(),
+        converted_count: entries.len(),
+        entries,
+        skipped_count,
+        warnings,
+        formatted_output,
+        error,
     }
 }
 
@@ -279,6 +762,224 @@ mod tests {
         assert!(result.unix_milliseconds > 0);
     }
 
+    #[test]
+    fn test_unix_to_datetime_named_timezone() {
+        let result = unix_to_datetime(
+            0,
+            TimestampUnit::Seconds,
+            TimezoneOption::Named("America/New_York".to_string()),
+        );
+        assert!(result.success);
+        assert_eq!(result.datetime, "1969-12-31 19:00:00 EST");
+        assert_eq!(result.iso8601, "1969-12-31T19:00:00-05:00");
+
+        let unknown = unix_to_datetime(
+            0,
+            TimestampUnit::Seconds,
+            TimezoneOption::Named("Mars/Olympus".to_string()),
+        );
+        assert!(!unknown.success);
+        assert!(unknown.error.unwrap().contains("Mars/Olympus"));
+    }
+
+    #[test]
+    fn test_datetime_to_unix_named_timezone() {
+        let london = TimezoneOption::Named("Europe/London".to_string());
+        let result = datetime_to_unix("2024-07-01 12:00:00", london);
+        assert!(result.success);
+        assert_eq!(result.unix_seconds, 1719831600);
+        assert!(result.dst_transition.is_none());
+    }
+
+    #[test]
+    fn test_datetime_to_unix_dst_transitions() {
+        let new_york = TimezoneOption::Named("America/New_York".to_string());
+
+        let gap = datetime_to_unix("2024-03-10 02:30:00", new_york.clone());
+        assert!(gap.success);
+        assert_eq!(gap.dst_transition, Some(DstTransition::Nonexistent));
+        assert_eq!(gap.unix_seconds, 1710055800);
+        assert!(gap.warning.unwrap().contains("03:30:00"));
+
+        let overlap = datetime_to_unix("2024-11-03 01:30:00", new_york);
+        assert!(overlap.success);
+        assert_eq!(overlap.dst_transition, Some(DstTransition::Ambiguous));
+        assert_eq!(overlap.unix_seconds, 1730611800);
+        assert_eq!(overlap.alternative_unix_seconds, Some(1730615400));
+    }
+
+    #[test]
+    fn test_list_timezones() {
+        let zones = list_timezones();
+        let tokyo = zones.iter().find(|z| z.name == "Asia/Tokyo").unwrap();
+        assert_eq!(tokyo.utc_offset, "+09:00");
+        assert_eq!(tokyo.offset_seconds, 9 * 3600);
+        assert!(zones.iter().any(|z| z.name == "Europe/London"));
+    }
+
+    #[test]
+    fn test_add_duration_clamps_month_end() {
+        let jan_31_2024 = 1706659200;
+        let result = add_duration(
+            jan_31_2024,
+            TimestampUnit::Seconds,
+            0,
+            1,
+            0,
+            0,
+            0,
+            0,
+            TimezoneOption::Utc,
+        );
+        assert!(result.success);
+        assert_eq!(result.unix_seconds, 1709164800); // 2024-02-29
+
+        let jan_31_2023 = 1675123200;
+        let result = add_duration(
+            jan_31_2023,
+            TimestampUnit::Seconds,
+            0,
+            1,
+            0,
+            0,
+            0,
+            0,
+            TimezoneOption::Utc,
+        );
+        assert_eq!(result.unix_seconds, 1677542400); // 2023-02-28
+
+        let mar_31_2024 = 1711843200;
+        let result = add_duration(
+            mar_31_2024,
+            TimestampUnit::Seconds,
+            0,
+            -1,
+            0,
+            0,
+            0,
+            0,
+            TimezoneOption::Utc,
+        );
+        assert_eq!(result.unix_seconds, 1709164800);
+    }
+
+    #[test]
+    fn test_add_duration_across_dst() {
+        let new_york = TimezoneOption::Named("America/New_York".to_string());
+        let before_dst = 1710003600; // 2024-03-09 12:00 EST
+
+        let one_day = add_duration(
+            before_dst,
+            TimestampUnit::Seconds,
+            0,
+            0,
+            1,
+            0,
+            0,
+            0,
+            new_york.clone(),
+        );
+        assert_eq!(one_day.unix_seconds - before_dst, 23 * 3600);
+
+        let twenty_four_hours = add_duration(
+            before_dst * 1000 + 250,
+            TimestampUnit::Milliseconds,
+            0,
+            0,
+            0,
+            24,
+            0,
+            0,
+            new_york,
+        );
+        assert_eq!(twenty_four_hours.unix_seconds - before_dst, 24 * 3600);
+        assert_eq!(twenty_four_hours.unix_milliseconds % 1000, 250);
+    }
+
+    #[test]
+    fn test_diff_datetimes() {
+        let later = 3 * 86400 + 4 * 3600 + 5 * 60 + 6;
+        let result = diff_datetimes(0, later, TimestampUnit::Seconds);
+        assert!(result.success);
+        assert_eq!(result.total_seconds, later);
+        assert_eq!(
+            result.human_readable,
+            "3 days, 4 hours, 5 minutes, 6 seconds"
+        );
+
+        let negative = diff_datetimes(later, 0, TimestampUnit::Seconds);
+        assert!(negative.is_negative);
+        assert_eq!(negative.total_seconds, -later);
+        assert_eq!(negative.days, 3);
+        assert_eq!(
+            negative.human_readable,
+            "-3 days, 4 hours, 5 minutes, 6 seconds"
+        );
+
+        let millis = diff_datetimes(1000, 2500, TimestampUnit::Milliseconds);
+        assert_eq!(millis.human_readable, "1 second, 500 milliseconds");
+        assert_eq!(
+            diff_datetimes(5, 5, TimestampUnit::Seconds).human_readable,
+            "0 seconds"
+        );
+    }
+
+    #[test]
+    fn test_auto_unit_detection() {
+        let seconds = unix_to_datetime(1700000000, TimestampUnit::Auto, TimezoneOption::Utc);
+        let millis = unix_to_datetime(1700000000000, TimestampUnit::Auto, TimezoneOption::Utc);
+        assert_eq!(seconds.datetime, "2023-11-14 22:13:20 UTC");
+        assert_eq!(millis.datetime, seconds.datetime);
+
+        let diff = diff_datetimes(1700000000, 1700000001500, TimestampUnit::Auto);
+        assert_eq!(diff.total_milliseconds, 1500);
+    }
+
+    #[test]
+    fn test_batch_convert_timestamps() {
+        let input = "0, 86400\n  1700000000000\tabc\n\n12x 60".to_string();
+        let result = batch_convert_timestamps(input, TimestampUnit::Auto, TimezoneOption::Utc);
+
+        assert!(result.success);
+        assert_eq!(result.converted_count, 4);
+        assert_eq!(result.skipped_count, 2);
+        assert_eq!(result.entries[2].line, 2);
+        assert_eq!(result.entries[2].datetime, "2023-11-14 22:13:20 UTC");
+        assert_eq!(
+            result.warnings,
+            vec![
+                "Line 2: 'abc' is not a valid timestamp",
+                "Line 4: '12x' is not a valid timestamp"
+            ]
+        );
+        assert!(result
+            .formatted_output
+            .starts_with("0\t1970-01-01 00:00:00 UTC\n86400\t1970-01-02 00:00:00 UTC\n"));
+    }
+
+    #[test]
+    fn test_batch_convert_large_input() {
+        let input = (0..100_000)
+            .map(|i| (1_700_000_000 + i).to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = batch_convert_timestamps(
+            input,
+            TimestampUnit::Seconds,
+            TimezoneOption::Named("Asia/Tokyo".to_string()),
+        );
+        assert_eq!(result.converted_count, 100_000);
+        assert_eq!(result.entries[0].datetime, "2023-11-15 07:13:20 JST");
+
+        let invalid = batch_convert_timestamps(
+            "x\n".repeat(MAX_BATCH_WARNINGS + 5),
+            TimestampUnit::Seconds,
+            TimezoneOption::Utc,
+        );
+        assert!(!invalid.success);
+        assert_eq!(invalid.warnings.len(), MAX_BATCH_WARNINGS + 1);
+    }
+
     #[test]
     fn test_relative_time() {
         let now = Utc::now().timestamp();
@@ -0,0 +1,276 @@
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+
+/// Characters left as-is by `encodeURIComponent`
+const COMPONENT_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+/// Characters left as-is by `encodeURI` (component set plus reserved URI characters)
+const URI_SET: &AsciiSet = &COMPONENT_SET
+    .remove(b';')
+    .remove(b',')
+    .remove(b'/')
+    .remove(b'?')
+    .remove(b':')
+    .remove(b'@')
+    .remove(b'&')
+    .remove(b'=')
+    .remove(b'+')
+    .remove(b'$')
+    .remove(b'#');
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlEncodeResult {
+    pub success: bool,
+    pub output: String,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlDecodeResult {
+    pub success: bool,
+    pub output: String,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParam {
+    pub key: String,
+    pub value: String,
+    pub raw_key: String,
+    pub raw_value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParseResult {
+    pub success: bool,
+    pub base_url: Option<String>,
+    pub params: Vec<QueryParam>,
+    pub fragment: Option<String>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Percent-encode a string.
+///
+/// `encode_all = true` behaves like `encodeURIComponent` (reserved characters such as
+/// `/ ? & =` are encoded), `false` behaves like `encodeURI` and keeps the URL structure.
+pub fn encode_url(input: &str, encode_all: bool) -> UrlEncodeResult {
+    if input.is_empty() {
+        return UrlEncodeResult {
+            success: false,
+            output: String::new(),
+            warnings: Vec::new(),
+            error: Some("Input is empty".to_string()),
+        };
+    }
+
+    let set = if encode_all { COMPONENT_SET } else { URI_SET };
+    let output = utf8_percent_encode(input, set).to_string();
+
+    let mut warnings = Vec::new();
+    if contains_percent_sequence(input) {
+        warnings.push(
+            "Input already contains percent-encoded sequences; encoding again will double-encode them"
+                .to_string(),
+        );
+    }
+
+    UrlEncodeResult {
+        success: true,
+        output,
+        warnings,
+        error: None,
+    }
+}
+
+/// Decode a percent-encoded string
+pub fn decode_url(input: &str) -> UrlDecodeResult {
+    if input.is_empty() {
+        return UrlDecodeResult {
+            success: false,
+            output: String::new(),
+            warnings: Vec::new(),
+            error: Some("Input is empty".to_string()),
+        };
+    }
+
+    match percent_decode_str(input).decode_utf8() {
+        Ok(decoded) => {
+            let output = decoded.to_string();
+            let mut warnings = Vec::new();
+            if contains_percent_sequence(&output) {
+                warnings.push(
+                    "Decoded output still contains percent-encoded sequences (possible double encoding)"
+                        .to_string(),
+                );
+            }
+            UrlDecodeResult {
+                success: true,
+                output,
+                warnings,
+                error: None,
+            }
+        }
+        Err(e) => UrlDecodeResult {
+            success: false,
+            output: String::new(),
+            warnings: Vec::new(),
+            error: Some(format!("Decoded bytes are not valid UTF-8: {}", e)),
+        },
+    }
+}
+
+/// Parse the query string of a URL (or a bare `a=1&b=2` string) into decoded key/value pairs
+pub fn parse_query_string(input: &str) -> QueryParseResult {
+    let input = input.trim();
+    if input.is_empty() {
+        return QueryParseResult {
+            success: false,
+            base_url: None,
+            params: Vec::new(),
+            fragment: None,
+            warnings: Vec::new(),
+            error: Some("Input is empty".to_string()),
+        };
+    }
+
+    let (without_fragment, fragment) = match input.split_once('#') {
+        Some((before, after)) => (before, Some(decode_query_component(after))),
+        None => (input, None),
+    };
+
+    let (base_url, query) = match without_fragment.split_once('?') {
+        Some((base, query)) => (
+            if base.is_empty() {
+                None
+            } else {
+                Some(base.to_string())
+            },
+            query,
+        ),
+        None if without_fragment.contains("://") => (Some(without_fragment.to_string()), ""),
+        None => (None, without_fragment),
+    };
+
+    let mut warnings = Vec::new();
+    let params: Vec<QueryParam> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (raw_key, raw_value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = decode_query_component(raw_key);
+            let value = decode_query_component(raw_value);
+            if contains_percent_sequence(&value) {
+                warnings.push(format!(
+                    "Value of '{}' still contains percent-encoded sequences (possible double encoding)",
+                    key
+                ));
+            }
+            QueryParam {
+                key,
+                value,
+                raw_key: raw_key.to_string(),
+                raw_value: raw_value.to_string(),
+            }
+        })
+        .collect();
+
+    QueryParseResult {
+        success: true,
+        base_url,
+        params,
+        fragment,
+        warnings,
+        error: None,
+    }
+}
+
+/// Decode a query component, treating `+` as a space like `application/x-www-form-urlencoded`
+fn decode_query_component(input: &str) -> String {
+    let replaced = input.replace('+', " ");
+    percent_decode_str(&replaced)
+        .decode_utf8_lossy()
+        .into_owned()
+}
+
+fn contains_percent_sequence(text: &str) -> bool {
+    text.as_bytes()
+        .windows(3)
+        .any(|w| w[0] == b'%' && w[1].is_ascii_hexdigit() && w[2].is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_component() {
+        let result = encode_url("a b&c=d/あ", true);
+        assert!(result.success);
+        assert_eq!(result.output, "a%20b%26c%3Dd%2F%E3%81%82");
+    }
+
+    #[test]
+    fn test_encode_uri_keeps_reserved() {
+        let result = encode_url("https://example.com/a b?q=あ&x=1", false);
+        assert_eq!(result.output, "https://example.com/a%20b?q=%E3%81%82&x=1");
+    }
+
+    #[test]
+    fn test_decode() {
+        let result = decode_url("%E3%81%82%20b");
+        assert!(result.success);
+        assert_eq!(result.output, "あ b");
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_double_encoding_warnings() {
+        assert!(!encode_url("%E3%81%82", true).warnings.is_empty());
+        let decoded = decode_url("%25E3%2581%2582");
+        assert_eq!(decoded.output, "%E3%81%82");
+        assert!(!decoded.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_decode_invalid_utf8() {
+        let result = decode_url("%FF%FE");
+        assert!(!result.success);
+        assert!(result.error.is_some());
+    }
+
+    #[test]
+    fn test_parse_query_string() {
+        let result = parse_query_string("?a=1&b=%E3%81%82&c=hello+world&flag");
+        assert!(result.success);
+        assert_eq!(result.params.len(), 4);
+        assert_eq!(result.params[1].key, "b");
+        assert_eq!(result.params[1].value, "あ");
+        assert_eq!(result.params[2].value, "hello world");
+        assert_eq!(result.params[3].key, "flag");
+        assert_eq!(result.params[3].value, "");
+    }
+
+    #[test]
+    fn test_parse_full_url() {
+        let result = parse_query_string("https://example.com/path?x=1#section");
+        assert_eq!(result.base_url.as_deref(), Some("https://example.com/path"));
+        assert_eq!(result.params.len(), 1);
+        assert_eq!(result.fragment.as_deref(), Some("section"));
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// Usage is written to disk at most this long after the latest change
+const SAVE_DELAY: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolUsage {
+    pub tool_id: String,
+    pub count: u64,
+    /// RFC 3339 time the tool was last opened
+    pub last_used: String,
+}
+
+/// Count one use of `tool_id` at `now`
+pub fn record_usage(usage: &mut Vec<ToolUsage>, tool_id: &str, now: &str) {
+    match usage.iter_mut().find(|u| u.tool_id == tool_id) {
+        Some(entry) => {
+            entry.count += 1;
+            entry.last_used = now.to_string();
+        }
+        None => usage.push(ToolUsage {
+            tool_id: tool_id.to_string(),
+            count: 1,
+            last_used: now.to_string(),
+        }),
+    }
+}
+
+/// Most recently used first, ties broken by count
+pub fn sort_by_recent(usage: &mut [ToolUsage]) {
+    usage.sort_by(|a, b| {
+        b.last_used
+            .cmp(&a.last_used)
+            .then_with(|| b.count.cmp(&a.count))
+    });
+}
+
+fn get_usage_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("tool_usage.json"))
+}
+
+fn load_usage(app: &AppHandle) -> Result<Vec<ToolUsage>, String> {
+    let path = get_usage_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read tool usage: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse tool usage: {}", e))
+}
+
+fn save_usage(app: &AppHandle, usage: &[ToolUsage]) -> Result<(), String> {
+    let path = get_usage_path(app)?;
+    let content =
+        serde_json::to_string(usage).map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write tool usage: {}", e))
+}
+
+/// Tool usage counts, managed as Tauri state.
+///
+/// Every tab switch records a use, so changes are kept in memory and written in one
+/// batch shortly afterwards instead of on every call.
+#[derive(Default)]
+pub struct UsageStore {
+    /// Loaded from disk on first use
+    usage: Mutex<Option<Vec<ToolUsage>>>,
+    save_pending: AtomicBool,
+}
+
+impl UsageStore {
+    fn with_usage<T>(
+        &self,
+        app: &AppHandle,
+        f: impl FnOnce(&mut Vec<ToolUsage>) -> T,
+    ) -> Result<T, String> {
+        let mut guard = self
+            .usage
+            .lock()
+            .map_err(|_| "Tool usage is unavailable".to_string())?;
+        if guard.is_none() {
+            // A corrupt file only loses the ranking, so start over instead of failing
+            *guard = Some(load_usage(app).unwrap_or_default());
+        }
+        Ok(f(guard.as_mut().expect("usage was just loaded")))
+    }
+
+    /// Write pending changes to disk now
+    pub fn flush(&self, app: &AppHandle) -> Result<(), String> {
+        if !self.save_pending.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+        let usage = self.with_usage(app, |usage| usage.clone())?;
+        save_usage(app, &usage)
+    }
+}
+
+pub fn record_tool_usage(app: &AppHandle, store: &UsageStore, tool_id: &str) -> Result<(), String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    store.with_usage(app, |usage| record_usage(usage, tool_id, &now))?;
+    if !store.save_pending.swap(true, Ordering::SeqCst) {
+        let app = app.clone();
+        thread::spawn(move || {
+            thread::sleep(SAVE_DELAY);
+            let _ = app.state::<UsageStore>().flush(&app);
+        });
+    }
+    Ok(())
+}
+
+pub fn get_tool_usage(app: &AppHandle, store: &UsageStore) -> Result<Vec<ToolUsage>, String> {
+    let mut usage = store.with_usage(app, |usage| usage.clone())?;
+    sort_by_recent(&mut usage);
+    Ok(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_usage_counts_and_sorts_by_recent() {
+        let mut usage = Vec::new();
+        record_usage(&mut usage, "json_formatter", "2024-05-01T10:00:00+00:00");
+        record_usage(&mut usage, "json_formatter", "2024-05-01T11:00:00+00:00");
+        record_usage(&mut usage, "text_diff", "2024-05-01T12:00:00+00:00");
+        record_usage(&mut usage, "uuid_generator", "2024-05-01T09:00:00+00:00");
+        assert_eq!(usage.len(), 3);
+        assert_eq!(usage[0].count, 2);
+        assert_eq!(usage[0].last_used, "2024-05-01T11:00:00+00:00");
+
+        sort_by_recent(&mut usage);
+        let ids: Vec<&str> = usage.iter().map(|u| u.tool_id.as_str()).collect();
+        assert_eq!(ids, vec!["text_diff", "json_formatter", "uuid_generator"]);
+    }
+}
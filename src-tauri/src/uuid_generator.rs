@@ -1,9 +1,22 @@
+use chrono::{DateTime, Local};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+const ULID_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const NANOID_DEFAULT_ALPHABET: &str =
+    "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const NANOID_DEFAULT_LENGTH: usize = 21;
+const NANOID_MAX_LENGTH: usize = 128;
+const NANOID_MAX_ALPHABET: usize = 256;
+/// Generation rate assumed for the NanoID collision estimate
+const NANOID_IDS_PER_SECOND: f64 = 1000.0;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum UuidVersion {
+    V3,
     V4,
+    V5,
     V7,
 }
 
@@ -22,6 +35,12 @@ pub struct UuidGenerateOptions {
     pub version: UuidVersion,
     pub format: UuidFormat,
     pub count: u32,
+    /// Namespace for v3/v5: `DNS`, `URL`, `OID`, `X500` or any UUID
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// Names for v3/v5, one UUID is generated per non-empty line
+    #[serde(default)]
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,70 +61,486 @@ fn format_uuid(uuid: &Uuid, format: &UuidFormat) -> String {
     }
 }
 
+fn parse_namespace(namespace: &str) -> Result<Uuid, String> {
+    match namespace.trim().to_ascii_uppercase().as_str() {
+        "DNS" => Ok(Uuid::NAMESPACE_DNS),
+        "URL" => Ok(Uuid::NAMESPACE_URL),
+        "OID" => Ok(Uuid::NAMESPACE_OID),
+        "X500" => Ok(Uuid::NAMESPACE_X500),
+        _ => Uuid::try_parse(strip_uuid_wrappers(namespace))
+            .map_err(|e| format!("Invalid namespace UUID: {}", e)),
+    }
+}
+
+fn generate_name_based(options: &UuidGenerateOptions) -> Result<Vec<Uuid>, String> {
+    let namespace = parse_namespace(options.namespace.as_deref().unwrap_or("DNS"))?;
+    let names: Vec<&str> = options
+        .name
+        .as_deref()
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(1000)
+        .collect();
+    if names.is_empty() {
+        return Err("A name is required for v3/v5 UUIDs".to_string());
+    }
+
+    Ok(names
+        .into_iter()
+        .map(|name| match options.version {
+            UuidVersion::V3 => Uuid::new_v3(&namespace, name.as_bytes()),
+            _ => Uuid::new_v5(&namespace, name.as_bytes()),
+        })
+        .collect())
+}
+
+/// Generate UUIDs. v3/v5 are deterministic, so instead of `count` they produce one UUID per
+/// line of `name`.
 pub fn generate_uuids(options: UuidGenerateOptions) -> UuidGenerateResult {
-    let count = options.count.clamp(1, 1000);
+    let uuids = match options.version {
+        UuidVersion::V3 | UuidVersion::V5 => match generate_name_based(&options) {
+            Ok(uuids) => uuids,
+            Err(e) => {
+                return UuidGenerateResult {
+                    success: false,
+                    uuids: Vec::new(),
+                    error: Some(e),
+                }
+            }
+        },
+        UuidVersion::V4 => (0..options.count.clamp(1, 1000))
+            .map(|_| Uuid::new_v4())
+            .collect(),
+        UuidVersion::V7 => (0..options.count.clamp(1, 1000))
+            .map(|_| Uuid::now_v7())
+            .collect(),
+    };
+
+    UuidGenerateResult {
+        success: true,
+        uuids: uuids
+            .iter()
+            .map(|uuid| format_uuid(uuid, &options.format))
+            .collect(),
+        error: None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum IdKind {
+    Ulid,
+    NanoId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdGenerateOptions {
+    pub count: u32,
+    /// NanoID length, 21 when omitted
+    #[serde(default)]
+    pub length: Option<usize>,
+    /// NanoID alphabet, URL-safe `A-Za-z0-9_-` when omitted
+    #[serde(default)]
+    pub alphabet: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedId {
+    pub value: String,
+    /// Decoded timestamp of a ULID
+    pub timestamp_utc: Option<String>,
+    pub timestamp_local: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdGenerateResult {
+    pub success: bool,
+    pub ids: Vec<GeneratedId>,
+    pub entropy_bits: Option<f64>,
+    /// How long it takes to reach a 1% collision probability, for NanoIDs
+    pub collision_estimate: Option<String>,
+    pub error: Option<String>,
+}
+
+impl IdGenerateResult {
+    fn failure(error: String) -> Self {
+        Self {
+            success: false,
+            ids: Vec::new(),
+            entropy_bits: None,
+            collision_estimate: None,
+            error: Some(error),
+        }
+    }
+}
+
+pub fn generate_ids(kind: IdKind, options: IdGenerateOptions) -> IdGenerateResult {
+    let count = options.count.clamp(1, 1000) as usize;
+    match kind {
+        IdKind::Ulid => generate_ulids(count),
+        IdKind::NanoId => generate_nanoids(count, &options),
+    }
+}
+
+fn encode_ulid(timestamp_ms: u64, randomness: u128) -> String {
+    let value = ((timestamp_ms as u128) << 80) | (randomness & ((1u128 << 80) - 1));
+    (0..26)
+        .rev()
+        .map(|i| ULID_ALPHABET[((value >> (i * 5)) & 0x1f) as usize] as char)
+        .collect()
+}
 
-    let uuids: Vec<String> = (0..count)
+/// Decode the millisecond timestamp of a ULID, accepting lowercase and Crockford's
+/// `I`/`L`/`O` aliases.
+pub fn decode_ulid_timestamp(ulid: &str) -> Result<u64, String> {
+    let ulid = ulid.trim();
+    if ulid.chars().count() != 26 {
+        return Err(format!(
+            "A ULID has 26 characters, but the input has {}",
+            ulid.chars().count()
+        ));
+    }
+
+    let mut value: u128 = 0;
+    for (i, c) in ulid.chars().enumerate() {
+        let c = match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        };
+        let digit = ULID_ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .ok_or_else(|| format!("Invalid ULID character '{}' at position {}", c, i + 1))?;
+        // The first character only has 3 bits available within 128 bits
+        if i == 0 && digit > 7 {
+            return Err("ULID timestamp overflows 48 bits".to_string());
+        }
+        value = (value << 5) | digit as u128;
+    }
+
+    Ok((value >> 80) as u64)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UlidDecodeResult {
+    pub valid: bool,
+    pub unix_milliseconds: Option<i64>,
+    pub timestamp_utc: Option<String>,
+    pub timestamp_local: Option<String>,
+    pub error: Option<String>,
+}
+
+pub fn decode_ulid(input: &str) -> UlidDecodeResult {
+    match decode_ulid_timestamp(input) {
+        Ok(ms) => {
+            let (millis, utc, local) =
+                format_timestamp((ms / 1000) as i64, (ms % 1000) as u32 * 1_000_000);
+            UlidDecodeResult {
+                valid: true,
+                unix_milliseconds: Some(millis),
+                timestamp_utc: Some(utc),
+                timestamp_local: Some(local),
+                error: None,
+            }
+        }
+        Err(e) => UlidDecodeResult {
+            valid: false,
+            unix_milliseconds: None,
+            timestamp_utc: None,
+            timestamp_local: None,
+            error: Some(e),
+        },
+    }
+}
+
+fn generate_ulids(count: usize) -> IdGenerateResult {
+    let mut rng = rand::thread_rng();
+    let timestamp_ms = chrono::Utc::now().timestamp_millis() as u64;
+    // Monotonic within the batch: the randomness is incremented for every following ID
+    // so the batch sorts in generation order
+    let mut randomness: u128 = rng.gen::<u128>() & ((1u128 << 79) - 1);
+
+    let (_, utc, local) = format_timestamp(
+        (timestamp_ms / 1000) as i64,
+        (timestamp_ms % 1000) as u32 * 1_000_000,
+    );
+    let ids = (0..count)
         .map(|_| {
-            let uuid = match options.version {
-                UuidVersion::V4 => Uuid::new_v4(),
-                UuidVersion::V7 => Uuid::now_v7(),
-            };
-            format_uuid(&uuid, &options.format)
+            let value = encode_ulid(timestamp_ms, randomness);
+            randomness += 1;
+            GeneratedId {
+                value,
+                timestamp_utc: Some(utc.clone()),
+                timestamp_local: Some(local.clone()),
+            }
         })
         .collect();
 
-    UuidGenerateResult {
+    IdGenerateResult {
         success: true,
-        uuids,
+        ids,
+        entropy_bits: Some(80.0),
+        collision_estimate: None,
         error: None,
     }
 }
 
+fn generate_nanoids(count: usize, options: &IdGenerateOptions) -> IdGenerateResult {
+    let length = options.length.unwrap_or(NANOID_DEFAULT_LENGTH);
+    if !(1..=NANOID_MAX_LENGTH).contains(&length) {
+        return IdGenerateResult::failure(format!(
+            "NanoID length must be between 1 and {}",
+            NANOID_MAX_LENGTH
+        ));
+    }
+
+    let alphabet: Vec<char> = options
+        .alphabet
+        .as_deref()
+        .filter(|a| !a.is_empty())
+        .unwrap_or(NANOID_DEFAULT_ALPHABET)
+        .chars()
+        .collect();
+    if alphabet.len() < 2 || alphabet.len() > NANOID_MAX_ALPHABET {
+        return IdGenerateResult::failure(format!(
+            "The alphabet must have between 2 and {} characters",
+            NANOID_MAX_ALPHABET
+        ));
+    }
+    let mut seen = std::collections::HashSet::new();
+    if let Some(duplicate) = alphabet.iter().find(|c| !seen.insert(**c)) {
+        return IdGenerateResult::failure(format!(
+            "The alphabet contains '{}' more than once",
+            duplicate
+        ));
+    }
+
+    let mut rng = rand::thread_rng();
+    let ids = (0..count)
+        .map(|_| GeneratedId {
+            value: (0..length)
+                .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+                .collect(),
+            timestamp_utc: None,
+            timestamp_local: None,
+        })
+        .collect();
+
+    let entropy_bits = length as f64 * (alphabet.len() as f64).log2();
+    IdGenerateResult {
+        success: true,
+        ids,
+        entropy_bits: Some(entropy_bits),
+        collision_estimate: Some(nanoid_collision_estimate(entropy_bits)),
+        error: None,
+    }
+}
+
+/// Describe how long generating IDs at `NANOID_IDS_PER_SECOND` takes before the birthday
+/// bound reaches a 1% collision probability.
+fn nanoid_collision_estimate(entropy_bits: f64) -> String {
+    // n ≈ sqrt(2 * N * ln(1 / (1 - p))), computed in log2 so large spaces do not overflow
+    let log2_ids = 0.5 * (1.0 + entropy_bits + (1.0f64 / 0.99).ln().log2());
+    let seconds = 2f64.powf(log2_ids) / NANOID_IDS_PER_SECOND;
+    format!(
+        "~{} needed, in order to have a 1% probability of at least one collision at {} IDs per second",
+        format_duration_estimate(seconds),
+        NANOID_IDS_PER_SECOND
+    )
+}
+
+fn format_duration_estimate(seconds: f64) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = 60.0 * MINUTE;
+    const DAY: f64 = 24.0 * HOUR;
+    const YEAR: f64 = 365.25 * DAY;
+
+    let (value, unit) = if seconds < 1.0 {
+        return "less than 1 second".to_string();
+    } else if seconds < MINUTE {
+        (seconds, "seconds")
+    } else if seconds < HOUR {
+        (seconds / MINUTE, "minutes")
+    } else if seconds < DAY {
+        (seconds / HOUR, "hours")
+    } else if seconds < YEAR {
+        (seconds / DAY, "days")
+    } else {
+        let years = seconds / YEAR;
+        return match years {
+            y if y < 1e3 => format!("{:.0} years", y),
+            y if y < 1e6 => format!("{:.0} thousand years", y / 1e3),
+            y if y < 1e9 => format!("{:.0} million years", y / 1e6),
+            y if y < 1e12 => format!("{:.0} billion years", y / 1e9),
+            y if y < 1e15 => format!("{:.0} trillion years", y / 1e12),
+            y => format!("{:.1e} years", y),
+        };
+    };
+    format!("{:.0} {}", value, unit)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UuidValidateResult {
     pub valid: bool,
+    pub normalized: Option<String>,
+    pub version_number: Option<u8>,
     pub version: Option<String>,
     pub variant: Option<String>,
+    pub unix_milliseconds: Option<i64>,
+    pub timestamp_utc: Option<String>,
+    pub timestamp_local: Option<String>,
+    pub node: Option<String>,
+    pub clock_sequence: Option<u16>,
     pub error: Option<String>,
 }
 
-pub fn validate_uuid(input: &str) -> UuidValidateResult {
-    match Uuid::parse_str(input.trim()) {
-        Ok(uuid) => {
-            let version = match uuid.get_version_num() {
-                1 => "v1 (Time-based)",
-                2 => "v2 (DCE Security)",
-                3 => "v3 (MD5 Name-based)",
-                4 => "v4 (Random)",
-                5 => "v5 (SHA-1 Name-based)",
-                6 => "v6 (Reordered Time-based)",
-                7 => "v7 (Unix Epoch Time-based)",
-                8 => "v8 (Custom)",
-                _ => "Unknown",
-            };
-
-            let variant = match uuid.get_variant() {
-                uuid::Variant::NCS => "NCS",
-                uuid::Variant::RFC4122 => "RFC 4122",
-                uuid::Variant::Microsoft => "Microsoft",
-                uuid::Variant::Future => "Future",
-                _ => "Unknown",
-            };
-
-            UuidValidateResult {
-                valid: true,
-                version: Some(version.to_string()),
-                variant: Some(variant.to_string()),
-                error: None,
-            }
-        }
-        Err(e) => UuidValidateResult {
+impl UuidValidateResult {
+    fn failure(error: String) -> Self {
+        Self {
             valid: false,
+            normalized: None,
+            version_number: None,
             version: None,
             variant: None,
-            error: Some(e.to_string()),
-        },
+            unix_milliseconds: None,
+            timestamp_utc: None,
+            timestamp_local: None,
+            node: None,
+            clock_sequence: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UuidBulkValidateEntry {
+    pub line: usize,
+    pub input: String,
+    pub result: UuidValidateResult,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UuidBulkValidateResult {
+    pub entries: Vec<UuidBulkValidateEntry>,
+    pub valid_count: usize,
+    pub invalid_count: usize,
+}
+
+/// Strip the wrappers UUIDs commonly appear in (`urn:uuid:` in any case, `{...}`) so that
+/// only the hex digits and optional hyphens are left for parsing.
+fn strip_uuid_wrappers(input: &str) -> &str {
+    let trimmed = input.trim();
+    let without_urn = match trimmed.get(..9) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("urn:uuid:") => &trimmed[9..],
+        _ => trimmed,
+    };
+    without_urn
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .unwrap_or(without_urn)
+        .trim()
+}
+
+fn format_timestamp(seconds: i64, nanos: u32) -> (i64, String, String) {
+    let utc = DateTime::from_timestamp(seconds, nanos).unwrap_or_default();
+    let local = utc.with_timezone(&Local);
+    (
+        utc.timestamp_millis(),
+        utc.format("%Y-%m-%d %H:%M:%S%.3f UTC").to_string(),
+        local.format("%Y-%m-%d %H:%M:%S%.3f %:z").to_string(),
+    )
+}
+
+pub fn validate_uuid(input: &str) -> UuidValidateResult {
+    let uuid = match Uuid::try_parse(strip_uuid_wrappers(input)) {
+        Ok(uuid) => uuid,
+        Err(e) => return UuidValidateResult::failure(e.to_string()),
+    };
+
+    let version_number = uuid.get_version_num() as u8;
+    let version = match version_number {
+        1 => "v1 (Time-based)",
+        2 => "v2 (DCE Security)",
+        3 => "v3 (MD5 Name-based)",
+        4 => "v4 (Random)",
+        5 => "v5 (SHA-1 Name-based)",
+        6 => "v6 (Reordered Time-based)",
+        7 => "v7 (Unix Epoch Time-based)",
+        8 => "v8 (Custom)",
+        _ if uuid.is_nil() => "Nil",
+        _ if uuid.is_max() => "Max",
+        _ => "Unknown",
+    };
+
+    let variant = match uuid.get_variant() {
+        uuid::Variant::NCS => "NCS",
+        uuid::Variant::RFC4122 => "RFC 4122",
+        uuid::Variant::Microsoft => "Microsoft",
+        uuid::Variant::Future => "Future",
+        _ => "Unknown",
+    };
+
+    // Only RFC 4122 UUIDs carry a meaningful version, so other variants are not decoded
+    let rfc4122 = uuid.get_variant() == uuid::Variant::RFC4122;
+    let timestamp = uuid.get_timestamp().filter(|_| rfc4122);
+    let (unix_milliseconds, timestamp_utc, timestamp_local) = match timestamp {
+        Some(ts) => {
+            let (seconds, nanos) = ts.to_unix();
+            let (millis, utc, local) = format_timestamp(seconds as i64, nanos);
+            (Some(millis), Some(utc), Some(local))
+        }
+        None => (None, None, None),
+    };
+
+    // v7 has no clock sequence; the counter bits of its timestamp are random data
+    let clock_sequence = timestamp
+        .filter(|_| matches!(version_number, 1 | 6))
+        .map(|ts| ts.to_gregorian().1);
+
+    let node = uuid.get_node_id().filter(|_| rfc4122).map(|bytes| {
+        bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(":")
+    });
+
+    UuidValidateResult {
+        valid: true,
+        normalized: Some(uuid.to_string()),
+        version_number: Some(version_number),
+        version: Some(version.to_string()),
+        variant: Some(variant.to_string()),
+        unix_milliseconds,
+        timestamp_utc,
+        timestamp_local,
+        node,
+        clock_sequence,
+        error: None,
+    }
+}
+
+/// Validate one UUID per line, skipping blank lines. Line numbers are 1-based.
+pub fn validate_uuids_bulk(input: &str) -> UuidBulkValidateResult {
+    let entries: Vec<UuidBulkValidateEntry> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| UuidBulkValidateEntry {
+            line: index + 1,
+            input: line.trim().to_string(),
+            result: validate_uuid(line),
+        })
+        .collect();
+
+    let valid_count = entries.iter().filter(|e| e.result.valid).count();
+    let invalid_count = entries.len() - valid_count;
+
+    UuidBulkValidateResult {
+        entries,
+        valid_count,
+        invalid_count,
     }
 }
 
@@ -119,6 +554,8 @@ mod tests {
             version: UuidVersion::V4,
             format: UuidFormat::Standard,
             count: 5,
+            namespace: None,
+            name: None,
         };
         let result = generate_uuids(options);
         assert!(result.success);
@@ -158,4 +595,196 @@ mod tests {
         assert!(!result.valid);
         assert!(result.error.is_some());
     }
+
+    #[test]
+    fn test_validate_uuid_normalizes_input() {
+        let expected = "550e8400-e29b-41d4-a716-446655440000";
+        for input in [
+            "550E8400-E29B-41D4-A716-446655440000",
+            "550e8400e29b41d4a716446655440000",
+            "{550e8400-e29b-41d4-a716-446655440000}",
+            "URN:UUID:550e8400-e29b-41d4-a716-446655440000",
+            "  urn:uuid:{550E8400E29B41D4A716446655440000}  ",
+        ] {
+            let result = validate_uuid(input);
+            assert!(result.valid, "{}: {:?}", input, result.error);
+            assert_eq!(result.normalized.as_deref(), Some(expected));
+            assert_eq!(result.version_number, Some(4));
+            assert_eq!(result.variant.as_deref(), Some("RFC 4122"));
+            assert!(result.timestamp_utc.is_none());
+            assert!(result.node.is_none());
+        }
+    }
+
+    #[test]
+    fn test_validate_uuid_v1_fields() {
+        // Example from RFC 9562 Appendix A.1
+        let result = validate_uuid("C232AB00-9414-11EC-B3C8-9F6BDECED846");
+        assert!(result.valid);
+        assert_eq!(result.version_number, Some(1));
+        assert_eq!(result.unix_milliseconds, Some(1645557742000));
+        assert_eq!(
+            result.timestamp_utc.as_deref(),
+            Some("2022-02-22 19:22:22.000 UTC")
+        );
+        assert!(result.timestamp_local.is_some());
+        assert_eq!(result.node.as_deref(), Some("9f:6b:de:ce:d8:46"));
+        assert_eq!(result.clock_sequence, Some(0x33c8));
+    }
+
+    #[test]
+    fn test_validate_uuid_v7_timestamp() {
+        // Example from RFC 9562 Appendix A.6
+        let result = validate_uuid("017F22E2-79B0-7CC3-98C4-DC0C0C07398F");
+        assert!(result.valid);
+        assert_eq!(result.version_number, Some(7));
+        assert_eq!(result.unix_milliseconds, Some(1645557742000));
+        assert_eq!(
+            result.timestamp_utc.as_deref(),
+            Some("2022-02-22 19:22:22.000 UTC")
+        );
+        assert!(result.node.is_none());
+        assert!(result.clock_sequence.is_none());
+
+        let generated = Uuid::now_v7();
+        let result = validate_uuid(&generated.to_string());
+        let now = chrono::Utc::now().timestamp_millis();
+        assert!((now - result.unix_milliseconds.unwrap()).abs() < 60_000);
+    }
+
+    #[test]
+    fn test_validate_uuids_bulk() {
+        let input = "550e8400-e29b-41d4-a716-446655440000\n\n  not-a-uuid  \n{017f22e2-79b0-7cc3-98c4-dc0c0c07398f}\n";
+        let result = validate_uuids_bulk(input);
+        assert_eq!(result.entries.len(), 3);
+        assert_eq!(result.valid_count, 2);
+        assert_eq!(result.invalid_count, 1);
+
+        let lines: Vec<usize> = result.entries.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![1, 3, 4]);
+        assert_eq!(result.entries[1].input, "not-a-uuid");
+        assert!(!result.entries[1].result.valid);
+        assert_eq!(result.entries[2].result.version_number, Some(7));
+    }
+
+    #[test]
+    fn test_generate_name_based_uuids() {
+        let options = |version, namespace: &str, name: &str| UuidGenerateOptions {
+            version,
+            format: UuidFormat::Standard,
+            count: 1,
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+        };
+
+        // Known values for python.org in the DNS namespace
+        let v3 = generate_uuids(options(UuidVersion::V3, "dns", "python.org"));
+        assert_eq!(v3.uuids, vec!["6fa459ea-ee8a-3ca4-894e-db77e160355e"]);
+        let v5 = generate_uuids(options(UuidVersion::V5, "DNS", "python.org\n\nexample.com"));
+        assert_eq!(v5.uuids.len(), 2);
+        assert_eq!(v5.uuids[0], "886313e1-3b8a-5372-9b90-0c9aee199e5d");
+
+        let custom = generate_uuids(options(
+            UuidVersion::V5,
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "python.org",
+        ));
+        assert_eq!(custom.uuids, v5.uuids[..1]);
+
+        let missing_name = generate_uuids(options(UuidVersion::V5, "DNS", "  "));
+        assert!(!missing_name.success);
+        let bad_namespace = generate_uuids(options(UuidVersion::V3, "nope", "a"));
+        assert!(bad_namespace.error.unwrap().contains("namespace"));
+    }
+
+    #[test]
+    fn test_generate_ulids() {
+        let before = chrono::Utc::now().timestamp_millis() as u64;
+        let options = IdGenerateOptions {
+            count: 50,
+            length: None,
+            alphabet: None,
+        };
+        let result = generate_ids(IdKind::Ulid, options);
+        assert!(result.success);
+        assert_eq!(result.ids.len(), 50);
+
+        let values: Vec<&str> = result.ids.iter().map(|id| id.value.as_str()).collect();
+        let mut sorted = values.clone();
+        sorted.sort();
+        assert_eq!(values, sorted);
+        sorted.dedup();
+        assert_eq!(sorted.len(), 50);
+
+        let first = &result.ids[0];
+        assert_eq!(first.value.len(), 26);
+        let decoded = decode_ulid_timestamp(&first.value).unwrap();
+        assert!(decoded >= before && decoded - before < 60_000);
+        assert!(first.timestamp_utc.as_deref().unwrap().ends_with("UTC"));
+    }
+
+    #[test]
+    fn test_decode_ulid_timestamp() {
+        assert_eq!(
+            decode_ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap(),
+            1469922850259
+        );
+        assert_eq!(
+            decode_ulid_timestamp("01arz3ndektsv4rrffq69g5fav").unwrap(),
+            1469922850259
+        );
+        assert!(decode_ulid_timestamp("01ARZ3NDEK").is_err());
+
+        let result = decode_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert!(result.valid);
+        assert_eq!(
+            result.timestamp_utc.as_deref(),
+            Some("2016-07-30 23:54:10.259 UTC")
+        );
+        assert!(decode_ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAU").is_err());
+        assert!(decode_ulid_timestamp("81ARZ3NDEKTSV4RRFFQ69G5FAV").is_err());
+    }
+
+    #[test]
+    fn test_generate_nanoids() {
+        let options = IdGenerateOptions {
+            count: 10,
+            length: None,
+            alphabet: None,
+        };
+        let result = generate_ids(IdKind::NanoId, options);
+        assert!(result.success);
+        assert!(result.ids.iter().all(|id| id.value.len() == 21
+            && id
+                .value
+                .chars()
+                .all(|c| NANOID_DEFAULT_ALPHABET.contains(c))));
+        assert_eq!(result.entropy_bits, Some(126.0));
+        assert!(result
+            .collision_estimate
+            .unwrap()
+            .contains("41 million years"));
+
+        let options = IdGenerateOptions {
+            count: 3,
+            length: Some(8),
+            alphabet: Some("0123456789".to_string()),
+        };
+        let result = generate_ids(IdKind::NanoId, options);
+        assert!(result
+            .ids
+            .iter()
+            .all(|id| id.value.len() == 8 && id.value.chars().all(|c| c.is_ascii_digit())));
+        assert!(result.collision_estimate.unwrap().contains("seconds"));
+
+        let duplicate = generate_ids(
+            IdKind::NanoId,
+            IdGenerateOptions {
+                count: 1,
+                length: Some(8),
+                alphabet: Some("aab".to_string()),
+            },
+        );
+        assert!(duplicate.error.unwrap().contains("'a'"));
+    }
 }
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, Window};
+
+/// Smallest window restored, so a bad file cannot leave the window unusably small
+const MIN_WIDTH: u32 = 400;
+const MIN_HEIGHT: u32 = 300;
+/// Part of the window that has to be on a monitor for the saved position to be kept
+const MIN_VISIBLE: i64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowBounds {
+    fn overlap(&self, other: &WindowBounds) -> (i64, i64) {
+        let left = i64::from(self.x).max(i64::from(other.x));
+        let top = i64::from(self.y).max(i64::from(other.y));
+        let right = (i64::from(self.x) + i64::from(self.width))
+            .min(i64::from(other.x) + i64::from(other.width));
+        let bottom = (i64::from(self.y) + i64::from(self.height))
+            .min(i64::from(other.y) + i64::from(other.height));
+        ((right - left).max(0), (bottom - top).max(0))
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WindowState {
+    /// Size and position of the window when it was last not maximized
+    pub bounds: Option<WindowBounds>,
+    pub maximized: bool,
+    pub last_tab: Option<String>,
+}
+
+/// Keep `bounds` where it is if enough of it is on one of `monitors`; otherwise shrink it
+/// to fit `primary` if needed and center it there
+pub fn fit_to_monitors(
+    bounds: WindowBounds,
+    monitors: &[WindowBounds],
+    primary: WindowBounds,
+) -> WindowBounds {
+    let width = bounds.width.max(MIN_WIDTH);
+    let height = bounds.height.max(MIN_HEIGHT);
+    let bounds = WindowBounds {
+        width,
+        height,
+        ..bounds
+    };
+    let visible = monitors.iter().any(|monitor| {
+        let (w, h) = bounds.overlap(monitor);
+        w >= MIN_VISIBLE.min(i64::from(width)) && h >= MIN_VISIBLE.min(i64::from(height))
+    });
+    if visible {
+        return bounds;
+    }
+
+    let width = width.min(primary.width);
+    let height = height.min(primary.height);
+    WindowBounds {
+        x: primary.x + ((primary.width - width) / 2) as i32,
+        y: primary.y + ((primary.height - height) / 2) as i32,
+        width,
+        height,
+    }
+}
+
+fn get_state_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("window_state.json"))
+}
+
+fn load_state(app: &AppHandle) -> WindowState {
+    get_state_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_state(app: &AppHandle, state: &WindowState) -> Result<(), String> {
+    let path = get_state_path(app)?;
+    let content =
+        serde_json::to_string_pretty(state).map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write window state: {}", e))
+}
+
+fn monitor_bounds(monitor: &tauri::Monitor) -> WindowBounds {
+    WindowBounds {
+        x: monitor.position().x,
+        y: monitor.position().y,
+        width: monitor.size().width,
+        height: monitor.size().height,
+    }
+}
+
+/// Move and resize the main window to where it was when the app last closed
+pub fn restore_window_state(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let state = load_state(app);
+    if let Some(bounds) = state.bounds {
+        let monitors: Vec<WindowBounds> = window
+            .available_monitors()
+            .map(|monitors| monitors.iter().map(monitor_bounds).collect())
+            .unwrap_or_default();
+        let primary = window
+            .primary_monitor()
+            .ok()
+            .flatten()
+            .map(|monitor| monitor_bounds(&monitor))
+            .or_else(|| monitors.first().copied());
+        if let Some(primary) = primary {
+            let bounds = fit_to_monitors(bounds, &monitors, primary);
+            let _ = window.set_size(PhysicalSize::new(bounds.width, bounds.height));
+            let _ = window.set_position(PhysicalPosition::new(bounds.x, bounds.y));
+        }
+    }
+    if state.maximized {
+        let _ = window.maximize();
+    }
+}
+
+/// Remember the size and position of `window` as it closes
+pub fn save_window_state(window: &Window) -> Result<(), String> {
+    let app = window.app_handle();
+    let mut state = load_state(app);
+    state.maximized = window.is_maximized().unwrap_or(false);
+    // A maximized window keeps the bounds it had before, so un-maximizing still works.
+    // The size saved is the inner size, since that is what `set_size` restores; the outer
+    // size would grow the window by its frame on every launch.
+    if !state.maximized {
+        if let (Ok(size), Ok(position)) = (window.inner_size(), window.outer_position()) {
+            state.bounds = Some(WindowBounds {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+            });
+        }
+    }
+    save_state(app, &state)
+}
+
+pub fn save_last_tab(app: &AppHandle, tab_id: String) -> Result<(), String> {
+    let mut state = load_state(app);
+    state.last_tab = Some(tab_id);
+    save_state(app, &state)
+}
+
+pub fn get_last_tab(app: &AppHandle) -> Option<String> {
+    load_state(app).last_tab
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIMARY: WindowBounds = WindowBounds {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1080,
+    };
+    const SECONDARY: WindowBounds = WindowBounds {
+        x: 1920,
+        y: -200,
+        width: 2560,
+        height: 1440,
+    };
+
+    #[test]
+    fn test_fit_keeps_visible_window() {
+        let on_secondary = WindowBounds {
+            x: 2500,
+            y: 100,
+            width: 1200,
+            height: 800,
+        };
+        assert_eq!(
+            fit_to_monitors(on_secondary, &[PRIMARY, SECONDARY], PRIMARY),
+            on_secondary
+        );
+
+        // Mostly off screen, but the title bar area is still reachable
+        let partly = WindowBounds {
+            x: -600,
+            y: 900,
+            width: 800,
+            height: 600,
+        };
+        assert_eq!(fit_to_monitors(partly, &[PRIMARY], PRIMARY), partly);
+    }
+
+    #[test]
+    fn test_fit_moves_lost_window_to_primary() {
+        // Saved on a monitor that is no longer connected
+        let lost = WindowBounds {
+            x: 2500,
+            y: 100,
+            width: 1200,
+            height: 800,
+        };
+        assert_eq!(
+            fit_to_monitors(lost, &[PRIMARY], PRIMARY),
+            WindowBounds {
+                x: 360,
+                y: 140,
+                width: 1200,
+                height: 800,
+            }
+        );
+
+        let huge = WindowBounds {
+            x: -5000,
+            y: -5000,
+            width: 4000,
+            height: 3000,
+        };
+        assert_eq!(fit_to_monitors(huge, &[PRIMARY], PRIMARY), PRIMARY);
+
+        let tiny = WindowBounds {
+            x: 10,
+            y: 10,
+            width: 20,
+            height: 20,
+        };
+        let fitted = fit_to_monitors(tiny, &[PRIMARY], PRIMARY);
+        assert_eq!((fitted.width, fitted.height), (MIN_WIDTH, MIN_HEIGHT));
+    }
+}
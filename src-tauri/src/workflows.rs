@@ -0,0 +1,572 @@
+use crate::base64_encoder::encode_image_to_base64;
+use crate::image_compressor::{compress_image, CompressionOptions};
+use crate::image_editor::{apply_filter, resize_image, ImageFilter};
+use crate::json_store::{load_json_store_for_update, read_json_store};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+const MAX_STEPS: usize = 20;
+/// Formats `compress_image` can write
+const COMPRESS_FORMATS: [&str; 5] = ["avif", "webp", "jpeg", "jpg", "png"];
+
+/// One saved step: a command name such as `compress_image` and its arguments other than
+/// the input and output paths
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStep {
+    pub command: String,
+    #[serde(default = "empty_params")]
+    pub params: Value,
+}
+
+fn empty_params() -> Value {
+    Value::Object(Default::default())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Workflow {
+    pub id: String,
+    pub name: String,
+    pub steps: Vec<WorkflowStep>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStepResult {
+    pub command: String,
+    pub success: bool,
+    pub output_path: Option<String>,
+    pub output_size: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowRunResult {
+    pub success: bool,
+    /// Every step that ran, ending with the failed one if any
+    pub steps: Vec<WorkflowStepResult>,
+    /// File written by the last successful step
+    pub output_path: Option<String>,
+    /// Data URL produced by an `encode_image_to_base64` step
+    pub text_output: Option<String>,
+    /// Zero-based index of the step that failed
+    pub failed_step: Option<usize>,
+    /// Files written by the steps before the final one, kept for inspection
+    pub intermediate_paths: Vec<String>,
+    /// Directory holding this run's files
+    pub run_dir: String,
+    pub error: Option<String>,
+}
+
+impl WorkflowRunResult {
+    fn failure(run_dir: &Path, message: String) -> Self {
+        Self {
+            success: false,
+            steps: Vec::new(),
+            output_path: None,
+            text_output: None,
+            failed_step: None,
+            intermediate_paths: Vec::new(),
+            run_dir: run_dir.to_string_lossy().to_string(),
+            error: Some(message),
+        }
+    }
+}
+
+fn default_quality() -> u8 {
+    80
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompressParams {
+    #[serde(default = "default_quality")]
+    quality: u8,
+    width: Option<u32>,
+    height: Option<u32>,
+    /// Keeps the current format when absent
+    output_format: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResizeParams {
+    width: u32,
+    height: u32,
+    #[serde(default = "default_true")]
+    maintain_aspect: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct FilterParams {
+    filter: ImageFilter,
+}
+
+/// A step with its parameters checked
+#[derive(Debug)]
+enum StepAction {
+    Compress(CompressParams),
+    Resize(ResizeParams),
+    Filter(FilterParams),
+    Base64,
+}
+
+fn parse_step(step: &WorkflowStep) -> Result<StepAction, String> {
+    let params = |command: &str| -> Result<Value, String> {
+        match &step.params {
+            Value::Null => Ok(empty_params()),
+            Value::Object(_) => Ok(step.params.clone()),
+            _ => Err(format!("{}: params must be an object", command)),
+        }
+    };
+    let invalid = |e: serde_json::Error| format!("{}: {}", step.command, e);
+    let action = match step.command.as_str() {
+        "compress_image" => {
+            let params: CompressParams =
+                serde_json::from_value(params(&step.command)?).map_err(invalid)?;
+            if !(1..=100).contains(&params.quality) {
+                return Err("compress_image: quality must be between 1 and 100".to_string());
+            }
+            if let Some(format) = &params.output_format {
+                if !COMPRESS_FORMATS.contains(&format.to_lowercase().as_str()) {
+                    return Err(format!("compress_image: unsupported format {}", format));
+                }
+            }
+            StepAction::Compress(params)
+        }
+        "resize_image" => {
+            let params: ResizeParams =
+                serde_json::from_value(params(&step.command)?).map_err(invalid)?;
+            if params.width == 0 || params.height == 0 {
+                return Err("resize_image: width and height must be at least 1".to_string());
+            }
+            StepAction::Resize(params)
+        }
+        "apply_filter" => {
+            StepAction::Filter(serde_json::from_value(params(&step.command)?).map_err(invalid)?)
+        }
+        "encode_image_to_base64" => StepAction::Base64,
+        other => return Err(format!("Unsupported workflow command: {}", other)),
+    };
+    Ok(action)
+}
+
+fn parse_steps(steps: &[WorkflowStep]) -> Result<Vec<StepAction>, String> {
+    if steps.is_empty() {
+        return Err("A workflow needs at least one step".to_string());
+    }
+    if steps.len() > MAX_STEPS {
+        return Err(format!("A workflow can have at most {} steps", MAX_STEPS));
+    }
+    let actions = steps
+        .iter()
+        .map(parse_step)
+        .collect::<Result<Vec<_>, _>>()?;
+    // Its output is text, so nothing can follow it
+    if actions[..actions.len() - 1]
+        .iter()
+        .any(|action| matches!(action, StepAction::Base64))
+    {
+        return Err("encode_image_to_base64 must be the last step".to_string());
+    }
+    Ok(actions)
+}
+
+fn extension_of(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_else(|| "png".to_string())
+}
+
+/// Run `steps` on `input_path`, writing each step's output into `run_dir`.
+/// Stops at the first failing step and reports the files written so far.
+fn run_steps(steps: &[WorkflowStep], input_path: &str, run_dir: &Path) -> WorkflowRunResult {
+    let actions = match parse_steps(steps) {
+        Ok(actions) => actions,
+        Err(e) => return WorkflowRunResult::failure(run_dir, e),
+    };
+    if !Path::new(input_path).is_file() {
+        return WorkflowRunResult::failure(run_dir, format!("File not found: {}", input_path));
+    }
+    if let Err(e) = fs::create_dir_all(run_dir) {
+        return WorkflowRunResult::failure(
+            run_dir,
+            format!("Failed to create output directory: {}", e),
+        );
+    }
+
+    let stem = Path::new(input_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("image");
+    let mut result = WorkflowRunResult {
+        success: true,
+        steps: Vec::new(),
+        output_path: None,
+        text_output: None,
+        failed_step: None,
+        intermediate_paths: Vec::new(),
+        run_dir: run_dir.to_string_lossy().to_string(),
+        error: None,
+    };
+    let mut current = PathBuf::from(input_path);
+
+    for (index, (step, action)) in steps.iter().zip(&actions).enumerate() {
+        let extension = match action {
+            StepAction::Compress(params) => match params.output_format.as_deref() {
+                Some(format) => match format.to_lowercase().as_str() {
+                    "jpeg" => "jpg".to_string(),
+                    other => other.to_string(),
+                },
+                None if COMPRESS_FORMATS.contains(&extension_of(&current).as_str()) => {
+                    extension_of(&current)
+                }
+                None => "png".to_string(),
+            },
+            StepAction::Base64 => "txt".to_string(),
+            _ => extension_of(&current),
+        };
+        let output = run_dir.join(format!(
+            "{}_{}_{}.{}",
+            stem,
+            index + 1,
+            step.command,
+            extension
+        ));
+        let output_str = output.to_string_lossy().to_string();
+        let input = current.to_string_lossy().to_string();
+
+        let outcome: Result<(), String> = match action {
+            StepAction::Compress(params) => {
+                let options = CompressionOptions {
+                    quality: params.quality,
+                    width: params.width,
+                    height: params.height,
+                    output_format: extension.clone(),
+                };
+                let res = compress_image(&input, &output_str, options);
+                res.error.filter(|_| !res.success).map_or(Ok(()), Err)
+            }
+            StepAction::Resize(params) => {
+                let res = resize_image(
+                    &input,
+                    &output_str,
+                    params.width,
+                    params.height,
+                    params.maintain_aspect,
+                );
+                res.error.filter(|_| !res.success).map_or(Ok(()), Err)
+            }
+            StepAction::Filter(params) => {
+                let res = apply_filter(&input, &output_str, params.filter);
+                res.error.filter(|_| !res.success).map_or(Ok(()), Err)
+            }
+            StepAction::Base64 => {
+                let res = encode_image_to_base64(&input);
+                if res.success {
+                    let written = fs::write(&output, &res.data_url)
+                        .map_err(|e| format!("Failed to write output: {}", e));
+                    result.text_output = Some(res.data_url);
+                    written
+                } else {
+                    Err(res.error.unwrap_or_default())
+                }
+            }
+        };
+
+        match outcome {
+            Ok(()) => {
+                result.steps.push(WorkflowStepResult {
+                    command: step.command.clone(),
+                    success: true,
+                    output_path: Some(output_str.clone()),
+                    output_size: fs::metadata(&output).map(|m| m.len()).unwrap_or(0),
+                    error: None,
+                });
+                if let Some(previous) = result.output_path.replace(output_str) {
+                    result.intermediate_paths.push(previous);
+                }
+                current = output;
+            }
+            Err(e) => {
+                let message = format!("Step {} ({}) failed: {}", index + 1, step.command, e);
+                result.steps.push(WorkflowStepResult {
+                    command: step.command.clone(),
+                    success: false,
+                    output_path: None,
+                    output_size: 0,
+                    error: Some(e),
+                });
+                // Everything written so far is an intermediate result of the failed run
+                result.intermediate_paths.extend(result.output_path.take());
+                result.success = false;
+                result.failed_step = Some(index);
+                result.error = Some(message);
+                break;
+            }
+        }
+    }
+    result
+}
+
+fn get_workflows_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("workflows.json"))
+}
+
+fn read_workflows(path: &Path) -> Result<Vec<Workflow>, String> {
+    read_json_store(path, "workflows file")
+}
+
+fn write_workflows(path: &Path, workflows: &[Workflow]) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(workflows)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("Failed to write workflows file: {}", e))
+}
+
+/// Save a new workflow, or replace the steps of the one with the same name
+fn save_workflow_at(path: &Path, name: &str, steps: Vec<WorkflowStep>) -> Result<Workflow, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Workflow name is required".to_string());
+    }
+    parse_steps(&steps)?;
+
+    let mut workflows: Vec<Workflow> = load_json_store_for_update(path, "workflows file")?;
+    let workflow = match workflows
+        .iter_mut()
+        .find(|workflow| workflow.name.trim().eq_ignore_ascii_case(name))
+    {
+        Some(existing) => {
+            existing.steps = steps;
+            existing.clone()
+        }
+        None => {
+            let workflow = Workflow {
+                id: uuid::Uuid::new_v4().to_string(),
+                name: name.to_string(),
+                steps,
+                created_at: chrono::Utc::now().to_rfc3339(),
+            };
+            workflows.push(workflow.clone());
+            workflow
+        }
+    };
+    write_workflows(path, &workflows)?;
+    Ok(workflow)
+}
+
+fn delete_workflow_at(path: &Path, id: &str) -> Result<(), String> {
+    let mut workflows: Vec<Workflow> = load_json_store_for_update(path, "workflows file")?;
+    let before = workflows.len();
+    workflows.retain(|workflow| workflow.id != id);
+    if workflows.len() == before {
+        return Err(format!("Workflow not found: {}", id));
+    }
+    write_workflows(path, &workflows)
+}
+
+pub fn save_workflow(
+    app: &AppHandle,
+    name: String,
+    steps: Vec<WorkflowStep>,
+) -> Result<Workflow, String> {
+    let path = get_workflows_path(app)?;
+    save_workflow_at(&path, &name, steps)
+}
+
+/// Saved workflows; an unreadable file gives an empty list
+pub fn list_workflows(app: &AppHandle) -> Vec<Workflow> {
+    get_workflows_path(app)
+        .and_then(|path| read_workflows(&path))
+        .unwrap_or_default()
+}
+
+pub fn delete_workflow(app: &AppHandle, id: String) -> Result<(), String> {
+    let path = get_workflows_path(app)?;
+    delete_workflow_at(&path, &id)
+}
+
+/// Run a saved workflow on `input_path`; outputs go to a new directory under
+/// `<app data>/workflow_runs`
+pub fn run_workflow(app: &AppHandle, workflow_id: &str, input_path: &str) -> WorkflowRunResult {
+    let app_data_dir = match app.path().app_data_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            return WorkflowRunResult::failure(
+                Path::new(""),
+                format!("Failed to get app data dir: {}", e),
+            )
+        }
+    };
+    let run_dir = app_data_dir.join("workflow_runs").join(format!(
+        "{}_{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+    ));
+
+    let workflow = get_workflows_path(app)
+        .and_then(|path| read_workflows(&path))
+        .and_then(|workflows| {
+            workflows
+                .into_iter()
+                .find(|workflow| workflow.id == workflow_id)
+                .ok_or_else(|| format!("Workflow not found: {}", workflow_id))
+        });
+    match workflow {
+        Ok(workflow) => run_steps(&workflow.steps, input_path, &run_dir),
+        Err(e) => WorkflowRunResult::failure(&run_dir, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+    use image::{Rgb, RgbImage};
+    use serde_json::json;
+
+    fn step(command: &str, params: Value) -> WorkflowStep {
+        WorkflowStep {
+            command: command.to_string(),
+            params,
+        }
+    }
+
+    fn sample_image(dir: &Path) -> String {
+        let path = dir.join("photo.png");
+        RgbImage::from_fn(64, 48, |x, y| Rgb([(x * 4) as u8, (y * 5) as u8, 128]))
+            .save(&path)
+            .unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_parse_steps_validation() {
+        assert!(parse_steps(&[]).is_err());
+        assert!(parse_steps(&[step("delete_file", json!({}))])
+            .unwrap_err()
+            .contains("Unsupported"));
+        assert!(parse_steps(&[step("resize_image", json!({"width": 0, "height": 10}))]).is_err());
+        assert!(parse_steps(&[step("compress_image", json!({"outputFormat": "bmp"}))]).is_err());
+        assert!(parse_steps(&[step("apply_filter", json!({"filter": "Glow"}))]).is_err());
+
+        let base64_first = [
+            step("encode_image_to_base64", Value::Null),
+            step("apply_filter", json!({"filter": "Invert"})),
+        ];
+        assert!(parse_steps(&base64_first)
+            .unwrap_err()
+            .contains("last step"));
+
+        let valid = [
+            step(
+                "compress_image",
+                json!({"quality": 70, "outputFormat": "jpeg"}),
+            ),
+            step("resize_image", json!({"width": 32, "height": 32})),
+            step("encode_image_to_base64", json!({})),
+        ];
+        assert_eq!(parse_steps(&valid).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_run_steps_chain() {
+        let dir = TempDir::new("workflow_chain");
+        let input = sample_image(&dir);
+        let steps = [
+            step(
+                "compress_image",
+                json!({"quality": 80, "outputFormat": "jpeg"}),
+            ),
+            step("resize_image", json!({"width": 32, "height": 32})),
+            step("apply_filter", json!({"filter": "Grayscale"})),
+            step("encode_image_to_base64", json!({})),
+        ];
+        let result = run_steps(&steps, &input, &dir.join("run"));
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.steps.len(), 4);
+        assert_eq!(result.intermediate_paths.len(), 3);
+        assert!(result.intermediate_paths[0].ends_with("photo_1_compress_image.jpg"));
+        assert!(result.intermediate_paths[1].ends_with("photo_2_resize_image.jpg"));
+        assert_eq!(
+            image::image_dimensions(&result.intermediate_paths[2]).unwrap(),
+            (32, 24)
+        );
+        assert!(result
+            .text_output
+            .unwrap()
+            .starts_with("data:image/jpeg;base64,"));
+        assert!(result.output_path.unwrap().ends_with(".txt"));
+    }
+
+    #[test]
+    fn test_run_steps_reports_failed_step() {
+        let dir = TempDir::new("workflow_failure");
+        let input = sample_image(&dir);
+        // Blur keeps the .png name, and an unreadable file stands in for a failing step
+        let steps = [
+            step("apply_filter", json!({"filter": "Blur"})),
+            step("compress_image", json!({"outputFormat": "webp"})),
+        ];
+        let run_dir = dir.join("run");
+        fs::create_dir_all(&run_dir).unwrap();
+        fs::create_dir_all(run_dir.join("photo_2_compress_image.webp")).unwrap();
+        let result = run_steps(&steps, &input, &run_dir);
+
+        assert!(!result.success);
+        assert_eq!(result.failed_step, Some(1));
+        assert_eq!(result.steps.len(), 2);
+        assert!(result.steps[0].success);
+        assert!(!result.steps[1].success);
+        assert!(result.output_path.is_none());
+        assert_eq!(result.intermediate_paths.len(), 1);
+        assert!(Path::new(&result.intermediate_paths[0]).is_file());
+        assert!(result
+            .error
+            .unwrap()
+            .starts_with("Step 2 (compress_image) failed"));
+    }
+
+    #[test]
+    fn test_save_and_delete_workflow() {
+        let dir = TempDir::new("workflow_store");
+        let path = dir.join("workflows.json");
+        let steps = vec![step("apply_filter", json!({"filter": "Sepia"}))];
+
+        assert!(save_workflow_at(&path, "  ", steps.clone()).is_err());
+        let saved = save_workflow_at(&path, "Sepia", steps).unwrap();
+        let replaced = save_workflow_at(
+            &path,
+            "sepia",
+            vec![step("resize_image", json!({"width": 10, "height": 10}))],
+        )
+        .unwrap();
+        assert_eq!(saved.id, replaced.id);
+
+        let workflows = read_workflows(&path).unwrap();
+        assert_eq!(workflows.len(), 1);
+        assert_eq!(workflows[0].steps[0].command, "resize_image");
+
+        delete_workflow_at(&path, &saved.id).unwrap();
+        assert!(read_workflows(&path).unwrap().is_empty());
+        assert!(delete_workflow_at(&path, &saved.id).is_err());
+    }
+}
@@ -1,23 +1,59 @@
 use crate::components::base64_encoder::Base64Encoder;
+use crate::components::business_days::BusinessDays;
+use crate::components::case_converter::CaseConverter;
 use crate::components::char_counter::CharCounter;
 use crate::components::cheatsheet_viewer::CheatsheetViewer;
-use crate::components::command_palette::{CommandPalette, ToolItem};
+use crate::components::chmod_calculator::ChmodCalculator;
+use crate::components::clipboard_history::ClipboardHistory;
+use crate::components::color_converter::ColorConverter;
+use crate::components::command_palette::{CommandPalette, ToolItem, ToolUsage};
+use crate::components::config_converter::ConfigConverter;
+use crate::components::cron_parser::CronParser;
 use crate::components::csv_viewer::CsvViewer;
+use crate::components::disk_analyzer::DiskAnalyzer;
+use crate::components::dummy_data_generator::DummyDataGenerator;
+use crate::components::duplicate_finder::DuplicateFinder;
+use crate::components::encoding_converter::EncodingConverter;
+use crate::components::env_converter::EnvConverter;
+use crate::components::escape_converter::EscapeConverter;
+use crate::components::exif_tools::ExifTools;
+use crate::components::file_renamer::FileRenamer;
+use crate::components::http_client::HttpClient;
+use crate::components::icon_exporter::IconExporter;
 use crate::components::image_compressor::ImageCompressor;
+use crate::components::image_converter::ImageConverter;
 use crate::components::image_editor::ImageEditor;
+use crate::components::japanese_text_converter::JapaneseTextConverter;
 use crate::components::json_formatter::JsonFormatter;
 use crate::components::kanban_board::KanbanBoardComponent;
 use crate::components::language_switcher::LanguageSwitcher;
+use crate::components::markdown_table::MarkdownTable;
 use crate::components::markdown_to_pdf::MarkdownToPdf;
+use crate::components::network_tools::NetworkTools;
+use crate::components::ocr::Ocr;
 use crate::components::password_generator::PasswordGenerator;
 use crate::components::pdf_tools::PdfTools;
+use crate::components::pomodoro::Pomodoro;
+use crate::components::process_tools::ProcessTools;
+use crate::components::qr_generator::QrGenerator;
+use crate::components::qr_reader::QrReader;
+use crate::components::radix_converter::RadixConverter;
 use crate::components::regex_tester::RegexTester;
 use crate::components::scratch_pad::ScratchPad;
+use crate::components::settings::{
+    load_settings, reset_settings, save_settings, AppSettings, Settings, SettingsContext,
+};
 use crate::components::shortcut_dictionary::ShortcutDictionary;
+use crate::components::sql_formatter::SqlFormatter;
+use crate::components::system_info::SystemInfo;
 use crate::components::text_diff::TextDiffComponent;
+use crate::components::text_tools::TextTools;
+use crate::components::theme_toggle::ThemeToggle;
 use crate::components::unit_converter::UnitConverter;
 use crate::components::unix_time_converter::UnixTimeConverter;
+use crate::components::url_encoder::UrlEncoder;
 use crate::components::uuid_generator::UuidGenerator;
+use crate::components::workflows::Workflows;
 use crate::i18n::{EN_TRANSLATIONS, JA_TRANSLATIONS};
 use i18nrs::yew::{use_translation, I18nProvider, I18nProviderConfig};
 use std::collections::HashMap;
@@ -27,6 +63,9 @@ use yew::prelude::*;
 
 #[wasm_bindgen]
 extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"], js_name = listen)]
     async fn tauri_listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
 }
@@ -35,22 +74,55 @@ extern "C" {
 enum Tab {
     ImageCompressor,
     ImageEditor,
+    ExifTools,
+    Ocr,
+    ImageConverter,
+    IconExporter,
+    Workflows,
     CsvViewer,
     PdfTools,
     MarkdownToPdf,
+    MarkdownTable,
     KanbanBoard,
+    Pomodoro,
     ScratchPad,
+    ClipboardHistory,
     UuidGenerator,
     PasswordGenerator,
     UnitConverter,
+    RadixConverter,
+    ChmodCalculator,
     UnixTimeConverter,
+    CronParser,
+    BusinessDays,
     TextDiff,
     RegexTester,
     JsonFormatter,
+    SqlFormatter,
+    ConfigConverter,
+    EnvConverter,
     Base64Encoder,
+    UrlEncoder,
+    HttpClient,
+    NetworkTools,
+    EscapeConverter,
+    ColorConverter,
+    DummyDataGenerator,
+    QrGenerator,
+    QrReader,
     ShortcutDictionary,
     CharCounter,
+    JapaneseTextConverter,
+    CaseConverter,
+    TextTools,
+    EncodingConverter,
     CheatsheetViewer,
+    FileRenamer,
+    DuplicateFinder,
+    DiskAnalyzer,
+    SystemInfo,
+    ProcessTools,
+    Settings,
 }
 
 impl Tab {
@@ -58,22 +130,55 @@ impl Tab {
         match self {
             Tab::ImageCompressor => "app.tabs.compress",
             Tab::ImageEditor => "app.tabs.edit",
+            Tab::ExifTools => "app.tabs.exif_tools",
+            Tab::Ocr => "app.tabs.ocr",
+            Tab::ImageConverter => "app.tabs.image_converter",
+            Tab::IconExporter => "app.tabs.icon_exporter",
+            Tab::Workflows => "app.tabs.workflows",
             Tab::CsvViewer => "app.tabs.csv",
             Tab::PdfTools => "app.tabs.pdf",
             Tab::MarkdownToPdf => "app.tabs.markdown",
+            Tab::MarkdownTable => "app.tabs.markdown_table",
             Tab::KanbanBoard => "app.tabs.kanban",
+            Tab::Pomodoro => "app.tabs.pomodoro",
             Tab::ScratchPad => "app.tabs.notes",
+            Tab::ClipboardHistory => "app.tabs.clipboard_history",
             Tab::UuidGenerator => "app.tabs.uuid",
             Tab::PasswordGenerator => "app.tabs.password",
             Tab::UnitConverter => "app.tabs.unit",
+            Tab::RadixConverter => "app.tabs.radix_converter",
+            Tab::ChmodCalculator => "app.tabs.chmod_calculator",
             Tab::UnixTimeConverter => "app.tabs.unix_time",
+            Tab::CronParser => "app.tabs.cron_parser",
+            Tab::BusinessDays => "app.tabs.business_days",
             Tab::TextDiff => "app.tabs.diff",
             Tab::RegexTester => "app.tabs.regex",
             Tab::JsonFormatter => "app.tabs.json",
+            Tab::SqlFormatter => "app.tabs.sql_formatter",
+            Tab::ConfigConverter => "app.tabs.config_converter",
+            Tab::EnvConverter => "app.tabs.env_converter",
             Tab::Base64Encoder => "app.tabs.base64",
+            Tab::UrlEncoder => "app.tabs.url_encoder",
+            Tab::HttpClient => "app.tabs.http_client",
+            Tab::NetworkTools => "app.tabs.network_tools",
+            Tab::EscapeConverter => "app.tabs.escape_converter",
+            Tab::ColorConverter => "app.tabs.color_converter",
+            Tab::DummyDataGenerator => "app.tabs.dummy_data_generator",
+            Tab::QrGenerator => "app.tabs.qr_generator",
+            Tab::QrReader => "app.tabs.qr_reader",
             Tab::ShortcutDictionary => "app.tabs.shortcut_dictionary",
             Tab::CharCounter => "app.tabs.char_counter",
+            Tab::JapaneseTextConverter => "app.tabs.japanese_text_converter",
+            Tab::CaseConverter => "app.tabs.case_converter",
+            Tab::TextTools => "app.tabs.text_tools",
+            Tab::EncodingConverter => "app.tabs.encoding_converter",
             Tab::CheatsheetViewer => "app.tabs.cheatsheet_viewer",
+            Tab::FileRenamer => "app.tabs.file_renamer",
+            Tab::DuplicateFinder => "app.tabs.duplicate_finder",
+            Tab::DiskAnalyzer => "app.tabs.disk_analyzer",
+            Tab::SystemInfo => "app.tabs.system_info",
+            Tab::ProcessTools => "app.tabs.process_tools",
+            Tab::Settings => "app.tabs.settings",
         }
     }
 
@@ -81,22 +186,55 @@ impl Tab {
         match self {
             Tab::ImageCompressor => "image_compressor",
             Tab::ImageEditor => "image_editor",
+            Tab::ExifTools => "exif_tools",
+            Tab::Ocr => "ocr",
+            Tab::ImageConverter => "image_converter",
+            Tab::IconExporter => "icon_exporter",
+            Tab::Workflows => "workflows",
             Tab::CsvViewer => "csv_viewer",
             Tab::PdfTools => "pdf_tools",
             Tab::MarkdownToPdf => "markdown_to_pdf",
+            Tab::MarkdownTable => "markdown_table",
             Tab::KanbanBoard => "kanban_board",
+            Tab::Pomodoro => "pomodoro",
             Tab::ScratchPad => "scratch_pad",
+            Tab::ClipboardHistory => "clipboard_history",
             Tab::UuidGenerator => "uuid_generator",
             Tab::PasswordGenerator => "password_generator",
             Tab::UnitConverter => "unit_converter",
+            Tab::RadixConverter => "radix_converter",
+            Tab::ChmodCalculator => "chmod_calculator",
             Tab::UnixTimeConverter => "unix_time_converter",
+            Tab::CronParser => "cron_parser",
+            Tab::BusinessDays => "business_days",
             Tab::TextDiff => "text_diff",
             Tab::RegexTester => "regex_tester",
             Tab::JsonFormatter => "json_formatter",
+            Tab::SqlFormatter => "sql_formatter",
+            Tab::ConfigConverter => "config_converter",
+            Tab::EnvConverter => "env_converter",
             Tab::Base64Encoder => "base64_encoder",
+            Tab::UrlEncoder => "url_encoder",
+            Tab::HttpClient => "http_client",
+            Tab::NetworkTools => "network_tools",
+            Tab::EscapeConverter => "escape_converter",
+            Tab::ColorConverter => "color_converter",
+            Tab::DummyDataGenerator => "dummy_data_generator",
+            Tab::QrGenerator => "qr_generator",
+            Tab::QrReader => "qr_reader",
             Tab::ShortcutDictionary => "shortcut_dictionary",
             Tab::CharCounter => "char_counter",
+            Tab::JapaneseTextConverter => "japanese_text_converter",
+            Tab::CaseConverter => "case_converter",
+            Tab::TextTools => "text_tools",
+            Tab::EncodingConverter => "encoding_converter",
             Tab::CheatsheetViewer => "cheatsheet_viewer",
+            Tab::FileRenamer => "file_renamer",
+            Tab::DuplicateFinder => "duplicate_finder",
+            Tab::DiskAnalyzer => "disk_analyzer",
+            Tab::SystemInfo => "system_info",
+            Tab::ProcessTools => "process_tools",
+            Tab::Settings => "settings",
         }
     }
 
@@ -104,22 +242,55 @@ impl Tab {
         match id {
             "image_compressor" => Some(Tab::ImageCompressor),
             "image_editor" => Some(Tab::ImageEditor),
+            "exif_tools" => Some(Tab::ExifTools),
+            "ocr" => Some(Tab::Ocr),
+            "image_converter" => Some(Tab::ImageConverter),
+            "icon_exporter" => Some(Tab::IconExporter),
+            "workflows" => Some(Tab::Workflows),
             "csv_viewer" => Some(Tab::CsvViewer),
             "pdf_tools" => Some(Tab::PdfTools),
             "markdown_to_pdf" => Some(Tab::MarkdownToPdf),
+            "markdown_table" => Some(Tab::MarkdownTable),
             "kanban_board" => Some(Tab::KanbanBoard),
+            "pomodoro" => Some(Tab::Pomodoro),
             "scratch_pad" => Some(Tab::ScratchPad),
+            "clipboard_history" => Some(Tab::ClipboardHistory),
             "uuid_generator" => Some(Tab::UuidGenerator),
             "password_generator" => Some(Tab::PasswordGenerator),
             "unit_converter" => Some(Tab::UnitConverter),
+            "radix_converter" => Some(Tab::RadixConverter),
+            "chmod_calculator" => Some(Tab::ChmodCalculator),
             "unix_time_converter" => Some(Tab::UnixTimeConverter),
+            "cron_parser" => Some(Tab::CronParser),
+            "business_days" => Some(Tab::BusinessDays),
             "text_diff" => Some(Tab::TextDiff),
             "regex_tester" => Some(Tab::RegexTester),
             "json_formatter" => Some(Tab::JsonFormatter),
+            "sql_formatter" => Some(Tab::SqlFormatter),
+            "config_converter" => Some(Tab::ConfigConverter),
+            "env_converter" => Some(Tab::EnvConverter),
             "base64_encoder" => Some(Tab::Base64Encoder),
+            "url_encoder" => Some(Tab::UrlEncoder),
+            "http_client" => Some(Tab::HttpClient),
+            "network_tools" => Some(Tab::NetworkTools),
+            "escape_converter" => Some(Tab::EscapeConverter),
+            "color_converter" => Some(Tab::ColorConverter),
+            "dummy_data_generator" => Some(Tab::DummyDataGenerator),
+            "qr_generator" => Some(Tab::QrGenerator),
+            "qr_reader" => Some(Tab::QrReader),
             "shortcut_dictionary" => Some(Tab::ShortcutDictionary),
             "char_counter" => Some(Tab::CharCounter),
+            "japanese_text_converter" => Some(Tab::JapaneseTextConverter),
+            "case_converter" => Some(Tab::CaseConverter),
+            "text_tools" => Some(Tab::TextTools),
+            "encoding_converter" => Some(Tab::EncodingConverter),
             "cheatsheet_viewer" => Some(Tab::CheatsheetViewer),
+            "file_renamer" => Some(Tab::FileRenamer),
+            "duplicate_finder" => Some(Tab::DuplicateFinder),
+            "disk_analyzer" => Some(Tab::DiskAnalyzer),
+            "system_info" => Some(Tab::SystemInfo),
+            "process_tools" => Some(Tab::ProcessTools),
+            "settings" => Some(Tab::Settings),
             _ => None,
         }
     }
@@ -128,22 +299,55 @@ impl Tab {
         match self {
             Tab::ImageCompressor => "command_palette.desc.compress",
             Tab::ImageEditor => "command_palette.desc.edit",
+            Tab::ExifTools => "command_palette.desc.exif_tools",
+            Tab::Ocr => "command_palette.desc.ocr",
+            Tab::ImageConverter => "command_palette.desc.image_converter",
+            Tab::IconExporter => "command_palette.desc.icon_exporter",
+            Tab::Workflows => "command_palette.desc.workflows",
             Tab::CsvViewer => "command_palette.desc.csv",
             Tab::PdfTools => "command_palette.desc.pdf",
             Tab::MarkdownToPdf => "command_palette.desc.markdown",
+            Tab::MarkdownTable => "command_palette.desc.markdown_table",
             Tab::KanbanBoard => "command_palette.desc.kanban",
+            Tab::Pomodoro => "command_palette.desc.pomodoro",
             Tab::ScratchPad => "command_palette.desc.notes",
+            Tab::ClipboardHistory => "command_palette.desc.clipboard_history",
             Tab::UuidGenerator => "command_palette.desc.uuid",
             Tab::PasswordGenerator => "command_palette.desc.password",
             Tab::UnitConverter => "command_palette.desc.unit",
+            Tab::RadixConverter => "command_palette.desc.radix_converter",
+            Tab::ChmodCalculator => "command_palette.desc.chmod_calculator",
             Tab::UnixTimeConverter => "command_palette.desc.unix_time",
+            Tab::CronParser => "command_palette.desc.cron_parser",
+            Tab::BusinessDays => "command_palette.desc.business_days",
             Tab::TextDiff => "command_palette.desc.diff",
             Tab::RegexTester => "command_palette.desc.regex",
             Tab::JsonFormatter => "command_palette.desc.json",
+            Tab::SqlFormatter => "command_palette.desc.sql_formatter",
+            Tab::ConfigConverter => "command_palette.desc.config_converter",
+            Tab::EnvConverter => "command_palette.desc.env_converter",
             Tab::Base64Encoder => "command_palette.desc.base64",
+            Tab::UrlEncoder => "command_palette.desc.url_encoder",
+            Tab::HttpClient => "command_palette.desc.http_client",
+            Tab::NetworkTools => "command_palette.desc.network_tools",
+            Tab::EscapeConverter => "command_palette.desc.escape_converter",
+            Tab::ColorConverter => "command_palette.desc.color_converter",
+            Tab::DummyDataGenerator => "command_palette.desc.dummy_data_generator",
+            Tab::QrGenerator => "command_palette.desc.qr_generator",
+            Tab::QrReader => "command_palette.desc.qr_reader",
             Tab::ShortcutDictionary => "command_palette.desc.shortcut_dictionary",
             Tab::CharCounter => "command_palette.desc.char_counter",
+            Tab::JapaneseTextConverter => "command_palette.desc.japanese_text_converter",
+            Tab::CaseConverter => "command_palette.desc.case_converter",
+            Tab::TextTools => "command_palette.desc.text_tools",
+            Tab::EncodingConverter => "command_palette.desc.encoding_converter",
             Tab::CheatsheetViewer => "command_palette.desc.cheatsheet_viewer",
+            Tab::FileRenamer => "command_palette.desc.file_renamer",
+            Tab::DuplicateFinder => "command_palette.desc.duplicate_finder",
+            Tab::DiskAnalyzer => "command_palette.desc.disk_analyzer",
+            Tab::SystemInfo => "command_palette.desc.system_info",
+            Tab::ProcessTools => "command_palette.desc.process_tools",
+            Tab::Settings => "command_palette.desc.settings",
         }
     }
 
@@ -170,6 +374,55 @@ impl Tab {
                 "編集".into(),
                 "リサイズ".into(),
             ],
+            Tab::ExifTools => vec![
+                "exif".into(),
+                "metadata".into(),
+                "gps".into(),
+                "location".into(),
+                "privacy".into(),
+                "camera".into(),
+                "メタデータ".into(),
+                "位置情報".into(),
+            ],
+            Tab::Ocr => vec![
+                "ocr".into(),
+                "text".into(),
+                "screenshot".into(),
+                "recognize".into(),
+                "tesseract".into(),
+                "文字認識".into(),
+                "テキスト抽出".into(),
+                "スクリーンショット".into(),
+            ],
+            Tab::ImageConverter => vec![
+                "convert".into(),
+                "format".into(),
+                "heic".into(),
+                "ico".into(),
+                "jpeg".into(),
+                "png".into(),
+                "webp".into(),
+                "batch".into(),
+                "変換".into(),
+                "フォーマット".into(),
+                "一括".into(),
+            ],
+            Tab::IconExporter => vec![
+                "icon".into(),
+                "favicon".into(),
+                "thumbnail".into(),
+                "resize".into(),
+                "アイコン".into(),
+                "サムネイル".into(),
+            ],
+            Tab::Workflows => vec![
+                "workflow".into(),
+                "chain".into(),
+                "batch".into(),
+                "automation".into(),
+                "ワークフロー".into(),
+                "連続処理".into(),
+            ],
             Tab::CsvViewer => vec![
                 "csv".into(),
                 "tsv".into(),
@@ -191,6 +444,14 @@ impl Tab {
                 "convert".into(),
                 "変換".into(),
             ],
+            Tab::MarkdownTable => vec![
+                "markdown".into(),
+                "table".into(),
+                "csv".into(),
+                "format".into(),
+                "表".into(),
+                "整形".into(),
+            ],
             Tab::KanbanBoard => vec![
                 "kanban".into(),
                 "task".into(),
@@ -198,6 +459,14 @@ impl Tab {
                 "タスク".into(),
                 "ボード".into(),
             ],
+            Tab::Pomodoro => vec![
+                "pomodoro".into(),
+                "timer".into(),
+                "focus".into(),
+                "ポモドーロ".into(),
+                "タイマー".into(),
+                "集中".into(),
+            ],
             Tab::ScratchPad => vec![
                 "note".into(),
                 "memo".into(),
@@ -205,6 +474,13 @@ impl Tab {
                 "メモ".into(),
                 "ノート".into(),
             ],
+            Tab::ClipboardHistory => vec![
+                "clipboard".into(),
+                "history".into(),
+                "copy".into(),
+                "paste".into(),
+                "pin".into(),
+            ],
             Tab::UuidGenerator => vec![
                 "uuid".into(),
                 "guid".into(),
@@ -230,6 +506,28 @@ impl Tab {
                 "単位".into(),
                 "変換".into(),
             ],
+            Tab::RadixConverter => vec![
+                "radix".into(),
+                "base".into(),
+                "binary".into(),
+                "hex".into(),
+                "octal".into(),
+                "bin".into(),
+                "ieee754".into(),
+                "float".into(),
+                "基数".into(),
+                "進数".into(),
+            ],
+            Tab::ChmodCalculator => vec![
+                "chmod".into(),
+                "permission".into(),
+                "unix".into(),
+                "rwx".into(),
+                "octal".into(),
+                "setuid".into(),
+                "パーミッション".into(),
+                "権限".into(),
+            ],
             Tab::UnixTimeConverter => vec![
                 "unix".into(),
                 "time".into(),
@@ -238,12 +536,33 @@ impl Tab {
                 "datetime".into(),
                 "時間".into(),
             ],
+            Tab::CronParser => vec![
+                "cron".into(),
+                "crontab".into(),
+                "schedule".into(),
+                "job".into(),
+                "@daily".into(),
+                "スケジュール".into(),
+                "定期実行".into(),
+            ],
+            Tab::BusinessDays => vec![
+                "business".into(),
+                "workday".into(),
+                "holiday".into(),
+                "calendar".into(),
+                "営業日".into(),
+                "祝日".into(),
+                "休日".into(),
+            ],
             Tab::TextDiff => vec![
                 "diff".into(),
                 "compare".into(),
                 "text".into(),
+                "folder".into(),
+                "directory".into(),
                 "差分".into(),
                 "比較".into(),
+                "フォルダ".into(),
             ],
             Tab::RegexTester => vec![
                 "regex".into(),
@@ -261,6 +580,32 @@ impl Tab {
                 "整形".into(),
                 "フォーマット".into(),
             ],
+            Tab::SqlFormatter => vec![
+                "sql".into(),
+                "query".into(),
+                "format".into(),
+                "database".into(),
+                "beautify".into(),
+            ],
+            Tab::ConfigConverter => vec![
+                "config".into(),
+                "yaml".into(),
+                "yml".into(),
+                "toml".into(),
+                "json".into(),
+                "convert".into(),
+                "設定".into(),
+                "変換".into(),
+            ],
+            Tab::EnvConverter => vec![
+                "env".into(),
+                "dotenv".into(),
+                "environment".into(),
+                "docker".into(),
+                "compose".into(),
+                "export".into(),
+                "環境変数".into(),
+            ],
             Tab::Base64Encoder => vec![
                 "base64".into(),
                 "encode".into(),
@@ -268,6 +613,88 @@ impl Tab {
                 "エンコード".into(),
                 "デコード".into(),
             ],
+            Tab::UrlEncoder => vec![
+                "url".into(),
+                "uri".into(),
+                "encode".into(),
+                "decode".into(),
+                "percent".into(),
+                "query".into(),
+                "エンコード".into(),
+                "デコード".into(),
+                "クエリ".into(),
+            ],
+            Tab::HttpClient => vec![
+                "http".into(),
+                "rest".into(),
+                "api".into(),
+                "curl".into(),
+                "request".into(),
+            ],
+            Tab::NetworkTools => vec![
+                "network".into(),
+                "ip".into(),
+                "cidr".into(),
+                "subnet".into(),
+                "port".into(),
+                "ping".into(),
+            ],
+            Tab::EscapeConverter => vec![
+                "escape".into(),
+                "unescape".into(),
+                "html".into(),
+                "entity".into(),
+                "unicode".into(),
+                "json".into(),
+                "xml".into(),
+                "エスケープ".into(),
+                "エンティティ".into(),
+            ],
+            Tab::ColorConverter => vec![
+                "color".into(),
+                "colour".into(),
+                "hex".into(),
+                "rgb".into(),
+                "hsl".into(),
+                "hsv".into(),
+                "cmyk".into(),
+                "palette".into(),
+                "カラー".into(),
+                "色".into(),
+                "配色".into(),
+            ],
+            Tab::DummyDataGenerator => vec![
+                "dummy".into(),
+                "data".into(),
+                "lorem".into(),
+                "ipsum".into(),
+                "fake".into(),
+                "test".into(),
+                "mock".into(),
+                "csv".into(),
+                "sql".into(),
+                "json".into(),
+                "ダミー".into(),
+                "テストデータ".into(),
+            ],
+            Tab::QrGenerator => vec![
+                "qr".into(),
+                "qrcode".into(),
+                "wifi".into(),
+                "vcard".into(),
+                "barcode".into(),
+                "QRコード".into(),
+                "二次元コード".into(),
+            ],
+            Tab::QrReader => vec![
+                "qr".into(),
+                "reader".into(),
+                "decode".into(),
+                "scan".into(),
+                "barcode".into(),
+                "読み取り".into(),
+                "スキャン".into(),
+            ],
             Tab::ShortcutDictionary => vec![
                 "shortcut".into(),
                 "keybinding".into(),
@@ -293,6 +720,59 @@ impl Tab {
                 "カウント".into(),
                 "カウンター".into(),
             ],
+            Tab::JapaneseTextConverter => vec![
+                "zenkaku".into(),
+                "hankaku".into(),
+                "full-width".into(),
+                "half-width".into(),
+                "hiragana".into(),
+                "katakana".into(),
+                "全角".into(),
+                "半角".into(),
+                "ひらがな".into(),
+                "カタカナ".into(),
+                "文字種".into(),
+            ],
+            Tab::CaseConverter => vec![
+                "case".into(),
+                "camel".into(),
+                "snake".into(),
+                "kebab".into(),
+                "pascal".into(),
+                "constant".into(),
+                "identifier".into(),
+                "ケース".into(),
+                "変換".into(),
+                "キャメル".into(),
+                "スネーク".into(),
+            ],
+            Tab::TextTools => vec![
+                "sort".into(),
+                "dedup".into(),
+                "unique".into(),
+                "shuffle".into(),
+                "lines".into(),
+                "natural".into(),
+                "trim".into(),
+                "ソート".into(),
+                "重複".into(),
+                "並べ替え".into(),
+                "行".into(),
+            ],
+            Tab::EncodingConverter => vec![
+                "encoding".into(),
+                "shift_jis".into(),
+                "sjis".into(),
+                "euc-jp".into(),
+                "utf-16".into(),
+                "utf-8".into(),
+                "bom".into(),
+                "mojibake".into(),
+                "charset".into(),
+                "文字コード".into(),
+                "文字化け".into(),
+                "改行コード".into(),
+            ],
             Tab::CheatsheetViewer => vec![
                 "cheatsheet".into(),
                 "cheat".into(),
@@ -308,6 +788,50 @@ impl Tab {
                 "リファレンス".into(),
                 "コマンド".into(),
             ],
+            Tab::FileRenamer => vec![
+                "rename".into(),
+                "batch".into(),
+                "files".into(),
+                "serial".into(),
+                "regex".into(),
+            ],
+            Tab::DuplicateFinder => vec![
+                "duplicate".into(),
+                "dedupe".into(),
+                "trash".into(),
+                "hash".into(),
+                "cleanup".into(),
+            ],
+            Tab::DiskAnalyzer => vec![
+                "disk".into(),
+                "storage".into(),
+                "usage".into(),
+                "folder".into(),
+                "size".into(),
+            ],
+            Tab::SystemInfo => vec![
+                "system".into(),
+                "cpu".into(),
+                "memory".into(),
+                "disk".into(),
+                "monitor".into(),
+                "システム".into(),
+            ],
+            Tab::ProcessTools => vec![
+                "process".into(),
+                "kill".into(),
+                "port".into(),
+                "pid".into(),
+                "lsof".into(),
+                "プロセス".into(),
+            ],
+            Tab::Settings => vec![
+                "settings".into(),
+                "preferences".into(),
+                "theme".into(),
+                "設定".into(),
+                "テーマ".into(),
+            ],
         }
     }
 
@@ -315,22 +839,55 @@ impl Tab {
         match self {
             Tab::ImageCompressor => "photo.stack",
             Tab::ImageEditor => "paintbrush",
+            Tab::ExifTools => "location.slash",
+            Tab::Ocr => "text.viewfinder",
+            Tab::ImageConverter => "arrow.2.squarepath",
+            Tab::IconExporter => "square.resize",
+            Tab::Workflows => "flowchart",
             Tab::CsvViewer => "tablecells",
             Tab::PdfTools => "doc.fill",
             Tab::MarkdownToPdf => "doc.text",
+            Tab::MarkdownTable => "tablecells.md",
             Tab::KanbanBoard => "rectangle.3.group",
+            Tab::Pomodoro => "timer",
             Tab::ScratchPad => "note.text",
+            Tab::ClipboardHistory => "clipboard.clock",
             Tab::UuidGenerator => "key.fill",
             Tab::PasswordGenerator => "lock.fill",
             Tab::UnitConverter => "arrow.left.arrow.right",
+            Tab::RadixConverter => "number",
+            Tab::ChmodCalculator => "lock.doc",
             Tab::UnixTimeConverter => "clock",
+            Tab::CronParser => "calendar.badge.clock",
+            Tab::BusinessDays => "calendar",
             Tab::TextDiff => "arrow.triangle.branch",
             Tab::RegexTester => "asterisk.circle",
             Tab::JsonFormatter => "curlybraces",
+            Tab::SqlFormatter => "database",
+            Tab::ConfigConverter => "doc.on.doc",
+            Tab::EnvConverter => "key.env",
             Tab::Base64Encoder => "doc.badge.gearshape",
+            Tab::UrlEncoder => "link",
+            Tab::HttpClient => "network",
+            Tab::NetworkTools => "server",
+            Tab::EscapeConverter => "ampersand",
+            Tab::ColorConverter => "paintpalette",
+            Tab::DummyDataGenerator => "dice",
+            Tab::QrGenerator => "qrcode",
+            Tab::QrReader => "qrcode.viewfinder",
             Tab::ShortcutDictionary => "keyboard",
             Tab::CharCounter => "textformat.abc",
+            Tab::JapaneseTextConverter => "character.ja",
+            Tab::CaseConverter => "textformat",
+            Tab::TextTools => "arrow.up.arrow.down",
+            Tab::EncodingConverter => "character.textbox",
             Tab::CheatsheetViewer => "book.closed",
+            Tab::FileRenamer => "pencil.line",
+            Tab::DuplicateFinder => "doc.on.doc.search",
+            Tab::DiskAnalyzer => "chart.pie",
+            Tab::SystemInfo => "cpu",
+            Tab::ProcessTools => "list.process",
+            Tab::Settings => "gearshape",
         }
     }
 }
@@ -355,29 +912,64 @@ impl Category {
 
     fn tabs(&self) -> Vec<Tab> {
         match self {
-            Category::Media => vec![Tab::ImageCompressor, Tab::ImageEditor],
+            Category::Media => vec![
+                Tab::ImageCompressor,
+                Tab::ImageEditor,
+                Tab::ExifTools,
+                Tab::Ocr,
+                Tab::ImageConverter,
+                Tab::IconExporter,
+                Tab::Workflows,
+            ],
             Category::Documents => vec![
                 Tab::CsvViewer,
                 Tab::PdfTools,
                 Tab::MarkdownToPdf,
+                Tab::MarkdownTable,
                 Tab::TextDiff,
                 Tab::JsonFormatter,
+                Tab::SqlFormatter,
+                Tab::ConfigConverter,
+                Tab::EnvConverter,
                 Tab::CharCounter,
+                Tab::JapaneseTextConverter,
+                Tab::CaseConverter,
+                Tab::TextTools,
+                Tab::EncodingConverter,
             ],
             Category::Generators => vec![
                 Tab::UuidGenerator,
                 Tab::PasswordGenerator,
                 Tab::UnitConverter,
+                Tab::RadixConverter,
+                Tab::ChmodCalculator,
                 Tab::UnixTimeConverter,
+                Tab::CronParser,
+                Tab::BusinessDays,
                 Tab::RegexTester,
                 Tab::Base64Encoder,
+                Tab::UrlEncoder,
+                Tab::HttpClient,
+                Tab::NetworkTools,
+                Tab::EscapeConverter,
+                Tab::ColorConverter,
+                Tab::DummyDataGenerator,
+                Tab::QrGenerator,
+                Tab::QrReader,
             ],
             Category::Productivity => {
                 vec![
                     Tab::KanbanBoard,
+                    Tab::Pomodoro,
                     Tab::ScratchPad,
+                    Tab::ClipboardHistory,
                     Tab::ShortcutDictionary,
                     Tab::CheatsheetViewer,
+                    Tab::FileRenamer,
+                    Tab::DuplicateFinder,
+                    Tab::DiskAnalyzer,
+                    Tab::SystemInfo,
+                    Tab::ProcessTools,
                 ]
             }
         }
@@ -401,6 +993,15 @@ fn is_image_file(path: &str) -> bool {
     )
 }
 
+/// Image inputs the format converter accepts beyond [`is_image_file`]
+fn is_convertible_image_file(path: &str) -> bool {
+    is_image_file(path)
+        || matches!(
+            get_file_extension(path).as_deref(),
+            Some("tif") | Some("tiff") | Some("heic") | Some("heif")
+        )
+}
+
 fn is_csv_file(path: &str) -> bool {
     matches!(
         get_file_extension(path).as_deref(),
@@ -495,12 +1096,73 @@ pub fn app() -> Html {
     }
 }
 
+#[derive(serde::Deserialize)]
+struct ThemeEvent {
+    payload: String,
+}
+
+/// Payload of `kanban-due-counts`
+#[derive(serde::Deserialize)]
+struct DueCounts {
+    overdue: usize,
+    due_today: usize,
+}
+
+#[derive(serde::Deserialize)]
+struct DueCountsEvent {
+    payload: DueCounts,
+}
+
+/// Only the list lengths of `get_due_summary_cmd` are needed for the badge
+#[derive(serde::Deserialize)]
+struct DueSummary {
+    overdue: Vec<serde::de::IgnoredAny>,
+    due_today: Vec<serde::de::IgnoredAny>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordUsageArgs {
+    tool_id: String,
+}
+
+/// Count an opened tool so the command palette can rank it
+fn record_tool_usage(tab: Tab) {
+    spawn_local(async move {
+        let args = RecordUsageArgs {
+            tool_id: tab.id().to_string(),
+        };
+        let args = serde_wasm_bindgen::to_value(&args).unwrap();
+        invoke("record_tool_usage_cmd", args).await;
+    });
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveLastTabArgs {
+    tab_id: String,
+}
+
+/// Remember `tab` so the next launch opens on it
+fn save_last_tab(tab: Tab) {
+    spawn_local(async move {
+        let args = SaveLastTabArgs {
+            tab_id: tab.id().to_string(),
+        };
+        let args = serde_wasm_bindgen::to_value(&args).unwrap();
+        invoke("save_last_tab_cmd", args).await;
+    });
+}
+
 #[function_component(AppInner)]
 fn app_inner() -> Html {
-    let (i18n, _set_language) = use_translation();
+    let (i18n, set_language) = use_translation();
     let active_tab = use_state(|| Tab::ImageCompressor);
     let sidebar_collapsed = use_state(|| false);
     let command_palette_visible = use_state(|| false);
+    let tool_usage = use_state(Vec::<ToolUsage>::new);
+    let settings = use_state(AppSettings::default);
+    let system_dark = use_state(|| true);
     let dropped_image_path = use_state(|| Option::<String>::None);
     let dropped_editor_path = use_state(|| Option::<String>::None);
     let dropped_csv_path = use_state(|| Option::<String>::None);
@@ -509,6 +1171,14 @@ fn app_inner() -> Html {
     let dropped_text_path = use_state(|| Option::<String>::None);
     let dropped_json_path = use_state(|| Option::<String>::None);
     let dropped_base64_image_path = use_state(|| Option::<String>::None);
+    let dropped_qr_image_path = use_state(|| Option::<String>::None);
+    let dropped_exif_image_path = use_state(|| Option::<String>::None);
+    let dropped_ocr_image_path = use_state(|| Option::<String>::None);
+    let dropped_workflow_image_path = use_state(|| Option::<String>::None);
+    let dropped_convert_image_paths = use_state(|| Option::<Vec<String>>::None);
+    let clipboard_cleared_toast = use_state(|| false);
+    // Overdue plus due-today kanban tasks, shown as a badge on the Kanban tab
+    let kanban_due_count = use_state(|| 0usize);
 
     // Set up drag-drop event listeners (only once on mount)
     {
@@ -521,6 +1191,11 @@ fn app_inner() -> Html {
         let dropped_text_path = dropped_text_path.clone();
         let dropped_json_path = dropped_json_path.clone();
         let dropped_base64_image_path = dropped_base64_image_path.clone();
+        let dropped_qr_image_path = dropped_qr_image_path.clone();
+        let dropped_exif_image_path = dropped_exif_image_path.clone();
+        let dropped_ocr_image_path = dropped_ocr_image_path.clone();
+        let dropped_workflow_image_path = dropped_workflow_image_path.clone();
+        let dropped_convert_image_paths = dropped_convert_image_paths.clone();
 
         use_effect_with((), move |_| {
             let active_tab = active_tab.clone();
@@ -532,6 +1207,12 @@ fn app_inner() -> Html {
             let dropped_text_path = dropped_text_path.clone();
             let dropped_json_path = dropped_json_path.clone();
             let dropped_base64_image_path = dropped_base64_image_path.clone();
+            let dropped_qr_image_path = dropped_qr_image_path.clone();
+            let dropped_exif_image_path = dropped_exif_image_path.clone();
+            let dropped_ocr_image_path = dropped_ocr_image_path.clone();
+            let dropped_workflow_image_path = dropped_workflow_image_path.clone();
+            let dropped_workflow_image_path = dropped_workflow_image_path.clone();
+            let dropped_convert_image_paths = dropped_convert_image_paths.clone();
 
             spawn_local(async move {
                 let drop_handler = {
@@ -544,14 +1225,39 @@ fn app_inner() -> Html {
                     let dropped_text_path = dropped_text_path.clone();
                     let dropped_json_path = dropped_json_path.clone();
                     let dropped_base64_image_path = dropped_base64_image_path.clone();
+                    let dropped_qr_image_path = dropped_qr_image_path.clone();
+                    let dropped_exif_image_path = dropped_exif_image_path.clone();
+                    let dropped_ocr_image_path = dropped_ocr_image_path.clone();
+                    let dropped_workflow_image_path = dropped_workflow_image_path.clone();
+                    let dropped_workflow_image_path = dropped_workflow_image_path.clone();
+                    let dropped_workflow_image_path = dropped_workflow_image_path.clone();
+                    let dropped_convert_image_paths = dropped_convert_image_paths.clone();
                     Closure::new(move |event: JsValue| {
                         if let Ok(paths) = serde_wasm_bindgen::from_value::<DropEvent>(event) {
                             if let Some(first_path) = paths.payload.first() {
-                                if is_image_file(first_path) {
+                                if *active_tab == Tab::ImageConverter
+                                    && is_convertible_image_file(first_path)
+                                {
+                                    let images = paths
+                                        .payload
+                                        .iter()
+                                        .filter(|path| is_convertible_image_file(path))
+                                        .cloned()
+                                        .collect();
+                                    dropped_convert_image_paths.set(Some(images));
+                                } else if is_image_file(first_path) {
                                     if *active_tab == Tab::ImageEditor {
                                         dropped_editor_path.set(Some(first_path.clone()));
                                     } else if *active_tab == Tab::Base64Encoder {
                                         dropped_base64_image_path.set(Some(first_path.clone()));
+                                    } else if *active_tab == Tab::QrReader {
+                                        dropped_qr_image_path.set(Some(first_path.clone()));
+                                    } else if *active_tab == Tab::ExifTools {
+                                        dropped_exif_image_path.set(Some(first_path.clone()));
+                                    } else if *active_tab == Tab::Ocr {
+                                        dropped_ocr_image_path.set(Some(first_path.clone()));
+                                    } else if *active_tab == Tab::Workflows {
+                                        dropped_workflow_image_path.set(Some(first_path.clone()));
                                     } else {
                                         dropped_image_path.set(Some(first_path.clone()));
                                         active_tab.set(Tab::ImageCompressor);
@@ -604,10 +1310,158 @@ fn app_inner() -> Html {
         });
     }
 
+    // Load saved settings once; the language stored there replaces the default
+    {
+        let settings = settings.clone();
+        let set_language = set_language.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let loaded = load_settings().await;
+                set_language.emit(loaded.language.clone());
+                settings.set(loaded);
+            });
+            || {}
+        });
+    }
+
+    // Reopen the tab that was active when the app was last closed
+    {
+        let active_tab = active_tab.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let last_tab = invoke("get_last_tab_cmd", JsValue::NULL).await;
+                if let Some(tab) = last_tab.as_string().as_deref().and_then(Tab::from_id) {
+                    active_tab.set(tab);
+                }
+            });
+            || {}
+        });
+    }
+
+    // Track the OS appearance so system mode can follow it while the app is open
+    {
+        let system_dark = system_dark.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let theme = invoke("get_system_theme_cmd", JsValue::NULL).await;
+                system_dark.set(theme.as_string().as_deref() != Some("light"));
+
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<ThemeEvent>(event) {
+                        system_dark.set(event.payload != "light");
+                    }
+                });
+                let _ = tauri_listen("system-theme-changed", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    // Tell the user when a copied password or ID has been wiped from the clipboard
+    {
+        let clipboard_cleared_toast = clipboard_cleared_toast.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |_: JsValue| {
+                    clipboard_cleared_toast.set(true);
+                    let clipboard_cleared_toast = clipboard_cleared_toast.clone();
+                    gloo_timers::callback::Timeout::new(3000, move || {
+                        clipboard_cleared_toast.set(false);
+                    })
+                    .forget();
+                });
+                let _ = tauri_listen("clipboard-cleared", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    // The backend checks due dates in the background and after every task change
+    {
+        let kanban_due_count = kanban_due_count.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let result = invoke("get_due_summary_cmd", JsValue::NULL).await;
+                if let Ok(summary) = serde_wasm_bindgen::from_value::<DueSummary>(result) {
+                    kanban_due_count.set(summary.overdue.len() + summary.due_today.len());
+                }
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<DueCountsEvent>(event) {
+                        kanban_due_count.set(event.payload.overdue + event.payload.due_today);
+                    }
+                });
+                let _ = tauri_listen("kanban-due-counts", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    let dark = settings.theme.is_dark(*system_dark);
+    use_effect_with(dark, |dark| {
+        if let Some(root) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.document_element())
+        {
+            let _ = root.set_attribute("data-theme", if *dark { "dark" } else { "light" });
+        }
+        || {}
+    });
+
+    let settings_context = {
+        let on_saved = {
+            let settings = settings.clone();
+            let set_language = set_language.clone();
+            move |saved: Option<AppSettings>| {
+                if let Some(saved) = saved {
+                    set_language.emit(saved.language.clone());
+                    settings.set(saved);
+                }
+            }
+        };
+        let update = {
+            let on_saved = on_saved.clone();
+            Callback::from(move |next: AppSettings| {
+                let on_saved = on_saved.clone();
+                spawn_local(async move { on_saved(save_settings(next).await) });
+            })
+        };
+        let reset = Callback::from(move |_| {
+            let on_saved = on_saved.clone();
+            spawn_local(async move { on_saved(reset_settings().await) });
+        });
+        SettingsContext {
+            settings: (*settings).clone(),
+            dark,
+            update,
+            reset,
+        }
+    };
+
+    // Refresh the usage ranking each time the palette opens
+    {
+        let tool_usage = tool_usage.clone();
+        use_effect_with(*command_palette_visible, move |visible| {
+            if *visible {
+                spawn_local(async move {
+                    let result = invoke("get_tool_usage_cmd", JsValue::NULL).await;
+                    if let Ok(usage) = serde_wasm_bindgen::from_value::<Vec<ToolUsage>>(result) {
+                        tool_usage.set(usage);
+                    }
+                });
+            }
+            || {}
+        });
+    }
+
     let on_tab_click = {
         let active_tab = active_tab.clone();
         Callback::from(move |tab: Tab| {
             active_tab.set(tab);
+            record_tool_usage(tab);
+            save_last_tab(tab);
         })
     };
 
@@ -674,6 +1528,41 @@ fn app_inner() -> Html {
         })
     };
 
+    let on_qr_image_file_processed = {
+        let dropped_qr_image_path = dropped_qr_image_path.clone();
+        Callback::from(move |_| {
+            dropped_qr_image_path.set(None);
+        })
+    };
+
+    let on_exif_image_file_processed = {
+        let dropped_exif_image_path = dropped_exif_image_path.clone();
+        Callback::from(move |_| {
+            dropped_exif_image_path.set(None);
+        })
+    };
+
+    let on_ocr_image_file_processed = {
+        let dropped_ocr_image_path = dropped_ocr_image_path.clone();
+        Callback::from(move |_| {
+            dropped_ocr_image_path.set(None);
+        })
+    };
+
+    let on_workflow_image_file_processed = {
+        let dropped_workflow_image_path = dropped_workflow_image_path.clone();
+        Callback::from(move |_| {
+            dropped_workflow_image_path.set(None);
+        })
+    };
+
+    let on_convert_image_files_processed = {
+        let dropped_convert_image_paths = dropped_convert_image_paths.clone();
+        Callback::from(move |_| {
+            dropped_convert_image_paths.set(None);
+        })
+    };
+
     let categories = vec![
         Category::Media,
         Category::Documents,
@@ -694,6 +1583,8 @@ fn app_inner() -> Html {
         Callback::from(move |id: String| {
             if let Some(tab) = Tab::from_id(&id) {
                 active_tab.set(tab);
+                record_tool_usage(tab);
+                save_last_tab(tab);
             }
             command_palette_visible.set(false);
         })
@@ -703,44 +1594,111 @@ fn app_inner() -> Html {
         let all_tabs = vec![
             Tab::ImageCompressor,
             Tab::ImageEditor,
+            Tab::ExifTools,
+            Tab::Ocr,
+            Tab::ImageConverter,
+            Tab::IconExporter,
+            Tab::Workflows,
             Tab::CsvViewer,
             Tab::PdfTools,
             Tab::MarkdownToPdf,
+            Tab::MarkdownTable,
             Tab::KanbanBoard,
+            Tab::Pomodoro,
             Tab::ScratchPad,
+            Tab::ClipboardHistory,
             Tab::UuidGenerator,
             Tab::PasswordGenerator,
             Tab::UnitConverter,
+            Tab::RadixConverter,
+            Tab::ChmodCalculator,
             Tab::UnixTimeConverter,
+            Tab::CronParser,
+            Tab::BusinessDays,
             Tab::TextDiff,
             Tab::RegexTester,
             Tab::JsonFormatter,
+            Tab::SqlFormatter,
+            Tab::ConfigConverter,
+            Tab::EnvConverter,
             Tab::Base64Encoder,
+            Tab::UrlEncoder,
+            Tab::HttpClient,
+            Tab::NetworkTools,
+            Tab::EscapeConverter,
+            Tab::ColorConverter,
+            Tab::DummyDataGenerator,
+            Tab::QrGenerator,
+            Tab::QrReader,
             Tab::ShortcutDictionary,
             Tab::CharCounter,
+            Tab::JapaneseTextConverter,
+            Tab::CaseConverter,
+            Tab::TextTools,
+            Tab::EncodingConverter,
             Tab::CheatsheetViewer,
+            Tab::FileRenamer,
+            Tab::DuplicateFinder,
+            Tab::DiskAnalyzer,
+            Tab::SystemInfo,
+            Tab::ProcessTools,
+            Tab::Settings,
         ];
         all_tabs
             .iter()
             .map(|tab| {
                 let category_name = match tab {
-                    Tab::ImageCompressor | Tab::ImageEditor => i18n.t("app.categories.media"),
+                    Tab::ImageCompressor
+                    | Tab::ImageEditor
+                    | Tab::ExifTools
+                    | Tab::Ocr
+                    | Tab::ImageConverter
+                    | Tab::IconExporter
+                    | Tab::Workflows => i18n.t("app.categories.media"),
                     Tab::CsvViewer
                     | Tab::PdfTools
                     | Tab::MarkdownToPdf
+                    | Tab::MarkdownTable
                     | Tab::TextDiff
                     | Tab::JsonFormatter
-                    | Tab::CharCounter => i18n.t("app.categories.documents"),
+                    | Tab::SqlFormatter
+                    | Tab::ConfigConverter
+                    | Tab::EnvConverter
+                    | Tab::CharCounter
+                    | Tab::JapaneseTextConverter
+                    | Tab::CaseConverter
+                    | Tab::TextTools
+                    | Tab::EncodingConverter => i18n.t("app.categories.documents"),
                     Tab::UuidGenerator
                     | Tab::PasswordGenerator
                     | Tab::UnitConverter
+                    | Tab::RadixConverter
+                    | Tab::ChmodCalculator
                     | Tab::UnixTimeConverter
+                    | Tab::CronParser
+                    | Tab::BusinessDays
                     | Tab::RegexTester
-                    | Tab::Base64Encoder => i18n.t("app.categories.generators"),
+                    | Tab::Base64Encoder
+                    | Tab::UrlEncoder
+                    | Tab::HttpClient
+                    | Tab::NetworkTools
+                    | Tab::EscapeConverter
+                    | Tab::ColorConverter
+                    | Tab::DummyDataGenerator
+                    | Tab::QrGenerator
+                    | Tab::QrReader => i18n.t("app.categories.generators"),
                     Tab::KanbanBoard
+                    | Tab::Pomodoro
                     | Tab::ScratchPad
+                    | Tab::ClipboardHistory
                     | Tab::ShortcutDictionary
-                    | Tab::CheatsheetViewer => i18n.t("app.categories.productivity"),
+                    | Tab::CheatsheetViewer
+                    | Tab::FileRenamer
+                    | Tab::DuplicateFinder
+                    | Tab::DiskAnalyzer
+                    | Tab::SystemInfo
+                    | Tab::ProcessTools => i18n.t("app.categories.productivity"),
+                    Tab::Settings => i18n.t("app.settings"),
                 };
                 ToolItem {
                     id: tab.id().to_string(),
@@ -761,150 +1719,289 @@ fn app_inner() -> Html {
     };
 
     html! {
-        <div class="app-layout">
-            <CommandPalette
-                visible={*command_palette_visible}
-                on_close={on_palette_close}
-                on_select={on_palette_select}
-                tools={tool_items}
-            />
-            <aside class={sidebar_class}>
-                <div class="sidebar-header">
-                    <h1 class="sidebar-title">
-                        if !*sidebar_collapsed {
-                            {"Taurin"}
-                        }
-                    </h1>
-                    <button class="sidebar-toggle" onclick={on_toggle_sidebar}>
-                        if *sidebar_collapsed {
-                            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
-                                <path d="M9 18l6-6-6-6"/>
-                            </svg>
-                        } else {
-                            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
-                                <path d="M15 18l-6-6 6-6"/>
-                            </svg>
-                        }
-                    </button>
-                </div>
-                <nav class="sidebar-nav">
-                    { for categories.iter().map(|category| {
-                        let tabs = category.tabs();
-                        let category_label = i18n.t(category.translation_key());
-                        html! {
-                            <div class="nav-group">
-                                if !*sidebar_collapsed {
-                                    <div class="nav-group-label">{category_label}</div>
-                                }
-                                <div class="nav-items">
-                                    { for tabs.iter().map(|tab| {
-                                        let is_active = *active_tab == *tab;
-                                        let on_click = on_tab_click.clone();
-                                        let t = *tab;
-                                        let tab_label = i18n.t(tab.translation_key());
-                                        html! {
-                                            <button
-                                                class={classes!("nav-item", is_active.then_some("active"))}
-                                                onclick={Callback::from(move |_| on_click.emit(t))}
-                                                title={tab_label.clone()}
-                                            >
-                                                <span class="nav-icon">{render_icon(tab.icon())}</span>
-                                                if !*sidebar_collapsed {
-                                                    <span class="nav-label">{tab_label}</span>
-                                                }
-                                            </button>
-                                        }
-                                    })}
+        <ContextProvider<SettingsContext> context={settings_context}>
+            <div class="app-layout">
+                <CommandPalette
+                    visible={*command_palette_visible}
+                    on_close={on_palette_close}
+                    on_select={on_palette_select}
+                    tools={tool_items}
+                    usage={(*tool_usage).clone()}
+                />
+                <aside class={sidebar_class}>
+                    <div class="sidebar-header">
+                        <h1 class="sidebar-title">
+                            if !*sidebar_collapsed {
+                                {"Taurin"}
+                            }
+                        </h1>
+                        <button class="sidebar-toggle" onclick={on_toggle_sidebar}>
+                            if *sidebar_collapsed {
+                                <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                                    <path d="M9 18l6-6-6-6"/>
+                                </svg>
+                            } else {
+                                <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                                    <path d="M15 18l-6-6 6-6"/>
+                                </svg>
+                            }
+                        </button>
+                    </div>
+                    <nav class="sidebar-nav">
+                        { for categories.iter().map(|category| {
+                            let tabs = category.tabs();
+                            let category_label = i18n.t(category.translation_key());
+                            html! {
+                                <div class="nav-group">
+                                    if !*sidebar_collapsed {
+                                        <div class="nav-group-label">{category_label}</div>
+                                    }
+                                    <div class="nav-items">
+                                        { for tabs.iter().map(|tab| {
+                                            let is_active = *active_tab == *tab;
+                                            let on_click = on_tab_click.clone();
+                                            let t = *tab;
+                                            let tab_label = i18n.t(tab.translation_key());
+                                            html! {
+                                                <button
+                                                    class={classes!("nav-item", is_active.then_some("active"))}
+                                                    onclick={Callback::from(move |_| on_click.emit(t))}
+                                                    title={tab_label.clone()}
+                                                >
+                                                    <span class="nav-icon">{render_icon(tab.icon())}</span>
+                                                    if !*sidebar_collapsed {
+                                                        <span class="nav-label">{tab_label}</span>
+                                                    }
+                                                    if *tab == Tab::KanbanBoard && *kanban_due_count > 0 {
+                                                        <span class="nav-badge">{*kanban_due_count}</span>
+                                                    }
+                                                </button>
+                                            }
+                                        })}
+                                    </div>
                                 </div>
-                            </div>
-                        }
-                    })}
-                </nav>
-                <div class="sidebar-footer">
-                    <LanguageSwitcher />
-                </div>
-            </aside>
-            <main class="main-content">
-                <div class={if *active_tab == Tab::ImageCompressor { "content-panel active" } else { "content-panel" }}>
-                    <ImageCompressor
-                        dropped_file={(*dropped_image_path).clone()}
-                        on_file_processed={on_image_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::ImageEditor { "content-panel active" } else { "content-panel" }}>
-                    <ImageEditor
-                        dropped_file={(*dropped_editor_path).clone()}
-                        on_file_processed={on_editor_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::CsvViewer { "content-panel active" } else { "content-panel" }}>
-                    <CsvViewer
-                        dropped_file={(*dropped_csv_path).clone()}
-                        on_file_processed={on_csv_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::PdfTools { "content-panel active" } else { "content-panel" }}>
-                    <PdfTools
-                        dropped_file={(*dropped_pdf_path).clone()}
-                        on_file_processed={on_pdf_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::MarkdownToPdf { "content-panel active" } else { "content-panel" }}>
-                    <MarkdownToPdf
-                        dropped_file={(*dropped_markdown_path).clone()}
-                        on_file_processed={on_markdown_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::KanbanBoard { "content-panel active" } else { "content-panel" }}>
-                    <KanbanBoardComponent />
-                </div>
-                <div class={if *active_tab == Tab::ScratchPad { "content-panel active" } else { "content-panel" }}>
-                    <ScratchPad />
-                </div>
-                <div class={if *active_tab == Tab::UuidGenerator { "content-panel active" } else { "content-panel" }}>
-                    <UuidGenerator />
-                </div>
-                <div class={if *active_tab == Tab::PasswordGenerator { "content-panel active" } else { "content-panel" }}>
-                    <PasswordGenerator />
-                </div>
-                <div class={if *active_tab == Tab::UnitConverter { "content-panel active" } else { "content-panel" }}>
-                    <UnitConverter />
-                </div>
-                <div class={if *active_tab == Tab::UnixTimeConverter { "content-panel active" } else { "content-panel" }}>
-                    <UnixTimeConverter />
-                </div>
-                <div class={if *active_tab == Tab::TextDiff { "content-panel active" } else { "content-panel" }}>
-                    <TextDiffComponent
-                        dropped_file={(*dropped_text_path).clone()}
-                        on_file_processed={on_text_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::RegexTester { "content-panel active" } else { "content-panel" }}>
-                    <RegexTester />
-                </div>
-                <div class={if *active_tab == Tab::JsonFormatter { "content-panel active" } else { "content-panel" }}>
-                    <JsonFormatter
-                        dropped_file={(*dropped_json_path).clone()}
-                        on_file_processed={on_json_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::Base64Encoder { "content-panel active" } else { "content-panel" }}>
-                    <Base64Encoder
-                        dropped_file={(*dropped_base64_image_path).clone()}
-                        on_file_processed={on_base64_image_file_processed}
-                    />
-                </div>
-                <div class={if *active_tab == Tab::ShortcutDictionary { "content-panel active" } else { "content-panel" }}>
-                    <ShortcutDictionary />
-                </div>
-                <div class={if *active_tab == Tab::CharCounter { "content-panel active" } else { "content-panel" }}>
-                    <CharCounter />
-                </div>
-                <div class={if *active_tab == Tab::CheatsheetViewer { "content-panel active" } else { "content-panel" }}>
-                    <CheatsheetViewer />
-                </div>
-            </main>
-        </div>
+                            }
+                        })}
+                    </nav>
+                    <div class="sidebar-footer">
+                        <button
+                            class={classes!("nav-item", (*active_tab == Tab::Settings).then_some("active"))}
+                            onclick={
+                                let on_click = on_tab_click.clone();
+                                Callback::from(move |_| on_click.emit(Tab::Settings))
+                            }
+                            title={i18n.t("app.settings")}
+                        >
+                            <span class="nav-icon">{render_icon(Tab::Settings.icon())}</span>
+                            if !*sidebar_collapsed {
+                                <span class="nav-label">{i18n.t("app.settings")}</span>
+                            }
+                        </button>
+                        <div class="sidebar-footer-controls">
+                            <ThemeToggle />
+                            <LanguageSwitcher />
+                        </div>
+                    </div>
+                </aside>
+                <main class="main-content">
+                    <div class={if *active_tab == Tab::ImageCompressor { "content-panel active" } else { "content-panel" }}>
+                        <ImageCompressor
+                            dropped_file={(*dropped_image_path).clone()}
+                            on_file_processed={on_image_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::ImageEditor { "content-panel active" } else { "content-panel" }}>
+                        <ImageEditor
+                            dropped_file={(*dropped_editor_path).clone()}
+                            on_file_processed={on_editor_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::ExifTools { "content-panel active" } else { "content-panel" }}>
+                        <ExifTools
+                            dropped_file={(*dropped_exif_image_path).clone()}
+                            on_file_processed={on_exif_image_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::Ocr { "content-panel active" } else { "content-panel" }}>
+                        <Ocr
+                            dropped_file={(*dropped_ocr_image_path).clone()}
+                            on_file_processed={on_ocr_image_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::ImageConverter { "content-panel active" } else { "content-panel" }}>
+                        <ImageConverter
+                            dropped_files={(*dropped_convert_image_paths).clone()}
+                            on_files_processed={on_convert_image_files_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::IconExporter { "content-panel active" } else { "content-panel" }}>
+                        <IconExporter />
+                    </div>
+                    <div class={if *active_tab == Tab::Workflows { "content-panel active" } else { "content-panel" }}>
+                        <Workflows
+                            dropped_file={(*dropped_workflow_image_path).clone()}
+                            on_file_processed={on_workflow_image_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::CsvViewer { "content-panel active" } else { "content-panel" }}>
+                        <CsvViewer
+                            dropped_file={(*dropped_csv_path).clone()}
+                            on_file_processed={on_csv_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::PdfTools { "content-panel active" } else { "content-panel" }}>
+                        <PdfTools
+                            dropped_file={(*dropped_pdf_path).clone()}
+                            on_file_processed={on_pdf_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::MarkdownToPdf { "content-panel active" } else { "content-panel" }}>
+                        <MarkdownToPdf
+                            dropped_file={(*dropped_markdown_path).clone()}
+                            on_file_processed={on_markdown_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::MarkdownTable { "content-panel active" } else { "content-panel" }}>
+                        <MarkdownTable />
+                    </div>
+                    <div class={if *active_tab == Tab::KanbanBoard { "content-panel active" } else { "content-panel" }}>
+                        <KanbanBoardComponent />
+                    </div>
+                    <div class={if *active_tab == Tab::Pomodoro { "content-panel active" } else { "content-panel" }}>
+                        <Pomodoro />
+                    </div>
+                    <div class={if *active_tab == Tab::ScratchPad { "content-panel active" } else { "content-panel" }}>
+                        <ScratchPad />
+                    </div>
+                    <div class={if *active_tab == Tab::ClipboardHistory { "content-panel active" } else { "content-panel" }}>
+                        <ClipboardHistory />
+                    </div>
+                    <div class={if *active_tab == Tab::UuidGenerator { "content-panel active" } else { "content-panel" }}>
+                        <UuidGenerator />
+                    </div>
+                    <div class={if *active_tab == Tab::PasswordGenerator { "content-panel active" } else { "content-panel" }}>
+                        <PasswordGenerator />
+                    </div>
+                    <div class={if *active_tab == Tab::UnitConverter { "content-panel active" } else { "content-panel" }}>
+                        <UnitConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::RadixConverter { "content-panel active" } else { "content-panel" }}>
+                        <RadixConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::ChmodCalculator { "content-panel active" } else { "content-panel" }}>
+                        <ChmodCalculator />
+                    </div>
+                    <div class={if *active_tab == Tab::UnixTimeConverter { "content-panel active" } else { "content-panel" }}>
+                        <UnixTimeConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::CronParser { "content-panel active" } else { "content-panel" }}>
+                        <CronParser />
+                    </div>
+                    <div class={if *active_tab == Tab::BusinessDays { "content-panel active" } else { "content-panel" }}>
+                        <BusinessDays />
+                    </div>
+                    <div class={if *active_tab == Tab::TextDiff { "content-panel active" } else { "content-panel" }}>
+                        <TextDiffComponent
+                            dropped_file={(*dropped_text_path).clone()}
+                            on_file_processed={on_text_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::RegexTester { "content-panel active" } else { "content-panel" }}>
+                        <RegexTester />
+                    </div>
+                    <div class={if *active_tab == Tab::JsonFormatter { "content-panel active" } else { "content-panel" }}>
+                        <JsonFormatter
+                            dropped_file={(*dropped_json_path).clone()}
+                            on_file_processed={on_json_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::SqlFormatter { "content-panel active" } else { "content-panel" }}>
+                        <SqlFormatter />
+                    </div>
+                    <div class={if *active_tab == Tab::ConfigConverter { "content-panel active" } else { "content-panel" }}>
+                        <ConfigConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::EnvConverter { "content-panel active" } else { "content-panel" }}>
+                        <EnvConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::Base64Encoder { "content-panel active" } else { "content-panel" }}>
+                        <Base64Encoder
+                            dropped_file={(*dropped_base64_image_path).clone()}
+                            on_file_processed={on_base64_image_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::UrlEncoder { "content-panel active" } else { "content-panel" }}>
+                        <UrlEncoder />
+                    </div>
+                    <div class={if *active_tab == Tab::HttpClient { "content-panel active" } else { "content-panel" }}>
+                        <HttpClient />
+                    </div>
+                    <div class={if *active_tab == Tab::NetworkTools { "content-panel active" } else { "content-panel" }}>
+                        <NetworkTools />
+                    </div>
+                    <div class={if *active_tab == Tab::EscapeConverter { "content-panel active" } else { "content-panel" }}>
+                        <EscapeConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::ColorConverter { "content-panel active" } else { "content-panel" }}>
+                        <ColorConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::DummyDataGenerator { "content-panel active" } else { "content-panel" }}>
+                        <DummyDataGenerator />
+                    </div>
+                    <div class={if *active_tab == Tab::QrGenerator { "content-panel active" } else { "content-panel" }}>
+                        <QrGenerator />
+                    </div>
+                    <div class={if *active_tab == Tab::QrReader { "content-panel active" } else { "content-panel" }}>
+                        <QrReader
+                            dropped_file={(*dropped_qr_image_path).clone()}
+                            on_file_processed={on_qr_image_file_processed}
+                        />
+                    </div>
+                    <div class={if *active_tab == Tab::ShortcutDictionary { "content-panel active" } else { "content-panel" }}>
+                        <ShortcutDictionary />
+                    </div>
+                    <div class={if *active_tab == Tab::CharCounter { "content-panel active" } else { "content-panel" }}>
+                        <CharCounter />
+                    </div>
+                    <div class={if *active_tab == Tab::JapaneseTextConverter { "content-panel active" } else { "content-panel" }}>
+                        <JapaneseTextConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::CaseConverter { "content-panel active" } else { "content-panel" }}>
+                        <CaseConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::TextTools { "content-panel active" } else { "content-panel" }}>
+                        <TextTools />
+                    </div>
+                    <div class={if *active_tab == Tab::EncodingConverter { "content-panel active" } else { "content-panel" }}>
+                        <EncodingConverter />
+                    </div>
+                    <div class={if *active_tab == Tab::CheatsheetViewer { "content-panel active" } else { "content-panel" }}>
+                        <CheatsheetViewer />
+                    </div>
+                    <div class={if *active_tab == Tab::FileRenamer { "content-panel active" } else { "content-panel" }}>
+                        <FileRenamer />
+                    </div>
+                    <div class={if *active_tab == Tab::DuplicateFinder { "content-panel active" } else { "content-panel" }}>
+                        <DuplicateFinder />
+                    </div>
+                    <div class={if *active_tab == Tab::DiskAnalyzer { "content-panel active" } else { "content-panel" }}>
+                        <DiskAnalyzer />
+                    </div>
+                    <div class={if *active_tab == Tab::SystemInfo { "content-panel active" } else { "content-panel" }}>
+                        <SystemInfo active={*active_tab == Tab::SystemInfo} />
+                    </div>
+                    <div class={if *active_tab == Tab::ProcessTools { "content-panel active" } else { "content-panel" }}>
+                        <ProcessTools active={*active_tab == Tab::ProcessTools} />
+                    </div>
+                    <div class={if *active_tab == Tab::Settings { "content-panel active" } else { "content-panel" }}>
+                        <Settings />
+                    </div>
+                </main>
+                if *clipboard_cleared_toast {
+                    <div class="toast">{i18n.t("app.clipboard_cleared")}</div>
+                }
+            </div>
+        </ContextProvider<SettingsContext>>
     }
 }
 
@@ -932,6 +2029,19 @@ fn render_icon(name: &str) -> Html {
                 <line x1="15" y1="3" x2="15" y2="21"/>
             </svg>
         },
+        "tablecells.md" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M3 5h18M3 12h18M3 19h18"/>
+                <line x1="9" y1="3" x2="9" y2="21"/>
+                <line x1="15" y1="3" x2="15" y2="21"/>
+            </svg>
+        },
+        "key.env" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="4" width="18" height="16" rx="2"/>
+                <path d="M7 10h4M7 14h6M15 10h2M15 14h2"/>
+            </svg>
+        },
         "doc.fill" => html! {
             <svg width="20" height="20" viewBox="0 0 24 24" fill="currentColor">
                 <path d="M14 2H6a2 2 0 00-2 2v16a2 2 0 002 2h12a2 2 0 002-2V8l-6-6z"/>
@@ -1043,6 +2153,16 @@ fn render_icon(name: &str) -> Html {
                 <line x1="8" y1="11" x2="14" y2="11"/>
             </svg>
         },
+        "character.ja" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="3" width="18" height="18" rx="2"/>
+                <path d="M7 8h6"/>
+                <path d="M10 6v6"/>
+                <path d="M13 10c-1 3-3 5-6 6"/>
+                <path d="M14 17l2.5-6 2.5 6"/>
+                <path d="M15 15h3"/>
+            </svg>
+        },
         "textformat.abc" => html! {
             <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
                 <path d="M3 7V5a2 2 0 012-2h14a2 2 0 012 2v2"/>
@@ -1051,6 +2171,239 @@ fn render_icon(name: &str) -> Html {
                 <text x="2" y="15" font-size="7" font-weight="bold" fill="currentColor" stroke="none">{"#"}</text>
             </svg>
         },
+        "doc.on.doc" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="8" y="8" width="13" height="13" rx="2"/>
+                <path d="M16 8V5a2 2 0 00-2-2H5a2 2 0 00-2 2v9a2 2 0 002 2h3"/>
+            </svg>
+        },
+        "link" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M10 13a5 5 0 007.54.54l3-3a5 5 0 00-7.07-7.07l-1.72 1.71"/>
+                <path d="M14 11a5 5 0 00-7.54-.54l-3 3a5 5 0 007.07 7.07l1.71-1.71"/>
+            </svg>
+        },
+        "paintpalette" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M12 3a9 9 0 000 18c1.1 0 1.7-.9 1.2-1.9-.5-1-.2-2.1 1-2.1H17a4 4 0 004-4c0-5.5-4-10-9-10z"/>
+                <circle cx="7.5" cy="11.5" r="1"/>
+                <circle cx="10" cy="7.5" r="1"/>
+                <circle cx="15" cy="7.5" r="1"/>
+            </svg>
+        },
+        "dice" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="3" width="18" height="18" rx="3"/>
+                <circle cx="8" cy="8" r="1"/>
+                <circle cx="16" cy="8" r="1"/>
+                <circle cx="12" cy="12" r="1"/>
+                <circle cx="8" cy="16" r="1"/>
+                <circle cx="16" cy="16" r="1"/>
+            </svg>
+        },
+        "calendar.badge.clock" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="4" width="18" height="17" rx="2"/>
+                <path d="M3 9h18M8 2v4M16 2v4"/>
+                <circle cx="15" cy="15" r="3"/>
+                <path d="M15 13.5V15l1 1"/>
+            </svg>
+        },
+        "calendar" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="4" width="18" height="17" rx="2"/>
+                <path d="M3 9h18M8 2v4M16 2v4"/>
+                <path d="M8 14l2.5 2.5L16 12"/>
+            </svg>
+        },
+        "textformat" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M4 7V5h10v2"/>
+                <path d="M9 5v14"/>
+                <path d="M7 19h4"/>
+                <path d="M14 13h7"/>
+                <path d="M17.5 13v6"/>
+            </svg>
+        },
+        "arrow.up.arrow.down" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M7 4v16"/>
+                <path d="M4 7l3-3 3 3"/>
+                <path d="M17 20V4"/>
+                <path d="M14 17l3 3 3-3"/>
+            </svg>
+        },
+        "ampersand" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M17 20l-9.5-10.5a3 3 0 1 1 4.2-4.2 3 3 0 0 1 0 4.2L6 15a3 3 0 0 0 4.2 4.2L18 12"/>
+            </svg>
+        },
+        "qrcode" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="3" width="7" height="7"/>
+                <rect x="14" y="3" width="7" height="7"/>
+                <rect x="3" y="14" width="7" height="7"/>
+                <path d="M14 14h3v3h-3z"/>
+                <path d="M20 14v.01"/>
+                <path d="M14 20h.01"/>
+                <path d="M17 17h4v4h-4"/>
+            </svg>
+        },
+        "qrcode.viewfinder" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M3 7V5a2 2 0 0 1 2-2h2"/>
+                <path d="M17 3h2a2 2 0 0 1 2 2v2"/>
+                <path d="M21 17v2a2 2 0 0 1-2 2h-2"/>
+                <path d="M7 21H5a2 2 0 0 1-2-2v-2"/>
+                <rect x="7" y="7" width="4" height="4"/>
+                <rect x="13" y="13" width="4" height="4"/>
+                <path d="M13 7h4v4"/>
+                <path d="M7 13v4h4"/>
+            </svg>
+        },
+        "location.slash" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M12 21s-7-6.1-7-11.5a7 7 0 0 1 11.9-5"/>
+                <path d="M18.6 7.5c.3.6.4 1.3.4 2 0 5.4-7 11.5-7 11.5"/>
+                <circle cx="12" cy="9.5" r="2.5"/>
+                <path d="M3 3l18 18"/>
+            </svg>
+        },
+        "text.viewfinder" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M3 8V5a2 2 0 0 1 2-2h3"/>
+                <path d="M16 3h3a2 2 0 0 1 2 2v3"/>
+                <path d="M21 16v3a2 2 0 0 1-2 2h-3"/>
+                <path d="M8 21H5a2 2 0 0 1-2-2v-3"/>
+                <path d="M8 9h8"/>
+                <path d="M8 12h8"/>
+                <path d="M8 15h5"/>
+            </svg>
+        },
+        "flowchart" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="3" width="7" height="5" rx="1"/>
+                <rect x="14" y="10" width="7" height="5" rx="1"/>
+                <rect x="3" y="16" width="7" height="5" rx="1"/>
+                <path d="M10 5.5h3.5a1 1 0 0 1 1 1V10"/>
+                <path d="M14 12.5h-3.5a1 1 0 0 0-1 1v2.5"/>
+            </svg>
+        },
+        "arrow.2.squarepath" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M4 7h13l-3-3"/>
+                <path d="M20 17H7l3 3"/>
+                <rect x="3" y="10" width="7" height="4" rx="1"/>
+                <rect x="14" y="10" width="7" height="4" rx="1"/>
+            </svg>
+        },
+        "network" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <circle cx="12" cy="12" r="10"/>
+                <line x1="2" y1="12" x2="22" y2="12"/>
+                <path d="M12 2a15.3 15.3 0 0 1 4 10 15.3 15.3 0 0 1-4 10 15.3 15.3 0 0 1-4-10 15.3 15.3 0 0 1 4-10z"/>
+            </svg>
+        },
+        "server" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="2" y="3" width="20" height="7" rx="1.5"/>
+                <rect x="2" y="14" width="20" height="7" rx="1.5"/>
+                <line x1="6" y1="6.5" x2="6.01" y2="6.5"/>
+                <line x1="6" y1="17.5" x2="6.01" y2="17.5"/>
+            </svg>
+        },
+        "database" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <ellipse cx="12" cy="5" rx="8" ry="3"/>
+                <path d="M4 5v14c0 1.66 3.58 3 8 3s8-1.34 8-3V5"/>
+                <path d="M4 12c0 1.66 3.58 3 8 3s8-1.34 8-3"/>
+            </svg>
+        },
+        "pencil.line" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M12 20h9"/>
+                <path d="M16.5 3.5a2.121 2.121 0 0 1 3 3L7 19l-4 1 1-4L16.5 3.5z"/>
+            </svg>
+        },
+        "doc.on.doc.search" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M8 4h8l4 4v10a2 2 0 0 1-2 2H8a2 2 0 0 1-2-2V6a2 2 0 0 1 2-2z"/>
+                <path d="M4 8v12a2 2 0 0 0 2 2h10"/>
+                <line x1="10" y1="12" x2="16" y2="12"/>
+                <line x1="10" y1="15" x2="16" y2="15"/>
+            </svg>
+        },
+        "list.process" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="4" width="18" height="16" rx="2"/>
+                <path d="M3 9h18M8 13h8M8 16h5"/>
+                <circle cx="6" cy="6.5" r="0.5" fill="currentColor"/>
+            </svg>
+        },
+        "cpu" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="6" y="6" width="12" height="12" rx="1"/>
+                <rect x="9.5" y="9.5" width="5" height="5"/>
+                <path d="M9 2v4M15 2v4M9 18v4M15 18v4M2 9h4M2 15h4M18 9h4M18 15h4"/>
+            </svg>
+        },
+        "chart.pie" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M21.21 15.89A10 10 0 1 1 8 2.83"/>
+                <path d="M22 12A10 10 0 0 0 12 2v10z"/>
+            </svg>
+        },
+        "clipboard.clock" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="8" y="2" width="8" height="4" rx="1"/>
+                <path d="M16 4h2a2 2 0 0 1 2 2v14a2 2 0 0 1-2 2H6a2 2 0 0 1-2-2V6a2 2 0 0 1 2-2h2"/>
+                <circle cx="12" cy="14" r="4"/>
+                <polyline points="12 12 12 14 13.5 15"/>
+            </svg>
+        },
+        "timer" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <circle cx="12" cy="13" r="8"/>
+                <polyline points="12 9 12 13 14.5 15.5"/>
+                <path d="M9 2h6"/>
+                <path d="M12 2v3"/>
+            </svg>
+        },
+        "gearshape" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <circle cx="12" cy="12" r="3"/>
+                <path d="M19.4 15a1.65 1.65 0 0 0 .33 1.82l.06.06a2 2 0 1 1-2.83 2.83l-.06-.06a1.65 1.65 0 0 0-1.82-.33 1.65 1.65 0 0 0-1 1.51V21a2 2 0 1 1-4 0v-.09A1.65 1.65 0 0 0 9 19.4a1.65 1.65 0 0 0-1.82.33l-.06.06a2 2 0 1 1-2.83-2.83l.06-.06A1.65 1.65 0 0 0 4.68 15a1.65 1.65 0 0 0-1.51-1H3a2 2 0 1 1 0-4h.09A1.65 1.65 0 0 0 4.6 9a1.65 1.65 0 0 0-.33-1.82l-.06-.06a2 2 0 1 1 2.83-2.83l.06.06A1.65 1.65 0 0 0 9 4.68a1.65 1.65 0 0 0 1-1.51V3a2 2 0 1 1 4 0v.09a1.65 1.65 0 0 0 1 1.51 1.65 1.65 0 0 0 1.82-.33l.06-.06a2 2 0 1 1 2.83 2.83l-.06.06A1.65 1.65 0 0 0 19.4 9a1.65 1.65 0 0 0 1.51 1H21a2 2 0 1 1 0 4h-.09a1.65 1.65 0 0 0-1.51 1z"/>
+            </svg>
+        },
+        "character.textbox" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M4 7V5h10v2"/>
+                <path d="M9 5v14"/>
+                <path d="M7 19h4"/>
+                <path d="M14 13l3 6 3-6"/>
+                <path d="M15 16h4"/>
+            </svg>
+        },
+        "square.resize" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="3" y="3" width="18" height="18" rx="2"/>
+                <rect x="7" y="7" width="10" height="10" rx="1"/>
+                <rect x="10" y="10" width="4" height="4"/>
+            </svg>
+        },
+        "lock.doc" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M14 3H6a2 2 0 00-2 2v14a2 2 0 002 2h5"/>
+                <path d="M14 3v5h5M19 8v3"/>
+                <rect x="14" y="16" width="7" height="5" rx="1"/>
+                <path d="M15.5 16v-1.5a2 2 0 014 0V16"/>
+            </svg>
+        },
+        "number" => html! {
+            <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M4 9h16M4 15h16M10 3L8 21M16 3l-2 18"/>
+            </svg>
+        },
         _ => html! {
             <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
                 <circle cx="12" cy="12" r="10"/>
@@ -1,5 +1,6 @@
 use i18nrs::yew::use_translation;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::window;
@@ -12,8 +13,17 @@ extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
 
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
     async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn save(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
 }
 
 #[derive(Serialize)]
@@ -22,6 +32,12 @@ struct OpenDialogOptions {
     filters: Vec<FileFilter>,
 }
 
+#[derive(Serialize)]
+struct SaveDialogOptions {
+    #[serde(rename = "defaultPath")]
+    default_path: String,
+}
+
 #[derive(Serialize)]
 struct FileFilter {
     name: String,
@@ -33,6 +49,71 @@ enum Mode {
     Encode,
     Decode,
     Image,
+    File,
+    Convert,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum TextEncoding {
+    PlainText,
+    Base64,
+    Base64Url,
+    Base32,
+    HexLower,
+    HexUpper,
+}
+
+impl TextEncoding {
+    const ALL: [TextEncoding; 6] = [
+        TextEncoding::PlainText,
+        TextEncoding::Base64,
+        TextEncoding::Base64Url,
+        TextEncoding::Base32,
+        TextEncoding::HexLower,
+        TextEncoding::HexUpper,
+    ];
+
+    fn id(&self) -> &'static str {
+        match self {
+            TextEncoding::PlainText => "plainText",
+            TextEncoding::Base64 => "base64",
+            TextEncoding::Base64Url => "base64Url",
+            TextEncoding::Base32 => "base32",
+            TextEncoding::HexLower => "hexLower",
+            TextEncoding::HexUpper => "hexUpper",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            TextEncoding::PlainText => "Text (UTF-8)",
+            TextEncoding::Base64 => "Base64",
+            TextEncoding::Base64Url => "Base64URL",
+            TextEncoding::Base32 => "Base32",
+            TextEncoding::HexLower => "Hex (lowercase)",
+            TextEncoding::HexUpper => "Hex (UPPERCASE)",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<TextEncoding> {
+        Self::ALL.into_iter().find(|e| e.id() == id)
+    }
+}
+
+#[derive(Serialize)]
+struct ConvertEncodingArgs {
+    input: String,
+    from: TextEncoding,
+    to: TextEncoding,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct EncodingConvertResult {
+    success: bool,
+    output: String,
+    warnings: Vec<String>,
+    error: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -59,6 +140,39 @@ struct ImageDecodeArgs {
     input: String,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FileEncodeArgs {
+    path: String,
+    as_data_uri: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FileDecodeArgs {
+    input: String,
+    output_path: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileEncodeResult {
+    success: bool,
+    output: String,
+    file_name: String,
+    mime_type: String,
+    size_bytes: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DecodeFileInfo {
+    output_path: String,
+    size_bytes: usize,
+    mime_type: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct EncodeResult {
     success: bool,
@@ -99,6 +213,55 @@ struct ImageDecodeResult {
     error: Option<String>,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamArgs {
+    input_path: String,
+    output_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamResult {
+    success: bool,
+    output_path: String,
+    bytes_read: u64,
+    bytes_written: u64,
+    cancelled: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct StreamProgressEvent {
+    payload: StreamProgress,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamProgress {
+    job_id: u64,
+    processed_bytes: u64,
+    total_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct StreamFinishedEvent {
+    payload: StreamFinished,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamFinished {
+    job_id: u64,
+    result: StreamResult,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelJobArgs {
+    job_id: u64,
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     #[prop_or_default]
@@ -123,6 +286,16 @@ pub fn base64_encoder(props: &Props) -> Html {
     let image_preview = use_state(|| Option::<String>::None); // data URL after encoding
     let image_info = use_state(|| Option::<(String, usize)>::None); // (mime_type, size)
     let decoded_image_preview = use_state(|| Option::<String>::None);
+
+    // File mode states
+    let as_data_uri = use_state(|| true);
+    let file_info = use_state(|| Option::<(String, String, usize)>::None); // (name, mime_type, size)
+    let saved_file = use_state(|| Option::<DecodeFileInfo>::None);
+
+    // Convert mode states
+    let from_encoding = use_state(|| TextEncoding::PlainText);
+    let to_encoding = use_state(|| TextEncoding::HexLower);
+    let warnings = use_state(Vec::<String>::new);
     let history_refresh = use_state(|| 0u32);
 
     // Handle dropped file
@@ -188,6 +361,9 @@ pub fn base64_encoder(props: &Props) -> Html {
         let image_info = image_info.clone();
         let decoded_image_preview = decoded_image_preview.clone();
         let is_binary = is_binary.clone();
+        let file_info = file_info.clone();
+        let saved_file = saved_file.clone();
+        let warnings = warnings.clone();
         Callback::from(move |new_mode: Mode| {
             mode.set(new_mode);
             input.set(String::new());
@@ -197,6 +373,9 @@ pub fn base64_encoder(props: &Props) -> Html {
             image_info.set(None);
             decoded_image_preview.set(None);
             is_binary.set(false);
+            file_info.set(None);
+            saved_file.set(None);
+            warnings.set(Vec::new());
         })
     };
 
@@ -226,9 +405,15 @@ pub fn base64_encoder(props: &Props) -> Html {
         let error = error.clone();
         let is_binary = is_binary.clone();
         let decoded_image_preview = decoded_image_preview.clone();
+        let from_encoding = from_encoding.clone();
+        let to_encoding = to_encoding.clone();
+        let warnings = warnings.clone();
 
         Callback::from(move |_| {
             let current_mode = *mode;
+            let from = *from_encoding;
+            let to = *to_encoding;
+            let warnings = warnings.clone();
             let input_val = (*input).clone();
             let url_safe_val = *url_safe;
             let output = output.clone();
@@ -313,8 +498,31 @@ pub fn base64_encoder(props: &Props) -> Html {
                             }
                         }
                     }
-                    Mode::Image => {
-                        // Image mode uses file drop
+                    Mode::Convert => {
+                        let args = serde_wasm_bindgen::to_value(&ConvertEncodingArgs {
+                            input: input_val,
+                            from,
+                            to,
+                        })
+                        .unwrap();
+                        let result = invoke("convert_encoding_cmd", args).await;
+
+                        if let Ok(res) =
+                            serde_wasm_bindgen::from_value::<EncodingConvertResult>(result)
+                        {
+                            if res.success {
+                                output.set(res.output);
+                                warnings.set(res.warnings);
+                                error.set(None);
+                            } else {
+                                output.set(String::new());
+                                warnings.set(Vec::new());
+                                error.set(res.error);
+                            }
+                        }
+                    }
+                    Mode::Image | Mode::File => {
+                        // Image and file modes use the file picker
                     }
                 }
 
@@ -383,6 +591,9 @@ pub fn base64_encoder(props: &Props) -> Html {
         let image_info = image_info.clone();
         let decoded_image_preview = decoded_image_preview.clone();
         let is_binary = is_binary.clone();
+        let file_info = file_info.clone();
+        let saved_file = saved_file.clone();
+        let warnings = warnings.clone();
         Callback::from(move |_| {
             input.set(String::new());
             output.set(String::new());
@@ -391,6 +602,9 @@ pub fn base64_encoder(props: &Props) -> Html {
             image_info.set(None);
             decoded_image_preview.set(None);
             is_binary.set(false);
+            file_info.set(None);
+            saved_file.set(None);
+            warnings.set(Vec::new());
         })
     };
 
@@ -398,18 +612,31 @@ pub fn base64_encoder(props: &Props) -> Html {
         let input = input.clone();
         let output = output.clone();
         let mode = mode.clone();
+        let from_encoding = from_encoding.clone();
+        let to_encoding = to_encoding.clone();
         Callback::from(move |_| {
             let current_output = (*output).clone();
             let current_mode = *mode;
 
-            if !current_output.is_empty() && current_mode != Mode::Image {
+            if current_mode == Mode::Convert {
+                let (from, to) = (*from_encoding, *to_encoding);
+                from_encoding.set(to);
+                to_encoding.set(from);
+                if !current_output.is_empty() {
+                    input.set(current_output);
+                    output.set(String::new());
+                }
+                return;
+            }
+
+            if !current_output.is_empty() && matches!(current_mode, Mode::Encode | Mode::Decode) {
                 input.set(current_output);
                 output.set(String::new());
                 // Toggle mode
                 match current_mode {
                     Mode::Encode => mode.set(Mode::Decode),
                     Mode::Decode => mode.set(Mode::Encode),
-                    Mode::Image => {}
+                    Mode::Image | Mode::File | Mode::Convert => {}
                 }
             }
         })
@@ -478,6 +705,124 @@ pub fn base64_encoder(props: &Props) -> Html {
         })
     };
 
+    let on_data_uri_change = {
+        let as_data_uri = as_data_uri.clone();
+        Callback::from(move |e: Event| {
+            let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+            as_data_uri.set(checkbox.checked());
+        })
+    };
+
+    let on_select_any_file = {
+        let output = output.clone();
+        let file_info = file_info.clone();
+        let as_data_uri = as_data_uri.clone();
+        let error = error.clone();
+        let is_processing = is_processing.clone();
+        Callback::from(move |_| {
+            let output = output.clone();
+            let file_info = file_info.clone();
+            let as_data_uri = *as_data_uri;
+            let error = error.clone();
+            let is_processing = is_processing.clone();
+
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    filters: vec![],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                let Some(path) = open(opts).await.as_string() else {
+                    return;
+                };
+
+                is_processing.set(true);
+
+                let args =
+                    serde_wasm_bindgen::to_value(&FileEncodeArgs { path, as_data_uri }).unwrap();
+                let result = invoke("encode_file_to_base64_cmd", args).await;
+
+                match serde_wasm_bindgen::from_value::<FileEncodeResult>(result) {
+                    Ok(res) if res.success => {
+                        output.set(res.output);
+                        file_info.set(Some((res.file_name, res.mime_type, res.size_bytes)));
+                        error.set(None);
+                    }
+                    Ok(res) => {
+                        output.set(String::new());
+                        file_info.set(None);
+                        error.set(res.error.or(Some("Encoding failed".to_string())));
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("Failed to parse result: {:?}", e)));
+                    }
+                }
+
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let on_save_as_file = {
+        let input = input.clone();
+        let error = error.clone();
+        let saved_file = saved_file.clone();
+        Callback::from(move |_| {
+            let input_val = (*input).clone();
+            let error = error.clone();
+            let saved_file = saved_file.clone();
+
+            if input_val.trim().is_empty() {
+                return;
+            }
+
+            spawn_local(async move {
+                let options = SaveDialogOptions {
+                    default_path: "decoded.bin".to_string(),
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                let Some(output_path) = save(opts).await.as_string() else {
+                    return;
+                };
+
+                let args = serde_wasm_bindgen::to_value(&FileDecodeArgs {
+                    input: input_val,
+                    output_path,
+                })
+                .unwrap();
+                match try_invoke("decode_base64_to_file_cmd", args).await {
+                    Ok(result) => {
+                        if let Ok(info) = serde_wasm_bindgen::from_value::<DecodeFileInfo>(result) {
+                            saved_file.set(Some(info));
+                            error.set(None);
+                        }
+                    }
+                    Err(e) => {
+                        saved_file.set(None);
+                        error.set(e.as_string());
+                    }
+                }
+            });
+        })
+    };
+
+    let encoding_select = |state: &UseStateHandle<TextEncoding>| {
+        let handle = state.clone();
+        let onchange = Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(encoding) = TextEncoding::from_id(&select.value()) {
+                handle.set(encoding);
+            }
+        });
+        html! {
+            <select class="encoding-select" {onchange}>
+                { for TextEncoding::ALL.iter().map(|enc| html! {
+                    <option value={enc.id()} selected={**state == *enc}>{enc.label()}</option>
+                }) }
+            </select>
+        }
+    };
+
     let on_history_restore = {
         let input = input.clone();
         let mode = mode.clone();
@@ -534,11 +879,48 @@ pub fn base64_encoder(props: &Props) -> Html {
                     >
                         {i18n.t("base64_encoder.mode_image")}
                     </button>
+                    <button
+                        class={classes!("mode-tab", (*mode == Mode::File).then_some("active"))}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(Mode::File))
+                        }
+                    >
+                        {i18n.t("base64_encoder.mode_file")}
+                    </button>
+                    <button
+                        class={classes!("mode-tab", (*mode == Mode::Convert).then_some("active"))}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(Mode::Convert))
+                        }
+                    >
+                        {i18n.t("base64_encoder.mode_convert")}
+                    </button>
                 </div>
             </div>
 
             // Options section
-            if *mode != Mode::Image {
+            if *mode == Mode::File {
+                <div class="section options-section">
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={*as_data_uri}
+                            onchange={on_data_uri_change}
+                        />
+                        <span>{i18n.t("base64_encoder.as_data_uri")}</span>
+                    </label>
+                </div>
+            } else if *mode == Mode::Convert {
+                <div class="section options-section encoding-options">
+                    <span class="encoding-label">{i18n.t("base64_encoder.from")}</span>
+                    {encoding_select(&from_encoding)}
+                    <span class="encoding-label">{"→"}</span>
+                    <span class="encoding-label">{i18n.t("base64_encoder.to")}</span>
+                    {encoding_select(&to_encoding)}
+                </div>
+            } else if *mode != Mode::Image {
                 <div class="section options-section">
                     <label class="checkbox-label">
                         <input
@@ -551,6 +933,10 @@ pub fn base64_encoder(props: &Props) -> Html {
                 </div>
             }
 
+            if *mode == Mode::File {
+                <LargeFileConverter />
+            }
+
             // Input section
             <div class="section input-section">
                 <div class="section-header">
@@ -559,6 +945,8 @@ pub fn base64_encoder(props: &Props) -> Html {
                             Mode::Encode => i18n.t("base64_encoder.text_input"),
                             Mode::Decode => i18n.t("base64_encoder.base64_input"),
                             Mode::Image => i18n.t("base64_encoder.image_input"),
+                            Mode::File => i18n.t("base64_encoder.file_input"),
+                            Mode::Convert => i18n.t("common.input"),
                         }}
                     </h3>
                     <button class="secondary-btn" onclick={on_clear}>
@@ -596,13 +984,41 @@ pub fn base64_encoder(props: &Props) -> Html {
                             </div>
                         }
                     </div>
+                } else if *mode == Mode::File {
+                    <div class="drop-zone" onclick={on_select_any_file}>
+                        if *is_processing {
+                            <div class="drop-zone-content">
+                                <span class="spinner"></span>
+                                <p>{i18n.t("common.processing")}</p>
+                            </div>
+                        } else if let Some((name, mime_type, size)) = (*file_info).clone() {
+                            <div class="drop-zone-content">
+                                <p class="file-name">{name}</p>
+                                <div class="image-info">
+                                    <span class="info-item">{mime_type}</span>
+                                    <span class="info-item">{format_file_size(size)}</span>
+                                </div>
+                                <p class="change-image-hint">{i18n.t("base64_encoder.click_to_change")}</p>
+                            </div>
+                        } else {
+                            <div class="drop-zone-content">
+                                <svg width="48" height="48" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                                    <path d="M14 2H6a2 2 0 00-2 2v16a2 2 0 002 2h12a2 2 0 002-2V8l-6-6z"/>
+                                    <path d="M14 2v6h6"/>
+                                </svg>
+                                <p>{i18n.t("base64_encoder.select_file")}</p>
+                                <span class="formats">{i18n.t("base64_encoder.file_size_limit")}</span>
+                            </div>
+                        }
+                    </div>
                 } else {
                     <textarea
                         class="input-textarea"
                         placeholder={match *mode {
                             Mode::Encode => i18n.t("base64_encoder.encode_placeholder"),
                             Mode::Decode => i18n.t("base64_encoder.decode_placeholder"),
-                            Mode::Image => String::new(),
+                            Mode::Convert => i18n.t("base64_encoder.convert_placeholder"),
+                            Mode::Image | Mode::File => String::new(),
                         }}
                         value={(*input).clone()}
                         oninput={on_input_change}
@@ -611,7 +1027,7 @@ pub fn base64_encoder(props: &Props) -> Html {
             </div>
 
             // Action buttons (only for text encode/decode modes)
-            if *mode != Mode::Image {
+            if matches!(*mode, Mode::Encode | Mode::Decode | Mode::Convert) {
                 <div class="action-buttons">
                     <button
                         class="primary-btn"
@@ -627,11 +1043,22 @@ pub fn base64_encoder(props: &Props) -> Html {
                             {match *mode {
                                 Mode::Encode => i18n.t("base64_encoder.encode_btn"),
                                 Mode::Decode => i18n.t("base64_encoder.decode_btn"),
-                                Mode::Image => String::new(),
+                                Mode::Convert => i18n.t("base64_encoder.convert_btn"),
+                                Mode::Image | Mode::File => String::new(),
                             }}
                         }
                     </button>
 
+                    if *mode == Mode::Decode {
+                        <button
+                            class="secondary-btn"
+                            onclick={on_save_as_file}
+                            disabled={(*input).trim().is_empty()}
+                        >
+                            {i18n.t("base64_encoder.save_as_file")}
+                        </button>
+                    }
+
                     if !(*output).is_empty() {
                         <button class="secondary-btn swap-btn" onclick={on_swap}>
                             <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
@@ -652,6 +1079,26 @@ pub fn base64_encoder(props: &Props) -> Html {
                 </div>
             }
 
+            if !(*warnings).is_empty() {
+                <div class="section warning-section">
+                    { for (*warnings).iter().map(|w| html! {
+                        <div class="warning-message">{"⚠ "}{w}</div>
+                    }) }
+                </div>
+            }
+
+            if let Some(info) = (*saved_file).clone() {
+                <div class="section saved-file-section">
+                    {format!(
+                        "✓ {} {} ({}{})",
+                        i18n.t("base64_encoder.saved_to"),
+                        info.output_path,
+                        format_file_size(info.size_bytes),
+                        info.mime_type.map(|m| format!(", {}", m)).unwrap_or_default()
+                    )}
+                </div>
+            }
+
             // Output section (show only after encoding)
             if !(*output).is_empty() {
                 <div class="section output-section">
@@ -666,7 +1113,8 @@ pub fn base64_encoder(props: &Props) -> Html {
                                         i18n.t("base64_encoder.text_output")
                                     }
                                 },
-                                Mode::Image => i18n.t("base64_encoder.base64_output"),
+                                Mode::Image | Mode::File => i18n.t("base64_encoder.base64_output"),
+                                Mode::Convert => format!("{} ({})", i18n.t("common.output"), to_encoding.label()),
                             }}
                         </h3>
                         <div class="output-actions">
@@ -713,7 +1161,7 @@ pub fn base64_encoder(props: &Props) -> Html {
                         <span class="stat-item">
                             {format!("{} {}", (*output).len(), i18n.t("common.characters"))}
                         </span>
-                        if *mode == Mode::Encode || *mode == Mode::Image {
+                        if matches!(*mode, Mode::Encode | Mode::Image | Mode::File) {
                             <span class="stat-item">
                                 {format!("≈ {}", format_file_size((*output).len() * 3 / 4))}
                             </span>
@@ -729,6 +1177,211 @@ pub fn base64_encoder(props: &Props) -> Html {
     }
 }
 
+/// File-to-file conversion for files too large to show as text, streamed by the backend
+#[function_component(LargeFileConverter)]
+fn large_file_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let is_running = use_state(|| false);
+    let progress = use_state(|| Option::<StreamProgress>::None);
+    let result = use_state(|| Option::<StreamResult>::None);
+    // Id of the job started here; events of other jobs are ignored
+    let job_id = use_mut_ref(|| Option::<u64>::None);
+    // Jobs that finished before their start command returned the id
+    let finished_early = use_mut_ref(HashMap::<u64, StreamResult>::new);
+
+    {
+        let progress = progress.clone();
+        let result = result.clone();
+        let is_running = is_running.clone();
+        let job_id = job_id.clone();
+        let finished_early = finished_early.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let on_progress = {
+                    let job_id = job_id.clone();
+                    Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                        if let Ok(event) =
+                            serde_wasm_bindgen::from_value::<StreamProgressEvent>(event)
+                        {
+                            if *job_id.borrow() == Some(event.payload.job_id) {
+                                progress.set(Some(event.payload));
+                            }
+                        }
+                    })
+                };
+                let on_finished = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    let Ok(event) = serde_wasm_bindgen::from_value::<StreamFinishedEvent>(event)
+                    else {
+                        return;
+                    };
+                    let finished = event.payload;
+                    if *job_id.borrow() == Some(finished.job_id) {
+                        *job_id.borrow_mut() = None;
+                        result.set(Some(finished.result));
+                        is_running.set(false);
+                    } else {
+                        finished_early
+                            .borrow_mut()
+                            .insert(finished.job_id, finished.result);
+                    }
+                });
+                let _ = listen("base64-file-progress", &on_progress).await;
+                let _ = listen("base64-file-finished", &on_finished).await;
+                on_progress.forget();
+                on_finished.forget();
+            });
+            || {}
+        });
+    }
+
+    let run = {
+        let is_running = is_running.clone();
+        let progress = progress.clone();
+        let result = result.clone();
+        let job_id = job_id.clone();
+        let finished_early = finished_early.clone();
+        move |command: &'static str, suffix: &'static str| {
+            let is_running = is_running.clone();
+            let progress = progress.clone();
+            let result = result.clone();
+            let job_id = job_id.clone();
+            let finished_early = finished_early.clone();
+            Callback::from(move |_: MouseEvent| {
+                let is_running = is_running.clone();
+                let progress = progress.clone();
+                let result = result.clone();
+                let job_id = job_id.clone();
+                let finished_early = finished_early.clone();
+                spawn_local(async move {
+                    let options = OpenDialogOptions {
+                        multiple: false,
+                        filters: vec![],
+                    };
+                    let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                    let Some(input_path) = open(opts).await.as_string() else {
+                        return;
+                    };
+                    let default_path = match suffix {
+                        ".b64" => format!("{}.b64", input_path),
+                        _ => input_path
+                            .strip_suffix(".b64")
+                            .or_else(|| input_path.strip_suffix(".txt"))
+                            .map(str::to_string)
+                            .unwrap_or_else(|| format!("{}.bin", input_path)),
+                    };
+                    let opts =
+                        serde_wasm_bindgen::to_value(&SaveDialogOptions { default_path }).unwrap();
+                    let Some(output_path) = save(opts).await.as_string() else {
+                        return;
+                    };
+
+                    is_running.set(true);
+                    progress.set(None);
+                    result.set(None);
+                    let args = serde_wasm_bindgen::to_value(&StreamArgs {
+                        input_path,
+                        output_path,
+                    })
+                    .unwrap();
+                    let response = invoke(command, args).await;
+                    let Ok(id) = serde_wasm_bindgen::from_value::<u64>(response) else {
+                        is_running.set(false);
+                        return;
+                    };
+                    let early = finished_early.borrow_mut().remove(&id);
+                    match early {
+                        Some(res) => {
+                            result.set(Some(res));
+                            is_running.set(false);
+                        }
+                        None => *job_id.borrow_mut() = Some(id),
+                    }
+                });
+            })
+        }
+    };
+
+    let on_cancel = {
+        let job_id = job_id.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(id) = *job_id.borrow() else {
+                return;
+            };
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&CancelJobArgs { job_id: id }).unwrap();
+                invoke("cancel_base64_file_job_cmd", args).await;
+            });
+        })
+    };
+
+    let percent = (*progress)
+        .as_ref()
+        .filter(|p| p.total_bytes > 0)
+        .map(|p| (p.processed_bytes as f64 / p.total_bytes as f64 * 100.0).min(100.0))
+        .unwrap_or(0.0);
+
+    html! {
+        <div class="section large-file-section">
+            <div class="section-header">
+                <h3>{i18n.t("base64_encoder.large_file")}</h3>
+            </div>
+            <p class="large-file-hint">{i18n.t("base64_encoder.large_file_hint")}</p>
+            <div class="large-file-actions">
+                <button
+                    class="secondary-btn"
+                    onclick={run("encode_file_to_base64_file_cmd", ".b64")}
+                    disabled={*is_running}
+                >
+                    {i18n.t("base64_encoder.encode_to_file")}
+                </button>
+                <button
+                    class="secondary-btn"
+                    onclick={run("decode_base64_file_to_file_cmd", "")}
+                    disabled={*is_running}
+                >
+                    {i18n.t("base64_encoder.decode_to_file")}
+                </button>
+                if *is_running {
+                    <button class="secondary-btn" onclick={on_cancel}>
+                        {i18n.t("base64_encoder.cancel")}
+                    </button>
+                }
+            </div>
+            if *is_running {
+                <div class="large-file-progress">
+                    <div class="large-file-progress-bar" style={format!("width: {:.1}%", percent)}></div>
+                </div>
+                if let Some(p) = (*progress).clone() {
+                    <p class="large-file-status">
+                        {format!(
+                            "{} / {} ({:.0}%)",
+                            format_file_size(p.processed_bytes as usize),
+                            format_file_size(p.total_bytes as usize),
+                            percent
+                        )}
+                    </p>
+                }
+            }
+            if let Some(res) = (*result).clone() {
+                if res.success {
+                    <p class="large-file-status success">
+                        {format!(
+                            "✓ {} → {}",
+                            format_file_size(res.bytes_read as usize),
+                            format_file_size(res.bytes_written as usize)
+                        )}
+                    </p>
+                    <p class="output-path">{format!("📁 {}", res.output_path)}</p>
+                } else if res.cancelled {
+                    <p class="large-file-status">{i18n.t("base64_encoder.large_file_cancelled")}</p>
+                } else if let Some(error) = res.error {
+                    <p class="error-message">{error}</p>
+                }
+            }
+        </div>
+    }
+}
+
 fn format_file_size(bytes: usize) -> String {
     if bytes < 1024 {
         format!("{} B", bytes)
@@ -0,0 +1,377 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Add,
+    Count,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum HolidayCountry {
+    Japan,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+enum HolidayKind {
+    National,
+    Substitute,
+    Citizens,
+    Custom,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Holiday {
+    date: String,
+    name: String,
+    kind: HolidayKind,
+    weekday: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct BusinessDayResult {
+    success: bool,
+    end_date: String,
+    end_weekday: u32,
+    business_days: i64,
+    calendar_days: i64,
+    weekend_days: i64,
+    holidays: Vec<Holiday>,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AddArgs {
+    start_date: String,
+    days: i32,
+    country: HolidayCountry,
+    custom_holidays: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CountArgs {
+    start: String,
+    end: String,
+    country: HolidayCountry,
+    custom_holidays: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ListArgs {
+    year: u16,
+    country: HolidayCountry,
+}
+
+/// Today and the last day of this month as `YYYY-MM-DD`, in local time
+fn today_and_month_end() -> (String, String) {
+    let now = js_sys::Date::new_0();
+    let (year, month) = (now.get_full_year(), now.get_month());
+    // Day 0 of the next month is the last day of this one
+    let month_end = js_sys::Date::new_with_year_month_day(year, month as i32 + 1, 0);
+    let format = |date: &js_sys::Date| {
+        format!(
+            "{:04}-{:02}-{:02}",
+            date.get_full_year(),
+            date.get_month() + 1,
+            date.get_date()
+        )
+    };
+    (format(&now), format(&month_end))
+}
+
+fn kind_translation_key(kind: HolidayKind) -> &'static str {
+    match kind {
+        HolidayKind::National => "business_days.kind_national",
+        HolidayKind::Substitute => "business_days.kind_substitute",
+        HolidayKind::Citizens => "business_days.kind_citizens",
+        HolidayKind::Custom => "business_days.kind_custom",
+    }
+}
+
+fn weekday_translation_key(weekday: u32) -> String {
+    format!("business_days.weekday_{}", weekday)
+}
+
+#[function_component(BusinessDays)]
+pub fn business_days() -> Html {
+    let (i18n, _) = use_translation();
+    let (today, month_end) = today_and_month_end();
+    let mode = use_state(|| Mode::Add);
+    let start = use_state(|| today.clone());
+    let end = use_state(|| month_end);
+    let days = use_state(|| 10i32);
+    let country = use_state(|| HolidayCountry::Japan);
+    let custom = use_state(String::new);
+    let result = use_state(|| Option::<BusinessDayResult>::None);
+    let year = use_state(|| today[..4].parse::<u16>().unwrap_or(2025));
+    let year_holidays = use_state(Vec::<Holiday>::new);
+
+    // Recalculate on every edit
+    {
+        let result = result.clone();
+        use_effect_with(
+            (
+                *mode,
+                (*start).clone(),
+                (*end).clone(),
+                *days,
+                *country,
+                (*custom).clone(),
+            ),
+            move |(mode, start, end, days, country, custom)| {
+                let (mode, start, end, days, country) =
+                    (*mode, start.clone(), end.clone(), *days, *country);
+                let custom_holidays: Vec<String> = custom.lines().map(str::to_string).collect();
+                spawn_local(async move {
+                    let response = match mode {
+                        Mode::Add => {
+                            let args = serde_wasm_bindgen::to_value(&AddArgs {
+                                start_date: start,
+                                days,
+                                country,
+                                custom_holidays,
+                            })
+                            .unwrap();
+                            invoke("add_business_days_cmd", args).await
+                        }
+                        Mode::Count => {
+                            let args = serde_wasm_bindgen::to_value(&CountArgs {
+                                start,
+                                end,
+                                country,
+                                custom_holidays,
+                            })
+                            .unwrap();
+                            invoke("count_business_days_cmd", args).await
+                        }
+                    };
+                    result.set(serde_wasm_bindgen::from_value(response).ok());
+                });
+                || ()
+            },
+        );
+    }
+
+    {
+        let year_holidays = year_holidays.clone();
+        use_effect_with((*year, *country), move |(year, country)| {
+            let args = serde_wasm_bindgen::to_value(&ListArgs {
+                year: *year,
+                country: *country,
+            })
+            .unwrap();
+            spawn_local(async move {
+                let response = invoke("list_holidays_cmd", args).await;
+                year_holidays.set(serde_wasm_bindgen::from_value(response).unwrap_or_default());
+            });
+            || ()
+        });
+    }
+
+    let text_setter = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            state.set(input.value());
+        })
+    };
+
+    let on_days_change = {
+        let days = days.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<i32>() {
+                days.set(value);
+            }
+        })
+    };
+
+    let on_country_change = {
+        let country = country.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            country.set(if select.value() == "none" {
+                HolidayCountry::None
+            } else {
+                HolidayCountry::Japan
+            });
+        })
+    };
+
+    let on_custom_change = {
+        let custom = custom.clone();
+        Callback::from(move |e: InputEvent| {
+            let textarea: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            custom.set(textarea.value());
+        })
+    };
+
+    let shift_year = |delta: i32| {
+        let year = year.clone();
+        Callback::from(move |_| {
+            let next = (i32::from(*year) + delta).clamp(2000, 2099);
+            year.set(next as u16);
+        })
+    };
+
+    let mode_button = |target: Mode, label: &str| {
+        let mode = mode.clone();
+        html! {
+            <button
+                class={classes!("mode-btn", (*mode == target).then_some("active"))}
+                onclick={Callback::from(move |_| mode.set(target))}
+            >
+                {label.to_string()}
+            </button>
+        }
+    };
+
+    let holiday_name = |holiday: &Holiday| {
+        if holiday.name.is_empty() {
+            i18n.t(kind_translation_key(holiday.kind))
+        } else {
+            holiday.name.clone()
+        }
+    };
+
+    let holiday_table = |holidays: &[Holiday]| {
+        html! {
+            <table class="business-days-table">
+                <tbody>
+                    { for holidays.iter().map(|holiday| html! {
+                        <tr key={holiday.date.clone()}>
+                            <td><code>{&holiday.date}</code></td>
+                            <td class="business-days-weekday">{i18n.t(&weekday_translation_key(holiday.weekday))}</td>
+                            <td>{holiday_name(holiday)}</td>
+                            <td>
+                                <span class={classes!("business-days-kind", format!("{:?}", holiday.kind).to_lowercase())}>
+                                    {i18n.t(kind_translation_key(holiday.kind))}
+                                </span>
+                            </td>
+                        </tr>
+                    }) }
+                </tbody>
+            </table>
+        }
+    };
+
+    html! {
+        <div class="business-days">
+            <div class="section">
+                <div class="mode-toggle">
+                    {mode_button(Mode::Add, &i18n.t("business_days.mode_add"))}
+                    {mode_button(Mode::Count, &i18n.t("business_days.mode_count"))}
+                </div>
+
+                <div class="business-days-inputs">
+                    <label>
+                        <span>{i18n.t("business_days.start_date")}</span>
+                        <input
+                            type="date"
+                            class="form-input"
+                            value={(*start).clone()}
+                            oninput={text_setter(&start)}
+                        />
+                    </label>
+                    if *mode == Mode::Add {
+                        <label>
+                            <span>{i18n.t("business_days.days")}</span>
+                            <input
+                                type="number"
+                                class="form-input"
+                                value={days.to_string()}
+                                oninput={on_days_change}
+                            />
+                        </label>
+                    } else {
+                        <label>
+                            <span>{i18n.t("business_days.end_date")}</span>
+                            <input
+                                type="date"
+                                class="form-input"
+                                value={(*end).clone()}
+                                oninput={text_setter(&end)}
+                            />
+                        </label>
+                    }
+                    <label>
+                        <span>{i18n.t("business_days.country")}</span>
+                        <select class="form-select" onchange={on_country_change}>
+                            <option value="japan" selected={*country == HolidayCountry::Japan}>
+                                {i18n.t("business_days.country_japan")}
+                            </option>
+                            <option value="none" selected={*country == HolidayCountry::None}>
+                                {i18n.t("business_days.country_none")}
+                            </option>
+                        </select>
+                    </label>
+                </div>
+
+                <label class="business-days-custom">
+                    <span>{i18n.t("business_days.custom_holidays")}</span>
+                    <textarea
+                        class="form-input"
+                        rows="3"
+                        value={(*custom).clone()}
+                        oninput={on_custom_change}
+                        placeholder={i18n.t("business_days.custom_placeholder")}
+                        spellcheck="false"
+                    />
+                </label>
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                if res.success {
+                    <div class="section">
+                        <div class="business-days-summary">
+                            if *mode == Mode::Add {
+                                <code>{&res.end_date}</code>
+                                {format!(" ({})", i18n.t(&weekday_translation_key(res.end_weekday)))}
+                            } else {
+                                {format!("{} {}", res.business_days, i18n.t("business_days.business_days_unit"))}
+                            }
+                        </div>
+                        <div class="business-days-stats">
+                            <span>{format!("{}: {}", i18n.t("business_days.calendar_days"), res.calendar_days)}</span>
+                            <span>{format!("{}: {}", i18n.t("business_days.weekend_days"), res.weekend_days)}</span>
+                            <span>{format!("{}: {}", i18n.t("business_days.holidays_skipped"), res.holidays.len())}</span>
+                        </div>
+                        if !res.holidays.is_empty() {
+                            {holiday_table(&res.holidays)}
+                        }
+                    </div>
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {"⚠ "}{res.error.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+                }
+            }
+
+            if *country == HolidayCountry::Japan {
+                <div class="section">
+                    <div class="business-days-year">
+                        <h3>{i18n.t("business_days.holiday_list")}</h3>
+                        <button class="secondary-btn" onclick={shift_year(-1)} disabled={*year <= 2000}>{"‹"}</button>
+                        <span class="business-days-year-value">{*year}</span>
+                        <button class="secondary-btn" onclick={shift_year(1)} disabled={*year >= 2099}>{"›"}</button>
+                    </div>
+                    {holiday_table(&year_holidays)}
+                </div>
+            }
+        </div>
+    }
+}
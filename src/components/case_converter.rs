@@ -0,0 +1,223 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum CaseStyle {
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+    Constant,
+    Title,
+}
+
+#[derive(Serialize)]
+struct ConvertCaseArgs {
+    input: String,
+    target: CaseStyle,
+    multiline: bool,
+}
+
+#[derive(Serialize)]
+struct ConvertAllCasesArgs {
+    input: String,
+    multiline: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CaseConvertResult {
+    output: String,
+    words: Vec<String>,
+    detected_style: Option<CaseStyle>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CaseConversion {
+    style: CaseStyle,
+    label: String,
+    output: String,
+}
+
+fn style_label(style: CaseStyle) -> &'static str {
+    match style {
+        CaseStyle::Camel => "camelCase",
+        CaseStyle::Pascal => "PascalCase",
+        CaseStyle::Snake => "snake_case",
+        CaseStyle::Kebab => "kebab-case",
+        CaseStyle::Constant => "CONSTANT_CASE",
+        CaseStyle::Title => "Title Case",
+    }
+}
+
+#[function_component(CaseConverter)]
+pub fn case_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(|| "HTTPServerConfig".to_string());
+    let multiline = use_state(|| false);
+    let analysis = use_state(|| Option::<CaseConvertResult>::None);
+    let conversions = use_state(Vec::<CaseConversion>::new);
+    let copied = use_state(|| Option::<String>::None);
+
+    // Convert on every edit
+    {
+        let analysis = analysis.clone();
+        let conversions = conversions.clone();
+        use_effect_with(((*input).clone(), *multiline), move |(input, multiline)| {
+            let input = input.clone();
+            let multiline = *multiline;
+            if input.trim().is_empty() {
+                analysis.set(None);
+                conversions.set(Vec::new());
+            } else {
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&ConvertAllCasesArgs {
+                        input: input.clone(),
+                        multiline,
+                    })
+                    .unwrap();
+                    let response = invoke("convert_all_cases_cmd", args).await;
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<Vec<CaseConversion>>(response)
+                    {
+                        conversions.set(res);
+                    }
+
+                    let args = serde_wasm_bindgen::to_value(&ConvertCaseArgs {
+                        input,
+                        target: CaseStyle::Snake,
+                        multiline,
+                    })
+                    .unwrap();
+                    let response = invoke("convert_case_cmd", args).await;
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<CaseConvertResult>(response) {
+                        analysis.set(Some(res));
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_multiline_change = {
+        let multiline = multiline.clone();
+        Callback::from(move |e: Event| {
+            let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+            multiline.set(checkbox.checked());
+        })
+    };
+
+    let on_clear = {
+        let input = input.clone();
+        Callback::from(move |_| input.set(String::new()))
+    };
+
+    let on_copy = {
+        let copied = copied.clone();
+        Callback::from(move |value: String| {
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ =
+                        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value)).await;
+                    copied.set(Some(value));
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(None);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    html! {
+        <div class="case-converter">
+            <div class="section case-input-section">
+                <textarea
+                    class="form-textarea case-input"
+                    value={(*input).clone()}
+                    oninput={on_input_change}
+                    placeholder={i18n.t("case_converter.input_placeholder")}
+                    rows={if *multiline { "8" } else { "3" }}
+                    spellcheck="false"
+                />
+                <div class="case-options">
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={*multiline}
+                            onchange={on_multiline_change}
+                        />
+                        {i18n.t("case_converter.multiline")}
+                    </label>
+                    <button class="secondary-btn" onclick={on_clear}>
+                        {i18n.t("common.clear")}
+                    </button>
+                </div>
+
+                if let Some(res) = (*analysis).clone() {
+                    <div class="case-analysis">
+                        <span class="case-detected">
+                            {format!("{}: ", i18n.t("case_converter.detected"))}
+                            {res.detected_style.map(style_label).map(str::to_string).unwrap_or_else(|| i18n.t("case_converter.detected_unknown"))}
+                        </span>
+                        <span class="case-words-label">{i18n.t("case_converter.words")}</span>
+                        { for res.words.iter().map(|word| html! {
+                            <code class="case-word">{word}</code>
+                        }) }
+                    </div>
+                }
+            </div>
+
+            if !(*conversions).is_empty() {
+                <div class="section case-results">
+                    { for (*conversions).iter().map(|conversion| {
+                        let value = conversion.output.clone();
+                        let is_copied = copied.as_deref() == Some(value.as_str());
+                        let is_detected = (*analysis)
+                            .as_ref()
+                            .and_then(|res| res.detected_style)
+                            == Some(conversion.style);
+                        let onclick = {
+                            let on_copy = on_copy.clone();
+                            let value = value.clone();
+                            Callback::from(move |_| on_copy.emit(value.clone()))
+                        };
+                        html! {
+                            <div class={classes!("case-result-row", is_detected.then_some("detected"))}>
+                                <span class="case-result-label">{&conversion.label}</span>
+                                <pre class="case-result-value">{value}</pre>
+                                <button class={classes!("copy-btn", is_copied.then_some("copied"))} {onclick}>
+                                    if is_copied {
+                                        {"✓"}
+                                    } else {
+                                        {i18n.t("common.copy")}
+                                    }
+                                </button>
+                            </div>
+                        }
+                    }) }
+                </div>
+            }
+        </div>
+    }
+}
@@ -17,10 +17,13 @@ extern "C" {
 struct CharCountResult {
     char_count: usize,
     char_count_no_spaces: usize,
+    char_count_no_newlines: usize,
+    grapheme_count: usize,
     word_count: usize,
     line_count: usize,
     paragraph_count: usize,
     byte_count_utf8: usize,
+    byte_count_utf16: usize,
     byte_count_sjis: usize,
     alphanumeric_count: usize,
     hiragana_count: usize,
@@ -28,6 +31,9 @@ struct CharCountResult {
     kanji_count: usize,
     fullwidth_count: usize,
     halfwidth_count: usize,
+    manuscript_pages: usize,
+    tweet_weighted_length: usize,
+    tweet_remaining: i64,
 }
 
 #[derive(Clone, PartialEq)]
@@ -105,11 +111,15 @@ pub fn char_counter() -> Html {
             if let Some(win) = window() {
                 let clipboard = win.navigator().clipboard();
                 let stats = format!(
-                    "{}: {}\n{}: {}\n{}: {}\n{}: {}\n{}: {}\nUTF-8: {} bytes\nShift_JIS: {} bytes\n{}: {}\n{}: {}\n{}: {}\n{}: {}",
+                    "{}: {}\n{}: {}\n{}: {}\n{}: {}\n{}: {}\n{}: {}\n{}: {}\nUTF-8: {} bytes\nUTF-16: {} bytes\nShift_JIS: {} bytes\n{}: {}\n{}: {}\n{}: {}\n{}: {}\n{}: {}\n{}: {}/280",
                     i18n.t("char_counter.char_count"),
                     res.char_count,
+                    i18n.t("char_counter.grapheme_count"),
+                    res.grapheme_count,
                     i18n.t("char_counter.char_count_no_spaces"),
                     res.char_count_no_spaces,
+                    i18n.t("char_counter.char_count_no_newlines"),
+                    res.char_count_no_newlines,
                     i18n.t("char_counter.word_count"),
                     res.word_count,
                     i18n.t("char_counter.line_count"),
@@ -117,6 +127,7 @@ pub fn char_counter() -> Html {
                     i18n.t("char_counter.paragraph_count"),
                     res.paragraph_count,
                     res.byte_count_utf8,
+                    res.byte_count_utf16,
                     res.byte_count_sjis,
                     i18n.t("char_counter.alphanumeric"),
                     res.alphanumeric_count,
@@ -126,6 +137,10 @@ pub fn char_counter() -> Html {
                     res.katakana_count,
                     i18n.t("char_counter.kanji"),
                     res.kanji_count,
+                    i18n.t("char_counter.manuscript_pages"),
+                    res.manuscript_pages,
+                    i18n.t("char_counter.tweet_length"),
+                    res.tweet_weighted_length,
                 );
                 spawn_local(async move {
                     let _ =
@@ -241,10 +256,18 @@ pub fn char_counter() -> Html {
                         <div class="stat-value">{res.char_count}</div>
                         <div class="stat-label">{i18n.t("char_counter.char_count")}</div>
                     </div>
+                    <div class="stat-card">
+                        <div class="stat-value">{res.grapheme_count}</div>
+                        <div class="stat-label">{i18n.t("char_counter.grapheme_count")}</div>
+                    </div>
                     <div class="stat-card">
                         <div class="stat-value">{res.char_count_no_spaces}</div>
                         <div class="stat-label">{i18n.t("char_counter.char_count_no_spaces")}</div>
                     </div>
+                    <div class="stat-card">
+                        <div class="stat-value">{res.char_count_no_newlines}</div>
+                        <div class="stat-label">{i18n.t("char_counter.char_count_no_newlines")}</div>
+                    </div>
                     <div class="stat-card">
                         <div class="stat-value">{res.word_count}</div>
                         <div class="stat-label">{i18n.t("char_counter.word_count")}</div>
@@ -267,6 +290,10 @@ pub fn char_counter() -> Html {
                         <div class="stat-value">{res.byte_count_utf8}</div>
                         <div class="stat-label">{"UTF-8"}</div>
                     </div>
+                    <div class="stat-card">
+                        <div class="stat-value">{res.byte_count_utf16}</div>
+                        <div class="stat-label">{"UTF-16"}</div>
+                    </div>
                     <div class="stat-card">
                         <div class="stat-value">{res.byte_count_sjis}</div>
                         <div class="stat-label">{"Shift_JIS"}</div>
@@ -274,6 +301,25 @@ pub fn char_counter() -> Html {
                 </div>
             </div>
 
+            <div class="section char-counter-conversions">
+                <h3>{i18n.t("char_counter.conversions")}</h3>
+                <div class="stats-grid">
+                    <div class="stat-card">
+                        <div class="stat-value">{res.manuscript_pages}</div>
+                        <div class="stat-label">{i18n.t("char_counter.manuscript_pages")}</div>
+                    </div>
+                    <div class={classes!("stat-card", (res.tweet_remaining < 0).then_some("over-limit"))}>
+                        <div class="stat-value">{format!("{} / 280", res.tweet_weighted_length)}</div>
+                        <div class="stat-label">
+                            {i18n.t("char_counter.tweet_length")}
+                            {" ("}
+                            {i18n.t("char_counter.tweet_remaining").replace("{count}", &res.tweet_remaining.to_string())}
+                            {")"}
+                        </div>
+                    </div>
+                </div>
+            </div>
+
             <div class="section char-counter-char-types">
                 <h3>{i18n.t("char_counter.char_types")}</h3>
                 <div class="stats-grid">
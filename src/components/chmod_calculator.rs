@@ -0,0 +1,372 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+/// Bit for each cell of the permission grid, by class row then read/write/execute column
+const GRID_BITS: [[u16; 3]; 3] = [
+    [0o400, 0o200, 0o100],
+    [0o040, 0o020, 0o010],
+    [0o004, 0o002, 0o001],
+];
+const SPECIAL_BITS: [(u16, &str); 3] = [(0o4000, "setuid"), (0o2000, "setgid"), (0o1000, "sticky")];
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PermissionClass {
+    class: String,
+    read: bool,
+    write: bool,
+    execute: bool,
+    description_en: String,
+    description_ja: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct SpecialBit {
+    name: String,
+    description_en: String,
+    description_ja: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PermissionResult {
+    success: bool,
+    octal: String,
+    symbolic: String,
+    classes: Vec<PermissionClass>,
+    special_bits: Vec<SpecialBit>,
+    warnings: Vec<String>,
+    error: Option<String>,
+    error_position: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PermissionPreset {
+    octal: String,
+    symbolic: String,
+    description_en: String,
+    description_ja: String,
+}
+
+#[derive(Serialize)]
+struct ParseArgs {
+    input: String,
+}
+
+#[derive(Serialize)]
+struct ApplyArgs {
+    base: String,
+    change: String,
+}
+
+/// The input with the character at `position` marked, so the user can see what is wrong
+fn highlighted_input(input: &str, position: usize) -> Html {
+    html! {
+        <code class="chmod-error-input">
+            { for input.trim().chars().enumerate().map(|(index, c)| {
+                if index == position {
+                    html! { <mark>{c}</mark> }
+                } else {
+                    html! { {c} }
+                }
+            }) }
+        </code>
+    }
+}
+
+#[function_component(ChmodCalculator)]
+pub fn chmod_calculator() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(|| "755".to_string());
+    let change = use_state(|| "u+x".to_string());
+    let result = use_state(|| Option::<PermissionResult>::None);
+    let change_result = use_state(|| Option::<PermissionResult>::None);
+    let presets = use_state(Vec::<PermissionPreset>::new);
+    let copied = use_state(|| Option::<String>::None);
+
+    {
+        let presets = presets.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let response = invoke("list_permission_presets_cmd", JsValue::NULL).await;
+                presets.set(serde_wasm_bindgen::from_value(response).unwrap_or_default());
+            });
+            || ()
+        });
+    }
+
+    // Re-parse on every edit
+    {
+        let result = result.clone();
+        use_effect_with((*input).clone(), move |input| {
+            if input.trim().is_empty() {
+                result.set(None);
+            } else {
+                let args = serde_wasm_bindgen::to_value(&ParseArgs {
+                    input: input.clone(),
+                })
+                .unwrap();
+                spawn_local(async move {
+                    let response = invoke("parse_permission_cmd", args).await;
+                    result.set(serde_wasm_bindgen::from_value(response).ok());
+                });
+            }
+            || ()
+        });
+    }
+
+    {
+        let change_result = change_result.clone();
+        use_effect_with(
+            ((*input).clone(), (*change).clone()),
+            move |(base, change)| {
+                if base.trim().is_empty() || change.trim().is_empty() {
+                    change_result.set(None);
+                } else {
+                    let args = serde_wasm_bindgen::to_value(&ApplyArgs {
+                        base: base.clone(),
+                        change: change.clone(),
+                    })
+                    .unwrap();
+                    spawn_local(async move {
+                        let response = invoke("apply_symbolic_change_cmd", args).await;
+                        change_result.set(serde_wasm_bindgen::from_value(response).ok());
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let on_input = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_change_input = {
+        let change = change.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            change.set(target.value());
+        })
+    };
+
+    let on_copy = {
+        let copied = copied.clone();
+        Callback::from(move |value: String| {
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ =
+                        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value)).await;
+                    copied.set(Some(value));
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(None);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let japanese = i18n.get_current_language() == "ja";
+    let current = (*result).clone().filter(|res| res.success);
+    let mode = current
+        .as_ref()
+        .and_then(|res| u16::from_str_radix(&res.octal, 8).ok())
+        .unwrap_or(0);
+
+    // Flip one bit from the grid and write the result back as octal
+    let toggle = |bit: u16| {
+        let input = input.clone();
+        Callback::from(move |_| {
+            let next = mode ^ bit;
+            input.set(if next & 0o7000 != 0 {
+                format!("{:04o}", next)
+            } else {
+                format!("{:03o}", next)
+            });
+        })
+    };
+
+    let result_row = |label: String, value: String| {
+        let is_copied = copied.as_deref() == Some(value.as_str());
+        let onclick = {
+            let on_copy = on_copy.clone();
+            let value = value.clone();
+            Callback::from(move |_| on_copy.emit(value.clone()))
+        };
+        html! {
+            <div class="radix-result-row">
+                <span class="radix-result-label">{label}</span>
+                <code class="radix-result-value">{value}</code>
+                <button class={classes!("copy-btn", is_copied.then_some("copied"))} {onclick}>
+                    if is_copied {
+                        {"✓"}
+                    } else {
+                        {i18n.t("common.copy")}
+                    }
+                </button>
+            </div>
+        }
+    };
+
+    let error_view = |res: &PermissionResult, source: &str| {
+        html! {
+            <div class="error-message">
+                {"⚠ "}{res.error.clone().unwrap_or_default()}
+                if let Some(position) = res.error_position {
+                    <div>{highlighted_input(source, position)}</div>
+                }
+            </div>
+        }
+    };
+
+    html! {
+        <div class="chmod-calculator">
+            <div class="section">
+                <h3>{i18n.t("chmod_calculator.title")}</h3>
+                <input
+                    type="text"
+                    class="form-input chmod-input"
+                    value={(*input).clone()}
+                    oninput={on_input}
+                    placeholder={i18n.t("chmod_calculator.input_placeholder")}
+                    spellcheck="false"
+                />
+
+                if let Some(res) = (*result).clone() {
+                    if res.success {
+                        <div class="radix-results">
+                            {result_row(i18n.t("chmod_calculator.octal"), res.octal.clone())}
+                            {result_row(i18n.t("chmod_calculator.symbolic"), res.symbolic.clone())}
+                            {result_row(i18n.t("chmod_calculator.command"), format!("chmod {} <file>", res.octal))}
+                        </div>
+                        { for res.warnings.iter().map(|w| html! {
+                            <div class="warning-message">{"⚠ "}{w}</div>
+                        }) }
+                    } else {
+                        {error_view(&res, &input)}
+                    }
+                }
+            </div>
+
+            <div class="section">
+                <table class="chmod-grid">
+                    <thead>
+                        <tr>
+                            <th></th>
+                            <th>{i18n.t("chmod_calculator.read")}</th>
+                            <th>{i18n.t("chmod_calculator.write")}</th>
+                            <th>{i18n.t("chmod_calculator.execute")}</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for ["owner", "group", "other"].iter().zip(GRID_BITS).map(|(class, bits)| {
+                            let description = current
+                                .as_ref()
+                                .and_then(|res| res.classes.iter().find(|c| c.class == *class))
+                                .map(|c| if japanese { c.description_ja.clone() } else { c.description_en.clone() })
+                                .unwrap_or_default();
+                            html! {
+                                <tr>
+                                    <th>{i18n.t(&format!("chmod_calculator.class_{}", class))}</th>
+                                    { for bits.iter().map(|bit| html! {
+                                        <td>
+                                            <input
+                                                type="checkbox"
+                                                checked={mode & bit != 0}
+                                                disabled={current.is_none()}
+                                                onclick={toggle(*bit)}
+                                            />
+                                        </td>
+                                    }) }
+                                    <td class="chmod-description">{description}</td>
+                                </tr>
+                            }
+                        }) }
+                    </tbody>
+                </table>
+                <div class="chmod-special-bits">
+                    { for SPECIAL_BITS.iter().map(|(bit, name)| html! {
+                        <label class="checkbox-option-inline">
+                            <input
+                                type="checkbox"
+                                checked={mode & bit != 0}
+                                disabled={current.is_none()}
+                                onclick={toggle(*bit)}
+                            />
+                            <span>{*name}</span>
+                        </label>
+                    }) }
+                </div>
+                if let Some(res) = current.as_ref() {
+                    { for res.special_bits.iter().map(|special| html! {
+                        <div class="chmod-special-description">
+                            <code>{&special.name}</code>
+                            {" "}
+                            {if japanese { special.description_ja.clone() } else { special.description_en.clone() }}
+                        </div>
+                    }) }
+                }
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("chmod_calculator.apply_change")}</h3>
+                <div class="chmod-change-row">
+                    <code>{(*input).clone()}</code>
+                    <span>{"+"}</span>
+                    <input
+                        type="text"
+                        class="form-input chmod-input"
+                        value={(*change).clone()}
+                        oninput={on_change_input}
+                        placeholder="u+x,g-w"
+                        spellcheck="false"
+                    />
+                    if let Some(res) = (*change_result).clone().filter(|res| res.success) {
+                        <span>{"→"}</span>
+                        <code class="chmod-change-result">{format!("{} ({})", res.octal, res.symbolic)}</code>
+                    }
+                </div>
+                if let Some(res) = (*change_result).clone().filter(|res| !res.success) {
+                    {error_view(&res, &change)}
+                }
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("chmod_calculator.presets")}</h3>
+                <div class="chmod-presets">
+                    { for presets.iter().map(|preset| {
+                        let onclick = {
+                            let input = input.clone();
+                            let octal = preset.octal.clone();
+                            Callback::from(move |_| input.set(octal.clone()))
+                        };
+                        html! {
+                            <button class="chmod-preset" {onclick}>
+                                <code>{format!("{} {}", preset.octal, preset.symbolic)}</code>
+                                <span>{if japanese { preset.description_ja.clone() } else { preset.description_en.clone() }}</span>
+                            </button>
+                        }
+                    }) }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,333 @@
+use gloo_timers::callback::Timeout;
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClipEntry {
+    id: String,
+    content: String,
+    created_at: String,
+    pinned: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClipboardSettings {
+    exclude_secrets: bool,
+}
+
+#[derive(Serialize)]
+struct IdArgs {
+    id: String,
+}
+
+#[derive(Serialize)]
+struct SettingsArgs {
+    settings: ClipboardSettings,
+}
+
+/// Longest preview shown for an entry before it is expanded
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CopySensitiveArgs<'a> {
+    text: &'a str,
+    clear_after_secs: u64,
+}
+
+const PREVIEW_CHARS: usize = 300;
+/// How long copied passwords and IDs stay on the clipboard
+const SENSITIVE_CLEAR_SECS: u64 = 30;
+
+/// Copy `text` through the backend, which clears the clipboard again after
+/// `SENSITIVE_CLEAR_SECS` unless something else was copied in the meantime
+pub async fn copy_sensitive_text(text: &str) {
+    let args = serde_wasm_bindgen::to_value(&CopySensitiveArgs {
+        text,
+        clear_after_secs: SENSITIVE_CLEAR_SECS,
+    })
+    .unwrap_or(JsValue::NULL);
+    let _ = invoke("copy_sensitive_text_cmd", args).await;
+}
+
+pub fn format_time(created_at: &str) -> String {
+    js_sys::Date::new(&JsValue::from_str(created_at))
+        .to_locale_string("default", &JsValue::UNDEFINED)
+        .into()
+}
+
+async fn fetch_history() -> Option<Vec<ClipEntry>> {
+    let result = invoke("get_clipboard_history_cmd", JsValue::NULL).await;
+    serde_wasm_bindgen::from_value::<Vec<ClipEntry>>(result).ok()
+}
+
+#[function_component(ClipboardHistory)]
+pub fn clipboard_history() -> Html {
+    let (i18n, _) = use_translation();
+    let entries = use_state(Vec::<ClipEntry>::new);
+    let running = use_state(|| false);
+    let exclude_secrets = use_state(|| true);
+    let search = use_state(String::new);
+    let expanded = use_state(|| Option::<String>::None);
+    let copied_id = use_state(|| Option::<String>::None);
+
+    // Load the saved history and follow new copies while the monitor runs
+    {
+        let entries = entries.clone();
+        let running = running.clone();
+        let exclude_secrets = exclude_secrets.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Some(history) = fetch_history().await {
+                    entries.set(history);
+                }
+                let status = invoke("is_clipboard_monitor_running_cmd", JsValue::NULL).await;
+                running.set(status.as_bool().unwrap_or(false));
+                let settings = invoke("get_clipboard_settings_cmd", JsValue::NULL).await;
+                if let Ok(settings) = serde_wasm_bindgen::from_value::<ClipboardSettings>(settings)
+                {
+                    exclude_secrets.set(settings.exclude_secrets);
+                }
+
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |_event: JsValue| {
+                    let entries = entries.clone();
+                    spawn_local(async move {
+                        if let Some(history) = fetch_history().await {
+                            entries.set(history);
+                        }
+                    });
+                });
+                let _ = listen("clipboard-history-updated", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    let on_toggle_monitor = {
+        let running = running.clone();
+        Callback::from(move |_| {
+            let running = running.clone();
+            let command = if *running {
+                "stop_clipboard_monitor_cmd"
+            } else {
+                "start_clipboard_monitor_cmd"
+            };
+            spawn_local(async move {
+                invoke(command, JsValue::NULL).await;
+                let status = invoke("is_clipboard_monitor_running_cmd", JsValue::NULL).await;
+                running.set(status.as_bool().unwrap_or(false));
+            });
+        })
+    };
+
+    let on_exclude_secrets_change = {
+        let exclude_secrets = exclude_secrets.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let exclude_secrets = exclude_secrets.clone();
+            let settings = ClipboardSettings {
+                exclude_secrets: input.checked(),
+            };
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&SettingsArgs { settings }).unwrap();
+                let result = invoke("update_clipboard_settings_cmd", args).await;
+                if let Ok(settings) = serde_wasm_bindgen::from_value::<ClipboardSettings>(result) {
+                    exclude_secrets.set(settings.exclude_secrets);
+                }
+            });
+        })
+    };
+
+    let on_search_input = {
+        let search = search.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            search.set(input.value());
+        })
+    };
+
+    let on_clear = {
+        let entries = entries.clone();
+        Callback::from(move |_| {
+            let entries = entries.clone();
+            spawn_local(async move {
+                let result = invoke("clear_clipboard_history_cmd", JsValue::NULL).await;
+                if let Ok(remaining) = serde_wasm_bindgen::from_value::<Vec<ClipEntry>>(result) {
+                    entries.set(remaining);
+                }
+            });
+        })
+    };
+
+    // Pin and delete change one entry and then reload the list
+    let entry_action = |command: &'static str, id: String| {
+        let entries = entries.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            let entries = entries.clone();
+            let id = id.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&IdArgs { id }).unwrap();
+                invoke(command, args).await;
+                if let Some(history) = fetch_history().await {
+                    entries.set(history);
+                }
+            });
+        })
+    };
+
+    let on_copy = |entry: &ClipEntry| {
+        let copied_id = copied_id.clone();
+        let id = entry.id.clone();
+        let text = entry.content.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            let copied_id = copied_id.clone();
+            let id = id.clone();
+            let text = text.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text)).await;
+                    copied_id.set(Some(id));
+
+                    Timeout::new(2000, move || {
+                        copied_id.set(None);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let on_toggle_expand = |id: String| {
+        let expanded = expanded.clone();
+        Callback::from(move |_| {
+            if expanded.as_deref() == Some(id.as_str()) {
+                expanded.set(None);
+            } else {
+                expanded.set(Some(id.clone()));
+            }
+        })
+    };
+
+    let query = search.to_lowercase();
+    let (pinned, recent): (Vec<ClipEntry>, Vec<ClipEntry>) = entries
+        .iter()
+        .filter(|entry| query.is_empty() || entry.content.to_lowercase().contains(&query))
+        .cloned()
+        .partition(|entry| entry.pinned);
+
+    let render_entry = |entry: &ClipEntry| {
+        let is_expanded = expanded.as_deref() == Some(entry.id.as_str());
+        let char_count = entry.content.chars().count();
+        let preview: String = if is_expanded || char_count <= PREVIEW_CHARS {
+            entry.content.clone()
+        } else {
+            let head: String = entry.content.chars().take(PREVIEW_CHARS).collect();
+            format!("{}…", head)
+        };
+        html! {
+            <div
+                key={entry.id.clone()}
+                class={classes!("clip-entry", entry.pinned.then_some("pinned"))}
+                onclick={on_toggle_expand(entry.id.clone())}
+            >
+                <pre class="clip-content">{preview}</pre>
+                <div class="clip-meta">
+                    <span>{format_time(&entry.created_at)}</span>
+                    <span>{format!("{} {}", char_count, i18n.t("clipboard_history.chars"))}</span>
+                    <div class="clip-actions">
+                        <button
+                            class={classes!("secondary-btn", (copied_id.as_deref() == Some(entry.id.as_str())).then_some("copied"))}
+                            onclick={on_copy(entry)}
+                        >
+                            if copied_id.as_deref() == Some(entry.id.as_str()) {
+                                {i18n.t("common.copied")}
+                            } else {
+                                {i18n.t("common.copy")}
+                            }
+                        </button>
+                        <button class="secondary-btn" onclick={entry_action("pin_clip_entry_cmd", entry.id.clone())}>
+                            if entry.pinned {
+                                {i18n.t("clipboard_history.unpin")}
+                            } else {
+                                {i18n.t("clipboard_history.pin")}
+                            }
+                        </button>
+                        <button class="move-btn" onclick={entry_action("delete_clip_entry_cmd", entry.id.clone())}>
+                            {"✕"}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="clipboard-history">
+            <div class="section clip-toolbar">
+                <button
+                    class={if *running { "secondary-btn" } else { "primary-btn" }}
+                    onclick={on_toggle_monitor}
+                >
+                    if *running {
+                        {i18n.t("clipboard_history.stop")}
+                    } else {
+                        {i18n.t("clipboard_history.start")}
+                    }
+                </button>
+                <span class={classes!("clip-status", running.then_some("active"))}>
+                    if *running {
+                        {i18n.t("clipboard_history.monitoring")}
+                    } else {
+                        {i18n.t("clipboard_history.stopped")}
+                    }
+                </span>
+                <label class="clip-option">
+                    <input type="checkbox" checked={*exclude_secrets} onchange={on_exclude_secrets_change} />
+                    {i18n.t("clipboard_history.exclude_secrets")}
+                </label>
+                <input
+                    type="text"
+                    class="clip-search"
+                    placeholder={i18n.t("clipboard_history.search")}
+                    value={(*search).clone()}
+                    oninput={on_search_input}
+                />
+                <button class="secondary-btn" onclick={on_clear} disabled={entries.iter().all(|e| e.pinned)}>
+                    {i18n.t("common.clear_all")}
+                </button>
+            </div>
+
+            if !pinned.is_empty() {
+                <div class="section">
+                    <h3>{i18n.t("clipboard_history.pinned")}</h3>
+                    { for pinned.iter().map(render_entry) }
+                </div>
+            }
+
+            <div class="section">
+                <h3>{format!("{} ({})", i18n.t("clipboard_history.recent"), recent.len())}</h3>
+                if recent.is_empty() {
+                    <p class="clip-empty">{i18n.t("clipboard_history.empty")}</p>
+                }
+                { for recent.iter().map(render_entry) }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,325 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum PaletteKind {
+    Complementary,
+    Analogous,
+    Triadic,
+    Shades,
+}
+
+impl PaletteKind {
+    const ALL: [PaletteKind; 4] = [
+        PaletteKind::Complementary,
+        PaletteKind::Analogous,
+        PaletteKind::Triadic,
+        PaletteKind::Shades,
+    ];
+
+    fn translation_key(&self) -> &'static str {
+        match self {
+            PaletteKind::Complementary => "color_converter.palette_complementary",
+            PaletteKind::Analogous => "color_converter.palette_analogous",
+            PaletteKind::Triadic => "color_converter.palette_triadic",
+            PaletteKind::Shades => "color_converter.palette_shades",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ConvertColorArgs {
+    input: String,
+}
+
+#[derive(Serialize)]
+struct GeneratePaletteArgs {
+    base: String,
+    kind: PaletteKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ColorValues {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ColorConvertResult {
+    success: bool,
+    hex: String,
+    rgb: String,
+    hsl: String,
+    hsv: String,
+    cmyk: String,
+    name: Option<String>,
+    values: Option<ColorValues>,
+    error: Option<String>,
+    invalid_part: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PaletteColor {
+    hex: String,
+    rgb: String,
+    hsl: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PaletteResult {
+    success: bool,
+    colors: Vec<PaletteColor>,
+    error: Option<String>,
+}
+
+fn request_conversion(input: String, result: UseStateHandle<Option<ColorConvertResult>>) {
+    if input.trim().is_empty() {
+        result.set(None);
+        return;
+    }
+    spawn_local(async move {
+        let args = serde_wasm_bindgen::to_value(&ConvertColorArgs { input }).unwrap();
+        let response = invoke("convert_color_cmd", args).await;
+        if let Ok(res) = serde_wasm_bindgen::from_value::<ColorConvertResult>(response) {
+            result.set(Some(res));
+        }
+    });
+}
+
+#[function_component(ColorConverter)]
+pub fn color_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(|| "#ff8800".to_string());
+    let result = use_state(|| Option::<ColorConvertResult>::None);
+    let palette_kind = use_state(|| PaletteKind::Complementary);
+    let palette = use_state(|| Option::<PaletteResult>::None);
+    let copied = use_state(|| Option::<String>::None);
+
+    {
+        let input = input.clone();
+        let result = result.clone();
+        use_effect_with((), move |_| {
+            request_conversion((*input).clone(), result);
+            || ()
+        });
+    }
+
+    let on_input_change = {
+        let input = input.clone();
+        let result = result.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            input.set(target.value());
+            request_conversion(target.value(), result.clone());
+        })
+    };
+
+    let on_generate_palette = {
+        let input = input.clone();
+        let palette_kind = palette_kind.clone();
+        let palette = palette.clone();
+        Callback::from(move |kind: PaletteKind| {
+            palette_kind.set(kind);
+            let base = (*input).clone();
+            let palette = palette.clone();
+            spawn_local(async move {
+                let args =
+                    serde_wasm_bindgen::to_value(&GeneratePaletteArgs { base, kind }).unwrap();
+                let response = invoke("generate_palette_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<PaletteResult>(response) {
+                    palette.set(Some(res));
+                }
+            });
+        })
+    };
+
+    let on_pick = {
+        let input = input.clone();
+        let result = result.clone();
+        Callback::from(move |value: String| {
+            input.set(value.clone());
+            request_conversion(value, result.clone());
+        })
+    };
+
+    let on_picker_input = {
+        let on_pick = on_pick.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            on_pick.emit(target.value());
+        })
+    };
+
+    let on_copy = {
+        let copied = copied.clone();
+        Callback::from(move |value: String| {
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ =
+                        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value)).await;
+                    copied.set(Some(value));
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(None);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let format_row = |label: &'static str, value: &str| {
+        let value = value.to_string();
+        let is_copied = copied.as_deref() == Some(value.as_str());
+        let onclick = {
+            let on_copy = on_copy.clone();
+            let value = value.clone();
+            Callback::from(move |_| on_copy.emit(value.clone()))
+        };
+        html! {
+            <div class="color-format-row">
+                <span class="color-format-label">{label}</span>
+                <code class="color-format-value">{value}</code>
+                <button class={classes!("copy-btn", is_copied.then_some("copied"))} {onclick}>
+                    if is_copied {
+                        {"✓"}
+                    } else {
+                        {i18n.t("common.copy")}
+                    }
+                </button>
+            </div>
+        }
+    };
+
+    // The native color picker only understands #rrggbb
+    let picker_value = (*result)
+        .as_ref()
+        .and_then(|res| res.values.as_ref())
+        .map(|v| format!("#{:02x}{:02x}{:02x}", v.red, v.green, v.blue))
+        .unwrap_or_else(|| "#000000".to_string());
+
+    html! {
+        <div class="color-converter">
+            <div class="section color-input-section">
+                <label class="format-label">{i18n.t("color_converter.input_label")}</label>
+                <div class="color-input-row">
+                    <input
+                        type="color"
+                        class="color-picker"
+                        value={picker_value}
+                        oninput={on_picker_input}
+                    />
+                    <input
+                        type="text"
+                        class="form-input color-text-input"
+                        placeholder={i18n.t("color_converter.input_placeholder")}
+                        value={(*input).clone()}
+                        oninput={on_input_change}
+                        spellcheck="false"
+                    />
+                </div>
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                if res.success {
+                    <div class="section color-result-section">
+                        <div class="color-swatch-wrapper">
+                            <div class="color-swatch" style={format!("background: {};", res.rgb)}></div>
+                            if let Some(name) = &res.name {
+                                <span class="color-name">{name}</span>
+                            }
+                            if let Some(values) = &res.values {
+                                if values.alpha < 1.0 {
+                                    <span class="color-alpha">
+                                        {format!("{}: {:.0}%", i18n.t("color_converter.alpha"), values.alpha * 100.0)}
+                                    </span>
+                                }
+                            }
+                        </div>
+                        <div class="color-formats">
+                            {format_row("HEX", &res.hex)}
+                            {format_row("RGB", &res.rgb)}
+                            {format_row("HSL", &res.hsl)}
+                            {format_row("HSV", &res.hsv)}
+                            {format_row("CMYK", &res.cmyk)}
+                        </div>
+                    </div>
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {"⚠ "}{res.error.clone().unwrap_or_default()}
+                            if let Some(part) = res.invalid_part.as_ref().filter(|p| !p.is_empty()) {
+                                <div class="color-invalid-part">
+                                    {i18n.t("color_converter.invalid_part")}{": "}<code>{part}</code>
+                                </div>
+                            }
+                        </div>
+                    </div>
+                }
+            }
+
+            <div class="section color-palette-section">
+                <h3>{i18n.t("color_converter.palette_section")}</h3>
+                <div class="color-palette-kinds">
+                    { for PaletteKind::ALL.iter().map(|kind| {
+                        let kind = *kind;
+                        let onclick = {
+                            let on_generate_palette = on_generate_palette.clone();
+                            Callback::from(move |_| on_generate_palette.emit(kind))
+                        };
+                        html! {
+                            <button
+                                class={classes!("secondary-btn", (*palette_kind == kind && palette.is_some()).then_some("active"))}
+                                {onclick}
+                                disabled={!(*result).as_ref().is_some_and(|r| r.success)}
+                            >
+                                {i18n.t(kind.translation_key())}
+                            </button>
+                        }
+                    }) }
+                </div>
+
+                if let Some(palette) = (*palette).clone() {
+                    if palette.success {
+                        <div class="color-palette">
+                            { for palette.colors.iter().map(|color| {
+                                let onclick = {
+                                    let on_pick = on_pick.clone();
+                                    let hex = color.hex.clone();
+                                    Callback::from(move |_| on_pick.emit(hex.clone()))
+                                };
+                                html! {
+                                    <button class="color-palette-item" {onclick} title={color.hsl.clone()}>
+                                        <span class="color-palette-swatch" style={format!("background: {};", color.rgb)}></span>
+                                        <code>{&color.hex}</code>
+                                    </button>
+                                }
+                            }) }
+                        </div>
+                    } else if let Some(err) = &palette.error {
+                        <div class="error-message">{"⚠ "}{err}</div>
+                    }
+                }
+            </div>
+        </div>
+    }
+}
@@ -1,4 +1,6 @@
 use i18nrs::yew::use_translation;
+use serde::Deserialize;
+use std::collections::HashMap;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
@@ -12,14 +14,27 @@ pub struct ToolItem {
     pub keywords: Vec<String>,
 }
 
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolUsage {
+    pub tool_id: String,
+    pub count: u64,
+}
+
 #[derive(Properties, PartialEq)]
 pub struct CommandPaletteProps {
     pub visible: bool,
     pub on_close: Callback<()>,
     pub on_select: Callback<String>,
     pub tools: Vec<ToolItem>,
+    /// Most recently used first
+    #[prop_or_default]
+    pub usage: Vec<ToolUsage>,
 }
 
+/// Tools shown in the "recently used" section when there is no query
+const RECENT_LIMIT: usize = 5;
+
 fn fuzzy_match(query: &str, target: &str) -> bool {
     if query.is_empty() {
         return true;
@@ -109,8 +124,36 @@ pub fn command_palette(props: &CommandPaletteProps) -> Html {
     let selected_index = use_state(|| 0usize);
     let input_ref = use_node_ref();
 
-    // Filter and sort tools
-    let filtered_tools = {
+    let counts: HashMap<&str, u64> = props
+        .usage
+        .iter()
+        .map(|u| (u.tool_id.as_str(), u.count))
+        .collect();
+    let count_of = |tool: &ToolItem| counts.get(tool.id.as_str()).copied().unwrap_or(0);
+
+    // Without a query, recently used tools come first and the rest follow by how often
+    // they are used; with a query, usage only breaks ties between equal scores
+    let (filtered_tools, recent_len) = if query.is_empty() {
+        let recent: Vec<ToolItem> = props
+            .usage
+            .iter()
+            .filter_map(|u| props.tools.iter().find(|tool| tool.id == u.tool_id))
+            .take(RECENT_LIMIT)
+            .cloned()
+            .collect();
+        let mut rest: Vec<ToolItem> = props
+            .tools
+            .iter()
+            .filter(|tool| !recent.iter().any(|r| r.id == tool.id))
+            .cloned()
+            .collect();
+        rest.sort_by_key(|tool| std::cmp::Reverse(count_of(tool)));
+        let recent_len = recent.len();
+        (
+            recent.into_iter().chain(rest).collect::<Vec<_>>(),
+            recent_len,
+        )
+    } else {
         let query_str = (*query).clone();
         let mut tools: Vec<(i32, ToolItem)> = props
             .tools
@@ -124,8 +167,14 @@ pub fn command_palette(props: &CommandPaletteProps) -> Html {
                 }
             })
             .collect();
-        tools.sort_by(|a, b| b.0.cmp(&a.0));
-        tools.into_iter().map(|(_, tool)| tool).collect::<Vec<_>>()
+        tools.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| count_of(&b.1).cmp(&count_of(&a.1)))
+        });
+        (
+            tools.into_iter().map(|(_, tool)| tool).collect::<Vec<_>>(),
+            0,
+        )
     };
 
     // Focus input when palette becomes visible
@@ -255,7 +304,18 @@ pub fn command_palette(props: &CommandPaletteProps) -> Html {
                                     selected_index.set(index);
                                 })
                             };
+                            let section_label = if recent_len > 0 && index == 0 {
+                                Some(i18n.t("command_palette.recent"))
+                            } else if recent_len > 0 && index == recent_len {
+                                Some(i18n.t("command_palette.all_tools"))
+                            } else {
+                                None
+                            };
                             html! {
+                                <>
+                                if let Some(label) = section_label {
+                                    <div class="command-palette-section">{label}</div>
+                                }
                                 <button
                                     class={classes!("command-palette-item", is_selected.then_some("selected"))}
                                     onclick={on_click}
@@ -270,6 +330,7 @@ pub fn command_palette(props: &CommandPaletteProps) -> Html {
                                     </div>
                                     <span class="command-palette-item-category">{&tool.category}</span>
                                 </button>
+                                </>
                             }
                         })}
                     }
@@ -0,0 +1,271 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl ConfigFormat {
+    const ALL: [ConfigFormat; 3] = [ConfigFormat::Json, ConfigFormat::Yaml, ConfigFormat::Toml];
+
+    fn label(&self) -> &'static str {
+        match self {
+            ConfigFormat::Json => "JSON",
+            ConfigFormat::Yaml => "YAML",
+            ConfigFormat::Toml => "TOML",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<ConfigFormat> {
+        Self::ALL.into_iter().find(|f| f.label() == label)
+    }
+}
+
+#[derive(Serialize)]
+struct ConvertConfigArgs {
+    input: String,
+    from: ConfigFormat,
+    to: ConfigFormat,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ConfigConvertResult {
+    success: bool,
+    output: String,
+    error: Option<String>,
+}
+
+#[function_component(ConfigConverter)]
+pub fn config_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let from = use_state(|| ConfigFormat::Json);
+    let to = use_state(|| ConfigFormat::Yaml);
+    let input = use_state(String::new);
+    let output = use_state(String::new);
+    let error = use_state(|| Option::<String>::None);
+    let is_processing = use_state(|| false);
+    let copy_feedback = use_state(|| false);
+
+    let format_select = |current: &UseStateHandle<ConfigFormat>| {
+        let state = current.clone();
+        let onchange = Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(format) = ConfigFormat::from_label(&select.value()) {
+                state.set(format);
+            }
+        });
+        html! {
+            <select class="format-select" {onchange}>
+                { for ConfigFormat::ALL.iter().map(|f| html! {
+                    <option value={f.label()} selected={**current == *f}>{f.label()}</option>
+                }) }
+            </select>
+        }
+    };
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_convert = {
+        let from = from.clone();
+        let to = to.clone();
+        let input = input.clone();
+        let output = output.clone();
+        let error = error.clone();
+        let is_processing = is_processing.clone();
+
+        Callback::from(move |_| {
+            let input_val = (*input).clone();
+            let from = *from;
+            let to = *to;
+            let output = output.clone();
+            let error = error.clone();
+            let is_processing = is_processing.clone();
+
+            if input_val.trim().is_empty() {
+                return;
+            }
+
+            is_processing.set(true);
+
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&ConvertConfigArgs {
+                    input: input_val,
+                    from,
+                    to,
+                })
+                .unwrap();
+                let result = invoke("convert_config_cmd", args).await;
+
+                match serde_wasm_bindgen::from_value::<ConfigConvertResult>(result) {
+                    Ok(res) if res.success => {
+                        output.set(res.output);
+                        error.set(None);
+                    }
+                    Ok(res) => {
+                        output.set(String::new());
+                        error.set(res.error);
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("Failed to parse result: {:?}", e)));
+                    }
+                }
+
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let on_swap = {
+        let from = from.clone();
+        let to = to.clone();
+        let input = input.clone();
+        let output = output.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            let (current_from, current_to) = (*from, *to);
+            from.set(current_to);
+            to.set(current_from);
+            if !(*output).is_empty() {
+                input.set((*output).clone());
+                output.set(String::new());
+            }
+            error.set(None);
+        })
+    };
+
+    let on_copy = {
+        let output = output.clone();
+        let copy_feedback = copy_feedback.clone();
+        Callback::from(move |_| {
+            let output_val = (*output).clone();
+            let copy_feedback = copy_feedback.clone();
+
+            if !output_val.is_empty() {
+                if let Some(win) = window() {
+                    let clipboard = win.navigator().clipboard();
+                    spawn_local(async move {
+                        let _ =
+                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&output_val))
+                                .await;
+                        copy_feedback.set(true);
+
+                        let copy_feedback_reset = copy_feedback.clone();
+                        gloo_timers::callback::Timeout::new(2000, move || {
+                            copy_feedback_reset.set(false);
+                        })
+                        .forget();
+                    });
+                }
+            }
+        })
+    };
+
+    let on_clear = {
+        let input = input.clone();
+        let output = output.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            input.set(String::new());
+            output.set(String::new());
+            error.set(None);
+        })
+    };
+
+    html! {
+        <div class="config-converter">
+            <div class="section format-section">
+                <label class="format-label">{i18n.t("config_converter.from")}</label>
+                {format_select(&from)}
+                <button class="secondary-btn swap-btn" onclick={on_swap} title={i18n.t("common.swap")}>
+                    <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                        <path d="M7 16V4M7 4L3 8M7 4L11 8M17 8V20M17 20L21 16M17 20L13 16"/>
+                    </svg>
+                </button>
+                <label class="format-label">{i18n.t("config_converter.to")}</label>
+                {format_select(&to)}
+                <button
+                    class="primary-btn"
+                    onclick={on_convert}
+                    disabled={*is_processing || (*input).trim().is_empty()}
+                >
+                    if *is_processing {
+                        <span class="processing">
+                            <span class="spinner"></span>
+                            {i18n.t("common.processing")}
+                        </span>
+                    } else {
+                        {i18n.t("config_converter.convert_btn")}
+                    }
+                </button>
+            </div>
+
+            if let Some(err) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">
+                        {"⚠ "}{err}
+                    </div>
+                </div>
+            }
+
+            <div class="section editor-section">
+                <div class="editor-panel">
+                    <div class="section-header">
+                        <h3>{format!("{} ({})", i18n.t("common.input"), from.label())}</h3>
+                        <button class="secondary-btn" onclick={on_clear}>
+                            {i18n.t("common.clear")}
+                        </button>
+                    </div>
+                    <textarea
+                        class="config-textarea"
+                        placeholder={i18n.t("config_converter.input_placeholder")}
+                        value={(*input).clone()}
+                        oninput={on_input_change}
+                        spellcheck="false"
+                    />
+                </div>
+                <div class="editor-panel">
+                    <div class="section-header">
+                        <h3>{format!("{} ({})", i18n.t("common.output"), to.label())}</h3>
+                        <button
+                            class={classes!("secondary-btn", (*copy_feedback).then_some("copied"))}
+                            onclick={on_copy}
+                            disabled={(*output).is_empty()}
+                        >
+                            if *copy_feedback {
+                                {format!("✓ {}", i18n.t("common.copied"))}
+                            } else {
+                                {i18n.t("common.copy")}
+                            }
+                        </button>
+                    </div>
+                    <textarea
+                        class="config-textarea"
+                        readonly=true
+                        value={(*output).clone()}
+                        spellcheck="false"
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
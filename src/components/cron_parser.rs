@@ -0,0 +1,273 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+const EXAMPLES: [&str; 6] = [
+    "*/5 * * * *",
+    "0 9 * * MON-FRI",
+    "30 2 1 * *",
+    "0 0 * * 0",
+    "*/30 * * * * *",
+    "@daily",
+];
+
+const FIELD_KEYS: [&str; 6] = [
+    "second",
+    "minute",
+    "hour",
+    "day_of_month",
+    "month",
+    "day_of_week",
+];
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+enum TimezoneOption {
+    Local,
+    Utc,
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct TimezoneInfo {
+    name: String,
+    utc_offset: String,
+}
+
+#[derive(Serialize)]
+struct ParseCronArgs {
+    expression: String,
+    timezone: TimezoneOption,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CronFieldDescription {
+    field: String,
+    value: String,
+    description_en: String,
+    description_ja: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CronNextRun {
+    datetime: String,
+    day_of_week: String,
+    unix_seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CronParseResult {
+    success: bool,
+    normalized: String,
+    fields: Vec<CronFieldDescription>,
+    description_en: String,
+    description_ja: String,
+    next_runs: Vec<CronNextRun>,
+    warnings: Vec<String>,
+    error: Option<String>,
+    invalid_field: Option<String>,
+}
+
+fn field_translation_key(field: &str) -> String {
+    format!("cron_parser.field_{}", field)
+}
+
+#[function_component(CronParser)]
+pub fn cron_parser() -> Html {
+    let (i18n, _) = use_translation();
+    let expression = use_state(|| "*/5 * * * *".to_string());
+    let timezone = use_state(|| TimezoneOption::Local);
+    let timezones = use_state(Vec::<TimezoneInfo>::new);
+    let result = use_state(|| Option::<CronParseResult>::None);
+
+    // Load the IANA timezone list once
+    {
+        let timezones = timezones.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let result = invoke("list_timezones_cmd", JsValue::NULL).await;
+                if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<TimezoneInfo>>(result) {
+                    timezones.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    // Re-parse on every edit
+    {
+        let result = result.clone();
+        use_effect_with(
+            ((*expression).clone(), (*timezone).clone()),
+            move |(expression, timezone)| {
+                let expression = expression.clone();
+                let timezone = timezone.clone();
+                if expression.trim().is_empty() {
+                    result.set(None);
+                } else {
+                    spawn_local(async move {
+                        let args = serde_wasm_bindgen::to_value(&ParseCronArgs {
+                            expression,
+                            timezone,
+                        })
+                        .unwrap();
+                        let response = invoke("parse_cron_cmd", args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<CronParseResult>(response)
+                        {
+                            result.set(Some(res));
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let on_expression_change = {
+        let expression = expression.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            expression.set(target.value());
+        })
+    };
+
+    let on_timezone_change = {
+        let timezone = timezone.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            match select.value().as_str() {
+                "local" => timezone.set(TimezoneOption::Local),
+                "utc" => timezone.set(TimezoneOption::Utc),
+                name => timezone.set(TimezoneOption::Named(name.to_string())),
+            }
+        })
+    };
+
+    let japanese = i18n.get_current_language() == "ja";
+    let invalid_field = (*result).as_ref().and_then(|res| res.invalid_field.clone());
+
+    // Label each token of the expression with its field so the broken one can be highlighted
+    let tokens: Vec<String> = expression.split_whitespace().map(str::to_string).collect();
+    let token_keys: &[&str] = match tokens.len() {
+        5 => &FIELD_KEYS[1..],
+        6 => &FIELD_KEYS,
+        _ => &[],
+    };
+
+    html! {
+        <div class="cron-parser">
+            <div class="section cron-input-section">
+                <div class="cron-input-row">
+                    <input
+                        type="text"
+                        class="form-input cron-expression-input"
+                        value={(*expression).clone()}
+                        oninput={on_expression_change}
+                        placeholder={i18n.t("cron_parser.input_placeholder")}
+                        spellcheck="false"
+                    />
+                    <select class="form-select" onchange={on_timezone_change}>
+                        <option value="local" selected={*timezone == TimezoneOption::Local}>
+                            {i18n.t("unix_time_converter.timezone_local")}
+                        </option>
+                        <option value="utc" selected={*timezone == TimezoneOption::Utc}>
+                            {i18n.t("unix_time_converter.timezone_utc")}
+                        </option>
+                        if !(*timezones).is_empty() {
+                            <optgroup label={i18n.t("unix_time_converter.timezone_iana")}>
+                                { for (*timezones).iter().map(|tz| {
+                                    let selected = matches!(&*timezone, TimezoneOption::Named(name) if *name == tz.name);
+                                    html! {
+                                        <option value={tz.name.clone()} {selected}>
+                                            {format!("{} (UTC{})", tz.name, tz.utc_offset)}
+                                        </option>
+                                    }
+                                }) }
+                            </optgroup>
+                        }
+                    </select>
+                </div>
+
+                if !token_keys.is_empty() {
+                    <div class="cron-tokens">
+                        { for tokens.iter().zip(token_keys.iter()).map(|(token, key)| html! {
+                            <div class={classes!("cron-token", (invalid_field.as_deref() == Some(*key)).then_some("invalid"))}>
+                                <code>{token}</code>
+                                <span>{i18n.t(&field_translation_key(key))}</span>
+                            </div>
+                        }) }
+                    </div>
+                }
+
+                <div class="cron-examples">
+                    <span class="cron-examples-label">{i18n.t("cron_parser.examples")}</span>
+                    { for EXAMPLES.iter().map(|example| {
+                        let expression = expression.clone();
+                        html! {
+                            <button
+                                class="secondary-btn cron-example"
+                                onclick={Callback::from(move |_| expression.set(example.to_string()))}
+                            >
+                                <code>{*example}</code>
+                            </button>
+                        }
+                    }) }
+                </div>
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                if res.success {
+                    <div class="section cron-summary-section">
+                        <div class="cron-summary">
+                            {if japanese { res.description_ja.clone() } else { res.description_en.clone() }}
+                        </div>
+                        if res.normalized != expression.trim() {
+                            <div class="cron-normalized">
+                                {format!("{}: ", i18n.t("cron_parser.normalized"))}<code>{&res.normalized}</code>
+                            </div>
+                        }
+                        <table class="cron-field-table">
+                            <tbody>
+                                { for res.fields.iter().map(|field| html! {
+                                    <tr>
+                                        <th>{i18n.t(&field_translation_key(&field.field))}</th>
+                                        <td><code>{&field.value}</code></td>
+                                        <td>{if japanese { field.description_ja.clone() } else { field.description_en.clone() }}</td>
+                                    </tr>
+                                }) }
+                            </tbody>
+                        </table>
+                    </div>
+
+                    <div class="section cron-runs-section">
+                        <h3>{i18n.t("cron_parser.next_runs")}</h3>
+                        { for res.warnings.iter().map(|w| html! {
+                            <div class="warning-message">{"⚠ "}{w}</div>
+                        }) }
+                        <ol class="cron-runs">
+                            { for res.next_runs.iter().map(|run| html! {
+                                <li key={run.unix_seconds}>
+                                    <code>{&run.datetime}</code>
+                                    <span class="cron-run-day">{&run.day_of_week}</span>
+                                </li>
+                            }) }
+                        </ol>
+                    </div>
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {"⚠ "}{res.error.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+                }
+            }
+        </div>
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
@@ -8,6 +9,9 @@ extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
 
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
     async fn open(options: JsValue) -> JsValue;
 
@@ -23,15 +27,70 @@ pub struct CsvData {
     pub total_columns: usize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct CsvInfo {
     pub file_name: String,
     pub file_size: u64,
     pub row_count: usize,
     pub column_count: usize,
     pub headers: Vec<String>,
+    #[serde(default)]
+    pub delimiter: String,
+    #[serde(default)]
+    pub encoding: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CsvChunk {
+    rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct NumericStats {
+    min: f64,
+    max: f64,
+    mean: f64,
+    median: f64,
+    std_dev: f64,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ValueCount {
+    value: String,
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ColumnSummary {
+    name: String,
+    inferred_type: String,
+    null_count: usize,
+    unique_count: usize,
+    unique_count_capped: bool,
+    numeric: Option<NumericStats>,
+    top_values: Vec<ValueCount>,
+    max_length: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CsvColumnStats {
+    total_rows: usize,
+    analyzed_rows: usize,
+    sampled: bool,
+    columns: Vec<ColumnSummary>,
+}
+
+/// Files above this size open as a read-only, chunked preview
+const STREAMING_THRESHOLD_BYTES: u64 = 10 * 1024 * 1024;
+/// Rows fetched per `read_csv_chunk_cmd` call
+const CHUNK_SIZE: usize = 500;
+/// Must match `.csv-stream-table td` in styles.css
+const ROW_HEIGHT: f64 = 33.0;
+/// Rows rendered above and below the visible area
+const OVERSCAN_ROWS: usize = 20;
+/// Chunks kept around the visible area; farther ones are dropped
+const MAX_CACHED_CHUNKS: usize = 8;
+
 #[derive(Serialize)]
 struct OpenDialogOptions {
     multiple: bool,
@@ -56,6 +115,13 @@ struct ReadCsvArgs {
     path: String,
 }
 
+#[derive(Serialize)]
+struct ReadCsvChunkArgs {
+    path: String,
+    offset: usize,
+    limit: usize,
+}
+
 #[derive(Serialize)]
 struct SaveCsvArgs {
     path: String,
@@ -63,6 +129,34 @@ struct SaveCsvArgs {
     rows: Vec<Vec<String>>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct CellEdit {
+    row: usize,
+    col: usize,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct UpdateCellsArgs {
+    path: String,
+    edits: Vec<CellEdit>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvToJsonOptions {
+    infer_types: bool,
+    pretty: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportJsonArgs {
+    path: String,
+    output_path: String,
+    options: CsvToJsonOptions,
+}
+
 #[derive(Clone, PartialEq)]
 enum SortOrder {
     None,
@@ -70,6 +164,215 @@ enum SortOrder {
     Desc,
 }
 
+fn delimiter_label(delimiter: &str) -> &str {
+    match delimiter {
+        "," => "Comma",
+        "\t" => "Tab",
+        ";" => "Semicolon",
+        other => other,
+    }
+}
+
+/// Up to four significant decimals, without trailing zeros
+fn format_stat(value: f64) -> String {
+    let formatted = format!("{:.4}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn encoding_label(encoding: &str) -> &str {
+    match encoding {
+        "ShiftJis" => "Shift_JIS",
+        "Utf8" => "UTF-8",
+        other => other,
+    }
+}
+
+/// State touched when a file is opened
+#[derive(Clone)]
+struct CsvLoader {
+    file_path: UseStateHandle<String>,
+    csv_data: UseStateHandle<Option<CsvData>>,
+    csv_info: UseStateHandle<Option<CsvInfo>>,
+    edited_rows: UseStateHandle<Vec<Vec<String>>>,
+    is_modified: UseStateHandle<bool>,
+    /// Cell edits not yet written back to `file_path`
+    pending_edits: UseStateHandle<Vec<CellEdit>>,
+    /// Rows were added or removed, so the edits can no longer be applied in place
+    rows_changed: UseStateHandle<bool>,
+    save_error: UseStateHandle<Option<String>>,
+    column_filters: UseStateHandle<Vec<String>>,
+    sort_column: UseStateHandle<Option<usize>>,
+    sort_order: UseStateHandle<SortOrder>,
+    is_loading: UseStateHandle<bool>,
+    column_stats: UseStateHandle<Option<CsvColumnStats>>,
+}
+
+impl CsvLoader {
+    /// Small files load fully and stay editable; large ones only keep their info
+    /// and are browsed chunk by chunk.
+    async fn load(self, path: String) {
+        self.file_path.set(path.clone());
+        self.is_loading.set(true);
+        self.csv_data.set(None);
+        self.column_stats.set(None);
+        self.is_modified.set(false);
+        self.pending_edits.set(Vec::new());
+        self.rows_changed.set(false);
+        self.save_error.set(None);
+        self.sort_column.set(None);
+        self.sort_order.set(SortOrder::None);
+
+        let args = serde_wasm_bindgen::to_value(&ReadCsvArgs { path: path.clone() }).unwrap();
+        let info_result = invoke("get_csv_info_cmd", args).await;
+        let info = serde_wasm_bindgen::from_value::<CsvInfo>(info_result).ok();
+        let streaming = info
+            .as_ref()
+            .is_some_and(|info| info.file_size > STREAMING_THRESHOLD_BYTES);
+        self.csv_info.set(info);
+
+        if !streaming {
+            let args = serde_wasm_bindgen::to_value(&ReadCsvArgs { path }).unwrap();
+            let data_result = invoke("read_csv_cmd", args).await;
+
+            if let Ok(data) = serde_wasm_bindgen::from_value::<CsvData>(data_result) {
+                self.column_filters
+                    .set(vec![String::new(); data.headers.len()]);
+                self.edited_rows.set(data.rows.clone());
+                self.csv_data.set(Some(data));
+            }
+        }
+
+        self.is_loading.set(false);
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CsvStreamTableProps {
+    path: String,
+    info: CsvInfo,
+}
+
+/// Read-only table that only renders the rows in view and fetches them on demand
+#[function_component(CsvStreamTable)]
+fn csv_stream_table(props: &CsvStreamTableProps) -> Html {
+    let chunks = use_mut_ref(HashMap::<usize, Vec<Vec<String>>>::new);
+    let pending = use_mut_ref(HashSet::<usize>::new);
+    let force_update = use_force_update();
+    let scroll_top = use_state(|| 0.0f64);
+
+    let total = props.info.row_count;
+    let first = ((*scroll_top / ROW_HEIGHT) as usize)
+        .saturating_sub(OVERSCAN_ROWS)
+        .min(total);
+    let last = (first + 2 * OVERSCAN_ROWS + 30).min(total);
+    let needed = (first / CHUNK_SIZE, last.saturating_sub(1) / CHUNK_SIZE);
+
+    {
+        let path = props.path.clone();
+        let chunks = chunks.clone();
+        let pending = pending.clone();
+        let force_update = force_update.clone();
+        use_effect_with((needed, total), move |&((from, to), total)| {
+            if total > 0 {
+                for chunk in from..=to {
+                    if chunks.borrow().contains_key(&chunk) || !pending.borrow_mut().insert(chunk) {
+                        continue;
+                    }
+                    let path = path.clone();
+                    let chunks = chunks.clone();
+                    let pending = pending.clone();
+                    let force_update = force_update.clone();
+                    spawn_local(async move {
+                        let args = serde_wasm_bindgen::to_value(&ReadCsvChunkArgs {
+                            path,
+                            offset: chunk * CHUNK_SIZE,
+                            limit: CHUNK_SIZE,
+                        })
+                        .unwrap();
+                        let result = invoke("read_csv_chunk_cmd", args).await;
+                        pending.borrow_mut().remove(&chunk);
+                        if let Ok(data) = serde_wasm_bindgen::from_value::<CsvChunk>(result) {
+                            let mut chunks = chunks.borrow_mut();
+                            chunks.insert(chunk, data.rows);
+                            if chunks.len() > MAX_CACHED_CHUNKS {
+                                chunks
+                                    .retain(|&key, _| key.abs_diff(chunk) < MAX_CACHED_CHUNKS / 2);
+                            }
+                        }
+                        force_update.force_update();
+                    });
+                }
+            }
+            || {}
+        });
+    }
+
+    let on_scroll = {
+        let scroll_top = scroll_top.clone();
+        Callback::from(move |e: Event| {
+            let element: web_sys::Element = e.target_unchecked_into();
+            scroll_top.set(element.scroll_top() as f64);
+        })
+    };
+
+    let column_count = props.info.headers.len();
+    let chunks = chunks.borrow();
+    let spacer = |rows: usize| {
+        html! { <tr class="csv-stream-spacer" style={format!("height: {}px;", rows as f64 * ROW_HEIGHT)}></tr> }
+    };
+
+    html! {
+        <div class="section csv-table-container">
+            <div class="csv-table-wrapper csv-stream-wrapper" onscroll={on_scroll}>
+                <table class="csv-table csv-stream-table">
+                    <thead>
+                        <tr>
+                            <th class="row-number-header">{"#"}</th>
+                            {for props.info.headers.iter().map(|header| html! { <th>{header}</th> })}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {spacer(first)}
+                        {for (first..last).map(|index| {
+                            let row = chunks
+                                .get(&(index / CHUNK_SIZE))
+                                .and_then(|rows| rows.get(index % CHUNK_SIZE));
+                            html! {
+                                <tr>
+                                    <td class="row-number">{index + 1}</td>
+                                    if let Some(row) = row {
+                                        {for (0..column_count).map(|col| html! {
+                                            <td>{row.get(col).cloned().unwrap_or_default()}</td>
+                                        })}
+                                    } else {
+                                        {for (0..column_count).map(|_| html! {
+                                            <td class="csv-stream-placeholder">{"…"}</td>
+                                        })}
+                                    }
+                                </tr>
+                            }
+                        })}
+                        {spacer(total - last)}
+                    </tbody>
+                </table>
+            </div>
+            <div class="table-footer">
+                <span class="row-count">
+                    {format!(
+                        "Rows {}–{} of {} · Large file, read-only preview",
+                        (first + 1).min(total),
+                        last,
+                        total
+                    )}
+                </span>
+            </div>
+        </div>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct CsvViewerProps {
     #[prop_or_default]
@@ -89,63 +392,45 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
     let editing_cell = use_state(|| Option::<(usize, usize)>::None);
     let edited_rows = use_state(|| Vec::<Vec<String>>::new());
     let is_modified = use_state(|| false);
+    let pending_edits = use_state(Vec::<CellEdit>::new);
+    let rows_changed = use_state(|| false);
+    let save_error = use_state(|| Option::<String>::None);
     let column_filters = use_state(|| Vec::<String>::new());
     let is_loading = use_state(|| false);
     let is_fullscreen = use_state(|| false);
+    let infer_types = use_state(|| true);
+    let column_stats = use_state(|| Option::<CsvColumnStats>::None);
+    let is_analyzing = use_state(|| false);
+
+    let loader = CsvLoader {
+        file_path: file_path.clone(),
+        csv_data: csv_data.clone(),
+        csv_info: csv_info.clone(),
+        edited_rows: edited_rows.clone(),
+        is_modified: is_modified.clone(),
+        pending_edits: pending_edits.clone(),
+        rows_changed: rows_changed.clone(),
+        save_error: save_error.clone(),
+        column_filters: column_filters.clone(),
+        sort_column: sort_column.clone(),
+        sort_order: sort_order.clone(),
+        is_loading: is_loading.clone(),
+        column_stats: column_stats.clone(),
+    };
 
     // Handle dropped file
     {
         let dropped_file = props.dropped_file.clone();
         let on_file_processed = props.on_file_processed.clone();
-        let file_path = file_path.clone();
-        let csv_data = csv_data.clone();
-        let csv_info = csv_info.clone();
-        let edited_rows = edited_rows.clone();
-        let is_modified = is_modified.clone();
-        let column_filters = column_filters.clone();
-        let sort_column = sort_column.clone();
-        let sort_order = sort_order.clone();
-        let is_loading = is_loading.clone();
+        let loader = loader.clone();
 
         use_effect_with(dropped_file.clone(), move |dropped_file| {
             if let Some(path) = dropped_file.clone() {
-                let file_path = file_path.clone();
-                let csv_data = csv_data.clone();
-                let csv_info = csv_info.clone();
-                let edited_rows = edited_rows.clone();
-                let is_modified = is_modified.clone();
-                let column_filters = column_filters.clone();
-                let sort_column = sort_column.clone();
-                let sort_order = sort_order.clone();
-                let is_loading = is_loading.clone();
+                let loader = loader.clone();
                 let on_file_processed = on_file_processed.clone();
 
                 spawn_local(async move {
-                    file_path.set(path.clone());
-                    is_loading.set(true);
-
-                    let args =
-                        serde_wasm_bindgen::to_value(&ReadCsvArgs { path: path.clone() }).unwrap();
-                    let data_result = invoke("read_csv_cmd", args).await;
-
-                    if let Ok(data) = serde_wasm_bindgen::from_value::<CsvData>(data_result) {
-                        let filters = vec![String::new(); data.headers.len()];
-                        column_filters.set(filters);
-                        edited_rows.set(data.rows.clone());
-                        csv_info.set(Some(CsvInfo {
-                            file_name: path.split('/').last().unwrap_or("unknown").to_string(),
-                            file_size: 0,
-                            row_count: data.total_rows,
-                            column_count: data.total_columns,
-                            headers: data.headers.clone(),
-                        }));
-                        csv_data.set(Some(data));
-                        is_modified.set(false);
-                        sort_column.set(None);
-                        sort_order.set(SortOrder::None);
-                    }
-
-                    is_loading.set(false);
+                    loader.load(path).await;
                     on_file_processed.emit(());
                 });
             }
@@ -154,26 +439,10 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
     }
 
     let on_select_file = {
-        let file_path = file_path.clone();
-        let csv_data = csv_data.clone();
-        let csv_info = csv_info.clone();
-        let edited_rows = edited_rows.clone();
-        let is_modified = is_modified.clone();
-        let column_filters = column_filters.clone();
-        let sort_column = sort_column.clone();
-        let sort_order = sort_order.clone();
-        let is_loading = is_loading.clone();
+        let loader = loader.clone();
 
         Callback::from(move |_| {
-            let file_path = file_path.clone();
-            let csv_data = csv_data.clone();
-            let csv_info = csv_info.clone();
-            let edited_rows = edited_rows.clone();
-            let is_modified = is_modified.clone();
-            let column_filters = column_filters.clone();
-            let sort_column = sort_column.clone();
-            let sort_order = sort_order.clone();
-            let is_loading = is_loading.clone();
+            let loader = loader.clone();
 
             spawn_local(async move {
                 let options = OpenDialogOptions {
@@ -187,30 +456,7 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
                 let result = open(options_js).await;
 
                 if let Some(path) = result.as_string() {
-                    file_path.set(path.clone());
-                    is_loading.set(true);
-
-                    let args = serde_wasm_bindgen::to_value(&ReadCsvArgs { path }).unwrap();
-                    let data_result = invoke("read_csv_cmd", args).await;
-
-                    if let Ok(data) = serde_wasm_bindgen::from_value::<CsvData>(data_result) {
-                        let filters = vec![String::new(); data.headers.len()];
-                        column_filters.set(filters);
-                        edited_rows.set(data.rows.clone());
-                        csv_info.set(Some(CsvInfo {
-                            file_name: file_path.split('/').last().unwrap_or("unknown").to_string(),
-                            file_size: 0,
-                            row_count: data.total_rows,
-                            column_count: data.total_columns,
-                            headers: data.headers.clone(),
-                        }));
-                        csv_data.set(Some(data));
-                        is_modified.set(false);
-                        sort_column.set(None);
-                        sort_order.set(SortOrder::None);
-                    }
-
-                    is_loading.set(false);
+                    loader.load(path).await;
                 }
             });
         })
@@ -257,13 +503,17 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
     let on_cell_change = {
         let edited_rows = edited_rows.clone();
         let is_modified = is_modified.clone();
+        let pending_edits = pending_edits.clone();
         let editing_cell = editing_cell.clone();
         Callback::from(move |(row, col, value): (usize, usize, String)| {
             let mut rows = (*edited_rows).clone();
-            if row < rows.len() && col < rows[row].len() {
-                rows[row][col] = value;
+            if row < rows.len() && col < rows[row].len() && rows[row][col] != value {
+                rows[row][col] = value.clone();
                 edited_rows.set(rows);
                 is_modified.set(true);
+                let mut edits = (*pending_edits).clone();
+                edits.push(CellEdit { row, col, value });
+                pending_edits.set(edits);
             }
             editing_cell.set(None);
         })
@@ -280,6 +530,7 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
         let edited_rows = edited_rows.clone();
         let csv_data = csv_data.clone();
         let is_modified = is_modified.clone();
+        let rows_changed = rows_changed.clone();
         Callback::from(move |_| {
             if let Some(data) = &*csv_data {
                 let mut rows = (*edited_rows).clone();
@@ -287,6 +538,7 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
                 rows.push(new_row);
                 edited_rows.set(rows);
                 is_modified.set(true);
+                rows_changed.set(true);
             }
         })
     };
@@ -294,16 +546,46 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
     let on_delete_row = {
         let edited_rows = edited_rows.clone();
         let is_modified = is_modified.clone();
+        let rows_changed = rows_changed.clone();
         Callback::from(move |row_idx: usize| {
             let mut rows = (*edited_rows).clone();
             if row_idx < rows.len() {
                 rows.remove(row_idx);
                 edited_rows.set(rows);
                 is_modified.set(true);
+                rows_changed.set(true);
             }
         })
     };
 
+    // Write only the edited cells back to the opened file
+    let on_save_in_place = {
+        let file_path = file_path.clone();
+        let pending_edits = pending_edits.clone();
+        let is_modified = is_modified.clone();
+        let save_error = save_error.clone();
+        Callback::from(move |_| {
+            let args = serde_wasm_bindgen::to_value(&UpdateCellsArgs {
+                path: (*file_path).clone(),
+                edits: (*pending_edits).clone(),
+            })
+            .unwrap();
+            let pending_edits = pending_edits.clone();
+            let is_modified = is_modified.clone();
+            let save_error = save_error.clone();
+            spawn_local(async move {
+                match try_invoke("update_csv_cells_cmd", args).await {
+                    Ok(_) => {
+                        pending_edits.set(Vec::new());
+                        is_modified.set(false);
+                        save_error.set(None);
+                    }
+                    Err(e) => save_error.set(e.as_string()),
+                }
+            });
+        })
+    };
+
     let on_save = {
         let file_path = file_path.clone();
         let csv_data = csv_data.clone();
@@ -348,6 +630,92 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
         })
     };
 
+    let on_infer_types_change = {
+        let infer_types = infer_types.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            infer_types.set(input.checked());
+        })
+    };
+
+    let on_export_json = {
+        let file_path = file_path.clone();
+        let infer_types = infer_types.clone();
+        let save_error = save_error.clone();
+        Callback::from(move |_| {
+            let file_path_val = (*file_path).clone();
+            let infer_types_val = *infer_types;
+            let save_error = save_error.clone();
+
+            if file_path_val.is_empty() {
+                return;
+            }
+
+            spawn_local(async move {
+                let file_stem = file_path_val
+                    .split('/')
+                    .next_back()
+                    .and_then(|name| name.rsplit_once('.').map(|(stem, _)| stem))
+                    .unwrap_or("export")
+                    .to_string();
+                let save_options = SaveDialogOptions {
+                    filters: vec![FileFilter {
+                        name: "JSON File".to_string(),
+                        extensions: vec!["json".to_string()],
+                    }],
+                    default_path: Some(format!("{}.json", file_stem)),
+                };
+                let save_options_js = serde_wasm_bindgen::to_value(&save_options).unwrap();
+                let save_result = save(save_options_js).await;
+
+                if let Some(output_path) = save_result.as_string() {
+                    let args = ExportJsonArgs {
+                        path: file_path_val,
+                        output_path,
+                        options: CsvToJsonOptions {
+                            infer_types: infer_types_val,
+                            pretty: true,
+                        },
+                    };
+                    let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                    match try_invoke("export_csv_to_json_cmd", args_js).await {
+                        Ok(_) => save_error.set(None),
+                        Err(e) => save_error.set(Some(
+                            e.as_string()
+                                .unwrap_or_else(|| "Failed to export JSON".to_string()),
+                        )),
+                    }
+                }
+            });
+        })
+    };
+
+    let on_analyze_columns = {
+        let file_path = file_path.clone();
+        let column_stats = column_stats.clone();
+        let is_analyzing = is_analyzing.clone();
+        Callback::from(move |_| {
+            let path = (*file_path).clone();
+            if path.is_empty() {
+                return;
+            }
+            let column_stats = column_stats.clone();
+            let is_analyzing = is_analyzing.clone();
+            is_analyzing.set(true);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&ReadCsvArgs { path }).unwrap();
+                let result = invoke("analyze_csv_columns_cmd", args).await;
+                column_stats.set(serde_wasm_bindgen::from_value::<CsvColumnStats>(result).ok());
+                is_analyzing.set(false);
+            });
+        })
+    };
+
+    let on_close_stats = {
+        let column_stats = column_stats.clone();
+        Callback::from(move |_| column_stats.set(None))
+    };
+
     let on_column_filter_change = {
         let column_filters = column_filters.clone();
         Callback::from(move |(col, value): (usize, String)| {
@@ -452,7 +820,16 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
             {if let Some(info) = &*csv_info {
                 html! {
                     <div class="section info-box">
-                        <h3>{"File Info"}</h3>
+                        <div class="csv-info-header">
+                            <h3>{"File Info"}</h3>
+                            <button
+                                onclick={on_analyze_columns}
+                                class="toolbar-btn"
+                                disabled={*is_analyzing}
+                            >
+                                {if *is_analyzing { "Analyzing..." } else { "Column Stats" }}
+                            </button>
+                        </div>
                         <div class="info-grid">
                             <div class="info-item">
                                 <div class="info-item-label">{"Rows"}</div>
@@ -462,6 +839,14 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
                                 <div class="info-item-label">{"Columns"}</div>
                                 <div class="info-item-value">{info.column_count}</div>
                             </div>
+                            <div class="info-item">
+                                <div class="info-item-label">{"Encoding"}</div>
+                                <div class="info-item-value">{encoding_label(&info.encoding)}</div>
+                            </div>
+                            <div class="info-item">
+                                <div class="info-item-label">{"Delimiter"}</div>
+                                <div class="info-item-value">{delimiter_label(&info.delimiter)}</div>
+                            </div>
                             <div class="info-item">
                                 <div class="info-item-label">{"Modified"}</div>
                                 <div class="info-item-value">{if *is_modified { "Yes" } else { "No" }}</div>
@@ -473,6 +858,83 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
                 html! {}
             }}
 
+            // Column Statistics
+            {if let Some(stats) = &*column_stats {
+                html! {
+                    <div class="section csv-stats">
+                        <div class="csv-info-header">
+                            <h3>{"Column Stats"}</h3>
+                            <button onclick={on_close_stats} class="toolbar-btn">{"Close"}</button>
+                        </div>
+                        {if stats.sampled {
+                            html! {
+                                <p class="csv-stats-note">
+                                    {format!("Sampled {} of {} rows", stats.analyzed_rows, stats.total_rows)}
+                                </p>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                        <div class="csv-stats-scroll">
+                            <table class="csv-stats-table">
+                                <thead>
+                                    <tr>
+                                        <th>{"Column"}</th>
+                                        <th>{"Type"}</th>
+                                        <th>{"Nulls"}</th>
+                                        <th>{"Unique"}</th>
+                                        <th>{"Min"}</th>
+                                        <th>{"Max"}</th>
+                                        <th>{"Mean"}</th>
+                                        <th>{"Median"}</th>
+                                        <th>{"Std Dev"}</th>
+                                        <th>{"Top Values"}</th>
+                                        <th>{"Max Length"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {for stats.columns.iter().map(|column| {
+                                        let numeric = |f: fn(&NumericStats) -> f64| {
+                                            column.numeric.as_ref().map(|n| format_stat(f(n))).unwrap_or_default()
+                                        };
+                                        html! {
+                                            <tr>
+                                                <td class="csv-stats-name">{&column.name}</td>
+                                                <td>
+                                                    <span class="csv-stats-type">{&column.inferred_type}</span>
+                                                </td>
+                                                <td>{column.null_count}</td>
+                                                <td>
+                                                    {column.unique_count}
+                                                    {if column.unique_count_capped { "+" } else { "" }}
+                                                </td>
+                                                <td>{numeric(|n| n.min)}</td>
+                                                <td>{numeric(|n| n.max)}</td>
+                                                <td>{numeric(|n| n.mean)}</td>
+                                                <td>{numeric(|n| n.median)}</td>
+                                                <td>{numeric(|n| n.std_dev)}</td>
+                                                <td>
+                                                    <div class="csv-stats-top">
+                                                        {for column.top_values.iter().map(|top| html! {
+                                                            <span class="csv-stats-value" title={top.value.clone()}>
+                                                                {format!("{} ({})", top.value, top.count)}
+                                                            </span>
+                                                        })}
+                                                    </div>
+                                                </td>
+                                                <td>{column.max_length.map(|len| len.to_string()).unwrap_or_default()}</td>
+                                            </tr>
+                                        }
+                                    })}
+                                </tbody>
+                            </table>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
             // Search & Actions
             {if csv_data.is_some() {
                 html! {
@@ -491,18 +953,48 @@ pub fn csv_viewer(props: &CsvViewerProps) -> Html {
                                 {"+ Add Row"}
                             </button>
                             <button
-                                onclick={on_save}
-                                class={if *is_modified { "toolbar-btn save-btn modified" } else { "toolbar-btn save-btn" }}
+                                onclick={on_save_in_place}
+                                class={if pending_edits.is_empty() { "toolbar-btn save-btn" } else { "toolbar-btn save-btn modified" }}
+                                disabled={pending_edits.is_empty() || *rows_changed}
+                                title={if *rows_changed { "Rows were added or deleted; use Save As" } else { "" }}
                             >
                                 {"Save"}
                             </button>
+                            <button
+                                onclick={on_save}
+                                class={if *is_modified && *rows_changed { "toolbar-btn save-btn modified" } else { "toolbar-btn save-btn" }}
+                            >
+                                {"Save As..."}
+                            </button>
+                            <label class="toolbar-checkbox">
+                                <input
+                                    type="checkbox"
+                                    checked={*infer_types}
+                                    onchange={on_infer_types_change}
+                                />
+                                {"Infer types"}
+                            </label>
+                            <button onclick={on_export_json} class="toolbar-btn">
+                                {"Export JSON"}
+                            </button>
                         </div>
+                        if let Some(error) = (*save_error).clone() {
+                            <div class="csv-save-error">{"⚠ "}{error}</div>
+                        }
                     </div>
                 }
             } else {
                 html! {}
             }}
 
+            // Large files: chunked, read-only table
+            {match (&*csv_info, &*csv_data) {
+                (Some(info), None) if !*is_loading && info.file_size > STREAMING_THRESHOLD_BYTES => html! {
+                    <CsvStreamTable key={(*file_path).clone()} path={(*file_path).clone()} info={info.clone()} />
+                },
+                _ => html! {},
+            }}
+
             // CSV Table
             {if let Some(data) = &*csv_data {
                 let table_container_class = if *is_fullscreen {
@@ -0,0 +1,356 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    directory: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AnalyzeDirectoryArgs {
+    path: String,
+    max_depth: u32,
+    top_files: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirNode {
+    name: String,
+    path: String,
+    size: u64,
+    file_count: u64,
+    dir_count: u64,
+    children: Vec<DirNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct LargeFile {
+    path: String,
+    size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirSizeResult {
+    success: bool,
+    root: Option<DirNode>,
+    largest_files: Vec<LargeFile>,
+    error_count: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ProgressEvent {
+    payload: Progress,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Progress {
+    scanned_files: u64,
+    scanned_bytes: u64,
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_073_741_824 {
+        format!("{:.2} GB", bytes as f64 / 1_073_741_824.0)
+    } else if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn percent_of(size: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        size as f64 * 100.0 / total as f64
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct DirTreeRowProps {
+    node: DirNode,
+    parent_size: u64,
+    depth: usize,
+}
+
+#[function_component(DirTreeRow)]
+fn dir_tree_row(props: &DirTreeRowProps) -> Html {
+    let expanded = use_state(|| props.depth == 0);
+    let node = &props.node;
+    let percent = percent_of(node.size, props.parent_size);
+    let has_children = !node.children.is_empty();
+
+    let on_toggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_| expanded.set(!*expanded))
+    };
+
+    html! {
+        <>
+            <div
+                class={classes!("disk-row", has_children.then_some("expandable"))}
+                style={format!("padding-left: calc({} * var(--space-4))", props.depth)}
+                title={node.path.clone()}
+                onclick={on_toggle}
+            >
+                <span class="disk-toggle">
+                    if has_children {
+                        {if *expanded { "▾" } else { "▸" }}
+                    }
+                </span>
+                <span class="disk-name">{node.name.clone()}</span>
+                <span class="disk-bar">
+                    <span class="disk-bar-fill" style={format!("width: {:.1}%", percent)}></span>
+                </span>
+                <span class="disk-percent">{format!("{:.1}%", percent)}</span>
+                <span class="disk-size">{format_size(node.size)}</span>
+                <span class="disk-count">{node.file_count.to_string()}</span>
+            </div>
+            if *expanded {
+                { for node.children.iter().map(|child| html! {
+                    <DirTreeRow
+                        key={child.path.clone()}
+                        node={child.clone()}
+                        parent_size={node.size}
+                        depth={props.depth + 1}
+                    />
+                }) }
+            }
+        </>
+    }
+}
+
+#[function_component(DiskAnalyzer)]
+pub fn disk_analyzer() -> Html {
+    let (i18n, _) = use_translation();
+    let path = use_state(String::new);
+    let max_depth = use_state(|| 3u32);
+    let top_files = use_state(|| 20usize);
+    let result = use_state(|| Option::<DirSizeResult>::None);
+    let progress = use_state(|| Option::<Progress>::None);
+    let is_scanning = use_state(|| false);
+
+    {
+        let progress = progress.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<ProgressEvent>(event) {
+                        progress.set(Some(event.payload));
+                    }
+                });
+                let _ = listen("disk-scan-progress", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    let on_select_folder = {
+        let path = path.clone();
+        Callback::from(move |_| {
+            let path = path.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    directory: true,
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(selected) = open(opts).await.as_string() {
+                    path.set(selected);
+                }
+            });
+        })
+    };
+
+    let on_depth_change = {
+        let max_depth = max_depth.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(depth) = select.value().parse::<u32>() {
+                max_depth.set(depth);
+            }
+        })
+    };
+
+    let on_top_files_change = {
+        let top_files = top_files.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(count) = select.value().parse::<usize>() {
+                top_files.set(count);
+            }
+        })
+    };
+
+    let on_analyze = {
+        let path = path.clone();
+        let max_depth = max_depth.clone();
+        let top_files = top_files.clone();
+        let result = result.clone();
+        let progress = progress.clone();
+        let is_scanning = is_scanning.clone();
+        Callback::from(move |_| {
+            if path.is_empty() {
+                return;
+            }
+            let args = AnalyzeDirectoryArgs {
+                path: (*path).clone(),
+                max_depth: *max_depth,
+                top_files: *top_files,
+            };
+            let result = result.clone();
+            let progress = progress.clone();
+            let is_scanning = is_scanning.clone();
+            is_scanning.set(true);
+            progress.set(None);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let res = invoke("analyze_directory_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<DirSizeResult>(res) {
+                    result.set(Some(res));
+                }
+                progress.set(None);
+                is_scanning.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="disk-analyzer">
+            <div class="section">
+                <div class="disk-controls">
+                    <button class="secondary-btn" onclick={on_select_folder}>
+                        {i18n.t("disk_analyzer.select_folder")}
+                    </button>
+                    <span class="disk-path">
+                        if path.is_empty() {
+                            {i18n.t("disk_analyzer.no_folder")}
+                        } else {
+                            {(*path).clone()}
+                        }
+                    </span>
+                </div>
+                <div class="disk-controls">
+                    <label>
+                        {i18n.t("disk_analyzer.depth")}
+                        <select onchange={on_depth_change}>
+                            { for (1u32..=6).map(|depth| html! {
+                                <option value={depth.to_string()} selected={*max_depth == depth}>{depth.to_string()}</option>
+                            }) }
+                        </select>
+                    </label>
+                    <label>
+                        {i18n.t("disk_analyzer.top_files")}
+                        <select onchange={on_top_files_change}>
+                            { for [10usize, 20, 50, 100].iter().map(|count| html! {
+                                <option value={count.to_string()} selected={*top_files == *count}>{count.to_string()}</option>
+                            }) }
+                        </select>
+                    </label>
+                    <button class="primary-btn" onclick={on_analyze} disabled={path.is_empty() || *is_scanning}>
+                        if *is_scanning {
+                            <span class="processing">
+                                <span class="spinner"></span>
+                                {i18n.t("disk_analyzer.scanning")}
+                            </span>
+                        } else {
+                            {i18n.t("disk_analyzer.analyze")}
+                        }
+                    </button>
+                </div>
+                if let Some(p) = (*progress).clone() {
+                    <p class="disk-progress">
+                        {format!(
+                            "{} {} / {}",
+                            p.scanned_files,
+                            i18n.t("disk_analyzer.files"),
+                            format_size(p.scanned_bytes)
+                        )}
+                    </p>
+                }
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                if res.success {
+                    if let Some(root) = res.root.clone() {
+                        <div class="section">
+                            <div class="section-header">
+                                <h3>{format!("{} ({})", root.name, format_size(root.size))}</h3>
+                                <span class="disk-summary">
+                                    {format!(
+                                        "{} {} / {} {}",
+                                        root.file_count,
+                                        i18n.t("disk_analyzer.files"),
+                                        root.dir_count,
+                                        i18n.t("disk_analyzer.folders")
+                                    )}
+                                    if res.error_count > 0 {
+                                        <span class="disk-errors">
+                                            {format!(" / {} {}", res.error_count, i18n.t("disk_analyzer.errors"))}
+                                        </span>
+                                    }
+                                </span>
+                            </div>
+                            <div class="disk-tree">
+                                <div class="disk-row disk-row-header">
+                                    <span class="disk-toggle"></span>
+                                    <span class="disk-name">{i18n.t("disk_analyzer.name")}</span>
+                                    <span class="disk-bar"></span>
+                                    <span class="disk-percent">{"%"}</span>
+                                    <span class="disk-size">{i18n.t("disk_analyzer.size")}</span>
+                                    <span class="disk-count">{i18n.t("disk_analyzer.files")}</span>
+                                </div>
+                                <DirTreeRow node={root.clone()} parent_size={root.size} depth={0} />
+                            </div>
+                        </div>
+
+                        if !res.largest_files.is_empty() {
+                            <div class="section">
+                                <h3>{i18n.t("disk_analyzer.largest_files")}</h3>
+                                <table class="disk-files">
+                                    <tbody>
+                                        { for res.largest_files.iter().map(|file| html! {
+                                            <tr>
+                                                <td class="disk-file-path">{file.path.clone()}</td>
+                                                <td class="disk-size">{format_size(file.size)}</td>
+                                                <td class="disk-percent">{format!("{:.1}%", percent_of(file.size, root.size))}</td>
+                                            </tr>
+                                        }) }
+                                    </tbody>
+                                </table>
+                            </div>
+                        }
+                    }
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">{format!("⚠ {}", res.error.clone().unwrap_or_default())}</div>
+                    </div>
+                }
+            }
+        </div>
+    }
+}
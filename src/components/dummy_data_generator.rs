@@ -0,0 +1,716 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+const MAX_RECORDS: u32 = 100_000;
+/// Largest integer JavaScript numbers represent exactly
+const MAX_SEED: u64 = (1 << 53) - 1;
+
+#[derive(Clone, Copy, PartialEq)]
+enum GeneratorMode {
+    Lorem,
+    Records,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum DummyFieldKind {
+    NameJa,
+    NameEn,
+    Email,
+    Phone,
+    Address,
+    Date,
+    Integer,
+    Uuid,
+    Boolean,
+}
+
+impl DummyFieldKind {
+    const ALL: [DummyFieldKind; 9] = [
+        DummyFieldKind::NameJa,
+        DummyFieldKind::NameEn,
+        DummyFieldKind::Email,
+        DummyFieldKind::Phone,
+        DummyFieldKind::Address,
+        DummyFieldKind::Date,
+        DummyFieldKind::Integer,
+        DummyFieldKind::Uuid,
+        DummyFieldKind::Boolean,
+    ];
+
+    fn value(&self) -> &'static str {
+        match self {
+            DummyFieldKind::NameJa => "name_ja",
+            DummyFieldKind::NameEn => "name_en",
+            DummyFieldKind::Email => "email",
+            DummyFieldKind::Phone => "phone",
+            DummyFieldKind::Address => "address",
+            DummyFieldKind::Date => "date",
+            DummyFieldKind::Integer => "integer",
+            DummyFieldKind::Uuid => "uuid",
+            DummyFieldKind::Boolean => "boolean",
+        }
+    }
+
+    fn translation_key(&self) -> &'static str {
+        match self {
+            DummyFieldKind::NameJa => "dummy_data.kind_name_ja",
+            DummyFieldKind::NameEn => "dummy_data.kind_name_en",
+            DummyFieldKind::Email => "dummy_data.kind_email",
+            DummyFieldKind::Phone => "dummy_data.kind_phone",
+            DummyFieldKind::Address => "dummy_data.kind_address",
+            DummyFieldKind::Date => "dummy_data.kind_date",
+            DummyFieldKind::Integer => "dummy_data.kind_integer",
+            DummyFieldKind::Uuid => "dummy_data.kind_uuid",
+            DummyFieldKind::Boolean => "dummy_data.kind_boolean",
+        }
+    }
+
+    fn from_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.value() == value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum DummyOutputFormat {
+    Json,
+    Csv,
+    Sql,
+}
+
+impl DummyOutputFormat {
+    const ALL: [DummyOutputFormat; 3] = [
+        DummyOutputFormat::Json,
+        DummyOutputFormat::Csv,
+        DummyOutputFormat::Sql,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            DummyOutputFormat::Json => "JSON",
+            DummyOutputFormat::Csv => "CSV",
+            DummyOutputFormat::Sql => "SQL INSERT",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.label() == label)
+    }
+}
+
+/// Editable field row; range inputs are kept as text until generation
+#[derive(Debug, Clone, PartialEq)]
+struct FieldRow {
+    name: String,
+    kind: DummyFieldKind,
+    min: String,
+    max: String,
+    date_from: String,
+    date_to: String,
+}
+
+impl FieldRow {
+    fn new(name: &str, kind: DummyFieldKind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            min: "0".to_string(),
+            max: "100".to_string(),
+            date_from: "2000-01-01".to_string(),
+            date_to: "2025-12-31".to_string(),
+        }
+    }
+
+    fn to_spec(&self) -> DummyFieldSpec {
+        let non_empty = |s: &str| (!s.trim().is_empty()).then(|| s.trim().to_string());
+        DummyFieldSpec {
+            name: self.name.trim().to_string(),
+            kind: self.kind,
+            min: self.min.trim().parse().ok(),
+            max: self.max.trim().parse().ok(),
+            date_from: non_empty(&self.date_from),
+            date_to: non_empty(&self.date_to),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DummyFieldSpec {
+    name: String,
+    kind: DummyFieldKind,
+    min: Option<i64>,
+    max: Option<i64>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateLoremArgs {
+    paragraphs: u32,
+    words_per_paragraph: u32,
+    japanese: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateRecordsArgs {
+    count: u32,
+    fields: Vec<DummyFieldSpec>,
+    format: DummyOutputFormat,
+    seed: Option<u64>,
+    table_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoremResult {
+    success: bool,
+    text: String,
+    word_count: usize,
+    char_count: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DummyDataResult {
+    success: bool,
+    output: String,
+    record_count: usize,
+    seed: Option<u64>,
+    error: Option<String>,
+}
+
+fn default_fields() -> Vec<FieldRow> {
+    vec![
+        FieldRow::new("id", DummyFieldKind::Uuid),
+        FieldRow::new("name", DummyFieldKind::NameJa),
+        FieldRow::new("email", DummyFieldKind::Email),
+        FieldRow::new("created_at", DummyFieldKind::Date),
+    ]
+}
+
+#[function_component(DummyDataGenerator)]
+pub fn dummy_data_generator() -> Html {
+    let (i18n, _) = use_translation();
+    let mode = use_state(|| GeneratorMode::Lorem);
+    let paragraphs = use_state(|| 3u32);
+    let words_per_paragraph = use_state(|| 50u32);
+    let japanese = use_state(|| false);
+    let count = use_state(|| 10u32);
+    let fields = use_state(default_fields);
+    let format = use_state(|| DummyOutputFormat::Json);
+    let table_name = use_state(|| "dummy_data".to_string());
+    let seed = use_state(String::new);
+    let used_seed = use_state(|| Option::<u64>::None);
+    let output = use_state(String::new);
+    let summary = use_state(String::new);
+    let error = use_state(|| Option::<String>::None);
+    let is_processing = use_state(|| false);
+    let copy_feedback = use_state(|| false);
+
+    let on_mode_change = {
+        let mode = mode.clone();
+        let output = output.clone();
+        let summary = summary.clone();
+        let error = error.clone();
+        Callback::from(move |new_mode: GeneratorMode| {
+            mode.set(new_mode);
+            output.set(String::new());
+            summary.set(String::new());
+            error.set(None);
+        })
+    };
+
+    let number_input = |state: &UseStateHandle<u32>, max: u32| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = target.value().parse::<u32>() {
+                state.set(value.clamp(1, max));
+            }
+        })
+    };
+    let on_paragraphs_change = number_input(&paragraphs, 100);
+    let on_words_change = number_input(&words_per_paragraph, 1000);
+    let on_count_change = number_input(&count, MAX_RECORDS);
+
+    let on_format_change = {
+        let format = format.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(f) = DummyOutputFormat::from_label(&select.value()) {
+                format.set(f);
+            }
+        })
+    };
+
+    let on_table_name_change = {
+        let table_name = table_name.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            table_name.set(target.value());
+        })
+    };
+
+    let on_seed_change = {
+        let seed = seed.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            seed.set(target.value());
+        })
+    };
+
+    let update_field = {
+        let fields = fields.clone();
+        Callback::from(move |(index, row): (usize, FieldRow)| {
+            let mut updated = (*fields).clone();
+            if let Some(slot) = updated.get_mut(index) {
+                *slot = row;
+            }
+            fields.set(updated);
+        })
+    };
+
+    let on_add_field = {
+        let fields = fields.clone();
+        Callback::from(move |_| {
+            let mut updated = (*fields).clone();
+            let name = format!("field_{}", updated.len() + 1);
+            updated.push(FieldRow::new(&name, DummyFieldKind::Integer));
+            fields.set(updated);
+        })
+    };
+
+    let on_remove_field = {
+        let fields = fields.clone();
+        Callback::from(move |index: usize| {
+            let mut updated = (*fields).clone();
+            if index < updated.len() {
+                updated.remove(index);
+            }
+            fields.set(updated);
+        })
+    };
+
+    let on_generate = {
+        let mode = mode.clone();
+        let paragraphs = paragraphs.clone();
+        let words_per_paragraph = words_per_paragraph.clone();
+        let japanese = japanese.clone();
+        let count = count.clone();
+        let fields = fields.clone();
+        let format = format.clone();
+        let table_name = table_name.clone();
+        let seed = seed.clone();
+        let used_seed = used_seed.clone();
+        let output = output.clone();
+        let summary = summary.clone();
+        let error = error.clone();
+        let is_processing = is_processing.clone();
+        let i18n = i18n.clone();
+
+        Callback::from(move |_| {
+            let output = output.clone();
+            let summary = summary.clone();
+            let error = error.clone();
+            let is_processing = is_processing.clone();
+
+            if *mode == GeneratorMode::Lorem {
+                let args = GenerateLoremArgs {
+                    paragraphs: *paragraphs,
+                    words_per_paragraph: *words_per_paragraph,
+                    japanese: *japanese,
+                };
+                let summary_template = i18n.t("dummy_data.lorem_summary");
+                is_processing.set(true);
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                    let result = invoke("generate_lorem_cmd", args).await;
+                    match serde_wasm_bindgen::from_value::<LoremResult>(result) {
+                        Ok(res) if res.success => {
+                            summary.set(
+                                summary_template
+                                    .replace("{words}", &res.word_count.to_string())
+                                    .replace("{chars}", &res.char_count.to_string()),
+                            );
+                            output.set(res.text);
+                            error.set(None);
+                        }
+                        Ok(res) => error.set(res.error),
+                        Err(e) => error.set(Some(format!("Failed to parse result: {:?}", e))),
+                    }
+                    is_processing.set(false);
+                });
+                return;
+            }
+
+            let seed_value = if seed.trim().is_empty() {
+                None
+            } else {
+                match seed.trim().parse::<u64>() {
+                    Ok(value) if value <= MAX_SEED => Some(value),
+                    _ => {
+                        error.set(Some(
+                            i18n.t("dummy_data.invalid_seed")
+                                .replace("{max}", &MAX_SEED.to_string()),
+                        ));
+                        return;
+                    }
+                }
+            };
+            let args = GenerateRecordsArgs {
+                count: *count,
+                fields: fields.iter().map(FieldRow::to_spec).collect(),
+                format: *format,
+                seed: seed_value,
+                table_name: Some((*table_name).clone()),
+            };
+            let summary_template = i18n.t("dummy_data.records_summary");
+            let used_seed = used_seed.clone();
+            is_processing.set(true);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let result = invoke("generate_dummy_records_cmd", args).await;
+                match serde_wasm_bindgen::from_value::<DummyDataResult>(result) {
+                    Ok(res) if res.success => {
+                        summary.set(
+                            summary_template.replace("{count}", &res.record_count.to_string()),
+                        );
+                        used_seed.set(res.seed);
+                        output.set(res.output);
+                        error.set(None);
+                    }
+                    Ok(res) => {
+                        output.set(String::new());
+                        summary.set(String::new());
+                        error.set(res.error);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to parse result: {:?}", e))),
+                }
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let on_reuse_seed = {
+        let seed = seed.clone();
+        let used_seed = used_seed.clone();
+        Callback::from(move |_| {
+            if let Some(value) = *used_seed {
+                seed.set(value.to_string());
+            }
+        })
+    };
+
+    let on_copy = {
+        let output = output.clone();
+        let copy_feedback = copy_feedback.clone();
+        Callback::from(move |_| {
+            let output_val = (*output).clone();
+            let copy_feedback = copy_feedback.clone();
+
+            if !output_val.is_empty() {
+                if let Some(win) = window() {
+                    let clipboard = win.navigator().clipboard();
+                    spawn_local(async move {
+                        let _ =
+                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&output_val))
+                                .await;
+                        copy_feedback.set(true);
+
+                        let copy_feedback_reset = copy_feedback.clone();
+                        gloo_timers::callback::Timeout::new(2000, move || {
+                            copy_feedback_reset.set(false);
+                        })
+                        .forget();
+                    });
+                }
+            }
+        })
+    };
+
+    let field_row = |index: usize, row: &FieldRow| {
+        let on_name = {
+            let update_field = update_field.clone();
+            let row = row.clone();
+            Callback::from(move |e: InputEvent| {
+                let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+                update_field.emit((
+                    index,
+                    FieldRow {
+                        name: target.value(),
+                        ..row.clone()
+                    },
+                ));
+            })
+        };
+        let on_kind = {
+            let update_field = update_field.clone();
+            let row = row.clone();
+            Callback::from(move |e: Event| {
+                let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                if let Some(kind) = DummyFieldKind::from_value(&select.value()) {
+                    update_field.emit((
+                        index,
+                        FieldRow {
+                            kind,
+                            ..row.clone()
+                        },
+                    ));
+                }
+            })
+        };
+        let text_setter = |apply: fn(&mut FieldRow, String)| {
+            let update_field = update_field.clone();
+            let row = row.clone();
+            Callback::from(move |e: InputEvent| {
+                let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let mut updated = row.clone();
+                apply(&mut updated, target.value());
+                update_field.emit((index, updated));
+            })
+        };
+        let on_remove = {
+            let on_remove_field = on_remove_field.clone();
+            Callback::from(move |_| on_remove_field.emit(index))
+        };
+
+        html! {
+            <div class="dummy-field-row">
+                <input
+                    type="text"
+                    class="form-input dummy-field-name"
+                    value={row.name.clone()}
+                    oninput={on_name}
+                    placeholder={i18n.t("dummy_data.field_name")}
+                />
+                <select class="form-select" onchange={on_kind}>
+                    { for DummyFieldKind::ALL.iter().map(|k| html! {
+                        <option value={k.value()} selected={row.kind == *k}>{i18n.t(k.translation_key())}</option>
+                    }) }
+                </select>
+                if row.kind == DummyFieldKind::Integer {
+                    <input
+                        type="number"
+                        class="form-input dummy-field-range"
+                        value={row.min.clone()}
+                        oninput={text_setter(|r, v| r.min = v)}
+                        placeholder={i18n.t("dummy_data.min")}
+                    />
+                    <span class="dummy-field-range-sep">{"〜"}</span>
+                    <input
+                        type="number"
+                        class="form-input dummy-field-range"
+                        value={row.max.clone()}
+                        oninput={text_setter(|r, v| r.max = v)}
+                        placeholder={i18n.t("dummy_data.max")}
+                    />
+                }
+                if row.kind == DummyFieldKind::Date {
+                    <input
+                        type="date"
+                        class="form-input dummy-field-range"
+                        value={row.date_from.clone()}
+                        oninput={text_setter(|r, v| r.date_from = v)}
+                    />
+                    <span class="dummy-field-range-sep">{"〜"}</span>
+                    <input
+                        type="date"
+                        class="form-input dummy-field-range"
+                        value={row.date_to.clone()}
+                        oninput={text_setter(|r, v| r.date_to = v)}
+                    />
+                }
+                <button class="secondary-btn dummy-field-remove" onclick={on_remove} title={i18n.t("common.delete")}>
+                    {"✕"}
+                </button>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="dummy-data-generator">
+            <div class="section">
+                <div class="mode-toggle">
+                    <button
+                        class={classes!("mode-btn", (*mode == GeneratorMode::Lorem).then_some("active"))}
+                        onclick={{
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(GeneratorMode::Lorem))
+                        }}
+                    >
+                        {i18n.t("dummy_data.mode_lorem")}
+                    </button>
+                    <button
+                        class={classes!("mode-btn", (*mode == GeneratorMode::Records).then_some("active"))}
+                        onclick={{
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(GeneratorMode::Records))
+                        }}
+                    >
+                        {i18n.t("dummy_data.mode_records")}
+                    </button>
+                </div>
+            </div>
+
+            if *mode == GeneratorMode::Lorem {
+                <div class="section dummy-options-section">
+                    <div class="dummy-options">
+                        <div class="form-group">
+                            <label>{i18n.t("dummy_data.paragraphs")}</label>
+                            <input
+                                type="number"
+                                class="form-input"
+                                min="1"
+                                max="100"
+                                value={paragraphs.to_string()}
+                                oninput={on_paragraphs_change}
+                            />
+                        </div>
+                        <div class="form-group">
+                            <label>{i18n.t("dummy_data.words_per_paragraph")}</label>
+                            <input
+                                type="number"
+                                class="form-input"
+                                min="1"
+                                max="1000"
+                                value={words_per_paragraph.to_string()}
+                                oninput={on_words_change}
+                            />
+                        </div>
+                        <label class="checkbox-option-inline">
+                            <input
+                                type="checkbox"
+                                checked={*japanese}
+                                onchange={{
+                                    let japanese = japanese.clone();
+                                    Callback::from(move |_| japanese.set(!*japanese))
+                                }}
+                            />
+                            <span>{i18n.t("dummy_data.japanese")}</span>
+                        </label>
+                    </div>
+                </div>
+            } else {
+                <div class="section dummy-options-section">
+                    <div class="dummy-options">
+                        <div class="form-group">
+                            <label>{i18n.t("dummy_data.count")}</label>
+                            <input
+                                type="number"
+                                class="form-input"
+                                min="1"
+                                max={MAX_RECORDS.to_string()}
+                                value={count.to_string()}
+                                oninput={on_count_change}
+                            />
+                        </div>
+                        <div class="form-group">
+                            <label>{i18n.t("dummy_data.format")}</label>
+                            <select class="form-select" onchange={on_format_change}>
+                                { for DummyOutputFormat::ALL.iter().map(|f| html! {
+                                    <option value={f.label()} selected={*format == *f}>{f.label()}</option>
+                                }) }
+                            </select>
+                        </div>
+                        if *format == DummyOutputFormat::Sql {
+                            <div class="form-group">
+                                <label>{i18n.t("dummy_data.table_name")}</label>
+                                <input
+                                    type="text"
+                                    class="form-input"
+                                    value={(*table_name).clone()}
+                                    oninput={on_table_name_change}
+                                />
+                            </div>
+                        }
+                        <div class="form-group">
+                            <label>{i18n.t("dummy_data.seed")}</label>
+                            <input
+                                type="text"
+                                class="form-input"
+                                value={(*seed).clone()}
+                                oninput={on_seed_change}
+                                placeholder={i18n.t("dummy_data.seed_placeholder")}
+                            />
+                        </div>
+                    </div>
+
+                    <h3>{i18n.t("dummy_data.fields")}</h3>
+                    <div class="dummy-field-list">
+                        { for fields.iter().enumerate().map(|(i, row)| field_row(i, row)) }
+                    </div>
+                    <button class="secondary-btn" onclick={on_add_field}>
+                        {format!("+ {}", i18n.t("dummy_data.add_field"))}
+                    </button>
+                </div>
+            }
+
+            <div class="section">
+                <button
+                    class="primary-btn generate-btn"
+                    onclick={on_generate}
+                    disabled={*is_processing}
+                >
+                    if *is_processing {
+                        <span class="processing">
+                            <span class="spinner"></span>
+                            {i18n.t("common.processing")}
+                        </span>
+                    } else {
+                        {i18n.t("dummy_data.generate")}
+                    }
+                </button>
+            </div>
+
+            if let Some(err) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{"⚠ "}{err}</div>
+                </div>
+            }
+
+            if !output.is_empty() {
+                <div class="section dummy-output-section">
+                    <div class="section-header">
+                        <span class="dummy-summary">{(*summary).clone()}</span>
+                        if *mode == GeneratorMode::Records {
+                            if let Some(value) = *used_seed {
+                                <button class="secondary-btn dummy-seed" onclick={on_reuse_seed} title={i18n.t("dummy_data.reuse_seed")}>
+                                    {format!("{}: {}", i18n.t("dummy_data.seed"), value)}
+                                </button>
+                            }
+                        }
+                        <button
+                            class={classes!("secondary-btn", (*copy_feedback).then_some("copied"))}
+                            onclick={on_copy}
+                        >
+                            if *copy_feedback {
+                                {format!("✓ {}", i18n.t("common.copied"))}
+                            } else {
+                                {i18n.t("common.copy")}
+                            }
+                        </button>
+                    </div>
+                    <textarea
+                        class="dummy-output"
+                        readonly=true
+                        value={(*output).clone()}
+                        spellcheck="false"
+                    />
+                </div>
+            }
+        </div>
+    }
+}
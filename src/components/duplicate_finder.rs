@@ -0,0 +1,506 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    directory: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateFindOptions {
+    min_size: u64,
+    extensions: Vec<String>,
+    include_hidden: bool,
+    symlinks: String,
+}
+
+#[derive(Serialize)]
+struct FindDuplicatesArgs {
+    dirs: Vec<String>,
+    options: DuplicateFindOptions,
+}
+
+#[derive(Serialize)]
+struct DeleteArgs {
+    paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateFile {
+    path: String,
+    modified: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateGroup {
+    hash: String,
+    size: u64,
+    files: Vec<DuplicateFile>,
+    reclaimable_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateFindResult {
+    success: bool,
+    groups: Vec<DuplicateGroup>,
+    scanned_files: usize,
+    duplicate_files: usize,
+    reclaimable_bytes: u64,
+    skipped: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct DeleteFailure {
+    path: String,
+    error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct DeleteDuplicatesResult {
+    deleted: Vec<String>,
+    failed: Vec<DeleteFailure>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ProgressEvent {
+    payload: Progress,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Progress {
+    stage: String,
+    processed: usize,
+    total: usize,
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_073_741_824 {
+        format!("{:.2} GB", bytes as f64 / 1_073_741_824.0)
+    } else if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+/// Trim an RFC 3339 timestamp to minutes for display
+fn format_modified(modified: &Option<String>) -> String {
+    modified
+        .as_deref()
+        .map(|m| m.get(..16).unwrap_or(m).replace('T', " "))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Drop deleted files from the result, along with groups that no longer have a duplicate
+fn remove_deleted(result: &DuplicateFindResult, deleted: &[String]) -> DuplicateFindResult {
+    let mut next = result.clone();
+    for group in &mut next.groups {
+        group.files.retain(|file| !deleted.contains(&file.path));
+        group.reclaimable_bytes = group.size * (group.files.len().max(1) as u64 - 1);
+    }
+    next.groups.retain(|group| group.files.len() > 1);
+    next.duplicate_files = next.groups.iter().map(|g| g.files.len()).sum();
+    next.reclaimable_bytes = next.groups.iter().map(|g| g.reclaimable_bytes).sum();
+    next
+}
+
+#[function_component(DuplicateFinder)]
+pub fn duplicate_finder() -> Html {
+    let (i18n, _) = use_translation();
+    let dirs = use_state(Vec::<String>::new);
+    let min_size_kb = use_state(|| 1u64);
+    let extensions = use_state(String::new);
+    let include_hidden = use_state(|| false);
+    let follow_symlinks = use_state(|| false);
+    let result = use_state(|| Option::<DuplicateFindResult>::None);
+    let selected = use_state(HashSet::<String>::new);
+    let progress = use_state(|| Option::<Progress>::None);
+    let is_scanning = use_state(|| false);
+    let is_deleting = use_state(|| false);
+    let error = use_state(|| Option::<String>::None);
+
+    {
+        let progress = progress.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<ProgressEvent>(event) {
+                        progress.set(Some(event.payload));
+                    }
+                });
+                let _ = listen("duplicate-scan-progress", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    let on_add_folder = {
+        let dirs = dirs.clone();
+        Callback::from(move |_| {
+            let dirs = dirs.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: true,
+                    directory: true,
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Ok(paths) = serde_wasm_bindgen::from_value::<Vec<String>>(open(opts).await) {
+                    let mut next = (*dirs).clone();
+                    for path in paths {
+                        if !next.contains(&path) {
+                            next.push(path);
+                        }
+                    }
+                    dirs.set(next);
+                }
+            });
+        })
+    };
+
+    let on_min_size_change = {
+        let min_size_kb = min_size_kb.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(kb) = input.value().parse::<u64>() {
+                min_size_kb.set(kb);
+            }
+        })
+    };
+
+    let on_extensions_input = {
+        let extensions = extensions.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            extensions.set(input.value());
+        })
+    };
+
+    let checkbox = |state: &UseStateHandle<bool>| {
+        let state = state.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            state.set(input.checked());
+        })
+    };
+
+    let on_scan = {
+        let dirs = dirs.clone();
+        let min_size_kb = min_size_kb.clone();
+        let extensions = extensions.clone();
+        let include_hidden = include_hidden.clone();
+        let follow_symlinks = follow_symlinks.clone();
+        let result = result.clone();
+        let selected = selected.clone();
+        let progress = progress.clone();
+        let is_scanning = is_scanning.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            if dirs.is_empty() {
+                return;
+            }
+            let args = FindDuplicatesArgs {
+                dirs: (*dirs).clone(),
+                options: DuplicateFindOptions {
+                    min_size: *min_size_kb * 1024,
+                    extensions: extensions
+                        .split(',')
+                        .map(|ext| ext.trim().to_string())
+                        .filter(|ext| !ext.is_empty())
+                        .collect(),
+                    include_hidden: *include_hidden,
+                    symlinks: if *follow_symlinks { "follow" } else { "skip" }.to_string(),
+                },
+            };
+            let result = result.clone();
+            let selected = selected.clone();
+            let progress = progress.clone();
+            let is_scanning = is_scanning.clone();
+            let error = error.clone();
+            is_scanning.set(true);
+            progress.set(None);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let res = invoke("find_duplicates_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<DuplicateFindResult>(res) {
+                    if res.success {
+                        error.set(None);
+                        result.set(Some(res));
+                    } else {
+                        error.set(res.error);
+                        result.set(None);
+                    }
+                    selected.set(HashSet::new());
+                }
+                progress.set(None);
+                is_scanning.set(false);
+            });
+        })
+    };
+
+    // Select every copy except the oldest one in each group
+    let on_select_keep_oldest = {
+        let result = result.clone();
+        let selected = selected.clone();
+        Callback::from(move |_| {
+            let Some(res) = (*result).clone() else {
+                return;
+            };
+            let mut next = HashSet::new();
+            for group in res.groups {
+                let mut files = group.files;
+                files.sort_by(|a, b| a.modified.cmp(&b.modified));
+                next.extend(files.into_iter().skip(1).map(|file| file.path));
+            }
+            selected.set(next);
+        })
+    };
+
+    let on_clear_selection = {
+        let selected = selected.clone();
+        Callback::from(move |_| selected.set(HashSet::new()))
+    };
+
+    let on_delete = {
+        let result = result.clone();
+        let selected = selected.clone();
+        let is_deleting = is_deleting.clone();
+        let error = error.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            if selected.is_empty() {
+                return;
+            }
+            let paths: Vec<String> = selected.iter().cloned().collect();
+            let result = result.clone();
+            let selected = selected.clone();
+            let is_deleting = is_deleting.clone();
+            let error = error.clone();
+            let i18n = i18n.clone();
+            is_deleting.set(true);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&DeleteArgs { paths }).unwrap();
+                let res = invoke("delete_duplicate_files_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<DeleteDuplicatesResult>(res) {
+                    if let Some(current) = (*result).as_ref() {
+                        result.set(Some(remove_deleted(current, &res.deleted)));
+                    }
+                    let mut next = (*selected).clone();
+                    for path in &res.deleted {
+                        next.remove(path);
+                    }
+                    selected.set(next);
+                    // Files that could not be trashed stay selected; report the first one
+                    error.set(res.failed.first().map(|failure| {
+                        format!(
+                            "{} ({}): {} - {}",
+                            i18n.t("duplicate_finder.delete_failed"),
+                            res.failed.len(),
+                            failure.path,
+                            failure.error
+                        )
+                    }));
+                }
+                is_deleting.set(false);
+            });
+        })
+    };
+
+    let toggle_file = |path: String| {
+        let selected = selected.clone();
+        Callback::from(move |_: Event| {
+            let mut next = (*selected).clone();
+            if !next.remove(&path) {
+                next.insert(path.clone());
+            }
+            selected.set(next);
+        })
+    };
+
+    let remove_dir = |path: String| {
+        let dirs = dirs.clone();
+        Callback::from(move |_: MouseEvent| {
+            dirs.set(dirs.iter().filter(|d| **d != path).cloned().collect());
+        })
+    };
+
+    let selected_bytes: u64 = (*result)
+        .as_ref()
+        .map(|res| {
+            res.groups
+                .iter()
+                .flat_map(|g| g.files.iter().map(move |f| (g.size, f)))
+                .filter(|(_, f)| selected.contains(&f.path))
+                .map(|(size, _)| size)
+                .sum()
+        })
+        .unwrap_or_default();
+
+    html! {
+        <div class="duplicate-finder">
+            <div class="section">
+                <div class="section-header">
+                    <h3>{i18n.t("duplicate_finder.folders")}</h3>
+                    <button class="secondary-btn" onclick={on_add_folder}>
+                        {i18n.t("duplicate_finder.add_folder")}
+                    </button>
+                </div>
+                if dirs.is_empty() {
+                    <p class="duplicate-empty">{i18n.t("duplicate_finder.no_folders")}</p>
+                } else {
+                    <ul class="duplicate-folders">
+                        { for dirs.iter().map(|dir| html! {
+                            <li key={dir.clone()}>
+                                <span>{dir.clone()}</span>
+                                <button class="move-btn" onclick={remove_dir(dir.clone())}>{"✕"}</button>
+                            </li>
+                        }) }
+                    </ul>
+                }
+
+                <div class="duplicate-options">
+                    <label>
+                        {i18n.t("duplicate_finder.min_size")}
+                        <input type="number" min="0" value={min_size_kb.to_string()} onchange={on_min_size_change} />
+                    </label>
+                    <label>
+                        {i18n.t("duplicate_finder.extensions")}
+                        <input
+                            type="text"
+                            placeholder="jpg, png, pdf"
+                            value={(*extensions).clone()}
+                            oninput={on_extensions_input}
+                        />
+                    </label>
+                    <label class="duplicate-check">
+                        <input type="checkbox" checked={*include_hidden} onchange={checkbox(&include_hidden)} />
+                        {i18n.t("duplicate_finder.include_hidden")}
+                    </label>
+                    <label class="duplicate-check">
+                        <input type="checkbox" checked={*follow_symlinks} onchange={checkbox(&follow_symlinks)} />
+                        {i18n.t("duplicate_finder.follow_symlinks")}
+                    </label>
+                </div>
+
+                <button class="primary-btn" onclick={on_scan} disabled={dirs.is_empty() || *is_scanning}>
+                    if *is_scanning {
+                        <span class="processing">
+                            <span class="spinner"></span>
+                            {i18n.t("duplicate_finder.scanning")}
+                        </span>
+                    } else {
+                        {i18n.t("duplicate_finder.scan")}
+                    }
+                </button>
+                if let Some(p) = (*progress).clone() {
+                    <p class="duplicate-progress">
+                        {i18n.t(&format!("duplicate_finder.stages.{}", p.stage))}
+                        {" "}
+                        if p.total > 0 {
+                            {format!("{} / {}", p.processed, p.total)}
+                        } else {
+                            {p.processed.to_string()}
+                        }
+                    </p>
+                }
+            </div>
+
+            if let Some(msg) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{format!("⚠ {}", msg)}</div>
+                </div>
+            }
+
+            if let Some(res) = (*result).clone() {
+                <div class="section">
+                    <div class="section-header">
+                        <h3>
+                            {format!(
+                                "{} {} / {} {} / {} {}",
+                                res.groups.len(),
+                                i18n.t("duplicate_finder.groups"),
+                                res.duplicate_files,
+                                i18n.t("duplicate_finder.files"),
+                                format_size(res.reclaimable_bytes),
+                                i18n.t("duplicate_finder.reclaimable"),
+                            )}
+                        </h3>
+                        <div class="panel-actions">
+                            <button class="secondary-btn" onclick={on_select_keep_oldest} disabled={res.groups.is_empty()}>
+                                {i18n.t("duplicate_finder.select_keep_oldest")}
+                            </button>
+                            <button class="secondary-btn" onclick={on_clear_selection} disabled={selected.is_empty()}>
+                                {i18n.t("duplicate_finder.clear_selection")}
+                            </button>
+                            <button
+                                class="primary-btn duplicate-delete-btn"
+                                onclick={on_delete}
+                                disabled={selected.is_empty() || *is_deleting}
+                            >
+                                {format!(
+                                    "{} ({}, {})",
+                                    i18n.t("duplicate_finder.move_to_trash"),
+                                    selected.len(),
+                                    format_size(selected_bytes)
+                                )}
+                            </button>
+                        </div>
+                    </div>
+                    <p class="duplicate-summary">
+                        {format!("{} {}", res.scanned_files, i18n.t("duplicate_finder.scanned"))}
+                        if res.skipped > 0 {
+                            {format!(" / {} {}", res.skipped, i18n.t("duplicate_finder.skipped"))}
+                        }
+                    </p>
+                    if res.groups.is_empty() {
+                        <p class="duplicate-empty">{i18n.t("duplicate_finder.no_duplicates")}</p>
+                    }
+                    { for res.groups.iter().map(|group| html! {
+                        <div class="duplicate-group" key={group.hash.clone()}>
+                            <div class="duplicate-group-header">
+                                <span>{format!("{} × {}", format_size(group.size), group.files.len())}</span>
+                                <code title={group.hash.clone()}>{group.hash.get(..12).unwrap_or(&group.hash).to_string()}</code>
+                            </div>
+                            { for group.files.iter().map(|file| html! {
+                                <label class="duplicate-file" key={file.path.clone()}>
+                                    <input
+                                        type="checkbox"
+                                        checked={selected.contains(&file.path)}
+                                        onchange={toggle_file(file.path.clone())}
+                                    />
+                                    <span class="duplicate-path">{file.path.clone()}</span>
+                                    <span class="duplicate-modified">{format_modified(&file.modified)}</span>
+                                </label>
+                            }) }
+                        </div>
+                    }) }
+                </div>
+            }
+        </div>
+    }
+}
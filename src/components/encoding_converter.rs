@@ -0,0 +1,372 @@
+use crate::components::settings::SettingsContext;
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn save(options: JsValue) -> JsValue;
+}
+
+const ENCODINGS: [&str; 5] = ["UTF-8", "Shift_JIS", "EUC-JP", "UTF-16LE", "UTF-16BE"];
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+}
+
+#[derive(Serialize)]
+struct SaveDialogOptions {
+    #[serde(rename = "defaultPath")]
+    default_path: String,
+}
+
+#[derive(Serialize)]
+struct DetectArgs {
+    path: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertOptions {
+    line_ending: String,
+    on_unmappable: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertArgs {
+    input_path: String,
+    output_path: String,
+    from: Option<String>,
+    to: String,
+    bom: bool,
+    options: ConvertOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncodingDetectResult {
+    encoding: String,
+    confidence: f32,
+    has_bom: bool,
+    line_ending: String,
+    preview: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnmappableChar {
+    line: usize,
+    column: usize,
+    character: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncodingConvertFileResult {
+    success: bool,
+    source_encoding: String,
+    target_encoding: String,
+    bytes_written: usize,
+    unmappable_count: usize,
+    unmappable: Vec<UnmappableChar>,
+    warnings: Vec<String>,
+    error: Option<String>,
+}
+
+/// `name_converted.ext` for `path`
+fn converted_file_name(path: &str) -> String {
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => format!("{}_converted.{}", stem, ext),
+        _ => format!("{}_converted", name),
+    }
+}
+
+fn select_value(e: &Event) -> String {
+    e.target_unchecked_into::<web_sys::HtmlSelectElement>()
+        .value()
+}
+
+#[function_component(EncodingConverter)]
+pub fn encoding_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let settings = use_context::<SettingsContext>().map(|ctx| ctx.settings);
+    let input_path = use_state(|| Option::<String>::None);
+    let detected = use_state(|| Option::<EncodingDetectResult>::None);
+    let source = use_state(|| "auto".to_string());
+    let target = use_state(|| "UTF-8".to_string());
+    let bom = use_state(|| false);
+    let line_ending = use_state(|| "keep".to_string());
+    let on_unmappable = use_state(|| "replace".to_string());
+    let result = use_state(|| Option::<EncodingConvertFileResult>::None);
+    let error = use_state(|| Option::<String>::None);
+    let is_processing = use_state(|| false);
+
+    let on_select_file = {
+        let input_path = input_path.clone();
+        let detected = detected.clone();
+        let result = result.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            let input_path = input_path.clone();
+            let detected = detected.clone();
+            let result = result.clone();
+            let error = error.clone();
+            spawn_local(async move {
+                let opts =
+                    serde_wasm_bindgen::to_value(&OpenDialogOptions { multiple: false }).unwrap();
+                let Some(path) = open(opts).await.as_string() else {
+                    return;
+                };
+                input_path.set(Some(path.clone()));
+                detected.set(None);
+                result.set(None);
+                error.set(None);
+                let args = serde_wasm_bindgen::to_value(&DetectArgs { path }).unwrap();
+                match try_invoke("detect_encoding_cmd", args).await {
+                    Ok(value) => {
+                        if let Ok(res) = serde_wasm_bindgen::from_value(value) {
+                            detected.set(Some(res));
+                        }
+                    }
+                    Err(e) => error.set(e.as_string()),
+                }
+            });
+        })
+    };
+
+    let on_convert = {
+        let input_path = input_path.clone();
+        let source = source.clone();
+        let target = target.clone();
+        let bom = bom.clone();
+        let line_ending = line_ending.clone();
+        let on_unmappable = on_unmappable.clone();
+        let result = result.clone();
+        let error = error.clone();
+        let is_processing = is_processing.clone();
+        Callback::from(move |_| {
+            let Some(path) = (*input_path).clone() else {
+                return;
+            };
+            let from = Some((*source).clone()).filter(|source| source != "auto");
+            let to = (*target).clone();
+            let bom = *bom && to.starts_with("UTF");
+            let options = ConvertOptions {
+                line_ending: (*line_ending).clone(),
+                on_unmappable: (*on_unmappable).clone(),
+            };
+            let settings = settings.clone();
+            let result = result.clone();
+            let error = error.clone();
+            let is_processing = is_processing.clone();
+            spawn_local(async move {
+                let default_name = converted_file_name(&path);
+                let default_path = match &settings {
+                    Some(settings) => settings.output_path(&default_name),
+                    None => default_name,
+                };
+                let opts =
+                    serde_wasm_bindgen::to_value(&SaveDialogOptions { default_path }).unwrap();
+                let Some(output_path) = save(opts).await.as_string() else {
+                    return;
+                };
+                is_processing.set(true);
+                error.set(None);
+                let args = ConvertArgs {
+                    input_path: path,
+                    output_path,
+                    from,
+                    to,
+                    bom,
+                    options,
+                };
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let response = invoke("convert_file_encoding_cmd", args).await;
+                match serde_wasm_bindgen::from_value::<EncodingConvertFileResult>(response) {
+                    Ok(res) => result.set(Some(res)),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let on_select =
+        |state: UseStateHandle<String>| Callback::from(move |e: Event| state.set(select_value(&e)));
+
+    let on_toggle_bom = {
+        let bom = bom.clone();
+        Callback::from(move |_| bom.set(!*bom))
+    };
+
+    // A BOM only exists for the Unicode encodings
+    let bom_available = target.starts_with("UTF");
+
+    html! {
+        <div class="encoding-converter">
+            <div class="section">
+                <div class="drop-zone" onclick={on_select_file}>
+                    if let Some(path) = (*input_path).clone() {
+                        <p class="drop-zone-text">{path}</p>
+                        <p class="drop-zone-hint">{i18n.t("encoding_converter.click_to_change")}</p>
+                    } else {
+                        <p class="drop-zone-text">{i18n.t("encoding_converter.select_file")}</p>
+                        <p class="drop-zone-hint">{i18n.t("encoding_converter.hint")}</p>
+                    }
+                </div>
+            </div>
+
+            if let Some(det) = (*detected).clone() {
+                <div class="section">
+                    <h3>{i18n.t("encoding_converter.detected")}</h3>
+                    <div class="encoding-detect-summary">
+                        <span class="encoding-badge">{&det.encoding}</span>
+                        <span>
+                            {format!("{}: {:.0}%", i18n.t("encoding_converter.confidence"), det.confidence * 100.0)}
+                        </span>
+                        if det.has_bom {
+                            <span class="encoding-badge">{"BOM"}</span>
+                        }
+                        <span>{format!("{}: {}", i18n.t("encoding_converter.line_ending"), det.line_ending)}</span>
+                    </div>
+                    <pre class="encoding-preview">{&det.preview}</pre>
+                </div>
+            }
+
+            <div class="section">
+                <h3>{i18n.t("encoding_converter.options")}</h3>
+                <div class="encoding-options">
+                    <label>
+                        <span>{i18n.t("encoding_converter.source")}</span>
+                        <select onchange={on_select(source.clone())}>
+                            <option value="auto" selected={*source == "auto"}>
+                                {i18n.t("encoding_converter.auto_detect")}
+                            </option>
+                            { for ENCODINGS.iter().map(|enc| html! {
+                                <option value={*enc} selected={*source == *enc}>{*enc}</option>
+                            }) }
+                        </select>
+                    </label>
+                    <label>
+                        <span>{i18n.t("encoding_converter.target")}</span>
+                        <select onchange={on_select(target.clone())}>
+                            { for ENCODINGS.iter().map(|enc| html! {
+                                <option value={*enc} selected={*target == *enc}>{*enc}</option>
+                            }) }
+                        </select>
+                    </label>
+                    <label>
+                        <span>{i18n.t("encoding_converter.line_ending")}</span>
+                        <select onchange={on_select(line_ending.clone())}>
+                            <option value="keep" selected={*line_ending == "keep"}>
+                                {i18n.t("encoding_converter.keep")}
+                            </option>
+                            { for [("lf", "LF"), ("crlf", "CRLF"), ("cr", "CR")].iter().map(|(value, label)| html! {
+                                <option value={*value} selected={*line_ending == *value}>{*label}</option>
+                            }) }
+                        </select>
+                    </label>
+                    <label>
+                        <span>{i18n.t("encoding_converter.unmappable")}</span>
+                        <select onchange={on_select(on_unmappable.clone())}>
+                            <option value="replace" selected={*on_unmappable == "replace"}>
+                                {i18n.t("encoding_converter.replace")}
+                            </option>
+                            <option value="abort" selected={*on_unmappable == "abort"}>
+                                {i18n.t("encoding_converter.abort")}
+                            </option>
+                        </select>
+                    </label>
+                    <label class="checkbox-option-inline">
+                        <input
+                            type="checkbox"
+                            checked={*bom && bom_available}
+                            disabled={!bom_available}
+                            onchange={on_toggle_bom}
+                        />
+                        {i18n.t("encoding_converter.add_bom")}
+                    </label>
+                </div>
+                <button
+                    class="primary-btn"
+                    onclick={on_convert}
+                    disabled={input_path.is_none() || *is_processing}
+                >
+                    if *is_processing {
+                        <span class="spinner"></span>
+                        {i18n.t("common.processing")}
+                    } else {
+                        {i18n.t("encoding_converter.convert")}
+                    }
+                </button>
+            </div>
+
+            if let Some(message) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{format!("⚠ {}", message)}</div>
+                </div>
+            }
+
+            if let Some(res) = (*result).clone() {
+                <div class={classes!("section", (!res.success).then_some("error-section"))}>
+                    if res.success {
+                        <h3>{i18n.t("encoding_converter.done")}</h3>
+                        <p>
+                            {format!(
+                                "{} → {} ({} bytes)",
+                                res.source_encoding, res.target_encoding, res.bytes_written
+                            )}
+                        </p>
+                    } else {
+                        <div class="error-message">
+                            {format!("⚠ {}", res.error.clone().unwrap_or_default())}
+                        </div>
+                    }
+                    { for res.warnings.iter().map(|warning| html! {
+                        <p class="encoding-warning">{format!("⚠ {}", warning)}</p>
+                    }) }
+                    if !res.unmappable.is_empty() {
+                        <table class="encoding-unmappable">
+                            <thead>
+                                <tr>
+                                    <th>{i18n.t("encoding_converter.line")}</th>
+                                    <th>{i18n.t("encoding_converter.column")}</th>
+                                    <th>{i18n.t("encoding_converter.character")}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                { for res.unmappable.iter().map(|c| html! {
+                                    <tr>
+                                        <td>{c.line}</td>
+                                        <td>{c.column}</td>
+                                        <td>{&c.character}</td>
+                                    </tr>
+                                }) }
+                            </tbody>
+                        </table>
+                        if res.unmappable_count > res.unmappable.len() {
+                            <p class="encoding-more">
+                                {i18n.t("encoding_converter.more").replace("{count}", &(res.unmappable_count - res.unmappable.len()).to_string())}
+                            </p>
+                        }
+                    }
+                </div>
+            }
+        </div>
+    }
+}
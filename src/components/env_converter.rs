@@ -0,0 +1,488 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum EnvTargetFormat {
+    Json,
+    Yaml,
+    DockerComposeEnv,
+    ShellExport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum ConfigFormat {
+    Json,
+    Yaml,
+}
+
+/// Conversion direction picked in the format select
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Conversion {
+    ToTarget(EnvTargetFormat),
+    FromConfig(ConfigFormat),
+}
+
+impl Conversion {
+    const ALL: [Conversion; 6] = [
+        Conversion::ToTarget(EnvTargetFormat::Json),
+        Conversion::ToTarget(EnvTargetFormat::Yaml),
+        Conversion::ToTarget(EnvTargetFormat::DockerComposeEnv),
+        Conversion::ToTarget(EnvTargetFormat::ShellExport),
+        Conversion::FromConfig(ConfigFormat::Json),
+        Conversion::FromConfig(ConfigFormat::Yaml),
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Conversion::ToTarget(EnvTargetFormat::Json) => ".env → JSON",
+            Conversion::ToTarget(EnvTargetFormat::Yaml) => ".env → YAML",
+            Conversion::ToTarget(EnvTargetFormat::DockerComposeEnv) => ".env → docker-compose",
+            Conversion::ToTarget(EnvTargetFormat::ShellExport) => ".env → shell export",
+            Conversion::FromConfig(ConfigFormat::Json) => "JSON → .env",
+            Conversion::FromConfig(ConfigFormat::Yaml) => "YAML / docker-compose → .env",
+        }
+    }
+
+    fn input_is_env(self) -> bool {
+        matches!(self, Conversion::ToTarget(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Convert,
+    Diff,
+}
+
+#[derive(Serialize)]
+struct ParseArgs {
+    content: String,
+}
+
+#[derive(Serialize)]
+struct ConvertEnvArgs {
+    content: String,
+    to: EnvTargetFormat,
+}
+
+#[derive(Serialize)]
+struct ConfigToEnvArgs {
+    input: String,
+    from: ConfigFormat,
+}
+
+#[derive(Serialize)]
+struct DiffArgs {
+    left: String,
+    right: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnvEntry {
+    key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnvIssue {
+    line: usize,
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateKey {
+    key: String,
+    lines: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnvParseResult {
+    entries: Vec<EnvEntry>,
+    duplicates: Vec<DuplicateKey>,
+    external_references: Vec<String>,
+    errors: Vec<EnvIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnvConvertResult {
+    success: bool,
+    output: String,
+    warnings: Vec<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnvDiffResult {
+    success: bool,
+    only_in_left: Vec<String>,
+    only_in_right: Vec<String>,
+    changed: Vec<String>,
+    common: usize,
+    error: Option<String>,
+}
+
+fn key_list(title: String, keys: &[String], class: &'static str) -> Html {
+    html! {
+        <div class={classes!("env-key-list", class)}>
+            <h4>{format!("{} ({})", title, keys.len())}</h4>
+            if !keys.is_empty() {
+                <ul>
+                    { for keys.iter().map(|key| html! { <li><code>{key}</code></li> }) }
+                </ul>
+            }
+        </div>
+    }
+}
+
+#[function_component(EnvConverter)]
+pub fn env_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let mode = use_state(|| Mode::Convert);
+    let conversion = use_state(|| Conversion::ToTarget(EnvTargetFormat::Json));
+    let input = use_state(String::new);
+    let convert_result = use_state(|| Option::<EnvConvertResult>::None);
+    let validation = use_state(|| Option::<EnvParseResult>::None);
+    let left = use_state(String::new);
+    let right = use_state(String::new);
+    let diff_result = use_state(|| Option::<EnvDiffResult>::None);
+    let copy_feedback = use_state(|| false);
+
+    // Validate .env input as it is typed
+    {
+        let validation = validation.clone();
+        use_effect_with(
+            ((*input).clone(), conversion.input_is_env()),
+            move |(content, is_env)| {
+                if !*is_env || content.trim().is_empty() {
+                    validation.set(None);
+                } else {
+                    let args = serde_wasm_bindgen::to_value(&ParseArgs {
+                        content: content.clone(),
+                    })
+                    .unwrap();
+                    spawn_local(async move {
+                        let result = invoke("parse_env_file_cmd", args).await;
+                        if let Ok(parsed) = serde_wasm_bindgen::from_value::<EnvParseResult>(result)
+                        {
+                            validation.set(Some(parsed));
+                        }
+                    });
+                }
+                || {}
+            },
+        );
+    }
+
+    let set_mode = |next: Mode| {
+        let mode = mode.clone();
+        Callback::from(move |_| mode.set(next))
+    };
+
+    let on_conversion_change = {
+        let conversion = conversion.clone();
+        let convert_result = convert_result.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(next) = Conversion::ALL
+                .into_iter()
+                .find(|c| c.label() == select.value())
+            {
+                conversion.set(next);
+                convert_result.set(None);
+            }
+        })
+    };
+
+    let textarea_input = |state: UseStateHandle<String>| {
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            state.set(target.value());
+        })
+    };
+
+    let on_convert = {
+        let conversion = conversion.clone();
+        let input = input.clone();
+        let convert_result = convert_result.clone();
+        Callback::from(move |_| {
+            let content = (*input).clone();
+            if content.trim().is_empty() {
+                return;
+            }
+            let (cmd, args) = match *conversion {
+                Conversion::ToTarget(to) => (
+                    "convert_env_cmd",
+                    serde_wasm_bindgen::to_value(&ConvertEnvArgs { content, to }).unwrap(),
+                ),
+                Conversion::FromConfig(from) => (
+                    "config_to_env_cmd",
+                    serde_wasm_bindgen::to_value(&ConfigToEnvArgs {
+                        input: content,
+                        from,
+                    })
+                    .unwrap(),
+                ),
+            };
+            let convert_result = convert_result.clone();
+            spawn_local(async move {
+                let result = invoke(cmd, args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<EnvConvertResult>(result) {
+                    convert_result.set(Some(res));
+                }
+            });
+        })
+    };
+
+    let on_compare = {
+        let left = left.clone();
+        let right = right.clone();
+        let diff_result = diff_result.clone();
+        Callback::from(move |_| {
+            let args = serde_wasm_bindgen::to_value(&DiffArgs {
+                left: (*left).clone(),
+                right: (*right).clone(),
+            })
+            .unwrap();
+            let diff_result = diff_result.clone();
+            spawn_local(async move {
+                let result = invoke("diff_env_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<EnvDiffResult>(result) {
+                    diff_result.set(Some(res));
+                }
+            });
+        })
+    };
+
+    let on_copy = {
+        let convert_result = convert_result.clone();
+        let copy_feedback = copy_feedback.clone();
+        Callback::from(move |_| {
+            let Some(output) = convert_result
+                .as_ref()
+                .filter(|res| res.success)
+                .map(|res| res.output.clone())
+            else {
+                return;
+            };
+            let copy_feedback = copy_feedback.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ =
+                        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&output)).await;
+                    copy_feedback.set(true);
+
+                    let copy_feedback_reset = copy_feedback.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copy_feedback_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let render_validation = |parsed: &EnvParseResult| {
+        html! {
+            <div class="env-validation">
+                <div class={classes!("env-validation-summary", parsed.errors.is_empty().then_some("ok"))}>
+                    {format!(
+                        "{} {} · {} {}",
+                        parsed.entries.len(),
+                        i18n.t("env_converter.variables"),
+                        parsed.errors.len(),
+                        i18n.t("env_converter.errors")
+                    )}
+                </div>
+                { for parsed.errors.iter().map(|issue| html! {
+                    <div class="env-issue error">
+                        {format!("{} {}: {}", i18n.t("env_converter.line"), issue.line, issue.message)}
+                    </div>
+                }) }
+                { for parsed.duplicates.iter().map(|dup| html! {
+                    <div class="env-issue warning">
+                        {format!(
+                            "{}: {} ({} {})",
+                            i18n.t("env_converter.duplicate_key"),
+                            dup.key,
+                            i18n.t("env_converter.line"),
+                            dup.lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(", ")
+                        )}
+                    </div>
+                }) }
+                if !parsed.external_references.is_empty() {
+                    <div class="env-issue info">
+                        {format!(
+                            "{}: {}",
+                            i18n.t("env_converter.external_references"),
+                            parsed.external_references.join(", ")
+                        )}
+                    </div>
+                }
+            </div>
+        }
+    };
+
+    let convert_view = html! {
+        <>
+            <div class="section format-section">
+                <select class="format-select" onchange={on_conversion_change}>
+                    { for Conversion::ALL.iter().map(|c| html! {
+                        <option value={c.label()} selected={*conversion == *c}>{c.label()}</option>
+                    }) }
+                </select>
+                <button class="primary-btn" onclick={on_convert} disabled={(*input).trim().is_empty()}>
+                    {i18n.t("env_converter.convert")}
+                </button>
+            </div>
+
+            if let Some(err) = convert_result.as_ref().and_then(|res| res.error.clone()) {
+                <div class="section error-section">
+                    <div class="error-message">{"⚠ "}{err}</div>
+                </div>
+            }
+
+            <div class="section editor-section">
+                <div class="editor-panel">
+                    <div class="section-header">
+                        <h3>{i18n.t("common.input")}</h3>
+                    </div>
+                    <textarea
+                        class="env-textarea"
+                        placeholder={if conversion.input_is_env() {
+                            "DB_HOST=localhost\nDB_PORT=5432"
+                        } else {
+                            "{\"DB_HOST\": \"localhost\"}"
+                        }}
+                        value={(*input).clone()}
+                        oninput={textarea_input(input.clone())}
+                        spellcheck="false"
+                    />
+                    if let Some(parsed) = validation.as_ref() {
+                        {render_validation(parsed)}
+                    }
+                </div>
+                <div class="editor-panel">
+                    <div class="section-header">
+                        <h3>{i18n.t("common.output")}</h3>
+                        <button
+                            class={classes!("secondary-btn", (*copy_feedback).then_some("copied"))}
+                            onclick={on_copy}
+                            disabled={!convert_result.as_ref().is_some_and(|res| res.success)}
+                        >
+                            if *copy_feedback {
+                                {format!("✓ {}", i18n.t("common.copied"))}
+                            } else {
+                                {i18n.t("common.copy")}
+                            }
+                        </button>
+                    </div>
+                    <textarea
+                        class="env-textarea"
+                        readonly=true
+                        value={convert_result.as_ref().map(|res| res.output.clone()).unwrap_or_default()}
+                        spellcheck="false"
+                    />
+                    if let Some(res) = convert_result.as_ref() {
+                        { for res.warnings.iter().map(|warning| html! {
+                            <div class="env-issue warning">{warning}</div>
+                        }) }
+                    }
+                </div>
+            </div>
+        </>
+    };
+
+    let diff_view = html! {
+        <>
+            <div class="section editor-section">
+                <div class="editor-panel">
+                    <div class="section-header">
+                        <h3>{i18n.t("env_converter.left")}</h3>
+                    </div>
+                    <textarea
+                        class="env-textarea"
+                        placeholder=".env.development"
+                        value={(*left).clone()}
+                        oninput={textarea_input(left.clone())}
+                        spellcheck="false"
+                    />
+                </div>
+                <div class="editor-panel">
+                    <div class="section-header">
+                        <h3>{i18n.t("env_converter.right")}</h3>
+                    </div>
+                    <textarea
+                        class="env-textarea"
+                        placeholder=".env.production"
+                        value={(*right).clone()}
+                        oninput={textarea_input(right.clone())}
+                        spellcheck="false"
+                    />
+                </div>
+            </div>
+            <div class="section format-section">
+                <button class="primary-btn" onclick={on_compare}>{i18n.t("env_converter.compare")}</button>
+            </div>
+            if let Some(res) = (*diff_result).clone() {
+                if let Some(err) = res.error.clone() {
+                    <div class="section error-section">
+                        <div class="error-message">{"⚠ "}{err}</div>
+                    </div>
+                } else if res.success {
+                    <div class="section env-diff">
+                        <div class="env-diff-summary">
+                            {format!("{} {}", res.common, i18n.t("env_converter.common_keys"))}
+                        </div>
+                        <div class="env-diff-lists">
+                            {key_list(i18n.t("env_converter.only_left"), &res.only_in_left, "left")}
+                            {key_list(i18n.t("env_converter.only_right"), &res.only_in_right, "right")}
+                            {key_list(i18n.t("env_converter.changed"), &res.changed, "changed")}
+                        </div>
+                    </div>
+                }
+            }
+        </>
+    };
+
+    html! {
+        <div class="env-converter">
+            <div class="mode-toggle">
+                <button
+                    class={classes!("mode-btn", (*mode == Mode::Convert).then_some("active"))}
+                    onclick={set_mode(Mode::Convert)}
+                >
+                    {i18n.t("env_converter.mode_convert")}
+                </button>
+                <button
+                    class={classes!("mode-btn", (*mode == Mode::Diff).then_some("active"))}
+                    onclick={set_mode(Mode::Diff)}
+                >
+                    {i18n.t("env_converter.mode_diff")}
+                </button>
+            </div>
+            if *mode == Mode::Convert {
+                {convert_view}
+            } else {
+                {diff_view}
+            }
+        </div>
+    }
+}
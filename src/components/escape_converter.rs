@@ -0,0 +1,353 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    Escape,
+    Unescape,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Html,
+    Unicode,
+    Json,
+    Xml,
+    Auto,
+}
+
+impl Format {
+    const ALL: [Format; 5] = [
+        Format::Html,
+        Format::Unicode,
+        Format::Json,
+        Format::Xml,
+        Format::Auto,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::Unicode => "unicode",
+            Format::Json => "json",
+            Format::Xml => "xml",
+            Format::Auto => "auto",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum EntityStyle {
+    Named,
+    Decimal,
+    Hex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum EscapeMode {
+    HtmlEntities {
+        style: EntityStyle,
+        encode_non_ascii: bool,
+    },
+    UnicodeEscape,
+    JsonStringEscape,
+    XmlEscape,
+    Auto,
+}
+
+#[derive(Serialize)]
+struct EscapeArgs {
+    input: String,
+    mode: EscapeMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EscapeWarning {
+    position: usize,
+    sequence: String,
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EscapeResult {
+    success: bool,
+    output: String,
+    replaced_count: usize,
+    warnings: Vec<EscapeWarning>,
+    error: Option<String>,
+}
+
+#[function_component(EscapeConverter)]
+pub fn escape_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let direction = use_state(|| Direction::Escape);
+    let format = use_state(|| Format::Html);
+    let entity_style = use_state(|| EntityStyle::Named);
+    let encode_non_ascii = use_state(|| false);
+    let input = use_state(String::new);
+    let result = use_state(|| Option::<EscapeResult>::None);
+    let copy_feedback = use_state(|| false);
+
+    // Auto detection only makes sense when unescaping
+    let effective_format = if *direction == Direction::Escape && *format == Format::Auto {
+        Format::Html
+    } else {
+        *format
+    };
+    let mode = match effective_format {
+        Format::Html => EscapeMode::HtmlEntities {
+            style: *entity_style,
+            encode_non_ascii: *encode_non_ascii,
+        },
+        Format::Unicode => EscapeMode::UnicodeEscape,
+        Format::Json => EscapeMode::JsonStringEscape,
+        Format::Xml => EscapeMode::XmlEscape,
+        Format::Auto => EscapeMode::Auto,
+    };
+
+    // Convert on every edit
+    {
+        let result = result.clone();
+        use_effect_with(
+            ((*input).clone(), *direction, mode),
+            move |(input, direction, mode)| {
+                let input = input.clone();
+                let mode = *mode;
+                let cmd = match direction {
+                    Direction::Escape => "escape_text_cmd",
+                    Direction::Unescape => "unescape_text_cmd",
+                };
+                if input.is_empty() {
+                    result.set(None);
+                } else {
+                    spawn_local(async move {
+                        let args =
+                            serde_wasm_bindgen::to_value(&EscapeArgs { input, mode }).unwrap();
+                        let response = invoke(cmd, args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<EscapeResult>(response) {
+                            result.set(Some(res));
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_format_change = {
+        let format = format.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(f) = Format::ALL.into_iter().find(|f| f.key() == select.value()) {
+                format.set(f);
+            }
+        })
+    };
+
+    let on_style_change = {
+        let entity_style = entity_style.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            entity_style.set(match select.value().as_str() {
+                "decimal" => EntityStyle::Decimal,
+                "hex" => EntityStyle::Hex,
+                _ => EntityStyle::Named,
+            });
+        })
+    };
+
+    let on_non_ascii_change = {
+        let encode_non_ascii = encode_non_ascii.clone();
+        Callback::from(move |e: Event| {
+            let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+            encode_non_ascii.set(checkbox.checked());
+        })
+    };
+
+    let on_clear = {
+        let input = input.clone();
+        Callback::from(move |_| input.set(String::new()))
+    };
+
+    let on_swap = {
+        let input = input.clone();
+        let direction = direction.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            if let Some(res) = (*result).clone().filter(|res| res.success) {
+                input.set(res.output);
+                direction.set(match *direction {
+                    Direction::Escape => Direction::Unescape,
+                    Direction::Unescape => Direction::Escape,
+                });
+            }
+        })
+    };
+
+    let on_copy = {
+        let result = result.clone();
+        let copy_feedback = copy_feedback.clone();
+        Callback::from(move |_| {
+            let Some(res) = (*result).clone() else {
+                return;
+            };
+            let copy_feedback = copy_feedback.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&res.output))
+                        .await;
+                    copy_feedback.set(true);
+
+                    let copy_feedback_reset = copy_feedback.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copy_feedback_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let direction_btn = |target: Direction, label_key: &str| {
+        let direction = direction.clone();
+        html! {
+            <button
+                class={classes!("mode-btn", (*direction == target).then_some("active"))}
+                onclick={Callback::from(move |_| direction.set(target))}
+            >
+                {i18n.t(label_key)}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="escape-converter">
+            <div class="section escape-options">
+                <div class="mode-toggle">
+                    {direction_btn(Direction::Escape, "escape_converter.escape")}
+                    {direction_btn(Direction::Unescape, "escape_converter.unescape")}
+                </div>
+                <select class="form-select" onchange={on_format_change}>
+                    { for Format::ALL.iter().filter(|f| **f != Format::Auto || *direction == Direction::Unescape).map(|f| html! {
+                        <option value={f.key()} selected={*f == effective_format}>
+                            {i18n.t(&format!("escape_converter.format_{}", f.key()))}
+                        </option>
+                    }) }
+                </select>
+                if effective_format == Format::Html && *direction == Direction::Escape {
+                    <select class="form-select" onchange={on_style_change}>
+                        <option value="named" selected={*entity_style == EntityStyle::Named}>
+                            {i18n.t("escape_converter.style_named")}
+                        </option>
+                        <option value="decimal" selected={*entity_style == EntityStyle::Decimal}>
+                            {i18n.t("escape_converter.style_decimal")}
+                        </option>
+                        <option value="hex" selected={*entity_style == EntityStyle::Hex}>
+                            {i18n.t("escape_converter.style_hex")}
+                        </option>
+                    </select>
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={*encode_non_ascii}
+                            onchange={on_non_ascii_change}
+                        />
+                        {i18n.t("escape_converter.encode_non_ascii")}
+                    </label>
+                }
+            </div>
+
+            <div class="section escape-pane">
+                <div class="section-header">
+                    <h3>{i18n.t("escape_converter.input")}</h3>
+                    <button class="secondary-btn" onclick={on_clear}>
+                        {i18n.t("common.clear")}
+                    </button>
+                </div>
+                <textarea
+                    class="form-textarea escape-textarea"
+                    value={(*input).clone()}
+                    oninput={on_input_change}
+                    placeholder={match *direction {
+                        Direction::Escape => i18n.t("escape_converter.escape_placeholder"),
+                        Direction::Unescape => i18n.t("escape_converter.unescape_placeholder"),
+                    }}
+                    spellcheck="false"
+                />
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                if res.success {
+                    if !res.warnings.is_empty() {
+                        <div class="section escape-warnings">
+                            { for res.warnings.iter().map(|w| html! {
+                                <div class="warning-message">
+                                    {"⚠ "}
+                                    {format!("{} {}: ", i18n.t("escape_converter.position"), w.position + 1)}
+                                    <code>{&w.sequence}</code>
+                                    {format!(" — {}", w.message)}
+                                </div>
+                            }) }
+                        </div>
+                    }
+                    <div class="section escape-pane">
+                        <div class="section-header">
+                            <h3>{i18n.t("escape_converter.output")}</h3>
+                            <div class="escape-output-actions">
+                                <span class="escape-count">
+                                    {format!("{} {}", res.replaced_count, i18n.t("escape_converter.replaced"))}
+                                </span>
+                                <button class="secondary-btn" onclick={on_swap}>
+                                    {i18n.t("common.swap")}
+                                </button>
+                                <button
+                                    class={classes!("secondary-btn", (*copy_feedback).then_some("copied"))}
+                                    onclick={on_copy}
+                                >
+                                    if *copy_feedback {
+                                        {format!("✓ {}", i18n.t("common.copied"))}
+                                    } else {
+                                        {i18n.t("common.copy")}
+                                    }
+                                </button>
+                            </div>
+                        </div>
+                        <textarea
+                            class="form-textarea escape-textarea"
+                            readonly=true
+                            value={res.output.clone()}
+                            spellcheck="false"
+                        />
+                    </div>
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {"⚠ "}{res.error.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,425 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    fn convertFileSrc(path: &str) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn save(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "opener"], js_name = openUrl)]
+    async fn open_url(url: &str) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct SaveDialogOptions {
+    filters: Vec<FileFilter>,
+    #[serde(rename = "defaultPath")]
+    default_path: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ReadExifArgs {
+    path: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StripExifArgs {
+    input_path: String,
+    output_path: String,
+    keep_orientation: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct GpsInfo {
+    latitude: f64,
+    longitude: f64,
+    latitude_dms: String,
+    longitude_dms: String,
+    altitude: Option<f64>,
+    map_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ExifField {
+    ifd: String,
+    tag: String,
+    value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ExifResult {
+    success: bool,
+    has_exif: bool,
+    camera_make: Option<String>,
+    camera_model: Option<String>,
+    lens_model: Option<String>,
+    software: Option<String>,
+    date_time_original: Option<String>,
+    iso: Option<u32>,
+    exposure_time: Option<String>,
+    f_number: Option<f64>,
+    focal_length: Option<f64>,
+    orientation: Option<u16>,
+    gps: Option<GpsInfo>,
+    fields: Vec<ExifField>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct EditResult {
+    success: bool,
+    output_path: String,
+    original_size: u64,
+    new_size: u64,
+    error: Option<String>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    #[prop_or_default]
+    pub dropped_file: Option<String>,
+    #[prop_or_default]
+    pub on_file_processed: Callback<()>,
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+/// Load `path` into the viewer, replacing the previous image
+fn load_image(
+    path: String,
+    input_path: UseStateHandle<Option<String>>,
+    exif: UseStateHandle<Option<ExifResult>>,
+    strip_result: UseStateHandle<Option<EditResult>>,
+) {
+    input_path.set(Some(path.clone()));
+    strip_result.set(None);
+    spawn_local(async move {
+        let args = serde_wasm_bindgen::to_value(&ReadExifArgs { path }).unwrap();
+        let response = invoke("read_exif_cmd", args).await;
+        if let Ok(res) = serde_wasm_bindgen::from_value::<ExifResult>(response) {
+            exif.set(Some(res));
+        }
+    });
+}
+
+#[function_component(ExifTools)]
+pub fn exif_tools(props: &Props) -> Html {
+    let (i18n, _) = use_translation();
+    let input_path = use_state(|| Option::<String>::None);
+    let exif = use_state(|| Option::<ExifResult>::None);
+    let keep_orientation = use_state(|| true);
+    let is_processing = use_state(|| false);
+    let strip_result = use_state(|| Option::<EditResult>::None);
+    let copy_feedback = use_state(|| false);
+
+    // Handle dropped file
+    {
+        let on_file_processed = props.on_file_processed.clone();
+        let input_path = input_path.clone();
+        let exif = exif.clone();
+        let strip_result = strip_result.clone();
+        use_effect_with(props.dropped_file.clone(), move |dropped_file| {
+            if let Some(path) = dropped_file.clone() {
+                load_image(path, input_path, exif, strip_result);
+                on_file_processed.emit(());
+            }
+            || {}
+        });
+    }
+
+    let on_select_file = {
+        let input_path = input_path.clone();
+        let exif = exif.clone();
+        let strip_result = strip_result.clone();
+        Callback::from(move |_| {
+            let input_path = input_path.clone();
+            let exif = exif.clone();
+            let strip_result = strip_result.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    filters: vec![FileFilter {
+                        name: "Images".to_string(),
+                        extensions: ["jpg", "jpeg", "png", "webp", "tif", "tiff", "heic"]
+                            .iter()
+                            .map(|ext| ext.to_string())
+                            .collect(),
+                    }],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(path) = open(opts).await.as_string() {
+                    load_image(path, input_path, exif, strip_result);
+                }
+            });
+        })
+    };
+
+    let on_keep_orientation_change = {
+        let keep_orientation = keep_orientation.clone();
+        Callback::from(move |e: Event| {
+            let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keep_orientation.set(checkbox.checked());
+        })
+    };
+
+    let on_strip = {
+        let input_path = input_path.clone();
+        let keep_orientation = keep_orientation.clone();
+        let is_processing = is_processing.clone();
+        let strip_result = strip_result.clone();
+        Callback::from(move |_| {
+            let Some(path) = (*input_path).clone() else {
+                return;
+            };
+            let keep_orientation = *keep_orientation;
+            let is_processing = is_processing.clone();
+            let strip_result = strip_result.clone();
+            spawn_local(async move {
+                let (stem, extension) = path
+                    .rsplit_once('.')
+                    .map(|(stem, ext)| (stem.to_string(), ext.to_lowercase()))
+                    .unwrap_or_else(|| (path.clone(), "jpg".to_string()));
+                let file_stem = stem.rsplit(['/', '\\']).next().unwrap_or("image");
+                let save_options = SaveDialogOptions {
+                    filters: vec![FileFilter {
+                        name: format!("{} Image", extension.to_uppercase()),
+                        extensions: vec![extension.clone()],
+                    }],
+                    default_path: Some(format!("{}_clean.{}", file_stem, extension)),
+                };
+                let save_options_js = serde_wasm_bindgen::to_value(&save_options).unwrap();
+                let Some(output_path) = save(save_options_js).await.as_string() else {
+                    return;
+                };
+
+                is_processing.set(true);
+                let args = serde_wasm_bindgen::to_value(&StripExifArgs {
+                    input_path: path,
+                    output_path,
+                    keep_orientation,
+                })
+                .unwrap();
+                let response = invoke("strip_exif_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<EditResult>(response) {
+                    strip_result.set(Some(res));
+                }
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let on_open_map = |url: String| {
+        Callback::from(move |_| {
+            let url = url.clone();
+            spawn_local(async move {
+                open_url(&url).await;
+            });
+        })
+    };
+
+    let on_copy_coordinates = {
+        let copy_feedback = copy_feedback.clone();
+        move |text: String| {
+            let copy_feedback = copy_feedback.clone();
+            Callback::from(move |_| {
+                let text = text.clone();
+                let copy_feedback = copy_feedback.clone();
+                if let Some(win) = window() {
+                    let clipboard = win.navigator().clipboard();
+                    spawn_local(async move {
+                        let _ =
+                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text)).await;
+                        copy_feedback.set(true);
+
+                        let copy_feedback_reset = copy_feedback.clone();
+                        gloo_timers::callback::Timeout::new(2000, move || {
+                            copy_feedback_reset.set(false);
+                        })
+                        .forget();
+                    });
+                }
+            })
+        }
+    };
+
+    let summary_row = |label_key: &str, value: Option<String>| {
+        value.map(|value| {
+            html! {
+                <>
+                    <dt>{i18n.t(label_key)}</dt>
+                    <dd>{value}</dd>
+                </>
+            }
+        })
+    };
+
+    html! {
+        <div class="exif-tools">
+            <div class="section">
+                <div class="drop-zone exif-drop" onclick={on_select_file}>
+                    if let Some(path) = (*input_path).clone() {
+                        <div class="exif-preview">
+                            if let Some(url) = convertFileSrc(&path).as_string() {
+                                <img src={url} alt="Preview" />
+                            }
+                            <p class="exif-path">{path}</p>
+                        </div>
+                        <p class="drop-zone-hint">{i18n.t("exif_tools.click_to_change")}</p>
+                    } else {
+                        <p class="drop-zone-text">{i18n.t("exif_tools.drop_image")}</p>
+                        <p class="drop-zone-hint">{i18n.t("exif_tools.supported_formats")}</p>
+                    }
+                </div>
+            </div>
+
+            if let Some(res) = (*exif).clone() {
+                if !res.success {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {"⚠ "}{res.error.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+                } else if !res.has_exif {
+                    <div class="section exif-empty">
+                        {"✓ "}{i18n.t("exif_tools.no_exif")}
+                    </div>
+                } else {
+                    if let Some(gps) = res.gps.clone() {
+                        <div class="section exif-gps">
+                            <div class="section-header">
+                                <h3>{"⚠ "}{i18n.t("exif_tools.gps_warning")}</h3>
+                            </div>
+                            <dl class="exif-summary">
+                                <dt>{i18n.t("exif_tools.decimal")}</dt>
+                                <dd>{format!("{:.6}, {:.6}", gps.latitude, gps.longitude)}</dd>
+                                <dt>{i18n.t("exif_tools.dms")}</dt>
+                                <dd>{format!("{} {}", gps.latitude_dms, gps.longitude_dms)}</dd>
+                                if let Some(altitude) = gps.altitude {
+                                    <dt>{i18n.t("exif_tools.altitude")}</dt>
+                                    <dd>{format!("{:.1} m", altitude)}</dd>
+                                }
+                            </dl>
+                            <div class="exif-actions">
+                                <button class="secondary-btn" onclick={on_open_map(gps.map_url.clone())}>
+                                    {i18n.t("exif_tools.open_map")}
+                                </button>
+                                <button
+                                    class={classes!("secondary-btn", (*copy_feedback).then_some("copied"))}
+                                    onclick={on_copy_coordinates(format!("{:.6}, {:.6}", gps.latitude, gps.longitude))}
+                                >
+                                    if *copy_feedback {
+                                        {format!("✓ {}", i18n.t("common.copied"))}
+                                    } else {
+                                        {i18n.t("common.copy")}
+                                    }
+                                </button>
+                            </div>
+                        </div>
+                    }
+
+                    <div class="section">
+                        <div class="section-header">
+                            <h3>{i18n.t("exif_tools.summary")}</h3>
+                        </div>
+                        <dl class="exif-summary">
+                            {summary_row("exif_tools.camera", match (res.camera_make.clone(), res.camera_model.clone()) {
+                                (Some(make), Some(model)) if model.starts_with(&make) => Some(model),
+                                (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+                                (make, model) => make.or(model),
+                            })}
+                            {summary_row("exif_tools.lens", res.lens_model.clone())}
+                            {summary_row("exif_tools.date_time", res.date_time_original.clone())}
+                            {summary_row("exif_tools.iso", res.iso.map(|iso| iso.to_string()))}
+                            {summary_row("exif_tools.shutter", res.exposure_time.clone().map(|t| format!("{} s", t)))}
+                            {summary_row("exif_tools.aperture", res.f_number.map(|f| format!("f/{:.1}", f)))}
+                            {summary_row("exif_tools.focal_length", res.focal_length.map(|f| format!("{:.0} mm", f)))}
+                            {summary_row("exif_tools.orientation", res.orientation.map(|o| o.to_string()))}
+                            {summary_row("exif_tools.software", res.software.clone())}
+                        </dl>
+                        <details class="exif-fields">
+                            <summary>{format!("{} ({})", i18n.t("exif_tools.all_fields"), res.fields.len())}</summary>
+                            <table>
+                                <tbody>
+                                    { for res.fields.iter().map(|field| html! {
+                                        <tr>
+                                            <td class="exif-field-tag">{&field.tag}</td>
+                                            <td>{&field.value}</td>
+                                            <td class="exif-field-ifd">{&field.ifd}</td>
+                                        </tr>
+                                    }) }
+                                </tbody>
+                            </table>
+                        </details>
+                    </div>
+                }
+
+                if res.success {
+                    <div class="section exif-strip">
+                        <label class="checkbox-label">
+                            <input
+                                type="checkbox"
+                                checked={*keep_orientation}
+                                onchange={on_keep_orientation_change}
+                            />
+                            {i18n.t("exif_tools.keep_orientation")}
+                        </label>
+                        <button class="primary-btn" onclick={on_strip} disabled={*is_processing}>
+                            if *is_processing {
+                                <span class="spinner"></span>
+                            }
+                            {i18n.t("exif_tools.strip")}
+                        </button>
+                        if let Some(result) = (*strip_result).clone() {
+                            if result.success {
+                                <div class="exif-strip-result">
+                                    {format!(
+                                        "✓ {} → {} ({})",
+                                        format_size(result.original_size),
+                                        format_size(result.new_size),
+                                        result.output_path
+                                    )}
+                                </div>
+                            } else {
+                                <div class="error-message">
+                                    {"⚠ "}{result.error.unwrap_or_default()}
+                                </div>
+                            }
+                        }
+                    </div>
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,583 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    directory: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NumberingRule {
+    start: u64,
+    step: u64,
+    digits: usize,
+    zero_pad: bool,
+    position: String,
+    separator: String,
+}
+
+impl Default for NumberingRule {
+    fn default() -> Self {
+        Self {
+            start: 1,
+            step: 1,
+            digits: 3,
+            zero_pad: true,
+            position: "prefix".to_string(),
+            separator: "_".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DateInsertRule {
+    format: String,
+    position: String,
+    separator: String,
+}
+
+impl Default for DateInsertRule {
+    fn default() -> Self {
+        Self {
+            format: "%Y%m%d".to_string(),
+            position: "prefix".to_string(),
+            separator: "_".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RenameRule {
+    find: String,
+    replace: String,
+    use_regex: bool,
+    case_insensitive: bool,
+    date: Option<DateInsertRule>,
+    numbering: Option<NumberingRule>,
+    prefix: String,
+    suffix: String,
+    lowercase_extension: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RenamePair {
+    from: String,
+    to: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RenamePreviewItem {
+    from: String,
+    old_name: String,
+    new_name: String,
+    changed: bool,
+    conflict: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RenamePreviewResult {
+    items: Vec<RenamePreviewItem>,
+    plan: Vec<RenamePair>,
+    conflicts: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RenameApplyResult {
+    success: bool,
+    renamed: usize,
+    undo: Vec<RenamePair>,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PreviewRenameArgs {
+    paths: Vec<String>,
+    rule: RenameRule,
+}
+
+#[derive(Serialize)]
+struct ApplyRenameArgs {
+    plan: Vec<RenamePair>,
+}
+
+/// Point every path in `files` that was renamed by `plan` at its new location
+fn follow_renames(files: &[String], plan: &[RenamePair]) -> Vec<String> {
+    files
+        .iter()
+        .map(|path| {
+            plan.iter()
+                .find(|pair| pair.from == *path)
+                .map(|pair| pair.to.clone())
+                .unwrap_or_else(|| path.clone())
+        })
+        .collect()
+}
+
+#[function_component(FileRenamer)]
+pub fn file_renamer() -> Html {
+    let (i18n, _) = use_translation();
+    let files = use_state(Vec::<String>::new);
+    let rule = use_state(RenameRule::default);
+    let preview = use_state(|| Option::<RenamePreviewResult>::None);
+    let undo_plan = use_state(Vec::<RenamePair>::new);
+    let message = use_state(|| Option::<String>::None);
+    let error = use_state(|| Option::<String>::None);
+    let is_processing = use_state(|| false);
+
+    // The preview follows the file list and every rule edit
+    {
+        let preview = preview.clone();
+        use_effect_with(((*files).clone(), (*rule).clone()), move |(paths, rule)| {
+            let paths = paths.clone();
+            let rule = rule.clone();
+            spawn_local(async move {
+                if paths.is_empty() {
+                    preview.set(None);
+                    return;
+                }
+                let args =
+                    serde_wasm_bindgen::to_value(&PreviewRenameArgs { paths, rule }).unwrap();
+                let result = invoke("preview_rename_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<RenamePreviewResult>(result) {
+                    preview.set(Some(res));
+                }
+            });
+            || {}
+        });
+    }
+
+    let on_select_files = {
+        let files = files.clone();
+        let undo_plan = undo_plan.clone();
+        let message = message.clone();
+        Callback::from(move |_| {
+            let files = files.clone();
+            let undo_plan = undo_plan.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: true,
+                    directory: false,
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Ok(paths) = serde_wasm_bindgen::from_value::<Vec<String>>(open(opts).await) {
+                    let mut next = (*files).clone();
+                    for path in paths {
+                        if !next.contains(&path) {
+                            next.push(path);
+                        }
+                    }
+                    files.set(next);
+                    undo_plan.set(Vec::new());
+                    message.set(None);
+                }
+            });
+        })
+    };
+
+    let on_clear = {
+        let files = files.clone();
+        let undo_plan = undo_plan.clone();
+        let message = message.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            files.set(Vec::new());
+            undo_plan.set(Vec::new());
+            message.set(None);
+            error.set(None);
+        })
+    };
+
+    let text_field = |apply: fn(&mut RenameRule, String)| {
+        let rule = rule.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*rule).clone();
+            apply(&mut next, input.value());
+            rule.set(next);
+        })
+    };
+
+    let check_field = |apply: fn(&mut RenameRule, bool)| {
+        let rule = rule.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*rule).clone();
+            apply(&mut next, input.checked());
+            rule.set(next);
+        })
+    };
+
+    let select_field = |apply: fn(&mut RenameRule, String)| {
+        let rule = rule.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*rule).clone();
+            apply(&mut next, select.value());
+            rule.set(next);
+        })
+    };
+
+    // Runs a plan and hands the result to `on_done`; Apply and Undo only differ there
+    let run_plan = {
+        let files = files.clone();
+        let message = message.clone();
+        let error = error.clone();
+        let is_processing = is_processing.clone();
+        move |plan: Vec<RenamePair>, on_done: Callback<RenameApplyResult>| {
+            let files = files.clone();
+            let message = message.clone();
+            let error = error.clone();
+            let is_processing = is_processing.clone();
+            is_processing.set(true);
+            spawn_local(async move {
+                let args =
+                    serde_wasm_bindgen::to_value(&ApplyRenameArgs { plan: plan.clone() }).unwrap();
+                let result = invoke("apply_rename_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<RenameApplyResult>(result) {
+                    if res.success {
+                        files.set(follow_renames(&files, &plan));
+                        error.set(None);
+                        message.set(None);
+                        on_done.emit(res);
+                    } else {
+                        error.set(res.error);
+                    }
+                }
+                is_processing.set(false);
+            });
+        }
+    };
+
+    let on_apply = {
+        let preview = preview.clone();
+        let undo_plan = undo_plan.clone();
+        let message = message.clone();
+        let run_plan = run_plan.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let Some(res) = (*preview).clone() else {
+                return;
+            };
+            if res.conflicts > 0 || res.plan.is_empty() {
+                return;
+            }
+            let undo_plan = undo_plan.clone();
+            let message = message.clone();
+            let i18n = i18n.clone();
+            run_plan(
+                res.plan,
+                Callback::from(move |applied: RenameApplyResult| {
+                    message.set(Some(format!(
+                        "{} {}",
+                        applied.renamed,
+                        i18n.t("file_renamer.renamed")
+                    )));
+                    undo_plan.set(applied.undo);
+                }),
+            );
+        })
+    };
+
+    let on_undo = {
+        let undo_plan = undo_plan.clone();
+        let message = message.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            if undo_plan.is_empty() {
+                return;
+            }
+            let plan = (*undo_plan).clone();
+            let undo_plan = undo_plan.clone();
+            let message = message.clone();
+            let i18n = i18n.clone();
+            run_plan(
+                plan,
+                Callback::from(move |_| {
+                    message.set(Some(i18n.t("file_renamer.undone")));
+                    undo_plan.set(Vec::new());
+                }),
+            );
+        })
+    };
+
+    let on_numbering_toggle = check_field(|r, on| {
+        r.numbering = on.then(NumberingRule::default);
+    });
+    let on_date_toggle = check_field(|r, on| {
+        r.date = on.then(DateInsertRule::default);
+    });
+
+    let position_options = |current: &str| {
+        html! {
+            <>
+                <option value="prefix" selected={current == "prefix"}>{i18n.t("file_renamer.before")}</option>
+                <option value="suffix" selected={current == "suffix"}>{i18n.t("file_renamer.after")}</option>
+            </>
+        }
+    };
+
+    let changed_count = (*preview)
+        .as_ref()
+        .map(|res| res.plan.len())
+        .unwrap_or_default();
+    let conflicts = (*preview)
+        .as_ref()
+        .map(|res| res.conflicts)
+        .unwrap_or_default();
+
+    html! {
+        <div class="file-renamer">
+            <div class="section">
+                <div class="section-header">
+                    <h3>{format!("{} ({})", i18n.t("file_renamer.files"), files.len())}</h3>
+                    <div class="panel-actions">
+                        <button class="primary-btn" onclick={on_select_files}>
+                            {i18n.t("file_renamer.add_files")}
+                        </button>
+                        <button class="secondary-btn" onclick={on_clear} disabled={files.is_empty()}>
+                            {i18n.t("common.clear")}
+                        </button>
+                    </div>
+                </div>
+            </div>
+
+            <div class="section renamer-rules">
+                <div class="renamer-rule-row">
+                    <label>
+                        {i18n.t("file_renamer.find")}
+                        <input type="text" value={rule.find.clone()} oninput={text_field(|r, v| r.find = v)} />
+                    </label>
+                    <label>
+                        {i18n.t("file_renamer.replace")}
+                        <input type="text" value={rule.replace.clone()} oninput={text_field(|r, v| r.replace = v)} />
+                    </label>
+                    <label class="renamer-check">
+                        <input type="checkbox" checked={rule.use_regex} onchange={check_field(|r, v| r.use_regex = v)} />
+                        {i18n.t("file_renamer.use_regex")}
+                    </label>
+                    <label class="renamer-check">
+                        <input
+                            type="checkbox"
+                            checked={rule.case_insensitive}
+                            onchange={check_field(|r, v| r.case_insensitive = v)}
+                        />
+                        {i18n.t("file_renamer.case_insensitive")}
+                    </label>
+                </div>
+
+                <div class="renamer-rule-row">
+                    <label>
+                        {i18n.t("file_renamer.prefix")}
+                        <input type="text" value={rule.prefix.clone()} oninput={text_field(|r, v| r.prefix = v)} />
+                    </label>
+                    <label>
+                        {i18n.t("file_renamer.suffix")}
+                        <input type="text" value={rule.suffix.clone()} oninput={text_field(|r, v| r.suffix = v)} />
+                    </label>
+                    <label class="renamer-check">
+                        <input
+                            type="checkbox"
+                            checked={rule.lowercase_extension}
+                            onchange={check_field(|r, v| r.lowercase_extension = v)}
+                        />
+                        {i18n.t("file_renamer.lowercase_extension")}
+                    </label>
+                </div>
+
+                <div class="renamer-rule-row">
+                    <label class="renamer-check">
+                        <input type="checkbox" checked={rule.numbering.is_some()} onchange={on_numbering_toggle} />
+                        {i18n.t("file_renamer.numbering")}
+                    </label>
+                    if let Some(numbering) = rule.numbering.clone() {
+                        <label>
+                            {i18n.t("file_renamer.start")}
+                            <input
+                                type="number"
+                                min="0"
+                                value={numbering.start.to_string()}
+                                oninput={text_field(|r, v| {
+                                    if let (Some(n), Ok(start)) = (r.numbering.as_mut(), v.parse()) {
+                                        n.start = start;
+                                    }
+                                })}
+                            />
+                        </label>
+                        <label>
+                            {i18n.t("file_renamer.step")}
+                            <input
+                                type="number"
+                                min="1"
+                                value={numbering.step.to_string()}
+                                oninput={text_field(|r, v| {
+                                    if let (Some(n), Ok(step)) = (r.numbering.as_mut(), v.parse()) {
+                                        n.step = step;
+                                    }
+                                })}
+                            />
+                        </label>
+                        <label>
+                            {i18n.t("file_renamer.digits")}
+                            <input
+                                type="number"
+                                min="1"
+                                max="10"
+                                value={numbering.digits.to_string()}
+                                oninput={text_field(|r, v| {
+                                    if let (Some(n), Ok(digits)) = (r.numbering.as_mut(), v.parse::<usize>()) {
+                                        n.digits = digits.min(10);
+                                    }
+                                })}
+                            />
+                        </label>
+                        <label class="renamer-check">
+                            <input
+                                type="checkbox"
+                                checked={numbering.zero_pad}
+                                onchange={check_field(|r, v| {
+                                    if let Some(n) = r.numbering.as_mut() {
+                                        n.zero_pad = v;
+                                    }
+                                })}
+                            />
+                            {i18n.t("file_renamer.zero_pad")}
+                        </label>
+                        <select onchange={select_field(|r, v| {
+                            if let Some(n) = r.numbering.as_mut() {
+                                n.position = v;
+                            }
+                        })}>
+                            {position_options(&numbering.position)}
+                        </select>
+                    }
+                </div>
+
+                <div class="renamer-rule-row">
+                    <label class="renamer-check">
+                        <input type="checkbox" checked={rule.date.is_some()} onchange={on_date_toggle} />
+                        {i18n.t("file_renamer.insert_date")}
+                    </label>
+                    if let Some(date) = rule.date.clone() {
+                        <label>
+                            {i18n.t("file_renamer.date_format")}
+                            <input
+                                type="text"
+                                value={date.format.clone()}
+                                oninput={text_field(|r, v| {
+                                    if let Some(d) = r.date.as_mut() {
+                                        d.format = v;
+                                    }
+                                })}
+                            />
+                        </label>
+                        <select onchange={select_field(|r, v| {
+                            if let Some(d) = r.date.as_mut() {
+                                d.position = v;
+                            }
+                        })}>
+                            {position_options(&date.position)}
+                        </select>
+                    }
+                </div>
+            </div>
+
+            if let Some(msg) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{format!("⚠ {}", msg)}</div>
+                </div>
+            }
+
+            if let Some(res) = (*preview).clone() {
+                <div class="section">
+                    <div class="section-header">
+                        <h3>{i18n.t("file_renamer.preview")}</h3>
+                        <div class="panel-actions">
+                            <span class="renamer-summary">
+                                {format!("{} {}", changed_count, i18n.t("file_renamer.changes"))}
+                                if conflicts > 0 {
+                                    <span class="renamer-conflict-count">
+                                        {format!(" / {} {}", conflicts, i18n.t("file_renamer.conflicts"))}
+                                    </span>
+                                }
+                            </span>
+                            <button
+                                class="primary-btn"
+                                onclick={on_apply}
+                                disabled={*is_processing || conflicts > 0 || changed_count == 0}
+                            >
+                                {i18n.t("file_renamer.apply")}
+                            </button>
+                            <button
+                                class="secondary-btn"
+                                onclick={on_undo}
+                                disabled={*is_processing || undo_plan.is_empty()}
+                            >
+                                {i18n.t("file_renamer.undo")}
+                            </button>
+                        </div>
+                    </div>
+                    if let Some(msg) = (*message).clone() {
+                        <p class="renamer-message">{msg}</p>
+                    }
+                    if conflicts > 0 {
+                        <p class="renamer-warning">{format!("⚠ {}", i18n.t("file_renamer.conflict_warning"))}</p>
+                    }
+                    if let Some(msg) = res.error.clone() {
+                        <p class="renamer-warning">{format!("⚠ {}", msg)}</p>
+                    }
+                    <table class="renamer-table">
+                        <thead>
+                            <tr>
+                                <th>{i18n.t("file_renamer.old_name")}</th>
+                                <th>{i18n.t("file_renamer.new_name")}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for res.items.iter().map(|item| html! {
+                                <tr
+                                    key={item.from.clone()}
+                                    title={item.from.clone()}
+                                    class={classes!(
+                                        item.changed.then_some("changed"),
+                                        item.conflict.then_some("conflict"),
+                                        item.error.is_some().then_some("invalid")
+                                    )}
+                                >
+                                    <td>{item.old_name.clone()}</td>
+                                    <td>
+                                        {item.new_name.clone()}
+                                        if let Some(msg) = item.error.clone() {
+                                            <span class="renamer-item-error">{msg}</span>
+                                        }
+                                    </td>
+                                </tr>
+                            }) }
+                        </tbody>
+                    </table>
+                </div>
+            }
+        </div>
+    }
+}
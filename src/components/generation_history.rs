@@ -0,0 +1,151 @@
+use crate::components::clipboard_history::{copy_sensitive_text, format_time};
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+/// Entries shown in the list; the backend keeps up to 1000 per tool
+const DISPLAY_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct GenerationEntry {
+    value: String,
+    created_at: String,
+}
+
+#[derive(Serialize)]
+struct AppendArgs<'a> {
+    tool: &'a str,
+    entries: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct GetArgs<'a> {
+    tool: &'a str,
+    limit: u32,
+}
+
+#[derive(Serialize)]
+struct ToolArgs<'a> {
+    tool: &'a str,
+}
+
+/// Save generated values to the tool's history
+pub async fn append_generation_history(tool: &str, entries: Vec<String>) {
+    if entries.is_empty() {
+        return;
+    }
+    let args = serde_wasm_bindgen::to_value(&AppendArgs { tool, entries }).unwrap();
+    let _ = invoke("append_history_cmd", args).await;
+}
+
+async fn fetch_entries(tool: &str) -> Vec<GenerationEntry> {
+    let args = serde_wasm_bindgen::to_value(&GetArgs {
+        tool,
+        limit: DISPLAY_LIMIT,
+    })
+    .unwrap();
+    serde_wasm_bindgen::from_value(invoke("get_history_cmd", args).await).unwrap_or_default()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GenerationHistoryProps {
+    /// History file name on the backend, e.g. `uuid_generator`
+    pub tool: AttrValue,
+    /// Bumped by the parent after it appends, to reload the list
+    #[prop_or_default]
+    pub refresh: u32,
+}
+
+/// "Previously generated" section listing saved values, newest first
+#[function_component(GenerationHistory)]
+pub fn generation_history(props: &GenerationHistoryProps) -> Html {
+    let (i18n, _) = use_translation();
+    let entries = use_state(Vec::<GenerationEntry>::new);
+    let copied = use_state(|| Option::<usize>::None);
+
+    {
+        let entries = entries.clone();
+        use_effect_with((props.tool.clone(), props.refresh), move |(tool, _)| {
+            let tool = tool.clone();
+            spawn_local(async move {
+                entries.set(fetch_entries(&tool).await);
+            });
+            || {}
+        });
+    }
+
+    let on_clear = {
+        let entries = entries.clone();
+        let tool = props.tool.clone();
+        Callback::from(move |_| {
+            let entries = entries.clone();
+            let tool = tool.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&ToolArgs { tool: &tool }).unwrap();
+                invoke("clear_history_cmd", args).await;
+                entries.set(fetch_entries(&tool).await);
+            });
+        })
+    };
+
+    if entries.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="section generation-history-section">
+            <div class="uuid-results-header">
+                <h3>{format!("{} ({})", i18n.t("generation_history.title"), entries.len())}</h3>
+                <button class="secondary-btn" onclick={on_clear}>
+                    {i18n.t("generation_history.clear")}
+                </button>
+            </div>
+            <div class="uuid-list generation-history-list">
+                { for entries.iter().enumerate().map(|(index, entry)| {
+                    let on_copy = {
+                        let copied = copied.clone();
+                        let value = entry.value.clone();
+                        Callback::from(move |_| {
+                            let copied = copied.clone();
+                            let value = value.clone();
+                            spawn_local(async move {
+                                copy_sensitive_text(&value).await;
+                                copied.set(Some(index));
+                                let copied_reset = copied.clone();
+                                gloo_timers::callback::Timeout::new(2000, move || {
+                                    copied_reset.set(None);
+                                })
+                                .forget();
+                            });
+                        })
+                    };
+                    let is_copied = *copied == Some(index);
+                    html! {
+                        <div class="uuid-item">
+                            <code class="uuid-value">{&entry.value}</code>
+                            <span class="uuid-timestamp">{format_time(&entry.created_at)}</span>
+                            <button
+                                class={classes!("copy-btn", is_copied.then_some("copied"))}
+                                onclick={on_copy}
+                            >
+                                if is_copied {
+                                    {"✓"}
+                                } else {
+                                    {"📋"}
+                                }
+                            </button>
+                        </div>
+                    }
+                })}
+            </div>
+        </div>
+    }
+}
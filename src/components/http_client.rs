@@ -0,0 +1,427 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+const METHODS: [&str; 7] = ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HttpRequestSpec {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    timeout_secs: Option<u64>,
+    follow_redirects: bool,
+    allow_invalid_certs: bool,
+}
+
+#[derive(Serialize)]
+struct SendArgs {
+    request: HttpRequestSpec,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HttpResponseResult {
+    success: bool,
+    status: u16,
+    status_text: String,
+    headers: Vec<(String, String)>,
+    body: String,
+    body_is_base64: bool,
+    formatted_json: Option<String>,
+    final_url: String,
+    duration_ms: u64,
+    size_bytes: u64,
+    truncated: bool,
+    error: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ResponseView {
+    Body,
+    Headers,
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn status_class(status: u16) -> &'static str {
+    match status {
+        200..=299 => "http-status success",
+        300..=399 => "http-status redirect",
+        _ => "http-status failure",
+    }
+}
+
+#[function_component(HttpClient)]
+pub fn http_client() -> Html {
+    let (i18n, _) = use_translation();
+    let method = use_state(|| "GET".to_string());
+    let url = use_state(String::new);
+    let headers = use_state(|| vec![(String::new(), String::new())]);
+    let body = use_state(String::new);
+    let timeout_secs = use_state(|| 30u64);
+    let follow_redirects = use_state(|| true);
+    let allow_invalid_certs = use_state(|| false);
+    let is_sending = use_state(|| false);
+    let response = use_state(|| Option::<HttpResponseResult>::None);
+    let view = use_state(|| ResponseView::Body);
+    let copied = use_state(|| false);
+
+    let has_body = !matches!(method.as_str(), "GET" | "HEAD");
+
+    let on_method_change = {
+        let method = method.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            method.set(select.value());
+        })
+    };
+
+    let on_url_input = {
+        let url = url.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            url.set(input.value());
+        })
+    };
+
+    let on_body_input = {
+        let body = body.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            body.set(input.value());
+        })
+    };
+
+    let on_timeout_change = {
+        let timeout_secs = timeout_secs.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(secs) = input.value().parse::<u64>() {
+                timeout_secs.set(secs.max(1));
+            }
+        })
+    };
+
+    let on_toggle_redirects = {
+        let follow_redirects = follow_redirects.clone();
+        Callback::from(move |_| follow_redirects.set(!*follow_redirects))
+    };
+
+    let on_toggle_invalid_certs = {
+        let allow_invalid_certs = allow_invalid_certs.clone();
+        Callback::from(move |_| allow_invalid_certs.set(!*allow_invalid_certs))
+    };
+
+    // Edits one side of a header row: `key` when `is_name`, otherwise the value
+    let on_header_input = {
+        let headers = headers.clone();
+        move |index: usize, is_name: bool| {
+            let headers = headers.clone();
+            Callback::from(move |e: InputEvent| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let mut next = (*headers).clone();
+                if let Some((name, value)) = next.get_mut(index) {
+                    if is_name {
+                        *name = input.value();
+                    } else {
+                        *value = input.value();
+                    }
+                }
+                headers.set(next);
+            })
+        }
+    };
+
+    let on_add_header = {
+        let headers = headers.clone();
+        Callback::from(move |_| {
+            let mut next = (*headers).clone();
+            next.push((String::new(), String::new()));
+            headers.set(next);
+        })
+    };
+
+    let on_remove_header = {
+        let headers = headers.clone();
+        move |index: usize| {
+            let headers = headers.clone();
+            Callback::from(move |_| {
+                let mut next = (*headers).clone();
+                next.remove(index);
+                headers.set(next);
+            })
+        }
+    };
+
+    let on_send = {
+        let method = method.clone();
+        let url = url.clone();
+        let headers = headers.clone();
+        let body = body.clone();
+        let timeout_secs = timeout_secs.clone();
+        let follow_redirects = follow_redirects.clone();
+        let allow_invalid_certs = allow_invalid_certs.clone();
+        let is_sending = is_sending.clone();
+        let response = response.clone();
+        Callback::from(move |_| {
+            if url.trim().is_empty() || *is_sending {
+                return;
+            }
+            let request = HttpRequestSpec {
+                method: (*method).clone(),
+                url: url.trim().to_string(),
+                // Rows left blank are placeholders, not headers
+                headers: headers
+                    .iter()
+                    .filter(|(name, _)| !name.trim().is_empty())
+                    .cloned()
+                    .collect(),
+                body: (has_body && !body.is_empty()).then(|| (*body).clone()),
+                timeout_secs: Some(*timeout_secs),
+                follow_redirects: *follow_redirects,
+                allow_invalid_certs: *allow_invalid_certs,
+            };
+            let is_sending = is_sending.clone();
+            let response = response.clone();
+            is_sending.set(true);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&SendArgs { request }).unwrap();
+                let result = invoke("send_http_request_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<HttpResponseResult>(result) {
+                    response.set(Some(res));
+                }
+                is_sending.set(false);
+            });
+        })
+    };
+
+    let on_url_keydown = {
+        let on_send = on_send.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                on_send.emit(());
+            }
+        })
+    };
+
+    let displayed_body = (*response).as_ref().map(|res| {
+        res.formatted_json
+            .clone()
+            .unwrap_or_else(|| res.body.clone())
+    });
+
+    let on_copy_body = {
+        let copied = copied.clone();
+        let displayed_body = displayed_body.clone().unwrap_or_default();
+        Callback::from(move |_| {
+            let copied = copied.clone();
+            let text = displayed_body.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text)).await;
+                    copied.set(true);
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let view_button = |target: ResponseView, label: String| {
+        let view = view.clone();
+        html! {
+            <button
+                class={if *view == target { "mode-btn active" } else { "mode-btn" }}
+                onclick={Callback::from(move |_| view.set(target))}
+            >
+                {label}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="http-client">
+            <div class="section">
+                <div class="http-request-line">
+                    <select class="http-method" onchange={on_method_change}>
+                        { for METHODS.iter().map(|m| html! {
+                            <option value={*m} selected={*method == *m}>{*m}</option>
+                        }) }
+                    </select>
+                    <input
+                        type="text"
+                        class="http-url"
+                        placeholder="https://api.example.com/items"
+                        value={(*url).clone()}
+                        oninput={on_url_input}
+                        onkeydown={on_url_keydown}
+                    />
+                    <button
+                        class="primary-btn http-send"
+                        onclick={on_send.reform(|_: MouseEvent| ())}
+                        disabled={url.trim().is_empty() || *is_sending}
+                    >
+                        if *is_sending {
+                            <span class="processing">
+                                <span class="spinner"></span>
+                                {i18n.t("http_client.sending")}
+                            </span>
+                        } else {
+                            {i18n.t("http_client.send")}
+                        }
+                    </button>
+                </div>
+            </div>
+
+            <div class="section">
+                <div class="section-header">
+                    <h3>{i18n.t("http_client.headers")}</h3>
+                    <button class="secondary-btn" onclick={on_add_header}>
+                        {i18n.t("http_client.add_header")}
+                    </button>
+                </div>
+                <div class="http-headers">
+                    { for headers.iter().enumerate().map(|(i, (name, value))| html! {
+                        <div class="http-header-row">
+                            <input
+                                type="text"
+                                placeholder="Content-Type"
+                                value={name.clone()}
+                                oninput={on_header_input(i, true)}
+                            />
+                            <input
+                                type="text"
+                                placeholder="application/json"
+                                value={value.clone()}
+                                oninput={on_header_input(i, false)}
+                            />
+                            <button class="move-btn" onclick={on_remove_header(i)}>{"✕"}</button>
+                        </div>
+                    }) }
+                </div>
+            </div>
+
+            if has_body {
+                <div class="section">
+                    <h3>{i18n.t("http_client.body")}</h3>
+                    <textarea
+                        class="http-body-input"
+                        placeholder="{\"key\": \"value\"}"
+                        value={(*body).clone()}
+                        oninput={on_body_input}
+                    />
+                </div>
+            }
+
+            <div class="section http-options">
+                <label class="http-timeout">
+                    {i18n.t("http_client.timeout")}
+                    <input
+                        type="number"
+                        min="1"
+                        max="600"
+                        value={timeout_secs.to_string()}
+                        onchange={on_timeout_change}
+                    />
+                </label>
+                <label class="toolbar-checkbox">
+                    <input type="checkbox" checked={*follow_redirects} onchange={on_toggle_redirects} />
+                    {i18n.t("http_client.follow_redirects")}
+                </label>
+                <label class="toolbar-checkbox">
+                    <input type="checkbox" checked={*allow_invalid_certs} onchange={on_toggle_invalid_certs} />
+                    {i18n.t("http_client.allow_invalid_certs")}
+                </label>
+            </div>
+
+            if let Some(res) = (*response).clone() {
+                if res.success {
+                    <div class="section">
+                        <div class="section-header">
+                            <div class="http-response-summary">
+                                <span class={status_class(res.status)}>
+                                    {format!("{} {}", res.status, res.status_text)}
+                                </span>
+                                <span class="http-meta">{format!("{} ms", res.duration_ms)}</span>
+                                <span class="http-meta">{format_size(res.size_bytes)}</span>
+                            </div>
+                            <div class="mode-toggle http-view-toggle">
+                                {view_button(ResponseView::Body, i18n.t("http_client.body"))}
+                                {view_button(
+                                    ResponseView::Headers,
+                                    format!("{} ({})", i18n.t("http_client.headers"), res.headers.len())
+                                )}
+                            </div>
+                        </div>
+                        if res.final_url != url.trim() && !res.final_url.is_empty() {
+                            <p class="http-note">{format!("→ {}", res.final_url)}</p>
+                        }
+                        if *view == ResponseView::Body {
+                            if res.body_is_base64 {
+                                <p class="http-note">{i18n.t("http_client.binary_body")}</p>
+                            }
+                            if res.truncated {
+                                <p class="http-note">{i18n.t("http_client.truncated")}</p>
+                            }
+                            <div class="http-body-actions">
+                                <button
+                                    class={classes!("move-btn", copied.then_some("copied"))}
+                                    onclick={on_copy_body}
+                                >
+                                    if *copied {
+                                        {format!("✓ {}", i18n.t("common.copied"))}
+                                    } else {
+                                        {i18n.t("common.copy")}
+                                    }
+                                </button>
+                            </div>
+                            <pre class="http-response-body">{displayed_body.unwrap_or_default()}</pre>
+                        } else {
+                            <table class="http-response-headers">
+                                <tbody>
+                                    { for res.headers.iter().map(|(name, value)| html! {
+                                        <tr>
+                                            <th>{name}</th>
+                                            <td>{value}</td>
+                                        </tr>
+                                    }) }
+                                </tbody>
+                            </table>
+                        }
+                    </div>
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {format!("⚠ {}", res.error.clone().unwrap_or_default())}
+                        </div>
+                    </div>
+                }
+            }
+        </div>
+    }
+}
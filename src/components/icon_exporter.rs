@@ -0,0 +1,498 @@
+use crate::components::settings::SettingsContext;
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+/// Extensions accepted as export input
+const INPUT_EXTENSIONS: [&str; 11] = [
+    "png", "jpg", "jpeg", "gif", "webp", "avif", "bmp", "tif", "tiff", "heic", "heif",
+];
+
+/// (value, label) for the custom size set
+const OUTPUT_FORMATS: [(&str, &str); 5] = [
+    ("png", "PNG"),
+    ("jpeg", "JPEG"),
+    ("webp", "WebP"),
+    ("avif", "AVIF"),
+    ("ico", "ICO"),
+];
+
+const CUSTOM_PRESET: &str = "custom";
+const DEFAULT_SIZES: &str = "16, 32, 64, 128, 256, 512";
+const DEFAULT_TEMPLATE: &str = "icon_{size}.png";
+
+#[derive(Serialize)]
+struct OpenFileOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct OpenDirectoryOptions {
+    directory: bool,
+    #[serde(rename = "defaultPath")]
+    default_path: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum SizeFit {
+    #[default]
+    SquareCrop,
+    LongEdge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum UpscaleMode {
+    #[default]
+    Upscale,
+    Skip,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum NamingPattern {
+    Template { template: String },
+    FileNames { names: Vec<String> },
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SizePreset {
+    id: String,
+    name: String,
+    sizes: Vec<u32>,
+    format: String,
+    fit: SizeFit,
+    naming: NamingPattern,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MultiExportOptions {
+    fit: SizeFit,
+    upscale: UpscaleMode,
+    quality: Option<u8>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportArgs {
+    input_path: String,
+    output_dir: String,
+    sizes: Vec<u32>,
+    format: String,
+    naming: NamingPattern,
+    options: MultiExportOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MultiExportItem {
+    width: u32,
+    height: u32,
+    output_path: String,
+    file_size: u64,
+    success: bool,
+    skipped: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MultiExportResult {
+    success: bool,
+    items: Vec<MultiExportItem>,
+    warnings: Vec<String>,
+    error: Option<String>,
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+/// Sizes typed as "16, 32 64", or None when any entry is not a positive number
+fn parse_sizes(text: &str) -> Option<Vec<u32>> {
+    let sizes: Vec<u32> = text
+        .split([',', ' ', '\n'])
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| part.trim().parse::<u32>().ok().filter(|size| *size > 0))
+        .collect::<Option<_>>()?;
+    (!sizes.is_empty()).then_some(sizes)
+}
+
+#[function_component(IconExporter)]
+pub fn icon_exporter() -> Html {
+    let (i18n, _) = use_translation();
+    let settings = use_context::<SettingsContext>().map(|ctx| ctx.settings);
+    let input_path = use_state(|| Option::<String>::None);
+    let presets = use_state(Vec::<SizePreset>::new);
+    let preset_id = use_state(|| CUSTOM_PRESET.to_string());
+    let sizes_text = use_state(|| DEFAULT_SIZES.to_string());
+    let template = use_state(|| DEFAULT_TEMPLATE.to_string());
+    let output_format = use_state(|| "png".to_string());
+    let fit = use_state(SizeFit::default);
+    let upscale = use_state(UpscaleMode::default);
+    let output_dir = use_state(|| Option::<String>::None);
+    let is_processing = use_state(|| false);
+    let result = use_state(|| Option::<MultiExportResult>::None);
+
+    {
+        let presets = presets.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let response = invoke("list_size_presets_cmd", JsValue::NULL).await;
+                if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<SizePreset>>(response) {
+                    presets.set(list);
+                }
+            });
+            || {}
+        });
+    }
+
+    let selected_preset = presets.iter().find(|p| p.id == *preset_id).cloned();
+
+    let on_select_input = {
+        let input_path = input_path.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            let input_path = input_path.clone();
+            let result = result.clone();
+            spawn_local(async move {
+                let options = OpenFileOptions {
+                    multiple: false,
+                    filters: vec![FileFilter {
+                        name: "Images".to_string(),
+                        extensions: INPUT_EXTENSIONS.iter().map(|ext| ext.to_string()).collect(),
+                    }],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(path) = open(opts).await.as_string() {
+                    input_path.set(Some(path));
+                    result.set(None);
+                }
+            });
+        })
+    };
+
+    let on_select_output = {
+        let output_dir = output_dir.clone();
+        let default_dir = settings.and_then(|s| s.default_output_dir);
+        Callback::from(move |_| {
+            let output_dir = output_dir.clone();
+            let default_path = (*output_dir).clone().or_else(|| default_dir.clone());
+            spawn_local(async move {
+                let options = OpenDirectoryOptions {
+                    directory: true,
+                    default_path,
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(path) = open(opts).await.as_string() {
+                    output_dir.set(Some(path));
+                }
+            });
+        })
+    };
+
+    let on_preset_change = {
+        let preset_id = preset_id.clone();
+        let presets = presets.clone();
+        let fit = fit.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let id = select.value();
+            if let Some(preset) = presets.iter().find(|p| p.id == id) {
+                fit.set(preset.fit);
+            }
+            preset_id.set(id);
+        })
+    };
+
+    let on_sizes_input = {
+        let sizes_text = sizes_text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            sizes_text.set(input.value());
+        })
+    };
+
+    let on_template_input = {
+        let template = template.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            template.set(input.value());
+        })
+    };
+
+    let on_format_change = {
+        let output_format = output_format.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            output_format.set(select.value());
+        })
+    };
+
+    let parsed_sizes = match &selected_preset {
+        Some(preset) => Some(preset.sizes.clone()),
+        None => parse_sizes(&sizes_text),
+    };
+
+    let on_export = {
+        let input_path = input_path.clone();
+        let output_dir = output_dir.clone();
+        let selected_preset = selected_preset.clone();
+        let parsed_sizes = parsed_sizes.clone();
+        let template = template.clone();
+        let output_format = output_format.clone();
+        let fit = fit.clone();
+        let upscale = upscale.clone();
+        let is_processing = is_processing.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            let (Some(input_path), Some(output_dir), Some(sizes)) = (
+                (*input_path).clone(),
+                (*output_dir).clone(),
+                parsed_sizes.clone(),
+            ) else {
+                return;
+            };
+            let (format, naming) = match &selected_preset {
+                Some(preset) => (preset.format.clone(), preset.naming.clone()),
+                None => (
+                    (*output_format).clone(),
+                    NamingPattern::Template {
+                        template: (*template).clone(),
+                    },
+                ),
+            };
+            let args = ExportArgs {
+                input_path,
+                output_dir,
+                sizes,
+                format,
+                naming,
+                options: MultiExportOptions {
+                    fit: *fit,
+                    upscale: *upscale,
+                    quality: None,
+                },
+            };
+            let is_processing = is_processing.clone();
+            let result = result.clone();
+            spawn_local(async move {
+                is_processing.set(true);
+                result.set(None);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let response = invoke("export_multiple_sizes_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<MultiExportResult>(response) {
+                    result.set(Some(res));
+                }
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let can_export =
+        input_path.is_some() && output_dir.is_some() && parsed_sizes.is_some() && !*is_processing;
+
+    html! {
+        <div class="icon-exporter">
+            <div class="section">
+                <div class="drop-zone" onclick={on_select_input}>
+                    if let Some(path) = (*input_path).clone() {
+                        <p class="drop-zone-text">{file_name(&path).to_string()}</p>
+                        <p class="drop-zone-hint">{path}</p>
+                    } else {
+                        <p class="drop-zone-text">{i18n.t("icon_exporter.select_image")}</p>
+                        <p class="drop-zone-hint">{i18n.t("icon_exporter.square_hint")}</p>
+                    }
+                </div>
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("icon_exporter.preset")}</h3>
+                <select class="icon-exporter-select" onchange={on_preset_change}>
+                    <option value={CUSTOM_PRESET} selected={*preset_id == CUSTOM_PRESET}>
+                        {i18n.t("icon_exporter.custom")}
+                    </option>
+                    { for presets.iter().map(|preset| html! {
+                        <option value={preset.id.clone()} selected={*preset_id == preset.id}>
+                            {preset.name.clone()}
+                        </option>
+                    }) }
+                </select>
+
+                if let Some(preset) = &selected_preset {
+                    <ul class="icon-exporter-preview">
+                        { for preset.sizes.iter().enumerate().map(|(index, size)| {
+                            let name = match &preset.naming {
+                                NamingPattern::FileNames { names } => names.get(index).cloned().unwrap_or_default(),
+                                NamingPattern::Template { template } => template.replace("{size}", &size.to_string()),
+                            };
+                            html! {
+                                <li>
+                                    <span class="icon-exporter-name">{name}</span>
+                                    <span class="icon-exporter-dimensions">{format!("{}px", size)}</span>
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                    if preset.id == "tauri" {
+                        <p class="icon-exporter-hint">{i18n.t("icon_exporter.icns_hint")}</p>
+                    }
+                } else {
+                    <div class="icon-exporter-field">
+                        <label>{i18n.t("icon_exporter.sizes")}</label>
+                        <input
+                            type="text"
+                            class={classes!("icon-exporter-input", parsed_sizes.is_none().then_some("invalid"))}
+                            value={(*sizes_text).clone()}
+                            oninput={on_sizes_input}
+                            placeholder={DEFAULT_SIZES}
+                        />
+                    </div>
+                    <div class="icon-exporter-field">
+                        <label>{i18n.t("icon_exporter.file_name")}</label>
+                        <input
+                            type="text"
+                            class="icon-exporter-input"
+                            value={(*template).clone()}
+                            oninput={on_template_input}
+                            placeholder={DEFAULT_TEMPLATE}
+                        />
+                        <p class="icon-exporter-hint">{i18n.t("icon_exporter.template_hint")}</p>
+                    </div>
+                    <div class="icon-exporter-field">
+                        <label>{i18n.t("icon_exporter.format")}</label>
+                        <select class="icon-exporter-select" onchange={on_format_change}>
+                            { for OUTPUT_FORMATS.iter().map(|(value, label)| html! {
+                                <option value={*value} selected={*output_format == *value}>{*label}</option>
+                            }) }
+                        </select>
+                    </div>
+                }
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("icon_exporter.options")}</h3>
+                <div class="mode-toggle">
+                    <button
+                        class={classes!("mode-btn", (*fit == SizeFit::SquareCrop).then_some("active"))}
+                        onclick={{ let fit = fit.clone(); Callback::from(move |_| fit.set(SizeFit::SquareCrop)) }}
+                    >
+                        {i18n.t("icon_exporter.square_crop")}
+                    </button>
+                    <button
+                        class={classes!("mode-btn", (*fit == SizeFit::LongEdge).then_some("active"))}
+                        onclick={{ let fit = fit.clone(); Callback::from(move |_| fit.set(SizeFit::LongEdge)) }}
+                    >
+                        {i18n.t("icon_exporter.long_edge")}
+                    </button>
+                </div>
+                <label class="checkbox-option-inline">
+                    <input
+                        type="checkbox"
+                        checked={*upscale == UpscaleMode::Skip}
+                        onchange={{
+                            let upscale = upscale.clone();
+                            Callback::from(move |e: Event| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                upscale.set(if input.checked() { UpscaleMode::Skip } else { UpscaleMode::Upscale });
+                            })
+                        }}
+                    />
+                    {i18n.t("icon_exporter.skip_upscale")}
+                </label>
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("icon_exporter.output_folder")}</h3>
+                <div class="icon-exporter-output">
+                    <button class="secondary-btn" onclick={on_select_output}>
+                        {i18n.t("icon_exporter.choose_folder")}
+                    </button>
+                    if let Some(dir) = (*output_dir).clone() {
+                        <span class="output-path">{format!("📁 {}", dir)}</span>
+                    }
+                </div>
+            </div>
+
+            <button class="primary-btn" onclick={on_export} disabled={!can_export}>
+                if *is_processing {
+                    <span class="processing">
+                        <span class="spinner"></span>
+                        {i18n.t("icon_exporter.exporting")}
+                    </span>
+                } else {
+                    {i18n.t("icon_exporter.export")}
+                }
+            </button>
+
+            if let Some(res) = (*result).clone() {
+                <div class="section">
+                    <h3>{i18n.t("common.results")}</h3>
+                    if let Some(error) = &res.error {
+                        <p class="error-message">{format!("⚠ {}", error)}</p>
+                    }
+                    { for res.warnings.iter().map(|warning| html! {
+                        <p class="icon-exporter-warning">{format!("⚠ {}", warning)}</p>
+                    }) }
+                    <ul class="icon-exporter-results">
+                        { for res.items.iter().map(|item| html! {
+                            <li class={classes!(
+                                "icon-exporter-result",
+                                (!item.success && !item.skipped).then_some("failed"),
+                                item.skipped.then_some("skipped")
+                            )}>
+                                <span class="icon-exporter-name">{file_name(&item.output_path).to_string()}</span>
+                                <span class="icon-exporter-dimensions">
+                                    {format!("{}×{}", item.width, item.height)}
+                                </span>
+                                <span class="icon-exporter-status">
+                                    if item.skipped {
+                                        {i18n.t("icon_exporter.skipped")}
+                                    } else if item.success {
+                                        {format_size(item.file_size)}
+                                    } else {
+                                        {item.error.clone().unwrap_or_default()}
+                                    }
+                                </span>
+                            </li>
+                        }) }
+                    </ul>
+                    if res.success {
+                        <p class="output-path">
+                            {format!("📁 {}", (*output_dir).clone().unwrap_or_default())}
+                        </p>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
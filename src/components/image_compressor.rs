@@ -1,3 +1,4 @@
+use crate::components::settings::SettingsContext;
 use i18nrs::yew::use_translation;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
@@ -32,6 +33,20 @@ pub struct CompressionResult {
     pub compressed_size: u64,
     pub compression_ratio: f64,
     pub error: Option<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    #[serde(default)]
+    pub target: Option<TargetSizeInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetSizeInfo {
+    pub target_bytes: u64,
+    pub quality: Option<u8>,
+    pub color_bits: Option<u8>,
+    pub scale: f64,
+    pub attempts: u32,
+    pub reached: bool,
 }
 
 #[derive(Serialize)]
@@ -71,6 +86,17 @@ struct CompressImageArgs {
     output_format: String,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CompressToTargetSizeArgs {
+    input_path: String,
+    output_path: String,
+    target_bytes: u64,
+    format: String,
+    min_quality: u8,
+    allow_resize: bool,
+}
+
 fn format_size(bytes: u64) -> String {
     if bytes >= 1_048_576 {
         format!("{:.1} MB", bytes as f64 / 1_048_576.0)
@@ -96,8 +122,27 @@ pub fn image_compressor(props: &ImageCompressorProps) -> Html {
     let output_format = use_state(|| "avif".to_string());
     let custom_width = use_state(|| Option::<u32>::None);
     let custom_height = use_state(|| Option::<u32>::None);
+    // Target size mode: search the quality for a file size instead of using the slider value
+    let target_mode = use_state(|| false);
+    let target_kb = use_state(|| 500u64);
+    let allow_resize = use_state(|| true);
     let compression_result = use_state(|| Option::<CompressionResult>::None);
     let is_processing = use_state(|| false);
+    let settings = use_context::<SettingsContext>().map(|ctx| ctx.settings);
+
+    // Start from the saved defaults, and follow them when they are changed in settings
+    {
+        let quality = quality.clone();
+        let output_format = output_format.clone();
+        let defaults = settings.as_ref().map(|s| s.image_compressor.clone());
+        use_effect_with(defaults, move |defaults| {
+            if let Some(defaults) = defaults {
+                quality.set(defaults.quality);
+                output_format.set(defaults.output_format.clone());
+            }
+            || {}
+        });
+    }
 
     // Handle dropped file
     {
@@ -180,8 +225,12 @@ pub fn image_compressor(props: &ImageCompressorProps) -> Html {
         let output_format = output_format.clone();
         let custom_width = custom_width.clone();
         let custom_height = custom_height.clone();
+        let target_mode = target_mode.clone();
+        let target_kb = target_kb.clone();
+        let allow_resize = allow_resize.clone();
         let compression_result = compression_result.clone();
         let is_processing = is_processing.clone();
+        let settings = settings.clone();
 
         Callback::from(move |_| {
             let input_path_val = (*input_path).clone();
@@ -193,36 +242,56 @@ pub fn image_compressor(props: &ImageCompressorProps) -> Html {
             let format_val = (*output_format).clone();
             let width_val = *custom_width;
             let height_val = *custom_height;
+            let target_bytes = target_mode.then(|| *target_kb * 1024);
+            let allow_resize_val = *allow_resize;
             let compression_result = compression_result.clone();
             let is_processing = is_processing.clone();
+            let settings = settings.clone();
 
             is_processing.set(true);
 
             spawn_local(async move {
                 let ext = format_val.clone();
                 let default_name = format!("compressed.{}", ext);
+                let default_path = match &settings {
+                    Some(settings) => settings.output_path(&default_name),
+                    None => default_name,
+                };
 
                 let save_options = SaveDialogOptions {
                     filters: vec![FileFilter {
                         name: format!("{} Image", ext.to_uppercase()),
                         extensions: vec![ext.clone()],
                     }],
-                    default_path: Some(default_name),
+                    default_path: Some(default_path),
                 };
                 let save_options_js = serde_wasm_bindgen::to_value(&save_options).unwrap();
                 let save_result = save(save_options_js).await;
 
                 if let Some(output_path) = save_result.as_string() {
-                    let args = CompressImageArgs {
-                        input_path: input_path_val,
-                        output_path,
-                        quality: quality_val,
-                        width: width_val,
-                        height: height_val,
-                        output_format: format_val,
+                    let result = if let Some(target_bytes) = target_bytes {
+                        let args = CompressToTargetSizeArgs {
+                            input_path: input_path_val,
+                            output_path,
+                            target_bytes,
+                            format: format_val,
+                            min_quality: quality_val,
+                            allow_resize: allow_resize_val,
+                        };
+                        let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                        invoke("compress_to_target_size_cmd", args_js).await
+                    } else {
+                        let args = CompressImageArgs {
+                            input_path: input_path_val,
+                            output_path,
+                            quality: quality_val,
+                            width: width_val,
+                            height: height_val,
+                            output_format: format_val,
+                        };
+                        let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                        invoke("compress_image_cmd", args_js).await
                     };
-                    let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
-                    let result = invoke("compress_image_cmd", args_js).await;
 
                     if let Ok(res) = serde_wasm_bindgen::from_value::<CompressionResult>(result) {
                         compression_result.set(Some(res));
@@ -277,6 +346,28 @@ pub fn image_compressor(props: &ImageCompressorProps) -> Html {
         })
     };
 
+    let on_target_mode_toggle = {
+        let target_mode = target_mode.clone();
+        Callback::from(move |_| target_mode.set(!*target_mode))
+    };
+
+    let on_target_kb_change = {
+        let target_kb = target_kb.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(kb) = input.value().parse::<u64>() {
+                if kb > 0 {
+                    target_kb.set(kb);
+                }
+            }
+        })
+    };
+
+    let on_allow_resize_toggle = {
+        let allow_resize = allow_resize.clone();
+        Callback::from(move |_| allow_resize.set(!*allow_resize))
+    };
+
     let formats = vec![
         ("avif", "AVIF", Some("Best")),
         ("webp", "WebP", Some("Good")),
@@ -377,7 +468,47 @@ pub fn image_compressor(props: &ImageCompressorProps) -> Html {
             </div>
 
             <div class="section">
-                <h3>{i18n.t("image_compressor.quality")}</h3>
+                <h3>{i18n.t("image_compressor.target_size")}</h3>
+                <div class="target-size-options">
+                    <label class="checkbox-option-inline">
+                        <input type="checkbox" checked={*target_mode} onchange={on_target_mode_toggle} />
+                        <span>{i18n.t("image_compressor.target_size_enable")}</span>
+                    </label>
+                    {if *target_mode {
+                        html! {
+                            <>
+                                <div class="resize-inputs">
+                                    <input
+                                        type="number"
+                                        min="1"
+                                        value={target_kb.to_string()}
+                                        oninput={on_target_kb_change}
+                                    />
+                                    <span>{"KB"}</span>
+                                </div>
+                                <label class="checkbox-option-inline">
+                                    <input type="checkbox" checked={*allow_resize} onchange={on_allow_resize_toggle} />
+                                    <span>{i18n.t("image_compressor.allow_resize")}</span>
+                                </label>
+                                {if *output_format == "png" {
+                                    html! { <p class="target-size-hint">{i18n.t("image_compressor.png_target_hint")}</p> }
+                                } else {
+                                    html! {}
+                                }}
+                            </>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </div>
+
+            <div class="section">
+                <h3>{if *target_mode {
+                    i18n.t("image_compressor.min_quality")
+                } else {
+                    i18n.t("image_compressor.quality")
+                }}</h3>
                 <div class="quality-slider">
                     <input
                         type="range"
@@ -390,7 +521,7 @@ pub fn image_compressor(props: &ImageCompressorProps) -> Html {
                 </div>
             </div>
 
-            <div class="section">
+            <div class="section" hidden={*target_mode}>
                 <h3>{i18n.t("image_compressor.resize_optional")}</h3>
                 <div class="resize-inputs">
                     <input
@@ -447,6 +578,32 @@ pub fn image_compressor(props: &ImageCompressorProps) -> Html {
                                             <div class="result-stat-value saved">{format!("{:.1}%", result.compression_ratio)}</div>
                                         </div>
                                     </div>
+                                    {if let Some(target) = &result.target {
+                                        let setting = match (target.quality, target.color_bits) {
+                                            (Some(q), _) => format!("{} {}%", i18n.t("image_compressor.quality"), q),
+                                            (None, Some(bits)) => i18n
+                                                .t("image_compressor.color_bits")
+                                                .replace("{n}", &bits.to_string()),
+                                            (None, None) => String::new(),
+                                        };
+                                        html! {
+                                            <p class="target-size-summary">
+                                                {format!(
+                                                    "{} · {} {:.0}% · {}",
+                                                    setting,
+                                                    i18n.t("image_compressor.scale"),
+                                                    target.scale * 100.0,
+                                                    i18n.t("image_compressor.attempts")
+                                                        .replace("{n}", &target.attempts.to_string())
+                                                )}
+                                            </p>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }}
+                                    {for result.warnings.iter().map(|w| html! {
+                                        <p class="target-size-warning">{format!("⚠ {}", w)}</p>
+                                    })}
                                     <p class="output-path">{format!("📁 {}", result.output_path)}</p>
                                 </>
                             }
@@ -0,0 +1,412 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
+}
+
+/// Extensions accepted as conversion input
+const INPUT_EXTENSIONS: [&str; 11] = [
+    "png", "jpg", "jpeg", "gif", "webp", "avif", "bmp", "tif", "tiff", "heic", "heif",
+];
+
+/// (value, label, uses quality)
+const OUTPUT_FORMATS: [(&str, &str, bool); 8] = [
+    ("png", "PNG", false),
+    ("jpeg", "JPEG", true),
+    ("webp", "WebP", true),
+    ("avif", "AVIF", true),
+    ("gif", "GIF", false),
+    ("bmp", "BMP", false),
+    ("tiff", "TIFF", false),
+    ("ico", "ICO", false),
+];
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct OpenDirectoryOptions {
+    directory: bool,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertBatchArgs {
+    inputs: Vec<String>,
+    output_dir: String,
+    format: String,
+    quality: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CompressionResult {
+    success: bool,
+    output_path: String,
+    original_size: u64,
+    compressed_size: u64,
+    #[serde(default)]
+    warnings: Vec<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct BatchItem {
+    input_path: String,
+    result: CompressionResult,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct BatchResult {
+    items: Vec<BatchItem>,
+    total_files: usize,
+    succeeded: usize,
+    failed: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ProgressEvent {
+    payload: Progress,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Progress {
+    completed: usize,
+    total: usize,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    #[prop_or_default]
+    pub dropped_files: Option<Vec<String>>,
+    #[prop_or_default]
+    pub on_files_processed: Callback<()>,
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else {
+        format!("{} KB", bytes / 1024)
+    }
+}
+
+/// Append `paths` to the queue, skipping ones already listed
+fn add_inputs(inputs: &UseStateHandle<Vec<String>>, paths: Vec<String>) {
+    let mut next = (**inputs).clone();
+    for path in paths {
+        if !next.contains(&path) {
+            next.push(path);
+        }
+    }
+    inputs.set(next);
+}
+
+#[function_component(ImageConverter)]
+pub fn image_converter(props: &Props) -> Html {
+    let (i18n, _) = use_translation();
+    let inputs = use_state(Vec::<String>::new);
+    let output_format = use_state(|| "png".to_string());
+    let quality = use_state(|| 90u8);
+    let is_processing = use_state(|| false);
+    let progress = use_state(|| Option::<Progress>::None);
+    let result = use_state(|| Option::<BatchResult>::None);
+
+    // Handle dropped files
+    {
+        let on_files_processed = props.on_files_processed.clone();
+        let inputs = inputs.clone();
+        use_effect_with(props.dropped_files.clone(), move |dropped_files| {
+            if let Some(paths) = dropped_files.clone() {
+                add_inputs(&inputs, paths);
+                on_files_processed.emit(());
+            }
+            || {}
+        });
+    }
+
+    // Follow the backend's per-file progress while a batch runs
+    {
+        let progress = progress.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<ProgressEvent>(event) {
+                        progress.set(Some(event.payload));
+                    }
+                });
+                let _ = listen("compression-progress", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    let on_add_files = {
+        let inputs = inputs.clone();
+        Callback::from(move |_| {
+            let inputs = inputs.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: true,
+                    filters: vec![FileFilter {
+                        name: "Images".to_string(),
+                        extensions: INPUT_EXTENSIONS.iter().map(|ext| ext.to_string()).collect(),
+                    }],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                let selected = open(opts).await;
+                if let Ok(paths) = serde_wasm_bindgen::from_value::<Vec<String>>(selected) {
+                    add_inputs(&inputs, paths);
+                }
+            });
+        })
+    };
+
+    let on_clear = {
+        let inputs = inputs.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            inputs.set(Vec::new());
+            result.set(None);
+        })
+    };
+
+    let on_remove = {
+        let inputs = inputs.clone();
+        move |index: usize| {
+            let inputs = inputs.clone();
+            Callback::from(move |_| {
+                let mut next = (*inputs).clone();
+                next.remove(index);
+                inputs.set(next);
+            })
+        }
+    };
+
+    let on_quality_change = {
+        let quality = quality.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(val) = input.value().parse::<u8>() {
+                quality.set(val);
+            }
+        })
+    };
+
+    let uses_quality = OUTPUT_FORMATS
+        .iter()
+        .any(|(value, _, lossy)| *lossy && *output_format == *value);
+
+    let on_convert = {
+        let inputs = inputs.clone();
+        let output_format = output_format.clone();
+        let quality = quality.clone();
+        let is_processing = is_processing.clone();
+        let progress = progress.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            if inputs.is_empty() {
+                return;
+            }
+            let inputs = (*inputs).clone();
+            let format = (*output_format).clone();
+            let quality = uses_quality.then_some(*quality);
+            let is_processing = is_processing.clone();
+            let progress = progress.clone();
+            let result = result.clone();
+            spawn_local(async move {
+                let opts = serde_wasm_bindgen::to_value(&OpenDirectoryOptions { directory: true })
+                    .unwrap();
+                let Some(output_dir) = open(opts).await.as_string() else {
+                    return;
+                };
+
+                is_processing.set(true);
+                progress.set(None);
+                result.set(None);
+                let args = ConvertBatchArgs {
+                    inputs,
+                    output_dir,
+                    format,
+                    quality,
+                };
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let response = invoke("convert_images_batch_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<BatchResult>(response) {
+                    result.set(Some(res));
+                }
+                is_processing.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="image-converter">
+            <div class="section">
+                <div class="drop-zone" onclick={on_add_files}>
+                    <p class="drop-zone-text">{i18n.t("image_converter.drop_images")}</p>
+                    <p class="drop-zone-hint">{i18n.t("image_converter.supported_formats")}</p>
+                </div>
+            </div>
+
+            if !inputs.is_empty() {
+                <div class="section">
+                    <div class="section-header">
+                        <h3>{format!("{} ({})", i18n.t("image_converter.files"), inputs.len())}</h3>
+                        <button class="secondary-btn" onclick={on_clear} disabled={*is_processing}>
+                            {i18n.t("common.clear_all")}
+                        </button>
+                    </div>
+                    <ul class="image-converter-files">
+                        { for inputs.iter().enumerate().map(|(i, path)| html! {
+                            <li title={path.clone()}>
+                                <span class="image-converter-file-name">{file_name(path)}</span>
+                                <button
+                                    class="move-btn"
+                                    onclick={on_remove(i)}
+                                    disabled={*is_processing}
+                                >
+                                    {"✕"}
+                                </button>
+                            </li>
+                        }) }
+                    </ul>
+                </div>
+            }
+
+            <div class="section">
+                <h3>{i18n.t("image_converter.output_format")}</h3>
+                <div class="format-options">
+                    { for OUTPUT_FORMATS.iter().map(|(value, name, _)| {
+                        let on_click = {
+                            let output_format = output_format.clone();
+                            Callback::from(move |_: MouseEvent| output_format.set(value.to_string()))
+                        };
+                        html! {
+                            <div class="format-option" onclick={on_click}>
+                                <input
+                                    type="radio"
+                                    name="convert-format"
+                                    value={*value}
+                                    checked={*output_format == *value}
+                                />
+                                <label>
+                                    <span class="format-name">{*name}</span>
+                                </label>
+                            </div>
+                        }
+                    }) }
+                </div>
+                if *output_format == "ico" {
+                    <p class="image-converter-hint">{i18n.t("image_converter.ico_hint")}</p>
+                } else if *output_format == "jpeg" {
+                    <p class="image-converter-hint">{i18n.t("image_converter.jpeg_hint")}</p>
+                }
+            </div>
+
+            if uses_quality {
+                <div class="section">
+                    <h3>{i18n.t("image_converter.quality")}</h3>
+                    <div class="quality-slider">
+                        <input
+                            type="range"
+                            min="1"
+                            max="100"
+                            value={quality.to_string()}
+                            oninput={on_quality_change}
+                        />
+                        <span class="quality-value">{format!("{}%", *quality)}</span>
+                    </div>
+                </div>
+            }
+
+            <button
+                class="primary-btn"
+                onclick={on_convert}
+                disabled={inputs.is_empty() || *is_processing}
+            >
+                if *is_processing {
+                    <span class="processing">
+                        <span class="spinner"></span>
+                        if let Some(p) = (*progress).clone() {
+                            {format!("{} {}/{}", i18n.t("common.converting"), p.completed, p.total)}
+                        } else {
+                            {i18n.t("common.converting")}
+                        }
+                    </span>
+                } else {
+                    {i18n.t("image_converter.convert")}
+                }
+            </button>
+
+            if let Some(res) = (*result).clone() {
+                <div class="section">
+                    <div class="section-header">
+                        <h3>{i18n.t("common.results")}</h3>
+                        <span class={classes!("image-converter-summary", (res.failed > 0).then_some("has-failures"))}>
+                            {format!(
+                                "{} {}/{}",
+                                i18n.t("image_converter.succeeded"),
+                                res.succeeded,
+                                res.total_files
+                            )}
+                        </span>
+                    </div>
+                    <ul class="image-converter-results">
+                        { for res.items.iter().map(|item| html! {
+                            <li class={classes!("image-converter-result", (!item.result.success).then_some("failed"))}>
+                                <div class="image-converter-result-header">
+                                    <span class="image-converter-file-name">{file_name(&item.input_path)}</span>
+                                    if item.result.success {
+                                        <span class="image-converter-size">
+                                            {format!(
+                                                "{} → {}",
+                                                format_size(item.result.original_size),
+                                                format_size(item.result.compressed_size)
+                                            )}
+                                        </span>
+                                    }
+                                </div>
+                                if item.result.success {
+                                    <p class="output-path">{format!("📁 {}", item.result.output_path)}</p>
+                                } else {
+                                    <p class="error-message">
+                                        {format!("⚠ {}", item.result.error.clone().unwrap_or_default())}
+                                    </p>
+                                }
+                                { for item.result.warnings.iter().map(|warning| html! {
+                                    <p class="image-converter-warning">{format!("⚠ {}", warning)}</p>
+                                }) }
+                            </li>
+                        }) }
+                    </ul>
+                </div>
+            }
+        </div>
+    }
+}
@@ -53,6 +53,104 @@ pub enum ImageFilter {
     Sharpen,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum WatermarkPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Center,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct WatermarkOptions {
+    pub text: String,
+    pub font_size: f32,
+    pub position: WatermarkPosition,
+    pub opacity: f32,
+    pub color: String,
+}
+
+impl Default for WatermarkOptions {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            font_size: 32.0,
+            position: WatermarkPosition::BottomRight,
+            opacity: 0.5,
+            color: "#ffffff".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum AspectRatio {
+    Square,
+    Ratio4x3,
+    Ratio16x9,
+    Ratio3x2,
+    Custom { width: u32, height: u32 },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum CropAnchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum CropBounds {
+    Error,
+    Clamp,
+}
+
+/// Arrangement for combining several images into one
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum CombineLayout {
+    Horizontal,
+    Vertical,
+    Grid { columns: u32 },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum CombineResize {
+    MatchSize,
+    Original,
+    Fit { width: u32, height: u32 },
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CombineOptions {
+    pub padding: u32,
+    pub background: String,
+    pub resize: CombineResize,
+}
+
+/// Crop settings besides the manual rectangle; `aspect: None` means a free crop
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CropSettings {
+    aspect: Option<AspectRatio>,
+    anchor: CropAnchor,
+    clamp_to_image: bool,
+}
+
+impl Default for CropSettings {
+    fn default() -> Self {
+        Self {
+            aspect: None,
+            anchor: CropAnchor::Center,
+            clamp_to_image: false,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 enum EditMode {
     Resize,
@@ -61,6 +159,7 @@ enum EditMode {
     Brightness,
     Contrast,
     Filter,
+    Watermark,
 }
 
 #[derive(Serialize)]
@@ -118,6 +217,17 @@ struct CropArgs {
     y: u32,
     width: u32,
     height: u32,
+    bounds: CropBounds,
+}
+
+#[derive(Serialize)]
+struct CropAspectArgs {
+    #[serde(rename = "inputPath")]
+    input_path: String,
+    #[serde(rename = "outputPath")]
+    output_path: String,
+    aspect: AspectRatio,
+    anchor: CropAnchor,
 }
 
 #[derive(Serialize)]
@@ -147,6 +257,15 @@ struct FilterArgs {
     filter: ImageFilter,
 }
 
+#[derive(Serialize)]
+struct WatermarkArgs {
+    #[serde(rename = "inputPath")]
+    input_path: String,
+    #[serde(rename = "outputPath")]
+    output_path: String,
+    options: WatermarkOptions,
+}
+
 #[derive(Serialize)]
 struct FlipArgs {
     #[serde(rename = "inputPath")]
@@ -155,6 +274,16 @@ struct FlipArgs {
     output_path: String,
 }
 
+#[derive(Serialize)]
+struct CombineArgs {
+    #[serde(rename = "inputPaths")]
+    input_paths: Vec<String>,
+    #[serde(rename = "outputPath")]
+    output_path: String,
+    layout: CombineLayout,
+    options: CombineOptions,
+}
+
 fn format_size(bytes: u64) -> String {
     if bytes >= 1_048_576 {
         format!("{:.1} MB", bytes as f64 / 1_048_576.0)
@@ -193,6 +322,7 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
     let crop_y = use_state(|| 0u32);
     let crop_width = use_state(|| 400u32);
     let crop_height = use_state(|| 300u32);
+    let crop_settings = use_state(CropSettings::default);
 
     // Brightness/Contrast options
     let brightness = use_state(|| 0i32);
@@ -201,6 +331,9 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
     // Filter option
     let selected_filter = use_state(|| ImageFilter::Grayscale);
 
+    // Watermark options
+    let watermark = use_state(WatermarkOptions::default);
+
     // Handle dropped file
     {
         let dropped_file = props.dropped_file.clone();
@@ -330,9 +463,11 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
         let crop_y = crop_y.clone();
         let crop_width = crop_width.clone();
         let crop_height = crop_height.clone();
+        let crop_settings = crop_settings.clone();
         let brightness = brightness.clone();
         let contrast = contrast.clone();
         let selected_filter = selected_filter.clone();
+        let watermark = watermark.clone();
 
         Callback::from(move |_| {
             let input_path_val = (*input_path).clone();
@@ -351,18 +486,33 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
             let crop_y_val = *crop_y;
             let crop_width_val = *crop_width;
             let crop_height_val = *crop_height;
+            let crop_settings_val = *crop_settings;
             let brightness_val = *brightness;
             let contrast_val = *contrast;
             let selected_filter_val = *selected_filter;
+            let watermark_val = (*watermark).clone();
+
+            if edit_mode_val == EditMode::Watermark && watermark_val.text.trim().is_empty() {
+                return;
+            }
 
             is_processing.set(true);
 
             spawn_local(async move {
-                let default_name = "edited.png".to_string();
+                // Watermarking keeps the input format, other edits are saved as PNG
+                let extension = if edit_mode_val == EditMode::Watermark {
+                    input_path_val
+                        .rsplit_once('.')
+                        .map(|(_, ext)| ext.to_lowercase())
+                        .unwrap_or_else(|| "png".to_string())
+                } else {
+                    "png".to_string()
+                };
+                let default_name = format!("edited.{}", extension);
                 let save_options = SaveDialogOptions {
                     filters: vec![FileFilter {
-                        name: "PNG Image".to_string(),
-                        extensions: vec!["png".to_string()],
+                        name: format!("{} Image", extension.to_uppercase()),
+                        extensions: vec![extension],
                     }],
                     default_path: Some(default_name),
                 };
@@ -392,16 +542,32 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
                             invoke("rotate_image_cmd", args_js).await
                         }
                         EditMode::Crop => {
-                            let args = CropArgs {
-                                input_path: input_path_val,
-                                output_path,
-                                x: crop_x_val,
-                                y: crop_y_val,
-                                width: crop_width_val,
-                                height: crop_height_val,
-                            };
-                            let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
-                            invoke("crop_image_cmd", args_js).await
+                            if let Some(aspect) = crop_settings_val.aspect {
+                                let args = CropAspectArgs {
+                                    input_path: input_path_val,
+                                    output_path,
+                                    aspect,
+                                    anchor: crop_settings_val.anchor,
+                                };
+                                let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                                invoke("crop_image_with_aspect_cmd", args_js).await
+                            } else {
+                                let args = CropArgs {
+                                    input_path: input_path_val,
+                                    output_path,
+                                    x: crop_x_val,
+                                    y: crop_y_val,
+                                    width: crop_width_val,
+                                    height: crop_height_val,
+                                    bounds: if crop_settings_val.clamp_to_image {
+                                        CropBounds::Clamp
+                                    } else {
+                                        CropBounds::Error
+                                    },
+                                };
+                                let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                                invoke("crop_image_cmd", args_js).await
+                            }
                         }
                         EditMode::Brightness => {
                             let args = BrightnessArgs {
@@ -430,6 +596,15 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
                             let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
                             invoke("apply_filter_cmd", args_js).await
                         }
+                        EditMode::Watermark => {
+                            let args = WatermarkArgs {
+                                input_path: input_path_val,
+                                output_path,
+                                options: watermark_val,
+                            };
+                            let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                            invoke("add_text_watermark_cmd", args_js).await
+                        }
                     };
 
                     if let Ok(res) = serde_wasm_bindgen::from_value::<EditResult>(result) {
@@ -623,6 +798,7 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
                     {render_mode_button(&edit_mode, EditMode::Brightness, "Brightness", on_mode_change.clone())}
                     {render_mode_button(&edit_mode, EditMode::Contrast, "Contrast", on_mode_change.clone())}
                     {render_mode_button(&edit_mode, EditMode::Filter, "Filter", on_mode_change.clone())}
+                    {render_mode_button(&edit_mode, EditMode::Watermark, "Watermark", on_mode_change.clone())}
                 </div>
             </div>
 
@@ -637,9 +813,11 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
                 &crop_y,
                 &crop_width,
                 &crop_height,
+                &crop_settings,
                 &brightness,
                 &contrast,
                 &selected_filter,
+                &watermark,
             )}
 
             // Quick Actions
@@ -719,6 +897,8 @@ pub fn image_editor(props: &ImageEditorProps) -> Html {
             } else {
                 html! {}
             }}
+
+            <CombineImages />
         </div>
     }
 }
@@ -752,17 +932,22 @@ fn render_edit_options(
     crop_y: &UseStateHandle<u32>,
     crop_width: &UseStateHandle<u32>,
     crop_height: &UseStateHandle<u32>,
+    crop_settings: &UseStateHandle<CropSettings>,
     brightness: &UseStateHandle<i32>,
     contrast: &UseStateHandle<f32>,
     selected_filter: &UseStateHandle<ImageFilter>,
+    watermark: &UseStateHandle<WatermarkOptions>,
 ) -> Html {
     match **edit_mode {
         EditMode::Resize => render_resize_options(resize_width, resize_height, maintain_aspect),
         EditMode::Rotate => render_rotate_options(rotation_angle),
-        EditMode::Crop => render_crop_options(crop_x, crop_y, crop_width, crop_height),
+        EditMode::Crop => {
+            render_crop_options(crop_x, crop_y, crop_width, crop_height, crop_settings)
+        }
         EditMode::Brightness => render_brightness_options(brightness),
         EditMode::Contrast => render_contrast_options(contrast),
         EditMode::Filter => render_filter_options(selected_filter),
+        EditMode::Watermark => render_watermark_options(watermark),
     }
 }
 
@@ -874,7 +1059,148 @@ fn render_crop_options(
     crop_y: &UseStateHandle<u32>,
     crop_width: &UseStateHandle<u32>,
     crop_height: &UseStateHandle<u32>,
+    crop_settings: &UseStateHandle<CropSettings>,
 ) -> Html {
+    let aspects = [
+        (None, "Free"),
+        (Some(AspectRatio::Square), "1:1"),
+        (Some(AspectRatio::Ratio4x3), "4:3"),
+        (Some(AspectRatio::Ratio16x9), "16:9"),
+        (Some(AspectRatio::Ratio3x2), "3:2"),
+        (
+            Some(AspectRatio::Custom {
+                width: 9,
+                height: 16,
+            }),
+            "Custom",
+        ),
+    ];
+    let anchors = [
+        (CropAnchor::TopLeft, "↖"),
+        (CropAnchor::Top, "↑"),
+        (CropAnchor::TopRight, "↗"),
+        (CropAnchor::Left, "←"),
+        (CropAnchor::Center, "●"),
+        (CropAnchor::Right, "→"),
+        (CropAnchor::BottomLeft, "↙"),
+        (CropAnchor::Bottom, "↓"),
+        (CropAnchor::BottomRight, "↘"),
+    ];
+
+    let update = {
+        let crop_settings = crop_settings.clone();
+        Callback::from(move |apply: Box<dyn Fn(&mut CropSettings)>| {
+            let mut settings = *crop_settings;
+            apply(&mut settings);
+            crop_settings.set(settings);
+        })
+    };
+
+    let is_custom = matches!(crop_settings.aspect, Some(AspectRatio::Custom { .. }));
+    // Any custom ratio counts as the "Custom" choice
+    let is_aspect_selected = |aspect: &Option<AspectRatio>| match aspect {
+        Some(AspectRatio::Custom { .. }) => is_custom,
+        _ => *aspect == crop_settings.aspect,
+    };
+
+    let on_custom_change = |is_width: bool| {
+        let update = update.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(v) = input.value().parse::<u32>() {
+                update.emit(Box::new(move |o| {
+                    if let Some(AspectRatio::Custom { width, height }) = &mut o.aspect {
+                        if is_width {
+                            *width = v;
+                        } else {
+                            *height = v;
+                        }
+                    }
+                }));
+            }
+        })
+    };
+
+    let on_clamp_toggle = {
+        let update = update.clone();
+        Callback::from(move |_| {
+            update.emit(Box::new(|o| o.clamp_to_image = !o.clamp_to_image));
+        })
+    };
+
+    let aspect_selector = html! {
+        <div class="format-options">
+            {for aspects.iter().map(|(aspect, label)| {
+                let aspect_value = *aspect;
+                let on_click = {
+                    let update = update.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        update.emit(Box::new(move |o| o.aspect = aspect_value));
+                    })
+                };
+                html! {
+                    <div class="format-option" onclick={on_click}>
+                        <input
+                            type="radio"
+                            name="crop-aspect"
+                            checked={is_aspect_selected(aspect)}
+                        />
+                        <label>
+                            <span class="format-name">{*label}</span>
+                        </label>
+                    </div>
+                }
+            })}
+        </div>
+    };
+
+    if let Some(aspect) = crop_settings.aspect {
+        return html! {
+            <div class="section">
+                <h3>{"Aspect Ratio"}</h3>
+                {aspect_selector}
+                if let AspectRatio::Custom { width, height } = aspect {
+                    <div class="resize-inputs crop-custom-ratio">
+                        <input
+                            type="number"
+                            min="1"
+                            value={width.to_string()}
+                            oninput={on_custom_change(true)}
+                        />
+                        <span>{":"}</span>
+                        <input
+                            type="number"
+                            min="1"
+                            value={height.to_string()}
+                            oninput={on_custom_change(false)}
+                        />
+                    </div>
+                }
+                <h3>{"Anchor"}</h3>
+                <div class="crop-anchor-grid">
+                    {for anchors.iter().map(|(anchor, label)| {
+                        let anchor_value = *anchor;
+                        let on_click = {
+                            let update = update.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                update.emit(Box::new(move |o| o.anchor = anchor_value));
+                            })
+                        };
+                        html! {
+                            <button
+                                class={classes!("crop-anchor", (crop_settings.anchor == *anchor).then_some("selected"))}
+                                onclick={on_click}
+                            >
+                                {*label}
+                            </button>
+                        }
+                    })}
+                </div>
+                <p class="crop-hint">{"Crops the largest area with this ratio from the chosen position"}</p>
+            </div>
+        };
+    }
+
     let on_x_change = {
         let crop_x = crop_x.clone();
         Callback::from(move |e: InputEvent| {
@@ -917,6 +1243,8 @@ fn render_crop_options(
 
     html! {
         <div class="section">
+            <h3>{"Aspect Ratio"}</h3>
+            {aspect_selector}
             <h3>{"Crop Area"}</h3>
             <div class="crop-inputs">
                 <div class="crop-row">
@@ -956,6 +1284,10 @@ fn render_crop_options(
                     </div>
                 </div>
             </div>
+            <div class="checkbox-option" onclick={on_clamp_toggle}>
+                <input type="checkbox" checked={crop_settings.clamp_to_image} />
+                <label>{"Fit to image bounds instead of failing"}</label>
+            </div>
         </div>
     }
 }
@@ -1058,3 +1390,427 @@ fn render_filter_options(selected_filter: &UseStateHandle<ImageFilter>) -> Html
         </div>
     }
 }
+
+fn render_watermark_options(watermark: &UseStateHandle<WatermarkOptions>) -> Html {
+    let positions = [
+        (WatermarkPosition::TopLeft, "↖ Top Left"),
+        (WatermarkPosition::TopRight, "↗ Top Right"),
+        (WatermarkPosition::Center, "● Center"),
+        (WatermarkPosition::BottomLeft, "↙ Bottom Left"),
+        (WatermarkPosition::BottomRight, "↘ Bottom Right"),
+    ];
+
+    let update = {
+        let watermark = watermark.clone();
+        Callback::from(move |apply: Box<dyn Fn(&mut WatermarkOptions)>| {
+            let mut options = (*watermark).clone();
+            apply(&mut options);
+            watermark.set(options);
+        })
+    };
+
+    let on_text_change = {
+        let update = update.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            update.emit(Box::new(move |o| o.text = value.clone()));
+        })
+    };
+
+    let on_font_size_change = {
+        let update = update.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(v) = input.value().parse::<f32>() {
+                update.emit(Box::new(move |o| o.font_size = v));
+            }
+        })
+    };
+
+    let on_opacity_change = {
+        let update = update.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(v) = input.value().parse::<f32>() {
+                update.emit(Box::new(move |o| o.opacity = v / 100.0));
+            }
+        })
+    };
+
+    let on_color_change = {
+        let update = update.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            update.emit(Box::new(move |o| o.color = value.clone()));
+        })
+    };
+
+    let opacity_percent = (watermark.opacity * 100.0).round() as i32;
+
+    html! {
+        <div class="section">
+            <h3>{"Watermark"}</h3>
+            <div class="watermark-options">
+                <div class="crop-input-group">
+                    <label>{"Text"}</label>
+                    <input
+                        type="text"
+                        placeholder="© Your Name"
+                        value={watermark.text.clone()}
+                        oninput={on_text_change}
+                    />
+                </div>
+                <div class="crop-row">
+                    <div class="crop-input-group">
+                        <label>{"Font Size"}</label>
+                        <input
+                            type="number"
+                            min="8"
+                            value={watermark.font_size.to_string()}
+                            oninput={on_font_size_change}
+                        />
+                    </div>
+                    <div class="crop-input-group">
+                        <label>{"Color"}</label>
+                        <input
+                            type="color"
+                            value={watermark.color.clone()}
+                            oninput={on_color_change}
+                        />
+                    </div>
+                </div>
+                <div class="quality-slider">
+                    <input
+                        type="range"
+                        min="0"
+                        max="100"
+                        value={opacity_percent.to_string()}
+                        oninput={on_opacity_change}
+                    />
+                    <span class="quality-value">{format!("{}%", opacity_percent)}</span>
+                </div>
+                <div class="filter-options">
+                    {for positions.iter().map(|(position, label)| {
+                        let is_selected = watermark.position == *position;
+                        let position_value = *position;
+                        let on_click = {
+                            let update = update.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                update.emit(Box::new(move |o| o.position = position_value));
+                            })
+                        };
+                        html! {
+                            <div class={if is_selected { "filter-option selected" } else { "filter-option" }} onclick={on_click}>
+                                <span class="filter-name">{*label}</span>
+                            </div>
+                        }
+                    })}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Picks several images and saves them side by side, stacked, or as a grid
+#[function_component(CombineImages)]
+fn combine_images() -> Html {
+    let paths = use_state(Vec::<String>::new);
+    let layout = use_state(|| CombineLayout::Horizontal);
+    let columns = use_state(|| 2u32);
+    let padding = use_state(|| 0u32);
+    let background = use_state(|| "#ffffff".to_string());
+    let transparent = use_state(|| false);
+    let resize = use_state(|| CombineResize::MatchSize);
+    let fit_size = use_state(|| (800u32, 600u32));
+    let result = use_state(|| Option::<EditResult>::None);
+    let is_processing = use_state(|| false);
+
+    let on_add = {
+        let paths = paths.clone();
+        Callback::from(move |_| {
+            let paths = paths.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: true,
+                    filters: vec![FileFilter {
+                        name: "Images".to_string(),
+                        extensions: ["png", "jpg", "jpeg", "webp", "gif", "bmp"]
+                            .iter()
+                            .map(|ext| ext.to_string())
+                            .collect(),
+                    }],
+                };
+                let options_js = serde_wasm_bindgen::to_value(&options).unwrap();
+                let selected = open(options_js).await;
+                if let Ok(selected) = serde_wasm_bindgen::from_value::<Vec<String>>(selected) {
+                    let mut next = (*paths).clone();
+                    next.extend(selected);
+                    paths.set(next);
+                }
+            });
+        })
+    };
+
+    // Swap the image at `index` with its neighbour `offset` places away, or remove it
+    let on_move = |index: usize, offset: Option<isize>| {
+        let paths = paths.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*paths).clone();
+            match offset {
+                Some(offset) => {
+                    let target = index as isize + offset;
+                    if target < 0 || target as usize >= next.len() {
+                        return;
+                    }
+                    next.swap(index, target as usize);
+                }
+                None => {
+                    next.remove(index);
+                }
+            }
+            paths.set(next);
+        })
+    };
+
+    let number_setter = |state: &UseStateHandle<u32>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<u32>() {
+                state.set(value);
+            }
+        })
+    };
+
+    let on_fit_change = |is_width: bool| {
+        let fit_size = fit_size.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<u32>() {
+                let (width, height) = *fit_size;
+                fit_size.set(if is_width {
+                    (value, height)
+                } else {
+                    (width, value)
+                });
+            }
+        })
+    };
+
+    let on_background_change = {
+        let background = background.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            background.set(input.value());
+        })
+    };
+
+    let on_transparent_change = {
+        let transparent = transparent.clone();
+        Callback::from(move |_| transparent.set(!*transparent))
+    };
+
+    let on_combine = {
+        let paths = paths.clone();
+        let layout = layout.clone();
+        let columns = columns.clone();
+        let padding = padding.clone();
+        let background = background.clone();
+        let transparent = transparent.clone();
+        let resize = resize.clone();
+        let fit_size = fit_size.clone();
+        let result = result.clone();
+        let is_processing = is_processing.clone();
+        Callback::from(move |_| {
+            let input_paths = (*paths).clone();
+            let layout = match *layout {
+                CombineLayout::Grid { .. } => CombineLayout::Grid { columns: *columns },
+                other => other,
+            };
+            let resize = match *resize {
+                CombineResize::Fit { .. } => CombineResize::Fit {
+                    width: fit_size.0,
+                    height: fit_size.1,
+                },
+                other => other,
+            };
+            let options = CombineOptions {
+                padding: *padding,
+                background: if *transparent {
+                    "transparent".to_string()
+                } else {
+                    (*background).clone()
+                },
+                resize,
+            };
+            let result = result.clone();
+            let is_processing = is_processing.clone();
+            is_processing.set(true);
+
+            spawn_local(async move {
+                let save_options = SaveDialogOptions {
+                    filters: vec![
+                        FileFilter {
+                            name: "PNG Image".to_string(),
+                            extensions: vec!["png".to_string()],
+                        },
+                        FileFilter {
+                            name: "JPEG Image".to_string(),
+                            extensions: vec!["jpg".to_string(), "jpeg".to_string()],
+                        },
+                    ],
+                    default_path: Some("combined.png".to_string()),
+                };
+                let save_options_js = serde_wasm_bindgen::to_value(&save_options).unwrap();
+                if let Some(output_path) = save(save_options_js).await.as_string() {
+                    let args = CombineArgs {
+                        input_paths,
+                        output_path,
+                        layout,
+                        options,
+                    };
+                    let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                    let response = invoke("combine_images_cmd", args_js).await;
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<EditResult>(response) {
+                        result.set(Some(res));
+                    }
+                }
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let layouts = [
+        (CombineLayout::Horizontal, "⇆ Horizontal"),
+        (CombineLayout::Vertical, "⇅ Vertical"),
+        (CombineLayout::Grid { columns: 0 }, "▦ Grid"),
+    ];
+    let resizes = [
+        (CombineResize::MatchSize, "Match height"),
+        (CombineResize::Original, "Keep original"),
+        (
+            CombineResize::Fit {
+                width: 0,
+                height: 0,
+            },
+            "Fit to size",
+        ),
+    ];
+    let same_variant = |a: &CombineLayout, b: &CombineLayout| {
+        std::mem::discriminant(a) == std::mem::discriminant(b)
+    };
+    let is_fit = matches!(*resize, CombineResize::Fit { .. });
+
+    html! {
+        <div class="section combine-images">
+            <div class="section-header">
+                <h3>{format!("Combine Images ({})", paths.len())}</h3>
+                <button class="secondary-btn" onclick={on_add} disabled={*is_processing}>
+                    {"+ Add Images"}
+                </button>
+            </div>
+
+            if !paths.is_empty() {
+                <ul class="image-converter-files">
+                    { for paths.iter().enumerate().map(|(index, path)| html! {
+                        <li title={path.clone()}>
+                            <span class="image-converter-file-name">
+                                {path.rsplit(['/', '\\']).next().unwrap_or(path)}
+                            </span>
+                            <button class="move-btn" onclick={on_move(index, Some(-1))} disabled={index == 0}>{"↑"}</button>
+                            <button class="move-btn" onclick={on_move(index, Some(1))} disabled={index + 1 == paths.len()}>{"↓"}</button>
+                            <button class="move-btn" onclick={on_move(index, None)}>{"✕"}</button>
+                        </li>
+                    }) }
+                </ul>
+            }
+
+            <div class="filter-options">
+                { for layouts.iter().map(|(option, label)| {
+                    let onclick = {
+                        let layout = layout.clone();
+                        let option = *option;
+                        Callback::from(move |_| layout.set(option))
+                    };
+                    html! {
+                        <div class={classes!("filter-option", same_variant(&layout, option).then_some("selected"))} {onclick}>
+                            <span class="filter-name">{*label}</span>
+                        </div>
+                    }
+                }) }
+            </div>
+
+            <div class="filter-options">
+                { for resizes.iter().map(|(option, label)| {
+                    let onclick = {
+                        let resize = resize.clone();
+                        let option = *option;
+                        Callback::from(move |_| resize.set(option))
+                    };
+                    let selected = std::mem::discriminant(&*resize) == std::mem::discriminant(option);
+                    html! {
+                        <div class={classes!("filter-option", selected.then_some("selected"))} {onclick}>
+                            <span class="filter-name">{*label}</span>
+                        </div>
+                    }
+                }) }
+            </div>
+
+            <div class="crop-row">
+                if matches!(*layout, CombineLayout::Grid { .. }) {
+                    <div class="crop-input-group">
+                        <label>{"Columns"}</label>
+                        <input type="number" min="1" value={columns.to_string()} oninput={number_setter(&columns)} />
+                    </div>
+                }
+                if is_fit {
+                    <div class="crop-input-group">
+                        <label>{"Max Width"}</label>
+                        <input type="number" min="1" value={fit_size.0.to_string()} oninput={on_fit_change(true)} />
+                    </div>
+                    <div class="crop-input-group">
+                        <label>{"Max Height"}</label>
+                        <input type="number" min="1" value={fit_size.1.to_string()} oninput={on_fit_change(false)} />
+                    </div>
+                }
+                <div class="crop-input-group">
+                    <label>{"Padding"}</label>
+                    <input type="number" min="0" value={padding.to_string()} oninput={number_setter(&padding)} />
+                </div>
+                <div class="crop-input-group combine-background">
+                    <label>{"Background"}</label>
+                    <input
+                        type="color"
+                        value={(*background).clone()}
+                        oninput={on_background_change}
+                        disabled={*transparent}
+                    />
+                </div>
+            </div>
+            <label class="checkbox-option-inline">
+                <input type="checkbox" checked={*transparent} onchange={on_transparent_change} />
+                <span>{"Transparent background (PNG only)"}</span>
+            </label>
+
+            <button
+                class="primary-btn compress-btn"
+                onclick={on_combine}
+                disabled={paths.is_empty() || *is_processing}
+            >
+                {if *is_processing { "Processing..." } else { "Combine & Save" }}
+            </button>
+
+            if let Some(result) = (*result).clone() {
+                if result.success {
+                    <p class="output-path">
+                        {format!("📁 {} ({}×{}, {})", result.output_path, result.new_width, result.new_height, format_size(result.new_size))}
+                    </p>
+                } else {
+                    <p class="error-message">{result.error.clone().unwrap_or_default()}</p>
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,262 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum JpConversion {
+    ToHalfWidthAlnum,
+    ToFullWidthAlnum,
+    ToHalfWidthSymbols,
+    ToFullWidthSymbols,
+    HalfKanaToFullKana,
+    FullKanaToHalfKana,
+    HiraganaToKatakana,
+    KatakanaToHiragana,
+    NormalizeSpaces,
+}
+
+impl JpConversion {
+    const ALL: [JpConversion; 9] = [
+        JpConversion::ToHalfWidthAlnum,
+        JpConversion::ToFullWidthAlnum,
+        JpConversion::ToHalfWidthSymbols,
+        JpConversion::ToFullWidthSymbols,
+        JpConversion::HalfKanaToFullKana,
+        JpConversion::FullKanaToHalfKana,
+        JpConversion::HiraganaToKatakana,
+        JpConversion::KatakanaToHiragana,
+        JpConversion::NormalizeSpaces,
+    ];
+
+    fn translation_key(self) -> &'static str {
+        match self {
+            JpConversion::ToHalfWidthAlnum => "japanese_text_converter.to_half_width_alnum",
+            JpConversion::ToFullWidthAlnum => "japanese_text_converter.to_full_width_alnum",
+            JpConversion::ToHalfWidthSymbols => "japanese_text_converter.to_half_width_symbols",
+            JpConversion::ToFullWidthSymbols => "japanese_text_converter.to_full_width_symbols",
+            JpConversion::HalfKanaToFullKana => "japanese_text_converter.half_kana_to_full_kana",
+            JpConversion::FullKanaToHalfKana => "japanese_text_converter.full_kana_to_half_kana",
+            JpConversion::HiraganaToKatakana => "japanese_text_converter.hiragana_to_katakana",
+            JpConversion::KatakanaToHiragana => "japanese_text_converter.katakana_to_hiragana",
+            JpConversion::NormalizeSpaces => "japanese_text_converter.normalize_spaces",
+        }
+    }
+
+    /// The conversion that would undo this one; selecting one drops the other
+    fn opposite(self) -> Option<JpConversion> {
+        match self {
+            JpConversion::ToHalfWidthAlnum => Some(JpConversion::ToFullWidthAlnum),
+            JpConversion::ToFullWidthAlnum => Some(JpConversion::ToHalfWidthAlnum),
+            JpConversion::ToHalfWidthSymbols => Some(JpConversion::ToFullWidthSymbols),
+            JpConversion::ToFullWidthSymbols => Some(JpConversion::ToHalfWidthSymbols),
+            JpConversion::HalfKanaToFullKana => Some(JpConversion::FullKanaToHalfKana),
+            JpConversion::FullKanaToHalfKana => Some(JpConversion::HalfKanaToFullKana),
+            JpConversion::HiraganaToKatakana => Some(JpConversion::KatakanaToHiragana),
+            JpConversion::KatakanaToHiragana => Some(JpConversion::HiraganaToKatakana),
+            JpConversion::NormalizeSpaces => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ConvertArgs {
+    input: String,
+    conversions: Vec<JpConversion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct JpConversionStat {
+    conversion: JpConversion,
+    converted_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct JpConvertResult {
+    output: String,
+    input_length: usize,
+    output_length: usize,
+    converted_count: usize,
+    steps: Vec<JpConversionStat>,
+}
+
+#[function_component(JapaneseTextConverter)]
+pub fn japanese_text_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(|| "ﾃｽﾄ　ＡＢＣ１２３ ｶﾞｲﾄﾞ".to_string());
+    // Applied in the order they were selected
+    let conversions = use_state(|| {
+        vec![
+            JpConversion::ToHalfWidthAlnum,
+            JpConversion::HalfKanaToFullKana,
+            JpConversion::NormalizeSpaces,
+        ]
+    });
+    let result = use_state(|| Option::<JpConvertResult>::None);
+    let copied = use_state(|| false);
+
+    // Convert on every edit
+    {
+        let result = result.clone();
+        use_effect_with(
+            ((*input).clone(), (*conversions).clone()),
+            move |(input, conversions)| {
+                if input.is_empty() {
+                    result.set(None);
+                } else {
+                    let args = serde_wasm_bindgen::to_value(&ConvertArgs {
+                        input: input.clone(),
+                        conversions: conversions.clone(),
+                    })
+                    .unwrap();
+                    spawn_local(async move {
+                        let response = invoke("convert_japanese_text_cmd", args).await;
+                        result.set(serde_wasm_bindgen::from_value(response).ok());
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let toggle = |conversion: JpConversion| {
+        let conversions = conversions.clone();
+        Callback::from(move |_| {
+            let mut next: Vec<JpConversion> = (*conversions).clone();
+            if next.contains(&conversion) {
+                next.retain(|c| *c != conversion);
+            } else {
+                next.retain(|c| Some(*c) != conversion.opposite());
+                next.push(conversion);
+            }
+            conversions.set(next);
+        })
+    };
+
+    let on_clear = {
+        let input = input.clone();
+        Callback::from(move |_| input.set(String::new()))
+    };
+
+    let on_use_output = {
+        let input = input.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            if let Some(res) = result.as_ref() {
+                input.set(res.output.clone());
+            }
+        })
+    };
+
+    let on_copy = {
+        let copied = copied.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            let Some(value) = result.as_ref().map(|res| res.output.clone()) else {
+                return;
+            };
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ =
+                        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value)).await;
+                    copied.set(true);
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    html! {
+        <div class="jp-text-converter">
+            <div class="section">
+                <textarea
+                    class="form-textarea jp-text-input"
+                    value={(*input).clone()}
+                    oninput={on_input_change}
+                    placeholder={i18n.t("japanese_text_converter.input_placeholder")}
+                    rows="6"
+                    spellcheck="false"
+                />
+                <div class="jp-text-conversions">
+                    { for JpConversion::ALL.iter().map(|&conversion| {
+                        let order = conversions.iter().position(|c| *c == conversion);
+                        html! {
+                            <button
+                                class={classes!("jp-text-chip", order.is_some().then_some("active"))}
+                                onclick={toggle(conversion)}
+                            >
+                                if let Some(order) = order {
+                                    <span class="jp-text-order">{order + 1}</span>
+                                }
+                                {i18n.t(conversion.translation_key())}
+                            </button>
+                        }
+                    }) }
+                </div>
+                <p class="jp-text-hint">{i18n.t("japanese_text_converter.order_hint")}</p>
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                <div class="section">
+                    <div class="section-header">
+                        <h3>{i18n.t("japanese_text_converter.output")}</h3>
+                        <div class="jp-text-actions">
+                            <button class="secondary-btn" onclick={on_use_output}>
+                                {i18n.t("japanese_text_converter.use_as_input")}
+                            </button>
+                            <button class="secondary-btn" onclick={on_clear}>
+                                {i18n.t("common.clear")}
+                            </button>
+                            <button class={classes!("copy-btn", (*copied).then_some("copied"))} onclick={on_copy}>
+                                if *copied {
+                                    {"✓"}
+                                } else {
+                                    {i18n.t("common.copy")}
+                                }
+                            </button>
+                        </div>
+                    </div>
+                    <textarea class="form-textarea jp-text-output" readonly=true rows="6" value={res.output.clone()} />
+                    <div class="jp-text-stats">
+                        <span>{format!("{}: {}", i18n.t("japanese_text_converter.input_length"), res.input_length)}</span>
+                        <span>{format!("{}: {}", i18n.t("japanese_text_converter.output_length"), res.output_length)}</span>
+                        <span>{format!("{}: {}", i18n.t("japanese_text_converter.converted_count"), res.converted_count)}</span>
+                    </div>
+                    if !res.steps.is_empty() {
+                        <ol class="jp-text-steps">
+                            { for res.steps.iter().map(|step| html! {
+                                <li class={classes!((step.converted_count == 0).then_some("unchanged"))}>
+                                    <span>{i18n.t(step.conversion.translation_key())}</span>
+                                    <code>{step.converted_count}</code>
+                                </li>
+                            }) }
+                        </ol>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
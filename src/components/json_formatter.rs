@@ -1,19 +1,29 @@
 use gloo_timers::callback::Timeout;
 use i18nrs::yew::use_translation;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::window;
 use yew::prelude::*;
 
 use crate::components::input_history::{save_history, InputHistoryPanel};
+use crate::components::settings::SettingsContext;
 
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
 }
 
+/// Children fetched per request; the backend pages anything larger
+const TREE_PAGE_SIZE: usize = 10_000;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JsonFormatResult {
@@ -39,13 +49,12 @@ pub struct ErrorPosition {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct JsonTreeNode {
+pub struct JsonTreeNodeSummary {
     pub key: String,
     pub value_type: JsonValueType,
     pub value: Option<String>,
     pub path: String,
-    pub children: Vec<JsonTreeNode>,
-    pub expanded: bool,
+    pub child_count: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -61,10 +70,10 @@ pub enum JsonValueType {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct JsonParseResult {
-    pub success: bool,
-    pub tree: Option<JsonTreeNode>,
-    pub error: Option<String>,
+pub struct JsonTreeRoot {
+    pub id: String,
+    pub root: JsonTreeNodeSummary,
+    pub children: Vec<JsonTreeNodeSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -96,6 +105,105 @@ pub struct JsonSearchMatch {
     pub value_type: JsonValueType,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaValidateResult {
+    pub valid: bool,
+    pub violations: Vec<SchemaViolation>,
+    pub error: Option<String>,
+    pub schema_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaViolation {
+    pub path: String,
+    pub keyword: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonQueryType {
+    Pointer,
+    JsonPath,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonQueryResult {
+    pub success: bool,
+    pub matches: Vec<JsonQueryMatch>,
+    pub total_count: usize,
+    pub formatted: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonQueryMatch {
+    pub path: String,
+    pub value: String,
+    pub value_type: JsonValueType,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonArrayDiffMode {
+    Ordered,
+    Unordered,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonDiffOptions {
+    pub array_mode: JsonArrayDiffMode,
+    pub array_id_key: Option<String>,
+    pub ignore_number_format: bool,
+    pub ignore_key_order: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonDiffKind {
+    Added,
+    Removed,
+    Changed,
+    TypeChanged,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonDiffEntry {
+    pub path: String,
+    pub kind: JsonDiffKind,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonDiffResult {
+    pub success: bool,
+    pub entries: Vec<JsonDiffEntry>,
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub type_changed: usize,
+    pub error: Option<String>,
+}
+
+impl JsonDiffKind {
+    fn class(&self) -> &'static str {
+        match self {
+            JsonDiffKind::Added => "diff-added",
+            JsonDiffKind::Removed => "diff-removed",
+            JsonDiffKind::Changed => "diff-changed",
+            JsonDiffKind::TypeChanged => "diff-type-changed",
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct FormatJsonArgs {
@@ -121,6 +229,20 @@ struct ParseJsonArgs {
     input: String,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TreeChildrenArgs<'a> {
+    input_id: &'a str,
+    path: &'a str,
+    offset: usize,
+    limit: usize,
+}
+
+#[derive(Serialize)]
+struct ReleaseTreeArgs {
+    id: String,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct SearchJsonArgs {
@@ -130,6 +252,182 @@ struct SearchJsonArgs {
     search_values: bool,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SchemaValidateArgs {
+    input: String,
+    schema: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryJsonArgs {
+    input: String,
+    query: String,
+    query_type: JsonQueryType,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeTarget {
+    TypeScript,
+    Rust,
+    Go,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum OptionalRule {
+    Missing,
+    MissingOrNull,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeGenOptions {
+    pub root_name: String,
+    pub camel_case: bool,
+    pub optional_rule: OptionalRule,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeGenResult {
+    pub success: bool,
+    pub code: String,
+    pub type_count: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateTypesArgs {
+    input: String,
+    target: TypeTarget,
+    options: TypeGenOptions,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiffJsonArgs {
+    old_input: String,
+    new_input: String,
+    options: JsonDiffOptions,
+}
+
+/// Tree view of the parsed input; children are fetched per path when a node is expanded
+struct TreeState {
+    /// Backend handle of the parsed document
+    id: Option<String>,
+    root: Option<JsonTreeNodeSummary>,
+    children: HashMap<String, Vec<JsonTreeNodeSummary>>,
+    /// Kept across re-parses so editing the input does not collapse the tree
+    expanded: HashSet<String>,
+    /// Bumped on every parse so a slower, older parse does not overwrite a newer one
+    generation: u32,
+}
+
+impl Default for TreeState {
+    fn default() -> Self {
+        Self {
+            id: None,
+            root: None,
+            children: HashMap::new(),
+            expanded: HashSet::from(["$".to_string()]),
+            generation: 0,
+        }
+    }
+}
+
+fn release_tree(id: String) {
+    spawn_local(async move {
+        let args = serde_wasm_bindgen::to_value(&ReleaseTreeArgs { id }).unwrap();
+        let _ = try_invoke("release_json_tree_cmd", args).await;
+    });
+}
+
+async fn fetch_tree_children(
+    id: &str,
+    path: &str,
+    offset: usize,
+) -> Result<Vec<JsonTreeNodeSummary>, String> {
+    let args = serde_wasm_bindgen::to_value(&TreeChildrenArgs {
+        input_id: id,
+        path,
+        offset,
+        limit: TREE_PAGE_SIZE,
+    })
+    .unwrap();
+    match try_invoke("get_json_tree_children_cmd", args).await {
+        Ok(result) => serde_wasm_bindgen::from_value(result).map_err(|e| e.to_string()),
+        Err(e) => Err(e.as_string().unwrap_or_default()),
+    }
+}
+
+/// Parse `input` into a new tree, reloading the children of every node that was expanded
+async fn load_tree(
+    tree: Rc<RefCell<TreeState>>,
+    input: String,
+    force_update: UseForceUpdateHandle,
+) {
+    let generation = {
+        let mut state = tree.borrow_mut();
+        state.generation += 1;
+        state.generation
+    };
+
+    let args = serde_wasm_bindgen::to_value(&ParseJsonArgs { input }).unwrap();
+    let parsed = try_invoke("parse_json_tree_root_cmd", args)
+        .await
+        .ok()
+        .and_then(|result| serde_wasm_bindgen::from_value::<JsonTreeRoot>(result).ok());
+    if tree.borrow().generation != generation {
+        if let Some(parsed) = parsed {
+            release_tree(parsed.id);
+        }
+        return;
+    }
+    let Some(parsed) = parsed else {
+        return;
+    };
+
+    let expanded: Vec<String> = {
+        let mut state = tree.borrow_mut();
+        if let Some(old_id) = state.id.replace(parsed.id.clone()) {
+            release_tree(old_id);
+        }
+        state.children = HashMap::from([("$".to_string(), parsed.children)]);
+        state.root = Some(parsed.root);
+        state
+            .expanded
+            .iter()
+            .filter(|path| *path != "$")
+            .cloned()
+            .collect()
+    };
+    force_update.force_update();
+
+    for path in expanded {
+        let result = fetch_tree_children(&parsed.id, &path, 0).await;
+        let mut state = tree.borrow_mut();
+        if state.generation != generation {
+            return;
+        }
+        match result {
+            Ok(children) => {
+                state.children.insert(path, children);
+            }
+            // The node no longer exists in the edited input
+            Err(_) => {
+                state.expanded.remove(&path);
+            }
+        }
+    }
+    force_update.force_update();
+}
+
 #[derive(Clone, PartialEq)]
 enum ViewMode {
     Text,
@@ -151,17 +449,46 @@ pub fn json_formatter(props: &Props) -> Html {
     let output = use_state(String::new);
     let indent_size = use_state(|| 2usize);
     let validation_result = use_state(|| Option::<JsonValidateResult>::None);
-    let tree_data = use_state(|| Option::<JsonTreeNode>::None);
+    let tree = use_mut_ref(TreeState::default);
+    let force_update = use_force_update();
     let search_query = use_state(String::new);
     let search_results = use_state(|| Option::<JsonSearchResult>::None);
     let search_keys = use_state(|| true);
     let search_values = use_state(|| true);
+    let query_type = use_state(|| JsonQueryType::JsonPath);
+    let query_text = use_state(String::new);
+    let query_result = use_state(|| Option::<JsonQueryResult>::None);
+    let schema_input = use_state(String::new);
+    let schema_result = use_state(|| Option::<JsonSchemaValidateResult>::None);
+    let compare_input = use_state(String::new);
+    let diff_unordered = use_state(|| false);
+    let diff_id_key = use_state(String::new);
+    let diff_ignore_number_format = use_state(|| true);
+    let diff_ignore_key_order = use_state(|| true);
+    let diff_result = use_state(|| Option::<JsonDiffResult>::None);
+    let type_target = use_state(|| TypeTarget::TypeScript);
+    let type_root_name = use_state(|| "Root".to_string());
+    let type_camel_case = use_state(|| false);
+    let optional_rule = use_state(|| OptionalRule::Missing);
+    let type_result = use_state(|| Option::<TypeGenResult>::None);
     let view_mode = use_state(|| ViewMode::Text);
     let is_processing = use_state(|| false);
     let copied = use_state(|| false);
-    let collapsed_paths = use_state(|| std::collections::HashSet::<String>::new());
     let history_refresh = use_state(|| 0u32);
 
+    // Start from the saved indent width, and follow it when it is changed in settings
+    {
+        let indent_size = indent_size.clone();
+        let default_indent =
+            use_context::<SettingsContext>().map(|ctx| ctx.settings.json_formatter.indent_size);
+        use_effect_with(default_indent, move |default_indent| {
+            if let Some(size) = default_indent {
+                indent_size.set(*size);
+            }
+            || {}
+        });
+    }
+
     // Handle dropped file
     {
         let dropped_file = props.dropped_file.clone();
@@ -216,25 +543,35 @@ pub fn json_formatter(props: &Props) -> Html {
     {
         let input_val = (*input).clone();
         let validation_result = validation_result.clone();
-        let tree_data = tree_data.clone();
+        let tree = tree.clone();
+        let force_update = force_update.clone();
         let output = output.clone();
         let indent_size = *indent_size;
 
         use_effect_with(input_val.clone(), move |input_val| {
             let input_val = input_val.clone();
             let validation_result = validation_result.clone();
-            let tree_data = tree_data.clone();
+            let tree = tree.clone();
+            let force_update = force_update.clone();
             let output = output.clone();
 
             if input_val.is_empty() {
                 validation_result.set(None);
-                tree_data.set(None);
+                {
+                    let mut state = tree.borrow_mut();
+                    if let Some(id) = state.id.take() {
+                        release_tree(id);
+                    }
+                    state.root = None;
+                    state.children.clear();
+                }
                 output.set(String::new());
             } else {
                 let timeout = Timeout::new(300, move || {
                     let input_val = input_val.clone();
                     let validation_result = validation_result.clone();
-                    let tree_data = tree_data.clone();
+                    let tree = tree.clone();
+                    let force_update = force_update.clone();
                     let output = output.clone();
 
                     spawn_local(async move {
@@ -265,19 +602,7 @@ pub fn json_formatter(props: &Props) -> Html {
                                     }
                                 }
 
-                                // Parse to tree
-                                let args = serde_wasm_bindgen::to_value(&ParseJsonArgs {
-                                    input: input_val,
-                                })
-                                .unwrap();
-                                let res = invoke("parse_json_to_tree_cmd", args).await;
-                                if let Ok(tree_result) =
-                                    serde_wasm_bindgen::from_value::<JsonParseResult>(res)
-                                {
-                                    if tree_result.success {
-                                        tree_data.set(tree_result.tree);
-                                    }
-                                }
+                                load_tree(tree, input_val, force_update).await;
                             }
                         }
                     });
@@ -422,17 +747,31 @@ pub fn json_formatter(props: &Props) -> Html {
         let input = input.clone();
         let output = output.clone();
         let validation_result = validation_result.clone();
-        let tree_data = tree_data.clone();
+        let tree = tree.clone();
         let search_query = search_query.clone();
         let search_results = search_results.clone();
+        let query_result = query_result.clone();
+        let schema_result = schema_result.clone();
 
         Callback::from(move |_| {
             input.set(String::new());
             output.set(String::new());
             validation_result.set(None);
-            tree_data.set(None);
+            {
+                let mut state = tree.borrow_mut();
+                if let Some(id) = state.id.take() {
+                    release_tree(id);
+                }
+                let generation = state.generation + 1;
+                *state = TreeState {
+                    generation,
+                    ..TreeState::default()
+                };
+            }
             search_query.set(String::new());
             search_results.set(None);
+            query_result.set(None);
+            schema_result.set(None);
         })
     };
 
@@ -523,33 +862,352 @@ pub fn json_formatter(props: &Props) -> Html {
         })
     };
 
+    let on_query_type_change = {
+        let query_type = query_type.clone();
+        let query_result = query_result.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            query_type.set(if select.value() == "pointer" {
+                JsonQueryType::Pointer
+            } else {
+                JsonQueryType::JsonPath
+            });
+            query_result.set(None);
+        })
+    };
+
+    let on_query_change = {
+        let query_text = query_text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input_el: web_sys::HtmlInputElement = e.target_unchecked_into();
+            query_text.set(input_el.value());
+        })
+    };
+
+    let do_query = {
+        let input = input.clone();
+        let query_text = query_text.clone();
+        let query_result = query_result.clone();
+        let query_type = *query_type;
+
+        move || {
+            let input_val = (*input).clone();
+            let query = (*query_text).clone();
+            let query_result = query_result.clone();
+
+            if input_val.is_empty() {
+                return;
+            }
+
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&QueryJsonArgs {
+                    input: input_val,
+                    query,
+                    query_type,
+                })
+                .unwrap();
+                let res = invoke("query_json_cmd", args).await;
+                if let Ok(result) = serde_wasm_bindgen::from_value::<JsonQueryResult>(res) {
+                    query_result.set(Some(result));
+                }
+            });
+        }
+    };
+
+    let on_query_click = {
+        let do_query = do_query.clone();
+        Callback::from(move |_: MouseEvent| {
+            do_query();
+        })
+    };
+
+    let on_query_keydown = {
+        let do_query = do_query.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                do_query();
+            }
+        })
+    };
+
+    let on_schema_change = {
+        let schema_input = schema_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            schema_input.set(target.value());
+        })
+    };
+
+    let on_schema_validate = {
+        let input = input.clone();
+        let schema_input = schema_input.clone();
+        let schema_result = schema_result.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let input_val = (*input).clone();
+            let schema = (*schema_input).clone();
+            let schema_result = schema_result.clone();
+
+            if input_val.is_empty() || schema.trim().is_empty() {
+                return;
+            }
+
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&SchemaValidateArgs {
+                    input: input_val,
+                    schema,
+                })
+                .unwrap();
+                let res = invoke("validate_json_with_schema_cmd", args).await;
+                if let Ok(result) = serde_wasm_bindgen::from_value::<JsonSchemaValidateResult>(res)
+                {
+                    schema_result.set(Some(result));
+                }
+            });
+        })
+    };
+
+    let on_compare_input_change = {
+        let compare_input = compare_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            compare_input.set(target.value());
+        })
+    };
+
+    let on_diff_id_key_change = {
+        let diff_id_key = diff_id_key.clone();
+        Callback::from(move |e: InputEvent| {
+            let input_el: web_sys::HtmlInputElement = e.target_unchecked_into();
+            diff_id_key.set(input_el.value());
+        })
+    };
+
+    let toggle_state = |state: &UseStateHandle<bool>| {
+        let state = state.clone();
+        Callback::from(move |_| state.set(!*state))
+    };
+
+    let on_diff = {
+        let input = input.clone();
+        let compare_input = compare_input.clone();
+        let diff_result = diff_result.clone();
+        let view_mode = view_mode.clone();
+        let id_key = diff_id_key.trim().to_string();
+        let options = JsonDiffOptions {
+            array_mode: if *diff_unordered {
+                JsonArrayDiffMode::Unordered
+            } else {
+                JsonArrayDiffMode::Ordered
+            },
+            array_id_key: (*diff_unordered && !id_key.is_empty()).then_some(id_key),
+            ignore_number_format: *diff_ignore_number_format,
+            ignore_key_order: *diff_ignore_key_order,
+        };
+
+        Callback::from(move |_: MouseEvent| {
+            let new_input = (*input).clone();
+            let old_input = (*compare_input).clone();
+            let diff_result = diff_result.clone();
+            let view_mode = view_mode.clone();
+            let options = options.clone();
+
+            if new_input.is_empty() || old_input.trim().is_empty() {
+                return;
+            }
+
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&DiffJsonArgs {
+                    old_input,
+                    new_input,
+                    options,
+                })
+                .unwrap();
+                let res = invoke("diff_json_cmd", args).await;
+                if let Ok(result) = serde_wasm_bindgen::from_value::<JsonDiffResult>(res) {
+                    if result.success && !result.entries.is_empty() {
+                        view_mode.set(ViewMode::Tree);
+                    }
+                    diff_result.set(Some(result));
+                }
+            });
+        })
+    };
+
+    let on_type_target_change = {
+        let type_target = type_target.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            type_target.set(match select.value().as_str() {
+                "rust" => TypeTarget::Rust,
+                "go" => TypeTarget::Go,
+                _ => TypeTarget::TypeScript,
+            });
+        })
+    };
+
+    let on_optional_rule_change = {
+        let optional_rule = optional_rule.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            optional_rule.set(match select.value().as_str() {
+                "missingOrNull" => OptionalRule::MissingOrNull,
+                "always" => OptionalRule::Always,
+                "never" => OptionalRule::Never,
+                _ => OptionalRule::Missing,
+            });
+        })
+    };
+
+    let on_type_root_name_change = {
+        let type_root_name = type_root_name.clone();
+        Callback::from(move |e: InputEvent| {
+            let input_el: web_sys::HtmlInputElement = e.target_unchecked_into();
+            type_root_name.set(input_el.value());
+        })
+    };
+
+    let on_generate_types = {
+        let input = input.clone();
+        let type_result = type_result.clone();
+        let target = *type_target;
+        let options = TypeGenOptions {
+            root_name: (*type_root_name).clone(),
+            camel_case: *type_camel_case,
+            optional_rule: *optional_rule,
+        };
+
+        Callback::from(move |_: MouseEvent| {
+            let input_val = (*input).clone();
+            let type_result = type_result.clone();
+            let options = options.clone();
+
+            if input_val.is_empty() {
+                return;
+            }
+
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&GenerateTypesArgs {
+                    input: input_val,
+                    target,
+                    options,
+                })
+                .unwrap();
+                let res = invoke("generate_types_cmd", args).await;
+                if let Ok(result) = serde_wasm_bindgen::from_value::<TypeGenResult>(res) {
+                    type_result.set(Some(result));
+                }
+            });
+        })
+    };
+
+    // Differences located in the current input, for coloring the tree
+    let diff_kinds: HashMap<String, JsonDiffKind> = match *diff_result {
+        Some(ref result) => result
+            .entries
+            .iter()
+            .filter(|entry| entry.kind != JsonDiffKind::Removed)
+            .map(|entry| (entry.path.clone(), entry.kind))
+            .collect(),
+        None => HashMap::new(),
+    };
+
+    let violation_messages: HashMap<String, Vec<String>> = {
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+        if let Some(ref result) = *schema_result {
+            for violation in &result.violations {
+                map.entry(violation.path.clone())
+                    .or_default()
+                    .push(violation.message.clone());
+            }
+        }
+        map
+    };
+
     let toggle_node = {
-        let collapsed_paths = collapsed_paths.clone();
+        let tree = tree.clone();
+        let force_update = force_update.clone();
         Callback::from(move |path: String| {
-            let mut paths = (*collapsed_paths).clone();
-            if paths.contains(&path) {
-                paths.remove(&path);
-            } else {
-                paths.insert(path);
+            let fetch_id = {
+                let mut state = tree.borrow_mut();
+                if state.expanded.remove(&path) {
+                    None
+                } else {
+                    state.expanded.insert(path.clone());
+                    state
+                        .id
+                        .clone()
+                        .filter(|_| !state.children.contains_key(&path))
+                }
+            };
+            if let Some(id) = fetch_id {
+                let tree = tree.clone();
+                let force_update = force_update.clone();
+                spawn_local(async move {
+                    if let Ok(children) = fetch_tree_children(&id, &path, 0).await {
+                        let mut state = tree.borrow_mut();
+                        if state.id.as_deref() == Some(id.as_str()) {
+                            state.children.insert(path, children);
+                        }
+                    }
+                    force_update.force_update();
+                });
             }
-            collapsed_paths.set(paths);
+            force_update.force_update();
+        })
+    };
+
+    let show_more = {
+        let tree = tree.clone();
+        let force_update = force_update.clone();
+        Callback::from(move |path: String| {
+            let state = tree.borrow();
+            let Some(id) = state.id.clone() else {
+                return;
+            };
+            let offset = state.children.get(&path).map_or(0, Vec::len);
+            let tree = tree.clone();
+            let force_update = force_update.clone();
+            spawn_local(async move {
+                if let Ok(more) = fetch_tree_children(&id, &path, offset).await {
+                    let mut state = tree.borrow_mut();
+                    if state.id.as_deref() == Some(id.as_str()) {
+                        state.children.entry(path).or_default().extend(more);
+                    }
+                }
+                force_update.force_update();
+            });
         })
     };
 
     let render_tree_node = {
-        let collapsed_paths = collapsed_paths.clone();
+        let tree = tree.clone();
         let on_copy_path = on_copy_path.clone();
         let toggle_node = toggle_node.clone();
+        let show_more = show_more.clone();
+
+        struct TreeCallbacks<'a> {
+            on_copy_path: &'a Callback<String>,
+            toggle_node: &'a Callback<String>,
+            show_more: &'a Callback<String>,
+            show_more_label: &'a str,
+        }
 
         fn render_node(
-            node: &JsonTreeNode,
+            node: &JsonTreeNodeSummary,
             depth: usize,
-            collapsed_paths: &std::collections::HashSet<String>,
-            on_copy_path: &Callback<String>,
-            toggle_node: &Callback<String>,
+            tree: &TreeState,
+            callbacks: &TreeCallbacks,
+            violations: &HashMap<String, Vec<String>>,
+            diff_kinds: &HashMap<String, JsonDiffKind>,
         ) -> Html {
-            let is_collapsed = collapsed_paths.contains(&node.path);
-            let has_children = !node.children.is_empty();
+            let is_collapsed = !tree.expanded.contains(&node.path);
+            let violation = violations
+                .get(&node.path)
+                .map(|messages| messages.join("\n"));
+            let has_violation = violation.is_some();
+            let diff_class = diff_kinds.get(&node.path).map(JsonDiffKind::class);
+            let has_children = node.child_count > 0;
             let indent = depth * 20;
 
             let value_class = match node.value_type {
@@ -563,19 +1221,31 @@ pub fn json_formatter(props: &Props) -> Html {
 
             let toggle_cb = {
                 let path = node.path.clone();
-                let toggle_node = toggle_node.clone();
+                let toggle_node = callbacks.toggle_node.clone();
                 Callback::from(move |_| toggle_node.emit(path.clone()))
             };
 
             let copy_cb = {
                 let path = node.path.clone();
-                let on_copy_path = on_copy_path.clone();
+                let on_copy_path = callbacks.on_copy_path.clone();
                 Callback::from(move |_| on_copy_path.emit(path.clone()))
             };
 
+            let show_more_cb = {
+                let path = node.path.clone();
+                let show_more = callbacks.show_more.clone();
+                Callback::from(move |_| show_more.emit(path.clone()))
+            };
+
+            let children = tree.children.get(&node.path);
+            let loaded = children.map_or(0, Vec::len);
+
             html! {
                 <div class="tree-node" style={format!("padding-left: {}px", indent)}>
-                    <div class="tree-node-content">
+                    <div
+                        class={classes!("tree-node-content", has_violation.then_some("schema-violation"), diff_class)}
+                        title={violation}
+                    >
                         if has_children {
                             <button class="tree-toggle" onclick={toggle_cb}>
                                 if is_collapsed {
@@ -605,17 +1275,43 @@ pub fn json_formatter(props: &Props) -> Html {
                     </div>
                     if has_children && !is_collapsed {
                         <div class="tree-children">
-                            { for node.children.iter().map(|child| {
-                                render_node(child, depth + 1, collapsed_paths, on_copy_path, toggle_node)
-                            })}
+                            if let Some(children) = children {
+                                { for children.iter().map(|child| {
+                                    render_node(child, depth + 1, tree, callbacks, violations, diff_kinds)
+                                })}
+                                if loaded < node.child_count {
+                                    <button class="tree-show-more" onclick={show_more_cb}>
+                                        {format!("{} ({} / {})", callbacks.show_more_label, loaded, node.child_count)}
+                                    </button>
+                                }
+                            } else {
+                                <div class="tree-loading">{"…"}</div>
+                            }
                         </div>
                     }
                 </div>
             }
         }
 
-        move |node: &JsonTreeNode| {
-            render_node(node, 0, &collapsed_paths, &on_copy_path, &toggle_node)
+        let show_more_label = i18n.t("json_formatter.tree_show_more");
+        move || {
+            let state = tree.borrow();
+            let callbacks = TreeCallbacks {
+                on_copy_path: &on_copy_path,
+                toggle_node: &toggle_node,
+                show_more: &show_more,
+                show_more_label: &show_more_label,
+            };
+            state.root.as_ref().map(|root| {
+                render_node(
+                    root,
+                    0,
+                    &state,
+                    &callbacks,
+                    &violation_messages,
+                    &diff_kinds,
+                )
+            })
         }
     };
 
@@ -759,6 +1455,72 @@ pub fn json_formatter(props: &Props) -> Html {
                 }
             </div>
 
+            <div class="section search-section query-section">
+                <div class="search-input-group">
+                    <select class="query-type-select" onchange={on_query_type_change}>
+                        <option value="jsonPath" selected={*query_type == JsonQueryType::JsonPath}>{"JSONPath"}</option>
+                        <option value="pointer" selected={*query_type == JsonQueryType::Pointer}>{"JSON Pointer"}</option>
+                    </select>
+                    <input
+                        type="text"
+                        class="search-input"
+                        placeholder={if *query_type == JsonQueryType::Pointer {
+                            i18n.t("json_formatter.query_placeholder_pointer")
+                        } else {
+                            i18n.t("json_formatter.query_placeholder_path")
+                        }}
+                        value={(*query_text).clone()}
+                        oninput={on_query_change}
+                        onkeydown={on_query_keydown}
+                    />
+                    <button class="primary-btn" onclick={on_query_click}>
+                        {i18n.t("json_formatter.extract")}
+                    </button>
+                </div>
+                if let Some(ref result) = *query_result {
+                    if let Some(ref error) = result.error {
+                        <p class="error-message">{error}</p>
+                    } else {
+                        <div class="search-results">
+                            <div class="query-results-header">
+                                <span class="results-count">{format!("{} {}", result.total_count, i18n.t("regex_tester.matches"))}</span>
+                                if !result.formatted.is_empty() {
+                                    <button
+                                        class="secondary-btn"
+                                        onclick={
+                                            let on_copy_path = on_copy_path.clone();
+                                            let formatted = result.formatted.clone();
+                                            Callback::from(move |_| on_copy_path.emit(formatted.clone()))
+                                        }
+                                    >
+                                        {i18n.t("json_formatter.copy_extracted")}
+                                    </button>
+                                }
+                            </div>
+                            if !result.matches.is_empty() {
+                                <div class="matches-list">
+                                    { for result.matches.iter().take(20).map(|m| {
+                                        let path = m.path.clone();
+                                        let on_copy_path = on_copy_path.clone();
+                                        html! {
+                                            <div class="match-item" onclick={Callback::from(move |_| on_copy_path.emit(path.clone()))}>
+                                                <span class="match-path">{&m.path}</span>
+                                                <span class="match-value">{&m.value}</span>
+                                            </div>
+                                        }
+                                    })}
+                                    if result.total_count > 20 {
+                                        <div class="more-results">
+                                            {format!("... +{}", result.total_count - 20)}
+                                        </div>
+                                    }
+                                </div>
+                            }
+                        </div>
+                    }
+                }
+            </div>
+
             <div class="section input-output-section">
                 <div class="panel input-panel">
                     <div class="panel-header">
@@ -797,8 +1559,8 @@ pub fn json_formatter(props: &Props) -> Html {
                         <pre class="json-output">{&*output}</pre>
                     } else {
                         <div class="tree-view">
-                            if let Some(ref tree) = *tree_data {
-                                {render_tree_node(tree)}
+                            if let Some(tree) = render_tree_node() {
+                                {tree}
                             } else {
                                 <div class="tree-placeholder">
                                     {i18n.t("json_formatter.tree_placeholder")}
@@ -809,6 +1571,217 @@ pub fn json_formatter(props: &Props) -> Html {
                 </div>
             </div>
 
+            <div class="section schema-section">
+                <div class="panel-header">
+                    <h4>{i18n.t("json_formatter.schema")}</h4>
+                    <div class="panel-actions">
+                        <button class="primary-btn" onclick={on_schema_validate}>
+                            {i18n.t("json_formatter.validate_schema")}
+                        </button>
+                    </div>
+                </div>
+                <textarea
+                    class="json-textarea schema-textarea"
+                    placeholder={i18n.t("json_formatter.schema_placeholder")}
+                    value={(*schema_input).clone()}
+                    oninput={on_schema_change}
+                    spellcheck="false"
+                />
+                if let Some(ref result) = *schema_result {
+                    if let Some(ref error) = result.schema_error {
+                        <p class="error-message">{format!("{}: {}", i18n.t("json_formatter.schema_error"), error)}</p>
+                    } else if let Some(ref error) = result.error {
+                        <p class="error-message">{error}</p>
+                    } else if result.valid {
+                        <div class="status-badge valid">{i18n.t("json_formatter.schema_valid")}</div>
+                    } else {
+                        <div class="search-results">
+                            <span class="results-count">
+                                {format!("{} {}", result.violations.len(), i18n.t("json_formatter.schema_violations"))}
+                            </span>
+                            <div class="matches-list">
+                                { for result.violations.iter().map(|v| {
+                                    let path = v.path.clone();
+                                    let on_copy_path = on_copy_path.clone();
+                                    html! {
+                                        <div class="match-item" onclick={Callback::from(move |_| on_copy_path.emit(path.clone()))}>
+                                            <span class="match-path">{&v.path}</span>
+                                            <span class="violation-keyword">{&v.keyword}</span>
+                                            <span class="violation-message">{&v.message}</span>
+                                        </div>
+                                    }
+                                })}
+                            </div>
+                        </div>
+                    }
+                }
+            </div>
+
+            <div class="section schema-section diff-section">
+                <div class="panel-header">
+                    <h4>{i18n.t("json_formatter.diff")}</h4>
+                    <div class="panel-actions">
+                        <button class="primary-btn" onclick={on_diff}>
+                            {i18n.t("json_formatter.compare")}
+                        </button>
+                    </div>
+                </div>
+                <div class="search-options">
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={*diff_unordered}
+                            onchange={toggle_state(&diff_unordered)}
+                        />
+                        {i18n.t("json_formatter.diff_ignore_array_order")}
+                    </label>
+                    if *diff_unordered {
+                        <input
+                            type="text"
+                            class="diff-id-key"
+                            placeholder={i18n.t("json_formatter.diff_id_key")}
+                            value={(*diff_id_key).clone()}
+                            oninput={on_diff_id_key_change}
+                        />
+                    }
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={*diff_ignore_number_format}
+                            onchange={toggle_state(&diff_ignore_number_format)}
+                        />
+                        {i18n.t("json_formatter.diff_ignore_number_format")}
+                    </label>
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={*diff_ignore_key_order}
+                            onchange={toggle_state(&diff_ignore_key_order)}
+                        />
+                        {i18n.t("json_formatter.diff_ignore_key_order")}
+                    </label>
+                </div>
+                <textarea
+                    class="json-textarea schema-textarea"
+                    placeholder={i18n.t("json_formatter.diff_placeholder")}
+                    value={(*compare_input).clone()}
+                    oninput={on_compare_input_change}
+                    spellcheck="false"
+                />
+                if let Some(ref result) = *diff_result {
+                    if let Some(ref error) = result.error {
+                        <p class="error-message">{error}</p>
+                    } else if result.entries.is_empty() {
+                        <div class="status-badge valid">{i18n.t("json_formatter.diff_identical")}</div>
+                    } else {
+                        <div class="search-results">
+                            <span class="results-count">
+                                {format!(
+                                    "+{} −{} ~{} {} {}",
+                                    result.added,
+                                    result.removed,
+                                    result.changed,
+                                    result.type_changed,
+                                    i18n.t("json_formatter.diff_type_changed")
+                                )}
+                            </span>
+                            <div class="matches-list">
+                                { for result.entries.iter().map(|entry| {
+                                    let path = entry.path.clone();
+                                    let on_copy_path = on_copy_path.clone();
+                                    let values = match (&entry.old_value, &entry.new_value) {
+                                        (Some(old), Some(new)) => format!("{} → {}", old, new),
+                                        (Some(old), None) => old.clone(),
+                                        (None, Some(new)) => new.clone(),
+                                        (None, None) => String::new(),
+                                    };
+                                    html! {
+                                        <div
+                                            class={classes!("match-item", entry.kind.class())}
+                                            onclick={Callback::from(move |_| on_copy_path.emit(path.clone()))}
+                                        >
+                                            <span class="match-path">{&entry.path}</span>
+                                            <span class="diff-values">{values}</span>
+                                        </div>
+                                    }
+                                })}
+                            </div>
+                        </div>
+                    }
+                }
+            </div>
+
+            <div class="section schema-section type-gen-section">
+                <div class="panel-header">
+                    <h4>{i18n.t("json_formatter.type_gen")}</h4>
+                    <div class="panel-actions">
+                        if let Some(ref result) = *type_result {
+                            if result.success {
+                                <button
+                                    class="secondary-btn"
+                                    onclick={
+                                        let on_copy_path = on_copy_path.clone();
+                                        let code = result.code.clone();
+                                        Callback::from(move |_| on_copy_path.emit(code.clone()))
+                                    }
+                                >
+                                    {i18n.t("common.copy")}
+                                </button>
+                            }
+                        }
+                        <button class="primary-btn" onclick={on_generate_types}>
+                            {i18n.t("json_formatter.type_gen_generate")}
+                        </button>
+                    </div>
+                </div>
+                <div class="search-options">
+                    <select class="query-type-select" onchange={on_type_target_change}>
+                        <option value="typeScript" selected={*type_target == TypeTarget::TypeScript}>{"TypeScript"}</option>
+                        <option value="rust" selected={*type_target == TypeTarget::Rust}>{"Rust"}</option>
+                        <option value="go" selected={*type_target == TypeTarget::Go}>{"Go"}</option>
+                    </select>
+                    <input
+                        type="text"
+                        class="diff-id-key"
+                        placeholder={i18n.t("json_formatter.type_gen_root_name")}
+                        title={i18n.t("json_formatter.type_gen_root_name")}
+                        value={(*type_root_name).clone()}
+                        oninput={on_type_root_name_change}
+                    />
+                    <select class="query-type-select" onchange={on_optional_rule_change}>
+                        <option value="missing" selected={*optional_rule == OptionalRule::Missing}>
+                            {i18n.t("json_formatter.optional_missing")}
+                        </option>
+                        <option value="missingOrNull" selected={*optional_rule == OptionalRule::MissingOrNull}>
+                            {i18n.t("json_formatter.optional_missing_or_null")}
+                        </option>
+                        <option value="always" selected={*optional_rule == OptionalRule::Always}>
+                            {i18n.t("json_formatter.optional_always")}
+                        </option>
+                        <option value="never" selected={*optional_rule == OptionalRule::Never}>
+                            {i18n.t("json_formatter.optional_never")}
+                        </option>
+                    </select>
+                    if *type_target == TypeTarget::TypeScript {
+                        <label class="checkbox-label">
+                            <input
+                                type="checkbox"
+                                checked={*type_camel_case}
+                                onchange={toggle_state(&type_camel_case)}
+                            />
+                            {i18n.t("json_formatter.type_gen_camel_case")}
+                        </label>
+                    }
+                </div>
+                if let Some(ref result) = *type_result {
+                    if let Some(ref error) = result.error {
+                        <p class="error-message">{error}</p>
+                    } else {
+                        <pre class="json-output type-gen-output">{&result.code}</pre>
+                    }
+                }
+            </div>
+
             if *is_processing {
                 <div class="processing-overlay">
                     <span class="spinner"></span>
@@ -9,6 +9,15 @@ use yew::prelude::*;
 extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn save(options: JsValue) -> JsValue;
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -64,6 +73,13 @@ impl TaskColumn {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Subtask {
+    pub id: String,
+    pub title: String,
+    pub done: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
@@ -75,6 +91,12 @@ pub struct Task {
     pub due_date: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub position: u32,
+    #[serde(default)]
+    pub subtasks: Vec<Subtask>,
+    #[serde(default)]
+    pub subtask_progress: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,16 +108,51 @@ pub struct ColumnConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KanbanBoard {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
     pub tasks: Vec<Task>,
     pub columns: Vec<ColumnConfig>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BoardSummary {
+    pub id: String,
+    pub name: String,
+    pub task_count: usize,
+    pub last_opened: bool,
+}
+
 #[derive(Serialize)]
-struct EmptyArgs {}
+#[serde(rename_all = "camelCase")]
+struct LoadBoardArgs {
+    board_id: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CreateBoardArgs {
+    name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RenameBoardArgs {
+    board_id: String,
+    name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteBoardArgs {
+    board_id: String,
+    confirmed: bool,
+}
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct CreateTaskArgs {
+    board_id: Option<String>,
     title: String,
     description: Option<String>,
     priority: TaskPriority,
@@ -106,14 +163,113 @@ struct CreateTaskArgs {
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct DeleteTaskArgs {
+    board_id: Option<String>,
     task_id: String,
 }
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct MoveTaskArgs {
+    board_id: Option<String>,
+    task_id: String,
+    column: TaskColumn,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReorderTaskArgs {
+    board_id: Option<String>,
     task_id: String,
     column: TaskColumn,
+    new_index: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AddSubtaskArgs {
+    board_id: Option<String>,
+    task_id: String,
+    title: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubtaskArgs {
+    board_id: Option<String>,
+    task_id: String,
+    subtask_id: String,
+}
+
+enum SubtaskAction {
+    Add(String),
+    Toggle(String),
+    Delete(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum BoardExportFormat {
+    Json,
+    Csv,
+}
+
+impl BoardExportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            BoardExportFormat::Json => "json",
+            BoardExportFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportBoardArgs {
+    board_id: Option<String>,
+    path: String,
+    format: BoardExportFormat,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportBoardArgs {
+    board_id: Option<String>,
+    path: String,
+    merge: bool,
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct SaveDialogOptions {
+    filters: Vec<FileFilter>,
+    #[serde(rename = "defaultPath")]
+    default_path: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+async fn fetch_boards() -> Vec<BoardSummary> {
+    let result = invoke("list_boards_cmd", JsValue::NULL).await;
+    serde_wasm_bindgen::from_value(result).unwrap_or_default()
+}
+
+async fn fetch_board(board_id: Option<String>) -> Result<KanbanBoard, String> {
+    let args = serde_wasm_bindgen::to_value(&LoadBoardArgs { board_id }).unwrap();
+    match try_invoke("load_kanban_board_cmd", args).await {
+        Ok(result) => serde_wasm_bindgen::from_value(result).map_err(|e| e.to_string()),
+        Err(e) => Err(e
+            .as_string()
+            .unwrap_or_else(|| "ボードの読み込みに失敗しました".to_string())),
+    }
 }
 
 #[derive(Properties, PartialEq)]
@@ -128,8 +284,20 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
     let search_query = use_state(String::new);
     let dragging_task_id = use_state(|| Option::<String>::None);
     let hover_column = use_state(|| Option::<TaskColumn>::None);
+    let import_merge = use_state(|| true);
+    let expanded_task_id = use_state(|| Option::<String>::None);
+    let new_subtask_title = use_state(String::new);
+    // (is_error, message) from the last export/import
+    let transfer_status = use_state(|| Option::<(bool, String)>::None);
     let drag_pos = use_state(|| (0i32, 0i32));
     let drag_offset = use_state(|| (0i32, 0i32));
+    let boards = use_state(Vec::<BoardSummary>::new);
+    // (is_rename, name) while the board name field is shown
+    let board_name_edit = use_state(|| Option::<(bool, String)>::None);
+    // Board waiting for the user to confirm deleting its tasks
+    let pending_board_delete = use_state(|| Option::<BoardSummary>::None);
+    let board_error = use_state(|| Option::<String>::None);
+    let board_id = (*board).as_ref().map(|b| b.id.clone());
 
     // Form states
     let new_title = use_state(String::new);
@@ -137,18 +305,18 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
     let new_priority = use_state(|| TaskPriority::Medium);
     let new_due_date = use_state(String::new);
 
-    // Load board on mount
+    // Load the last opened board on mount
     {
         let board = board.clone();
+        let boards = boards.clone();
         let is_loading = is_loading.clone();
 
         use_effect_with((), move |_| {
             spawn_local(async move {
-                let args = serde_wasm_bindgen::to_value(&EmptyArgs {}).unwrap();
-                let result = invoke("load_kanban_board_cmd", args).await;
-                if let Ok(data) = serde_wasm_bindgen::from_value::<KanbanBoard>(result) {
+                if let Ok(data) = fetch_board(None).await {
                     board.set(Some(data));
                 }
+                boards.set(fetch_boards().await);
                 is_loading.set(false);
             });
             || {}
@@ -226,6 +394,154 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
         })
     };
 
+    let on_subtask = {
+        let board = board.clone();
+        let board_id = board_id.clone();
+        Callback::from(move |(task_id, action): (String, SubtaskAction)| {
+            let board = board.clone();
+            let board_id = board_id.clone();
+            spawn_local(async move {
+                let (cmd, args) = match action {
+                    SubtaskAction::Add(title) => (
+                        "add_subtask_cmd",
+                        serde_wasm_bindgen::to_value(&AddSubtaskArgs {
+                            board_id,
+                            task_id: task_id.clone(),
+                            title,
+                        }),
+                    ),
+                    SubtaskAction::Toggle(subtask_id) => (
+                        "toggle_subtask_cmd",
+                        serde_wasm_bindgen::to_value(&SubtaskArgs {
+                            board_id,
+                            task_id: task_id.clone(),
+                            subtask_id,
+                        }),
+                    ),
+                    SubtaskAction::Delete(subtask_id) => (
+                        "delete_subtask_cmd",
+                        serde_wasm_bindgen::to_value(&SubtaskArgs {
+                            board_id,
+                            task_id: task_id.clone(),
+                            subtask_id,
+                        }),
+                    ),
+                };
+                match try_invoke(cmd, args.unwrap()).await {
+                    Ok(result) => {
+                        if let Ok(updated_task) = serde_wasm_bindgen::from_value::<Task>(result) {
+                            if let Some(mut b) = (*board).clone() {
+                                if let Some(task) = b.tasks.iter_mut().find(|t| t.id == task_id) {
+                                    *task = updated_task;
+                                }
+                                board.set(Some(b));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        web_sys::console::error_1(&format!("{} failed: {:?}", cmd, e).into());
+                    }
+                }
+            });
+        })
+    };
+
+    let on_export = {
+        let transfer_status = transfer_status.clone();
+        let board_id = board_id.clone();
+        Callback::from(move |format: BoardExportFormat| {
+            let transfer_status = transfer_status.clone();
+            let board_id = board_id.clone();
+            spawn_local(async move {
+                let ext = format.extension();
+                let save_options = SaveDialogOptions {
+                    filters: vec![FileFilter {
+                        name: ext.to_uppercase(),
+                        extensions: vec![ext.to_string()],
+                    }],
+                    default_path: Some(format!("kanban.{}", ext)),
+                };
+                let save_result = save(serde_wasm_bindgen::to_value(&save_options).unwrap()).await;
+                let Some(path) = save_result.as_string() else {
+                    return;
+                };
+
+                let args = serde_wasm_bindgen::to_value(&ExportBoardArgs {
+                    board_id,
+                    path,
+                    format,
+                })
+                .unwrap();
+                match try_invoke("export_kanban_board_cmd", args).await {
+                    Ok(_) => transfer_status.set(Some((false, "エクスポートしました".to_string()))),
+                    Err(e) => transfer_status.set(Some((
+                        true,
+                        e.as_string()
+                            .unwrap_or_else(|| "エクスポートに失敗しました".to_string()),
+                    ))),
+                }
+            });
+        })
+    };
+
+    let on_import = {
+        let board = board.clone();
+        let board_id = board_id.clone();
+        let import_merge = import_merge.clone();
+        let transfer_status = transfer_status.clone();
+        Callback::from(move |_: MouseEvent| {
+            let board = board.clone();
+            let board_id = board_id.clone();
+            let merge = *import_merge;
+            let transfer_status = transfer_status.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    filters: vec![FileFilter {
+                        name: "Kanban Board".to_string(),
+                        extensions: vec!["json".to_string(), "csv".to_string()],
+                    }],
+                };
+                let result = open(serde_wasm_bindgen::to_value(&options).unwrap()).await;
+                let Some(path) = result.as_string() else {
+                    return;
+                };
+
+                let args = serde_wasm_bindgen::to_value(&ImportBoardArgs {
+                    board_id,
+                    path,
+                    merge,
+                })
+                .unwrap();
+                match try_invoke("import_kanban_board_cmd", args).await {
+                    Ok(result) => {
+                        if let Ok(imported) = serde_wasm_bindgen::from_value::<KanbanBoard>(result)
+                        {
+                            transfer_status.set(Some((
+                                false,
+                                format!("インポートしました（{}件のタスク）", imported.tasks.len()),
+                            )));
+                            board.set(Some(imported));
+                        }
+                    }
+                    Err(e) => transfer_status.set(Some((
+                        true,
+                        e.as_string()
+                            .unwrap_or_else(|| "インポートに失敗しました".to_string()),
+                    ))),
+                }
+            });
+        })
+    };
+
+    let on_import_merge_change = {
+        let import_merge = import_merge.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            import_merge.set(input.checked());
+        })
+    };
+
     let on_open_create_modal = {
         let show_create_modal = show_create_modal.clone();
         let new_title = new_title.clone();
@@ -252,6 +568,7 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
 
     let on_create_task = {
         let board = board.clone();
+        let board_id = board_id.clone();
         let show_create_modal = show_create_modal.clone();
         let new_title = new_title.clone();
         let new_description = new_description.clone();
@@ -259,6 +576,7 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
         let new_due_date = new_due_date.clone();
         Callback::from(move |_| {
             let board = board.clone();
+            let board_id = board_id.clone();
             let show_create_modal = show_create_modal.clone();
             let title = (*new_title).clone();
             let description = if new_description.is_empty() {
@@ -279,6 +597,7 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
 
             spawn_local(async move {
                 let args = serde_wasm_bindgen::to_value(&CreateTaskArgs {
+                    board_id,
                     title,
                     description,
                     priority,
@@ -300,11 +619,14 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
 
     let on_delete_task = {
         let board = board.clone();
+        let board_id = board_id.clone();
         Callback::from(move |task_id: String| {
             let board = board.clone();
+            let board_id = board_id.clone();
             let task_id_clone = task_id.clone();
             spawn_local(async move {
                 let args = serde_wasm_bindgen::to_value(&DeleteTaskArgs {
+                    board_id,
                     task_id: task_id_clone.clone(),
                 })
                 .unwrap();
@@ -321,13 +643,16 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
 
     let on_move_task = {
         let board = board.clone();
+        let board_id = board_id.clone();
         Callback::from(move |(task_id, column): (String, TaskColumn)| {
             let board = board.clone();
+            let board_id = board_id.clone();
             let task_id_clone = task_id.clone();
             let column_clone = column.clone();
             web_sys::console::log_1(&format!("Moving task {} to {:?}", task_id, column).into());
             spawn_local(async move {
                 let args = serde_wasm_bindgen::to_value(&MoveTaskArgs {
+                    board_id,
                     task_id: task_id_clone.clone(),
                     column: column_clone,
                 })
@@ -354,6 +679,211 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
         })
     };
 
+    let on_reorder_task = {
+        let board = board.clone();
+        let board_id = board_id.clone();
+        Callback::from(
+            move |(task_id, column, new_index): (String, TaskColumn, usize)| {
+                let board = board.clone();
+                let board_id = board_id.clone();
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&ReorderTaskArgs {
+                        board_id,
+                        task_id,
+                        column,
+                        new_index,
+                    })
+                    .unwrap();
+                    let result = invoke("reorder_task_cmd", args).await;
+                    match serde_wasm_bindgen::from_value::<KanbanBoard>(result) {
+                        Ok(updated_board) => board.set(Some(updated_board)),
+                        Err(e) => {
+                            web_sys::console::error_1(
+                                &format!("Failed to reorder task: {:?}", e).into(),
+                            );
+                        }
+                    }
+                });
+            },
+        )
+    };
+
+    // Open another board; the backend remembers it as the last opened one
+    let on_select_board = {
+        let board = board.clone();
+        let boards = boards.clone();
+        let board_error = board_error.clone();
+        let expanded_task_id = expanded_task_id.clone();
+        Callback::from(move |target_id: String| {
+            let board = board.clone();
+            let boards = boards.clone();
+            let board_error = board_error.clone();
+            expanded_task_id.set(None);
+            spawn_local(async move {
+                match fetch_board(Some(target_id)).await {
+                    Ok(data) => {
+                        board.set(Some(data));
+                        board_error.set(None);
+                    }
+                    Err(e) => board_error.set(Some(e)),
+                }
+                boards.set(fetch_boards().await);
+            });
+        })
+    };
+
+    let on_board_select_change = {
+        let on_select_board = on_select_board.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            on_select_board.emit(select.value());
+        })
+    };
+
+    let on_start_create_board = {
+        let board_name_edit = board_name_edit.clone();
+        Callback::from(move |_: MouseEvent| {
+            board_name_edit.set(Some((false, String::new())));
+        })
+    };
+
+    let on_start_rename_board = {
+        let board_name_edit = board_name_edit.clone();
+        let current_name = (*board)
+            .as_ref()
+            .map(|b| b.name.clone())
+            .unwrap_or_default();
+        Callback::from(move |_: MouseEvent| {
+            board_name_edit.set(Some((true, current_name.clone())));
+        })
+    };
+
+    let on_board_name_input = {
+        let board_name_edit = board_name_edit.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Some((is_rename, _)) = *board_name_edit {
+                board_name_edit.set(Some((is_rename, input.value())));
+            }
+        })
+    };
+
+    let on_cancel_board_name = {
+        let board_name_edit = board_name_edit.clone();
+        Callback::from(move |_: MouseEvent| {
+            board_name_edit.set(None);
+        })
+    };
+
+    let on_submit_board_name = {
+        let board = board.clone();
+        let board_id = board_id.clone();
+        let boards = boards.clone();
+        let board_name_edit = board_name_edit.clone();
+        let board_error = board_error.clone();
+        let on_select_board = on_select_board.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some((is_rename, name)) = (*board_name_edit).clone() else {
+                return;
+            };
+            let board = board.clone();
+            let board_id = board_id.clone();
+            let boards = boards.clone();
+            let board_name_edit = board_name_edit.clone();
+            let board_error = board_error.clone();
+            let on_select_board = on_select_board.clone();
+            spawn_local(async move {
+                let result = if is_rename {
+                    let args = serde_wasm_bindgen::to_value(&RenameBoardArgs {
+                        board_id: board_id.unwrap_or_default(),
+                        name,
+                    })
+                    .unwrap();
+                    try_invoke("rename_board_cmd", args).await
+                } else {
+                    let args = serde_wasm_bindgen::to_value(&CreateBoardArgs { name }).unwrap();
+                    try_invoke("create_board_cmd", args).await
+                };
+                let summary = match result {
+                    Ok(value) => serde_wasm_bindgen::from_value::<BoardSummary>(value).ok(),
+                    Err(e) => {
+                        board_error.set(e.as_string());
+                        return;
+                    }
+                };
+                board_name_edit.set(None);
+                board_error.set(None);
+                let Some(summary) = summary else {
+                    return;
+                };
+                if is_rename {
+                    if let Some(mut b) = (*board).clone() {
+                        b.name = summary.name;
+                        board.set(Some(b));
+                    }
+                    boards.set(fetch_boards().await);
+                } else {
+                    on_select_board.emit(summary.id);
+                }
+            });
+        })
+    };
+
+    let delete_board = {
+        let board = board.clone();
+        let boards = boards.clone();
+        let board_error = board_error.clone();
+        let pending_board_delete = pending_board_delete.clone();
+        Callback::from(move |(target_id, confirmed): (String, bool)| {
+            let board = board.clone();
+            let boards = boards.clone();
+            let board_error = board_error.clone();
+            let pending_board_delete = pending_board_delete.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&DeleteBoardArgs {
+                    board_id: target_id,
+                    confirmed,
+                })
+                .unwrap();
+                pending_board_delete.set(None);
+                match try_invoke("delete_board_cmd", args).await {
+                    Ok(value) => {
+                        boards.set(serde_wasm_bindgen::from_value(value).unwrap_or_default());
+                        board_error.set(None);
+                        if let Ok(data) = fetch_board(None).await {
+                            board.set(Some(data));
+                        }
+                    }
+                    Err(e) => board_error.set(e.as_string()),
+                }
+            });
+        })
+    };
+
+    // Boards that still have tasks ask for confirmation first
+    let on_delete_board = {
+        let current = boards
+            .iter()
+            .find(|b| Some(&b.id) == board_id.as_ref())
+            .cloned();
+        let task_count = (*board).as_ref().map(|b| b.tasks.len()).unwrap_or(0);
+        let pending_board_delete = pending_board_delete.clone();
+        let delete_board = delete_board.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(summary) = current.clone() else {
+                return;
+            };
+            if task_count > 0 {
+                pending_board_delete.set(Some(BoardSummary {
+                    task_count,
+                    ..summary
+                }));
+            } else {
+                delete_board.emit((summary.id, false));
+            }
+        })
+    };
+
     // Filter tasks based on search query
     let filtered_tasks: Vec<Task> = if let Some(b) = (*board).clone() {
         if search_query.is_empty() {
@@ -407,6 +937,80 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
         <div class="kanban-board">
             <h2>{"📋 Kanban Board"}</h2>
 
+            // Board switcher
+            <div class="kanban-boards section">
+                if let Some((is_rename, name)) = (*board_name_edit).clone() {
+                    <input
+                        type="text"
+                        class="kanban-board-name-input"
+                        placeholder="ボード名"
+                        value={name}
+                        oninput={on_board_name_input}
+                    />
+                    <button class="primary-btn" onclick={on_submit_board_name}>
+                        { if is_rename { "変更" } else { "作成" } }
+                    </button>
+                    <button class="secondary-btn" onclick={on_cancel_board_name}>
+                        {"キャンセル"}
+                    </button>
+                } else {
+                    <select class="kanban-board-select" onchange={on_board_select_change}>
+                        { for boards.iter().map(|b| html! {
+                            <option value={b.id.clone()} selected={Some(&b.id) == board_id.as_ref()}>
+                                {&b.name}
+                            </option>
+                        })}
+                    </select>
+                    <button class="secondary-btn" onclick={on_start_create_board}>
+                        {"＋ ボード"}
+                    </button>
+                    <button class="secondary-btn" onclick={on_start_rename_board}>
+                        {"名前変更"}
+                    </button>
+                    <button
+                        class="secondary-btn"
+                        onclick={on_delete_board}
+                        disabled={boards.len() <= 1}
+                    >
+                        {"ボード削除"}
+                    </button>
+                }
+            </div>
+
+            if let Some(pending) = (*pending_board_delete).clone() {
+                <div class="kanban-board-confirm">
+                    <span>
+                        {format!(
+                            "ボード「{}」には{}件のタスクがあります。タスクごと削除しますか？",
+                            pending.name, pending.task_count
+                        )}
+                    </span>
+                    <button
+                        class="secondary-btn kanban-board-delete"
+                        onclick={
+                            let delete_board = delete_board.clone();
+                            let id = pending.id.clone();
+                            Callback::from(move |_| delete_board.emit((id.clone(), true)))
+                        }
+                    >
+                        {"削除する"}
+                    </button>
+                    <button
+                        class="secondary-btn"
+                        onclick={
+                            let pending_board_delete = pending_board_delete.clone();
+                            Callback::from(move |_| pending_board_delete.set(None))
+                        }
+                    >
+                        {"キャンセル"}
+                    </button>
+                </div>
+            }
+
+            if let Some(error) = (*board_error).clone() {
+                <div class="kanban-transfer-status error">{error}</div>
+            }
+
             // Toolbar
             <div class="kanban-toolbar section">
                 <div class="search-box">
@@ -418,11 +1022,48 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
                         oninput={on_search_change}
                     />
                 </div>
+                <div class="kanban-transfer">
+                    <button
+                        class="secondary-btn"
+                        onclick={
+                            let on_export = on_export.clone();
+                            Callback::from(move |_| on_export.emit(BoardExportFormat::Json))
+                        }
+                    >
+                        {"JSON出力"}
+                    </button>
+                    <button
+                        class="secondary-btn"
+                        onclick={
+                            let on_export = on_export.clone();
+                            Callback::from(move |_| on_export.emit(BoardExportFormat::Csv))
+                        }
+                    >
+                        {"CSV出力"}
+                    </button>
+                    <button class="secondary-btn" onclick={on_import}>
+                        {"インポート"}
+                    </button>
+                    <label class="toolbar-checkbox" title="オフにすると現在のボードを置き換えます">
+                        <input
+                            type="checkbox"
+                            checked={*import_merge}
+                            onchange={on_import_merge_change}
+                        />
+                        {"既存に追加"}
+                    </label>
+                </div>
                 <button class="primary-btn" onclick={on_open_create_modal}>
                     {"＋ タスク追加"}
                 </button>
             </div>
 
+            if let Some((is_error, message)) = (*transfer_status).clone() {
+                <div class={classes!("kanban-transfer-status", is_error.then_some("error"))}>
+                    {message}
+                </div>
+            }
+
             if *is_loading {
                 <div class="loading-state">
                     <div class="spinner-large"></div>
@@ -432,12 +1073,27 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
                 // Columns
                 <div class="kanban-columns">
                     { for columns.iter().map(|col| {
-                        let col_tasks: Vec<&Task> = filtered_tasks.iter()
+                        let mut col_tasks: Vec<&Task> = filtered_tasks.iter()
                             .filter(|t| t.column == *col)
                             .collect();
+                        col_tasks.sort_by_key(|t| t.position);
+
+                        // Unfiltered column order, so drops while searching land at the right index
+                        let mut col_order: Vec<(u32, String)> = board
+                            .as_ref()
+                            .map(|b| {
+                                b.tasks
+                                    .iter()
+                                    .filter(|t| t.column == *col)
+                                    .map(|t| (t.position, t.id.clone()))
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        col_order.sort();
 
                         let on_delete = on_delete_task.clone();
                         let on_move = on_move_task.clone();
+                        let on_reorder = on_reorder_task.clone();
                         let col_clone = col.clone();
                         let dragging = (*dragging_task_id).clone();
                         let current_hover = (*hover_column).clone();
@@ -509,10 +1165,10 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
                                             let drag_offset = drag_offset.clone();
                                             let task_id = task_id.clone();
                                             Callback::from(move |e: MouseEvent| {
-                                                // Don't start drag if clicking on a button
+                                                // Don't start drag if clicking on a control
                                                 let target = e.target().unwrap();
                                                 let element: web_sys::Element = target.dyn_into().unwrap();
-                                                if element.closest("button").ok().flatten().is_some() {
+                                                if element.closest("button, input, label").ok().flatten().is_some() {
                                                     return;
                                                 }
                                                 // Get card element and calculate offset
@@ -528,9 +1184,21 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
                                         };
 
                                         let onmouseup_card = {
+                                            let dragging_task_id = dragging_task_id.clone();
+                                            let on_reorder = on_reorder.clone();
+                                            let col = col.clone();
+                                            let target_index = col_order.iter().position(|(_, id)| *id == task.id);
+                                            let target_id = task.id.clone();
                                             Callback::from(move |e: MouseEvent| {
                                                 // Stop propagation so column doesn't also handle it
                                                 e.stop_propagation();
+                                                // Dropping onto another card takes that card's slot
+                                                if let Some(task_id) = (*dragging_task_id).clone() {
+                                                    if let (true, Some(index)) = (task_id != target_id, target_index) {
+                                                        on_reorder.emit((task_id, col.clone(), index));
+                                                    }
+                                                    dragging_task_id.set(None);
+                                                }
                                             })
                                         };
 
@@ -556,6 +1224,22 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
                                                 if let Some(desc) = &task.description {
                                                     <div class="card-description">{desc}</div>
                                                 }
+                                                if let Some(progress) = task.subtask_progress {
+                                                    <div class="card-subtask-progress">
+                                                        <div class="card-progress-bar">
+                                                            <div
+                                                                class="card-progress-fill"
+                                                                style={format!("width: {:.0}%", progress * 100.0)}
+                                                            ></div>
+                                                        </div>
+                                                    </div>
+                                                }
+                                                { render_checklist(
+                                                    task,
+                                                    expanded_task_id.clone(),
+                                                    new_subtask_title.clone(),
+                                                    on_subtask.clone(),
+                                                ) }
                                                 <div class="card-footer">
                                                     if let Some(assignee) = &task.assignee {
                                                         <span class="card-assignee">{"👤 "}{assignee}</span>
@@ -735,3 +1419,111 @@ pub fn kanban_board(_props: &KanbanBoardProps) -> Html {
         </div>
     }
 }
+
+/// Subtask toggle button and, when expanded, the editable checklist for a card
+fn render_checklist(
+    task: &Task,
+    expanded_task_id: UseStateHandle<Option<String>>,
+    new_subtask_title: UseStateHandle<String>,
+    on_subtask: Callback<(String, SubtaskAction)>,
+) -> Html {
+    let is_expanded = expanded_task_id.as_ref() == Some(&task.id);
+    let done = task.subtasks.iter().filter(|s| s.done).count();
+
+    let on_toggle_expand = {
+        let expanded_task_id = expanded_task_id.clone();
+        let new_subtask_title = new_subtask_title.clone();
+        let task_id = task.id.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            new_subtask_title.set(String::new());
+            if is_expanded {
+                expanded_task_id.set(None);
+            } else {
+                expanded_task_id.set(Some(task_id.clone()));
+            }
+        })
+    };
+
+    let label = if task.subtasks.is_empty() {
+        "☐ チェックリスト".to_string()
+    } else {
+        format!("☑ {}/{}", done, task.subtasks.len())
+    };
+
+    let on_add = {
+        let new_subtask_title = new_subtask_title.clone();
+        let on_subtask = on_subtask.clone();
+        let task_id = task.id.clone();
+        Callback::from(move |_: ()| {
+            let title = (*new_subtask_title).trim().to_string();
+            if !title.is_empty() {
+                on_subtask.emit((task_id.clone(), SubtaskAction::Add(title)));
+                new_subtask_title.set(String::new());
+            }
+        })
+    };
+
+    html! {
+        <div class="card-checklist">
+            <button class="checklist-toggle" onclick={on_toggle_expand}>{label}</button>
+            if is_expanded {
+                <ul class="checklist-items">
+                    { for task.subtasks.iter().map(|subtask| {
+                        let on_toggle = {
+                            let on_subtask = on_subtask.clone();
+                            let task_id = task.id.clone();
+                            let subtask_id = subtask.id.clone();
+                            Callback::from(move |_: Event| {
+                                on_subtask.emit((task_id.clone(), SubtaskAction::Toggle(subtask_id.clone())));
+                            })
+                        };
+                        let on_delete = {
+                            let on_subtask = on_subtask.clone();
+                            let task_id = task.id.clone();
+                            let subtask_id = subtask.id.clone();
+                            Callback::from(move |e: MouseEvent| {
+                                e.stop_propagation();
+                                on_subtask.emit((task_id.clone(), SubtaskAction::Delete(subtask_id.clone())));
+                            })
+                        };
+                        html! {
+                            <li class={classes!("checklist-item", subtask.done.then_some("done"))}>
+                                <label>
+                                    <input type="checkbox" checked={subtask.done} onchange={on_toggle} />
+                                    <span>{&subtask.title}</span>
+                                </label>
+                                <button class="checklist-delete-btn" onclick={on_delete}>{"×"}</button>
+                            </li>
+                        }
+                    })}
+                </ul>
+                <div class="checklist-add">
+                    <input
+                        type="text"
+                        placeholder="サブタスクを追加..."
+                        value={(*new_subtask_title).clone()}
+                        oninput={{
+                            let new_subtask_title = new_subtask_title.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                new_subtask_title.set(input.value());
+                            })
+                        }}
+                        onkeydown={{
+                            let on_add = on_add.clone();
+                            Callback::from(move |e: KeyboardEvent| {
+                                if e.key() == "Enter" {
+                                    on_add.emit(());
+                                }
+                            })
+                        }}
+                    />
+                    <button class="checklist-add-btn" onclick={Callback::from(move |_| on_add.emit(()))}>
+                        {"追加"}
+                    </button>
+                </div>
+            }
+        </div>
+    }
+}
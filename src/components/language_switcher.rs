@@ -1,3 +1,4 @@
+use crate::components::settings::SettingsContext;
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 
@@ -5,18 +6,19 @@ use yew::prelude::*;
 pub fn language_switcher() -> Html {
     let (i18n, set_language) = use_translation();
     let current_lang = i18n.get_current_language();
+    let settings = use_context::<SettingsContext>();
 
-    let on_switch_to_en = {
+    // Save the choice as well when settings are available, so it survives a restart
+    let switch_to = |language: &'static str| {
         let set_language = set_language.clone();
-        Callback::from(move |_| {
-            set_language.emit("en".to_string());
-        })
-    };
-
-    let on_switch_to_ja = {
-        let set_language = set_language.clone();
-        Callback::from(move |_| {
-            set_language.emit("ja".to_string());
+        let settings = settings.clone();
+        Callback::from(move |_| match &settings {
+            Some(ctx) => {
+                let mut next = ctx.settings.clone();
+                next.language = language.to_string();
+                ctx.update.emit(next);
+            }
+            None => set_language.emit(language.to_string()),
         })
     };
 
@@ -24,14 +26,14 @@ pub fn language_switcher() -> Html {
         <div class="language-switcher">
             <button
                 class={classes!("lang-btn", (current_lang == "en").then_some("active"))}
-                onclick={on_switch_to_en}
+                onclick={switch_to("en")}
                 title="English"
             >
                 {"EN"}
             </button>
             <button
                 class={classes!("lang-btn", (current_lang == "ja").then_some("active"))}
-                onclick={on_switch_to_ja}
+                onclick={switch_to("ja")}
                 title="Japanese"
             >
                 {"JA"}
@@ -0,0 +1,402 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum ColumnAlign {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl ColumnAlign {
+    const ALL: [ColumnAlign; 4] = [
+        ColumnAlign::None,
+        ColumnAlign::Left,
+        ColumnAlign::Center,
+        ColumnAlign::Right,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            ColumnAlign::None => "none",
+            ColumnAlign::Left => "left",
+            ColumnAlign::Center => "center",
+            ColumnAlign::Right => "right",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ColumnAlign::None => "---",
+            ColumnAlign::Left => ":--",
+            ColumnAlign::Center => ":-:",
+            ColumnAlign::Right => "--:",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum TableOperation {
+    InsertRow { index: usize },
+    DeleteRow { index: usize },
+    InsertColumn { index: usize },
+    DeleteColumn { index: usize },
+}
+
+#[derive(Serialize)]
+struct FormatArgs {
+    input: String,
+    align: Option<Vec<ColumnAlign>>,
+}
+
+#[derive(Serialize)]
+struct EditArgs {
+    input: String,
+    operation: TableOperation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvArgs {
+    csv_text: String,
+}
+
+#[derive(Serialize)]
+struct InputArgs {
+    input: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TableFormatResult {
+    success: bool,
+    output: String,
+    rows: usize,
+    columns: usize,
+    aligns: Vec<ColumnAlign>,
+    error: Option<String>,
+}
+
+/// Whether the output holds a Markdown table, which can be edited further, or CSV
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputKind {
+    Markdown,
+    Csv,
+}
+
+const SAMPLE: &str =
+    "| Name | Qty | Note |\n|:-|-:|:-:|\n| りんご | 3 | 赤い |\n| banana | 12 | `a \\| b` |";
+
+async fn run_table_command(cmd: &str, args: JsValue) -> Option<TableFormatResult> {
+    let result = invoke(cmd, args).await;
+    serde_wasm_bindgen::from_value(result).ok()
+}
+
+#[function_component(MarkdownTable)]
+pub fn markdown_table() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(|| SAMPLE.to_string());
+    let result = use_state(|| Option::<TableFormatResult>::None);
+    let output_kind = use_state(|| OutputKind::Markdown);
+    let row_index = use_state(|| 0usize);
+    let column_index = use_state(|| 0usize);
+    let copied = use_state(|| false);
+
+    let set_result = {
+        let result = result.clone();
+        let output_kind = output_kind.clone();
+        move |cmd: &'static str, args: JsValue, kind: OutputKind| {
+            let result = result.clone();
+            let output_kind = output_kind.clone();
+            spawn_local(async move {
+                if let Some(res) = run_table_command(cmd, args).await {
+                    // A failed edit keeps the last good table on screen
+                    if res.success {
+                        output_kind.set(kind);
+                    }
+                    result.set(Some(res));
+                }
+            });
+        }
+    };
+
+    // The table that alignment changes and row/column edits apply to
+    let current_table = {
+        let input = input.clone();
+        let result = result.clone();
+        let output_kind = output_kind.clone();
+        move || match result.as_ref() {
+            Some(res) if res.success && *output_kind == OutputKind::Markdown => res.output.clone(),
+            _ => (*input).clone(),
+        }
+    };
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_format = {
+        let input = input.clone();
+        let set_result = set_result.clone();
+        Callback::from(move |_| {
+            let args = serde_wasm_bindgen::to_value(&FormatArgs {
+                input: (*input).clone(),
+                align: None,
+            })
+            .unwrap();
+            set_result("format_markdown_table_cmd", args, OutputKind::Markdown);
+        })
+    };
+
+    let on_from_csv = {
+        let input = input.clone();
+        let set_result = set_result.clone();
+        Callback::from(move |_| {
+            let args = serde_wasm_bindgen::to_value(&CsvArgs {
+                csv_text: (*input).clone(),
+            })
+            .unwrap();
+            set_result("csv_to_markdown_table_cmd", args, OutputKind::Markdown);
+        })
+    };
+
+    let on_to_csv = {
+        let input = input.clone();
+        let set_result = set_result.clone();
+        Callback::from(move |_| {
+            let args = serde_wasm_bindgen::to_value(&InputArgs {
+                input: (*input).clone(),
+            })
+            .unwrap();
+            set_result("markdown_table_to_csv_cmd", args, OutputKind::Csv);
+        })
+    };
+
+    let on_align_change = {
+        let result = result.clone();
+        let set_result = set_result.clone();
+        let current_table = current_table.clone();
+        move |column: usize| {
+            let result = result.clone();
+            let set_result = set_result.clone();
+            let current_table = current_table.clone();
+            Callback::from(move |e: Event| {
+                let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let Some(align) = ColumnAlign::ALL
+                    .into_iter()
+                    .find(|a| a.id() == select.value())
+                else {
+                    return;
+                };
+                let mut aligns = result
+                    .as_ref()
+                    .map(|res| res.aligns.clone())
+                    .unwrap_or_default();
+                if let Some(slot) = aligns.get_mut(column) {
+                    *slot = align;
+                }
+                let args = serde_wasm_bindgen::to_value(&FormatArgs {
+                    input: current_table(),
+                    align: Some(aligns),
+                })
+                .unwrap();
+                set_result("format_markdown_table_cmd", args, OutputKind::Markdown);
+            })
+        }
+    };
+
+    let edit = {
+        let set_result = set_result.clone();
+        let current_table = current_table.clone();
+        move |operation: TableOperation| {
+            let set_result = set_result.clone();
+            let current_table = current_table.clone();
+            Callback::from(move |_| {
+                let args = serde_wasm_bindgen::to_value(&EditArgs {
+                    input: current_table(),
+                    operation,
+                })
+                .unwrap();
+                set_result("edit_markdown_table_cmd", args, OutputKind::Markdown);
+            })
+        }
+    };
+
+    let index_input = |state: UseStateHandle<usize>| {
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            // Shown 1-based
+            if let Ok(value) = target.value().parse::<usize>() {
+                state.set(value.saturating_sub(1));
+            }
+        })
+    };
+
+    let on_use_output = {
+        let input = input.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            if let Some(res) = result.as_ref().filter(|res| res.success) {
+                input.set(res.output.clone());
+            }
+        })
+    };
+
+    let on_copy = {
+        let copied = copied.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            let Some(value) = result
+                .as_ref()
+                .filter(|res| res.success)
+                .map(|res| res.output.clone())
+            else {
+                return;
+            };
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ =
+                        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value)).await;
+                    copied.set(true);
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let table_result = (*result)
+        .clone()
+        .filter(|res| res.success && *output_kind == OutputKind::Markdown);
+
+    html! {
+        <div class="md-table">
+            <div class="section">
+                <textarea
+                    class="form-textarea md-table-input"
+                    value={(*input).clone()}
+                    oninput={on_input_change}
+                    placeholder={i18n.t("markdown_table.input_placeholder")}
+                    rows="8"
+                    spellcheck="false"
+                />
+                <div class="md-table-actions">
+                    <button class="primary-btn" onclick={on_format}>{i18n.t("markdown_table.format")}</button>
+                    <button class="secondary-btn" onclick={on_from_csv}>{i18n.t("markdown_table.from_csv")}</button>
+                    <button class="secondary-btn" onclick={on_to_csv}>{i18n.t("markdown_table.to_csv")}</button>
+                </div>
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                if let Some(error) = res.error.clone() {
+                    <div class="section error-section">
+                        <div class="error-message">{"⚠ "}{error}</div>
+                    </div>
+                }
+            }
+
+            if let Some(res) = table_result.clone() {
+                <div class="section">
+                    <div class="section-header">
+                        <h3>{i18n.t("markdown_table.columns")}</h3>
+                    </div>
+                    <div class="md-table-aligns">
+                        { for res.aligns.iter().enumerate().map(|(column, align)| html! {
+                            <label>
+                                <span>{column + 1}</span>
+                                <select class="form-select" onchange={on_align_change(column)}>
+                                    { for ColumnAlign::ALL.iter().map(|a| html! {
+                                        <option value={a.id()} selected={a == align}>{a.label()}</option>
+                                    }) }
+                                </select>
+                            </label>
+                        }) }
+                    </div>
+                    <div class="md-table-edit">
+                        <label>
+                            {i18n.t("markdown_table.row")}
+                            <input
+                                type="number"
+                                class="form-input"
+                                min="1"
+                                max={(res.rows + 1).to_string()}
+                                value={(*row_index + 1).to_string()}
+                                oninput={index_input(row_index.clone())}
+                            />
+                        </label>
+                        <button class="secondary-btn" onclick={edit(TableOperation::InsertRow { index: (*row_index).min(res.rows) })}>
+                            {i18n.t("markdown_table.insert_row")}
+                        </button>
+                        <button class="secondary-btn" onclick={edit(TableOperation::DeleteRow { index: *row_index })}>
+                            {i18n.t("markdown_table.delete_row")}
+                        </button>
+                        <label>
+                            {i18n.t("markdown_table.column")}
+                            <input
+                                type="number"
+                                class="form-input"
+                                min="1"
+                                max={(res.columns + 1).to_string()}
+                                value={(*column_index + 1).to_string()}
+                                oninput={index_input(column_index.clone())}
+                            />
+                        </label>
+                        <button class="secondary-btn" onclick={edit(TableOperation::InsertColumn { index: (*column_index).min(res.columns) })}>
+                            {i18n.t("markdown_table.insert_column")}
+                        </button>
+                        <button class="secondary-btn" onclick={edit(TableOperation::DeleteColumn { index: *column_index })}>
+                            {i18n.t("markdown_table.delete_column")}
+                        </button>
+                    </div>
+                </div>
+            }
+
+            if let Some(res) = (*result).clone().filter(|res| res.success) {
+                <div class="section">
+                    <div class="section-header">
+                        <h3>
+                            {i18n.t("markdown_table.output")}
+                            {format!(" ({} × {})", res.rows, res.columns)}
+                        </h3>
+                        <div class="md-table-actions">
+                            <button class="secondary-btn" onclick={on_use_output}>
+                                {i18n.t("markdown_table.use_as_input")}
+                            </button>
+                            <button class={classes!("copy-btn", (*copied).then_some("copied"))} onclick={on_copy}>
+                                if *copied {
+                                    {"✓"}
+                                } else {
+                                    {i18n.t("common.copy")}
+                                }
+                            </button>
+                        </div>
+                    </div>
+                    <textarea class="form-textarea md-table-output" readonly=true rows="10" value={res.output.clone()} />
+                </div>
+            }
+        </div>
+    }
+}
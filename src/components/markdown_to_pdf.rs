@@ -1,3 +1,4 @@
+use crate::components::settings::SettingsContext;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
@@ -13,19 +14,87 @@ extern "C" {
 
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
     async fn save(options: JsValue) -> JsValue;
+
+    // Loaded from index.html; the calls fail harmlessly when the scripts are unavailable
+    #[wasm_bindgen(js_namespace = ["window", "mermaid"], js_name = run, catch)]
+    fn mermaid_run(options: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "katex"], js_name = render, catch)]
+    fn katex_render(tex: &str, element: &web_sys::Element, options: JsValue)
+        -> Result<(), JsValue>;
 }
 
+/// Where the preview HTML is mounted
+const PREVIEW_SELECTOR: &str = ".markdown-preview-content";
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MarkdownInfo {
     pub file_name: String,
     pub file_size: u64,
     pub content: String,
+    #[serde(default)]
+    pub stats: MarkdownStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MarkdownStats {
+    pub outline: Vec<OutlineEntry>,
+    pub word_count: usize,
+    pub char_count: usize,
+    pub char_count_no_spaces: usize,
+    pub code_block_count: usize,
+    pub code_languages: Vec<CodeLanguageCount>,
+    pub images: Vec<MarkdownReference>,
+    pub links: Vec<MarkdownReference>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutlineEntry {
+    pub level: u8,
+    pub text: String,
+    pub line: usize,
+    pub children: Vec<OutlineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeLanguageCount {
+    pub language: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownReference {
+    pub url: String,
+    pub text: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokenLink {
+    pub url: String,
+    pub line: usize,
+    pub is_image: bool,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkCheckResult {
+    pub success: bool,
+    pub checked_local: usize,
+    pub checked_external: usize,
+    pub skipped_external: usize,
+    pub broken: Vec<BrokenLink>,
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarkdownToHtmlResult {
     pub success: bool,
     pub html: String,
+    #[serde(default)]
+    pub theme: String,
+    #[serde(default)]
+    pub toc_html: String,
     pub error: Option<String>,
 }
 
@@ -63,9 +132,108 @@ struct ReadMarkdownArgs {
     path: String,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckLinksArgs {
+    path: String,
+    check_external: bool,
+}
+
+/// Mirrors the backend's `MarkdownRenderOptions`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+struct MarkdownRenderOptions {
+    gfm: bool,
+    mermaid: bool,
+    math: bool,
+}
+
+impl Default for MarkdownRenderOptions {
+    fn default() -> Self {
+        Self {
+            gfm: true,
+            mermaid: true,
+            math: true,
+        }
+    }
+}
+
 #[derive(Serialize)]
 struct MarkdownToHtmlArgs {
     markdown: String,
+    theme: String,
+    options: MarkdownRenderOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum PaperSize {
+    A4,
+    A3,
+    Letter,
+    Legal,
+}
+
+const PAPER_SIZES: [(PaperSize, &str); 4] = [
+    (PaperSize::A4, "A4"),
+    (PaperSize::A3, "A3"),
+    (PaperSize::Letter, "Letter"),
+    (PaperSize::Legal, "Legal"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+struct Margins {
+    top: f64,
+    right: f64,
+    bottom: f64,
+    left: f64,
+}
+
+/// Mirrors the backend's `PdfPageOptions`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct PdfPageOptions {
+    paper_size: PaperSize,
+    orientation: Orientation,
+    margin_mm: Margins,
+    header_text: Option<String>,
+    footer_text: Option<String>,
+    page_numbers: bool,
+}
+
+impl Default for PdfPageOptions {
+    fn default() -> Self {
+        Self {
+            paper_size: PaperSize::A4,
+            orientation: Orientation::Portrait,
+            margin_mm: Margins {
+                top: 15.0,
+                right: 15.0,
+                bottom: 15.0,
+                left: 15.0,
+            },
+            header_text: None,
+            footer_text: None,
+            page_numbers: false,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MermaidRunOptions {
+    query_selector: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KatexOptions {
+    display_mode: bool,
+    throw_on_error: bool,
 }
 
 #[derive(Serialize)]
@@ -75,6 +243,78 @@ struct ConvertToPdfArgs {
     output_path: String,
     #[serde(rename = "sourcePath")]
     source_path: Option<String>,
+    theme: String,
+    #[serde(rename = "includeToc")]
+    include_toc: bool,
+    options: MarkdownRenderOptions,
+    #[serde(rename = "pageOptions")]
+    page_options: PdfPageOptions,
+}
+
+async fn render_preview(
+    markdown: String,
+    theme: String,
+    options: MarkdownRenderOptions,
+) -> Option<MarkdownToHtmlResult> {
+    let args = MarkdownToHtmlArgs {
+        markdown,
+        theme,
+        options,
+    };
+    let html_args = serde_wasm_bindgen::to_value(&args).unwrap();
+    let html_result = invoke("markdown_to_html_cmd", html_args).await;
+
+    serde_wasm_bindgen::from_value::<MarkdownToHtmlResult>(html_result)
+        .ok()
+        .filter(|res| res.success)
+}
+
+/// Draw Mermaid diagrams and typeset math in the rendered preview
+fn render_preview_extras() {
+    let options = MermaidRunOptions {
+        query_selector: format!("{} .mermaid", PREVIEW_SELECTOR),
+    };
+    let _ = mermaid_run(serde_wasm_bindgen::to_value(&options).unwrap());
+
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let Ok(nodes) = document.query_selector_all(&format!("{} .math", PREVIEW_SELECTOR)) else {
+        return;
+    };
+    for i in 0..nodes.length() {
+        let Some(element) = nodes
+            .item(i)
+            .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+        else {
+            continue;
+        };
+        let tex = element.text_content().unwrap_or_default();
+        let options = KatexOptions {
+            display_mode: element.class_name().contains("math-display"),
+            throw_on_error: false,
+        };
+        let _ = katex_render(
+            &tex,
+            &element,
+            serde_wasm_bindgen::to_value(&options).unwrap(),
+        );
+    }
+}
+
+fn render_outline(entries: &[OutlineEntry]) -> Html {
+    html! {
+        <ul>
+            {for entries.iter().map(|entry| html! {
+                <li>
+                    <span class="markdown-outline-level">{format!("H{}", entry.level)}</span>
+                    {&entry.text}
+                    <span class="markdown-outline-line">{format!("L{}", entry.line)}</span>
+                    {if entry.children.is_empty() { html! {} } else { render_outline(&entry.children) }}
+                </li>
+            })}
+        </ul>
+    }
 }
 
 fn format_size(bytes: u64) -> String {
@@ -102,6 +342,23 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
     let markdown_info = use_state(|| Option::<MarkdownInfo>::None);
     let html_preview = use_state(|| String::new());
     let convert_result = use_state(|| Option::<MarkdownToPdfResult>::None);
+    let code_theme = use_state(|| "light".to_string());
+    let include_toc = use_state(|| false);
+    let toc_preview = use_state(String::new);
+    let render_options = use_state(MarkdownRenderOptions::default);
+    let page_options = use_state(PdfPageOptions::default);
+    let check_external = use_state(|| true);
+    let is_checking_links = use_state(|| false);
+    // Kept with the path it was run for, so it disappears once another file is loaded
+    let link_check = use_state(|| Option::<(String, LinkCheckResult)>::None);
+
+    // The preview HTML is replaced wholesale, so diagrams and math are redrawn every time
+    use_effect_with((*html_preview).clone(), |html| {
+        if !html.is_empty() {
+            render_preview_extras();
+        }
+        || {}
+    });
 
     // Handle dropped file
     {
@@ -110,15 +367,21 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
         let input_path = input_path.clone();
         let markdown_info = markdown_info.clone();
         let html_preview = html_preview.clone();
+        let toc_preview = toc_preview.clone();
         let convert_result = convert_result.clone();
+        let code_theme = code_theme.clone();
+        let render_options = render_options.clone();
 
         use_effect_with(dropped_file.clone(), move |dropped_file| {
             if let Some(path) = dropped_file.clone() {
                 let input_path = input_path.clone();
                 let markdown_info = markdown_info.clone();
                 let html_preview = html_preview.clone();
+                let toc_preview = toc_preview.clone();
                 let convert_result = convert_result.clone();
                 let on_file_processed = on_file_processed.clone();
+                let theme = (*code_theme).clone();
+                let options = *render_options;
 
                 spawn_local(async move {
                     let args =
@@ -131,18 +394,11 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
                         convert_result.set(None);
 
                         // Generate HTML preview
-                        let html_args = serde_wasm_bindgen::to_value(&MarkdownToHtmlArgs {
-                            markdown: info.content.clone(),
-                        })
-                        .unwrap();
-                        let html_result = invoke("markdown_to_html_cmd", html_args).await;
-
-                        if let Ok(html_res) =
-                            serde_wasm_bindgen::from_value::<MarkdownToHtmlResult>(html_result)
+                        if let Some(res) =
+                            render_preview(info.content.clone(), theme, options).await
                         {
-                            if html_res.success {
-                                html_preview.set(html_res.html);
-                            }
+                            html_preview.set(res.html);
+                            toc_preview.set(res.toc_html);
                         }
 
                         markdown_info.set(Some(info));
@@ -159,12 +415,18 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
         let input_path = input_path.clone();
         let markdown_info = markdown_info.clone();
         let html_preview = html_preview.clone();
+        let toc_preview = toc_preview.clone();
         let convert_result = convert_result.clone();
+        let code_theme = code_theme.clone();
+        let render_options = render_options.clone();
         Callback::from(move |_| {
             let input_path = input_path.clone();
             let markdown_info = markdown_info.clone();
             let html_preview = html_preview.clone();
+            let toc_preview = toc_preview.clone();
             let convert_result = convert_result.clone();
+            let theme = (*code_theme).clone();
+            let render_options = *render_options;
             spawn_local(async move {
                 let options = OpenDialogOptions {
                     multiple: false,
@@ -186,18 +448,11 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
 
                     if let Ok(info) = serde_wasm_bindgen::from_value::<MarkdownInfo>(info_result) {
                         // Generate HTML preview
-                        let html_args = serde_wasm_bindgen::to_value(&MarkdownToHtmlArgs {
-                            markdown: info.content.clone(),
-                        })
-                        .unwrap();
-                        let html_result = invoke("markdown_to_html_cmd", html_args).await;
-
-                        if let Ok(html_res) =
-                            serde_wasm_bindgen::from_value::<MarkdownToHtmlResult>(html_result)
+                        if let Some(res) =
+                            render_preview(info.content.clone(), theme, render_options).await
                         {
-                            if html_res.success {
-                                html_preview.set(html_res.html);
-                            }
+                            html_preview.set(res.html);
+                            toc_preview.set(res.toc_html);
                         }
 
                         markdown_info.set(Some(info));
@@ -207,11 +462,75 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
         })
     };
 
+    let on_theme_change = {
+        let code_theme = code_theme.clone();
+        let markdown_info = markdown_info.clone();
+        let html_preview = html_preview.clone();
+        let toc_preview = toc_preview.clone();
+        let render_options = render_options.clone();
+        Callback::from(move |theme: String| {
+            code_theme.set(theme.clone());
+
+            if let Some(info) = &*markdown_info {
+                let markdown = info.content.clone();
+                let html_preview = html_preview.clone();
+                let toc_preview = toc_preview.clone();
+                let options = *render_options;
+                spawn_local(async move {
+                    if let Some(res) = render_preview(markdown, theme, options).await {
+                        html_preview.set(res.html);
+                        toc_preview.set(res.toc_html);
+                    }
+                });
+            }
+        })
+    };
+
+    // Follow the app theme with the code theme, re-rendering an open preview
+    {
+        let on_theme_change = on_theme_change.clone();
+        let dark = use_context::<SettingsContext>().map(|ctx| ctx.dark);
+        use_effect_with(dark, move |dark| {
+            if let Some(dark) = dark {
+                on_theme_change.emit(if *dark { "dark" } else { "light" }.to_string());
+            }
+            || {}
+        });
+    }
+
+    let on_render_options_change = {
+        let code_theme = code_theme.clone();
+        let markdown_info = markdown_info.clone();
+        let html_preview = html_preview.clone();
+        let toc_preview = toc_preview.clone();
+        let render_options = render_options.clone();
+        Callback::from(move |options: MarkdownRenderOptions| {
+            render_options.set(options);
+
+            if let Some(info) = &*markdown_info {
+                let markdown = info.content.clone();
+                let theme = (*code_theme).clone();
+                let html_preview = html_preview.clone();
+                let toc_preview = toc_preview.clone();
+                spawn_local(async move {
+                    if let Some(res) = render_preview(markdown, theme, options).await {
+                        html_preview.set(res.html);
+                        toc_preview.set(res.toc_html);
+                    }
+                });
+            }
+        })
+    };
+
     let on_convert = {
         let input_path = input_path.clone();
         let markdown_info = markdown_info.clone();
         let convert_result = convert_result.clone();
         let is_processing = is_processing.clone();
+        let code_theme = code_theme.clone();
+        let include_toc = include_toc.clone();
+        let render_options = render_options.clone();
+        let page_options = page_options.clone();
 
         Callback::from(move |_| {
             let markdown_content = match &*markdown_info {
@@ -226,6 +545,10 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
 
             let convert_result = convert_result.clone();
             let is_processing = is_processing.clone();
+            let theme = (*code_theme).clone();
+            let include_toc = *include_toc;
+            let options = *render_options;
+            let page_options = (*page_options).clone();
 
             is_processing.set(true);
 
@@ -253,6 +576,10 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
                         markdown: markdown_content,
                         output_path,
                         source_path: Some(source_path),
+                        theme,
+                        include_toc,
+                        options,
+                        page_options,
                     };
                     let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
                     let result = invoke("convert_markdown_to_pdf_cmd", args_js).await;
@@ -271,15 +598,102 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
         let input_path = input_path.clone();
         let markdown_info = markdown_info.clone();
         let html_preview = html_preview.clone();
+        let toc_preview = toc_preview.clone();
         let convert_result = convert_result.clone();
         Callback::from(move |_| {
             input_path.set(String::new());
             markdown_info.set(None);
             html_preview.set(String::new());
+            toc_preview.set(String::new());
             convert_result.set(None);
         })
     };
 
+    // Builds a change handler that writes an input's value into the page options
+    let update_page_options = {
+        let page_options = page_options.clone();
+        move |apply: fn(&mut PdfPageOptions, String)| {
+            let page_options = page_options.clone();
+            Callback::from(move |e: Event| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let mut next = (*page_options).clone();
+                apply(&mut next, input.value());
+                page_options.set(next);
+            })
+        }
+    };
+
+    let on_paper_size_change = {
+        let page_options = page_options.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some((size, _)) = PAPER_SIZES.iter().find(|(_, name)| *name == select.value()) {
+                page_options.set(PdfPageOptions {
+                    paper_size: *size,
+                    ..(*page_options).clone()
+                });
+            }
+        })
+    };
+
+    let on_orientation_change = {
+        let page_options = page_options.clone();
+        Callback::from(move |orientation: Orientation| {
+            page_options.set(PdfPageOptions {
+                orientation,
+                ..(*page_options).clone()
+            });
+        })
+    };
+
+    let on_toggle_page_numbers = {
+        let page_options = page_options.clone();
+        Callback::from(move |_| {
+            page_options.set(PdfPageOptions {
+                page_numbers: !page_options.page_numbers,
+                ..(*page_options).clone()
+            });
+        })
+    };
+
+    let on_toggle_check_external = {
+        let check_external = check_external.clone();
+        Callback::from(move |_| check_external.set(!*check_external))
+    };
+
+    let on_check_links = {
+        let input_path = input_path.clone();
+        let check_external = check_external.clone();
+        let is_checking_links = is_checking_links.clone();
+        let link_check = link_check.clone();
+        Callback::from(move |_| {
+            let path = (*input_path).clone();
+            if path.is_empty() {
+                return;
+            }
+            let args = CheckLinksArgs {
+                path: path.clone(),
+                check_external: *check_external,
+            };
+            let is_checking_links = is_checking_links.clone();
+            let link_check = link_check.clone();
+            is_checking_links.set(true);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let result = invoke("check_markdown_links_cmd", args).await;
+                if let Ok(result) = serde_wasm_bindgen::from_value::<LinkCheckResult>(result) {
+                    link_check.set(Some((path, result)));
+                }
+                is_checking_links.set(false);
+            });
+        })
+    };
+
+    let on_toggle_toc = {
+        let include_toc = include_toc.clone();
+        Callback::from(move |_| include_toc.set(!*include_toc))
+    };
+
     html! {
         <div class="markdown-to-pdf">
             // Processing Overlay
@@ -327,8 +741,106 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
                             </div>
                             <div class="info-item">
                                 <div class="info-item-label">{"Characters"}</div>
-                                <div class="info-item-value">{info.content.len()}</div>
+                                <div class="info-item-value">{info.stats.char_count}</div>
+                            </div>
+                            <div class="info-item">
+                                <div class="info-item-label">{"Words"}</div>
+                                <div class="info-item-value">{info.stats.word_count}</div>
+                            </div>
+                            <div class="info-item">
+                                <div class="info-item-label">{"Code Blocks"}</div>
+                                <div class="info-item-value">{info.stats.code_block_count}</div>
+                            </div>
+                            <div class="info-item">
+                                <div class="info-item-label">{"Links / Images"}</div>
+                                <div class="info-item-value">
+                                    {format!("{} / {}", info.stats.links.len(), info.stats.images.len())}
+                                </div>
+                            </div>
+                        </div>
+                        {if info.stats.code_languages.is_empty() {
+                            html! {}
+                        } else {
+                            html! {
+                                <div class="markdown-code-languages">
+                                    {for info.stats.code_languages.iter().map(|lang| html! {
+                                        <span class="format-badge">
+                                            {format!(
+                                                "{} × {}",
+                                                if lang.language.is_empty() { "plain" } else { lang.language.as_str() },
+                                                lang.count
+                                            )}
+                                        </span>
+                                    })}
+                                </div>
+                            }
+                        }}
+                        {if info.stats.outline.is_empty() {
+                            html! {}
+                        } else {
+                            html! {
+                                <div class="markdown-outline">
+                                    <h4>{"Outline"}</h4>
+                                    {render_outline(&info.stats.outline)}
+                                </div>
+                            }
+                        }}
+                        <div class="markdown-link-check">
+                            <div class="markdown-link-check-actions">
+                                <label class="toolbar-checkbox">
+                                    <input type="checkbox" checked={*check_external} onchange={on_toggle_check_external} />
+                                    {"Check http(s) links"}
+                                </label>
+                                <button
+                                    class="secondary-btn"
+                                    onclick={on_check_links}
+                                    disabled={*is_checking_links}
+                                >
+                                    {if *is_checking_links { "Checking..." } else { "Check Links" }}
+                                </button>
                             </div>
+                            {match &*link_check {
+                                Some((path, result)) if *path == *input_path => {
+                                    if let Some(error) = &result.error {
+                                        html! { <p class="error-message">{error}</p> }
+                                    } else {
+                                        html! {
+                                            <>
+                                                <p class="markdown-link-summary">
+                                                    {format!(
+                                                        "{} local, {} external checked{} — {} broken",
+                                                        result.checked_local,
+                                                        result.checked_external,
+                                                        if result.skipped_external > 0 {
+                                                            format!(", {} external skipped", result.skipped_external)
+                                                        } else {
+                                                            String::new()
+                                                        },
+                                                        result.broken.len()
+                                                    )}
+                                                </p>
+                                                {if result.broken.is_empty() {
+                                                    html! {}
+                                                } else {
+                                                    html! {
+                                                        <ul class="markdown-broken-links">
+                                                            {for result.broken.iter().map(|link| html! {
+                                                                <li>
+                                                                    <span class="markdown-outline-line">{format!("L{}", link.line)}</span>
+                                                                    {if link.is_image { "🖼 " } else { "" }}
+                                                                    <span class="markdown-broken-url">{&link.url}</span>
+                                                                    <span class="markdown-broken-reason">{&link.reason}</span>
+                                                                </li>
+                                                            })}
+                                                        </ul>
+                                                    }
+                                                }}
+                                            </>
+                                        }
+                                    }
+                                }
+                                _ => html! {},
+                            }}
                         </div>
                     </div>
                 }
@@ -340,7 +852,54 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
             {if !html_preview.is_empty() {
                 html! {
                     <div class="section">
-                        <h3>{"Preview"}</h3>
+                        <div class="markdown-preview-header">
+                            <h3>{"Preview"}</h3>
+                            <div class="mode-toggle code-theme-toggle">
+                                {for [("light", "Light Code"), ("dark", "Dark Code")].into_iter().map(|(value, label)| {
+                                    let on_theme_change = on_theme_change.clone();
+                                    html! {
+                                        <button
+                                            class={if *code_theme == value { "mode-btn active" } else { "mode-btn" }}
+                                            onclick={Callback::from(move |_| on_theme_change.emit(value.to_string()))}
+                                        >
+                                            {label}
+                                        </button>
+                                    }
+                                })}
+                            </div>
+                        </div>
+                        <label class="toolbar-checkbox markdown-toc-option">
+                            <input type="checkbox" checked={*include_toc} onchange={on_toggle_toc} />
+                            {"Include table of contents (H1-H3)"}
+                        </label>
+                        <div class="markdown-render-options">
+                            {for [
+                                ("GitHub extensions (tables, task lists, footnotes)", render_options.gfm, MarkdownRenderOptions { gfm: !render_options.gfm, ..*render_options }),
+                                ("Mermaid diagrams", render_options.mermaid, MarkdownRenderOptions { mermaid: !render_options.mermaid, ..*render_options }),
+                                ("Math (KaTeX)", render_options.math, MarkdownRenderOptions { math: !render_options.math, ..*render_options }),
+                            ].into_iter().map(|(label, checked, toggled)| {
+                                let on_render_options_change = on_render_options_change.clone();
+                                html! {
+                                    <label class="toolbar-checkbox">
+                                        <input
+                                            type="checkbox"
+                                            checked={checked}
+                                            onchange={Callback::from(move |_| on_render_options_change.emit(toggled))}
+                                        />
+                                        {label}
+                                    </label>
+                                }
+                            })}
+                        </div>
+                        {if *include_toc && !toc_preview.is_empty() {
+                            html! {
+                                <div class="markdown-toc-preview">
+                                    {Html::from_html_unchecked(AttrValue::from((*toc_preview).clone()))}
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
                         <div class="markdown-preview">
                             <div class="markdown-preview-content">
                                 {Html::from_html_unchecked(AttrValue::from((*html_preview).clone()))}
@@ -352,6 +911,77 @@ pub fn markdown_to_pdf(props: &MarkdownToPdfProps) -> Html {
                 html! {}
             }}
 
+            // Page Setup
+            {if markdown_info.is_some() {
+                let margin_input = |label: &'static str, value: f64, apply: fn(&mut PdfPageOptions, String)| html! {
+                    <label class="pdf-page-field">
+                        <span>{label}</span>
+                        <input type="number" min="0" step="1" value={value.to_string()} onchange={update_page_options(apply)} />
+                    </label>
+                };
+                html! {
+                    <div class="section pdf-page-setup">
+                        <h3>{"Page Setup"}</h3>
+                        <div class="pdf-page-row">
+                            <label class="pdf-page-field">
+                                <span>{"Paper"}</span>
+                                <select onchange={on_paper_size_change}>
+                                    {for PAPER_SIZES.iter().map(|(size, name)| html! {
+                                        <option value={*name} selected={page_options.paper_size == *size}>{*name}</option>
+                                    })}
+                                </select>
+                            </label>
+                            <div class="mode-toggle pdf-orientation-toggle">
+                                {for [(Orientation::Portrait, "Portrait"), (Orientation::Landscape, "Landscape")].into_iter().map(|(orientation, label)| {
+                                    let on_orientation_change = on_orientation_change.clone();
+                                    html! {
+                                        <button
+                                            class={if page_options.orientation == orientation { "mode-btn active" } else { "mode-btn" }}
+                                            onclick={Callback::from(move |_| on_orientation_change.emit(orientation))}
+                                        >
+                                            {label}
+                                        </button>
+                                    }
+                                })}
+                            </div>
+                        </div>
+                        <div class="pdf-page-row">
+                            {margin_input("Top (mm)", page_options.margin_mm.top, |o, v| o.margin_mm.top = v.parse().unwrap_or(0.0))}
+                            {margin_input("Right (mm)", page_options.margin_mm.right, |o, v| o.margin_mm.right = v.parse().unwrap_or(0.0))}
+                            {margin_input("Bottom (mm)", page_options.margin_mm.bottom, |o, v| o.margin_mm.bottom = v.parse().unwrap_or(0.0))}
+                            {margin_input("Left (mm)", page_options.margin_mm.left, |o, v| o.margin_mm.left = v.parse().unwrap_or(0.0))}
+                        </div>
+                        <div class="pdf-page-row">
+                            <label class="pdf-page-field pdf-page-text">
+                                <span>{"Header"}</span>
+                                <input
+                                    type="text"
+                                    placeholder="{title}"
+                                    value={page_options.header_text.clone().unwrap_or_default()}
+                                    onchange={update_page_options(|o, v| o.header_text = Some(v).filter(|v| !v.is_empty()))}
+                                />
+                            </label>
+                            <label class="pdf-page-field pdf-page-text">
+                                <span>{"Footer"}</span>
+                                <input
+                                    type="text"
+                                    placeholder="{date}"
+                                    value={page_options.footer_text.clone().unwrap_or_default()}
+                                    onchange={update_page_options(|o, v| o.footer_text = Some(v).filter(|v| !v.is_empty()))}
+                                />
+                            </label>
+                        </div>
+                        <p class="pdf-page-hint">{"Placeholders: {page} {total} {date} {title}"}</p>
+                        <label class="toolbar-checkbox">
+                            <input type="checkbox" checked={page_options.page_numbers} onchange={on_toggle_page_numbers} />
+                            {"Page numbers"}
+                        </label>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
             // Action Buttons
             <div class="pdf-action-buttons">
                 <button
@@ -1,21 +1,56 @@
 pub mod base64_encoder;
+pub mod business_days;
+pub mod case_converter;
 pub mod char_counter;
 pub mod cheatsheet_viewer;
+pub mod chmod_calculator;
+pub mod clipboard_history;
+pub mod color_converter;
 pub mod command_palette;
+pub mod config_converter;
+pub mod cron_parser;
 pub mod csv_viewer;
+pub mod disk_analyzer;
+pub mod dummy_data_generator;
+pub mod duplicate_finder;
+pub mod encoding_converter;
+pub mod env_converter;
+pub mod escape_converter;
+pub mod exif_tools;
+pub mod file_renamer;
+pub mod generation_history;
+pub mod http_client;
+pub mod icon_exporter;
 pub mod image_compressor;
+pub mod image_converter;
 pub mod image_editor;
 pub mod input_history;
+pub mod japanese_text_converter;
 pub mod json_formatter;
 pub mod kanban_board;
 pub mod language_switcher;
+pub mod markdown_table;
 pub mod markdown_to_pdf;
+pub mod network_tools;
+pub mod ocr;
 pub mod password_generator;
 pub mod pdf_tools;
+pub mod pomodoro;
+pub mod process_tools;
+pub mod qr_generator;
+pub mod qr_reader;
+pub mod radix_converter;
 pub mod regex_tester;
 pub mod scratch_pad;
+pub mod settings;
 pub mod shortcut_dictionary;
+pub mod sql_formatter;
+pub mod system_info;
 pub mod text_diff;
+pub mod text_tools;
+pub mod theme_toggle;
 pub mod unit_converter;
 pub mod unix_time_converter;
+pub mod url_encoder;
 pub mod uuid_generator;
+pub mod workflows;
@@ -0,0 +1,389 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct InputArgs {
+    input: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckPortArgs {
+    host: String,
+    port: u16,
+    timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CidrResult {
+    success: bool,
+    version: u8,
+    prefix_length: u8,
+    cidr: String,
+    network_address: String,
+    broadcast_address: Option<String>,
+    first_host: String,
+    last_host: String,
+    total_addresses: String,
+    host_count: String,
+    subnet_mask: String,
+    wildcard_mask: String,
+    host_bits_set: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IpInfo {
+    success: bool,
+    address: String,
+    version: u8,
+    category: Option<String>,
+    expanded: String,
+    binary: String,
+    integer: String,
+    reverse_pointer: String,
+    mapped_ipv4: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PortCheckResult {
+    success: bool,
+    host: String,
+    port: u16,
+    open: bool,
+    resolved_address: Option<String>,
+    response_time_ms: Option<u64>,
+    error: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Cidr,
+    Ip,
+    Port,
+}
+
+fn result_row(label: String, value: String) -> Html {
+    html! {
+        <tr>
+            <th>{label}</th>
+            <td>{value}</td>
+        </tr>
+    }
+}
+
+#[function_component(NetworkTools)]
+pub fn network_tools() -> Html {
+    let (i18n, _) = use_translation();
+    let mode = use_state(|| Mode::Cidr);
+    let cidr_input = use_state(|| "192.168.1.0/24".to_string());
+    let cidr_result = use_state(|| Option::<CidrResult>::None);
+    let ip_input = use_state(String::new);
+    let ip_result = use_state(|| Option::<IpInfo>::None);
+    let port_host = use_state(|| "localhost".to_string());
+    let port_number = use_state(|| 80u16);
+    let port_timeout = use_state(|| 3000u64);
+    let port_result = use_state(|| Option::<PortCheckResult>::None);
+    let is_checking = use_state(|| false);
+
+    // CIDR and IP results follow the input as it is typed
+    {
+        let cidr_result = cidr_result.clone();
+        use_effect_with((*cidr_input).clone(), move |input| {
+            let input = input.clone();
+            spawn_local(async move {
+                if input.trim().is_empty() {
+                    cidr_result.set(None);
+                    return;
+                }
+                let args = serde_wasm_bindgen::to_value(&InputArgs { input }).unwrap();
+                let result = invoke("analyze_cidr_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<CidrResult>(result) {
+                    cidr_result.set(Some(res));
+                }
+            });
+            || {}
+        });
+    }
+
+    {
+        let ip_result = ip_result.clone();
+        use_effect_with((*ip_input).clone(), move |input| {
+            let input = input.clone();
+            spawn_local(async move {
+                if input.trim().is_empty() {
+                    ip_result.set(None);
+                    return;
+                }
+                let args = serde_wasm_bindgen::to_value(&InputArgs { input }).unwrap();
+                let result = invoke("parse_ip_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<IpInfo>(result) {
+                    ip_result.set(Some(res));
+                }
+            });
+            || {}
+        });
+    }
+
+    let text_input = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            state.set(input.value());
+        })
+    };
+
+    let on_port_host_input = text_input(&port_host);
+
+    let on_port_number_change = {
+        let port_number = port_number.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(port) = input.value().parse::<u16>() {
+                port_number.set(port);
+            }
+        })
+    };
+
+    let on_port_timeout_change = {
+        let port_timeout = port_timeout.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(ms) = input.value().parse::<u64>() {
+                port_timeout.set(ms.max(100));
+            }
+        })
+    };
+
+    let on_check_port = {
+        let port_host = port_host.clone();
+        let port_number = port_number.clone();
+        let port_timeout = port_timeout.clone();
+        let port_result = port_result.clone();
+        let is_checking = is_checking.clone();
+        Callback::from(move |_| {
+            let args = CheckPortArgs {
+                host: (*port_host).clone(),
+                port: *port_number,
+                timeout_ms: *port_timeout,
+            };
+            let port_result = port_result.clone();
+            let is_checking = is_checking.clone();
+            is_checking.set(true);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let result = invoke("check_port_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<PortCheckResult>(result) {
+                    port_result.set(Some(res));
+                }
+                is_checking.set(false);
+            });
+        })
+    };
+
+    let mode_button = |target: Mode, label: String| {
+        let mode = mode.clone();
+        html! {
+            <button
+                class={if *mode == target { "mode-btn active" } else { "mode-btn" }}
+                onclick={Callback::from(move |_| mode.set(target))}
+            >
+                {label}
+            </button>
+        }
+    };
+
+    let error_section = |error: Option<String>| {
+        html! {
+            <div class="section error-section">
+                <div class="error-message">{format!("⚠ {}", error.unwrap_or_default())}</div>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="network-tools">
+            <div class="mode-toggle">
+                {mode_button(Mode::Cidr, i18n.t("network_tools.cidr"))}
+                {mode_button(Mode::Ip, i18n.t("network_tools.ip"))}
+                {mode_button(Mode::Port, i18n.t("network_tools.port"))}
+            </div>
+
+            if *mode == Mode::Cidr {
+                <div class="section">
+                    <input
+                        type="text"
+                        class="network-input"
+                        placeholder="192.168.1.0/24, 2001:db8::/48"
+                        value={(*cidr_input).clone()}
+                        oninput={text_input(&cidr_input)}
+                    />
+                </div>
+                if let Some(res) = (*cidr_result).clone() {
+                    if res.success {
+                        <div class="section">
+                            <div class="section-header">
+                                <h3>{format!("IPv{} /{}", res.version, res.prefix_length)}</h3>
+                                <span class="network-cidr">{res.cidr.clone()}</span>
+                            </div>
+                            if res.host_bits_set {
+                                <p class="network-note">
+                                    {format!("{} {}", i18n.t("network_tools.host_bits_set"), res.cidr)}
+                                </p>
+                            }
+                            <table class="network-table">
+                                <tbody>
+                                    {result_row(i18n.t("network_tools.network_address"), res.network_address.clone())}
+                                    if let Some(broadcast) = res.broadcast_address.clone() {
+                                        {result_row(i18n.t("network_tools.broadcast_address"), broadcast)}
+                                    }
+                                    {result_row(
+                                        i18n.t("network_tools.host_range"),
+                                        format!("{} - {}", res.first_host, res.last_host)
+                                    )}
+                                    {result_row(i18n.t("network_tools.host_count"), res.host_count.clone())}
+                                    {result_row(i18n.t("network_tools.total_addresses"), res.total_addresses.clone())}
+                                    {result_row(i18n.t("network_tools.subnet_mask"), res.subnet_mask.clone())}
+                                    {result_row(i18n.t("network_tools.wildcard_mask"), res.wildcard_mask.clone())}
+                                </tbody>
+                            </table>
+                        </div>
+                    } else {
+                        {error_section(res.error.clone())}
+                    }
+                }
+            }
+
+            if *mode == Mode::Ip {
+                <div class="section">
+                    <input
+                        type="text"
+                        class="network-input"
+                        placeholder="10.0.0.1, fe80::1"
+                        value={(*ip_input).clone()}
+                        oninput={text_input(&ip_input)}
+                    />
+                </div>
+                if let Some(res) = (*ip_result).clone() {
+                    if res.success {
+                        <div class="section">
+                            <div class="section-header">
+                                <h3>{format!("IPv{} {}", res.version, res.address)}</h3>
+                                if let Some(category) = res.category.clone() {
+                                    <span class={classes!("network-category", category.clone())}>
+                                        {i18n.t(&format!("network_tools.categories.{}", category))}
+                                    </span>
+                                }
+                            </div>
+                            <table class="network-table">
+                                <tbody>
+                                    {result_row(i18n.t("network_tools.expanded"), res.expanded.clone())}
+                                    {result_row(i18n.t("network_tools.binary"), res.binary.clone())}
+                                    {result_row(i18n.t("network_tools.integer"), res.integer.clone())}
+                                    {result_row(i18n.t("network_tools.reverse_pointer"), res.reverse_pointer.clone())}
+                                    if let Some(v4) = res.mapped_ipv4.clone() {
+                                        {result_row(i18n.t("network_tools.mapped_ipv4"), v4)}
+                                    }
+                                </tbody>
+                            </table>
+                        </div>
+                    } else {
+                        {error_section(res.error.clone())}
+                    }
+                }
+            }
+
+            if *mode == Mode::Port {
+                <div class="section network-port-form">
+                    <label>
+                        {i18n.t("network_tools.host")}
+                        <input
+                            type="text"
+                            class="network-input"
+                            value={(*port_host).clone()}
+                            oninput={on_port_host_input}
+                        />
+                    </label>
+                    <label>
+                        {i18n.t("network_tools.port_number")}
+                        <input
+                            type="number"
+                            min="1"
+                            max="65535"
+                            value={port_number.to_string()}
+                            onchange={on_port_number_change}
+                        />
+                    </label>
+                    <label>
+                        {i18n.t("network_tools.timeout_ms")}
+                        <input
+                            type="number"
+                            min="100"
+                            step="100"
+                            value={port_timeout.to_string()}
+                            onchange={on_port_timeout_change}
+                        />
+                    </label>
+                </div>
+                <button
+                    class="primary-btn"
+                    onclick={on_check_port}
+                    disabled={port_host.trim().is_empty() || *is_checking}
+                >
+                    if *is_checking {
+                        <span class="processing">
+                            <span class="spinner"></span>
+                            {i18n.t("network_tools.checking")}
+                        </span>
+                    } else {
+                        {i18n.t("network_tools.check")}
+                    }
+                </button>
+                if let Some(res) = (*port_result).clone() {
+                    if res.success {
+                        <div class="section">
+                            <div class="section-header">
+                                <h3>{format!("{}:{}", res.host, res.port)}</h3>
+                                <span class={classes!("network-port-status", if res.open { "open" } else { "closed" })}>
+                                    if res.open {
+                                        {i18n.t("network_tools.open")}
+                                    } else {
+                                        {i18n.t("network_tools.closed")}
+                                    }
+                                </span>
+                            </div>
+                            <table class="network-table">
+                                <tbody>
+                                    if let Some(addr) = res.resolved_address.clone() {
+                                        {result_row(i18n.t("network_tools.resolved_address"), addr)}
+                                    }
+                                    if let Some(ms) = res.response_time_ms {
+                                        {result_row(i18n.t("network_tools.response_time"), format!("{} ms", ms))}
+                                    }
+                                    if let Some(error) = res.error.clone() {
+                                        {result_row(i18n.t("common.error"), error)}
+                                    }
+                                </tbody>
+                            </table>
+                        </div>
+                    } else {
+                        {error_section(res.error.clone())}
+                    }
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,351 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    fn convertFileSrc(path: &str) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum OcrLanguage {
+    English,
+    Japanese,
+    EnglishJapanese,
+}
+
+#[derive(Serialize)]
+struct ExtractArgs {
+    path: String,
+    language: OcrLanguage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+enum OcrErrorKind {
+    ImageUnreadable,
+    EngineUnavailable,
+    RecognitionFailed,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BoundingBox {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OcrLine {
+    text: String,
+    bounds: BoundingBox,
+    confidence: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OcrResult {
+    success: bool,
+    text: String,
+    lines: Vec<OcrLine>,
+    image_width: u32,
+    image_height: u32,
+    error: Option<String>,
+    error_kind: Option<OcrErrorKind>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    #[prop_or_default]
+    pub dropped_file: Option<String>,
+    #[prop_or_default]
+    pub on_file_processed: Callback<()>,
+}
+
+/// Below this confidence a line is flagged as possibly misread
+const LOW_CONFIDENCE: f32 = 60.0;
+
+#[function_component(Ocr)]
+pub fn ocr(props: &Props) -> Html {
+    let (i18n, _) = use_translation();
+    let path = use_state(|| Option::<String>::None);
+    let language = use_state(|| OcrLanguage::EnglishJapanese);
+    let result = use_state(|| Option::<OcrResult>::None);
+    let is_processing = use_state(|| false);
+    // `None` is the full text, `Some(i)` a single line
+    let copied = use_state(|| Option::<Option<usize>>::None);
+    let selected_line = use_state(|| Option::<usize>::None);
+
+    // Run recognition whenever the image or language changes
+    {
+        let result = result.clone();
+        let is_processing = is_processing.clone();
+        let selected_line = selected_line.clone();
+        use_effect_with(((*path).clone(), *language), move |(path, language)| {
+            if let Some(path) = path.clone() {
+                let language = *language;
+                result.set(None);
+                selected_line.set(None);
+                is_processing.set(true);
+                spawn_local(async move {
+                    let args =
+                        serde_wasm_bindgen::to_value(&ExtractArgs { path, language }).unwrap();
+                    let response = invoke("extract_text_from_image_cmd", args).await;
+                    result.set(serde_wasm_bindgen::from_value(response).ok());
+                    is_processing.set(false);
+                });
+            }
+            || {}
+        });
+    }
+
+    // Handle dropped file
+    {
+        let on_file_processed = props.on_file_processed.clone();
+        let path = path.clone();
+        use_effect_with(props.dropped_file.clone(), move |dropped_file| {
+            if let Some(dropped) = dropped_file.clone() {
+                path.set(Some(dropped));
+                on_file_processed.emit(());
+            }
+            || {}
+        });
+    }
+
+    let on_select_file = {
+        let path = path.clone();
+        Callback::from(move |_| {
+            let path = path.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    filters: vec![FileFilter {
+                        name: "Images".to_string(),
+                        extensions: ["png", "jpg", "jpeg", "gif", "webp", "bmp", "tiff"]
+                            .iter()
+                            .map(|ext| ext.to_string())
+                            .collect(),
+                    }],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(selected) = open(opts).await.as_string() {
+                    path.set(Some(selected));
+                }
+            });
+        })
+    };
+
+    let on_language_change = {
+        let language = language.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            language.set(match select.value().as_str() {
+                "english" => OcrLanguage::English,
+                "japanese" => OcrLanguage::Japanese,
+                _ => OcrLanguage::EnglishJapanese,
+            });
+        })
+    };
+
+    let copy_text = {
+        let copied = copied.clone();
+        move |key: Option<usize>, text: String| {
+            let copied = copied.clone();
+            Callback::from(move |_| {
+                let copied = copied.clone();
+                let text = text.clone();
+                if let Some(win) = window() {
+                    let clipboard = win.navigator().clipboard();
+                    spawn_local(async move {
+                        let _ =
+                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text)).await;
+                        copied.set(Some(key));
+
+                        let copied_reset = copied.clone();
+                        gloo_timers::callback::Timeout::new(2000, move || {
+                            copied_reset.set(None);
+                        })
+                        .forget();
+                    });
+                }
+            })
+        }
+    };
+
+    let copy_label = |key: Option<usize>| {
+        if *copied == Some(key) {
+            format!("✓ {}", i18n.t("common.copied"))
+        } else {
+            i18n.t("common.copy")
+        }
+    };
+
+    let res = (*result).clone();
+
+    // Boxes are positioned in percent so they follow the scaled preview
+    let overlay_style = |bounds: &BoundingBox| {
+        let Some(res) = res.as_ref().filter(|res| res.image_width > 0) else {
+            return String::new();
+        };
+        let pct = |v: u32, total: u32| v as f64 * 100.0 / total as f64;
+        format!(
+            "left: {:.2}%; top: {:.2}%; width: {:.2}%; height: {:.2}%;",
+            pct(bounds.x, res.image_width),
+            pct(bounds.y, res.image_height),
+            pct(bounds.width, res.image_width),
+            pct(bounds.height, res.image_height),
+        )
+    };
+
+    let select_line = |index: usize| {
+        let selected_line = selected_line.clone();
+        Callback::from(move |_| {
+            selected_line.set(if *selected_line == Some(index) {
+                None
+            } else {
+                Some(index)
+            });
+        })
+    };
+
+    let lines = res
+        .as_ref()
+        .filter(|res| res.success)
+        .map(|res| res.lines.clone())
+        .unwrap_or_default();
+
+    html! {
+        <div class="ocr">
+            <div class="section">
+                <div class="ocr-options">
+                    <label>
+                        <span>{i18n.t("ocr.language")}</span>
+                        <select class="form-select" onchange={on_language_change}>
+                            <option value="english_japanese" selected={*language == OcrLanguage::EnglishJapanese}>
+                                {i18n.t("ocr.language_english_japanese")}
+                            </option>
+                            <option value="japanese" selected={*language == OcrLanguage::Japanese}>
+                                {i18n.t("ocr.language_japanese")}
+                            </option>
+                            <option value="english" selected={*language == OcrLanguage::English}>
+                                {i18n.t("ocr.language_english")}
+                            </option>
+                        </select>
+                    </label>
+                </div>
+                <div class="drop-zone ocr-drop" onclick={on_select_file}>
+                    if let Some(url) = path.as_ref().and_then(|p| convertFileSrc(p).as_string()) {
+                        <div class="ocr-preview">
+                            <img src={url} alt="OCR" />
+                            { for lines.iter().enumerate().map(|(i, line)| html! {
+                                <div
+                                    class={classes!("ocr-box", (*selected_line == Some(i)).then_some("selected"))}
+                                    style={overlay_style(&line.bounds)}
+                                />
+                            }) }
+                        </div>
+                        <p class="drop-zone-hint">{i18n.t("ocr.click_to_change")}</p>
+                    } else {
+                        <p class="drop-zone-text">{i18n.t("ocr.drop_image")}</p>
+                        <p class="drop-zone-hint">{i18n.t("ocr.supported_formats")}</p>
+                    }
+                </div>
+            </div>
+
+            if *is_processing {
+                <div class="section ocr-status">
+                    <span class="spinner"></span>
+                    {i18n.t("ocr.recognizing")}
+                </div>
+            } else if let Some(res) = res.clone() {
+                if res.success && res.lines.is_empty() {
+                    <div class="section ocr-status">{i18n.t("ocr.no_text")}</div>
+                } else if res.success {
+                    <div class="section">
+                        <div class="section-header">
+                            <h3>{i18n.t("ocr.text")}</h3>
+                            <button
+                                class={classes!("move-btn", (*copied == Some(None)).then_some("copied"))}
+                                onclick={copy_text(None, res.text.clone())}
+                            >
+                                {copy_label(None)}
+                            </button>
+                        </div>
+                        <textarea class="form-input ocr-text" readonly=true rows="8" value={res.text.clone()} />
+                    </div>
+                    <div class="section">
+                        <div class="section-header">
+                            <h3>{format!("{} ({})", i18n.t("ocr.lines"), res.lines.len())}</h3>
+                        </div>
+                        <div class="ocr-lines">
+                            { for res.lines.iter().enumerate().map(|(i, line)| html! {
+                                <div
+                                    class={classes!("ocr-line", (*selected_line == Some(i)).then_some("selected"))}
+                                    onclick={select_line(i)}
+                                >
+                                    <span class="ocr-line-text">{&line.text}</span>
+                                    <span
+                                        class={classes!("ocr-confidence", (line.confidence < LOW_CONFIDENCE).then_some("low"))}
+                                        title={i18n.t("ocr.confidence")}
+                                    >
+                                        {format!("{:.0}%", line.confidence)}
+                                    </span>
+                                    <button
+                                        class={classes!("copy-btn", (*copied == Some(Some(i))).then_some("copied"))}
+                                        onclick={copy_text(Some(i), line.text.clone())}
+                                    >
+                                        if *copied == Some(Some(i)) {
+                                            {"✓"}
+                                        } else {
+                                            {"📋"}
+                                        }
+                                    </button>
+                                </div>
+                            }) }
+                        </div>
+                    </div>
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {"⚠ "}
+                            {match res.error_kind {
+                                Some(OcrErrorKind::EngineUnavailable) => i18n.t("ocr.engine_unavailable"),
+                                Some(OcrErrorKind::ImageUnreadable) => i18n.t("ocr.unreadable"),
+                                _ => i18n.t("ocr.recognition_failed"),
+                            }}
+                        </div>
+                        if res.error_kind == Some(OcrErrorKind::EngineUnavailable) {
+                            <div class="ocr-detail">{i18n.t("ocr.engine_hint")}</div>
+                        } else {
+                            <div class="ocr-detail">{res.error.clone().unwrap_or_default()}</div>
+                        }
+                    </div>
+                }
+            }
+        </div>
+    }
+}
@@ -1,14 +1,43 @@
+use crate::components::clipboard_history::copy_sensitive_text;
+use crate::components::generation_history::{append_generation_history, GenerationHistory};
+use crate::components::settings::SettingsContext;
 use i18nrs::yew::use_translation;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::window;
 use yew::prelude::*;
 
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+enum BuiltinWordlist {
+    English,
+    JapaneseRomaji,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+enum WordlistSource {
+    Builtin(BuiltinWordlist),
+    CustomFile(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -28,6 +57,7 @@ struct PasswordOptions {
     exclude_ambiguous: bool,
     custom_exclude: String,
     count: u32,
+    min_strength_score: Option<u8>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +68,7 @@ struct PassphraseOptions {
     capitalize: bool,
     include_number: bool,
     count: u32,
+    wordlist: WordlistSource,
 }
 
 #[derive(Serialize)]
@@ -57,17 +88,36 @@ struct PasswordStrength {
     entropy: f64,
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PasswordStrengthResult {
+    score: u8,
+    label: String,
+    entropy: f64,
+    #[allow(dead_code)]
+    crack_time_seconds: f64,
+    crack_time_display: String,
+    suggestions: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct EvaluatePasswordArgs {
+    password: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct GeneratedPassword {
     value: String,
     strength: PasswordStrength,
+    evaluation: PasswordStrengthResult,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 struct PasswordGenerateResult {
     success: bool,
     passwords: Vec<GeneratedPassword>,
-    #[allow(dead_code)]
+    wordlist_name: Option<String>,
+    entropy_per_word: Option<f64>,
+    warnings: Vec<String>,
     error: Option<String>,
 }
 
@@ -77,6 +127,7 @@ struct DisplayPassword {
     strength: u32,
     strength_label: String,
     entropy: f64,
+    crack_time: String,
     copied: bool,
 }
 
@@ -92,14 +143,26 @@ pub fn password_generator() -> Html {
     let exclude_ambiguous = use_state(|| false);
     let custom_exclude = use_state(String::new);
     let count = use_state(|| 1u32);
+    let min_strength_score = use_state(|| Option::<u8>::None);
+    let generate_error = use_state(|| Option::<String>::None);
+
+    let check_input = use_state(String::new);
+    let check_result = use_state(|| Option::<PasswordStrengthResult>::None);
 
     let word_count = use_state(|| 4u32);
     let separator = use_state(|| "-".to_string());
     let capitalize = use_state(|| true);
     let include_number = use_state(|| true);
+    let wordlist = use_state(|| WordlistSource::Builtin(BuiltinWordlist::English));
+    let wordlist_info = use_state(|| Option::<(String, f64)>::None);
+    let generate_warnings = use_state(Vec::<String>::new);
 
     let generated_passwords = use_state(Vec::<DisplayPassword>::new);
     let is_generating = use_state(|| false);
+    // Passwords are only kept in the generation history when the user opted in
+    let save_history = use_context::<SettingsContext>()
+        .is_some_and(|ctx| ctx.settings.generation_history.save_passwords);
+    let history_version = use_state(|| 0u32);
     let copy_all_feedback = use_state(|| false);
 
     let on_mode_change = {
@@ -167,17 +230,27 @@ pub fn password_generator() -> Html {
         let exclude_ambiguous = exclude_ambiguous.clone();
         let custom_exclude = custom_exclude.clone();
         let count = count.clone();
+        let min_strength_score = min_strength_score.clone();
+        let generate_error = generate_error.clone();
         let word_count = word_count.clone();
         let separator = separator.clone();
         let capitalize = capitalize.clone();
         let include_number = include_number.clone();
+        let wordlist = wordlist.clone();
+        let wordlist_info = wordlist_info.clone();
+        let generate_warnings = generate_warnings.clone();
         let generated_passwords = generated_passwords.clone();
         let is_generating = is_generating.clone();
+        let history_version = history_version.clone();
 
         Callback::from(move |_| {
             let mode_value = (*mode).clone();
             let generated_passwords = generated_passwords.clone();
             let is_generating = is_generating.clone();
+            let history_version = history_version.clone();
+            let generate_error = generate_error.clone();
+            let wordlist_info = wordlist_info.clone();
+            let generate_warnings = generate_warnings.clone();
 
             is_generating.set(true);
 
@@ -192,6 +265,7 @@ pub fn password_generator() -> Html {
                         exclude_ambiguous: *exclude_ambiguous,
                         custom_exclude: (*custom_exclude).clone(),
                         count: *count,
+                        min_strength_score: *min_strength_score,
                     };
 
                     spawn_local(async move {
@@ -202,6 +276,9 @@ pub fn password_generator() -> Html {
                         if let Ok(res) =
                             serde_wasm_bindgen::from_value::<PasswordGenerateResult>(result)
                         {
+                            generate_error.set(res.error.clone());
+                            generate_warnings.set(res.warnings.clone());
+                            wordlist_info.set(None);
                             if res.success {
                                 let passwords: Vec<DisplayPassword> = res
                                     .passwords
@@ -211,10 +288,16 @@ pub fn password_generator() -> Html {
                                         strength: p.strength.score,
                                         strength_label: p.strength.label,
                                         entropy: p.strength.entropy,
+                                        crack_time: p.evaluation.crack_time_display,
                                         copied: false,
                                     })
                                     .collect();
+                                let values = passwords.iter().map(|p| p.value.clone()).collect();
                                 generated_passwords.set(passwords);
+                                if save_history {
+                                    append_generation_history("password_generator", values).await;
+                                    history_version.set(*history_version + 1);
+                                }
                             }
                         }
 
@@ -228,6 +311,7 @@ pub fn password_generator() -> Html {
                         capitalize: *capitalize,
                         include_number: *include_number,
                         count: *count,
+                        wordlist: (*wordlist).clone(),
                     };
 
                     spawn_local(async move {
@@ -239,6 +323,9 @@ pub fn password_generator() -> Html {
                         if let Ok(res) =
                             serde_wasm_bindgen::from_value::<PasswordGenerateResult>(result)
                         {
+                            generate_error.set(res.error.clone());
+                            generate_warnings.set(res.warnings.clone());
+                            wordlist_info.set(res.wordlist_name.clone().zip(res.entropy_per_word));
                             if res.success {
                                 let passwords: Vec<DisplayPassword> = res
                                     .passwords
@@ -248,10 +335,16 @@ pub fn password_generator() -> Html {
                                         strength: p.strength.score,
                                         strength_label: p.strength.label,
                                         entropy: p.strength.entropy,
+                                        crack_time: p.evaluation.crack_time_display,
                                         copied: false,
                                     })
                                     .collect();
+                                let values = passwords.iter().map(|p| p.value.clone()).collect();
                                 generated_passwords.set(passwords);
+                                if save_history {
+                                    append_generation_history("password_generator", values).await;
+                                    history_version.set(*history_version + 1);
+                                }
                             }
                         }
 
@@ -268,30 +361,26 @@ pub fn password_generator() -> Html {
             let generated_passwords = generated_passwords.clone();
             if let Some(password) = (*generated_passwords).get(index) {
                 let value = password.value.clone();
-                if let Some(win) = window() {
-                    let clipboard = win.navigator().clipboard();
-                    let generated_passwords_inner = generated_passwords.clone();
-                    spawn_local(async move {
-                        let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value))
-                            .await;
+                let generated_passwords_inner = generated_passwords.clone();
+                spawn_local(async move {
+                    copy_sensitive_text(&value).await;
+
+                    let mut passwords = (*generated_passwords_inner).clone();
+                    if let Some(p) = passwords.get_mut(index) {
+                        p.copied = true;
+                    }
+                    generated_passwords_inner.set(passwords);
 
-                        let mut passwords = (*generated_passwords_inner).clone();
+                    let generated_passwords_reset = generated_passwords_inner.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        let mut passwords = (*generated_passwords_reset).clone();
                         if let Some(p) = passwords.get_mut(index) {
-                            p.copied = true;
+                            p.copied = false;
                         }
-                        generated_passwords_inner.set(passwords);
-
-                        let generated_passwords_reset = generated_passwords_inner.clone();
-                        gloo_timers::callback::Timeout::new(2000, move || {
-                            let mut passwords = (*generated_passwords_reset).clone();
-                            if let Some(p) = passwords.get_mut(index) {
-                                p.copied = false;
-                            }
-                            generated_passwords_reset.set(passwords);
-                        })
-                        .forget();
-                    });
-                }
+                        generated_passwords_reset.set(passwords);
+                    })
+                    .forget();
+                });
             }
         })
     };
@@ -309,25 +398,88 @@ pub fn password_generator() -> Html {
                     .collect::<Vec<_>>()
                     .join("\n");
 
-                if let Some(win) = window() {
-                    let clipboard = win.navigator().clipboard();
-                    spawn_local(async move {
-                        let _ =
-                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&all_values))
-                                .await;
-                        copy_all_feedback.set(true);
-
-                        let copy_all_feedback_reset = copy_all_feedback.clone();
-                        gloo_timers::callback::Timeout::new(2000, move || {
-                            copy_all_feedback_reset.set(false);
-                        })
-                        .forget();
-                    });
+                spawn_local(async move {
+                    copy_sensitive_text(&all_values).await;
+                    copy_all_feedback.set(true);
+
+                    let copy_all_feedback_reset = copy_all_feedback.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copy_all_feedback_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let on_check_input = {
+        let check_input = check_input.clone();
+        let check_result = check_result.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let password = input.value();
+            check_input.set(password.clone());
+
+            if password.is_empty() {
+                check_result.set(None);
+                return;
+            }
+
+            let check_result = check_result.clone();
+            spawn_local(async move {
+                let args =
+                    serde_wasm_bindgen::to_value(&EvaluatePasswordArgs { password }).unwrap();
+                let result = invoke("evaluate_password_strength_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<PasswordStrengthResult>(result) {
+                    check_result.set(Some(res));
                 }
+            });
+        })
+    };
+
+    let on_wordlist_change = {
+        let wordlist = wordlist.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            match select.value().as_str() {
+                "english" => wordlist.set(WordlistSource::Builtin(BuiltinWordlist::English)),
+                "japanese_romaji" => {
+                    wordlist.set(WordlistSource::Builtin(BuiltinWordlist::JapaneseRomaji))
+                }
+                "custom" => wordlist.set(WordlistSource::CustomFile(String::new())),
+                _ => {}
             }
         })
     };
 
+    let on_select_wordlist_file = {
+        let wordlist = wordlist.clone();
+        Callback::from(move |_| {
+            let wordlist = wordlist.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    filters: vec![FileFilter {
+                        name: "Text".to_string(),
+                        extensions: vec!["txt".to_string()],
+                    }],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(path) = open(opts).await.as_string() {
+                    wordlist.set(WordlistSource::CustomFile(path));
+                }
+            });
+        })
+    };
+
+    let on_min_strength_change = {
+        let min_strength_score = min_strength_score.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            min_strength_score.set(select.value().parse::<u8>().ok());
+        })
+    };
+
     let strength_class = |score: u32| -> &'static str {
         match score {
             1 => "strength-very-weak",
@@ -478,6 +630,20 @@ pub fn password_generator() -> Html {
                                 oninput={on_count_change.clone()}
                             />
                         </div>
+
+                        <div class="form-group">
+                            <label>{i18n.t("password_generator.min_strength_label")}</label>
+                            <select class="form-select" onchange={on_min_strength_change}>
+                                <option value="" selected={min_strength_score.is_none()}>
+                                    {i18n.t("password_generator.min_strength_none")}
+                                </option>
+                                { for (1..=4u8).map(|score| html! {
+                                    <option value={score.to_string()} selected={*min_strength_score == Some(score)}>
+                                        {i18n.t(&format!("password_generator.strength_score_{}", score))}
+                                    </option>
+                                }) }
+                            </select>
+                        </div>
                     </div>
                 </div>
             }
@@ -509,6 +675,44 @@ pub fn password_generator() -> Html {
                             </div>
                         </div>
 
+                        <div class="form-group">
+                            <label>{i18n.t("password_generator.wordlist_label")}</label>
+                            <select class="form-select" onchange={on_wordlist_change}>
+                                <option
+                                    value="english"
+                                    selected={*wordlist == WordlistSource::Builtin(BuiltinWordlist::English)}
+                                >
+                                    {i18n.t("password_generator.wordlist_english")}
+                                </option>
+                                <option
+                                    value="japanese_romaji"
+                                    selected={*wordlist == WordlistSource::Builtin(BuiltinWordlist::JapaneseRomaji)}
+                                >
+                                    {i18n.t("password_generator.wordlist_japanese_romaji")}
+                                </option>
+                                <option
+                                    value="custom"
+                                    selected={matches!(*wordlist, WordlistSource::CustomFile(_))}
+                                >
+                                    {i18n.t("password_generator.wordlist_custom")}
+                                </option>
+                            </select>
+                            if let WordlistSource::CustomFile(path) = &*wordlist {
+                                <div class="wordlist-file">
+                                    <button class="secondary-btn" onclick={on_select_wordlist_file}>
+                                        {i18n.t("password_generator.wordlist_select_file")}
+                                    </button>
+                                    <span class="wordlist-path">
+                                        if path.is_empty() {
+                                            {i18n.t("password_generator.wordlist_no_file")}
+                                        } else {
+                                            {path.clone()}
+                                        }
+                                    </span>
+                                </div>
+                            }
+                        </div>
+
                         <div class="form-group">
                             <label>{i18n.t("password_generator.separator_label")}</label>
                             <select class="form-select" onchange={on_separator_change}>
@@ -566,7 +770,9 @@ pub fn password_generator() -> Html {
             <button
                 class="primary-btn generate-btn"
                 onclick={on_generate}
-                disabled={*is_generating}
+                disabled={*is_generating
+                    || (*mode == GeneratorMode::Passphrase
+                        && *wordlist == WordlistSource::CustomFile(String::new()))}
             >
                 if *is_generating {
                     <span class="processing">
@@ -582,11 +788,30 @@ pub fn password_generator() -> Html {
                 }
             </button>
 
+            if let Some(err) = (*generate_error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{"⚠ "}{err}</div>
+                </div>
+            }
+
+            if !generate_warnings.is_empty() {
+                <div class="section warning-section">
+                    { for generate_warnings.iter().map(|w| html! {
+                        <div class="warning-message">{"⚠ "}{w}</div>
+                    }) }
+                </div>
+            }
+
             // Generated Passwords
             if !generated_passwords.is_empty() {
                 <div class="section password-results-section">
                     <div class="password-results-header">
                         <h3>{format!("{} ({})", i18n.t("password_generator.results_title"), generated_passwords.len())}</h3>
+                        if let Some((name, bits)) = (*wordlist_info).clone() {
+                            <span class="wordlist-info">
+                                {format!("{}: {} · {:.2} bits/{}", i18n.t("password_generator.wordlist_label"), name, bits, i18n.t("password_generator.word"))}
+                            </span>
+                        }
                         <button
                             class={classes!("secondary-btn", "copy-all-btn", (*copy_all_feedback).then_some("copied"))}
                             onclick={on_copy_all}
@@ -615,6 +840,9 @@ pub fn password_generator() -> Html {
                                             <span class="entropy-value">
                                                 {format!("Entropy: {:.1} bits", password.entropy)}
                                             </span>
+                                            <span class="crack-time-value">
+                                                {format!("{}: {}", i18n.t("password_generator.crack_time"), password.crack_time)}
+                                            </span>
                                         </div>
                                     </div>
                                     <button
@@ -633,6 +861,52 @@ pub fn password_generator() -> Html {
                     </div>
                 </div>
             }
+
+            if save_history {
+                <GenerationHistory tool="password_generator" refresh={*history_version} />
+            }
+
+            // Strength Checker
+            <div class="section strength-check-section">
+                <h3>{i18n.t("password_generator.check_title")}</h3>
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder={i18n.t("password_generator.check_placeholder")}
+                    value={(*check_input).clone()}
+                    oninput={on_check_input}
+                    spellcheck="false"
+                    autocomplete="off"
+                />
+                if let Some(result) = (*check_result).clone() {
+                    <div class="strength-check-result">
+                        <div class="strength-meter">
+                            { for (0..4u8).map(|i| html! {
+                                <span class={classes!(
+                                    "strength-meter-segment",
+                                    (i < result.score).then(|| strength_class(u32::from(result.score) + 1)),
+                                )}></span>
+                            }) }
+                        </div>
+                        <div class="password-meta">
+                            <span class={classes!("strength-badge", strength_class(u32::from(result.score) + 1))}>
+                                {format!("{} ({}/4)", result.label, result.score)}
+                            </span>
+                            <span class="entropy-value">
+                                {format!("Entropy: {:.1} bits", result.entropy)}
+                            </span>
+                            <span class="crack-time-value">
+                                {format!("{}: {}", i18n.t("password_generator.crack_time"), result.crack_time_display)}
+                            </span>
+                        </div>
+                        if !result.suggestions.is_empty() {
+                            <ul class="strength-suggestions">
+                                { for result.suggestions.iter().map(|s| html! { <li>{s}</li> }) }
+                            </ul>
+                        }
+                    </div>
+                }
+            </div>
         </div>
     }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
@@ -13,6 +14,9 @@ extern "C" {
 
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
     async fn save(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -20,6 +24,8 @@ pub struct PdfInfo {
     pub page_count: u32,
     pub file_size: u64,
     pub file_name: String,
+    #[serde(default)]
+    pub is_encrypted: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +44,74 @@ pub struct PdfMergeResult {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfRotateResult {
+    pub success: bool,
+    pub output_path: String,
+    pub rotated_pages: Vec<u32>,
+    pub errors: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageText {
+    pub page: u32,
+    pub text: String,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfTextResult {
+    pub success: bool,
+    pub pages: Vec<PageText>,
+    pub full_text: String,
+    pub page_count: u32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfCompressResult {
+    pub success: bool,
+    pub output_path: String,
+    pub original_size: u64,
+    pub compressed_size: u64,
+    pub reduction_ratio: f64,
+    pub images_found: usize,
+    pub images_compressed: usize,
+    pub nothing_to_reduce: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PdfThumbnailResult {
+    pub success: bool,
+    pub page: u32,
+    pub image_base64: String,
+    pub width: u32,
+    pub height: u32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfThumbnailsResult {
+    pub success: bool,
+    pub page_count: u32,
+    pub thumbnails: Vec<PdfThumbnailResult>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct PdfThumbnailProgress {
+    input_path: String,
+    total: u32,
+    thumbnail: PdfThumbnailResult,
+}
+
+#[derive(Deserialize)]
+struct PdfThumbnailProgressEvent {
+    payload: PdfThumbnailProgress,
+}
+
 #[derive(Serialize)]
 struct OpenDialogOptions {
     multiple: bool,
@@ -91,6 +165,77 @@ struct MergePdfsArgs {
     output_path: String,
 }
 
+#[derive(Serialize)]
+struct RotatePagesArgs {
+    #[serde(rename = "inputPath")]
+    input_path: String,
+    #[serde(rename = "outputPath")]
+    output_path: String,
+    pages: Vec<u32>,
+    angle: u16,
+}
+
+#[derive(Serialize)]
+struct ExtractTextArgs {
+    #[serde(rename = "inputPath")]
+    input_path: String,
+    #[serde(rename = "startPage")]
+    start_page: Option<u32>,
+    #[serde(rename = "endPage")]
+    end_page: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct CompressPdfArgs {
+    #[serde(rename = "inputPath")]
+    input_path: String,
+    #[serde(rename = "outputPath")]
+    output_path: String,
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct RenderThumbnailsArgs {
+    #[serde(rename = "inputPath")]
+    input_path: String,
+    #[serde(rename = "maxWidth")]
+    max_width: u32,
+}
+
+#[derive(Serialize)]
+struct ExportTextArgs {
+    path: String,
+    #[serde(rename = "outputPath")]
+    output_path: String,
+}
+
+/// Parse a page list like "1, 3, 5-7" into page numbers. An empty string means all pages.
+fn parse_page_list(input: &str) -> Result<Vec<u32>, String> {
+    let mut pages = Vec::new();
+    for part in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        if let Some((start, end)) = part.split_once('-') {
+            let start: u32 = start
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid page range: {}", part))?;
+            let end: u32 = end
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid page range: {}", part))?;
+            if start == 0 || start > end {
+                return Err(format!("Invalid page range: {}", part));
+            }
+            pages.extend(start..=end);
+        } else {
+            let page: u32 = part
+                .parse()
+                .map_err(|_| format!("Invalid page number: {}", part))?;
+            pages.push(page);
+        }
+    }
+    Ok(pages)
+}
+
 fn format_size(bytes: u64) -> String {
     if bytes >= 1_048_576 {
         format!("{:.1} MB", bytes as f64 / 1_048_576.0)
@@ -103,6 +248,9 @@ fn format_size(bytes: u64) -> String {
 enum PdfMode {
     Split,
     Merge,
+    Rotate,
+    Text,
+    Compress,
 }
 
 #[derive(Clone, PartialEq)]
@@ -117,6 +265,140 @@ struct PdfFile {
     info: PdfInfo,
 }
 
+const THUMBNAIL_WIDTH: u32 = 160;
+
+#[derive(Properties, PartialEq)]
+struct PageThumbnailGridProps {
+    path: String,
+    selected: Vec<u32>,
+    /// Page number and whether Shift was held
+    on_select: Callback<(u32, bool)>,
+}
+
+/// Page previews of one PDF, filled in as the backend renders them
+#[function_component(PageThumbnailGrid)]
+fn page_thumbnail_grid(props: &PageThumbnailGridProps) -> Html {
+    let thumbnails = use_mut_ref(BTreeMap::<u32, PdfThumbnailResult>::new);
+    let total = use_mut_ref(|| 0u32);
+    let current_path = use_mut_ref(String::new);
+    let error = use_state(|| Option::<String>::None);
+    let force_update = use_force_update();
+
+    {
+        let thumbnails = thumbnails.clone();
+        let total = total.clone();
+        let current_path = current_path.clone();
+        let force_update = force_update.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    let Ok(event) =
+                        serde_wasm_bindgen::from_value::<PdfThumbnailProgressEvent>(event)
+                    else {
+                        return;
+                    };
+                    let progress = event.payload;
+                    if progress.input_path != *current_path.borrow() {
+                        return;
+                    }
+                    *total.borrow_mut() = progress.total;
+                    thumbnails
+                        .borrow_mut()
+                        .insert(progress.thumbnail.page, progress.thumbnail);
+                    force_update.force_update();
+                });
+                let _ = listen("pdf-thumbnail-progress", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    {
+        let thumbnails = thumbnails.clone();
+        let total = total.clone();
+        let current_path = current_path.clone();
+        let error = error.clone();
+        let force_update = force_update.clone();
+        use_effect_with(props.path.clone(), move |path| {
+            thumbnails.borrow_mut().clear();
+            *total.borrow_mut() = 0;
+            *current_path.borrow_mut() = path.clone();
+            error.set(None);
+            force_update.force_update();
+
+            let path = path.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&RenderThumbnailsArgs {
+                    input_path: path.clone(),
+                    max_width: THUMBNAIL_WIDTH,
+                })
+                .unwrap();
+                let result = invoke("render_pdf_thumbnails_cmd", args).await;
+                if *current_path.borrow() != path {
+                    return;
+                }
+                match serde_wasm_bindgen::from_value::<PdfThumbnailsResult>(result) {
+                    Ok(result) if result.success => {
+                        *total.borrow_mut() = result.page_count;
+                        *thumbnails.borrow_mut() = result
+                            .thumbnails
+                            .into_iter()
+                            .map(|thumbnail| (thumbnail.page, thumbnail))
+                            .collect();
+                    }
+                    Ok(result) => error.set(result.error),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                force_update.force_update();
+            });
+            || {}
+        });
+    }
+
+    if let Some(error) = &*error {
+        return html! { <p class="pdf-thumb-grid-error">{format!("⚠ {}", error)}</p> };
+    }
+
+    let thumbnails = thumbnails.borrow();
+    let total = *total.borrow();
+    html! {
+        <div class="pdf-thumb-grid">
+            {for (1..=total).map(|page| {
+                let on_click = {
+                    let on_select = props.on_select.clone();
+                    Callback::from(move |e: MouseEvent| on_select.emit((page, e.shift_key())))
+                };
+                let class = if props.selected.contains(&page) {
+                    "pdf-thumb selected"
+                } else {
+                    "pdf-thumb"
+                };
+                let preview = match thumbnails.get(&page) {
+                    Some(thumbnail) if thumbnail.success => html! {
+                        <img
+                            src={format!("data:image/png;base64,{}", thumbnail.image_base64)}
+                            alt={format!("Page {}", page)}
+                        />
+                    },
+                    Some(thumbnail) => html! {
+                        <div class="pdf-thumb-failed" title={thumbnail.error.clone().unwrap_or_default()}>
+                            {"⚠"}
+                        </div>
+                    },
+                    None => html! { <div class="pdf-thumb-loading"></div> },
+                };
+                html! {
+                    <button class={class} onclick={on_click}>
+                        {preview}
+                        <span class="pdf-thumb-page">{page}</span>
+                    </button>
+                }
+            })}
+        </div>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct PdfToolsProps {
     #[prop_or_default]
@@ -142,6 +424,26 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
     let merge_files = use_state(|| Vec::<PdfFile>::new());
     let merge_result = use_state(|| Option::<PdfMergeResult>::None);
 
+    // Rotate mode state
+    let rotate_input_path = use_state(String::new);
+    let rotate_pdf_info = use_state(|| Option::<PdfInfo>::None);
+    let rotate_pages = use_state(String::new);
+    let rotate_angle = use_state(|| 90u16);
+    let rotate_result = use_state(|| Option::<PdfRotateResult>::None);
+    let rotate_error = use_state(|| Option::<String>::None);
+
+    // Text mode state
+    let text_input_path = use_state(String::new);
+    let text_result = use_state(|| Option::<PdfTextResult>::None);
+    let text_export_path = use_state(|| Option::<String>::None);
+    let text_copied = use_state(|| false);
+
+    // Compress mode state
+    let compress_input_path = use_state(String::new);
+    let compress_pdf_info = use_state(|| Option::<PdfInfo>::None);
+    let compress_level = use_state(|| "medium");
+    let compress_result = use_state(|| Option::<PdfCompressResult>::None);
+
     // Handle dropped file
     {
         let dropped_file = props.dropped_file.clone();
@@ -153,6 +455,14 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
         let split_result = split_result.clone();
         let merge_files = merge_files.clone();
         let merge_result = merge_result.clone();
+        let rotate_input_path = rotate_input_path.clone();
+        let rotate_pdf_info = rotate_pdf_info.clone();
+        let rotate_result = rotate_result.clone();
+        let text_input_path = text_input_path.clone();
+        let text_result = text_result.clone();
+        let compress_input_path = compress_input_path.clone();
+        let compress_pdf_info = compress_pdf_info.clone();
+        let compress_result = compress_result.clone();
 
         use_effect_with(dropped_file.clone(), move |dropped_file| {
             if let Some(path) = dropped_file.clone() {
@@ -163,6 +473,14 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
                 let split_result = split_result.clone();
                 let merge_files = merge_files.clone();
                 let merge_result = merge_result.clone();
+                let rotate_input_path = rotate_input_path.clone();
+                let rotate_pdf_info = rotate_pdf_info.clone();
+                let rotate_result = rotate_result.clone();
+                let text_input_path = text_input_path.clone();
+                let text_result = text_result.clone();
+                let compress_input_path = compress_input_path.clone();
+                let compress_pdf_info = compress_pdf_info.clone();
+                let compress_result = compress_result.clone();
                 let on_file_processed = on_file_processed.clone();
 
                 spawn_local(async move {
@@ -170,17 +488,43 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
                         .unwrap();
                     let info_result = invoke("get_pdf_info_cmd", args).await;
 
-                    if let Ok(info) = serde_wasm_bindgen::from_value::<PdfInfo>(info_result) {
-                        if *mode == PdfMode::Split {
-                            split_input_path.set(path);
-                            end_page.set(info.page_count);
-                            split_pdf_info.set(Some(info));
-                            split_result.set(None);
-                        } else {
-                            let mut files = (*merge_files).clone();
-                            files.push(PdfFile { path, info });
-                            merge_files.set(files);
-                            merge_result.set(None);
+                    if *mode == PdfMode::Text {
+                        text_input_path.set(path.clone());
+                        let args = ExtractTextArgs {
+                            input_path: path,
+                            start_page: None,
+                            end_page: None,
+                        };
+                        let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                        let result = invoke("extract_pdf_text_cmd", args_js).await;
+                        text_result
+                            .set(serde_wasm_bindgen::from_value::<PdfTextResult>(result).ok());
+                    } else if let Ok(info) = serde_wasm_bindgen::from_value::<PdfInfo>(info_result)
+                    {
+                        match *mode {
+                            PdfMode::Split => {
+                                split_input_path.set(path);
+                                end_page.set(info.page_count);
+                                split_pdf_info.set(Some(info));
+                                split_result.set(None);
+                            }
+                            PdfMode::Merge => {
+                                let mut files = (*merge_files).clone();
+                                files.push(PdfFile { path, info });
+                                merge_files.set(files);
+                                merge_result.set(None);
+                            }
+                            PdfMode::Rotate => {
+                                rotate_input_path.set(path);
+                                rotate_pdf_info.set(Some(info));
+                                rotate_result.set(None);
+                            }
+                            PdfMode::Compress => {
+                                compress_input_path.set(path);
+                                compress_pdf_info.set(Some(info));
+                                compress_result.set(None);
+                            }
+                            PdfMode::Text => {}
                         }
                     }
 
@@ -469,6 +813,20 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
         })
     };
 
+    let on_split_page_select = {
+        let start_page = start_page.clone();
+        let end_page = end_page.clone();
+        Callback::from(move |(page, extend): (u32, bool)| {
+            if extend {
+                start_page.set((*start_page).min(page));
+                end_page.set((*end_page).max(page));
+            } else {
+                start_page.set(page);
+                end_page.set(page);
+            }
+        })
+    };
+
     let on_split_type_change = {
         let split_type = split_type.clone();
         Callback::from(move |new_type: SplitType| {
@@ -476,114 +834,507 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
         })
     };
 
-    // Reset handlers
-    let on_reset_split = {
-        let split_input_path = split_input_path.clone();
-        let split_pdf_info = split_pdf_info.clone();
-        let split_result = split_result.clone();
-        let start_page = start_page.clone();
-        let end_page = end_page.clone();
+    // Rotate mode handlers
+    let on_select_rotate_file = {
+        let rotate_input_path = rotate_input_path.clone();
+        let rotate_pdf_info = rotate_pdf_info.clone();
+        let rotate_result = rotate_result.clone();
         Callback::from(move |_| {
-            split_input_path.set(String::new());
-            split_pdf_info.set(None);
-            split_result.set(None);
-            start_page.set(1);
-            end_page.set(1);
+            let rotate_input_path = rotate_input_path.clone();
+            let rotate_pdf_info = rotate_pdf_info.clone();
+            let rotate_result = rotate_result.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    directory: false,
+                    filters: vec![FileFilter {
+                        name: "PDF".to_string(),
+                        extensions: vec!["pdf".to_string()],
+                    }],
+                };
+                let options_js = serde_wasm_bindgen::to_value(&options).unwrap();
+                let result = open(options_js).await;
+
+                if let Some(path) = result.as_string() {
+                    rotate_input_path.set(path.clone());
+                    rotate_result.set(None);
+
+                    let args = serde_wasm_bindgen::to_value(&GetPdfInfoArgs { path }).unwrap();
+                    let info_result = invoke("get_pdf_info_cmd", args).await;
+
+                    if let Ok(info) = serde_wasm_bindgen::from_value::<PdfInfo>(info_result) {
+                        rotate_pdf_info.set(Some(info));
+                    }
+                }
+            });
         })
     };
 
-    let on_reset_merge = {
-        let merge_files = merge_files.clone();
-        let merge_result = merge_result.clone();
-        Callback::from(move |_| {
-            merge_files.set(Vec::new());
-            merge_result.set(None);
+    let on_rotate_pages_change = {
+        let rotate_pages = rotate_pages.clone();
+        let rotate_error = rotate_error.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            rotate_pages.set(input.value());
+            rotate_error.set(None);
         })
     };
 
-    html! {
-        <div class="pdf-tools">
-            // Processing Overlay
-            {if *is_processing {
-                html! {
-                    <div class="processing-overlay">
-                        <div class="processing-content">
-                            <div class="processing-spinner"></div>
-                            <p class="processing-title">{"Processing..."}</p>
-                            <p class="processing-hint">{"Please wait while your PDF is being processed"}</p>
-                        </div>
-                    </div>
-                }
+    let on_rotate_page_select = {
+        let rotate_pages = rotate_pages.clone();
+        let rotate_error = rotate_error.clone();
+        Callback::from(move |(page, _): (u32, bool)| {
+            let mut pages = parse_page_list(&rotate_pages).unwrap_or_default();
+            pages.sort_unstable();
+            pages.dedup();
+            if let Some(index) = pages.iter().position(|&p| p == page) {
+                pages.remove(index);
             } else {
-                html! {}
-            }}
-
-            // Mode Toggle
-            <div class="section">
-                <div class="mode-toggle">
-                    <button
-                        class={if *mode == PdfMode::Split { "mode-btn active" } else { "mode-btn" }}
-                        onclick={
-                            let on_mode_change = on_mode_change.clone();
-                            Callback::from(move |_| on_mode_change.emit(PdfMode::Split))
-                        }
-                    >
-                        {"Split PDF"}
-                    </button>
-                    <button
-                        class={if *mode == PdfMode::Merge { "mode-btn active" } else { "mode-btn" }}
-                        onclick={
-                            let on_mode_change = on_mode_change.clone();
-                            Callback::from(move |_| on_mode_change.emit(PdfMode::Merge))
-                        }
-                    >
-                        {"Merge PDFs"}
-                    </button>
-                </div>
-            </div>
+                pages.push(page);
+                pages.sort_unstable();
+            }
+            rotate_pages.set(
+                pages
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            rotate_error.set(None);
+        })
+    };
 
-            // Split Mode
-            {if *mode == PdfMode::Split {
-                html! {
-                    <>
-                        // File Selection
-                        <div class="section" onclick={on_select_split_file.clone()}>
-                            <div class="drop-zone">
-                                <div class="drop-zone-icon">{"📄"}</div>
-                                <p class="drop-zone-text">{"Click or drag & drop a PDF"}</p>
-                                <p class="drop-zone-hint">{"Select a PDF file to split"}</p>
-                            </div>
-                            {if !split_input_path.is_empty() {
-                                html! { <p class="file-path">{&*split_input_path}</p> }
-                            } else {
-                                html! {}
-                            }}
-                        </div>
+    let on_rotate_angle_change = {
+        let rotate_angle = rotate_angle.clone();
+        Callback::from(move |angle: u16| {
+            rotate_angle.set(angle);
+        })
+    };
 
-                        // PDF Info
-                        {if let Some(info) = &*split_pdf_info {
-                            html! {
-                                <div class="section info-box">
-                                    <h3>{"PDF Info"}</h3>
-                                    <div class="info-grid">
-                                        <div class="info-item">
-                                            <div class="info-item-label">{"Pages"}</div>
-                                            <div class="info-item-value">{info.page_count}</div>
-                                        </div>
-                                        <div class="info-item">
-                                            <div class="info-item-label">{"Size"}</div>
-                                            <div class="info-item-value">{format_size(info.file_size)}</div>
-                                        </div>
-                                        <div class="info-item">
-                                            <div class="info-item-label">{"File"}</div>
-                                            <div class="info-item-value file-name-value">{&info.file_name}</div>
-                                        </div>
-                                    </div>
-                                </div>
-                            }
-                        } else {
-                            html! {}
-                        }}
+    let on_rotate = {
+        let rotate_input_path = rotate_input_path.clone();
+        let rotate_pages = rotate_pages.clone();
+        let rotate_angle = rotate_angle.clone();
+        let rotate_result = rotate_result.clone();
+        let rotate_error = rotate_error.clone();
+        let is_processing = is_processing.clone();
+
+        Callback::from(move |_| {
+            let input_path = (*rotate_input_path).clone();
+            if input_path.is_empty() {
+                return;
+            }
+
+            let pages = match parse_page_list(&rotate_pages) {
+                Ok(pages) => pages,
+                Err(e) => {
+                    rotate_error.set(Some(e));
+                    return;
+                }
+            };
+            let angle = *rotate_angle;
+            let rotate_result = rotate_result.clone();
+            let is_processing = is_processing.clone();
+
+            is_processing.set(true);
+
+            spawn_local(async move {
+                let save_options = SaveDialogOptions {
+                    filters: vec![FileFilter {
+                        name: "PDF".to_string(),
+                        extensions: vec!["pdf".to_string()],
+                    }],
+                    default_path: Some("rotated.pdf".to_string()),
+                };
+                let save_options_js = serde_wasm_bindgen::to_value(&save_options).unwrap();
+                let save_result = save(save_options_js).await;
+
+                if let Some(output_path) = save_result.as_string() {
+                    let args = RotatePagesArgs {
+                        input_path,
+                        output_path,
+                        pages,
+                        angle,
+                    };
+                    let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                    let result = invoke("rotate_pdf_pages_cmd", args_js).await;
+
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<PdfRotateResult>(result) {
+                        rotate_result.set(Some(res));
+                    }
+                }
+
+                is_processing.set(false);
+            });
+        })
+    };
+
+    // Text mode handlers
+    let on_select_text_file = {
+        let text_input_path = text_input_path.clone();
+        let text_result = text_result.clone();
+        let text_export_path = text_export_path.clone();
+        let is_processing = is_processing.clone();
+        Callback::from(move |_| {
+            let text_input_path = text_input_path.clone();
+            let text_result = text_result.clone();
+            let text_export_path = text_export_path.clone();
+            let is_processing = is_processing.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    directory: false,
+                    filters: vec![FileFilter {
+                        name: "PDF".to_string(),
+                        extensions: vec!["pdf".to_string()],
+                    }],
+                };
+                let options_js = serde_wasm_bindgen::to_value(&options).unwrap();
+                let result = open(options_js).await;
+
+                if let Some(path) = result.as_string() {
+                    text_input_path.set(path.clone());
+                    text_export_path.set(None);
+                    is_processing.set(true);
+
+                    let args = ExtractTextArgs {
+                        input_path: path,
+                        start_page: None,
+                        end_page: None,
+                    };
+                    let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                    let result = invoke("extract_pdf_text_cmd", args_js).await;
+                    text_result.set(serde_wasm_bindgen::from_value::<PdfTextResult>(result).ok());
+
+                    is_processing.set(false);
+                }
+            });
+        })
+    };
+
+    let on_copy_text = {
+        let text_result = text_result.clone();
+        let text_copied = text_copied.clone();
+        Callback::from(move |_| {
+            let Some(full_text) = (*text_result).as_ref().map(|r| r.full_text.clone()) else {
+                return;
+            };
+            let text_copied = text_copied.clone();
+
+            if let Some(win) = web_sys::window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&full_text))
+                        .await;
+                    text_copied.set(true);
+
+                    let text_copied_reset = text_copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        text_copied_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let on_export_text = {
+        let text_input_path = text_input_path.clone();
+        let text_export_path = text_export_path.clone();
+        Callback::from(move |_| {
+            let path = (*text_input_path).clone();
+            if path.is_empty() {
+                return;
+            }
+            let text_export_path = text_export_path.clone();
+
+            spawn_local(async move {
+                let save_options = SaveDialogOptions {
+                    filters: vec![FileFilter {
+                        name: "Text".to_string(),
+                        extensions: vec!["txt".to_string()],
+                    }],
+                    default_path: Some("extracted.txt".to_string()),
+                };
+                let save_options_js = serde_wasm_bindgen::to_value(&save_options).unwrap();
+                let save_result = save(save_options_js).await;
+
+                if let Some(output_path) = save_result.as_string() {
+                    let args = ExportTextArgs {
+                        path,
+                        output_path: output_path.clone(),
+                    };
+                    let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                    let result = invoke("export_pdf_text_cmd", args_js).await;
+
+                    if result.is_null() || result.is_undefined() {
+                        text_export_path.set(Some(output_path));
+                    }
+                }
+            });
+        })
+    };
+
+    // Reset handlers
+    let on_reset_split = {
+        let split_input_path = split_input_path.clone();
+        let split_pdf_info = split_pdf_info.clone();
+        let split_result = split_result.clone();
+        let start_page = start_page.clone();
+        let end_page = end_page.clone();
+        Callback::from(move |_| {
+            split_input_path.set(String::new());
+            split_pdf_info.set(None);
+            split_result.set(None);
+            start_page.set(1);
+            end_page.set(1);
+        })
+    };
+
+    let on_reset_merge = {
+        let merge_files = merge_files.clone();
+        let merge_result = merge_result.clone();
+        Callback::from(move |_| {
+            merge_files.set(Vec::new());
+            merge_result.set(None);
+        })
+    };
+
+    // Compress mode handlers
+    let on_select_compress_file = {
+        let compress_input_path = compress_input_path.clone();
+        let compress_pdf_info = compress_pdf_info.clone();
+        let compress_result = compress_result.clone();
+        Callback::from(move |_| {
+            let compress_input_path = compress_input_path.clone();
+            let compress_pdf_info = compress_pdf_info.clone();
+            let compress_result = compress_result.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    directory: false,
+                    filters: vec![FileFilter {
+                        name: "PDF".to_string(),
+                        extensions: vec!["pdf".to_string()],
+                    }],
+                };
+                let options_js = serde_wasm_bindgen::to_value(&options).unwrap();
+                let result = open(options_js).await;
+
+                if let Some(path) = result.as_string() {
+                    compress_input_path.set(path.clone());
+                    compress_result.set(None);
+
+                    let args = serde_wasm_bindgen::to_value(&GetPdfInfoArgs { path }).unwrap();
+                    let info_result = invoke("get_pdf_info_cmd", args).await;
+
+                    if let Ok(info) = serde_wasm_bindgen::from_value::<PdfInfo>(info_result) {
+                        compress_pdf_info.set(Some(info));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_compress = {
+        let compress_input_path = compress_input_path.clone();
+        let compress_level = compress_level.clone();
+        let compress_result = compress_result.clone();
+        let is_processing = is_processing.clone();
+
+        Callback::from(move |_| {
+            let input_path = (*compress_input_path).clone();
+            if input_path.is_empty() {
+                return;
+            }
+            let level = *compress_level;
+            let compress_result = compress_result.clone();
+            let is_processing = is_processing.clone();
+
+            is_processing.set(true);
+
+            spawn_local(async move {
+                let save_options = SaveDialogOptions {
+                    filters: vec![FileFilter {
+                        name: "PDF".to_string(),
+                        extensions: vec!["pdf".to_string()],
+                    }],
+                    default_path: Some("compressed.pdf".to_string()),
+                };
+                let save_options_js = serde_wasm_bindgen::to_value(&save_options).unwrap();
+                let save_result = save(save_options_js).await;
+
+                if let Some(output_path) = save_result.as_string() {
+                    let args = CompressPdfArgs {
+                        input_path,
+                        output_path,
+                        level,
+                    };
+                    let args_js = serde_wasm_bindgen::to_value(&args).unwrap();
+                    let result = invoke("compress_pdf_cmd", args_js).await;
+
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<PdfCompressResult>(result) {
+                        compress_result.set(Some(res));
+                    }
+                }
+
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let on_reset_compress = {
+        let compress_input_path = compress_input_path.clone();
+        let compress_pdf_info = compress_pdf_info.clone();
+        let compress_result = compress_result.clone();
+        Callback::from(move |_| {
+            compress_input_path.set(String::new());
+            compress_pdf_info.set(None);
+            compress_result.set(None);
+        })
+    };
+
+    let on_reset_rotate = {
+        let rotate_input_path = rotate_input_path.clone();
+        let rotate_pdf_info = rotate_pdf_info.clone();
+        let rotate_pages = rotate_pages.clone();
+        let rotate_result = rotate_result.clone();
+        let rotate_error = rotate_error.clone();
+        Callback::from(move |_| {
+            rotate_input_path.set(String::new());
+            rotate_pdf_info.set(None);
+            rotate_pages.set(String::new());
+            rotate_result.set(None);
+            rotate_error.set(None);
+        })
+    };
+
+    let on_reset_text = {
+        let text_input_path = text_input_path.clone();
+        let text_result = text_result.clone();
+        let text_export_path = text_export_path.clone();
+        Callback::from(move |_| {
+            text_input_path.set(String::new());
+            text_result.set(None);
+            text_export_path.set(None);
+        })
+    };
+
+    html! {
+        <div class="pdf-tools">
+            // Processing Overlay
+            {if *is_processing {
+                html! {
+                    <div class="processing-overlay">
+                        <div class="processing-content">
+                            <div class="processing-spinner"></div>
+                            <p class="processing-title">{"Processing..."}</p>
+                            <p class="processing-hint">{"Please wait while your PDF is being processed"}</p>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
+            // Mode Toggle
+            <div class="section">
+                <div class="mode-toggle">
+                    <button
+                        class={if *mode == PdfMode::Split { "mode-btn active" } else { "mode-btn" }}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(PdfMode::Split))
+                        }
+                    >
+                        {"Split PDF"}
+                    </button>
+                    <button
+                        class={if *mode == PdfMode::Merge { "mode-btn active" } else { "mode-btn" }}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(PdfMode::Merge))
+                        }
+                    >
+                        {"Merge PDFs"}
+                    </button>
+                    <button
+                        class={if *mode == PdfMode::Rotate { "mode-btn active" } else { "mode-btn" }}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(PdfMode::Rotate))
+                        }
+                    >
+                        {"Rotate Pages"}
+                    </button>
+                    <button
+                        class={if *mode == PdfMode::Text { "mode-btn active" } else { "mode-btn" }}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(PdfMode::Text))
+                        }
+                    >
+                        {"Extract Text"}
+                    </button>
+                    <button
+                        class={if *mode == PdfMode::Compress { "mode-btn active" } else { "mode-btn" }}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(PdfMode::Compress))
+                        }
+                    >
+                        {"Compress"}
+                    </button>
+                </div>
+            </div>
+
+            // Split Mode
+            {if *mode == PdfMode::Split {
+                html! {
+                    <>
+                        // File Selection
+                        <div class="section" onclick={on_select_split_file.clone()}>
+                            <div class="drop-zone">
+                                <div class="drop-zone-icon">{"📄"}</div>
+                                <p class="drop-zone-text">{"Click or drag & drop a PDF"}</p>
+                                <p class="drop-zone-hint">{"Select a PDF file to split"}</p>
+                            </div>
+                            {if !split_input_path.is_empty() {
+                                html! { <p class="file-path">{&*split_input_path}</p> }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        // PDF Info
+                        {if let Some(info) = &*split_pdf_info {
+                            html! {
+                                <div class="section info-box">
+                                    <h3>{"PDF Info"}</h3>
+                                    <div class="info-grid">
+                                        <div class="info-item">
+                                            <div class="info-item-label">{"Pages"}</div>
+                                            <div class="info-item-value">{info.page_count}</div>
+                                        </div>
+                                        <div class="info-item">
+                                            <div class="info-item-label">{"Size"}</div>
+                                            <div class="info-item-value">{format_size(info.file_size)}</div>
+                                        </div>
+                                        <div class="info-item">
+                                            <div class="info-item-label">{"File"}</div>
+                                            <div class="info-item-value file-name-value">{&info.file_name}</div>
+                                        </div>
+                                    </div>
+                                    {if info.is_encrypted {
+                                        html! { <p class="pdf-encrypted-warning">{"🔒 This PDF is password protected. Remove the password before splitting it."}</p> }
+                                    } else {
+                                        html! {}
+                                    }}
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
 
                         // Split Options
                         {if split_pdf_info.is_some() {
@@ -652,6 +1403,12 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
                                             placeholder="End"
                                         />
                                     </div>
+                                    <p class="pdf-thumb-hint">{"Click a page to start the range, Shift+click to extend it"}</p>
+                                    <PageThumbnailGrid
+                                        path={(*split_input_path).clone()}
+                                        selected={(*start_page..=*end_page).collect::<Vec<u32>>()}
+                                        on_select={on_split_page_select}
+                                    />
                                 </div>
                             }
                         } else {
@@ -711,6 +1468,334 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
                         }}
                     </>
                 }
+            } else if *mode == PdfMode::Rotate {
+                html! {
+                    <>
+                        // File Selection
+                        <div class="section" onclick={on_select_rotate_file.clone()}>
+                            <div class="drop-zone">
+                                <div class="drop-zone-icon">{"🔄"}</div>
+                                <p class="drop-zone-text">{"Click or drag & drop a PDF"}</p>
+                                <p class="drop-zone-hint">{"Select a PDF file to rotate"}</p>
+                            </div>
+                            {if !rotate_input_path.is_empty() {
+                                html! { <p class="file-path">{&*rotate_input_path}</p> }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        // Rotate Options
+                        {if let Some(info) = &*rotate_pdf_info {
+                            html! {
+                                <div class="section">
+                                    <h3>{"Rotation"}</h3>
+                                    <div class="format-options rotate-options">
+                                        {for [90u16, 180, 270].into_iter().map(|angle| {
+                                            let id = format!("rotate_{}", angle);
+                                            let on_rotate_angle_change = on_rotate_angle_change.clone();
+                                            html! {
+                                                <div class="format-option">
+                                                    <input
+                                                        type="radio"
+                                                        name="rotate_angle"
+                                                        id={id.clone()}
+                                                        checked={*rotate_angle == angle}
+                                                        onclick={Callback::from(move |_| on_rotate_angle_change.emit(angle))}
+                                                    />
+                                                    <label for={id}>
+                                                        <span class="format-name">{format!("{}°", angle)}</span>
+                                                    </label>
+                                                </div>
+                                            }
+                                        })}
+                                    </div>
+                                    <h3>{"Pages"}</h3>
+                                    <div class="page-range-inputs">
+                                        <input
+                                            type="text"
+                                            class="rotate-pages-input"
+                                            value={(*rotate_pages).clone()}
+                                            oninput={on_rotate_pages_change}
+                                            placeholder={format!("All pages (e.g. 1, 3, 5-{})", info.page_count.max(5))}
+                                        />
+                                    </div>
+                                    {if let Some(error) = &*rotate_error {
+                                        html! { <p class="rotate-pages-error">{error}</p> }
+                                    } else {
+                                        html! {}
+                                    }}
+                                    <p class="pdf-thumb-hint">{"Click pages to add or remove them"}</p>
+                                    <PageThumbnailGrid
+                                        path={(*rotate_input_path).clone()}
+                                        selected={parse_page_list(&rotate_pages).unwrap_or_default()}
+                                        on_select={on_rotate_page_select}
+                                    />
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
+
+                        // Action Buttons
+                        <div class="pdf-action-buttons">
+                            <button
+                                onclick={on_rotate}
+                                disabled={rotate_input_path.is_empty() || *is_processing}
+                                class="primary-btn compress-btn"
+                            >
+                                {"Rotate Pages"}
+                            </button>
+                            {if !rotate_input_path.is_empty() {
+                                html! {
+                                    <button
+                                        onclick={on_reset_rotate.clone()}
+                                        class="secondary-btn reset-btn"
+                                    >
+                                        {"Reset"}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        // Rotate Result
+                        {if let Some(result) = &*rotate_result {
+                            html! {
+                                <div class={if result.success { "section result-box success" } else { "section result-box error" }}>
+                                    {if result.success {
+                                        html! {
+                                            <>
+                                                <h3>{"Rotation Complete!"}</h3>
+                                                <p class="output-path">{format!("Rotated {} page(s)", result.rotated_pages.len())}</p>
+                                                <p class="output-path">{format!("📁 {}", result.output_path)}</p>
+                                            </>
+                                        }
+                                    } else {
+                                        html! {
+                                            <>
+                                                <h3>{"Rotation Failed"}</h3>
+                                                <p>{result.error.clone().unwrap_or_default()}</p>
+                                            </>
+                                        }
+                                    }}
+                                    {for result.errors.iter().map(|e| html! { <p class="rotate-warning">{format!("⚠ {}", e)}</p> })}
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </>
+                }
+            } else if *mode == PdfMode::Text {
+                html! {
+                    <>
+                        // File Selection
+                        <div class="section" onclick={on_select_text_file.clone()}>
+                            <div class="drop-zone">
+                                <div class="drop-zone-icon">{"📝"}</div>
+                                <p class="drop-zone-text">{"Click or drag & drop a PDF"}</p>
+                                <p class="drop-zone-hint">{"Select a PDF file to extract its text"}</p>
+                            </div>
+                            {if !text_input_path.is_empty() {
+                                html! { <p class="file-path">{&*text_input_path}</p> }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        // Action Buttons
+                        <div class="pdf-action-buttons">
+                            <button
+                                onclick={on_copy_text}
+                                disabled={!text_result.as_ref().is_some_and(|r| r.success && !r.full_text.is_empty())}
+                                class="primary-btn compress-btn"
+                            >
+                                {if *text_copied { "Copied!" } else { "Copy Text" }}
+                            </button>
+                            <button
+                                onclick={on_export_text}
+                                disabled={!text_result.as_ref().is_some_and(|r| r.success)}
+                                class="secondary-btn"
+                            >
+                                {"Export .txt"}
+                            </button>
+                            {if !text_input_path.is_empty() {
+                                html! {
+                                    <button
+                                        onclick={on_reset_text.clone()}
+                                        class="secondary-btn reset-btn"
+                                    >
+                                        {"Reset"}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        {if let Some(output_path) = &*text_export_path {
+                            html! { <p class="output-path">{format!("📁 {}", output_path)}</p> }
+                        } else {
+                            html! {}
+                        }}
+
+                        // Extracted Text
+                        {if let Some(result) = &*text_result {
+                            if result.success {
+                                html! {
+                                    <div class="section pdf-text-pages">
+                                        <h3>{format!("Extracted Text ({} pages)", result.pages.len())}</h3>
+                                        {for result.pages.iter().map(|page| html! {
+                                            <div class="pdf-text-page">
+                                                <div class="pdf-text-page-label">{format!("Page {}", page.page)}</div>
+                                                {if let Some(note) = &page.note {
+                                                    html! { <p class="pdf-text-note">{note}</p> }
+                                                } else {
+                                                    html! { <pre class="pdf-text-content">{&page.text}</pre> }
+                                                }}
+                                            </div>
+                                        })}
+                                    </div>
+                                }
+                            } else {
+                                html! {
+                                    <div class="section result-box error">
+                                        <h3>{"Extraction Failed"}</h3>
+                                        <p>{result.error.clone().unwrap_or_default()}</p>
+                                    </div>
+                                }
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </>
+                }
+            } else if *mode == PdfMode::Compress {
+                html! {
+                    <>
+                        // File Selection
+                        <div class="section" onclick={on_select_compress_file.clone()}>
+                            <div class="drop-zone">
+                                <div class="drop-zone-icon">{"🗜️"}</div>
+                                <p class="drop-zone-text">{"Click or drag & drop a PDF"}</p>
+                                <p class="drop-zone-hint">{"Images are recompressed; text and vector graphics are kept as is"}</p>
+                            </div>
+                            {if !compress_input_path.is_empty() {
+                                html! { <p class="file-path">{&*compress_input_path}</p> }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        {if let Some(info) = &*compress_pdf_info {
+                            html! {
+                                <div class="section">
+                                    <p class="output-path">{format!("{} pages, {}", info.page_count, format_size(info.file_size))}</p>
+                                    <h3>{"Compression Level"}</h3>
+                                    <div class="format-options">
+                                        {for [
+                                            ("low", "Low", "JPEG 85, 200 dpi"),
+                                            ("medium", "Medium", "JPEG 70, 150 dpi"),
+                                            ("high", "High", "JPEG 50, 96 dpi"),
+                                        ].into_iter().map(|(value, name, detail)| {
+                                            let id = format!("compress_{}", value);
+                                            let compress_level = compress_level.clone();
+                                            html! {
+                                                <div class="format-option">
+                                                    <input
+                                                        type="radio"
+                                                        name="compress_level"
+                                                        id={id.clone()}
+                                                        checked={*compress_level == value}
+                                                        onclick={Callback::from(move |_| compress_level.set(value))}
+                                                    />
+                                                    <label for={id}>
+                                                        <span class="format-name">{name}</span>
+                                                        <span class="format-badge">{detail}</span>
+                                                    </label>
+                                                </div>
+                                            }
+                                        })}
+                                    </div>
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
+
+                        // Action Buttons
+                        <div class="pdf-action-buttons">
+                            <button
+                                onclick={on_compress}
+                                disabled={compress_input_path.is_empty() || *is_processing}
+                                class="primary-btn compress-btn"
+                            >
+                                {"Compress PDF"}
+                            </button>
+                            {if !compress_input_path.is_empty() {
+                                html! {
+                                    <button
+                                        onclick={on_reset_compress.clone()}
+                                        class="secondary-btn reset-btn"
+                                    >
+                                        {"Reset"}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        // Compress Result
+                        {if let Some(result) = &*compress_result {
+                            html! {
+                                <div class={if result.success { "section result-box success" } else { "section result-box error" }}>
+                                    {if result.success {
+                                        html! {
+                                            <>
+                                                <h3>{"Compression Complete!"}</h3>
+                                                <div class="result-stats">
+                                                    <div class="result-stat">
+                                                        <div class="result-stat-label">{"Original"}</div>
+                                                        <div class="result-stat-value original">{format_size(result.original_size)}</div>
+                                                    </div>
+                                                    <div class="result-stat">
+                                                        <div class="result-stat-label">{"Compressed"}</div>
+                                                        <div class="result-stat-value compressed">{format_size(result.compressed_size)}</div>
+                                                    </div>
+                                                    <div class="result-stat">
+                                                        <div class="result-stat-label">{"Saved"}</div>
+                                                        <div class="result-stat-value saved">{format!("{:.1}%", result.reduction_ratio)}</div>
+                                                    </div>
+                                                </div>
+                                                <p class="output-path">{format!("Recompressed {} of {} image(s)", result.images_compressed, result.images_found)}</p>
+                                                <p class="output-path">{format!("📁 {}", result.output_path)}</p>
+                                            </>
+                                        }
+                                    } else if result.nothing_to_reduce {
+                                        html! {
+                                            <>
+                                                <h3>{"Nothing to Reduce"}</h3>
+                                                <p>{result.error.clone().unwrap_or_default()}</p>
+                                            </>
+                                        }
+                                    } else {
+                                        html! {
+                                            <>
+                                                <h3>{"Compression Failed"}</h3>
+                                                <p>{result.error.clone().unwrap_or_default()}</p>
+                                            </>
+                                        }
+                                    }}
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </>
+                }
             } else {
                 // Merge Mode
                 html! {
@@ -760,7 +1845,13 @@ pub fn pdf_tools(props: &PdfToolsProps) -> Html {
                                                     </div>
                                                     <div class="merge-file-info">
                                                         <span class="merge-file-name">{&file.info.file_name}</span>
-                                                        <span class="merge-file-pages">{format!("{} pages", file.info.page_count)}</span>
+                                                        <span class="merge-file-pages">
+                                                            {if file.info.is_encrypted {
+                                                                "🔒 Password protected".to_string()
+                                                            } else {
+                                                                format!("{} pages", file.info.page_count)
+                                                            }}
+                                                        </span>
                                                     </div>
                                                     <button class="remove-file-btn" onclick={on_remove}>{"×"}</button>
                                                 </div>
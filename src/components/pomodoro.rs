@@ -0,0 +1,385 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
+}
+
+/// Days shown in the stats section, ending today
+const STATS_DAYS: i32 = 7;
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum PomodoroPhase {
+    #[default]
+    Idle,
+    Work,
+    Break,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PomodoroState {
+    phase: PomodoroPhase,
+    task_id: Option<String>,
+    remaining_seconds: u32,
+    work_minutes: u32,
+    break_minutes: u32,
+    paused: bool,
+    completed: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct TickEvent {
+    payload: PomodoroState,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskPomodoroCount {
+    task_id: Option<String>,
+    count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct DailyPomodoroStats {
+    date: String,
+    count: u32,
+    minutes: u32,
+    tasks: Vec<TaskPomodoroCount>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct KanbanTask {
+    id: String,
+    title: String,
+    column: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct KanbanBoard {
+    tasks: Vec<KanbanTask>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StartArgs {
+    task_id: Option<String>,
+    work_minutes: u32,
+    break_minutes: u32,
+}
+
+#[derive(Serialize)]
+struct DateRange {
+    start: String,
+    end: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsArgs {
+    date_range: DateRange,
+}
+
+fn format_remaining(seconds: u32) -> String {
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Local date `days_ago` days before today as `YYYY-MM-DD`
+fn local_date(days_ago: i32) -> String {
+    let now = js_sys::Date::now();
+    let date = js_sys::Date::new(&JsValue::from_f64(
+        now - f64::from(days_ago) * 24.0 * 60.0 * 60.0 * 1000.0,
+    ));
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date()
+    )
+}
+
+async fn fetch_stats() -> Option<Vec<DailyPomodoroStats>> {
+    let args = StatsArgs {
+        date_range: DateRange {
+            start: local_date(STATS_DAYS - 1),
+            end: local_date(0),
+        },
+    };
+    let args = serde_wasm_bindgen::to_value(&args).unwrap();
+    let result = invoke("get_pomodoro_stats_cmd", args).await;
+    serde_wasm_bindgen::from_value::<Vec<DailyPomodoroStats>>(result).ok()
+}
+
+#[function_component(Pomodoro)]
+pub fn pomodoro() -> Html {
+    let (i18n, _) = use_translation();
+    let state = use_state(PomodoroState::default);
+    let tasks = use_state(Vec::<KanbanTask>::new);
+    let selected_task = use_state(String::new);
+    let work_minutes = use_state(|| 25u32);
+    let break_minutes = use_state(|| 5u32);
+    let stats = use_state(Vec::<DailyPomodoroStats>::new);
+    let error = use_state(|| Option::<String>::None);
+
+    // Restore the running timer, load tasks and follow every tick
+    {
+        let state = state.clone();
+        let tasks = tasks.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let current = invoke("get_pomodoro_state_cmd", JsValue::NULL).await;
+                if let Ok(current) = serde_wasm_bindgen::from_value::<PomodoroState>(current) {
+                    state.set(current);
+                }
+                let board = invoke("load_kanban_board_cmd", JsValue::NULL).await;
+                if let Ok(board) = serde_wasm_bindgen::from_value::<KanbanBoard>(board) {
+                    tasks.set(board.tasks);
+                }
+
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<TickEvent>(event) {
+                        state.set(event.payload);
+                    }
+                });
+                let _ = listen("pomodoro-tick", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    // Reload the stats on open and whenever another work period is finished
+    {
+        let stats = stats.clone();
+        use_effect_with(state.completed, move |_| {
+            spawn_local(async move {
+                if let Some(days) = fetch_stats().await {
+                    stats.set(days);
+                }
+            });
+            || {}
+        });
+    }
+
+    let on_task_change = {
+        let selected_task = selected_task.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            selected_task.set(select.value());
+        })
+    };
+
+    let minutes_input = |handle: UseStateHandle<u32>| {
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(minutes) = input.value().parse::<u32>() {
+                handle.set(minutes);
+            }
+        })
+    };
+
+    let on_start = {
+        let state = state.clone();
+        let selected_task = selected_task.clone();
+        let work_minutes = work_minutes.clone();
+        let break_minutes = break_minutes.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            let args = StartArgs {
+                task_id: (!selected_task.is_empty()).then(|| (*selected_task).clone()),
+                work_minutes: *work_minutes,
+                break_minutes: *break_minutes,
+            };
+            let state = state.clone();
+            let error = error.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                match try_invoke("start_pomodoro_cmd", args).await {
+                    Ok(result) => {
+                        if let Ok(started) = serde_wasm_bindgen::from_value::<PomodoroState>(result)
+                        {
+                            state.set(started);
+                        }
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e.as_string().unwrap_or_default())),
+                }
+            });
+        })
+    };
+
+    let on_pause = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            let state = state.clone();
+            spawn_local(async move {
+                let result = invoke("pause_pomodoro_cmd", JsValue::NULL).await;
+                if let Ok(current) = serde_wasm_bindgen::from_value::<PomodoroState>(result) {
+                    state.set(current);
+                }
+            });
+        })
+    };
+
+    let on_stop = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            let state = state.clone();
+            spawn_local(async move {
+                let result = invoke("stop_pomodoro_cmd", JsValue::NULL).await;
+                if let Ok(current) = serde_wasm_bindgen::from_value::<PomodoroState>(result) {
+                    state.set(current);
+                }
+            });
+        })
+    };
+
+    let task_title = |task_id: &Option<String>| match task_id {
+        Some(id) => tasks
+            .iter()
+            .find(|task| &task.id == id)
+            .map(|task| task.title.clone())
+            .unwrap_or_else(|| i18n.t("pomodoro.deleted_task")),
+        None => i18n.t("pomodoro.no_task"),
+    };
+
+    let is_idle = state.phase == PomodoroPhase::Idle;
+    let phase_label = match state.phase {
+        PomodoroPhase::Idle => i18n.t("pomodoro.idle"),
+        PomodoroPhase::Work => i18n.t("pomodoro.work"),
+        PomodoroPhase::Break => i18n.t("pomodoro.break"),
+    };
+    let phase_class = match state.phase {
+        PomodoroPhase::Idle => "idle",
+        PomodoroPhase::Work => "work",
+        PomodoroPhase::Break => "break",
+    };
+    let display_seconds = if is_idle {
+        *work_minutes * 60
+    } else {
+        state.remaining_seconds
+    };
+    let max_count = stats.iter().map(|day| day.count).max().unwrap_or(0).max(1);
+    let week_count: u32 = stats.iter().map(|day| day.count).sum();
+    let week_minutes: u32 = stats.iter().map(|day| day.minutes).sum();
+
+    html! {
+        <div class="pomodoro">
+            <div class="section pomodoro-timer">
+                <span class={classes!("pomodoro-phase", phase_class)}>
+                    {phase_label}
+                    if state.paused {
+                        {format!(" ({})", i18n.t("pomodoro.paused"))}
+                    }
+                </span>
+                <div class={classes!("pomodoro-clock", phase_class)}>{format_remaining(display_seconds)}</div>
+                if !is_idle {
+                    <p class="pomodoro-current">
+                        {format!(
+                            "{} / {} {}",
+                            task_title(&state.task_id),
+                            state.completed,
+                            i18n.t("pomodoro.completed")
+                        )}
+                    </p>
+                }
+                <div class="pomodoro-actions">
+                    if is_idle {
+                        <button class="primary-btn" onclick={on_start}>{i18n.t("pomodoro.start")}</button>
+                    } else {
+                        <button class="secondary-btn" onclick={on_pause}>
+                            if state.paused {
+                                {i18n.t("pomodoro.resume")}
+                            } else {
+                                {i18n.t("pomodoro.pause")}
+                            }
+                        </button>
+                        <button class="secondary-btn" onclick={on_stop}>{i18n.t("pomodoro.stop")}</button>
+                    }
+                </div>
+            </div>
+
+            <div class="section pomodoro-settings">
+                <label>
+                    {i18n.t("pomodoro.task")}
+                    <select onchange={on_task_change} disabled={!is_idle}>
+                        <option value="" selected={selected_task.is_empty()}>{i18n.t("pomodoro.no_task")}</option>
+                        { for tasks.iter().filter(|task| task.column != "Done").map(|task| html! {
+                            <option value={task.id.clone()} selected={*selected_task == task.id}>{task.title.clone()}</option>
+                        }) }
+                    </select>
+                </label>
+                <label>
+                    {i18n.t("pomodoro.work_minutes")}
+                    <input
+                        type="number"
+                        min="1"
+                        max="240"
+                        value={work_minutes.to_string()}
+                        oninput={minutes_input(work_minutes.clone())}
+                        disabled={!is_idle}
+                    />
+                </label>
+                <label>
+                    {i18n.t("pomodoro.break_minutes")}
+                    <input
+                        type="number"
+                        min="1"
+                        max="240"
+                        value={break_minutes.to_string()}
+                        oninput={minutes_input(break_minutes.clone())}
+                        disabled={!is_idle}
+                    />
+                </label>
+            </div>
+
+            if let Some(err) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{format!("⚠ {}", err)}</div>
+                </div>
+            }
+
+            <div class="section">
+                <div class="section-header">
+                    <h3>{i18n.t("pomodoro.stats")}</h3>
+                    <span class="pomodoro-summary">
+                        {format!("{} {} / {} {}", week_count, i18n.t("pomodoro.pomodoros"), week_minutes, i18n.t("pomodoro.minutes"))}
+                    </span>
+                </div>
+                <div class="pomodoro-stats">
+                    { for stats.iter().map(|day| html! {
+                        <div class="pomodoro-day" key={day.date.clone()}>
+                            <span class="pomodoro-date">{day.date.clone()}</span>
+                            <span class="pomodoro-bar">
+                                <span
+                                    class="pomodoro-bar-fill"
+                                    style={format!("width: {:.1}%", day.count as f64 * 100.0 / max_count as f64)}
+                                ></span>
+                            </span>
+                            <span class="pomodoro-count">{day.count.to_string()}</span>
+                            <span class="pomodoro-tasks">
+                                { for day.tasks.iter().map(|task| html! {
+                                    <span class="pomodoro-task-chip">
+                                        {format!("{} × {}", task_title(&task.task_id), task.count)}
+                                    </span>
+                                }) }
+                            </span>
+                        </div>
+                    }) }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,416 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum ProcessSort {
+    Cpu,
+    Memory,
+    Pid,
+    Name,
+}
+
+impl ProcessSort {
+    const ALL: [ProcessSort; 4] = [
+        ProcessSort::Cpu,
+        ProcessSort::Memory,
+        ProcessSort::Pid,
+        ProcessSort::Name,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            ProcessSort::Cpu => "cpu",
+            ProcessSort::Memory => "memory",
+            ProcessSort::Pid => "pid",
+            ProcessSort::Name => "name",
+        }
+    }
+
+    fn translation_key(self) -> &'static str {
+        match self {
+            ProcessSort::Cpu => "process_tools.sort_cpu",
+            ProcessSort::Memory => "process_tools.sort_memory",
+            ProcessSort::Pid => "process_tools.sort_pid",
+            ProcessSort::Name => "process_tools.sort_name",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ListProcessesArgs {
+    sort: ProcessSort,
+    filter: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FindByPortArgs {
+    port: u16,
+}
+
+#[derive(Serialize)]
+struct KillProcessArgs {
+    pid: u32,
+    force: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProcessInfo {
+    pid: u32,
+    name: String,
+    cpu_usage: f32,
+    memory_bytes: u64,
+    command: String,
+    protected: bool,
+}
+
+/// Processes found for a port lookup
+#[derive(Debug, Clone, PartialEq)]
+struct PortLookup {
+    port: u16,
+    processes: Vec<ProcessInfo>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// The list is loaded the first time the tab is shown
+    #[prop_or_default]
+    pub active: bool,
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_073_741_824 {
+        format!("{:.2} GB", bytes as f64 / 1_073_741_824.0)
+    } else if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn js_error(e: JsValue) -> String {
+    e.as_string().unwrap_or_else(|| "Unknown error".to_string())
+}
+
+#[function_component(ProcessTools)]
+pub fn process_tools(props: &Props) -> Html {
+    let (i18n, _) = use_translation();
+    let processes = use_state(|| Option::<Vec<ProcessInfo>>::None);
+    let sort = use_state(|| ProcessSort::Cpu);
+    let filter = use_state(String::new);
+    let is_loading = use_state(|| false);
+    let port_input = use_state(String::new);
+    let port_lookup = use_state(|| Option::<PortLookup>::None);
+    // Kill waiting for confirmation: (pid, force)
+    let pending_kill = use_state(|| Option::<(u32, bool)>::None);
+    let error = use_state(|| Option::<String>::None);
+    // Bumped to reload the list, e.g. after a kill
+    let reload = use_state(|| 0u32);
+    let loaded_once = use_state(|| false);
+
+    {
+        let processes = processes.clone();
+        let is_loading = is_loading.clone();
+        let loaded_once = loaded_once.clone();
+        use_effect_with(
+            (
+                props.active || *loaded_once,
+                *sort,
+                (*filter).clone(),
+                *reload,
+            ),
+            move |(enabled, sort, filter, _)| {
+                if *enabled {
+                    let args = serde_wasm_bindgen::to_value(&ListProcessesArgs {
+                        sort: *sort,
+                        filter: Some(filter.clone()).filter(|f| !f.trim().is_empty()),
+                    })
+                    .unwrap();
+                    is_loading.set(true);
+                    loaded_once.set(true);
+                    spawn_local(async move {
+                        let result = invoke("list_processes_cmd", args).await;
+                        if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<ProcessInfo>>(result)
+                        {
+                            processes.set(Some(list));
+                        }
+                        is_loading.set(false);
+                    });
+                }
+                || {}
+            },
+        );
+    }
+
+    let run_port_lookup = {
+        let port_lookup = port_lookup.clone();
+        let error = error.clone();
+        move |port: u16| {
+            let port_lookup = port_lookup.clone();
+            let error = error.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&FindByPortArgs { port }).unwrap();
+                match try_invoke("find_process_by_port_cmd", args).await {
+                    Ok(result) => {
+                        let processes = serde_wasm_bindgen::from_value(result).unwrap_or_default();
+                        port_lookup.set(Some(PortLookup { port, processes }));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(js_error(e))),
+                }
+            });
+        }
+    };
+
+    let on_find_port = {
+        let port_input = port_input.clone();
+        let error = error.clone();
+        let run_port_lookup = run_port_lookup.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            match port_input.trim().parse::<u16>() {
+                Ok(port) if port > 0 => run_port_lookup(port),
+                _ => error.set(Some(i18n.t("process_tools.invalid_port"))),
+            }
+        })
+    };
+
+    let on_port_input = {
+        let port_input = port_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            port_input.set(input.value());
+        })
+    };
+
+    let on_clear_port = {
+        let port_lookup = port_lookup.clone();
+        Callback::from(move |_| port_lookup.set(None))
+    };
+
+    let on_filter_change = {
+        let filter = filter.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            filter.set(input.value());
+        })
+    };
+
+    let on_sort_change = {
+        let sort = sort.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(next) = ProcessSort::ALL
+                .into_iter()
+                .find(|s| s.id() == select.value())
+            {
+                sort.set(next);
+            }
+        })
+    };
+
+    let on_refresh = {
+        let reload = reload.clone();
+        Callback::from(move |_| reload.set(*reload + 1))
+    };
+
+    let request_kill = {
+        let pending_kill = pending_kill.clone();
+        move |pid: u32, force: bool| {
+            let pending_kill = pending_kill.clone();
+            Callback::from(move |_| pending_kill.set(Some((pid, force))))
+        }
+    };
+
+    let on_cancel_kill = {
+        let pending_kill = pending_kill.clone();
+        Callback::from(move |_| pending_kill.set(None))
+    };
+
+    let on_confirm_kill = {
+        let pending_kill = pending_kill.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        let port_lookup = port_lookup.clone();
+        let run_port_lookup = run_port_lookup.clone();
+        Callback::from(move |_| {
+            let Some((pid, force)) = *pending_kill else {
+                return;
+            };
+            pending_kill.set(None);
+            let error = error.clone();
+            let reload = reload.clone();
+            let lookup_port = port_lookup.as_ref().map(|lookup| lookup.port);
+            let run_port_lookup = run_port_lookup.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&KillProcessArgs { pid, force }).unwrap();
+                match try_invoke("kill_process_cmd", args).await {
+                    Ok(_) => {
+                        error.set(None);
+                        reload.set(*reload + 1);
+                        if let Some(port) = lookup_port {
+                            run_port_lookup(port);
+                        }
+                    }
+                    Err(e) => error.set(Some(js_error(e))),
+                }
+            });
+        })
+    };
+
+    let render_table = |list: &[ProcessInfo]| {
+        html! {
+            <div class="process-table-wrapper">
+                <table class="process-table">
+                    <thead>
+                        <tr>
+                            <th class="num">{"PID"}</th>
+                            <th>{i18n.t("process_tools.name")}</th>
+                            <th class="num">{"CPU"}</th>
+                            <th class="num">{i18n.t("process_tools.memory")}</th>
+                            <th>{i18n.t("process_tools.command")}</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for list.iter().map(|process| {
+                            let confirming = pending_kill.filter(|(pid, _)| *pid == process.pid);
+                            html! {
+                                <tr class={classes!(process.protected.then_some("protected"))}>
+                                    <td class="num">{process.pid}</td>
+                                    <td class="process-name">{&process.name}</td>
+                                    <td class="num">{format!("{:.1}%", process.cpu_usage)}</td>
+                                    <td class="num">{format_size(process.memory_bytes)}</td>
+                                    <td class="process-command" title={process.command.clone()}>{&process.command}</td>
+                                    <td class="process-actions">
+                                        if let Some((_, force)) = confirming {
+                                            <span class="process-confirm">
+                                                {if force {
+                                                    i18n.t("process_tools.confirm_force_kill")
+                                                } else {
+                                                    i18n.t("process_tools.confirm_kill")
+                                                }}
+                                            </span>
+                                            <button class="secondary-btn process-kill-btn" onclick={on_confirm_kill.clone()}>
+                                                {i18n.t("process_tools.yes")}
+                                            </button>
+                                            <button class="secondary-btn" onclick={on_cancel_kill.clone()}>
+                                                {i18n.t("process_tools.cancel")}
+                                            </button>
+                                        } else if process.protected {
+                                            <span class="process-protected" title={i18n.t("process_tools.protected_hint")}>
+                                                {i18n.t("process_tools.protected")}
+                                            </span>
+                                        } else {
+                                            <button class="secondary-btn" onclick={request_kill(process.pid, false)}>
+                                                {i18n.t("process_tools.kill")}
+                                            </button>
+                                            <button class="secondary-btn process-kill-btn" onclick={request_kill(process.pid, true)}>
+                                                {i18n.t("process_tools.force_kill")}
+                                            </button>
+                                        }
+                                    </td>
+                                </tr>
+                            }
+                        }) }
+                    </tbody>
+                </table>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="process-tools">
+            <div class="section">
+                <div class="section-header">
+                    <h3>{i18n.t("process_tools.find_by_port")}</h3>
+                </div>
+                <form class="process-port-form" onsubmit={on_find_port}>
+                    <input
+                        type="number"
+                        class="form-input"
+                        min="1"
+                        max="65535"
+                        placeholder="3000"
+                        value={(*port_input).clone()}
+                        oninput={on_port_input}
+                    />
+                    <button type="submit" class="primary-btn">{i18n.t("process_tools.find")}</button>
+                </form>
+                if let Some(lookup) = (*port_lookup).clone() {
+                    <div class="process-port-result">
+                        <div class="section-header">
+                            <span>{format!("{} {}", i18n.t("process_tools.port"), lookup.port)}</span>
+                            <button class="secondary-btn" onclick={on_clear_port}>{i18n.t("common.clear")}</button>
+                        </div>
+                        if lookup.processes.is_empty() {
+                            <p class="process-empty">{i18n.t("process_tools.port_free")}</p>
+                        } else {
+                            {render_table(&lookup.processes)}
+                        }
+                    </div>
+                }
+            </div>
+
+            if let Some(message) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{"⚠ "}{message}</div>
+                </div>
+            }
+
+            <div class="section">
+                <div class="section-header">
+                    <h3>
+                        {i18n.t("process_tools.processes")}
+                        if let Some(list) = processes.as_ref() {
+                            {format!(" ({})", list.len())}
+                        }
+                    </h3>
+                    <div class="process-controls">
+                        <input
+                            type="search"
+                            class="form-input"
+                            placeholder={i18n.t("process_tools.filter_placeholder")}
+                            value={(*filter).clone()}
+                            onchange={on_filter_change}
+                        />
+                        <select class="form-select" onchange={on_sort_change}>
+                            { for ProcessSort::ALL.iter().map(|s| html! {
+                                <option value={s.id()} selected={*sort == *s}>{i18n.t(s.translation_key())}</option>
+                            }) }
+                        </select>
+                        <button class="secondary-btn" onclick={on_refresh} disabled={*is_loading}>
+                            if *is_loading {
+                                <span class="spinner"></span>
+                            }
+                            {i18n.t("process_tools.refresh")}
+                        </button>
+                    </div>
+                </div>
+                if let Some(list) = processes.as_ref() {
+                    if list.is_empty() {
+                        <p class="process-empty">{i18n.t("process_tools.no_match")}</p>
+                    } else {
+                        {render_table(list)}
+                    }
+                }
+            </div>
+        </div>
+    }
+}
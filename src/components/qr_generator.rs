@@ -0,0 +1,543 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn save(options: JsValue) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct SaveDialogOptions {
+    #[serde(rename = "defaultPath")]
+    default_path: String,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ContentMode {
+    Text,
+    Wifi,
+    VCard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum ErrorCorrection {
+    L,
+    M,
+    Q,
+    H,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QrOptions {
+    size: u32,
+    error_correction: ErrorCorrection,
+    foreground: String,
+    background: String,
+    margin: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum WifiSecurity {
+    Wpa,
+    Wep,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WifiConfig {
+    ssid: String,
+    password: String,
+    security: WifiSecurity,
+    hidden: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VCardInfo {
+    first_name: String,
+    last_name: String,
+    organization: String,
+    title: String,
+    phone: String,
+    email: String,
+    url: String,
+    address: String,
+    note: String,
+}
+
+#[derive(Serialize)]
+struct WifiArgs {
+    config: WifiConfig,
+}
+
+#[derive(Serialize)]
+struct VCardArgs {
+    card: VCardInfo,
+}
+
+#[derive(Serialize)]
+struct GenerateArgs {
+    content: String,
+    options: QrOptions,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveArgs {
+    content: String,
+    options: QrOptions,
+    output_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QrGenerateResult {
+    success: bool,
+    data_uri: String,
+    version: u32,
+    modules: u32,
+    byte_length: usize,
+    error: Option<String>,
+}
+
+type VCardField = fn(&mut VCardInfo) -> &mut String;
+
+const VCARD_FIELDS: [(&str, VCardField); 9] = [
+    ("last_name", |c| &mut c.last_name),
+    ("first_name", |c| &mut c.first_name),
+    ("organization", |c| &mut c.organization),
+    ("title", |c| &mut c.title),
+    ("phone", |c| &mut c.phone),
+    ("email", |c| &mut c.email),
+    ("url", |c| &mut c.url),
+    ("address", |c| &mut c.address),
+    ("note", |c| &mut c.note),
+];
+
+#[function_component(QrGenerator)]
+pub fn qr_generator() -> Html {
+    let (i18n, _) = use_translation();
+    let mode = use_state(|| ContentMode::Text);
+    let text = use_state(|| "https://".to_string());
+    let wifi = use_state(|| WifiConfig {
+        ssid: String::new(),
+        password: String::new(),
+        security: WifiSecurity::Wpa,
+        hidden: false,
+    });
+    let vcard = use_state(VCardInfo::default);
+    let options = use_state(|| QrOptions {
+        size: 256,
+        error_correction: ErrorCorrection::M,
+        foreground: "#000000".to_string(),
+        background: "#ffffff".to_string(),
+        margin: 4,
+    });
+    let content = use_state(String::new);
+    let result = use_state(|| Option::<QrGenerateResult>::None);
+    let save_message = use_state(|| Option::<Result<String, String>>::None);
+
+    // Rebuild the content and preview on every edit
+    {
+        let content = content.clone();
+        let result = result.clone();
+        use_effect_with(
+            (
+                *mode,
+                (*text).clone(),
+                (*wifi).clone(),
+                (*vcard).clone(),
+                (*options).clone(),
+            ),
+            move |(mode, text, wifi, vcard, options)| {
+                let (mode, text, wifi, vcard, options) = (
+                    *mode,
+                    text.clone(),
+                    wifi.clone(),
+                    vcard.clone(),
+                    options.clone(),
+                );
+                spawn_local(async move {
+                    let built = match mode {
+                        ContentMode::Text => text,
+                        ContentMode::Wifi if wifi.ssid.is_empty() => String::new(),
+                        ContentMode::Wifi => {
+                            let args =
+                                serde_wasm_bindgen::to_value(&WifiArgs { config: wifi }).unwrap();
+                            invoke("build_wifi_qr_content_cmd", args)
+                                .await
+                                .as_string()
+                                .unwrap_or_default()
+                        }
+                        ContentMode::VCard
+                            if vcard.first_name.is_empty() && vcard.last_name.is_empty() =>
+                        {
+                            String::new()
+                        }
+                        ContentMode::VCard => {
+                            let args =
+                                serde_wasm_bindgen::to_value(&VCardArgs { card: vcard }).unwrap();
+                            invoke("build_vcard_qr_content_cmd", args)
+                                .await
+                                .as_string()
+                                .unwrap_or_default()
+                        }
+                    };
+                    content.set(built.clone());
+                    if built.is_empty() {
+                        result.set(None);
+                        return;
+                    }
+
+                    let args = serde_wasm_bindgen::to_value(&GenerateArgs {
+                        content: built,
+                        options,
+                    })
+                    .unwrap();
+                    let response = invoke("generate_qr_cmd", args).await;
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<QrGenerateResult>(response) {
+                        result.set(Some(res));
+                    }
+                });
+                || ()
+            },
+        );
+    }
+
+    let on_text_change = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            text.set(target.value());
+        })
+    };
+
+    let on_ssid_change = {
+        let wifi = wifi.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut config = (*wifi).clone();
+            config.ssid = target.value();
+            wifi.set(config);
+        })
+    };
+
+    let on_wifi_password_change = {
+        let wifi = wifi.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut config = (*wifi).clone();
+            config.password = target.value();
+            wifi.set(config);
+        })
+    };
+
+    let on_security_change = {
+        let wifi = wifi.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut config = (*wifi).clone();
+            config.security = match select.value().as_str() {
+                "wep" => WifiSecurity::Wep,
+                "none" => WifiSecurity::None,
+                _ => WifiSecurity::Wpa,
+            };
+            wifi.set(config);
+        })
+    };
+
+    let on_hidden_change = {
+        let wifi = wifi.clone();
+        Callback::from(move |e: Event| {
+            let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut config = (*wifi).clone();
+            config.hidden = checkbox.checked();
+            wifi.set(config);
+        })
+    };
+
+    let update_options = {
+        let options = options.clone();
+        Callback::from(move |update: Box<dyn Fn(&mut QrOptions)>| {
+            let mut next = (*options).clone();
+            update(&mut next);
+            options.set(next);
+        })
+    };
+
+    let on_size_change = {
+        let update_options = update_options.clone();
+        Callback::from(move |e: Event| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(size) = target.value().parse::<u32>() {
+                update_options.emit(Box::new(move |o| o.size = size));
+            }
+        })
+    };
+
+    let on_margin_change = {
+        let update_options = update_options.clone();
+        Callback::from(move |e: Event| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(margin) = target.value().parse::<u32>() {
+                update_options.emit(Box::new(move |o| o.margin = margin));
+            }
+        })
+    };
+
+    let on_level_change = {
+        let update_options = update_options.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let level = match select.value().as_str() {
+                "L" => ErrorCorrection::L,
+                "Q" => ErrorCorrection::Q,
+                "H" => ErrorCorrection::H,
+                _ => ErrorCorrection::M,
+            };
+            update_options.emit(Box::new(move |o| o.error_correction = level));
+        })
+    };
+
+    let on_foreground_change = {
+        let update_options = update_options.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let color = target.value();
+            update_options.emit(Box::new(move |o| o.foreground = color.clone()));
+        })
+    };
+
+    let on_background_change = {
+        let update_options = update_options.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let color = target.value();
+            update_options.emit(Box::new(move |o| o.background = color.clone()));
+        })
+    };
+
+    let on_save = {
+        let content = content.clone();
+        let options = options.clone();
+        let save_message = save_message.clone();
+        Callback::from(move |_| {
+            let content = (*content).clone();
+            let options = options.clone();
+            let save_message = save_message.clone();
+            if content.is_empty() {
+                return;
+            }
+            spawn_local(async move {
+                let dialog_options = SaveDialogOptions {
+                    default_path: "qrcode.png".to_string(),
+                    filters: vec![
+                        FileFilter {
+                            name: "PNG".to_string(),
+                            extensions: vec!["png".to_string()],
+                        },
+                        FileFilter {
+                            name: "SVG".to_string(),
+                            extensions: vec!["svg".to_string()],
+                        },
+                    ],
+                };
+                let opts = serde_wasm_bindgen::to_value(&dialog_options).unwrap();
+                let Some(output_path) = save(opts).await.as_string() else {
+                    return;
+                };
+
+                let args = serde_wasm_bindgen::to_value(&SaveArgs {
+                    content,
+                    options: (*options).clone(),
+                    output_path: output_path.clone(),
+                })
+                .unwrap();
+                match try_invoke("save_qr_cmd", args).await {
+                    Ok(_) => save_message.set(Some(Ok(output_path))),
+                    Err(e) => save_message.set(Some(Err(e.as_string().unwrap_or_default()))),
+                }
+            });
+        })
+    };
+
+    let mode_btn = |target: ContentMode, label_key: &str| {
+        let mode = mode.clone();
+        html! {
+            <button
+                class={classes!("mode-btn", (*mode == target).then_some("active"))}
+                onclick={Callback::from(move |_| mode.set(target))}
+            >
+                {i18n.t(label_key)}
+            </button>
+        }
+    };
+
+    let vcard_input = |key: &str, field: VCardField| {
+        let vcard = vcard.clone();
+        let value = field(&mut (*vcard).clone()).clone();
+        let oninput = Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut card = (*vcard).clone();
+            *field(&mut card) = target.value();
+            vcard.set(card);
+        });
+        html! {
+            <label class="qr-field">
+                <span>{i18n.t(&format!("qr_generator.vcard_{}", key))}</span>
+                <input type="text" class="form-input" {value} {oninput} />
+            </label>
+        }
+    };
+
+    html! {
+        <div class="qr-generator">
+            <div class="qr-layout">
+                <div class="qr-form">
+                    <div class="section">
+                        <div class="mode-toggle">
+                            {mode_btn(ContentMode::Text, "qr_generator.mode_text")}
+                            {mode_btn(ContentMode::Wifi, "qr_generator.mode_wifi")}
+                            {mode_btn(ContentMode::VCard, "qr_generator.mode_vcard")}
+                        </div>
+
+                        <div class="qr-content-fields">
+                            {match *mode {
+                                ContentMode::Text => html! {
+                                    <textarea
+                                        class="form-textarea qr-text"
+                                        value={(*text).clone()}
+                                        oninput={on_text_change}
+                                        placeholder={i18n.t("qr_generator.text_placeholder")}
+                                    />
+                                },
+                                ContentMode::Wifi => html! {
+                                    <>
+                                        <label class="qr-field">
+                                            <span>{i18n.t("qr_generator.ssid")}</span>
+                                            <input type="text" class="form-input" value={wifi.ssid.clone()} oninput={on_ssid_change} />
+                                        </label>
+                                        <label class="qr-field">
+                                            <span>{i18n.t("qr_generator.security")}</span>
+                                            <select class="form-select" onchange={on_security_change}>
+                                                <option value="wpa" selected={wifi.security == WifiSecurity::Wpa}>{"WPA/WPA2/WPA3"}</option>
+                                                <option value="wep" selected={wifi.security == WifiSecurity::Wep}>{"WEP"}</option>
+                                                <option value="none" selected={wifi.security == WifiSecurity::None}>{i18n.t("qr_generator.security_none")}</option>
+                                            </select>
+                                        </label>
+                                        if wifi.security != WifiSecurity::None {
+                                            <label class="qr-field">
+                                                <span>{i18n.t("qr_generator.password")}</span>
+                                                <input type="text" class="form-input" value={wifi.password.clone()} oninput={on_wifi_password_change} />
+                                            </label>
+                                        }
+                                        <label class="checkbox-label">
+                                            <input type="checkbox" checked={wifi.hidden} onchange={on_hidden_change} />
+                                            {i18n.t("qr_generator.hidden")}
+                                        </label>
+                                    </>
+                                },
+                                ContentMode::VCard => html! {
+                                    <div class="qr-vcard-grid">
+                                        { for VCARD_FIELDS.iter().map(|(key, field)| vcard_input(key, *field)) }
+                                    </div>
+                                },
+                            }}
+                        </div>
+                    </div>
+
+                    <div class="section qr-options">
+                        <label class="qr-field">
+                            <span>{format!("{} ({} px)", i18n.t("qr_generator.size"), options.size)}</span>
+                            <input type="range" min="64" max="1024" step="32" value={options.size.to_string()} onchange={on_size_change} />
+                        </label>
+                        <label class="qr-field">
+                            <span>{format!("{} ({})", i18n.t("qr_generator.margin"), options.margin)}</span>
+                            <input type="range" min="0" max="10" value={options.margin.to_string()} onchange={on_margin_change} />
+                        </label>
+                        <label class="qr-field">
+                            <span>{i18n.t("qr_generator.error_correction")}</span>
+                            <select class="form-select" onchange={on_level_change}>
+                                { for [("L", "7%"), ("M", "15%"), ("Q", "25%"), ("H", "30%")].iter().map(|(level, recovery)| html! {
+                                    <option value={*level} selected={format!("{:?}", options.error_correction) == *level}>
+                                        {format!("{} ({})", level, recovery)}
+                                    </option>
+                                }) }
+                            </select>
+                        </label>
+                        <div class="qr-colors">
+                            <label class="qr-field">
+                                <span>{i18n.t("qr_generator.foreground")}</span>
+                                <input type="color" value={options.foreground.clone()} oninput={on_foreground_change} />
+                            </label>
+                            <label class="qr-field">
+                                <span>{i18n.t("qr_generator.background")}</span>
+                                <input type="color" value={options.background.clone()} oninput={on_background_change} />
+                            </label>
+                        </div>
+                    </div>
+                </div>
+
+                <div class="section qr-preview">
+                    if let Some(res) = (*result).clone() {
+                        if res.success {
+                            <img class="qr-image" src={res.data_uri.clone()} alt="QR code" />
+                            <div class="qr-meta">
+                                {format!(
+                                    "{} {} · {}×{} · {} {}",
+                                    i18n.t("qr_generator.version"),
+                                    res.version,
+                                    res.modules,
+                                    res.modules,
+                                    res.byte_length,
+                                    i18n.t("qr_generator.bytes")
+                                )}
+                            </div>
+                            <button class="primary-btn" onclick={on_save}>
+                                {i18n.t("qr_generator.save")}
+                            </button>
+                            if let Some(message) = (*save_message).clone() {
+                                {match message {
+                                    Ok(path) => html! {
+                                        <div class="qr-saved">{format!("{}: {}", i18n.t("qr_generator.saved"), path)}</div>
+                                    },
+                                    Err(e) => html! {
+                                        <div class="error-message">{"⚠ "}{e}</div>
+                                    },
+                                }}
+                            }
+                        } else {
+                            <div class="error-message">
+                                {"⚠ "}{res.error.clone().unwrap_or_default()}
+                            </div>
+                        }
+                    } else {
+                        <div class="qr-placeholder">{i18n.t("qr_generator.empty")}</div>
+                    }
+                    if *mode != ContentMode::Text && !content.is_empty() {
+                        <pre class="qr-content-preview">{(*content).clone()}</pre>
+                    }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,321 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    fn convertFileSrc(path: &str) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct DecodeArgs {
+    path: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+enum QrDecodeErrorKind {
+    ImageUnreadable,
+    NotFound,
+    DecodeFailed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+enum WifiSecurity {
+    Wpa,
+    Wep,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WifiConfig {
+    ssid: String,
+    password: String,
+    security: WifiSecurity,
+    hidden: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BoundingBox {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DecodedQr {
+    content: String,
+    bounds: BoundingBox,
+    version: usize,
+    is_url: bool,
+    wifi: Option<WifiConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QrDecodeResult {
+    success: bool,
+    codes: Vec<DecodedQr>,
+    image_width: u32,
+    image_height: u32,
+    error: Option<String>,
+    error_kind: Option<QrDecodeErrorKind>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    #[prop_or_default]
+    pub dropped_file: Option<String>,
+    #[prop_or_default]
+    pub on_file_processed: Callback<()>,
+}
+
+/// Decode `path` and show it in the preview
+fn decode_path(
+    path: String,
+    preview_url: UseStateHandle<Option<String>>,
+    result: UseStateHandle<Option<QrDecodeResult>>,
+    is_processing: UseStateHandle<bool>,
+) {
+    preview_url.set(convertFileSrc(&path).as_string());
+    result.set(None);
+    is_processing.set(true);
+    spawn_local(async move {
+        let args = serde_wasm_bindgen::to_value(&DecodeArgs { path }).unwrap();
+        let response = invoke("decode_qr_from_image_cmd", args).await;
+        if let Ok(res) = serde_wasm_bindgen::from_value::<QrDecodeResult>(response) {
+            result.set(Some(res));
+        }
+        is_processing.set(false);
+    });
+}
+
+#[function_component(QrReader)]
+pub fn qr_reader(props: &Props) -> Html {
+    let (i18n, _) = use_translation();
+    let preview_url = use_state(|| Option::<String>::None);
+    let result = use_state(|| Option::<QrDecodeResult>::None);
+    let is_processing = use_state(|| false);
+    let copied_index = use_state(|| Option::<usize>::None);
+
+    // Handle dropped file
+    {
+        let on_file_processed = props.on_file_processed.clone();
+        let preview_url = preview_url.clone();
+        let result = result.clone();
+        let is_processing = is_processing.clone();
+        use_effect_with(props.dropped_file.clone(), move |dropped_file| {
+            if let Some(path) = dropped_file.clone() {
+                decode_path(path, preview_url, result, is_processing);
+                on_file_processed.emit(());
+            }
+            || {}
+        });
+    }
+
+    let on_select_file = {
+        let preview_url = preview_url.clone();
+        let result = result.clone();
+        let is_processing = is_processing.clone();
+        Callback::from(move |_| {
+            let preview_url = preview_url.clone();
+            let result = result.clone();
+            let is_processing = is_processing.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    filters: vec![FileFilter {
+                        name: "Images".to_string(),
+                        extensions: ["png", "jpg", "jpeg", "gif", "webp", "bmp"]
+                            .iter()
+                            .map(|ext| ext.to_string())
+                            .collect(),
+                    }],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(path) = open(opts).await.as_string() {
+                    decode_path(path, preview_url, result, is_processing);
+                }
+            });
+        })
+    };
+
+    let copy_content = {
+        let copied_index = copied_index.clone();
+        move |index: usize, content: String| {
+            let copied_index = copied_index.clone();
+            Callback::from(move |_| {
+                let copied_index = copied_index.clone();
+                let content = content.clone();
+                if let Some(win) = window() {
+                    let clipboard = win.navigator().clipboard();
+                    spawn_local(async move {
+                        let _ =
+                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&content))
+                                .await;
+                        copied_index.set(Some(index));
+
+                        let copied_index_reset = copied_index.clone();
+                        gloo_timers::callback::Timeout::new(2000, move || {
+                            copied_index_reset.set(None);
+                        })
+                        .forget();
+                    });
+                }
+            })
+        }
+    };
+
+    let res = (*result).clone();
+    let codes = res
+        .as_ref()
+        .filter(|res| res.success)
+        .map(|res| res.codes.clone())
+        .unwrap_or_default();
+
+    // Boxes are positioned in percent so they follow the scaled preview
+    let overlay_style = |bounds: &BoundingBox| {
+        let Some(res) = res.as_ref().filter(|res| res.image_width > 0) else {
+            return String::new();
+        };
+        let pct = |v: u32, total: u32| v as f64 * 100.0 / total as f64;
+        format!(
+            "left: {:.2}%; top: {:.2}%; width: {:.2}%; height: {:.2}%;",
+            pct(bounds.x, res.image_width),
+            pct(bounds.y, res.image_height),
+            pct(bounds.width, res.image_width),
+            pct(bounds.height, res.image_height),
+        )
+    };
+
+    let security_label = |security: WifiSecurity| match security {
+        WifiSecurity::Wpa => "WPA/WPA2".to_string(),
+        WifiSecurity::Wep => "WEP".to_string(),
+        WifiSecurity::None => i18n.t("qr_reader.open_network"),
+    };
+
+    html! {
+        <div class="qr-reader">
+            <div class="section">
+                <div class="drop-zone qr-reader-drop" onclick={on_select_file}>
+                    if let Some(url) = (*preview_url).clone() {
+                        <div class="qr-reader-preview">
+                            <img src={url} alt="QR" />
+                            { for codes.iter().enumerate().map(|(i, code)| html! {
+                                <div class="qr-reader-box" style={overlay_style(&code.bounds)}>
+                                    <span class="qr-reader-box-label">{i + 1}</span>
+                                </div>
+                            }) }
+                        </div>
+                        <p class="drop-zone-hint">{i18n.t("qr_reader.click_to_change")}</p>
+                    } else {
+                        <p class="drop-zone-text">{i18n.t("qr_reader.drop_image")}</p>
+                        <p class="drop-zone-hint">{i18n.t("qr_reader.supported_formats")}</p>
+                    }
+                </div>
+            </div>
+
+            if *is_processing {
+                <div class="section qr-reader-status">
+                    <span class="spinner"></span>
+                    {i18n.t("common.processing")}
+                </div>
+            } else if let Some(res) = res.clone() {
+                if res.success {
+                    <div class="section">
+                        <div class="section-header">
+                            <h3>{format!("{} ({})", i18n.t("qr_reader.results"), res.codes.len())}</h3>
+                        </div>
+                        <div class="qr-reader-results">
+                            { for res.codes.iter().enumerate().map(|(i, code)| html! {
+                                <div class="qr-reader-card">
+                                    <div class="qr-reader-card-header">
+                                        <span class="qr-reader-index">{i + 1}</span>
+                                        if code.is_url {
+                                            <span class="qr-reader-tag">{"URL"}</span>
+                                        }
+                                        if code.wifi.is_some() {
+                                            <span class="qr-reader-tag">{"Wi-Fi"}</span>
+                                        }
+                                        <span class="qr-reader-meta">
+                                            {format!(
+                                                "v{} · {}, {} · {}×{}",
+                                                code.version,
+                                                code.bounds.x,
+                                                code.bounds.y,
+                                                code.bounds.width,
+                                                code.bounds.height
+                                            )}
+                                        </span>
+                                        <button
+                                            class={classes!("move-btn", (*copied_index == Some(i)).then_some("copied"))}
+                                            onclick={copy_content(i, code.content.clone())}
+                                        >
+                                            if *copied_index == Some(i) {
+                                                {format!("✓ {}", i18n.t("common.copied"))}
+                                            } else {
+                                                {i18n.t("common.copy")}
+                                            }
+                                        </button>
+                                    </div>
+                                    <pre class="qr-reader-content">{&code.content}</pre>
+                                    if let Some(wifi) = &code.wifi {
+                                        <dl class="qr-reader-wifi">
+                                            <dt>{"SSID"}</dt>
+                                            <dd>{&wifi.ssid}</dd>
+                                            <dt>{i18n.t("qr_reader.password")}</dt>
+                                            <dd>{&wifi.password}</dd>
+                                            <dt>{i18n.t("qr_reader.security")}</dt>
+                                            <dd>{security_label(wifi.security)}</dd>
+                                            if wifi.hidden {
+                                                <dt>{i18n.t("qr_reader.hidden")}</dt>
+                                                <dd>{"✓"}</dd>
+                                            }
+                                        </dl>
+                                    }
+                                </div>
+                            }) }
+                        </div>
+                    </div>
+                } else {
+                    <div class="section error-section">
+                        <div class="error-message">
+                            {"⚠ "}
+                            {match res.error_kind {
+                                Some(QrDecodeErrorKind::NotFound) => i18n.t("qr_reader.not_found"),
+                                Some(QrDecodeErrorKind::DecodeFailed) => i18n.t("qr_reader.decode_failed"),
+                                _ => i18n.t("qr_reader.unreadable"),
+                            }}
+                        </div>
+                        <div class="qr-reader-detail">{res.error.clone().unwrap_or_default()}</div>
+                    </div>
+                }
+            }
+        </div>
+    }
+}
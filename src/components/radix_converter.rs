@@ -0,0 +1,425 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum FloatPrecision {
+    F32,
+    F64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+enum FloatClass {
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    NaN,
+}
+
+impl FloatClass {
+    fn translation_key(&self) -> &'static str {
+        match self {
+            FloatClass::Zero => "radix_converter.class_zero",
+            FloatClass::Subnormal => "radix_converter.class_subnormal",
+            FloatClass::Normal => "radix_converter.class_normal",
+            FloatClass::Infinite => "radix_converter.class_infinite",
+            FloatClass::NaN => "radix_converter.class_nan",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FloatDirection {
+    ToBits,
+    FromBits,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertRadixArgs {
+    input: String,
+    from_base: u32,
+    to_base: u32,
+    fraction_digits: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct FloatArgs {
+    input: String,
+    precision: FloatPrecision,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RadixConvertResult {
+    success: bool,
+    detected_base: u32,
+    output: String,
+    binary: String,
+    octal: String,
+    decimal: String,
+    hexadecimal: String,
+    bit_pattern: String,
+    bit_width: u32,
+    byte_count: u32,
+    fraction_truncated: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FloatBitsResult {
+    success: bool,
+    value: String,
+    hex: String,
+    sign: String,
+    exponent: String,
+    mantissa: String,
+    exponent_value: i32,
+    class: FloatClass,
+    error: Option<String>,
+}
+
+/// Bases offered in the selects; any of 2-36 is accepted by the backend
+const BASES: std::ops::RangeInclusive<u32> = 2..=36;
+
+#[function_component(RadixConverter)]
+pub fn radix_converter() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(|| "0xFF".to_string());
+    let from_base = use_state(|| 0u32);
+    let to_base = use_state(|| 36u32);
+    let fraction_digits = use_state(|| 16usize);
+    let result = use_state(|| Option::<RadixConvertResult>::None);
+    let float_input = use_state(|| "0.1".to_string());
+    let precision = use_state(|| FloatPrecision::F64);
+    let direction = use_state(|| FloatDirection::ToBits);
+    let float_result = use_state(|| Option::<FloatBitsResult>::None);
+    let copied = use_state(|| Option::<String>::None);
+
+    // Convert on every edit
+    {
+        let result = result.clone();
+        use_effect_with(
+            ((*input).clone(), *from_base, *to_base, *fraction_digits),
+            move |(input, from_base, to_base, fraction_digits)| {
+                let input = input.clone();
+                let (from_base, to_base) = (*from_base, *to_base);
+                let fraction_digits = Some(*fraction_digits);
+                if input.trim().is_empty() {
+                    result.set(None);
+                } else {
+                    spawn_local(async move {
+                        let args = serde_wasm_bindgen::to_value(&ConvertRadixArgs {
+                            input,
+                            from_base,
+                            to_base,
+                            fraction_digits,
+                        })
+                        .unwrap();
+                        let response = invoke("convert_radix_cmd", args).await;
+                        if let Ok(res) =
+                            serde_wasm_bindgen::from_value::<RadixConvertResult>(response)
+                        {
+                            result.set(Some(res));
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    {
+        let float_result = float_result.clone();
+        use_effect_with(
+            ((*float_input).clone(), *precision, *direction),
+            move |(input, precision, direction)| {
+                let input = input.clone();
+                let precision = *precision;
+                let command = match direction {
+                    FloatDirection::ToBits => "float_to_bits_cmd",
+                    FloatDirection::FromBits => "bits_to_float_cmd",
+                };
+                if input.trim().is_empty() {
+                    float_result.set(None);
+                } else {
+                    spawn_local(async move {
+                        let args =
+                            serde_wasm_bindgen::to_value(&FloatArgs { input, precision }).unwrap();
+                        let response = invoke(command, args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<FloatBitsResult>(response)
+                        {
+                            float_result.set(Some(res));
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_from_base_change = {
+        let from_base = from_base.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            from_base.set(select.value().parse().unwrap_or(0));
+        })
+    };
+
+    let on_to_base_change = {
+        let to_base = to_base.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            to_base.set(select.value().parse().unwrap_or(10));
+        })
+    };
+
+    let on_fraction_digits_change = {
+        let fraction_digits = fraction_digits.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(digits) = target.value().parse::<usize>() {
+                fraction_digits.set(digits.clamp(1, 64));
+            }
+        })
+    };
+
+    let on_float_input_change = {
+        let float_input = float_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            float_input.set(target.value());
+        })
+    };
+
+    let set_precision = |value: FloatPrecision| {
+        let precision = precision.clone();
+        Callback::from(move |_: MouseEvent| precision.set(value))
+    };
+
+    let set_direction = |value: FloatDirection| {
+        let direction = direction.clone();
+        let float_input = float_input.clone();
+        let float_result = float_result.clone();
+        Callback::from(move |_: MouseEvent| {
+            // Carry the current result over so the other direction starts from it
+            if let Some(res) = float_result.as_ref().filter(|r| r.success) {
+                float_input.set(match value {
+                    FloatDirection::ToBits => res.value.clone(),
+                    FloatDirection::FromBits => format!("0x{}", res.hex),
+                });
+            }
+            direction.set(value);
+        })
+    };
+
+    let on_copy = {
+        let copied = copied.clone();
+        Callback::from(move |value: String| {
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ =
+                        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value)).await;
+                    copied.set(Some(value));
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(None);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let result_row = |label: String, value: String| {
+        let is_copied = copied.as_deref() == Some(value.as_str());
+        let onclick = {
+            let on_copy = on_copy.clone();
+            let value = value.clone();
+            Callback::from(move |_| on_copy.emit(value.clone()))
+        };
+        html! {
+            <div class="radix-result-row">
+                <span class="radix-result-label">{label}</span>
+                <code class="radix-result-value">{value}</code>
+                <button class={classes!("copy-btn", is_copied.then_some("copied"))} {onclick}>
+                    if is_copied {
+                        {"✓"}
+                    } else {
+                        {i18n.t("common.copy")}
+                    }
+                </button>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="radix-converter">
+            <div class="section">
+                <h3>{i18n.t("radix_converter.title")}</h3>
+                <div class="radix-form">
+                    <input
+                        type="text"
+                        class="form-input radix-input"
+                        value={(*input).clone()}
+                        oninput={on_input_change}
+                        placeholder={i18n.t("radix_converter.input_placeholder")}
+                        spellcheck="false"
+                    />
+                    <label class="radix-field">
+                        {i18n.t("radix_converter.from_base")}
+                        <select class="form-select" onchange={on_from_base_change}>
+                            <option value="0" selected={*from_base == 0}>
+                                {i18n.t("radix_converter.auto")}
+                            </option>
+                            { for BASES.map(|base| html! {
+                                <option value={base.to_string()} selected={*from_base == base}>
+                                    {base}
+                                </option>
+                            }) }
+                        </select>
+                    </label>
+                    <label class="radix-field">
+                        {i18n.t("radix_converter.to_base")}
+                        <select class="form-select" onchange={on_to_base_change}>
+                            { for BASES.map(|base| html! {
+                                <option value={base.to_string()} selected={*to_base == base}>
+                                    {base}
+                                </option>
+                            }) }
+                        </select>
+                    </label>
+                    <label class="radix-field">
+                        {i18n.t("radix_converter.fraction_digits")}
+                        <input
+                            type="number"
+                            class="form-input radix-digits"
+                            min="1"
+                            max="64"
+                            value={fraction_digits.to_string()}
+                            oninput={on_fraction_digits_change}
+                        />
+                    </label>
+                </div>
+                <p class="radix-hint">{i18n.t("radix_converter.hint")}</p>
+
+                if let Some(res) = (*result).clone() {
+                    if res.success {
+                        <div class="radix-results">
+                            {result_row(format!("{} ({})", i18n.t("radix_converter.output"), *to_base), res.output.clone())}
+                            {result_row(i18n.t("radix_converter.binary"), res.binary.clone())}
+                            {result_row(i18n.t("radix_converter.octal"), res.octal.clone())}
+                            {result_row(i18n.t("radix_converter.decimal"), res.decimal.clone())}
+                            {result_row(i18n.t("radix_converter.hexadecimal"), res.hexadecimal.clone())}
+                            {result_row(
+                                i18n.t("radix_converter.bits")
+                                    .replace("{bits}", &res.bit_width.to_string())
+                                    .replace("{bytes}", &res.byte_count.to_string()),
+                                res.bit_pattern.clone(),
+                            )}
+                        </div>
+                        <div class="radix-meta">
+                            {i18n.t("radix_converter.detected_base").replace("{base}", &res.detected_base.to_string())}
+                            if res.fraction_truncated {
+                                {" · "}{i18n.t("radix_converter.fraction_truncated")}
+                            }
+                        </div>
+                    } else if let Some(error) = res.error {
+                        <div class="radix-error">{error}</div>
+                    }
+                }
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("radix_converter.float_title")}</h3>
+                <div class="radix-float-options">
+                    <div class="mode-toggle">
+                        <button
+                            class={classes!("mode-btn", (*direction == FloatDirection::ToBits).then_some("active"))}
+                            onclick={set_direction(FloatDirection::ToBits)}
+                        >
+                            {i18n.t("radix_converter.to_bits")}
+                        </button>
+                        <button
+                            class={classes!("mode-btn", (*direction == FloatDirection::FromBits).then_some("active"))}
+                            onclick={set_direction(FloatDirection::FromBits)}
+                        >
+                            {i18n.t("radix_converter.from_bits")}
+                        </button>
+                    </div>
+                    <div class="mode-toggle">
+                        <button
+                            class={classes!("mode-btn", (*precision == FloatPrecision::F32).then_some("active"))}
+                            onclick={set_precision(FloatPrecision::F32)}
+                        >
+                            {"f32"}
+                        </button>
+                        <button
+                            class={classes!("mode-btn", (*precision == FloatPrecision::F64).then_some("active"))}
+                            onclick={set_precision(FloatPrecision::F64)}
+                        >
+                            {"f64"}
+                        </button>
+                    </div>
+                </div>
+                <input
+                    type="text"
+                    class="form-input radix-input"
+                    value={(*float_input).clone()}
+                    oninput={on_float_input_change}
+                    placeholder={match *direction {
+                        FloatDirection::ToBits => i18n.t("radix_converter.float_placeholder"),
+                        FloatDirection::FromBits => i18n.t("radix_converter.bits_placeholder"),
+                    }}
+                    spellcheck="false"
+                />
+
+                if let Some(res) = (*float_result).clone() {
+                    if res.success {
+                        <div class="radix-float-bits">
+                            <span class="radix-bit-sign" title={i18n.t("radix_converter.sign")}>{&res.sign}</span>
+                            <span class="radix-bit-exponent" title={i18n.t("radix_converter.exponent")}>{&res.exponent}</span>
+                            <span class="radix-bit-mantissa" title={i18n.t("radix_converter.mantissa")}>{&res.mantissa}</span>
+                        </div>
+                        <div class="radix-float-legend">
+                            <span class="radix-bit-sign">{i18n.t("radix_converter.sign")}</span>
+                            <span class="radix-bit-exponent">
+                                {format!("{} (2^{})", i18n.t("radix_converter.exponent"), res.exponent_value)}
+                            </span>
+                            <span class="radix-bit-mantissa">{i18n.t("radix_converter.mantissa")}</span>
+                            <span>{i18n.t(res.class.translation_key())}</span>
+                        </div>
+                        <div class="radix-results">
+                            {result_row(i18n.t("radix_converter.value"), res.value.clone())}
+                            {result_row(i18n.t("radix_converter.hexadecimal"), format!("0x{}", res.hex))}
+                        </div>
+                    } else if let Some(error) = res.error {
+                        <div class="radix-error">{error}</div>
+                    }
+                }
+            </div>
+        </div>
+    }
+}
@@ -40,6 +40,10 @@ pub struct MatchInfo {
     pub full_match: String,
     pub start: usize,
     pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub utf16_start: usize,
+    pub utf16_end: usize,
     pub groups: Vec<GroupInfo>,
 }
 
@@ -51,6 +55,10 @@ pub struct GroupInfo {
     pub value: String,
     pub start: usize,
     pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub utf16_start: usize,
+    pub utf16_end: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,18 +67,40 @@ pub struct RegexResult {
     pub success: bool,
     pub matches: Vec<MatchInfo>,
     pub match_count: usize,
+    #[serde(default)]
+    pub truncated: bool,
     pub error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplacementPair {
+    pub start: usize,
+    pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub original: String,
+    pub replaced: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReplaceResult {
     pub success: bool,
     pub result: String,
     pub replacements: usize,
+    #[serde(default)]
+    pub pairs: Vec<ReplacementPair>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    #[serde(default)]
+    pub truncated: bool,
     pub error: Option<String>,
 }
 
+/// Replacement pairs listed under the replace result; the output itself is never cut
+const MAX_PAIRS_SHOWN: usize = 200;
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct TestRegexArgs {
@@ -88,115 +118,79 @@ struct ReplaceRegexArgs {
     flags: RegexFlags,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateSamplesArgs {
+    pattern: String,
+    count: u32,
+    max_repeat: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RegexSampleResult {
+    success: bool,
+    samples: Vec<String>,
+    warnings: Vec<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum PresetCategory {
     Common,
     Validation,
     Web,
     DateTime,
+    Japanese,
+    Custom,
 }
 
 impl PresetCategory {
+    const ALL: [PresetCategory; 6] = [
+        PresetCategory::Common,
+        PresetCategory::Validation,
+        PresetCategory::Web,
+        PresetCategory::DateTime,
+        PresetCategory::Japanese,
+        PresetCategory::Custom,
+    ];
+
     fn translation_key(&self) -> &'static str {
         match self {
             PresetCategory::Common => "regex_tester.category_common",
             PresetCategory::Validation => "regex_tester.category_validation",
             PresetCategory::Web => "regex_tester.category_web",
             PresetCategory::DateTime => "regex_tester.category_datetime",
+            PresetCategory::Japanese => "regex_tester.category_japanese",
+            PresetCategory::Custom => "regex_tester.category_custom",
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct RegexPreset {
-    name: &'static str,
-    pattern: &'static str,
-    description: &'static str,
+    id: String,
+    name: String,
+    pattern: String,
+    flags: RegexFlags,
+    description: Option<String>,
     category: PresetCategory,
+    builtin: bool,
+}
+
+#[derive(Serialize)]
+struct SaveRegexPresetArgs {
+    name: String,
+    pattern: String,
+    flags: RegexFlags,
+    description: Option<String>,
 }
 
-fn get_presets() -> Vec<RegexPreset> {
-    vec![
-        // Common
-        RegexPreset {
-            name: "Email",
-            pattern: r"[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}",
-            description: "Match email addresses",
-            category: PresetCategory::Validation,
-        },
-        RegexPreset {
-            name: "Phone (JP)",
-            pattern: r"0\d{1,4}-?\d{1,4}-?\d{4}",
-            description: "Match Japanese phone numbers",
-            category: PresetCategory::Validation,
-        },
-        RegexPreset {
-            name: "URL",
-            pattern: r"https?://[\w\-._~:/?#\[\]@!$&'()*+,;=%]+",
-            description: "Match HTTP/HTTPS URLs",
-            category: PresetCategory::Web,
-        },
-        RegexPreset {
-            name: "IPv4",
-            pattern: r"\b(?:(?:25[0-5]|2[0-4]\d|[01]?\d\d?)\.){3}(?:25[0-5]|2[0-4]\d|[01]?\d\d?)\b",
-            description: "Match IPv4 addresses",
-            category: PresetCategory::Web,
-        },
-        RegexPreset {
-            name: "Date (YYYY-MM-DD)",
-            pattern: r"\d{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])",
-            description: "Match ISO date format",
-            category: PresetCategory::DateTime,
-        },
-        RegexPreset {
-            name: "Time (HH:MM:SS)",
-            pattern: r"(?:[01]\d|2[0-3]):[0-5]\d(?::[0-5]\d)?",
-            description: "Match 24-hour time format",
-            category: PresetCategory::DateTime,
-        },
-        RegexPreset {
-            name: "Postal Code (JP)",
-            pattern: r"\d{3}-?\d{4}",
-            description: "Match Japanese postal codes",
-            category: PresetCategory::Validation,
-        },
-        RegexPreset {
-            name: "Credit Card",
-            pattern: r"\b(?:\d{4}[- ]?){3}\d{4}\b",
-            description: "Match credit card numbers",
-            category: PresetCategory::Validation,
-        },
-        RegexPreset {
-            name: "Hex Color",
-            pattern: r"#(?:[0-9a-fA-F]{3}){1,2}\b",
-            description: "Match hex color codes",
-            category: PresetCategory::Common,
-        },
-        RegexPreset {
-            name: "HTML Tag",
-            pattern: r"<([a-zA-Z][a-zA-Z0-9]*)\b[^>]*>.*?</\1>|<[a-zA-Z][a-zA-Z0-9]*\b[^/>]*/>",
-            description: "Match HTML tags",
-            category: PresetCategory::Web,
-        },
-        RegexPreset {
-            name: "Whitespace",
-            pattern: r"\s+",
-            description: "Match whitespace characters",
-            category: PresetCategory::Common,
-        },
-        RegexPreset {
-            name: "Numbers",
-            pattern: r"-?\d+\.?\d*",
-            description: "Match integers and decimals",
-            category: PresetCategory::Common,
-        },
-        RegexPreset {
-            name: "Word Boundary",
-            pattern: r"\b\w+\b",
-            description: "Match whole words",
-            category: PresetCategory::Common,
-        },
-    ]
+#[derive(Serialize)]
+struct DeleteRegexPresetArgs {
+    id: String,
 }
 
 #[derive(Properties, PartialEq)]
@@ -217,9 +211,30 @@ pub fn regex_tester(_props: &Props) -> Html {
     let selected_category = use_state(|| Option::<PresetCategory>::None);
     let show_presets = use_state(|| false);
     let show_replace = use_state(|| false);
+    let show_samples = use_state(|| false);
+    let sample_count = use_state(|| 5u32);
+    let sample_max_repeat = use_state(|| 5u32);
+    let sample_result = use_state(|| Option::<RegexSampleResult>::None);
     let history_refresh = use_state(|| 0u32);
+    let presets = use_state(Vec::<RegexPreset>::new);
+    let presets_refresh = use_state(|| 0u32);
+    let preset_name = use_state(String::new);
+    let preset_description = use_state(String::new);
+    let preset_error = use_state(|| Option::<String>::None);
 
-    let presets = get_presets();
+    // Built-in presets plus the ones saved by the user, reloaded after each save or delete
+    {
+        let presets = presets.clone();
+        use_effect_with(*presets_refresh, move |_| {
+            spawn_local(async move {
+                let res = invoke("list_regex_presets_cmd", JsValue::NULL).await;
+                if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<RegexPreset>>(res) {
+                    presets.set(list);
+                }
+            });
+            || {}
+        });
+    }
 
     // Auto-test on pattern or test_text change with debounce
     {
@@ -349,13 +364,79 @@ pub fn regex_tester(_props: &Props) -> Html {
 
     let on_preset_select = {
         let pattern = pattern.clone();
+        let flags = flags.clone();
         let show_presets = show_presets.clone();
-        Callback::from(move |preset_pattern: String| {
-            pattern.set(preset_pattern);
+        Callback::from(move |preset: RegexPreset| {
+            pattern.set(preset.pattern);
+            flags.set(preset.flags);
             show_presets.set(false);
         })
     };
 
+    let on_preset_name_change = {
+        let preset_name = preset_name.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            preset_name.set(input.value());
+        })
+    };
+
+    let on_preset_description_change = {
+        let preset_description = preset_description.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            preset_description.set(input.value());
+        })
+    };
+
+    let on_save_preset = {
+        let pattern = pattern.clone();
+        let flags = flags.clone();
+        let preset_name = preset_name.clone();
+        let preset_description = preset_description.clone();
+        let preset_error = preset_error.clone();
+        let presets_refresh = presets_refresh.clone();
+        Callback::from(move |_| {
+            let args = SaveRegexPresetArgs {
+                name: (*preset_name).clone(),
+                pattern: (*pattern).clone(),
+                flags: *flags,
+                description: Some((*preset_description).clone()),
+            };
+            let preset_name = preset_name.clone();
+            let preset_description = preset_description.clone();
+            let preset_error = preset_error.clone();
+            let presets_refresh = presets_refresh.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let res = invoke("save_regex_preset_cmd", args).await;
+                if serde_wasm_bindgen::from_value::<RegexPreset>(res.clone()).is_ok() {
+                    preset_name.set(String::new());
+                    preset_description.set(String::new());
+                    preset_error.set(None);
+                    presets_refresh.set(*presets_refresh + 1);
+                } else {
+                    preset_error.set(res.as_string());
+                }
+            });
+        })
+    };
+
+    let on_delete_preset = {
+        let preset_error = preset_error.clone();
+        let presets_refresh = presets_refresh.clone();
+        Callback::from(move |id: String| {
+            let preset_error = preset_error.clone();
+            let presets_refresh = presets_refresh.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&DeleteRegexPresetArgs { id }).unwrap();
+                let res = invoke("delete_regex_preset_cmd", args).await;
+                preset_error.set(res.as_string());
+                presets_refresh.set(*presets_refresh + 1);
+            });
+        })
+    };
+
     let on_category_select = {
         let selected_category = selected_category.clone();
         Callback::from(move |cat: Option<PresetCategory>| {
@@ -377,6 +458,73 @@ pub fn regex_tester(_props: &Props) -> Html {
         })
     };
 
+    let toggle_samples = {
+        let show_samples = show_samples.clone();
+        Callback::from(move |_| {
+            show_samples.set(!*show_samples);
+        })
+    };
+
+    let on_sample_count_change = {
+        let sample_count = sample_count.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<u32>() {
+                sample_count.set(value.clamp(1, 100));
+            }
+        })
+    };
+
+    let on_sample_max_repeat_change = {
+        let sample_max_repeat = sample_max_repeat.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<u32>() {
+                sample_max_repeat.set(value.min(100));
+            }
+        })
+    };
+
+    let on_generate_samples = {
+        let pattern = pattern.clone();
+        let sample_count = sample_count.clone();
+        let sample_max_repeat = sample_max_repeat.clone();
+        let sample_result = sample_result.clone();
+        Callback::from(move |_| {
+            let pattern_val = (*pattern).clone();
+            if pattern_val.is_empty() {
+                return;
+            }
+            let args = GenerateSamplesArgs {
+                pattern: pattern_val,
+                count: *sample_count,
+                max_repeat: *sample_max_repeat,
+            };
+            let sample_result = sample_result.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let res = invoke("generate_regex_samples_cmd", args).await;
+                sample_result.set(serde_wasm_bindgen::from_value::<RegexSampleResult>(res).ok());
+            });
+        })
+    };
+
+    // Appends the samples to the test string, one per line
+    let on_use_samples = {
+        let test_text = test_text.clone();
+        let sample_result = sample_result.clone();
+        Callback::from(move |_| {
+            if let Some(result) = &*sample_result {
+                let mut text = (*test_text).clone();
+                if !text.is_empty() && !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                text.push_str(&result.samples.join("\n"));
+                test_text.set(text);
+            }
+        })
+    };
+
     let on_replace = {
         let pattern = pattern.clone();
         let test_text = test_text.clone();
@@ -528,8 +676,8 @@ pub fn regex_tester(_props: &Props) -> Html {
                                     "Match #{}: {:?} ({}..{}){}",
                                     i + 1,
                                     m.full_match,
-                                    m.start,
-                                    m.end,
+                                    m.char_start,
+                                    m.char_end,
                                     groups
                                 )
                             })
@@ -554,31 +702,38 @@ pub fn regex_tester(_props: &Props) -> Html {
             return html! { <span class="no-match">{text}</span> };
         }
 
+        // Offsets are in UTF-16 code units, as in the textarea, and are clamped in case
+        // the result is from an older version of the text
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let slice = |start: usize, end: usize| {
+            let end = end.min(units.len());
+            String::from_utf16_lossy(&units[start.min(end)..end])
+        };
         let mut parts: Vec<Html> = Vec::new();
         let mut last_end = 0;
 
         for (i, m) in matches.iter().enumerate() {
             // Add non-matched text before this match
-            if m.start > last_end {
+            if m.utf16_start > last_end {
                 parts.push(html! {
-                    <span class="no-match">{&text[last_end..m.start]}</span>
+                    <span class="no-match">{slice(last_end, m.utf16_start)}</span>
                 });
             }
 
             // Add matched text with highlight
             parts.push(html! {
                 <span class={format!("match-highlight match-{}", i % 4)} title={format!("Match #{}", i + 1)}>
-                    {&text[m.start..m.end]}
+                    {slice(m.utf16_start, m.utf16_end)}
                 </span>
             });
 
-            last_end = m.end;
+            last_end = m.utf16_end;
         }
 
         // Add remaining text
-        if last_end < text.len() {
+        if last_end < units.len() {
             parts.push(html! {
-                <span class="no-match">{&text[last_end..]}</span>
+                <span class="no-match">{slice(last_end, units.len())}</span>
             });
         }
 
@@ -602,8 +757,8 @@ pub fn regex_tester(_props: &Props) -> Html {
         })
     };
 
-    let filtered_presets: Vec<&RegexPreset> = if let Some(ref cat) = *selected_category {
-        presets.iter().filter(|p| &p.category == cat).collect()
+    let filtered_presets: Vec<&RegexPreset> = if let Some(cat) = *selected_category {
+        presets.iter().filter(|p| p.category == cat).collect()
     } else {
         presets.iter().collect()
     };
@@ -677,6 +832,13 @@ pub fn regex_tester(_props: &Props) -> Html {
                             {format!("{} ▼", i18n.t("regex_tester.show_replace"))}
                         }
                     </button>
+                    <button class="samples-toggle-btn" onclick={toggle_samples}>
+                        if *show_samples {
+                            {format!("{} ▲", i18n.t("regex_tester.hide_samples"))}
+                        } else {
+                            {format!("{} ▼", i18n.t("regex_tester.show_samples"))}
+                        }
+                    </button>
                 </div>
             </div>
 
@@ -694,17 +856,17 @@ pub fn regex_tester(_props: &Props) -> Html {
                             {i18n.t("regex_tester.category_all")}
                         </button>
                         {
-                            [PresetCategory::Common, PresetCategory::Validation, PresetCategory::Web, PresetCategory::DateTime]
+                            PresetCategory::ALL
                                 .iter()
                                 .map(|cat| {
                                     let is_active = selected_category.as_ref() == Some(cat);
-                                    let cat_clone = cat.clone();
+                                    let cat = *cat;
                                     let on_click = on_category_select.clone();
                                     let label = i18n.t(cat.translation_key());
                                     html! {
                                         <button
                                             class={classes!("category-btn", is_active.then_some("active"))}
-                                            onclick={Callback::from(move |_| on_click.emit(Some(cat_clone.clone())))}
+                                            onclick={Callback::from(move |_| on_click.emit(Some(cat)))}
                                         >
                                             {label}
                                         </button>
@@ -716,21 +878,60 @@ pub fn regex_tester(_props: &Props) -> Html {
                     <div class="presets-grid">
                         {
                             filtered_presets.iter().map(|preset| {
-                                let pattern = preset.pattern.to_string();
+                                let selected = (*preset).clone();
                                 let on_select = on_preset_select.clone();
+                                let id = preset.id.clone();
+                                let on_delete = on_delete_preset.clone();
                                 html! {
-                                    <button
-                                        class="preset-btn"
-                                        onclick={Callback::from(move |_| on_select.emit(pattern.clone()))}
-                                        title={preset.description}
-                                    >
-                                        <span class="preset-name">{preset.name}</span>
-                                        <span class="preset-pattern">{preset.pattern}</span>
-                                    </button>
+                                    <div class="preset-item">
+                                        <button
+                                            class="preset-btn"
+                                            onclick={Callback::from(move |_| on_select.emit(selected.clone()))}
+                                            title={preset.description.clone().unwrap_or_default()}
+                                        >
+                                            <span class="preset-name">{&preset.name}</span>
+                                            <span class="preset-pattern">{&preset.pattern}</span>
+                                        </button>
+                                        if !preset.builtin {
+                                            <button
+                                                class="preset-delete-btn"
+                                                onclick={Callback::from(move |_| on_delete.emit(id.clone()))}
+                                                title={i18n.t("common.delete")}
+                                            >
+                                                {"✕"}
+                                            </button>
+                                        }
+                                    </div>
                                 }
                             }).collect::<Html>()
                         }
                     </div>
+                    <div class="preset-save-row">
+                        <input
+                            type="text"
+                            class="preset-save-input"
+                            placeholder={i18n.t("regex_tester.preset_name_placeholder")}
+                            value={(*preset_name).clone()}
+                            oninput={on_preset_name_change}
+                        />
+                        <input
+                            type="text"
+                            class="preset-save-input"
+                            placeholder={i18n.t("regex_tester.preset_description_placeholder")}
+                            value={(*preset_description).clone()}
+                            oninput={on_preset_description_change}
+                        />
+                        <button
+                            class="secondary-btn"
+                            onclick={on_save_preset}
+                            disabled={pattern.is_empty() || preset_name.trim().is_empty()}
+                        >
+                            {i18n.t("regex_tester.save_preset")}
+                        </button>
+                    </div>
+                    if let Some(error) = (*preset_error).clone() {
+                        <p class="error-message">{error}</p>
+                    }
                 </div>
             }
 
@@ -760,6 +961,59 @@ pub fn regex_tester(_props: &Props) -> Html {
                 </div>
             }
 
+            if *show_samples {
+                <div class="section samples-section">
+                    <h3>{i18n.t("regex_tester.samples_title")}</h3>
+                    <div class="samples-options">
+                        <label>
+                            {i18n.t("regex_tester.sample_count")}
+                            <input
+                                type="number"
+                                min="1"
+                                max="100"
+                                value={sample_count.to_string()}
+                                oninput={on_sample_count_change}
+                            />
+                        </label>
+                        <label>
+                            {i18n.t("regex_tester.sample_max_repeat")}
+                            <input
+                                type="number"
+                                min="0"
+                                max="100"
+                                value={sample_max_repeat.to_string()}
+                                oninput={on_sample_max_repeat_change}
+                            />
+                        </label>
+                        <button class="primary-btn" onclick={on_generate_samples} disabled={pattern.is_empty()}>
+                            {i18n.t("regex_tester.generate_samples")}
+                        </button>
+                    </div>
+                    if let Some(ref res) = *sample_result {
+                        if let Some(ref error) = res.error {
+                            <p class="error-message">{error}</p>
+                        }
+                        if !res.warnings.is_empty() {
+                            <ul class="replace-warnings">
+                                { for res.warnings.iter().map(|w| html! { <li>{w}</li> }) }
+                            </ul>
+                        }
+                        if res.success && !res.samples.is_empty() {
+                            <ul class="sample-list">
+                                { for res.samples.iter().map(|sample| html! {
+                                    <li><code>{sample}</code></li>
+                                }) }
+                            </ul>
+                            <div class="action-buttons">
+                                <button class="secondary-btn" onclick={on_use_samples}>
+                                    {i18n.t("regex_tester.use_samples")}
+                                </button>
+                            </div>
+                        }
+                    }
+                </div>
+            }
+
             if *show_replace {
                 <div class="section replace-section">
                     <h3>{i18n.t("regex_tester.replace_title")}</h3>
@@ -790,7 +1044,33 @@ pub fn regex_tester(_props: &Props) -> Html {
                                     }
                                 </button>
                             </div>
+                            if !res.warnings.is_empty() {
+                                <ul class="replace-warnings">
+                                    { for res.warnings.iter().map(|w| html! { <li>{w}</li> }) }
+                                </ul>
+                            }
+                            if res.truncated {
+                                <div class="regex-truncated">{i18n.t("regex_tester.truncated")}</div>
+                            }
                             <pre class="replace-output">{&res.result}</pre>
+                            if !res.pairs.is_empty() {
+                                <div class="replace-pairs">
+                                    <div class="replace-pairs-title">{i18n.t("regex_tester.replacement_pairs")}</div>
+                                    { for res.pairs.iter().take(MAX_PAIRS_SHOWN).map(|pair| html! {
+                                        <div class="replace-pair">
+                                            <span class="replace-pair-position">{format!("{}..{}", pair.char_start, pair.char_end)}</span>
+                                            <code class="replace-pair-before">{&pair.original}</code>
+                                            <span class="replace-pair-arrow">{"→"}</span>
+                                            <code class="replace-pair-after">{&pair.replaced}</code>
+                                        </div>
+                                    }) }
+                                    if res.pairs.len() > MAX_PAIRS_SHOWN {
+                                        <div class="replace-pairs-more">
+                                            {format!("+{}", res.pairs.len() - MAX_PAIRS_SHOWN)}
+                                        </div>
+                                    }
+                                </div>
+                            }
                         </div>
                     }
                 </div>
@@ -816,6 +1096,9 @@ pub fn regex_tester(_props: &Props) -> Html {
                             <span class="stat-label">{i18n.t("regex_tester.groups")}</span>
                         </div>
                     </div>
+                    if res.truncated {
+                        <div class="regex-truncated">{i18n.t("regex_tester.truncated")}</div>
+                    }
                 </div>
 
                 <div class="section result-section">
@@ -838,7 +1121,7 @@ pub fn regex_tester(_props: &Props) -> Html {
                                                     {format!("#{}", i + 1)}
                                                 </span>
                                                 <span class="match-position">
-                                                    {format!("{}: {}..{}", i18n.t("regex_tester.position"), m.start, m.end)}
+                                                    {format!("{}: {}..{}", i18n.t("regex_tester.position"), m.char_start, m.char_end)}
                                                 </span>
                                             </div>
                                             <div class="match-content">
@@ -851,7 +1134,7 @@ pub fn regex_tester(_props: &Props) -> Html {
                                                         {
                                                             m.groups.iter().map(|g| {
                                                                 let label = if let Some(ref name) = g.name {
-                                                                    name.clone()
+                                                                    format!("{} (${})", name, g.index)
                                                                 } else {
                                                                     format!("${}", g.index)
                                                                 };
@@ -15,11 +15,27 @@ extern "C" {
 extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"], catch)]
     async fn save(options: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"], catch)]
+    async fn open(options: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
 }
 
+/// Quick reminder offsets shown in the reminder panel, in minutes
+const REMINDER_PRESETS: [u32; 4] = [10, 30, 60, 180];
+/// Characters of the note sent as the notification body
+const REMINDER_BODY_CHARS: usize = 200;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Note {
     pub id: String,
+    #[serde(default)]
+    pub title: String,
     pub content: String,
     pub created_at: String,
     pub updated_at: String,
@@ -27,22 +43,18 @@ pub struct Note {
 
 impl Note {
     fn title(&self) -> String {
-        let first_line = self.content.lines().next().unwrap_or("");
-        let title = first_line.trim_start_matches('#').trim();
-        if title.is_empty() {
+        if self.title.is_empty() {
             "New Note".to_string()
-        } else if title.len() > 30 {
-            format!("{}...", &title[..27])
         } else {
-            title.to_string()
+            self.title.clone()
         }
     }
 
     fn preview(&self) -> String {
         let lines: Vec<&str> = self.content.lines().skip(1).take(2).collect();
         let preview = lines.join(" ").trim().to_string();
-        if preview.len() > 50 {
-            format!("{}...", &preview[..47])
+        if preview.chars().count() > 50 {
+            format!("{}...", preview.chars().take(47).collect::<String>())
         } else if preview.is_empty() {
             "No additional text".to_string()
         } else {
@@ -51,6 +63,66 @@ impl Note {
     }
 }
 
+/// Same rule the backend uses for automatic titles
+fn title_from_content(content: &str) -> String {
+    let line = content
+        .lines()
+        .map(|l| l.trim_start_matches('#').trim())
+        .find(|l| !l.is_empty())
+        .unwrap_or("");
+    if line.chars().count() > 30 {
+        format!("{}...", line.chars().take(27).collect::<String>())
+    } else {
+        line.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NoteSearchResult {
+    pub note_id: String,
+    pub title: String,
+    pub title_matched: bool,
+    pub snippet: String,
+    pub snippet_match_start: usize,
+    pub snippet_match_end: usize,
+    pub match_position: Option<usize>,
+    pub match_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NoteHistoryEntry {
+    pub version_id: String,
+    pub created_at: String,
+    pub title: String,
+    pub preview: String,
+    pub char_count: usize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReminderStatus {
+    Pending,
+    Fired,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminder {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub fire_at: i64,
+    pub created_at: i64,
+    pub status: ReminderStatus,
+    pub finished_at: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct ReminderFiredEvent {
+    payload: Reminder,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScratchPadData {
     pub notes: Vec<Note>,
@@ -67,6 +139,7 @@ impl Default for ScratchPadData {
         let id = format!("{:x}", js_sys::Math::random().to_bits());
         let default_note = Note {
             id: id.clone(),
+            title: String::new(),
             content: String::new(),
             created_at: now.clone(),
             updated_at: now,
@@ -93,6 +166,38 @@ struct EmptyArgs {}
 struct UpdateNoteArgs {
     note_id: String,
     content: String,
+    title: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListNoteHistoryArgs {
+    note_id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RestoreNoteVersionArgs {
+    note_id: String,
+    version_id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateReminderArgs {
+    title: String,
+    body: String,
+    fire_at: i64,
+}
+
+#[derive(Serialize)]
+struct CancelReminderArgs {
+    id: String,
+}
+
+#[derive(Serialize)]
+struct SearchNotesArgs {
+    query: String,
 }
 
 #[derive(Serialize)]
@@ -113,13 +218,58 @@ struct MarkdownToHtmlArgs {
     markdown: String,
 }
 
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum NoteExportFormat {
+    Markdown,
+    Html,
+    Pdf,
+}
+
+impl NoteExportFormat {
+    const ALL: [NoteExportFormat; 3] = [
+        NoteExportFormat::Markdown,
+        NoteExportFormat::Html,
+        NoteExportFormat::Pdf,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            NoteExportFormat::Markdown => "Markdown",
+            NoteExportFormat::Html => "HTML",
+            NoteExportFormat::Pdf => "PDF",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            NoteExportFormat::Markdown => "md",
+            NoteExportFormat::Html => "html",
+            NoteExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-struct ExportToFileArgs {
-    content: String,
+struct ExportNoteArgs {
+    note_id: String,
+    format: NoteExportFormat,
     path: String,
 }
 
+#[derive(Serialize)]
+struct ExportAllNotesArgs {
+    dir: String,
+    format: NoteExportFormat,
+}
+
+#[derive(Serialize)]
+struct DirectoryDialogOptions {
+    directory: bool,
+    title: String,
+}
+
 #[derive(Serialize)]
 struct SaveDialogOptions {
     title: String,
@@ -149,6 +299,30 @@ impl ViewMode {
     }
 }
 
+fn now_secs() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
+
+/// Parse a `datetime-local` value, which the browser reads as local time
+fn parse_local_datetime(value: &str) -> Option<i64> {
+    let millis = js_sys::Date::new(&JsValue::from_str(value)).get_time();
+    (!millis.is_nan()).then(|| (millis / 1000.0) as i64)
+}
+
+fn format_reminder_time(secs: i64) -> String {
+    js_sys::Date::new(&JsValue::from_f64(secs as f64 * 1000.0))
+        .to_locale_string("default", &JsValue::UNDEFINED)
+        .as_string()
+        .unwrap_or_default()
+}
+
+async fn fetch_reminders() -> Vec<Reminder> {
+    match invoke("list_reminders_cmd", JsValue::NULL).await {
+        Ok(result) => serde_wasm_bindgen::from_value(result).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct ScratchPadProps {}
 
@@ -161,6 +335,44 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
     let view_mode = use_state(|| ViewMode::Split);
     let auto_save_pending = use_state(|| false);
     let save_status = use_state(|| "");
+    let search_query = use_state(String::new);
+    let search_results = use_state(Vec::<NoteSearchResult>::new);
+    // None while the history panel is closed
+    let history_entries = use_state(|| Option::<Vec<NoteHistoryEntry>>::None);
+    // None while the reminder panel is closed
+    let reminders = use_state(|| Option::<Vec<Reminder>>::None);
+    let reminder_time = use_state(String::new);
+    let reminder_error = use_state(|| Option::<String>::None);
+    let fired_reminder = use_state(|| Option::<String>::None);
+    let export_format = use_state(|| NoteExportFormat::Markdown);
+
+    // Refresh the open reminder panel when a reminder fires
+    {
+        let fired_reminder = fired_reminder.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<ReminderFiredEvent>(event) {
+                        fired_reminder.set(Some(event.payload.id));
+                    }
+                });
+                let _ = listen("reminder-fired", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+    {
+        let reminders = reminders.clone();
+        use_effect_with((*fired_reminder).clone(), move |_| {
+            if reminders.is_some() {
+                spawn_local(async move {
+                    reminders.set(Some(fetch_reminders().await));
+                });
+            }
+            || {}
+        });
+    }
 
     // Load data on mount
     {
@@ -238,7 +450,9 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
     let on_select_note = {
         let data = data.clone();
         let preview_html = preview_html.clone();
+        let history_entries = history_entries.clone();
         Callback::from(move |note_id: String| {
+            history_entries.set(None);
             let data = data.clone();
             let preview_html = preview_html.clone();
             spawn_local(async move {
@@ -328,6 +542,10 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
                 if let Some(d) = (*data_clone).clone() {
                     let mut new_data = d;
                     if let Some(n) = new_data.notes.iter_mut().find(|n| n.id == note.id) {
+                        // Automatic titles follow the first line, custom ones stay
+                        if n.title == title_from_content(&n.content) {
+                            n.title = title_from_content(&new_content);
+                        }
                         n.content = new_content.clone();
                     }
                     data_clone.set(Some(new_data));
@@ -370,6 +588,7 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
                             let args = serde_wasm_bindgen::to_value(&UpdateNoteArgs {
                                 note_id,
                                 content: content_for_save,
+                                title: None,
                             })
                             .unwrap();
                             match invoke("update_note_cmd", args).await {
@@ -400,6 +619,216 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
         })
     };
 
+    let on_title_change = {
+        let data = data.clone();
+        let active_note = active_note.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let Some(note) = active_note.clone() else {
+                return;
+            };
+            let data = data.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&UpdateNoteArgs {
+                    note_id: note.id.clone(),
+                    content: note.content.clone(),
+                    title: Some(input.value()),
+                })
+                .unwrap();
+                if let Ok(result) = invoke("update_note_cmd", args).await {
+                    if let Ok(updated) = serde_wasm_bindgen::from_value::<Note>(result) {
+                        input.set_value(&updated.title);
+                        if let Some(mut d) = (*data).clone() {
+                            if let Some(n) = d.notes.iter_mut().find(|n| n.id == updated.id) {
+                                n.title = updated.title;
+                            }
+                            data.set(Some(d));
+                        }
+                    }
+                }
+            });
+        })
+    };
+
+    let on_search_input = {
+        let search_query = search_query.clone();
+        let search_results = search_results.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let query = input.value();
+            search_query.set(query.clone());
+            if query.trim().is_empty() {
+                search_results.set(Vec::new());
+                return;
+            }
+            let search_results = search_results.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&SearchNotesArgs { query }).unwrap();
+                if let Ok(result) = invoke("search_notes_cmd", args).await {
+                    if let Ok(results) =
+                        serde_wasm_bindgen::from_value::<Vec<NoteSearchResult>>(result)
+                    {
+                        search_results.set(results);
+                    }
+                }
+            });
+        })
+    };
+
+    let on_toggle_history = {
+        let history_entries = history_entries.clone();
+        let active_note = active_note.clone();
+        Callback::from(move |_: MouseEvent| {
+            if history_entries.is_some() {
+                history_entries.set(None);
+                return;
+            }
+            let Some(note) = active_note.clone() else {
+                return;
+            };
+            let history_entries = history_entries.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&ListNoteHistoryArgs { note_id: note.id })
+                    .unwrap();
+                let entries = match invoke("list_note_history_cmd", args).await {
+                    Ok(result) => serde_wasm_bindgen::from_value::<Vec<NoteHistoryEntry>>(result)
+                        .unwrap_or_default(),
+                    Err(_) => Vec::new(),
+                };
+                history_entries.set(Some(entries));
+            });
+        })
+    };
+
+    let on_toggle_reminders = {
+        let reminders = reminders.clone();
+        let reminder_error = reminder_error.clone();
+        Callback::from(move |_: MouseEvent| {
+            reminder_error.set(None);
+            if reminders.is_some() {
+                reminders.set(None);
+                return;
+            }
+            let reminders = reminders.clone();
+            spawn_local(async move {
+                reminders.set(Some(fetch_reminders().await));
+            });
+        })
+    };
+
+    // Schedule a reminder for the active note at the given Unix time
+    let on_create_reminder = {
+        let reminders = reminders.clone();
+        let reminder_error = reminder_error.clone();
+        let active_note = active_note.clone();
+        Callback::from(move |fire_at: i64| {
+            let Some(note) = active_note.clone() else {
+                return;
+            };
+            let reminders = reminders.clone();
+            let reminder_error = reminder_error.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&CreateReminderArgs {
+                    title: note.title(),
+                    body: note.content.chars().take(REMINDER_BODY_CHARS).collect(),
+                    fire_at,
+                })
+                .unwrap();
+                match invoke("create_reminder_cmd", args).await {
+                    Ok(_) => {
+                        reminder_error.set(None);
+                        reminders.set(Some(fetch_reminders().await));
+                    }
+                    Err(e) => reminder_error.set(e.as_string()),
+                }
+            });
+        })
+    };
+
+    let on_reminder_time_change = {
+        let reminder_time = reminder_time.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            reminder_time.set(input.value());
+        })
+    };
+
+    let on_create_custom_reminder = {
+        let reminder_time = reminder_time.clone();
+        let reminder_error = reminder_error.clone();
+        let on_create_reminder = on_create_reminder.clone();
+        let invalid_message = i18n.t("scratch_pad.reminder_invalid_time");
+        Callback::from(
+            move |_: MouseEvent| match parse_local_datetime(&reminder_time) {
+                Some(fire_at) => on_create_reminder.emit(fire_at),
+                None => reminder_error.set(Some(invalid_message.clone())),
+            },
+        )
+    };
+
+    let on_cancel_reminder = {
+        let reminders = reminders.clone();
+        let reminder_error = reminder_error.clone();
+        Callback::from(move |id: String| {
+            let reminders = reminders.clone();
+            let reminder_error = reminder_error.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&CancelReminderArgs { id }).unwrap();
+                if let Err(e) = invoke("cancel_reminder_cmd", args).await {
+                    reminder_error.set(e.as_string());
+                }
+                reminders.set(Some(fetch_reminders().await));
+            });
+        })
+    };
+
+    let on_restore_version = {
+        let data = data.clone();
+        let preview_html = preview_html.clone();
+        let history_entries = history_entries.clone();
+        let active_note = active_note.clone();
+        Callback::from(move |version_id: String| {
+            let Some(note) = active_note.clone() else {
+                return;
+            };
+            let data = data.clone();
+            let preview_html = preview_html.clone();
+            let history_entries = history_entries.clone();
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&RestoreNoteVersionArgs {
+                    note_id: note.id,
+                    version_id,
+                })
+                .unwrap();
+                let Ok(result) = invoke("restore_note_version_cmd", args).await else {
+                    return;
+                };
+                let Ok(restored) = serde_wasm_bindgen::from_value::<Note>(result) else {
+                    return;
+                };
+
+                let md_args = serde_wasm_bindgen::to_value(&MarkdownToHtmlArgs {
+                    markdown: restored.content.clone(),
+                })
+                .unwrap();
+                if let Ok(html_result) = invoke("markdown_to_html_cmd", md_args).await {
+                    if let Ok(res) =
+                        serde_wasm_bindgen::from_value::<MarkdownToHtmlResult>(html_result)
+                    {
+                        preview_html.set(res.html.unwrap_or_default());
+                    }
+                }
+                if let Some(mut d) = (*data).clone() {
+                    if let Some(n) = d.notes.iter_mut().find(|n| n.id == restored.id) {
+                        *n = restored;
+                    }
+                    data.set(Some(d));
+                }
+                history_entries.set(None);
+            });
+        })
+    };
+
     let on_view_mode_change = {
         let view_mode = view_mode.clone();
         Callback::from(move |mode: ViewMode| {
@@ -407,30 +836,51 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
         })
     };
 
+    let on_export_format_change = {
+        let export_format = export_format.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(format) = NoteExportFormat::ALL
+                .into_iter()
+                .find(|f| f.extension() == select.value())
+            {
+                export_format.set(format);
+            }
+        })
+    };
+
     let on_save_file = {
         let active_note = active_note.clone();
         let save_status = save_status.clone();
+        let export_format = export_format.clone();
         Callback::from(move |_| {
             if let Some(note) = active_note.clone() {
-                let content = note.content.clone();
+                let format = *export_format;
                 let save_status = save_status.clone();
                 spawn_local(async move {
                     let options = serde_wasm_bindgen::to_value(&SaveDialogOptions {
-                        title: "Save Markdown File".to_string(),
+                        title: format!("Save {} File", format.label()),
                         filters: vec![SaveFilter {
-                            name: "Markdown".to_string(),
-                            extensions: vec!["md".to_string()],
+                            name: format.label().to_string(),
+                            extensions: vec![format.extension().to_string()],
                         }],
                     })
                     .unwrap();
 
                     if let Ok(path_result) = save(options).await {
                         if let Some(path) = path_result.as_string() {
-                            let args =
-                                serde_wasm_bindgen::to_value(&ExportToFileArgs { content, path })
-                                    .unwrap();
-                            let _ = invoke("export_to_file_cmd", args).await;
-                            save_status.set("Exported!");
+                            let args = serde_wasm_bindgen::to_value(&ExportNoteArgs {
+                                note_id: note.id,
+                                format,
+                                path,
+                            })
+                            .unwrap();
+                            let result = invoke("export_note_as_cmd", args).await;
+                            save_status.set(if result.is_ok() {
+                                "Exported!"
+                            } else {
+                                "Export failed"
+                            });
                             let status_reset = save_status.clone();
                             Timeout::new(2000, move || {
                                 status_reset.set("");
@@ -443,6 +893,40 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
         })
     };
 
+    let on_export_all = {
+        let save_status = save_status.clone();
+        let export_format = export_format.clone();
+        let title = i18n.t("scratch_pad.export_all");
+        Callback::from(move |_| {
+            let format = *export_format;
+            let save_status = save_status.clone();
+            let title = title.clone();
+            spawn_local(async move {
+                let options = serde_wasm_bindgen::to_value(&DirectoryDialogOptions {
+                    directory: true,
+                    title,
+                })
+                .unwrap();
+                let Some(dir) = open(options).await.ok().and_then(|d| d.as_string()) else {
+                    return;
+                };
+                let args =
+                    serde_wasm_bindgen::to_value(&ExportAllNotesArgs { dir, format }).unwrap();
+                let result = invoke("export_all_notes_cmd", args).await;
+                save_status.set(if result.is_ok() {
+                    "Exported!"
+                } else {
+                    "Export failed"
+                });
+                let status_reset = save_status.clone();
+                Timeout::new(2000, move || {
+                    status_reset.set("");
+                })
+                .forget();
+            });
+        })
+    };
+
     if *is_loading {
         return html! {
             <div class="container container-wide">
@@ -473,53 +957,142 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
                             </svg>
                         </button>
                     </div>
-                    <div class="notes-list">
-                        { for notes.iter().map(|note| {
-                            let is_active = active_note.as_ref().map(|n| n.id == note.id).unwrap_or(false);
-                            let on_select = on_select_note.clone();
-                            let on_delete = on_delete_note.clone();
-                            let id_for_select = note.id.clone();
-                            let id_for_delete = note.id.clone();
-                            html! {
-                                <div
-                                    class={classes!("note-item", is_active.then_some("active"))}
-                                    onclick={Callback::from(move |_| on_select.emit(id_for_select.clone()))}
-                                >
-                                    <div class="note-item-content">
-                                        <div class="note-item-title">{note.title()}</div>
-                                        <div class="note-item-preview">{note.preview()}</div>
-                                    </div>
-                                    if notes.len() > 1 {
-                                        <button
-                                            class="note-delete-btn"
-                                            onclick={Callback::from(move |e: MouseEvent| {
-                                                e.stop_propagation();
-                                                on_delete.emit(id_for_delete.clone());
-                                            })}
-                                            title={i18n.t("common.delete")}
-                                        >
-                                            <svg width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
-                                                <line x1="18" y1="6" x2="6" y2="18"/>
-                                                <line x1="6" y1="6" x2="18" y2="18"/>
-                                            </svg>
-                                        </button>
-                                    }
-                                </div>
-                            }
-                        })}
+                    <div class="notes-search">
+                        <input
+                            type="text"
+                            class="notes-search-input"
+                            placeholder={i18n.t("scratch_pad.search_placeholder")}
+                            value={(*search_query).clone()}
+                            oninput={on_search_input}
+                        />
                     </div>
+                    if !search_query.trim().is_empty() {
+                        <div class="notes-list">
+                            if search_results.is_empty() {
+                                <div class="notes-search-empty">{i18n.t("scratch_pad.no_results")}</div>
+                            }
+                            { for search_results.iter().map(|result| {
+                                let is_active = active_note.as_ref().map(|n| n.id == result.note_id).unwrap_or(false);
+                                let on_select = on_select_note.clone();
+                                let id_for_select = result.note_id.clone();
+                                let chars: Vec<char> = result.snippet.chars().collect();
+                                let start = result.snippet_match_start.min(chars.len());
+                                let end = result.snippet_match_end.clamp(start, chars.len());
+                                let before: String = chars[..start].iter().collect();
+                                let matched: String = chars[start..end].iter().collect();
+                                let after: String = chars[end..].iter().collect();
+                                let title = if result.title.is_empty() {
+                                    "New Note".to_string()
+                                } else {
+                                    result.title.clone()
+                                };
+                                html! {
+                                    <div
+                                        class={classes!("note-item", is_active.then_some("active"))}
+                                        onclick={Callback::from(move |_| on_select.emit(id_for_select.clone()))}
+                                    >
+                                        <div class="note-item-content">
+                                            <div class={classes!("note-item-title", result.title_matched.then_some("matched"))}>
+                                                {title}
+                                            </div>
+                                            <div class="note-item-preview">
+                                                {before}<mark>{matched}</mark>{after}
+                                            </div>
+                                        </div>
+                                        if result.match_count > 1 {
+                                            <span class="notes-search-count">{result.match_count}</span>
+                                        }
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    } else {
+                        <div class="notes-list">
+                            { for notes.iter().map(|note| {
+                                let is_active = active_note.as_ref().map(|n| n.id == note.id).unwrap_or(false);
+                                let on_select = on_select_note.clone();
+                                let on_delete = on_delete_note.clone();
+                                let id_for_select = note.id.clone();
+                                let id_for_delete = note.id.clone();
+                                html! {
+                                    <div
+                                        class={classes!("note-item", is_active.then_some("active"))}
+                                        onclick={Callback::from(move |_| on_select.emit(id_for_select.clone()))}
+                                    >
+                                        <div class="note-item-content">
+                                            <div class="note-item-title">{note.title()}</div>
+                                            <div class="note-item-preview">{note.preview()}</div>
+                                        </div>
+                                        if notes.len() > 1 {
+                                            <button
+                                                class="note-delete-btn"
+                                                onclick={Callback::from(move |e: MouseEvent| {
+                                                    e.stop_propagation();
+                                                    on_delete.emit(id_for_delete.clone());
+                                                })}
+                                                title={i18n.t("common.delete")}
+                                            >
+                                                <svg width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                                                    <line x1="18" y1="6" x2="6" y2="18"/>
+                                                    <line x1="6" y1="6" x2="18" y2="18"/>
+                                                </svg>
+                                            </button>
+                                        }
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    }
                 </div>
 
                 // Editor
                 <div class="notes-editor">
                     if let Some(note) = active_note.clone() {
                         <div class="notes-editor-header">
+                            <input
+                                type="text"
+                                class="note-title-input"
+                                value={note.title.clone()}
+                                placeholder={i18n.t("scratch_pad.title_placeholder")}
+                                onchange={on_title_change}
+                            />
                             <div class="notes-editor-status">
                                 if !(*save_status).is_empty() {
                                     <span class="save-status">{*save_status}</span>
                                 }
                             </div>
                             <div class="notes-editor-actions">
+                                <button
+                                    class={classes!("export-btn", history_entries.is_some().then_some("active"))}
+                                    onclick={on_toggle_history}
+                                    title={i18n.t("scratch_pad.history")}
+                                >
+                                    <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                                        <circle cx="12" cy="12" r="9"/>
+                                        <polyline points="12 7 12 12 15 14"/>
+                                    </svg>
+                                </button>
+                                <button
+                                    class={classes!("export-btn", reminders.is_some().then_some("active"))}
+                                    onclick={on_toggle_reminders}
+                                    title={i18n.t("scratch_pad.reminder")}
+                                >
+                                    <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                                        <path d="M18 8a6 6 0 00-12 0c0 7-3 9-3 9h18s-3-2-3-9"/>
+                                        <path d="M13.73 21a2 2 0 01-3.46 0"/>
+                                    </svg>
+                                </button>
+                                <select
+                                    class="note-export-format"
+                                    onchange={on_export_format_change}
+                                    title={i18n.t("scratch_pad.export_format")}
+                                >
+                                    { for NoteExportFormat::ALL.iter().map(|format| html! {
+                                        <option value={format.extension()} selected={*export_format == *format}>
+                                            {format.label()}
+                                        </option>
+                                    })}
+                                </select>
                                 <button class="export-btn" onclick={on_save_file} title={i18n.t("common.export")}>
                                     <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
                                         <path d="M21 15v4a2 2 0 01-2 2H5a2 2 0 01-2-2v-4"/>
@@ -527,6 +1100,13 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
                                         <line x1="12" y1="15" x2="12" y2="3"/>
                                     </svg>
                                 </button>
+                                <button class="export-btn" onclick={on_export_all} title={i18n.t("scratch_pad.export_all")}>
+                                    <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                                        <path d="M22 19a2 2 0 01-2 2H4a2 2 0 01-2-2V5a2 2 0 012-2h5l2 3h9a2 2 0 012 2z"/>
+                                        <polyline points="9 13 12 16 15 13"/>
+                                        <line x1="12" y1="16" x2="12" y2="10"/>
+                                    </svg>
+                                </button>
                                 <div class="view-mode-tabs">
                                     { for view_modes.iter().map(|mode| {
                                         let is_active = *view_mode == *mode;
@@ -545,6 +1125,113 @@ pub fn scratch_pad(_props: &ScratchPadProps) -> Html {
                                 </div>
                             </div>
                         </div>
+                        if let Some(entries) = (*history_entries).clone() {
+                            <div class="note-history-panel">
+                                <div class="note-history-title">{i18n.t("scratch_pad.history")}</div>
+                                if entries.is_empty() {
+                                    <div class="note-history-empty">{i18n.t("scratch_pad.history_empty")}</div>
+                                }
+                                { for entries.iter().map(|entry| {
+                                    let on_restore = on_restore_version.clone();
+                                    let version_id = entry.version_id.clone();
+                                    let time = js_sys::Date::new(&JsValue::from_str(&entry.created_at))
+                                        .to_locale_string("default", &JsValue::UNDEFINED)
+                                        .as_string()
+                                        .unwrap_or_else(|| entry.created_at.clone());
+                                    html! {
+                                        <div class="note-history-item">
+                                            <div class="note-history-info">
+                                                <div class="note-history-time">
+                                                    {time}
+                                                    <span class="note-history-size">
+                                                        {format!(" · {} {}", entry.char_count, i18n.t("common.characters"))}
+                                                    </span>
+                                                </div>
+                                                <div class="note-item-preview">{&entry.preview}</div>
+                                            </div>
+                                            <button
+                                                class="secondary-btn"
+                                                onclick={Callback::from(move |_| on_restore.emit(version_id.clone()))}
+                                            >
+                                                {i18n.t("scratch_pad.restore")}
+                                            </button>
+                                        </div>
+                                    }
+                                })}
+                            </div>
+                        }
+                        if let Some(list) = (*reminders).clone() {
+                            <div class="note-history-panel note-reminder-panel">
+                                <div class="note-history-title">{i18n.t("scratch_pad.reminder")}</div>
+                                <div class="note-reminder-form">
+                                    { for REMINDER_PRESETS.iter().map(|minutes| {
+                                        let on_create = on_create_reminder.clone();
+                                        let fire_in = i64::from(*minutes) * 60;
+                                        let label = if minutes % 60 == 0 {
+                                            i18n.t("scratch_pad.reminder_in_hours")
+                                                .replace("{n}", &(minutes / 60).to_string())
+                                        } else {
+                                            i18n.t("scratch_pad.reminder_in_minutes")
+                                                .replace("{n}", &minutes.to_string())
+                                        };
+                                        html! {
+                                            <button
+                                                class="secondary-btn"
+                                                onclick={Callback::from(move |_| on_create.emit(now_secs() + fire_in))}
+                                            >
+                                                {label}
+                                            </button>
+                                        }
+                                    })}
+                                    <input
+                                        type="datetime-local"
+                                        class="note-reminder-input"
+                                        value={(*reminder_time).clone()}
+                                        onchange={on_reminder_time_change}
+                                    />
+                                    <button class="secondary-btn" onclick={on_create_custom_reminder}>
+                                        {i18n.t("scratch_pad.reminder_set")}
+                                    </button>
+                                </div>
+                                if let Some(error) = (*reminder_error).clone() {
+                                    <div class="note-reminder-error">{error}</div>
+                                }
+                                if list.is_empty() {
+                                    <div class="note-history-empty">{i18n.t("scratch_pad.reminder_empty")}</div>
+                                }
+                                { for list.iter().map(|reminder| {
+                                    let on_cancel = on_cancel_reminder.clone();
+                                    let id = reminder.id.clone();
+                                    let pending = reminder.status == ReminderStatus::Pending;
+                                    let status_key = match reminder.status {
+                                        ReminderStatus::Pending => "scratch_pad.reminder_pending",
+                                        ReminderStatus::Fired => "scratch_pad.reminder_fired",
+                                        ReminderStatus::Cancelled => "scratch_pad.reminder_cancelled",
+                                    };
+                                    html! {
+                                        <div class={classes!("note-history-item", (!pending).then_some("finished"))}>
+                                            <div class="note-history-info">
+                                                <div class="note-history-time">
+                                                    {format_reminder_time(reminder.fire_at)}
+                                                    <span class="note-history-size">
+                                                        {format!(" · {}", i18n.t(status_key))}
+                                                    </span>
+                                                </div>
+                                                <div class="note-item-preview">{&reminder.title}</div>
+                                            </div>
+                                            if pending {
+                                                <button
+                                                    class="secondary-btn"
+                                                    onclick={Callback::from(move |_| on_cancel.emit(id.clone()))}
+                                                >
+                                                    {i18n.t("scratch_pad.reminder_cancel")}
+                                                </button>
+                                            }
+                                        </div>
+                                    }
+                                })}
+                            </div>
+                        }
                         <div class={classes!(
                             "notes-editor-content",
                             match *view_mode {
@@ -0,0 +1,396 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::System => "system",
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Self {
+        match value {
+            "light" => ThemeMode::Light,
+            "dark" => ThemeMode::Dark,
+            _ => ThemeMode::System,
+        }
+    }
+
+    /// Whether the dark theme applies, given the OS preference for system mode
+    pub fn is_dark(&self, system_dark: bool) -> bool {
+        match self {
+            ThemeMode::System => system_dark,
+            ThemeMode::Light => false,
+            ThemeMode::Dark => true,
+        }
+    }
+
+    /// Order used by the sidebar toggle
+    pub fn next(&self) -> Self {
+        match self {
+            ThemeMode::System => ThemeMode::Light,
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::System,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ImageCompressorDefaults {
+    pub quality: u8,
+    pub output_format: String,
+}
+
+impl Default for ImageCompressorDefaults {
+    fn default() -> Self {
+        Self {
+            quality: 80,
+            output_format: "avif".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsonFormatterDefaults {
+    pub indent_size: usize,
+}
+
+impl Default for JsonFormatterDefaults {
+    fn default() -> Self {
+        Self { indent_size: 2 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct KanbanDueSettings {
+    pub notify: bool,
+    pub check_interval_minutes: u32,
+}
+
+impl Default for KanbanDueSettings {
+    fn default() -> Self {
+        Self {
+            notify: true,
+            check_interval_minutes: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GenerationHistorySettings {
+    pub save_passwords: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppSettings {
+    pub version: u32,
+    pub language: String,
+    pub theme: ThemeMode,
+    pub default_output_dir: Option<String>,
+    pub image_compressor: ImageCompressorDefaults,
+    pub json_formatter: JsonFormatterDefaults,
+    pub kanban_due: KanbanDueSettings,
+    pub generation_history: GenerationHistorySettings,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            language: "ja".to_string(),
+            theme: ThemeMode::default(),
+            default_output_dir: None,
+            image_compressor: ImageCompressorDefaults::default(),
+            json_formatter: JsonFormatterDefaults::default(),
+            kanban_due: KanbanDueSettings::default(),
+            generation_history: GenerationHistorySettings::default(),
+        }
+    }
+}
+
+impl AppSettings {
+    /// `file_name` inside the default output folder, or just `file_name` when none is set
+    pub fn output_path(&self, file_name: &str) -> String {
+        match &self.default_output_dir {
+            Some(dir) => {
+                let separator = if dir.contains('\\') { '\\' } else { '/' };
+                format!(
+                    "{}{}{}",
+                    dir.trim_end_matches(['/', '\\']),
+                    separator,
+                    file_name
+                )
+            }
+            None => file_name.to_string(),
+        }
+    }
+}
+
+/// Provided by the app so tools can read their defaults and the settings page can save
+#[derive(Clone, PartialEq)]
+pub struct SettingsContext {
+    pub settings: AppSettings,
+    /// The theme currently shown, with system mode already resolved
+    pub dark: bool,
+    pub update: Callback<AppSettings>,
+    pub reset: Callback<()>,
+}
+
+#[derive(Serialize)]
+struct SaveSettingsArgs {
+    settings: AppSettings,
+}
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    directory: bool,
+}
+
+pub async fn load_settings() -> AppSettings {
+    let result = invoke("load_settings_cmd", JsValue::NULL).await;
+    serde_wasm_bindgen::from_value::<AppSettings>(result).unwrap_or_default()
+}
+
+/// Save `settings`, returning them as normalized by the backend
+pub async fn save_settings(settings: AppSettings) -> Option<AppSettings> {
+    let args = serde_wasm_bindgen::to_value(&SaveSettingsArgs { settings }).unwrap();
+    let result = invoke("save_settings_cmd", args).await;
+    serde_wasm_bindgen::from_value::<AppSettings>(result).ok()
+}
+
+pub async fn reset_settings() -> Option<AppSettings> {
+    let result = invoke("reset_settings_cmd", JsValue::NULL).await;
+    serde_wasm_bindgen::from_value::<AppSettings>(result).ok()
+}
+
+#[function_component(Settings)]
+pub fn settings() -> Html {
+    let (i18n, _) = use_translation();
+    let Some(ctx) = use_context::<SettingsContext>() else {
+        return html! {};
+    };
+    let settings = ctx.settings.clone();
+
+    // Each control edits a copy of the settings and saves it right away
+    let change = |edit: fn(&mut AppSettings, String)| {
+        let ctx = ctx.clone();
+        Callback::from(move |e: Event| {
+            // Used by both selects and inputs, so read `value` without casting the target
+            let value = e
+                .target()
+                .and_then(|target| js_sys::Reflect::get(&target, &JsValue::from_str("value")).ok())
+                .and_then(|value| value.as_string())
+                .unwrap_or_default();
+            let mut next = ctx.settings.clone();
+            edit(&mut next, value);
+            ctx.update.emit(next);
+        })
+    };
+
+    let on_select_output_dir = {
+        let ctx = ctx.clone();
+        Callback::from(move |_| {
+            let ctx = ctx.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    directory: true,
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(selected) = open(opts).await.as_string() {
+                    let mut next = ctx.settings.clone();
+                    next.default_output_dir = Some(selected);
+                    ctx.update.emit(next);
+                }
+            });
+        })
+    };
+
+    let on_clear_output_dir = {
+        let ctx = ctx.clone();
+        Callback::from(move |_| {
+            let mut next = ctx.settings.clone();
+            next.default_output_dir = None;
+            ctx.update.emit(next);
+        })
+    };
+
+    let on_due_notify_change = {
+        let ctx = ctx.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = ctx.settings.clone();
+            next.kanban_due.notify = input.checked();
+            ctx.update.emit(next);
+        })
+    };
+
+    let on_save_passwords_change = {
+        let ctx = ctx.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = ctx.settings.clone();
+            next.generation_history.save_passwords = input.checked();
+            ctx.update.emit(next);
+        })
+    };
+
+    let on_reset = {
+        let reset = ctx.reset.clone();
+        Callback::from(move |_| reset.emit(()))
+    };
+
+    html! {
+        <div class="settings-page">
+            <div class="section">
+                <h3>{i18n.t("settings.general")}</h3>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.language")}</label>
+                    <select onchange={change(|s, v| s.language = v)}>
+                        <option value="ja" selected={settings.language == "ja"}>{"日本語"}</option>
+                        <option value="en" selected={settings.language == "en"}>{"English"}</option>
+                    </select>
+                </div>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.theme")}</label>
+                    <select onchange={change(|s, v| s.theme = ThemeMode::from_value(&v))}>
+                        { for [ThemeMode::System, ThemeMode::Light, ThemeMode::Dark].iter().map(|mode| html! {
+                            <option value={mode.as_str()} selected={settings.theme == *mode}>
+                                {i18n.t(&format!("settings.theme_{}", mode.as_str()))}
+                            </option>
+                        }) }
+                    </select>
+                </div>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.output_dir")}</label>
+                    <span class="settings-path">
+                        {settings.default_output_dir.clone().unwrap_or_else(|| i18n.t("settings.output_dir_none"))}
+                    </span>
+                    <button class="secondary-btn" onclick={on_select_output_dir}>
+                        {i18n.t("settings.select_folder")}
+                    </button>
+                    if settings.default_output_dir.is_some() {
+                        <button class="secondary-btn" onclick={on_clear_output_dir}>{i18n.t("common.clear")}</button>
+                    }
+                </div>
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("app.tabs.compress")}</h3>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.quality")}</label>
+                    <input
+                        type="range"
+                        min="1"
+                        max="100"
+                        value={settings.image_compressor.quality.to_string()}
+                        onchange={change(|s, v| {
+                            if let Ok(quality) = v.parse() {
+                                s.image_compressor.quality = quality;
+                            }
+                        })}
+                    />
+                    <span class="settings-value">{format!("{}%", settings.image_compressor.quality)}</span>
+                </div>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.output_format")}</label>
+                    <select onchange={change(|s, v| s.image_compressor.output_format = v)}>
+                        { for [("avif", "AVIF"), ("webp", "WebP"), ("jpeg", "JPEG"), ("png", "PNG")].iter().map(|(value, label)| html! {
+                            <option value={*value} selected={settings.image_compressor.output_format == *value}>{*label}</option>
+                        }) }
+                    </select>
+                </div>
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("app.tabs.json")}</h3>
+                <div class="settings-row">
+                    <label>{i18n.t("json_formatter.indent_label")}</label>
+                    <select onchange={change(|s, v| {
+                        if let Ok(indent) = v.parse() {
+                            s.json_formatter.indent_size = indent;
+                        }
+                    })}>
+                        { for [2usize, 4, 8].iter().map(|size| html! {
+                            <option value={size.to_string()} selected={settings.json_formatter.indent_size == *size}>{size.to_string()}</option>
+                        }) }
+                    </select>
+                </div>
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("app.tabs.kanban")}</h3>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.due_notify")}</label>
+                    <input
+                        type="checkbox"
+                        checked={settings.kanban_due.notify}
+                        onchange={on_due_notify_change}
+                    />
+                </div>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.due_check_interval")}</label>
+                    <select onchange={change(|s, v| {
+                        if let Ok(minutes) = v.parse() {
+                            s.kanban_due.check_interval_minutes = minutes;
+                        }
+                    })}>
+                        { for [15u32, 30, 60, 180, 360].iter().map(|minutes| html! {
+                            <option
+                                value={minutes.to_string()}
+                                selected={settings.kanban_due.check_interval_minutes == *minutes}
+                            >
+                                {i18n.t("settings.minutes").replace("{n}", &minutes.to_string())}
+                            </option>
+                        }) }
+                    </select>
+                </div>
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("generation_history.title")}</h3>
+                <div class="settings-row">
+                    <label>{i18n.t("settings.save_password_history")}</label>
+                    <input
+                        type="checkbox"
+                        checked={settings.generation_history.save_passwords}
+                        onchange={on_save_passwords_change}
+                    />
+                </div>
+            </div>
+
+            <div class="settings-actions">
+                <button class="secondary-btn" onclick={on_reset}>{i18n.t("settings.reset")}</button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,323 @@
+use gloo_timers::callback::Timeout;
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+use crate::components::input_history::{save_history, InputHistoryPanel};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlFormatResult {
+    pub success: bool,
+    pub formatted: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SqlFormatOptions {
+    indent_width: u8,
+    keyword_case: String,
+    columns_per_line: usize,
+    dialect: String,
+    minify: bool,
+}
+
+#[derive(Serialize)]
+struct FormatSqlArgs {
+    input: String,
+    options: SqlFormatOptions,
+}
+
+/// (value, label key)
+const DIALECTS: [(&str, &str); 3] = [
+    ("generic", "sql_formatter.dialect_generic"),
+    ("mySql", "sql_formatter.dialect_mysql"),
+    ("postgreSql", "sql_formatter.dialect_postgresql"),
+];
+
+const KEYWORD_CASES: [(&str, &str); 3] = [
+    ("upper", "sql_formatter.case_upper"),
+    ("lower", "sql_formatter.case_lower"),
+    ("preserve", "sql_formatter.case_preserve"),
+];
+
+#[function_component(SqlFormatter)]
+pub fn sql_formatter() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(String::new);
+    let output = use_state(String::new);
+    let error = use_state(|| Option::<String>::None);
+    let indent_width = use_state(|| 2u8);
+    let keyword_case = use_state(|| "upper".to_string());
+    let columns_per_line = use_state(|| 1usize);
+    let dialect = use_state(|| "generic".to_string());
+    let is_processing = use_state(|| false);
+    let copied = use_state(|| false);
+    let history_refresh = use_state(|| 0u32);
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let textarea: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(textarea.value());
+        })
+    };
+
+    let select_handler = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            state.set(select.value());
+        })
+    };
+
+    let on_indent_change = {
+        let indent_width = indent_width.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(width) = select.value().parse::<u8>() {
+                indent_width.set(width);
+            }
+        })
+    };
+
+    let on_columns_change = {
+        let columns_per_line = columns_per_line.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(columns) = select.value().parse::<usize>() {
+                columns_per_line.set(columns);
+            }
+        })
+    };
+
+    // Format and Minify share the command; only the `minify` flag differs
+    let run_format = {
+        let input = input.clone();
+        let output = output.clone();
+        let error = error.clone();
+        let indent_width = indent_width.clone();
+        let keyword_case = keyword_case.clone();
+        let columns_per_line = columns_per_line.clone();
+        let dialect = dialect.clone();
+        let is_processing = is_processing.clone();
+        let history_refresh = history_refresh.clone();
+
+        move |minify: bool| {
+            let input = input.clone();
+            let output = output.clone();
+            let error = error.clone();
+            let indent_width = indent_width.clone();
+            let keyword_case = keyword_case.clone();
+            let columns_per_line = columns_per_line.clone();
+            let dialect = dialect.clone();
+            let is_processing = is_processing.clone();
+            let history_refresh = history_refresh.clone();
+
+            Callback::from(move |_: MouseEvent| {
+                let input_val = (*input).clone();
+                if input_val.trim().is_empty() {
+                    return;
+                }
+                let options = SqlFormatOptions {
+                    indent_width: *indent_width,
+                    keyword_case: (*keyword_case).clone(),
+                    columns_per_line: *columns_per_line,
+                    dialect: (*dialect).clone(),
+                    minify,
+                };
+                let output = output.clone();
+                let error = error.clone();
+                let is_processing = is_processing.clone();
+                let history_refresh = history_refresh.clone();
+
+                is_processing.set(true);
+
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&FormatSqlArgs {
+                        input: input_val.clone(),
+                        options,
+                    })
+                    .unwrap();
+                    let res = invoke("format_sql_cmd", args).await;
+                    if let Ok(result) = serde_wasm_bindgen::from_value::<SqlFormatResult>(res) {
+                        if result.success {
+                            output.set(result.formatted);
+                            error.set(None);
+                            save_history(
+                                "sql_formatter",
+                                serde_json::json!({"input": input_val}),
+                                None,
+                            );
+                            history_refresh.set(*history_refresh + 1);
+                        } else {
+                            error.set(result.error);
+                        }
+                    }
+                    is_processing.set(false);
+                });
+            })
+        }
+    };
+
+    let on_format = run_format(false);
+    let on_minify = run_format(true);
+
+    let on_copy = {
+        let output = output.clone();
+        let copied = copied.clone();
+
+        Callback::from(move |_| {
+            let text = (*output).clone();
+            let copied = copied.clone();
+
+            if text.is_empty() {
+                return;
+            }
+
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text)).await;
+                    copied.set(true);
+
+                    Timeout::new(2000, move || {
+                        copied.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let on_clear = {
+        let input = input.clone();
+        let output = output.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            input.set(String::new());
+            output.set(String::new());
+            error.set(None);
+        })
+    };
+
+    let on_history_restore = {
+        let input = input.clone();
+        Callback::from(move |inputs: serde_json::Value| {
+            if let Some(val) = inputs.get("input").and_then(|v| v.as_str()) {
+                input.set(val.to_string());
+            }
+        })
+    };
+
+    html! {
+        <div class="sql-formatter-container">
+            <div class="section sql-header">
+                <div class="sql-title-row">
+                    <h3>{i18n.t("sql_formatter.title")}</h3>
+                    <InputHistoryPanel
+                        tool_id="sql_formatter"
+                        on_restore={on_history_restore}
+                        refresh_trigger={*history_refresh}
+                    />
+                </div>
+                <div class="sql-controls">
+                    <div class="sql-option">
+                        <label>{i18n.t("sql_formatter.dialect")}</label>
+                        <select onchange={select_handler(&dialect)}>
+                            { for DIALECTS.iter().map(|(value, key)| html! {
+                                <option value={*value} selected={*dialect == *value}>{i18n.t(key)}</option>
+                            }) }
+                        </select>
+                    </div>
+                    <div class="sql-option">
+                        <label>{i18n.t("sql_formatter.keyword_case")}</label>
+                        <select onchange={select_handler(&keyword_case)}>
+                            { for KEYWORD_CASES.iter().map(|(value, key)| html! {
+                                <option value={*value} selected={*keyword_case == *value}>{i18n.t(key)}</option>
+                            }) }
+                        </select>
+                    </div>
+                    <div class="sql-option">
+                        <label>{i18n.t("sql_formatter.indent")}</label>
+                        <select onchange={on_indent_change}>
+                            { for [2u8, 4].iter().map(|width| html! {
+                                <option value={width.to_string()} selected={*indent_width == *width}>
+                                    {format!("{} {}", width, i18n.t("sql_formatter.spaces"))}
+                                </option>
+                            }) }
+                        </select>
+                    </div>
+                    <div class="sql-option">
+                        <label>{i18n.t("sql_formatter.columns_per_line")}</label>
+                        <select onchange={on_columns_change}>
+                            { for [1usize, 2, 3, 4, 5].iter().map(|columns| html! {
+                                <option value={columns.to_string()} selected={*columns_per_line == *columns}>
+                                    {columns.to_string()}
+                                </option>
+                            }) }
+                        </select>
+                    </div>
+                </div>
+            </div>
+
+            if let Some(message) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{format!("⚠ {}", message)}</div>
+                </div>
+            }
+
+            <div class="section input-output-section">
+                <div class="panel input-panel">
+                    <div class="panel-header">
+                        <h4>{i18n.t("common.input")}</h4>
+                        <div class="panel-actions">
+                            <button class="secondary-btn" onclick={on_clear}>{i18n.t("common.clear")}</button>
+                        </div>
+                    </div>
+                    <textarea
+                        class="sql-textarea"
+                        placeholder={i18n.t("sql_formatter.input_placeholder")}
+                        value={(*input).clone()}
+                        oninput={on_input_change}
+                    />
+                </div>
+
+                <div class="panel output-panel">
+                    <div class="panel-header">
+                        <h4>{i18n.t("common.output")}</h4>
+                        <div class="panel-actions">
+                            <button class="primary-btn" onclick={on_format} disabled={*is_processing}>
+                                {i18n.t("common.format")}
+                            </button>
+                            <button class="secondary-btn" onclick={on_minify} disabled={*is_processing}>
+                                {i18n.t("common.minify")}
+                            </button>
+                            <button
+                                class={classes!("secondary-btn", (*copied).then_some("copied"))}
+                                onclick={on_copy}
+                            >
+                                if *copied {
+                                    {i18n.t("common.copied")}
+                                } else {
+                                    {i18n.t("common.copy")}
+                                }
+                            </button>
+                        </div>
+                    </div>
+                    <pre class="sql-output">{&*output}</pre>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,429 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StartMonitorArgs {
+    interval_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiskInfo {
+    name: String,
+    mount_point: String,
+    file_system: String,
+    total_bytes: u64,
+    available_bytes: u64,
+    used_bytes: u64,
+    is_removable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SystemInfoResult {
+    os_name: String,
+    kernel_version: String,
+    host_name: String,
+    cpu_arch: String,
+    cpu_model: String,
+    physical_cores: Option<usize>,
+    logical_cores: usize,
+    uptime_secs: u64,
+    stats: SystemStats,
+    disks: Vec<DiskInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SystemStats {
+    cpu_usage: f32,
+    core_usages: Vec<f32>,
+    memory_used: u64,
+    memory_total: u64,
+    swap_used: u64,
+    swap_total: u64,
+}
+
+#[derive(Deserialize)]
+struct StatsEvent {
+    payload: SystemStats,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// Monitoring only runs while the tab is shown
+    #[prop_or_default]
+    pub active: bool,
+}
+
+/// Samples kept for the usage graphs
+const HISTORY_LEN: usize = 60;
+
+const INTERVALS_MS: [u64; 4] = [1000, 2000, 5000, 10000];
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1_073_741_824 {
+        format!("{:.2} GB", bytes as f64 / 1_073_741_824.0)
+    } else if bytes >= 1_048_576 {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn percent_of(used: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        used as f64 * 100.0 / total as f64
+    }
+}
+
+fn format_uptime(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = secs % 86_400 / 3600;
+    let minutes = secs % 3600 / 60;
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else {
+        format!("{}h {}m", hours, minutes)
+    }
+}
+
+/// SVG points for percent values, newest sample at the right edge
+fn graph_points(values: &[f64]) -> String {
+    let offset = HISTORY_LEN.saturating_sub(values.len());
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| format!("{},{:.1}", offset + i, 100.0 - value.clamp(0.0, 100.0)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn usage_graph(class: &'static str, values: &[f64]) -> Html {
+    html! {
+        <svg
+            class={classes!("sysinfo-graph", class)}
+            viewBox={format!("0 0 {} 100", HISTORY_LEN - 1)}
+            preserveAspectRatio="none"
+        >
+            <polyline points={graph_points(values)} vector-effect="non-scaling-stroke" />
+        </svg>
+    }
+}
+
+fn usage_bar(percent: f64) -> Html {
+    let level = if percent >= 90.0 {
+        "high"
+    } else if percent >= 70.0 {
+        "medium"
+    } else {
+        "low"
+    };
+    html! {
+        <div class="sysinfo-bar">
+            <div class={classes!("sysinfo-bar-fill", level)} style={format!("width: {:.1}%;", percent.clamp(0.0, 100.0))}></div>
+        </div>
+    }
+}
+
+#[function_component(SystemInfo)]
+pub fn system_info(props: &Props) -> Html {
+    let (i18n, _) = use_translation();
+    let info = use_state(|| Option::<SystemInfoResult>::None);
+    let is_loading = use_state(|| false);
+    let live = use_state(|| true);
+    let interval_ms = use_state(|| INTERVALS_MS[0]);
+    let error = use_state(|| Option::<String>::None);
+    let history = use_mut_ref(VecDeque::<SystemStats>::new);
+    // Latest sample from the monitor; setting it also redraws the graphs
+    let latest = use_state(|| Option::<SystemStats>::None);
+
+    let load_info = {
+        let info = info.clone();
+        let is_loading = is_loading.clone();
+        Callback::from(move |_| {
+            let info = info.clone();
+            let is_loading = is_loading.clone();
+            is_loading.set(true);
+            spawn_local(async move {
+                let result = invoke("get_system_info_cmd", JsValue::NULL).await;
+                if let Ok(result) = serde_wasm_bindgen::from_value::<SystemInfoResult>(result) {
+                    info.set(Some(result));
+                }
+                is_loading.set(false);
+            });
+        })
+    };
+
+    // Follow the monitor's samples; stop it when the component goes away
+    {
+        let history = history.clone();
+        let latest = latest.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) = serde_wasm_bindgen::from_value::<StatsEvent>(event) {
+                        let mut history = history.borrow_mut();
+                        if history.len() == HISTORY_LEN {
+                            history.pop_front();
+                        }
+                        history.push_back(event.payload.clone());
+                        latest.set(Some(event.payload));
+                    }
+                });
+                let _ = listen("system-stats", &handler).await;
+                handler.forget();
+            });
+            || {
+                spawn_local(async {
+                    invoke("stop_system_monitor_cmd", JsValue::NULL).await;
+                });
+            }
+        });
+    }
+
+    // Load the static details the first time the tab is shown
+    {
+        let info = info.clone();
+        let load_info = load_info.clone();
+        use_effect_with(props.active, move |active| {
+            if *active && info.is_none() {
+                load_info.emit(());
+            }
+            || {}
+        });
+    }
+
+    // Starting again replaces the running monitor, so only one ever samples
+    {
+        let error = error.clone();
+        use_effect_with(
+            (props.active && *live, *interval_ms),
+            move |(monitoring, interval_ms)| {
+                let monitoring = *monitoring;
+                let interval_ms = *interval_ms;
+                spawn_local(async move {
+                    if monitoring {
+                        let args = serde_wasm_bindgen::to_value(&StartMonitorArgs { interval_ms })
+                            .unwrap();
+                        match try_invoke("start_system_monitor_cmd", args).await {
+                            Ok(_) => error.set(None),
+                            Err(e) => error.set(e.as_string()),
+                        }
+                    } else {
+                        invoke("stop_system_monitor_cmd", JsValue::NULL).await;
+                    }
+                });
+                || {}
+            },
+        );
+    }
+
+    let on_toggle_live = {
+        let live = live.clone();
+        Callback::from(move |_| live.set(!*live))
+    };
+
+    let on_interval_change = {
+        let interval_ms = interval_ms.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(value) = select.value().parse::<u64>() {
+                interval_ms.set(value);
+            }
+        })
+    };
+
+    let Some(res) = (*info).clone() else {
+        return html! {
+            <div class="sysinfo">
+                <div class="section sysinfo-status">
+                    <span class="spinner"></span>
+                    {i18n.t("system_info.loading")}
+                </div>
+            </div>
+        };
+    };
+
+    let stats = (*latest).clone().unwrap_or_else(|| res.stats.clone());
+    let (cpu_history, memory_history): (Vec<f64>, Vec<f64>) = history
+        .borrow()
+        .iter()
+        .map(|s| {
+            (
+                f64::from(s.cpu_usage),
+                percent_of(s.memory_used, s.memory_total),
+            )
+        })
+        .unzip();
+    let memory_percent = percent_of(stats.memory_used, stats.memory_total);
+    let swap_percent = percent_of(stats.swap_used, stats.swap_total);
+
+    html! {
+        <div class="sysinfo">
+            <div class="section">
+                <div class="section-header">
+                    <h3>{i18n.t("system_info.overview")}</h3>
+                    <div class="sysinfo-actions">
+                        <label class="sysinfo-interval">
+                            {i18n.t("system_info.interval")}
+                            <select class="form-select" onchange={on_interval_change}>
+                                { for INTERVALS_MS.iter().map(|ms| html! {
+                                    <option value={ms.to_string()} selected={*interval_ms == *ms}>
+                                        {format!("{}s", ms / 1000)}
+                                    </option>
+                                }) }
+                            </select>
+                        </label>
+                        <button
+                            class={classes!("secondary-btn", live.then_some("active"))}
+                            onclick={on_toggle_live}
+                        >
+                            if *live {
+                                {i18n.t("system_info.pause")}
+                            } else {
+                                {i18n.t("system_info.resume")}
+                            }
+                        </button>
+                        <button class="secondary-btn" onclick={load_info.reform(|_: MouseEvent| ())} disabled={*is_loading}>
+                            {i18n.t("system_info.refresh")}
+                        </button>
+                    </div>
+                </div>
+                if let Some(message) = (*error).clone() {
+                    <div class="error-message">{message}</div>
+                }
+                <dl class="sysinfo-details">
+                    <dt>{i18n.t("system_info.os")}</dt>
+                    <dd>{&res.os_name}</dd>
+                    <dt>{i18n.t("system_info.kernel")}</dt>
+                    <dd>{&res.kernel_version}</dd>
+                    <dt>{i18n.t("system_info.host")}</dt>
+                    <dd>{&res.host_name}</dd>
+                    <dt>{i18n.t("system_info.cpu")}</dt>
+                    <dd>{format!("{} ({})", res.cpu_model, res.cpu_arch)}</dd>
+                    <dt>{i18n.t("system_info.cores")}</dt>
+                    <dd>
+                        {match res.physical_cores {
+                            Some(physical) => format!(
+                                "{} {} / {} {}",
+                                physical,
+                                i18n.t("system_info.physical"),
+                                res.logical_cores,
+                                i18n.t("system_info.logical")
+                            ),
+                            None => format!("{} {}", res.logical_cores, i18n.t("system_info.logical")),
+                        }}
+                    </dd>
+                    <dt>{i18n.t("system_info.uptime")}</dt>
+                    <dd>{format_uptime(res.uptime_secs)}</dd>
+                </dl>
+            </div>
+
+            <div class="section sysinfo-usage">
+                <div class="sysinfo-card">
+                    <div class="sysinfo-card-header">
+                        <span>{i18n.t("system_info.cpu_usage")}</span>
+                        <strong>{format!("{:.1}%", stats.cpu_usage)}</strong>
+                    </div>
+                    {usage_graph("cpu", &cpu_history)}
+                </div>
+                <div class="sysinfo-card">
+                    <div class="sysinfo-card-header">
+                        <span>{i18n.t("system_info.memory")}</span>
+                        <strong>{format!("{:.1}%", memory_percent)}</strong>
+                    </div>
+                    {usage_graph("memory", &memory_history)}
+                    <div class="sysinfo-card-detail">
+                        {format!("{} / {}", format_size(stats.memory_used), format_size(stats.memory_total))}
+                    </div>
+                    if stats.swap_total > 0 {
+                        <div class="sysinfo-card-detail">
+                            {format!(
+                                "{}: {} / {} ({:.1}%)",
+                                i18n.t("system_info.swap"),
+                                format_size(stats.swap_used),
+                                format_size(stats.swap_total),
+                                swap_percent
+                            )}
+                        </div>
+                    }
+                </div>
+            </div>
+
+            <div class="section">
+                <div class="section-header">
+                    <h3>{i18n.t("system_info.per_core")}</h3>
+                </div>
+                <div class="sysinfo-cores">
+                    { for stats.core_usages.iter().enumerate().map(|(i, usage)| html! {
+                        <div class="sysinfo-core" title={format!("#{} {:.1}%", i, usage)}>
+                            <span class="sysinfo-core-label">{format!("#{}", i)}</span>
+                            {usage_bar(f64::from(*usage))}
+                            <span class="sysinfo-core-value">{format!("{:.0}%", usage)}</span>
+                        </div>
+                    }) }
+                </div>
+            </div>
+
+            <div class="section">
+                <div class="section-header">
+                    <h3>{i18n.t("system_info.disks")}</h3>
+                </div>
+                if res.disks.is_empty() {
+                    <p class="sysinfo-empty">{i18n.t("system_info.no_disks")}</p>
+                }
+                <div class="sysinfo-disks">
+                    { for res.disks.iter().map(|disk| {
+                        let percent = percent_of(disk.used_bytes, disk.total_bytes);
+                        html! {
+                            <div class="sysinfo-disk">
+                                <div class="sysinfo-disk-header">
+                                    <span class="sysinfo-disk-mount">{&disk.mount_point}</span>
+                                    <span class="sysinfo-disk-meta">
+                                        {format!("{} · {}", disk.name, disk.file_system)}
+                                        if disk.is_removable {
+                                            {format!(" · {}", i18n.t("system_info.removable"))}
+                                        }
+                                    </span>
+                                </div>
+                                {usage_bar(percent)}
+                                <div class="sysinfo-card-detail">
+                                    {format!(
+                                        "{} / {} ({:.1}%) · {} {}",
+                                        format_size(disk.used_bytes),
+                                        format_size(disk.total_bytes),
+                                        percent,
+                                        format_size(disk.available_bytes),
+                                        i18n.t("system_info.free")
+                                    )}
+                                </div>
+                            </div>
+                        }
+                    }) }
+                </div>
+            </div>
+        </div>
+    }
+}
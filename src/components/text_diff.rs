@@ -11,6 +11,18 @@ use crate::components::input_history::{save_history, InputHistoryPanel};
 extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn save(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &Closure<dyn Fn(JsValue)>) -> JsValue;
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -56,6 +68,52 @@ impl ViewMode {
     }
 }
 
+/// Which whitespace differences are ignored when matching lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum WhitespaceMode {
+    #[default]
+    None,
+    Trailing,
+    Leading,
+    All,
+}
+
+impl WhitespaceMode {
+    fn label(&self) -> &'static str {
+        match self {
+            WhitespaceMode::None => "None",
+            WhitespaceMode::Trailing => "Trailing",
+            WhitespaceMode::Leading => "Leading",
+            WhitespaceMode::All => "All",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiffOptions {
+    ignore_whitespace: WhitespaceMode,
+    ignore_case: bool,
+    ignore_blank_lines: bool,
+}
+
+/// Whether two texts or two folders are compared
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareTarget {
+    Text,
+    Folders,
+}
+
+impl CompareTarget {
+    fn label(&self) -> &'static str {
+        match self {
+            CompareTarget::Text => "Text",
+            CompareTarget::Folders => "Folders",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct InlineChange {
@@ -72,6 +130,24 @@ struct LineDiff {
     content: String,
     #[allow(dead_code)]
     inline_changes: Vec<InlineChange>,
+    #[serde(default)]
+    inline_spans: Vec<InlineSpan>,
+    /// Only differs in ignored whitespace, case or blank lines
+    #[serde(default)]
+    ignored: bool,
+    /// New-side text of an ignored equal line, when it differs from `content`
+    #[serde(default)]
+    new_content: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InlineSpan {
+    /// UTF-16 offsets into the line content
+    start: usize,
+    end: usize,
+    /// "insert" or "delete"
+    kind: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +160,8 @@ struct DiffStats {
     unchanged: usize,
     total_lines_old: usize,
     total_lines_new: usize,
+    #[serde(default)]
+    ignored_changes: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +171,8 @@ struct DiffResult {
     lines: Vec<LineDiff>,
     stats: DiffStats,
     unified_diff: String,
+    #[serde(default)]
+    is_binary: bool,
     error: Option<String>,
 }
 
@@ -111,6 +191,55 @@ struct ComputeDiffArgs {
     old_text: String,
     new_text: String,
     mode: DiffMode,
+    options: DiffOptions,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ComputeFileDiffArgs {
+    old_path: String,
+    new_path: String,
+    mode: DiffMode,
+    options: DiffOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum DiffExportFormat {
+    UnifiedPatch,
+    Html,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiffExportResult {
+    success: bool,
+    content: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportDiffArgs {
+    old_text: String,
+    new_text: String,
+    mode: DiffMode,
+    format: DiffExportFormat,
+    output_path: Option<String>,
+    context_lines: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct SaveDialogOptions {
+    filters: Vec<FileFilter>,
+    #[serde(rename = "defaultPath")]
+    default_path: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -119,6 +248,98 @@ struct GetFileInfoArgs {
     path: String,
 }
 
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    directory: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum DirCompareMethod {
+    SizeAndTime,
+    Hash,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DirCompareOptions {
+    ignore_patterns: Vec<String>,
+    method: DirCompareMethod,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CompareDirectoriesArgs {
+    dir_a: String,
+    dir_b: String,
+    options: DirCompareOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum DirEntryStatus {
+    OnlyInA,
+    OnlyInB,
+    Modified,
+    Identical,
+}
+
+impl DirEntryStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            DirEntryStatus::OnlyInA => "Only in A",
+            DirEntryStatus::OnlyInB => "Only in B",
+            DirEntryStatus::Modified => "Modified",
+            DirEntryStatus::Identical => "Identical",
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            DirEntryStatus::OnlyInA => "only-a",
+            DirEntryStatus::OnlyInB => "only-b",
+            DirEntryStatus::Modified => "modified",
+            DirEntryStatus::Identical => "identical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirCompareEntry {
+    relative_path: String,
+    status: DirEntryStatus,
+    size_a: Option<u64>,
+    size_b: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirCompareResult {
+    success: bool,
+    entries: Vec<DirCompareEntry>,
+    only_in_a: usize,
+    only_in_b: usize,
+    modified: usize,
+    identical: usize,
+    skipped: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirCompareProgress {
+    stage: String,
+    processed: usize,
+    total: usize,
+}
+
+#[derive(Deserialize)]
+struct DirCompareProgressEvent {
+    payload: DirCompareProgress,
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     #[prop_or_default]
@@ -133,13 +354,19 @@ pub fn text_diff(props: &Props) -> Html {
     let new_text = use_state(String::new);
     let old_file_name = use_state(|| Option::<String>::None);
     let new_file_name = use_state(|| Option::<String>::None);
+    // Paths of files loaded into each side; cleared once the text is edited by hand
+    let old_file_path = use_state(|| Option::<String>::None);
+    let new_file_path = use_state(|| Option::<String>::None);
     let diff_result = use_state(|| Option::<DiffResult>::None);
     let is_comparing = use_state(|| false);
     let diff_mode = use_state(|| DiffMode::Line);
+    let diff_options = use_state(DiffOptions::default);
     let view_mode = use_state(|| ViewMode::SideBySide);
     let copied = use_state(|| false);
+    let context_lines = use_state(|| 3usize);
     let error_message = use_state(|| Option::<String>::None);
     let history_refresh = use_state(|| 0u32);
+    let compare_target = use_state(|| CompareTarget::Text);
 
     // Handle dropped file
     {
@@ -148,6 +375,8 @@ pub fn text_diff(props: &Props) -> Html {
         let old_file_name = old_file_name.clone();
         let new_text = new_text.clone();
         let new_file_name = new_file_name.clone();
+        let old_file_path = old_file_path.clone();
+        let new_file_path = new_file_path.clone();
         let on_file_processed = props.on_file_processed.clone();
         let error_message = error_message.clone();
 
@@ -157,6 +386,8 @@ pub fn text_diff(props: &Props) -> Html {
                 let old_file_name = old_file_name.clone();
                 let new_text = new_text.clone();
                 let new_file_name = new_file_name.clone();
+                let old_file_path = old_file_path.clone();
+                let new_file_path = new_file_path.clone();
                 let on_file_processed = on_file_processed.clone();
                 let error_message = error_message.clone();
 
@@ -168,14 +399,18 @@ pub fn text_diff(props: &Props) -> Html {
                         if (*old_text).is_empty() {
                             old_text.set(file_info.content);
                             old_file_name.set(Some(file_info.name));
+                            old_file_path.set(Some(file_info.path));
                         } else if (*new_text).is_empty() {
                             new_text.set(file_info.content);
                             new_file_name.set(Some(file_info.name));
+                            new_file_path.set(Some(file_info.path));
                         } else {
                             old_text.set((*new_text).clone());
                             old_file_name.set((*new_file_name).clone());
+                            old_file_path.set((*new_file_path).clone());
                             new_text.set(file_info.content);
                             new_file_name.set(Some(file_info.name));
+                            new_file_path.set(Some(file_info.path));
                         }
                         error_message.set(None);
                     } else {
@@ -194,19 +429,59 @@ pub fn text_diff(props: &Props) -> Html {
         let diff_result = diff_result.clone();
         let is_comparing = is_comparing.clone();
         let diff_mode = diff_mode.clone();
+        let diff_options = diff_options.clone();
         let error_message = error_message.clone();
         let history_refresh = history_refresh.clone();
+        let old_file_path = old_file_path.clone();
+        let new_file_path = new_file_path.clone();
 
         Callback::from(move |_| {
             if (*old_text).is_empty() && (*new_text).is_empty() {
                 return;
             }
 
+            // Both sides are untouched files: let the backend read them directly
+            if let (Some(old_path), Some(new_path)) =
+                ((*old_file_path).clone(), (*new_file_path).clone())
+            {
+                let diff_result = diff_result.clone();
+                let is_comparing = is_comparing.clone();
+                let error_message = error_message.clone();
+                let mode = (*diff_mode).clone();
+                let options = (*diff_options).clone();
+                is_comparing.set(true);
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&ComputeFileDiffArgs {
+                        old_path,
+                        new_path,
+                        mode,
+                        options,
+                    })
+                    .unwrap();
+                    match try_invoke("compute_file_diff_cmd", args).await {
+                        Ok(result) => match serde_wasm_bindgen::from_value::<DiffResult>(result) {
+                            Ok(res) => {
+                                diff_result.set(Some(res));
+                                error_message.set(None);
+                            }
+                            Err(_) => error_message.set(Some("Failed to compute diff".to_string())),
+                        },
+                        Err(e) => error_message.set(Some(
+                            e.as_string()
+                                .unwrap_or_else(|| "Failed to compute diff".to_string()),
+                        )),
+                    }
+                    is_comparing.set(false);
+                });
+                return;
+            }
+
             let old_text_val = (*old_text).clone();
             let new_text_val = (*new_text).clone();
             let diff_result = diff_result.clone();
             let is_comparing = is_comparing.clone();
             let mode = (*diff_mode).clone();
+            let options = (*diff_options).clone();
             let error_message = error_message.clone();
             let history_refresh = history_refresh.clone();
 
@@ -219,6 +494,7 @@ pub fn text_diff(props: &Props) -> Html {
                     old_text: old_text_val,
                     new_text: new_text_val,
                     mode,
+                    options,
                 })
                 .unwrap();
 
@@ -250,17 +526,21 @@ pub fn text_diff(props: &Props) -> Html {
 
     let on_old_text_change = {
         let old_text = old_text.clone();
+        let old_file_path = old_file_path.clone();
         Callback::from(move |e: InputEvent| {
             let textarea: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
             old_text.set(textarea.value());
+            old_file_path.set(None);
         })
     };
 
     let on_new_text_change = {
         let new_text = new_text.clone();
+        let new_file_path = new_file_path.clone();
         Callback::from(move |e: InputEvent| {
             let textarea: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
             new_text.set(textarea.value());
+            new_file_path.set(None);
         })
     };
 
@@ -271,6 +551,38 @@ pub fn text_diff(props: &Props) -> Html {
         })
     };
 
+    let on_whitespace_mode_change = {
+        let diff_options = diff_options.clone();
+        Callback::from(move |mode: WhitespaceMode| {
+            diff_options.set(DiffOptions {
+                ignore_whitespace: mode,
+                ..(*diff_options).clone()
+            });
+        })
+    };
+
+    let on_toggle_ignore_case = {
+        let diff_options = diff_options.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            diff_options.set(DiffOptions {
+                ignore_case: input.checked(),
+                ..(*diff_options).clone()
+            });
+        })
+    };
+
+    let on_toggle_ignore_blank_lines = {
+        let diff_options = diff_options.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            diff_options.set(DiffOptions {
+                ignore_blank_lines: input.checked(),
+                ..(*diff_options).clone()
+            });
+        })
+    };
+
     let on_view_mode_change = {
         let view_mode = view_mode.clone();
         Callback::from(move |mode: ViewMode| {
@@ -279,17 +591,35 @@ pub fn text_diff(props: &Props) -> Html {
     };
 
     let on_copy_unified = {
-        let diff_result = diff_result.clone();
+        let old_text = old_text.clone();
+        let new_text = new_text.clone();
+        let diff_mode = diff_mode.clone();
+        let context_lines = context_lines.clone();
         let copied = copied.clone();
 
         Callback::from(move |_| {
-            if let Some(ref result) = *diff_result {
-                if let Some(win) = window() {
-                    let clipboard = win.navigator().clipboard();
-                    let text = result.unified_diff.clone();
-                    let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                let args = ExportDiffArgs {
+                    old_text: (*old_text).clone(),
+                    new_text: (*new_text).clone(),
+                    mode: (*diff_mode).clone(),
+                    format: DiffExportFormat::UnifiedPatch,
+                    output_path: None,
+                    context_lines: Some(*context_lines),
+                };
+                let copied = copied.clone();
 
-                    spawn_local(async move {
+                spawn_local(async move {
+                    let result = invoke(
+                        "export_diff_cmd",
+                        serde_wasm_bindgen::to_value(&args).unwrap(),
+                    )
+                    .await;
+                    if let Some(text) = serde_wasm_bindgen::from_value::<DiffExportResult>(result)
+                        .ok()
+                        .and_then(|r| r.content)
+                    {
                         let _ =
                             wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text)).await;
                         copied.set(true);
@@ -298,8 +628,67 @@ pub fn text_diff(props: &Props) -> Html {
                             copied.set(false);
                         })
                         .forget();
-                    });
+                    }
+                });
+            }
+        })
+    };
+
+    let on_export = {
+        let old_text = old_text.clone();
+        let new_text = new_text.clone();
+        let diff_mode = diff_mode.clone();
+        let context_lines = context_lines.clone();
+        let error_message = error_message.clone();
+
+        Callback::from(move |format: DiffExportFormat| {
+            let (name, extensions, default_name) = match format {
+                DiffExportFormat::UnifiedPatch => ("Patch", vec!["patch", "diff"], "changes.patch"),
+                DiffExportFormat::Html => ("HTML", vec!["html"], "diff.html"),
+            };
+            let options = SaveDialogOptions {
+                filters: vec![FileFilter {
+                    name: name.to_string(),
+                    extensions: extensions.into_iter().map(String::from).collect(),
+                }],
+                default_path: Some(default_name.to_string()),
+            };
+            let mut args = ExportDiffArgs {
+                old_text: (*old_text).clone(),
+                new_text: (*new_text).clone(),
+                mode: (*diff_mode).clone(),
+                format,
+                output_path: None,
+                context_lines: Some(*context_lines),
+            };
+            let error_message = error_message.clone();
+
+            spawn_local(async move {
+                let save_result = save(serde_wasm_bindgen::to_value(&options).unwrap()).await;
+                let Some(path) = save_result.as_string() else {
+                    return;
+                };
+                args.output_path = Some(path);
+                let result = invoke(
+                    "export_diff_cmd",
+                    serde_wasm_bindgen::to_value(&args).unwrap(),
+                )
+                .await;
+                match serde_wasm_bindgen::from_value::<DiffExportResult>(result) {
+                    Ok(res) if res.success => error_message.set(None),
+                    Ok(res) => error_message.set(res.error),
+                    Err(_) => error_message.set(Some("Failed to export diff".to_string())),
                 }
+            });
+        })
+    };
+
+    let on_context_lines_change = {
+        let context_lines = context_lines.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<usize>() {
+                context_lines.set(value.min(100));
             }
         })
     };
@@ -309,6 +698,8 @@ pub fn text_diff(props: &Props) -> Html {
         let new_text = new_text.clone();
         let old_file_name = old_file_name.clone();
         let new_file_name = new_file_name.clone();
+        let old_file_path = old_file_path.clone();
+        let new_file_path = new_file_path.clone();
         let diff_result = diff_result.clone();
         let error_message = error_message.clone();
 
@@ -317,6 +708,8 @@ pub fn text_diff(props: &Props) -> Html {
             new_text.set(String::new());
             old_file_name.set(None);
             new_file_name.set(None);
+            old_file_path.set(None);
+            new_file_path.set(None);
             diff_result.set(None);
             error_message.set(None);
         })
@@ -327,14 +720,19 @@ pub fn text_diff(props: &Props) -> Html {
         let new_text = new_text.clone();
         let old_file_name = old_file_name.clone();
         let new_file_name = new_file_name.clone();
+        let old_file_path = old_file_path.clone();
+        let new_file_path = new_file_path.clone();
 
         Callback::from(move |_| {
             let temp_text = (*old_text).clone();
             let temp_name = (*old_file_name).clone();
+            let temp_path = (*old_file_path).clone();
             old_text.set((*new_text).clone());
             old_file_name.set((*new_file_name).clone());
+            old_file_path.set((*new_file_path).clone());
             new_text.set(temp_text);
             new_file_name.set(temp_name);
+            new_file_path.set(temp_path);
         })
     };
 
@@ -351,6 +749,80 @@ pub fn text_diff(props: &Props) -> Html {
         })
     };
 
+    // A file picked from the folder comparison is loaded into both sides and diffed
+    let on_open_pair = {
+        let old_text = old_text.clone();
+        let new_text = new_text.clone();
+        let old_file_name = old_file_name.clone();
+        let new_file_name = new_file_name.clone();
+        let old_file_path = old_file_path.clone();
+        let new_file_path = new_file_path.clone();
+        let diff_result = diff_result.clone();
+        let diff_mode = diff_mode.clone();
+        let diff_options = diff_options.clone();
+        let error_message = error_message.clone();
+
+        Callback::from(move |(old_path, new_path): (String, String)| {
+            let old_text = old_text.clone();
+            let new_text = new_text.clone();
+            let old_file_name = old_file_name.clone();
+            let new_file_name = new_file_name.clone();
+            let old_file_path = old_file_path.clone();
+            let new_file_path = new_file_path.clone();
+            let diff_result = diff_result.clone();
+            let error_message = error_message.clone();
+            let mode = (*diff_mode).clone();
+            let options = (*diff_options).clone();
+
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&ComputeFileDiffArgs {
+                    old_path: old_path.clone(),
+                    new_path: new_path.clone(),
+                    mode,
+                    options,
+                })
+                .unwrap();
+                let result = match try_invoke("compute_file_diff_cmd", args).await {
+                    Ok(result) => serde_wasm_bindgen::from_value::<DiffResult>(result)
+                        .map_err(|_| "Failed to compute diff".to_string()),
+                    Err(e) => Err(e
+                        .as_string()
+                        .unwrap_or_else(|| "Failed to compute diff".to_string())),
+                };
+                let res = match result {
+                    Ok(res) => res,
+                    Err(e) => {
+                        error_message.set(Some(e));
+                        return;
+                    }
+                };
+
+                // Binary files have no text to show; the result only says whether they differ
+                if !res.is_binary {
+                    for (path, text, name, file_path) in [
+                        (old_path, &old_text, &old_file_name, &old_file_path),
+                        (new_path, &new_text, &new_file_name, &new_file_path),
+                    ] {
+                        let args = serde_wasm_bindgen::to_value(&GetFileInfoArgs { path }).unwrap();
+                        let info = invoke("get_text_file_info_cmd", args).await;
+                        if let Ok(info) = serde_wasm_bindgen::from_value::<FileInfo>(info) {
+                            text.set(info.content);
+                            name.set(Some(info.name));
+                            file_path.set(Some(info.path));
+                        }
+                    }
+                }
+                diff_result.set(Some(res));
+                error_message.set(None);
+            });
+        })
+    };
+
+    let on_compare_target_change = {
+        let compare_target = compare_target.clone();
+        Callback::from(move |target: CompareTarget| compare_target.set(target))
+    };
+
     html! {
         <div class="text-diff-container">
             <div class="section diff-header">
@@ -363,6 +835,26 @@ pub fn text_diff(props: &Props) -> Html {
                     />
                 </div>
                 <div class="diff-controls">
+                    <div class="control-group">
+                        <label class="control-label">{"Compare"}</label>
+                        <div class="mode-buttons">
+                            {
+                                [CompareTarget::Text, CompareTarget::Folders].iter().map(|target| {
+                                    let is_active = *compare_target == *target;
+                                    let on_click = on_compare_target_change.clone();
+                                    let t = *target;
+                                    html! {
+                                        <button
+                                            class={classes!("mode-btn", is_active.then_some("active"))}
+                                            onclick={Callback::from(move |_| on_click.emit(t))}
+                                        >
+                                            {target.label()}
+                                        </button>
+                                    }
+                                }).collect::<Html>()
+                            }
+                        </div>
+                    </div>
                     <div class="control-group">
                         <label class="control-label">{"Diff Mode"}</label>
                         <div class="mode-buttons">
@@ -383,6 +875,39 @@ pub fn text_diff(props: &Props) -> Html {
                             }
                         </div>
                     </div>
+                    <div class="control-group">
+                        <label class="control-label">{"Ignore Whitespace"}</label>
+                        <div class="mode-buttons">
+                            {
+                                [WhitespaceMode::None, WhitespaceMode::Trailing, WhitespaceMode::Leading, WhitespaceMode::All].iter().map(|mode| {
+                                    let is_active = diff_options.ignore_whitespace == *mode;
+                                    let on_click = on_whitespace_mode_change.clone();
+                                    let m = *mode;
+                                    html! {
+                                        <button
+                                            class={classes!("mode-btn", is_active.then_some("active"))}
+                                            onclick={Callback::from(move |_| on_click.emit(m))}
+                                        >
+                                            {mode.label()}
+                                        </button>
+                                    }
+                                }).collect::<Html>()
+                            }
+                        </div>
+                    </div>
+                    <div class="control-group">
+                        <label class="control-label">{"Ignore"}</label>
+                        <div class="diff-ignore-options">
+                            <label>
+                                <input type="checkbox" checked={diff_options.ignore_case} onchange={on_toggle_ignore_case} />
+                                {"Case"}
+                            </label>
+                            <label>
+                                <input type="checkbox" checked={diff_options.ignore_blank_lines} onchange={on_toggle_ignore_blank_lines} />
+                                {"Blank lines"}
+                            </label>
+                        </div>
+                    </div>
                     <div class="control-group">
                         <label class="control-label">{"View Mode"}</label>
                         <div class="mode-buttons">
@@ -407,66 +932,73 @@ pub fn text_diff(props: &Props) -> Html {
                 </div>
             </div>
 
-            <div class="section input-section">
-                <div class="input-panels">
-                    <div class="input-panel">
-                        <div class="panel-header">
-                            <span class="panel-title">
-                                {"Original"}
-                                if let Some(ref name) = *old_file_name {
-                                    <span class="file-name">{format!(" ({})", name)}</span>
-                                }
-                            </span>
-                            <span class="line-count">{format!("{} lines", (*old_text).lines().count())}</span>
+            // Kept mounted so the folder results survive switching back to text
+            <div class={classes!("folder-compare-panel", (*compare_target != CompareTarget::Folders).then_some("hidden"))}>
+                <FolderCompare on_open_file={on_open_pair} />
+            </div>
+
+            if *compare_target == CompareTarget::Text {
+                <div class="section input-section">
+                    <div class="input-panels">
+                        <div class="input-panel">
+                            <div class="panel-header">
+                                <span class="panel-title">
+                                    {"Original"}
+                                    if let Some(ref name) = *old_file_name {
+                                        <span class="file-name">{format!(" ({})", name)}</span>
+                                    }
+                                </span>
+                                <span class="line-count">{format!("{} lines", (*old_text).lines().count())}</span>
+                            </div>
+                            <textarea
+                                class="diff-textarea"
+                                placeholder="Paste original text here or drop a file..."
+                                value={(*old_text).clone()}
+                                oninput={on_old_text_change}
+                            />
+                        </div>
+                        <div class="swap-button-container">
+                            <button class="swap-btn" onclick={on_swap} title="Swap texts">
+                                {"<->"}
+                            </button>
+                        </div>
+                        <div class="input-panel">
+                            <div class="panel-header">
+                                <span class="panel-title">
+                                    {"Modified"}
+                                    if let Some(ref name) = *new_file_name {
+                                        <span class="file-name">{format!(" ({})", name)}</span>
+                                    }
+                                </span>
+                                <span class="line-count">{format!("{} lines", (*new_text).lines().count())}</span>
+                            </div>
+                            <textarea
+                                class="diff-textarea"
+                                placeholder="Paste modified text here or drop a file..."
+                                value={(*new_text).clone()}
+                                oninput={on_new_text_change}
+                            />
                         </div>
-                        <textarea
-                            class="diff-textarea"
-                            placeholder="Paste original text here or drop a file..."
-                            value={(*old_text).clone()}
-                            oninput={on_old_text_change}
-                        />
                     </div>
-                    <div class="swap-button-container">
-                        <button class="swap-btn" onclick={on_swap} title="Swap texts">
-                            {"<->"}
+                    <div class="action-buttons">
+                        <button
+                            class="primary-btn"
+                            onclick={on_compare.clone()}
+                            disabled={*is_comparing || ((*old_text).is_empty() && (*new_text).is_empty())}
+                        >
+                            if *is_comparing {
+                                <span class="spinner"></span>
+                                <span>{"Comparing..."}</span>
+                            } else {
+                                <span>{"Compare"}</span>
+                            }
+                        </button>
+                        <button class="secondary-btn" onclick={on_clear}>
+                            {"Clear All"}
                         </button>
-                    </div>
-                    <div class="input-panel">
-                        <div class="panel-header">
-                            <span class="panel-title">
-                                {"Modified"}
-                                if let Some(ref name) = *new_file_name {
-                                    <span class="file-name">{format!(" ({})", name)}</span>
-                                }
-                            </span>
-                            <span class="line-count">{format!("{} lines", (*new_text).lines().count())}</span>
-                        </div>
-                        <textarea
-                            class="diff-textarea"
-                            placeholder="Paste modified text here or drop a file..."
-                            value={(*new_text).clone()}
-                            oninput={on_new_text_change}
-                        />
                     </div>
                 </div>
-                <div class="action-buttons">
-                    <button
-                        class="primary-btn"
-                        onclick={on_compare.clone()}
-                        disabled={*is_comparing || ((*old_text).is_empty() && (*new_text).is_empty())}
-                    >
-                        if *is_comparing {
-                            <span class="spinner"></span>
-                            <span>{"Comparing..."}</span>
-                        } else {
-                            <span>{"Compare"}</span>
-                        }
-                    </button>
-                    <button class="secondary-btn" onclick={on_clear}>
-                        {"Clear All"}
-                    </button>
-                </div>
-            </div>
+            }
 
             if let Some(ref error) = *error_message {
                 <div class="section error-section">
@@ -474,7 +1006,18 @@ pub fn text_diff(props: &Props) -> Html {
                 </div>
             }
 
-            if let Some(ref result) = *diff_result {
+            if let Some(result) = diff_result.as_ref().filter(|r| r.is_binary) {
+                <div class="section result-section">
+                    <h3>{"// DIFF RESULT"}</h3>
+                    <p class="diff-binary-notice">
+                        if result.unified_diff.is_empty() {
+                            {"Binary files are identical"}
+                        } else {
+                            {"Binary files differ (contents cannot be shown as text)"}
+                        }
+                    </p>
+                </div>
+            } else if let Some(ref result) = *diff_result {
                 <div class="section stats-section">
                     <h3>{"// DIFF STATISTICS"}</h3>
                     <div class="stats-grid">
@@ -495,21 +1038,57 @@ pub fn text_diff(props: &Props) -> Html {
                             <span class="stat-label">{"Lines"}</span>
                         </div>
                     </div>
+                    if result.stats.ignored_changes > 0 {
+                        <p class="diff-ignored-notice">
+                            {format!(
+                                "Hiding {} {} with only ignored changes (shown as unchanged)",
+                                result.stats.ignored_changes,
+                                if result.stats.ignored_changes == 1 { "line" } else { "lines" },
+                            )}
+                        </p>
+                    }
                 </div>
 
                 <div class="section result-section">
                     <div class="result-header">
                         <h3>{"// DIFF RESULT"}</h3>
-                        <button
-                            class={classes!("copy-btn", (*copied).then_some("copied"))}
-                            onclick={on_copy_unified}
-                        >
-                            if *copied {
-                                {"Copied!"}
-                            } else {
-                                {"Copy Unified Diff"}
-                            }
-                        </button>
+                        <div class="diff-export-actions">
+                            <label class="diff-context-lines" title="Context lines for the unified diff">
+                                {"Context"}
+                                <input
+                                    type="number"
+                                    min="0"
+                                    max="100"
+                                    value={context_lines.to_string()}
+                                    onchange={on_context_lines_change}
+                                />
+                            </label>
+                            <button
+                                class={classes!("copy-btn", (*copied).then_some("copied"))}
+                                onclick={on_copy_unified}
+                            >
+                                if *copied {
+                                    {"Copied!"}
+                                } else {
+                                    {"Copy Unified Diff"}
+                                }
+                            </button>
+                            <button
+                                class="copy-btn"
+                                onclick={
+                                    let on_export = on_export.clone();
+                                    Callback::from(move |_| on_export.emit(DiffExportFormat::UnifiedPatch))
+                                }
+                            >
+                                {"Save .patch"}
+                            </button>
+                            <button
+                                class="copy-btn"
+                                onclick={Callback::from(move |_| on_export.emit(DiffExportFormat::Html))}
+                            >
+                                {"Save HTML"}
+                            </button>
+                        </div>
                     </div>
 
                     {
@@ -525,6 +1104,50 @@ pub fn text_diff(props: &Props) -> Html {
     }
 }
 
+/// Row class; lines with only ignored changes are shown dimmed like unchanged ones
+fn line_class(line: &LineDiff) -> &'static str {
+    if line.ignored {
+        return "diff-line equal ignored";
+    }
+    match line.tag.as_str() {
+        "delete" => "diff-line delete",
+        "insert" => "diff-line insert",
+        _ => "diff-line equal",
+    }
+}
+
+/// Line text with the changed parts of a modified line wrapped in highlight spans
+fn render_line_content(line: &LineDiff) -> Html {
+    if line.inline_spans.is_empty() {
+        return html! { <span class="line-content">{&line.content}</span> };
+    }
+
+    let units: Vec<u16> = line.content.encode_utf16().collect();
+    let slice = |start: usize, end: usize| {
+        let end = end.min(units.len());
+        String::from_utf16_lossy(&units[start.min(end)..end])
+    };
+    let mut parts = Vec::new();
+    let mut pos = 0;
+    for span in &line.inline_spans {
+        if span.start > pos {
+            parts.push(html! { {slice(pos, span.start)} });
+        }
+        let class = if span.kind == "insert" {
+            "diff-inline-add"
+        } else {
+            "diff-inline-remove"
+        };
+        parts.push(html! { <span class={class}>{slice(span.start, span.end)}</span> });
+        pos = pos.max(span.end);
+    }
+    if pos < units.len() {
+        parts.push(html! { {slice(pos, units.len())} });
+    }
+
+    html! { <span class="line-content">{ for parts }</span> }
+}
+
 fn render_side_by_side(lines: &[LineDiff]) -> Html {
     let mut old_lines: Vec<&LineDiff> = Vec::new();
     let mut new_lines: Vec<&LineDiff> = Vec::new();
@@ -550,16 +1173,12 @@ fn render_side_by_side(lines: &[LineDiff]) -> Html {
                 <div class="diff-lines">
                     { for (0..max_len).map(|i| {
                         if let Some(line) = old_lines.get(i) {
-                            let class = match line.tag.as_str() {
-                                "delete" => "diff-line delete",
-                                _ => "diff-line equal",
-                            };
                             html! {
-                                <div class={class}>
+                                <div class={line_class(line)}>
                                     <span class="line-number">
                                         {line.line_number_old.map(|n| n.to_string()).unwrap_or_default()}
                                     </span>
-                                    <span class="line-content">{&line.content}</span>
+                                    {render_line_content(line)}
                                 </div>
                             }
                         } else {
@@ -578,16 +1197,16 @@ fn render_side_by_side(lines: &[LineDiff]) -> Html {
                 <div class="diff-lines">
                     { for (0..max_len).map(|i| {
                         if let Some(line) = new_lines.get(i) {
-                            let class = match line.tag.as_str() {
-                                "insert" => "diff-line insert",
-                                _ => "diff-line equal",
-                            };
                             html! {
-                                <div class={class}>
+                                <div class={line_class(line)}>
                                     <span class="line-number">
                                         {line.line_number_new.map(|n| n.to_string()).unwrap_or_default()}
                                     </span>
-                                    <span class="line-content">{&line.content}</span>
+                                    if let Some(ref content) = line.new_content {
+                                        <span class="line-content">{content}</span>
+                                    } else {
+                                        {render_line_content(line)}
+                                    }
                                 </div>
                             }
                         } else {
@@ -610,13 +1229,13 @@ fn render_unified(lines: &[LineDiff]) -> Html {
         <div class="diff-view unified">
             <div class="diff-lines">
                 { for lines.iter().map(|line| {
-                    let (class, prefix) = match line.tag.as_str() {
-                        "delete" => ("diff-line delete", "-"),
-                        "insert" => ("diff-line insert", "+"),
-                        _ => ("diff-line equal", " "),
+                    let prefix = match line.tag.as_str() {
+                        "delete" => "-",
+                        "insert" => "+",
+                        _ => " ",
                     };
                     html! {
-                        <div class={class}>
+                        <div class={line_class(line)}>
                             <span class="line-number old">
                                 {line.line_number_old.map(|n| n.to_string()).unwrap_or_default()}
                             </span>
@@ -624,7 +1243,7 @@ fn render_unified(lines: &[LineDiff]) -> Html {
                                 {line.line_number_new.map(|n| n.to_string()).unwrap_or_default()}
                             </span>
                             <span class="line-prefix">{prefix}</span>
-                            <span class="line-content">{&line.content}</span>
+                            {render_line_content(line)}
                         </div>
                     }
                 })}
@@ -634,7 +1253,10 @@ fn render_unified(lines: &[LineDiff]) -> Html {
 }
 
 fn render_diff_only(lines: &[LineDiff]) -> Html {
-    let diff_lines: Vec<&LineDiff> = lines.iter().filter(|l| l.tag != "equal").collect();
+    let diff_lines: Vec<&LineDiff> = lines
+        .iter()
+        .filter(|l| l.tag != "equal" && !l.ignored)
+        .collect();
 
     if diff_lines.is_empty() {
         return html! {
@@ -650,13 +1272,13 @@ fn render_diff_only(lines: &[LineDiff]) -> Html {
         <div class="diff-view diff-only">
             <div class="diff-lines">
                 { for diff_lines.iter().map(|line| {
-                    let (class, prefix) = match line.tag.as_str() {
-                        "delete" => ("diff-line delete", "-"),
-                        "insert" => ("diff-line insert", "+"),
-                        _ => ("diff-line equal", " "),
+                    let prefix = match line.tag.as_str() {
+                        "delete" => "-",
+                        "insert" => "+",
+                        _ => " ",
                     };
                     html! {
-                        <div class={class}>
+                        <div class={line_class(line)}>
                             <span class="line-number old">
                                 {line.line_number_old.map(|n| n.to_string()).unwrap_or_default()}
                             </span>
@@ -664,7 +1286,7 @@ fn render_diff_only(lines: &[LineDiff]) -> Html {
                                 {line.line_number_new.map(|n| n.to_string()).unwrap_or_default()}
                             </span>
                             <span class="line-prefix">{prefix}</span>
-                            <span class="line-content">{&line.content}</span>
+                            {render_line_content(line)}
                         </div>
                     }
                 })}
@@ -672,3 +1294,264 @@ fn render_diff_only(lines: &[LineDiff]) -> Html {
         </div>
     }
 }
+
+#[derive(Properties, PartialEq)]
+struct FolderCompareProps {
+    /// Called with the paths in A and B of a file present in both
+    on_open_file: Callback<(String, String)>,
+}
+
+fn join_path(dir: &str, relative_path: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches(['/', '\\']), relative_path)
+}
+
+fn format_size(size: Option<u64>) -> String {
+    match size {
+        Some(size) if size >= 1024 * 1024 => format!("{:.1} MB", size as f64 / 1024.0 / 1024.0),
+        Some(size) if size >= 1024 => format!("{:.1} KB", size as f64 / 1024.0),
+        Some(size) => format!("{} B", size),
+        None => "-".to_string(),
+    }
+}
+
+#[function_component(FolderCompare)]
+fn folder_compare(props: &FolderCompareProps) -> Html {
+    let dir_a = use_state(|| Option::<String>::None);
+    let dir_b = use_state(|| Option::<String>::None);
+    let ignore_patterns = use_state(|| ".git, node_modules".to_string());
+    let method = use_state(|| DirCompareMethod::SizeAndTime);
+    let show_identical = use_state(|| false);
+    let result = use_state(|| Option::<DirCompareResult>::None);
+    let progress = use_state(|| Option::<DirCompareProgress>::None);
+    let is_comparing = use_state(|| false);
+    let selected = use_state(|| Option::<String>::None);
+
+    {
+        let progress = progress.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let handler = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+                    if let Ok(event) =
+                        serde_wasm_bindgen::from_value::<DirCompareProgressEvent>(event)
+                    {
+                        progress.set(Some(event.payload));
+                    }
+                });
+                let _ = listen("dir-compare-progress", &handler).await;
+                handler.forget();
+            });
+            || {}
+        });
+    }
+
+    let pick_dir = |target: UseStateHandle<Option<String>>| {
+        Callback::from(move |_| {
+            let target = target.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    directory: true,
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(path) = open(opts).await.as_string() {
+                    target.set(Some(path));
+                }
+            });
+        })
+    };
+
+    let on_ignore_change = {
+        let ignore_patterns = ignore_patterns.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            ignore_patterns.set(input.value());
+        })
+    };
+
+    let on_method_change = {
+        let method = method.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            method.set(if select.value() == "hash" {
+                DirCompareMethod::Hash
+            } else {
+                DirCompareMethod::SizeAndTime
+            });
+        })
+    };
+
+    let on_toggle_identical = {
+        let show_identical = show_identical.clone();
+        Callback::from(move |_| show_identical.set(!*show_identical))
+    };
+
+    let on_compare = {
+        let dir_a = dir_a.clone();
+        let dir_b = dir_b.clone();
+        let ignore_patterns = ignore_patterns.clone();
+        let method = method.clone();
+        let result = result.clone();
+        let progress = progress.clone();
+        let is_comparing = is_comparing.clone();
+        let selected = selected.clone();
+        Callback::from(move |_| {
+            let (Some(a), Some(b)) = ((*dir_a).clone(), (*dir_b).clone()) else {
+                return;
+            };
+            let args = CompareDirectoriesArgs {
+                dir_a: a,
+                dir_b: b,
+                options: DirCompareOptions {
+                    ignore_patterns: ignore_patterns
+                        .split(',')
+                        .map(|p| p.trim().to_string())
+                        .filter(|p| !p.is_empty())
+                        .collect(),
+                    method: *method,
+                },
+            };
+            let result = result.clone();
+            let progress = progress.clone();
+            let is_comparing = is_comparing.clone();
+            is_comparing.set(true);
+            progress.set(None);
+            selected.set(None);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&args).unwrap();
+                let response = invoke("compare_directories_cmd", args).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<DirCompareResult>(response) {
+                    result.set(Some(res));
+                }
+                progress.set(None);
+                is_comparing.set(false);
+            });
+        })
+    };
+
+    let open_entry = {
+        let dir_a = dir_a.clone();
+        let dir_b = dir_b.clone();
+        let selected = selected.clone();
+        let on_open_file = props.on_open_file.clone();
+        move |relative_path: String| {
+            let dir_a = dir_a.clone();
+            let dir_b = dir_b.clone();
+            let selected = selected.clone();
+            let on_open_file = on_open_file.clone();
+            Callback::from(move |_| {
+                if let (Some(a), Some(b)) = ((*dir_a).clone(), (*dir_b).clone()) {
+                    selected.set(Some(relative_path.clone()));
+                    on_open_file
+                        .emit((join_path(&a, &relative_path), join_path(&b, &relative_path)));
+                }
+            })
+        }
+    };
+
+    let dir_label = |dir: &Option<String>| {
+        dir.clone()
+            .unwrap_or_else(|| "Select folder...".to_string())
+    };
+
+    html! {
+        <div class="section folder-compare">
+            <div class="folder-compare-dirs">
+                <button class="secondary-btn folder-compare-dir" onclick={pick_dir(dir_a.clone())}>
+                    <span class="folder-compare-side">{"A"}</span>
+                    <span class="folder-compare-path">{dir_label(&dir_a)}</span>
+                </button>
+                <button class="secondary-btn folder-compare-dir" onclick={pick_dir(dir_b.clone())}>
+                    <span class="folder-compare-side">{"B"}</span>
+                    <span class="folder-compare-path">{dir_label(&dir_b)}</span>
+                </button>
+            </div>
+            <div class="folder-compare-options">
+                <label class="control-label">
+                    {"Ignore (glob, comma separated)"}
+                    <input type="text" value={(*ignore_patterns).clone()} oninput={on_ignore_change} />
+                </label>
+                <label class="control-label">
+                    {"Compare contents by"}
+                    <select onchange={on_method_change}>
+                        <option value="sizeAndTime" selected={*method == DirCompareMethod::SizeAndTime}>
+                            {"Size + modified time"}
+                        </option>
+                        <option value="hash" selected={*method == DirCompareMethod::Hash}>
+                            {"Hash (SHA-256)"}
+                        </option>
+                    </select>
+                </label>
+            </div>
+            <div class="action-buttons">
+                <button
+                    class="primary-btn"
+                    onclick={on_compare}
+                    disabled={*is_comparing || dir_a.is_none() || dir_b.is_none()}
+                >
+                    if *is_comparing {
+                        <span class="spinner"></span>
+                        <span>{"Comparing..."}</span>
+                    } else {
+                        <span>{"Compare Folders"}</span>
+                    }
+                </button>
+                if let Some(p) = (*progress).clone().filter(|_| *is_comparing) {
+                    <span class="folder-compare-progress">
+                        {match p.stage.as_str() {
+                            "scanningA" => format!("Scanning A: {} files", p.processed),
+                            "scanningB" => format!("Scanning B: {} files", p.processed),
+                            _ => format!("Comparing: {} / {}", p.processed, p.total),
+                        }}
+                    </span>
+                }
+            </div>
+
+            if let Some(res) = (*result).clone() {
+                if let Some(error) = res.error.clone() {
+                    <p class="error-message">{error}</p>
+                } else {
+                    <div class="folder-compare-summary">
+                        <span class="folder-status only-a">{format!("Only in A: {}", res.only_in_a)}</span>
+                        <span class="folder-status only-b">{format!("Only in B: {}", res.only_in_b)}</span>
+                        <span class="folder-status modified">{format!("Modified: {}", res.modified)}</span>
+                        <span class="folder-status identical">{format!("Identical: {}", res.identical)}</span>
+                        if res.skipped > 0 {
+                            <span class="folder-compare-skipped">{format!("{} unreadable", res.skipped)}</span>
+                        }
+                        <label class="folder-compare-toggle">
+                            <input type="checkbox" checked={*show_identical} onchange={on_toggle_identical} />
+                            {"Show identical"}
+                        </label>
+                    </div>
+                    <div class="folder-compare-list">
+                        { for res.entries.iter()
+                            .filter(|entry| *show_identical || entry.status != DirEntryStatus::Identical)
+                            .map(|entry| {
+                                let openable = entry.status == DirEntryStatus::Modified;
+                                let is_selected = selected.as_deref() == Some(entry.relative_path.as_str());
+                                html! {
+                                    <div
+                                        class={classes!(
+                                            "folder-compare-row",
+                                            openable.then_some("openable"),
+                                            is_selected.then_some("selected")
+                                        )}
+                                        onclick={openable.then(|| open_entry(entry.relative_path.clone()))}
+                                        title={if openable { "Show the diff of this file" } else { "" }}
+                                    >
+                                        <span class={classes!("folder-status", entry.status.class())}>
+                                            {entry.status.label()}
+                                        </span>
+                                        <span class="folder-compare-file">{&entry.relative_path}</span>
+                                        <span class="folder-compare-size">
+                                            {format!("{} → {}", format_size(entry.size_a), format_size(entry.size_b))}
+                                        </span>
+                                    </div>
+                                }
+                            }) }
+                    </div>
+                }
+            }
+        </div>
+    }
+}
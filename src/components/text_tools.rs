@@ -0,0 +1,542 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum SortMode {
+    Lexical,
+    Natural,
+    Numeric,
+    Length,
+}
+
+impl SortMode {
+    const ALL: [SortMode; 4] = [
+        SortMode::Lexical,
+        SortMode::Natural,
+        SortMode::Numeric,
+        SortMode::Length,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            SortMode::Lexical => "lexical",
+            SortMode::Natural => "natural",
+            SortMode::Numeric => "numeric",
+            SortMode::Length => "length",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+enum LineOperation {
+    Sort { mode: SortMode, descending: bool },
+    Dedup { ignore_case: bool },
+    Reverse,
+    Shuffle { seed: Option<u64> },
+    Trim,
+    RemoveEmpty,
+    AddLineNumbers { start: i64, separator: String },
+    AddPrefix(String),
+    AddSuffix(String),
+    RemovePrefix(String),
+    RemoveSuffix(String),
+}
+
+const OPERATION_KEYS: [&str; 11] = [
+    "sort",
+    "dedup",
+    "reverse",
+    "shuffle",
+    "trim",
+    "remove_empty",
+    "add_line_numbers",
+    "add_prefix",
+    "add_suffix",
+    "remove_prefix",
+    "remove_suffix",
+];
+
+impl LineOperation {
+    fn from_key(key: &str) -> Option<Self> {
+        Some(match key {
+            "sort" => LineOperation::Sort {
+                mode: SortMode::Natural,
+                descending: false,
+            },
+            "dedup" => LineOperation::Dedup { ignore_case: false },
+            "reverse" => LineOperation::Reverse,
+            "shuffle" => LineOperation::Shuffle { seed: None },
+            "trim" => LineOperation::Trim,
+            "remove_empty" => LineOperation::RemoveEmpty,
+            "add_line_numbers" => LineOperation::AddLineNumbers {
+                start: 1,
+                separator: ". ".to_string(),
+            },
+            "add_prefix" => LineOperation::AddPrefix(String::new()),
+            "add_suffix" => LineOperation::AddSuffix(String::new()),
+            "remove_prefix" => LineOperation::RemovePrefix(String::new()),
+            "remove_suffix" => LineOperation::RemoveSuffix(String::new()),
+            _ => return None,
+        })
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            LineOperation::Sort { .. } => "sort",
+            LineOperation::Dedup { .. } => "dedup",
+            LineOperation::Reverse => "reverse",
+            LineOperation::Shuffle { .. } => "shuffle",
+            LineOperation::Trim => "trim",
+            LineOperation::RemoveEmpty => "remove_empty",
+            LineOperation::AddLineNumbers { .. } => "add_line_numbers",
+            LineOperation::AddPrefix(_) => "add_prefix",
+            LineOperation::AddSuffix(_) => "add_suffix",
+            LineOperation::RemovePrefix(_) => "remove_prefix",
+            LineOperation::RemoveSuffix(_) => "remove_suffix",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ProcessLinesArgs {
+    input: String,
+    operations: Vec<LineOperation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct LineProcessResult {
+    output: String,
+    input_line_count: usize,
+    output_line_count: usize,
+    removed_duplicates: Vec<String>,
+    shuffle_seed: Option<u64>,
+}
+
+fn operation_translation_key(key: &str) -> String {
+    format!("text_tools.op_{}", key)
+}
+
+#[function_component(TextTools)]
+pub fn text_tools() -> Html {
+    let (i18n, _) = use_translation();
+    let input = use_state(String::new);
+    let operations = use_state(|| {
+        vec![
+            LineOperation::Trim,
+            LineOperation::RemoveEmpty,
+            LineOperation::Sort {
+                mode: SortMode::Natural,
+                descending: false,
+            },
+        ]
+    });
+    let result = use_state(|| Option::<LineProcessResult>::None);
+    let copied = use_state(|| false);
+
+    // Re-run the pipeline on every edit
+    {
+        let result = result.clone();
+        use_effect_with(
+            ((*input).clone(), (*operations).clone()),
+            move |(input, operations)| {
+                let input = input.clone();
+                let operations = operations.clone();
+                if input.is_empty() {
+                    result.set(None);
+                } else {
+                    spawn_local(async move {
+                        let args =
+                            serde_wasm_bindgen::to_value(&ProcessLinesArgs { input, operations })
+                                .unwrap();
+                        let response = invoke("process_lines_cmd", args).await;
+                        if let Ok(res) =
+                            serde_wasm_bindgen::from_value::<LineProcessResult>(response)
+                        {
+                            result.set(Some(res));
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_add_operation = {
+        let operations = operations.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(operation) = LineOperation::from_key(&select.value()) {
+                let mut list = (*operations).clone();
+                list.push(operation);
+                operations.set(list);
+            }
+            select.set_value("");
+        })
+    };
+
+    let update_operation = {
+        let operations = operations.clone();
+        Callback::from(move |(index, operation): (usize, LineOperation)| {
+            let mut list = (*operations).clone();
+            list[index] = operation;
+            operations.set(list);
+        })
+    };
+
+    let on_clear_operations = {
+        let operations = operations.clone();
+        Callback::from(move |_| operations.set(Vec::new()))
+    };
+
+    let on_use_output = {
+        let input = input.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            if let Some(res) = &*result {
+                input.set(res.output.clone());
+            }
+        })
+    };
+
+    let on_copy = {
+        let copied = copied.clone();
+        let result = result.clone();
+        Callback::from(move |_| {
+            let Some(res) = (*result).clone() else {
+                return;
+            };
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&res.output))
+                        .await;
+                    copied.set(true);
+
+                    let copied_reset = copied.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copied_reset.set(false);
+                    })
+                    .forget();
+                });
+            }
+        })
+    };
+
+    let render_params = |index: usize, operation: &LineOperation| -> Html {
+        let update = update_operation.clone();
+        match operation.clone() {
+            LineOperation::Sort { mode, descending } => {
+                let on_mode = {
+                    let update = update.clone();
+                    Callback::from(move |e: Event| {
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let mode = SortMode::ALL
+                            .into_iter()
+                            .find(|m| m.key() == select.value())
+                            .unwrap_or(SortMode::Lexical);
+                        update.emit((index, LineOperation::Sort { mode, descending }));
+                    })
+                };
+                let on_descending = Callback::from(move |e: Event| {
+                    let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    update.emit((
+                        index,
+                        LineOperation::Sort {
+                            mode,
+                            descending: checkbox.checked(),
+                        },
+                    ));
+                });
+                html! {
+                    <>
+                        <select class="form-select" onchange={on_mode}>
+                            { for SortMode::ALL.iter().map(|m| html! {
+                                <option value={m.key()} selected={*m == mode}>
+                                    {i18n.t(&format!("text_tools.sort_{}", m.key()))}
+                                </option>
+                            }) }
+                        </select>
+                        <label class="checkbox-label">
+                            <input type="checkbox" checked={descending} onchange={on_descending} />
+                            {i18n.t("text_tools.descending")}
+                        </label>
+                    </>
+                }
+            }
+            LineOperation::Dedup { ignore_case } => {
+                let onchange = Callback::from(move |e: Event| {
+                    let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    update.emit((
+                        index,
+                        LineOperation::Dedup {
+                            ignore_case: checkbox.checked(),
+                        },
+                    ));
+                });
+                html! {
+                    <label class="checkbox-label">
+                        <input type="checkbox" checked={ignore_case} {onchange} />
+                        {i18n.t("text_tools.ignore_case")}
+                    </label>
+                }
+            }
+            LineOperation::Shuffle { seed } => {
+                let oninput = Callback::from(move |e: InputEvent| {
+                    let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    let seed = target.value().trim().parse().ok();
+                    update.emit((index, LineOperation::Shuffle { seed }));
+                });
+                html! {
+                    <input
+                        type="number"
+                        class="form-input text-tools-param"
+                        min="0"
+                        value={seed.map(|s| s.to_string()).unwrap_or_default()}
+                        placeholder={i18n.t("text_tools.seed_placeholder")}
+                        {oninput}
+                    />
+                }
+            }
+            LineOperation::AddLineNumbers { start, separator } => {
+                let on_start = {
+                    let update = update.clone();
+                    let separator = separator.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        let start = target.value().trim().parse().unwrap_or(1);
+                        update.emit((
+                            index,
+                            LineOperation::AddLineNumbers {
+                                start,
+                                separator: separator.clone(),
+                            },
+                        ));
+                    })
+                };
+                let on_separator = Callback::from(move |e: InputEvent| {
+                    let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    update.emit((
+                        index,
+                        LineOperation::AddLineNumbers {
+                            start,
+                            separator: target.value(),
+                        },
+                    ));
+                });
+                html! {
+                    <>
+                        <label class="text-tools-param-label">{i18n.t("text_tools.start")}</label>
+                        <input
+                            type="number"
+                            class="form-input text-tools-param"
+                            value={start.to_string()}
+                            oninput={on_start}
+                        />
+                        <label class="text-tools-param-label">{i18n.t("text_tools.separator")}</label>
+                        <input
+                            type="text"
+                            class="form-input text-tools-param"
+                            value={separator}
+                            oninput={on_separator}
+                        />
+                    </>
+                }
+            }
+            LineOperation::AddPrefix(text)
+            | LineOperation::AddSuffix(text)
+            | LineOperation::RemovePrefix(text)
+            | LineOperation::RemoveSuffix(text) => {
+                let key = operation.key();
+                let oninput = Callback::from(move |e: InputEvent| {
+                    let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    let value = target.value();
+                    let operation = match key {
+                        "add_prefix" => LineOperation::AddPrefix(value),
+                        "add_suffix" => LineOperation::AddSuffix(value),
+                        "remove_prefix" => LineOperation::RemovePrefix(value),
+                        _ => LineOperation::RemoveSuffix(value),
+                    };
+                    update.emit((index, operation));
+                });
+                html! {
+                    <input
+                        type="text"
+                        class="form-input text-tools-param"
+                        value={text}
+                        placeholder={i18n.t("text_tools.text_placeholder")}
+                        {oninput}
+                    />
+                }
+            }
+            LineOperation::Reverse | LineOperation::Trim | LineOperation::RemoveEmpty => html! {},
+        }
+    };
+
+    html! {
+        <div class="text-tools">
+            <div class="section text-tools-pipeline">
+                <div class="text-tools-pipeline-header">
+                    <h3>{i18n.t("text_tools.pipeline")}</h3>
+                    <div class="text-tools-pipeline-actions">
+                        <select class="form-select" onchange={on_add_operation}>
+                            <option value="" selected=true>{i18n.t("text_tools.add_operation")}</option>
+                            { for OPERATION_KEYS.iter().map(|key| html! {
+                                <option value={*key}>{i18n.t(&operation_translation_key(key))}</option>
+                            }) }
+                        </select>
+                        <button class="secondary-btn" onclick={on_clear_operations}>
+                            {i18n.t("common.clear")}
+                        </button>
+                    </div>
+                </div>
+
+                if (*operations).is_empty() {
+                    <div class="text-tools-empty">{i18n.t("text_tools.no_operations")}</div>
+                } else {
+                    <ol class="text-tools-steps">
+                        { for (*operations).iter().enumerate().map(|(index, operation)| {
+                            let last = (*operations).len() - 1;
+                            let move_step = |to: usize| {
+                                let operations = operations.clone();
+                                Callback::from(move |_| {
+                                    let mut list = (*operations).clone();
+                                    list.swap(index, to);
+                                    operations.set(list);
+                                })
+                            };
+                            let on_remove = {
+                                let operations = operations.clone();
+                                Callback::from(move |_| {
+                                    let mut list = (*operations).clone();
+                                    list.remove(index);
+                                    operations.set(list);
+                                })
+                            };
+                            html! {
+                                <li class="text-tools-step">
+                                    <span class="text-tools-step-name">
+                                        {i18n.t(&operation_translation_key(operation.key()))}
+                                    </span>
+                                    <div class="text-tools-step-params">
+                                        {render_params(index, operation)}
+                                    </div>
+                                    <div class="text-tools-step-actions">
+                                        <button
+                                            class="move-btn"
+                                            disabled={index == 0}
+                                            onclick={move_step(index.saturating_sub(1))}
+                                            title={i18n.t("text_tools.move_up")}
+                                        >
+                                            {"↑"}
+                                        </button>
+                                        <button
+                                            class="move-btn"
+                                            disabled={index == last}
+                                            onclick={move_step((index + 1).min(last))}
+                                            title={i18n.t("text_tools.move_down")}
+                                        >
+                                            {"↓"}
+                                        </button>
+                                        <button
+                                            class="move-btn"
+                                            onclick={on_remove}
+                                            title={i18n.t("text_tools.remove")}
+                                        >
+                                            {"×"}
+                                        </button>
+                                    </div>
+                                </li>
+                            }
+                        }) }
+                    </ol>
+                }
+            </div>
+
+            <div class="text-tools-panes">
+                <div class="section text-tools-pane">
+                    <div class="text-tools-pane-header">
+                        <h3>{i18n.t("text_tools.input")}</h3>
+                        if let Some(res) = &*result {
+                            <span class="text-tools-count">
+                                {format!("{} {}", res.input_line_count, i18n.t("text_tools.lines"))}
+                            </span>
+                        }
+                    </div>
+                    <textarea
+                        class="form-textarea text-tools-textarea"
+                        value={(*input).clone()}
+                        oninput={on_input_change}
+                        placeholder={i18n.t("text_tools.input_placeholder")}
+                        spellcheck="false"
+                    />
+                </div>
+
+                <div class="section text-tools-pane">
+                    <div class="text-tools-pane-header">
+                        <h3>{i18n.t("text_tools.output")}</h3>
+                        if let Some(res) = &*result {
+                            <span class="text-tools-count">
+                                {format!("{} {}", res.output_line_count, i18n.t("text_tools.lines"))}
+                            </span>
+                            <button class="secondary-btn" onclick={on_use_output}>
+                                {i18n.t("text_tools.use_as_input")}
+                            </button>
+                            <button class={classes!("copy-btn", (*copied).then_some("copied"))} onclick={on_copy}>
+                                if *copied {
+                                    {"✓"}
+                                } else {
+                                    {i18n.t("common.copy")}
+                                }
+                            </button>
+                        }
+                    </div>
+                    <textarea
+                        class="form-textarea text-tools-textarea"
+                        value={(*result).as_ref().map(|res| res.output.clone()).unwrap_or_default()}
+                        readonly=true
+                        spellcheck="false"
+                    />
+                </div>
+            </div>
+
+            if let Some(res) = &*result {
+                if let Some(seed) = res.shuffle_seed {
+                    <div class="text-tools-seed">
+                        {format!("{}: ", i18n.t("text_tools.shuffle_seed"))}<code>{seed}</code>
+                    </div>
+                }
+                if !res.removed_duplicates.is_empty() {
+                    <details class="section text-tools-duplicates">
+                        <summary>
+                            {format!("{} ({})", i18n.t("text_tools.removed_duplicates"), res.removed_duplicates.len())}
+                        </summary>
+                        <ul>
+                            { for res.removed_duplicates.iter().map(|line| html! {
+                                <li><code>{line}</code></li>
+                            }) }
+                        </ul>
+                    </details>
+                }
+            }
+        </div>
+    }
+}
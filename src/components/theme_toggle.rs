@@ -0,0 +1,53 @@
+use crate::components::settings::{SettingsContext, ThemeMode};
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+#[function_component(ThemeToggle)]
+pub fn theme_toggle() -> Html {
+    let (i18n, _) = use_translation();
+    let Some(ctx) = use_context::<SettingsContext>() else {
+        return html! {};
+    };
+    let mode = ctx.settings.theme;
+
+    let on_click = {
+        let ctx = ctx.clone();
+        Callback::from(move |_| {
+            let mut next = ctx.settings.clone();
+            next.theme = ctx.settings.theme.next();
+            ctx.update.emit(next);
+        })
+    };
+
+    let label = i18n.t(&format!("settings.theme_{}", mode.as_str()));
+    let icon = match mode {
+        ThemeMode::System => html! {
+            <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <rect x="2" y="3" width="20" height="14" rx="2"/>
+                <line x1="8" y1="21" x2="16" y2="21"/>
+                <line x1="12" y1="17" x2="12" y2="21"/>
+            </svg>
+        },
+        ThemeMode::Light => html! {
+            <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <circle cx="12" cy="12" r="4"/>
+                <path d="M12 2v2M12 20v2M4.93 4.93l1.41 1.41M17.66 17.66l1.41 1.41M2 12h2M20 12h2M4.93 19.07l1.41-1.41M17.66 6.34l1.41-1.41"/>
+            </svg>
+        },
+        ThemeMode::Dark => html! {
+            <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5">
+                <path d="M21 12.79A9 9 0 1 1 11.21 3 7 7 0 0 0 21 12.79z"/>
+            </svg>
+        },
+    };
+
+    html! {
+        <button
+            class="theme-toggle"
+            onclick={on_click}
+            title={format!("{}: {}", i18n.t("settings.theme"), label)}
+        >
+            {icon}
+        </button>
+    }
+}
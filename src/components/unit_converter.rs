@@ -22,6 +22,11 @@ pub enum UnitCategory {
     Time,
     Area,
     Volume,
+    Currency,
+    Speed,
+    Pressure,
+    Energy,
+    FuelEconomy,
 }
 
 impl UnitCategory {
@@ -34,6 +39,11 @@ impl UnitCategory {
             UnitCategory::Time => "unit_converter.category_time",
             UnitCategory::Area => "unit_converter.category_area",
             UnitCategory::Volume => "unit_converter.category_volume",
+            UnitCategory::Currency => "unit_converter.category_currency",
+            UnitCategory::Speed => "unit_converter.category_speed",
+            UnitCategory::Pressure => "unit_converter.category_pressure",
+            UnitCategory::Energy => "unit_converter.category_energy",
+            UnitCategory::FuelEconomy => "unit_converter.category_fuel_economy",
         }
     }
 
@@ -46,6 +56,11 @@ impl UnitCategory {
             UnitCategory::Time => "⏱️",
             UnitCategory::Area => "📐",
             UnitCategory::Volume => "🧊",
+            UnitCategory::Currency => "💱",
+            UnitCategory::Speed => "🚀",
+            UnitCategory::Pressure => "🎈",
+            UnitCategory::Energy => "⚡",
+            UnitCategory::FuelEconomy => "⛽",
         }
     }
 
@@ -58,6 +73,11 @@ impl UnitCategory {
             UnitCategory::Time,
             UnitCategory::Area,
             UnitCategory::Volume,
+            UnitCategory::Currency,
+            UnitCategory::Speed,
+            UnitCategory::Pressure,
+            UnitCategory::Energy,
+            UnitCategory::FuelEconomy,
         ]
     }
 }
@@ -416,6 +436,243 @@ impl VolumeUnit {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SpeedUnit {
+    KilometerPerHour,
+    MeterPerSecond,
+    MilePerHour,
+    Knot,
+}
+
+impl SpeedUnit {
+    #[allow(dead_code)]
+    fn translation_key(&self) -> &'static str {
+        match self {
+            SpeedUnit::KilometerPerHour => "unit_converter.speed_kilometerperhour",
+            SpeedUnit::MeterPerSecond => "unit_converter.speed_meterpersecond",
+            SpeedUnit::MilePerHour => "unit_converter.speed_mileperhour",
+            SpeedUnit::Knot => "unit_converter.speed_knot",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            SpeedUnit::KilometerPerHour => "km/h",
+            SpeedUnit::MeterPerSecond => "m/s",
+            SpeedUnit::MilePerHour => "mph",
+            SpeedUnit::Knot => "kn",
+        }
+    }
+
+    fn all() -> Vec<SpeedUnit> {
+        vec![
+            SpeedUnit::KilometerPerHour,
+            SpeedUnit::MeterPerSecond,
+            SpeedUnit::MilePerHour,
+            SpeedUnit::Knot,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PressureUnit {
+    Pascal,
+    Kilopascal,
+    Bar,
+    Atmosphere,
+    Psi,
+    MillimeterOfMercury,
+}
+
+impl PressureUnit {
+    #[allow(dead_code)]
+    fn translation_key(&self) -> &'static str {
+        match self {
+            PressureUnit::Pascal => "unit_converter.pressure_pascal",
+            PressureUnit::Kilopascal => "unit_converter.pressure_kilopascal",
+            PressureUnit::Bar => "unit_converter.pressure_bar",
+            PressureUnit::Atmosphere => "unit_converter.pressure_atmosphere",
+            PressureUnit::Psi => "unit_converter.pressure_psi",
+            PressureUnit::MillimeterOfMercury => "unit_converter.pressure_millimeterofmercury",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            PressureUnit::Pascal => "Pa",
+            PressureUnit::Kilopascal => "kPa",
+            PressureUnit::Bar => "bar",
+            PressureUnit::Atmosphere => "atm",
+            PressureUnit::Psi => "psi",
+            PressureUnit::MillimeterOfMercury => "mmHg",
+        }
+    }
+
+    fn all() -> Vec<PressureUnit> {
+        vec![
+            PressureUnit::Pascal,
+            PressureUnit::Kilopascal,
+            PressureUnit::Bar,
+            PressureUnit::Atmosphere,
+            PressureUnit::Psi,
+            PressureUnit::MillimeterOfMercury,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EnergyUnit {
+    Joule,
+    Kilojoule,
+    Calorie,
+    Kilocalorie,
+    KilowattHour,
+    Btu,
+}
+
+impl EnergyUnit {
+    #[allow(dead_code)]
+    fn translation_key(&self) -> &'static str {
+        match self {
+            EnergyUnit::Joule => "unit_converter.energy_joule",
+            EnergyUnit::Kilojoule => "unit_converter.energy_kilojoule",
+            EnergyUnit::Calorie => "unit_converter.energy_calorie",
+            EnergyUnit::Kilocalorie => "unit_converter.energy_kilocalorie",
+            EnergyUnit::KilowattHour => "unit_converter.energy_kilowatthour",
+            EnergyUnit::Btu => "unit_converter.energy_btu",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            EnergyUnit::Joule => "J",
+            EnergyUnit::Kilojoule => "kJ",
+            EnergyUnit::Calorie => "cal",
+            EnergyUnit::Kilocalorie => "kcal",
+            EnergyUnit::KilowattHour => "kWh",
+            EnergyUnit::Btu => "BTU",
+        }
+    }
+
+    fn all() -> Vec<EnergyUnit> {
+        vec![
+            EnergyUnit::Joule,
+            EnergyUnit::Kilojoule,
+            EnergyUnit::Calorie,
+            EnergyUnit::Kilocalorie,
+            EnergyUnit::KilowattHour,
+            EnergyUnit::Btu,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FuelEconomyUnit {
+    KilometerPerLiter,
+    LiterPer100Km,
+    MpgUs,
+    MpgUk,
+}
+
+impl FuelEconomyUnit {
+    #[allow(dead_code)]
+    fn translation_key(&self) -> &'static str {
+        match self {
+            FuelEconomyUnit::KilometerPerLiter => "unit_converter.fuel_kilometerperliter",
+            FuelEconomyUnit::LiterPer100Km => "unit_converter.fuel_literper100km",
+            FuelEconomyUnit::MpgUs => "unit_converter.fuel_mpgus",
+            FuelEconomyUnit::MpgUk => "unit_converter.fuel_mpguk",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            FuelEconomyUnit::KilometerPerLiter => "km/L",
+            FuelEconomyUnit::LiterPer100Km => "L/100km",
+            FuelEconomyUnit::MpgUs => "mpg (US)",
+            FuelEconomyUnit::MpgUk => "mpg (UK)",
+        }
+    }
+
+    fn all() -> Vec<FuelEconomyUnit> {
+        vec![
+            FuelEconomyUnit::KilometerPerLiter,
+            FuelEconomyUnit::LiterPer100Km,
+            FuelEconomyUnit::MpgUs,
+            FuelEconomyUnit::MpgUk,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CurrencyUnit {
+    Usd,
+    Eur,
+    Jpy,
+    Gbp,
+    Cny,
+    Krw,
+    Aud,
+    Cad,
+    Chf,
+    Hkd,
+    Sgd,
+    Inr,
+    Twd,
+    Thb,
+    Brl,
+}
+
+impl CurrencyUnit {
+    fn label(&self) -> &'static str {
+        match self {
+            CurrencyUnit::Usd => "USD",
+            CurrencyUnit::Eur => "EUR",
+            CurrencyUnit::Jpy => "JPY",
+            CurrencyUnit::Gbp => "GBP",
+            CurrencyUnit::Cny => "CNY",
+            CurrencyUnit::Krw => "KRW",
+            CurrencyUnit::Aud => "AUD",
+            CurrencyUnit::Cad => "CAD",
+            CurrencyUnit::Chf => "CHF",
+            CurrencyUnit::Hkd => "HKD",
+            CurrencyUnit::Sgd => "SGD",
+            CurrencyUnit::Inr => "INR",
+            CurrencyUnit::Twd => "TWD",
+            CurrencyUnit::Thb => "THB",
+            CurrencyUnit::Brl => "BRL",
+        }
+    }
+
+    fn all() -> Vec<CurrencyUnit> {
+        vec![
+            CurrencyUnit::Usd,
+            CurrencyUnit::Eur,
+            CurrencyUnit::Jpy,
+            CurrencyUnit::Gbp,
+            CurrencyUnit::Cny,
+            CurrencyUnit::Krw,
+            CurrencyUnit::Aud,
+            CurrencyUnit::Cad,
+            CurrencyUnit::Chf,
+            CurrencyUnit::Hkd,
+            CurrencyUnit::Sgd,
+            CurrencyUnit::Inr,
+            CurrencyUnit::Twd,
+            CurrencyUnit::Thb,
+            CurrencyUnit::Brl,
+        ]
+    }
+
+    fn from_label(label: &str) -> CurrencyUnit {
+        Self::all()
+            .into_iter()
+            .find(|u| u.label() == label)
+            .unwrap_or(CurrencyUnit::Usd)
+    }
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ConvertLengthArgs {
@@ -472,13 +729,111 @@ struct ConvertVolumeArgs {
     to: VolumeUnit,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertSpeedArgs {
+    value: f64,
+    from: SpeedUnit,
+    to: SpeedUnit,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertPressureArgs {
+    value: f64,
+    from: PressureUnit,
+    to: PressureUnit,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertEnergyArgs {
+    value: f64,
+    from: EnergyUnit,
+    to: EnergyUnit,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertFuelEconomyArgs {
+    value: f64,
+    from: FuelEconomyUnit,
+    to: FuelEconomyUnit,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertCurrencyArgs {
+    value: f64,
+    from: CurrencyUnit,
+    to: CurrencyUnit,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+enum RateSource {
+    Bundled,
+    Api,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CurrencyRateInfo {
+    rate: f64,
+    updated_at: String,
+    source: RateSource,
+    is_stale: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CurrencyRates {
+    updated_at: String,
+    source: RateSource,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CurrencyRatesResult {
+    success: bool,
+    rates: CurrencyRates,
+    is_stale: bool,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertToAllArgs {
+    value: f64,
+    category: UnitCategory,
+    from_unit: String,
+    significant_digits: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct UnitConversionEntry {
+    unit: String,
+    label: String,
+    #[allow(dead_code)]
+    value: f64,
+    formatted: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct MultiConversionResult {
+    success: bool,
+    results: Vec<UnitConversionEntry>,
+    #[allow(dead_code)]
+    error: Option<String>,
+}
+
+/// Choices for the significant digits of the all-units table; `None` keeps the default format
+const SIGNIFICANT_DIGIT_OPTIONS: [Option<u32>; 5] = [None, Some(3), Some(6), Some(10), Some(15)];
+
 #[derive(Debug, Clone, Deserialize)]
 struct ConversionResult {
     success: bool,
     #[allow(dead_code)]
     result: f64,
     formatted: String,
-    #[allow(dead_code)]
+    #[serde(default)]
+    rate_info: Option<CurrencyRateInfo>,
     error: Option<String>,
 }
 
@@ -497,6 +852,7 @@ pub fn unit_converter() -> Html {
     let category = use_state(|| UnitCategory::Length);
     let input_value = use_state(String::new);
     let result_value = use_state(String::new);
+    let conversion_error = use_state(|| Option::<String>::None);
     let is_converting = use_state(|| false);
     let history = use_state(Vec::<HistoryEntry>::new);
     let copied = use_state(|| false);
@@ -517,13 +873,51 @@ pub fn unit_converter() -> Html {
     let area_to = use_state(|| AreaUnit::Tsubo);
     let volume_from = use_state(|| VolumeUnit::Liter);
     let volume_to = use_state(|| VolumeUnit::Milliliter);
+    let speed_from = use_state(|| SpeedUnit::KilometerPerHour);
+    let speed_to = use_state(|| SpeedUnit::MeterPerSecond);
+    let pressure_from = use_state(|| PressureUnit::Atmosphere);
+    let pressure_to = use_state(|| PressureUnit::Kilopascal);
+    let energy_from = use_state(|| EnergyUnit::Kilocalorie);
+    let energy_to = use_state(|| EnergyUnit::Kilojoule);
+    let fuel_from = use_state(|| FuelEconomyUnit::KilometerPerLiter);
+    let fuel_to = use_state(|| FuelEconomyUnit::LiterPer100Km);
+    let currency_from = use_state(|| CurrencyUnit::Usd);
+    let currency_to = use_state(|| CurrencyUnit::Jpy);
+    let rate_info = use_state(|| Option::<CurrencyRateInfo>::None);
+    let rates_status = use_state(|| Option::<CurrencyRatesResult>::None);
+    let show_all_units = use_state(|| false);
+    let significant_digits = use_state(|| Option::<u32>::None);
+    let all_results = use_state(Vec::<UnitConversionEntry>::new);
+    let all_from_unit = use_state(String::new);
+    let is_updating_rates = use_state(|| false);
 
     let on_category_change = {
         let category = category.clone();
         let result_value = result_value.clone();
+        let conversion_error = conversion_error.clone();
+        let all_results = all_results.clone();
         Callback::from(move |cat: UnitCategory| {
             category.set(cat);
             result_value.set(String::new());
+            conversion_error.set(None);
+            all_results.set(Vec::new());
+        })
+    };
+
+    let on_toggle_show_all = {
+        let show_all_units = show_all_units.clone();
+        let all_results = all_results.clone();
+        Callback::from(move |_| {
+            show_all_units.set(!*show_all_units);
+            all_results.set(Vec::new());
+        })
+    };
+
+    let on_digits_change = {
+        let significant_digits = significant_digits.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            significant_digits.set(select.value().parse::<u32>().ok());
         })
     };
 
@@ -551,6 +945,16 @@ pub fn unit_converter() -> Html {
         let area_to = area_to.clone();
         let volume_from = volume_from.clone();
         let volume_to = volume_to.clone();
+        let speed_from = speed_from.clone();
+        let speed_to = speed_to.clone();
+        let pressure_from = pressure_from.clone();
+        let pressure_to = pressure_to.clone();
+        let energy_from = energy_from.clone();
+        let energy_to = energy_to.clone();
+        let fuel_from = fuel_from.clone();
+        let fuel_to = fuel_to.clone();
+        let currency_from = currency_from.clone();
+        let currency_to = currency_to.clone();
         let result_value = result_value.clone();
 
         Callback::from(move |_| {
@@ -598,6 +1002,36 @@ pub fn unit_converter() -> Html {
                     volume_from.set(to);
                     volume_to.set(from);
                 }
+                UnitCategory::Currency => {
+                    let from = *currency_from;
+                    let to = *currency_to;
+                    currency_from.set(to);
+                    currency_to.set(from);
+                }
+                UnitCategory::Speed => {
+                    let from = (*speed_from).clone();
+                    let to = (*speed_to).clone();
+                    speed_from.set(to);
+                    speed_to.set(from);
+                }
+                UnitCategory::Pressure => {
+                    let from = (*pressure_from).clone();
+                    let to = (*pressure_to).clone();
+                    pressure_from.set(to);
+                    pressure_to.set(from);
+                }
+                UnitCategory::Energy => {
+                    let from = (*energy_from).clone();
+                    let to = (*energy_to).clone();
+                    energy_from.set(to);
+                    energy_to.set(from);
+                }
+                UnitCategory::FuelEconomy => {
+                    let from = (*fuel_from).clone();
+                    let to = (*fuel_to).clone();
+                    fuel_from.set(to);
+                    fuel_to.set(from);
+                }
             }
         })
     };
@@ -606,9 +1040,11 @@ pub fn unit_converter() -> Html {
         let category = category.clone();
         let input_value = input_value.clone();
         let result_value = result_value.clone();
+        let conversion_error = conversion_error.clone();
         let is_converting = is_converting.clone();
         let history = history.clone();
         let history_refresh = history_refresh.clone();
+        let rate_info = rate_info.clone();
         let length_from = length_from.clone();
         let length_to = length_to.clone();
         let weight_from = weight_from.clone();
@@ -623,6 +1059,20 @@ pub fn unit_converter() -> Html {
         let area_to = area_to.clone();
         let volume_from = volume_from.clone();
         let volume_to = volume_to.clone();
+        let speed_from = speed_from.clone();
+        let speed_to = speed_to.clone();
+        let pressure_from = pressure_from.clone();
+        let pressure_to = pressure_to.clone();
+        let energy_from = energy_from.clone();
+        let energy_to = energy_to.clone();
+        let fuel_from = fuel_from.clone();
+        let fuel_to = fuel_to.clone();
+        let currency_from = currency_from.clone();
+        let currency_to = currency_to.clone();
+        let show_all_units = show_all_units.clone();
+        let significant_digits = significant_digits.clone();
+        let all_results = all_results.clone();
+        let all_from_unit = all_from_unit.clone();
 
         Callback::from(move |_| {
             let value = match (*input_value).parse::<f64>() {
@@ -632,11 +1082,49 @@ pub fn unit_converter() -> Html {
 
             let cat = *category;
             let result_value = result_value.clone();
+            let conversion_error = conversion_error.clone();
             let is_converting = is_converting.clone();
             let history = history.clone();
             let input_str = (*input_value).clone();
 
             is_converting.set(true);
+            conversion_error.set(None);
+
+            if *show_all_units {
+                let from_unit = match cat {
+                    UnitCategory::Length => format!("{:?}", *length_from),
+                    UnitCategory::Weight => format!("{:?}", *weight_from),
+                    UnitCategory::DataSize => format!("{:?}", *data_from),
+                    UnitCategory::Temperature => format!("{:?}", *temp_from),
+                    UnitCategory::Time => format!("{:?}", *time_from),
+                    UnitCategory::Area => format!("{:?}", *area_from),
+                    UnitCategory::Volume => format!("{:?}", *volume_from),
+                    UnitCategory::Currency => currency_from.label().to_string(),
+                    UnitCategory::Speed => format!("{:?}", *speed_from),
+                    UnitCategory::Pressure => format!("{:?}", *pressure_from),
+                    UnitCategory::Energy => format!("{:?}", *energy_from),
+                    UnitCategory::FuelEconomy => format!("{:?}", *fuel_from),
+                };
+                let significant_digits = *significant_digits;
+                let all_results = all_results.clone();
+                all_from_unit.set(from_unit.clone());
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&ConvertToAllArgs {
+                        value,
+                        category: cat,
+                        from_unit,
+                        significant_digits,
+                    })
+                    .unwrap();
+                    let result = invoke("convert_to_all_cmd", args).await;
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<MultiConversionResult>(result)
+                    {
+                        if res.success {
+                            all_results.set(res.results);
+                        }
+                    }
+                });
+            }
 
             match cat {
                 UnitCategory::Length => {
@@ -675,6 +1163,8 @@ pub fn unit_converter() -> Html {
                                     None,
                                 );
                                 history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
                             }
                         }
                         is_converting.set(false);
@@ -716,6 +1206,8 @@ pub fn unit_converter() -> Html {
                                     None,
                                 );
                                 history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
                             }
                         }
                         is_converting.set(false);
@@ -731,11 +1223,231 @@ pub fn unit_converter() -> Html {
                         let args =
                             serde_wasm_bindgen::to_value(&ConvertDataSizeArgs { value, from, to })
                                 .unwrap();
-                        let result = invoke("convert_data_size_cmd", args).await;
+                        let result = invoke("convert_data_size_cmd", args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
+                        {
+                            if res.success {
+                                result_value.set(res.formatted.clone());
+                                let mut h = (*history).clone();
+                                h.insert(
+                                    0,
+                                    HistoryEntry {
+                                        category: cat,
+                                        from_value: input_str.clone(),
+                                        from_unit: from_label.clone(),
+                                        to_value: res.formatted,
+                                        to_unit: to_label.clone(),
+                                    },
+                                );
+                                if h.len() > 10 {
+                                    h.pop();
+                                }
+                                history.set(h);
+                                save_history(
+                                    "unit_converter",
+                                    serde_json::json!({"value": input_str, "category": "data_size", "from_unit": from_label, "to_unit": to_label}),
+                                    None,
+                                );
+                                history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
+                            }
+                        }
+                        is_converting.set(false);
+                    });
+                }
+                UnitCategory::Temperature => {
+                    let from = (*temp_from).clone();
+                    let to = (*temp_to).clone();
+                    let from_label = from.label().to_string();
+                    let to_label = to.label().to_string();
+                    let history_refresh = history_refresh.clone();
+                    spawn_local(async move {
+                        let args = serde_wasm_bindgen::to_value(&ConvertTemperatureArgs {
+                            value,
+                            from,
+                            to,
+                        })
+                        .unwrap();
+                        let result = invoke("convert_temperature_cmd", args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
+                        {
+                            if res.success {
+                                result_value.set(res.formatted.clone());
+                                let mut h = (*history).clone();
+                                h.insert(
+                                    0,
+                                    HistoryEntry {
+                                        category: cat,
+                                        from_value: input_str.clone(),
+                                        from_unit: from_label.clone(),
+                                        to_value: res.formatted,
+                                        to_unit: to_label.clone(),
+                                    },
+                                );
+                                if h.len() > 10 {
+                                    h.pop();
+                                }
+                                history.set(h);
+                                save_history(
+                                    "unit_converter",
+                                    serde_json::json!({"value": input_str, "category": "temperature", "from_unit": from_label, "to_unit": to_label}),
+                                    None,
+                                );
+                                history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
+                            }
+                        }
+                        is_converting.set(false);
+                    });
+                }
+                UnitCategory::Time => {
+                    let from = (*time_from).clone();
+                    let to = (*time_to).clone();
+                    let from_label = from.label().to_string();
+                    let to_label = to.label().to_string();
+                    let history_refresh = history_refresh.clone();
+                    spawn_local(async move {
+                        let args =
+                            serde_wasm_bindgen::to_value(&ConvertTimeArgs { value, from, to })
+                                .unwrap();
+                        let result = invoke("convert_time_cmd", args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
+                        {
+                            if res.success {
+                                result_value.set(res.formatted.clone());
+                                let mut h = (*history).clone();
+                                h.insert(
+                                    0,
+                                    HistoryEntry {
+                                        category: cat,
+                                        from_value: input_str.clone(),
+                                        from_unit: from_label.clone(),
+                                        to_value: res.formatted,
+                                        to_unit: to_label.clone(),
+                                    },
+                                );
+                                if h.len() > 10 {
+                                    h.pop();
+                                }
+                                history.set(h);
+                                save_history(
+                                    "unit_converter",
+                                    serde_json::json!({"value": input_str, "category": "time", "from_unit": from_label, "to_unit": to_label}),
+                                    None,
+                                );
+                                history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
+                            }
+                        }
+                        is_converting.set(false);
+                    });
+                }
+                UnitCategory::Area => {
+                    let from = (*area_from).clone();
+                    let to = (*area_to).clone();
+                    let from_label = from.label().to_string();
+                    let to_label = to.label().to_string();
+                    let history_refresh = history_refresh.clone();
+                    spawn_local(async move {
+                        let args =
+                            serde_wasm_bindgen::to_value(&ConvertAreaArgs { value, from, to })
+                                .unwrap();
+                        let result = invoke("convert_area_cmd", args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
+                        {
+                            if res.success {
+                                result_value.set(res.formatted.clone());
+                                let mut h = (*history).clone();
+                                h.insert(
+                                    0,
+                                    HistoryEntry {
+                                        category: cat,
+                                        from_value: input_str.clone(),
+                                        from_unit: from_label.clone(),
+                                        to_value: res.formatted,
+                                        to_unit: to_label.clone(),
+                                    },
+                                );
+                                if h.len() > 10 {
+                                    h.pop();
+                                }
+                                history.set(h);
+                                save_history(
+                                    "unit_converter",
+                                    serde_json::json!({"value": input_str, "category": "area", "from_unit": from_label, "to_unit": to_label}),
+                                    None,
+                                );
+                                history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
+                            }
+                        }
+                        is_converting.set(false);
+                    });
+                }
+                UnitCategory::Volume => {
+                    let from = (*volume_from).clone();
+                    let to = (*volume_to).clone();
+                    let from_label = from.label().to_string();
+                    let to_label = to.label().to_string();
+                    let history_refresh = history_refresh.clone();
+                    spawn_local(async move {
+                        let args =
+                            serde_wasm_bindgen::to_value(&ConvertVolumeArgs { value, from, to })
+                                .unwrap();
+                        let result = invoke("convert_volume_cmd", args).await;
+                        if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
+                        {
+                            if res.success {
+                                result_value.set(res.formatted.clone());
+                                let mut h = (*history).clone();
+                                h.insert(
+                                    0,
+                                    HistoryEntry {
+                                        category: cat,
+                                        from_value: input_str.clone(),
+                                        from_unit: from_label.clone(),
+                                        to_value: res.formatted,
+                                        to_unit: to_label.clone(),
+                                    },
+                                );
+                                if h.len() > 10 {
+                                    h.pop();
+                                }
+                                history.set(h);
+                                save_history(
+                                    "unit_converter",
+                                    serde_json::json!({"value": input_str, "category": "volume", "from_unit": from_label, "to_unit": to_label}),
+                                    None,
+                                );
+                                history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
+                            }
+                        }
+                        is_converting.set(false);
+                    });
+                }
+                UnitCategory::Currency => {
+                    let from = *currency_from;
+                    let to = *currency_to;
+                    let from_label = from.label().to_string();
+                    let to_label = to.label().to_string();
+                    let history_refresh = history_refresh.clone();
+                    let rate_info = rate_info.clone();
+                    spawn_local(async move {
+                        let args =
+                            serde_wasm_bindgen::to_value(&ConvertCurrencyArgs { value, from, to })
+                                .unwrap();
+                        let result = invoke("convert_currency_cmd", args).await;
                         if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
                         {
                             if res.success {
                                 result_value.set(res.formatted.clone());
+                                rate_info.set(res.rate_info.clone());
                                 let mut h = (*history).clone();
                                 h.insert(
                                     0,
@@ -753,29 +1465,28 @@ pub fn unit_converter() -> Html {
                                 history.set(h);
                                 save_history(
                                     "unit_converter",
-                                    serde_json::json!({"value": input_str, "category": "data_size", "from_unit": from_label, "to_unit": to_label}),
+                                    serde_json::json!({"value": input_str, "category": "currency", "from_unit": from_label, "to_unit": to_label}),
                                     None,
                                 );
                                 history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
                             }
                         }
                         is_converting.set(false);
                     });
                 }
-                UnitCategory::Temperature => {
-                    let from = (*temp_from).clone();
-                    let to = (*temp_to).clone();
+                UnitCategory::Speed => {
+                    let from = (*speed_from).clone();
+                    let to = (*speed_to).clone();
                     let from_label = from.label().to_string();
                     let to_label = to.label().to_string();
                     let history_refresh = history_refresh.clone();
                     spawn_local(async move {
-                        let args = serde_wasm_bindgen::to_value(&ConvertTemperatureArgs {
-                            value,
-                            from,
-                            to,
-                        })
-                        .unwrap();
-                        let result = invoke("convert_temperature_cmd", args).await;
+                        let args =
+                            serde_wasm_bindgen::to_value(&ConvertSpeedArgs { value, from, to })
+                                .unwrap();
+                        let result = invoke("convert_speed_cmd", args).await;
                         if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
                         {
                             if res.success {
@@ -797,26 +1508,28 @@ pub fn unit_converter() -> Html {
                                 history.set(h);
                                 save_history(
                                     "unit_converter",
-                                    serde_json::json!({"value": input_str, "category": "temperature", "from_unit": from_label, "to_unit": to_label}),
+                                    serde_json::json!({"value": input_str, "category": "speed", "from_unit": from_label, "to_unit": to_label}),
                                     None,
                                 );
                                 history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
                             }
                         }
                         is_converting.set(false);
                     });
                 }
-                UnitCategory::Time => {
-                    let from = (*time_from).clone();
-                    let to = (*time_to).clone();
+                UnitCategory::Pressure => {
+                    let from = (*pressure_from).clone();
+                    let to = (*pressure_to).clone();
                     let from_label = from.label().to_string();
                     let to_label = to.label().to_string();
                     let history_refresh = history_refresh.clone();
                     spawn_local(async move {
                         let args =
-                            serde_wasm_bindgen::to_value(&ConvertTimeArgs { value, from, to })
+                            serde_wasm_bindgen::to_value(&ConvertPressureArgs { value, from, to })
                                 .unwrap();
-                        let result = invoke("convert_time_cmd", args).await;
+                        let result = invoke("convert_pressure_cmd", args).await;
                         if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
                         {
                             if res.success {
@@ -838,26 +1551,28 @@ pub fn unit_converter() -> Html {
                                 history.set(h);
                                 save_history(
                                     "unit_converter",
-                                    serde_json::json!({"value": input_str, "category": "time", "from_unit": from_label, "to_unit": to_label}),
+                                    serde_json::json!({"value": input_str, "category": "pressure", "from_unit": from_label, "to_unit": to_label}),
                                     None,
                                 );
                                 history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
                             }
                         }
                         is_converting.set(false);
                     });
                 }
-                UnitCategory::Area => {
-                    let from = (*area_from).clone();
-                    let to = (*area_to).clone();
+                UnitCategory::Energy => {
+                    let from = (*energy_from).clone();
+                    let to = (*energy_to).clone();
                     let from_label = from.label().to_string();
                     let to_label = to.label().to_string();
                     let history_refresh = history_refresh.clone();
                     spawn_local(async move {
                         let args =
-                            serde_wasm_bindgen::to_value(&ConvertAreaArgs { value, from, to })
+                            serde_wasm_bindgen::to_value(&ConvertEnergyArgs { value, from, to })
                                 .unwrap();
-                        let result = invoke("convert_area_cmd", args).await;
+                        let result = invoke("convert_energy_cmd", args).await;
                         if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
                         {
                             if res.success {
@@ -879,26 +1594,31 @@ pub fn unit_converter() -> Html {
                                 history.set(h);
                                 save_history(
                                     "unit_converter",
-                                    serde_json::json!({"value": input_str, "category": "area", "from_unit": from_label, "to_unit": to_label}),
+                                    serde_json::json!({"value": input_str, "category": "energy", "from_unit": from_label, "to_unit": to_label}),
                                     None,
                                 );
                                 history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
                             }
                         }
                         is_converting.set(false);
                     });
                 }
-                UnitCategory::Volume => {
-                    let from = (*volume_from).clone();
-                    let to = (*volume_to).clone();
+                UnitCategory::FuelEconomy => {
+                    let from = (*fuel_from).clone();
+                    let to = (*fuel_to).clone();
                     let from_label = from.label().to_string();
                     let to_label = to.label().to_string();
                     let history_refresh = history_refresh.clone();
                     spawn_local(async move {
-                        let args =
-                            serde_wasm_bindgen::to_value(&ConvertVolumeArgs { value, from, to })
-                                .unwrap();
-                        let result = invoke("convert_volume_cmd", args).await;
+                        let args = serde_wasm_bindgen::to_value(&ConvertFuelEconomyArgs {
+                            value,
+                            from,
+                            to,
+                        })
+                        .unwrap();
+                        let result = invoke("convert_fuel_economy_cmd", args).await;
                         if let Ok(res) = serde_wasm_bindgen::from_value::<ConversionResult>(result)
                         {
                             if res.success {
@@ -920,10 +1640,12 @@ pub fn unit_converter() -> Html {
                                 history.set(h);
                                 save_history(
                                     "unit_converter",
-                                    serde_json::json!({"value": input_str, "category": "volume", "from_unit": from_label, "to_unit": to_label}),
+                                    serde_json::json!({"value": input_str, "category": "fuel_economy", "from_unit": from_label, "to_unit": to_label}),
                                     None,
                                 );
                                 history_refresh.set(*history_refresh + 1);
+                            } else {
+                                conversion_error.set(res.error);
                             }
                         }
                         is_converting.set(false);
@@ -974,6 +1696,29 @@ pub fn unit_converter() -> Html {
         })
     };
 
+    let on_update_rates = {
+        let rate_info = rate_info.clone();
+        let rates_status = rates_status.clone();
+        let is_updating_rates = is_updating_rates.clone();
+        Callback::from(move |_| {
+            let rate_info = rate_info.clone();
+            let rates_status = rates_status.clone();
+            let is_updating_rates = is_updating_rates.clone();
+            is_updating_rates.set(true);
+            spawn_local(async move {
+                let result = invoke("update_currency_rates_cmd", JsValue::NULL).await;
+                if let Ok(res) = serde_wasm_bindgen::from_value::<CurrencyRatesResult>(result) {
+                    // The last conversion used the previous rates
+                    if res.success {
+                        rate_info.set(None);
+                    }
+                    rates_status.set(Some(res));
+                }
+                is_updating_rates.set(false);
+            });
+        })
+    };
+
     let render_unit_selectors = || -> Html {
         match *category {
             UnitCategory::Length => {
@@ -1412,9 +2157,290 @@ pub fn unit_converter() -> Html {
                     </>
                 }
             }
+            UnitCategory::Currency => {
+                let on_from_change = {
+                    let currency_from = currency_from.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        currency_from.set(CurrencyUnit::from_label(&select.value()));
+                    })
+                };
+                let on_to_change = {
+                    let currency_to = currency_to.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        currency_to.set(CurrencyUnit::from_label(&select.value()));
+                    })
+                };
+                html! {
+                    <>
+                        <select class="form-select" onchange={on_from_change}>
+                            { for CurrencyUnit::all().iter().map(|u| html! {
+                                <option value={u.label()} selected={*currency_from == *u}>
+                                    {u.label()}
+                                </option>
+                            })}
+                        </select>
+                        <select class="form-select" onchange={on_to_change}>
+                            { for CurrencyUnit::all().iter().map(|u| html! {
+                                <option value={u.label()} selected={*currency_to == *u}>
+                                    {u.label()}
+                                </option>
+                            })}
+                        </select>
+                    </>
+                }
+            }
+            UnitCategory::Speed => {
+                let on_from_change = {
+                    let speed_from = speed_from.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "MeterPerSecond" => SpeedUnit::MeterPerSecond,
+                            "MilePerHour" => SpeedUnit::MilePerHour,
+                            "Knot" => SpeedUnit::Knot,
+                            _ => SpeedUnit::KilometerPerHour,
+                        };
+                        speed_from.set(unit);
+                    })
+                };
+                let on_to_change = {
+                    let speed_to = speed_to.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "MeterPerSecond" => SpeedUnit::MeterPerSecond,
+                            "MilePerHour" => SpeedUnit::MilePerHour,
+                            "Knot" => SpeedUnit::Knot,
+                            _ => SpeedUnit::KilometerPerHour,
+                        };
+                        speed_to.set(unit);
+                    })
+                };
+                html! {
+                    <>
+                        <select class="form-select" onchange={on_from_change}>
+                            { for SpeedUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*speed_from == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                        <select class="form-select" onchange={on_to_change}>
+                            { for SpeedUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*speed_to == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                    </>
+                }
+            }
+            UnitCategory::Pressure => {
+                let on_from_change = {
+                    let pressure_from = pressure_from.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "Kilopascal" => PressureUnit::Kilopascal,
+                            "Bar" => PressureUnit::Bar,
+                            "Atmosphere" => PressureUnit::Atmosphere,
+                            "Psi" => PressureUnit::Psi,
+                            "MillimeterOfMercury" => PressureUnit::MillimeterOfMercury,
+                            _ => PressureUnit::Pascal,
+                        };
+                        pressure_from.set(unit);
+                    })
+                };
+                let on_to_change = {
+                    let pressure_to = pressure_to.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "Kilopascal" => PressureUnit::Kilopascal,
+                            "Bar" => PressureUnit::Bar,
+                            "Atmosphere" => PressureUnit::Atmosphere,
+                            "Psi" => PressureUnit::Psi,
+                            "MillimeterOfMercury" => PressureUnit::MillimeterOfMercury,
+                            _ => PressureUnit::Pascal,
+                        };
+                        pressure_to.set(unit);
+                    })
+                };
+                html! {
+                    <>
+                        <select class="form-select" onchange={on_from_change}>
+                            { for PressureUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*pressure_from == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                        <select class="form-select" onchange={on_to_change}>
+                            { for PressureUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*pressure_to == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                    </>
+                }
+            }
+            UnitCategory::Energy => {
+                let on_from_change = {
+                    let energy_from = energy_from.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "Kilojoule" => EnergyUnit::Kilojoule,
+                            "Calorie" => EnergyUnit::Calorie,
+                            "Kilocalorie" => EnergyUnit::Kilocalorie,
+                            "KilowattHour" => EnergyUnit::KilowattHour,
+                            "Btu" => EnergyUnit::Btu,
+                            _ => EnergyUnit::Joule,
+                        };
+                        energy_from.set(unit);
+                    })
+                };
+                let on_to_change = {
+                    let energy_to = energy_to.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "Kilojoule" => EnergyUnit::Kilojoule,
+                            "Calorie" => EnergyUnit::Calorie,
+                            "Kilocalorie" => EnergyUnit::Kilocalorie,
+                            "KilowattHour" => EnergyUnit::KilowattHour,
+                            "Btu" => EnergyUnit::Btu,
+                            _ => EnergyUnit::Joule,
+                        };
+                        energy_to.set(unit);
+                    })
+                };
+                html! {
+                    <>
+                        <select class="form-select" onchange={on_from_change}>
+                            { for EnergyUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*energy_from == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                        <select class="form-select" onchange={on_to_change}>
+                            { for EnergyUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*energy_to == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                    </>
+                }
+            }
+            UnitCategory::FuelEconomy => {
+                let on_from_change = {
+                    let fuel_from = fuel_from.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "LiterPer100Km" => FuelEconomyUnit::LiterPer100Km,
+                            "MpgUs" => FuelEconomyUnit::MpgUs,
+                            "MpgUk" => FuelEconomyUnit::MpgUk,
+                            _ => FuelEconomyUnit::KilometerPerLiter,
+                        };
+                        fuel_from.set(unit);
+                    })
+                };
+                let on_to_change = {
+                    let fuel_to = fuel_to.clone();
+                    let result_value = result_value.clone();
+                    Callback::from(move |e: Event| {
+                        result_value.set(String::new());
+                        let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                        let unit = match select.value().as_str() {
+                            "LiterPer100Km" => FuelEconomyUnit::LiterPer100Km,
+                            "MpgUs" => FuelEconomyUnit::MpgUs,
+                            "MpgUk" => FuelEconomyUnit::MpgUk,
+                            _ => FuelEconomyUnit::KilometerPerLiter,
+                        };
+                        fuel_to.set(unit);
+                    })
+                };
+                html! {
+                    <>
+                        <select class="form-select" onchange={on_from_change}>
+                            { for FuelEconomyUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*fuel_from == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                        <select class="form-select" onchange={on_to_change}>
+                            { for FuelEconomyUnit::all().iter().map(|u| {
+                                let value = format!("{:?}", u);
+                                html! {
+                                    <option value={value.clone()} selected={*fuel_to == *u}>
+                                        {u.label()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                    </>
+                }
+            }
         }
     };
 
+    // Rate details come from the last conversion, or from the last update before converting
+    let rates_meta = match (&*rate_info, &*rates_status) {
+        (Some(info), _) => Some((info.updated_at.clone(), info.source.clone(), info.is_stale)),
+        (None, Some(status)) => Some((
+            status.rates.updated_at.clone(),
+            status.rates.source.clone(),
+            status.is_stale,
+        )),
+        (None, None) => None,
+    };
+
     html! {
         <div class="unit-converter">
             <div class="section unit-category-section">
@@ -1487,6 +2513,98 @@ pub fn unit_converter() -> Html {
                 </div>
             </div>
 
+            <div class="section unit-all-toggle-section">
+                <label class="unit-all-toggle">
+                    <input type="checkbox" checked={*show_all_units} onchange={on_toggle_show_all} />
+                    {i18n.t("unit_converter.show_all_units")}
+                </label>
+                if *show_all_units {
+                    <label class="unit-digits-label">
+                        {i18n.t("unit_converter.significant_digits")}
+                        <select class="form-select" onchange={on_digits_change}>
+                            { for SIGNIFICANT_DIGIT_OPTIONS.iter().map(|digits| html! {
+                                <option
+                                    value={digits.map(|d| d.to_string()).unwrap_or_default()}
+                                    selected={*significant_digits == *digits}
+                                >
+                                    {match digits {
+                                        Some(d) => d.to_string(),
+                                        None => i18n.t("unit_converter.significant_digits_auto"),
+                                    }}
+                                </option>
+                            }) }
+                        </select>
+                    </label>
+                }
+            </div>
+
+            if *show_all_units && !all_results.is_empty() {
+                <div class="section unit-all-results-section">
+                    <h3>{i18n.t("unit_converter.all_units_section")}</h3>
+                    <table class="unit-all-table">
+                        <tbody>
+                            { for all_results.iter().map(|entry| html! {
+                                <tr class={classes!((entry.unit == *all_from_unit).then_some("source-unit"))}>
+                                    <td class="unit-all-value">{&entry.formatted}</td>
+                                    <td class="unit-all-label">{&entry.label}</td>
+                                </tr>
+                            }) }
+                        </tbody>
+                    </table>
+                </div>
+            }
+
+            if *category == UnitCategory::Currency {
+                <div class="section currency-rate-section">
+                    <div class="currency-rate-header">
+                        <h3>{i18n.t("unit_converter.rates_section")}</h3>
+                        <button
+                            class="secondary-btn"
+                            onclick={on_update_rates}
+                            disabled={*is_updating_rates}
+                        >
+                            if *is_updating_rates {
+                                <span class="processing">
+                                    <span class="spinner"></span>
+                                    {i18n.t("unit_converter.rates_updating")}
+                                </span>
+                            } else {
+                                {i18n.t("unit_converter.rates_update")}
+                            }
+                        </button>
+                    </div>
+                    if let Some(info) = &*rate_info {
+                        <div class="currency-rate-value">
+                            {format!("1 {} = {} {}", currency_from.label(), info.rate, currency_to.label())}
+                        </div>
+                    }
+                    if let Some((updated_at, source, _)) = rates_meta.clone() {
+                        <div class="currency-rate-meta">
+                            {i18n.t("unit_converter.rates_updated_at").replace("{time}", &updated_at)}
+                            {" · "}
+                            {match source {
+                                RateSource::Bundled => i18n.t("unit_converter.rates_source_bundled"),
+                                RateSource::Api => i18n.t("unit_converter.rates_source_api"),
+                            }}
+                        </div>
+                    }
+                    if rates_meta.as_ref().is_some_and(|(_, _, is_stale)| *is_stale) {
+                        <div class="currency-rate-warning">{format!("⚠ {}", i18n.t("unit_converter.rates_stale"))}</div>
+                    }
+                    if let Some(error) = rates_status.as_ref().and_then(|s| s.error.clone()) {
+                        <div class="currency-rate-error">
+                            {error}{" — "}{i18n.t("unit_converter.rates_offline_fallback")}
+                        </div>
+                    }
+                </div>
+            }
+
+            if let Some(error) = &*conversion_error {
+                <div class="section unit-result-section">
+                    <div class="unit-convert-error">{error}</div>
+                </div>
+            }
+
             if !result_value.is_empty() {
                 <div class="section unit-result-section">
                     <h3>{i18n.t("unit_converter.result_section")}</h3>
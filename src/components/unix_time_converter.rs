@@ -17,18 +17,37 @@ extern "C" {
 enum Mode {
     UnixToDatetime,
     DatetimeToUnix,
+    Calculate,
+    Batch,
 }
 
+/// Batch results beyond this many rows are only included in the copied TSV.
+const MAX_BATCH_ROWS: usize = 1000;
+
+const DURATION_FIELDS: [&str; 6] = ["years", "months", "days", "hours", "minutes", "seconds"];
+
 #[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
 enum TimestampUnit {
     Seconds,
     Milliseconds,
+    Auto,
 }
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 enum TimezoneOption {
     Local,
     Utc,
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct TimezoneInfo {
+    name: String,
+    #[allow(dead_code)]
+    abbreviation: String,
+    utc_offset: String,
+    #[allow(dead_code)]
+    offset_seconds: i32,
 }
 
 #[derive(Serialize)]
@@ -46,6 +65,28 @@ struct DatetimeToUnixArgs {
     timezone: TimezoneOption,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AddDurationArgs {
+    timestamp: i64,
+    unit: TimestampUnit,
+    years: i32,
+    months: i32,
+    days: i32,
+    hours: i32,
+    minutes: i32,
+    seconds: i32,
+    timezone: TimezoneOption,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiffDatetimesArgs {
+    a: i64,
+    b: i64,
+    unit: TimestampUnit,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct UnixToDatetimeResult {
     success: bool,
@@ -63,6 +104,54 @@ struct DatetimeToUnixResult {
     success: bool,
     unix_seconds: i64,
     unix_milliseconds: i64,
+    alternative_unix_seconds: Option<i64>,
+    warning: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchConvertArgs {
+    input: String,
+    unit: TimestampUnit,
+    timezone: TimezoneOption,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct BatchConvertEntry {
+    line: usize,
+    original: String,
+    #[allow(dead_code)]
+    unix_milliseconds: i64,
+    datetime: String,
+    iso8601: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct BatchConvertResult {
+    success: bool,
+    entries: Vec<BatchConvertEntry>,
+    converted_count: usize,
+    skipped_count: usize,
+    warnings: Vec<String>,
+    formatted_output: String,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DurationResult {
+    success: bool,
+    total_seconds: i64,
+    total_milliseconds: i64,
+    #[allow(dead_code)]
+    is_negative: bool,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    #[allow(dead_code)]
+    milliseconds: i64,
+    human_readable: String,
     error: Option<String>,
 }
 
@@ -82,6 +171,7 @@ pub fn unix_time_converter() -> Html {
     let input = use_state(String::new);
     let unit = use_state(|| TimestampUnit::Seconds);
     let timezone = use_state(|| TimezoneOption::Local);
+    let timezones = use_state(Vec::<TimezoneInfo>::new);
     let is_processing = use_state(|| false);
     let error = use_state(|| Option::<String>::None);
     let copy_feedback = use_state(|| false);
@@ -92,6 +182,18 @@ pub fn unix_time_converter() -> Html {
     // Results for Datetime to Unix
     let unix_result = use_state(|| Option::<DatetimeToUnixResult>::None);
 
+    // Duration calculator
+    let add_base = use_state(String::new);
+    let add_amounts = use_state(|| [0i32; 6]);
+    let add_result = use_state(|| Option::<DatetimeToUnixResult>::None);
+    let diff_a = use_state(String::new);
+    let diff_b = use_state(String::new);
+    let diff_result = use_state(|| Option::<DurationResult>::None);
+
+    // Batch conversion
+    let batch_input = use_state(String::new);
+    let batch_result = use_state(|| Option::<BatchConvertResult>::None);
+
     // Current time display
     let current_time = use_state(|| Option::<CurrentUnixTimeResult>::None);
     let history_refresh = use_state(|| 0u32);
@@ -140,6 +242,21 @@ pub fn unix_time_converter() -> Html {
         });
     }
 
+    // Load the IANA timezone list once
+    {
+        let timezones = timezones.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(result) = invoke("list_timezones_cmd", JsValue::NULL).await {
+                    if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<TimezoneInfo>>(result) {
+                        timezones.set(list);
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
     let on_mode_change = {
         let mode = mode.clone();
         let input = input.clone();
@@ -178,6 +295,7 @@ pub fn unix_time_converter() -> Html {
             match select.value().as_str() {
                 "seconds" => unit.set(TimestampUnit::Seconds),
                 "milliseconds" => unit.set(TimestampUnit::Milliseconds),
+                "auto" => unit.set(TimestampUnit::Auto),
                 _ => {}
             }
         })
@@ -190,7 +308,7 @@ pub fn unix_time_converter() -> Html {
             match select.value().as_str() {
                 "local" => timezone.set(TimezoneOption::Local),
                 "utc" => timezone.set(TimezoneOption::Utc),
-                _ => {}
+                name => timezone.set(TimezoneOption::Named(name.to_string())),
             }
         })
     };
@@ -210,7 +328,7 @@ pub fn unix_time_converter() -> Html {
             let current_mode = *mode;
             let input_val = (*input).clone();
             let current_unit = *unit;
-            let current_timezone = *timezone;
+            let current_timezone = (*timezone).clone();
             let is_processing = is_processing.clone();
             let error = error.clone();
             let datetime_result = datetime_result.clone();
@@ -268,6 +386,7 @@ pub fn unix_time_converter() -> Html {
                             error.set(Some("Invalid timestamp format".to_string()));
                         }
                     }
+                    Mode::Calculate | Mode::Batch => {}
                     Mode::DatetimeToUnix => {
                         let args = serde_wasm_bindgen::to_value(&DatetimeToUnixArgs {
                             datetime_str: input_val.clone(),
@@ -311,6 +430,139 @@ pub fn unix_time_converter() -> Html {
         })
     };
 
+    let on_add_duration = {
+        let add_base = add_base.clone();
+        let add_amounts = add_amounts.clone();
+        let add_result = add_result.clone();
+        let unit = unit.clone();
+        let timezone = timezone.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            let Ok(timestamp) = add_base.trim().parse::<i64>() else {
+                error.set(Some("Invalid timestamp format".to_string()));
+                return;
+            };
+            let [years, months, days, hours, minutes, seconds] = *add_amounts;
+            let args = serde_wasm_bindgen::to_value(&AddDurationArgs {
+                timestamp,
+                unit: *unit,
+                years,
+                months,
+                days,
+                hours,
+                minutes,
+                seconds,
+                timezone: (*timezone).clone(),
+            })
+            .unwrap();
+            let add_result = add_result.clone();
+            let error = error.clone();
+
+            spawn_local(async move {
+                match invoke("add_duration_cmd", args).await {
+                    Ok(result) => {
+                        match serde_wasm_bindgen::from_value::<DatetimeToUnixResult>(result) {
+                            Ok(res) if res.success => {
+                                add_result.set(Some(res));
+                                error.set(None);
+                            }
+                            Ok(res) => error.set(res.error),
+                            Err(e) => error.set(Some(format!("Parse error: {:?}", e))),
+                        }
+                    }
+                    Err(e) => {
+                        let err_msg = e.as_string().unwrap_or_else(|| format!("{:?}", e));
+                        error.set(Some(format!("Invoke error: {}", err_msg)));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_diff = {
+        let diff_a = diff_a.clone();
+        let diff_b = diff_b.clone();
+        let diff_result = diff_result.clone();
+        let unit = unit.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            let (Ok(a), Ok(b)) = (diff_a.trim().parse::<i64>(), diff_b.trim().parse::<i64>())
+            else {
+                error.set(Some("Invalid timestamp format".to_string()));
+                return;
+            };
+            let args =
+                serde_wasm_bindgen::to_value(&DiffDatetimesArgs { a, b, unit: *unit }).unwrap();
+            let diff_result = diff_result.clone();
+            let error = error.clone();
+
+            spawn_local(async move {
+                match invoke("diff_datetimes_cmd", args).await {
+                    Ok(result) => match serde_wasm_bindgen::from_value::<DurationResult>(result) {
+                        Ok(res) if res.success => {
+                            diff_result.set(Some(res));
+                            error.set(None);
+                        }
+                        Ok(res) => error.set(res.error),
+                        Err(e) => error.set(Some(format!("Parse error: {:?}", e))),
+                    },
+                    Err(e) => {
+                        let err_msg = e.as_string().unwrap_or_else(|| format!("{:?}", e));
+                        error.set(Some(format!("Invoke error: {}", err_msg)));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_batch_convert = {
+        let batch_input = batch_input.clone();
+        let batch_result = batch_result.clone();
+        let unit = unit.clone();
+        let timezone = timezone.clone();
+        let is_processing = is_processing.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            let args = serde_wasm_bindgen::to_value(&BatchConvertArgs {
+                input: (*batch_input).clone(),
+                unit: *unit,
+                timezone: (*timezone).clone(),
+            })
+            .unwrap();
+            let batch_result = batch_result.clone();
+            let is_processing = is_processing.clone();
+            let error = error.clone();
+
+            is_processing.set(true);
+            spawn_local(async move {
+                match invoke("batch_convert_timestamps_cmd", args).await {
+                    Ok(result) => {
+                        match serde_wasm_bindgen::from_value::<BatchConvertResult>(result) {
+                            Ok(res) => {
+                                error.set(res.error.clone());
+                                batch_result.set(Some(res));
+                            }
+                            Err(e) => error.set(Some(format!("Parse error: {:?}", e))),
+                        }
+                    }
+                    Err(e) => {
+                        let err_msg = e.as_string().unwrap_or_else(|| format!("{:?}", e));
+                        error.set(Some(format!("Invoke error: {}", err_msg)));
+                    }
+                }
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let text_setter = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+            state.set(target.value());
+        })
+    };
+
     let on_copy = {
         let copy_feedback = copy_feedback.clone();
         Callback::from(move |text: String| {
@@ -338,7 +590,7 @@ pub fn unix_time_converter() -> Html {
         Callback::from(move |_| {
             if let Some(ct) = (*current_time).clone() {
                 let timestamp = match *unit {
-                    TimestampUnit::Seconds => ct.unix_seconds.to_string(),
+                    TimestampUnit::Seconds | TimestampUnit::Auto => ct.unix_seconds.to_string(),
                     TimestampUnit::Milliseconds => ct.unix_milliseconds.to_string(),
                 };
                 input.set(timestamp);
@@ -449,13 +701,31 @@ pub fn unix_time_converter() -> Html {
                     >
                         {i18n.t("unix_time_converter.mode_datetime_to_unix")}
                     </button>
+                    <button
+                        class={classes!("mode-tab", (*mode == Mode::Calculate).then_some("active"))}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(Mode::Calculate))
+                        }
+                    >
+                        {i18n.t("unix_time_converter.mode_calculate")}
+                    </button>
+                    <button
+                        class={classes!("mode-tab", (*mode == Mode::Batch).then_some("active"))}
+                        onclick={
+                            let on_mode_change = on_mode_change.clone();
+                            Callback::from(move |_| on_mode_change.emit(Mode::Batch))
+                        }
+                    >
+                        {i18n.t("unix_time_converter.mode_batch")}
+                    </button>
                 </div>
             </div>
 
             // Options section
             <div class="section options-section">
                 <div class="options-row">
-                    if *mode == Mode::UnixToDatetime {
+                    if *mode != Mode::DatetimeToUnix {
                         <div class="option-group">
                             <label>{i18n.t("unix_time_converter.unit")}</label>
                             <select onchange={on_unit_change}>
@@ -465,6 +735,9 @@ pub fn unix_time_converter() -> Html {
                                 <option value="milliseconds" selected={*unit == TimestampUnit::Milliseconds}>
                                     {i18n.t("unix_time_converter.unit_milliseconds")}
                                 </option>
+                                <option value="auto" selected={*unit == TimestampUnit::Auto}>
+                                    {i18n.t("unix_time_converter.unit_auto")}
+                                </option>
                             </select>
                         </div>
                     }
@@ -477,66 +750,330 @@ pub fn unix_time_converter() -> Html {
                             <option value="utc" selected={*timezone == TimezoneOption::Utc}>
                                 {i18n.t("unix_time_converter.timezone_utc")}
                             </option>
+                            if !(*timezones).is_empty() {
+                                <optgroup label={i18n.t("unix_time_converter.timezone_iana")}>
+                                    { for (*timezones).iter().map(|tz| {
+                                        let selected = matches!(&*timezone, TimezoneOption::Named(name) if *name == tz.name);
+                                        html! {
+                                            <option value={tz.name.clone()} {selected}>
+                                                {format!("{} (UTC{})", tz.name, tz.utc_offset)}
+                                            </option>
+                                        }
+                                    }) }
+                                </optgroup>
+                            }
                         </select>
                     </div>
                 </div>
             </div>
 
             // Input section
-            <div class="section input-section">
-                <div class="section-header">
-                    <h3>
-                        {match *mode {
-                            Mode::UnixToDatetime => i18n.t("unix_time_converter.unix_input"),
-                            Mode::DatetimeToUnix => i18n.t("unix_time_converter.datetime_input"),
-                        }}
-                    </h3>
-                    <div class="input-actions">
-                        if *mode == Mode::UnixToDatetime {
-                            <button class="secondary-btn" onclick={on_use_current_time}>
-                                {i18n.t("unix_time_converter.use_current")}
+            if matches!(*mode, Mode::UnixToDatetime | Mode::DatetimeToUnix) {
+                <div class="section input-section">
+                    <div class="section-header">
+                        <h3>
+                            {match *mode {
+                                Mode::UnixToDatetime => i18n.t("unix_time_converter.unix_input"),
+                                Mode::DatetimeToUnix => i18n.t("unix_time_converter.datetime_input"),
+                                Mode::Calculate | Mode::Batch => String::new(),
+                            }}
+                        </h3>
+                        <div class="input-actions">
+                            if *mode == Mode::UnixToDatetime {
+                                <button class="secondary-btn" onclick={on_use_current_time}>
+                                    {i18n.t("unix_time_converter.use_current")}
+                                </button>
+                            }
+                            <button class="secondary-btn" onclick={on_clear}>
+                                {i18n.t("common.clear")}
                             </button>
+                        </div>
+                    </div>
+                    <input
+                        type="text"
+                        class="input-field"
+                        placeholder={match *mode {
+                            Mode::UnixToDatetime => i18n.t("unix_time_converter.unix_placeholder"),
+                            Mode::DatetimeToUnix => i18n.t("unix_time_converter.datetime_placeholder"),
+                            Mode::Calculate | Mode::Batch => String::new(),
+                        }}
+                        value={(*input).clone()}
+                        oninput={on_input_change}
+                        onchange={on_change}
+                    />
+                    if *mode == Mode::DatetimeToUnix {
+                        <div class="format-hint">
+                            {i18n.t("unix_time_converter.format_hint")}
+                        </div>
+                    }
+                </div>
+
+                // Convert button
+                <div class="action-buttons">
+                    <button
+                        class="primary-btn"
+                        onclick={on_convert}
+                        disabled={*is_processing || (*input).is_empty()}
+                    >
+                        if *is_processing {
+                            <span class="processing">
+                                <span class="spinner"></span>
+                                {i18n.t("common.processing")}
+                            </span>
+                        } else {
+                            {i18n.t("common.convert")}
                         }
-                        <button class="secondary-btn" onclick={on_clear}>
-                            {i18n.t("common.clear")}
+                    </button>
+                </div>
+            }
+
+            // Duration calculator
+            if *mode == Mode::Calculate {
+                <div class="section calc-section">
+                    <div class="section-header">
+                        <h3>{i18n.t("unix_time_converter.add_duration")}</h3>
+                    </div>
+                    <div class="calc-row">
+                        <input
+                            type="text"
+                            class="input-field"
+                            placeholder={i18n.t("unix_time_converter.unix_placeholder")}
+                            value={(*add_base).clone()}
+                            oninput={text_setter(&add_base)}
+                        />
+                        <button
+                            class="secondary-btn"
+                            onclick={
+                                let add_base = add_base.clone();
+                                let current_time = current_time.clone();
+                                let unit = unit.clone();
+                                Callback::from(move |_| {
+                                    if let Some(ct) = (*current_time).clone() {
+                                        add_base.set(match *unit {
+                                            TimestampUnit::Seconds | TimestampUnit::Auto => ct.unix_seconds.to_string(),
+                                            TimestampUnit::Milliseconds => ct.unix_milliseconds.to_string(),
+                                        });
+                                    }
+                                })
+                            }
+                        >
+                            {i18n.t("unix_time_converter.use_current")}
+                        </button>
+                    </div>
+                    <div class="duration-fields">
+                        { for DURATION_FIELDS.iter().enumerate().map(|(index, field)| {
+                            let value = add_amounts[index].to_string();
+                            let add_amounts = add_amounts.clone();
+                            let oninput = Callback::from(move |e: InputEvent| {
+                                let target: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                let mut amounts = *add_amounts;
+                                amounts[index] = target.value().trim().parse().unwrap_or(0);
+                                add_amounts.set(amounts);
+                            });
+                            html! {
+                                <label class="duration-field">
+                                    <span>{i18n.t(&format!("unix_time_converter.duration_{}", field))}</span>
+                                    <input type="number" {value} {oninput} />
+                                </label>
+                            }
+                        }) }
+                    </div>
+                    <div class="action-buttons">
+                        <button
+                            class="primary-btn"
+                            onclick={on_add_duration}
+                            disabled={(*add_base).trim().is_empty()}
+                        >
+                            {i18n.t("unix_time_converter.calculate")}
+                        </button>
+                    </div>
+                    if let Some(result) = (*add_result).clone() {
+                        <div class="result-grid">
+                            <div class="result-item">
+                                <span class="result-label">{i18n.t("unix_time_converter.unix_seconds")}</span>
+                                <span class="result-value">{result.unix_seconds.to_string()}</span>
+                            </div>
+                            <div class="result-item">
+                                <span class="result-label">{i18n.t("unix_time_converter.unix_milliseconds")}</span>
+                                <span class="result-value">{result.unix_milliseconds.to_string()}</span>
+                            </div>
+                        </div>
+                        if let Some(warning) = result.warning.clone() {
+                            <div class="dst-warning">{"⚠ "}{warning}</div>
+                        }
+                    }
+                </div>
+
+                <div class="section calc-section">
+                    <div class="section-header">
+                        <h3>{i18n.t("unix_time_converter.difference")}</h3>
+                    </div>
+                    <div class="calc-row">
+                        <input
+                            type="text"
+                            class="input-field"
+                            placeholder={i18n.t("unix_time_converter.diff_from")}
+                            value={(*diff_a).clone()}
+                            oninput={text_setter(&diff_a)}
+                        />
+                        <span class="calc-arrow">{"→"}</span>
+                        <input
+                            type="text"
+                            class="input-field"
+                            placeholder={i18n.t("unix_time_converter.diff_to")}
+                            value={(*diff_b).clone()}
+                            oninput={text_setter(&diff_b)}
+                        />
+                    </div>
+                    <div class="action-buttons">
+                        <button
+                            class="primary-btn"
+                            onclick={on_diff}
+                            disabled={(*diff_a).trim().is_empty() || (*diff_b).trim().is_empty()}
+                        >
+                            {i18n.t("unix_time_converter.calculate")}
                         </button>
                     </div>
+                    if let Some(result) = (*diff_result).clone() {
+                        <div class="result-grid">
+                            <div class="result-item">
+                                <span class="result-label">{i18n.t("unix_time_converter.duration")}</span>
+                                <span class="result-value relative">{result.human_readable.clone()}</span>
+                            </div>
+                            <div class="result-item">
+                                <span class="result-label">{i18n.t("unix_time_converter.duration_breakdown")}</span>
+                                <span class="result-value">
+                                    {format!("{}d {}h {}m {}s", result.days, result.hours, result.minutes, result.seconds)}
+                                </span>
+                            </div>
+                            <div class="result-item">
+                                <span class="result-label">{i18n.t("unix_time_converter.total_seconds")}</span>
+                                <span class="result-value">{result.total_seconds.to_string()}</span>
+                            </div>
+                            <div class="result-item">
+                                <span class="result-label">{i18n.t("unix_time_converter.total_milliseconds")}</span>
+                                <span class="result-value">{result.total_milliseconds.to_string()}</span>
+                            </div>
+                        </div>
+                    }
                 </div>
-                <input
-                    type="text"
-                    class="input-field"
-                    placeholder={match *mode {
-                        Mode::UnixToDatetime => i18n.t("unix_time_converter.unix_placeholder"),
-                        Mode::DatetimeToUnix => i18n.t("unix_time_converter.datetime_placeholder"),
-                    }}
-                    value={(*input).clone()}
-                    oninput={on_input_change}
-                    onchange={on_change}
-                />
-                if *mode == Mode::DatetimeToUnix {
-                    <div class="format-hint">
-                        {i18n.t("unix_time_converter.format_hint")}
+            }
+
+            // Batch conversion
+            if *mode == Mode::Batch {
+                <div class="section batch-section">
+                    <div class="section-header">
+                        <h3>{i18n.t("unix_time_converter.batch_input")}</h3>
+                        <button
+                            class="secondary-btn"
+                            onclick={
+                                let batch_input = batch_input.clone();
+                                let batch_result = batch_result.clone();
+                                Callback::from(move |_| {
+                                    batch_input.set(String::new());
+                                    batch_result.set(None);
+                                })
+                            }
+                        >
+                            {i18n.t("common.clear")}
+                        </button>
                     </div>
-                }
-            </div>
+                    <textarea
+                        class="batch-textarea"
+                        placeholder={i18n.t("unix_time_converter.batch_placeholder")}
+                        value={(*batch_input).clone()}
+                        oninput={
+                            let batch_input = batch_input.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                                batch_input.set(target.value());
+                            })
+                        }
+                        spellcheck="false"
+                    />
+                    <div class="action-buttons">
+                        <button
+                            class="primary-btn"
+                            onclick={on_batch_convert}
+                            disabled={*is_processing || (*batch_input).trim().is_empty()}
+                        >
+                            if *is_processing {
+                                <span class="processing">
+                                    <span class="spinner"></span>
+                                    {i18n.t("common.processing")}
+                                </span>
+                            } else {
+                                {i18n.t("common.convert")}
+                            }
+                        </button>
+                    </div>
+                </div>
 
-            // Convert button
-            <div class="action-buttons">
-                <button
-                    class="primary-btn"
-                    onclick={on_convert}
-                    disabled={*is_processing || (*input).is_empty()}
-                >
-                    if *is_processing {
-                        <span class="processing">
-                            <span class="spinner"></span>
-                            {i18n.t("common.processing")}
-                        </span>
-                    } else {
-                        {i18n.t("common.convert")}
+                if let Some(result) = (*batch_result).clone() {
+                    if result.success {
+                        <div class="section result-section">
+                            <div class="section-header">
+                                <h3>
+                                    {format!(
+                                        "{} ({} / {})",
+                                        i18n.t("common.result"),
+                                        result.converted_count,
+                                        result.converted_count + result.skipped_count
+                                    )}
+                                </h3>
+                                <button
+                                    class={classes!("secondary-btn", (*copy_feedback).then_some("copied"))}
+                                    onclick={
+                                        let on_copy = on_copy.clone();
+                                        let value = result.formatted_output.clone();
+                                        Callback::from(move |_| on_copy.emit(value.clone()))
+                                    }
+                                >
+                                    if *copy_feedback {
+                                        {format!("✓ {}", i18n.t("common.copied"))}
+                                    } else {
+                                        {i18n.t("unix_time_converter.copy_tsv")}
+                                    }
+                                </button>
+                            </div>
+                            <div class="batch-table-wrapper">
+                                <table class="batch-table">
+                                    <thead>
+                                        <tr>
+                                            <th>{"#"}</th>
+                                            <th>{i18n.t("unix_time_converter.batch_original")}</th>
+                                            <th>{i18n.t("unix_time_converter.datetime")}</th>
+                                            <th>{"ISO 8601"}</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        { for result.entries.iter().take(MAX_BATCH_ROWS).map(|entry| html! {
+                                            <tr>
+                                                <td class="line-number">{entry.line}</td>
+                                                <td>{entry.original.clone()}</td>
+                                                <td>{entry.datetime.clone()}</td>
+                                                <td>{entry.iso8601.clone()}</td>
+                                            </tr>
+                                        }) }
+                                    </tbody>
+                                </table>
+                            </div>
+                            if result.entries.len() > MAX_BATCH_ROWS {
+                                <div class="format-hint">
+                                    {i18n.t("unix_time_converter.batch_truncated").replace("{count}", &MAX_BATCH_ROWS.to_string())}
+                                </div>
+                            }
+                        </div>
                     }
-                </button>
-            </div>
+                    if !result.warnings.is_empty() {
+                        <div class="section batch-warnings">
+                            { for result.warnings.iter().map(|w| html! {
+                                <div class="dst-warning">{"⚠ "}{w}</div>
+                            }) }
+                        </div>
+                    }
+                }
+            }
 
             // Error display
             if let Some(err) = (*error).clone() {
@@ -656,7 +1193,16 @@ pub fn unix_time_converter() -> Html {
                                     </button>
                                 </div>
                             </div>
+                            if let Some(alternative) = result.alternative_unix_seconds {
+                                <div class="result-item">
+                                    <span class="result-label">{i18n.t("unix_time_converter.alternative_unix_seconds")}</span>
+                                    <span class="result-value">{alternative.to_string()}</span>
+                                </div>
+                            }
                         </div>
+                        if let Some(warning) = result.warning.clone() {
+                            <div class="dst-warning">{"⚠ "}{warning}</div>
+                        }
                     </div>
                 }
             }
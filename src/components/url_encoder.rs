@@ -0,0 +1,510 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+use crate::components::input_history::{save_history, InputHistoryPanel};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[derive(Clone, PartialEq, Copy)]
+enum Mode {
+    Encode,
+    Decode,
+    Query,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EncodeArgs {
+    input: String,
+    encode_all: bool,
+}
+
+#[derive(Serialize)]
+struct InputArgs {
+    input: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ConvertResult {
+    success: bool,
+    output: String,
+    warnings: Vec<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryParam {
+    key: String,
+    value: String,
+    #[allow(dead_code)]
+    raw_key: String,
+    raw_value: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryParseResult {
+    success: bool,
+    base_url: Option<String>,
+    params: Vec<QueryParam>,
+    fragment: Option<String>,
+    warnings: Vec<String>,
+    error: Option<String>,
+}
+
+#[function_component(UrlEncoder)]
+pub fn url_encoder() -> Html {
+    let (i18n, _) = use_translation();
+    let mode = use_state(|| Mode::Encode);
+    let input = use_state(String::new);
+    let output = use_state(String::new);
+    let encode_all = use_state(|| true);
+    let is_processing = use_state(|| false);
+    let error = use_state(|| Option::<String>::None);
+    let warnings = use_state(Vec::<String>::new);
+    let query_result = use_state(|| Option::<QueryParseResult>::None);
+    let copy_feedback = use_state(|| false);
+    let history_refresh = use_state(|| 0u32);
+
+    let on_mode_change = {
+        let mode = mode.clone();
+        let output = output.clone();
+        let error = error.clone();
+        let warnings = warnings.clone();
+        let query_result = query_result.clone();
+        Callback::from(move |new_mode: Mode| {
+            mode.set(new_mode);
+            output.set(String::new());
+            error.set(None);
+            warnings.set(Vec::new());
+            query_result.set(None);
+        })
+    };
+
+    let on_input_change = {
+        let input = input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            input.set(target.value());
+        })
+    };
+
+    let on_encode_all_change = {
+        let encode_all = encode_all.clone();
+        Callback::from(move |e: Event| {
+            let checkbox: web_sys::HtmlInputElement = e.target_unchecked_into();
+            encode_all.set(checkbox.checked());
+        })
+    };
+
+    let history_refresh_for_html = history_refresh.clone();
+    let on_convert = {
+        let mode = mode.clone();
+        let input = input.clone();
+        let output = output.clone();
+        let encode_all = encode_all.clone();
+        let is_processing = is_processing.clone();
+        let error = error.clone();
+        let warnings = warnings.clone();
+        let query_result = query_result.clone();
+
+        Callback::from(move |_| {
+            let current_mode = *mode;
+            let input_val = (*input).clone();
+            let encode_all_val = *encode_all;
+            let output = output.clone();
+            let is_processing = is_processing.clone();
+            let error = error.clone();
+            let warnings = warnings.clone();
+            let query_result = query_result.clone();
+            let history_refresh = history_refresh.clone();
+
+            if input_val.trim().is_empty() {
+                return;
+            }
+
+            is_processing.set(true);
+
+            spawn_local(async move {
+                let input_val_for_history = input_val.clone();
+                let (mode_name, succeeded) = match current_mode {
+                    Mode::Encode | Mode::Decode => {
+                        let (cmd, args, mode_name) = if current_mode == Mode::Encode {
+                            (
+                                "encode_url_cmd",
+                                serde_wasm_bindgen::to_value(&EncodeArgs {
+                                    input: input_val,
+                                    encode_all: encode_all_val,
+                                })
+                                .unwrap(),
+                                "encode",
+                            )
+                        } else {
+                            (
+                                "decode_url_cmd",
+                                serde_wasm_bindgen::to_value(&InputArgs { input: input_val })
+                                    .unwrap(),
+                                "decode",
+                            )
+                        };
+                        let result = invoke(cmd, args).await;
+
+                        match serde_wasm_bindgen::from_value::<ConvertResult>(result) {
+                            Ok(res) if res.success => {
+                                output.set(res.output);
+                                warnings.set(res.warnings);
+                                error.set(None);
+                                (mode_name, true)
+                            }
+                            Ok(res) => {
+                                output.set(String::new());
+                                warnings.set(Vec::new());
+                                error.set(res.error);
+                                (mode_name, false)
+                            }
+                            Err(e) => {
+                                error.set(Some(format!("Failed to parse result: {:?}", e)));
+                                (mode_name, false)
+                            }
+                        }
+                    }
+                    Mode::Query => {
+                        let args =
+                            serde_wasm_bindgen::to_value(&InputArgs { input: input_val }).unwrap();
+                        let result = invoke("parse_query_string_cmd", args).await;
+
+                        match serde_wasm_bindgen::from_value::<QueryParseResult>(result) {
+                            Ok(res) if res.success => {
+                                warnings.set(res.warnings.clone());
+                                error.set(None);
+                                query_result.set(Some(res));
+                                ("query", true)
+                            }
+                            Ok(res) => {
+                                warnings.set(Vec::new());
+                                error.set(res.error);
+                                query_result.set(None);
+                                ("query", false)
+                            }
+                            Err(e) => {
+                                error.set(Some(format!("Failed to parse result: {:?}", e)));
+                                ("query", false)
+                            }
+                        }
+                    }
+                };
+
+                if succeeded {
+                    save_history(
+                        "url_encoder",
+                        serde_json::json!({"input": input_val_for_history, "mode": mode_name}),
+                        None,
+                    );
+                    history_refresh.set(*history_refresh + 1);
+                }
+
+                is_processing.set(false);
+            });
+        })
+    };
+
+    let on_copy = {
+        let output = output.clone();
+        let copy_feedback = copy_feedback.clone();
+        Callback::from(move |_| {
+            let output_val = (*output).clone();
+            let copy_feedback = copy_feedback.clone();
+
+            if !output_val.is_empty() {
+                if let Some(win) = window() {
+                    let clipboard = win.navigator().clipboard();
+                    spawn_local(async move {
+                        let _ =
+                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&output_val))
+                                .await;
+                        copy_feedback.set(true);
+
+                        let copy_feedback_reset = copy_feedback.clone();
+                        gloo_timers::callback::Timeout::new(2000, move || {
+                            copy_feedback_reset.set(false);
+                        })
+                        .forget();
+                    });
+                }
+            }
+        })
+    };
+
+    let on_clear = {
+        let input = input.clone();
+        let output = output.clone();
+        let error = error.clone();
+        let warnings = warnings.clone();
+        let query_result = query_result.clone();
+        Callback::from(move |_| {
+            input.set(String::new());
+            output.set(String::new());
+            error.set(None);
+            warnings.set(Vec::new());
+            query_result.set(None);
+        })
+    };
+
+    let on_swap = {
+        let input = input.clone();
+        let output = output.clone();
+        let mode = mode.clone();
+        let warnings = warnings.clone();
+        Callback::from(move |_| {
+            let current_output = (*output).clone();
+
+            if !current_output.is_empty() {
+                input.set(current_output);
+                output.set(String::new());
+                warnings.set(Vec::new());
+                match *mode {
+                    Mode::Encode => mode.set(Mode::Decode),
+                    Mode::Decode => mode.set(Mode::Encode),
+                    Mode::Query => {}
+                }
+            }
+        })
+    };
+
+    let on_history_restore = {
+        let input = input.clone();
+        let mode = mode.clone();
+        Callback::from(move |inputs: serde_json::Value| {
+            if let Some(val) = inputs.get("input").and_then(|v| v.as_str()) {
+                input.set(val.to_string());
+            }
+            if let Some(m) = inputs.get("mode").and_then(|v| v.as_str()) {
+                match m {
+                    "encode" => mode.set(Mode::Encode),
+                    "decode" => mode.set(Mode::Decode),
+                    "query" => mode.set(Mode::Query),
+                    _ => {}
+                }
+            }
+        })
+    };
+
+    let mode_tab = |target: Mode, label_key: &str| {
+        let on_mode_change = on_mode_change.clone();
+        html! {
+            <button
+                class={classes!("mode-tab", (*mode == target).then_some("active"))}
+                onclick={Callback::from(move |_| on_mode_change.emit(target))}
+            >
+                {i18n.t(label_key)}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="url-encoder">
+            // Mode selector
+            <div class="section mode-section">
+                <div style="display: flex; align-items: center; justify-content: space-between; margin-bottom: var(--space-2);">
+                    <InputHistoryPanel
+                        tool_id="url_encoder"
+                        on_restore={on_history_restore}
+                        refresh_trigger={*history_refresh_for_html}
+                    />
+                </div>
+                <div class="mode-tabs">
+                    {mode_tab(Mode::Encode, "url_encoder.mode_encode")}
+                    {mode_tab(Mode::Decode, "url_encoder.mode_decode")}
+                    {mode_tab(Mode::Query, "url_encoder.mode_query")}
+                </div>
+            </div>
+
+            // Options section
+            if *mode == Mode::Encode {
+                <div class="section options-section">
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={*encode_all}
+                            onchange={on_encode_all_change}
+                        />
+                        <span>{i18n.t("url_encoder.encode_all")}</span>
+                    </label>
+                    <p class="option-hint">
+                        {if *encode_all {
+                            i18n.t("url_encoder.encode_all_hint")
+                        } else {
+                            i18n.t("url_encoder.encode_uri_hint")
+                        }}
+                    </p>
+                </div>
+            }
+
+            // Input section
+            <div class="section input-section">
+                <div class="section-header">
+                    <h3>
+                        {match *mode {
+                            Mode::Encode => i18n.t("url_encoder.text_input"),
+                            Mode::Decode => i18n.t("url_encoder.encoded_input"),
+                            Mode::Query => i18n.t("url_encoder.url_input"),
+                        }}
+                    </h3>
+                    <button class="secondary-btn" onclick={on_clear}>
+                        {i18n.t("common.clear")}
+                    </button>
+                </div>
+                <textarea
+                    class="input-textarea"
+                    placeholder={match *mode {
+                        Mode::Encode => i18n.t("url_encoder.encode_placeholder"),
+                        Mode::Decode => i18n.t("url_encoder.decode_placeholder"),
+                        Mode::Query => i18n.t("url_encoder.query_placeholder"),
+                    }}
+                    value={(*input).clone()}
+                    oninput={on_input_change}
+                />
+            </div>
+
+            // Action buttons
+            <div class="action-buttons">
+                <button
+                    class="primary-btn"
+                    onclick={on_convert}
+                    disabled={*is_processing || (*input).is_empty()}
+                >
+                    if *is_processing {
+                        <span class="processing">
+                            <span class="spinner"></span>
+                            {i18n.t("common.processing")}
+                        </span>
+                    } else {
+                        {match *mode {
+                            Mode::Encode => i18n.t("url_encoder.encode_btn"),
+                            Mode::Decode => i18n.t("url_encoder.decode_btn"),
+                            Mode::Query => i18n.t("url_encoder.parse_btn"),
+                        }}
+                    }
+                </button>
+
+                if !(*output).is_empty() && *mode != Mode::Query {
+                    <button class="secondary-btn swap-btn" onclick={on_swap}>
+                        <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                            <path d="M7 16V4M7 4L3 8M7 4L11 8M17 8V20M17 20L21 16M17 20L13 16"/>
+                        </svg>
+                        {i18n.t("common.swap")}
+                    </button>
+                }
+            </div>
+
+            // Error display
+            if let Some(err) = (*error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">
+                        {"⚠ "}{err}
+                    </div>
+                </div>
+            }
+
+            // Double-encoding warnings
+            if !(*warnings).is_empty() {
+                <div class="section warning-section">
+                    { for (*warnings).iter().map(|w| html! {
+                        <div class="warning-message">{"⚠ "}{w}</div>
+                    }) }
+                </div>
+            }
+
+            // Output section
+            if !(*output).is_empty() && *mode != Mode::Query {
+                <div class="section output-section">
+                    <div class="section-header">
+                        <h3>
+                            {if *mode == Mode::Encode {
+                                i18n.t("url_encoder.encoded_output")
+                            } else {
+                                i18n.t("url_encoder.decoded_output")
+                            }}
+                        </h3>
+                        <div class="output-actions">
+                            <button
+                                class={classes!("secondary-btn", (*copy_feedback).then_some("copied"))}
+                                onclick={on_copy}
+                            >
+                                if *copy_feedback {
+                                    {format!("✓ {}", i18n.t("common.copied"))}
+                                } else {
+                                    {i18n.t("common.copy")}
+                                }
+                            </button>
+                        </div>
+                    </div>
+                    <textarea
+                        class="output-textarea"
+                        readonly=true
+                        value={(*output).clone()}
+                    />
+                    <div class="output-stats">
+                        <span class="stat-item">
+                            {format!("{} {}", (*output).chars().count(), i18n.t("common.characters"))}
+                        </span>
+                    </div>
+                </div>
+            }
+
+            // Query parse result
+            if *mode == Mode::Query {
+                if let Some(res) = (*query_result).clone() {
+                    <div class="section output-section">
+                        <div class="section-header">
+                            <h3>{format!("{} ({})", i18n.t("url_encoder.query_params"), res.params.len())}</h3>
+                        </div>
+                        if let Some(base_url) = res.base_url.clone() {
+                            <div class="query-meta">
+                                <span class="query-meta-label">{i18n.t("url_encoder.base_url")}</span>
+                                <code>{base_url}</code>
+                            </div>
+                        }
+                        if res.params.is_empty() {
+                            <p class="query-empty">{i18n.t("url_encoder.no_params")}</p>
+                        } else {
+                            <table class="query-table">
+                                <thead>
+                                    <tr>
+                                        <th>{i18n.t("url_encoder.key")}</th>
+                                        <th>{i18n.t("url_encoder.value")}</th>
+                                        <th>{i18n.t("url_encoder.raw_value")}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for res.params.iter().map(|p| html! {
+                                        <tr>
+                                            <td>{p.key.clone()}</td>
+                                            <td>{p.value.clone()}</td>
+                                            <td class="raw">{p.raw_value.clone()}</td>
+                                        </tr>
+                                    }) }
+                                </tbody>
+                            </table>
+                        }
+                        if let Some(fragment) = res.fragment.clone() {
+                            <div class="query-meta">
+                                <span class="query-meta-label">{i18n.t("url_encoder.fragment")}</span>
+                                <code>{fragment}</code>
+                            </div>
+                        }
+                    </div>
+                }
+            }
+        </div>
+    }
+}
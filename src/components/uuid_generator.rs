@@ -1,8 +1,9 @@
+use crate::components::clipboard_history::copy_sensitive_text;
+use crate::components::generation_history::{append_generation_history, GenerationHistory};
 use i18nrs::yew::use_translation;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::window;
 use yew::prelude::*;
 
 #[wasm_bindgen]
@@ -13,18 +14,60 @@ extern "C" {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum UuidVersion {
+    V3,
     V4,
+    V5,
     V7,
 }
 
-impl UuidVersion {
-    #[allow(dead_code)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+enum IdKind {
+    Ulid,
+    NanoId,
+}
+
+/// Everything the generator can produce: a UUID version or one of the other ID formats
+#[derive(Debug, Clone, PartialEq)]
+enum IdType {
+    Uuid(UuidVersion),
+    Other(IdKind),
+}
+
+impl IdType {
+    const ALL: [IdType; 6] = [
+        IdType::Uuid(UuidVersion::V4),
+        IdType::Uuid(UuidVersion::V7),
+        IdType::Uuid(UuidVersion::V3),
+        IdType::Uuid(UuidVersion::V5),
+        IdType::Other(IdKind::Ulid),
+        IdType::Other(IdKind::NanoId),
+    ];
+
+    fn value(&self) -> &'static str {
+        match self {
+            IdType::Uuid(UuidVersion::V3) => "V3",
+            IdType::Uuid(UuidVersion::V4) => "V4",
+            IdType::Uuid(UuidVersion::V5) => "V5",
+            IdType::Uuid(UuidVersion::V7) => "V7",
+            IdType::Other(IdKind::Ulid) => "ULID",
+            IdType::Other(IdKind::NanoId) => "NanoID",
+        }
+    }
+
     fn translation_key(&self) -> &'static str {
         match self {
-            UuidVersion::V4 => "uuid_generator.version_v4",
-            UuidVersion::V7 => "uuid_generator.version_v7",
+            IdType::Uuid(UuidVersion::V3) => "uuid_generator.version_v3",
+            IdType::Uuid(UuidVersion::V4) => "uuid_generator.version_v4",
+            IdType::Uuid(UuidVersion::V5) => "uuid_generator.version_v5",
+            IdType::Uuid(UuidVersion::V7) => "uuid_generator.version_v7",
+            IdType::Other(IdKind::Ulid) => "uuid_generator.version_ulid",
+            IdType::Other(IdKind::NanoId) => "uuid_generator.version_nanoid",
         }
     }
+
+    fn is_name_based(&self) -> bool {
+        matches!(self, IdType::Uuid(UuidVersion::V3 | UuidVersion::V5))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -67,6 +110,21 @@ struct GenerateUuidsArgs {
     version: UuidVersion,
     format: UuidFormat,
     count: u32,
+    namespace: Option<String>,
+    name: Option<String>,
+}
+
+#[derive(Serialize)]
+struct IdGenerateOptions {
+    count: u32,
+    length: Option<usize>,
+    alphabet: Option<String>,
+}
+
+#[derive(Serialize)]
+struct GenerateIdsArgs {
+    kind: IdKind,
+    options: IdGenerateOptions,
 }
 
 #[derive(Serialize)]
@@ -78,45 +136,144 @@ struct ValidateUuidArgs {
 struct UuidGenerateResult {
     success: bool,
     uuids: Vec<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GeneratedId {
+    value: String,
+    timestamp_utc: Option<String>,
+    #[allow(dead_code)]
+    timestamp_local: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdGenerateResult {
+    success: bool,
+    ids: Vec<GeneratedId>,
+    entropy_bits: Option<f64>,
+    collision_estimate: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct UlidDecodeResult {
+    valid: bool,
     #[allow(dead_code)]
+    unix_milliseconds: Option<i64>,
+    timestamp_utc: Option<String>,
+    timestamp_local: Option<String>,
     error: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 struct UuidValidateResult {
     valid: bool,
+    normalized: Option<String>,
+    #[allow(dead_code)]
+    version_number: Option<u8>,
     version: Option<String>,
     variant: Option<String>,
+    #[allow(dead_code)]
+    unix_milliseconds: Option<i64>,
+    timestamp_utc: Option<String>,
+    timestamp_local: Option<String>,
+    node: Option<String>,
+    clock_sequence: Option<u16>,
     error: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct UuidBulkValidateEntry {
+    line: usize,
+    input: String,
+    result: UuidValidateResult,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct UuidBulkValidateResult {
+    entries: Vec<UuidBulkValidateEntry>,
+    valid_count: usize,
+    invalid_count: usize,
+}
+
 #[derive(Clone, PartialEq)]
 struct GeneratedUuid {
     value: String,
+    timestamp: Option<String>,
     copied: bool,
 }
 
+/// ULIDs are 26 Crockford base32 characters; anything else is validated as a UUID
+fn looks_like_ulid(input: &str) -> bool {
+    let input = input.trim();
+    input.len() == 26 && input.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
 #[function_component(UuidGenerator)]
 pub fn uuid_generator() -> Html {
     let (i18n, _) = use_translation();
-    let selected_version = use_state(|| UuidVersion::V4);
+    let selected_type = use_state(|| IdType::Uuid(UuidVersion::V4));
     let selected_format = use_state(|| UuidFormat::Standard);
     let count = use_state(|| 1u32);
+    let namespace = use_state(|| "DNS".to_string());
+    let name = use_state(String::new);
+    let nanoid_length = use_state(|| 21usize);
+    let nanoid_alphabet = use_state(String::new);
     let generated_uuids = use_state(Vec::<GeneratedUuid>::new);
+    let id_info = use_state(|| Option::<(f64, Option<String>)>::None);
+    let generate_error = use_state(|| Option::<String>::None);
     let is_generating = use_state(|| false);
     let validate_input = use_state(String::new);
     let validate_result = use_state(|| Option::<UuidValidateResult>::None);
+    let ulid_result = use_state(|| Option::<UlidDecodeResult>::None);
+    let bulk_input = use_state(String::new);
+    let bulk_result = use_state(|| Option::<UuidBulkValidateResult>::None);
     let copy_all_feedback = use_state(|| false);
+    let history_version = use_state(|| 0u32);
 
-    let on_version_change = {
-        let selected_version = selected_version.clone();
+    let on_type_change = {
+        let selected_type = selected_type.clone();
         Callback::from(move |e: Event| {
             let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
-            let version = match select.value().as_str() {
-                "V7" => UuidVersion::V7,
-                _ => UuidVersion::V4,
-            };
-            selected_version.set(version);
+            let value = select.value();
+            if let Some(id_type) = IdType::ALL.into_iter().find(|t| t.value() == value) {
+                selected_type.set(id_type);
+            }
+        })
+    };
+
+    let on_namespace_change = {
+        let namespace = namespace.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            namespace.set(input.value());
+        })
+    };
+
+    let on_name_change = {
+        let name = name.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            name.set(target.value());
+        })
+    };
+
+    let on_nanoid_length_change = {
+        let nanoid_length = nanoid_length.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<usize>() {
+                nanoid_length.set(value.clamp(1, 128));
+            }
+        })
+    };
+
+    let on_nanoid_alphabet_change = {
+        let nanoid_alphabet = nanoid_alphabet.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            nanoid_alphabet.set(input.value());
         })
     };
 
@@ -147,42 +304,107 @@ pub fn uuid_generator() -> Html {
     };
 
     let on_generate = {
-        let selected_version = selected_version.clone();
+        let selected_type = selected_type.clone();
         let selected_format = selected_format.clone();
         let count = count.clone();
+        let namespace = namespace.clone();
+        let name = name.clone();
+        let nanoid_length = nanoid_length.clone();
+        let nanoid_alphabet = nanoid_alphabet.clone();
         let generated_uuids = generated_uuids.clone();
+        let id_info = id_info.clone();
+        let generate_error = generate_error.clone();
         let is_generating = is_generating.clone();
+        let history_version = history_version.clone();
 
         Callback::from(move |_| {
-            let version = (*selected_version).clone();
+            let id_type = (*selected_type).clone();
             let format = (*selected_format).clone();
             let count_value = *count;
+            let namespace = (*namespace).clone();
+            let name = (*name).clone();
+            let length = *nanoid_length;
+            let alphabet = (*nanoid_alphabet).clone();
             let generated_uuids = generated_uuids.clone();
+            let id_info = id_info.clone();
+            let generate_error = generate_error.clone();
             let is_generating = is_generating.clone();
+            let history_version = history_version.clone();
 
             is_generating.set(true);
 
             spawn_local(async move {
-                let args = serde_wasm_bindgen::to_value(&GenerateUuidsArgs {
-                    version,
-                    format,
-                    count: count_value,
-                })
-                .unwrap();
-
-                let result = invoke("generate_uuids_cmd", args).await;
-
-                if let Ok(res) = serde_wasm_bindgen::from_value::<UuidGenerateResult>(result) {
-                    if res.success {
-                        let new_uuids: Vec<GeneratedUuid> = res
-                            .uuids
-                            .into_iter()
-                            .map(|value| GeneratedUuid {
-                                value,
-                                copied: false,
-                            })
-                            .collect();
+                let outcome = match id_type {
+                    IdType::Uuid(version) => {
+                        let args = serde_wasm_bindgen::to_value(&GenerateUuidsArgs {
+                            version,
+                            format,
+                            count: count_value,
+                            namespace: Some(namespace),
+                            name: Some(name),
+                        })
+                        .unwrap();
+                        let result = invoke("generate_uuids_cmd", args).await;
+
+                        match serde_wasm_bindgen::from_value::<UuidGenerateResult>(result) {
+                            Ok(res) if res.success => Ok((
+                                res.uuids
+                                    .into_iter()
+                                    .map(|value| GeneratedUuid {
+                                        value,
+                                        timestamp: None,
+                                        copied: false,
+                                    })
+                                    .collect::<Vec<_>>(),
+                                None,
+                            )),
+                            Ok(res) => Err(res.error.unwrap_or_default()),
+                            Err(e) => Err(format!("Failed to parse result: {:?}", e)),
+                        }
+                    }
+                    IdType::Other(kind) => {
+                        let args = serde_wasm_bindgen::to_value(&GenerateIdsArgs {
+                            kind,
+                            options: IdGenerateOptions {
+                                count: count_value,
+                                length: Some(length),
+                                alphabet: (!alphabet.is_empty()).then_some(alphabet),
+                            },
+                        })
+                        .unwrap();
+                        let result = invoke("generate_ids_cmd", args).await;
+
+                        match serde_wasm_bindgen::from_value::<IdGenerateResult>(result) {
+                            Ok(res) if res.success => Ok((
+                                res.ids
+                                    .into_iter()
+                                    .map(|id| GeneratedUuid {
+                                        value: id.value,
+                                        timestamp: id.timestamp_utc,
+                                        copied: false,
+                                    })
+                                    .collect::<Vec<_>>(),
+                                res.entropy_bits.map(|bits| (bits, res.collision_estimate)),
+                            )),
+                            Ok(res) => Err(res.error.unwrap_or_default()),
+                            Err(e) => Err(format!("Failed to parse result: {:?}", e)),
+                        }
+                    }
+                };
+
+                match outcome {
+                    Ok((new_uuids, info)) => {
+                        let values = new_uuids.iter().map(|u| u.value.clone()).collect();
                         generated_uuids.set(new_uuids);
+                        id_info.set(info);
+                        generate_error.set(None);
+                        append_generation_history("uuid_generator", values).await;
+                        history_version.set(*history_version + 1);
+                    }
+                    Err(e) => {
+                        generated_uuids.set(Vec::new());
+                        id_info.set(None);
+                        generate_error.set(Some(e));
                     }
                 }
 
@@ -197,31 +419,27 @@ pub fn uuid_generator() -> Html {
             let generated_uuids = generated_uuids.clone();
             if let Some(uuid) = (*generated_uuids).get(index) {
                 let value = uuid.value.clone();
-                if let Some(win) = window() {
-                    let clipboard = win.navigator().clipboard();
-                    let generated_uuids_inner = generated_uuids.clone();
-                    spawn_local(async move {
-                        let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&value))
-                            .await;
-
-                        let mut uuids = (*generated_uuids_inner).clone();
+                let generated_uuids_inner = generated_uuids.clone();
+                spawn_local(async move {
+                    copy_sensitive_text(&value).await;
+
+                    let mut uuids = (*generated_uuids_inner).clone();
+                    if let Some(uuid) = uuids.get_mut(index) {
+                        uuid.copied = true;
+                    }
+                    generated_uuids_inner.set(uuids);
+
+                    // Reset copied state after 2 seconds
+                    let generated_uuids_reset = generated_uuids_inner.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        let mut uuids = (*generated_uuids_reset).clone();
                         if let Some(uuid) = uuids.get_mut(index) {
-                            uuid.copied = true;
+                            uuid.copied = false;
                         }
-                        generated_uuids_inner.set(uuids);
-
-                        // Reset copied state after 2 seconds
-                        let generated_uuids_reset = generated_uuids_inner.clone();
-                        gloo_timers::callback::Timeout::new(2000, move || {
-                            let mut uuids = (*generated_uuids_reset).clone();
-                            if let Some(uuid) = uuids.get_mut(index) {
-                                uuid.copied = false;
-                            }
-                            generated_uuids_reset.set(uuids);
-                        })
-                        .forget();
-                    });
-                }
+                        generated_uuids_reset.set(uuids);
+                    })
+                    .forget();
+                });
             }
         })
     };
@@ -239,21 +457,16 @@ pub fn uuid_generator() -> Html {
                     .collect::<Vec<_>>()
                     .join("\n");
 
-                if let Some(win) = window() {
-                    let clipboard = win.navigator().clipboard();
-                    spawn_local(async move {
-                        let _ =
-                            wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&all_values))
-                                .await;
-                        copy_all_feedback.set(true);
-
-                        let copy_all_feedback_reset = copy_all_feedback.clone();
-                        gloo_timers::callback::Timeout::new(2000, move || {
-                            copy_all_feedback_reset.set(false);
-                        })
-                        .forget();
-                    });
-                }
+                spawn_local(async move {
+                    copy_sensitive_text(&all_values).await;
+                    copy_all_feedback.set(true);
+
+                    let copy_all_feedback_reset = copy_all_feedback.clone();
+                    gloo_timers::callback::Timeout::new(2000, move || {
+                        copy_all_feedback_reset.set(false);
+                    })
+                    .forget();
+                });
             }
         })
     };
@@ -269,26 +482,79 @@ pub fn uuid_generator() -> Html {
     let on_validate = {
         let validate_input = validate_input.clone();
         let validate_result = validate_result.clone();
+        let ulid_result = ulid_result.clone();
         Callback::from(move |_| {
             let input = (*validate_input).clone();
             let validate_result = validate_result.clone();
+            let ulid_result = ulid_result.clone();
 
+            validate_result.set(None);
+            ulid_result.set(None);
             if input.trim().is_empty() {
-                validate_result.set(None);
                 return;
             }
 
             spawn_local(async move {
+                let is_ulid = looks_like_ulid(&input);
                 let args = serde_wasm_bindgen::to_value(&ValidateUuidArgs { input }).unwrap();
-                let result = invoke("validate_uuid_cmd", args).await;
 
-                if let Ok(res) = serde_wasm_bindgen::from_value::<UuidValidateResult>(result) {
-                    validate_result.set(Some(res));
+                if is_ulid {
+                    let result = invoke("decode_ulid_cmd", args).await;
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<UlidDecodeResult>(result) {
+                        ulid_result.set(Some(res));
+                    }
+                } else {
+                    let result = invoke("validate_uuid_cmd", args).await;
+                    if let Ok(res) = serde_wasm_bindgen::from_value::<UuidValidateResult>(result) {
+                        validate_result.set(Some(res));
+                    }
                 }
             });
         })
     };
 
+    let on_bulk_input_change = {
+        let bulk_input = bulk_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            bulk_input.set(target.value());
+        })
+    };
+
+    let on_bulk_validate = {
+        let bulk_input = bulk_input.clone();
+        let bulk_result = bulk_result.clone();
+        Callback::from(move |_| {
+            let input = (*bulk_input).clone();
+            let bulk_result = bulk_result.clone();
+
+            if input.trim().is_empty() {
+                bulk_result.set(None);
+                return;
+            }
+
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&ValidateUuidArgs { input }).unwrap();
+                let result = invoke("validate_uuids_bulk_cmd", args).await;
+
+                if let Ok(res) = serde_wasm_bindgen::from_value::<UuidBulkValidateResult>(result) {
+                    bulk_result.set(Some(res));
+                }
+            });
+        })
+    };
+
+    let info_row = |label: String, value: Option<String>| {
+        html! {
+            if let Some(value) = value {
+                <div class="validate-info">
+                    <span class="info-label">{label}</span>
+                    <span class="info-value">{value}</span>
+                </div>
+            }
+        }
+    };
+
     html! {
         <div class="uuid-generator">
             // Generate Section
@@ -298,55 +564,113 @@ pub fn uuid_generator() -> Html {
                 <div class="uuid-options">
                     <div class="form-group">
                         <label>{i18n.t("uuid_generator.version_label")}</label>
-                        <select class="form-select" onchange={on_version_change}>
-                            <option value="V4" selected={*selected_version == UuidVersion::V4}>
-                                {i18n.t("uuid_generator.version_v4")}
-                            </option>
-                            <option value="V7" selected={*selected_version == UuidVersion::V7}>
-                                {i18n.t("uuid_generator.version_v7")}
-                            </option>
+                        <select class="form-select" onchange={on_type_change}>
+                            { for IdType::ALL.iter().map(|t| html! {
+                                <option value={t.value()} selected={*selected_type == *t}>
+                                    {i18n.t(t.translation_key())}
+                                </option>
+                            }) }
                         </select>
                     </div>
 
-                    <div class="form-group">
-                        <label>{i18n.t("uuid_generator.format_label")}</label>
-                        <select class="form-select" onchange={on_format_change}>
-                            <option value="Standard" selected={*selected_format == UuidFormat::Standard}>
-                                {i18n.t(UuidFormat::Standard.translation_key())}
-                            </option>
-                            <option value="NoHyphens" selected={*selected_format == UuidFormat::NoHyphens}>
-                                {i18n.t(UuidFormat::NoHyphens.translation_key())}
-                            </option>
-                            <option value="Uppercase" selected={*selected_format == UuidFormat::Uppercase}>
-                                {i18n.t(UuidFormat::Uppercase.translation_key())}
-                            </option>
-                            <option value="UppercaseNoHyphens" selected={*selected_format == UuidFormat::UppercaseNoHyphens}>
-                                {i18n.t(UuidFormat::UppercaseNoHyphens.translation_key())}
-                            </option>
-                            <option value="Braces" selected={*selected_format == UuidFormat::Braces}>
-                                {i18n.t(UuidFormat::Braces.translation_key())}
-                            </option>
-                            <option value="Urn" selected={*selected_format == UuidFormat::Urn}>
-                                {i18n.t(UuidFormat::Urn.translation_key())}
-                            </option>
-                        </select>
-                        <div class="format-example">
-                            {i18n.t("common.example")}{": "}{selected_format.example()}
+                    if matches!(*selected_type, IdType::Uuid(_)) {
+                        <div class="form-group">
+                            <label>{i18n.t("uuid_generator.format_label")}</label>
+                            <select class="form-select" onchange={on_format_change}>
+                                <option value="Standard" selected={*selected_format == UuidFormat::Standard}>
+                                    {i18n.t(UuidFormat::Standard.translation_key())}
+                                </option>
+                                <option value="NoHyphens" selected={*selected_format == UuidFormat::NoHyphens}>
+                                    {i18n.t(UuidFormat::NoHyphens.translation_key())}
+                                </option>
+                                <option value="Uppercase" selected={*selected_format == UuidFormat::Uppercase}>
+                                    {i18n.t(UuidFormat::Uppercase.translation_key())}
+                                </option>
+                                <option value="UppercaseNoHyphens" selected={*selected_format == UuidFormat::UppercaseNoHyphens}>
+                                    {i18n.t(UuidFormat::UppercaseNoHyphens.translation_key())}
+                                </option>
+                                <option value="Braces" selected={*selected_format == UuidFormat::Braces}>
+                                    {i18n.t(UuidFormat::Braces.translation_key())}
+                                </option>
+                                <option value="Urn" selected={*selected_format == UuidFormat::Urn}>
+                                    {i18n.t(UuidFormat::Urn.translation_key())}
+                                </option>
+                            </select>
+                            <div class="format-example">
+                                {i18n.t("common.example")}{": "}{selected_format.example()}
+                            </div>
                         </div>
-                    </div>
+                    }
+
+                    if selected_type.is_name_based() {
+                        <div class="form-group">
+                            <label>{i18n.t("uuid_generator.namespace_label")}</label>
+                            <input
+                                type="text"
+                                class="form-input"
+                                list="uuid-namespaces"
+                                value={(*namespace).clone()}
+                                oninput={on_namespace_change}
+                            />
+                            <datalist id="uuid-namespaces">
+                                <option value="DNS" />
+                                <option value="URL" />
+                                <option value="OID" />
+                                <option value="X500" />
+                            </datalist>
+                        </div>
+                    } else {
+                        <div class="form-group">
+                            <label>{i18n.t("uuid_generator.count_label")}</label>
+                            <input
+                                type="number"
+                                class="form-input"
+                                min="1"
+                                max="100"
+                                value={count.to_string()}
+                                oninput={on_count_change}
+                            />
+                        </div>
+                    }
+
+                    if *selected_type == IdType::Other(IdKind::NanoId) {
+                        <div class="form-group">
+                            <label>{i18n.t("uuid_generator.length_label")}</label>
+                            <input
+                                type="number"
+                                class="form-input"
+                                min="1"
+                                max="128"
+                                value={nanoid_length.to_string()}
+                                oninput={on_nanoid_length_change}
+                            />
+                        </div>
+                        <div class="form-group">
+                            <label>{i18n.t("uuid_generator.alphabet_label")}</label>
+                            <input
+                                type="text"
+                                class="form-input"
+                                placeholder="_-0-9a-zA-Z"
+                                value={(*nanoid_alphabet).clone()}
+                                oninput={on_nanoid_alphabet_change}
+                                spellcheck="false"
+                            />
+                        </div>
+                    }
+                </div>
 
+                if selected_type.is_name_based() {
                     <div class="form-group">
-                        <label>{i18n.t("uuid_generator.count_label")}</label>
-                        <input
-                            type="number"
-                            class="form-input"
-                            min="1"
-                            max="100"
-                            value={count.to_string()}
-                            oninput={on_count_change}
+                        <label>{i18n.t("uuid_generator.name_label")}</label>
+                        <textarea
+                            class="uuid-name-textarea"
+                            placeholder={i18n.t("uuid_generator.name_placeholder")}
+                            value={(*name).clone()}
+                            oninput={on_name_change}
+                            spellcheck="false"
                         />
                     </div>
-                </div>
+                }
 
                 <button
                     class="primary-btn generate-btn"
@@ -364,6 +688,12 @@ pub fn uuid_generator() -> Html {
                 </button>
             </div>
 
+            if let Some(err) = (*generate_error).clone() {
+                <div class="section error-section">
+                    <div class="error-message">{"⚠ "}{err}</div>
+                </div>
+            }
+
             // Generated UUIDs Section
             if !generated_uuids.is_empty() {
                 <div class="section uuid-results-section">
@@ -380,6 +710,14 @@ pub fn uuid_generator() -> Html {
                             }
                         </button>
                     </div>
+                    if let Some((bits, estimate)) = (*id_info).clone() {
+                        <div class="id-info">
+                            <span>{i18n.t("uuid_generator.entropy_info").replace("{bits}", &format!("{:.1}", bits))}</span>
+                            if let Some(estimate) = estimate {
+                                <span class="collision-estimate">{estimate}</span>
+                            }
+                        </div>
+                    }
                     <div class="uuid-list">
                         { for (*generated_uuids).iter().enumerate().map(|(index, uuid)| {
                             let on_copy = {
@@ -389,6 +727,9 @@ pub fn uuid_generator() -> Html {
                             html! {
                                 <div class="uuid-item">
                                     <code class="uuid-value">{&uuid.value}</code>
+                                    if let Some(timestamp) = &uuid.timestamp {
+                                        <span class="uuid-timestamp">{timestamp}</span>
+                                    }
                                     <button
                                         class={classes!("copy-btn", uuid.copied.then_some("copied"))}
                                         onclick={on_copy}
@@ -406,6 +747,8 @@ pub fn uuid_generator() -> Html {
                 </div>
             }
 
+            <GenerationHistory tool="uuid_generator" refresh={*history_version} />
+
             // Validate Section
             <div class="section uuid-validate-section">
                 <h3>{i18n.t("uuid_generator.validate_section")}</h3>
@@ -426,18 +769,16 @@ pub fn uuid_generator() -> Html {
                     <div class={classes!("validate-result", result.valid.then_some("valid").or(Some("invalid")))}>
                         if result.valid {
                             <div class="validate-status">{format!("✓ {}", i18n.t("uuid_generator.valid_uuid"))}</div>
-                            if let Some(version) = &result.version {
-                                <div class="validate-info">
-                                    <span class="info-label">{i18n.t("uuid_generator.version_info")}</span>
-                                    <span class="info-value">{version}</span>
-                                </div>
-                            }
-                            if let Some(variant) = &result.variant {
-                                <div class="validate-info">
-                                    <span class="info-label">{i18n.t("uuid_generator.variant_info")}</span>
-                                    <span class="info-value">{variant}</span>
-                                </div>
-                            }
+                            {info_row(i18n.t("uuid_generator.normalized_info"), result.normalized.clone())}
+                            {info_row(i18n.t("uuid_generator.version_info"), result.version.clone())}
+                            {info_row(i18n.t("uuid_generator.variant_info"), result.variant.clone())}
+                            {info_row(i18n.t("uuid_generator.timestamp_utc_info"), result.timestamp_utc.clone())}
+                            {info_row(i18n.t("uuid_generator.timestamp_local_info"), result.timestamp_local.clone())}
+                            {info_row(i18n.t("uuid_generator.node_info"), result.node.clone())}
+                            {info_row(
+                                i18n.t("uuid_generator.clock_sequence_info"),
+                                result.clock_sequence.map(|seq| format!("{} (0x{:04x})", seq, seq)),
+                            )}
                         } else {
                             <div class="validate-status">{format!("✕ {}", i18n.t("uuid_generator.invalid_uuid"))}</div>
                             if let Some(error) = &result.error {
@@ -446,6 +787,81 @@ pub fn uuid_generator() -> Html {
                         }
                     </div>
                 }
+
+                if let Some(result) = &*ulid_result {
+                    <div class={classes!("validate-result", if result.valid { "valid" } else { "invalid" })}>
+                        if result.valid {
+                            <div class="validate-status">{format!("✓ {}", i18n.t("uuid_generator.valid_ulid"))}</div>
+                            {info_row(i18n.t("uuid_generator.timestamp_utc_info"), result.timestamp_utc.clone())}
+                            {info_row(i18n.t("uuid_generator.timestamp_local_info"), result.timestamp_local.clone())}
+                        } else {
+                            <div class="validate-status">{format!("✕ {}", i18n.t("uuid_generator.invalid_ulid"))}</div>
+                            if let Some(error) = &result.error {
+                                <div class="validate-error">{error}</div>
+                            }
+                        }
+                    </div>
+                }
+            </div>
+
+            // Bulk Validate Section
+            <div class="section uuid-bulk-section">
+                <h3>{i18n.t("uuid_generator.bulk_section")}</h3>
+                <textarea
+                    class="uuid-bulk-textarea"
+                    placeholder={i18n.t("uuid_generator.bulk_placeholder")}
+                    value={(*bulk_input).clone()}
+                    oninput={on_bulk_input_change}
+                    spellcheck="false"
+                />
+                <button
+                    class="secondary-btn"
+                    onclick={on_bulk_validate}
+                    disabled={(*bulk_input).trim().is_empty()}
+                >
+                    {i18n.t("common.validate")}
+                </button>
+
+                if let Some(result) = &*bulk_result {
+                    <div class="uuid-bulk-summary">
+                        <span class="bulk-valid">
+                            {i18n.t("uuid_generator.bulk_valid_count").replace("{count}", &result.valid_count.to_string())}
+                        </span>
+                        <span class="bulk-invalid">
+                            {i18n.t("uuid_generator.bulk_invalid_count").replace("{count}", &result.invalid_count.to_string())}
+                        </span>
+                    </div>
+                    <div class="uuid-bulk-table-wrapper">
+                        <table class="uuid-bulk-table">
+                            <thead>
+                                <tr>
+                                    <th>{i18n.t("uuid_generator.bulk_line")}</th>
+                                    <th>{i18n.t("uuid_generator.bulk_input")}</th>
+                                    <th>{i18n.t("uuid_generator.version_info")}</th>
+                                    <th>{i18n.t("uuid_generator.timestamp_utc_info")}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                { for result.entries.iter().map(|entry| html! {
+                                    <tr class={if entry.result.valid { "valid" } else { "invalid" }}>
+                                        <td>{entry.line}</td>
+                                        <td class="bulk-uuid">
+                                            {entry.result.normalized.clone().unwrap_or_else(|| entry.input.clone())}
+                                        </td>
+                                        if entry.result.valid {
+                                            <td>{entry.result.version.clone().unwrap_or_default()}</td>
+                                            <td>{entry.result.timestamp_utc.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        } else {
+                                            <td colspan="2" class="bulk-error">
+                                                {entry.result.error.clone().unwrap_or_default()}
+                                            </td>
+                                        }
+                                    </tr>
+                                }) }
+                            </tbody>
+                        </table>
+                    </div>
+                }
             </div>
         </div>
     }
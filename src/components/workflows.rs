@@ -0,0 +1,755 @@
+use i18nrs::yew::use_translation;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "opener"], js_name = revealItemInDir)]
+    async fn reveal_item_in_dir(path: &str) -> JsValue;
+}
+
+const INPUT_EXTENSIONS: [&str; 8] = ["png", "jpg", "jpeg", "gif", "webp", "avif", "bmp", "tiff"];
+/// (value, label); an empty value keeps the current format
+const COMPRESS_FORMATS: [(&str, &str); 5] = [
+    ("", "—"),
+    ("webp", "WebP"),
+    ("jpeg", "JPEG"),
+    ("png", "PNG"),
+    ("avif", "AVIF"),
+];
+const FILTERS: [&str; 5] = ["Grayscale", "Sepia", "Invert", "Blur", "Sharpen"];
+const COMMANDS: [&str; 4] = [
+    "compress_image",
+    "resize_image",
+    "apply_filter",
+    "encode_image_to_base64",
+];
+
+#[derive(Serialize)]
+struct OpenDialogOptions {
+    multiple: bool,
+    filters: Vec<FileFilter>,
+}
+
+#[derive(Serialize)]
+struct FileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkflowStep {
+    command: String,
+    params: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Workflow {
+    id: String,
+    name: String,
+    steps: Vec<WorkflowStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkflowStepResult {
+    command: String,
+    success: bool,
+    output_path: Option<String>,
+    output_size: u64,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkflowRunResult {
+    success: bool,
+    steps: Vec<WorkflowStepResult>,
+    output_path: Option<String>,
+    text_output: Option<String>,
+    failed_step: Option<usize>,
+    intermediate_paths: Vec<String>,
+    run_dir: String,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SaveArgs {
+    name: String,
+    steps: Vec<WorkflowStep>,
+}
+
+#[derive(Serialize)]
+struct DeleteArgs {
+    id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RunArgs {
+    workflow_id: String,
+    input_path: String,
+}
+
+/// A step being edited, with numeric fields kept as typed text
+#[derive(Debug, Clone, PartialEq)]
+enum StepDraft {
+    Compress {
+        quality: u8,
+        format: String,
+    },
+    Resize {
+        width: String,
+        height: String,
+        maintain_aspect: bool,
+    },
+    Filter {
+        filter: String,
+    },
+    Base64,
+}
+
+impl StepDraft {
+    fn new(command: &str) -> Self {
+        match command {
+            "resize_image" => StepDraft::Resize {
+                width: "1280".to_string(),
+                height: "1280".to_string(),
+                maintain_aspect: true,
+            },
+            "apply_filter" => StepDraft::Filter {
+                filter: FILTERS[0].to_string(),
+            },
+            "encode_image_to_base64" => StepDraft::Base64,
+            _ => StepDraft::Compress {
+                quality: 80,
+                format: "webp".to_string(),
+            },
+        }
+    }
+
+    fn command(&self) -> &'static str {
+        match self {
+            StepDraft::Compress { .. } => "compress_image",
+            StepDraft::Resize { .. } => "resize_image",
+            StepDraft::Filter { .. } => "apply_filter",
+            StepDraft::Base64 => "encode_image_to_base64",
+        }
+    }
+
+    fn to_step(&self) -> WorkflowStep {
+        let params = match self {
+            StepDraft::Compress { quality, format } => json!({
+                "quality": quality,
+                "outputFormat": (!format.is_empty()).then_some(format),
+            }),
+            StepDraft::Resize {
+                width,
+                height,
+                maintain_aspect,
+            } => json!({
+                "width": width.trim().parse::<u32>().unwrap_or(0),
+                "height": height.trim().parse::<u32>().unwrap_or(0),
+                "maintainAspect": maintain_aspect,
+            }),
+            StepDraft::Filter { filter } => json!({ "filter": filter }),
+            StepDraft::Base64 => json!({}),
+        };
+        WorkflowStep {
+            command: self.command().to_string(),
+            params,
+        }
+    }
+
+    fn from_step(step: &WorkflowStep) -> Self {
+        let params = &step.params;
+        let number = |key: &str| {
+            params[key]
+                .as_u64()
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        };
+        match step.command.as_str() {
+            "resize_image" => StepDraft::Resize {
+                width: number("width"),
+                height: number("height"),
+                maintain_aspect: params["maintainAspect"].as_bool().unwrap_or(true),
+            },
+            "apply_filter" => StepDraft::Filter {
+                filter: params["filter"].as_str().unwrap_or(FILTERS[0]).to_string(),
+            },
+            "encode_image_to_base64" => StepDraft::Base64,
+            _ => StepDraft::Compress {
+                quality: params["quality"].as_u64().unwrap_or(80) as u8,
+                format: params["outputFormat"].as_str().unwrap_or("").to_string(),
+            },
+        }
+    }
+}
+
+fn command_translation_key(command: &str) -> String {
+    format!("workflows.command_{}", command)
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+async fn fetch_workflows() -> Vec<Workflow> {
+    serde_wasm_bindgen::from_value(invoke("list_workflows_cmd", JsValue::NULL).await)
+        .unwrap_or_default()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    #[prop_or_default]
+    pub dropped_file: Option<String>,
+    #[prop_or_default]
+    pub on_file_processed: Callback<()>,
+}
+
+#[function_component(Workflows)]
+pub fn workflows(props: &Props) -> Html {
+    let (i18n, _) = use_translation();
+    let workflows = use_state(Vec::<Workflow>::new);
+    let selected_id = use_state(|| Option::<String>::None);
+    let name = use_state(String::new);
+    let drafts = use_state(|| vec![StepDraft::new("compress_image")]);
+    let save_error = use_state(|| Option::<String>::None);
+    let input_path = use_state(|| Option::<String>::None);
+    let run_result = use_state(|| Option::<WorkflowRunResult>::None);
+    let is_running = use_state(|| false);
+    let copied = use_state(|| false);
+
+    {
+        let workflows = workflows.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                workflows.set(fetch_workflows().await);
+            });
+            || {}
+        });
+    }
+
+    // Handle dropped file
+    {
+        let on_file_processed = props.on_file_processed.clone();
+        let input_path = input_path.clone();
+        let run_result = run_result.clone();
+        use_effect_with(props.dropped_file.clone(), move |dropped_file| {
+            if let Some(path) = dropped_file.clone() {
+                input_path.set(Some(path));
+                run_result.set(None);
+                on_file_processed.emit(());
+            }
+            || {}
+        });
+    }
+
+    let update_draft = {
+        let drafts = drafts.clone();
+        move |index: usize, draft: StepDraft| {
+            let mut next = (*drafts).clone();
+            next[index] = draft;
+            drafts.set(next);
+        }
+    };
+
+    let on_add_step = {
+        let drafts = drafts.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if !select.value().is_empty() {
+                let mut next = (*drafts).clone();
+                next.push(StepDraft::new(&select.value()));
+                drafts.set(next);
+                select.set_value("");
+            }
+        })
+    };
+
+    let on_name_input = {
+        let name = name.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            name.set(input.value());
+        })
+    };
+
+    let on_save = {
+        let name = name.clone();
+        let drafts = drafts.clone();
+        let workflows = workflows.clone();
+        let selected_id = selected_id.clone();
+        let save_error = save_error.clone();
+        Callback::from(move |_| {
+            let args = serde_wasm_bindgen::to_value(&SaveArgs {
+                name: (*name).clone(),
+                steps: drafts.iter().map(StepDraft::to_step).collect(),
+            })
+            .unwrap();
+            let workflows = workflows.clone();
+            let selected_id = selected_id.clone();
+            let save_error = save_error.clone();
+            spawn_local(async move {
+                match try_invoke("save_workflow_cmd", args).await {
+                    Ok(saved) => {
+                        if let Ok(saved) = serde_wasm_bindgen::from_value::<Workflow>(saved) {
+                            selected_id.set(Some(saved.id));
+                        }
+                        save_error.set(None);
+                        workflows.set(fetch_workflows().await);
+                    }
+                    Err(e) => save_error.set(e.as_string()),
+                }
+            });
+        })
+    };
+
+    let on_select_file = {
+        let input_path = input_path.clone();
+        let run_result = run_result.clone();
+        Callback::from(move |_| {
+            let input_path = input_path.clone();
+            let run_result = run_result.clone();
+            spawn_local(async move {
+                let options = OpenDialogOptions {
+                    multiple: false,
+                    filters: vec![FileFilter {
+                        name: "Images".to_string(),
+                        extensions: INPUT_EXTENSIONS.iter().map(|e| e.to_string()).collect(),
+                    }],
+                };
+                let opts = serde_wasm_bindgen::to_value(&options).unwrap();
+                if let Some(path) = open(opts).await.as_string() {
+                    input_path.set(Some(path));
+                    run_result.set(None);
+                }
+            });
+        })
+    };
+
+    let on_run = {
+        let selected_id = selected_id.clone();
+        let input_path = input_path.clone();
+        let run_result = run_result.clone();
+        let is_running = is_running.clone();
+        Callback::from(move |_| {
+            let (Some(workflow_id), Some(input_path)) =
+                ((*selected_id).clone(), (*input_path).clone())
+            else {
+                return;
+            };
+            let run_result = run_result.clone();
+            let is_running = is_running.clone();
+            is_running.set(true);
+            run_result.set(None);
+            spawn_local(async move {
+                let args = serde_wasm_bindgen::to_value(&RunArgs {
+                    workflow_id,
+                    input_path,
+                })
+                .unwrap();
+                let response = invoke("run_workflow_cmd", args).await;
+                run_result.set(serde_wasm_bindgen::from_value(response).ok());
+                is_running.set(false);
+            });
+        })
+    };
+
+    let on_copy_text = {
+        let copied = copied.clone();
+        let run_result = run_result.clone();
+        Callback::from(move |_| {
+            let Some(text) = run_result.as_ref().and_then(|r| r.text_output.clone()) else {
+                return;
+            };
+            let copied = copied.clone();
+            if let Some(win) = window() {
+                let clipboard = win.navigator().clipboard();
+                spawn_local(async move {
+                    let _ = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text)).await;
+                    copied.set(true);
+                    gloo_timers::callback::Timeout::new(2000, move || copied.set(false)).forget();
+                });
+            }
+        })
+    };
+
+    let reveal = |path: String| {
+        Callback::from(move |_| {
+            let path = path.clone();
+            spawn_local(async move {
+                reveal_item_in_dir(&path).await;
+            });
+        })
+    };
+
+    let render_draft = |index: usize, draft: &StepDraft| {
+        let update = update_draft.clone();
+        let fields = match draft.clone() {
+            StepDraft::Compress { quality, format } => {
+                let on_quality = {
+                    let update = update.clone();
+                    let format = format.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        let quality = input.value().parse().unwrap_or(quality);
+                        update(
+                            index,
+                            StepDraft::Compress {
+                                quality,
+                                format: format.clone(),
+                            },
+                        );
+                    })
+                };
+                let on_format = Callback::from(move |e: Event| {
+                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    update(
+                        index,
+                        StepDraft::Compress {
+                            quality,
+                            format: select.value(),
+                        },
+                    );
+                });
+                html! {
+                    <>
+                        <label class="workflow-field">
+                            <span>{format!("{} {}", i18n.t("workflows.quality"), quality)}</span>
+                            <input type="range" min="1" max="100" value={quality.to_string()} oninput={on_quality} />
+                        </label>
+                        <label class="workflow-field">
+                            <span>{i18n.t("workflows.format")}</span>
+                            <select class="form-select" onchange={on_format}>
+                                { for COMPRESS_FORMATS.iter().map(|(value, label)| html! {
+                                    <option value={*value} selected={format == *value}>
+                                        {if value.is_empty() { i18n.t("workflows.keep_format") } else { label.to_string() }}
+                                    </option>
+                                }) }
+                            </select>
+                        </label>
+                    </>
+                }
+            }
+            StepDraft::Resize {
+                width,
+                height,
+                maintain_aspect,
+            } => {
+                let on_width = {
+                    let update = update.clone();
+                    let height = height.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        update(
+                            index,
+                            StepDraft::Resize {
+                                width: input.value(),
+                                height: height.clone(),
+                                maintain_aspect,
+                            },
+                        );
+                    })
+                };
+                let on_height = {
+                    let update = update.clone();
+                    let width = width.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        update(
+                            index,
+                            StepDraft::Resize {
+                                width: width.clone(),
+                                height: input.value(),
+                                maintain_aspect,
+                            },
+                        );
+                    })
+                };
+                let on_aspect = {
+                    let (width, height) = (width.clone(), height.clone());
+                    Callback::from(move |_| {
+                        update(
+                            index,
+                            StepDraft::Resize {
+                                width: width.clone(),
+                                height: height.clone(),
+                                maintain_aspect: !maintain_aspect,
+                            },
+                        );
+                    })
+                };
+                html! {
+                    <>
+                        <label class="workflow-field">
+                            <span>{i18n.t("workflows.width")}</span>
+                            <input type="number" min="1" class="form-input" value={width} oninput={on_width} />
+                        </label>
+                        <label class="workflow-field">
+                            <span>{i18n.t("workflows.height")}</span>
+                            <input type="number" min="1" class="form-input" value={height} oninput={on_height} />
+                        </label>
+                        <label class="checkbox-option-inline">
+                            <input type="checkbox" checked={maintain_aspect} onclick={on_aspect} />
+                            <span>{i18n.t("workflows.maintain_aspect")}</span>
+                        </label>
+                    </>
+                }
+            }
+            StepDraft::Filter { filter } => {
+                let on_filter = Callback::from(move |e: Event| {
+                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    update(
+                        index,
+                        StepDraft::Filter {
+                            filter: select.value(),
+                        },
+                    );
+                });
+                html! {
+                    <label class="workflow-field">
+                        <span>{i18n.t("workflows.filter")}</span>
+                        <select class="form-select" onchange={on_filter}>
+                            { for FILTERS.iter().map(|value| html! {
+                                <option value={*value} selected={filter == *value}>
+                                    {i18n.t(&format!("workflows.filter_{}", value.to_lowercase()))}
+                                </option>
+                            }) }
+                        </select>
+                    </label>
+                }
+            }
+            StepDraft::Base64 => html! {
+                <span class="workflow-hint">{i18n.t("workflows.base64_hint")}</span>
+            },
+        };
+
+        let move_step = |offset: isize| {
+            let drafts = drafts.clone();
+            Callback::from(move |_| {
+                let target = index as isize + offset;
+                if target >= 0 && (target as usize) < drafts.len() {
+                    let mut next = (*drafts).clone();
+                    next.swap(index, target as usize);
+                    drafts.set(next);
+                }
+            })
+        };
+        let on_remove = {
+            let drafts = drafts.clone();
+            Callback::from(move |_| {
+                let mut next = (*drafts).clone();
+                next.remove(index);
+                drafts.set(next);
+            })
+        };
+
+        html! {
+            <div class="workflow-step">
+                <span class="workflow-step-index">{index + 1}</span>
+                <span class="workflow-step-command">{i18n.t(&command_translation_key(draft.command()))}</span>
+                <div class="workflow-step-fields">{fields}</div>
+                <div class="workflow-step-actions">
+                    <button class="move-btn" onclick={move_step(-1)} disabled={index == 0}>{"↑"}</button>
+                    <button class="move-btn" onclick={move_step(1)} disabled={index + 1 == drafts.len()}>{"↓"}</button>
+                    <button class="move-btn" onclick={on_remove}>{"✕"}</button>
+                </div>
+            </div>
+        }
+    };
+
+    let selected = workflows
+        .iter()
+        .find(|workflow| selected_id.as_deref() == Some(workflow.id.as_str()))
+        .cloned();
+
+    html! {
+        <div class="workflows">
+            <div class="section">
+                <h3>{i18n.t("workflows.saved")}</h3>
+                if workflows.is_empty() {
+                    <p class="workflow-hint">{i18n.t("workflows.empty")}</p>
+                } else {
+                    <div class="workflow-list">
+                        { for workflows.iter().map(|workflow| {
+                            let is_selected = selected_id.as_deref() == Some(workflow.id.as_str());
+                            let on_select = {
+                                let selected_id = selected_id.clone();
+                                let id = workflow.id.clone();
+                                Callback::from(move |_| selected_id.set(Some(id.clone())))
+                            };
+                            let on_edit = {
+                                let name = name.clone();
+                                let drafts = drafts.clone();
+                                let workflow = workflow.clone();
+                                Callback::from(move |e: MouseEvent| {
+                                    e.stop_propagation();
+                                    name.set(workflow.name.clone());
+                                    drafts.set(workflow.steps.iter().map(StepDraft::from_step).collect());
+                                })
+                            };
+                            let on_delete = {
+                                let workflows = workflows.clone();
+                                let selected_id = selected_id.clone();
+                                let id = workflow.id.clone();
+                                Callback::from(move |e: MouseEvent| {
+                                    e.stop_propagation();
+                                    let workflows = workflows.clone();
+                                    let selected_id = selected_id.clone();
+                                    let id = id.clone();
+                                    spawn_local(async move {
+                                        let args = serde_wasm_bindgen::to_value(&DeleteArgs { id: id.clone() }).unwrap();
+                                        let _ = try_invoke("delete_workflow_cmd", args).await;
+                                        if selected_id.as_deref() == Some(id.as_str()) {
+                                            selected_id.set(None);
+                                        }
+                                        workflows.set(fetch_workflows().await);
+                                    });
+                                })
+                            };
+                            html! {
+                                <div class={classes!("workflow-item", is_selected.then_some("selected"))} onclick={on_select}>
+                                    <span class="workflow-item-name">{&workflow.name}</span>
+                                    <span class="workflow-item-steps">
+                                        { for workflow.steps.iter().enumerate().map(|(i, step)| html! {
+                                            <>
+                                                if i > 0 {
+                                                    <span class="workflow-arrow">{"→"}</span>
+                                                }
+                                                <span class="workflow-chip">{i18n.t(&command_translation_key(&step.command))}</span>
+                                            </>
+                                        }) }
+                                    </span>
+                                    <button class="secondary-btn" onclick={on_edit}>{i18n.t("workflows.edit")}</button>
+                                    <button class="secondary-btn" onclick={on_delete}>{i18n.t("workflows.delete")}</button>
+                                </div>
+                            }
+                        }) }
+                    </div>
+                }
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("workflows.run")}</h3>
+                <div class="drop-zone workflow-drop" onclick={on_select_file}>
+                    if let Some(path) = (*input_path).clone() {
+                        <p class="drop-zone-text">{file_name(&path).to_string()}</p>
+                        <p class="drop-zone-hint">{i18n.t("workflows.click_to_change")}</p>
+                    } else {
+                        <p class="drop-zone-text">{i18n.t("workflows.drop_image")}</p>
+                    }
+                </div>
+                <button
+                    class="primary-btn"
+                    onclick={on_run}
+                    disabled={selected.is_none() || input_path.is_none() || *is_running}
+                >
+                    if *is_running {
+                        <span class="spinner"></span>
+                        {i18n.t("common.processing")}
+                    } else if let Some(workflow) = &selected {
+                        {format!("{}: {}", i18n.t("workflows.run_button"), workflow.name)}
+                    } else {
+                        {i18n.t("workflows.select_workflow")}
+                    }
+                </button>
+
+                if let Some(result) = (*run_result).clone() {
+                    <div class="workflow-results">
+                        { for result.steps.iter().enumerate().map(|(i, step)| html! {
+                            <div class={classes!("workflow-result", (!step.success).then_some("failed"))}>
+                                <span class="workflow-step-index">{i + 1}</span>
+                                <span class="workflow-step-command">{i18n.t(&command_translation_key(&step.command))}</span>
+                                if let Some(path) = &step.output_path {
+                                    <code class="workflow-result-path" title={path.clone()}>{file_name(path).to_string()}</code>
+                                    <span class="workflow-result-size">{format_size(step.output_size)}</span>
+                                } else {
+                                    <span class="workflow-result-error">{step.error.clone().unwrap_or_default()}</span>
+                                }
+                            </div>
+                        }) }
+                        if let Some(failed) = result.failed_step {
+                            <div class="error-message">
+                                {"⚠ "}{i18n.t("workflows.failed_at").replace("{step}", &(failed + 1).to_string())}
+                            </div>
+                            if !result.intermediate_paths.is_empty() {
+                                <div class="workflow-hint">{i18n.t("workflows.intermediate_kept")}</div>
+                            }
+                        } else if !result.success {
+                            <div class="error-message">{"⚠ "}{result.error.clone().unwrap_or_default()}</div>
+                        }
+                        <div class="workflow-result-actions">
+                            if result.text_output.is_some() {
+                                <button class={classes!("secondary-btn", (*copied).then_some("copied"))} onclick={on_copy_text}>
+                                    if *copied {
+                                        {i18n.t("common.copied")}
+                                    } else {
+                                        {i18n.t("workflows.copy_base64")}
+                                    }
+                                </button>
+                            }
+                            if let Some(path) = result.output_path.clone().or_else(|| result.intermediate_paths.last().cloned()) {
+                                <button class="secondary-btn" onclick={reveal(path)}>{i18n.t("workflows.show_in_folder")}</button>
+                            } else if !result.run_dir.is_empty() && !result.steps.is_empty() {
+                                <button class="secondary-btn" onclick={reveal(result.run_dir.clone())}>{i18n.t("workflows.show_in_folder")}</button>
+                            }
+                        </div>
+                    </div>
+                }
+            </div>
+
+            <div class="section">
+                <h3>{i18n.t("workflows.editor")}</h3>
+                <input
+                    type="text"
+                    class="form-input workflow-name"
+                    value={(*name).clone()}
+                    oninput={on_name_input}
+                    placeholder={i18n.t("workflows.name_placeholder")}
+                />
+                <div class="workflow-steps">
+                    { for drafts.iter().enumerate().map(|(index, draft)| render_draft(index, draft)) }
+                </div>
+                <div class="workflow-editor-actions">
+                    <select class="form-select" onchange={on_add_step}>
+                        <option value="" selected=true>{format!("+ {}", i18n.t("workflows.add_step"))}</option>
+                        { for COMMANDS.iter().map(|command| html! {
+                            <option value={*command}>{i18n.t(&command_translation_key(command))}</option>
+                        }) }
+                    </select>
+                    <button class="primary-btn" onclick={on_save} disabled={name.trim().is_empty() || drafts.is_empty()}>
+                        {i18n.t("workflows.save")}
+                    </button>
+                </div>
+                if let Some(error) = (*save_error).clone() {
+                    <div class="error-message">{"⚠ "}{error}</div>
+                }
+            </div>
+        </div>
+    }
+}
@@ -66,8 +66,43 @@ pub const EN_TRANSLATIONS: &str = r#"{
       "unix_time": "Unix Time",
       "shortcut_dictionary": "Shortcuts",
       "char_counter": "Char Count",
-      "cheatsheet_viewer": "Cheat Sheet"
-    }
+      "cheatsheet_viewer": "Cheat Sheet",
+      "url_encoder": "URL Encode",
+      "config_converter": "Config Convert",
+      "color_converter": "Color Convert",
+      "dummy_data_generator": "Dummy Data",
+      "cron_parser": "Cron Parser",
+      "case_converter": "Case Convert",
+      "text_tools": "Line Tools",
+      "escape_converter": "Escape",
+      "qr_generator": "QR Code",
+      "qr_reader": "QR Reader",
+      "exif_tools": "EXIF Viewer",
+      "image_converter": "Format Converter",
+      "http_client": "HTTP Client",
+      "network_tools": "Network Tools",
+      "sql_formatter": "SQL Formatter",
+      "file_renamer": "File Renamer",
+      "duplicate_finder": "Duplicate Finder",
+      "disk_analyzer": "Disk Usage",
+      "clipboard_history": "Clipboard History",
+      "pomodoro": "Pomodoro",
+      "settings": "Settings",
+      "encoding_converter": "Encoding Converter",
+      "icon_exporter": "Icon Export",
+      "radix_converter": "Radix",
+      "business_days": "Business Days",
+      "chmod_calculator": "chmod",
+      "ocr": "OCR",
+      "workflows": "Workflows",
+      "japanese_text_converter": "Zenkaku/Hankaku",
+      "system_info": "System Info",
+      "process_tools": "Processes",
+      "markdown_table": "Markdown Table",
+      "env_converter": ".env Converter"
+    },
+    "settings": "Settings",
+    "clipboard_cleared": "Copied text was cleared from the clipboard"
   },
   "language_switcher": {
     "title": "Language",
@@ -91,11 +126,34 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "count_label": "Count",
     "results_title": "Generated Results",
     "items_count": "{count} items",
-    "validate_input_placeholder": "Enter UUID to validate...",
+    "validate_input_placeholder": "Enter UUID or ULID to validate...",
     "valid_uuid": "Valid UUID",
     "invalid_uuid": "Invalid UUID",
     "version_info": "Version:",
-    "variant_info": "Variant:"
+    "variant_info": "Variant:",
+    "normalized_info": "Normalized:",
+    "timestamp_utc_info": "Timestamp (UTC):",
+    "timestamp_local_info": "Timestamp (Local):",
+    "node_info": "Node (MAC):",
+    "clock_sequence_info": "Clock Sequence:",
+    "bulk_section": "Bulk Validate",
+    "bulk_placeholder": "Paste UUIDs, one per line...",
+    "bulk_valid_count": "{count} valid",
+    "bulk_invalid_count": "{count} invalid",
+    "bulk_line": "Line",
+    "bulk_input": "UUID",
+    "version_v3": "UUID v3 (MD5 Name-based)",
+    "version_v5": "UUID v5 (SHA-1 Name-based)",
+    "version_ulid": "ULID",
+    "version_nanoid": "NanoID",
+    "namespace_label": "Namespace",
+    "name_label": "Names",
+    "name_placeholder": "One name per line, e.g. example.com",
+    "length_label": "Length",
+    "alphabet_label": "Alphabet",
+    "entropy_info": "Entropy: {bits} bits",
+    "valid_ulid": "Valid ULID",
+    "invalid_ulid": "Invalid ULID"
   },
   "password_generator": {
     "title": "Password Generator",
@@ -126,7 +184,23 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "generate_password": "Generate Password",
     "generate_passphrase": "Generate Passphrase",
     "results_title": "Generated Results",
-    "entropy_label": "Entropy: {value} bits"
+    "entropy_label": "Entropy: {value} bits",
+    "min_strength_label": "Minimum Strength",
+    "min_strength_none": "No requirement",
+    "strength_score_1": "Weak or better (1)",
+    "strength_score_2": "Fair or better (2)",
+    "strength_score_3": "Strong or better (3)",
+    "strength_score_4": "Very strong (4)",
+    "crack_time": "Time to crack",
+    "check_title": "Check Password Strength",
+    "check_placeholder": "Type a password to evaluate...",
+    "wordlist_label": "Wordlist",
+    "wordlist_english": "English (built-in)",
+    "wordlist_japanese_romaji": "Japanese Romaji (built-in)",
+    "wordlist_custom": "Custom file",
+    "wordlist_select_file": "Select File",
+    "wordlist_no_file": "No file selected (one word per line)",
+    "word": "word"
   },
   "scratch_pad": {
     "title": "Scratch Pad",
@@ -135,7 +209,25 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "no_additional_text": "No additional text",
     "select_or_create": "Select a note or create a new one",
     "placeholder": "Start writing in Markdown format...",
-    "save_failed": "Save failed"
+    "save_failed": "Save failed",
+    "search_placeholder": "Search notes...",
+    "no_results": "No matching notes",
+    "title_placeholder": "Title (defaults to the first line)",
+    "history": "History",
+    "history_empty": "No saved versions yet",
+    "restore": "Restore",
+    "reminder": "Reminder",
+    "reminder_in_minutes": "In {n} min",
+    "reminder_in_hours": "In {n} h",
+    "reminder_set": "Set",
+    "reminder_cancel": "Cancel",
+    "reminder_empty": "No reminders",
+    "reminder_pending": "Scheduled",
+    "reminder_fired": "Fired",
+    "reminder_cancelled": "Cancelled",
+    "reminder_invalid_time": "Choose a date and time",
+    "export_format": "Export format",
+    "export_all": "Export all notes to a folder"
   },
   "regex_tester": {
     "title": "Regex Tester",
@@ -159,7 +251,7 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "test_placeholder": "Enter text to test against the pattern...",
     "export_results": "Export Results",
     "replace_title": "// REPLACE",
-    "replace_placeholder": "Replacement string (use $1, $2 for groups)...",
+    "replace_placeholder": "Replacement string ($1, ${1}, ${name}; $$ for a literal $)...",
     "replacements_count": "{count} replacements",
     "copy_result": "Copy Result",
     "testing": "Testing...",
@@ -169,7 +261,21 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "highlighted_title": "// HIGHLIGHTED TEXT",
     "details_title": "// MATCH DETAILS",
     "position": "Position: {start}..{end}",
-    "capture_groups": "Capture Groups:"
+    "capture_groups": "Capture Groups:",
+    "truncated": "Stopped after 10,000 matches",
+    "replacement_pairs": "Replacements",
+    "category_japanese": "Japanese",
+    "category_custom": "My Patterns",
+    "preset_name_placeholder": "Preset name",
+    "preset_description_placeholder": "Description (optional)",
+    "save_preset": "Save Current Pattern",
+    "show_samples": "Sample Strings",
+    "hide_samples": "Hide Samples",
+    "samples_title": "Generate Matching Samples",
+    "sample_count": "Count",
+    "sample_max_repeat": "Max repeat",
+    "generate_samples": "Generate",
+    "use_samples": "Add to Test String"
   },
   "unit_converter": {
     "title": "Unit Converter",
@@ -236,7 +342,44 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "volume_gallon": "Gallon (gal)",
     "volume_quart": "Quart (qt)",
     "volume_pint": "Pint (pt)",
-    "volume_cup": "Cup"
+    "volume_cup": "Cup",
+    "category_currency": "Currency",
+    "rates_section": "Exchange Rates",
+    "rates_update": "Update Rates",
+    "rates_updating": "Updating...",
+    "rates_updated_at": "Rates as of {time}",
+    "rates_source_bundled": "bundled defaults",
+    "rates_source_api": "fetched online",
+    "rates_stale": "These rates are more than 7 days old. Update them for accurate results.",
+    "rates_offline_fallback": "using the cached rates instead",
+    "show_all_units": "Show all units",
+    "significant_digits": "Significant digits",
+    "significant_digits_auto": "Auto",
+    "all_units_section": "All Units",
+    "category_speed": "Speed",
+    "category_pressure": "Pressure",
+    "category_energy": "Energy",
+    "category_fuel_economy": "Fuel Economy",
+    "speed_kilometerperhour": "Kilometer per Hour (km/h)",
+    "speed_meterpersecond": "Meter per Second (m/s)",
+    "speed_mileperhour": "Mile per Hour (mph)",
+    "speed_knot": "Knot (kn)",
+    "pressure_pascal": "Pascal (Pa)",
+    "pressure_kilopascal": "Kilopascal (kPa)",
+    "pressure_bar": "Bar",
+    "pressure_atmosphere": "Atmosphere (atm)",
+    "pressure_psi": "PSI",
+    "pressure_millimeterofmercury": "Millimeter of Mercury (mmHg)",
+    "energy_joule": "Joule (J)",
+    "energy_kilojoule": "Kilojoule (kJ)",
+    "energy_calorie": "Calorie (cal)",
+    "energy_kilocalorie": "Kilocalorie (kcal)",
+    "energy_kilowatthour": "Kilowatt Hour (kWh)",
+    "energy_btu": "BTU",
+    "fuel_kilometerperliter": "Kilometer per Liter (km/L)",
+    "fuel_literper100km": "Liter per 100 km (L/100km)",
+    "fuel_mpgus": "Miles per Gallon (US)",
+    "fuel_mpguk": "Miles per Gallon (UK)"
   },
   "image_compressor": {
     "compressing": "Compressing...",
@@ -257,7 +400,15 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "compression_failed": "Compression Failed",
     "original": "Original",
     "compressed": "Compressed",
-    "saved": "Saved"
+    "saved": "Saved",
+    "target_size": "Target File Size",
+    "target_size_enable": "Compress to a file size",
+    "allow_resize": "Shrink the resolution if the minimum quality is still too large",
+    "png_target_hint": "PNG has no quality setting, so colors are reduced instead. Minimum quality limits how far.",
+    "min_quality": "Minimum Quality",
+    "color_bits": "{n} bits per channel",
+    "scale": "Scale",
+    "attempts": "{n} attempts"
   },
   "json_formatter": {
     "title": "// JSON FORMATTER",
@@ -277,7 +428,35 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "more_results": "... and {count} more",
     "input_placeholder": "Paste your JSON here...",
     "tree_placeholder": "Enter valid JSON to see the tree view",
-    "error_details": "// ERROR DETAILS"
+    "error_details": "// ERROR DETAILS",
+    "query_placeholder_path": "$.items[*].name",
+    "query_placeholder_pointer": "/items/0/name",
+    "extract": "Extract",
+    "copy_extracted": "Copy result JSON",
+    "schema": "JSON Schema",
+    "schema_placeholder": "Paste a JSON Schema (draft-07 or 2020-12)...",
+    "validate_schema": "Validate",
+    "schema_valid": "Matches the schema",
+    "schema_violations": "violations",
+    "schema_error": "Invalid schema",
+    "diff": "JSON Diff",
+    "compare": "Compare",
+    "diff_placeholder": "Paste the old JSON to compare the input against...",
+    "diff_ignore_array_order": "Ignore array order",
+    "diff_id_key": "ID key (e.g. id)",
+    "diff_ignore_number_format": "Treat 1 and 1.0 as equal",
+    "diff_ignore_key_order": "Ignore key order",
+    "diff_identical": "No differences",
+    "diff_type_changed": "type changes",
+    "type_gen": "Generate Types",
+    "type_gen_generate": "Generate",
+    "type_gen_root_name": "Root type name",
+    "type_gen_camel_case": "snake_case → camelCase",
+    "optional_missing": "Optional if missing",
+    "optional_missing_or_null": "Optional if missing or null",
+    "optional_always": "All optional",
+    "optional_never": "None optional",
+    "tree_show_more": "Show more"
   },
   "base64_encoder": {
     "title": "Base64 Encoder/Decoder",
@@ -299,7 +478,25 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "decode_btn": "Decode",
     "copy_data_url": "Copy Data URL",
     "click_to_change": "Click to change image",
-    "original_size": "Original:"
+    "original_size": "Original:",
+    "mode_file": "File",
+    "file_input": "File Input",
+    "as_data_uri": "Output as Data URI (data:<mime>;base64,...)",
+    "select_file": "Click to select any file",
+    "file_size_limit": "Any file type, up to 50 MB",
+    "save_as_file": "Save as File",
+    "saved_to": "Saved to",
+    "mode_convert": "Convert",
+    "from": "From",
+    "to": "To",
+    "convert_btn": "Convert",
+    "convert_placeholder": "Enter text, Base64, Base32 or hex...",
+    "large_file": "Large File Conversion",
+    "large_file_hint": "Converts file to file in chunks without loading the whole file, so files of several GB can be handled.",
+    "encode_to_file": "Encode File → .b64",
+    "decode_to_file": "Decode .b64 → File",
+    "cancel": "Cancel",
+    "large_file_cancelled": "Conversion cancelled"
   },
   "command_palette": {
     "search_placeholder": "Search tools...",
@@ -326,8 +523,43 @@ pub const EN_TRANSLATIONS: &str = r#"{
       "base64": "Encode/decode Base64 text and images",
       "shortcut_dictionary": "Search keyboard shortcuts for VSCode, IntelliJ, Vim, Terminal",
       "char_counter": "Count characters, words, lines, bytes in real-time",
-      "cheatsheet_viewer": "Quick reference for Git, Docker, Kubernetes, tmux, Bash commands"
-    }
+      "cheatsheet_viewer": "Quick reference for Git, Docker, Kubernetes, tmux, Bash commands",
+      "url_encoder": "Percent-encode/decode URLs and parse query strings",
+      "config_converter": "Convert config files between JSON, YAML and TOML",
+      "color_converter": "Convert colors between HEX, RGB, HSL, HSV and CMYK and build palettes",
+      "dummy_data_generator": "Generate Lorem Ipsum text and test records as JSON, CSV or SQL",
+      "cron_parser": "Explain cron expressions and list the next run times",
+      "case_converter": "Convert text between camelCase, snake_case, kebab-case and more",
+      "text_tools": "Sort, dedupe, shuffle and tidy up lines of text",
+      "escape_converter": "Escape and unescape HTML entities, \\uXXXX, JSON strings and XML",
+      "qr_generator": "Generate QR codes for URLs, Wi-Fi and contacts",
+      "qr_reader": "Read QR codes from screenshots and images",
+      "exif_tools": "View and remove EXIF metadata such as GPS location",
+      "image_converter": "Batch convert images between formats, including HEIC and ICO",
+      "http_client": "Send HTTP requests and inspect responses",
+      "network_tools": "CIDR calculator, IP classification and port check",
+      "sql_formatter": "Format, minify and recase SQL queries",
+      "file_renamer": "Batch rename files with find/replace, numbering and dates",
+      "duplicate_finder": "Find files with identical contents and move extra copies to the trash",
+      "disk_analyzer": "See which folders and files take up the most space",
+      "clipboard_history": "Keep a searchable history of copied text with pinning",
+      "pomodoro": "Focus timer with work and break cycles linked to tasks",
+      "settings": "Theme, language, output folder and tool defaults",
+      "encoding_converter": "Detect and convert text file encodings (Shift_JIS, EUC-JP, UTF-16)",
+      "icon_exporter": "Export an image at multiple sizes for icons and thumbnails",
+      "radix_converter": "Convert numbers between bases and inspect IEEE 754 bits",
+      "business_days": "Add business days and count working days with Japanese holidays",
+      "chmod_calculator": "Convert Unix permissions between 755 and rwxr-xr-x",
+      "ocr": "Extract text from screenshots and images",
+      "workflows": "Save image tool chains and run them on a file in one step",
+      "japanese_text_converter": "Convert full/half-width characters and hiragana/katakana",
+      "system_info": "Check CPU, memory and disk usage of this machine",
+      "process_tools": "List processes, find what is using a port and stop it",
+      "markdown_table": "Align Markdown table columns and convert from CSV",
+      "env_converter": "Convert .env files to JSON, YAML, docker-compose or shell exports and compare keys"
+    },
+    "recent": "Recently used",
+    "all_tools": "All tools"
   },
   "char_counter": {
     "title": "Character Counter",
@@ -348,7 +580,13 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "katakana": "Katakana",
     "kanji": "Kanji",
     "fullwidth": "Fullwidth",
-    "halfwidth": "Halfwidth"
+    "halfwidth": "Halfwidth",
+    "grapheme_count": "Graphemes",
+    "char_count_no_newlines": "Characters (no line breaks)",
+    "conversions": "Conversions",
+    "manuscript_pages": "Manuscript pages (400 chars)",
+    "tweet_length": "Tweet length",
+    "tweet_remaining": "{count} left"
   },
   "shortcut_dictionary": {
     "title": "Shortcut Dictionary",
@@ -400,7 +638,32 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "date": "Date",
     "time": "Time",
     "day_of_week": "Day of Week",
-    "relative_time": "Relative Time"
+    "relative_time": "Relative Time",
+    "timezone_iana": "IANA timezones",
+    "alternative_unix_seconds": "Unix (later offset)",
+    "mode_calculate": "Calculate",
+    "add_duration": "Add / Subtract Duration",
+    "difference": "Difference",
+    "diff_from": "From timestamp",
+    "diff_to": "To timestamp",
+    "calculate": "Calculate",
+    "duration": "Duration",
+    "duration_breakdown": "Breakdown",
+    "total_seconds": "Total Seconds",
+    "total_milliseconds": "Total Milliseconds",
+    "duration_years": "Years",
+    "duration_months": "Months",
+    "duration_days": "Days",
+    "duration_hours": "Hours",
+    "duration_minutes": "Minutes",
+    "duration_seconds": "Seconds",
+    "unit_auto": "Auto (13+ digits = ms)",
+    "mode_batch": "Batch",
+    "batch_input": "Timestamps",
+    "batch_placeholder": "Paste timestamps separated by newlines, commas or spaces...",
+    "batch_original": "Original",
+    "copy_tsv": "Copy as TSV",
+    "batch_truncated": "Showing the first {count} rows. Copy as TSV to get all results."
   },
   "input_history": {
     "title": "History",
@@ -451,6 +714,696 @@ pub const EN_TRANSLATIONS: &str = r#"{
     "cat_text_processing": "Text",
     "cat_process": "Process",
     "cat_navigation": "Navigation"
+  },
+  "url_encoder": {
+    "title": "URL Encoder/Decoder",
+    "mode_encode": "Encode",
+    "mode_decode": "Decode",
+    "mode_query": "Query Parse",
+    "encode_all": "Encode reserved characters (encodeURIComponent)",
+    "encode_all_hint": "Encodes everything except A-Z a-z 0-9 - _ . ! ~ * ' ( )",
+    "encode_uri_hint": "Keeps URL structure characters such as : / ? & = # (encodeURI)",
+    "text_input": "Text Input",
+    "encoded_input": "Encoded Input",
+    "url_input": "URL / Query String",
+    "encode_placeholder": "Enter text or URL to encode...",
+    "decode_placeholder": "Enter percent-encoded text to decode...",
+    "query_placeholder": "https://example.com/?a=1&b=%E3%81%82",
+    "encode_btn": "Encode",
+    "decode_btn": "Decode",
+    "parse_btn": "Parse",
+    "encoded_output": "Encoded Output",
+    "decoded_output": "Decoded Output",
+    "query_params": "Query Parameters",
+    "base_url": "Base URL",
+    "fragment": "Fragment",
+    "key": "Key",
+    "value": "Value",
+    "raw_value": "Raw Value",
+    "no_params": "No query parameters found"
+  },
+  "config_converter": {
+    "from": "From",
+    "to": "To",
+    "convert_btn": "Convert",
+    "input_placeholder": "Paste JSON, YAML or TOML here..."
+  },
+  "color_converter": {
+    "input_label": "Color",
+    "input_placeholder": "tomato, #ff8800, rgb(255, 136, 0), hsl(32, 100%, 50%)...",
+    "alpha": "Alpha",
+    "invalid_part": "Could not interpret",
+    "palette_section": "Palette",
+    "palette_complementary": "Complementary",
+    "palette_analogous": "Analogous",
+    "palette_triadic": "Triadic",
+    "palette_shades": "Shades"
+  },
+  "dummy_data": {
+    "mode_lorem": "Lorem Ipsum",
+    "mode_records": "Records",
+    "paragraphs": "Paragraphs",
+    "words_per_paragraph": "Words per paragraph",
+    "japanese": "Japanese text",
+    "count": "Records",
+    "format": "Output format",
+    "table_name": "Table name",
+    "seed": "Seed",
+    "seed_placeholder": "Empty for random",
+    "reuse_seed": "Use this seed to reproduce the output",
+    "invalid_seed": "Seed must be an integer between 0 and {max}",
+    "fields": "Fields",
+    "field_name": "Field name",
+    "add_field": "Add field",
+    "min": "Min",
+    "max": "Max",
+    "generate": "Generate",
+    "lorem_summary": "{words} words / {chars} characters",
+    "records_summary": "{count} records",
+    "kind_name_ja": "Name (Japanese)",
+    "kind_name_en": "Name (English)",
+    "kind_email": "Email",
+    "kind_phone": "Phone number",
+    "kind_address": "Address",
+    "kind_date": "Date",
+    "kind_integer": "Integer",
+    "kind_uuid": "UUID",
+    "kind_boolean": "Boolean"
+  },
+  "cron_parser": {
+    "input_placeholder": "*/5 * * * *",
+    "examples": "Examples",
+    "normalized": "Expanded",
+    "next_runs": "Next 10 runs",
+    "field_second": "Second",
+    "field_minute": "Minute",
+    "field_hour": "Hour",
+    "field_day_of_month": "Day of month",
+    "field_month": "Month",
+    "field_day_of_week": "Day of week"
+  },
+  "case_converter": {
+    "input_placeholder": "Enter an identifier or phrase (e.g. HTTPServerConfig, user_name, Hello World)",
+    "multiline": "Convert each line separately",
+    "detected": "Detected style",
+    "detected_unknown": "Unknown",
+    "words": "Words"
+  },
+  "text_tools": {
+    "pipeline": "Operations",
+    "add_operation": "+ Add operation",
+    "no_operations": "No operations. Add one to start processing lines.",
+    "input": "Input",
+    "output": "Output",
+    "lines": "lines",
+    "input_placeholder": "Paste lines of text here...",
+    "use_as_input": "Use as input",
+    "shuffle_seed": "Shuffle seed",
+    "removed_duplicates": "Removed duplicates",
+    "move_up": "Move up",
+    "move_down": "Move down",
+    "remove": "Remove",
+    "descending": "Descending",
+    "ignore_case": "Ignore case",
+    "seed_placeholder": "Seed (random if empty)",
+    "text_placeholder": "Text",
+    "start": "Start",
+    "separator": "Separator",
+    "sort_lexical": "Alphabetical",
+    "sort_natural": "Natural (file2 < file10)",
+    "sort_numeric": "Numeric",
+    "sort_length": "Length",
+    "op_sort": "Sort",
+    "op_dedup": "Remove duplicates",
+    "op_reverse": "Reverse",
+    "op_shuffle": "Shuffle",
+    "op_trim": "Trim whitespace",
+    "op_remove_empty": "Remove empty lines",
+    "op_add_line_numbers": "Add line numbers",
+    "op_add_prefix": "Add prefix",
+    "op_add_suffix": "Add suffix",
+    "op_remove_prefix": "Remove prefix",
+    "op_remove_suffix": "Remove suffix"
+  },
+  "escape_converter": {
+    "escape": "Escape",
+    "unescape": "Unescape",
+    "format_html": "HTML entities",
+    "format_unicode": "Unicode escape (\\uXXXX)",
+    "format_json": "JSON string",
+    "format_xml": "XML",
+    "format_auto": "Auto detect (mixed)",
+    "style_named": "Named (&amp;)",
+    "style_decimal": "Decimal (&#38;)",
+    "style_hex": "Hex (&#x26;)",
+    "encode_non_ascii": "Also escape non-ASCII characters",
+    "input": "Input",
+    "output": "Output",
+    "escape_placeholder": "Enter text to escape...",
+    "unescape_placeholder": "Enter text containing &amp;, &#x3042;, \\u3042 ...",
+    "position": "Position",
+    "replaced": "replaced"
+  },
+  "qr_generator": {
+    "mode_text": "Text / URL",
+    "mode_wifi": "Wi-Fi",
+    "mode_vcard": "Contact (vCard)",
+    "text_placeholder": "Enter a URL or text",
+    "ssid": "Network name (SSID)",
+    "password": "Password",
+    "security": "Security",
+    "security_none": "None (open)",
+    "hidden": "Hidden network",
+    "vcard_last_name": "Last name",
+    "vcard_first_name": "First name",
+    "vcard_organization": "Organization",
+    "vcard_title": "Title",
+    "vcard_phone": "Phone",
+    "vcard_email": "Email",
+    "vcard_url": "Website",
+    "vcard_address": "Address",
+    "vcard_note": "Note",
+    "size": "Size",
+    "margin": "Margin",
+    "error_correction": "Error correction",
+    "foreground": "Foreground",
+    "background": "Background",
+    "version": "Version",
+    "bytes": "bytes",
+    "save": "Save as PNG / SVG",
+    "saved": "Saved",
+    "empty": "Enter content to preview the QR code"
+  },
+  "qr_reader": {
+    "drop_image": "Drop an image here or click to select",
+    "supported_formats": "PNG, JPEG, GIF, WebP, BMP",
+    "click_to_change": "Click or drop another image to change",
+    "results": "Detected codes",
+    "password": "Password",
+    "security": "Security",
+    "open_network": "None (open)",
+    "hidden": "Hidden network",
+    "not_found": "No QR code was found in this image",
+    "decode_failed": "A QR code was found but could not be decoded",
+    "unreadable": "The image could not be read"
+  },
+  "exif_tools": {
+    "drop_image": "Drop an image here or click to select",
+    "supported_formats": "JPEG, PNG, WebP, TIFF, HEIC",
+    "click_to_change": "Click or drop another image to change",
+    "no_exif": "This image has no EXIF metadata",
+    "gps_warning": "This image contains location data",
+    "decimal": "Decimal",
+    "dms": "DMS",
+    "altitude": "Altitude",
+    "open_map": "Open in map",
+    "summary": "Summary",
+    "camera": "Camera",
+    "lens": "Lens",
+    "date_time": "Taken",
+    "iso": "ISO",
+    "shutter": "Shutter speed",
+    "aperture": "Aperture",
+    "focal_length": "Focal length",
+    "orientation": "Orientation",
+    "software": "Software",
+    "all_fields": "All fields",
+    "keep_orientation": "Keep orientation",
+    "strip": "Save without metadata"
+  },
+  "image_converter": {
+    "drop_images": "Drop images here or click to add",
+    "supported_formats": "PNG, JPEG, WebP, AVIF, GIF, BMP, TIFF, HEIC",
+    "files": "Files",
+    "output_format": "Output format",
+    "quality": "Quality",
+    "ico_hint": "Icons include 16, 32, 48, 64, 128 and 256 px sizes up to the source size",
+    "jpeg_hint": "Transparent areas are filled with white",
+    "convert": "Convert and choose folder",
+    "succeeded": "Converted"
+  },
+  "http_client": {
+    "send": "Send",
+    "sending": "Sending...",
+    "headers": "Headers",
+    "add_header": "+ Add Header",
+    "body": "Body",
+    "timeout": "Timeout (s)",
+    "follow_redirects": "Follow redirects",
+    "allow_invalid_certs": "Allow invalid certificates",
+    "binary_body": "Binary response shown as Base64",
+    "truncated": "Response body was truncated"
+  },
+  "network_tools": {
+    "cidr": "CIDR",
+    "ip": "IP Address",
+    "port": "Port Check",
+    "host_bits_set": "Host bits are set; the block is",
+    "network_address": "Network address",
+    "broadcast_address": "Broadcast address",
+    "host_range": "Host range",
+    "host_count": "Usable hosts",
+    "total_addresses": "Total addresses",
+    "subnet_mask": "Subnet mask",
+    "wildcard_mask": "Wildcard mask",
+    "expanded": "Expanded",
+    "binary": "Binary",
+    "integer": "Integer",
+    "reverse_pointer": "Reverse DNS",
+    "mapped_ipv4": "Mapped IPv4",
+    "host": "Host",
+    "port_number": "Port",
+    "timeout_ms": "Timeout (ms)",
+    "check": "Check",
+    "checking": "Checking...",
+    "open": "Open",
+    "closed": "Closed",
+    "resolved_address": "Resolved address",
+    "response_time": "Response time",
+    "categories": {
+      "unspecified": "Unspecified",
+      "loopback": "Loopback",
+      "private": "Private",
+      "sharedAddress": "Shared (CGNAT)",
+      "linkLocal": "Link-local",
+      "uniqueLocal": "Unique local",
+      "multicast": "Multicast",
+      "broadcast": "Broadcast",
+      "documentation": "Documentation",
+      "reserved": "Reserved",
+      "global": "Global"
+    }
+  },
+  "sql_formatter": {
+    "title": "SQL Formatter",
+    "dialect": "Dialect",
+    "dialect_generic": "Generic",
+    "dialect_mysql": "MySQL",
+    "dialect_postgresql": "PostgreSQL",
+    "keyword_case": "Keywords",
+    "case_upper": "UPPERCASE",
+    "case_lower": "lowercase",
+    "case_preserve": "Keep as is",
+    "indent": "Indent",
+    "spaces": "spaces",
+    "columns_per_line": "Columns per line",
+    "input_placeholder": "Paste a SQL query..."
+  },
+  "file_renamer": {
+    "files": "Files",
+    "add_files": "Add Files",
+    "find": "Find",
+    "replace": "Replace with",
+    "use_regex": "Regex",
+    "case_insensitive": "Ignore case",
+    "prefix": "Prefix",
+    "suffix": "Suffix",
+    "lowercase_extension": "Lowercase extension",
+    "numbering": "Numbering",
+    "start": "Start",
+    "step": "Step",
+    "digits": "Digits",
+    "zero_pad": "Zero pad",
+    "before": "Before name",
+    "after": "After name",
+    "insert_date": "Insert modified date",
+    "date_format": "Format",
+    "preview": "Preview",
+    "changes": "changes",
+    "conflicts": "conflicts",
+    "apply": "Rename",
+    "undo": "Undo",
+    "renamed": "files renamed",
+    "undone": "Restored the original names",
+    "conflict_warning": "Some files would end up with the same name or overwrite an existing file. Resolve the conflicts to rename.",
+    "old_name": "Current name",
+    "new_name": "New name"
+  },
+  "duplicate_finder": {
+    "folders": "Folders",
+    "add_folder": "Add Folder",
+    "no_folders": "Add one or more folders to scan.",
+    "min_size": "Minimum size (KB)",
+    "extensions": "Extensions",
+    "include_hidden": "Include hidden files",
+    "follow_symlinks": "Follow symbolic links",
+    "scan": "Find Duplicates",
+    "scanning": "Scanning...",
+    "stages": {
+      "scanning": "Listing files",
+      "partialHash": "Comparing file headers",
+      "fullHash": "Comparing full contents"
+    },
+    "groups": "groups",
+    "files": "files",
+    "reclaimable": "reclaimable",
+    "scanned": "files scanned",
+    "skipped": "could not be read",
+    "select_keep_oldest": "Select all but oldest",
+    "clear_selection": "Clear selection",
+    "move_to_trash": "Move to Trash",
+    "no_duplicates": "No duplicate files found.",
+    "delete_failed": "Some files could not be moved to the trash"
+  },
+  "disk_analyzer": {
+    "select_folder": "Select Folder",
+    "no_folder": "No folder selected",
+    "depth": "Depth",
+    "top_files": "Largest files",
+    "analyze": "Analyze",
+    "scanning": "Scanning...",
+    "files": "files",
+    "folders": "folders",
+    "errors": "entries could not be read",
+    "name": "Name",
+    "size": "Size",
+    "largest_files": "Largest Files"
+  },
+  "clipboard_history": {
+    "start": "Start Monitoring",
+    "stop": "Stop Monitoring",
+    "monitoring": "Recording copied text",
+    "stopped": "Not recording",
+    "exclude_secrets": "Skip text that looks like a password",
+    "search": "Search history...",
+    "pinned": "Pinned",
+    "recent": "Recent",
+    "empty": "No copied text yet. Start monitoring and copy something.",
+    "pin": "Pin",
+    "unpin": "Unpin",
+    "chars": "chars"
+  },
+  "pomodoro": {
+    "idle": "Ready",
+    "work": "Focus",
+    "break": "Break",
+    "paused": "paused",
+    "completed": "completed",
+    "start": "Start",
+    "pause": "Pause",
+    "resume": "Resume",
+    "stop": "Stop",
+    "task": "Task",
+    "no_task": "No task",
+    "deleted_task": "Deleted task",
+    "work_minutes": "Work (min)",
+    "break_minutes": "Break (min)",
+    "stats": "Last 7 days",
+    "pomodoros": "pomodoros",
+    "minutes": "min"
+  },
+  "settings": {
+    "general": "General",
+    "language": "Language",
+    "theme": "Theme",
+    "theme_dark": "Dark",
+    "theme_light": "Light",
+    "output_dir": "Default output folder",
+    "output_dir_none": "Not set",
+    "select_folder": "Choose Folder",
+    "quality": "Default quality",
+    "output_format": "Default format",
+    "reset": "Reset to Defaults",
+    "theme_system": "System",
+    "due_notify": "Notify about overdue and due-today tasks",
+    "due_check_interval": "Due date check interval",
+    "minutes": "{n} min",
+    "save_password_history": "Save generated passwords to history"
+  },
+  "encoding_converter": {
+    "select_file": "Click to select a text file",
+    "hint": "CSV, logs and other text files",
+    "click_to_change": "Click to choose another file",
+    "detected": "Detected encoding",
+    "confidence": "Confidence",
+    "line_ending": "Line endings",
+    "options": "Conversion",
+    "source": "From",
+    "target": "To",
+    "auto_detect": "Auto detect",
+    "keep": "Keep",
+    "unmappable": "Unconvertible characters",
+    "replace": "Replace with ?",
+    "abort": "Stop conversion",
+    "add_bom": "Add BOM",
+    "convert": "Convert and save",
+    "done": "Converted",
+    "line": "Line",
+    "column": "Column",
+    "character": "Character",
+    "more": "…and {count} more"
+  },
+  "icon_exporter": {
+    "select_image": "Click to select an image",
+    "square_hint": "A square image of 512px or more works best",
+    "preset": "Size Set",
+    "custom": "Custom",
+    "sizes": "Sizes (px)",
+    "file_name": "File Name",
+    "template_hint": "Placeholders: {size}, {width}, {height}, {name}, {ext}",
+    "format": "Format",
+    "icns_hint": "icon.icns is not generated; run `tauri icon` on icon.png for macOS",
+    "options": "Options",
+    "square_crop": "Square (center crop)",
+    "long_edge": "Fit long edge",
+    "skip_upscale": "Skip sizes larger than the source",
+    "output_folder": "Output Folder",
+    "choose_folder": "Choose Folder",
+    "export": "Export",
+    "exporting": "Exporting...",
+    "skipped": "Skipped"
+  },
+  "radix_converter": {
+    "title": "Base Conversion",
+    "input_placeholder": "e.g. 0xFF, 0b1010, -42, 3.14",
+    "from_base": "From",
+    "to_base": "To",
+    "auto": "Auto",
+    "fraction_digits": "Fraction digits",
+    "hint": "0x / 0b / 0o prefixes are detected in Auto. Underscores and spaces are ignored. Integers up to 128 bits.",
+    "output": "Base",
+    "binary": "Binary",
+    "octal": "Octal",
+    "decimal": "Decimal",
+    "hexadecimal": "Hex",
+    "bits": "Bits ({bits} bit / {bytes} bytes)",
+    "detected_base": "Read as base {base}",
+    "fraction_truncated": "Fraction cut off at the digit limit",
+    "float_title": "IEEE 754 Floating Point",
+    "to_bits": "Number → Bits",
+    "from_bits": "Bits → Number",
+    "float_placeholder": "e.g. 0.1, -2.5e10, inf",
+    "bits_placeholder": "e.g. 0x3F800000 or 0b0011…",
+    "sign": "Sign",
+    "exponent": "Exponent",
+    "mantissa": "Mantissa",
+    "value": "Value",
+    "class_zero": "Zero",
+    "class_subnormal": "Subnormal",
+    "class_normal": "Normal",
+    "class_infinite": "Infinity",
+    "class_nan": "NaN"
+  },
+  "generation_history": {
+    "title": "Previously Generated",
+    "clear": "Clear History"
+  },
+  "business_days": {
+    "mode_add": "Add business days",
+    "mode_count": "Count business days",
+    "start_date": "Start date",
+    "end_date": "End date",
+    "days": "Business days (negative = before)",
+    "country": "Holidays",
+    "country_japan": "Japan",
+    "country_none": "Weekends only",
+    "custom_holidays": "Additional closure days (one per line)",
+    "custom_placeholder": "2025-12-29 Year-end closure",
+    "business_days_unit": "business days",
+    "calendar_days": "Calendar days",
+    "weekend_days": "Weekend days",
+    "holidays_skipped": "Holidays skipped",
+    "holiday_list": "Public holidays",
+    "kind_national": "National holiday",
+    "kind_substitute": "Substitute holiday",
+    "kind_citizens": "Citizens' holiday",
+    "kind_custom": "Closure day",
+    "weekday_0": "Sun",
+    "weekday_1": "Mon",
+    "weekday_2": "Tue",
+    "weekday_3": "Wed",
+    "weekday_4": "Thu",
+    "weekday_5": "Fri",
+    "weekday_6": "Sat"
+  },
+  "chmod_calculator": {
+    "title": "Unix Permissions",
+    "input_placeholder": "755, 0644, rwxr-xr-x or u+x,g-w",
+    "octal": "Octal",
+    "symbolic": "Symbolic",
+    "command": "Command",
+    "read": "Read",
+    "write": "Write",
+    "execute": "Execute",
+    "class_owner": "Owner",
+    "class_group": "Group",
+    "class_other": "Others",
+    "apply_change": "Apply a change",
+    "presets": "Common permissions"
+  },
+  "ocr": {
+    "language": "Language",
+    "language_english_japanese": "Japanese + English",
+    "language_japanese": "Japanese",
+    "language_english": "English",
+    "drop_image": "Drop an image here or click to select",
+    "click_to_change": "Click to choose another image",
+    "supported_formats": "PNG, JPEG, GIF, WebP, BMP, TIFF",
+    "recognizing": "Recognizing text...",
+    "no_text": "No text found in the image",
+    "text": "Text",
+    "lines": "Lines",
+    "confidence": "Confidence",
+    "engine_unavailable": "OCR engine is not available",
+    "engine_hint": "Install Tesseract with the language data (e.g. brew install tesseract tesseract-lang) and restart the app.",
+    "unreadable": "Could not read the image",
+    "recognition_failed": "Text recognition failed"
+  },
+  "workflows": {
+    "saved": "Saved workflows",
+    "empty": "No workflows yet. Build one below and save it.",
+    "edit": "Edit",
+    "delete": "Delete",
+    "run": "Run",
+    "run_button": "Run",
+    "select_workflow": "Select a workflow to run",
+    "drop_image": "Drop an image here or click to select",
+    "click_to_change": "Click to choose another image",
+    "failed_at": "Stopped at step {step}",
+    "intermediate_kept": "Outputs of the completed steps were kept in the run folder.",
+    "copy_base64": "Copy Base64",
+    "show_in_folder": "Show in folder",
+    "editor": "Workflow editor",
+    "name_placeholder": "Workflow name (saving with an existing name replaces it)",
+    "add_step": "Add step",
+    "save": "Save workflow",
+    "quality": "Quality",
+    "format": "Format",
+    "keep_format": "Keep format",
+    "width": "Width",
+    "height": "Height",
+    "maintain_aspect": "Keep aspect ratio",
+    "filter": "Filter",
+    "filter_grayscale": "Grayscale",
+    "filter_sepia": "Sepia",
+    "filter_invert": "Invert",
+    "filter_blur": "Blur",
+    "filter_sharpen": "Sharpen",
+    "base64_hint": "Outputs a data URL; must be the last step",
+    "command_compress_image": "Compress",
+    "command_resize_image": "Resize",
+    "command_apply_filter": "Filter",
+    "command_encode_image_to_base64": "Base64"
+  },
+  "japanese_text_converter": {
+    "input_placeholder": "Enter Japanese text to convert...",
+    "order_hint": "Selected conversions are applied in the numbered order.",
+    "output": "Result",
+    "use_as_input": "Use as input",
+    "input_length": "Before",
+    "output_length": "After",
+    "converted_count": "Converted",
+    "to_half_width_alnum": "Full-width alnum → half",
+    "to_full_width_alnum": "Half-width alnum → full",
+    "to_half_width_symbols": "Full-width symbols → half",
+    "to_full_width_symbols": "Half-width symbols → full",
+    "half_kana_to_full_kana": "Half-width kana → full",
+    "full_kana_to_half_kana": "Full-width kana → half",
+    "hiragana_to_katakana": "Hiragana → katakana",
+    "katakana_to_hiragana": "Katakana → hiragana",
+    "normalize_spaces": "Full-width spaces → half"
+  },
+  "system_info": {
+    "loading": "Reading system information...",
+    "overview": "System",
+    "interval": "Interval",
+    "pause": "Pause",
+    "resume": "Resume",
+    "refresh": "Refresh",
+    "os": "OS",
+    "kernel": "Kernel",
+    "host": "Host",
+    "cpu": "CPU",
+    "cores": "Cores",
+    "physical": "physical",
+    "logical": "logical",
+    "uptime": "Uptime",
+    "cpu_usage": "CPU Usage",
+    "memory": "Memory",
+    "swap": "Swap",
+    "per_core": "Per Core",
+    "disks": "Disks",
+    "no_disks": "No disks found",
+    "removable": "removable",
+    "free": "free"
+  },
+  "process_tools": {
+    "find_by_port": "Find Process by Port",
+    "find": "Find",
+    "port": "Port",
+    "port_free": "No process is using this port",
+    "invalid_port": "Enter a port between 1 and 65535",
+    "processes": "Processes",
+    "filter_placeholder": "Filter by name, command or PID",
+    "sort_cpu": "CPU",
+    "sort_memory": "Memory",
+    "sort_pid": "PID",
+    "sort_name": "Name",
+    "refresh": "Refresh",
+    "no_match": "No matching processes",
+    "name": "Name",
+    "memory": "Memory",
+    "command": "Command",
+    "kill": "Stop",
+    "force_kill": "Kill",
+    "confirm_kill": "Stop this process?",
+    "confirm_force_kill": "Force kill this process?",
+    "yes": "Yes",
+    "cancel": "Cancel",
+    "protected": "Protected",
+    "protected_hint": "This app, system processes and processes of other users cannot be stopped here"
+  },
+  "markdown_table": {
+    "input_placeholder": "Paste a Markdown table or CSV/TSV text",
+    "format": "Format",
+    "from_csv": "CSV → Table",
+    "to_csv": "Table → CSV",
+    "columns": "Columns and rows",
+    "row": "Row",
+    "column": "Column",
+    "insert_row": "Insert row",
+    "delete_row": "Delete row",
+    "insert_column": "Insert column",
+    "delete_column": "Delete column",
+    "output": "Output",
+    "use_as_input": "Use as input"
+  },
+  "env_converter": {
+    "mode_convert": "Convert",
+    "mode_diff": "Compare",
+    "convert": "Convert",
+    "variables": "variables",
+    "errors": "errors",
+    "line": "Line",
+    "duplicate_key": "Duplicate key",
+    "external_references": "References not defined in this file",
+    "left": "Left .env",
+    "right": "Right .env",
+    "compare": "Compare keys",
+    "common_keys": "keys in both files",
+    "only_left": "Only in left",
+    "only_right": "Only in right",
+    "changed": "Different values"
   }
 }"#;
 
@@ -522,8 +1475,43 @@ pub const JA_TRANSLATIONS: &str = r#"{
       "unix_time": "Unix時間",
       "shortcut_dictionary": "ショートカット",
       "char_counter": "文字数カウント",
-      "cheatsheet_viewer": "チートシート"
-    }
+      "cheatsheet_viewer": "チートシート",
+      "url_encoder": "URLエンコード",
+      "config_converter": "設定ファイル変換",
+      "color_converter": "カラー変換",
+      "dummy_data_generator": "ダミーデータ",
+      "cron_parser": "cron式パーサー",
+      "case_converter": "ケース変換",
+      "text_tools": "行ツール",
+      "escape_converter": "エスケープ変換",
+      "qr_generator": "QRコード",
+      "qr_reader": "QR読み取り",
+      "exif_tools": "EXIFビューア",
+      "image_converter": "フォーマット変換",
+      "http_client": "HTTPクライアント",
+      "network_tools": "ネットワークツール",
+      "sql_formatter": "SQLフォーマッター",
+      "file_renamer": "ファイルリネーム",
+      "duplicate_finder": "重複ファイル検出",
+      "disk_analyzer": "ディスク使用量",
+      "clipboard_history": "クリップボード履歴",
+      "pomodoro": "ポモドーロ",
+      "settings": "設定",
+      "encoding_converter": "文字コード変換",
+      "icon_exporter": "アイコン書き出し",
+      "radix_converter": "基数変換",
+      "business_days": "営業日計算",
+      "chmod_calculator": "chmod",
+      "ocr": "文字認識 (OCR)",
+      "workflows": "ワークフロー",
+      "japanese_text_converter": "全角/半角変換",
+      "system_info": "システム情報",
+      "process_tools": "プロセス",
+      "markdown_table": "Markdownテーブル",
+      "env_converter": ".env変換"
+    },
+    "settings": "設定",
+    "clipboard_cleared": "コピーした内容をクリップボードから消去しました"
   },
   "language_switcher": {
     "title": "言語",
@@ -547,11 +1535,34 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "count_label": "生成個数",
     "results_title": "生成結果",
     "items_count": "{count}件",
-    "validate_input_placeholder": "検証するUUIDを入力...",
+    "validate_input_placeholder": "検証するUUIDまたはULIDを入力...",
     "valid_uuid": "有効なUUID",
     "invalid_uuid": "無効なUUID",
     "version_info": "バージョン:",
-    "variant_info": "バリアント:"
+    "variant_info": "バリアント:",
+    "normalized_info": "正規化:",
+    "timestamp_utc_info": "タイムスタンプ (UTC):",
+    "timestamp_local_info": "タイムスタンプ (ローカル):",
+    "node_info": "ノード (MAC):",
+    "clock_sequence_info": "クロックシーケンス:",
+    "bulk_section": "一括検証",
+    "bulk_placeholder": "UUIDを1行に1つずつ貼り付け...",
+    "bulk_valid_count": "有効 {count}件",
+    "bulk_invalid_count": "無効 {count}件",
+    "bulk_line": "行",
+    "bulk_input": "UUID",
+    "version_v3": "UUID v3（MD5 名前ベース）",
+    "version_v5": "UUID v5（SHA-1 名前ベース）",
+    "version_ulid": "ULID",
+    "version_nanoid": "NanoID",
+    "namespace_label": "名前空間",
+    "name_label": "名前",
+    "name_placeholder": "1行に1つずつ入力（例: example.com）",
+    "length_label": "長さ",
+    "alphabet_label": "使用文字",
+    "entropy_info": "エントロピー: {bits} ビット",
+    "valid_ulid": "有効なULID",
+    "invalid_ulid": "無効なULID"
   },
   "password_generator": {
     "title": "パスワード生成",
@@ -582,7 +1593,23 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "generate_password": "パスワードを生成",
     "generate_passphrase": "パスフレーズを生成",
     "results_title": "生成結果",
-    "entropy_label": "エントロピー: {value} bits"
+    "entropy_label": "エントロピー: {value} bits",
+    "min_strength_label": "最低強度",
+    "min_strength_none": "指定なし",
+    "strength_score_1": "弱い以上 (1)",
+    "strength_score_2": "普通以上 (2)",
+    "strength_score_3": "強い以上 (3)",
+    "strength_score_4": "非常に強い (4)",
+    "crack_time": "推定解読時間",
+    "check_title": "パスワード強度チェック",
+    "check_placeholder": "評価するパスワードを入力...",
+    "wordlist_label": "辞書",
+    "wordlist_english": "英語（内蔵）",
+    "wordlist_japanese_romaji": "日本語ローマ字（内蔵）",
+    "wordlist_custom": "カスタムファイル",
+    "wordlist_select_file": "ファイルを選択",
+    "wordlist_no_file": "ファイル未選択（1行1単語）",
+    "word": "語"
   },
   "scratch_pad": {
     "title": "メモ帳",
@@ -591,7 +1618,25 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "no_additional_text": "追加テキストなし",
     "select_or_create": "メモを選択するか、新しく作成してください",
     "placeholder": "Markdown形式で入力を開始...",
-    "save_failed": "保存に失敗しました"
+    "save_failed": "保存に失敗しました",
+    "search_placeholder": "メモを検索...",
+    "no_results": "一致するメモはありません",
+    "title_placeholder": "タイトル（未入力なら1行目）",
+    "history": "履歴",
+    "history_empty": "保存された履歴はまだありません",
+    "restore": "復元",
+    "reminder": "リマインダー",
+    "reminder_in_minutes": "{n}分後",
+    "reminder_in_hours": "{n}時間後",
+    "reminder_set": "設定",
+    "reminder_cancel": "取り消し",
+    "reminder_empty": "リマインダーはありません",
+    "reminder_pending": "予定",
+    "reminder_fired": "通知済み",
+    "reminder_cancelled": "キャンセル済み",
+    "reminder_invalid_time": "日時を選択してください",
+    "export_format": "エクスポート形式",
+    "export_all": "すべてのノートをフォルダにエクスポート"
   },
   "regex_tester": {
     "title": "正規表現テスター",
@@ -615,7 +1660,7 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "test_placeholder": "パターンでテストするテキストを入力...",
     "export_results": "結果をエクスポート",
     "replace_title": "// 置換",
-    "replace_placeholder": "置換文字列（$1, $2でグループを参照）...",
+    "replace_placeholder": "置換文字列（$1, ${1}, ${name} でグループを参照、$$ で $ そのもの）...",
     "replacements_count": "{count}件の置換",
     "copy_result": "結果をコピー",
     "testing": "テスト中...",
@@ -625,7 +1670,21 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "highlighted_title": "// ハイライト表示",
     "details_title": "// マッチ詳細",
     "position": "位置: {start}..{end}",
-    "capture_groups": "キャプチャグループ:"
+    "capture_groups": "キャプチャグループ:",
+    "truncated": "10,000件で打ち切りました",
+    "replacement_pairs": "置換内容",
+    "category_japanese": "日本語",
+    "category_custom": "マイパターン",
+    "preset_name_placeholder": "プリセット名",
+    "preset_description_placeholder": "説明（任意）",
+    "save_preset": "現在のパターンを保存",
+    "show_samples": "サンプル生成",
+    "hide_samples": "サンプルを隠す",
+    "samples_title": "マッチするサンプルを生成",
+    "sample_count": "件数",
+    "sample_max_repeat": "最大繰り返し",
+    "generate_samples": "生成",
+    "use_samples": "テスト文字列に追加"
   },
   "unit_converter": {
     "title": "単位変換",
@@ -692,7 +1751,44 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "volume_gallon": "ガロン (gal)",
     "volume_quart": "クォート (qt)",
     "volume_pint": "パイント (pt)",
-    "volume_cup": "カップ"
+    "volume_cup": "カップ",
+    "category_currency": "通貨",
+    "rates_section": "為替レート",
+    "rates_update": "レートを更新",
+    "rates_updating": "更新中...",
+    "rates_updated_at": "{time} 時点のレート",
+    "rates_source_bundled": "同梱のデフォルト値",
+    "rates_source_api": "オンラインで取得",
+    "rates_stale": "レートが7日以上前のものです。正確な結果のために更新してください。",
+    "rates_offline_fallback": "キャッシュ済みのレートで計算します",
+    "show_all_units": "全単位表示",
+    "significant_digits": "有効桁数",
+    "significant_digits_auto": "自動",
+    "all_units_section": "全単位の変換結果",
+    "category_speed": "速度",
+    "category_pressure": "圧力",
+    "category_energy": "エネルギー",
+    "category_fuel_economy": "燃費",
+    "speed_kilometerperhour": "キロメートル毎時 (km/h)",
+    "speed_meterpersecond": "メートル毎秒 (m/s)",
+    "speed_mileperhour": "マイル毎時 (mph)",
+    "speed_knot": "ノット (kn)",
+    "pressure_pascal": "パスカル (Pa)",
+    "pressure_kilopascal": "キロパスカル (kPa)",
+    "pressure_bar": "バール (bar)",
+    "pressure_atmosphere": "気圧 (atm)",
+    "pressure_psi": "PSI",
+    "pressure_millimeterofmercury": "水銀柱ミリメートル (mmHg)",
+    "energy_joule": "ジュール (J)",
+    "energy_kilojoule": "キロジュール (kJ)",
+    "energy_calorie": "カロリー (cal)",
+    "energy_kilocalorie": "キロカロリー (kcal)",
+    "energy_kilowatthour": "キロワット時 (kWh)",
+    "energy_btu": "BTU",
+    "fuel_kilometerperliter": "キロメートル毎リットル (km/L)",
+    "fuel_literper100km": "リットル毎100km (L/100km)",
+    "fuel_mpgus": "マイル毎ガロン (米)",
+    "fuel_mpguk": "マイル毎ガロン (英)"
   },
   "image_compressor": {
     "compressing": "圧縮中...",
@@ -713,7 +1809,15 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "compression_failed": "圧縮に失敗しました",
     "original": "元のサイズ",
     "compressed": "圧縮後",
-    "saved": "削減率"
+    "saved": "削減率",
+    "target_size": "目標ファイルサイズ",
+    "target_size_enable": "ファイルサイズを指定して圧縮",
+    "allow_resize": "最低品質でも収まらない場合は解像度を縮小する",
+    "png_target_hint": "PNG には品質設定がないため、減色でサイズを近づけます。最低品質で減色の限度を決めます。",
+    "min_quality": "最低品質",
+    "color_bits": "チャンネルあたり {n} ビット",
+    "scale": "縮小率",
+    "attempts": "{n} 回試行"
   },
   "json_formatter": {
     "title": "// JSONフォーマッター",
@@ -733,7 +1837,35 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "more_results": "... 他{count}件",
     "input_placeholder": "JSONを貼り付け...",
     "tree_placeholder": "有効なJSONを入力するとツリービューが表示されます",
-    "error_details": "// エラー詳細"
+    "error_details": "// エラー詳細",
+    "query_placeholder_path": "$.items[*].name",
+    "query_placeholder_pointer": "/items/0/name",
+    "extract": "抽出",
+    "copy_extracted": "結果JSONをコピー",
+    "schema": "JSON Schema",
+    "schema_placeholder": "JSON Schema（draft-07 / 2020-12）を貼り付け...",
+    "validate_schema": "検証",
+    "schema_valid": "スキーマに適合しています",
+    "schema_violations": "件の違反",
+    "schema_error": "スキーマが不正です",
+    "diff": "差分比較",
+    "compare": "比較",
+    "diff_placeholder": "入力と比較する旧JSONを貼り付け...",
+    "diff_ignore_array_order": "配列の順序を無視",
+    "diff_id_key": "IDキー (例: id)",
+    "diff_ignore_number_format": "1 と 1.0 を同じ値とみなす",
+    "diff_ignore_key_order": "キーの順序を無視",
+    "diff_identical": "差分はありません",
+    "diff_type_changed": "型の変更",
+    "type_gen": "型定義生成",
+    "type_gen_generate": "生成",
+    "type_gen_root_name": "ルート型名",
+    "type_gen_camel_case": "snake_case → camelCase",
+    "optional_missing": "欠けていれば optional",
+    "optional_missing_or_null": "欠けているか null なら optional",
+    "optional_always": "すべて optional",
+    "optional_never": "optional にしない",
+    "tree_show_more": "さらに表示"
   },
   "base64_encoder": {
     "title": "Base64エンコード/デコード",
@@ -755,7 +1887,25 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "decode_btn": "デコード",
     "copy_data_url": "Data URLをコピー",
     "click_to_change": "クリックで画像を変更",
-    "original_size": "元サイズ:"
+    "original_size": "元サイズ:",
+    "mode_file": "ファイル",
+    "file_input": "ファイル入力",
+    "as_data_uri": "Data URI形式で出力（data:<mime>;base64,...）",
+    "select_file": "クリックしてファイルを選択",
+    "file_size_limit": "任意の形式、最大50MB",
+    "save_as_file": "ファイルとして保存",
+    "saved_to": "保存先",
+    "mode_convert": "相互変換",
+    "from": "変換元",
+    "to": "変換先",
+    "convert_btn": "変換",
+    "convert_placeholder": "テキスト・Base64・Base32・Hexを入力...",
+    "large_file": "大容量ファイル変換",
+    "large_file_hint": "ファイル全体を読み込まずにチャンク単位でファイルからファイルへ変換するため、数GBのファイルも扱えます。",
+    "encode_to_file": "ファイル → .b64 にエンコード",
+    "decode_to_file": ".b64 → ファイルにデコード",
+    "cancel": "キャンセル",
+    "large_file_cancelled": "変換をキャンセルしました"
   },
   "command_palette": {
     "search_placeholder": "ツールを検索...",
@@ -782,8 +1932,43 @@ pub const JA_TRANSLATIONS: &str = r#"{
       "base64": "Base64テキスト・画像のエンコード/デコード",
       "shortcut_dictionary": "VSCode, IntelliJ, Vim, ターミナルのキーボードショートカットを検索",
       "char_counter": "文字数、単語数、行数、バイト数をリアルタイムでカウント",
-      "cheatsheet_viewer": "Git, Docker, Kubernetes, tmux, Bashコマンドのクイックリファレンス"
-    }
+      "cheatsheet_viewer": "Git, Docker, Kubernetes, tmux, Bashコマンドのクイックリファレンス",
+      "url_encoder": "URLのパーセントエンコード/デコードとクエリ文字列の解析",
+      "config_converter": "JSON・YAML・TOMLの設定ファイルを相互変換",
+      "color_converter": "HEX・RGB・HSL・HSV・CMYKを相互変換し配色パレットを作成",
+      "dummy_data_generator": "Lorem Ipsumやテスト用レコードをJSON・CSV・SQLで生成",
+      "cron_parser": "cron式の意味と次回実行日時を表示",
+      "case_converter": "camelCase・snake_case・kebab-case などのケースを相互変換",
+      "text_tools": "テキスト行のソート・重複排除・シャッフル・整形",
+      "escape_converter": "HTMLエンティティ・\\uXXXX・JSON文字列・XMLのエスケープを相互変換",
+      "qr_generator": "URL・Wi-Fi設定・連絡先のQRコードを生成",
+      "qr_reader": "スクリーンショットや画像からQRコードを読み取る",
+      "exif_tools": "位置情報などのEXIFメタデータを確認・削除",
+      "image_converter": "HEICやICOを含む画像フォーマットを一括変換",
+      "http_client": "HTTPリクエストを送信してレスポンスを確認",
+      "network_tools": "CIDR計算・IP分類・ポートチェック",
+      "sql_formatter": "SQLを整形・1行化・キーワードの大文字小文字変換",
+      "file_renamer": "置換・連番・日付でファイル名を一括変更",
+      "duplicate_finder": "内容が同じファイルを見つけて余分なコピーをゴミ箱へ移動",
+      "disk_analyzer": "容量を多く使っているフォルダとファイルを確認",
+      "clipboard_history": "コピーしたテキストの履歴を検索・ピン留め",
+      "pomodoro": "タスクと連携した作業・休憩サイクルのタイマー",
+      "settings": "テーマ・言語・出力先フォルダ・各ツールの初期値",
+      "encoding_converter": "テキストファイルの文字コードを判定・変換（Shift_JIS / EUC-JP / UTF-16）",
+      "icon_exporter": "画像をアイコンやサムネイル用に複数サイズで書き出し",
+      "radix_converter": "数値の基数変換と IEEE 754 ビット表現の確認",
+      "business_days": "日本の祝日を考慮して営業日を加算・カウント",
+      "chmod_calculator": "Unix パーミッションを 755 と rwxr-xr-x で相互変換",
+      "ocr": "スクリーンショットや画像からテキストを抽出",
+      "workflows": "画像ツールの連続処理を保存してワンステップで実行",
+      "japanese_text_converter": "全角⇔半角、ひらがな⇔カタカナの文字種を変換",
+      "system_info": "このマシンのCPU・メモリ・ディスク使用量を確認",
+      "process_tools": "プロセス一覧の確認、ポートを使用中のプロセス検索と停止",
+      "markdown_table": "Markdownテーブルの列幅を揃え、CSVから生成",
+      "env_converter": ".envをJSON・YAML・docker-compose・shell exportに変換し、キーを比較"
+    },
+    "recent": "最近使ったツール",
+    "all_tools": "すべてのツール"
   },
   "char_counter": {
     "title": "文字数カウンター",
@@ -804,7 +1989,13 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "katakana": "カタカナ",
     "kanji": "漢字",
     "fullwidth": "全角",
-    "halfwidth": "半角"
+    "halfwidth": "半角",
+    "grapheme_count": "書記素数",
+    "char_count_no_newlines": "文字数（改行除く）",
+    "conversions": "換算",
+    "manuscript_pages": "原稿用紙（400字詰め）",
+    "tweet_length": "ツイート換算",
+    "tweet_remaining": "残り {count}"
   },
   "shortcut_dictionary": {
     "title": "ショートカットキー辞典",
@@ -856,7 +2047,32 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "date": "日付",
     "time": "時刻",
     "day_of_week": "曜日",
-    "relative_time": "相対時間"
+    "relative_time": "相対時間",
+    "timezone_iana": "IANAタイムゾーン",
+    "alternative_unix_seconds": "Unix（遅い方のオフセット）",
+    "mode_calculate": "期間計算",
+    "add_duration": "期間の加算・減算",
+    "difference": "日時の差",
+    "diff_from": "開始タイムスタンプ",
+    "diff_to": "終了タイムスタンプ",
+    "calculate": "計算",
+    "duration": "期間",
+    "duration_breakdown": "内訳",
+    "total_seconds": "合計秒数",
+    "total_milliseconds": "合計ミリ秒",
+    "duration_years": "年",
+    "duration_months": "月",
+    "duration_days": "日",
+    "duration_hours": "時間",
+    "duration_minutes": "分",
+    "duration_seconds": "秒",
+    "unit_auto": "自動（13桁以上はミリ秒）",
+    "mode_batch": "一括変換",
+    "batch_input": "タイムスタンプ",
+    "batch_placeholder": "改行・カンマ・空白区切りでタイムスタンプを貼り付け...",
+    "batch_original": "元の値",
+    "copy_tsv": "TSVでコピー",
+    "batch_truncated": "先頭{count}行のみ表示しています。全件はTSVでコピーしてください。"
   },
   "input_history": {
     "title": "履歴",
@@ -907,5 +2123,695 @@ pub const JA_TRANSLATIONS: &str = r#"{
     "cat_text_processing": "テキスト処理",
     "cat_process": "プロセス",
     "cat_navigation": "ナビゲーション"
+  },
+  "url_encoder": {
+    "title": "URLエンコード/デコード",
+    "mode_encode": "エンコード",
+    "mode_decode": "デコード",
+    "mode_query": "クエリ解析",
+    "encode_all": "予約文字もエンコード (encodeURIComponent)",
+    "encode_all_hint": "A-Z a-z 0-9 - _ . ! ~ * ' ( ) 以外をすべてエンコードします",
+    "encode_uri_hint": ": / ? & = # などURL構造の文字は保持します (encodeURI)",
+    "text_input": "テキスト入力",
+    "encoded_input": "エンコード済み入力",
+    "url_input": "URL / クエリ文字列",
+    "encode_placeholder": "エンコードするテキストまたはURLを入力...",
+    "decode_placeholder": "デコードするパーセントエンコード文字列を入力...",
+    "query_placeholder": "https://example.com/?a=1&b=%E3%81%82",
+    "encode_btn": "エンコード",
+    "decode_btn": "デコード",
+    "parse_btn": "解析",
+    "encoded_output": "エンコード結果",
+    "decoded_output": "デコード結果",
+    "query_params": "クエリパラメータ",
+    "base_url": "ベースURL",
+    "fragment": "フラグメント",
+    "key": "キー",
+    "value": "値",
+    "raw_value": "元の値",
+    "no_params": "クエリパラメータが見つかりません"
+  },
+  "config_converter": {
+    "from": "変換元",
+    "to": "変換先",
+    "convert_btn": "変換",
+    "input_placeholder": "JSON・YAML・TOMLを貼り付け..."
+  },
+  "color_converter": {
+    "input_label": "カラー",
+    "input_placeholder": "tomato、#ff8800、rgb(255, 136, 0)、hsl(32, 100%, 50%) など",
+    "alpha": "透明度",
+    "invalid_part": "解釈できなかった部分",
+    "palette_section": "パレット",
+    "palette_complementary": "補色",
+    "palette_analogous": "類似色",
+    "palette_triadic": "トライアド",
+    "palette_shades": "明度シェード"
+  },
+  "dummy_data": {
+    "mode_lorem": "Lorem Ipsum",
+    "mode_records": "レコード",
+    "paragraphs": "段落数",
+    "words_per_paragraph": "段落あたりの単語数",
+    "japanese": "日本語テキスト",
+    "count": "件数",
+    "format": "出力形式",
+    "table_name": "テーブル名",
+    "seed": "シード",
+    "seed_placeholder": "空欄でランダム",
+    "reuse_seed": "このシードで同じ結果を再生成",
+    "invalid_seed": "シードは0〜{max}の整数で指定してください",
+    "fields": "フィールド",
+    "field_name": "フィールド名",
+    "add_field": "フィールドを追加",
+    "min": "最小",
+    "max": "最大",
+    "generate": "生成",
+    "lorem_summary": "{words}語 / {chars}文字",
+    "records_summary": "{count}件",
+    "kind_name_ja": "氏名（日本語）",
+    "kind_name_en": "氏名（英語）",
+    "kind_email": "メールアドレス",
+    "kind_phone": "電話番号",
+    "kind_address": "住所",
+    "kind_date": "日付",
+    "kind_integer": "整数",
+    "kind_uuid": "UUID",
+    "kind_boolean": "真偽値"
+  },
+  "cron_parser": {
+    "input_placeholder": "*/5 * * * *",
+    "examples": "例",
+    "normalized": "展開後",
+    "next_runs": "次回実行日時（10件）",
+    "field_second": "秒",
+    "field_minute": "分",
+    "field_hour": "時",
+    "field_day_of_month": "日",
+    "field_month": "月",
+    "field_day_of_week": "曜日"
+  },
+  "case_converter": {
+    "input_placeholder": "識別子や文章を入力（例: HTTPServerConfig, user_name, Hello World）",
+    "multiline": "行ごとに変換",
+    "detected": "検出されたスタイル",
+    "detected_unknown": "不明",
+    "words": "単語"
+  },
+  "text_tools": {
+    "pipeline": "処理",
+    "add_operation": "+ 処理を追加",
+    "no_operations": "処理がありません。追加すると行の処理を開始します。",
+    "input": "入力",
+    "output": "出力",
+    "lines": "行",
+    "input_placeholder": "テキストを貼り付け...",
+    "use_as_input": "入力に反映",
+    "shuffle_seed": "シャッフルのシード",
+    "removed_duplicates": "除去された重複行",
+    "move_up": "上へ",
+    "move_down": "下へ",
+    "remove": "削除",
+    "descending": "降順",
+    "ignore_case": "大文字小文字を無視",
+    "seed_placeholder": "シード（空欄でランダム）",
+    "text_placeholder": "テキスト",
+    "start": "開始",
+    "separator": "区切り",
+    "sort_lexical": "辞書順",
+    "sort_natural": "自然順 (file2 < file10)",
+    "sort_numeric": "数値順",
+    "sort_length": "長さ順",
+    "op_sort": "ソート",
+    "op_dedup": "重複を除去",
+    "op_reverse": "逆順",
+    "op_shuffle": "シャッフル",
+    "op_trim": "前後の空白を除去",
+    "op_remove_empty": "空行を除去",
+    "op_add_line_numbers": "行番号を付加",
+    "op_add_prefix": "先頭に追加",
+    "op_add_suffix": "末尾に追加",
+    "op_remove_prefix": "先頭から除去",
+    "op_remove_suffix": "末尾から除去"
+  },
+  "escape_converter": {
+    "escape": "エスケープ",
+    "unescape": "アンエスケープ",
+    "format_html": "HTMLエンティティ",
+    "format_unicode": "Unicodeエスケープ (\\uXXXX)",
+    "format_json": "JSON文字列",
+    "format_xml": "XML",
+    "format_auto": "自動判別（混在可）",
+    "style_named": "名前付き (&amp;)",
+    "style_decimal": "10進数 (&#38;)",
+    "style_hex": "16進数 (&#x26;)",
+    "encode_non_ascii": "非ASCII文字もエスケープ",
+    "input": "入力",
+    "output": "出力",
+    "escape_placeholder": "エスケープするテキストを入力...",
+    "unescape_placeholder": "&amp;、&#x3042;、\\u3042 などを含むテキストを入力...",
+    "position": "位置",
+    "replaced": "件変換"
+  },
+  "qr_generator": {
+    "mode_text": "テキスト / URL",
+    "mode_wifi": "Wi-Fi",
+    "mode_vcard": "連絡先 (vCard)",
+    "text_placeholder": "URLまたはテキストを入力",
+    "ssid": "ネットワーク名 (SSID)",
+    "password": "パスワード",
+    "security": "暗号化方式",
+    "security_none": "なし（オープン）",
+    "hidden": "ステルスネットワーク",
+    "vcard_last_name": "姓",
+    "vcard_first_name": "名",
+    "vcard_organization": "会社・組織",
+    "vcard_title": "役職",
+    "vcard_phone": "電話番号",
+    "vcard_email": "メール",
+    "vcard_url": "Webサイト",
+    "vcard_address": "住所",
+    "vcard_note": "メモ",
+    "size": "サイズ",
+    "margin": "余白",
+    "error_correction": "誤り訂正レベル",
+    "foreground": "前景色",
+    "background": "背景色",
+    "version": "バージョン",
+    "bytes": "バイト",
+    "save": "PNG / SVGで保存",
+    "saved": "保存しました",
+    "empty": "内容を入力するとQRコードをプレビューします"
+  },
+  "qr_reader": {
+    "drop_image": "画像をドロップ、またはクリックして選択",
+    "supported_formats": "PNG, JPEG, GIF, WebP, BMP",
+    "click_to_change": "クリックまたは別の画像をドロップして変更",
+    "results": "検出したコード",
+    "password": "パスワード",
+    "security": "暗号化方式",
+    "open_network": "なし（オープン）",
+    "hidden": "ステルスネットワーク",
+    "not_found": "画像内にQRコードが見つかりませんでした",
+    "decode_failed": "QRコードを検出しましたが、読み取れませんでした",
+    "unreadable": "画像を読み込めませんでした"
+  },
+  "exif_tools": {
+    "drop_image": "画像をドロップ、またはクリックして選択",
+    "supported_formats": "JPEG, PNG, WebP, TIFF, HEIC",
+    "click_to_change": "クリックまたは別の画像をドロップして変更",
+    "no_exif": "この画像にEXIF情報はありません",
+    "gps_warning": "この画像には位置情報が含まれています",
+    "decimal": "10進数",
+    "dms": "度分秒",
+    "altitude": "高度",
+    "open_map": "地図で開く",
+    "summary": "概要",
+    "camera": "カメラ",
+    "lens": "レンズ",
+    "date_time": "撮影日時",
+    "iso": "ISO感度",
+    "shutter": "シャッター速度",
+    "aperture": "絞り",
+    "focal_length": "焦点距離",
+    "orientation": "向き",
+    "software": "ソフトウェア",
+    "all_fields": "すべての項目",
+    "keep_orientation": "回転情報を残す",
+    "strip": "メタデータを削除して保存"
+  },
+  "image_converter": {
+    "drop_images": "画像をドロップするかクリックして追加",
+    "supported_formats": "PNG, JPEG, WebP, AVIF, GIF, BMP, TIFF, HEIC",
+    "files": "ファイル",
+    "output_format": "出力フォーマット",
+    "quality": "品質",
+    "ico_hint": "16, 32, 48, 64, 128, 256 px のうち元画像以下のサイズを含めます",
+    "jpeg_hint": "透明部分は白で塗りつぶされます",
+    "convert": "保存先を選んで変換",
+    "succeeded": "変換完了"
+  },
+  "http_client": {
+    "send": "送信",
+    "sending": "送信中...",
+    "headers": "ヘッダー",
+    "add_header": "+ ヘッダーを追加",
+    "body": "ボディ",
+    "timeout": "タイムアウト (秒)",
+    "follow_redirects": "リダイレクトを追跡",
+    "allow_invalid_certs": "無効な証明書を許可",
+    "binary_body": "バイナリレスポンスはBase64で表示しています",
+    "truncated": "レスポンスボディは切り詰められました"
+  },
+  "network_tools": {
+    "cidr": "CIDR",
+    "ip": "IPアドレス",
+    "port": "ポートチェック",
+    "host_bits_set": "ホスト部にビットが立っています。ブロックは",
+    "network_address": "ネットワークアドレス",
+    "broadcast_address": "ブロードキャストアドレス",
+    "host_range": "ホスト範囲",
+    "host_count": "利用可能ホスト数",
+    "total_addresses": "総アドレス数",
+    "subnet_mask": "サブネットマスク",
+    "wildcard_mask": "ワイルドカードマスク",
+    "expanded": "展開形式",
+    "binary": "2進数",
+    "integer": "整数",
+    "reverse_pointer": "逆引きDNS",
+    "mapped_ipv4": "マップされたIPv4",
+    "host": "ホスト",
+    "port_number": "ポート",
+    "timeout_ms": "タイムアウト (ms)",
+    "check": "チェック",
+    "checking": "チェック中...",
+    "open": "開いています",
+    "closed": "閉じています",
+    "resolved_address": "解決されたアドレス",
+    "response_time": "応答時間",
+    "categories": {
+      "unspecified": "未指定",
+      "loopback": "ループバック",
+      "private": "プライベート",
+      "sharedAddress": "共有 (CGNAT)",
+      "linkLocal": "リンクローカル",
+      "uniqueLocal": "ユニークローカル",
+      "multicast": "マルチキャスト",
+      "broadcast": "ブロードキャスト",
+      "documentation": "ドキュメント用",
+      "reserved": "予約済み",
+      "global": "グローバル"
+    }
+  },
+  "sql_formatter": {
+    "title": "SQLフォーマッター",
+    "dialect": "方言",
+    "dialect_generic": "汎用",
+    "dialect_mysql": "MySQL",
+    "dialect_postgresql": "PostgreSQL",
+    "keyword_case": "キーワード",
+    "case_upper": "大文字",
+    "case_lower": "小文字",
+    "case_preserve": "そのまま",
+    "indent": "インデント",
+    "spaces": "スペース",
+    "columns_per_line": "1行あたりのカラム数",
+    "input_placeholder": "SQLクエリを貼り付け..."
+  },
+  "file_renamer": {
+    "files": "ファイル",
+    "add_files": "ファイルを追加",
+    "find": "検索",
+    "replace": "置換後",
+    "use_regex": "正規表現",
+    "case_insensitive": "大文字小文字を区別しない",
+    "prefix": "接頭辞",
+    "suffix": "接尾辞",
+    "lowercase_extension": "拡張子を小文字にする",
+    "numbering": "連番",
+    "start": "開始",
+    "step": "増分",
+    "digits": "桁数",
+    "zero_pad": "ゼロ埋め",
+    "before": "名前の前",
+    "after": "名前の後",
+    "insert_date": "更新日時を挿入",
+    "date_format": "書式",
+    "preview": "プレビュー",
+    "changes": "件の変更",
+    "conflicts": "件の競合",
+    "apply": "リネーム",
+    "undo": "元に戻す",
+    "renamed": "件のファイルをリネームしました",
+    "undone": "元の名前に戻しました",
+    "conflict_warning": "同じ名前になるファイル、または既存ファイルを上書きするファイルがあります。競合を解消してからリネームしてください。",
+    "old_name": "現在の名前",
+    "new_name": "新しい名前"
+  },
+  "duplicate_finder": {
+    "folders": "フォルダ",
+    "add_folder": "フォルダを追加",
+    "no_folders": "スキャンするフォルダを追加してください。",
+    "min_size": "最小サイズ (KB)",
+    "extensions": "拡張子",
+    "include_hidden": "隠しファイルを含める",
+    "follow_symlinks": "シンボリックリンクをたどる",
+    "scan": "重複を検索",
+    "scanning": "スキャン中...",
+    "stages": {
+      "scanning": "ファイルを列挙中",
+      "partialHash": "ファイル先頭を比較中",
+      "fullHash": "ファイル全体を比較中"
+    },
+    "groups": "グループ",
+    "files": "ファイル",
+    "reclaimable": "削減可能",
+    "scanned": "ファイルをスキャン",
+    "skipped": "件は読み込めませんでした",
+    "select_keep_oldest": "最も古いもの以外を選択",
+    "clear_selection": "選択を解除",
+    "move_to_trash": "ゴミ箱へ移動",
+    "no_duplicates": "重複ファイルは見つかりませんでした。",
+    "delete_failed": "ゴミ箱へ移動できなかったファイルがあります"
+  },
+  "disk_analyzer": {
+    "select_folder": "フォルダを選択",
+    "no_folder": "フォルダが選択されていません",
+    "depth": "階層",
+    "top_files": "大きいファイル",
+    "analyze": "解析",
+    "scanning": "スキャン中...",
+    "files": "ファイル",
+    "folders": "フォルダ",
+    "errors": "件は読み込めませんでした",
+    "name": "名前",
+    "size": "サイズ",
+    "largest_files": "大きいファイル"
+  },
+  "clipboard_history": {
+    "start": "監視を開始",
+    "stop": "監視を停止",
+    "monitoring": "コピーを記録中",
+    "stopped": "記録していません",
+    "exclude_secrets": "パスワードらしき文字列を除外",
+    "search": "履歴を検索...",
+    "pinned": "ピン留め",
+    "recent": "最近の履歴",
+    "empty": "まだ履歴がありません。監視を開始して何かコピーしてください。",
+    "pin": "ピン留め",
+    "unpin": "ピン留め解除",
+    "chars": "文字"
+  },
+  "pomodoro": {
+    "idle": "待機中",
+    "work": "作業",
+    "break": "休憩",
+    "paused": "一時停止中",
+    "completed": "完了",
+    "start": "開始",
+    "pause": "一時停止",
+    "resume": "再開",
+    "stop": "停止",
+    "task": "タスク",
+    "no_task": "タスクなし",
+    "deleted_task": "削除されたタスク",
+    "work_minutes": "作業時間（分）",
+    "break_minutes": "休憩時間（分）",
+    "stats": "過去7日間",
+    "pomodoros": "ポモドーロ",
+    "minutes": "分"
+  },
+  "settings": {
+    "general": "一般",
+    "language": "言語",
+    "theme": "テーマ",
+    "theme_dark": "ダーク",
+    "theme_light": "ライト",
+    "output_dir": "デフォルトの出力先",
+    "output_dir_none": "未設定",
+    "select_folder": "フォルダを選択",
+    "quality": "デフォルトの品質",
+    "output_format": "デフォルトの形式",
+    "reset": "初期設定に戻す",
+    "theme_system": "システムに合わせる",
+    "due_notify": "期限切れ・本日締切のタスクを通知",
+    "due_check_interval": "期限チェックの間隔",
+    "minutes": "{n}分",
+    "save_password_history": "生成したパスワードを履歴に保存"
+  },
+  "encoding_converter": {
+    "select_file": "クリックしてテキストファイルを選択",
+    "hint": "CSV・ログなどのテキストファイル",
+    "click_to_change": "クリックして別のファイルを選択",
+    "detected": "判定結果",
+    "confidence": "信頼度",
+    "line_ending": "改行コード",
+    "options": "変換設定",
+    "source": "変換元",
+    "target": "変換先",
+    "auto_detect": "自動判定",
+    "keep": "変更しない",
+    "unmappable": "変換できない文字",
+    "replace": "? に置換",
+    "abort": "変換を中断",
+    "add_bom": "BOM を付ける",
+    "convert": "変換して保存",
+    "done": "変換しました",
+    "line": "行",
+    "column": "列",
+    "character": "文字",
+    "more": "…ほか {count} 件"
+  },
+  "icon_exporter": {
+    "select_image": "クリックして画像を選択",
+    "square_hint": "512px以上の正方形画像がおすすめです",
+    "preset": "サイズセット",
+    "custom": "カスタム",
+    "sizes": "サイズ (px)",
+    "file_name": "ファイル名",
+    "template_hint": "プレースホルダー: {size}, {width}, {height}, {name}, {ext}",
+    "format": "形式",
+    "icns_hint": "icon.icns は生成されません。macOS 用には icon.png に対して `tauri icon` を実行してください",
+    "options": "オプション",
+    "square_crop": "正方形 (中央切り抜き)",
+    "long_edge": "長辺に合わせる",
+    "skip_upscale": "元画像より大きいサイズはスキップ",
+    "output_folder": "出力フォルダ",
+    "choose_folder": "フォルダを選択",
+    "export": "書き出し",
+    "exporting": "書き出し中...",
+    "skipped": "スキップ"
+  },
+  "radix_converter": {
+    "title": "基数変換",
+    "input_placeholder": "例: 0xFF, 0b1010, -42, 3.14",
+    "from_base": "変換元",
+    "to_base": "変換先",
+    "auto": "自動",
+    "fraction_digits": "小数桁数",
+    "hint": "自動では 0x / 0b / 0o プレフィックスを判別します。アンダースコアと空白は無視されます。整数は128ビットまで対応。",
+    "output": "基数",
+    "binary": "2進数",
+    "octal": "8進数",
+    "decimal": "10進数",
+    "hexadecimal": "16進数",
+    "bits": "ビット ({bits}ビット / {bytes}バイト)",
+    "detected_base": "{base}進数として解釈",
+    "fraction_truncated": "小数部を指定桁数で打ち切りました",
+    "float_title": "IEEE 754 浮動小数点",
+    "to_bits": "数値 → ビット",
+    "from_bits": "ビット → 数値",
+    "float_placeholder": "例: 0.1, -2.5e10, inf",
+    "bits_placeholder": "例: 0x3F800000 または 0b0011…",
+    "sign": "符号",
+    "exponent": "指数",
+    "mantissa": "仮数",
+    "value": "値",
+    "class_zero": "ゼロ",
+    "class_subnormal": "非正規化数",
+    "class_normal": "正規化数",
+    "class_infinite": "無限大",
+    "class_nan": "NaN"
+  },
+  "generation_history": {
+    "title": "過去の生成分",
+    "clear": "履歴をクリア"
+  },
+  "business_days": {
+    "mode_add": "営業日を加算",
+    "mode_count": "営業日数を数える",
+    "start_date": "開始日",
+    "end_date": "終了日",
+    "days": "営業日数（負の値で前）",
+    "country": "祝日",
+    "country_japan": "日本",
+    "country_none": "土日のみ",
+    "custom_holidays": "独自の休業日（1行に1日）",
+    "custom_placeholder": "2025-12-29 年末休業",
+    "business_days_unit": "営業日",
+    "calendar_days": "暦日数",
+    "weekend_days": "土日",
+    "holidays_skipped": "休日",
+    "holiday_list": "祝日一覧",
+    "kind_national": "国民の祝日",
+    "kind_substitute": "振替休日",
+    "kind_citizens": "国民の休日",
+    "kind_custom": "休業日",
+    "weekday_0": "日",
+    "weekday_1": "月",
+    "weekday_2": "火",
+    "weekday_3": "水",
+    "weekday_4": "木",
+    "weekday_5": "金",
+    "weekday_6": "土"
+  },
+  "chmod_calculator": {
+    "title": "Unix パーミッション",
+    "input_placeholder": "755、0644、rwxr-xr-x、u+x,g-w など",
+    "octal": "8進数",
+    "symbolic": "シンボリック",
+    "command": "コマンド",
+    "read": "読み取り",
+    "write": "書き込み",
+    "execute": "実行",
+    "class_owner": "所有者",
+    "class_group": "グループ",
+    "class_other": "その他",
+    "apply_change": "変更を適用",
+    "presets": "よく使うパーミッション"
+  },
+  "ocr": {
+    "language": "言語",
+    "language_english_japanese": "日本語 + 英語",
+    "language_japanese": "日本語",
+    "language_english": "英語",
+    "drop_image": "画像をドロップ、またはクリックして選択",
+    "click_to_change": "クリックして別の画像を選択",
+    "supported_formats": "PNG, JPEG, GIF, WebP, BMP, TIFF",
+    "recognizing": "文字を認識中...",
+    "no_text": "画像からテキストが見つかりませんでした",
+    "text": "テキスト",
+    "lines": "行",
+    "confidence": "信頼度",
+    "engine_unavailable": "OCRエンジンが利用できません",
+    "engine_hint": "Tesseractと言語データをインストールしてからアプリを再起動してください（例: brew install tesseract tesseract-lang）。",
+    "unreadable": "画像を読み込めませんでした",
+    "recognition_failed": "文字認識に失敗しました"
+  },
+  "workflows": {
+    "saved": "保存済みワークフロー",
+    "empty": "ワークフローはまだありません。下で作成して保存してください。",
+    "edit": "編集",
+    "delete": "削除",
+    "run": "実行",
+    "run_button": "実行",
+    "select_workflow": "実行するワークフローを選択",
+    "drop_image": "画像をドロップまたはクリックして選択",
+    "click_to_change": "クリックして別の画像を選択",
+    "failed_at": "ステップ {step} で停止しました",
+    "intermediate_kept": "完了したステップの出力は実行フォルダに残っています。",
+    "copy_base64": "Base64をコピー",
+    "show_in_folder": "フォルダで表示",
+    "editor": "ワークフロー編集",
+    "name_placeholder": "ワークフロー名（同じ名前で保存すると上書き）",
+    "add_step": "ステップを追加",
+    "save": "ワークフローを保存",
+    "quality": "品質",
+    "format": "形式",
+    "keep_format": "形式を維持",
+    "width": "幅",
+    "height": "高さ",
+    "maintain_aspect": "縦横比を維持",
+    "filter": "フィルター",
+    "filter_grayscale": "グレースケール",
+    "filter_sepia": "セピア",
+    "filter_invert": "反転",
+    "filter_blur": "ぼかし",
+    "filter_sharpen": "シャープ",
+    "base64_hint": "データURLを出力します（最後のステップのみ）",
+    "command_compress_image": "圧縮",
+    "command_resize_image": "リサイズ",
+    "command_apply_filter": "フィルター",
+    "command_encode_image_to_base64": "Base64"
+  },
+  "japanese_text_converter": {
+    "input_placeholder": "変換する日本語テキストを入力...",
+    "order_hint": "選択した変換は番号の順に適用されます。",
+    "output": "変換結果",
+    "use_as_input": "入力に反映",
+    "input_length": "変換前",
+    "output_length": "変換後",
+    "converted_count": "変換数",
+    "to_half_width_alnum": "全角英数 → 半角",
+    "to_full_width_alnum": "半角英数 → 全角",
+    "to_half_width_symbols": "全角記号 → 半角",
+    "to_full_width_symbols": "半角記号 → 全角",
+    "half_kana_to_full_kana": "半角カナ → 全角",
+    "full_kana_to_half_kana": "全角カナ → 半角",
+    "hiragana_to_katakana": "ひらがな → カタカナ",
+    "katakana_to_hiragana": "カタカナ → ひらがな",
+    "normalize_spaces": "全角スペース → 半角"
+  },
+  "system_info": {
+    "loading": "システム情報を取得中...",
+    "overview": "システム",
+    "interval": "更新間隔",
+    "pause": "一時停止",
+    "resume": "再開",
+    "refresh": "再取得",
+    "os": "OS",
+    "kernel": "カーネル",
+    "host": "ホスト名",
+    "cpu": "CPU",
+    "cores": "コア数",
+    "physical": "物理",
+    "logical": "論理",
+    "uptime": "稼働時間",
+    "cpu_usage": "CPU使用率",
+    "memory": "メモリ",
+    "swap": "スワップ",
+    "per_core": "コア別",
+    "disks": "ディスク",
+    "no_disks": "ディスクが見つかりません",
+    "removable": "リムーバブル",
+    "free": "空き"
+  },
+  "process_tools": {
+    "find_by_port": "ポートからプロセスを検索",
+    "find": "検索",
+    "port": "ポート",
+    "port_free": "このポートを使用しているプロセスはありません",
+    "invalid_port": "1〜65535のポート番号を入力してください",
+    "processes": "プロセス",
+    "filter_placeholder": "名前・コマンド・PIDで絞り込み",
+    "sort_cpu": "CPU",
+    "sort_memory": "メモリ",
+    "sort_pid": "PID",
+    "sort_name": "名前",
+    "refresh": "更新",
+    "no_match": "一致するプロセスはありません",
+    "name": "名前",
+    "memory": "メモリ",
+    "command": "コマンド",
+    "kill": "停止",
+    "force_kill": "強制終了",
+    "confirm_kill": "このプロセスを停止しますか？",
+    "confirm_force_kill": "このプロセスを強制終了しますか？",
+    "yes": "はい",
+    "cancel": "キャンセル",
+    "protected": "保護",
+    "protected_hint": "このアプリ自身、システムプロセス、他ユーザーのプロセスはここから停止できません"
+  },
+  "markdown_table": {
+    "input_placeholder": "MarkdownテーブルまたはCSV/TSVを貼り付け",
+    "format": "整形",
+    "from_csv": "CSV → テーブル",
+    "to_csv": "テーブル → CSV",
+    "columns": "列と行",
+    "row": "行",
+    "column": "列",
+    "insert_row": "行を挿入",
+    "delete_row": "行を削除",
+    "insert_column": "列を挿入",
+    "delete_column": "列を削除",
+    "output": "出力",
+    "use_as_input": "入力に反映"
+  },
+  "env_converter": {
+    "mode_convert": "変換",
+    "mode_diff": "比較",
+    "convert": "変換",
+    "variables": "個の変数",
+    "errors": "件のエラー",
+    "line": "行",
+    "duplicate_key": "重複キー",
+    "external_references": "このファイルで未定義の参照",
+    "left": "左の .env",
+    "right": "右の .env",
+    "compare": "キーを比較",
+    "common_keys": "個のキーが両方に存在",
+    "only_left": "左にのみ存在",
+    "only_right": "右にのみ存在",
+    "changed": "値が異なる"
   }
 }"#;